@@ -0,0 +1,416 @@
+use crate::format::Base58Format;
+use crate::network::Base58Network;
+use crate::private_key::Base58PrivateKey;
+use crate::public_key::Base58PublicKey;
+use wagyu_model::no_std::*;
+use wagyu_model::{crypto::hash160, encoding::base58check, Address, AddressError, PrivateKey};
+
+use base58::FromBase58;
+use core::{convert::TryFrom, fmt, marker::PhantomData, str::FromStr};
+
+/// Represents a base58 address
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Base58Address<N: Base58Network> {
+    /// The base58 address
+    address: String,
+    /// The format of the address
+    format: Base58Format,
+    /// PhantomData
+    _network: PhantomData<N>,
+}
+
+impl<N: Base58Network> Address for Base58Address<N> {
+    type Format = Base58Format;
+    type PrivateKey = Base58PrivateKey<N>;
+    type PublicKey = Base58PublicKey<N>;
+
+    /// Returns the address corresponding to the given private key.
+    fn from_private_key(private_key: &Self::PrivateKey, format: &Self::Format) -> Result<Self, AddressError> {
+        Self::from_public_key(&private_key.to_public_key(), format)
+    }
+
+    /// Returns the address corresponding to the given public key.
+    fn from_public_key(public_key: &Self::PublicKey, format: &Self::Format) -> Result<Self, AddressError> {
+        match format {
+            Base58Format::P2PKH => Self::p2pkh(public_key),
+        }
+    }
+}
+
+impl<N: Base58Network> Base58Address<N> {
+    /// Returns a P2PKH address from a given public key.
+    pub fn p2pkh(public_key: &<Self as Address>::PublicKey) -> Result<Self, AddressError> {
+        let public_key = match public_key.is_compressed() {
+            true => public_key.to_secp256k1_public_key().serialize_compressed().to_vec(),
+            false => public_key.to_secp256k1_public_key().serialize().to_vec(),
+        };
+
+        let mut address = [0u8; 21];
+        address[0] = N::P2PKH_PREFIX;
+        address[1..21].copy_from_slice(&hash160(&public_key));
+
+        Ok(Self {
+            address: base58check::encode(&address),
+            format: Base58Format::P2PKH,
+            _network: PhantomData,
+        })
+    }
+
+    /// Returns the format of the address.
+    pub fn format(&self) -> Base58Format {
+        self.format.clone()
+    }
+}
+
+impl<'a, N: Base58Network> TryFrom<&'a str> for Base58Address<N> {
+    type Error = AddressError;
+
+    fn try_from(address: &'a str) -> Result<Self, Self::Error> {
+        Self::from_str(address)
+    }
+}
+
+impl<N: Base58Network> FromStr for Base58Address<N> {
+    type Err = AddressError;
+
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        if address.len() < 26 || address.len() > 35 {
+            return Err(AddressError::InvalidCharacterLength(address.len()));
+        }
+
+        let data = address.from_base58()?;
+        if data.len() != 25 {
+            return Err(AddressError::InvalidByteLength(data.len()));
+        }
+
+        base58check::verify(&data)?;
+
+        // Check that the address prefix corresponds to the correct network.
+        let _ = N::from_address_prefix(&data[0..1])?;
+        let format = Base58Format::from_address_prefix::<N>(&data[0..1])?;
+
+        Ok(Self {
+            address: address.into(),
+            format,
+            _network: PhantomData,
+        })
+    }
+}
+
+impl<N: Base58Network> fmt::Display for Base58Address<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+    use wagyu_model::public_key::PublicKey;
+
+    fn test_from_private_key<N: Base58Network>(
+        expected_address: &str,
+        private_key: &Base58PrivateKey<N>,
+        format: &Base58Format,
+    ) {
+        let address = Base58Address::from_private_key(private_key, format).unwrap();
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    fn test_from_public_key<N: Base58Network>(
+        expected_address: &str,
+        public_key: &Base58PublicKey<N>,
+        format: &Base58Format,
+    ) {
+        let address = Base58Address::from_public_key(public_key, format).unwrap();
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    fn test_from_str<N: Base58Network>(expected_address: &str, expected_format: &Base58Format) {
+        let address = Base58Address::<N>::from_str(expected_address).unwrap();
+        assert_eq!(expected_address, address.to_string());
+        assert_eq!(*expected_format, address.format);
+    }
+
+    fn test_to_str<N: Base58Network>(expected_address: &str, address: &Base58Address<N>) {
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    mod p2pkh_mainnet_compressed {
+        use super::*;
+
+        type N = Mainnet;
+
+        const KEYPAIRS: [(&str, &str); 5] = [
+            (
+                "QUC2aKai8Fde3Un3N4LMXXxepoueRL9B55LagEHXzSQDnS5DFrHm",
+                "DLd3FsCbAyVum3ia38jKwd9Kc3C1aftCva",
+            ),
+            (
+                "QS8F5rttA2HqTVon4XsvSeCo6phR8CUzfiZNqvyYaqqHh8hM8hiB",
+                "DNAyEpRitUwYxVx4aMqcS4sMNiuefcEbDi",
+            ),
+            (
+                "QTm3hGhwcxecUhHzn9zB8ezqMfxApYP4Ab8Nsdbj4zJu9nDsKaWz",
+                "D7bjCTeugzaTMSVAdbna5owRYyZ7SKe8iL",
+            ),
+            (
+                "QNvCACi3VoNPbgBUNJLY53ZGFcWqbt4141F5QrXwUfiN5DLXBxVB",
+                "DMijkXEJLZvmVesebuj3tJdNtqWYKzT82M",
+            ),
+            (
+                "QNm2zYzmifkVw8CEJBENGo6LFiUnN2CeuNWBUKLFHLjSXPxXYTvQ",
+                "D6eTPdH57HsuvNZ95j6CVWKL73s9cc1eXr",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = Base58PrivateKey::<N>::from_str(private_key).unwrap();
+                test_from_private_key(address, &private_key, &Base58Format::P2PKH);
+            });
+        }
+
+        #[test]
+        fn from_public_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = Base58PrivateKey::<N>::from_str(private_key).unwrap();
+                let public_key = Base58PublicKey::<N>::from_private_key(&private_key);
+                test_from_public_key(address, &public_key, &Base58Format::P2PKH);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, address)| {
+                test_from_str::<N>(address, &Base58Format::P2PKH);
+            });
+        }
+
+        #[test]
+        fn to_str() {
+            KEYPAIRS.iter().for_each(|(_, expected_address)| {
+                let address = Base58Address::<N>::from_str(expected_address).unwrap();
+                test_to_str(expected_address, &address);
+            });
+        }
+    }
+
+    mod p2pkh_mainnet_uncompressed {
+        use super::*;
+
+        type N = Mainnet;
+
+        const KEYPAIRS: [(&str, &str); 5] = [
+            (
+                "6KTppzDXrnNrSLW8tCDExN6XmnA6XqPD1KNUdiprcv5xSmzt7tM",
+                "DCKgMHHLNhCuHikDQiS75ZQgbXh3sCZC71",
+            ),
+            (
+                "6L3EvWtuQvE58CLTmti57w2RjqV2VTPq8tqrF3s8PueFQJHYZTA",
+                "DDAPya5afo2NeJTZ1xH9WoyCFaG8aFfyjg",
+            ),
+            (
+                "6KXHqBoYT7ZmYB6iT149YdHDYDAFyYbhdD5MJ81KnGjJHfa8NNe",
+                "DSwewhiNqhNqtc1wPSwKzq8GXFtQ5RYGMj",
+            ),
+            (
+                "6KmUVJqytSypDH9HTzPDmuHmLhYmCk6YkVfGtmhBaaH8uGtU6kC",
+                "DT7MnszoFUh8Lb5j9tvJCv3sStXmjL4Rav",
+            ),
+            (
+                "6JNwpm4FFnu34xiyYK5ELrGngKKuo8FVbVPTSqYLGgh9BePJoCM",
+                "DBvyDGBeNV5hdipdWJhuQCwxaNrZkjQHuk",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = Base58PrivateKey::<N>::from_str(private_key).unwrap();
+                test_from_private_key(address, &private_key, &Base58Format::P2PKH);
+            });
+        }
+
+        #[test]
+        fn from_public_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = Base58PrivateKey::<N>::from_str(private_key).unwrap();
+                let public_key = Base58PublicKey::<N>::from_private_key(&private_key);
+                test_from_public_key(address, &public_key, &Base58Format::P2PKH);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, address)| {
+                test_from_str::<N>(address, &Base58Format::P2PKH);
+            });
+        }
+
+        #[test]
+        fn to_str() {
+            KEYPAIRS.iter().for_each(|(_, expected_address)| {
+                let address = Base58Address::<N>::from_str(expected_address).unwrap();
+                test_to_str(expected_address, &address);
+            });
+        }
+
+        #[test]
+        fn test_invalid() {
+            // Mismatched keypair
+
+            let private_key = "6KTppzDXrnNrSLW8tCDExN6XmnA6XqPD1KNUdiprcv5xSmzt7tM";
+            let expected_address = "D6eTPdH57HsuvNZ95j6CVWKL73s9cc1eXr";
+
+            let private_key = Base58PrivateKey::<N>::from_str(private_key).unwrap();
+            let address = Base58Address::<N>::from_private_key(&private_key, &Base58Format::P2PKH).unwrap();
+            assert_ne!(expected_address, address.to_string());
+
+            let public_key = Base58PublicKey::<N>::from_private_key(&private_key);
+            let address = Base58Address::<N>::from_public_key(&public_key, &Base58Format::P2PKH).unwrap();
+            assert_ne!(expected_address, address.to_string());
+
+            // Invalid address length
+
+            let address = "1";
+            assert!(Base58Address::<N>::from_str(address).is_err());
+
+            let address = "12WMrNLRosydPNN";
+            assert!(Base58Address::<N>::from_str(address).is_err());
+
+            let address = "12WMrNLRosydPNNYM96dwk9jDv8rDRom3";
+            assert!(Base58Address::<N>::from_str(address).is_err());
+
+            let address = "12WMrNLRosydPNNYM96dwk9jDv8rDRom3J12WMrNLRosydPNNYM";
+            assert!(Base58Address::<N>::from_str(address).is_err());
+
+            let address = "12WMrNLRosydPNNYM96dwk9jDv8rDRom3J12WMrNLRosydPNNYM96dwk9jDv8rDRom3J";
+            assert!(Base58Address::<N>::from_str(address).is_err());
+        }
+    }
+
+    mod p2pkh_testnet_compressed {
+        use super::*;
+
+        type N = Testnet;
+
+        const KEYPAIRS: [(&str, &str); 5] = [
+            (
+                "cjN1y5jbnbHkqZ6jPnBqrLeteiMe5LsQMKmcnvitaDwsw9yDzdTw",
+                "njsRexF16dkR6DpBzRCoGVaCXzaHUYALa6",
+            ),
+            (
+                "cfyM3gES3Pxeh5rUYrXkTo8fmGCWDWoNYtQe2JZw5oD31ikanpdE",
+                "nmxLyryRpgqeyyjpTPDGcEsdjSHyiCvhwK",
+            ),
+            (
+                "cfJqcc69heHRvsCq9asdNaarssR6NHfBFdVa8AXZXMsEZDmjAwMX",
+                "nWULs61TfUbhfTfVxU2FWApPjW4JhnmiN6",
+            ),
+            (
+                "cjb5mtoz47WMPX1LzHt53HdyGyes3MYMxDF4KuSsYpAaG8wmmPKY",
+                "nqttUWVkB33QMFYT8X3mhuDchbo59WC9xm",
+            ),
+            (
+                "ckzbWymWYtLMxqhDt8SrAJPfyCXXEdjgieciKE84duqsC3LEoY2v",
+                "nYNZW54DFRSmGV7ddCtNuXB4qJk9aaFQLr",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = Base58PrivateKey::<N>::from_str(private_key).unwrap();
+                test_from_private_key(address, &private_key, &Base58Format::P2PKH);
+            });
+        }
+
+        #[test]
+        fn from_public_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = Base58PrivateKey::<N>::from_str(private_key).unwrap();
+                let public_key = Base58PublicKey::<N>::from_private_key(&private_key);
+                test_from_public_key(address, &public_key, &Base58Format::P2PKH);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, address)| {
+                test_from_str::<N>(address, &Base58Format::P2PKH);
+            });
+        }
+
+        #[test]
+        fn to_str() {
+            KEYPAIRS.iter().for_each(|(_, expected_address)| {
+                let address = Base58Address::<N>::from_str(expected_address).unwrap();
+                test_to_str(expected_address, &address);
+            });
+        }
+    }
+
+    mod p2pkh_testnet_uncompressed {
+        use super::*;
+
+        type N = Testnet;
+
+        const KEYPAIRS: [(&str, &str); 5] = [
+            (
+                "96xJr1P4FxoDLCbnvX8XV3iiSavzbDw2pU5D9oRSoZhX4vg5eFy",
+                "nmkHWYudc8Gsoqkg8fcfFgytb5VsviuR4n",
+            ),
+            (
+                "95Wx2SET6e11RjbGyh5Np6JPHdrcDJxEmksygCNUcKUZcEg1zQR",
+                "njjne3yFh6hnhg2fpkpUFH4LBiBrvQHmmM",
+            ),
+            (
+                "96ARzz4ARumeWh9hLRWxw5trfKgZCZYcK43m7T8gKbh9qoqxiif",
+                "ncDv1U18kFYMixvoVtZzujwmstTYDHLERn",
+            ),
+            (
+                "96Hem6tuw56oWbWZWSj5aDiP9grxusNHGBZQ7GYmY9Fyink4Yqg",
+                "nd6kNWgeE2qc64SDNARDAzH3drFSCiyruJ",
+            ),
+            (
+                "96Adg7xnTSBpRwW1u3qwqEyG1jYRfcxe83w9M24MdXVz2ozRDuM",
+                "njWdW7GykCqWzg9ZatHytxB88QpNUFSimf",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = Base58PrivateKey::<N>::from_str(private_key).unwrap();
+                test_from_private_key(address, &private_key, &Base58Format::P2PKH);
+            });
+        }
+
+        #[test]
+        fn from_public_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = Base58PrivateKey::<N>::from_str(private_key).unwrap();
+                let public_key = Base58PublicKey::<N>::from_private_key(&private_key);
+                test_from_public_key(address, &public_key, &Base58Format::P2PKH);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, address)| {
+                test_from_str::<N>(address, &Base58Format::P2PKH);
+            });
+        }
+
+        #[test]
+        fn to_str() {
+            KEYPAIRS.iter().for_each(|(_, expected_address)| {
+                let address = Base58Address::<N>::from_str(expected_address).unwrap();
+                test_to_str(expected_address, &address);
+            });
+        }
+    }
+}
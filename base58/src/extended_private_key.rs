@@ -0,0 +1,717 @@
+use crate::address::Base58Address;
+use crate::derivation_path::Base58DerivationPath;
+use crate::extended_public_key::Base58ExtendedPublicKey;
+use crate::format::Base58Format;
+use crate::network::Base58Network;
+use crate::private_key::Base58PrivateKey;
+use crate::public_key::Base58PublicKey;
+use wagyu_model::{
+    crypto::hash160, encoding::base58check, AddressError, ChildIndex, DerivationPath, ExtendedPrivateKey,
+    ExtendedPrivateKeyError, ExtendedPublicKey, PrivateKey, WalletGenerator,
+};
+
+use base58::FromBase58;
+use core::{convert::TryFrom, fmt, fmt::Display, str::FromStr};
+use hmac::{Hmac, Mac};
+use secp256k1::{PublicKey, SecretKey};
+use sha2::Sha512;
+use zeroize::Zeroize;
+
+type HmacSha512 = Hmac<Sha512>;
+
+/// Represents a base58 extended private key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Base58ExtendedPrivateKey<N: Base58Network> {
+    /// The address format
+    pub(super) format: Base58Format,
+    /// The depth of key derivation, e.g. 0x00 for master nodes, 0x01 for level-1 derived keys, ...
+    pub(super) depth: u8,
+    /// The first 32 bits of the key identifier (hash160(ECDSA_public_key))
+    pub(super) parent_fingerprint: [u8; 4],
+    /// The child index of the key (0 for master key)
+    pub(super) child_index: ChildIndex,
+    /// The chain code for this extended private key
+    pub(super) chain_code: [u8; 32],
+    /// The private key
+    private_key: Base58PrivateKey<N>,
+}
+
+/// Represents a lazy iterator over the wallets of a derivation path template, e.g. `m/44'/0'/0'/0/*`
+pub type Base58WalletIter<N> = WalletGenerator<Base58ExtendedPrivateKey<N>>;
+
+impl<N: Base58Network> ExtendedPrivateKey for Base58ExtendedPrivateKey<N> {
+    type Address = Base58Address<N>;
+    type DerivationPath = Base58DerivationPath<N>;
+    type ExtendedPublicKey = Base58ExtendedPublicKey<N>;
+    type Format = Base58Format;
+    type PrivateKey = Base58PrivateKey<N>;
+    type PublicKey = Base58PublicKey<N>;
+
+    /// Returns a new extended private key.
+    fn new(seed: &[u8], format: &Self::Format, path: &Self::DerivationPath) -> Result<Self, ExtendedPrivateKeyError> {
+        Ok(Self::new_master(seed, format)?.derive(path)?)
+    }
+
+    /// Returns a new extended private key.
+    fn new_master(seed: &[u8], format: &Self::Format) -> Result<Self, ExtendedPrivateKeyError> {
+        let mut mac = HmacSha512::new_varkey(b"Bitcoin seed")?;
+        mac.input(seed);
+        let hmac = mac.result().code();
+        let private_key = Self::PrivateKey::from_secp256k1_secret_key(&SecretKey::parse_slice(&hmac[0..32])?, true);
+
+        let mut chain_code = [0u8; 32];
+        chain_code[0..32].copy_from_slice(&hmac[32..]);
+
+        Ok(Self {
+            format: format.clone(),
+            depth: 0,
+            parent_fingerprint: [0u8; 4],
+            child_index: ChildIndex::Normal(0),
+            chain_code,
+            private_key,
+        })
+    }
+
+    /// Returns the extended private key of the given derivation path.
+    fn derive(&self, path: &Self::DerivationPath) -> Result<Self, ExtendedPrivateKeyError> {
+        if self.depth == 255 {
+            return Err(ExtendedPrivateKeyError::MaximumChildDepthReached(self.depth));
+        }
+
+        let mut extended_private_key = self.clone();
+
+        for index in path.to_vec()?.into_iter() {
+            let public_key = &PublicKey::from_secret_key(&extended_private_key.private_key.to_secp256k1_secret_key())
+                .serialize_compressed()[..];
+
+            let mut mac = HmacSha512::new_varkey(&extended_private_key.chain_code)?;
+            match index {
+                // HMAC-SHA512(Key = cpar, Data = serP(point(kpar)) || ser32(i)).
+                ChildIndex::Normal(_) => mac.input(public_key),
+                // HMAC-SHA512(Key = cpar, Data = 0x00 || ser256(kpar) || ser32(i))
+                // (Note: The 0x00 pads the private key to make it 33 bytes long.)
+                ChildIndex::Hardened(_) => {
+                    mac.input(&[0u8]);
+                    mac.input(&extended_private_key.private_key.to_secp256k1_secret_key().serialize());
+                }
+            }
+            // Append the child index in big-endian format
+            mac.input(&u32::from(index).to_be_bytes());
+            let hmac = mac.result().code();
+
+            let mut secret_key = SecretKey::parse_slice(&hmac[0..32])?;
+            secret_key.tweak_add_assign(&extended_private_key.private_key.to_secp256k1_secret_key())?;
+            let private_key = Self::PrivateKey::from_secp256k1_secret_key(&secret_key, true);
+
+            let mut chain_code = [0u8; 32];
+            chain_code[0..32].copy_from_slice(&hmac[32..]);
+
+            let mut parent_fingerprint = [0u8; 4];
+            parent_fingerprint.copy_from_slice(&hash160(public_key)[0..4]);
+
+            extended_private_key = Self {
+                format: extended_private_key.format.clone(),
+                depth: extended_private_key.depth + 1,
+                parent_fingerprint,
+                child_index: index,
+                chain_code,
+                private_key,
+            }
+        }
+
+        Ok(extended_private_key)
+    }
+
+    /// Returns the extended public key of the corresponding extended private key.
+    fn to_extended_public_key(&self) -> Self::ExtendedPublicKey {
+        Self::ExtendedPublicKey::from_extended_private_key(&self)
+    }
+
+    /// Returns the private key of the corresponding extended private key.
+    fn to_private_key(&self) -> Self::PrivateKey {
+        self.private_key.clone()
+    }
+
+    /// Returns the public key of the corresponding extended private key.
+    fn to_public_key(&self) -> Self::PublicKey {
+        self.private_key.to_public_key()
+    }
+
+    /// Returns the address of the corresponding extended private key.
+    fn to_address(&self, format: &Self::Format) -> Result<Self::Address, AddressError> {
+        self.private_key.to_address(format)
+    }
+}
+
+impl<N: Base58Network> Base58ExtendedPrivateKey<N> {
+    /// Returns the format of the extended private key.
+    pub fn format(&self) -> Base58Format {
+        self.format.clone()
+    }
+}
+
+impl<N: Base58Network> FromStr for Base58ExtendedPrivateKey<N> {
+    type Err = ExtendedPrivateKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data = s.from_base58()?;
+        if data.len() != 82 {
+            return Err(ExtendedPrivateKeyError::InvalidByteLength(data.len()));
+        }
+
+        // Check that the version bytes correspond with the correct network.
+        let _ = N::from_extended_private_key_version_bytes(&data[0..4])?;
+        let format = Base58Format::from_extended_private_key_version_bytes::<N>(&data[0..4])?;
+
+        let depth = data[4];
+
+        let mut parent_fingerprint = [0u8; 4];
+        parent_fingerprint.copy_from_slice(&data[5..9]);
+
+        let child_index = ChildIndex::from(u32::from_be_bytes(<[u8; 4]>::try_from(&data[9..13])?));
+
+        let mut chain_code = [0u8; 32];
+        chain_code.copy_from_slice(&data[13..45]);
+
+        let private_key = Base58PrivateKey::from_secp256k1_secret_key(&SecretKey::parse_slice(&data[46..78])?, true);
+
+        base58check::verify(&data)?;
+
+        Ok(Self {
+            format,
+            depth,
+            parent_fingerprint,
+            child_index,
+            chain_code,
+            private_key,
+        })
+    }
+}
+
+impl<N: Base58Network> Display for Base58ExtendedPrivateKey<N> {
+    /// BIP32 serialization format
+    /// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let mut result = [0u8; 78];
+        result[0..4].copy_from_slice(&N::EXTENDED_PRIVATE_KEY_VERSION_BYTES);
+        result[4] = self.depth;
+        result[5..9].copy_from_slice(&self.parent_fingerprint[..]);
+        result[9..13].copy_from_slice(&u32::from(self.child_index).to_be_bytes());
+        result[13..45].copy_from_slice(&self.chain_code[..]);
+        result[45] = 0;
+        result[46..78].copy_from_slice(&self.private_key.to_secp256k1_secret_key().serialize());
+
+        fmt.write_str(&base58check::encode(&result))
+    }
+}
+
+impl<N: Base58Network> Zeroize for Base58ExtendedPrivateKey<N> {
+    /// Overwrites the chain code and the private key.
+    fn zeroize(&mut self) {
+        self.chain_code.zeroize();
+        self.private_key.zeroize();
+    }
+}
+
+impl<N: Base58Network> Drop for Base58ExtendedPrivateKey<N> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    use core::convert::TryInto;
+    use hex;
+
+    fn test_new<N: Base58Network>(
+        expected_extended_private_key: &str,
+        expected_parent_fingerprint: &str,
+        expected_child_index: u32,
+        expected_chain_code: &str,
+        expected_secret_key: &str,
+        seed: &str,
+        format: &Base58Format,
+        path: &Base58DerivationPath<N>,
+    ) {
+        let extended_private_key =
+            Base58ExtendedPrivateKey::<N>::new(&hex::decode(seed).unwrap(), format, path).unwrap();
+        assert_eq!(expected_extended_private_key, extended_private_key.to_string());
+        assert_eq!(
+            expected_parent_fingerprint,
+            hex::encode(extended_private_key.parent_fingerprint)
+        );
+        assert_eq!(expected_child_index, u32::from(extended_private_key.child_index));
+        assert_eq!(expected_chain_code, hex::encode(extended_private_key.chain_code));
+        assert_eq!(
+            expected_secret_key,
+            hex::encode(&extended_private_key.private_key.to_secp256k1_secret_key().serialize())
+        );
+    }
+
+    // Check: (extended_private_key1 -> extended_private_key2) == (expected_extended_private_key2)
+    fn test_derive<N: Base58Network>(
+        expected_extended_private_key1: &str,
+        expected_extended_private_key2: &str,
+        expected_child_index2: u32,
+    ) {
+        let path = vec![ChildIndex::from(expected_child_index2)].try_into().unwrap();
+
+        let extended_private_key1 = Base58ExtendedPrivateKey::<N>::from_str(expected_extended_private_key1).unwrap();
+        let extended_private_key2 = extended_private_key1.derive(&path).unwrap();
+
+        let expected_extended_private_key2 =
+            Base58ExtendedPrivateKey::<N>::from_str(&expected_extended_private_key2).unwrap();
+
+        assert_eq!(expected_extended_private_key2, extended_private_key2);
+        assert_eq!(
+            expected_extended_private_key2.private_key,
+            extended_private_key2.private_key
+        );
+        assert_eq!(expected_extended_private_key2.depth, extended_private_key2.depth);
+        assert_eq!(
+            expected_extended_private_key2.child_index,
+            extended_private_key2.child_index
+        );
+        assert_eq!(
+            expected_extended_private_key2.chain_code,
+            extended_private_key2.chain_code
+        );
+        assert_eq!(
+            expected_extended_private_key2.parent_fingerprint,
+            extended_private_key2.parent_fingerprint
+        );
+    }
+
+    fn test_to_extended_public_key<N: Base58Network>(
+        expected_extended_public_key: &str,
+        seed: &str,
+        format: &Base58Format,
+        path: &Base58DerivationPath<N>,
+    ) {
+        let extended_private_key =
+            Base58ExtendedPrivateKey::<N>::new(&hex::decode(seed).unwrap(), format, path).unwrap();
+        let extended_public_key = extended_private_key.to_extended_public_key();
+        assert_eq!(expected_extended_public_key, extended_public_key.to_string());
+    }
+
+    fn test_from_str<N: Base58Network>(
+        expected_extended_private_key: &str,
+        expected_parent_fingerprint: &str,
+        expected_child_index: u32,
+        expected_chain_code: &str,
+        expected_secret_key: &str,
+    ) {
+        let extended_private_key = Base58ExtendedPrivateKey::<N>::from_str(expected_extended_private_key).unwrap();
+        assert_eq!(expected_extended_private_key, extended_private_key.to_string());
+        assert_eq!(
+            expected_parent_fingerprint,
+            hex::encode(extended_private_key.parent_fingerprint)
+        );
+        assert_eq!(expected_child_index, u32::from(extended_private_key.child_index));
+        assert_eq!(expected_chain_code, hex::encode(extended_private_key.chain_code));
+        assert_eq!(
+            expected_secret_key,
+            hex::encode(&extended_private_key.private_key.to_secp256k1_secret_key().serialize())
+        );
+    }
+
+    fn test_to_string<N: Base58Network>(expected_extended_private_key: &str) {
+        let extended_private_key = Base58ExtendedPrivateKey::<N>::from_str(expected_extended_private_key).unwrap();
+        assert_eq!(expected_extended_private_key, extended_private_key.to_string());
+    }
+
+    mod p2pkh_mainnet {
+        use super::*;
+
+        type N = Mainnet;
+
+        // (path, seed, child_index, secret_key, chain_code, parent_fingerprint, extended_private_key, extended_public_key)
+        const KEYPAIRS: [(&str, &str, &str, &str, &str, &str, &str, &str); 26] = [
+
+            // BIP32 Derivation Paths
+            (
+                "m",
+                "000102030405060708090a0b0c0d0e0f",
+                "0",
+                "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35",
+                "873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508",
+                "00000000",
+                "dgpv51eADS3spNJh9Gjth94XcPwAczvQaDJs9rqx11kvxKs6r3Ek8AgERHhjLs6mzXQFHRzQqGwqdeoDkZmr8jQMBfi43b7sT3sx3cCSk5fGeUR",
+                "dgub8kXBZ7ymNWy2S8Q3jNgVjFUm5ZJ3QLLaSTdAA89ukSv7Q6MSXwE14b7Nv6eDpE9JJXinTKc8LeLVu19uDPrm5uJuhpKNzV2kAgncwo6bNpP"
+            ),
+            (
+                "m/0'",
+                "000102030405060708090a0b0c0d0e0f",
+                "2147483648",
+                "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea",
+                "47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141",
+                "3442193e",
+                "dgpv53uaD9MLudRgHssbttwAVS3GwpUkxHnsqUGqy793vX4PDKXvYQDKYS4988T7QEnCzUt7CaGi21e6UKoZnKgXyjna7To1h1aqkcqJBDM65ur",
+                "dgub8nnbYqHETn61ajXkw8Z8cHasQNrPnQpb85448DY2ie7PmNecxAm6BjTnhNCvZY3qJk1MKZ9Z5HQasQ83ARb99nmduT7dunvxgcvBFVHuvrq"
+            ),
+            (
+                "m/0'/1",
+                "000102030405060708090a0b0c0d0e0f",
+                "1",
+                "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368",
+                "2a7857631386ba23dacac34180dd1983734e444fdbf774041578e9b6adb37c19",
+                "5c1bd648",
+                "dgpv565hQvuEJLJk8Kv3d9q36Avw1CTrxKXAmnwgZNurs9rbSs34GCddVzxNYBeB1AZFSZdo1Ps96ibWcGKnufUWkuH1dEkjkmMhRR9fi7Po6B2",
+                "dgub8pxikcq7rUy5RBaCfPT1D2UXTkqVnSYt4PitiVJqfGubzv9kfyBQ9JN27SfVyUmBGTdQ6ybfBsu4Thrrdkm2qSbaCexVPRwEKMSxYLP2A41"
+            ),
+            (
+                "m/0'/1/2'",
+                "000102030405060708090a0b0c0d0e0f",
+                "2147483650",
+                "cbce0d719ecf7431d88e6a89fa1483e02e35092af60c042b1df2ff59fa424dca",
+                "04466b9cc8e161e966409ca52986c584f07e9dc81f735db683c3ff6ec7b1503f",
+                "bef5a2f9",
+                "dgpv58gyTTj61DA9zVi8skEQTAy5EMLPDs7A7LBMoiD232E2riEB4xU4QSWJ6DrnyQ4jx2fBbrp4X8RQqU4YVgPhszifyrKHuhbe2gttLnRB4a6",
+                "dgub8sZzo9eyZMpVHMNHuyrNa2Wfgui23z8sPvxZxpbzq9H3QmLsUj1q3juwfTrLRMCVcyj8iMaGZpU2v319LrJZttkQnYvdUNzv33N6dcqeZ8X"
+            ),
+            (
+                "m/0'/1/2'/2",
+                "000102030405060708090a0b0c0d0e0f",
+                "2",
+                "0f479245fb19a38a1954c5c7c0ebab2f9bdfd96a17563ef28a6a4b1a2a764ef4",
+                "cfb71883f01676f587d023cc53a35bc7f88f724b1f8c2892ac1275ac822a3edd",
+                "ee7ab90c",
+                "dgpv5AvNHtr3Bgq94yBra1SVLg8PKAd7rTRMYp4f4fjVMTneDorY8jARc1yDmYGFS4UB1pntDn3dRwsaJexzh6w45PJiP6QPTnRMBfN3rDUiyyH",
+                "dgub8uoPdamvjqVUMpr1cF4TTXfymizkgaT4qQqsDn8U9aqemryEYViCFKNsLnqiq9ME6HrJrN4DcZN9UTM9S9jmcVDfhLUpJZtk3jGwnGkhd8u"
+            ),
+            (
+                "m/0'/1/2'/2/1000000000",
+                "000102030405060708090a0b0c0d0e0f",
+                "1000000000",
+                "471b76e389e528d6de6d816857e012c5455051cad6660850e58372a6c3e6e7c8",
+                "c783e67b921d2beb8f6b389cc646d7263b4145701dadd2161548a8b078e65e9e",
+                "d880d7d8",
+                "dgpv5Ce8maTHJpDLbJyJgZ1DeP8P7QCRfxEPM4TDJx7dZYB8vwFvf9R5s88HQQ3TLybFdEC9192aGzQhJpyNEAwnCLxFibAcahB4TzvQbJyp2im",
+                "dgub8wXA7GPArxsftAdTindBmEfyZxa4W5G6dfERU4WcMfE9UzNd4uxrWRXvyckfgQRwZz8rMhz29m4k4skAY1EcTkNnZstu73UNrgts2MA5evC"
+            ),
+            (
+                "m",
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "0",
+                "4b03d6fc340455b363f51020ad3ecca4f0850280cf436c70c727923f6db46c3e",
+                "60499f801b896d83179a4374aeb7822aaeaceaa0db1f85ee3e904c4defbd9689",
+                "00000000",
+                "dgpv51eADS3spNJh8tFDoJ8p4bevsC4qFqZgqSdgHxVkvhyy92FwJKTArBsKgvsqB2xLXUjqaZQHukqQr6VxB9o3o32pW1C7bPngcrpg75LUw8V",
+                "dgub8kXBZ7ymNWy2RjuNqXknBTCXKkSU5xbQ83QtT4tjiq2yh5Ndi5zwVVGyGCjCXUWGD5xaMzGHjiqkcnt8LamvDpJrZkWqpyXQV4TjDhfyo9Q"
+            ),
+            (
+                "m/0",
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "0",
+                "abe74a98f6c7eabee0428f53798f0ab8aa1bd37873999041703c742f15ac7e1e",
+                "f0909affaa7ee7abe5dd4e100598d4dc53cd709d5a5c2cac40e7412f232f7c9c",
+                "bd16bee5",
+                "dgpv54uuV9jqJP8mf9qWw5Wh8Q4TE9p5xp3yPcP3TmXD17qvpzhusaoF12SaGS9dp6oAw8yfUZp2LvFYCc8mjSJ6jGCDWBcAysxRkGjEUK7pYvw",
+                "dgub8onvpqfirXo6x1VfyK8fFFc3giBinw5ggDAFcsvBoEtwP3pcHMM1eKrDqfh6KZWhRQSkEDG38ogimxJpDjULZQy8qoFWjKfncYaPesrSURc"
+            ),
+            (
+                "m/0/2147483647'",
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "4294967295",
+                "877c779ad9687164e9c2f4f0f4ff0340814392330693ce95a58fe18fd52e6e93",
+                "be17a268474a6bb9c61e1d720cf6215e2a88c5406c4aee7b38547f585c9a37d9",
+                "5a61ff8e",
+                "dgpv564xjkmMgHJsq23hMXWLbjk1cu2zWJDfUmTaPARyercKHHwNVUDZD6EAsndcYMXeqNJZFb1fPvkedqsYTouEJZdmvuqWkPggY44mEn4uizf",
+                "dgub8pwz5ShFERyD7shrPm8JibHc5TQdLRFNmNEnYGpxSyfKqM44uEmKrPdpT3wD5J7oCvNHt47eS27KSdB9zdxTHZRmNssa63voUvqzVgkMK7p"
+            ),
+            (
+                "m/0/2147483647'/1",
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "1",
+                "704addf544a06e5ee4bea37098463c23613da32020d604506da8c0518e1da4b7",
+                "f366f48f1ea9f2d1d3fe958c95ca84ea18e4c4ddb9366c336c927eb246fb38cb",
+                "d8ab4937",
+                "dgpv58sw9mkHqvc4vdiqRBeuZggExRuVcV8voCxSEE6Jxjxkx7oXnykG6zcYiaqEa4jM9KfFzt63oURrxYehWhRcK3T54gNKbVf51rVViRSkahZ",
+                "dgub8skxVTgBQ5GQDVNzTRGsgYDqQzH8ScAe5ojePLVHks1mWAvECkJ2kJ2CHr8LsAp5o6pqihCt59R9XRSAuYPQYttfyA5RJbN1QhWwkCcvPdA"
+            ),
+            (
+                "m/0/2147483647'/1/2147483646'",
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "4294967294",
+                "f1c7c871a54a804afe328b4c83a1c33b8e5ff48f5087273f04efa83b247d6a2d",
+                "637807030d55d01f9a0cb3a7839515d796bd07706386a6eddf06cc29a65a0e29",
+                "78412e3a",
+                "dgpv5A3y4jgeTKZnDaxS9fUSwCNRsANrfTmRpugKkwnioWE1QU8TtegGdWyeMHZdCV7dgtwxJhs3Br1Smfk52eL6zt8EtcudjhghMSW1nDNfmHP",
+                "dgub8tvzQRcY1UE7WScbBu6R43v2KikVVao97WTXv4BhbdH1xXFAJRE3GpPHvWFr4YLXkYUUXCGb7kk1B4bZbRVvGFb8F4PurtTRGvbXH6bMPN3"
+            ),
+            (
+                "m/0/2147483647'/1/2147483646'/2",
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "2",
+                "bb7d39bdb83ecf58f2fd82b6d918341cbef428661ef01ab97c28a4842125ac23",
+                "9452b549be8cea3ecb7a84bec10dcfd94afe4d129ebfd3b3cb58eedf394ed271",
+                "31a507b8",
+                "dgpv5BR12Au9yVt1xr9Ksjc8uwkQGDuxungWV5JDAj9DSJ5bUfU34Hc2p5veRhEdMWChjCogbTVrdwr8pDdakxhL3rrxhUR8o7pR3oqZrnPNxDt",
+                "dgub8vJ2Mrq3XeYMFhoUuyE72oHzinHbjuiDmg5RKqYCER8c2iajU49oTPLHzuL8C7hEGbgho7n11TkzfVf4RXBpaWShtEDzoFk9xDnhwhVSxT7"
+            ),
+
+            // BIP 44 Derivation Paths
+            (
+                "m/44'/0'/0'/0",
+                "747f302d9c916698912d5f70be53a6cf53bc495803a5523d3a7c3afa2afba94ec3803f838b3e1929ab5481f9da35441372283690fdcf27372c38f40ba134fe03",
+                "0",
+                "4338d5f49fecfe380b9e60e032b7da0845e7f9d441ac78bd58ab1af423187d7d",
+                "c2c14ca7969bfea1753c39eb5238cb948dc3202478b414e0e924e7a21f4fe654",
+                "929c7e3c",
+                "dgpv5AFCm6BNXiqNauLfK2bas5HwgSj1nSq23BJYTBPy3jifoykLmrHRSVVZuKvmcwkQSFo7qXJ2nPzQtbefwBXvbZPwH3UTA3b6KNkwG9pdkD9",
+                "dgub8u8E6n7G5sVhskzpMGDYyvqY916ecZrjKn5kcHnwqrmgN2s3BcqC5nuDUbSB9ctk1wZMa6E1mDpsC1kDxn84NJpF4dYL5X9H9KWrvha5mkr"
+            ),
+            (
+                "m/44'/0'/0'/0/1",
+                "747f302d9c916698912d5f70be53a6cf53bc495803a5523d3a7c3afa2afba94ec3803f838b3e1929ab5481f9da35441372283690fdcf27372c38f40ba134fe03",
+                "1",
+                "0075bcd6a7c659d8336141ad6a3103a34a9a3a5a6dfdcab7df9670646f0b6761",
+                "c96ae85842d7d557c15a09e19286475ede2b895b91a277a4c624a974b0a3811d",
+                "09d96c42",
+                "dgpv5B833LJ7CuWbPdrNdGxbvjD9RJPDSvZ6H8LRpRYBJ6aV4Eckdiv2Amdq3kUwPecwa4TypJWjkk2Zjxn1eDi4Tvg7Qag7UM8qKCWASsBo7ao",
+                "dgub8v14P2Dzm4AvgVWXfWaa3akjsrkrH3aoZj7dyXwA6DdVcHjT3VTnp53Ud1smi6rcbrJDeAmMqQCzAwmyJ1Z7D273u8Ax2aynyF1n7mdxkfJ"
+            ),
+            (
+                "m/44'/0'/0'/1",
+                "747f302d9c916698912d5f70be53a6cf53bc495803a5523d3a7c3afa2afba94ec3803f838b3e1929ab5481f9da35441372283690fdcf27372c38f40ba134fe03",
+                "1",
+                "f29d6ddd6b0cd1fd59ed99900edd5a53e905b87dfe06824751010feb5228d960",
+                "0674ec59dbc724f29fea71d771b62aba454732067055e3d29fe640c3c4cef2a4",
+                "929c7e3c",
+                "dgpv5AFCm6BNXiqNbaS8Zd4CQv5w3rVNMNTuxxJcDmSbUnNov91rnEE5STwo8q6LbzU6sYipsCuxr3JMQHP8HZMew1cxJHBJaccoKSXPRc9L6FV",
+                "dgub8u8E6n7G5sVhtS6HbrgAXmdXWQs1BVVdFZ5pNsqaGuRpUC8ZBzmr5mMSi44afZoh7YQ4ZEMk9soFo6uzYTvXqqm9HtS3BjJeHsQi6BbUVod"
+            ),
+            (
+                "m/44'/0'/0'/1/1",
+                "747f302d9c916698912d5f70be53a6cf53bc495803a5523d3a7c3afa2afba94ec3803f838b3e1929ab5481f9da35441372283690fdcf27372c38f40ba134fe03",
+                "1",
+                "41429c1338d0b8b0a3ef984f02b9e0f05100e45f4c639937191e400345402764",
+                "e35471cdbd74303352b18df7efbfc0144da3cb416f8cc15ea90ca18357e89607",
+                "411064ed",
+                "dgpv5BXaHFYvf2XBwUkjYwWVaGYzmNHRQD2qp1WpVRPZDHSG3yXbibAD4F913zzrbppMt2mwMw2qKMQb3k2wF58jXqSemQ6tdpVRC2wYBUon5aU",
+                "dgub8vQbcwUpDBBXELQtbB8Th86bDvf4EL4Z6cJ2eXnY1QVGc2eJ8MhyhYYedFMPD5bCJAFDDpCay8pek6eeGehhFWw5douRQPzVH7gXxM1QdbA"
+            ),
+            (
+                "m/44'/0'/1'/0",
+                "747f302d9c916698912d5f70be53a6cf53bc495803a5523d3a7c3afa2afba94ec3803f838b3e1929ab5481f9da35441372283690fdcf27372c38f40ba134fe03",
+                "0",
+                "95ee1cfd2375843a1f4e59af8e6ca43c179bf19779b188031ccc9285dbea8073",
+                "2f87b7dcaa60ad05af4f207f1e5c336b0727eaf5bbe80aa555e5e725d58b45fa",
+                "67384ff6",
+                "dgpv59vhsDF4j1EEXsvQsa5hjkt1FVev3jRqbDDqgsBAzy3XGdodJq4UQ9YcKwywVFdsDcnFfNAqdMPy16gWBEza6XavpyyRjRmQgUoaNPius34",
+                "dgub8tojCuAxH9tZpjaZuohfrcRbi42YsrTYsp13qya9o66XpgvKibcF3SxFuCej1HNKtzWFcqiJmHRUNudbPkkbvHSBGAiWNK9CUc5p5CCkSHj"
+            ),
+            (
+                "m/44'/0'/1'/0/1",
+                "747f302d9c916698912d5f70be53a6cf53bc495803a5523d3a7c3afa2afba94ec3803f838b3e1929ab5481f9da35441372283690fdcf27372c38f40ba134fe03",
+                "1",
+                "dec327ab861f7b4da1ea67b45516f0b6d71b93ba4f3de22dd2b1e12203ca1f02",
+                "976331516208a95719780daaaaaff9dbd22665cf17370009abbbb4855569cf89",
+                "c014feb5",
+                "dgpv5CTivorYn2F5pV5mVH4VicG6WKTL5Yg1N7qjxo5m8DFVxKsG5dtMeAfs9EfucayaWkf4ZXJtFVXCfmfJcNjgE3bypGyXbiZJBphAqUcpvUU",
+                "dgub8wLkGVnSLAuR7LjvXWgTqTogxspxufhieicx7uUjvLJWWNyxVQS8HU5WiStXokqJ3KHZhMmEnLyBhLLRdA1BuKj1dusP4j8GE2MejK5tAoK"
+            ),
+            (
+                "m/44'/0'/1'/1",
+                "747f302d9c916698912d5f70be53a6cf53bc495803a5523d3a7c3afa2afba94ec3803f838b3e1929ab5481f9da35441372283690fdcf27372c38f40ba134fe03",
+                "1",
+                "b86188deda09b95d38290624b326f36e233ad87c7b74fdabaa0078f5187b0838",
+                "6ebc69c29028acba7b300da6f21f3482bb99cb13948f4d95e63e18478135e55f",
+                "67384ff6",
+                "dgpv59vhsDF4j1EEb4FHMHXnAQtHQ1KJsoggrA1ss9x4XLnASqmv1z49Q2y9FS6fSARXKuVSvMxrLuQnu1eUoEHbH1wscFTHDRDU61sm8cv5ZYr",
+                "dgub8tojCuAxH9tZsuuSPX9kHGRsrZgwhviQ8ko62GM3KTqAzttcRkbv3LNnpftxTnJ3sHdAua9VfCXy7yYCda1TuHXPUxMQoj9PvVtDbDhWc4T"
+            ),
+            (
+                "m/44'/0'/1'/1/1",
+                "747f302d9c916698912d5f70be53a6cf53bc495803a5523d3a7c3afa2afba94ec3803f838b3e1929ab5481f9da35441372283690fdcf27372c38f40ba134fe03",
+                "1",
+                "398ade967bd6c3e7131647a5f819746624e419eae739d757bde2506fc0aec8bf",
+                "19debf400dd10bfe2b72813a7e21cde2dba9f9830c5b5264d4f6c11f52814086",
+                "b4a881d9",
+                "dgpv5CNrTsJ6D34kXybpddhQqqFoJrGqYB2ksjY3vRbXrWuay6toxf2CD5DHufuoujukKTv7mjjNs15b1TunzZ7iR7tf54ZYHBCnkHqvHkw1sce",
+                "dgub8wFsoZDymBj5pqFyfsKNxgoPmQeUNJ4UALKG5XzWedxbXA1WNRZxrNcwUuodFKRYt1HzX15tXnUeBSiz2Y6jDnoffyKFjorckpyn3gJEJfJ"
+            ),
+
+            // Dogecoin Core Derivation Paths
+            (
+                "m/0'/0'",
+                "747f302d9c916698912d5f70be53a6cf53bc495803a5523d3a7c3afa2afba94ec3803f838b3e1929ab5481f9da35441372283690fdcf27372c38f40ba134fe03",
+                "2147483648",
+                "f0ba40d643ad82ccf5b6228cd0b723144d6a2b6af3daec5c57631f54b4619e77",
+                "7db48bfda81e8d1c18aa9f8649dea892c8abea84d54d0c0e895857c16fc585df",
+                "2b889e8f",
+                "dgpv55izM1V42ZQgHpXdKzUU8hnc3nftBziD6Lu6214CyRir48Ui4YK8J9YVFXPFRMJisS2nYExmCkDtL1FPsBHBiRvduSWHR51fZUr94JiWR4v",
+                "dgub8pc1ghQwai51agBnNE6SFZLCWM3X27jvNwgJB7TBmYmrcBbQUJrtwSx8pkgX2VdWLV6YXEr6oPXtgmpysHwa9rvy9Cu4GqkGq97gfpmdbPV"
+            ),
+            (
+                "m/0'/0'/0'",
+                "747f302d9c916698912d5f70be53a6cf53bc495803a5523d3a7c3afa2afba94ec3803f838b3e1929ab5481f9da35441372283690fdcf27372c38f40ba134fe03",
+                "2147483648",
+                "b33f5ac29e0b36a65ed527e8f7d394a33a6767031e68afe6df8f366c09547e30",
+                "3601dd1853197d38d4f9d4f8b5cbbc4d2062768dc6279c68b2bd085345ca71f2",
+                "2e494d03",
+                "dgpv57dJGQjFLiX3U6A48R3iDjeSj8gAGf6KYqfveRK6n5j4mDsQcaDbbWW5PJxwe9sHuDSVPe1m9TT11LpjrQXij1JcweMQupoyAew1JZKSymo",
+                "dgub8rWKc6f8tsBNkwpDAefgLbC3Bh3o6n82qST8oXi5aCn5KGz72LmNEouixWv3qyqZpbhJShZy8ATnTKTaZ2aN6ALifp4oDAXqjudkezryc2v"
+            ),
+
+            // Multibit Derivation Paths
+            (
+                "m/0'/0",
+                "747f302d9c916698912d5f70be53a6cf53bc495803a5523d3a7c3afa2afba94ec3803f838b3e1929ab5481f9da35441372283690fdcf27372c38f40ba134fe03",
+                "0",
+                "74d29dfbbc821a973c87ec53ff446369cb4639ad6a4c74e08ec771d052c56ccf",
+                "402baa6c2837a364e0ee384afd99a339a9f01b68c42eba5fd840a7475392e87b",
+                "2b889e8f",
+                "dgpv55izM1Uugtsi6vhvkczw3FxT7yTnDLUAGCBpwWG1khGfuDcp9SFhXQ5bxY5qFNMFVWXYLXqKx4wqet51tnCo71iDiqmLWvchcyghQMtqMxW",
+                "dgub8pc1ghQoF3Y3PnN5nrcuA7W3aXqR3TVsYny36cezYpKgTGjWZCoUAhVFXkwWGpfs6rZmLjs6nR4PUYyWXXJmQQHkAwNDRnzMAPqt3434Ftg"
+            ),
+            (
+                "m/0'/0/0",
+                "747f302d9c916698912d5f70be53a6cf53bc495803a5523d3a7c3afa2afba94ec3803f838b3e1929ab5481f9da35441372283690fdcf27372c38f40ba134fe03",
+                "0",
+                "05b232fd66616e350c2db176aa650f3f642df22e0efb7831d5c3ee5206639cdb",
+                "fd9a583afe8f38d077335f4bdf96146070782581aa174bf2ade5a7009e6247f6",
+                "c677886d",
+                "dgpv58kB5u5Dbgz25fv2nrX48WnTYpjN8RCq5PTFtthZL4wvopE49cC1q9j1YxJqq8s2b7i3HoYXcc7wqJ4vyhq33n5WdyTESJWs9aURscaVwKd",
+                "dgub8sdCRb179qeMNXaBq692FNL41P6zxYEYMzEU416Y8BzwMsLkZNjnUT8f8C1A4158p265YQktqopfZZS3MTd7U6YkywGcBV2Gybd1JgeN3Ja"
+            ),
+
+            // Block Explorer Derivation Paths (example: blockchain.info)
+            (
+                "m/44'/0'/0'",
+                "747f302d9c916698912d5f70be53a6cf53bc495803a5523d3a7c3afa2afba94ec3803f838b3e1929ab5481f9da35441372283690fdcf27372c38f40ba134fe03",
+                "2147483648",
+                "47beaf6a6be527c151297bba99b3da1cd02fa88f6278d550cd5a282123283c9f",
+                "d4297bd8126147c4da781f78af3d5e92992fb799a0fa73cb254bfc5b4358167d",
+                "88d9ebc3",
+                "dgpv58HuaLZ8eqxshgn4BHXE4rB1uRvjpyqsWHhTT6ndfyUQb5RqKCunN3QLtHLXbgpPhbReYm6sr4zmJ3YDKANUvHNhBMZ6wrmWtzXbhJH7Y16",
+                "dgub8sAvv2V2CzdCzYSDDX9CBhicMzJNf6santUfcDBcU6XR98YXiyTZ1LozTXHnDnkMYW9YEWTmvmLxB5pY7uUyPNKsXcvPKL8BTcs3aLDL2wh"
+            ),
+            (
+                "m/44'/0'/0'/0",
+                "747f302d9c916698912d5f70be53a6cf53bc495803a5523d3a7c3afa2afba94ec3803f838b3e1929ab5481f9da35441372283690fdcf27372c38f40ba134fe03",
+                "0",
+                "4338d5f49fecfe380b9e60e032b7da0845e7f9d441ac78bd58ab1af423187d7d",
+                "c2c14ca7969bfea1753c39eb5238cb948dc3202478b414e0e924e7a21f4fe654",
+                "929c7e3c",
+                "dgpv5AFCm6BNXiqNauLfK2bas5HwgSj1nSq23BJYTBPy3jifoykLmrHRSVVZuKvmcwkQSFo7qXJ2nPzQtbefwBXvbZPwH3UTA3b6KNkwG9pdkD9",
+                "dgub8u8E6n7G5sVhskzpMGDYyvqY916ecZrjKn5kcHnwqrmgN2s3BcqC5nuDUbSB9ctk1wZMa6E1mDpsC1kDxn84NJpF4dYL5X9H9KWrvha5mkr"
+            )
+        ];
+
+        #[test]
+        fn new() {
+            KEYPAIRS.iter().for_each(
+                |(path, seed, child_index, secret_key, chain_code, parent_fingerprint, extended_private_key, _)| {
+                    test_new::<N>(
+                        extended_private_key,
+                        parent_fingerprint,
+                        child_index.parse().unwrap(),
+                        chain_code,
+                        secret_key,
+                        seed,
+                        &Base58Format::P2PKH,
+                        &Base58DerivationPath::from_str(path).unwrap(),
+                    );
+                },
+            );
+        }
+
+        #[test]
+        fn derive() {
+            KEYPAIRS.chunks(2).for_each(|pair| {
+                let (_, _, _, _, _, _, expected_extended_private_key1, _) = pair[0];
+                let (_, _, expected_child_index2, _, _, _, expected_extended_private_key2, _) = pair[1];
+                test_derive::<N>(
+                    expected_extended_private_key1,
+                    expected_extended_private_key2,
+                    expected_child_index2.parse().unwrap(),
+                );
+            });
+        }
+
+        #[test]
+        fn to_extended_public_key() {
+            KEYPAIRS
+                .iter()
+                .for_each(|(path, seed, _, _, _, _, _, expected_public_key)| {
+                    test_to_extended_public_key::<N>(
+                        expected_public_key,
+                        seed,
+                        &Base58Format::P2PKH,
+                        &Base58DerivationPath::from_str(path).unwrap(),
+                    );
+                });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(
+                |(_, _, child_index, secret_key, chain_code, parent_fingerprint, extended_private_key, _)| {
+                    test_from_str::<N>(
+                        extended_private_key,
+                        parent_fingerprint,
+                        child_index.parse().unwrap(),
+                        chain_code,
+                        secret_key,
+                    );
+                },
+            );
+        }
+
+        #[test]
+        fn to_string() {
+            KEYPAIRS.iter().for_each(|(_, _, _, _, _, _, extended_private_key, _)| {
+                test_to_string::<N>(extended_private_key);
+            });
+        }
+    }
+
+    mod test_invalid {
+        use super::*;
+
+        type N = Mainnet;
+
+        const INVALID_EXTENDED_PRIVATE_KEY_SECP256K1_SECRET_KEY: &str = "dgpv51eADS3spNJh7veMCAiKkjPZevJqLc7Fk8RcZ7otUGJWivyGsXLe2SZWtHb8LLTWP4wfAJPchhMb2hLGkPijGiVcCbg8j4zpzjxQvkJddyY";
+        const INVALID_EXTENDED_PRIVATE_KEY_NETWORK: &str = "xprv8s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi";
+        const INVALID_EXTENDED_PRIVATE_KEY_CHECKSUM: &str = "dgpv51eADS3spNJh9Gjth94XcPwAczvQaDJs9rqx11kvxKs6r3Ek8AgERHhjLs6mzXQFHRzQqGwqdeoDkZmr8jQMBfi43b7sT3sx3cCSk5gkdkm";
+        const VALID_EXTENDED_PRIVATE_KEY: &str = "dgpv51eADS3spNJh9Gjth94XcPwAczvQaDJs9rqx11kvxKs6r3Ek8AgERHhjLs6mzXQFHRzQqGwqdeoDkZmr8jQMBfi43b7sT3sx3cCSk5fGeUR";
+
+        #[test]
+        #[should_panic(expected = "Crate(\"libsecp256k1\", \"InvalidSecretKey\")")]
+        fn from_str_invalid_secret_key() {
+            let _result =
+                Base58ExtendedPrivateKey::<N>::from_str(INVALID_EXTENDED_PRIVATE_KEY_SECP256K1_SECRET_KEY).unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "InvalidVersionBytes([4, 136, 173, 227])")]
+        fn from_str_invalid_version() {
+            let _result = Base58ExtendedPrivateKey::<N>::from_str(INVALID_EXTENDED_PRIVATE_KEY_NETWORK).unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "InvalidChecksum(\"5Bjdr9\", \"5AFeZo\")")]
+        fn from_str_invalid_checksum() {
+            let _result = Base58ExtendedPrivateKey::<N>::from_str(INVALID_EXTENDED_PRIVATE_KEY_CHECKSUM).unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "InvalidByteLength(81)")]
+        fn from_str_short() {
+            let _result = Base58ExtendedPrivateKey::<N>::from_str(&VALID_EXTENDED_PRIVATE_KEY[1..]).unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "InvalidByteLength(83)")]
+        fn from_str_long() {
+            let mut string = String::from(VALID_EXTENDED_PRIVATE_KEY);
+            string.push_str("aa");
+            let _result = Base58ExtendedPrivateKey::<N>::from_str(&string).unwrap();
+        }
+    }
+}
@@ -0,0 +1,501 @@
+use crate::address::Base58Address;
+use crate::derivation_path::Base58DerivationPath;
+use crate::extended_private_key::Base58ExtendedPrivateKey;
+use crate::format::Base58Format;
+use crate::network::Base58Network;
+use crate::public_key::Base58PublicKey;
+use wagyu_model::{
+    crypto::hash160, encoding::base58check, AddressError, ChildIndex, DerivationPath, ExtendedPrivateKey,
+    ExtendedPublicKey, ExtendedPublicKeyError, PublicKey,
+};
+
+use base58::FromBase58;
+use core::{convert::TryFrom, fmt, str::FromStr};
+use hmac::{Hmac, Mac};
+use secp256k1::{PublicKey as Secp256k1_PublicKey, SecretKey};
+use sha2::Sha512;
+
+type HmacSha512 = Hmac<Sha512>;
+
+/// Represents a base58 extended public key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Base58ExtendedPublicKey<N: Base58Network> {
+    /// The address format
+    format: Base58Format,
+    /// The depth of key derivation, e.g. 0x00 for master nodes, 0x01 for level-1 derived keys, ...
+    depth: u8,
+    /// The first 32 bits of the key identifier (hash160(ECDSA_public_key))
+    parent_fingerprint: [u8; 4],
+    /// The child index of the key (0 for master key)
+    child_index: ChildIndex,
+    /// The chain code from the extended private key
+    chain_code: [u8; 32],
+    /// The public key
+    public_key: Base58PublicKey<N>,
+}
+
+impl<N: Base58Network> ExtendedPublicKey for Base58ExtendedPublicKey<N> {
+    type Address = Base58Address<N>;
+    type DerivationPath = Base58DerivationPath<N>;
+    type ExtendedPrivateKey = Base58ExtendedPrivateKey<N>;
+    type Format = Base58Format;
+    type PublicKey = Base58PublicKey<N>;
+
+    /// Returns the extended public key of the corresponding extended private key.
+    fn from_extended_private_key(extended_private_key: &Self::ExtendedPrivateKey) -> Self {
+        Self {
+            format: extended_private_key.format.clone(),
+            depth: extended_private_key.depth,
+            parent_fingerprint: extended_private_key.parent_fingerprint,
+            child_index: extended_private_key.child_index,
+            chain_code: extended_private_key.chain_code,
+            public_key: extended_private_key.to_public_key(),
+        }
+    }
+
+    /// Returns the extended public key for the given derivation path.
+    fn derive(&self, path: &Self::DerivationPath) -> Result<Self, ExtendedPublicKeyError> {
+        if self.depth == 255 {
+            return Err(ExtendedPublicKeyError::MaximumChildDepthReached(self.depth));
+        }
+
+        let mut extended_public_key = self.clone();
+
+        for index in path.to_vec()?.into_iter() {
+            let public_key_serialized = &extended_public_key
+                .public_key
+                .to_secp256k1_public_key()
+                .serialize_compressed()[..];
+
+            let mut mac = HmacSha512::new_varkey(&extended_public_key.chain_code)?;
+            match index {
+                // HMAC-SHA512(Key = cpar, Data = serP(Kpar) || ser32(i))
+                ChildIndex::Normal(_) => mac.input(public_key_serialized),
+                // Return failure
+                ChildIndex::Hardened(_) => {
+                    return Err(ExtendedPublicKeyError::InvalidChildNumber(1 << 31, u32::from(index)))
+                }
+            }
+            // Append the child index in big-endian format
+            mac.input(&u32::from(index).to_be_bytes());
+            let hmac = mac.result().code();
+
+            let mut chain_code = [0u8; 32];
+            chain_code[0..32].copy_from_slice(&hmac[32..]);
+
+            let mut public_key = extended_public_key.public_key.to_secp256k1_public_key();
+            public_key.tweak_add_assign(&SecretKey::parse_slice(&hmac[..32])?)?;
+            let public_key = Self::PublicKey::from_secp256k1_public_key(public_key, true);
+
+            let mut parent_fingerprint = [0u8; 4];
+            parent_fingerprint.copy_from_slice(&hash160(public_key_serialized)[0..4]);
+
+            extended_public_key = Self {
+                format: extended_public_key.format.clone(),
+                depth: extended_public_key.depth + 1,
+                parent_fingerprint,
+                child_index: index,
+                chain_code,
+                public_key,
+            };
+        }
+
+        Ok(extended_public_key)
+    }
+
+    /// Returns the public key of the corresponding extended public key.
+    fn to_public_key(&self) -> Self::PublicKey {
+        self.public_key.clone()
+    }
+
+    /// Returns the address of the corresponding extended public key.
+    fn to_address(&self, format: &Self::Format) -> Result<Self::Address, AddressError> {
+        self.public_key.to_address(format)
+    }
+}
+
+impl<N: Base58Network> Base58ExtendedPublicKey<N> {
+    /// Returns the format of the extended private key.
+    pub fn format(&self) -> Base58Format {
+        self.format.clone()
+    }
+}
+
+impl<N: Base58Network> FromStr for Base58ExtendedPublicKey<N> {
+    type Err = ExtendedPublicKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data = s.from_base58()?;
+        if data.len() != 82 {
+            return Err(ExtendedPublicKeyError::InvalidByteLength(data.len()));
+        }
+
+        // Check that the version bytes correspond with the correct network.
+        let _ = N::from_extended_public_key_version_bytes(&data[0..4])?;
+        let format = Base58Format::from_extended_public_key_version_bytes::<N>(&data[0..4])?;
+
+        let mut version = [0u8; 4];
+        version.copy_from_slice(&data[0..4]);
+
+        let depth = data[4];
+
+        let mut parent_fingerprint = [0u8; 4];
+        parent_fingerprint.copy_from_slice(&data[5..9]);
+
+        let child_index = ChildIndex::from(u32::from_be_bytes(<[u8; 4]>::try_from(&data[9..13])?));
+
+        let mut chain_code = [0u8; 32];
+        chain_code.copy_from_slice(&data[13..45]);
+
+        let secp256k1_public_key = Secp256k1_PublicKey::parse_slice(&data[45..78], None)?;
+        let public_key = Base58PublicKey::from_secp256k1_public_key(secp256k1_public_key, true);
+
+        base58check::verify(&data)?;
+
+        Ok(Self {
+            format,
+            depth,
+            parent_fingerprint,
+            child_index,
+            chain_code,
+            public_key,
+        })
+    }
+}
+
+impl<N: Base58Network> fmt::Display for Base58ExtendedPublicKey<N> {
+    /// BIP32 serialization format
+    /// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let mut result = [0u8; 78];
+        result[0..4].copy_from_slice(&N::EXTENDED_PUBLIC_KEY_VERSION_BYTES);
+        result[4] = self.depth;
+        result[5..9].copy_from_slice(&self.parent_fingerprint[..]);
+        result[9..13].copy_from_slice(&u32::from(self.child_index).to_be_bytes());
+        result[13..45].copy_from_slice(&self.chain_code[..]);
+        result[45..78].copy_from_slice(&self.public_key.to_secp256k1_public_key().serialize_compressed()[..]);
+
+        fmt.write_str(&base58check::encode(&result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+    use wagyu_model::extended_private_key::ExtendedPrivateKey;
+
+    use core::convert::TryInto;
+    use hex;
+
+    fn test_from_extended_private_key<N: Base58Network>(
+        expected_extended_public_key: &str,
+        expected_public_key: &str,
+        expected_child_index: u32,
+        expected_chain_code: &str,
+        expected_parent_fingerprint: &str,
+        extended_private_key: &str,
+    ) {
+        let extended_private_key = Base58ExtendedPrivateKey::<N>::from_str(extended_private_key).unwrap();
+        let extended_public_key = Base58ExtendedPublicKey::<N>::from_extended_private_key(&extended_private_key);
+        assert_eq!(expected_extended_public_key, extended_public_key.to_string());
+        assert_eq!(expected_public_key, extended_public_key.public_key.to_string());
+        assert_eq!(expected_child_index, u32::from(extended_public_key.child_index));
+        assert_eq!(expected_chain_code, hex::encode(extended_public_key.chain_code));
+        assert_eq!(
+            expected_parent_fingerprint,
+            hex::encode(extended_public_key.parent_fingerprint)
+        );
+    }
+
+    // Check: (extended_private_key1 -> extended_private_key2 -> extended_public_key2) == (expected_extended_public_key2)
+    fn test_derive<N: Base58Network>(
+        expected_extended_private_key1: &str,
+        expected_extended_public_key2: &str,
+        expected_child_index2: u32,
+    ) {
+        let path = vec![ChildIndex::from(expected_child_index2)].try_into().unwrap();
+
+        let extended_private_key1 = Base58ExtendedPrivateKey::<N>::from_str(expected_extended_private_key1).unwrap();
+        let extended_private_key2 = extended_private_key1.derive(&path).unwrap();
+        let extended_public_key2 = extended_private_key2.to_extended_public_key();
+
+        let expected_extended_public_key2 =
+            Base58ExtendedPublicKey::<N>::from_str(&expected_extended_public_key2).unwrap();
+
+        assert_eq!(expected_extended_public_key2, extended_public_key2);
+        assert_eq!(
+            expected_extended_public_key2.public_key,
+            extended_public_key2.public_key
+        );
+        assert_eq!(expected_extended_public_key2.depth, extended_public_key2.depth);
+        assert_eq!(
+            expected_extended_public_key2.child_index,
+            extended_public_key2.child_index
+        );
+        assert_eq!(
+            expected_extended_public_key2.chain_code,
+            extended_public_key2.chain_code
+        );
+        assert_eq!(
+            expected_extended_public_key2.parent_fingerprint,
+            extended_public_key2.parent_fingerprint
+        );
+    }
+
+    fn test_from_str<N: Base58Network>(
+        expected_public_key: &str,
+        expected_child_index: u32,
+        expected_chain_code: &str,
+        expected_parent_fingerprint: &str,
+        extended_public_key: &str,
+    ) {
+        let extended_public_key = Base58ExtendedPublicKey::<N>::from_str(&extended_public_key).unwrap();
+        assert_eq!(expected_public_key, extended_public_key.public_key.to_string());
+        assert_eq!(expected_child_index, u32::from(extended_public_key.child_index));
+        assert_eq!(expected_chain_code, hex::encode(extended_public_key.chain_code));
+        assert_eq!(
+            expected_parent_fingerprint,
+            hex::encode(extended_public_key.parent_fingerprint)
+        );
+    }
+
+    fn test_to_string<N: Base58Network>(expected_extended_public_key: &str) {
+        let extended_public_key = Base58ExtendedPublicKey::<N>::from_str(&expected_extended_public_key).unwrap();
+        assert_eq!(expected_extended_public_key, extended_public_key.to_string());
+    }
+
+    mod bip32_mainnet {
+        use super::*;
+
+        type N = Mainnet;
+
+        // (path, seed, child_index, public_key, chain_code, parent_fingerprint, extended_private_key, extended_public_key)
+        const KEYPAIRS: [(&str, &str, &str, &str, &str, &str, &str, &str); 12] = [
+            (
+                "m",
+                "000102030405060708090a0b0c0d0e0f",
+                "0",
+                "0339a36013301597daef41fbe593a02cc513d0b55527ec2df1050e2e8ff49c85c2",
+                "873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508",
+                "00000000",
+                "dgpv51eADS3spNJh9Gjth94XcPwAczvQaDJs9rqx11kvxKs6r3Ek8AgERHhjLs6mzXQFHRzQqGwqdeoDkZmr8jQMBfi43b7sT3sx3cCSk5fGeUR",
+                "dgub8kXBZ7ymNWy2S8Q3jNgVjFUm5ZJ3QLLaSTdAA89ukSv7Q6MSXwE14b7Nv6eDpE9JJXinTKc8LeLVu19uDPrm5uJuhpKNzV2kAgncwo6bNpP"
+            ),
+            (
+                "m/0'",
+                "000102030405060708090a0b0c0d0e0f",
+                "2147483648",
+                "035a784662a4a20a65bf6aab9ae98a6c068a81c52e4b032c0fb5400c706cfccc56",
+                "47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141",
+                "3442193e",
+                "dgpv53uaD9MLudRgHssbttwAVS3GwpUkxHnsqUGqy793vX4PDKXvYQDKYS4988T7QEnCzUt7CaGi21e6UKoZnKgXyjna7To1h1aqkcqJBDM65ur",
+                "dgub8nnbYqHETn61ajXkw8Z8cHasQNrPnQpb85448DY2ie7PmNecxAm6BjTnhNCvZY3qJk1MKZ9Z5HQasQ83ARb99nmduT7dunvxgcvBFVHuvrq"
+            ),
+            (
+                "m/0'/1",
+                "000102030405060708090a0b0c0d0e0f",
+                "1",
+                "03501e454bf00751f24b1b489aa925215d66af2234e3891c3b21a52bedb3cd711c",
+                "2a7857631386ba23dacac34180dd1983734e444fdbf774041578e9b6adb37c19",
+                "5c1bd648",
+                "dgpv565hQvuEJLJk8Kv3d9q36Avw1CTrxKXAmnwgZNurs9rbSs34GCddVzxNYBeB1AZFSZdo1Ps96ibWcGKnufUWkuH1dEkjkmMhRR9fi7Po6B2",
+                "dgub8pxikcq7rUy5RBaCfPT1D2UXTkqVnSYt4PitiVJqfGubzv9kfyBQ9JN27SfVyUmBGTdQ6ybfBsu4Thrrdkm2qSbaCexVPRwEKMSxYLP2A41"
+            ),
+            (
+                "m/0'/1/2'",
+                "000102030405060708090a0b0c0d0e0f",
+                "2147483650",
+                "0357bfe1e341d01c69fe5654309956cbea516822fba8a601743a012a7896ee8dc2",
+                "04466b9cc8e161e966409ca52986c584f07e9dc81f735db683c3ff6ec7b1503f",
+                "bef5a2f9",
+                "dgpv58gyTTj61DA9zVi8skEQTAy5EMLPDs7A7LBMoiD232E2riEB4xU4QSWJ6DrnyQ4jx2fBbrp4X8RQqU4YVgPhszifyrKHuhbe2gttLnRB4a6",
+                "dgub8sZzo9eyZMpVHMNHuyrNa2Wfgui23z8sPvxZxpbzq9H3QmLsUj1q3juwfTrLRMCVcyj8iMaGZpU2v319LrJZttkQnYvdUNzv33N6dcqeZ8X"
+            ),
+            (
+                "m/0'/1/2'/2",
+                "000102030405060708090a0b0c0d0e0f",
+                "2",
+                "02e8445082a72f29b75ca48748a914df60622a609cacfce8ed0e35804560741d29",
+                "cfb71883f01676f587d023cc53a35bc7f88f724b1f8c2892ac1275ac822a3edd",
+                "ee7ab90c",
+                "dgpv5AvNHtr3Bgq94yBra1SVLg8PKAd7rTRMYp4f4fjVMTneDorY8jARc1yDmYGFS4UB1pntDn3dRwsaJexzh6w45PJiP6QPTnRMBfN3rDUiyyH",
+                "dgub8uoPdamvjqVUMpr1cF4TTXfymizkgaT4qQqsDn8U9aqemryEYViCFKNsLnqiq9ME6HrJrN4DcZN9UTM9S9jmcVDfhLUpJZtk3jGwnGkhd8u"
+            ),
+            (
+                "m/0'/1/2'/2/1000000000",
+                "000102030405060708090a0b0c0d0e0f",
+                "1000000000",
+                "022a471424da5e657499d1ff51cb43c47481a03b1e77f951fe64cec9f5a48f7011",
+                "c783e67b921d2beb8f6b389cc646d7263b4145701dadd2161548a8b078e65e9e",
+                "d880d7d8",
+                "dgpv5Ce8maTHJpDLbJyJgZ1DeP8P7QCRfxEPM4TDJx7dZYB8vwFvf9R5s88HQQ3TLybFdEC9192aGzQhJpyNEAwnCLxFibAcahB4TzvQbJyp2im",
+                "dgub8wXA7GPArxsftAdTindBmEfyZxa4W5G6dfERU4WcMfE9UzNd4uxrWRXvyckfgQRwZz8rMhz29m4k4skAY1EcTkNnZstu73UNrgts2MA5evC"
+            ),
+            (
+                "m",
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "0",
+                "03cbcaa9c98c877a26977d00825c956a238e8dddfbd322cce4f74b0b5bd6ace4a7",
+                "60499f801b896d83179a4374aeb7822aaeaceaa0db1f85ee3e904c4defbd9689",
+                "00000000",
+                "dgpv51eADS3spNJh8tFDoJ8p4bevsC4qFqZgqSdgHxVkvhyy92FwJKTArBsKgvsqB2xLXUjqaZQHukqQr6VxB9o3o32pW1C7bPngcrpg75LUw8V",
+                "dgub8kXBZ7ymNWy2RjuNqXknBTCXKkSU5xbQ83QtT4tjiq2yh5Ndi5zwVVGyGCjCXUWGD5xaMzGHjiqkcnt8LamvDpJrZkWqpyXQV4TjDhfyo9Q"
+            ),
+            (
+                "m/0",
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "0",
+                "02fc9e5af0ac8d9b3cecfe2a888e2117ba3d089d8585886c9c826b6b22a98d12ea",
+                "f0909affaa7ee7abe5dd4e100598d4dc53cd709d5a5c2cac40e7412f232f7c9c",
+                "bd16bee5",
+                "dgpv54uuV9jqJP8mf9qWw5Wh8Q4TE9p5xp3yPcP3TmXD17qvpzhusaoF12SaGS9dp6oAw8yfUZp2LvFYCc8mjSJ6jGCDWBcAysxRkGjEUK7pYvw",
+                "dgub8onvpqfirXo6x1VfyK8fFFc3giBinw5ggDAFcsvBoEtwP3pcHMM1eKrDqfh6KZWhRQSkEDG38ogimxJpDjULZQy8qoFWjKfncYaPesrSURc"
+            ),
+            (
+                "m/0/2147483647'",
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "4294967295",
+                "03c01e7425647bdefa82b12d9bad5e3e6865bee0502694b94ca58b666abc0a5c3b",
+                "be17a268474a6bb9c61e1d720cf6215e2a88c5406c4aee7b38547f585c9a37d9",
+                "5a61ff8e",
+                "dgpv564xjkmMgHJsq23hMXWLbjk1cu2zWJDfUmTaPARyercKHHwNVUDZD6EAsndcYMXeqNJZFb1fPvkedqsYTouEJZdmvuqWkPggY44mEn4uizf",
+                "dgub8pwz5ShFERyD7shrPm8JibHc5TQdLRFNmNEnYGpxSyfKqM44uEmKrPdpT3wD5J7oCvNHt47eS27KSdB9zdxTHZRmNssa63voUvqzVgkMK7p"
+            ),
+            (
+                "m/0/2147483647'/1",
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "1",
+                "03a7d1d856deb74c508e05031f9895dab54626251b3806e16b4bd12e781a7df5b9",
+                "f366f48f1ea9f2d1d3fe958c95ca84ea18e4c4ddb9366c336c927eb246fb38cb",
+                "d8ab4937",
+                "dgpv58sw9mkHqvc4vdiqRBeuZggExRuVcV8voCxSEE6Jxjxkx7oXnykG6zcYiaqEa4jM9KfFzt63oURrxYehWhRcK3T54gNKbVf51rVViRSkahZ",
+                "dgub8skxVTgBQ5GQDVNzTRGsgYDqQzH8ScAe5ojePLVHks1mWAvECkJ2kJ2CHr8LsAp5o6pqihCt59R9XRSAuYPQYttfyA5RJbN1QhWwkCcvPdA"
+            ),
+            (
+                "m/0/2147483647'/1/2147483646'",
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "4294967294",
+                "02d2b36900396c9282fa14628566582f206a5dd0bcc8d5e892611806cafb0301f0",
+                "637807030d55d01f9a0cb3a7839515d796bd07706386a6eddf06cc29a65a0e29",
+                "78412e3a",
+                "dgpv5A3y4jgeTKZnDaxS9fUSwCNRsANrfTmRpugKkwnioWE1QU8TtegGdWyeMHZdCV7dgtwxJhs3Br1Smfk52eL6zt8EtcudjhghMSW1nDNfmHP",
+                "dgub8tvzQRcY1UE7WScbBu6R43v2KikVVao97WTXv4BhbdH1xXFAJRE3GpPHvWFr4YLXkYUUXCGb7kk1B4bZbRVvGFb8F4PurtTRGvbXH6bMPN3"
+            ),
+            (
+                "m/0/2147483647'/1/2147483646'/2",
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "2",
+                "024d902e1a2fc7a8755ab5b694c575fce742c48d9ff192e63df5193e4c7afe1f9c",
+                "9452b549be8cea3ecb7a84bec10dcfd94afe4d129ebfd3b3cb58eedf394ed271",
+                "31a507b8",
+                "dgpv5BR12Au9yVt1xr9Ksjc8uwkQGDuxungWV5JDAj9DSJ5bUfU34Hc2p5veRhEdMWChjCogbTVrdwr8pDdakxhL3rrxhUR8o7pR3oqZrnPNxDt",
+                "dgub8vJ2Mrq3XeYMFhoUuyE72oHzinHbjuiDmg5RKqYCER8c2iajU49oTPLHzuL8C7hEGbgho7n11TkzfVf4RXBpaWShtEDzoFk9xDnhwhVSxT7"
+            ),
+        ];
+
+        #[test]
+        fn from_extended_private_key() {
+            KEYPAIRS.iter().for_each(
+                |(
+                    _,
+                    _,
+                    child_index,
+                    public_key,
+                    chain_code,
+                    parent_fingerprint,
+                    extended_private_key,
+                    extended_public_key,
+                )| {
+                    test_from_extended_private_key::<N>(
+                        extended_public_key,
+                        public_key,
+                        child_index.parse().unwrap(),
+                        chain_code,
+                        parent_fingerprint,
+                        extended_private_key,
+                    );
+                },
+            );
+        }
+
+        #[test]
+        fn derive() {
+            KEYPAIRS.chunks(2).for_each(|pair| {
+                let (_, _, _, _, _, _, expected_extended_private_key1, _) = pair[0];
+                let (_, _, expected_child_index2, _, _, _, _, expected_extended_public_key2) = pair[1];
+                test_derive::<N>(
+                    expected_extended_private_key1,
+                    expected_extended_public_key2,
+                    expected_child_index2.parse().unwrap(),
+                );
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(
+                |(_, _, child_index, public_key, chain_code, parent_fingerprint, _, extended_public_key)| {
+                    test_from_str::<N>(
+                        public_key,
+                        child_index.parse().unwrap(),
+                        chain_code,
+                        parent_fingerprint,
+                        extended_public_key,
+                    );
+                },
+            );
+        }
+
+        #[test]
+        fn to_string() {
+            KEYPAIRS.iter().for_each(|(_, _, _, _, _, _, _, extended_public_key)| {
+                test_to_string::<N>(extended_public_key);
+            });
+        }
+    }
+
+    mod test_invalid {
+        use super::*;
+
+        type N = Mainnet;
+
+        const INVALID_EXTENDED_PUBLIC_KEY_SECP256K1_PUBLIC_KEY: &str = "dgub8kXBZ7ymNWy2r8Q3jNgVjFUm5ZJ3QLLaSTdAA89ukSv7Q6MSXwE14b7Nv6eDpE9JJXinTKc8LeLVu19uDPrm5uJuhpKNzV2kAgncwn2BQmQ";
+        const INVALID_EXTENDED_PUBLIC_KEY_NETWORK: &str = "xpub561MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8";
+        const INVALID_EXTENDED_PUBLIC_KEY_CHECKSUM: &str = "dgub8kXBZ7ymNWy2S8Q3jNgVjFUm5ZJ3QLLaSTdAA89ukSv7Q6MSXwE14b7Nv6eDpE9JJXinTKc8LeLVu19uDPrm5uJuhpKNzV2kAgncwo85N6j";
+        const VALID_EXTENDED_PUBLIC_KEY: &str = "dgub8kXBZ7ymNWy2S8Q3jNgVjFUm5ZJ3QLLaSTdAA89ukSv7Q6MSXwE14b7Nv6eDpE9JJXinTKc8LeLVu19uDPrm5uJuhpKNzV2kAgncwo6bNpP";
+
+        #[test]
+        #[should_panic(expected = "Crate(\"libsecp256k1\", \"InvalidPublicKey\")")]
+        fn from_str_invalid_secret_key() {
+            let _result =
+                Base58ExtendedPublicKey::<N>::from_str(INVALID_EXTENDED_PUBLIC_KEY_SECP256K1_PUBLIC_KEY).unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "InvalidVersionBytes([4, 136, 178, 29])")]
+        fn from_str_invalid_version() {
+            let _result = Base58ExtendedPublicKey::<N>::from_str(INVALID_EXTENDED_PUBLIC_KEY_NETWORK).unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "InvalidChecksum(\"5KoVZH\", \"5JKWGw\")")]
+        fn from_str_invalid_checksum() {
+            let _result = Base58ExtendedPublicKey::<N>::from_str(INVALID_EXTENDED_PUBLIC_KEY_CHECKSUM).unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "InvalidByteLength(81)")]
+        fn from_str_short() {
+            let _result = Base58ExtendedPublicKey::<N>::from_str(&VALID_EXTENDED_PUBLIC_KEY[1..]).unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "InvalidByteLength(83)")]
+        fn from_str_long() {
+            let mut string = String::from(VALID_EXTENDED_PUBLIC_KEY);
+            string.push_str("aa");
+            let _result = Base58ExtendedPublicKey::<N>::from_str(&string).unwrap();
+        }
+    }
+}
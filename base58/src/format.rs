@@ -0,0 +1,60 @@
+use crate::network::Base58Network;
+use wagyu_model::no_std::*;
+use wagyu_model::{AddressError, ExtendedPrivateKeyError, ExtendedPublicKeyError, Format};
+
+use core::fmt;
+use serde::Serialize;
+
+/// Represents the format of a base58 address
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Base58Format {
+    /// Pay-to-Pubkey Hash, e.g. 1JwSSubhmg6iPtRjtyqhUYYH7bZg3Lfy1T
+    P2PKH,
+}
+
+impl Format for Base58Format {}
+
+impl Base58Format {
+    /// Returns the address prefix of the given network.
+    pub fn to_address_prefix<N: Base58Network>(&self) -> Vec<u8> {
+        match self {
+            Base58Format::P2PKH => vec![N::P2PKH_PREFIX],
+        }
+    }
+
+    /// Returns the format of the given address prefix of the network.
+    pub fn from_address_prefix<N: Base58Network>(prefix: &[u8]) -> Result<Self, AddressError> {
+        match prefix.first() {
+            Some(&version) if version == N::P2PKH_PREFIX => Ok(Base58Format::P2PKH),
+            _ => Err(AddressError::InvalidPrefix(prefix.to_vec())),
+        }
+    }
+
+    /// Returns the format of the given extended private key version bytes of the network.
+    pub fn from_extended_private_key_version_bytes<N: Base58Network>(
+        prefix: &[u8],
+    ) -> Result<Self, ExtendedPrivateKeyError> {
+        match prefix.get(0..4) == Some(&N::EXTENDED_PRIVATE_KEY_VERSION_BYTES[..]) {
+            true => Ok(Base58Format::P2PKH),
+            false => Err(ExtendedPrivateKeyError::InvalidVersionBytes(prefix.to_vec())),
+        }
+    }
+
+    /// Returns the format of the given extended public key version bytes of the network.
+    pub fn from_extended_public_key_version_bytes<N: Base58Network>(
+        prefix: &[u8],
+    ) -> Result<Self, ExtendedPublicKeyError> {
+        match prefix.get(0..4) == Some(&N::EXTENDED_PUBLIC_KEY_VERSION_BYTES[..]) {
+            true => Ok(Base58Format::P2PKH),
+            false => Err(ExtendedPublicKeyError::InvalidVersionBytes(prefix.to_vec())),
+        }
+    }
+}
+
+impl fmt::Display for Base58Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Base58Format::P2PKH => write!(f, "p2pkh"),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! # Base58
+//!
+//! A library for generating the P2PKH and BIP32 wallets of Bitcoin forks with base58 version bytes.
+//! A coin is a parameter set over this library, as its networks implement `Base58Network`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(unused_extern_crates, dead_code)]
+#![forbid(unsafe_code)]
+
+pub mod address;
+pub use self::address::*;
+
+pub mod derivation_path;
+pub use self::derivation_path::*;
+
+pub mod extended_private_key;
+pub use self::extended_private_key::*;
+
+pub mod extended_public_key;
+pub use self::extended_public_key::*;
+
+pub mod format;
+pub use self::format::*;
+
+pub mod mnemonic;
+pub use self::mnemonic::*;
+
+pub mod network;
+pub use self::network::*;
+
+pub mod private_key;
+pub use self::private_key::*;
+
+pub mod public_key;
+pub use self::public_key::*;
+
+pub mod wordlist;
+pub use self::wordlist::*;
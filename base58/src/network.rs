@@ -0,0 +1,78 @@
+pub use wagyu_model::Base58Network;
+
+/// The networks of the test vectors, which are configured with the version bytes of Dogecoin.
+#[cfg(test)]
+mod test_network {
+    use wagyu_model::{Base58Network, ChildIndex, Network, NetworkError};
+
+    use core::{fmt, str::FromStr};
+
+    #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Mainnet;
+
+    impl Network for Mainnet {
+        const NAME: &'static str = "mainnet";
+    }
+
+    impl Base58Network for Mainnet {
+        const HD_COIN_TYPE: ChildIndex = ChildIndex::Hardened(3);
+        const P2PKH_PREFIX: u8 = 0x1E;
+        const P2SH_PREFIX: u8 = 0x16;
+        const WIF_PREFIX: u8 = 0x9E;
+        const EXTENDED_PRIVATE_KEY_VERSION_BYTES: [u8; 4] = [0x02, 0xFA, 0xC3, 0x98]; // dgpv
+        const EXTENDED_PUBLIC_KEY_VERSION_BYTES: [u8; 4] = [0x02, 0xFA, 0xCA, 0xFD]; // dgub
+    }
+
+    #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Testnet;
+
+    impl Network for Testnet {
+        const NAME: &'static str = "testnet";
+    }
+
+    impl Base58Network for Testnet {
+        const HD_COIN_TYPE: ChildIndex = ChildIndex::Hardened(1);
+        const P2PKH_PREFIX: u8 = 0x71;
+        const P2SH_PREFIX: u8 = 0xC4;
+        const WIF_PREFIX: u8 = 0xF1;
+        const EXTENDED_PRIVATE_KEY_VERSION_BYTES: [u8; 4] = [0x04, 0x35, 0x83, 0x94]; // tprv
+        const EXTENDED_PUBLIC_KEY_VERSION_BYTES: [u8; 4] = [0x04, 0x35, 0x87, 0xCF]; // tpub
+    }
+
+    impl FromStr for Mainnet {
+        type Err = NetworkError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                Self::NAME => Ok(Self),
+                _ => Err(NetworkError::InvalidNetwork(s.into())),
+            }
+        }
+    }
+
+    impl fmt::Display for Mainnet {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{}", Self::NAME)
+        }
+    }
+
+    impl FromStr for Testnet {
+        type Err = NetworkError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                Self::NAME => Ok(Self),
+                _ => Err(NetworkError::InvalidNetwork(s.into())),
+            }
+        }
+    }
+
+    impl fmt::Display for Testnet {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{}", Self::NAME)
+        }
+    }
+}
+
+#[cfg(test)]
+pub use self::test_network::*;
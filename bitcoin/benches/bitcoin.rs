@@ -0,0 +1,65 @@
+use wagyu_bitcoin::{
+    BitcoinAmount, BitcoinFormat, BitcoinNetwork, BitcoinPrivateKey, BitcoinTransaction, BitcoinTransactionInput,
+    BitcoinTransactionOutput, BitcoinTransactionParameters, Mainnet, SignatureHash,
+};
+use wagyu_model::{PrivateKey, Transaction};
+
+use core::str::FromStr;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+const PRIVATE_KEY: &str = "L5BsLN6keEWUuF1JxfG6w5U1FDHs29faMpr9QX2MMVuQt7ymTorX";
+const TRANSACTION_ID: [u8; 32] = [0x61; 32];
+
+fn private_key_new<N: BitcoinNetwork>(c: &mut Criterion) {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    c.bench_function("bitcoin_private_key_new", |b| {
+        b.iter(|| BitcoinPrivateKey::<N>::new(rng).unwrap())
+    });
+}
+
+fn to_address<N: BitcoinNetwork>(c: &mut Criterion) {
+    let private_key = BitcoinPrivateKey::<N>::from_str(PRIVATE_KEY).unwrap();
+    for format in &[BitcoinFormat::P2PKH, BitcoinFormat::P2SH_P2WPKH, BitcoinFormat::Bech32] {
+        c.bench_function(&format!("bitcoin_to_address_{}", format), |b| {
+            b.iter(|| black_box(&private_key).to_address(format).unwrap())
+        });
+    }
+}
+
+fn sign<N: BitcoinNetwork>(c: &mut Criterion) {
+    let private_key = BitcoinPrivateKey::<N>::from_str(PRIVATE_KEY).unwrap();
+    let address = private_key.to_address(&BitcoinFormat::P2PKH).unwrap();
+    let input = BitcoinTransactionInput::<N>::new(
+        TRANSACTION_ID.to_vec(),
+        0,
+        Some(address.clone()),
+        Some(BitcoinAmount::from_satoshi(100000).unwrap()),
+        None,
+        None,
+        None,
+        SignatureHash::SIGHASH_ALL,
+    )
+    .unwrap();
+    let output = BitcoinTransactionOutput::new(&address, BitcoinAmount::from_satoshi(90000).unwrap()).unwrap();
+    let parameters = BitcoinTransactionParameters::<N> {
+        version: 1,
+        inputs: vec![input],
+        outputs: vec![output],
+        lock_time: 0,
+        segwit_flag: false,
+    };
+    let transaction = BitcoinTransaction::<N>::new(&parameters).unwrap();
+    c.bench_function("bitcoin_transaction_sign", |b| {
+        b.iter(|| black_box(&transaction).sign(&private_key).unwrap())
+    });
+}
+
+criterion_group!(
+    benches,
+    private_key_new::<Mainnet>,
+    to_address::<Mainnet>,
+    sign::<Mainnet>
+);
+criterion_main!(benches);
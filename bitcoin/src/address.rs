@@ -4,12 +4,9 @@ use crate::private_key::BitcoinPrivateKey;
 use crate::public_key::BitcoinPublicKey;
 use crate::witness_program::WitnessProgram;
 use wagyu_model::no_std::*;
-use wagyu_model::{
-    crypto::{checksum, hash160},
-    Address, AddressError, PrivateKey,
-};
+use wagyu_model::{crypto::hash160, encoding::base58check, Address, AddressError, PrivateKey};
 
-use base58::{FromBase58, ToBase58};
+use base58::FromBase58;
 use bech32::{u5, Bech32, FromBase32, ToBase32};
 use core::{convert::TryFrom, fmt, marker::PhantomData, str::FromStr};
 use sha2::{Digest, Sha256};
@@ -70,15 +67,12 @@ impl<N: BitcoinNetwork> BitcoinAddress<N> {
             false => public_key.to_secp256k1_public_key().serialize().to_vec(),
         };
 
-        let mut address = [0u8; 25];
+        let mut address = [0u8; 21];
         address[0] = N::to_address_prefix(&BitcoinFormat::P2PKH)[0];
         address[1..21].copy_from_slice(&hash160(&public_key));
 
-        let sum = &checksum(&address[0..21])[0..4];
-        address[21..25].copy_from_slice(sum);
-
         Ok(Self {
-            address: address.to_base58(),
+            address: base58check::encode(&address),
             format: BitcoinFormat::P2PKH,
             _network: PhantomData,
         })
@@ -107,15 +101,12 @@ impl<N: BitcoinNetwork> BitcoinAddress<N> {
 
     /// Returns a P2SH_P2WPKH address from a given Bitcoin public key.
     pub fn p2sh_p2wpkh(public_key: &<Self as Address>::PublicKey) -> Result<Self, AddressError> {
-        let mut address = [0u8; 25];
+        let mut address = [0u8; 21];
         address[0] = N::to_address_prefix(&BitcoinFormat::P2SH_P2WPKH)[0];
         address[1..21].copy_from_slice(&hash160(&Self::create_redeem_script(public_key)));
 
-        let sum = &checksum(&address[0..21])[0..4];
-        address[21..25].copy_from_slice(sum);
-
         Ok(Self {
-            address: address.to_base58(),
+            address: base58check::encode(&address),
             format: BitcoinFormat::P2SH_P2WPKH,
             _network: PhantomData,
         })
@@ -168,6 +159,10 @@ impl<N: BitcoinNetwork> FromStr for BitcoinAddress<N> {
             return Err(AddressError::InvalidCharacterLength(address.len()));
         }
 
+        if !address.is_ascii() {
+            return Err(AddressError::InvalidAddress(address.to_owned()));
+        }
+
         let prefix = &address.to_lowercase()[0..2];
 
         if let Ok(format) = BitcoinFormat::from_address_prefix(prefix.as_bytes()) {
@@ -186,8 +181,12 @@ impl<N: BitcoinNetwork> FromStr for BitcoinAddress<N> {
 
                 // Check that the witness program is valid.
                 let _ = WitnessProgram::new(data.as_slice())?;
-                // Check that the address prefix corresponds to the correct network.
-                let _ = N::from_address_prefix(prefix.as_bytes())?;
+                // Check that the human-readable part corresponds to the correct network.
+                let hrp = bech32.hrp().as_bytes();
+                let _ = N::from_address_prefix(hrp)?;
+                if hrp != N::to_address_prefix(&BitcoinFormat::Bech32).as_slice() {
+                    return Err(AddressError::InvalidPrefix(hrp.to_vec()));
+                }
 
                 return Ok(Self {
                     address: address.to_owned(),
@@ -201,6 +200,7 @@ impl<N: BitcoinNetwork> FromStr for BitcoinAddress<N> {
         if data.len() != 25 {
             return Err(AddressError::InvalidByteLength(data.len()));
         }
+        base58check::verify(&data)?;
 
         // Check that the address prefix corresponds to the correct network.
         let _ = N::from_address_prefix(&data[0..2])?;
@@ -406,6 +406,14 @@ mod tests {
 
             let address = "12WMrNLRosydPNNYM96dwk9jDv8rDRom3J12WMrNLRosydPNNYM96dwk9jDv8rDRom3J";
             assert!(BitcoinAddress::<N>::from_str(address).is_err());
+
+            // Invalid checksum
+
+            let address = "12WMrNLRosydPNNYM96dwk9jDv8rDRom3K";
+            assert!(match BitcoinAddress::<N>::from_str(address) {
+                Err(AddressError::InvalidChecksum(_, _)) => true,
+                _ => false,
+            });
         }
     }
 
@@ -839,6 +847,60 @@ mod tests {
         }
     }
 
+    mod bech32_regtest {
+        use super::*;
+
+        type N = Regtest;
+
+        const KEYPAIRS: [(&str, &str); 5] = [
+            (
+                "cVQmTtLoCjDJAXVj778xyww1ZbpJQt7Vq9sDt8Mdmw97Rg7TaNes",
+                "bcrt1qmkvfprg8pkr3apv9gyykmhe26fexyla0unfacp",
+            ),
+            (
+                "cTxHRG8MgrnSQstuMs5VnQcFBjrs67NmiJGo1kevnJDS7QFGLUAi",
+                "bcrt1qfe0dnfpxp4c9lfdjzvmf5q72jg83emgk3jpt3y",
+            ),
+            (
+                "cSN1N2Vmhg9jPSUpXyQj8WbNUgeLHbC3Yj8SFX2N834YMepMwNZH",
+                "bcrt1qx4jm2s3ks5vadh2ja3flsn4ckjzhdxmxej6w40",
+            ),
+            (
+                "cMvmoqYYzr4dgzNZ22PvaqSnNx98evXc1b7m8FfK9SdCqhiWdP2c",
+                "bcrt1ql0g42pusevlgd0jh9gyr32s0h0pe96wp32e9xj",
+            ),
+            (
+                "cVodD5ifcBjYVUs19GLwz6YzU2hUhdNagBx9QQcZp7TgjLuuFYn3",
+                "bcrt1qwnh7hu5qfrjsk9pyn3vvmzr48v4l8kp47pkx36",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = BitcoinPrivateKey::<N>::from_str(private_key).unwrap();
+                test_from_private_key(address, &private_key, &BitcoinFormat::Bech32);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, address)| {
+                test_from_str::<N>(address, &BitcoinFormat::Bech32);
+            });
+        }
+
+        #[test]
+        fn from_other_network() {
+            KEYPAIRS.iter().for_each(|(_, address)| {
+                assert!(BitcoinAddress::<Mainnet>::from_str(address).is_err());
+                assert!(BitcoinAddress::<Testnet>::from_str(address).is_err());
+            });
+            assert!(BitcoinAddress::<N>::from_str("tb1qmkvfprg8pkr3apv9gyykmhe26fexyla076ss0g").is_err());
+            assert!(BitcoinAddress::<N>::from_str("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4").is_err());
+        }
+    }
+
     mod p2wsh_testnet {
         use super::*;
 
@@ -111,6 +111,49 @@ impl BitcoinAmount {
         Self::from_satoshi(satoshis)
     }
 
+    /// Returns the amount of a decimal value in BTC, as in `0.0005`, with at most 8 decimal places.
+    pub fn from_btc_str(btc_value: &str) -> Result<Self, AmountError> {
+        let invalid = || AmountError::InvalidAmount(btc_value.into());
+        let (integer, fraction) = match btc_value.find('.') {
+            Some(index) => (&btc_value[..index], &btc_value[index + 1..]),
+            None => (btc_value, ""),
+        };
+        let precision = Denomination::Bitcoin.precision() as usize;
+        if (integer.is_empty() && fraction.is_empty())
+            || fraction.len() > precision
+            || !integer.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let integer = match integer.is_empty() {
+            true => 0,
+            false => integer.parse::<i64>().map_err(|_| invalid())?,
+        };
+        let fraction = format!("{:0<1$}", fraction, precision)
+            .parse::<i64>()
+            .map_err(|_| invalid())?;
+        match integer
+            .checked_mul(COIN)
+            .and_then(|satoshis| satoshis.checked_add(fraction))
+        {
+            Some(satoshis) => Self::from_satoshi(satoshis),
+            None => Err(invalid()),
+        }
+    }
+
+    /// Returns the decimal value of the amount in BTC, without trailing zeros, as in `0.0005`.
+    pub fn to_btc_string(&self) -> String {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let (integer, fraction) = (self.0.abs() / COIN, self.0.abs() % COIN);
+        match fraction {
+            0 => format!("{}{}", sign, integer),
+            _ => format!("{}{}.{:08}", sign, integer, fraction)
+                .trim_end_matches('0')
+                .to_string(),
+        }
+    }
+
     pub fn add(self, b: Self) -> Result<Self, AmountError> {
         Self::from_satoshi(self.0 + b.0)
     }
@@ -272,6 +315,27 @@ mod tests {
                 .iter()
                 .for_each(|amounts| test_from_btc(amounts.bitcoin, BitcoinAmount(amounts.satoshi)));
         }
+
+        #[test]
+        fn test_btc_str_conversion() {
+            TEST_AMOUNTS.iter().for_each(|amounts| {
+                let amount = BitcoinAmount::from_btc_str(&amounts.bitcoin.to_string()).unwrap();
+                assert_eq!(BitcoinAmount(amounts.satoshi), amount);
+                assert_eq!(amounts.bitcoin.to_string(), amount.to_btc_string());
+            });
+            [
+                ("0.0005", 50000),
+                ("1.00000001", 100000001),
+                (".5", 50000000),
+                ("20.30", 2030000000),
+            ]
+            .iter()
+            .for_each(|(btc_value, satoshi)| {
+                assert_eq!(BitcoinAmount(*satoshi), BitcoinAmount::from_btc_str(btc_value).unwrap());
+            });
+            assert_eq!("20.3", BitcoinAmount(2030000000).to_btc_string());
+            assert_eq!("-0.00000001", BitcoinAmount(-1).to_btc_string());
+        }
     }
 
     mod valid_arithmetic {
@@ -301,6 +365,13 @@ mod tests {
     mod test_invalid {
         use super::*;
 
+        #[test]
+        fn test_invalid_btc_str_conversion() {
+            ["", ".", "-1", "1e8", "0.000000001", "1,000", "21000000.00000001"]
+                .iter()
+                .for_each(|btc_value| assert!(BitcoinAmount::from_btc_str(btc_value).is_err()));
+        }
+
         mod test_out_of_bounds {
             use super::*;
 
@@ -0,0 +1,457 @@
+use crate::amount::BitcoinAmount;
+use crate::format::BitcoinFormat;
+use crate::transaction::{variable_length_integer, BitcoinTransactionOutput};
+use wagyu_model::no_std::*;
+use wagyu_model::TransactionError;
+
+/// The virtual size of a transaction without its inputs and outputs, in vbytes, rounded up
+/// (version, lock time, input and output counts, and the SegWit marker and flag)
+const TRANSACTION_VSIZE: u64 = 11;
+/// The maximum number of combinations of unspent outputs tried by branch and bound
+const MAXIMUM_TRIES: usize = 100_000;
+/// The fee rate of the dust threshold of Bitcoin Core, in satoshi per vbyte
+const DUST_FEE_RATE: u64 = 3;
+
+/// Represents a strategy to select the unspent outputs to fund a transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoinSelectionStrategy {
+    /// Searches for a combination of unspent outputs that funds the transaction without change,
+    /// as Bitcoin Core, and falls back to the largest first strategy if there is none
+    BranchAndBound,
+    /// Selects the unspent outputs of the largest amounts first, and returns the remainder as change
+    LargestFirst,
+}
+
+/// Represents an unspent output available to fund a transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Utxo {
+    /// The amount of the unspent output
+    pub amount: BitcoinAmount,
+    /// The virtual size of the signed input spending the unspent output, in vbytes
+    pub input_vsize: u64,
+}
+
+impl Utxo {
+    /// Returns an unspent output of the given amount, held by an address of the given format.
+    /// The virtual size of a P2WSH input depends on its witness script, and is specified with `input_vsize`.
+    pub fn new(amount: BitcoinAmount, format: &BitcoinFormat) -> Result<Self, TransactionError> {
+        match input_vsize(format) {
+            Some(input_vsize) => Ok(Self { amount, input_vsize }),
+            None => Err(TransactionError::InvalidInputs("P2WSH".into())),
+        }
+    }
+
+    /// Returns the amount of the unspent output, less the fee to spend it at the given fee rate.
+    fn effective_value(&self, fee_rate: u64) -> i64 {
+        self.amount.0 - (self.input_vsize * fee_rate) as i64
+    }
+}
+
+/// Represents the unspent outputs selected to fund a transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoinSelection {
+    /// The indices of the selected unspent outputs, in the order given
+    pub inputs: Vec<usize>,
+    /// The amount of the change output, if the remainder of the selection is not dust
+    pub change: Option<BitcoinAmount>,
+    /// The fee of the transaction
+    pub fee: BitcoinAmount,
+}
+
+impl CoinSelection {
+    /// Returns the unspent outputs selected to fund the given outputs at the given fee rate, in satoshi per vbyte,
+    /// and the amount of change to an address of the given format.
+    pub fn new(
+        utxos: &[Utxo],
+        outputs: &[BitcoinTransactionOutput],
+        fee_rate: u64,
+        change_format: &BitcoinFormat,
+        strategy: CoinSelectionStrategy,
+    ) -> Result<Self, TransactionError> {
+        let mut vsize = TRANSACTION_VSIZE;
+        for output in outputs {
+            vsize += output_vsize(output.script_pub_key.len())?;
+        }
+        let amount = outputs.iter().map(|output| output.amount.0).sum::<i64>();
+        let target = amount + (vsize * fee_rate) as i64;
+
+        let available = utxos
+            .iter()
+            .map(|utxo| utxo.effective_value(fee_rate))
+            .filter(|value| *value > 0)
+            .sum::<i64>();
+        if available < target {
+            return Err(TransactionError::InsufficientFunds(available, target));
+        }
+
+        let change_vsize = output_vsize(script_pub_key_length(change_format))?;
+        let change_fee = (change_vsize * fee_rate) as i64;
+        let dust = dust_threshold(change_format)?;
+
+        // A selection by branch and bound does not need change, as its excess is less than the cost of change
+        let selection = match strategy {
+            CoinSelectionStrategy::BranchAndBound => {
+                let cost_of_change = change_fee + (input_vsize(change_format).unwrap_or(68) * fee_rate) as i64;
+                branch_and_bound(utxos, fee_rate, target, cost_of_change)
+            }
+            CoinSelectionStrategy::LargestFirst => None,
+        };
+        let (mut inputs, change) = match selection {
+            Some(inputs) => (inputs, None),
+            None => {
+                let inputs = largest_first(utxos, fee_rate, target);
+                let effective = inputs
+                    .iter()
+                    .map(|index| utxos[*index].effective_value(fee_rate))
+                    .sum::<i64>();
+                let change = effective - target - change_fee;
+                match change >= dust {
+                    true => (inputs, Some(BitcoinAmount::from_satoshi(change)?)),
+                    false => (inputs, None),
+                }
+            }
+        };
+        inputs.sort();
+
+        // The fee is the remainder of the selection after the outputs and change
+        let selected = inputs.iter().map(|index| utxos[*index].amount.0).sum::<i64>();
+        let fee = selected - amount - change.map(|change| change.0).unwrap_or(0);
+
+        Ok(Self {
+            inputs,
+            change,
+            fee: BitcoinAmount::from_satoshi(fee)?,
+        })
+    }
+}
+
+impl CoinSelection {
+    /// Returns the selection of every unspent output worth more than the fee to spend it at the given fee rate,
+    /// in satoshi per vbyte, consolidated into a single output to an address of the given format.
+    /// The amount of the output, the selected amount less the fee, is returned as the change of the selection.
+    pub fn consolidate(utxos: &[Utxo], fee_rate: u64, format: &BitcoinFormat) -> Result<Self, TransactionError> {
+        let inputs = (0..utxos.len())
+            .filter(|index| utxos[*index].effective_value(fee_rate) > 0)
+            .collect::<Vec<usize>>();
+
+        let mut vsize = TRANSACTION_VSIZE + output_vsize(script_pub_key_length(format))?;
+        vsize += inputs.iter().map(|index| utxos[*index].input_vsize).sum::<u64>();
+        let fee = (vsize * fee_rate) as i64;
+
+        // The output must not be dust, or the consolidation would not be relayed
+        let selected = inputs.iter().map(|index| utxos[*index].amount.0).sum::<i64>();
+        let dust = dust_threshold(format)?;
+        if selected - fee < dust {
+            return Err(TransactionError::InsufficientFunds(selected, fee + dust));
+        }
+
+        Ok(Self {
+            inputs,
+            change: Some(BitcoinAmount::from_satoshi(selected - fee)?),
+            fee: BitcoinAmount::from_satoshi(fee)?,
+        })
+    }
+}
+
+/// Returns the indices of a combination of unspent outputs whose effective value exceeds the target
+/// by less than the cost of change, with the least excess, searched depth first with the largest first.
+/// https://github.com/bitcoin/bitcoin/blob/v0.21.0/src/wallet/coinselection.cpp#L21
+fn branch_and_bound(utxos: &[Utxo], fee_rate: u64, target: i64, cost_of_change: i64) -> Option<Vec<usize>> {
+    let mut candidates = (0..utxos.len())
+        .map(|index| (index, utxos[index].effective_value(fee_rate)))
+        .filter(|(_, value)| *value > 0)
+        .collect::<Vec<(usize, i64)>>();
+    candidates.sort_by_key(|candidate| core::cmp::Reverse(candidate.1));
+
+    // The total effective value of the candidates from each depth
+    let mut remaining = vec![0i64; candidates.len() + 1];
+    for depth in (0..candidates.len()).rev() {
+        remaining[depth] = remaining[depth + 1] + candidates[depth].1;
+    }
+
+    let mut selection: Vec<usize> = vec![];
+    let mut best: Option<(Vec<usize>, i64)> = None;
+    let (mut depth, mut value) = (0, 0);
+    for _ in 0..MAXIMUM_TRIES {
+        let backtrack = if value + remaining[depth] < target || value > target + cost_of_change {
+            true
+        } else if value >= target {
+            let excess = value - target;
+            if best.as_ref().is_none_or(|(_, best_excess)| excess < *best_excess) {
+                best = Some((selection.clone(), excess));
+            }
+            true
+        } else {
+            false
+        };
+
+        if backtrack {
+            // Exclude the last selected candidate, and search the candidates after it
+            match selection.pop() {
+                Some(last) => {
+                    value -= candidates[last].1;
+                    depth = last + 1;
+                }
+                None => break,
+            }
+        } else {
+            value += candidates[depth].1;
+            selection.push(depth);
+            depth += 1;
+        }
+    }
+
+    best.map(|(selection, _)| selection.iter().map(|depth| candidates[*depth].0).collect())
+}
+
+/// Returns the indices of the unspent outputs of the largest effective values,
+/// until their total effective value is at least the target.
+fn largest_first(utxos: &[Utxo], fee_rate: u64, target: i64) -> Vec<usize> {
+    let mut candidates = (0..utxos.len()).collect::<Vec<usize>>();
+    candidates.sort_by_key(|index| -utxos[*index].effective_value(fee_rate));
+
+    let mut value = 0;
+    candidates
+        .into_iter()
+        .take_while(|index| {
+            let take = value < target;
+            value += utxos[*index].effective_value(fee_rate);
+            take
+        })
+        .collect()
+}
+
+/// Returns the dust threshold of an output to an address of the given format, as Bitcoin Core,
+/// the amount below which the output costs more to spend than it is worth at the dust fee rate.
+fn dust_threshold(format: &BitcoinFormat) -> Result<i64, TransactionError> {
+    let vsize = output_vsize(script_pub_key_length(format))?;
+    Ok(match format {
+        BitcoinFormat::P2PKH | BitcoinFormat::P2SH_P2WPKH => (vsize + 148) * DUST_FEE_RATE,
+        BitcoinFormat::Bech32 | BitcoinFormat::P2WSH => (vsize + 67) * DUST_FEE_RATE,
+    } as i64)
+}
+
+/// Returns the virtual size of a signed input spending an output of the given format, in vbytes,
+/// or `None` for P2WSH, as it depends on the witness script.
+fn input_vsize(format: &BitcoinFormat) -> Option<u64> {
+    match format {
+        BitcoinFormat::P2PKH => Some(148),
+        BitcoinFormat::P2SH_P2WPKH => Some(91),
+        BitcoinFormat::Bech32 => Some(68),
+        BitcoinFormat::P2WSH => None,
+    }
+}
+
+/// Returns the virtual size of an output with a script public key of the given length, in vbytes.
+pub(crate) fn output_vsize(script_pub_key_length: usize) -> Result<u64, TransactionError> {
+    Ok((8 + variable_length_integer(script_pub_key_length as u64)?.len() + script_pub_key_length) as u64)
+}
+
+/// Returns the length of the script public key of an address of the given format.
+fn script_pub_key_length(format: &BitcoinFormat) -> usize {
+    match format {
+        BitcoinFormat::P2PKH => 25,
+        BitcoinFormat::P2SH_P2WPKH => 23,
+        BitcoinFormat::Bech32 => 22,
+        BitcoinFormat::P2WSH => 34,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::BitcoinAddress;
+    use crate::network::Mainnet;
+
+    use core::str::FromStr;
+
+    type N = Mainnet;
+
+    const FORMAT: &BitcoinFormat = &BitcoinFormat::Bech32;
+
+    fn to_utxos(amounts: &[i64]) -> Vec<Utxo> {
+        amounts
+            .iter()
+            .map(|amount| Utxo::new(BitcoinAmount(*amount), FORMAT).unwrap())
+            .collect()
+    }
+
+    fn to_outputs(amount: i64) -> Vec<BitcoinTransactionOutput> {
+        let address = BitcoinAddress::<N>::from_str("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4").unwrap();
+        vec![BitcoinTransactionOutput::new(&address, BitcoinAmount(amount)).unwrap()]
+    }
+
+    #[test]
+    fn branch_and_bound() {
+        // The first two unspent outputs fund the output and the fee of 42 + 2 * 68 vbytes exactly
+        let utxos = to_utxos(&[60_068, 40_110, 150_000, 20_000]);
+        let selection = CoinSelection::new(
+            &utxos,
+            &to_outputs(100_000),
+            1,
+            FORMAT,
+            CoinSelectionStrategy::BranchAndBound,
+        )
+        .unwrap();
+        assert_eq!(vec![0, 1], selection.inputs);
+        assert_eq!(None, selection.change);
+        assert_eq!(BitcoinAmount(178), selection.fee);
+
+        // An excess less than the cost of change is added to the fee
+        let utxos = to_utxos(&[60_100, 40_110, 150_000, 20_000]);
+        let selection = CoinSelection::new(
+            &utxos,
+            &to_outputs(100_000),
+            1,
+            FORMAT,
+            CoinSelectionStrategy::BranchAndBound,
+        )
+        .unwrap();
+        assert_eq!(vec![0, 1], selection.inputs);
+        assert_eq!(None, selection.change);
+        assert_eq!(BitcoinAmount(210), selection.fee);
+    }
+
+    #[test]
+    fn branch_and_bound_fallback() {
+        // No combination is within the cost of change, so the largest unspent output is selected with change
+        let utxos = to_utxos(&[70_000, 50_000, 150_000]);
+        let selection = CoinSelection::new(
+            &utxos,
+            &to_outputs(100_000),
+            1,
+            FORMAT,
+            CoinSelectionStrategy::BranchAndBound,
+        )
+        .unwrap();
+        assert_eq!(
+            CoinSelection::new(
+                &utxos,
+                &to_outputs(100_000),
+                1,
+                FORMAT,
+                CoinSelectionStrategy::LargestFirst
+            )
+            .unwrap(),
+            selection
+        );
+    }
+
+    #[test]
+    fn largest_first() {
+        let utxos = to_utxos(&[60_068, 40_110, 150_000, 20_000]);
+        let selection = CoinSelection::new(
+            &utxos,
+            &to_outputs(100_000),
+            1,
+            FORMAT,
+            CoinSelectionStrategy::LargestFirst,
+        )
+        .unwrap();
+        assert_eq!(vec![2], selection.inputs);
+        assert_eq!(Some(BitcoinAmount(49_859)), selection.change);
+        assert_eq!(BitcoinAmount(141), selection.fee);
+
+        // The fee rate applies to the inputs, outputs, and change
+        let selection = CoinSelection::new(
+            &utxos,
+            &to_outputs(100_000),
+            10,
+            FORMAT,
+            CoinSelectionStrategy::LargestFirst,
+        )
+        .unwrap();
+        assert_eq!(vec![2], selection.inputs);
+        assert_eq!(Some(BitcoinAmount(48_590)), selection.change);
+        assert_eq!(BitcoinAmount(1_410), selection.fee);
+
+        // Several unspent outputs are selected in their given order
+        let selection = CoinSelection::new(
+            &utxos,
+            &to_outputs(200_000),
+            1,
+            FORMAT,
+            CoinSelectionStrategy::LargestFirst,
+        )
+        .unwrap();
+        assert_eq!(vec![0, 2], selection.inputs);
+    }
+
+    #[test]
+    fn dust_change() {
+        // The remainder of 100 satoshi is below the dust threshold of a P2WPKH output, and is added to the fee
+        let utxos = to_utxos(&[100_241]);
+        let selection = CoinSelection::new(
+            &utxos,
+            &to_outputs(100_000),
+            1,
+            FORMAT,
+            CoinSelectionStrategy::LargestFirst,
+        )
+        .unwrap();
+        assert_eq!(None, selection.change);
+        assert_eq!(BitcoinAmount(241), selection.fee);
+    }
+
+    #[test]
+    fn insufficient_funds() {
+        let utxos = to_utxos(&[60_000, 40_000]);
+        let error = CoinSelection::new(
+            &utxos,
+            &to_outputs(100_000),
+            1,
+            FORMAT,
+            CoinSelectionStrategy::LargestFirst,
+        );
+        assert!(error.is_err());
+
+        // An unspent output worth less than the fee to spend it is not selected
+        let utxos = to_utxos(&[100_110, 500]);
+        let selection = CoinSelection::new(
+            &utxos,
+            &to_outputs(100_000),
+            10,
+            FORMAT,
+            CoinSelectionStrategy::LargestFirst,
+        );
+        assert!(selection.is_err());
+    }
+
+    #[test]
+    fn consolidate() {
+        // Every unspent output is spent to one output, less the fee of 42 + 3 * 68 vbytes
+        let utxos = to_utxos(&[60_000, 40_000, 20_000]);
+        let selection = CoinSelection::consolidate(&utxos, 1, FORMAT).unwrap();
+        assert_eq!(vec![0, 1, 2], selection.inputs);
+        assert_eq!(Some(BitcoinAmount(119_754)), selection.change);
+        assert_eq!(BitcoinAmount(246), selection.fee);
+
+        // An unspent output worth less than the fee to spend it is left out
+        let utxos = to_utxos(&[60_000, 500, 40_000]);
+        let selection = CoinSelection::consolidate(&utxos, 10, FORMAT).unwrap();
+        assert_eq!(vec![0, 2], selection.inputs);
+        assert_eq!(Some(BitcoinAmount(98_220)), selection.change);
+        assert_eq!(BitcoinAmount(1_780), selection.fee);
+
+        // The output of the consolidation must not be dust
+        let utxos = to_utxos(&[700]);
+        assert!(CoinSelection::consolidate(&utxos, 1, FORMAT).is_ok());
+        assert!(CoinSelection::consolidate(&utxos, 4, FORMAT).is_err());
+        assert!(CoinSelection::consolidate(&[], 1, FORMAT).is_err());
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            148,
+            Utxo::new(BitcoinAmount(0), &BitcoinFormat::P2PKH).unwrap().input_vsize
+        );
+        assert_eq!(
+            91,
+            Utxo::new(BitcoinAmount(0), &BitcoinFormat::P2SH_P2WPKH)
+                .unwrap()
+                .input_vsize
+        );
+        assert!(Utxo::new(BitcoinAmount(0), &BitcoinFormat::P2WSH).is_err());
+    }
+}
@@ -1,5 +1,5 @@
 use crate::network::BitcoinNetwork;
-use wagyu_model::derivation_path::{ChildIndex, DerivationPath, DerivationPathError};
+use wagyu_model::derivation_path::{BIP32Path, ChildIndex, DerivationPath, DerivationPathError};
 use wagyu_model::no_std::*;
 
 use core::{convert::TryFrom, fmt, marker::PhantomData, str::FromStr};
@@ -88,14 +88,7 @@ impl<N: BitcoinNetwork> FromStr for BitcoinDerivationPath<N> {
     type Err = DerivationPathError;
 
     fn from_str(path: &str) -> Result<Self, Self::Err> {
-        let mut parts = path.split("/");
-
-        if parts.next().unwrap() != "m" {
-            return Err(DerivationPathError::InvalidDerivationPath(path.to_string()));
-        }
-
-        let path: Result<Vec<ChildIndex>, Self::Err> = parts.map(str::parse).collect();
-        Self::from_vec(&path?)
+        Self::from_vec(&BIP32Path::from_str(path)?.to_vec()?)
     }
 }
 
@@ -123,15 +116,8 @@ impl<N: BitcoinNetwork> fmt::Debug for BitcoinDerivationPath<N> {
 
 impl<N: BitcoinNetwork> fmt::Display for BitcoinDerivationPath<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self.to_vec() {
-            Ok(path) => {
-                f.write_str("m")?;
-                for index in path.iter() {
-                    f.write_str("/")?;
-                    fmt::Display::fmt(index, f)?;
-                }
-                Ok(())
-            }
+        match self.to_vec().and_then(|path| BIP32Path::from_vec(&path)) {
+            Ok(path) => fmt::Display::fmt(&path, f),
             Err(_) => Err(fmt::Error),
         }
     }
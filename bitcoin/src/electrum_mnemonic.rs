@@ -0,0 +1,403 @@
+use crate::address::BitcoinAddress;
+use crate::extended_private_key::BitcoinExtendedPrivateKey;
+use crate::extended_public_key::BitcoinExtendedPublicKey;
+use crate::format::BitcoinFormat;
+use crate::mnemonic::BitcoinMnemonic;
+use crate::network::BitcoinNetwork;
+use crate::private_key::BitcoinPrivateKey;
+use crate::public_key::BitcoinPublicKey;
+use crate::wordlist::BitcoinWordlist;
+use wagyu_model::no_std::*;
+use wagyu_model::{ExtendedPrivateKey, Mnemonic, MnemonicError, MnemonicExtended};
+
+use core::{fmt, marker::PhantomData, str::FromStr};
+use hmac::{Hmac, Mac};
+use pbkdf2::pbkdf2;
+use rand::Rng;
+use sha2::Sha512;
+use zeroize::Zeroize;
+
+type HmacSha512 = Hmac<Sha512>;
+
+const PBKDF2_ROUNDS: usize = 2048;
+const PBKDF2_BYTES: usize = 64;
+const WORD_COUNT: usize = 12;
+
+/// Represents the seed type of an Electrum mnemonic, as committed to by its version prefix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ElectrumSeedType {
+    /// Standard seed for P2PKH wallets
+    Standard,
+    /// Segwit seed for native P2WPKH (Bech32) wallets
+    Segwit,
+}
+
+impl ElectrumSeedType {
+    /// Returns the hex-encoded version prefix of the seed type.
+    pub fn to_prefix(&self) -> &'static str {
+        match self {
+            ElectrumSeedType::Standard => "01",
+            ElectrumSeedType::Segwit => "100",
+        }
+    }
+
+    /// Returns the address format of the seed type.
+    pub fn to_format(&self) -> BitcoinFormat {
+        match self {
+            ElectrumSeedType::Standard => BitcoinFormat::P2PKH,
+            ElectrumSeedType::Segwit => BitcoinFormat::Bech32,
+        }
+    }
+
+    /// Returns the derivation path of the account used by Electrum for the seed type.
+    pub fn to_account_path(&self) -> &'static str {
+        match self {
+            ElectrumSeedType::Standard => "m",
+            ElectrumSeedType::Segwit => "m/0'",
+        }
+    }
+
+    /// Returns the seed type of the given normalized phrase.
+    fn from_phrase(phrase: &str) -> Result<Self, MnemonicError> {
+        let mut mac = HmacSha512::new_varkey(b"Seed version")
+            .map_err(|error| MnemonicError::Crate("hmac", format!("{:?}", error)))?;
+        mac.input(phrase.as_bytes());
+        let version = hex::encode(mac.result().code());
+
+        if version.starts_with(ElectrumSeedType::Segwit.to_prefix()) {
+            Ok(ElectrumSeedType::Segwit)
+        } else if version.starts_with(ElectrumSeedType::Standard.to_prefix()) {
+            Ok(ElectrumSeedType::Standard)
+        } else {
+            Err(MnemonicError::InvalidPhrase(phrase.into()))
+        }
+    }
+}
+
+impl fmt::Display for ElectrumSeedType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ElectrumSeedType::Standard => write!(f, "standard"),
+            ElectrumSeedType::Segwit => write!(f, "segwit"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Represents an Electrum mnemonic
+pub struct ElectrumMnemonic<N: BitcoinNetwork, W: BitcoinWordlist> {
+    /// Wordlist indices of the phrase
+    indices: Vec<u16>,
+    /// Seed type committed to by the phrase
+    seed_type: ElectrumSeedType,
+    /// PhantomData
+    _network: PhantomData<N>,
+    /// PhantomData
+    _wordlist: PhantomData<W>,
+}
+
+impl<N: BitcoinNetwork, W: BitcoinWordlist> Mnemonic for ElectrumMnemonic<N, W> {
+    type Address = BitcoinAddress<N>;
+    type Format = BitcoinFormat;
+    type PrivateKey = BitcoinPrivateKey<N>;
+    type PublicKey = BitcoinPublicKey<N>;
+
+    /// Returns a new segwit mnemonic.
+    fn new<R: Rng>(rng: &mut R) -> Result<Self, MnemonicError> {
+        Self::new_with_seed_type(rng, ElectrumSeedType::Segwit)
+    }
+
+    /// Returns the mnemonic for the given phrase.
+    fn from_phrase(phrase: &str) -> Result<Self, MnemonicError> {
+        let mut normalized = normalize(phrase);
+        if normalized.is_empty() {
+            return Err(MnemonicError::MissingWord);
+        }
+
+        let mut indices = Vec::with_capacity(WORD_COUNT);
+        for word in normalized.split(' ') {
+            indices.push(W::get_index(word)? as u16);
+        }
+
+        let seed_type = ElectrumSeedType::from_phrase(&normalized);
+        normalized.zeroize();
+
+        Ok(Self {
+            indices,
+            seed_type: seed_type.map_err(|_| MnemonicError::InvalidPhrase(phrase.into()))?,
+            _network: PhantomData,
+            _wordlist: PhantomData,
+        })
+    }
+
+    /// Returns the phrase of the corresponding mnemonic.
+    fn to_phrase(&self) -> Result<String, MnemonicError> {
+        let wordlist = W::get_all();
+        let mut words = Vec::with_capacity(self.indices.len());
+        for index in &self.indices {
+            match wordlist.get(*index as usize) {
+                Some(word) => words.push(*word),
+                None => return Err(MnemonicError::InvalidIndex(*index as usize)),
+            }
+        }
+        Ok(words.join(" "))
+    }
+
+    /// Returns the private key of the corresponding mnemonic.
+    fn to_private_key(&self, password: Option<&str>) -> Result<Self::PrivateKey, MnemonicError> {
+        Ok(self.to_extended_private_key(password)?.to_private_key())
+    }
+
+    /// Returns the public key of the corresponding mnemonic.
+    fn to_public_key(&self, password: Option<&str>) -> Result<Self::PublicKey, MnemonicError> {
+        Ok(self.to_extended_private_key(password)?.to_public_key())
+    }
+
+    /// Returns the address of the corresponding mnemonic.
+    fn to_address(&self, password: Option<&str>, format: &Self::Format) -> Result<Self::Address, MnemonicError> {
+        Ok(self.to_extended_private_key(password)?.to_address(format)?)
+    }
+}
+
+impl<N: BitcoinNetwork, W: BitcoinWordlist> MnemonicExtended for ElectrumMnemonic<N, W> {
+    type ExtendedPrivateKey = BitcoinExtendedPrivateKey<N>;
+    type ExtendedPublicKey = BitcoinExtendedPublicKey<N>;
+
+    /// Returns the master extended private key of the corresponding mnemonic,
+    /// in the address format of its seed type.
+    fn to_extended_private_key(&self, password: Option<&str>) -> Result<Self::ExtendedPrivateKey, MnemonicError> {
+        let mut seed = self.to_seed(password)?;
+        let extended_private_key = Self::ExtendedPrivateKey::new_master(seed.as_slice(), &self.seed_type.to_format());
+        seed.zeroize();
+        Ok(extended_private_key?)
+    }
+
+    /// Returns the master extended public key of the corresponding mnemonic.
+    fn to_extended_public_key(&self, password: Option<&str>) -> Result<Self::ExtendedPublicKey, MnemonicError> {
+        Ok(self.to_extended_private_key(password)?.to_extended_public_key())
+    }
+}
+
+impl<N: BitcoinNetwork, W: BitcoinWordlist> ElectrumMnemonic<N, W> {
+    /// Returns a new mnemonic of the given seed type.
+    /// Phrases which are also valid BIP39 mnemonics are skipped, as Electrum does.
+    pub fn new_with_seed_type<R: Rng>(rng: &mut R, seed_type: ElectrumSeedType) -> Result<Self, MnemonicError> {
+        loop {
+            let mnemonic = Self {
+                indices: (0..WORD_COUNT).map(|_| rng.gen_range(0, 2048)).collect(),
+                seed_type,
+                _network: PhantomData,
+                _wordlist: PhantomData,
+            };
+
+            let mut phrase = mnemonic.to_phrase()?;
+            let is_seed_type = ElectrumSeedType::from_phrase(&phrase).ok() == Some(seed_type);
+            let is_bip39 = BitcoinMnemonic::<N, W>::verify_phrase(&phrase);
+            phrase.zeroize();
+
+            if is_seed_type && !is_bip39 {
+                return Ok(mnemonic);
+            }
+        }
+    }
+
+    /// Returns the seed type of the corresponding mnemonic.
+    pub fn seed_type(&self) -> ElectrumSeedType {
+        self.seed_type
+    }
+
+    /// Returns `true` if the given phrase is a valid Electrum mnemonic.
+    pub fn verify_phrase(phrase: &str) -> bool {
+        Self::from_phrase(phrase).is_ok()
+    }
+
+    /// Returns a seed using the given password and mnemonic.
+    fn to_seed(&self, password: Option<&str>) -> Result<Vec<u8>, MnemonicError> {
+        let mut seed = vec![0u8; PBKDF2_BYTES];
+        let mut salt = format!("electrum{}", normalize(password.unwrap_or("")));
+        let mut phrase = self.to_phrase()?;
+        pbkdf2::<Hmac<Sha512>>(phrase.as_bytes(), salt.as_bytes(), PBKDF2_ROUNDS, &mut seed);
+        salt.zeroize();
+        phrase.zeroize();
+        Ok(seed)
+    }
+}
+
+impl<N: BitcoinNetwork, W: BitcoinWordlist> FromStr for ElectrumMnemonic<N, W> {
+    type Err = MnemonicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_phrase(s)
+    }
+}
+
+impl<N: BitcoinNetwork, W: BitcoinWordlist> fmt::Display for ElectrumMnemonic<N, W> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self.to_phrase() {
+                Ok(phrase) => phrase,
+                _ => return Err(fmt::Error),
+            }
+        )
+    }
+}
+
+impl<N: BitcoinNetwork, W: BitcoinWordlist> Zeroize for ElectrumMnemonic<N, W> {
+    /// Overwrites the mnemonic indices with zeros.
+    fn zeroize(&mut self) {
+        self.indices.zeroize();
+    }
+}
+
+impl<N: BitcoinNetwork, W: BitcoinWordlist> Drop for ElectrumMnemonic<N, W> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// Returns the given text in lowercase with its whitespace collapsed, as normalized by Electrum.
+fn normalize(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::derivation_path::BitcoinDerivationPath;
+    use crate::network::*;
+    use crate::wordlist::*;
+    use wagyu_model::{BIP32Path, ChildIndex};
+
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    type N = Mainnet;
+    type W = English;
+
+    /// Test vectors from https://github.com/spesmilo/electrum/blob/master/electrum/tests
+    // (phrase, seed type, password, seed)
+    const SEEDS: [(&str, ElectrumSeedType, &str, &str); 2] = [
+        (
+            "wild father tree among universe such mobile favorite target dynamic credit identify",
+            ElectrumSeedType::Segwit,
+            "",
+            "aac2a6302e48577ab4b46f23dbae0774e2e62c796f797d0a1b5faeb528301e3064342dafb79069e7c4c6b8c38ae11d7a973bec0d4f70626f8cc5184a8d0b0756",
+        ),
+        (
+            "wild father tree among universe such mobile favorite target dynamic credit identify",
+            ElectrumSeedType::Segwit,
+            "Did you ever hear the tragedy of Darth Plagueis the Wise?",
+            "4aa29f2aeb0127efb55138ab9e7be83b36750358751906f86c662b21a1ea1370f949e6d1a12fa56d3d93cadda93038c76ac8118597364e46f5156fde6183c82f",
+        ),
+    ];
+
+    // (phrase, seed type, account extended public key, first receiving address)
+    const WALLETS: [(&str, ElectrumSeedType, &str, &str); 2] = [
+        (
+            "cycle rocket west magnet parrot shuffle foot correct salt library feed song",
+            ElectrumSeedType::Standard,
+            "xpub661MyMwAqRbcFWohJWt7PHsFEJfZAvw9ZxwQoDa4SoMgsDDM1T7WK3u9E4edkC4ugRnZ8E4xDZRpk8Rnts3Nbt97dPwT52CwBdDWroaZf8U",
+            "1NNkttn1YvVGdqBW4PR6zvc3Zx3H5owKRf",
+        ),
+        (
+            "bitter grass shiver impose acquire brush forget axis eager alone wine silver",
+            ElectrumSeedType::Segwit,
+            "zpub6nsHdRuY92FsMKdbn9BfjBCG6X8pyhCibNP6uDvpnw2cyrVhecvHRMa3Ne8kdJZxjxgwnpbHLkcR4bfnhHy6auHPJyDTQ3kianeuVLdkCYQ",
+            "bc1q3g5tmkmlvxryhh843v4dz026avatc0zzr6h3af",
+        ),
+    ];
+
+    #[test]
+    fn new() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        for seed_type in &[ElectrumSeedType::Standard, ElectrumSeedType::Segwit] {
+            let mnemonic = ElectrumMnemonic::<N, W>::new_with_seed_type(rng, *seed_type).unwrap();
+            let phrase = mnemonic.to_phrase().unwrap();
+            assert_eq!(WORD_COUNT, phrase.split(' ').count());
+            assert_eq!(
+                *seed_type,
+                ElectrumMnemonic::<N, W>::from_phrase(&phrase).unwrap().seed_type()
+            );
+            assert!(!BitcoinMnemonic::<N, W>::verify_phrase(&phrase));
+        }
+    }
+
+    #[test]
+    fn from_phrase() {
+        WALLETS.iter().for_each(|(phrase, seed_type, _, _)| {
+            let mnemonic = ElectrumMnemonic::<N, W>::from_phrase(phrase).unwrap();
+            assert_eq!(*seed_type, mnemonic.seed_type());
+            assert_eq!(*phrase, mnemonic.to_phrase().unwrap());
+        });
+    }
+
+    #[test]
+    fn from_phrase_normalized() {
+        let (phrase, _, _, _) = WALLETS[0];
+        let mnemonic = ElectrumMnemonic::<N, W>::from_phrase(&format!("  {}  ", phrase.to_uppercase())).unwrap();
+        assert_eq!(phrase, mnemonic.to_phrase().unwrap());
+    }
+
+    #[test]
+    fn to_seed() {
+        SEEDS.iter().for_each(|(phrase, seed_type, password, expected_seed)| {
+            let mnemonic = ElectrumMnemonic::<N, W>::from_phrase(phrase).unwrap();
+            assert_eq!(*seed_type, mnemonic.seed_type());
+            assert_eq!(*expected_seed, hex::encode(mnemonic.to_seed(Some(password)).unwrap()));
+        });
+    }
+
+    #[test]
+    fn to_extended_private_key() {
+        WALLETS
+            .iter()
+            .for_each(|(phrase, seed_type, expected_extended_public_key, expected_address)| {
+                let mnemonic = ElectrumMnemonic::<N, W>::from_phrase(phrase).unwrap();
+                let master_extended_private_key = mnemonic.to_extended_private_key(None).unwrap();
+
+                let account_path = BitcoinDerivationPath::from_str(seed_type.to_account_path()).unwrap();
+                let account = master_extended_private_key.derive(&account_path).unwrap();
+                assert_eq!(
+                    *expected_extended_public_key,
+                    account.to_extended_public_key().to_string()
+                );
+
+                let address_path = BIP32Path::from_str(seed_type.to_account_path())
+                    .unwrap()
+                    .extend(vec![ChildIndex::Normal(0), ChildIndex::Normal(0)])
+                    .unwrap();
+                let address = master_extended_private_key
+                    .derive_path(&address_path)
+                    .unwrap()
+                    .to_address(&seed_type.to_format())
+                    .unwrap();
+                assert_eq!(*expected_address, address.to_string());
+            });
+    }
+
+    #[test]
+    fn zeroize() {
+        let (phrase, _, _, _) = WALLETS[0];
+        let mut mnemonic = ElectrumMnemonic::<N, W>::from_phrase(phrase).unwrap();
+        mnemonic.zeroize();
+        assert!(mnemonic.indices.is_empty());
+    }
+
+    #[test]
+    fn from_phrase_invalid_version() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        assert!(ElectrumMnemonic::<N, W>::from_phrase(phrase).is_err());
+        assert!(!ElectrumMnemonic::<N, W>::verify_phrase(phrase));
+    }
+
+    #[test]
+    fn from_phrase_invalid_word() {
+        let phrase = "cycle rocket west magnet parrot shuffle foot correct salt library feed wagyu";
+        assert!(ElectrumMnemonic::<N, W>::from_phrase(phrase).is_err());
+    }
+}
@@ -6,16 +6,16 @@ use crate::network::BitcoinNetwork;
 use crate::private_key::BitcoinPrivateKey;
 use crate::public_key::BitcoinPublicKey;
 use wagyu_model::{
-    crypto::{checksum, hash160},
-    AddressError, ChildIndex, DerivationPath, ExtendedPrivateKey, ExtendedPrivateKeyError, ExtendedPublicKey,
-    PrivateKey,
+    crypto::hash160, encoding::base58check, AddressError, ChildIndex, DerivationPath, ExtendedPrivateKey,
+    ExtendedPrivateKeyError, ExtendedPublicKey, PrivateKey, WalletGenerator,
 };
 
-use base58::{FromBase58, ToBase58};
+use base58::FromBase58;
 use core::{convert::TryFrom, fmt, fmt::Display, str::FromStr};
 use hmac::{Hmac, Mac};
 use secp256k1::{PublicKey, SecretKey};
 use sha2::Sha512;
+use zeroize::Zeroize;
 
 type HmacSha512 = Hmac<Sha512>;
 
@@ -36,6 +36,9 @@ pub struct BitcoinExtendedPrivateKey<N: BitcoinNetwork> {
     private_key: BitcoinPrivateKey<N>,
 }
 
+/// Represents a lazy iterator over the Bitcoin wallets of a derivation path template, e.g. `m/44'/0'/0'/0/*`
+pub type BitcoinWalletIter<N> = WalletGenerator<BitcoinExtendedPrivateKey<N>>;
+
 impl<N: BitcoinNetwork> ExtendedPrivateKey for BitcoinExtendedPrivateKey<N> {
     type Address = BitcoinAddress<N>;
     type DerivationPath = BitcoinDerivationPath<N>;
@@ -71,54 +74,13 @@ impl<N: BitcoinNetwork> ExtendedPrivateKey for BitcoinExtendedPrivateKey<N> {
 
     /// Returns the extended private key of the given derivation path.
     fn derive(&self, path: &Self::DerivationPath) -> Result<Self, ExtendedPrivateKeyError> {
-        if self.depth == 255 {
-            return Err(ExtendedPrivateKeyError::MaximumChildDepthReached(self.depth));
-        }
-
         let mut extended_private_key = self.clone();
-
         for index in path.to_vec()?.into_iter() {
-            let public_key = &PublicKey::from_secret_key(&extended_private_key.private_key.to_secp256k1_secret_key())
-                .serialize_compressed()[..];
-
-            let mut mac = HmacSha512::new_varkey(&extended_private_key.chain_code)?;
-            match index {
-                // HMAC-SHA512(Key = cpar, Data = serP(point(kpar)) || ser32(i)).
-                ChildIndex::Normal(_) => mac.input(public_key),
-                // HMAC-SHA512(Key = cpar, Data = 0x00 || ser256(kpar) || ser32(i))
-                // (Note: The 0x00 pads the private key to make it 33 bytes long.)
-                ChildIndex::Hardened(_) => {
-                    mac.input(&[0u8]);
-                    mac.input(&extended_private_key.private_key.to_secp256k1_secret_key().serialize());
-                }
-            }
-            // Append the child index in big-endian format
-            mac.input(&u32::from(index).to_be_bytes());
-            let hmac = mac.result().code();
-
-            let mut secret_key = SecretKey::parse_slice(&hmac[0..32])?;
-            secret_key.tweak_add_assign(&extended_private_key.private_key.to_secp256k1_secret_key())?;
-            let private_key = Self::PrivateKey::from_secp256k1_secret_key(&secret_key, true);
-
-            let mut chain_code = [0u8; 32];
-            chain_code[0..32].copy_from_slice(&hmac[32..]);
-
-            let mut parent_fingerprint = [0u8; 4];
-            parent_fingerprint.copy_from_slice(&hash160(public_key)[0..4]);
-
-            let format = match path {
-                BitcoinDerivationPath::BIP49(_) => BitcoinFormat::P2SH_P2WPKH,
-                _ => extended_private_key.format.clone(),
-            };
-
-            extended_private_key = Self {
-                format,
-                depth: extended_private_key.depth + 1,
-                parent_fingerprint,
-                child_index: index,
-                chain_code,
-                private_key,
-            }
+            extended_private_key = extended_private_key.derive_child(index)?;
+        }
+
+        if let BitcoinDerivationPath::BIP49(_) = path {
+            extended_private_key.format = BitcoinFormat::P2SH_P2WPKH;
         }
 
         Ok(extended_private_key)
@@ -146,10 +108,63 @@ impl<N: BitcoinNetwork> ExtendedPrivateKey for BitcoinExtendedPrivateKey<N> {
 }
 
 impl<N: BitcoinNetwork> BitcoinExtendedPrivateKey<N> {
+    /// Returns the extended private key of the given child index, in the format of its parent.
+    /// Deriving the children of a cached parent, e.g. the chain of an account, skips re-deriving its path.
+    pub fn derive_child(&self, index: ChildIndex) -> Result<Self, ExtendedPrivateKeyError> {
+        if self.depth == 255 {
+            return Err(ExtendedPrivateKeyError::MaximumChildDepthReached(self.depth));
+        }
+
+        let public_key =
+            &PublicKey::from_secret_key(&self.private_key.to_secp256k1_secret_key()).serialize_compressed()[..];
+
+        let mut mac = HmacSha512::new_varkey(&self.chain_code)?;
+        match index {
+            // HMAC-SHA512(Key = cpar, Data = serP(point(kpar)) || ser32(i)).
+            ChildIndex::Normal(_) => mac.input(public_key),
+            // HMAC-SHA512(Key = cpar, Data = 0x00 || ser256(kpar) || ser32(i))
+            // (Note: The 0x00 pads the private key to make it 33 bytes long.)
+            ChildIndex::Hardened(_) => {
+                mac.input(&[0u8]);
+                mac.input(&self.private_key.to_secp256k1_secret_key().serialize());
+            }
+        }
+        // Append the child index in big-endian format
+        mac.input(&u32::from(index).to_be_bytes());
+        let hmac = mac.result().code();
+
+        let mut secret_key = SecretKey::parse_slice(&hmac[0..32])?;
+        secret_key.tweak_add_assign(&self.private_key.to_secp256k1_secret_key())?;
+        let private_key = BitcoinPrivateKey::from_secp256k1_secret_key(&secret_key, true);
+
+        let mut chain_code = [0u8; 32];
+        chain_code[0..32].copy_from_slice(&hmac[32..]);
+
+        let mut parent_fingerprint = [0u8; 4];
+        parent_fingerprint.copy_from_slice(&hash160(public_key)[0..4]);
+
+        Ok(Self {
+            format: self.format.clone(),
+            depth: self.depth + 1,
+            parent_fingerprint,
+            child_index: index,
+            chain_code,
+            private_key,
+        })
+    }
+
     /// Returns the format of the Bitcoin extended private key.
     pub fn format(&self) -> BitcoinFormat {
         self.format.clone()
     }
+    /// Returns the extended private key with the given format, which is encoded with its SLIP-132 version bytes,
+    /// e.g. yprv or zprv, or errors if the format has no version bytes.
+    pub fn with_format(&self, format: &BitcoinFormat) -> Result<Self, ExtendedPrivateKeyError> {
+        N::to_extended_private_key_version_bytes(format)?;
+        let mut extended_private_key = self.clone();
+        extended_private_key.format = format.clone();
+        Ok(extended_private_key)
+    }
 }
 
 impl<N: BitcoinNetwork> FromStr for BitcoinExtendedPrivateKey<N> {
@@ -177,13 +192,7 @@ impl<N: BitcoinNetwork> FromStr for BitcoinExtendedPrivateKey<N> {
 
         let private_key = BitcoinPrivateKey::from_secp256k1_secret_key(&SecretKey::parse_slice(&data[46..78])?, true);
 
-        let expected = &data[78..82];
-        let checksum = &checksum(&data[0..78])[0..4];
-        if *expected != *checksum {
-            let expected = expected.to_base58();
-            let found = checksum.to_base58();
-            return Err(ExtendedPrivateKeyError::InvalidChecksum(expected, found));
-        }
+        base58check::verify(&data)?;
 
         Ok(Self {
             format,
@@ -200,7 +209,7 @@ impl<N: BitcoinNetwork> Display for BitcoinExtendedPrivateKey<N> {
     /// BIP32 serialization format
     /// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let mut result = [0u8; 82];
+        let mut result = [0u8; 78];
         result[0..4].copy_from_slice(match &N::to_extended_private_key_version_bytes(&self.format) {
             Ok(version) => version,
             Err(_) => return Err(fmt::Error),
@@ -212,10 +221,21 @@ impl<N: BitcoinNetwork> Display for BitcoinExtendedPrivateKey<N> {
         result[45] = 0;
         result[46..78].copy_from_slice(&self.private_key.to_secp256k1_secret_key().serialize());
 
-        let checksum = &checksum(&result[0..78])[0..4];
-        result[78..82].copy_from_slice(&checksum);
+        fmt.write_str(&base58check::encode(&result))
+    }
+}
 
-        fmt.write_str(&result.to_base58())
+impl<N: BitcoinNetwork> Zeroize for BitcoinExtendedPrivateKey<N> {
+    /// Overwrites the chain code and the private key.
+    fn zeroize(&mut self) {
+        self.chain_code.zeroize();
+        self.private_key.zeroize();
+    }
+}
+
+impl<N: BitcoinNetwork> Drop for BitcoinExtendedPrivateKey<N> {
+    fn drop(&mut self) {
+        self.zeroize();
     }
 }
 
@@ -223,6 +243,7 @@ impl<N: BitcoinNetwork> Display for BitcoinExtendedPrivateKey<N> {
 mod tests {
     use super::*;
     use crate::network::*;
+    use wagyu_model::BIP32Path;
 
     use core::convert::TryInto;
     use hex;
@@ -634,6 +655,66 @@ mod tests {
             });
         }
 
+        #[test]
+        fn derive_path() {
+            let (_, master_seed, _, _, _, _, master_extended_private_key, _) = KEYPAIRS[0];
+            let master_extended_private_key =
+                BitcoinExtendedPrivateKey::<N>::from_str(master_extended_private_key).unwrap();
+            KEYPAIRS
+                .iter()
+                .filter(|(_, seed, _, _, _, _, _, _)| *seed == master_seed)
+                .for_each(|(path, _, _, _, _, _, expected_extended_private_key, _)| {
+                    let path = BIP32Path::from_str(&path.replace("'", "h")).unwrap();
+                    let extended_private_key = master_extended_private_key.derive_path(&path).unwrap();
+                    assert_eq!(*expected_extended_private_key, extended_private_key.to_string());
+                });
+        }
+
+        #[test]
+        fn wallet_iter() {
+            let master_extended_private_key = BitcoinExtendedPrivateKey::<N>::from_str(KEYPAIRS[0].6).unwrap();
+            let format = BitcoinFormat::P2PKH;
+
+            // m/0'/1/2'/* yields m/0'/1/2'/0, m/0'/1/2'/1, m/0'/1/2'/2, ...
+            let mut wallets = BitcoinWalletIter::new(&master_extended_private_key, "m/0'/1/2'/*", &format).unwrap();
+            let (path, private_key, address) = wallets.nth(2).unwrap().unwrap();
+            let expected = BitcoinExtendedPrivateKey::<N>::from_str(KEYPAIRS[4].6).unwrap();
+            assert_eq!(KEYPAIRS[4].0, path.to_string());
+            assert_eq!(expected.to_private_key(), private_key);
+            assert_eq!(expected.to_address(&format).unwrap(), address);
+
+            // m/*' yields the hardened children of the master key
+            let mut wallets = BitcoinWalletIter::new(&master_extended_private_key, "m/*'", &format).unwrap();
+            let (path, private_key, _) = wallets.next().unwrap().unwrap();
+            let expected = BitcoinExtendedPrivateKey::<N>::from_str(KEYPAIRS[1].6).unwrap();
+            assert_eq!(KEYPAIRS[1].0, path.to_string());
+            assert_eq!(expected.to_private_key(), private_key);
+
+            // The iterator ends after the last child index
+            assert!(wallets.nth((1 << 31) - 2).unwrap().is_ok());
+            assert!(wallets.next().is_none());
+        }
+
+        #[test]
+        fn derive_child() {
+            let (_, master_seed, _, _, _, _, master_extended_private_key, _) = KEYPAIRS[0];
+            let master_extended_private_key =
+                BitcoinExtendedPrivateKey::<N>::from_str(master_extended_private_key).unwrap();
+            KEYPAIRS
+                .iter()
+                .filter(|(_, seed, _, _, _, _, _, _)| *seed == master_seed)
+                .for_each(|(path, _, _, _, _, _, expected_extended_private_key, _)| {
+                    let path = BIP32Path::from_str(&path.replace("'", "h")).unwrap();
+                    let extended_private_key = path
+                        .iter()
+                        .try_fold(master_extended_private_key.clone(), |parent, index| {
+                            parent.derive_child(*index)
+                        })
+                        .unwrap();
+                    assert_eq!(*expected_extended_private_key, extended_private_key.to_string());
+                });
+        }
+
         #[test]
         fn to_extended_public_key() {
             KEYPAIRS
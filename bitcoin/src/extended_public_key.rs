@@ -5,11 +5,11 @@ use crate::format::BitcoinFormat;
 use crate::network::BitcoinNetwork;
 use crate::public_key::BitcoinPublicKey;
 use wagyu_model::{
-    crypto::{checksum, hash160},
-    AddressError, ChildIndex, DerivationPath, ExtendedPrivateKey, ExtendedPublicKey, ExtendedPublicKeyError, PublicKey,
+    crypto::hash160, encoding::base58check, AddressError, ChildIndex, DerivationPath, ExtendedPrivateKey,
+    ExtendedPublicKey, ExtendedPublicKeyError, NonHardenedDerivationPath, PublicKey,
 };
 
-use base58::{FromBase58, ToBase58};
+use base58::FromBase58;
 use core::{convert::TryFrom, fmt, str::FromStr};
 use hmac::{Hmac, Mac};
 use secp256k1::{PublicKey as Secp256k1_PublicKey, SecretKey};
@@ -55,24 +55,28 @@ impl<N: BitcoinNetwork> ExtendedPublicKey for BitcoinExtendedPublicKey<N> {
 
     /// Returns the extended public key for the given derivation path.
     fn derive(&self, path: &Self::DerivationPath) -> Result<Self, ExtendedPublicKeyError> {
-        if self.depth == 255 {
-            return Err(ExtendedPublicKeyError::MaximumChildDepthReached(self.depth));
-        }
+        self.derive_public(&path.to_non_hardened()?)
+    }
 
+    /// Returns the extended public key for the given non-hardened derivation path.
+    fn derive_public(&self, path: &NonHardenedDerivationPath) -> Result<Self, ExtendedPublicKeyError> {
         let mut extended_public_key = self.clone();
 
-        for index in path.to_vec()?.into_iter() {
-            let public_key_serialized = &self.public_key.to_secp256k1_public_key().serialize_compressed()[..];
-
-            let mut mac = HmacSha512::new_varkey(&self.chain_code)?;
-            match index {
-                // HMAC-SHA512(Key = cpar, Data = serP(Kpar) || ser32(i))
-                ChildIndex::Normal(_) => mac.input(public_key_serialized),
-                // Return failure
-                ChildIndex::Hardened(_) => {
-                    return Err(ExtendedPublicKeyError::InvalidChildNumber(1 << 31, u32::from(index)))
-                }
+        for index in path.to_vec().into_iter() {
+            if extended_public_key.depth == 255 {
+                return Err(ExtendedPublicKeyError::MaximumChildDepthReached(
+                    extended_public_key.depth,
+                ));
             }
+
+            let public_key_serialized = &extended_public_key
+                .public_key
+                .to_secp256k1_public_key()
+                .serialize_compressed()[..];
+
+            // HMAC-SHA512(Key = cpar, Data = serP(Kpar) || ser32(i))
+            let mut mac = HmacSha512::new_varkey(&extended_public_key.chain_code)?;
+            mac.input(public_key_serialized);
             // Append the child index in big-endian format
             mac.input(&u32::from(index).to_be_bytes());
             let hmac = mac.result().code();
@@ -80,7 +84,7 @@ impl<N: BitcoinNetwork> ExtendedPublicKey for BitcoinExtendedPublicKey<N> {
             let mut chain_code = [0u8; 32];
             chain_code[0..32].copy_from_slice(&hmac[32..]);
 
-            let mut public_key = self.public_key.to_secp256k1_public_key();
+            let mut public_key = extended_public_key.public_key.to_secp256k1_public_key();
             public_key.tweak_add_assign(&SecretKey::parse_slice(&hmac[..32])?)?;
             let public_key = Self::PublicKey::from_secp256k1_public_key(public_key, true);
 
@@ -116,6 +120,15 @@ impl<N: BitcoinNetwork> BitcoinExtendedPublicKey<N> {
     pub fn format(&self) -> BitcoinFormat {
         self.format.clone()
     }
+    /// Returns the extended public key with the given format, which is encoded with its SLIP-132 version bytes,
+    /// e.g. ypub or zpub, or errors if the format has no version bytes.
+    pub fn with_format(&self, format: &BitcoinFormat) -> Result<Self, ExtendedPublicKeyError> {
+        N::to_extended_public_key_version_bytes(format)?;
+        Ok(Self {
+            format: format.clone(),
+            ..self.clone()
+        })
+    }
 }
 
 impl<N: BitcoinNetwork> FromStr for BitcoinExtendedPublicKey<N> {
@@ -147,13 +160,7 @@ impl<N: BitcoinNetwork> FromStr for BitcoinExtendedPublicKey<N> {
         let secp256k1_public_key = Secp256k1_PublicKey::parse_slice(&data[45..78], None)?;
         let public_key = BitcoinPublicKey::from_secp256k1_public_key(secp256k1_public_key, true);
 
-        let expected = &data[78..82];
-        let checksum = &checksum(&data[0..78])[0..4];
-        if *expected != *checksum {
-            let expected = expected.to_base58();
-            let found = checksum.to_base58();
-            return Err(ExtendedPublicKeyError::InvalidChecksum(expected, found));
-        }
+        base58check::verify(&data)?;
 
         Ok(Self {
             format,
@@ -170,7 +177,7 @@ impl<N: BitcoinNetwork> fmt::Display for BitcoinExtendedPublicKey<N> {
     /// BIP32 serialization format
     /// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let mut result = [0u8; 82];
+        let mut result = [0u8; 78];
         result[0..4].copy_from_slice(match &N::to_extended_public_key_version_bytes(&self.format) {
             Ok(version) => version,
             Err(_) => return Err(fmt::Error),
@@ -181,10 +188,7 @@ impl<N: BitcoinNetwork> fmt::Display for BitcoinExtendedPublicKey<N> {
         result[13..45].copy_from_slice(&self.chain_code[..]);
         result[45..78].copy_from_slice(&self.public_key.to_secp256k1_public_key().serialize_compressed()[..]);
 
-        let sum = &checksum(&result[0..78])[0..4];
-        result[78..82].copy_from_slice(sum);
-
-        fmt.write_str(&result.to_base58())
+        fmt.write_str(&base58check::encode(&result))
     }
 }
 
@@ -252,6 +256,17 @@ mod tests {
         );
     }
 
+    fn test_derive_public<N: BitcoinNetwork>(
+        expected_extended_public_key: &str,
+        extended_public_key: &str,
+        path: &str,
+    ) {
+        let path = NonHardenedDerivationPath::from_str(path).unwrap();
+        let extended_public_key = BitcoinExtendedPublicKey::<N>::from_str(extended_public_key).unwrap();
+        let extended_public_key = extended_public_key.derive_public(&path).unwrap();
+        assert_eq!(expected_extended_public_key, extended_public_key.to_string());
+    }
+
     fn test_from_str<N: BitcoinNetwork>(
         expected_public_key: &str,
         expected_child_index: u32,
@@ -441,6 +456,12 @@ mod tests {
             });
         }
 
+        #[test]
+        fn derive_public() {
+            // m/0'/1/2' -> m/0'/1/2'/2/1000000000
+            test_derive_public::<N>(KEYPAIRS[5].7, KEYPAIRS[3].7, "m/2/1000000000");
+        }
+
         #[test]
         fn from_str() {
             KEYPAIRS.iter().for_each(
@@ -464,6 +485,66 @@ mod tests {
         }
     }
 
+    mod slip132 {
+        use super::*;
+
+        // (extended_public_key, format, address of m/0/0) for the account of "abandon ... about"
+        const KEYPAIRS: [(&str, BitcoinFormat, &str); 2] = [
+            (
+                "ypub6Ww3ibxVfGzLrAH1PNcjyAWenMTbbAosGNB6VvmSEgytSER9azLDWCxoJwW7Ke7icmizBMXrzBx9979FfaHxHcrArf3zbeJJJUZPf663zsP",
+                BitcoinFormat::P2SH_P2WPKH,
+                "37VucYSaXLCAsxYyAPfbSi9eh4iEcbShgf",
+            ),
+            (
+                "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs",
+                BitcoinFormat::Bech32,
+                "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu",
+            ),
+        ];
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(extended_public_key, format, address)| {
+                let extended_public_key = BitcoinExtendedPublicKey::<Mainnet>::from_str(extended_public_key).unwrap();
+                let path = NonHardenedDerivationPath::from_str("m/0/0").unwrap();
+                let child = extended_public_key.derive_public(&path).unwrap();
+                assert_eq!(*format, extended_public_key.format());
+                assert_eq!(*address, child.to_address(&child.format()).unwrap().to_string());
+            });
+        }
+
+        #[test]
+        fn with_format() {
+            KEYPAIRS.iter().for_each(|(expected_extended_public_key, format, _)| {
+                let extended_public_key =
+                    BitcoinExtendedPublicKey::<Mainnet>::from_str(expected_extended_public_key).unwrap();
+                let xpub = extended_public_key.with_format(&BitcoinFormat::P2PKH).unwrap();
+                assert!(xpub.to_string().starts_with("xpub"));
+                assert_eq!(extended_public_key.to_public_key(), xpub.to_public_key());
+                assert_eq!(
+                    *expected_extended_public_key,
+                    xpub.with_format(format).unwrap().to_string()
+                );
+            });
+
+            let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+            let tprv = BitcoinExtendedPrivateKey::<Testnet>::new_master(&seed, &BitcoinFormat::P2PKH).unwrap();
+            let tpub = tprv.to_extended_public_key();
+            assert!(tpub.to_string().starts_with("tpub"));
+            assert!(tpub
+                .with_format(&BitcoinFormat::P2SH_P2WPKH)
+                .unwrap()
+                .to_string()
+                .starts_with("upub"));
+            assert!(tpub
+                .with_format(&BitcoinFormat::Bech32)
+                .unwrap()
+                .to_string()
+                .starts_with("vpub"));
+            assert!(tpub.with_format(&BitcoinFormat::P2WSH).is_err());
+        }
+    }
+
     mod test_invalid {
         use super::*;
 
@@ -506,5 +587,13 @@ mod tests {
             string.push('a');
             let _result = BitcoinExtendedPublicKey::<N>::from_str(&string).unwrap();
         }
+
+        #[test]
+        #[should_panic(expected = "ExpectedNormalPath")]
+        fn derive_hardened() {
+            let extended_public_key = BitcoinExtendedPublicKey::<N>::from_str(VALID_EXTENDED_PUBLIC_KEY).unwrap();
+            let path = BitcoinDerivationPath::from_str("m/0/1'").unwrap();
+            let _result = extended_public_key.derive(&path).unwrap();
+        }
     }
 }
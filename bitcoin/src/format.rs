@@ -1,6 +1,6 @@
 use crate::network::BitcoinNetwork;
 use wagyu_model::no_std::*;
-use wagyu_model::{AddressError, ExtendedPrivateKeyError, ExtendedPublicKeyError, Format};
+use wagyu_model::{AddressError, ChildIndex, ExtendedPrivateKeyError, ExtendedPublicKeyError, Format};
 
 use core::fmt;
 use serde::Serialize;
@@ -40,12 +40,24 @@ impl BitcoinFormat {
         }
     }
 
+    /// Returns the format of the given derivation path purpose, or `None` if the purpose has no SLIP-132 version bytes.
+    /// https://github.com/satoshilabs/slips/blob/master/slip-0132.md
+    pub fn from_purpose(purpose: ChildIndex) -> Option<Self> {
+        match purpose {
+            ChildIndex::Hardened(44) => Some(BitcoinFormat::P2PKH),
+            ChildIndex::Hardened(49) => Some(BitcoinFormat::P2SH_P2WPKH),
+            ChildIndex::Hardened(84) => Some(BitcoinFormat::Bech32),
+            _ => None,
+        }
+    }
+
     /// Returns the network of the given extended private key version bytes.
     /// https://github.com/satoshilabs/slips/blob/master/slip-0132.md
     pub fn from_extended_private_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPrivateKeyError> {
         match prefix[0..4] {
             [0x04, 0x88, 0xAD, 0xE4] | [0x04, 0x35, 0x83, 0x94] => Ok(BitcoinFormat::P2PKH),
             [0x04, 0x9D, 0x78, 0x78] | [0x04, 0x4A, 0x4E, 0x28] => Ok(BitcoinFormat::P2SH_P2WPKH),
+            [0x04, 0xB2, 0x43, 0x0C] | [0x04, 0x5F, 0x18, 0xBC] => Ok(BitcoinFormat::Bech32),
             _ => Err(ExtendedPrivateKeyError::InvalidVersionBytes(prefix.to_vec())),
         }
     }
@@ -56,6 +68,7 @@ impl BitcoinFormat {
         match prefix[0..4] {
             [0x04, 0x88, 0xB2, 0x1E] | [0x04, 0x35, 0x87, 0xCF] => Ok(BitcoinFormat::P2PKH),
             [0x04, 0x9D, 0x7C, 0xB2] | [0x04, 0x4A, 0x52, 0x62] => Ok(BitcoinFormat::P2SH_P2WPKH),
+            [0x04, 0xB2, 0x47, 0x46] | [0x04, 0x5F, 0x1C, 0xF6] => Ok(BitcoinFormat::Bech32),
             _ => Err(ExtendedPublicKeyError::InvalidVersionBytes(prefix.to_vec())),
         }
     }
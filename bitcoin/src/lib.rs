@@ -4,7 +4,11 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(unused_extern_crates, dead_code)]
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "wasm"), forbid(unsafe_code))]
+
+// PBKDF2 over rayon spawns threads, which are unavailable to wasm32-unknown-unknown
+#[cfg(all(target_arch = "wasm32", feature = "parallel"))]
+compile_error!("the `parallel` feature is unsupported on wasm32, build with `--no-default-features --features wasm`");
 
 #[macro_use]
 extern crate failure;
@@ -15,6 +19,9 @@ pub use self::address::*;
 pub mod amount;
 pub use self::amount::*;
 
+pub mod coin_selection;
+pub use self::coin_selection::*;
+
 pub mod derivation_path;
 pub use self::derivation_path::*;
 
@@ -24,24 +31,45 @@ pub use self::extended_private_key::*;
 pub mod extended_public_key;
 pub use self::extended_public_key::*;
 
+pub mod electrum_mnemonic;
+pub use self::electrum_mnemonic::*;
+
 pub mod format;
 pub use self::format::*;
 
+pub mod miniscript;
+pub use self::miniscript::*;
+
 pub mod mnemonic;
 pub use self::mnemonic::*;
 
 pub mod network;
 pub use self::network::*;
 
+pub mod payment_code;
+pub use self::payment_code::*;
+
+pub mod policy;
+pub use self::policy::*;
+
 pub mod private_key;
 pub use self::private_key::*;
 
 pub mod public_key;
 pub use self::public_key::*;
 
+pub mod script;
+pub use self::script::*;
+
 pub mod transaction;
 pub use self::transaction::*;
 
+pub mod uri;
+pub use self::uri::*;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 mod witness_program;
 
 pub mod wordlist;
@@ -0,0 +1,583 @@
+//!
+//! Miniscript
+//!
+//! This module compiles spending policies into miniscript (http://bitcoin.sipa.be/miniscript/),
+//! the witness scripts they encode, and the P2WSH addresses paying to them:
+//!
+//! `or(pk(A),and(pk(B),older(144)))` => `or_d(pk(A),and_v(v:pk(B),older(144)))`
+//!
+//! A policy is made of the following, where keys are compressed public keys in hex:
+//!
+//! `pk(<key>)`, `after(<lock time>)`, `older(<relative lock time>)`,
+//! `sha256(<hash>)`, `hash256(<hash>)`, `ripemd160(<hash>)`, `hash160(<hash>)`,
+//! `and(<policy>,<policy>)`, `or(<policy>,<policy>)`, `thresh(<k>,<policy>,...)`
+//!
+//! Each policy is compiled with a fixed translation rather than searched for its cheapest encoding,
+//! so the probabilities of the branches of an `or`, as in `or(9@pk(A),1@pk(B))`, are accepted and ignored.
+//!
+
+use crate::address::BitcoinAddress;
+use crate::network::BitcoinNetwork;
+use crate::script::Script;
+use crate::transaction::Opcode;
+use wagyu_model::no_std::*;
+use wagyu_model::AddressError;
+
+use core::{fmt, str::FromStr};
+
+/// The maximum size of a witness script relayed by Bitcoin Core, in bytes
+pub const MAX_WITNESS_SCRIPT_SIZE: usize = 3600;
+/// The maximum number of keys of a `multi` fragment
+pub const MAX_MULTI_KEYS: usize = 20;
+
+/// The size of a signature in a witness, with its sighash byte and length prefix, in bytes
+const SIGNATURE_SIZE: usize = 74;
+/// The size of a 32-byte preimage in a witness, with its length prefix, in bytes
+const PREIMAGE_SIZE: usize = 33;
+
+#[derive(Debug, Fail, PartialEq, Eq)]
+pub enum MiniscriptError {
+    #[fail(display = "invalid hash: {}", _0)]
+    InvalidHash(String),
+
+    #[fail(display = "invalid lock time: {}", _0)]
+    InvalidLockTime(String),
+
+    #[fail(display = "invalid policy: {}", _0)]
+    InvalidPolicy(String),
+
+    #[fail(display = "invalid public key: {}", _0)]
+    InvalidPublicKey(String),
+
+    #[fail(display = "invalid threshold: {} of {}", _0, _1)]
+    InvalidThreshold(usize, usize),
+
+    #[fail(display = "witness script of {} bytes exceeds the maximum of {} bytes", _0, _1)]
+    OversizedScript(usize, usize),
+}
+
+/// Represents a spending policy, as parsed from its string
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Policy {
+    Key(Vec<u8>),
+    After(u32),
+    Older(u32),
+    Hash(Opcode, Vec<u8>),
+    And(Box<Policy>, Box<Policy>),
+    Or(Box<Policy>, Box<Policy>),
+    Thresh(usize, Vec<Policy>),
+}
+
+impl FromStr for Policy {
+    type Err = MiniscriptError;
+
+    fn from_str(policy: &str) -> Result<Self, Self::Err> {
+        let invalid = || MiniscriptError::InvalidPolicy(policy.into());
+        let open = policy.find('(').ok_or_else(invalid)?;
+        if !policy.ends_with(')') {
+            return Err(invalid());
+        }
+        let (name, arguments) = (&policy[..open], split_arguments(&policy[open + 1..policy.len() - 1])?);
+
+        let hash = |opcode: Opcode, length: usize| -> Result<Self, MiniscriptError> {
+            match arguments.as_slice() {
+                [hash] => match hex::decode(hash) {
+                    Ok(bytes) if bytes.len() == length => Ok(Policy::Hash(opcode, bytes)),
+                    _ => Err(MiniscriptError::InvalidHash((*hash).into())),
+                },
+                _ => Err(invalid()),
+            }
+        };
+        let lock_time = |argument: &str| -> Result<u32, MiniscriptError> {
+            match argument.parse::<u32>() {
+                Ok(lock_time) if lock_time > 0 && lock_time < 0x8000_0000 => Ok(lock_time),
+                _ => Err(MiniscriptError::InvalidLockTime(argument.into())),
+            }
+        };
+        // The probability of a branch of an `or`, as in `9@pk(A)`, is ignored
+        let branch = |argument: &str| -> Result<Box<Self>, MiniscriptError> {
+            match argument.find('@') {
+                Some(index) if argument[..index].parse::<u32>().is_ok() => Ok(Box::new(argument[index + 1..].parse()?)),
+                _ => Ok(Box::new(argument.parse()?)),
+            }
+        };
+
+        match (name, arguments.as_slice()) {
+            ("pk", [key]) => match hex::decode(key) {
+                Ok(bytes) if bytes.len() == 33 && secp256k1::PublicKey::parse_slice(&bytes, None).is_ok() => {
+                    Ok(Policy::Key(bytes))
+                }
+                _ => Err(MiniscriptError::InvalidPublicKey((*key).into())),
+            },
+            ("after", [argument]) => Ok(Policy::After(lock_time(argument)?)),
+            ("older", [argument]) => Ok(Policy::Older(lock_time(argument)?)),
+            ("sha256", _) => hash(Opcode::OP_SHA256, 32),
+            ("hash256", _) => hash(Opcode::OP_HASH256, 32),
+            ("ripemd160", _) => hash(Opcode::OP_RIPEMD160, 20),
+            ("hash160", _) => hash(Opcode::OP_HASH160, 20),
+            ("and", [left, right]) => Ok(Policy::And(Box::new(left.parse()?), Box::new(right.parse()?))),
+            ("or", [left, right]) => Ok(Policy::Or(branch(left)?, branch(right)?)),
+            ("thresh", [k, policies @ ..]) => {
+                let k = k.parse::<usize>().map_err(|_| invalid())?;
+                if k == 0 || k > policies.len() {
+                    return Err(MiniscriptError::InvalidThreshold(k, policies.len()));
+                }
+                let policies = policies
+                    .iter()
+                    .map(|policy| policy.parse())
+                    .collect::<Result<Vec<Self>, MiniscriptError>>()?;
+                Ok(Policy::Thresh(k, policies))
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Returns the arguments of a policy, split on the commas outside of any parentheses.
+fn split_arguments(arguments: &str) -> Result<Vec<&str>, MiniscriptError> {
+    let invalid = || MiniscriptError::InvalidPolicy(arguments.into());
+    let (mut result, mut depth, mut start) = (vec![], 0usize, 0);
+    for (index, character) in arguments.char_indices() {
+        match character {
+            '(' => depth += 1,
+            ')' => depth = depth.checked_sub(1).ok_or_else(invalid)?,
+            ',' if depth == 0 => {
+                result.push(&arguments[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(invalid());
+    }
+    result.push(&arguments[start..]);
+    match result.iter().any(|argument| argument.is_empty()) {
+        true => Err(invalid()),
+        false => Ok(result),
+    }
+}
+
+/// Represents a compiled miniscript fragment, with the properties of its type that
+/// decide how it may be composed, and the sizes of its witnesses
+#[derive(Debug, Clone)]
+struct Fragment {
+    miniscript: String,
+    script: Script,
+    /// Whether the fragment may be dissatisfied without a signature (d)
+    dissatisfiable: bool,
+    /// Whether the fragment leaves exactly 1 on the stack when satisfied (u)
+    unit: bool,
+    /// The maximum size of a satisfaction, in bytes
+    satisfaction: usize,
+    /// The maximum size of a dissatisfaction, in bytes, if the fragment is dissatisfiable
+    dissatisfaction: Option<usize>,
+}
+
+impl Fragment {
+    /// Returns the fragment with the given wrapper applied, merged into the wrappers of its name.
+    fn wrap(mut self, wrapper: char) -> Self {
+        let open = self.miniscript.find('(').unwrap_or(0);
+        self.miniscript = match self.miniscript[..open].contains(':') {
+            true => format!("{}{}", wrapper, self.miniscript),
+            false => format!("{}:{}", wrapper, self.miniscript),
+        };
+        self
+    }
+
+    /// Returns the fragment wrapped in `v:`, which verifies its result instead of leaving it on the stack.
+    fn verify(self) -> Self {
+        let mut fragment = self.wrap('v');
+        let last = fragment.script.0.pop();
+        let last = match last {
+            Some(opcode) if opcode == Opcode::OP_CHECKSIG as u8 => Opcode::OP_CHECKSIGVERIFY as u8,
+            Some(opcode) if opcode == Opcode::OP_CHECKMULTISIG as u8 => Opcode::OP_CHECKMULTISIGVERIFY as u8,
+            Some(opcode) if opcode == Opcode::OP_EQUAL as u8 => Opcode::OP_EQUALVERIFY as u8,
+            Some(opcode) => {
+                fragment.script.0.push(opcode);
+                Opcode::OP_VERIFY as u8
+            }
+            None => Opcode::OP_VERIFY as u8,
+        };
+        fragment.script.0.push(last);
+        Fragment {
+            dissatisfiable: false,
+            unit: false,
+            dissatisfaction: None,
+            ..fragment
+        }
+    }
+
+    /// Returns the fragment wrapped in `l:`, as `or_i(0,X)`, which is dissatisfied by taking its empty branch.
+    fn dissatisfiable(self) -> Self {
+        let mut fragment = self.wrap('l');
+        fragment.script = Script::default()
+            .push_opcode(Opcode::OP_IF)
+            .push_opcode(Opcode::OP_0)
+            .push_opcode(Opcode::OP_ELSE)
+            .push_script(&fragment.script)
+            .push_opcode(Opcode::OP_ENDIF);
+        Fragment {
+            dissatisfiable: true,
+            satisfaction: fragment.satisfaction + 1,
+            dissatisfaction: Some(2),
+            ..fragment
+        }
+    }
+
+    /// Returns the fragment wrapped in `n:`, which leaves exactly 1 on the stack when satisfied.
+    fn unit(self) -> Self {
+        let mut fragment = self.wrap('n');
+        fragment.script = fragment.script.push_opcode(Opcode::OP_0NOTEQUAL);
+        Fragment { unit: true, ..fragment }
+    }
+
+    /// Returns the fragment wrapped in `a:`, which evaluates it on the alternate stack, above the result before it.
+    fn alternate(self) -> Self {
+        let mut fragment = self.wrap('a');
+        fragment.script = Script::default()
+            .push_opcode(Opcode::OP_TOALTSTACK)
+            .push_script(&fragment.script)
+            .push_opcode(Opcode::OP_FROMALTSTACK);
+        fragment
+    }
+}
+
+/// Returns the fragment compiled from the given policy.
+fn compile(policy: &Policy) -> Result<Fragment, MiniscriptError> {
+    match policy {
+        Policy::Key(key) => Ok(Fragment {
+            miniscript: format!("pk({})", hex::encode(key)),
+            script: Script::default().push_slice(key).push_opcode(Opcode::OP_CHECKSIG),
+            dissatisfiable: true,
+            unit: true,
+            satisfaction: SIGNATURE_SIZE,
+            dissatisfaction: Some(1),
+        }),
+        Policy::After(lock_time) | Policy::Older(lock_time) => {
+            let (name, opcode) = match policy {
+                Policy::After(_) => ("after", Opcode::OP_CHECKLOCKTIMEVERIFY),
+                _ => ("older", Opcode::OP_CHECKSEQUENCEVERIFY),
+            };
+            Ok(Fragment {
+                miniscript: format!("{}({})", name, lock_time),
+                script: Script::default().push_int(*lock_time).push_opcode(opcode),
+                dissatisfiable: false,
+                unit: false,
+                satisfaction: 0,
+                dissatisfaction: None,
+            })
+        }
+        Policy::Hash(opcode, hash) => {
+            let name = match opcode {
+                Opcode::OP_SHA256 => "sha256",
+                Opcode::OP_HASH256 => "hash256",
+                Opcode::OP_RIPEMD160 => "ripemd160",
+                _ => "hash160",
+            };
+            Ok(Fragment {
+                miniscript: format!("{}({})", name, hex::encode(hash)),
+                script: Script::default()
+                    .push_opcode(Opcode::OP_SIZE)
+                    .push_int(32)
+                    .push_opcode(Opcode::OP_EQUALVERIFY)
+                    .push_opcode(*opcode)
+                    .push_slice(hash)
+                    .push_opcode(Opcode::OP_EQUAL),
+                dissatisfiable: true,
+                unit: true,
+                satisfaction: PREIMAGE_SIZE,
+                dissatisfaction: Some(PREIMAGE_SIZE),
+            })
+        }
+        Policy::And(left, right) => {
+            // and_v(v:X,Y)
+            let (left, right) = (compile(left)?.verify(), compile(right)?);
+            Ok(Fragment {
+                miniscript: format!("and_v({},{})", left.miniscript, right.miniscript),
+                script: left.script.push_script(&right.script),
+                dissatisfiable: false,
+                unit: right.unit,
+                satisfaction: left.satisfaction + right.satisfaction,
+                dissatisfaction: None,
+            })
+        }
+        Policy::Or(left, right) => {
+            let (left, right) = (compile(left)?, compile(right)?);
+            let (left, right) = match (left.dissatisfiable && left.unit, right.dissatisfiable && right.unit) {
+                (false, true) => (right, left),
+                _ => (left, right),
+            };
+            match (left.dissatisfiable && left.unit, left.dissatisfaction) {
+                // or_d(X,Y), which evaluates Y only if X is dissatisfied
+                (true, Some(left_dissatisfaction)) => Ok(Fragment {
+                    miniscript: format!("or_d({},{})", left.miniscript, right.miniscript),
+                    script: left
+                        .script
+                        .push_opcode(Opcode::OP_IFDUP)
+                        .push_opcode(Opcode::OP_NOTIF)
+                        .push_script(&right.script)
+                        .push_opcode(Opcode::OP_ENDIF),
+                    dissatisfiable: right.dissatisfiable,
+                    unit: right.unit,
+                    satisfaction: left.satisfaction.max(left_dissatisfaction + right.satisfaction),
+                    dissatisfaction: right.dissatisfaction.map(|size| left_dissatisfaction + size),
+                }),
+                // or_i(X,Y), which selects the branch with a witness of 1 or empty
+                _ => Ok(Fragment {
+                    miniscript: format!("or_i({},{})", left.miniscript, right.miniscript),
+                    script: Script::default()
+                        .push_opcode(Opcode::OP_IF)
+                        .push_script(&left.script)
+                        .push_opcode(Opcode::OP_ELSE)
+                        .push_script(&right.script)
+                        .push_opcode(Opcode::OP_ENDIF),
+                    dissatisfiable: left.dissatisfiable || right.dissatisfiable,
+                    unit: left.unit && right.unit,
+                    satisfaction: (left.satisfaction + 2).max(right.satisfaction + 1),
+                    dissatisfaction: match (left.dissatisfaction, right.dissatisfaction) {
+                        (Some(left), Some(right)) => Some((left + 2).max(right + 1)),
+                        (Some(left), None) => Some(left + 2),
+                        (None, right) => right.map(|size| size + 1),
+                    },
+                }),
+            }
+        }
+        Policy::Thresh(k, policies) if policies.iter().all(|policy| matches!(policy, Policy::Key(_))) => {
+            // multi(k,keys), checking the signatures of k of the keys
+            if policies.len() > MAX_MULTI_KEYS {
+                return Err(MiniscriptError::InvalidThreshold(*k, policies.len()));
+            }
+            let keys = policies
+                .iter()
+                .filter_map(|policy| match policy {
+                    Policy::Key(key) => Some(key),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            let script = keys
+                .iter()
+                .fold(Script::default().push_int(*k as u32), |script, key| {
+                    script.push_slice(key)
+                })
+                .push_int(keys.len() as u32)
+                .push_opcode(Opcode::OP_CHECKMULTISIG);
+            let keys = keys.iter().map(hex::encode).collect::<Vec<String>>().join(",");
+            Ok(Fragment {
+                miniscript: format!("multi({},{})", k, keys),
+                script,
+                dissatisfiable: true,
+                unit: true,
+                satisfaction: 1 + SIGNATURE_SIZE * k,
+                dissatisfaction: Some(1 + k),
+            })
+        }
+        Policy::Thresh(k, policies) => {
+            // thresh(k,X1,a:X2,...), summing the results of its fragments, each dissatisfiable with a unit result
+            let mut fragments = vec![];
+            for (index, policy) in policies.iter().enumerate() {
+                let mut fragment = compile(policy)?;
+                if !fragment.dissatisfiable {
+                    fragment = fragment.dissatisfiable();
+                }
+                if !fragment.unit {
+                    fragment = fragment.unit();
+                }
+                if index > 0 {
+                    fragment = fragment.alternate();
+                }
+                fragments.push(fragment);
+            }
+
+            let mut script = Script::default();
+            for (index, fragment) in fragments.iter().enumerate() {
+                script = script.push_script(&fragment.script);
+                if index > 0 {
+                    script = script.push_opcode(Opcode::OP_ADD);
+                }
+            }
+
+            let dissatisfactions = fragments
+                .iter()
+                .map(|fragment| fragment.dissatisfaction.unwrap_or(0))
+                .collect::<Vec<usize>>();
+            let dissatisfaction = dissatisfactions.iter().sum::<usize>();
+            // The largest satisfaction satisfies the k fragments that grow the witness the most
+            let mut differences = fragments
+                .iter()
+                .zip(dissatisfactions.iter())
+                .map(|(fragment, size)| fragment.satisfaction.saturating_sub(*size))
+                .collect::<Vec<usize>>();
+            differences.sort_unstable_by(|a, b| b.cmp(a));
+
+            let miniscripts = fragments
+                .iter()
+                .map(|fragment| fragment.miniscript.clone())
+                .collect::<Vec<String>>();
+            Ok(Fragment {
+                miniscript: format!("thresh({},{})", k, miniscripts.join(",")),
+                script: script.push_int(*k as u32).push_opcode(Opcode::OP_EQUAL),
+                dissatisfiable: true,
+                unit: true,
+                satisfaction: dissatisfaction + differences.iter().take(*k).sum::<usize>(),
+                dissatisfaction: Some(dissatisfaction),
+            })
+        }
+    }
+}
+
+/// Represents a policy compiled to miniscript, and the witness script it encodes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Miniscript {
+    /// The miniscript of the policy
+    pub miniscript: String,
+    /// The witness script of the miniscript
+    pub witness_script: Script,
+    /// The maximum weight of the witness that satisfies the script, in weight units,
+    /// including the witness script and the count of its items
+    pub max_satisfaction_weight: usize,
+}
+
+impl Miniscript {
+    /// Returns the miniscript compiled from the given policy.
+    pub fn from_policy(policy: &str) -> Result<Self, MiniscriptError> {
+        let policy: String = policy.chars().filter(|character| !character.is_whitespace()).collect();
+        let fragment = compile(&Policy::from_str(&policy)?)?;
+
+        let script_length = fragment.script.0.len();
+        if script_length > MAX_WITNESS_SCRIPT_SIZE {
+            return Err(MiniscriptError::OversizedScript(script_length, MAX_WITNESS_SCRIPT_SIZE));
+        }
+        let script_length_size = match script_length {
+            0..=0xfc => 1,
+            0xfd..=0xffff => 3,
+            _ => 5,
+        };
+
+        Ok(Self {
+            miniscript: fragment.miniscript,
+            max_satisfaction_weight: 1 + fragment.satisfaction + script_length_size + script_length,
+            witness_script: fragment.script,
+        })
+    }
+
+    /// Returns the Pay-to-Witness-Script Hash script paying to the witness script.
+    pub fn to_script_pub_key(&self) -> Script {
+        self.witness_script.to_p2wsh()
+    }
+
+    /// Returns the P2WSH address of the witness script.
+    pub fn to_address<N: BitcoinNetwork>(&self) -> Result<BitcoinAddress<N>, AddressError> {
+        BitcoinAddress::p2wsh(&self.witness_script.0)
+    }
+}
+
+impl FromStr for Miniscript {
+    type Err = MiniscriptError;
+
+    fn from_str(policy: &str) -> Result<Self, Self::Err> {
+        Self::from_policy(policy)
+    }
+}
+
+impl fmt::Display for Miniscript {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.miniscript)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    const A: &str = "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5";
+    const B: &str = "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9";
+    const C: &str = "03e60fce93b59e9ec53011aabc21c23e97b2a31369b87a5ae9c44ee89e2a6dec0a";
+
+    #[test]
+    fn or_d() {
+        let miniscript = Miniscript::from_policy(&format!("or(pk({}),and(pk({}),older(144)))", A, B)).unwrap();
+        assert_eq!(
+            format!("or_d(pk({}),and_v(v:pk({}),older(144)))", A, B),
+            miniscript.to_string()
+        );
+        assert_eq!(
+            format!("21{}ac736421{}ad029000b268", A, B),
+            miniscript.witness_script.to_string()
+        );
+        // An empty signature for A and a signature for B, or a signature for A
+        assert_eq!(1 + 75 + 1 + 77, miniscript.max_satisfaction_weight);
+
+        let address = miniscript.to_address::<Mainnet>().unwrap();
+        assert!(address.to_string().starts_with("bc1q"));
+        assert_eq!(62, address.to_string().len());
+        assert_eq!(
+            crate::policy::OutputType::P2WSH,
+            miniscript.to_script_pub_key().output_type()
+        );
+
+        // The probabilities of the branches and whitespace are ignored
+        let weighted = format!("or(99@pk({}), 1@and(pk({}), older(144)))", A, B);
+        assert_eq!(miniscript, Miniscript::from_policy(&weighted).unwrap());
+    }
+
+    #[test]
+    fn multi() {
+        let miniscript = Miniscript::from_policy(&format!("thresh(2,pk({}),pk({}),pk({}))", A, B, C)).unwrap();
+        assert_eq!(format!("multi(2,{},{},{})", A, B, C), miniscript.miniscript);
+        assert_eq!(
+            format!("5221{}21{}21{}53ae", A, B, C),
+            miniscript.witness_script.to_string()
+        );
+        assert_eq!(1 + (1 + 2 * 74) + 1 + 105, miniscript.max_satisfaction_weight);
+    }
+
+    #[test]
+    fn thresh() {
+        let hash = "0000000000000000000000000000000000000000000000000000000000000001";
+        let policy = format!("thresh(2,pk({}),sha256({}),after(500000))", A, hash);
+        let miniscript = Miniscript::from_policy(&policy).unwrap();
+        assert_eq!(
+            format!("thresh(2,pk({}),a:sha256({}),anl:after(500000))", A, hash),
+            miniscript.miniscript
+        );
+        assert_eq!(
+            format!("21{}ac6b82012088a820{}876c936b6300670320a107b168926c935287", A, hash),
+            miniscript.witness_script.to_string()
+        );
+    }
+
+    #[test]
+    fn or_i() {
+        let miniscript = Miniscript::from_policy("or(older(144),after(500000))").unwrap();
+        assert_eq!("or_i(older(144),after(500000))", miniscript.miniscript);
+        assert_eq!("63029000b2670320a107b168", miniscript.witness_script.to_string());
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Err(MiniscriptError::InvalidThreshold(3, 2)),
+            Miniscript::from_policy(&format!("thresh(3,pk({}),pk({}))", A, B))
+        );
+        assert!(matches!(
+            Miniscript::from_policy("pk(02c6047f)"),
+            Err(MiniscriptError::InvalidPublicKey(_))
+        ));
+        assert!(matches!(
+            Miniscript::from_policy("older(0)"),
+            Err(MiniscriptError::InvalidLockTime(_))
+        ));
+        assert!(matches!(
+            Miniscript::from_policy("sha256(00)"),
+            Err(MiniscriptError::InvalidHash(_))
+        ));
+        assert!(Miniscript::from_policy(&format!("and(pk({}))", A)).is_err());
+        assert!(Miniscript::from_policy(&format!("or(pk({}),pk({})", A, B)).is_err());
+        assert!(Miniscript::from_policy("multi(1,pk)").is_err());
+
+        let keys = vec![format!("pk({})", A); MAX_MULTI_KEYS + 1].join(",");
+        assert!(Miniscript::from_policy(&format!("thresh(1,{})", keys)).is_err());
+    }
+}
@@ -15,6 +15,7 @@ use hmac::Hmac;
 use pbkdf2::pbkdf2;
 use rand::Rng;
 use sha2::{Digest, Sha256, Sha512};
+use zeroize::Zeroize;
 
 const PBKDF2_ROUNDS: usize = 2048;
 const PBKDF2_BYTES: usize = 64;
@@ -168,10 +169,10 @@ impl<N: BitcoinNetwork, W: BitcoinWordlist> MnemonicExtended for BitcoinMnemonic
 
     /// Returns the extended private key of the corresponding mnemonic.
     fn to_extended_private_key(&self, password: Option<&str>) -> Result<Self::ExtendedPrivateKey, MnemonicError> {
-        Ok(Self::ExtendedPrivateKey::new_master(
-            self.to_seed(password)?.as_slice(),
-            &BitcoinFormat::P2PKH,
-        )?)
+        let mut seed = self.to_seed(password)?;
+        let extended_private_key = Self::ExtendedPrivateKey::new_master(seed.as_slice(), &BitcoinFormat::P2PKH);
+        seed.zeroize();
+        Ok(extended_private_key?)
     }
 
     /// Returns the extended public key of the corresponding mnemonic.
@@ -187,10 +188,13 @@ impl<N: BitcoinNetwork, W: BitcoinWordlist> BitcoinMnemonic<N, W> {
     }
 
     /// Returns a seed using the given password and mnemonic.
-    fn to_seed(&self, password: Option<&str>) -> Result<Vec<u8>, MnemonicError> {
+    pub fn to_seed(&self, password: Option<&str>) -> Result<Vec<u8>, MnemonicError> {
         let mut seed = vec![0u8; PBKDF2_BYTES];
-        let salt = format!("mnemonic{}", password.unwrap_or(""));
-        pbkdf2::<Hmac<Sha512>>(&self.to_phrase()?.as_bytes(), salt.as_bytes(), PBKDF2_ROUNDS, &mut seed);
+        let mut salt = format!("mnemonic{}", password.unwrap_or(""));
+        let mut phrase = self.to_phrase()?;
+        pbkdf2::<Hmac<Sha512>>(phrase.as_bytes(), salt.as_bytes(), PBKDF2_ROUNDS, &mut seed);
+        salt.zeroize();
+        phrase.zeroize();
         Ok(seed)
     }
 }
@@ -216,6 +220,19 @@ impl<N: BitcoinNetwork, W: BitcoinWordlist> fmt::Display for BitcoinMnemonic<N,
     }
 }
 
+impl<N: BitcoinNetwork, W: BitcoinWordlist> Zeroize for BitcoinMnemonic<N, W> {
+    /// Overwrites the mnemonic entropy with zeros.
+    fn zeroize(&mut self) {
+        self.entropy.zeroize();
+    }
+}
+
+impl<N: BitcoinNetwork, W: BitcoinWordlist> Drop for BitcoinMnemonic<N, W> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -504,6 +521,15 @@ mod tests {
                     test_to_extended_private_key::<N, W>(expected_extended_private_key, Some(PASSWORD), phrase);
                 });
         }
+
+        #[test]
+        fn zeroize() {
+            KEYPAIRS.iter().for_each(|(_, phrase, _, _)| {
+                let mut mnemonic = BitcoinMnemonic::<N, W>::from_phrase(phrase).unwrap();
+                mnemonic.zeroize();
+                assert!(mnemonic.entropy.is_empty());
+            });
+        }
     }
 
     mod test_invalid {
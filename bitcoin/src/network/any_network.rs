@@ -0,0 +1,163 @@
+use crate::network::{BitcoinNetwork, Mainnet, Regtest, Testnet};
+use wagyu_model::{Network, NetworkError};
+
+use core::{convert::TryFrom, fmt, str::FromStr};
+
+/// Represents a Bitcoin network selected at runtime.
+///
+/// Each variant corresponds to a type-level network, to and from which it converts, and `apply`
+/// runs a function that is generic over the Bitcoin networks on the type-level network it selects.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AnyNetwork {
+    Mainnet,
+    Testnet,
+    Regtest,
+}
+
+/// The interface for a function that is generic over the Bitcoin networks,
+/// which is applied to a network selected at runtime with `AnyNetwork::apply`.
+pub trait BitcoinNetworkFn {
+    type Output;
+
+    /// Returns the output of the function on the network `N`.
+    fn call<N: BitcoinNetwork>(self) -> Self::Output;
+}
+
+impl AnyNetwork {
+    /// The Bitcoin networks
+    pub const ALL: [AnyNetwork; 3] = [AnyNetwork::Mainnet, AnyNetwork::Testnet, AnyNetwork::Regtest];
+
+    /// Returns the runtime network of the type-level network `N`.
+    pub fn of<N: BitcoinNetwork>() -> Result<Self, NetworkError> {
+        Self::from_str(N::NAME)
+    }
+
+    /// Returns the name of the network.
+    pub fn name(&self) -> &'static str {
+        match self {
+            AnyNetwork::Mainnet => Mainnet::NAME,
+            AnyNetwork::Testnet => Testnet::NAME,
+            AnyNetwork::Regtest => Regtest::NAME,
+        }
+    }
+
+    /// Returns the output of the function on the type-level network of the network.
+    pub fn apply<F: BitcoinNetworkFn>(&self, function: F) -> F::Output {
+        match self {
+            AnyNetwork::Mainnet => function.call::<Mainnet>(),
+            AnyNetwork::Testnet => function.call::<Testnet>(),
+            AnyNetwork::Regtest => function.call::<Regtest>(),
+        }
+    }
+}
+
+impl From<Mainnet> for AnyNetwork {
+    fn from(_: Mainnet) -> Self {
+        AnyNetwork::Mainnet
+    }
+}
+
+impl TryFrom<AnyNetwork> for Mainnet {
+    type Error = NetworkError;
+
+    fn try_from(network: AnyNetwork) -> Result<Self, Self::Error> {
+        match network {
+            AnyNetwork::Mainnet => Ok(Mainnet),
+            _ => Err(NetworkError::InvalidNetwork(network.name().into())),
+        }
+    }
+}
+
+impl From<Testnet> for AnyNetwork {
+    fn from(_: Testnet) -> Self {
+        AnyNetwork::Testnet
+    }
+}
+
+impl TryFrom<AnyNetwork> for Testnet {
+    type Error = NetworkError;
+
+    fn try_from(network: AnyNetwork) -> Result<Self, Self::Error> {
+        match network {
+            AnyNetwork::Testnet => Ok(Testnet),
+            _ => Err(NetworkError::InvalidNetwork(network.name().into())),
+        }
+    }
+}
+
+impl From<Regtest> for AnyNetwork {
+    fn from(_: Regtest) -> Self {
+        AnyNetwork::Regtest
+    }
+}
+
+impl TryFrom<AnyNetwork> for Regtest {
+    type Error = NetworkError;
+
+    fn try_from(network: AnyNetwork) -> Result<Self, Self::Error> {
+        match network {
+            AnyNetwork::Regtest => Ok(Regtest),
+            _ => Err(NetworkError::InvalidNetwork(network.name().into())),
+        }
+    }
+}
+
+impl FromStr for AnyNetwork {
+    type Err = NetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|network| network.name() == s)
+            .copied()
+            .ok_or_else(|| NetworkError::InvalidNetwork(s.into()))
+    }
+}
+
+impl fmt::Display for AnyNetwork {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::BitcoinFormat;
+
+    struct AddressPrefix<'a>(&'a BitcoinFormat);
+
+    impl<'a> BitcoinNetworkFn for AddressPrefix<'a> {
+        type Output = Vec<u8>;
+
+        fn call<N: BitcoinNetwork>(self) -> Vec<u8> {
+            N::to_address_prefix(self.0)
+        }
+    }
+
+    #[test]
+    fn apply() {
+        let format = BitcoinFormat::P2PKH;
+        assert_eq!(vec![0x00], AnyNetwork::Mainnet.apply(AddressPrefix(&format)));
+        assert_eq!(vec![0x6F], AnyNetwork::Testnet.apply(AddressPrefix(&format)));
+        assert_eq!(
+            b"bcrt".to_vec(),
+            AnyNetwork::Regtest.apply(AddressPrefix(&BitcoinFormat::Bech32))
+        );
+    }
+
+    #[test]
+    fn convert() {
+        for network in AnyNetwork::ALL.iter() {
+            assert_eq!(*network, AnyNetwork::from_str(network.name()).unwrap());
+            assert_eq!(network.name(), network.to_string());
+        }
+        assert_eq!(AnyNetwork::Testnet, AnyNetwork::from(Testnet));
+        assert_eq!(AnyNetwork::Testnet, AnyNetwork::of::<Testnet>().unwrap());
+        assert_eq!(Testnet, Testnet::try_from(AnyNetwork::Testnet).unwrap());
+        assert!(Mainnet::try_from(AnyNetwork::Testnet).is_err());
+        assert_eq!(AnyNetwork::Regtest, AnyNetwork::from_str("regtest").unwrap());
+        assert!(Testnet::try_from(AnyNetwork::Regtest).is_err());
+        assert!(AnyNetwork::from_str("signet").is_err());
+    }
+}
@@ -1,40 +1,28 @@
 use crate::format::BitcoinFormat;
 use wagyu_model::no_std::*;
-use wagyu_model::{
-    AddressError, ChildIndex, ExtendedPrivateKeyError, ExtendedPublicKeyError, Network, PrivateKeyError,
-};
+use wagyu_model::{Base58Network, ExtendedPrivateKeyError, ExtendedPublicKeyError};
+
+pub mod any_network;
+pub use self::any_network::*;
 
 pub mod mainnet;
 pub use self::mainnet::*;
 
+pub mod regtest;
+pub use self::regtest::*;
+
 pub mod testnet;
 pub use self::testnet::*;
 
-/// The interface for a Bitcoin network.
-pub trait BitcoinNetwork: Network {
-    const HD_COIN_TYPE: ChildIndex;
-
+/// The interface for a Bitcoin network, configured by its table of base58 version bytes,
+/// and the bech32 and SLIP-132 prefixes of its segwit formats.
+pub trait BitcoinNetwork: Base58Network {
     /// Returns the address prefix of the given network.
     fn to_address_prefix(format: &BitcoinFormat) -> Vec<u8>;
 
-    /// Returns the network of the given address prefix.
-    fn from_address_prefix(prefix: &[u8]) -> Result<Self, AddressError>;
-
-    /// Returns the wif prefix of the given network.
-    fn to_private_key_prefix() -> u8;
-
-    /// Returns the network of the given wif prefix.
-    fn from_private_key_prefix(prefix: u8) -> Result<Self, PrivateKeyError>;
-
     /// Returns the extended private key version bytes of the given network.
     fn to_extended_private_key_version_bytes(format: &BitcoinFormat) -> Result<Vec<u8>, ExtendedPrivateKeyError>;
 
-    /// Returns the network of the given extended private key version bytes.
-    fn from_extended_private_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPrivateKeyError>;
-
     /// Returns the extended public key version bytes of the given network.
     fn to_extended_public_key_version_bytes(format: &BitcoinFormat) -> Result<Vec<u8>, ExtendedPublicKeyError>;
-
-    /// Returns the network of the given extended public key version bytes.
-    fn from_extended_public_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPublicKeyError>;
 }
@@ -0,0 +1,103 @@
+use crate::format::BitcoinFormat;
+use crate::network::BitcoinNetwork;
+use wagyu_model::no_std::*;
+use wagyu_model::{
+    AddressError, Base58Network, ChildIndex, ExtendedPrivateKeyError, ExtendedPublicKeyError, Network, NetworkError,
+};
+
+use core::{fmt, str::FromStr};
+use serde::Serialize;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct Regtest;
+
+impl Network for Regtest {
+    const NAME: &'static str = "regtest";
+}
+
+impl Base58Network for Regtest {
+    const HD_COIN_TYPE: ChildIndex = ChildIndex::Hardened(1);
+    const P2PKH_PREFIX: u8 = 0x6F;
+    const P2SH_PREFIX: u8 = 0xC4;
+    const WIF_PREFIX: u8 = 0xEF;
+    const EXTENDED_PRIVATE_KEY_VERSION_BYTES: [u8; 4] = [0x04, 0x35, 0x83, 0x94]; // tprv
+    const EXTENDED_PUBLIC_KEY_VERSION_BYTES: [u8; 4] = [0x04, 0x35, 0x87, 0xCF]; // tpub
+
+    /// Returns the network of the given address prefix.
+    /// Base58 addresses share the prefixes of testnet, and bech32 addresses have the human-readable part `bcrt`.
+    fn from_address_prefix(prefix: &[u8]) -> Result<Self, AddressError> {
+        match prefix {
+            [0x6F, ..] | [0xC4, ..] | [0x62, 0x63, 0x72, 0x74, ..] => Ok(Self),
+            _ => Err(AddressError::InvalidPrefix(prefix.to_owned())),
+        }
+    }
+
+    /// Returns the network of the given extended private key version bytes.
+    /// https://github.com/satoshilabs/slips/blob/master/slip-0132.md
+    fn from_extended_private_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPrivateKeyError> {
+        match prefix[0..4] {
+            [0x04, 0x35, 0x83, 0x94] | [0x04, 0x4A, 0x4E, 0x28] | [0x04, 0x5F, 0x18, 0xBC] => Ok(Self),
+            _ => Err(ExtendedPrivateKeyError::InvalidVersionBytes(prefix.to_vec())),
+        }
+    }
+
+    /// Returns the network of the given extended public key version bytes.
+    /// https://github.com/satoshilabs/slips/blob/master/slip-0132.md
+    fn from_extended_public_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPublicKeyError> {
+        match prefix[0..4] {
+            [0x04, 0x35, 0x87, 0xCF] | [0x04, 0x4A, 0x52, 0x62] | [0x04, 0x5F, 0x1C, 0xF6] => Ok(Self),
+            _ => Err(ExtendedPublicKeyError::InvalidVersionBytes(prefix.to_vec())),
+        }
+    }
+}
+
+impl BitcoinNetwork for Regtest {
+    /// Returns the address prefix of the given network.
+    fn to_address_prefix(format: &BitcoinFormat) -> Vec<u8> {
+        match format {
+            BitcoinFormat::P2PKH => vec![Self::P2PKH_PREFIX],
+            BitcoinFormat::P2WSH => vec![0x00],
+            BitcoinFormat::P2SH_P2WPKH => vec![Self::P2SH_PREFIX],
+            BitcoinFormat::Bech32 => vec![0x62, 0x63, 0x72, 0x74],
+        }
+    }
+
+    /// Returns the extended private key version bytes of the given network.
+    /// https://github.com/satoshilabs/slips/blob/master/slip-0132.md
+    fn to_extended_private_key_version_bytes(format: &BitcoinFormat) -> Result<Vec<u8>, ExtendedPrivateKeyError> {
+        match format {
+            BitcoinFormat::P2PKH => Ok(Self::EXTENDED_PRIVATE_KEY_VERSION_BYTES.to_vec()),
+            BitcoinFormat::P2SH_P2WPKH => Ok(vec![0x04, 0x4A, 0x4E, 0x28]), // upriv
+            BitcoinFormat::Bech32 => Ok(vec![0x04, 0x5F, 0x18, 0xBC]),      // vprv
+            _ => Err(ExtendedPrivateKeyError::UnsupportedFormat(format.to_string())),
+        }
+    }
+
+    /// Returns the extended public key version bytes of the given network.
+    /// https://github.com/satoshilabs/slips/blob/master/slip-0132.md
+    fn to_extended_public_key_version_bytes(format: &BitcoinFormat) -> Result<Vec<u8>, ExtendedPublicKeyError> {
+        match format {
+            BitcoinFormat::P2PKH => Ok(Self::EXTENDED_PUBLIC_KEY_VERSION_BYTES.to_vec()),
+            BitcoinFormat::P2SH_P2WPKH => Ok(vec![0x04, 0x4A, 0x52, 0x62]), // upub
+            BitcoinFormat::Bech32 => Ok(vec![0x04, 0x5F, 0x1C, 0xF6]),      // vpub
+            _ => Err(ExtendedPublicKeyError::UnsupportedFormat(format.to_string())),
+        }
+    }
+}
+
+impl FromStr for Regtest {
+    type Err = NetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            Self::NAME => Ok(Self),
+            _ => Err(NetworkError::InvalidNetwork(s.into())),
+        }
+    }
+}
+
+impl fmt::Display for Regtest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::NAME)
+    }
+}
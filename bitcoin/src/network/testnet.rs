@@ -2,31 +2,26 @@ use crate::format::BitcoinFormat;
 use crate::network::BitcoinNetwork;
 use wagyu_model::no_std::*;
 use wagyu_model::{
-    AddressError, ChildIndex, ExtendedPrivateKeyError, ExtendedPublicKeyError, Network, NetworkError, PrivateKeyError,
+    AddressError, Base58Network, ChildIndex, ExtendedPrivateKeyError, ExtendedPublicKeyError, Network, NetworkError,
 };
 
 use core::{fmt, str::FromStr};
 use serde::Serialize;
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
 pub struct Testnet;
 
 impl Network for Testnet {
     const NAME: &'static str = "testnet";
 }
 
-impl BitcoinNetwork for Testnet {
+impl Base58Network for Testnet {
     const HD_COIN_TYPE: ChildIndex = ChildIndex::Hardened(1);
-
-    /// Returns the address prefix of the given network.
-    fn to_address_prefix(format: &BitcoinFormat) -> Vec<u8> {
-        match format {
-            BitcoinFormat::P2PKH => vec![0x6F],
-            BitcoinFormat::P2WSH => vec![0x00],
-            BitcoinFormat::P2SH_P2WPKH => vec![0xC4],
-            BitcoinFormat::Bech32 => vec![0x74, 0x62],
-        }
-    }
+    const P2PKH_PREFIX: u8 = 0x6F;
+    const P2SH_PREFIX: u8 = 0xC4;
+    const WIF_PREFIX: u8 = 0xEF;
+    const EXTENDED_PRIVATE_KEY_VERSION_BYTES: [u8; 4] = [0x04, 0x35, 0x83, 0x94]; // tprv
+    const EXTENDED_PUBLIC_KEY_VERSION_BYTES: [u8; 4] = [0x04, 0x35, 0x87, 0xCF]; // tpub
 
     /// Returns the network of the given address prefix.
     fn from_address_prefix(prefix: &[u8]) -> Result<Self, AddressError> {
@@ -36,16 +31,33 @@ impl BitcoinNetwork for Testnet {
         }
     }
 
-    /// Returns the wif prefix of the given network.
-    fn to_private_key_prefix() -> u8 {
-        0xEF
+    /// Returns the network of the given extended private key version bytes.
+    /// https://github.com/satoshilabs/slips/blob/master/slip-0132.md
+    fn from_extended_private_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPrivateKeyError> {
+        match prefix[0..4] {
+            [0x04, 0x35, 0x83, 0x94] | [0x04, 0x4A, 0x4E, 0x28] | [0x04, 0x5F, 0x18, 0xBC] => Ok(Self),
+            _ => Err(ExtendedPrivateKeyError::InvalidVersionBytes(prefix.to_vec())),
+        }
+    }
+
+    /// Returns the network of the given extended public key version bytes.
+    /// https://github.com/satoshilabs/slips/blob/master/slip-0132.md
+    fn from_extended_public_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPublicKeyError> {
+        match prefix[0..4] {
+            [0x04, 0x35, 0x87, 0xCF] | [0x04, 0x4A, 0x52, 0x62] | [0x04, 0x5F, 0x1C, 0xF6] => Ok(Self),
+            _ => Err(ExtendedPublicKeyError::InvalidVersionBytes(prefix.to_vec())),
+        }
     }
+}
 
-    /// Returns the network of the given wif prefix.
-    fn from_private_key_prefix(prefix: u8) -> Result<Self, PrivateKeyError> {
-        match prefix {
-            0xEF => Ok(Self),
-            _ => Err(PrivateKeyError::InvalidPrefix(vec![prefix])),
+impl BitcoinNetwork for Testnet {
+    /// Returns the address prefix of the given network.
+    fn to_address_prefix(format: &BitcoinFormat) -> Vec<u8> {
+        match format {
+            BitcoinFormat::P2PKH => vec![Self::P2PKH_PREFIX],
+            BitcoinFormat::P2WSH => vec![0x00],
+            BitcoinFormat::P2SH_P2WPKH => vec![Self::P2SH_PREFIX],
+            BitcoinFormat::Bech32 => vec![0x74, 0x62],
         }
     }
 
@@ -53,39 +65,23 @@ impl BitcoinNetwork for Testnet {
     /// https://github.com/satoshilabs/slips/blob/master/slip-0132.md
     fn to_extended_private_key_version_bytes(format: &BitcoinFormat) -> Result<Vec<u8>, ExtendedPrivateKeyError> {
         match format {
-            BitcoinFormat::P2PKH => Ok(vec![0x04, 0x35, 0x83, 0x94]), // tpriv
+            BitcoinFormat::P2PKH => Ok(Self::EXTENDED_PRIVATE_KEY_VERSION_BYTES.to_vec()),
             BitcoinFormat::P2SH_P2WPKH => Ok(vec![0x04, 0x4A, 0x4E, 0x28]), // upriv
+            BitcoinFormat::Bech32 => Ok(vec![0x04, 0x5F, 0x18, 0xBC]),      // vprv
             _ => Err(ExtendedPrivateKeyError::UnsupportedFormat(format.to_string())),
         }
     }
 
-    /// Returns the network of the given extended private key version bytes.
-    /// https://github.com/satoshilabs/slips/blob/master/slip-0132.md
-    fn from_extended_private_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPrivateKeyError> {
-        match prefix[0..4] {
-            [0x04, 0x35, 0x83, 0x94] | [0x04, 0x4A, 0x4E, 0x28] => Ok(Self),
-            _ => Err(ExtendedPrivateKeyError::InvalidVersionBytes(prefix.to_vec())),
-        }
-    }
-
     /// Returns the extended public key version bytes of the given network.
     /// https://github.com/satoshilabs/slips/blob/master/slip-0132.md
     fn to_extended_public_key_version_bytes(format: &BitcoinFormat) -> Result<Vec<u8>, ExtendedPublicKeyError> {
         match format {
-            BitcoinFormat::P2PKH => Ok(vec![0x04, 0x35, 0x87, 0xCF]), // tpub
+            BitcoinFormat::P2PKH => Ok(Self::EXTENDED_PUBLIC_KEY_VERSION_BYTES.to_vec()),
             BitcoinFormat::P2SH_P2WPKH => Ok(vec![0x04, 0x4A, 0x52, 0x62]), // upub
+            BitcoinFormat::Bech32 => Ok(vec![0x04, 0x5F, 0x1C, 0xF6]),      // vpub
             _ => Err(ExtendedPublicKeyError::UnsupportedFormat(format.to_string())),
         }
     }
-
-    /// Returns the network of the given extended public key version bytes.
-    /// https://github.com/satoshilabs/slips/blob/master/slip-0132.md
-    fn from_extended_public_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPublicKeyError> {
-        match prefix[0..4] {
-            [0x04, 0x35, 0x87, 0xCF] | [0x04, 0x4A, 0x52, 0x62] => Ok(Self),
-            _ => Err(ExtendedPublicKeyError::InvalidVersionBytes(prefix.to_vec())),
-        }
-    }
 }
 
 impl FromStr for Testnet {
@@ -0,0 +1,476 @@
+//!
+//! BIP-47 Payment Codes
+//!
+//! A payment code is the public key and chain code of the BIP-32 node m/47'/coin'/account',
+//! which a payee shares once instead of a static address (https://github.com/bitcoin/bips/blob/master/bip-0047.mediawiki).
+//!
+//! A payer notifies the payee of its own payment code with a notification transaction to the notification address
+//! of the payee, whose OP_RETURN output holds the payment code of the payer blinded with a key only the payee can derive.
+//! Both then derive the same sequence of deposit addresses from a shared secret of the notification key of the payer
+//! and the i-th child key of the payee, which no one else can link to the payment codes.
+//!
+
+use crate::address::BitcoinAddress;
+use crate::extended_private_key::BitcoinExtendedPrivateKey;
+use crate::format::BitcoinFormat;
+use crate::network::BitcoinNetwork;
+use crate::private_key::BitcoinPrivateKey;
+use crate::public_key::BitcoinPublicKey;
+use wagyu_model::no_std::*;
+use wagyu_model::{
+    encoding::base58check, Address, AddressError, ChildIndex, DerivationPathError, ExtendedPrivateKey,
+    ExtendedPrivateKeyError, PrivateKey,
+};
+
+use core::{fmt, str::FromStr};
+use hmac::{Hmac, Mac};
+use secp256k1::{PublicKey, SecretKey};
+use sha2::{Digest, Sha256, Sha512};
+
+type HmacSha512 = Hmac<Sha512>;
+
+/// The version byte of a base58check encoded payment code, which encodes to the prefix `P`
+pub const PAYMENT_CODE_VERSION_BYTE: u8 = 0x47;
+/// The version of a payment code, as defined by BIP-47
+pub const PAYMENT_CODE_VERSION: u8 = 0x01;
+/// The BIP-43 purpose of the derivation path of a payment code
+pub const PAYMENT_CODE_PURPOSE: u32 = 47;
+
+/// The length of the binary payload of a payment code, in bytes
+const PAYLOAD_LENGTH: usize = 80;
+
+#[derive(Debug, Fail)]
+pub enum PaymentCodeError {
+    #[fail(display = "{}: {}", _0, _1)]
+    Crate(&'static str, String),
+
+    #[fail(display = "{}", _0)]
+    AddressError(AddressError),
+
+    #[fail(display = "{}", _0)]
+    DerivationPathError(DerivationPathError),
+
+    #[fail(display = "{}", _0)]
+    ExtendedPrivateKeyError(ExtendedPrivateKeyError),
+
+    #[fail(display = "invalid payment code byte length: {}", _0)]
+    InvalidByteLength(usize),
+
+    #[fail(display = "invalid payment code: {}", _0)]
+    InvalidPaymentCode(String),
+
+    #[fail(display = "invalid payment code version byte: {}", _0)]
+    InvalidVersionByte(u8),
+
+    #[fail(display = "shared secret is not a valid secp256k1 scalar")]
+    InvalidSharedSecret,
+
+    #[fail(display = "unsupported payment code version: {}", _0)]
+    UnsupportedVersion(u8),
+}
+
+impl From<AddressError> for PaymentCodeError {
+    fn from(error: AddressError) -> Self {
+        PaymentCodeError::AddressError(error)
+    }
+}
+
+impl From<ExtendedPrivateKeyError> for PaymentCodeError {
+    fn from(error: ExtendedPrivateKeyError) -> Self {
+        PaymentCodeError::ExtendedPrivateKeyError(error)
+    }
+}
+
+impl From<DerivationPathError> for PaymentCodeError {
+    fn from(error: DerivationPathError) -> Self {
+        PaymentCodeError::DerivationPathError(error)
+    }
+}
+
+impl From<secp256k1::Error> for PaymentCodeError {
+    fn from(error: secp256k1::Error) -> Self {
+        PaymentCodeError::Crate("libsecp256k1", format!("{:?}", error))
+    }
+}
+
+/// Represents a BIP-47 version 1 payment code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitcoinPaymentCode<N: BitcoinNetwork> {
+    /// The compressed public key of the payment code node
+    public_key: BitcoinPublicKey<N>,
+    /// The chain code of the payment code node
+    chain_code: [u8; 32],
+}
+
+impl<N: BitcoinNetwork> BitcoinPaymentCode<N> {
+    /// Returns the payment code node m/47'/coin'/account' of the given master extended private key.
+    pub fn derive_node(
+        master: &BitcoinExtendedPrivateKey<N>,
+        account: u32,
+    ) -> Result<BitcoinExtendedPrivateKey<N>, PaymentCodeError> {
+        Ok(master
+            .derive_child(ChildIndex::hardened(PAYMENT_CODE_PURPOSE)?)?
+            .derive_child(N::HD_COIN_TYPE)?
+            .derive_child(ChildIndex::hardened(account)?)?)
+    }
+
+    /// Returns the payment code of the given payment code node, as derived by `derive_node`.
+    pub fn from_extended_private_key(node: &BitcoinExtendedPrivateKey<N>) -> Self {
+        Self {
+            public_key: node.to_public_key(),
+            chain_code: node.chain_code,
+        }
+    }
+
+    /// Returns the payment code of the given 80-byte binary payload.
+    pub fn from_payload(payload: &[u8]) -> Result<Self, PaymentCodeError> {
+        if payload.len() != PAYLOAD_LENGTH {
+            return Err(PaymentCodeError::InvalidByteLength(payload.len()));
+        }
+        if payload[0] != PAYMENT_CODE_VERSION {
+            return Err(PaymentCodeError::UnsupportedVersion(payload[0]));
+        }
+        if payload[2] != 0x02 && payload[2] != 0x03 {
+            return Err(PaymentCodeError::InvalidPaymentCode(format!(
+                "invalid public key sign byte {}",
+                payload[2]
+            )));
+        }
+
+        let public_key = PublicKey::parse_slice(&payload[2..35], None)?;
+        let mut chain_code = [0u8; 32];
+        chain_code.copy_from_slice(&payload[35..67]);
+
+        Ok(Self {
+            public_key: BitcoinPublicKey::from_secp256k1_public_key(public_key, true),
+            chain_code,
+        })
+    }
+
+    /// Returns the 80-byte binary payload of the payment code: its version, a features byte,
+    /// its compressed public key and chain code, and 13 reserved bytes.
+    pub fn to_payload(&self) -> [u8; PAYLOAD_LENGTH] {
+        let mut payload = [0u8; PAYLOAD_LENGTH];
+        payload[0] = PAYMENT_CODE_VERSION;
+        payload[2..35].copy_from_slice(&self.public_key.to_secp256k1_public_key().serialize_compressed());
+        payload[35..67].copy_from_slice(&self.chain_code);
+        payload
+    }
+
+    /// Returns the compressed public key of the payment code.
+    pub fn to_public_key(&self) -> BitcoinPublicKey<N> {
+        self.public_key.clone()
+    }
+
+    /// Returns the chain code of the payment code.
+    pub fn to_chain_code(&self) -> [u8; 32] {
+        self.chain_code
+    }
+
+    /// Returns the public key of the non-hardened child of the payment code with the given index.
+    pub fn to_child_public_key(&self, index: u32) -> Result<BitcoinPublicKey<N>, PaymentCodeError> {
+        let public_key = self.public_key.to_secp256k1_public_key();
+
+        // HMAC-SHA512(Key = c, Data = serP(K) || ser32(i))
+        let mut mac = HmacSha512::new_varkey(&self.chain_code).map_err(ExtendedPrivateKeyError::from)?;
+        mac.input(&public_key.serialize_compressed());
+        mac.input(&u32::from(ChildIndex::normal(index)?).to_be_bytes());
+        let hmac = mac.result().code();
+
+        let mut child = public_key;
+        child.tweak_add_assign(&SecretKey::parse_slice(&hmac[..32])?)?;
+        Ok(BitcoinPublicKey::from_secp256k1_public_key(child, true))
+    }
+
+    /// Returns the notification address of the payment code, the P2PKH address of its first child.
+    pub fn to_notification_address(&self) -> Result<BitcoinAddress<N>, PaymentCodeError> {
+        Ok(BitcoinAddress::p2pkh(&self.to_child_public_key(0)?)?)
+    }
+
+    /// Returns the address of the given format to which the owner of the given payment code node
+    /// sends its payment with the given index to this payment code, the payee.
+    pub fn to_send_address(
+        &self,
+        node: &BitcoinExtendedPrivateKey<N>,
+        index: u32,
+        format: &BitcoinFormat,
+    ) -> Result<BitcoinAddress<N>, PaymentCodeError> {
+        // B' = B + s * G, for s = SHA256(Sx) and S = a * B
+        let notification_key = node.derive_child(ChildIndex::normal(0)?)?.to_private_key();
+        let public_key = self.to_child_public_key(index)?.to_secp256k1_public_key();
+        let shared_secret = Self::shared_secret(&public_key, &notification_key.to_secp256k1_secret_key())?;
+
+        let mut send_public_key = public_key;
+        send_public_key.tweak_add_assign(&shared_secret)?;
+        Ok(BitcoinAddress::from_public_key(
+            &BitcoinPublicKey::from_secp256k1_public_key(send_public_key, true),
+            format,
+        )?)
+    }
+
+    /// Returns the private key with which the owner of the given payment code node
+    /// receives the payment with the given index from this payment code, the payer.
+    pub fn to_receive_private_key(
+        &self,
+        node: &BitcoinExtendedPrivateKey<N>,
+        index: u32,
+    ) -> Result<BitcoinPrivateKey<N>, PaymentCodeError> {
+        // b' = b + s, for s = SHA256(Sx) and S = b * A
+        let private_key = node
+            .derive_child(ChildIndex::normal(index)?)?
+            .to_private_key()
+            .to_secp256k1_secret_key();
+        let notification_public_key = self.to_child_public_key(0)?.to_secp256k1_public_key();
+        let shared_secret = Self::shared_secret(&notification_public_key, &private_key)?;
+
+        let mut receive_private_key = private_key;
+        receive_private_key.tweak_add_assign(&shared_secret)?;
+        Ok(BitcoinPrivateKey::from_secp256k1_secret_key(&receive_private_key, true))
+    }
+
+    /// Returns the address of the given format with which the owner of the given payment code node
+    /// receives the payment with the given index from this payment code, the payer.
+    pub fn to_receive_address(
+        &self,
+        node: &BitcoinExtendedPrivateKey<N>,
+        index: u32,
+        format: &BitcoinFormat,
+    ) -> Result<BitcoinAddress<N>, PaymentCodeError> {
+        Ok(self.to_receive_private_key(node, index)?.to_address(format)?)
+    }
+
+    /// Returns the payload of the OP_RETURN output of a notification transaction from this payment code
+    /// to the given payee, spending the given outpoint (a transaction id in little-endian and a 4-byte
+    /// little-endian index) with the given private key. The public key and chain code of the payload
+    /// are blinded with HMAC-SHA512(Key = outpoint, Data = x), for the shared secret point x * G = a * B.
+    pub fn to_blinded_payload(
+        &self,
+        payee: &BitcoinPaymentCode<N>,
+        outpoint: &[u8; 36],
+        private_key: &BitcoinPrivateKey<N>,
+    ) -> Result<[u8; PAYLOAD_LENGTH], PaymentCodeError> {
+        let notification_public_key = payee.to_child_public_key(0)?.to_secp256k1_public_key();
+        let mask = Self::blinding_mask(
+            &notification_public_key,
+            &private_key.to_secp256k1_secret_key(),
+            outpoint,
+        )?;
+
+        let mut payload = self.to_payload();
+        payload[3..67]
+            .iter_mut()
+            .zip(mask.iter())
+            .for_each(|(byte, mask)| *byte ^= mask);
+        Ok(payload)
+    }
+
+    /// Returns the payment code of the payer of a notification transaction to the given payment code node,
+    /// given the payload of its OP_RETURN output, and the outpoint and public key of the input which blinded it.
+    pub fn from_blinded_payload(
+        payload: &[u8],
+        node: &BitcoinExtendedPrivateKey<N>,
+        outpoint: &[u8; 36],
+        public_key: &BitcoinPublicKey<N>,
+    ) -> Result<Self, PaymentCodeError> {
+        if payload.len() != PAYLOAD_LENGTH {
+            return Err(PaymentCodeError::InvalidByteLength(payload.len()));
+        }
+        let notification_key = node.derive_child(ChildIndex::normal(0)?)?.to_private_key();
+        let mask = Self::blinding_mask(
+            &public_key.to_secp256k1_public_key(),
+            &notification_key.to_secp256k1_secret_key(),
+            outpoint,
+        )?;
+
+        let mut payload = payload.to_vec();
+        payload[3..67]
+            .iter_mut()
+            .zip(mask.iter())
+            .for_each(|(byte, mask)| *byte ^= mask);
+        Self::from_payload(&payload)
+    }
+
+    /// Returns the x coordinate of the shared secret point of the given public and secret key.
+    fn shared_secret_point(public_key: &PublicKey, secret_key: &SecretKey) -> Result<[u8; 32], PaymentCodeError> {
+        let mut point = public_key.clone();
+        point.tweak_mul_assign(secret_key)?;
+
+        let mut x = [0u8; 32];
+        x.copy_from_slice(&point.serialize_compressed()[1..33]);
+        Ok(x)
+    }
+
+    /// Returns the shared secret s = SHA256(Sx) of the given public and secret key, as a scalar.
+    fn shared_secret(public_key: &PublicKey, secret_key: &SecretKey) -> Result<SecretKey, PaymentCodeError> {
+        let x = Self::shared_secret_point(public_key, secret_key)?;
+        SecretKey::parse_slice(&Sha256::digest(&x)).map_err(|_| PaymentCodeError::InvalidSharedSecret)
+    }
+
+    /// Returns the blinding mask of a notification transaction, HMAC-SHA512(Key = outpoint, Data = x).
+    fn blinding_mask(
+        public_key: &PublicKey,
+        secret_key: &SecretKey,
+        outpoint: &[u8; 36],
+    ) -> Result<Vec<u8>, PaymentCodeError> {
+        let x = Self::shared_secret_point(public_key, secret_key)?;
+        let mut mac = HmacSha512::new_varkey(outpoint).map_err(ExtendedPrivateKeyError::from)?;
+        mac.input(&x);
+        Ok(mac.result().code().to_vec())
+    }
+}
+
+impl<N: BitcoinNetwork> FromStr for BitcoinPaymentCode<N> {
+    type Err = PaymentCodeError;
+
+    fn from_str(payment_code: &str) -> Result<Self, Self::Err> {
+        let data = base58check::decode(payment_code)
+            .map_err(|error| PaymentCodeError::InvalidPaymentCode(error.to_string()))?;
+        match data.first() {
+            Some(&PAYMENT_CODE_VERSION_BYTE) => Self::from_payload(&data[1..]),
+            Some(version_byte) => Err(PaymentCodeError::InvalidVersionByte(*version_byte)),
+            None => Err(PaymentCodeError::InvalidByteLength(0)),
+        }
+    }
+}
+
+impl<N: BitcoinNetwork> fmt::Display for BitcoinPaymentCode<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut data = vec![PAYMENT_CODE_VERSION_BYTE];
+        data.extend_from_slice(&self.to_payload());
+        write!(f, "{}", base58check::encode(&data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mnemonic::BitcoinMnemonic;
+    use crate::network::Mainnet;
+    use crate::wordlist::English;
+    use wagyu_model::{Mnemonic, MnemonicExtended};
+
+    type N = Mainnet;
+
+    // BIP-47 test vectors
+    const ALICE_MNEMONIC: &str = "response seminar brave tip suit recall often sound stick owner lottery motion";
+    const ALICE_PAYMENT_CODE: &str = "PM8TJTLJbPRGxSbc8EJi42Wrr6QbNSaSSVJ5Y3E4pbCYiTHUskHg13935Ubb7q8tx9GVbh2UuRnBc3WSyJHhUrw8KhprKnn9eDznYGieTzFcwQRya4GA";
+    const ALICE_NOTIFICATION_ADDRESS: &str = "1JDdmqFLhpzcUwPeinhJbUPw4Co3aWLyzW";
+    const BOB_MNEMONIC: &str = "reward upper indicate eight swift arch injury crystal super wrestle already dentist";
+    const BOB_PAYMENT_CODE: &str = "PM8TJS2JxQ5ztXUpBBRnpTbcUXbUHy2T1abfrb3KkAAtMEGNbey4oumH7Hc578WgQJhPjBxteQ5GHHToTYHE3A1w6p7tU6KSoFmWBVbFGjKPisZDbP97";
+    const BOB_NOTIFICATION_ADDRESS: &str = "1ChvUUvht2hUQufHBXF8NgLhW8SwE2ecGV";
+    const ALICE_TO_BOB_ADDRESSES: [&str; 10] = [
+        "141fi7TY3h936vRUKh1qfUZr8rSBuYbVBK",
+        "12u3Uued2fuko2nY4SoSFGCoGLCBUGPkk6",
+        "1FsBVhT5dQutGwaPePTYMe5qvYqqjxyftc",
+        "1CZAmrbKL6fJ7wUxb99aETwXhcGeG3CpeA",
+        "1KQvRShk6NqPfpr4Ehd53XUhpemBXtJPTL",
+        "1KsLV2F47JAe6f8RtwzfqhjVa8mZEnTM7t",
+        "1DdK9TknVwvBrJe7urqFmaxEtGF2TMWxzD",
+        "16DpovNuhQJH7JUSZQFLBQgQYS4QB9Wy8e",
+        "17qK2RPGZMDcci2BLQ6Ry2PDGJErrNojT5",
+        "1GxfdfP286uE24qLZ9YRP3EWk2urqXgC4s",
+    ];
+
+    fn node(mnemonic: &str) -> BitcoinExtendedPrivateKey<N> {
+        let mnemonic = BitcoinMnemonic::<N, English>::from_phrase(mnemonic).unwrap();
+        let master = mnemonic.to_extended_private_key(None).unwrap();
+        BitcoinPaymentCode::derive_node(&master, 0).unwrap()
+    }
+
+    #[test]
+    fn payment_codes() {
+        for (mnemonic, payment_code, notification_address) in &[
+            (ALICE_MNEMONIC, ALICE_PAYMENT_CODE, ALICE_NOTIFICATION_ADDRESS),
+            (BOB_MNEMONIC, BOB_PAYMENT_CODE, BOB_NOTIFICATION_ADDRESS),
+        ] {
+            let derived = BitcoinPaymentCode::from_extended_private_key(&node(mnemonic));
+            assert_eq!(*payment_code, derived.to_string());
+
+            let parsed = BitcoinPaymentCode::<N>::from_str(payment_code).unwrap();
+            assert_eq!(derived, parsed);
+            assert_eq!(
+                *notification_address,
+                parsed.to_notification_address().unwrap().to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn deposit_addresses() {
+        let alice = node(ALICE_MNEMONIC);
+        let bob = node(BOB_MNEMONIC);
+        let alice_payment_code = BitcoinPaymentCode::from_extended_private_key(&alice);
+        let bob_payment_code = BitcoinPaymentCode::<N>::from_str(BOB_PAYMENT_CODE).unwrap();
+
+        for (index, expected) in ALICE_TO_BOB_ADDRESSES.iter().enumerate() {
+            let index = index as u32;
+            let sent = bob_payment_code
+                .to_send_address(&alice, index, &BitcoinFormat::P2PKH)
+                .unwrap();
+            let received = alice_payment_code
+                .to_receive_address(&bob, index, &BitcoinFormat::P2PKH)
+                .unwrap();
+            assert_eq!(*expected, sent.to_string());
+            assert_eq!(sent, received);
+        }
+
+        // Addresses of other formats are derived from the same keys
+        assert_eq!(
+            bob_payment_code
+                .to_send_address(&alice, 0, &BitcoinFormat::Bech32)
+                .unwrap(),
+            alice_payment_code
+                .to_receive_address(&bob, 0, &BitcoinFormat::Bech32)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn notification_payload() {
+        let alice = node(ALICE_MNEMONIC);
+        let bob = node(BOB_MNEMONIC);
+        let alice_payment_code = BitcoinPaymentCode::from_extended_private_key(&alice);
+        let bob_payment_code = BitcoinPaymentCode::from_extended_private_key(&bob);
+
+        let mut outpoint = [0u8; 36];
+        outpoint.copy_from_slice(
+            &hex::decode("86f411ab1c8e70ae8a0795ab7a6757aea6e4d5ae1826fc7b8f00c597d500609c01000000").unwrap(),
+        );
+        let private_key =
+            BitcoinPrivateKey::<N>::from_str("Kx983SRhAZpAhj7Aac1wUXMJ6XZeyJKqCxJJ49dxEbYCT4a1ozRD").unwrap();
+
+        let payload = alice_payment_code
+            .to_blinded_payload(&bob_payment_code, &outpoint, &private_key)
+            .unwrap();
+        assert_eq!(
+            "010002063e4eb95e62791b06c50e1a3a942e1ecaaa9afbbeb324d16ae6821e091611fa96c0cf048f607fe51a0327f5e2528979311c78cb2de0d682c61e1180fc3d543b00000000000000000000000000",
+            hex::encode(&payload[..])
+        );
+
+        let unblinded =
+            BitcoinPaymentCode::from_blinded_payload(&payload, &bob, &outpoint, &private_key.to_public_key()).unwrap();
+        assert_eq!(alice_payment_code, unblinded);
+    }
+
+    #[test]
+    fn invalid_payment_codes() {
+        let payment_code = BitcoinPaymentCode::<N>::from_str(ALICE_PAYMENT_CODE).unwrap();
+
+        let mut payload = payment_code.to_payload();
+        payload[0] = 0x02;
+        assert!(BitcoinPaymentCode::<N>::from_payload(&payload).is_err());
+
+        let mut payload = payment_code.to_payload();
+        payload[2] = 0x04;
+        assert!(BitcoinPaymentCode::<N>::from_payload(&payload).is_err());
+        assert!(BitcoinPaymentCode::<N>::from_payload(&payload[..79]).is_err());
+
+        // An extended public key is not a payment code
+        assert!(BitcoinPaymentCode::<N>::from_str(
+            "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8"
+        )
+        .is_err());
+        // A mistyped payment code
+        assert!(BitcoinPaymentCode::<N>::from_str(&ALICE_PAYMENT_CODE.replace('J', "K")).is_err());
+    }
+}
@@ -0,0 +1,281 @@
+use crate::coin_selection::output_vsize;
+use crate::network::BitcoinNetwork;
+use crate::transaction::{BitcoinTransaction, BitcoinTransactionOutput, Opcode};
+use wagyu_model::{Transaction, TransactionError};
+
+/// The fee rate of the dust limits of Bitcoin Core, in satoshi per vbyte
+pub const DUST_RELAY_FEE_RATE: u64 = 3;
+/// The maximum size of a null data script relayed by Bitcoin Core, in bytes
+/// (80 bytes of data, with OP_RETURN and its push opcodes)
+pub const MAX_NULL_DATA_SIZE: usize = 83;
+
+/// Represents the type of a transaction output, from the template of its public key script
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputType {
+    /// Pay-to-Pubkey Hash, `OP_DUP OP_HASH160 <20 bytes> OP_EQUALVERIFY OP_CHECKSIG`
+    P2PKH,
+    /// Pay-to-Script Hash, `OP_HASH160 <20 bytes> OP_EQUAL`
+    P2SH,
+    /// Pay-to-Witness-Public-Key Hash, `OP_0 <20 bytes>`
+    P2WPKH,
+    /// Pay-to-Witness-Script Hash, `OP_0 <32 bytes>`
+    P2WSH,
+    /// Pay-to-Taproot, `OP_1 <32 bytes>`
+    P2TR,
+    /// Null data, `OP_RETURN <data>`, which is provably unspendable
+    NullData,
+    /// Any other script
+    NonStandard,
+}
+
+impl OutputType {
+    /// Returns the type of an output with the given public key script.
+    pub fn from_script_pub_key(script: &[u8]) -> Self {
+        match script {
+            [0x76, 0xa9, 0x14, .., 0x88, 0xac] if script.len() == 25 => OutputType::P2PKH,
+            [0xa9, 0x14, .., 0x87] if script.len() == 23 => OutputType::P2SH,
+            [0x00, 0x14, ..] if script.len() == 22 => OutputType::P2WPKH,
+            [0x00, 0x20, ..] if script.len() == 34 => OutputType::P2WSH,
+            [0x51, 0x20, ..] if script.len() == 34 => OutputType::P2TR,
+            [opcode, ..] if *opcode == Opcode::OP_RETURN as u8 => OutputType::NullData,
+            _ => OutputType::NonStandard,
+        }
+    }
+}
+
+/// Represents the dust limit of each type of output, in satoshi,
+/// below which an output costs more to spend than it is worth
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DustLimits {
+    pub p2pkh: i64,
+    pub p2sh: i64,
+    pub p2wpkh: i64,
+    pub p2wsh: i64,
+    pub p2tr: i64,
+}
+
+impl DustLimits {
+    /// Returns the dust limits at the given fee rate, in satoshi per vbyte, as the fee to create
+    /// and spend an output of each type, as Bitcoin Core.
+    pub fn from_fee_rate(fee_rate: u64) -> Result<Self, TransactionError> {
+        // The virtual size of an input spending a witness program, or any other output
+        let (witness_input, input) = (67, 148);
+        let limit = |script_pub_key_length: usize, input_vsize: u64| -> Result<i64, TransactionError> {
+            Ok(((output_vsize(script_pub_key_length)? + input_vsize) * fee_rate) as i64)
+        };
+        Ok(Self {
+            p2pkh: limit(25, input)?,
+            p2sh: limit(23, input)?,
+            p2wpkh: limit(22, witness_input)?,
+            p2wsh: limit(34, witness_input)?,
+            p2tr: limit(34, witness_input)?,
+        })
+    }
+
+    /// Returns the dust limit of an output of the given type, or `None` for null data.
+    /// Outputs of nonstandard types are held to the dust limit of P2PKH, the highest.
+    pub fn to_limit(&self, output_type: OutputType) -> Option<i64> {
+        match output_type {
+            OutputType::P2PKH | OutputType::NonStandard => Some(self.p2pkh),
+            OutputType::P2SH => Some(self.p2sh),
+            OutputType::P2WPKH => Some(self.p2wpkh),
+            OutputType::P2WSH => Some(self.p2wsh),
+            OutputType::P2TR => Some(self.p2tr),
+            OutputType::NullData => None,
+        }
+    }
+}
+
+impl Default for DustLimits {
+    /// Returns the dust limits of Bitcoin Core (546 satoshi for P2PKH, 294 satoshi for P2WPKH, 330 satoshi for P2TR).
+    fn default() -> Self {
+        Self::from_fee_rate(DUST_RELAY_FEE_RATE).expect("the dust limits of Bitcoin Core are valid")
+    }
+}
+
+/// Represents the policy a transaction is checked against before it is constructed,
+/// to refuse outputs that would not be relayed, or a fee that is likely a mistake
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitcoinTransactionPolicy {
+    /// The dust limit of each type of output
+    pub dust_limits: DustLimits,
+    /// The maximum size of the public key script of a null data output, in bytes
+    pub max_null_data_size: usize,
+    /// The maximum fee, as a percentage of the amount of the outputs, if any
+    pub max_fee_percentage: Option<u64>,
+}
+
+impl Default for BitcoinTransactionPolicy {
+    /// Returns the relay policy of Bitcoin Core, without a maximum fee.
+    fn default() -> Self {
+        Self {
+            dust_limits: DustLimits::default(),
+            max_null_data_size: MAX_NULL_DATA_SIZE,
+            max_fee_percentage: None,
+        }
+    }
+}
+
+impl BitcoinTransactionPolicy {
+    /// Returns the policy with the given dust limits.
+    pub fn with_dust_limits(mut self, dust_limits: DustLimits) -> Self {
+        self.dust_limits = dust_limits;
+        self
+    }
+
+    /// Returns the policy with the given maximum size of a null data script, in bytes.
+    pub fn with_max_null_data_size(mut self, max_null_data_size: usize) -> Self {
+        self.max_null_data_size = max_null_data_size;
+        self
+    }
+
+    /// Returns the policy with the given maximum fee, as a percentage of the amount of the outputs.
+    pub fn with_max_fee_percentage(mut self, max_fee_percentage: u64) -> Self {
+        self.max_fee_percentage = Some(max_fee_percentage);
+        self
+    }
+
+    /// Checks the given outputs against the dust limits and the maximum size of a null data script.
+    pub fn check_outputs(&self, outputs: &[BitcoinTransactionOutput]) -> Result<(), TransactionError> {
+        for (index, output) in outputs.iter().enumerate() {
+            let output_type = OutputType::from_script_pub_key(&output.script_pub_key);
+            match self.dust_limits.to_limit(output_type) {
+                Some(limit) if output.amount.0 < limit => {
+                    return Err(TransactionError::DustOutput(index, output.amount.0, limit))
+                }
+                None if output.script_pub_key.len() > self.max_null_data_size => {
+                    return Err(TransactionError::OversizedNullData(
+                        index,
+                        output.script_pub_key.len(),
+                        self.max_null_data_size,
+                    ))
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the given fee against the maximum fee, as a percentage of the amount of the given outputs.
+    pub fn check_fee(&self, fee: i64, outputs: &[BitcoinTransactionOutput]) -> Result<(), TransactionError> {
+        if let Some(max_fee_percentage) = self.max_fee_percentage {
+            let amount = outputs.iter().map(|output| output.amount.0).sum::<i64>();
+            if fee as i128 * 100 > amount as i128 * max_fee_percentage as i128 {
+                return Err(TransactionError::ExcessiveFee(fee, amount, max_fee_percentage));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks the outputs and the fee of the given transaction against the policy.
+    /// The amounts of the inputs must be known to check the fee, if there is a maximum fee.
+    pub fn check<N: BitcoinNetwork>(&self, transaction: &BitcoinTransaction<N>) -> Result<(), TransactionError> {
+        let outputs = &transaction.parameters().outputs;
+        self.check_outputs(outputs)?;
+        if self.max_fee_percentage.is_some() {
+            match transaction.fee() {
+                Some(fee) => self.check_fee(fee.0, outputs)?,
+                None => return Err(TransactionError::MissingOutpointAmount),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::amount::BitcoinAmount;
+
+    fn output(amount: i64, script_pub_key: &str) -> BitcoinTransactionOutput {
+        BitcoinTransactionOutput {
+            amount: BitcoinAmount::from_satoshi(amount).unwrap(),
+            script_pub_key: hex::decode(script_pub_key).unwrap(),
+        }
+    }
+
+    const P2PKH: &str = "76a914751e76e8199196d454941c45d1b3a323f1433bd688ac";
+    const P2WPKH: &str = "0014751e76e8199196d454941c45d1b3a323f1433bd6";
+    const P2TR: &str = "5120a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c";
+
+    #[test]
+    fn output_type() {
+        assert_eq!(
+            OutputType::P2PKH,
+            OutputType::from_script_pub_key(&hex::decode(P2PKH).unwrap())
+        );
+        assert_eq!(
+            OutputType::P2SH,
+            OutputType::from_script_pub_key(&hex::decode("a914751e76e8199196d454941c45d1b3a323f1433bd687").unwrap())
+        );
+        assert_eq!(
+            OutputType::P2WPKH,
+            OutputType::from_script_pub_key(&hex::decode(P2WPKH).unwrap())
+        );
+        assert_eq!(
+            OutputType::P2TR,
+            OutputType::from_script_pub_key(&hex::decode(P2TR).unwrap())
+        );
+        assert_eq!(
+            OutputType::NullData,
+            OutputType::from_script_pub_key(&[0x6a, 0x01, 0x00])
+        );
+        assert_eq!(OutputType::NonStandard, OutputType::from_script_pub_key(&[0x51]));
+    }
+
+    #[test]
+    fn dust_limits() {
+        let limits = DustLimits::default();
+        assert_eq!(
+            (546, 540, 294, 330, 330),
+            (limits.p2pkh, limits.p2sh, limits.p2wpkh, limits.p2wsh, limits.p2tr)
+        );
+
+        let policy = BitcoinTransactionPolicy::default();
+        assert!(policy
+            .check_outputs(&[output(546, P2PKH), output(294, P2WPKH), output(330, P2TR)])
+            .is_ok());
+        assert!(matches!(
+            policy.check_outputs(&[output(546, P2PKH), output(293, P2WPKH)]),
+            Err(TransactionError::DustOutput(1, 293, 294))
+        ));
+        assert!(policy.check_outputs(&[output(329, P2TR)]).is_err());
+
+        // The dust limits are configurable per type of output
+        let policy = policy.with_dust_limits(DustLimits { p2tr: 0, ..limits });
+        assert!(policy.check_outputs(&[output(1, P2TR)]).is_ok());
+        assert!(policy.check_outputs(&[output(545, P2PKH)]).is_err());
+    }
+
+    #[test]
+    fn null_data_size() {
+        let policy = BitcoinTransactionPolicy::default();
+        let data = |size: usize| [vec![0x6a, 0x4c, size as u8], vec![0u8; size]].concat();
+
+        let script = hex::encode(data(80));
+        assert!(policy.check_outputs(&[output(0, &script)]).is_ok());
+        let script = hex::encode(data(81));
+        assert!(matches!(
+            policy.check_outputs(&[output(0, &script)]),
+            Err(TransactionError::OversizedNullData(0, 84, 83))
+        ));
+        assert!(policy
+            .with_max_null_data_size(84)
+            .check_outputs(&[output(0, &script)])
+            .is_ok());
+    }
+
+    #[test]
+    fn max_fee_percentage() {
+        let outputs = [output(60_000, P2PKH), output(40_000, P2WPKH)];
+        let policy = BitcoinTransactionPolicy::default();
+        assert!(policy.check_fee(1_000_000, &outputs).is_ok());
+
+        let policy = policy.with_max_fee_percentage(5);
+        assert!(policy.check_fee(5_000, &outputs).is_ok());
+        assert!(matches!(
+            policy.check_fee(5_001, &outputs),
+            Err(TransactionError::ExcessiveFee(5_001, 100_000, 5))
+        ));
+    }
+}
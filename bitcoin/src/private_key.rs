@@ -2,12 +2,13 @@ use crate::address::BitcoinAddress;
 use crate::format::BitcoinFormat;
 use crate::network::BitcoinNetwork;
 use crate::public_key::BitcoinPublicKey;
-use wagyu_model::{crypto::checksum, Address, AddressError, PrivateKey, PrivateKeyError, PublicKey};
+use wagyu_model::{encoding::base58check, Address, AddressError, PrivateKey, PrivateKeyError, PublicKey};
 
-use base58::{FromBase58, ToBase58};
+use base58::FromBase58;
 use core::{fmt, fmt::Display, marker::PhantomData, str::FromStr};
 use rand::Rng;
 use secp256k1;
+use zeroize::Zeroize;
 
 /// Represents a Bitcoin private key
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -55,11 +56,25 @@ impl<N: BitcoinNetwork> BitcoinPrivateKey<N> {
         }
     }
 
+    /// Returns a private key given the 32 bytes of a secp256k1 secret key.
+    pub fn from_secret_key_bytes(bytes: &[u8], compressed: bool) -> Result<Self, PrivateKeyError> {
+        Ok(Self {
+            secret_key: secp256k1::SecretKey::parse_slice(bytes)?,
+            compressed,
+            _network: PhantomData,
+        })
+    }
+
     /// Returns the secp256k1 secret key of the private key.
     pub fn to_secp256k1_secret_key(&self) -> secp256k1::SecretKey {
         self.secret_key.clone()
     }
 
+    /// Returns the 32 bytes of the secp256k1 secret key of the private key.
+    pub fn to_secret_key_bytes(&self) -> [u8; 32] {
+        self.secret_key.serialize()
+    }
+
     /// Returns `true` if the private key is in compressed form.
     pub fn is_compressed(&self) -> bool {
         self.compressed
@@ -77,13 +92,7 @@ impl<N: BitcoinNetwork> FromStr for BitcoinPrivateKey<N> {
             return Err(PrivateKeyError::InvalidByteLength(len));
         }
 
-        let expected = &data[len - 4..len];
-        let checksum = &checksum(&data[0..len - 4])[0..4];
-        if *expected != *checksum {
-            let expected = expected.to_base58();
-            let found = checksum.to_base58();
-            return Err(PrivateKeyError::InvalidChecksum(expected, found));
-        }
+        base58check::verify(&data)?;
 
         // Check that the wif prefix corresponds to the correct network.
         let _ = N::from_private_key_prefix(data[0])?;
@@ -98,25 +107,35 @@ impl<N: BitcoinNetwork> FromStr for BitcoinPrivateKey<N> {
 
 impl<N: BitcoinNetwork> Display for BitcoinPrivateKey<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut wif = [0u8; 38];
-        wif[0] = N::to_private_key_prefix();
+        let mut wif = [0u8; 34];
+        wif[0] = N::WIF_PREFIX;
         wif[1..33].copy_from_slice(&self.secret_key.serialize());
 
         let output = if self.compressed {
             wif[33] = 0x01;
-            let sum = &checksum(&wif[0..34])[0..4];
-            wif[34..].copy_from_slice(sum);
-            wif.to_base58()
+            base58check::encode(&wif)
         } else {
-            let sum = &checksum(&wif[0..33])[0..4];
-            wif[33..37].copy_from_slice(sum);
-            wif[..37].to_base58()
+            base58check::encode(&wif[..33])
         };
 
         write!(f, "{}", output)
     }
 }
 
+impl<N: BitcoinNetwork> Zeroize for BitcoinPrivateKey<N> {
+    /// Replaces the secret key with a placeholder, clearing the original secret key on drop.
+    fn zeroize(&mut self) {
+        self.secret_key = secp256k1::SecretKey::default();
+        self.compressed.zeroize();
+    }
+}
+
+impl<N: BitcoinNetwork> Drop for BitcoinPrivateKey<N> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -244,7 +263,7 @@ mod tests {
                         expected_public_key,
                         expected_address,
                         &BitcoinFormat::P2PKH,
-                        private_key.secret_key,
+                        private_key.secret_key.clone(),
                         true,
                     );
                 });
@@ -358,7 +377,7 @@ mod tests {
                         expected_public_key,
                         expected_address,
                         &BitcoinFormat::P2PKH,
-                        private_key.secret_key,
+                        private_key.secret_key.clone(),
                         false,
                     );
                 });
@@ -388,6 +407,19 @@ mod tests {
                 test_to_str(expected_private_key, &private_key);
             });
         }
+
+        #[test]
+        fn secret_key_bytes() {
+            KEYPAIRS.iter().for_each(|(expected_private_key, _, _)| {
+                let private_key = BitcoinPrivateKey::<N>::from_str(expected_private_key).unwrap();
+                let bytes = private_key.to_secret_key_bytes();
+                let uncompressed = BitcoinPrivateKey::<N>::from_secret_key_bytes(&bytes, false).unwrap();
+                let compressed = BitcoinPrivateKey::<N>::from_secret_key_bytes(&bytes, true).unwrap();
+                assert_eq!(*expected_private_key, uncompressed.to_string());
+                assert_eq!(private_key.secret_key, compressed.secret_key);
+                assert!(compressed.is_compressed());
+            });
+        }
     }
 
     mod p2pkh_testnet_compressed {
@@ -452,7 +484,7 @@ mod tests {
                         expected_public_key,
                         expected_address,
                         &BitcoinFormat::P2PKH,
-                        private_key.secret_key,
+                        private_key.secret_key.clone(),
                         true,
                     );
                 });
@@ -546,7 +578,7 @@ mod tests {
                         expected_public_key,
                         expected_address,
                         &BitcoinFormat::P2PKH,
-                        private_key.secret_key,
+                        private_key.secret_key.clone(),
                         false,
                     );
                 });
@@ -640,7 +672,7 @@ mod tests {
                         expected_public_key,
                         expected_address,
                         &BitcoinFormat::P2SH_P2WPKH,
-                        private_key.secret_key,
+                        private_key.secret_key.clone(),
                         true,
                     );
                 });
@@ -754,7 +786,7 @@ mod tests {
                         expected_public_key,
                         expected_address,
                         &BitcoinFormat::P2SH_P2WPKH,
-                        private_key.secret_key,
+                        private_key.secret_key.clone(),
                         true,
                     );
                 });
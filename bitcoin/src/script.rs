@@ -0,0 +1,238 @@
+use crate::policy::{OutputType, MAX_NULL_DATA_SIZE};
+use crate::transaction::Opcode;
+use wagyu_model::crypto::hash160;
+use wagyu_model::no_std::*;
+use wagyu_model::TransactionError;
+
+use core::{fmt, str::FromStr};
+use sha2::{Digest, Sha256};
+
+/// Represents a Bitcoin script, such as the public key script of an output.
+/// Any script may be paid to, including those compiled from miniscript, or built from the common templates.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Script(pub Vec<u8>);
+
+impl Script {
+    /// Returns the script of the given bytes, unchecked.
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the Pay-to-Pubkey Hash script of the given hash160 of a public key,
+    /// `OP_DUP OP_HASH160 <20 bytes> OP_EQUALVERIFY OP_CHECKSIG`.
+    pub fn p2pkh(public_key_hash: &[u8; 20]) -> Self {
+        Self::default()
+            .push_opcode(Opcode::OP_DUP)
+            .push_opcode(Opcode::OP_HASH160)
+            .push_slice(public_key_hash)
+            .push_opcode(Opcode::OP_EQUALVERIFY)
+            .push_opcode(Opcode::OP_CHECKSIG)
+    }
+
+    /// Returns the Pay-to-Script Hash script of the given hash160 of a redeem script, `OP_HASH160 <20 bytes> OP_EQUAL`.
+    pub fn p2sh(script_hash: &[u8; 20]) -> Self {
+        Self::default()
+            .push_opcode(Opcode::OP_HASH160)
+            .push_slice(script_hash)
+            .push_opcode(Opcode::OP_EQUAL)
+    }
+
+    /// Returns the Pay-to-Witness-Public-Key Hash script of the given hash160 of a public key, `OP_0 <20 bytes>`.
+    pub fn p2wpkh(public_key_hash: &[u8; 20]) -> Self {
+        Self::default().push_opcode(Opcode::OP_0).push_slice(public_key_hash)
+    }
+
+    /// Returns the Pay-to-Witness-Script Hash script of the given SHA-256 of a witness script, `OP_0 <32 bytes>`.
+    pub fn p2wsh(script_hash: &[u8; 32]) -> Self {
+        Self::default().push_opcode(Opcode::OP_0).push_slice(script_hash)
+    }
+
+    /// Returns the Pay-to-Taproot script of the given x-only output key, `OP_1 <32 bytes>`.
+    pub fn p2tr(output_key: &[u8; 32]) -> Self {
+        Self::default().push_opcode(Opcode::OP_1).push_slice(output_key)
+    }
+
+    /// Returns the null data script of the given data, of up to 80 bytes, `OP_RETURN <data>`.
+    /// Errors if the script exceeds the maximum size of a null data script relayed by Bitcoin Core.
+    pub fn null_data(data: &[u8]) -> Result<Self, TransactionError> {
+        let script = Self::default().push_opcode(Opcode::OP_RETURN);
+        let script = match data.is_empty() {
+            true => script,
+            false => script.push_slice(data),
+        };
+        match script.0.len() > MAX_NULL_DATA_SIZE {
+            true => Err(TransactionError::OversizedNullData(
+                0,
+                script.0.len(),
+                MAX_NULL_DATA_SIZE,
+            )),
+            false => Ok(script),
+        }
+    }
+
+    /// Returns the script with the given opcode appended.
+    pub fn push_opcode(mut self, opcode: Opcode) -> Self {
+        self.0.push(opcode as u8);
+        self
+    }
+
+    /// Returns the script with the given data, of up to 65535 bytes, appended with the smallest opcode that pushes it.
+    /// https://github.com/bitcoin/bips/blob/master/bip-0062.mediawiki#push-operators
+    pub fn push_slice(mut self, data: &[u8]) -> Self {
+        match data.len() {
+            0 => self.0.push(Opcode::OP_0 as u8),
+            length @ 1..=75 => self.0.push(length as u8),
+            length @ 76..=0xff => self.0.extend(&[Opcode::OP_PUSHDATA1 as u8, length as u8]),
+            length => {
+                self.0.push(Opcode::OP_PUSHDATA2 as u8);
+                self.0.extend(&(length as u16).to_le_bytes());
+            }
+        }
+        self.0.extend(data);
+        self
+    }
+
+    /// Returns the script with the opcodes of the given script appended.
+    pub fn push_script(mut self, script: &Script) -> Self {
+        self.0.extend(&script.0);
+        self
+    }
+
+    /// Returns the script with the given number appended, as a small integer opcode from 0 to 16,
+    /// or as the minimal push of its little-endian encoding, with a zero byte if its most significant bit is set.
+    pub fn push_int(mut self, number: u32) -> Self {
+        match number {
+            0 => self.push_opcode(Opcode::OP_0),
+            1..=16 => {
+                self.0.push(Opcode::OP_1 as u8 + number as u8 - 1);
+                self
+            }
+            _ => {
+                let mut bytes = number.to_le_bytes().to_vec();
+                while bytes.last() == Some(&0) {
+                    bytes.pop();
+                }
+                // The most significant bit of a script number is its sign
+                if bytes.last().map(|byte| byte & 0x80 != 0).unwrap_or(false) {
+                    bytes.push(0);
+                }
+                self.push_slice(&bytes)
+            }
+        }
+    }
+
+    /// Returns the Pay-to-Script Hash script paying to this script as its redeem script.
+    pub fn to_p2sh(&self) -> Self {
+        let mut script_hash = [0u8; 20];
+        script_hash.copy_from_slice(&hash160(&self.0));
+        Self::p2sh(&script_hash)
+    }
+
+    /// Returns the Pay-to-Witness-Script Hash script paying to this script as its witness script.
+    pub fn to_p2wsh(&self) -> Self {
+        let mut script_hash = [0u8; 32];
+        script_hash.copy_from_slice(&Sha256::digest(&self.0));
+        Self::p2wsh(&script_hash)
+    }
+
+    /// Returns the type of an output with this script as its public key script.
+    pub fn output_type(&self) -> OutputType {
+        OutputType::from_script_pub_key(&self.0)
+    }
+
+    /// Returns the bytes of the script.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for Script {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Script> for Vec<u8> {
+    fn from(script: Script) -> Self {
+        script.0
+    }
+}
+
+impl FromStr for Script {
+    type Err = TransactionError;
+
+    fn from_str(script: &str) -> Result<Self, Self::Err> {
+        Ok(Self(hex::decode(script)?))
+    }
+}
+
+impl fmt::Display for Script {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", hex::encode(&self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH: [u8; 20] = [
+        0x75, 0x1e, 0x76, 0xe8, 0x19, 0x91, 0x96, 0xd4, 0x54, 0x94, 0x1c, 0x45, 0xd1, 0xb3, 0xa3, 0x23, 0xf1, 0x43,
+        0x3b, 0xd6,
+    ];
+
+    #[test]
+    fn templates() {
+        let p2pkh = Script::p2pkh(&HASH);
+        assert_eq!("76a914751e76e8199196d454941c45d1b3a323f1433bd688ac", p2pkh.to_string());
+        assert_eq!(OutputType::P2PKH, p2pkh.output_type());
+        assert_eq!(
+            "a914751e76e8199196d454941c45d1b3a323f1433bd687",
+            Script::p2sh(&HASH).to_string()
+        );
+        assert_eq!(
+            "0014751e76e8199196d454941c45d1b3a323f1433bd6",
+            Script::p2wpkh(&HASH).to_string()
+        );
+        assert_eq!(OutputType::P2TR, Script::p2tr(&[0x55; 32]).output_type());
+        assert_eq!(OutputType::NullData, Script::null_data(b"wagyu").unwrap().output_type());
+    }
+
+    #[test]
+    fn to_p2wsh() {
+        // The witness script `OP_1`, which anyone can spend
+        let script = Script::default().push_opcode(Opcode::OP_1);
+        assert_eq!(
+            "00204ae81572f06e1b88fd5ced7a1a000945432e83e1551e6f721ee9c00b8cc33260",
+            script.to_p2wsh().to_string()
+        );
+        assert_eq!(OutputType::P2SH, script.to_p2sh().output_type());
+    }
+
+    #[test]
+    fn push_slice() {
+        assert_eq!(vec![0x00], Script::default().push_slice(&[]).0);
+        assert_eq!([0x4b], Script::default().push_slice(&[0u8; 75]).0[..1]);
+        assert_eq!([0x4c, 0x4c], Script::default().push_slice(&[0u8; 76]).0[..2]);
+        assert_eq!([0x4d, 0x00, 0x01], Script::default().push_slice(&[0u8; 256]).0[..3]);
+    }
+
+    #[test]
+    fn push_int() {
+        let encode = |number: u32| Script::default().push_int(number).to_string();
+        assert_eq!("00", encode(0));
+        assert_eq!("51", encode(1));
+        assert_eq!("60", encode(16));
+        assert_eq!("0111", encode(17));
+        assert_eq!("029000", encode(144));
+        assert_eq!("03ffff00", encode(65535));
+        assert_eq!("050000008000", encode(0x8000_0000));
+    }
+
+    #[test]
+    fn from_str() {
+        let script = Script::from_str("51").unwrap();
+        assert_eq!(Script::default().push_opcode(Opcode::OP_1), script);
+        assert!(Script::from_str("not hex").is_err());
+    }
+}
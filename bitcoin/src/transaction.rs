@@ -2,15 +2,17 @@ use crate::address::BitcoinAddress;
 use crate::amount::BitcoinAmount;
 use crate::format::BitcoinFormat;
 use crate::network::BitcoinNetwork;
+use crate::policy::BitcoinTransactionPolicy;
 use crate::private_key::BitcoinPrivateKey;
 use crate::public_key::BitcoinPublicKey;
+use crate::script::Script;
 use crate::witness_program::WitnessProgram;
 use wagyu_model::no_std::{io::Read, *};
-use wagyu_model::{PrivateKey, Transaction, TransactionError, TransactionId};
+use wagyu_model::{PrivateKey, PublicKey, Transaction, TransactionError, TransactionId};
 
 use base58::FromBase58;
 use bech32::{Bech32, FromBase32};
-use core::{fmt, str::FromStr};
+use core::{convert::TryFrom, fmt, str::FromStr};
 use secp256k1;
 use serde::Serialize;
 use sha2::{Digest, Sha256};
@@ -34,13 +36,13 @@ pub fn variable_length_integer(value: u64) -> Result<Vec<u8>, TransactionError>
 /// https://en.bitcoin.it/wiki/Protocol_documentation#Variable_length_integer
 pub fn read_variable_length_integer<R: Read>(mut reader: R) -> Result<usize, TransactionError> {
     let mut flag = [0u8; 1];
-    reader.read(&mut flag)?;
+    reader.read_exact(&mut flag)?;
 
     match flag[0] {
         0..=252 => Ok(flag[0] as usize),
         0xfd => {
             let mut size = [0u8; 2];
-            reader.read(&mut size)?;
+            reader.read_exact(&mut size)?;
             match u16::from_le_bytes(size) {
                 s if s < 253 => return Err(TransactionError::InvalidVariableSizeInteger(s as usize)),
                 s => Ok(s as usize),
@@ -48,7 +50,7 @@ pub fn read_variable_length_integer<R: Read>(mut reader: R) -> Result<usize, Tra
         }
         0xfe => {
             let mut size = [0u8; 4];
-            reader.read(&mut size)?;
+            reader.read_exact(&mut size)?;
             match u32::from_le_bytes(size) {
                 s if s < 65536 => return Err(TransactionError::InvalidVariableSizeInteger(s as usize)),
                 s => Ok(s as usize),
@@ -56,7 +58,7 @@ pub fn read_variable_length_integer<R: Read>(mut reader: R) -> Result<usize, Tra
         }
         _ => {
             let mut size = [0u8; 8];
-            reader.read(&mut size)?;
+            reader.read_exact(&mut size)?;
             match u64::from_le_bytes(size) {
                 s if s < 4294967296 => return Err(TransactionError::InvalidVariableSizeInteger(s as usize)),
                 s => Ok(s as usize),
@@ -194,21 +196,69 @@ impl SignatureHash {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
 #[allow(non_camel_case_types)]
 pub enum Opcode {
+    OP_0 = 0x00,
+    OP_1 = 0x51,
+    OP_PUSHDATA1 = 0x4c,
+    OP_PUSHDATA2 = 0x4d,
+    OP_IF = 0x63,
+    OP_NOTIF = 0x64,
+    OP_ELSE = 0x67,
+    OP_ENDIF = 0x68,
+    OP_VERIFY = 0x69,
+    OP_RETURN = 0x6a,
+    OP_TOALTSTACK = 0x6b,
+    OP_FROMALTSTACK = 0x6c,
+    OP_IFDUP = 0x73,
     OP_DUP = 0x76,
-    OP_HASH160 = 0xa9,
-    OP_CHECKSIG = 0xac,
+    OP_SIZE = 0x82,
     OP_EQUAL = 0x87,
     OP_EQUALVERIFY = 0x88,
+    OP_0NOTEQUAL = 0x92,
+    OP_ADD = 0x93,
+    OP_RIPEMD160 = 0xa6,
+    OP_SHA256 = 0xa8,
+    OP_HASH160 = 0xa9,
+    OP_HASH256 = 0xaa,
+    OP_CHECKSIG = 0xac,
+    OP_CHECKSIGVERIFY = 0xad,
+    OP_CHECKMULTISIG = 0xae,
+    OP_CHECKMULTISIGVERIFY = 0xaf,
+    OP_CHECKLOCKTIMEVERIFY = 0xb1,
+    OP_CHECKSEQUENCEVERIFY = 0xb2,
 }
 
 impl fmt::Display for Opcode {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            Opcode::OP_0 => write!(f, "OP_0"),
+            Opcode::OP_1 => write!(f, "OP_1"),
+            Opcode::OP_PUSHDATA1 => write!(f, "OP_PUSHDATA1"),
+            Opcode::OP_PUSHDATA2 => write!(f, "OP_PUSHDATA2"),
+            Opcode::OP_IF => write!(f, "OP_IF"),
+            Opcode::OP_NOTIF => write!(f, "OP_NOTIF"),
+            Opcode::OP_ELSE => write!(f, "OP_ELSE"),
+            Opcode::OP_ENDIF => write!(f, "OP_ENDIF"),
+            Opcode::OP_VERIFY => write!(f, "OP_VERIFY"),
+            Opcode::OP_RETURN => write!(f, "OP_RETURN"),
+            Opcode::OP_TOALTSTACK => write!(f, "OP_TOALTSTACK"),
+            Opcode::OP_FROMALTSTACK => write!(f, "OP_FROMALTSTACK"),
+            Opcode::OP_IFDUP => write!(f, "OP_IFDUP"),
             Opcode::OP_DUP => write!(f, "OP_DUP"),
-            Opcode::OP_HASH160 => write!(f, "OP_HASH160"),
-            Opcode::OP_CHECKSIG => write!(f, "OP_CHECKSIG"),
+            Opcode::OP_SIZE => write!(f, "OP_SIZE"),
             Opcode::OP_EQUAL => write!(f, "OP_EQUAL"),
             Opcode::OP_EQUALVERIFY => write!(f, "OP_EQUALVERIFY"),
+            Opcode::OP_0NOTEQUAL => write!(f, "OP_0NOTEQUAL"),
+            Opcode::OP_ADD => write!(f, "OP_ADD"),
+            Opcode::OP_RIPEMD160 => write!(f, "OP_RIPEMD160"),
+            Opcode::OP_SHA256 => write!(f, "OP_SHA256"),
+            Opcode::OP_HASH160 => write!(f, "OP_HASH160"),
+            Opcode::OP_HASH256 => write!(f, "OP_HASH256"),
+            Opcode::OP_CHECKSIG => write!(f, "OP_CHECKSIG"),
+            Opcode::OP_CHECKSIGVERIFY => write!(f, "OP_CHECKSIGVERIFY"),
+            Opcode::OP_CHECKMULTISIG => write!(f, "OP_CHECKMULTISIG"),
+            Opcode::OP_CHECKMULTISIGVERIFY => write!(f, "OP_CHECKMULTISIGVERIFY"),
+            Opcode::OP_CHECKLOCKTIMEVERIFY => write!(f, "OP_CHECKLOCKTIMEVERIFY"),
+            Opcode::OP_CHECKSEQUENCEVERIFY => write!(f, "OP_CHECKSEQUENCEVERIFY"),
         }
     }
 }
@@ -318,6 +368,10 @@ pub struct BitcoinTransactionInput<N: BitcoinNetwork> {
 
 impl<N: BitcoinNetwork> BitcoinTransactionInput<N> {
     const DEFAULT_SEQUENCE: [u8; 4] = [0xff, 0xff, 0xff, 0xff];
+    /// The sequence number that enables the lock time of the transaction, without signaling replaceability
+    pub const LOCK_TIME_SEQUENCE: u32 = 0xffff_fffe;
+    /// The sequence number that signals replaceability (BIP 125) and enables the lock time of the transaction
+    pub const REPLACEABLE_SEQUENCE: u32 = 0xffff_fffd;
 
     /// Returns a new Bitcoin transaction input without the script (unlocking).
     pub fn new(
@@ -366,8 +420,8 @@ impl<N: BitcoinNetwork> BitcoinTransactionInput<N> {
         let mut vin = [0u8; 4];
         let mut sequence = [0u8; 4];
 
-        reader.read(&mut transaction_hash)?;
-        reader.read(&mut vin)?;
+        reader.read_exact(&mut transaction_hash)?;
+        reader.read_exact(&mut vin)?;
 
         let outpoint = Outpoint::<N>::new(
             transaction_hash.to_vec(),
@@ -380,16 +434,22 @@ impl<N: BitcoinNetwork> BitcoinTransactionInput<N> {
 
         let script_sig: Vec<u8> = BitcoinVector::read(&mut reader, |s| {
             let mut byte = [0u8; 1];
-            s.read(&mut byte)?;
+            s.read_exact(&mut byte)?;
             Ok(byte[0])
         })?;
 
-        reader.read(&mut sequence)?;
+        reader.read_exact(&mut sequence)?;
 
-        let script_sig_len = read_variable_length_integer(&script_sig[..])?;
+        let script_sig_len = match script_sig.is_empty() {
+            true => 0,
+            false => read_variable_length_integer(&script_sig[..])?,
+        };
         let sighash_code = SignatureHash::from_byte(&match script_sig_len {
             0 => 0x01,
-            length => script_sig[length],
+            length => match script_sig.get(length) {
+                Some(sighash_code) => *sighash_code,
+                None => return Err(TransactionError::InvalidVariableSizeInteger(length)),
+            },
         });
 
         Ok(Self {
@@ -404,6 +464,15 @@ impl<N: BitcoinNetwork> BitcoinTransactionInput<N> {
         })
     }
 
+    /// Returns true if the input signals that its transaction may be replaced (BIP 125),
+    /// as its sequence number is less than 0xfffffffe.
+    pub fn is_replaceable(&self) -> bool {
+        match <[u8; 4]>::try_from(&self.sequence[..]) {
+            Ok(sequence) => u32::from_le_bytes(sequence) < Self::LOCK_TIME_SEQUENCE,
+            Err(_) => false,
+        }
+    }
+
     /// Returns the serialized transaction input.
     pub fn serialize(&self, raw: bool) -> Result<Vec<u8>, TransactionError> {
         let mut input = vec![];
@@ -461,14 +530,29 @@ impl BitcoinTransactionOutput {
         })
     }
 
+    /// Returns a Bitcoin transaction output paying to the given script, such as a custom or miniscript-compiled
+    /// script that has no address. The script is not checked, so an output to an invalid script is unspendable.
+    pub fn from_script(script: Script, amount: BitcoinAmount) -> Self {
+        Self {
+            amount,
+            script_pub_key: script.into(),
+        }
+    }
+
+    /// Returns a null data output of the given data, of up to 80 bytes, with no amount.
+    /// Its public key script begins with OP_RETURN, so the output is provably unspendable.
+    pub fn null_data(data: &[u8]) -> Result<Self, TransactionError> {
+        Ok(Self::from_script(Script::null_data(data)?, BitcoinAmount(0)))
+    }
+
     /// Read and output a Bitcoin transaction output
     pub fn read<R: Read>(mut reader: &mut R) -> Result<Self, TransactionError> {
         let mut amount = [0u8; 8];
-        reader.read(&mut amount)?;
+        reader.read_exact(&mut amount)?;
 
         let script_pub_key: Vec<u8> = BitcoinVector::read(&mut reader, |s| {
             let mut byte = [0u8; 1];
-            s.read(&mut byte)?;
+            s.read_exact(&mut byte)?;
             Ok(byte[0])
         })?;
 
@@ -523,14 +607,14 @@ impl<N: BitcoinNetwork> BitcoinTransactionParameters<N> {
     /// Read and output the Bitcoin transaction parameters
     pub fn read<R: Read>(mut reader: R) -> Result<Self, TransactionError> {
         let mut version = [0u8; 4];
-        reader.read(&mut version)?;
+        reader.read_exact(&mut version)?;
 
         let mut inputs = BitcoinVector::read(&mut reader, BitcoinTransactionInput::<N>::read)?;
 
         let segwit_flag = match inputs.is_empty() {
             true => {
                 let mut flag = [0u8; 1];
-                reader.read(&mut flag)?;
+                reader.read_exact(&mut flag)?;
                 match flag[0] {
                     1 => {
                         inputs = BitcoinVector::read(&mut reader, BitcoinTransactionInput::<N>::read)?;
@@ -549,7 +633,7 @@ impl<N: BitcoinNetwork> BitcoinTransactionParameters<N> {
                 let witnesses: Vec<Vec<u8>> = BitcoinVector::read(&mut reader, |s| {
                     let (size, witness) = BitcoinVector::read_witness(s, |sr| {
                         let mut byte = [0u8; 1];
-                        sr.read(&mut byte)?;
+                        sr.read_exact(&mut byte)?;
                         Ok(byte[0])
                     })?;
 
@@ -565,7 +649,7 @@ impl<N: BitcoinNetwork> BitcoinTransactionParameters<N> {
         }
 
         let mut lock_time = [0u8; 4];
-        reader.read(&mut lock_time)?;
+        reader.read_exact(&mut lock_time)?;
 
         let transaction_parameters = BitcoinTransactionParameters::<N> {
             version: u32::from_le_bytes(version),
@@ -588,6 +672,7 @@ pub struct BitcoinTransaction<N: BitcoinNetwork> {
 
 impl<N: BitcoinNetwork> Transaction for BitcoinTransaction<N> {
     type Address = BitcoinAddress<N>;
+    type Amount = BitcoinAmount;
     type Format = BitcoinFormat;
     type PrivateKey = BitcoinPrivateKey<N>;
     type PublicKey = BitcoinPublicKey<N>;
@@ -603,118 +688,41 @@ impl<N: BitcoinNetwork> Transaction for BitcoinTransaction<N> {
 
     /// Returns a signed transaction given the private key of the sender.
     fn sign(&self, private_key: &Self::PrivateKey) -> Result<Self, TransactionError> {
-        let mut transaction = self.clone();
-        for (vin, input) in self.parameters.inputs.iter().enumerate() {
-            let address = match &input.outpoint.address {
-                Some(address) => address,
-                None => continue,
-            };
-
-            let address_is_valid = match &address.format() {
-                BitcoinFormat::P2WSH => {
-                    let input_script = match &input.outpoint.redeem_script {
-                        Some(redeem_script) => redeem_script.clone(),
-                        None => return Err(TransactionError::InvalidInputs("P2WSH".into())),
-                    };
-                    let c_address = BitcoinAddress::<N>::p2wsh(&input_script)?;
-                    address == &c_address
-                }
-                _ => address == &private_key.to_address(&address.format())?,
-            };
-
-            if address_is_valid && !transaction.parameters.inputs[vin].is_signed {
-                // Transaction hash
-                let preimage = match &address.format() {
-                    BitcoinFormat::P2PKH => transaction.p2pkh_hash_preimage(vin, input.sighash_code)?,
-                    _ => transaction.segwit_hash_preimage(vin, input.sighash_code)?,
-                };
-                let transaction_hash = Sha256::digest(&Sha256::digest(&preimage));
+        self.sign_with_secret_key(&private_key.to_secp256k1_secret_key(), &private_key.to_public_key())
+    }
 
-                // Signature
-                let (signature, _) = secp256k1::sign(
-                    &secp256k1::Message::parse_slice(&transaction_hash)?,
-                    &private_key.to_secp256k1_secret_key(),
-                );
-                let mut signature = signature.serialize_der().as_ref().to_vec();
-                signature.push((input.sighash_code as u32).to_le_bytes()[0]);
-                let signature = [variable_length_integer(signature.len() as u64)?, signature].concat();
-
-                // Public key
-                let public_key = private_key.to_public_key();
-                let public_key_bytes = match (&address.format(), public_key.is_compressed()) {
-                    (BitcoinFormat::P2PKH, false) => public_key.to_secp256k1_public_key().serialize().to_vec(),
-                    _ => public_key.to_secp256k1_public_key().serialize_compressed().to_vec(),
-                };
-                let public_key = [vec![public_key_bytes.len() as u8], public_key_bytes].concat();
+    /// Returns a transaction signed with a signature produced outside of the library, such as by a
+    /// hardware device, given the public key of the signer. The signature is inserted into each unsigned
+    /// input of the public key whose sighash it signs, or errors if it signs none of them.
+    fn sign_with_signature(&self, signature: &[u8], public_key: &Self::PublicKey) -> Result<Self, TransactionError> {
+        let mut signature = match signature.len() {
+            64 | 65 => secp256k1::Signature::parse_slice(&signature[..64])?,
+            length => {
+                return Err(TransactionError::Message(format!(
+                    "invalid signature length {}",
+                    length
+                )))
+            }
+        };
+        signature.normalize_s();
 
-                match &address.format() {
-                    BitcoinFormat::P2PKH => {
-                        transaction.parameters.inputs[vin].script_sig = [signature.clone(), public_key].concat();
-                        transaction.parameters.inputs[vin].is_signed = true;
-                    }
-                    BitcoinFormat::P2WSH => {
-                        let input_script = match &input.outpoint.redeem_script {
-                            Some(redeem_script) => redeem_script.clone(),
-                            None => return Err(TransactionError::InvalidInputs("P2WSH".into())),
-                        };
-
-                        let ser_input_script =
-                            [variable_length_integer(input_script.len() as u64)?, input_script].concat();
-                        transaction.parameters.segwit_flag = true;
-                        transaction.parameters.inputs[vin].script_sig = vec![];
-                        // TODO: (jaakinyele) Generalize to a vec of additional witnesses
-                        let (other_signature, is_other_sig_first) =
-                            match transaction.parameters.inputs[vin].additional_witness.clone() {
-                                Some(n) => (n.0, n.1),
-                                None => {
-                                    return Err(TransactionError::InvalidInputs(
-                                        "P2WSH: missing additional witness input to complete multi-sig".into(),
-                                    ))
-                                }
-                            };
-                        // Determine whether to append or prepend other signature(s)
-                        let mut witness_field = match is_other_sig_first {
-                            true => vec![other_signature, signature.clone()],
-                            false => vec![signature.clone(), other_signature],
-                        };
-                        // Append witness stack script args (before witness script)
-                        if transaction.parameters.inputs[vin].witness_script_data.is_some() {
-                            let witness_script_data =
-                                transaction.parameters.inputs[vin].witness_script_data.clone().unwrap();
-                            let witness_script_data =
-                                [vec![witness_script_data.len() as u8], witness_script_data].concat();
-                            witness_field.append(&mut vec![witness_script_data]);
-                        }
-                        // Append the witness script last
-                        witness_field.append(&mut vec![ser_input_script.clone()]);
-                        transaction.parameters.inputs[vin].witnesses.append(&mut witness_field);
-                        transaction.parameters.inputs[vin].is_signed = true;
-                    }
-                    BitcoinFormat::P2SH_P2WPKH => {
-                        let input_script = match &input.outpoint.redeem_script {
-                            Some(redeem_script) => redeem_script.clone(),
-                            None => return Err(TransactionError::InvalidInputs("P2SH_P2WPKH".into())),
-                        };
-                        transaction.parameters.segwit_flag = true;
-                        transaction.parameters.inputs[vin].script_sig =
-                            [variable_length_integer(input_script.len() as u64)?, input_script].concat();
-                        transaction.parameters.inputs[vin]
-                            .witnesses
-                            .append(&mut vec![signature.clone(), public_key]);
-                        transaction.parameters.inputs[vin].is_signed = true;
-                    }
-                    BitcoinFormat::Bech32 => {
-                        transaction.parameters.segwit_flag = true;
-                        transaction.parameters.inputs[vin]
-                            .witnesses
-                            .append(&mut vec![signature.clone(), public_key]);
-                        transaction.parameters.inputs[vin].is_signed = true;
-                    }
-                };
+        let mut transaction = self.clone();
+        let mut is_signed = false;
+        for vin in 0..self.parameters.inputs.len() {
+            if transaction.is_signer(vin, public_key)? && !transaction.parameters.inputs[vin].is_signed {
+                let message = secp256k1::Message::parse_slice(&transaction.to_sighash(vin)?)?;
+                if secp256k1::verify(&message, &signature, &public_key.to_secp256k1_public_key()) {
+                    transaction.insert_signature(vin, &signature, public_key)?;
+                    is_signed = true;
+                }
             }
         }
-        // TODO: (raychu86) Raise error if no input was signed
-        Ok(transaction)
+        match is_signed {
+            true => Ok(transaction),
+            false => Err(TransactionError::Message(
+                "the signature does not sign an input of the public key".into(),
+            )),
+        }
     }
 
     /// Returns a transaction given the transaction bytes.
@@ -766,6 +774,23 @@ impl<N: BitcoinNetwork> Transaction for BitcoinTransaction<N> {
         Ok(transaction)
     }
 
+    /// Returns the hash of the P2PKH or SegWit preimage of the input at the given index,
+    /// with its signature hash type.
+    fn to_sighash(&self, index: usize) -> Result<Vec<u8>, TransactionError> {
+        let input = match self.parameters.inputs.get(index) {
+            Some(input) => input,
+            None => return Err(TransactionError::InvalidInputs(format!("no input at index {}", index))),
+        };
+        let preimage = match &input.outpoint.address {
+            Some(address) if address.format() == BitcoinFormat::P2PKH => {
+                self.p2pkh_hash_preimage(index, input.sighash_code)?
+            }
+            Some(_) => self.segwit_hash_preimage(index, input.sighash_code)?,
+            None => return Err(TransactionError::MissingOutpointAddress),
+        };
+        Ok(Sha256::digest(&Sha256::digest(&preimage)).to_vec())
+    }
+
     /// Returns the transaction id.
     fn to_transaction_id(&self) -> Result<Self::TransactionId, TransactionError> {
         let mut txid = Sha256::digest(&Sha256::digest(&self.to_transaction_bytes_without_witness()?)).to_vec();
@@ -776,9 +801,180 @@ impl<N: BitcoinNetwork> Transaction for BitcoinTransaction<N> {
 
         Ok(Self::TransactionId { txid, wtxid })
     }
+
+    /// Returns the fee of the transaction, the amount of its inputs minus the amount of its outputs,
+    /// or `None` if the amount of an input is unknown, as it is not serialized in the transaction.
+    fn fee(&self) -> Option<Self::Amount> {
+        if self.parameters.inputs.is_empty() {
+            return None;
+        }
+
+        let mut fee = 0;
+        for input in &self.parameters.inputs {
+            fee += input.outpoint.amount?.0;
+        }
+        for output in &self.parameters.outputs {
+            fee -= output.amount.0;
+        }
+        BitcoinAmount::from_satoshi(fee).ok()
+    }
+
+    /// Returns the virtual size of the transaction, its weight divided by four and rounded up,
+    /// as its witness weighs one unit per byte and the rest of the transaction weighs four.
+    /// https://github.com/bitcoin/bips/blob/master/bip-0141.mediawiki#transaction-size-calculations
+    fn vsize(&self) -> Result<usize, TransactionError> {
+        let weight = self.to_transaction_bytes_without_witness()?.len() * 3 + self.size()?;
+        Ok(weight.div_ceil(4))
+    }
 }
 
 impl<N: BitcoinNetwork> BitcoinTransaction<N> {
+    /// Returns the transaction parameters.
+    pub fn parameters(&self) -> &BitcoinTransactionParameters<N> {
+        &self.parameters
+    }
+
+    /// Returns the transactions signed with the given private key, in order.
+    /// The secret key and public key are computed once for the batch, instead of
+    /// once for each transaction as by `sign`.
+    pub fn sign_batch(
+        transactions: &[Self],
+        private_key: &BitcoinPrivateKey<N>,
+    ) -> Result<Vec<Self>, TransactionError> {
+        let secret_key = private_key.to_secp256k1_secret_key();
+        let public_key = private_key.to_public_key();
+        transactions
+            .iter()
+            .map(|transaction| transaction.sign_with_secret_key(&secret_key, &public_key))
+            .collect()
+    }
+
+    /// Returns the transaction with each unsigned input of the given public key signed with its secret key.
+    fn sign_with_secret_key(
+        &self,
+        secret_key: &secp256k1::SecretKey,
+        public_key: &BitcoinPublicKey<N>,
+    ) -> Result<Self, TransactionError> {
+        let mut transaction = self.clone();
+        for vin in 0..self.parameters.inputs.len() {
+            if transaction.is_signer(vin, public_key)? && !transaction.parameters.inputs[vin].is_signed {
+                // Transaction hash
+                let transaction_hash = transaction.to_sighash(vin)?;
+
+                // Signature
+                let (signature, _) = secp256k1::sign(&secp256k1::Message::parse_slice(&transaction_hash)?, secret_key);
+                transaction.insert_signature(vin, &signature, public_key)?;
+            }
+        }
+        // TODO: (raychu86) Raise error if no input was signed
+        Ok(transaction)
+    }
+
+    /// Returns `true` if the input at the given index is spent by the given public key,
+    /// the public key of its address, or a signer of the witness script of its P2WSH address.
+    fn is_signer(&self, vin: usize, public_key: &BitcoinPublicKey<N>) -> Result<bool, TransactionError> {
+        let input = &self.parameters.inputs[vin];
+        match &input.outpoint.address {
+            Some(address) => match address.format() {
+                BitcoinFormat::P2WSH => match &input.outpoint.redeem_script {
+                    Some(redeem_script) => Ok(address == &BitcoinAddress::<N>::p2wsh(redeem_script)?),
+                    None => Err(TransactionError::InvalidInputs("P2WSH".into())),
+                },
+                format => Ok(address == &public_key.to_address(&format)?),
+            },
+            None => Ok(false),
+        }
+    }
+
+    /// Inserts the signature of the input at the given index, by the given public key,
+    /// into its script or witness with the signature hash type of the input.
+    fn insert_signature(
+        &mut self,
+        vin: usize,
+        signature: &secp256k1::Signature,
+        public_key: &BitcoinPublicKey<N>,
+    ) -> Result<(), TransactionError> {
+        let address = match &self.parameters.inputs[vin].outpoint.address {
+            Some(address) => address.clone(),
+            None => return Err(TransactionError::MissingOutpointAddress),
+        };
+
+        let mut signature = signature.serialize_der().as_ref().to_vec();
+        signature.push((self.parameters.inputs[vin].sighash_code as u32).to_le_bytes()[0]);
+        let signature = [variable_length_integer(signature.len() as u64)?, signature].concat();
+
+        // Public key
+        let public_key_bytes = match (&address.format(), public_key.is_compressed()) {
+            (BitcoinFormat::P2PKH, false) => public_key.to_secp256k1_public_key().serialize().to_vec(),
+            _ => public_key.to_secp256k1_public_key().serialize_compressed().to_vec(),
+        };
+        let public_key = [vec![public_key_bytes.len() as u8], public_key_bytes].concat();
+
+        match &address.format() {
+            BitcoinFormat::P2PKH => {
+                self.parameters.inputs[vin].script_sig = [signature.clone(), public_key].concat();
+                self.parameters.inputs[vin].is_signed = true;
+            }
+            BitcoinFormat::P2WSH => {
+                let input_script = match &self.parameters.inputs[vin].outpoint.redeem_script {
+                    Some(redeem_script) => redeem_script.clone(),
+                    None => return Err(TransactionError::InvalidInputs("P2WSH".into())),
+                };
+
+                let ser_input_script = [variable_length_integer(input_script.len() as u64)?, input_script].concat();
+                self.parameters.segwit_flag = true;
+                self.parameters.inputs[vin].script_sig = vec![];
+                // TODO: (jaakinyele) Generalize to a vec of additional witnesses
+                let (other_signature, is_other_sig_first) = match self.parameters.inputs[vin].additional_witness.clone()
+                {
+                    Some(n) => (n.0, n.1),
+                    None => {
+                        return Err(TransactionError::InvalidInputs(
+                            "P2WSH: missing additional witness input to complete multi-sig".into(),
+                        ))
+                    }
+                };
+                // Determine whether to append or prepend other signature(s)
+                let mut witness_field = match is_other_sig_first {
+                    true => vec![other_signature, signature.clone()],
+                    false => vec![signature.clone(), other_signature],
+                };
+                // Append witness stack script args (before witness script)
+                if self.parameters.inputs[vin].witness_script_data.is_some() {
+                    let witness_script_data = self.parameters.inputs[vin].witness_script_data.clone().unwrap();
+                    let witness_script_data = [vec![witness_script_data.len() as u8], witness_script_data].concat();
+                    witness_field.append(&mut vec![witness_script_data]);
+                }
+                // Append the witness script last
+                witness_field.append(&mut vec![ser_input_script.clone()]);
+                self.parameters.inputs[vin].witnesses.append(&mut witness_field);
+                self.parameters.inputs[vin].is_signed = true;
+            }
+            BitcoinFormat::P2SH_P2WPKH => {
+                let input_script = match &self.parameters.inputs[vin].outpoint.redeem_script {
+                    Some(redeem_script) => redeem_script.clone(),
+                    None => return Err(TransactionError::InvalidInputs("P2SH_P2WPKH".into())),
+                };
+                self.parameters.segwit_flag = true;
+                self.parameters.inputs[vin].script_sig =
+                    [variable_length_integer(input_script.len() as u64)?, input_script].concat();
+                self.parameters.inputs[vin]
+                    .witnesses
+                    .append(&mut vec![signature.clone(), public_key]);
+                self.parameters.inputs[vin].is_signed = true;
+            }
+            BitcoinFormat::Bech32 => {
+                self.parameters.segwit_flag = true;
+                self.parameters.inputs[vin]
+                    .witnesses
+                    .append(&mut vec![signature.clone(), public_key]);
+                self.parameters.inputs[vin].is_signed = true;
+            }
+        };
+
+        Ok(())
+    }
+
     /// Return the P2PKH hash preimage of the raw transaction.
     pub fn p2pkh_hash_preimage(&self, vin: usize, sighash: SignatureHash) -> Result<Vec<u8>, TransactionError> {
         let mut preimage = self.parameters.version.to_le_bytes().to_vec();
@@ -888,6 +1084,64 @@ impl<N: BitcoinNetwork> BitcoinTransaction<N> {
         Ok(transaction)
     }
 
+    /// Returns an unsigned transaction given the transaction parameters,
+    /// or refuses to construct it if it does not comply with the given policy.
+    pub fn new_with_policy(
+        parameters: &BitcoinTransactionParameters<N>,
+        policy: &BitcoinTransactionPolicy,
+    ) -> Result<Self, TransactionError> {
+        let transaction = Self::new(parameters)?;
+        policy.check(&transaction)?;
+        Ok(transaction)
+    }
+
+    /// Returns true if an input of the transaction signals that it may be replaced (BIP 125).
+    pub fn is_replaceable(&self) -> bool {
+        self.parameters.inputs.iter().any(|input| input.is_replaceable())
+    }
+
+    /// Returns an unsigned transaction to replace the signed transaction (BIP 125), paying the fee
+    /// of the given fee rate, in satoshi per vbyte, by reducing the output of the given index, such as its change.
+    /// The amounts of the inputs must be known, and the fee must increase by at least the incremental
+    /// relay fee of 1 satoshi per vbyte of the transaction, as the replacement pays for its own relay.
+    /// https://github.com/bitcoin/bips/blob/master/bip-0125.mediawiki#implementation-details
+    pub fn bump_fee(&self, fee_rate: u64, vout: usize) -> Result<Self, TransactionError> {
+        if self.parameters.inputs.iter().any(|input| !input.is_signed) {
+            return Err(TransactionError::InvalidInputs("signed inputs".into()));
+        }
+        let fee = match self.fee() {
+            Some(fee) => fee.0,
+            None => return Err(TransactionError::MissingOutpointAmount),
+        };
+
+        // The virtual size of the replacement, once signed, is the virtual size of the transaction
+        let vsize = self.vsize()? as i64;
+        let replacement_fee = vsize * fee_rate as i64;
+        if replacement_fee < fee + vsize {
+            return Err(TransactionError::InsufficientFee(replacement_fee, fee + vsize));
+        }
+
+        let mut transaction = self.clone();
+        let output = match transaction.parameters.outputs.get_mut(vout) {
+            Some(output) => output,
+            None => return Err(TransactionError::InvalidInputs(format!("output {}", vout))),
+        };
+        let increase = replacement_fee - fee;
+        if output.amount.0 < increase {
+            return Err(TransactionError::InsufficientFunds(output.amount.0, increase));
+        }
+        output.amount = BitcoinAmount::from_satoshi(output.amount.0 - increase)?;
+
+        // The signatures commit to the outputs, and are removed to sign the replacement
+        for input in transaction.parameters.inputs.iter_mut() {
+            input.script_sig = vec![];
+            input.witnesses = vec![];
+            input.is_signed = false;
+        }
+        transaction.parameters.segwit_flag = false;
+        Ok(transaction)
+    }
+
     /// Update a transaction's input outpoint
     #[allow(dead_code)]
     pub fn update_outpoint(&self, outpoint: Outpoint<N>) -> Self {
@@ -1044,6 +1298,9 @@ mod tests {
         expected_signed_transaction: &str,
         expected_transaction_id: &str,
     ) {
+        let expected_fee = inputs.iter().map(|input| input.utxo_amount.0).sum::<i64>()
+            - outputs.iter().map(|output| output.amount.0).sum::<i64>();
+
         let mut input_vec = vec![];
         for input in &inputs {
             let private_key = BitcoinPrivateKey::from_str(input.private_key).unwrap();
@@ -1095,9 +1352,10 @@ mod tests {
         };
 
         let mut transaction = BitcoinTransaction::<N>::new(&transaction_parameters).unwrap();
+        let mut detached_transaction = transaction.clone();
 
         // Sign transaction
-        for input in inputs {
+        for input in &inputs {
             transaction = transaction
                 .sign(&BitcoinPrivateKey::from_str(input.private_key).unwrap())
                 .unwrap();
@@ -1108,6 +1366,48 @@ mod tests {
 
         assert_eq!(expected_signed_transaction, &signed_transaction);
         assert_eq!(expected_transaction_id, &transaction_id);
+        assert_eq!(Some(BitcoinAmount(expected_fee)), transaction.fee());
+        assert_eq!(expected_signed_transaction.len() / 2, transaction.size().unwrap());
+        assert!(transaction.vsize().unwrap() <= transaction.size().unwrap());
+
+        // Sign transaction with detached signatures of the sighash of each input
+        for (vin, input) in inputs.iter().enumerate() {
+            let private_key = BitcoinPrivateKey::<N>::from_str(input.private_key).unwrap();
+            let sighash = detached_transaction.to_sighash(vin).unwrap();
+            let (signature, _) = secp256k1::sign(
+                &secp256k1::Message::parse_slice(&sighash).unwrap(),
+                &private_key.to_secp256k1_secret_key(),
+            );
+            detached_transaction = detached_transaction
+                .sign_with_signature(&signature.serialize(), &private_key.to_public_key())
+                .unwrap();
+        }
+        assert_eq!(
+            expected_signed_transaction,
+            hex::encode(&detached_transaction.to_transaction_bytes().unwrap())
+        );
+
+        // Sign transaction in a batch for each input key, which signs each transaction as `sign` does
+        let mut batch = vec![transaction_parameters.clone(); 2];
+        batch[1].lock_time = lock_time + 1;
+        let mut batch = batch
+            .iter()
+            .map(|parameters| BitcoinTransaction::<N>::new(parameters).unwrap())
+            .collect::<Vec<_>>();
+        let mut expected_batch = batch.clone();
+        for input in &inputs {
+            let private_key = BitcoinPrivateKey::from_str(input.private_key).unwrap();
+            batch = BitcoinTransaction::sign_batch(&batch, &private_key).unwrap();
+            expected_batch = expected_batch
+                .iter()
+                .map(|transaction| transaction.sign(&private_key).unwrap())
+                .collect();
+        }
+        assert_eq!(
+            expected_signed_transaction,
+            hex::encode(&batch[0].to_transaction_bytes().unwrap())
+        );
+        assert_eq!(expected_batch, batch);
     }
 
     fn test_reconstructed_transaction<N: BitcoinNetwork>(
@@ -1939,6 +2239,94 @@ mod tests {
         }
     }
 
+    mod test_bump_fee {
+        use super::*;
+        type N = Mainnet;
+
+        const PRIVATE_KEY: &str = "L1uyy5qTuGrVXrmrsvHWHgVzW9kKdrp27wBC7Vs6nZDTF2BRUVwy";
+        const TRANSACTION_ID: &str = "61d520ccb74288c96bc1a2b20ea1c0d5a704776dd0164a396efec3ea7040349d";
+
+        fn signed_transaction(sequence: u32) -> BitcoinTransaction<N> {
+            let private_key = BitcoinPrivateKey::<N>::from_str(PRIVATE_KEY).unwrap();
+            let address = private_key.to_address(&BitcoinFormat::Bech32).unwrap();
+            let input = BitcoinTransactionInput::<N>::new(
+                hex::decode(TRANSACTION_ID).unwrap(),
+                0,
+                Some(address.clone()),
+                Some(BitcoinAmount(100_000)),
+                None,
+                None,
+                Some(sequence.to_le_bytes().to_vec()),
+                SignatureHash::SIGHASH_ALL,
+            )
+            .unwrap();
+            let recipient = BitcoinAddress::<N>::from_str("1cMh228HTCiwS8ZsaakH8A8wze1JR5ZsP").unwrap();
+            let parameters = BitcoinTransactionParameters::<N> {
+                version: 2,
+                inputs: vec![input],
+                outputs: vec![
+                    BitcoinTransactionOutput::new(&recipient, BitcoinAmount(50_000)).unwrap(),
+                    BitcoinTransactionOutput::new(&address, BitcoinAmount(49_000)).unwrap(),
+                ],
+                lock_time: 0,
+                segwit_flag: false,
+            };
+            BitcoinTransaction::<N>::new(&parameters)
+                .unwrap()
+                .sign(&private_key)
+                .unwrap()
+        }
+
+        #[test]
+        fn test_is_replaceable() {
+            let sequence = BitcoinTransactionInput::<N>::REPLACEABLE_SEQUENCE;
+            assert!(signed_transaction(sequence).is_replaceable());
+            let sequence = BitcoinTransactionInput::<N>::LOCK_TIME_SEQUENCE;
+            assert!(!signed_transaction(sequence).is_replaceable());
+            assert!(!signed_transaction(0xffff_ffff).is_replaceable());
+        }
+
+        #[test]
+        fn test_bump_fee() {
+            let transaction = signed_transaction(BitcoinTransactionInput::<N>::REPLACEABLE_SEQUENCE);
+            let vsize = transaction.vsize().unwrap() as i64;
+
+            let replacement = transaction.bump_fee(20, 1).unwrap();
+            assert_eq!(Some(BitcoinAmount(vsize * 20)), replacement.fee());
+            assert_eq!(BitcoinAmount(50_000), replacement.parameters().outputs[0].amount);
+            assert_eq!(
+                BitcoinAmount(50_000 - vsize * 20),
+                replacement.parameters().outputs[1].amount
+            );
+            assert!(replacement.parameters().inputs.iter().all(|input| !input.is_signed));
+            assert!(replacement.is_replaceable());
+
+            let private_key = BitcoinPrivateKey::<N>::from_str(PRIVATE_KEY).unwrap();
+            let replacement = replacement.sign(&private_key).unwrap();
+            assert_eq!(vsize, replacement.vsize().unwrap() as i64);
+        }
+
+        #[test]
+        fn test_invalid_bump_fee() {
+            let transaction = signed_transaction(BitcoinTransactionInput::<N>::REPLACEABLE_SEQUENCE);
+            let vsize = transaction.vsize().unwrap() as i64;
+
+            // The fee must increase by the incremental relay fee
+            let fee_rate = (1_000 / vsize + 1) as u64;
+            assert!(transaction.bump_fee(fee_rate, 1).is_err());
+            // The output must pay the increase of the fee
+            assert!(transaction.bump_fee(1_000, 1).is_err());
+            assert!(transaction.bump_fee(20, 2).is_err());
+            // The transaction must be signed
+            let mut unsigned = transaction.bump_fee(20, 1).unwrap();
+            assert!(unsigned.bump_fee(30, 1).is_err());
+            // The amounts of the inputs must be known
+            unsigned =
+                BitcoinTransaction::<N>::from_transaction_bytes(&transaction.to_transaction_bytes().unwrap()).unwrap();
+            assert!(unsigned.bump_fee(20, 1).is_err());
+        }
+    }
+
     mod test_helper_functions {
         use super::*;
 
@@ -1965,6 +2353,27 @@ mod tests {
             ),
         ];
 
+        #[test]
+        fn test_null_data() {
+            let output = BitcoinTransactionOutput::null_data(b"wagyu").unwrap();
+            assert_eq!(0, output.amount.0);
+            assert_eq!("6a057761677975", hex::encode(&output.script_pub_key));
+
+            let output = BitcoinTransactionOutput::null_data(&[0xff; 80]).unwrap();
+            assert_eq!([0x6a, 0x4c, 80], output.script_pub_key[..3]);
+            assert_eq!(83, output.script_pub_key.len());
+            assert!(BitcoinTransactionPolicy::default().check_outputs(&[output]).is_ok());
+
+            assert_eq!(
+                vec![0x6a],
+                BitcoinTransactionOutput::null_data(&[]).unwrap().script_pub_key
+            );
+            assert!(matches!(
+                BitcoinTransactionOutput::null_data(&[0xff; 81]),
+                Err(TransactionError::OversizedNullData(0, 84, 83))
+            ));
+        }
+
         #[test]
         fn test_variable_length_integer() {
             LENGTH_VALUES.iter().for_each(|(size, expected_output)| {
@@ -0,0 +1,255 @@
+//!
+//! URI
+//!
+//! This module contains the `bitcoin:` payment request URIs specified in BIP-21
+//! (https://github.com/bitcoin/bips/blob/master/bip-0021.mediawiki):
+//!
+//! `bitcoin:<address>[?amount=<amount>][?label=<label>][?message=<message>]`
+//!
+//! The amount is a decimal value in BTC, and the label and message are percent-encoded.
+//! Parameters prefixed with `req-` are required to be understood, so a URI with an unknown
+//! required parameter is rejected, while other unknown parameters are kept as they are.
+//!
+//! Bech32 addresses may be written in uppercase, as in `BITCOIN:BC1Q...`, to encode the URI
+//! in the smaller alphanumeric mode of a QR code, and are parsed to their lowercase form.
+//!
+
+use crate::address::BitcoinAddress;
+use crate::amount::BitcoinAmount;
+use crate::format::BitcoinFormat;
+use crate::network::BitcoinNetwork;
+use wagyu_model::no_std::*;
+use wagyu_model::{AddressError, AmountError};
+
+use core::{fmt, str::FromStr};
+
+#[derive(Debug, Fail, PartialEq, Eq)]
+pub enum UriError {
+    #[fail(display = "{}: {}", _0, _1)]
+    Crate(&'static str, String),
+
+    #[fail(display = "duplicate URI parameter: {}", _0)]
+    DuplicateParameter(String),
+
+    #[fail(display = "invalid URI: {}", _0)]
+    InvalidUri(String),
+
+    #[fail(display = "invalid URI parameter: {}", _0)]
+    InvalidParameter(String),
+
+    #[fail(display = "unsupported required URI parameter: {}", _0)]
+    UnsupportedParameter(String),
+}
+
+impl From<AddressError> for UriError {
+    fn from(error: AddressError) -> Self {
+        UriError::Crate("address", format!("{}", error))
+    }
+}
+
+impl From<AmountError> for UriError {
+    fn from(error: AmountError) -> Self {
+        UriError::Crate("amount", format!("{}", error))
+    }
+}
+
+/// Represents a Bitcoin payment request URI (BIP-21)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitcoinUri<N: BitcoinNetwork> {
+    /// The address of the receiver
+    pub address: BitcoinAddress<N>,
+    /// The amount requested
+    pub amount: Option<BitcoinAmount>,
+    /// The label of the receiver
+    pub label: Option<String>,
+    /// The message describing the payment
+    pub message: Option<String>,
+    /// The other parameters of the URI, in order
+    pub parameters: Vec<(String, String)>,
+}
+
+impl<N: BitcoinNetwork> BitcoinUri<N> {
+    /// Returns a payment request URI for the specified address.
+    pub fn new(address: BitcoinAddress<N>) -> Self {
+        Self {
+            address,
+            amount: None,
+            label: None,
+            message: None,
+            parameters: vec![],
+        }
+    }
+}
+
+impl<N: BitcoinNetwork> FromStr for BitcoinUri<N> {
+    type Err = UriError;
+
+    fn from_str(uri: &str) -> Result<Self, Self::Err> {
+        let request = match uri.get(..8) {
+            Some(scheme) if scheme.eq_ignore_ascii_case("bitcoin:") => &uri[8..],
+            _ => return Err(UriError::InvalidUri(uri.into())),
+        };
+        let (address, query) = match request.find('?') {
+            Some(index) => (&request[..index], Some(&request[index + 1..])),
+            None => (request, None),
+        };
+
+        // Bech32 addresses are case insensitive, and are parsed in lowercase
+        let lowercase = address.to_lowercase();
+        let is_bech32 = match lowercase.get(..2) {
+            Some(prefix) => matches!(
+                BitcoinFormat::from_address_prefix(prefix.as_bytes()),
+                Ok(BitcoinFormat::Bech32)
+            ),
+            None => false,
+        };
+        let address = match is_bech32 && address == address.to_uppercase() {
+            true => lowercase,
+            false => address.into(),
+        };
+
+        let mut uri = Self::new(BitcoinAddress::from_str(&address)?);
+        for parameter in query
+            .unwrap_or_default()
+            .split('&')
+            .filter(|parameter| !parameter.is_empty())
+        {
+            let (key, value) = match parameter.find('=') {
+                Some(index) => (&parameter[..index], percent_decode(&parameter[index + 1..])?),
+                None => return Err(UriError::InvalidParameter(parameter.into())),
+            };
+            let duplicate = || UriError::DuplicateParameter(key.into());
+            match key {
+                "amount" if uri.amount.is_some() => return Err(duplicate()),
+                "amount" => uri.amount = Some(BitcoinAmount::from_btc_str(&value)?),
+                "label" if uri.label.is_some() => return Err(duplicate()),
+                "label" => uri.label = Some(value),
+                "message" if uri.message.is_some() => return Err(duplicate()),
+                "message" => uri.message = Some(value),
+                key if key.starts_with("req-") => return Err(UriError::UnsupportedParameter(key.into())),
+                key => uri.parameters.push((key.into(), value)),
+            }
+        }
+        Ok(uri)
+    }
+}
+
+impl<N: BitcoinNetwork> fmt::Display for BitcoinUri<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "bitcoin:{}", self.address)?;
+
+        let mut parameters = vec![];
+        if let Some(amount) = self.amount {
+            parameters.push(format!("amount={}", amount.to_btc_string()));
+        }
+        if let Some(label) = &self.label {
+            parameters.push(format!("label={}", percent_encode(label)));
+        }
+        if let Some(message) = &self.message {
+            parameters.push(format!("message={}", percent_encode(message)));
+        }
+        for (key, value) in &self.parameters {
+            parameters.push(format!("{}={}", key, percent_encode(value)));
+        }
+        match parameters.is_empty() {
+            true => Ok(()),
+            false => write!(f, "?{}", parameters.join("&")),
+        }
+    }
+}
+
+/// Returns the value with each character other than an unreserved character percent-encoded.
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Returns the value with its percent-encoded characters decoded.
+fn percent_decode(value: &str) -> Result<String, UriError> {
+    let invalid = || UriError::InvalidParameter(value.into());
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut input = value.bytes();
+    while let Some(byte) = input.next() {
+        match byte {
+            b'%' => {
+                let hex = [input.next().ok_or_else(invalid)?, input.next().ok_or_else(invalid)?];
+                bytes.extend(hex::decode(hex).map_err(|_| invalid())?);
+            }
+            byte => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    #[test]
+    fn from_str() {
+        // Examples of BIP-21, with a valid address in place of its example address, which has an invalid checksum
+        assert!(BitcoinUri::<Mainnet>::from_str("bitcoin:175tWpb8K1S7NmH4Zx6rewF9WQrcZv245W").is_err());
+        let uri = BitcoinUri::<Mainnet>::from_str("bitcoin:1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH").unwrap();
+        assert_eq!("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH", uri.address.to_string());
+        assert_eq!(None, uri.amount);
+
+        let uri = BitcoinUri::<Mainnet>::from_str(
+            "bitcoin:1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH?amount=20.3&label=Luke-Jr&message=Donation%20for%20project%20xyz",
+        )
+        .unwrap();
+        assert_eq!(Some(BitcoinAmount(2_030_000_000)), uri.amount);
+        assert_eq!(Some("Luke-Jr".into()), uri.label);
+        assert_eq!(Some("Donation for project xyz".into()), uri.message);
+
+        let uri = BitcoinUri::<Mainnet>::from_str(
+            "bitcoin:1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH?somethingyoudontunderstand=50&somethingelseyoudontget=999",
+        )
+        .unwrap();
+        assert_eq!(2, uri.parameters.len());
+
+        assert!(BitcoinUri::<Mainnet>::from_str(
+            "bitcoin:1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH?req-somethingyoudontunderstand=50&req-somethingelseyoudontget=999"
+        )
+        .is_err());
+
+        // Bech32 addresses in uppercase
+        let uri = BitcoinUri::<Mainnet>::from_str("BITCOIN:BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4?amount=0.0005")
+            .unwrap();
+        assert_eq!("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4", uri.address.to_string());
+        assert_eq!(Some(BitcoinAmount(50_000)), uri.amount);
+
+        assert!(BitcoinUri::<Mainnet>::from_str("bitcoin:BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7Kv8f3t4").is_err());
+        assert!(BitcoinUri::<Mainnet>::from_str("litecoin:1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH").is_err());
+        assert!(BitcoinUri::<Testnet>::from_str("bitcoin:1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH").is_err());
+        assert!(BitcoinUri::<Mainnet>::from_str("bitcoin:1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH?amount=1e8").is_err());
+        assert!(
+            BitcoinUri::<Mainnet>::from_str("bitcoin:1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH?amount=1&amount=2").is_err()
+        );
+        assert!(BitcoinUri::<Mainnet>::from_str("bitcoin:1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH?label=%E").is_err());
+    }
+
+    #[test]
+    fn to_string() {
+        let address = BitcoinAddress::<Testnet>::from_str("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx").unwrap();
+        let mut uri = BitcoinUri::new(address);
+        assert_eq!("bitcoin:tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx", uri.to_string());
+
+        uri.amount = Some(BitcoinAmount(100_000_001));
+        uri.label = Some("Luke-Jr".into());
+        uri.message = Some("Coffee & cake".into());
+        uri.parameters = vec![("lightning".into(), "lntb1".into())];
+        let expected = "bitcoin:tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx\
+                        ?amount=1.00000001&label=Luke-Jr&message=Coffee%20%26%20cake&lightning=lntb1";
+        assert_eq!(expected, uri.to_string());
+        assert_eq!(uri, BitcoinUri::from_str(expected).unwrap());
+        let uppercase = "BITCOIN:TB1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KXPJZSX\
+                         ?amount=1.00000001&label=Luke-Jr&message=Coffee%20%26%20cake&lightning=lntb1";
+        assert_eq!(uri, BitcoinUri::from_str(uppercase).unwrap());
+    }
+}
@@ -0,0 +1,294 @@
+//! WebAssembly bindings for generating Bitcoin wallets, importing mnemonics, and signing transactions.
+
+use crate::address::BitcoinAddress;
+use crate::amount::BitcoinAmount;
+use crate::derivation_path::BitcoinDerivationPath;
+use crate::extended_private_key::BitcoinExtendedPrivateKey;
+use crate::format::BitcoinFormat;
+use crate::mnemonic::BitcoinMnemonic;
+use crate::network::{BitcoinNetwork, Mainnet, Regtest, Testnet};
+use crate::private_key::BitcoinPrivateKey;
+use crate::transaction::{BitcoinTransaction, Outpoint};
+use crate::wordlist::English;
+use wagyu_model::no_std::{vec, String, ToString, Vec};
+use wagyu_model::{
+    crypto::hash160, ExtendedPrivateKey, Mnemonic, MnemonicCount, MnemonicExtended, Network, OsWalletRng, PrivateKey,
+    PublicKey, Transaction,
+};
+
+use core::{fmt::Display, str::FromStr};
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroize;
+
+/// Represents a Bitcoin wallet exposed to JavaScript
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct BitcoinWallet {
+    mnemonic: Option<String>,
+    extended_private_key: Option<String>,
+    private_key: String,
+    public_key: String,
+    address: String,
+}
+
+#[wasm_bindgen]
+impl BitcoinWallet {
+    /// Returns a randomly-generated wallet for the given network and address format.
+    pub fn new(network: &str, format: &str) -> Result<BitcoinWallet, JsValue> {
+        let format = to_format(format)?;
+        match network {
+            Mainnet::NAME => Self::from_private_key(
+                &BitcoinPrivateKey::<Mainnet>::new(&mut OsWalletRng::new()).map_err(to_error)?,
+                &format,
+            ),
+            Testnet::NAME => Self::from_private_key(
+                &BitcoinPrivateKey::<Testnet>::new(&mut OsWalletRng::new()).map_err(to_error)?,
+                &format,
+            ),
+            Regtest::NAME => Self::from_private_key(
+                &BitcoinPrivateKey::<Regtest>::new(&mut OsWalletRng::new()).map_err(to_error)?,
+                &format,
+            ),
+            _ => Err(to_error(format_args!("invalid network: {}", network))),
+        }
+    }
+
+    /// Returns a randomly-generated HD wallet for the given network, English mnemonic word count,
+    /// password, and derivation path.
+    pub fn new_hd(
+        network: &str,
+        word_count: u8,
+        password: Option<String>,
+        path: &str,
+    ) -> Result<BitcoinWallet, JsValue> {
+        match network {
+            Mainnet::NAME => {
+                let mnemonic = BitcoinMnemonic::<Mainnet, English>::new_with_count(&mut OsWalletRng::new(), word_count);
+                Self::from_bitcoin_mnemonic(&mnemonic.map_err(to_error)?, password.as_deref(), path)
+            }
+            Testnet::NAME => {
+                let mnemonic = BitcoinMnemonic::<Testnet, English>::new_with_count(&mut OsWalletRng::new(), word_count);
+                Self::from_bitcoin_mnemonic(&mnemonic.map_err(to_error)?, password.as_deref(), path)
+            }
+            Regtest::NAME => {
+                let mnemonic = BitcoinMnemonic::<Regtest, English>::new_with_count(&mut OsWalletRng::new(), word_count);
+                Self::from_bitcoin_mnemonic(&mnemonic.map_err(to_error)?, password.as_deref(), path)
+            }
+            _ => Err(to_error(format_args!("invalid network: {}", network))),
+        }
+    }
+
+    /// Returns the HD wallet of the given network, English mnemonic, password, and derivation path.
+    pub fn from_mnemonic(
+        network: &str,
+        mnemonic: &str,
+        password: Option<String>,
+        path: &str,
+    ) -> Result<BitcoinWallet, JsValue> {
+        match network {
+            Mainnet::NAME => {
+                let mnemonic = BitcoinMnemonic::<Mainnet, English>::from_phrase(mnemonic).map_err(to_error)?;
+                Self::from_bitcoin_mnemonic(&mnemonic, password.as_deref(), path)
+            }
+            Testnet::NAME => {
+                let mnemonic = BitcoinMnemonic::<Testnet, English>::from_phrase(mnemonic).map_err(to_error)?;
+                Self::from_bitcoin_mnemonic(&mnemonic, password.as_deref(), path)
+            }
+            Regtest::NAME => {
+                let mnemonic = BitcoinMnemonic::<Regtest, English>::from_phrase(mnemonic).map_err(to_error)?;
+                Self::from_bitcoin_mnemonic(&mnemonic, password.as_deref(), path)
+            }
+            _ => Err(to_error(format_args!("invalid network: {}", network))),
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn mnemonic(&self) -> Option<String> {
+        self.mnemonic.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn extended_private_key(&self) -> Option<String> {
+        self.extended_private_key.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn private_key(&self) -> String {
+        self.private_key.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn public_key(&self) -> String {
+        self.public_key.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn address(&self) -> String {
+        self.address.clone()
+    }
+}
+
+impl BitcoinWallet {
+    fn from_private_key<N: BitcoinNetwork>(
+        private_key: &BitcoinPrivateKey<N>,
+        format: &BitcoinFormat,
+    ) -> Result<Self, JsValue> {
+        let public_key = private_key.to_public_key();
+        Ok(Self {
+            mnemonic: None,
+            extended_private_key: None,
+            private_key: private_key.to_string(),
+            public_key: public_key.to_string(),
+            address: public_key.to_address(format).map_err(to_error)?.to_string(),
+        })
+    }
+
+    fn from_bitcoin_mnemonic<N: BitcoinNetwork>(
+        mnemonic: &BitcoinMnemonic<N, English>,
+        password: Option<&str>,
+        path: &str,
+    ) -> Result<Self, JsValue> {
+        let master_extended_private_key = mnemonic.to_extended_private_key(password).map_err(to_error)?;
+        let derivation_path = BitcoinDerivationPath::from_str(path).map_err(to_error)?;
+        let extended_private_key: BitcoinExtendedPrivateKey<N> =
+            master_extended_private_key.derive(&derivation_path).map_err(to_error)?;
+        let private_key = extended_private_key.to_private_key();
+        let public_key = private_key.to_public_key();
+        Ok(Self {
+            mnemonic: Some(mnemonic.to_string()),
+            extended_private_key: Some(extended_private_key.to_string()),
+            private_key: private_key.to_string(),
+            public_key: public_key.to_string(),
+            address: public_key
+                .to_address(&extended_private_key.format())
+                .map_err(to_error)?
+                .to_string(),
+        })
+    }
+}
+
+impl Drop for BitcoinWallet {
+    fn drop(&mut self) {
+        self.mnemonic.zeroize();
+        self.extended_private_key.zeroize();
+        self.private_key.zeroize();
+    }
+}
+
+/// Represents an input of the transaction to sign, as accepted by `sign_bitcoin_transaction`
+#[derive(Deserialize)]
+struct TransactionInput {
+    txid: String,
+    vout: u32,
+    amount: u64,
+    address: String,
+    #[serde(rename = "privatekey")]
+    private_key: String,
+    #[serde(rename = "redeemScript")]
+    redeem_script: Option<String>,
+    #[serde(rename = "scriptPubKey")]
+    script_pub_key: Option<String>,
+}
+
+/// Returns the signed transaction hex of the given network, raw transaction hex, and JSON array of
+/// inputs of the form `[{"txid", "vout", "amount", "address", "privatekey", "redeemScript", "scriptPubKey"}]`.
+#[wasm_bindgen]
+pub fn sign_bitcoin_transaction(network: &str, transaction_hex: &str, inputs: &str) -> Result<String, JsValue> {
+    fn sign<N: BitcoinNetwork>(transaction_hex: &str, inputs: &str) -> Result<String, JsValue> {
+        let mut inputs: Vec<TransactionInput> = serde_json::from_str(inputs).map_err(to_error)?;
+        let transaction_bytes = hex::decode(transaction_hex).map_err(to_error)?;
+        let mut transaction = BitcoinTransaction::<N>::from_transaction_bytes(&transaction_bytes).map_err(to_error)?;
+
+        for input in &inputs {
+            let private_key = BitcoinPrivateKey::<N>::from_str(&input.private_key).map_err(to_error)?;
+            let address = BitcoinAddress::<N>::from_str(&input.address).map_err(to_error)?;
+
+            let redeem_script = match (&input.redeem_script, address.format()) {
+                (Some(script), _) => Some(hex::decode(script).map_err(to_error)?),
+                (None, BitcoinFormat::P2SH_P2WPKH) => {
+                    let mut redeem_script = vec![0x00, 0x14];
+                    redeem_script.extend(&hash160(
+                        &private_key.to_public_key().to_secp256k1_public_key().serialize(),
+                    ));
+                    Some(redeem_script)
+                }
+                (None, _) => None,
+            };
+
+            let script_pub_key = match &input.script_pub_key {
+                Some(script) => Some(hex::decode(script).map_err(to_error)?),
+                None => None,
+            };
+
+            let mut reverse_transaction_id = hex::decode(&input.txid).map_err(to_error)?;
+            reverse_transaction_id.reverse();
+
+            let outpoint = Outpoint::<N>::new(
+                reverse_transaction_id,
+                input.vout,
+                Some(address),
+                Some(BitcoinAmount::from_satoshi(input.amount as i64).map_err(to_error)?),
+                redeem_script,
+                script_pub_key,
+            )
+            .map_err(to_error)?;
+
+            transaction = transaction.update_outpoint(outpoint);
+            transaction = transaction.sign(&private_key).map_err(to_error)?;
+        }
+        inputs.iter_mut().for_each(|input| input.private_key.zeroize());
+
+        Ok(hex::encode(&transaction.to_transaction_bytes().map_err(to_error)?))
+    }
+
+    match network {
+        Mainnet::NAME => sign::<Mainnet>(transaction_hex, inputs),
+        Testnet::NAME => sign::<Testnet>(transaction_hex, inputs),
+        Regtest::NAME => sign::<Regtest>(transaction_hex, inputs),
+        _ => Err(to_error(format_args!("invalid network: {}", network))),
+    }
+}
+
+/// Returns the address format of the given name.
+fn to_format(format: &str) -> Result<BitcoinFormat, JsValue> {
+    match format {
+        "legacy" | "p2pkh" => Ok(BitcoinFormat::P2PKH),
+        "segwit" | "p2sh_p2wpkh" => Ok(BitcoinFormat::P2SH_P2WPKH),
+        "bech32" => Ok(BitcoinFormat::Bech32),
+        _ => Err(to_error(format_args!("invalid format: {}", format))),
+    }
+}
+
+/// Returns a JavaScript error with the message of the given error.
+fn to_error<E: Display>(error: E) -> JsValue {
+    JsValue::from_str(&error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_mnemonic() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let wallet = BitcoinWallet::from_mnemonic("mainnet", mnemonic, None, "m/44'/0'/0'/0/0").unwrap();
+        assert_eq!(Some(mnemonic.to_string()), wallet.mnemonic());
+        assert_eq!(
+            "L4p2b9VAf8k5aUahF1JCJUzZkgNEAqLfq8DDdQiyAprQAKSbu8hf",
+            wallet.private_key()
+        );
+        assert_eq!("1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA", wallet.address());
+    }
+
+    #[test]
+    fn sign_transaction() {
+        let transaction_hex = "01000000019d344070eac3fe6e394a16d06d7704a7d5c0a10eb2a2c16bc98842b7cc20d5610000000000ffffffff01e02e0000000000001976a91406afd46bcdfd22ef94ac122aa11f241244a37ecc88ac00000000";
+        let inputs = r#"[{"txid":"61d520ccb74288c96bc1a2b20ea1c0d5a704776dd0164a396efec3ea7040349d","vout":0,"amount":0,"address":"17XBj6iFEsf8kzDMGQk5ghZipxX49VXuaV","privatekey":"L1uyy5qTuGrVXrmrsvHWHgVzW9kKdrp27wBC7Vs6nZDTF2BRUVwy"}]"#;
+        let expected_signed_transaction = "01000000019d344070eac3fe6e394a16d06d7704a7d5c0a10eb2a2c16bc98842b7cc20d561000000006b48304502210088828c0bdfcdca68d8ae0caeb6ec62cd3fd5f9b2191848edae33feb533df35d302202e0beadd35e17e7f83a733f5277028a9b453d525553e3f5d2d7a7aa8010a81d60121029f50f51d63b345039a290c94bffd3180c99ed659ff6ea6b1242bca47eb93b59fffffffff01e02e0000000000001976a91406afd46bcdfd22ef94ac122aa11f241244a37ecc88ac00000000";
+        assert_eq!(
+            expected_signed_transaction,
+            sign_bitcoin_transaction("mainnet", transaction_hex, inputs).unwrap()
+        );
+    }
+}
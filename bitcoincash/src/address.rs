@@ -0,0 +1,658 @@
+use crate::cashaddr;
+use crate::format::BitcoinCashFormat;
+use crate::network::BitcoinCashNetwork;
+use crate::private_key::BitcoinCashPrivateKey;
+use crate::public_key::BitcoinCashPublicKey;
+use wagyu_model::no_std::*;
+use wagyu_model::{crypto::hash160, encoding::base58check, Address, AddressError, PrivateKey};
+
+use base58::FromBase58;
+use core::{convert::TryFrom, fmt, marker::PhantomData, str::FromStr};
+
+/// Represents a Bitcoin Cash address
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BitcoinCashAddress<N: BitcoinCashNetwork> {
+    /// The Bitcoin Cash address
+    address: String,
+    /// The format of the address
+    format: BitcoinCashFormat,
+    /// PhantomData
+    _network: PhantomData<N>,
+}
+
+impl<N: BitcoinCashNetwork> Address for BitcoinCashAddress<N> {
+    type Format = BitcoinCashFormat;
+    type PrivateKey = BitcoinCashPrivateKey<N>;
+    type PublicKey = BitcoinCashPublicKey<N>;
+
+    /// Returns the address corresponding to the given Bitcoin Cash private key.
+    fn from_private_key(private_key: &Self::PrivateKey, format: &Self::Format) -> Result<Self, AddressError> {
+        Self::from_public_key(&private_key.to_public_key(), format)
+    }
+
+    /// Returns the address corresponding to the given Bitcoin Cash public key.
+    fn from_public_key(public_key: &Self::PublicKey, format: &Self::Format) -> Result<Self, AddressError> {
+        match format {
+            BitcoinCashFormat::P2PKH => Self::p2pkh(public_key),
+            BitcoinCashFormat::CashAddr => Self::cashaddr(public_key),
+        }
+    }
+}
+
+impl<N: BitcoinCashNetwork> BitcoinCashAddress<N> {
+    /// Returns a P2PKH address from a given Bitcoin Cash public key.
+    pub fn p2pkh(public_key: &<Self as Address>::PublicKey) -> Result<Self, AddressError> {
+        Self::from_hash(&Self::public_key_hash(public_key), &BitcoinCashFormat::P2PKH)
+    }
+
+    /// Returns a CashAddr address from a given Bitcoin Cash public key.
+    pub fn cashaddr(public_key: &<Self as Address>::PublicKey) -> Result<Self, AddressError> {
+        Self::from_hash(&Self::public_key_hash(public_key), &BitcoinCashFormat::CashAddr)
+    }
+
+    /// Returns the address of the given public key hash in the specified format.
+    pub fn from_hash(hash: &[u8], format: &BitcoinCashFormat) -> Result<Self, AddressError> {
+        if hash.len() != 20 {
+            return Err(AddressError::InvalidByteLength(hash.len()));
+        }
+
+        let address = match format {
+            BitcoinCashFormat::P2PKH => {
+                let mut address = [0u8; 21];
+                address[0] = N::to_address_prefix(format)[0];
+                address[1..21].copy_from_slice(hash);
+
+                base58check::encode(&address)
+            }
+            BitcoinCashFormat::CashAddr => {
+                let prefix = String::from_utf8(N::to_address_prefix(format))?;
+                cashaddr::encode(&prefix, cashaddr::P2PKH_VERSION, hash)?
+            }
+        };
+
+        Ok(Self {
+            address,
+            format: format.clone(),
+            _network: PhantomData,
+        })
+    }
+
+    /// Returns the public key hash of the Bitcoin Cash address.
+    pub fn to_hash(&self) -> Result<Vec<u8>, AddressError> {
+        match self.format {
+            BitcoinCashFormat::P2PKH => Ok(self.address.from_base58()?[1..21].to_vec()),
+            BitcoinCashFormat::CashAddr => {
+                let prefix = String::from_utf8(N::to_address_prefix(&self.format))?;
+                Ok(cashaddr::decode(&self.address, &prefix)?.2)
+            }
+        }
+    }
+
+    /// Returns the legacy base58 encoding of the Bitcoin Cash address.
+    pub fn to_legacy(&self) -> Result<Self, AddressError> {
+        Self::from_hash(&self.to_hash()?, &BitcoinCashFormat::P2PKH)
+    }
+
+    /// Returns the CashAddr encoding of the Bitcoin Cash address.
+    pub fn to_cashaddr(&self) -> Result<Self, AddressError> {
+        Self::from_hash(&self.to_hash()?, &BitcoinCashFormat::CashAddr)
+    }
+
+    /// Returns the format of the Bitcoin Cash address.
+    pub fn format(&self) -> BitcoinCashFormat {
+        self.format.clone()
+    }
+
+    /// Returns the hash160 of the serialized public key.
+    fn public_key_hash(public_key: &<Self as Address>::PublicKey) -> Vec<u8> {
+        match public_key.is_compressed() {
+            true => hash160(&public_key.to_secp256k1_public_key().serialize_compressed()),
+            false => hash160(&public_key.to_secp256k1_public_key().serialize()),
+        }
+    }
+
+    /// Returns a CashAddr address from a given string, with or without its network prefix.
+    fn from_cashaddr_str(address: &str) -> Result<Self, AddressError> {
+        let default_prefix = String::from_utf8(N::to_address_prefix(&BitcoinCashFormat::CashAddr))?;
+        let (prefix, version, hash) = cashaddr::decode(address, &default_prefix)?;
+
+        // Check that the address prefix corresponds to the correct network.
+        let _ = N::from_address_prefix(prefix.as_bytes())?;
+        if version != cashaddr::P2PKH_VERSION {
+            return Err(AddressError::InvalidAddress(address.into()));
+        }
+
+        Self::from_hash(&hash, &BitcoinCashFormat::CashAddr)
+    }
+}
+
+impl<'a, N: BitcoinCashNetwork> TryFrom<&'a str> for BitcoinCashAddress<N> {
+    type Error = AddressError;
+
+    fn try_from(address: &'a str) -> Result<Self, Self::Error> {
+        Self::from_str(address)
+    }
+}
+
+impl<N: BitcoinCashNetwork> FromStr for BitcoinCashAddress<N> {
+    type Err = AddressError;
+
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        // Legacy addresses are at most 35 characters, while CashAddr payloads alone are 42 characters.
+        if address.contains(':') || address.len() > 35 {
+            return Self::from_cashaddr_str(address);
+        }
+
+        if address.len() < 26 {
+            return Err(AddressError::InvalidCharacterLength(address.len()));
+        }
+
+        let data = address.from_base58()?;
+        if data.len() != 25 {
+            return Err(AddressError::InvalidByteLength(data.len()));
+        }
+
+        base58check::verify(&data)?;
+
+        // Check that the address prefix corresponds to the correct network.
+        let _ = N::from_address_prefix(&data[0..1])?;
+        let format = BitcoinCashFormat::from_address_prefix(&data[0..1])?;
+
+        Ok(Self {
+            address: address.into(),
+            format,
+            _network: PhantomData,
+        })
+    }
+}
+
+impl<N: BitcoinCashNetwork> fmt::Display for BitcoinCashAddress<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+    use wagyu_model::public_key::PublicKey;
+
+    fn test_from_private_key<N: BitcoinCashNetwork>(
+        expected_address: &str,
+        private_key: &BitcoinCashPrivateKey<N>,
+        format: &BitcoinCashFormat,
+    ) {
+        let address = BitcoinCashAddress::from_private_key(private_key, format).unwrap();
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    fn test_from_public_key<N: BitcoinCashNetwork>(
+        expected_address: &str,
+        public_key: &BitcoinCashPublicKey<N>,
+        format: &BitcoinCashFormat,
+    ) {
+        let address = BitcoinCashAddress::from_public_key(public_key, format).unwrap();
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    fn test_from_str<N: BitcoinCashNetwork>(expected_address: &str, expected_format: &BitcoinCashFormat) {
+        let address = BitcoinCashAddress::<N>::from_str(expected_address).unwrap();
+        assert_eq!(expected_address, address.to_string());
+        assert_eq!(*expected_format, address.format);
+    }
+
+    fn test_to_str<N: BitcoinCashNetwork>(expected_address: &str, address: &BitcoinCashAddress<N>) {
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    mod p2pkh_mainnet_compressed {
+        use super::*;
+
+        type N = Mainnet;
+
+        const KEYPAIRS: [(&str, &str); 5] = [
+            (
+                "L2o7RUmise9WoxNzmnVZeK83Mmt5Nn1NBpeftbthG5nsLWCzSKVg",
+                "1GUwicFwsZbdE3XyJYjmPryiiuTiK7mZgS",
+            ),
+            (
+                "KzjKw25tuQoiDyQjUG38ZRNBdnfr5eMBnTsU4JahrVDwFCpRZP1J",
+                "1J2shZV5b53GRVmTqmr3tJhkVbBML29C1z",
+            ),
+            (
+                "L2N8YRtxNMAVFAtxBt9PFSADtdvbmzFFHLSU61CtLdhYhrCGPfWh",
+                "13TdfCiGPagApSJZu1o1Y3mpfqpp6oK2GB",
+            ),
+            (
+                "KwXH1Mu4FBtGN9nRn2VkBpienaVGZKvCAkZAdE96kK71dHR1oDRs",
+                "1HaeDGHf3A2Uxeh3sKjVLYTn1hnEyuzLjF",
+            ),
+            (
+                "KwN7qiBnU4GNhboBhuPaPaFingTDKU4r27pGggwQYz865TvBT74V",
+                "12WMrNLRosydPNNYM96dwk9jDv8rDRom3J",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = BitcoinCashPrivateKey::<N>::from_str(private_key).unwrap();
+                test_from_private_key(address, &private_key, &BitcoinCashFormat::P2PKH);
+            });
+        }
+
+        #[test]
+        fn from_public_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = BitcoinCashPrivateKey::<N>::from_str(private_key).unwrap();
+                let public_key = BitcoinCashPublicKey::<N>::from_private_key(&private_key);
+                test_from_public_key(address, &public_key, &BitcoinCashFormat::P2PKH);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, address)| {
+                test_from_str::<N>(address, &BitcoinCashFormat::P2PKH);
+            });
+        }
+
+        #[test]
+        fn to_str() {
+            KEYPAIRS.iter().for_each(|(_, expected_address)| {
+                let address = BitcoinCashAddress::<N>::from_str(expected_address).unwrap();
+                test_to_str(expected_address, &address);
+            });
+        }
+    }
+
+    mod p2pkh_mainnet_uncompressed {
+        use super::*;
+
+        type N = Mainnet;
+
+        const KEYPAIRS: [(&str, &str); 5] = [
+            (
+                "5K9VY2kaJ264Pj4ygobGLk7JJMgZ2i6wQ9FFKEBxoFtKeAXPHYm",
+                "18Bap2Lh5HJckiZcg8SYXoF5iPxkUoCN8u",
+            ),
+            (
+                "5KiudZRwr9wH5auJaW66WK3CGR1UzL7ZXiicvZEEaFScbbEt9Qs",
+                "192JSK8wNP867JGxHNHay3obNSXqEyyhtx",
+            ),
+            (
+                "5KCxYELatMGyVZfZFcSAw1Hz4ngiURKS22x7ydNRxcXfUzhgWMH",
+                "1NoZQSmjYHUZMbqLerwmT4xfe8A6mAo8TT",
+            ),
+            (
+                "5KT9CMP2Kgh2Afi8GbmFAHJXsH5DhcpH9KY3aH4Hkv5W6dASy7F",
+                "1NyGFd49x4nqoau8RJvjf9tGZkoUNjwd5a",
+            ),
+            (
+                "5J4cXobHh2cF2MHpLvTFjEHZCtrNHzyDzKGE8LuST2VWP129pAE",
+                "17nsg1F155BR6ie2miiLrSnMhF8GWcGq6V",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = BitcoinCashPrivateKey::<N>::from_str(private_key).unwrap();
+                test_from_private_key(address, &private_key, &BitcoinCashFormat::P2PKH);
+            });
+        }
+
+        #[test]
+        fn from_public_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = BitcoinCashPrivateKey::<N>::from_str(private_key).unwrap();
+                let public_key = BitcoinCashPublicKey::<N>::from_private_key(&private_key);
+                test_from_public_key(address, &public_key, &BitcoinCashFormat::P2PKH);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, address)| {
+                test_from_str::<N>(address, &BitcoinCashFormat::P2PKH);
+            });
+        }
+
+        #[test]
+        fn to_str() {
+            KEYPAIRS.iter().for_each(|(_, expected_address)| {
+                let address = BitcoinCashAddress::<N>::from_str(expected_address).unwrap();
+                test_to_str(expected_address, &address);
+            });
+        }
+
+        #[test]
+        fn test_invalid() {
+            // Mismatched keypair
+
+            let private_key = "5K9VY2kaJ264Pj4ygobGLk7JJMgZ2i6wQ9FFKEBxoFtKeAXPHYm";
+            let expected_address = "12WMrNLRosydPNNYM96dwk9jDv8rDRom3J";
+
+            let private_key = BitcoinCashPrivateKey::<N>::from_str(private_key).unwrap();
+            let address = BitcoinCashAddress::<N>::from_private_key(&private_key, &BitcoinCashFormat::P2PKH).unwrap();
+            assert_ne!(expected_address, address.to_string());
+
+            let public_key = BitcoinCashPublicKey::<N>::from_private_key(&private_key);
+            let address = BitcoinCashAddress::<N>::from_public_key(&public_key, &BitcoinCashFormat::P2PKH).unwrap();
+            assert_ne!(expected_address, address.to_string());
+
+            // Invalid address length
+
+            let address = "1";
+            assert!(BitcoinCashAddress::<N>::from_str(address).is_err());
+
+            let address = "12WMrNLRosydPNN";
+            assert!(BitcoinCashAddress::<N>::from_str(address).is_err());
+
+            let address = "12WMrNLRosydPNNYM96dwk9jDv8rDRom3";
+            assert!(BitcoinCashAddress::<N>::from_str(address).is_err());
+
+            let address = "12WMrNLRosydPNNYM96dwk9jDv8rDRom3J12WMrNLRosydPNNYM";
+            assert!(BitcoinCashAddress::<N>::from_str(address).is_err());
+
+            let address = "12WMrNLRosydPNNYM96dwk9jDv8rDRom3J12WMrNLRosydPNNYM96dwk9jDv8rDRom3J";
+            assert!(BitcoinCashAddress::<N>::from_str(address).is_err());
+        }
+    }
+
+    mod p2pkh_testnet_compressed {
+        use super::*;
+
+        type N = Testnet;
+
+        const KEYPAIRS: [(&str, &str); 5] = [
+            (
+                "cSCkpm1oSHTUtX5CHdQ4FzTv9qxLQWKx2SXMg22hbGSTNVcsUcCX",
+                "mwCDgjeRgGpfTMY1waYAJF2dGz4Q5XAx6w",
+            ),
+            (
+                "cNp5uMWdh68Nk3pwShjxsSwhGPoCYgFvE1ANuPsk6qhcT4Jvp57n",
+                "myH91eNrQKuuM7TeQYYddzL4URn6HiYbxW",
+            ),
+            (
+                "cN9aUHNMMLT9yqBJ3S5qnEPtP11nhT7ivkFK1FqNYQMozZPgMTjJ",
+                "mho8tsQtF7fx2bPKudMcXvGpUVYRHHiH4m",
+            ),
+            (
+                "cSRpda6Bhog5SUyot96HSwSzn7FZNWzudKzoCzkgZrf9hUaL3Ass",
+                "n3DgWHuAkg7eiPGH5gP8jeg3SbHBhuPJWS",
+            ),
+            (
+                "cTqLNf3iCaW61ofgmyf4ZxChUL8DZoCEPmNTCKRsexLSdNuGWQT1",
+                "mjhMXrTdq4X1dcqTaNDjwGdVaJEGBKpCRj",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = BitcoinCashPrivateKey::<N>::from_str(private_key).unwrap();
+                test_from_private_key(address, &private_key, &BitcoinCashFormat::P2PKH);
+            });
+        }
+
+        #[test]
+        fn from_public_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = BitcoinCashPrivateKey::<N>::from_str(private_key).unwrap();
+                let public_key = BitcoinCashPublicKey::<N>::from_private_key(&private_key);
+                test_from_public_key(address, &public_key, &BitcoinCashFormat::P2PKH);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, address)| {
+                test_from_str::<N>(address, &BitcoinCashFormat::P2PKH);
+            });
+        }
+
+        #[test]
+        fn to_str() {
+            KEYPAIRS.iter().for_each(|(_, expected_address)| {
+                let address = BitcoinCashAddress::<N>::from_str(expected_address).unwrap();
+                test_to_str(expected_address, &address);
+            });
+        }
+    }
+
+    mod p2pkh_testnet_uncompressed {
+        use super::*;
+
+        type N = Testnet;
+
+        const KEYPAIRS: [(&str, &str); 5] = [
+            (
+                "934pVYUzZ7Sm4ZSP7MtXaQXAcMhZHpFHFBvzfW3epFgk5cWeYih",
+                "my55YLK4BmM8AyUW5px2HSSKL4yzUE5Pho",
+            ),
+            (
+                "91dTfyLPPneZA6RsAXqNuT6qTQdAuuGVCUjmBtzgd1Tnd4RQT5K",
+                "mw4afqNgGjn34okVmv9qH2WkvhfyTyNbde",
+            ),
+            (
+                "92GweXA6j4RCF3zHXGGy2ShJq6T7u9rrjmuYd9ktLHgNrWznzUC",
+                "moYi3FQZKtcc66edT3uMwVQCcswenpNscU",
+            ),
+            (
+                "92QAQdzrEDkMExM9hHV5faWqKTdXcTgXguRBcyAyYqFCjVzhDLE",
+                "mpRYQJ64ofurTCA3KKkaCjjUNqjYkUvB4w",
+            ),
+            (
+                "92H9Kf4ikaqNAJLc5tbwvbmiBWJzNDGtYmnvrigZeDVD3aqJ85Q",
+                "mvqRXtgQKqumMosPY3dLvhdYsQJV2AswkA",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = BitcoinCashPrivateKey::<N>::from_str(private_key).unwrap();
+                test_from_private_key(address, &private_key, &BitcoinCashFormat::P2PKH);
+            });
+        }
+
+        #[test]
+        fn from_public_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = BitcoinCashPrivateKey::<N>::from_str(private_key).unwrap();
+                let public_key = BitcoinCashPublicKey::<N>::from_private_key(&private_key);
+                test_from_public_key(address, &public_key, &BitcoinCashFormat::P2PKH);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, address)| {
+                test_from_str::<N>(address, &BitcoinCashFormat::P2PKH);
+            });
+        }
+
+        #[test]
+        fn to_str() {
+            KEYPAIRS.iter().for_each(|(_, expected_address)| {
+                let address = BitcoinCashAddress::<N>::from_str(expected_address).unwrap();
+                test_to_str(expected_address, &address);
+            });
+        }
+    }
+
+    mod cashaddr_mainnet_compressed {
+        use super::*;
+
+        type N = Mainnet;
+
+        const KEYPAIRS: [(&str, &str, &str); 5] = [
+            (
+                "L2o7RUmise9WoxNzmnVZeK83Mmt5Nn1NBpeftbthG5nsLWCzSKVg",
+                "1GUwicFwsZbdE3XyJYjmPryiiuTiK7mZgS",
+                "bitcoincash:qz5a9jezg98mdfy6ml8fwjm49ekn95nchseh23me8p",
+            ),
+            (
+                "KzjKw25tuQoiDyQjUG38ZRNBdnfr5eMBnTsU4JahrVDwFCpRZP1J",
+                "1J2shZV5b53GRVmTqmr3tJhkVbBML29C1z",
+                "bitcoincash:qzadfcpan2nvlpalxa885s25pjzwcpm80s8hf2mwg2",
+            ),
+            (
+                "L2N8YRtxNMAVFAtxBt9PFSADtdvbmzFFHLSU61CtLdhYhrCGPfWh",
+                "13TdfCiGPagApSJZu1o1Y3mpfqpp6oK2GB",
+                "bitcoincash:qqd0n2hvu5v4uqenzmwyrn6zx653y2vjuymgzh25dh",
+            ),
+            (
+                "KwXH1Mu4FBtGN9nRn2VkBpienaVGZKvCAkZAdE96kK71dHR1oDRs",
+                "1HaeDGHf3A2Uxeh3sKjVLYTn1hnEyuzLjF",
+                "bitcoincash:qz6aan2y0r9ag0pkngupljwevj396ttxkge5u0lqn2",
+            ),
+            (
+                "KwN7qiBnU4GNhboBhuPaPaFingTDKU4r27pGggwQYz865TvBT74V",
+                "12WMrNLRosydPNNYM96dwk9jDv8rDRom3J",
+                "bitcoincash:qqggtxp8kp6s0w9ye5xgqypykpq2zxxumy52dahjf0",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(private_key, _, address)| {
+                let private_key = BitcoinCashPrivateKey::<N>::from_str(private_key).unwrap();
+                test_from_private_key(address, &private_key, &BitcoinCashFormat::CashAddr);
+            });
+        }
+
+        #[test]
+        fn from_public_key() {
+            KEYPAIRS.iter().for_each(|(private_key, _, address)| {
+                let private_key = BitcoinCashPrivateKey::<N>::from_str(private_key).unwrap();
+                let public_key = BitcoinCashPublicKey::<N>::from_private_key(&private_key);
+                test_from_public_key(address, &public_key, &BitcoinCashFormat::CashAddr);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, _, address)| {
+                test_from_str::<N>(address, &BitcoinCashFormat::CashAddr);
+            });
+        }
+
+        #[test]
+        fn to_str() {
+            KEYPAIRS.iter().for_each(|(_, _, expected_address)| {
+                let address = BitcoinCashAddress::<N>::from_str(expected_address).unwrap();
+                test_to_str(expected_address, &address);
+            });
+        }
+
+        #[test]
+        fn conversion() {
+            KEYPAIRS.iter().for_each(|(_, legacy, cashaddr)| {
+                let address = BitcoinCashAddress::<N>::from_str(legacy).unwrap();
+                assert_eq!(*cashaddr, address.to_cashaddr().unwrap().to_string());
+
+                let address = BitcoinCashAddress::<N>::from_str(cashaddr).unwrap();
+                assert_eq!(*legacy, address.to_legacy().unwrap().to_string());
+
+                // The network prefix may be omitted.
+                let address = BitcoinCashAddress::<N>::from_str(&cashaddr["bitcoincash:".len()..]).unwrap();
+                assert_eq!(*cashaddr, address.to_string());
+            });
+        }
+
+        #[test]
+        fn test_invalid() {
+            // Invalid checksum
+
+            let address = "bitcoincash:qz5a9jezg98mdfy6ml8fwjm49ekn95nchseh23me8q";
+            assert!(BitcoinCashAddress::<N>::from_str(address).is_err());
+
+            // Mixed case
+
+            let address = "bitcoincash:qz5a9jezg98mdfy6ml8fwjm49ekn95nchseh23mE8p";
+            assert!(BitcoinCashAddress::<N>::from_str(address).is_err());
+
+            // Invalid network
+
+            let address = "bchtest:qz4ldagr0lmwe5errg0pfxqjq4mzdjypmg6rzegts4";
+            assert!(BitcoinCashAddress::<N>::from_str(address).is_err());
+
+            // P2SH is unsupported
+
+            let address = "bitcoincash:ppm2qsznhks23z7629mms6s4cwef74vcwvn0h829pq";
+            assert!(BitcoinCashAddress::<N>::from_str(address).is_err());
+        }
+    }
+
+    mod cashaddr_testnet_compressed {
+        use super::*;
+
+        type N = Testnet;
+
+        const KEYPAIRS: [(&str, &str, &str); 5] = [
+            (
+                "cSCkpm1oSHTUtX5CHdQ4FzTv9qxLQWKx2SXMg22hbGSTNVcsUcCX",
+                "mwCDgjeRgGpfTMY1waYAJF2dGz4Q5XAx6w",
+                "bchtest:qz4ldagr0lmwe5errg0pfxqjq4mzdjypmg6rzegts4",
+            ),
+            (
+                "cNp5uMWdh68Nk3pwShjxsSwhGPoCYgFvE1ANuPsk6qhcT4Jvp57n",
+                "myH91eNrQKuuM7TeQYYddzL4URn6HiYbxW",
+                "bchtest:qrpd2ccxj5fx7zzsf3ydgzezvg2zpfs7lgmdmt3rdd",
+            ),
+            (
+                "cN9aUHNMMLT9yqBJ3S5qnEPtP11nhT7ivkFK1FqNYQMozZPgMTjJ",
+                "mho8tsQtF7fx2bPKudMcXvGpUVYRHHiH4m",
+                "bchtest:qqv0lsts2kafnjqetm3m9cxv6gh0frrha58zamcrka",
+            ),
+            (
+                "cSRpda6Bhog5SUyot96HSwSzn7FZNWzudKzoCzkgZrf9hUaL3Ass",
+                "n3DgWHuAkg7eiPGH5gP8jeg3SbHBhuPJWS",
+                "bchtest:qrhqapqjyrz38cgvtmwvscdd63a726kq8q9jhj24m7",
+            ),
+            (
+                "cTqLNf3iCaW61ofgmyf4ZxChUL8DZoCEPmNTCKRsexLSdNuGWQT1",
+                "mjhMXrTdq4X1dcqTaNDjwGdVaJEGBKpCRj",
+                "bchtest:qqkasrusxltw8cytfhpgdx4gpsxc8kxnfuuu3vjqg7",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(private_key, _, address)| {
+                let private_key = BitcoinCashPrivateKey::<N>::from_str(private_key).unwrap();
+                test_from_private_key(address, &private_key, &BitcoinCashFormat::CashAddr);
+            });
+        }
+
+        #[test]
+        fn from_public_key() {
+            KEYPAIRS.iter().for_each(|(private_key, _, address)| {
+                let private_key = BitcoinCashPrivateKey::<N>::from_str(private_key).unwrap();
+                let public_key = BitcoinCashPublicKey::<N>::from_private_key(&private_key);
+                test_from_public_key(address, &public_key, &BitcoinCashFormat::CashAddr);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, _, address)| {
+                test_from_str::<N>(address, &BitcoinCashFormat::CashAddr);
+            });
+        }
+
+        #[test]
+        fn conversion() {
+            KEYPAIRS.iter().for_each(|(_, legacy, cashaddr)| {
+                let address = BitcoinCashAddress::<N>::from_str(legacy).unwrap();
+                assert_eq!(*cashaddr, address.to_cashaddr().unwrap().to_string());
+
+                let address = BitcoinCashAddress::<N>::from_str(cashaddr).unwrap();
+                assert_eq!(*legacy, address.to_legacy().unwrap().to_string());
+            });
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use wagyu_model::no_std::*;
+use wagyu_model::{Amount, AmountError};
+
+use core::fmt;
+use serde::Serialize;
+
+// Number of satoshi (base unit) per BCH
+const COIN: i64 = 1_0000_0000;
+
+// Maximum number of satoshi
+const MAX_COINS: i64 = 21_000_000 * COIN;
+
+/// Represents the amount of Bitcoin Cash in satoshi
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct BitcoinCashAmount(pub i64);
+
+pub enum Denomination {
+    // satoshi
+    Satoshi,
+    // BCH
+    BitcoinCash,
+}
+
+impl Denomination {
+    /// The number of decimal places more than a satoshi.
+    fn precision(self) -> u32 {
+        match self {
+            Denomination::Satoshi => 0,
+            Denomination::BitcoinCash => 8,
+        }
+    }
+}
+
+impl fmt::Display for Denomination {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Denomination::Satoshi => "satoshi",
+                Denomination::BitcoinCash => "BCH",
+            }
+        )
+    }
+}
+
+impl Amount for BitcoinCashAmount {}
+
+impl BitcoinCashAmount {
+    /// The zero amount.
+    pub const ZERO: BitcoinCashAmount = BitcoinCashAmount(0);
+    /// Exactly one satoshi.
+    pub const ONE_SAT: BitcoinCashAmount = BitcoinCashAmount(1);
+    /// Exactly one bitcoin cash.
+    pub const ONE_BCH: BitcoinCashAmount = BitcoinCashAmount(COIN);
+
+    pub fn from_satoshi(satoshi: i64) -> Result<Self, AmountError> {
+        if (-MAX_COINS..=MAX_COINS).contains(&satoshi) {
+            Ok(Self(satoshi))
+        } else {
+            Err(AmountError::AmountOutOfBounds(
+                satoshi.to_string(),
+                MAX_COINS.to_string(),
+            ))
+        }
+    }
+
+    pub fn from_bch(bch_value: i64) -> Result<Self, AmountError> {
+        match bch_value.checked_mul(10_i64.pow(Denomination::BitcoinCash.precision())) {
+            Some(satoshi) => Self::from_satoshi(satoshi),
+            None => Err(AmountError::AmountOutOfBounds(
+                bch_value.to_string(),
+                (MAX_COINS / COIN).to_string(),
+            )),
+        }
+    }
+
+    /// Returns the amount of a decimal value in BCH, as in `12.5`, with at most 8 decimal places.
+    pub fn from_bch_str(bch_value: &str) -> Result<Self, AmountError> {
+        let invalid = || AmountError::InvalidAmount(bch_value.into());
+        let (integer, fraction) = match bch_value.find('.') {
+            Some(index) => (&bch_value[..index], &bch_value[index + 1..]),
+            None => (bch_value, ""),
+        };
+        let precision = Denomination::BitcoinCash.precision() as usize;
+        if (integer.is_empty() && fraction.is_empty())
+            || fraction.len() > precision
+            || !integer.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let integer = match integer.is_empty() {
+            true => 0,
+            false => integer.parse::<i64>().map_err(|_| invalid())?,
+        };
+        let fraction = format!("{:0<1$}", fraction, precision)
+            .parse::<i64>()
+            .map_err(|_| invalid())?;
+        match integer
+            .checked_mul(COIN)
+            .and_then(|satoshi| satoshi.checked_add(fraction))
+        {
+            Some(satoshi) => Self::from_satoshi(satoshi),
+            None => Err(invalid()),
+        }
+    }
+
+    /// Returns the decimal value of the amount in BCH, without trailing zeros, as in `12.5`.
+    pub fn to_bch_string(&self) -> String {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let (integer, fraction) = (self.0.abs() / COIN, self.0.abs() % COIN);
+        match fraction {
+            0 => format!("{}{}", sign, integer),
+            _ => format!("{}{}.{:08}", sign, integer, fraction)
+                .trim_end_matches('0')
+                .to_string(),
+        }
+    }
+
+    pub fn add(self, b: Self) -> Result<Self, AmountError> {
+        Self::from_satoshi(self.0 + b.0)
+    }
+
+    pub fn sub(self, b: BitcoinCashAmount) -> Result<Self, AmountError> {
+        Self::from_satoshi(self.0 - b.0)
+    }
+}
+
+impl fmt::Display for BitcoinCashAmount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_satoshi_conversion() {
+        assert_eq!(BitcoinCashAmount(0), BitcoinCashAmount::from_satoshi(0).unwrap());
+        assert_eq!(BitcoinCashAmount(1), BitcoinCashAmount::from_satoshi(1).unwrap());
+        assert_eq!(
+            BitcoinCashAmount(MAX_COINS),
+            BitcoinCashAmount::from_satoshi(MAX_COINS).unwrap()
+        );
+        assert!(BitcoinCashAmount::from_satoshi(MAX_COINS + 1).is_err());
+        assert!(BitcoinCashAmount::from_satoshi(-MAX_COINS - 1).is_err());
+    }
+
+    #[test]
+    fn test_bch_conversion() {
+        assert_eq!(BitcoinCashAmount::ONE_BCH, BitcoinCashAmount::from_bch(1).unwrap());
+        assert_eq!(
+            BitcoinCashAmount(69_000_000_000),
+            BitcoinCashAmount::from_bch(690).unwrap()
+        );
+        assert_eq!(
+            BitcoinCashAmount(MAX_COINS),
+            BitcoinCashAmount::from_bch(21_000_000).unwrap()
+        );
+        assert!(BitcoinCashAmount::from_bch(21_000_001).is_err());
+        assert!(BitcoinCashAmount::from_bch(i64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_bch_str_conversion() {
+        let pairs = [
+            ("0", 0),
+            ("1", 100_000_000),
+            ("12.5", 1_250_000_000),
+            (".5", 50_000_000),
+            ("0.00000001", 1),
+            ("21000000", MAX_COINS),
+        ];
+        pairs.iter().for_each(|(bch_value, satoshi)| {
+            let amount = BitcoinCashAmount::from_bch_str(bch_value).unwrap();
+            assert_eq!(BitcoinCashAmount(*satoshi), amount);
+        });
+        assert_eq!("12.5", BitcoinCashAmount(1_250_000_000).to_bch_string());
+        assert_eq!("0.00000001", BitcoinCashAmount(1).to_bch_string());
+        assert_eq!("-3", BitcoinCashAmount(-300_000_000).to_bch_string());
+
+        let invalid = ["", ".", "-1", "1e8", "0.000000001", "1.0.0", "21000000.00000001"];
+        invalid.iter().for_each(|bch_value| {
+            assert!(BitcoinCashAmount::from_bch_str(bch_value).is_err(), "{}", bch_value);
+        });
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = BitcoinCashAmount::from_bch(100).unwrap();
+        let b = BitcoinCashAmount::from_satoshi(1).unwrap();
+        assert_eq!(BitcoinCashAmount(10_000_000_001), a.add(b).unwrap());
+        assert_eq!(BitcoinCashAmount(9_999_999_999), a.sub(b).unwrap());
+        assert!(BitcoinCashAmount(MAX_COINS).add(b).is_err());
+        assert!(BitcoinCashAmount(-MAX_COINS).sub(b).is_err());
+    }
+}
@@ -0,0 +1,168 @@
+//!
+//! CashAddr
+//!
+//! This module contains the encoding and decoding of Bitcoin Cash addresses in the CashAddr format.
+//! https://github.com/bitcoincashorg/bitcoincash.org/blob/master/spec/cashaddr.md
+//!
+//! A CashAddr address is a prefix, such as `bitcoincash`, a separator `:`, and a base32 payload.
+//! The payload is a version byte, the hash, and a 40-bit BCH checksum over the prefix and payload.
+//! The version byte holds the type of the address in bits 3 to 6 (0 for P2PKH, 1 for P2SH),
+//! and the size of the hash in bits 0 to 2 (0 for a 160-bit hash).
+//!
+
+use wagyu_model::no_std::*;
+use wagyu_model::AddressError;
+
+/// The base32 characters of a CashAddr payload
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// The generators of the BCH checksum
+const GENERATORS: [u64; 5] = [0x98f2bc8e61, 0x79b76d99e2, 0xf33e5fb3c4, 0xae2eabe2a8, 0x1e4f43e470];
+
+/// The version byte of a P2PKH address with a 160-bit hash
+pub const P2PKH_VERSION: u8 = 0x00;
+
+/// Returns the 40-bit BCH checksum of the given 5-bit values.
+fn polymod(values: &[u8]) -> u64 {
+    let mut c = 1u64;
+    for value in values {
+        let c0 = (c >> 35) as u8;
+        c = ((c & 0x07_ffff_ffff) << 5) ^ (*value as u64);
+        GENERATORS.iter().enumerate().for_each(|(i, generator)| {
+            if c0 & (1 << i) != 0 {
+                c ^= generator;
+            }
+        });
+    }
+    c ^ 1
+}
+
+/// Returns the lower 5 bits of each character of the prefix, followed by the zero separator.
+fn expand_prefix(prefix: &str) -> Vec<u8> {
+    prefix.bytes().map(|byte| byte & 0x1f).chain(Some(0)).collect()
+}
+
+/// Returns the eight 5-bit values of the checksum of the given prefix and payload.
+fn checksum(prefix: &str, payload: &[u8]) -> Vec<u8> {
+    let checksum = polymod(&[expand_prefix(prefix), payload.to_vec(), vec![0; 8]].concat());
+    (0..8).map(|i| ((checksum >> (5 * (7 - i))) & 0x1f) as u8).collect()
+}
+
+/// Returns the given values regrouped from `from` bits to `to` bits, padding the final group with zeros if `pad`.
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Result<Vec<u8>, AddressError> {
+    let (mut accumulator, mut bits) = (0u32, 0u32);
+    let max = (1u32 << to) - 1;
+    let mut result = vec![];
+    for value in data {
+        accumulator = (accumulator << from) | *value as u32;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            result.push(((accumulator >> bits) & max) as u8);
+        }
+    }
+    match (pad, bits) {
+        (true, 0) => {}
+        (true, _) => result.push(((accumulator << (to - bits)) & max) as u8),
+        (false, _) if bits >= from || (accumulator << (to - bits)) & max != 0 => {
+            return Err(AddressError::InvalidAddress("invalid padding".into()))
+        }
+        (false, _) => {}
+    };
+    Ok(result)
+}
+
+/// Returns the CashAddr address of the given prefix, version byte, and hash.
+pub fn encode(prefix: &str, version: u8, hash: &[u8]) -> Result<String, AddressError> {
+    let payload = convert_bits(&[&[version], hash].concat(), 8, 5, true)?;
+    let checksum = checksum(prefix, &payload);
+
+    let payload: String = payload
+        .into_iter()
+        .chain(checksum)
+        .map(|value| CHARSET[value as usize] as char)
+        .collect();
+    Ok(format!("{}:{}", prefix, payload))
+}
+
+/// Returns the prefix, version byte, and hash of the given CashAddr address.
+/// If the address omits its prefix, then it is decoded with the given default prefix.
+pub fn decode(address: &str, default_prefix: &str) -> Result<(String, u8, Vec<u8>), AddressError> {
+    let lowercase = address.to_lowercase();
+    if lowercase != address && address.to_uppercase() != address {
+        return Err(AddressError::InvalidAddress(address.into()));
+    }
+
+    let (prefix, payload) = match lowercase.rfind(':') {
+        Some(index) => (lowercase[..index].to_string(), &lowercase[index + 1..]),
+        None => (default_prefix.to_string(), &lowercase[..]),
+    };
+    let values = payload
+        .bytes()
+        .map(|byte| CHARSET.iter().position(|c| *c == byte).map(|value| value as u8))
+        .collect::<Option<Vec<u8>>>()
+        .ok_or_else(|| AddressError::InvalidAddress(address.into()))?;
+    if values.len() < 9 {
+        return Err(AddressError::InvalidCharacterLength(address.len()));
+    }
+    if polymod(&[expand_prefix(&prefix), values.clone()].concat()) != 0 {
+        let (data, found) = values.split_at(values.len() - 8);
+        let expected = checksum(&prefix, data);
+        let to_string =
+            |values: &[u8]| -> String { values.iter().map(|value| CHARSET[*value as usize] as char).collect() };
+        return Err(AddressError::InvalidChecksum(to_string(&expected), to_string(found)));
+    }
+
+    let data = convert_bits(&values[..values.len() - 8], 5, 8, false)?;
+    let (version, hash) = data.split_first().ok_or(AddressError::InvalidByteLength(0))?;
+    if *version & 0x80 != 0 || *version & 0x07 != 0 || hash.len() != 20 {
+        return Err(AddressError::InvalidByteLength(hash.len()));
+    }
+    Ok((prefix, *version, hash.to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode() {
+        // https://github.com/bitcoincashorg/bitcoincash.org/blob/master/spec/cashaddr.md#examples-of-address-translation
+        let hash = hex::decode("76a04053bda0a88bda5177b86a15c3b29f559873").unwrap();
+        let address = "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a";
+        assert_eq!(address, encode("bitcoincash", P2PKH_VERSION, &hash).unwrap());
+        assert_eq!(
+            ("bitcoincash".to_string(), P2PKH_VERSION, hash.clone()),
+            decode(address, "bitcoincash").unwrap()
+        );
+        assert_eq!(
+            ("bitcoincash".to_string(), P2PKH_VERSION, hash.clone()),
+            decode("qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a", "bitcoincash").unwrap()
+        );
+        assert_eq!(
+            ("bitcoincash".to_string(), P2PKH_VERSION, hash),
+            decode(&address.to_uppercase(), "bitcoincash").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_decode() {
+        let invalid = [
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6c",
+            "bchtest:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a",
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdX6a",
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdxba",
+            "bitcoincash:",
+        ];
+        invalid.iter().for_each(|address| {
+            assert!(decode(address, "bitcoincash").is_err(), "{}", address);
+        });
+
+        match decode(invalid[0], "bitcoincash") {
+            Err(AddressError::InvalidChecksum(expected, found)) => {
+                assert_eq!(("y22gdx6a", "y22gdx6c"), (&*expected, &*found))
+            }
+            result => panic!("{:?}", result),
+        };
+    }
+}
@@ -0,0 +1,58 @@
+use crate::network::BitcoinCashNetwork;
+use wagyu_model::no_std::*;
+use wagyu_model::{AddressError, ExtendedPrivateKeyError, ExtendedPublicKeyError, Format};
+
+use core::fmt;
+use serde::Serialize;
+
+/// Represents the format of a Bitcoin Cash address
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BitcoinCashFormat {
+    /// Legacy Pay-to-Pubkey Hash, e.g. 1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu
+    P2PKH,
+    /// CashAddr Pay-to-Pubkey Hash, e.g. bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a
+    CashAddr,
+}
+
+impl Format for BitcoinCashFormat {}
+
+impl BitcoinCashFormat {
+    /// Returns the address prefix of the given network.
+    pub fn to_address_prefix<N: BitcoinCashNetwork>(&self) -> Vec<u8> {
+        N::to_address_prefix(self)
+    }
+
+    /// Returns the format of the given address prefix.
+    pub fn from_address_prefix(prefix: &[u8]) -> Result<Self, AddressError> {
+        match prefix {
+            [0x00] | [0x6F] => Ok(BitcoinCashFormat::P2PKH),
+            b"bitcoincash" | b"bchtest" => Ok(BitcoinCashFormat::CashAddr),
+            _ => Err(AddressError::InvalidPrefix(prefix.to_vec())),
+        }
+    }
+
+    /// Returns the network of the given extended private key version bytes.
+    pub fn from_extended_private_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPrivateKeyError> {
+        match prefix[0..4] {
+            [0x04, 0x88, 0xAD, 0xE4] | [0x04, 0x35, 0x83, 0x94] => Ok(BitcoinCashFormat::P2PKH),
+            _ => Err(ExtendedPrivateKeyError::InvalidVersionBytes(prefix.to_vec())),
+        }
+    }
+
+    /// Returns the network of the given extended public key version bytes.
+    pub fn from_extended_public_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPublicKeyError> {
+        match prefix[0..4] {
+            [0x04, 0x88, 0xB2, 0x1E] | [0x04, 0x35, 0x87, 0xCF] => Ok(BitcoinCashFormat::P2PKH),
+            _ => Err(ExtendedPublicKeyError::InvalidVersionBytes(prefix.to_vec())),
+        }
+    }
+}
+
+impl fmt::Display for BitcoinCashFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BitcoinCashFormat::P2PKH => write!(f, "p2pkh"),
+            BitcoinCashFormat::CashAddr => write!(f, "cashaddr"),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::network::{BitcoinCashNetwork, Mainnet, Testnet};
+use wagyu_model::{Network, NetworkError};
+
+use core::{convert::TryFrom, fmt, str::FromStr};
+
+/// Represents a Bitcoin Cash network selected at runtime.
+///
+/// Each variant corresponds to a type-level network, to and from which it converts, and `apply`
+/// runs a function that is generic over the Bitcoin Cash networks on the type-level network it selects.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AnyNetwork {
+    Mainnet,
+    Testnet,
+}
+
+/// The interface for a function that is generic over the Bitcoin Cash networks,
+/// which is applied to a network selected at runtime with `AnyNetwork::apply`.
+pub trait BitcoinCashNetworkFn {
+    type Output;
+
+    /// Returns the output of the function on the network `N`.
+    fn call<N: BitcoinCashNetwork>(self) -> Self::Output;
+}
+
+impl AnyNetwork {
+    /// The Bitcoin Cash networks
+    pub const ALL: [AnyNetwork; 2] = [AnyNetwork::Mainnet, AnyNetwork::Testnet];
+
+    /// Returns the runtime network of the type-level network `N`.
+    pub fn of<N: BitcoinCashNetwork>() -> Result<Self, NetworkError> {
+        Self::from_str(N::NAME)
+    }
+
+    /// Returns the name of the network.
+    pub fn name(&self) -> &'static str {
+        match self {
+            AnyNetwork::Mainnet => Mainnet::NAME,
+            AnyNetwork::Testnet => Testnet::NAME,
+        }
+    }
+
+    /// Returns the output of the function on the type-level network of the network.
+    pub fn apply<F: BitcoinCashNetworkFn>(&self, function: F) -> F::Output {
+        match self {
+            AnyNetwork::Mainnet => function.call::<Mainnet>(),
+            AnyNetwork::Testnet => function.call::<Testnet>(),
+        }
+    }
+}
+
+impl From<Mainnet> for AnyNetwork {
+    fn from(_: Mainnet) -> Self {
+        AnyNetwork::Mainnet
+    }
+}
+
+impl TryFrom<AnyNetwork> for Mainnet {
+    type Error = NetworkError;
+
+    fn try_from(network: AnyNetwork) -> Result<Self, Self::Error> {
+        match network {
+            AnyNetwork::Mainnet => Ok(Mainnet),
+            _ => Err(NetworkError::InvalidNetwork(network.name().into())),
+        }
+    }
+}
+
+impl From<Testnet> for AnyNetwork {
+    fn from(_: Testnet) -> Self {
+        AnyNetwork::Testnet
+    }
+}
+
+impl TryFrom<AnyNetwork> for Testnet {
+    type Error = NetworkError;
+
+    fn try_from(network: AnyNetwork) -> Result<Self, Self::Error> {
+        match network {
+            AnyNetwork::Testnet => Ok(Testnet),
+            _ => Err(NetworkError::InvalidNetwork(network.name().into())),
+        }
+    }
+}
+
+impl FromStr for AnyNetwork {
+    type Err = NetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|network| network.name() == s)
+            .copied()
+            .ok_or_else(|| NetworkError::InvalidNetwork(s.into()))
+    }
+}
+
+impl fmt::Display for AnyNetwork {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,96 @@
+use crate::format::BitcoinCashFormat;
+use crate::network::BitcoinCashNetwork;
+use wagyu_model::no_std::*;
+use wagyu_model::{
+    AddressError, ChildIndex, ExtendedPrivateKeyError, ExtendedPublicKeyError, Network, NetworkError, PrivateKeyError,
+};
+
+use core::{fmt, str::FromStr};
+use serde::Serialize;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct Mainnet;
+
+impl Network for Mainnet {
+    const NAME: &'static str = "mainnet";
+}
+
+impl BitcoinCashNetwork for Mainnet {
+    const HD_COIN_TYPE: ChildIndex = ChildIndex::Hardened(145);
+
+    /// Returns the address prefix of the given network.
+    fn to_address_prefix(format: &BitcoinCashFormat) -> Vec<u8> {
+        match format {
+            BitcoinCashFormat::P2PKH => vec![0x00],
+            BitcoinCashFormat::CashAddr => b"bitcoincash".to_vec(),
+        }
+    }
+
+    /// Returns the network of the given address prefix.
+    fn from_address_prefix(prefix: &[u8]) -> Result<Self, AddressError> {
+        match prefix {
+            [0x00] | b"bitcoincash" => Ok(Self),
+            _ => Err(AddressError::InvalidPrefix(prefix.to_owned())),
+        }
+    }
+
+    /// Returns the wif prefix of the given network.
+    fn to_private_key_prefix() -> u8 {
+        0x80
+    }
+
+    /// Returns the network of the given wif prefix.
+    fn from_private_key_prefix(prefix: u8) -> Result<Self, PrivateKeyError> {
+        match prefix {
+            0x80 => Ok(Self),
+            _ => Err(PrivateKeyError::InvalidPrefix(vec![prefix])),
+        }
+    }
+
+    /// Returns the extended private key version bytes of the given network.
+    fn to_extended_private_key_version_bytes(format: &BitcoinCashFormat) -> Result<Vec<u8>, ExtendedPrivateKeyError> {
+        match format {
+            BitcoinCashFormat::P2PKH | BitcoinCashFormat::CashAddr => Ok(vec![0x04, 0x88, 0xAD, 0xE4]), // xprv
+        }
+    }
+
+    /// Returns the network of the given extended private key version bytes.
+    fn from_extended_private_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPrivateKeyError> {
+        match prefix[0..4] {
+            [0x04, 0x88, 0xAD, 0xE4] => Ok(Self),
+            _ => Err(ExtendedPrivateKeyError::InvalidVersionBytes(prefix.to_vec())),
+        }
+    }
+
+    /// Returns the extended public key version bytes of the given network.
+    fn to_extended_public_key_version_bytes(format: &BitcoinCashFormat) -> Result<Vec<u8>, ExtendedPublicKeyError> {
+        match format {
+            BitcoinCashFormat::P2PKH | BitcoinCashFormat::CashAddr => Ok(vec![0x04, 0x88, 0xB2, 0x1E]), // xpub
+        }
+    }
+
+    /// Returns the network of the given extended public key version bytes.
+    fn from_extended_public_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPublicKeyError> {
+        match prefix[0..4] {
+            [0x04, 0x88, 0xB2, 0x1E] => Ok(Self),
+            _ => Err(ExtendedPublicKeyError::InvalidVersionBytes(prefix.to_vec())),
+        }
+    }
+}
+
+impl FromStr for Mainnet {
+    type Err = NetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            Self::NAME => Ok(Self),
+            _ => Err(NetworkError::InvalidNetwork(s.into())),
+        }
+    }
+}
+
+impl fmt::Display for Mainnet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::NAME)
+    }
+}
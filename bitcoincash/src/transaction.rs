@@ -0,0 +1,961 @@
+use crate::address::BitcoinCashAddress;
+use crate::amount::BitcoinCashAmount;
+use crate::format::BitcoinCashFormat;
+use crate::network::BitcoinCashNetwork;
+use crate::private_key::BitcoinCashPrivateKey;
+use crate::public_key::BitcoinCashPublicKey;
+use wagyu_model::no_std::{io::Read, *};
+use wagyu_model::{PrivateKey, PublicKey, Transaction, TransactionError, TransactionId};
+
+use core::{fmt, str::FromStr};
+use secp256k1;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+/// Returns the variable length integer of the given value.
+/// https://en.bitcoin.it/wiki/Protocol_documentation#Variable_length_integer
+pub fn variable_length_integer(value: u64) -> Result<Vec<u8>, TransactionError> {
+    match value {
+        // bounded by u8::max_value()
+        0..=252 => Ok(vec![value as u8]),
+        // bounded by u16::max_value()
+        253..=65535 => Ok([vec![0xfd], (value as u16).to_le_bytes().to_vec()].concat()),
+        // bounded by u32::max_value()
+        65536..=4294967295 => Ok([vec![0xfe], (value as u32).to_le_bytes().to_vec()].concat()),
+        // bounded by u64::max_value()
+        _ => Ok([vec![0xff], value.to_le_bytes().to_vec()].concat()),
+    }
+}
+
+/// Decode the value of a variable length integer.
+/// https://en.bitcoin.it/wiki/Protocol_documentation#Variable_length_integer
+pub fn read_variable_length_integer<R: Read>(mut reader: R) -> Result<usize, TransactionError> {
+    let mut flag = [0u8; 1];
+    reader.read_exact(&mut flag)?;
+
+    match flag[0] {
+        0..=252 => Ok(flag[0] as usize),
+        0xfd => {
+            let mut size = [0u8; 2];
+            reader.read_exact(&mut size)?;
+            match u16::from_le_bytes(size) {
+                s if s < 253 => Err(TransactionError::InvalidVariableSizeInteger(s as usize)),
+                s => Ok(s as usize),
+            }
+        }
+        0xfe => {
+            let mut size = [0u8; 4];
+            reader.read_exact(&mut size)?;
+            match u32::from_le_bytes(size) {
+                s if s < 65536 => Err(TransactionError::InvalidVariableSizeInteger(s as usize)),
+                s => Ok(s as usize),
+            }
+        }
+        _ => {
+            let mut size = [0u8; 8];
+            reader.read_exact(&mut size)?;
+            match u64::from_le_bytes(size) {
+                s if s < 4294967296 => Err(TransactionError::InvalidVariableSizeInteger(s as usize)),
+                s => Ok(s as usize),
+            }
+        }
+    }
+}
+
+pub struct BitcoinCashVector;
+
+impl BitcoinCashVector {
+    /// Read and output a vector with a variable length integer
+    pub fn read<R: Read, E, F>(mut reader: R, func: F) -> Result<Vec<E>, TransactionError>
+    where
+        F: Fn(&mut R) -> Result<E, TransactionError>,
+    {
+        let count = read_variable_length_integer(&mut reader)?;
+        (0..count).map(|_| func(&mut reader)).collect()
+    }
+}
+
+/// Generate the script_pub_key of a corresponding address.
+/// Legacy and CashAddr addresses both pay to a public key hash, and share the same script.
+pub fn create_script_pub_key<N: BitcoinCashNetwork>(
+    address: &BitcoinCashAddress<N>,
+) -> Result<Vec<u8>, TransactionError> {
+    let pub_key_hash = address.to_hash()?;
+
+    let mut script = vec![];
+    script.push(Opcode::OP_DUP as u8);
+    script.push(Opcode::OP_HASH160 as u8);
+    script.extend(variable_length_integer(pub_key_hash.len() as u64)?);
+    script.extend(pub_key_hash);
+    script.push(Opcode::OP_EQUALVERIFY as u8);
+    script.push(Opcode::OP_CHECKSIG as u8);
+    Ok(script)
+}
+
+/// Represents a Bitcoin Cash signature hash, which always sets SIGHASH_FORKID (0x40)
+/// to prevent the replay of its signatures on Bitcoin.
+/// https://github.com/bitcoincashorg/bitcoincash.org/blob/master/spec/replay-protected-sighash.md
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[allow(non_camel_case_types)]
+pub enum SignatureHash {
+    /// Signs all inputs and outputs.
+    SIGHASH_ALL_SIGHASH_FORKID = 0x41,
+    /// Signs all inputs and none of the outputs.
+    SIGHASH_NONE_SIGHASH_FORKID = 0x42,
+    /// Signs all inputs and one corresponding output per input.
+    SIGHASH_SINGLE_SIGHASH_FORKID = 0x43,
+    /// Signs only one input and all outputs.
+    SIGHASH_ALL_SIGHASH_FORKID_SIGHASH_ANYONECANPAY = 0xc1,
+    /// Signs only one input and none of the outputs.
+    SIGHASH_NONE_SIGHASH_FORKID_SIGHASH_ANYONECANPAY = 0xc2,
+    /// Signs only one input and one corresponding output per input.
+    SIGHASH_SINGLE_SIGHASH_FORKID_SIGHASH_ANYONECANPAY = 0xc3,
+}
+
+impl fmt::Display for SignatureHash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SignatureHash::SIGHASH_ALL_SIGHASH_FORKID => write!(f, "SIGHASH_ALL | SIGHASH_FORKID"),
+            SignatureHash::SIGHASH_NONE_SIGHASH_FORKID => write!(f, "SIGHASH_NONE | SIGHASH_FORKID"),
+            SignatureHash::SIGHASH_SINGLE_SIGHASH_FORKID => write!(f, "SIGHASH_SINGLE | SIGHASH_FORKID"),
+            SignatureHash::SIGHASH_ALL_SIGHASH_FORKID_SIGHASH_ANYONECANPAY => {
+                write!(f, "SIGHASH_ALL | SIGHASH_FORKID | SIGHASH_ANYONECANPAY")
+            }
+            SignatureHash::SIGHASH_NONE_SIGHASH_FORKID_SIGHASH_ANYONECANPAY => {
+                write!(f, "SIGHASH_NONE | SIGHASH_FORKID | SIGHASH_ANYONECANPAY")
+            }
+            SignatureHash::SIGHASH_SINGLE_SIGHASH_FORKID_SIGHASH_ANYONECANPAY => {
+                write!(f, "SIGHASH_SINGLE | SIGHASH_FORKID | SIGHASH_ANYONECANPAY")
+            }
+        }
+    }
+}
+
+impl SignatureHash {
+    fn from_byte(byte: &u8) -> Self {
+        match byte {
+            0x42 => SignatureHash::SIGHASH_NONE_SIGHASH_FORKID,
+            0x43 => SignatureHash::SIGHASH_SINGLE_SIGHASH_FORKID,
+            0xc1 => SignatureHash::SIGHASH_ALL_SIGHASH_FORKID_SIGHASH_ANYONECANPAY,
+            0xc2 => SignatureHash::SIGHASH_NONE_SIGHASH_FORKID_SIGHASH_ANYONECANPAY,
+            0xc3 => SignatureHash::SIGHASH_SINGLE_SIGHASH_FORKID_SIGHASH_ANYONECANPAY,
+            _ => SignatureHash::SIGHASH_ALL_SIGHASH_FORKID,
+        }
+    }
+
+    /// Returns true if the signature hash only signs its own input.
+    fn is_anyone_can_pay(&self) -> bool {
+        *self as u8 & 0x80 != 0
+    }
+
+    /// Returns the base type of the signature hash (1 for ALL, 2 for NONE, 3 for SINGLE).
+    fn base_type(&self) -> u8 {
+        *self as u8 & 0x1f
+    }
+}
+
+/// Represents the commonly used script opcodes
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[allow(non_camel_case_types)]
+pub enum Opcode {
+    OP_DUP = 0x76,
+    OP_HASH160 = 0xa9,
+    OP_CHECKSIG = 0xac,
+    OP_EQUALVERIFY = 0x88,
+}
+
+impl fmt::Display for Opcode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Opcode::OP_DUP => write!(f, "OP_DUP"),
+            Opcode::OP_HASH160 => write!(f, "OP_HASH160"),
+            Opcode::OP_CHECKSIG => write!(f, "OP_CHECKSIG"),
+            Opcode::OP_EQUALVERIFY => write!(f, "OP_EQUALVERIFY"),
+        }
+    }
+}
+
+/// Represents a Bitcoin Cash transaction outpoint
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Outpoint<N: BitcoinCashNetwork> {
+    /// The previous transaction hash (32 bytes) (uses reversed hash order from Bitcoin Cash RPC)
+    pub reverse_transaction_id: Vec<u8>,
+    /// The index of the transaction input (4 bytes)
+    pub index: u32,
+    /// The amount associated with this input (used for transaction signatures)
+    pub amount: Option<BitcoinCashAmount>,
+    /// The script public key associated with spending this input
+    pub script_pub_key: Option<Vec<u8>>,
+    /// The address of the outpoint
+    pub address: Option<BitcoinCashAddress<N>>,
+}
+
+impl<N: BitcoinCashNetwork> Outpoint<N> {
+    /// Returns a new Bitcoin Cash transaction outpoint
+    pub fn new(
+        reverse_transaction_id: Vec<u8>,
+        index: u32,
+        address: Option<BitcoinCashAddress<N>>,
+        amount: Option<BitcoinCashAmount>,
+        script_pub_key: Option<Vec<u8>>,
+    ) -> Result<Self, TransactionError> {
+        let script_pub_key = match &address {
+            Some(address) => {
+                let script_pub_key = match script_pub_key {
+                    Some(script_pub_key) => script_pub_key,
+                    None => create_script_pub_key::<N>(address)?,
+                };
+                if script_pub_key.len() != 25
+                    || script_pub_key[0] != Opcode::OP_DUP as u8
+                    || script_pub_key[1] != Opcode::OP_HASH160 as u8
+                    || script_pub_key[24] != Opcode::OP_CHECKSIG as u8
+                {
+                    return Err(TransactionError::InvalidScriptPubKey("P2PKH".into()));
+                }
+                Some(script_pub_key)
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            reverse_transaction_id,
+            index,
+            amount,
+            script_pub_key,
+            address,
+        })
+    }
+}
+
+/// Represents a Bitcoin Cash transaction input
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BitcoinCashTransactionInput<N: BitcoinCashNetwork> {
+    /// The outpoint (36 bytes)
+    pub outpoint: Outpoint<N>,
+    /// The transaction input script (variable size)
+    pub script_sig: Vec<u8>,
+    /// The sequence number (4 bytes) (0xFFFFFFFF unless lock > 0)
+    pub sequence: Vec<u8>,
+    /// The signature hash (4 bytes) (used in signing raw transaction only)
+    pub sighash_code: SignatureHash,
+    /// If true, the input has been signed
+    pub is_signed: bool,
+}
+
+impl<N: BitcoinCashNetwork> BitcoinCashTransactionInput<N> {
+    const DEFAULT_SEQUENCE: [u8; 4] = [0xff, 0xff, 0xff, 0xff];
+
+    /// Returns a new Bitcoin Cash transaction input without the script (unlocking).
+    pub fn new(
+        transaction_id: Vec<u8>,
+        index: u32,
+        address: Option<BitcoinCashAddress<N>>,
+        amount: Option<BitcoinCashAmount>,
+        script_pub_key: Option<Vec<u8>>,
+        sequence: Option<Vec<u8>>,
+        sighash: SignatureHash,
+    ) -> Result<Self, TransactionError> {
+        if transaction_id.len() != 32 {
+            return Err(TransactionError::InvalidTransactionId(transaction_id.len()));
+        }
+
+        // Byte-wise reverse of computed SHA-256 hash values
+        // https://bitcoin.org/en/developer-reference#hash-byte-order
+        let mut reverse_transaction_id = transaction_id;
+        reverse_transaction_id.reverse();
+
+        let outpoint = Outpoint::<N>::new(reverse_transaction_id, index, address, amount, script_pub_key)?;
+
+        Ok(Self {
+            outpoint,
+            script_sig: vec![],
+            sequence: sequence.unwrap_or_else(|| Self::DEFAULT_SEQUENCE.to_vec()),
+            sighash_code: sighash,
+            is_signed: false,
+        })
+    }
+
+    /// Read and output a Bitcoin Cash transaction input
+    pub fn read<R: Read>(mut reader: &mut R) -> Result<Self, TransactionError> {
+        let mut transaction_hash = [0u8; 32];
+        let mut vin = [0u8; 4];
+        let mut sequence = [0u8; 4];
+
+        reader.read_exact(&mut transaction_hash)?;
+        reader.read_exact(&mut vin)?;
+
+        let outpoint = Outpoint::<N>::new(transaction_hash.to_vec(), u32::from_le_bytes(vin), None, None, None)?;
+
+        let script_sig: Vec<u8> = BitcoinCashVector::read(&mut reader, |s| {
+            let mut byte = [0u8; 1];
+            s.read_exact(&mut byte)?;
+            Ok(byte[0])
+        })?;
+
+        reader.read_exact(&mut sequence)?;
+
+        let script_sig_len = match script_sig.is_empty() {
+            true => 0,
+            false => read_variable_length_integer(&script_sig[..])?,
+        };
+        let sighash_code = SignatureHash::from_byte(&match script_sig_len {
+            0 => SignatureHash::SIGHASH_ALL_SIGHASH_FORKID as u8,
+            length => match script_sig.get(length) {
+                Some(sighash_code) => *sighash_code,
+                None => return Err(TransactionError::InvalidVariableSizeInteger(length)),
+            },
+        });
+
+        Ok(Self {
+            outpoint,
+            is_signed: !script_sig.is_empty(),
+            script_sig,
+            sequence: sequence.to_vec(),
+            sighash_code,
+        })
+    }
+
+    /// Returns the serialized transaction input.
+    pub fn serialize(&self) -> Result<Vec<u8>, TransactionError> {
+        let mut input = vec![];
+        input.extend(&self.outpoint.reverse_transaction_id);
+        input.extend(&self.outpoint.index.to_le_bytes());
+        input.extend(variable_length_integer(self.script_sig.len() as u64)?);
+        input.extend(&self.script_sig);
+        input.extend(&self.sequence);
+        Ok(input)
+    }
+}
+
+/// Represents a Bitcoin Cash transaction output
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BitcoinCashTransactionOutput {
+    /// The amount (in satoshi)
+    pub amount: BitcoinCashAmount,
+    /// The public key script
+    pub script_pub_key: Vec<u8>,
+}
+
+impl BitcoinCashTransactionOutput {
+    /// Returns a Bitcoin Cash transaction output.
+    pub fn new<N: BitcoinCashNetwork>(
+        address: &BitcoinCashAddress<N>,
+        amount: BitcoinCashAmount,
+    ) -> Result<Self, TransactionError> {
+        Ok(Self {
+            amount,
+            script_pub_key: create_script_pub_key::<N>(address)?,
+        })
+    }
+
+    /// Read and output a Bitcoin Cash transaction output
+    pub fn read<R: Read>(mut reader: &mut R) -> Result<Self, TransactionError> {
+        let mut amount = [0u8; 8];
+        reader.read_exact(&mut amount)?;
+
+        let script_pub_key: Vec<u8> = BitcoinCashVector::read(&mut reader, |s| {
+            let mut byte = [0u8; 1];
+            s.read_exact(&mut byte)?;
+            Ok(byte[0])
+        })?;
+
+        Ok(Self {
+            amount: BitcoinCashAmount::from_satoshi(u64::from_le_bytes(amount) as i64)?,
+            script_pub_key,
+        })
+    }
+
+    /// Returns the serialized transaction output.
+    pub fn serialize(&self) -> Result<Vec<u8>, TransactionError> {
+        let mut output = vec![];
+        output.extend(&self.amount.0.to_le_bytes());
+        output.extend(variable_length_integer(self.script_pub_key.len() as u64)?);
+        output.extend(&self.script_pub_key);
+        Ok(output)
+    }
+}
+
+/// Represents a Bitcoin Cash transaction id
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BitcoinCashTransactionId {
+    txid: Vec<u8>,
+}
+
+impl TransactionId for BitcoinCashTransactionId {}
+
+impl fmt::Display for BitcoinCashTransactionId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", &hex::encode(&self.txid))
+    }
+}
+
+/// Represents the Bitcoin Cash transaction parameters
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BitcoinCashTransactionParameters<N: BitcoinCashNetwork> {
+    /// The version number (4 bytes)
+    pub version: u32,
+    /// The transaction inputs
+    pub inputs: Vec<BitcoinCashTransactionInput<N>>,
+    /// The transaction outputs
+    pub outputs: Vec<BitcoinCashTransactionOutput>,
+    /// The lock time (4 bytes)
+    pub lock_time: u32,
+}
+
+impl<N: BitcoinCashNetwork> BitcoinCashTransactionParameters<N> {
+    /// Read and output the Bitcoin Cash transaction parameters
+    pub fn read<R: Read>(mut reader: R) -> Result<Self, TransactionError> {
+        let mut version = [0u8; 4];
+        reader.read_exact(&mut version)?;
+
+        let inputs = BitcoinCashVector::read(&mut reader, BitcoinCashTransactionInput::<N>::read)?;
+        let outputs = BitcoinCashVector::read(&mut reader, BitcoinCashTransactionOutput::read)?;
+
+        let mut lock_time = [0u8; 4];
+        reader.read_exact(&mut lock_time)?;
+
+        Ok(Self {
+            version: u32::from_le_bytes(version),
+            inputs,
+            outputs,
+            lock_time: u32::from_le_bytes(lock_time),
+        })
+    }
+}
+
+/// Represents a Bitcoin Cash transaction
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BitcoinCashTransaction<N: BitcoinCashNetwork> {
+    /// The transaction parameters (version, inputs, outputs, lock_time)
+    parameters: BitcoinCashTransactionParameters<N>,
+}
+
+impl<N: BitcoinCashNetwork> Transaction for BitcoinCashTransaction<N> {
+    type Address = BitcoinCashAddress<N>;
+    type Amount = BitcoinCashAmount;
+    type Format = BitcoinCashFormat;
+    type PrivateKey = BitcoinCashPrivateKey<N>;
+    type PublicKey = BitcoinCashPublicKey<N>;
+    type TransactionId = BitcoinCashTransactionId;
+    type TransactionParameters = BitcoinCashTransactionParameters<N>;
+
+    /// Returns an unsigned transaction given the transaction parameters.
+    fn new(parameters: &Self::TransactionParameters) -> Result<Self, TransactionError> {
+        Ok(Self {
+            parameters: parameters.clone(),
+        })
+    }
+
+    /// Returns a signed transaction given the private key of the sender.
+    fn sign(&self, private_key: &Self::PrivateKey) -> Result<Self, TransactionError> {
+        let public_key = private_key.to_public_key();
+        let mut transaction = self.clone();
+        for vin in 0..self.parameters.inputs.len() {
+            if transaction.is_signer(vin, &public_key)? && !transaction.parameters.inputs[vin].is_signed {
+                // Transaction hash
+                let transaction_hash = transaction.to_sighash(vin)?;
+
+                // Signature
+                let (signature, _) = secp256k1::sign(
+                    &secp256k1::Message::parse_slice(&transaction_hash)?,
+                    &private_key.to_secp256k1_secret_key(),
+                );
+                transaction.insert_signature(vin, &signature, &public_key)?;
+            }
+        }
+        Ok(transaction)
+    }
+
+    /// Returns a transaction signed with a signature produced outside of the library, such as by a
+    /// hardware device, given the public key of the signer. The signature is inserted into each unsigned
+    /// input of the public key whose sighash it signs, or errors if it signs none of them.
+    fn sign_with_signature(&self, signature: &[u8], public_key: &Self::PublicKey) -> Result<Self, TransactionError> {
+        let mut signature = match signature.len() {
+            64 | 65 => secp256k1::Signature::parse_slice(&signature[..64])?,
+            length => {
+                return Err(TransactionError::Message(format!(
+                    "invalid signature length {}",
+                    length
+                )))
+            }
+        };
+        signature.normalize_s();
+
+        let mut transaction = self.clone();
+        let mut is_signed = false;
+        for vin in 0..self.parameters.inputs.len() {
+            if transaction.is_signer(vin, public_key)? && !transaction.parameters.inputs[vin].is_signed {
+                let message = secp256k1::Message::parse_slice(&transaction.to_sighash(vin)?)?;
+                if secp256k1::verify(&message, &signature, &public_key.to_secp256k1_public_key()) {
+                    transaction.insert_signature(vin, &signature, public_key)?;
+                    is_signed = true;
+                }
+            }
+        }
+        match is_signed {
+            true => Ok(transaction),
+            false => Err(TransactionError::Message(
+                "the signature does not sign an input of the public key".into(),
+            )),
+        }
+    }
+
+    /// Returns a transaction given the transaction bytes.
+    fn from_transaction_bytes(transaction: &Vec<u8>) -> Result<Self, TransactionError> {
+        Ok(Self {
+            parameters: Self::TransactionParameters::read(&transaction[..])?,
+        })
+    }
+
+    /// Returns the transaction in bytes.
+    fn to_transaction_bytes(&self) -> Result<Vec<u8>, TransactionError> {
+        let mut transaction = self.parameters.version.to_le_bytes().to_vec();
+
+        transaction.extend(variable_length_integer(self.parameters.inputs.len() as u64)?);
+        for input in &self.parameters.inputs {
+            transaction.extend(input.serialize()?);
+        }
+
+        transaction.extend(variable_length_integer(self.parameters.outputs.len() as u64)?);
+        for output in &self.parameters.outputs {
+            transaction.extend(output.serialize()?);
+        }
+
+        transaction.extend(&self.parameters.lock_time.to_le_bytes());
+
+        Ok(transaction)
+    }
+
+    /// Returns the transaction id.
+    /// Returns the hash of the preimage of the input at the given index, with its signature hash type.
+    fn to_sighash(&self, index: usize) -> Result<Vec<u8>, TransactionError> {
+        let input = match self.parameters.inputs.get(index) {
+            Some(input) => input,
+            None => return Err(TransactionError::InvalidInputs(format!("no input at index {}", index))),
+        };
+        let preimage = self.hash_preimage(index, input.sighash_code)?;
+        Ok(Sha256::digest(&Sha256::digest(&preimage)).to_vec())
+    }
+
+    /// Returns the transaction id.
+    fn to_transaction_id(&self) -> Result<Self::TransactionId, TransactionError> {
+        let mut txid = Sha256::digest(&Sha256::digest(&self.to_transaction_bytes()?)).to_vec();
+        txid.reverse();
+
+        Ok(Self::TransactionId { txid })
+    }
+
+    /// Returns the fee of the transaction, the amount of its inputs minus the amount of its outputs,
+    /// or `None` if the amount of an input is unknown, as it is not serialized in the transaction.
+    fn fee(&self) -> Option<Self::Amount> {
+        if self.parameters.inputs.is_empty() {
+            return None;
+        }
+
+        let mut fee = 0;
+        for input in &self.parameters.inputs {
+            fee += input.outpoint.amount?.0;
+        }
+        for output in &self.parameters.outputs {
+            fee -= output.amount.0;
+        }
+        BitcoinCashAmount::from_satoshi(fee).ok()
+    }
+}
+
+impl<N: BitcoinCashNetwork> BitcoinCashTransaction<N> {
+    /// Returns the transaction parameters.
+    pub fn parameters(&self) -> &BitcoinCashTransactionParameters<N> {
+        &self.parameters
+    }
+
+    /// Returns `true` if the input at the given index is spent by the given public key.
+    fn is_signer(&self, vin: usize, public_key: &BitcoinCashPublicKey<N>) -> Result<bool, TransactionError> {
+        match &self.parameters.inputs[vin].outpoint.address {
+            Some(address) => Ok(address == &public_key.to_address(&address.format())?),
+            None => Ok(false),
+        }
+    }
+
+    /// Inserts the signature of the input at the given index, by the given public key,
+    /// into its script with the signature hash type of the input.
+    fn insert_signature(
+        &mut self,
+        vin: usize,
+        signature: &secp256k1::Signature,
+        public_key: &BitcoinCashPublicKey<N>,
+    ) -> Result<(), TransactionError> {
+        let mut signature = signature.serialize_der().as_ref().to_vec();
+        signature.push(self.parameters.inputs[vin].sighash_code as u8);
+        let signature = [variable_length_integer(signature.len() as u64)?, signature].concat();
+
+        // Public key
+        let public_key_bytes = match public_key.is_compressed() {
+            true => public_key.to_secp256k1_public_key().serialize_compressed().to_vec(),
+            false => public_key.to_secp256k1_public_key().serialize().to_vec(),
+        };
+        let public_key = [vec![public_key_bytes.len() as u8], public_key_bytes].concat();
+
+        self.parameters.inputs[vin].script_sig = [signature, public_key].concat();
+        self.parameters.inputs[vin].is_signed = true;
+        Ok(())
+    }
+
+    /// Return the hash preimage of the raw transaction, which follows the BIP 143 digest algorithm,
+    /// committing to the amount of the input being signed.
+    /// https://github.com/bitcoincashorg/bitcoincash.org/blob/master/spec/replay-protected-sighash.md
+    pub fn hash_preimage(&self, vin: usize, sighash: SignatureHash) -> Result<Vec<u8>, TransactionError> {
+        let input = match self.parameters.inputs.get(vin) {
+            Some(input) => input,
+            None => return Err(TransactionError::InvalidInputs(format!("input {}", vin))),
+        };
+
+        let mut prev_outputs = vec![];
+        let mut prev_sequences = vec![];
+        for input in &self.parameters.inputs {
+            prev_outputs.extend(&input.outpoint.reverse_transaction_id);
+            prev_outputs.extend(&input.outpoint.index.to_le_bytes());
+            prev_sequences.extend(&input.sequence);
+        }
+
+        let mut outputs = vec![];
+        match (sighash.base_type(), self.parameters.outputs.get(vin)) {
+            (0x02, _) | (0x03, None) => {}
+            (0x03, Some(output)) => outputs.extend(output.serialize()?),
+            _ => {
+                for output in &self.parameters.outputs {
+                    outputs.extend(output.serialize()?);
+                }
+            }
+        };
+
+        let hash_prev_outputs = match sighash.is_anyone_can_pay() {
+            true => [0u8; 32].to_vec(),
+            false => Sha256::digest(&Sha256::digest(&prev_outputs)).to_vec(),
+        };
+        let hash_sequence = match sighash.is_anyone_can_pay() || sighash.base_type() != 0x01 {
+            true => [0u8; 32].to_vec(),
+            false => Sha256::digest(&Sha256::digest(&prev_sequences)).to_vec(),
+        };
+        let hash_outputs = match outputs.is_empty() {
+            true => [0u8; 32].to_vec(),
+            false => Sha256::digest(&Sha256::digest(&outputs)).to_vec(),
+        };
+
+        let script_code = match &input.outpoint.script_pub_key {
+            Some(script) => [variable_length_integer(script.len() as u64)?, script.clone()].concat(),
+            None => return Err(TransactionError::MissingOutpointScriptPublicKey),
+        };
+        let outpoint_amount = match &input.outpoint.amount {
+            Some(amount) => amount.0.to_le_bytes(),
+            None => return Err(TransactionError::MissingOutpointAmount),
+        };
+
+        let mut preimage = vec![];
+        preimage.extend(&self.parameters.version.to_le_bytes());
+        preimage.extend(hash_prev_outputs);
+        preimage.extend(hash_sequence);
+        preimage.extend(&input.outpoint.reverse_transaction_id);
+        preimage.extend(&input.outpoint.index.to_le_bytes());
+        preimage.extend(&script_code);
+        preimage.extend(&outpoint_amount);
+        preimage.extend(&input.sequence);
+        preimage.extend(hash_outputs);
+        preimage.extend(&self.parameters.lock_time.to_le_bytes());
+        preimage.extend(&(sighash as u32).to_le_bytes());
+
+        Ok(preimage)
+    }
+}
+
+impl<N: BitcoinCashNetwork> FromStr for BitcoinCashTransaction<N> {
+    type Err = TransactionError;
+
+    fn from_str(transaction: &str) -> Result<Self, Self::Err> {
+        Self::from_transaction_bytes(&hex::decode(transaction)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Mainnet;
+
+    pub struct TransactionTestCase<'a> {
+        pub version: u32,
+        pub lock_time: u32,
+        pub inputs: &'a [Input],
+        pub outputs: &'a [Output],
+        pub expected_signed_transaction: &'a str,
+        pub expected_transaction_id: &'a str,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Input {
+        pub private_key: &'static str,
+        pub address_format: BitcoinCashFormat,
+        pub transaction_id: &'static str,
+        pub index: u32,
+        pub utxo_amount: BitcoinCashAmount,
+        pub sequence: Option<[u8; 4]>,
+        pub sighash_code: SignatureHash,
+    }
+
+    #[derive(Clone)]
+    pub struct Output {
+        pub address: &'static str,
+        pub amount: BitcoinCashAmount,
+    }
+
+    fn test_transaction<N: BitcoinCashNetwork>(
+        version: u32,
+        lock_time: u32,
+        inputs: Vec<Input>,
+        outputs: Vec<Output>,
+        expected_signed_transaction: &str,
+        expected_transaction_id: &str,
+    ) {
+        let expected_fee = inputs.iter().map(|input| input.utxo_amount.0).sum::<i64>()
+            - outputs.iter().map(|output| output.amount.0).sum::<i64>();
+
+        let mut input_vec = vec![];
+        for input in &inputs {
+            let private_key = BitcoinCashPrivateKey::<N>::from_str(input.private_key).unwrap();
+            let address = private_key.to_address(&input.address_format).unwrap();
+            let transaction_id = hex::decode(input.transaction_id).unwrap();
+            let sequence = input.sequence.map(|seq| seq.to_vec());
+            let transaction_input = BitcoinCashTransactionInput::<N>::new(
+                transaction_id,
+                input.index,
+                Some(address),
+                Some(input.utxo_amount),
+                None,
+                sequence,
+                input.sighash_code,
+            )
+            .unwrap();
+
+            input_vec.push(transaction_input);
+        }
+
+        let mut output_vec = vec![];
+        for output in outputs {
+            let address = BitcoinCashAddress::<N>::from_str(output.address).unwrap();
+            output_vec.push(BitcoinCashTransactionOutput::new(&address, output.amount).unwrap());
+        }
+
+        let transaction_parameters = BitcoinCashTransactionParameters::<N> {
+            version,
+            inputs: input_vec,
+            outputs: output_vec,
+            lock_time,
+        };
+
+        let mut transaction = BitcoinCashTransaction::<N>::new(&transaction_parameters).unwrap();
+
+        // Sign transaction
+        for input in inputs {
+            transaction = transaction
+                .sign(&BitcoinCashPrivateKey::from_str(input.private_key).unwrap())
+                .unwrap();
+        }
+
+        let signed_transaction = hex::encode(transaction.to_transaction_bytes().unwrap());
+        let transaction_id = hex::encode(&transaction.to_transaction_id().unwrap().txid);
+
+        assert_eq!(expected_signed_transaction, &signed_transaction);
+        assert_eq!(expected_transaction_id, &transaction_id);
+        assert_eq!(Some(BitcoinCashAmount(expected_fee)), transaction.fee());
+        assert_eq!(expected_signed_transaction.len() / 2, transaction.size().unwrap());
+    }
+
+    fn test_reconstructed_transaction<N: BitcoinCashNetwork>(expected_signed_transaction: &str) {
+        let transaction = BitcoinCashTransaction::<N>::from_str(expected_signed_transaction).unwrap();
+        let signed_transaction = hex::encode(transaction.to_transaction_bytes().unwrap());
+        assert_eq!(expected_signed_transaction, &signed_transaction);
+        assert!(transaction.parameters().inputs.iter().all(|input| input.is_signed));
+    }
+
+    mod test_valid_mainnet_transactions {
+        use super::*;
+        type N = Mainnet;
+
+        const TRANSACTIONS: [TransactionTestCase; 3] = [
+            TransactionTestCase {
+                version: 2,
+                lock_time: 600000,
+                inputs: &[
+                    Input {
+                        private_key: "L2o7RUmise9WoxNzmnVZeK83Mmt5Nn1NBpeftbthG5nsLWCzSKVg",
+                        address_format: BitcoinCashFormat::CashAddr,
+                        transaction_id: "7a06ea98cd40ba2e3288262b28638cec5337c1456aaf5eedc8e9e5a20f062bdf",
+                        index: 1,
+                        utxo_amount: BitcoinCashAmount(150_000),
+                        sequence: None,
+                        sighash_code: SignatureHash::SIGHASH_ALL_SIGHASH_FORKID,
+                    },
+                    Input {
+                        private_key: "5K9VY2kaJ264Pj4ygobGLk7JJMgZ2i6wQ9FFKEBxoFtKeAXPHYm",
+                        address_format: BitcoinCashFormat::P2PKH,
+                        transaction_id: "04bf7ff8ab2bdc9b0ea3d1e6f5c1a1e0e1e4c3c1a7d2bb33e0e4f2a4c0a3c3d2",
+                        index: 0,
+                        utxo_amount: BitcoinCashAmount(50_000),
+                        sequence: Some([0xfe, 0xff, 0xff, 0xff]),
+                        sighash_code: SignatureHash::SIGHASH_ALL_SIGHASH_FORKID,
+                    },
+                ],
+                outputs: &[
+                    Output {
+                        address: "bitcoincash:qqggtxp8kp6s0w9ye5xgqypykpq2zxxumy52dahjf0",
+                        amount: BitcoinCashAmount(120_000),
+                    },
+                    Output {
+                        address: "1GUwicFwsZbdE3XyJYjmPryiiuTiK7mZgS",
+                        amount: BitcoinCashAmount(79_000),
+                    },
+                ],
+                expected_signed_transaction: "0200000002df2b060fa2e5e9c8ed5eaf6a45c13753ec8c63282b2688322eba40cd98ea067a010000006b483045022100fd9b1c20c9a713754ec69f62e6592eb2690ea878fd519440707af5d5f460bc4302207d306c785d729b5912f02c3f214b14e65476a15836fad7f886769f7454818e1341210261a944bb3e1324537c8468c9e48b92205f0d36bd84bcdf476e152f64e2b2f2e0ffffffffd2c3a3c0a4f2e4e033bbd2a7c1c3e4e1e0a1c1f5e6d1a30e9bdc2babf87fbf04000000008b483045022100fcec19b6aeb2dba093b540717c704249e3faaecaa936e559fcd77e84af3c81c10220721658981e0b85fc003682b475fd6763884b7f9f0202b2b05ab8a5d9956d529c4141042f0a20cdb0334407f13a5847521151fa3b17944507b2626c806fe2790178ff7f086c7c3d16fc90b7476196fc39f685f1fe1f73e5a141b1be3b0ad41439890da0feffffff02c0d40100000000001976a91410859827b07507b8a4cd0c801024b040a118dcd988ac98340100000000001976a914a9d2cb22414fb6a49adfce974b752e6d32d278bc88acc0270900",
+                expected_transaction_id: "21cba268b94e82469c570ca1b1c0e86a28729c2be4b391bd62fa311b9703f6cb",
+            },
+            TransactionTestCase {
+                version: 2,
+                lock_time: 600000,
+                inputs: &[
+                    Input {
+                        private_key: "L2o7RUmise9WoxNzmnVZeK83Mmt5Nn1NBpeftbthG5nsLWCzSKVg",
+                        address_format: BitcoinCashFormat::CashAddr,
+                        transaction_id: "7a06ea98cd40ba2e3288262b28638cec5337c1456aaf5eedc8e9e5a20f062bdf",
+                        index: 1,
+                        utxo_amount: BitcoinCashAmount(150_000),
+                        sequence: None,
+                        sighash_code: SignatureHash::SIGHASH_SINGLE_SIGHASH_FORKID_SIGHASH_ANYONECANPAY,
+                    },
+                    Input {
+                        private_key: "5K9VY2kaJ264Pj4ygobGLk7JJMgZ2i6wQ9FFKEBxoFtKeAXPHYm",
+                        address_format: BitcoinCashFormat::P2PKH,
+                        transaction_id: "04bf7ff8ab2bdc9b0ea3d1e6f5c1a1e0e1e4c3c1a7d2bb33e0e4f2a4c0a3c3d2",
+                        index: 0,
+                        utxo_amount: BitcoinCashAmount(50_000),
+                        sequence: Some([0xfe, 0xff, 0xff, 0xff]),
+                        sighash_code: SignatureHash::SIGHASH_SINGLE_SIGHASH_FORKID_SIGHASH_ANYONECANPAY,
+                    },
+                ],
+                outputs: &[
+                    Output {
+                        address: "bitcoincash:qqggtxp8kp6s0w9ye5xgqypykpq2zxxumy52dahjf0",
+                        amount: BitcoinCashAmount(120_000),
+                    },
+                    Output {
+                        address: "1GUwicFwsZbdE3XyJYjmPryiiuTiK7mZgS",
+                        amount: BitcoinCashAmount(79_000),
+                    },
+                ],
+                expected_signed_transaction: "0200000002df2b060fa2e5e9c8ed5eaf6a45c13753ec8c63282b2688322eba40cd98ea067a010000006b483045022100a8ee5aa535dac17f03a2355dcbee79e8919b9ec68366a3dca482dc4bd1f4d19702207d533addd7bdacf461890687097446989a8855029215ef8ccc37658102b30871c3210261a944bb3e1324537c8468c9e48b92205f0d36bd84bcdf476e152f64e2b2f2e0ffffffffd2c3a3c0a4f2e4e033bbd2a7c1c3e4e1e0a1c1f5e6d1a30e9bdc2babf87fbf04000000008a47304402203916e8e06bdb2298a462f587345e424517f5996e7f172064ed80a76479f7d642022014a4a4bbe33d3be2411df391bc2ef2e74840bb990ced97c423b6a3f4feaa6a96c341042f0a20cdb0334407f13a5847521151fa3b17944507b2626c806fe2790178ff7f086c7c3d16fc90b7476196fc39f685f1fe1f73e5a141b1be3b0ad41439890da0feffffff02c0d40100000000001976a91410859827b07507b8a4cd0c801024b040a118dcd988ac98340100000000001976a914a9d2cb22414fb6a49adfce974b752e6d32d278bc88acc0270900",
+                expected_transaction_id: "de10ed38196d9c5548dbc70e86bc3d51fe86ee99e94866e65764f5de89cc11e8",
+            },
+            TransactionTestCase {
+                version: 2,
+                lock_time: 600000,
+                inputs: &[
+                    Input {
+                        private_key: "L2o7RUmise9WoxNzmnVZeK83Mmt5Nn1NBpeftbthG5nsLWCzSKVg",
+                        address_format: BitcoinCashFormat::CashAddr,
+                        transaction_id: "7a06ea98cd40ba2e3288262b28638cec5337c1456aaf5eedc8e9e5a20f062bdf",
+                        index: 1,
+                        utxo_amount: BitcoinCashAmount(150_000),
+                        sequence: None,
+                        sighash_code: SignatureHash::SIGHASH_NONE_SIGHASH_FORKID,
+                    },
+                    Input {
+                        private_key: "5K9VY2kaJ264Pj4ygobGLk7JJMgZ2i6wQ9FFKEBxoFtKeAXPHYm",
+                        address_format: BitcoinCashFormat::P2PKH,
+                        transaction_id: "04bf7ff8ab2bdc9b0ea3d1e6f5c1a1e0e1e4c3c1a7d2bb33e0e4f2a4c0a3c3d2",
+                        index: 0,
+                        utxo_amount: BitcoinCashAmount(50_000),
+                        sequence: Some([0xfe, 0xff, 0xff, 0xff]),
+                        sighash_code: SignatureHash::SIGHASH_NONE_SIGHASH_FORKID,
+                    },
+                ],
+                outputs: &[
+                    Output {
+                        address: "bitcoincash:qqggtxp8kp6s0w9ye5xgqypykpq2zxxumy52dahjf0",
+                        amount: BitcoinCashAmount(120_000),
+                    },
+                    Output {
+                        address: "1GUwicFwsZbdE3XyJYjmPryiiuTiK7mZgS",
+                        amount: BitcoinCashAmount(79_000),
+                    },
+                ],
+                expected_signed_transaction: "0200000002df2b060fa2e5e9c8ed5eaf6a45c13753ec8c63282b2688322eba40cd98ea067a010000006b483045022100a8aa4a3f7e404b10c57cd30c4d16f19d59b79b55c60ce95412902bc4cbb3628c02207ede6e8c53e0f164f3938c18958273588354e225d539be5a2c160824d8e5254942210261a944bb3e1324537c8468c9e48b92205f0d36bd84bcdf476e152f64e2b2f2e0ffffffffd2c3a3c0a4f2e4e033bbd2a7c1c3e4e1e0a1c1f5e6d1a30e9bdc2babf87fbf04000000008b483045022100a7ba38c515880285cc8c68702a353c2d990888e742132a7053ba4557c1415fa002204986843644d6fb4ef3b96049d7a75fa5b800de642b9ede309f6d68eb0ccf9f1e4241042f0a20cdb0334407f13a5847521151fa3b17944507b2626c806fe2790178ff7f086c7c3d16fc90b7476196fc39f685f1fe1f73e5a141b1be3b0ad41439890da0feffffff02c0d40100000000001976a91410859827b07507b8a4cd0c801024b040a118dcd988ac98340100000000001976a914a9d2cb22414fb6a49adfce974b752e6d32d278bc88acc0270900",
+                expected_transaction_id: "7b2d925df44fe1b1e98e6477a641ec66dc1a79c81c4b4ff89f644b54681ba453",
+            },
+        ];
+
+        #[test]
+        fn test_mainnet_transactions() {
+            TRANSACTIONS.iter().for_each(|transaction| {
+                test_transaction::<N>(
+                    transaction.version,
+                    transaction.lock_time,
+                    transaction.inputs.to_vec(),
+                    transaction.outputs.to_vec(),
+                    transaction.expected_signed_transaction,
+                    transaction.expected_transaction_id,
+                );
+            });
+        }
+
+        #[test]
+        fn test_reconstructed_transactions() {
+            TRANSACTIONS.iter().for_each(|transaction| {
+                test_reconstructed_transaction::<N>(transaction.expected_signed_transaction);
+            });
+        }
+    }
+
+    mod test_invalid_transactions {
+        use super::*;
+        type N = Mainnet;
+
+        #[test]
+        fn test_missing_outpoint_amount() {
+            let private_key =
+                BitcoinCashPrivateKey::<N>::from_str("L2o7RUmise9WoxNzmnVZeK83Mmt5Nn1NBpeftbthG5nsLWCzSKVg").unwrap();
+            let address = private_key.to_address(&BitcoinCashFormat::CashAddr).unwrap();
+            let input = BitcoinCashTransactionInput::<N>::new(
+                vec![0u8; 32],
+                0,
+                Some(address.clone()),
+                None,
+                None,
+                None,
+                SignatureHash::SIGHASH_ALL_SIGHASH_FORKID,
+            )
+            .unwrap();
+            let output = BitcoinCashTransactionOutput::new(&address, BitcoinCashAmount(1_000)).unwrap();
+            let transaction_parameters = BitcoinCashTransactionParameters::<N> {
+                version: 2,
+                inputs: vec![input],
+                outputs: vec![output],
+                lock_time: 0,
+            };
+
+            let transaction = BitcoinCashTransaction::<N>::new(&transaction_parameters).unwrap();
+            assert!(transaction.sign(&private_key).is_err());
+        }
+
+        #[test]
+        fn test_invalid_transaction_id() {
+            let input = BitcoinCashTransactionInput::<N>::new(
+                vec![0u8; 31],
+                0,
+                None,
+                None,
+                None,
+                None,
+                SignatureHash::SIGHASH_ALL_SIGHASH_FORKID,
+            );
+            assert!(input.is_err());
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use crate::format::CosmosFormat;
+use crate::private_key::CosmosPrivateKey;
+use crate::public_key::CosmosPublicKey;
+use wagyu_model::{crypto::hash160, Address, AddressError, PrivateKey};
+
+use bech32::{Bech32, FromBase32, ToBase32};
+use core::{convert::TryFrom, fmt, str::FromStr};
+use serde::Serialize;
+
+/// Represents a Cosmos address
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Hash)]
+pub struct CosmosAddress {
+    /// The bech32 encoding of the address
+    address: String,
+    /// The 20-byte account hash, the RIPEMD-160 of the SHA-256 of the compressed public key
+    account_id: [u8; 20],
+    /// The format of the address
+    format: CosmosFormat,
+}
+
+impl Address for CosmosAddress {
+    type Format = CosmosFormat;
+    type PrivateKey = CosmosPrivateKey;
+    type PublicKey = CosmosPublicKey;
+
+    /// Returns the address corresponding to the given private key.
+    fn from_private_key(private_key: &Self::PrivateKey, format: &Self::Format) -> Result<Self, AddressError> {
+        Self::from_public_key(&private_key.to_public_key(), format)
+    }
+
+    /// Returns the address corresponding to the given public key.
+    fn from_public_key(public_key: &Self::PublicKey, format: &Self::Format) -> Result<Self, AddressError> {
+        let mut account_id = [0u8; 20];
+        account_id.copy_from_slice(&hash160(&public_key.to_secp256k1_public_key().serialize_compressed()));
+        Self::from_account_id(&account_id, format)
+    }
+}
+
+impl CosmosAddress {
+    /// Returns the address of the given 20-byte account hash in the given format.
+    pub fn from_account_id(account_id: &[u8; 20], format: &CosmosFormat) -> Result<Self, AddressError> {
+        let bech32 = Bech32::new(format.hrp().into(), account_id.to_base32())?;
+        Ok(Self {
+            address: bech32.to_string(),
+            account_id: *account_id,
+            format: format.clone(),
+        })
+    }
+
+    /// Returns the 20-byte account hash of the address.
+    pub fn to_account_id(&self) -> [u8; 20] {
+        self.account_id
+    }
+
+    /// Returns the format of the address.
+    pub fn format(&self) -> &CosmosFormat {
+        &self.format
+    }
+
+    /// Returns the address of the same account on the chain with the given format,
+    /// as in an `osmo` address for a `cosmos` address.
+    pub fn to_format(&self, format: &CosmosFormat) -> Result<Self, AddressError> {
+        Self::from_account_id(&self.account_id, format)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for CosmosAddress {
+    type Error = AddressError;
+
+    fn try_from(address: &'a str) -> Result<Self, Self::Error> {
+        Self::from_str(address)
+    }
+}
+
+impl FromStr for CosmosAddress {
+    type Err = AddressError;
+
+    /// Returns the address given its bech32 encoding, keeping its human-readable part as the format.
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        let bech32 = Bech32::from_str(address)?;
+        let format = CosmosFormat::new(bech32.hrp())?;
+
+        let data = Vec::<u8>::from_base32(bech32.data())?;
+        if data.len() != 20 {
+            return Err(AddressError::InvalidByteLength(data.len()));
+        }
+
+        let mut account_id = [0u8; 20];
+        account_id.copy_from_slice(&data);
+        Self::from_account_id(&account_id, &format)
+    }
+}
+
+impl fmt::Display for CosmosAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wagyu_model::public_key::PublicKey;
+
+    fn test_from_private_key(expected_address: &str, private_key: &CosmosPrivateKey, format: &CosmosFormat) {
+        let address = CosmosAddress::from_private_key(private_key, format).unwrap();
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    fn test_from_public_key(expected_address: &str, public_key: &CosmosPublicKey, format: &CosmosFormat) {
+        let address = CosmosAddress::from_public_key(public_key, format).unwrap();
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    fn test_from_str(expected_address: &str, expected_format: &CosmosFormat) {
+        let address = CosmosAddress::from_str(expected_address).unwrap();
+        assert_eq!(expected_address, address.to_string());
+        assert_eq!(expected_format, address.format());
+    }
+
+    fn test_to_str(expected_address: &str, address: &CosmosAddress) {
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    mod cosmos {
+        use super::*;
+
+        const KEYPAIRS: [(&str, &str); 5] = [
+            (
+                "f89f23eaeac18252fedf81bb8318d3c111d48c19b0680dcf6e0a8d5136caf287",
+                "cosmos13jl4usyc8v9kruashf5j4kkastzvtfyl9nm6d3",
+            ),
+            (
+                "a93701ea343247db13466f6448ffbca658726e2b4a77530db3eca3c9250b4f0d",
+                "cosmos16alzu953uzp20zqf9vljs3js49hmxzw3quvrw3",
+            ),
+            (
+                "de61e35e2e5eb9504d52f5042126591d80144d49f74b8ced68f4959a3e8edffd",
+                "cosmos1qj2jw6nmyv9dmt7acgkyw590akmv852u09d9hn",
+            ),
+            (
+                "56f01d5e01b6fd1cc123d8d1eae0d148e00c025b5be2ef624775f7a1b802e9c1",
+                "cosmos13snrusgedxwphyczwazr5v6wcs5wyrffj8scxy",
+            ),
+            (
+                "363af8b4d3ff22bb0e4ffc2ff198b4b5be0316f8a507ad5fe32f021c3d1ae8ad",
+                "cosmos1gupgaqs4n7uldklmk6atuxfdaekr86kmr9sp9y",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = CosmosPrivateKey::from_str(private_key).unwrap();
+                test_from_private_key(address, &private_key, &CosmosFormat::default());
+            });
+        }
+
+        #[test]
+        fn from_public_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = CosmosPrivateKey::from_str(private_key).unwrap();
+                let public_key = CosmosPublicKey::from_private_key(&private_key);
+                test_from_public_key(address, &public_key, &CosmosFormat::default());
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, address)| {
+                test_from_str(address, &CosmosFormat::default());
+            });
+        }
+
+        #[test]
+        fn to_str() {
+            KEYPAIRS.iter().for_each(|(_, expected_address)| {
+                let address = CosmosAddress::from_str(expected_address).unwrap();
+                test_to_str(expected_address, &address);
+            });
+        }
+    }
+
+    mod osmo {
+        use super::*;
+
+        const KEYPAIRS: [(&str, &str); 5] = [
+            (
+                "f89f23eaeac18252fedf81bb8318d3c111d48c19b0680dcf6e0a8d5136caf287",
+                "osmo13jl4usyc8v9kruashf5j4kkastzvtfyldgg2mr",
+            ),
+            (
+                "a93701ea343247db13466f6448ffbca658726e2b4a77530db3eca3c9250b4f0d",
+                "osmo16alzu953uzp20zqf9vljs3js49hmxzw3g8lncr",
+            ),
+            (
+                "de61e35e2e5eb9504d52f5042126591d80144d49f74b8ced68f4959a3e8edffd",
+                "osmo1qj2jw6nmyv9dmt7acgkyw590akmv852u8774pp",
+            ),
+            (
+                "56f01d5e01b6fd1cc123d8d1eae0d148e00c025b5be2ef624775f7a1b802e9c1",
+                "osmo13snrusgedxwphyczwazr5v6wcs5wyrff6urgsk",
+            ),
+            (
+                "363af8b4d3ff22bb0e4ffc2ff198b4b5be0316f8a507ad5fe32f021c3d1ae8ad",
+                "osmo1gupgaqs4n7uldklmk6atuxfdaekr86kmt7r3nk",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            let format = CosmosFormat::new("osmo").unwrap();
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = CosmosPrivateKey::from_str(private_key).unwrap();
+                test_from_private_key(address, &private_key, &format);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            let format = CosmosFormat::new("osmo").unwrap();
+            KEYPAIRS.iter().for_each(|(_, address)| {
+                test_from_str(address, &format);
+            });
+        }
+    }
+
+    #[test]
+    fn to_format() {
+        let address = CosmosAddress::from_str("cosmos13jl4usyc8v9kruashf5j4kkastzvtfyl9nm6d3").unwrap();
+        let osmo = address.to_format(&CosmosFormat::new("osmo").unwrap()).unwrap();
+        assert_eq!("osmo13jl4usyc8v9kruashf5j4kkastzvtfyldgg2mr", osmo.to_string());
+        assert_eq!(address.to_account_id(), osmo.to_account_id());
+    }
+
+    #[test]
+    fn test_invalid() {
+        // Mismatched keypair
+
+        let private_key = "f89f23eaeac18252fedf81bb8318d3c111d48c19b0680dcf6e0a8d5136caf287";
+        let expected_address = "cosmos1gupgaqs4n7uldklmk6atuxfdaekr86kmr9sp9y";
+
+        let private_key = CosmosPrivateKey::from_str(private_key).unwrap();
+        let address = CosmosAddress::from_private_key(&private_key, &CosmosFormat::default()).unwrap();
+        assert_ne!(expected_address, address.to_string());
+
+        // Invalid address length
+
+        let address = "cosmos1";
+        assert!(CosmosAddress::from_str(address).is_err());
+
+        let address = "cosmos13jl4usyc8v9kruashf5j4kkastzvtfyl9nm6d";
+        assert!(CosmosAddress::from_str(address).is_err());
+
+        // Invalid checksum
+
+        let address = "cosmos13jl4usyc8v9kruashf5j4kkastzvtfyl9nm6d4";
+        assert!(CosmosAddress::from_str(address).is_err());
+
+        // Invalid data length
+
+        let address = "cosmospub1addwnpepqdmyp34js8a50gllx6764pju3khysymgk5qjf4tfpkwcc0v0ckgh5xx27wf";
+        assert!(CosmosAddress::from_str(address).is_err());
+
+        // Invalid encoding
+
+        let address = "TPDFhum222kAaz9zfyj7vLpaUMYivkcwmN";
+        assert!(CosmosAddress::from_str(address).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+use wagyu_model::{AddressError, Format};
+
+use core::fmt;
+use serde::Serialize;
+
+/// The human-readable part of Cosmos Hub addresses
+pub const DEFAULT_HRP: &str = "cosmos";
+
+/// Represents the format of a Cosmos address, the bech32 human-readable part (HRP)
+/// of the chain it belongs to, as in `cosmos`, `osmo`, or `juno`.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CosmosFormat {
+    hrp: String,
+}
+
+impl Format for CosmosFormat {}
+
+impl CosmosFormat {
+    /// Returns the format with the given human-readable part.
+    /// The HRP is kept short enough for the `pub` suffix of the amino public key encoding.
+    pub fn new(hrp: &str) -> Result<Self, AddressError> {
+        if hrp.is_empty() || hrp.len() > 80 {
+            return Err(AddressError::InvalidPrefixLength(hrp.len()));
+        }
+
+        if hrp.bytes().any(|c| !(33..=126).contains(&c) || c.is_ascii_uppercase()) {
+            return Err(AddressError::InvalidPrefix(hrp.as_bytes().to_vec()));
+        }
+
+        Ok(Self { hrp: hrp.into() })
+    }
+
+    /// Returns the human-readable part of the format.
+    pub fn hrp(&self) -> &str {
+        &self.hrp
+    }
+}
+
+impl Default for CosmosFormat {
+    fn default() -> Self {
+        Self {
+            hrp: DEFAULT_HRP.into(),
+        }
+    }
+}
+
+impl fmt::Display for CosmosFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.hrp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        ["cosmos", "osmo", "juno", "terra", "akash"].iter().for_each(|hrp| {
+            assert_eq!(*hrp, CosmosFormat::new(hrp).unwrap().hrp());
+        });
+        assert_eq!(DEFAULT_HRP, CosmosFormat::default().to_string());
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(CosmosFormat::new("").is_err());
+        assert!(CosmosFormat::new("Cosmos").is_err());
+        assert!(CosmosFormat::new("cosmos hub").is_err());
+        assert!(CosmosFormat::new(&"a".repeat(81)).is_err());
+    }
+}
@@ -0,0 +1,37 @@
+//! # Cosmos
+//!
+//! A library for generating Cosmos wallets.
+//!
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(unused_extern_crates, dead_code)]
+#![forbid(unsafe_code)]
+
+pub mod address;
+pub use self::address::*;
+
+pub mod derivation_path;
+pub use self::derivation_path::*;
+
+pub mod extended_private_key;
+pub use self::extended_private_key::*;
+
+pub mod extended_public_key;
+pub use self::extended_public_key::*;
+
+pub mod format;
+pub use self::format::*;
+
+pub mod mnemonic;
+pub use self::mnemonic::*;
+
+pub mod network;
+pub use self::network::*;
+
+pub mod private_key;
+pub use self::private_key::*;
+
+pub mod public_key;
+pub use self::public_key::*;
+
+pub mod wordlist;
+pub use self::wordlist::*;
@@ -0,0 +1,240 @@
+use crate::address::CosmosAddress;
+use crate::format::CosmosFormat;
+use crate::public_key::CosmosPublicKey;
+use wagyu_model::{Address, AddressError, PrivateKey, PrivateKeyError, PublicKey};
+
+use core::{fmt, fmt::Display, str::FromStr};
+use rand::Rng;
+use secp256k1;
+use zeroize::Zeroize;
+
+/// Represents a Cosmos private key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CosmosPrivateKey(secp256k1::SecretKey);
+
+impl PrivateKey for CosmosPrivateKey {
+    type Address = CosmosAddress;
+    type Format = CosmosFormat;
+    type PublicKey = CosmosPublicKey;
+
+    /// Returns a randomly-generated Cosmos private key.
+    fn new<R: Rng>(rng: &mut R) -> Result<Self, PrivateKeyError> {
+        let random: [u8; 32] = rng.gen();
+        Ok(Self(secp256k1::SecretKey::parse_slice(&random)?))
+    }
+
+    /// Returns the public key of the corresponding Cosmos private key.
+    fn to_public_key(&self) -> Self::PublicKey {
+        CosmosPublicKey::from_private_key(self)
+    }
+
+    /// Returns the address of the corresponding Cosmos private key.
+    fn to_address(&self, _format: &Self::Format) -> Result<Self::Address, AddressError> {
+        CosmosAddress::from_private_key(self, _format)
+    }
+}
+
+impl CosmosPrivateKey {
+    /// Returns a private key given a secp256k1 secret key.
+    pub fn from_secp256k1_secret_key(secret_key: &secp256k1::SecretKey) -> Self {
+        Self(secret_key.clone())
+    }
+
+    /// Returns the secp256k1 secret key of the private key.
+    pub fn to_secp256k1_secret_key(&self) -> secp256k1::SecretKey {
+        self.0.clone()
+    }
+}
+
+impl FromStr for CosmosPrivateKey {
+    type Err = PrivateKeyError;
+
+    fn from_str(private_key: &str) -> Result<Self, PrivateKeyError> {
+        if private_key.len() != 64 {
+            return Err(PrivateKeyError::InvalidCharacterLength(private_key.len()));
+        }
+
+        let secret_key = hex::decode(private_key)?;
+        Ok(Self(secp256k1::SecretKey::parse_slice(&secret_key)?))
+    }
+}
+
+impl Display for CosmosPrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut private_key = [0u8; 32];
+        private_key.copy_from_slice(&self.0.serialize());
+        write!(f, "{}", hex::encode(private_key).to_string())
+    }
+}
+
+impl Zeroize for CosmosPrivateKey {
+    /// Replaces the secret key with a placeholder, clearing the original secret key on drop.
+    fn zeroize(&mut self) {
+        self.0 = secp256k1::SecretKey::default();
+    }
+}
+
+impl Drop for CosmosPrivateKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_to_public_key(expected_public_key: &CosmosPublicKey, private_key: &CosmosPrivateKey) {
+        let public_key = private_key.to_public_key();
+        assert_eq!(*expected_public_key, public_key);
+    }
+
+    fn test_to_address(expected_address: &CosmosAddress, private_key: &CosmosPrivateKey) {
+        let address = private_key.to_address(&CosmosFormat::default()).unwrap();
+        assert_eq!(*expected_address, address);
+    }
+
+    fn test_from_secp256k1_secret_key(
+        expected_private_key: &str,
+        expected_public_key: &str,
+        expected_address: &str,
+        secret_key: secp256k1::SecretKey,
+    ) {
+        let private_key = CosmosPrivateKey::from_secp256k1_secret_key(&secret_key);
+        assert_eq!(secret_key, private_key.0);
+        assert_eq!(expected_private_key, private_key.to_string());
+        assert_eq!(expected_public_key, private_key.to_public_key().to_string());
+        assert_eq!(
+            expected_address,
+            private_key.to_address(&CosmosFormat::default()).unwrap().to_string()
+        );
+    }
+
+    fn test_from_str(
+        expected_secret_key: &secp256k1::SecretKey,
+        expected_public_key: &str,
+        expected_address: &str,
+        private_key: &str,
+    ) {
+        let private_key = CosmosPrivateKey::from_str(private_key).unwrap();
+        assert_eq!(*expected_secret_key, private_key.0);
+        assert_eq!(expected_public_key, private_key.to_public_key().to_string());
+        assert_eq!(
+            expected_address,
+            private_key.to_address(&CosmosFormat::default()).unwrap().to_string()
+        );
+    }
+
+    fn test_to_str(expected_private_key: &str, private_key: &CosmosPrivateKey) {
+        assert_eq!(expected_private_key, private_key.to_string());
+    }
+
+    mod standard {
+        use super::*;
+
+        const KEYPAIRS: [(&str, &str, &str); 5] = [
+            (
+                "8279d7c0ae2c3266b557845d50ede43e22a7e60408b7c90ee279b8848dbac771",
+                "029e984180d8e431b31f51d605639d6eaa447a36189834c10238203aff6c100090",
+                "cosmos1tj9vafjjy2pcy87djvfn540v5nntd63vmdnj9u",
+            ),
+            (
+                "444d0c9a7cb33240a0799a0edc0d89a96b20abf10f91b33d7f5812b49d4f0d95",
+                "03c86d6b2d319e8267a5dac084aed74c28754b9ea18291ed36d5f1dcf7f9debaef",
+                "cosmos1jkkp995ks3t0q7yhfnltyk9g0vns9ue93gnz46",
+            ),
+            (
+                "40d4098958b22c19e866f0761f5d589fcc088b78f4e881bfda7ebee7df044bdd",
+                "03d1b1ab9c694894950da166520af3081c1f169c7306f2ed8ce507928832aa0429",
+                "cosmos1vw58ttx7dknvru5q25dgpelfay807hy3v4xu9s",
+            ),
+            (
+                "f56ebd9b96ddbd8faf320ae8af2b49aeff4b54dc8867a6c39092fe1aa7434b7e",
+                "028d270aba1ed09d353d7c8c892593b628499eb1d714fbaabd9938e43cbb847cef",
+                "cosmos1e77slm62w8d4vpca80cctger5slum874hvyryz",
+            ),
+            (
+                "ab95d2466269a48e96f92fe36dfcecf67b4a6f9394de9ec7314dd584426a638c",
+                "028269368cad7ce74a530954da01db01e4e62f17625869ad10eabf3a261b5ab6d3",
+                "cosmos1j3k5xta30tg8jqfjkfdkzd4ar72uu9qn56hhtm",
+            ),
+        ];
+
+        #[test]
+        fn to_public_key() {
+            KEYPAIRS.iter().for_each(|(private_key, public_key, _)| {
+                let public_key = CosmosPublicKey::from_str(public_key).unwrap();
+                let private_key = CosmosPrivateKey::from_str(&private_key).unwrap();
+                test_to_public_key(&public_key, &private_key);
+            });
+        }
+
+        #[test]
+        fn to_address() {
+            KEYPAIRS.iter().for_each(|(private_key, _, address)| {
+                let address = CosmosAddress::from_str(address).unwrap();
+                let private_key = CosmosPrivateKey::from_str(&private_key).unwrap();
+                test_to_address(&address, &private_key);
+            });
+        }
+
+        #[test]
+        fn from_secp256k1_secret_key() {
+            KEYPAIRS
+                .iter()
+                .for_each(|(expected_private_key, expected_public_key, expected_address)| {
+                    let private_key = CosmosPrivateKey::from_str(&expected_private_key).unwrap();
+                    test_from_secp256k1_secret_key(
+                        expected_private_key,
+                        expected_public_key,
+                        expected_address,
+                        private_key.0.clone(),
+                    );
+                });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS
+                .iter()
+                .for_each(|(private_key, expected_public_key, expected_address)| {
+                    let expected_private_key = CosmosPrivateKey::from_str(&private_key).unwrap();
+                    test_from_str(
+                        &expected_private_key.0,
+                        expected_public_key,
+                        expected_address,
+                        &private_key,
+                    );
+                });
+        }
+
+        #[test]
+        fn to_str() {
+            KEYPAIRS.iter().for_each(|(expected_private_key, _, _)| {
+                let private_key = CosmosPrivateKey::from_str(expected_private_key).unwrap();
+                test_to_str(expected_private_key, &private_key);
+            });
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        // Invalid private key length
+
+        let private_key = "8";
+        assert!(CosmosPrivateKey::from_str(private_key).is_err());
+
+        let private_key = "8279d7c0ae2c3266b557845d50ede43";
+        assert!(CosmosPrivateKey::from_str(private_key).is_err());
+
+        let private_key = "8279d7c0ae2c3266b557845d50ede43e22a7e60408b7c90ee279b8848dbac77";
+        assert!(CosmosPrivateKey::from_str(private_key).is_err());
+
+        let private_key =
+            "8279d7c0ae2c3266b557845d50ede43e22a7e60408b7c90ee279b8848dbac7718279d7c0ae2c3266b557845d50ede43";
+        assert!(CosmosPrivateKey::from_str(private_key).is_err());
+
+        let private_key = "8279d7c0ae2c3266b557845d50ede43e22a7e60408b7c90ee279b8848dbac7718279d7c0ae2c3266b557845d50ede43e22a7e60408b7c90ee279b8848dbac771";
+        assert!(CosmosPrivateKey::from_str(private_key).is_err());
+    }
+}
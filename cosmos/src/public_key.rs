@@ -0,0 +1,262 @@
+use crate::address::CosmosAddress;
+use crate::format::CosmosFormat;
+use crate::private_key::CosmosPrivateKey;
+use wagyu_model::{Address, AddressError, PublicKey, PublicKeyError};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use bech32::{Bech32, FromBase32, ToBase32};
+use core::{fmt, fmt::Display, str::FromStr};
+use secp256k1;
+
+/// The amino prefix of a secp256k1 public key, the registered type `tendermint/PubKeySecp256k1`
+pub const AMINO_SECP256K1_PREFIX: [u8; 5] = [0xeb, 0x5a, 0xe9, 0x87, 0x21];
+
+/// The protobuf type URL of a secp256k1 public key
+pub const PROTO_SECP256K1_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PubKey";
+
+/// Represents a Cosmos public key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CosmosPublicKey(secp256k1::PublicKey);
+
+impl PublicKey for CosmosPublicKey {
+    type Address = CosmosAddress;
+    type Format = CosmosFormat;
+    type PrivateKey = CosmosPrivateKey;
+
+    /// Returns the address corresponding to the given public key.
+    fn from_private_key(private_key: &Self::PrivateKey) -> Self {
+        Self(secp256k1::PublicKey::from_secret_key(
+            &private_key.to_secp256k1_secret_key(),
+        ))
+    }
+
+    /// Returns the address of the corresponding private key.
+    fn to_address(&self, format: &Self::Format) -> Result<Self::Address, AddressError> {
+        CosmosAddress::from_public_key(self, format)
+    }
+}
+
+impl CosmosPublicKey {
+    /// Returns a public key given a secp256k1 public key.
+    pub fn from_secp256k1_public_key(public_key: secp256k1::PublicKey) -> Self {
+        Self(public_key)
+    }
+
+    /// Returns the secp256k1 public key of the public key
+    pub fn to_secp256k1_public_key(&self) -> secp256k1::PublicKey {
+        self.0.clone()
+    }
+
+    /// Returns a public key given its amino bech32 encoding, as in `cosmospub1addwnpep...`.
+    pub fn from_amino(public_key: &str) -> Result<Self, PublicKeyError> {
+        let bech32 = Bech32::from_str(public_key)?;
+        if !bech32.hrp().ends_with("pub") {
+            return Err(PublicKeyError::InvalidPrefix(bech32.hrp().into()));
+        }
+
+        let data = Vec::<u8>::from_base32(bech32.data())?;
+        if data.len() != 38 {
+            return Err(PublicKeyError::InvalidByteLength(data.len()));
+        }
+        if data[0..5] != AMINO_SECP256K1_PREFIX {
+            return Err(PublicKeyError::InvalidPrefix(hex::encode(&data[0..5])));
+        }
+
+        Ok(Self(secp256k1::PublicKey::parse_slice(&data[5..], None)?))
+    }
+
+    /// Returns the amino bech32 encoding of the public key, the legacy encoding
+    /// with the human-readable part of the given format followed by `pub`.
+    pub fn to_amino(&self, format: &CosmosFormat) -> Result<String, PublicKeyError> {
+        let mut data = AMINO_SECP256K1_PREFIX.to_vec();
+        data.extend_from_slice(&self.0.serialize_compressed());
+        Ok(Bech32::new(format!("{}pub", format.hrp()), data.to_base32())?.to_string())
+    }
+
+    /// Returns the protobuf JSON encoding of the public key, as used by the Cosmos SDK since v0.40.
+    pub fn to_proto_json(&self) -> String {
+        format!(
+            "{{\"@type\":\"{}\",\"key\":\"{}\"}}",
+            PROTO_SECP256K1_TYPE_URL,
+            STANDARD.encode(&self.0.serialize_compressed()[..])
+        )
+    }
+}
+
+impl FromStr for CosmosPublicKey {
+    type Err = PublicKeyError;
+
+    fn from_str(public_key: &str) -> Result<Self, Self::Err> {
+        if public_key.len() != 66 {
+            return Err(PublicKeyError::InvalidCharacterLength(public_key.len()));
+        }
+
+        Ok(Self(secp256k1::PublicKey::parse_slice(
+            &hex::decode(public_key)?,
+            None,
+        )?))
+    }
+}
+
+impl Display for CosmosPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", hex::encode(&self.0.serialize_compressed()[..]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_from_private_key(expected_public_key: &CosmosPublicKey, private_key: &CosmosPrivateKey) {
+        let public_key = CosmosPublicKey::from_private_key(private_key);
+        assert_eq!(*expected_public_key, public_key);
+    }
+
+    fn test_to_address(expected_address: &CosmosAddress, public_key: &CosmosPublicKey) {
+        let address = public_key.to_address(&CosmosFormat::default()).unwrap();
+        assert_eq!(*expected_address, address);
+    }
+
+    fn test_from_str(expected_public_key: &str, expected_address: &str) {
+        let public_key = CosmosPublicKey::from_str(expected_public_key).unwrap();
+        let address = public_key.to_address(&CosmosFormat::default()).unwrap();
+        assert_eq!(expected_public_key, public_key.to_string());
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    fn test_to_str(expected_public_key: &str, public_key: &CosmosPublicKey) {
+        assert_eq!(expected_public_key, public_key.to_string());
+    }
+
+    fn test_amino(expected_public_key: &str, expected_amino: &str) {
+        let public_key = CosmosPublicKey::from_amino(expected_amino).unwrap();
+        assert_eq!(expected_public_key, public_key.to_string());
+        assert_eq!(expected_amino, public_key.to_amino(&CosmosFormat::default()).unwrap());
+    }
+
+    fn test_proto_json(expected_proto_json: &str, public_key: &CosmosPublicKey) {
+        assert_eq!(expected_proto_json, public_key.to_proto_json());
+    }
+
+    mod cosmos {
+        use super::*;
+
+        const KEYPAIRS: [(&str, &str, &str, &str, &str); 5] = [
+            (
+                "2f46188bd601ece2a4446fa31de9419ee9baabf5305d65a5a7aea8badee27a5a",
+                "0206d68e391c6961fceb5d8c5ad8ee5c6346db24df9dae61c9c0b0142409760451",
+                "cosmos1ygeh3knlscjwrczsk48znuheuk2w56p87mguvx",
+                "cosmospub1addwnpepqgrddr3er35krl8ttkx94k8wt335dkeym7w6ucwfczcpgfqfwcz9zdt9hk9",
+                "AgbWjjkcaWH8612MWtjuXGNG2yTfna5hycCwFCQJdgRR",
+            ),
+            (
+                "d96c4c30bbabde58653e4fb4f4d97d064c70e300a37ab8780a8ecc15220423fb",
+                "02bfe0746c85802c3ca1c2d5e4f4d23fb8321b8b1009af67855cc9a4aed8285567",
+                "cosmos15czlw7v9pwvx0cxjp706lv3psl7n69q6ryw7gz",
+                "cosmospub1addwnpepq2l7qarvskqzc09pct27faxj87uryxutzqy67eu9tny6ftkc9p2kwg5tf83",
+                "Ar/gdGyFgCw8ocLV5PTSP7gyG4sQCa9nhVzJpK7YKFVn",
+            ),
+            (
+                "c677a1215eebd35d20337d8896ee6579c78f41f93946b17c8d4ccb772c25cde4",
+                "02ff3e50efb509efd0d18ff9074bc8b253419d2437e0c1e81661c1ba419f877162",
+                "cosmos1jp3u2ut0qx7uh7enz7p7dx39ws4z7wqe789q8l",
+                "cosmospub1addwnpepqtlnu580k5y7l5x33luswj7gkff5r8fyxlsvr6qkv8qm5svlsackys29y0g",
+                "Av8+UO+1Ce/Q0Y/5B0vIslNBnSQ34MHoFmHBukGfh3Fi",
+            ),
+            (
+                "b681e5bd4ddffefe1a691fe7c6375775c11992b9a25e4f9e3f235eb054d49343",
+                "02d9ed72afa68a9732df005df2dbbfb2abcad050579bd8dfeb32389d0f1e492d13",
+                "cosmos1ljm36u0ajyt2336056l5ejvfyhhhj0zlk7kq30",
+                "cosmospub1addwnpepqtv76u40569fwvklqpwl9kalk24u45zs27da3hltxguf6rc7fyk3x74amu8",
+                "Atntcq+mipcy3wBd8tu/sqvK0FBXm9jf6zI4nQ8eSS0T",
+            ),
+            (
+                "da5d359af6827e76e0a1b71c75c375f0d33f63bae4fd551d81ee10faa34e33e9",
+                "020b752d5e89126b62a99edfe40a4cbd9122cfb04257a28d225858d38bc92a0e15",
+                "cosmos1a63ggfcef6z4ctn4pl8jxmlanv4vtl6hv4z4gn",
+                "cosmospub1addwnpepqg9h2t273yfxkc4fnm07gzjvhkgj9nasgft69rfztpvd8z7f9g8p2say825",
+                "Agt1LV6JEmtiqZ7f5ApMvZEiz7BCV6KNIlhY04vJKg4V",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(private_key, public_key, _, _, _)| {
+                let public_key = CosmosPublicKey::from_str(public_key).unwrap();
+                let private_key = CosmosPrivateKey::from_str(private_key).unwrap();
+                test_from_private_key(&public_key, &private_key);
+            });
+        }
+
+        #[test]
+        fn to_address() {
+            KEYPAIRS.iter().for_each(|(_, public_key, address, _, _)| {
+                let address = CosmosAddress::from_str(address).unwrap();
+                let public_key = CosmosPublicKey::from_str(public_key).unwrap();
+                test_to_address(&address, &public_key);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS
+                .iter()
+                .for_each(|(_, expected_public_key, expected_address, _, _)| {
+                    test_from_str(expected_public_key, expected_address);
+                });
+        }
+
+        #[test]
+        fn to_str() {
+            KEYPAIRS.iter().for_each(|(_, expected_public_key, _, _, _)| {
+                let public_key = CosmosPublicKey::from_str(expected_public_key).unwrap();
+                test_to_str(expected_public_key, &public_key);
+            });
+        }
+
+        #[test]
+        fn amino() {
+            KEYPAIRS.iter().for_each(|(_, public_key, _, amino, _)| {
+                test_amino(public_key, amino);
+            });
+        }
+
+        #[test]
+        fn proto_json() {
+            KEYPAIRS.iter().for_each(|(_, public_key, _, _, key)| {
+                let public_key = CosmosPublicKey::from_str(public_key).unwrap();
+                let expected_proto_json =
+                    format!("{{\"@type\":\"/cosmos.crypto.secp256k1.PubKey\",\"key\":\"{}\"}}", key);
+                test_proto_json(&expected_proto_json, &public_key);
+            });
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        // Invalid public key length
+
+        let public_key = "0";
+        assert!(CosmosPublicKey::from_str(public_key).is_err());
+
+        let public_key = "0206d68e391c6961fceb5d8c5ad8ee5c6346db24df9dae61c9c0b01424097604";
+        assert!(CosmosPublicKey::from_str(public_key).is_err());
+
+        let public_key = "0206d68e391c6961fceb5d8c5ad8ee5c6346db24df9dae61c9c0b0142409760451d9";
+        assert!(CosmosPublicKey::from_str(public_key).is_err());
+
+        // Invalid public key prefix
+
+        let public_key = "0506d68e391c6961fceb5d8c5ad8ee5c6346db24df9dae61c9c0b0142409760451";
+        assert!(CosmosPublicKey::from_str(public_key).is_err());
+
+        // Invalid amino encoding
+
+        let public_key = "cosmos1ygeh3knlscjwrczsk48znuheuk2w56p87mguvx";
+        assert!(CosmosPublicKey::from_amino(public_key).is_err());
+
+        let public_key = "0206d68e391c6961fceb5d8c5ad8ee5c6346db24df9dae61c9c0b0142409760451";
+        assert!(CosmosPublicKey::from_amino(public_key).is_err());
+    }
+}
@@ -0,0 +1,185 @@
+use wagyu_model::no_std::*;
+use wagyu_model::{Amount, AmountError};
+
+use core::fmt;
+use serde::Serialize;
+
+// Number of duffs (base unit) per DASH
+const COIN: i64 = 1_0000_0000;
+
+// Maximum number of duffs
+const MAX_COINS: i64 = 21_000_000 * COIN;
+
+/// Represents the amount of Dash in duffs
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct DashAmount(pub i64);
+
+pub enum Denomination {
+    // duffs
+    Duff,
+    // DASH
+    Dash,
+}
+
+impl Denomination {
+    /// The number of decimal places more than a duff.
+    fn precision(self) -> u32 {
+        match self {
+            Denomination::Duff => 0,
+            Denomination::Dash => 8,
+        }
+    }
+}
+
+impl fmt::Display for Denomination {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Denomination::Duff => "duffs",
+                Denomination::Dash => "DASH",
+            }
+        )
+    }
+}
+
+impl Amount for DashAmount {}
+
+impl DashAmount {
+    /// The zero amount.
+    pub const ZERO: DashAmount = DashAmount(0);
+    /// Exactly one duff.
+    pub const ONE_DUFF: DashAmount = DashAmount(1);
+    /// Exactly one dash.
+    pub const ONE_DASH: DashAmount = DashAmount(COIN);
+
+    pub fn from_duffs(duffs: i64) -> Result<Self, AmountError> {
+        if (-MAX_COINS..=MAX_COINS).contains(&duffs) {
+            Ok(Self(duffs))
+        } else {
+            Err(AmountError::AmountOutOfBounds(duffs.to_string(), MAX_COINS.to_string()))
+        }
+    }
+
+    pub fn from_dash(dash_value: i64) -> Result<Self, AmountError> {
+        match dash_value.checked_mul(10_i64.pow(Denomination::Dash.precision())) {
+            Some(duffs) => Self::from_duffs(duffs),
+            None => Err(AmountError::AmountOutOfBounds(
+                dash_value.to_string(),
+                (MAX_COINS / COIN).to_string(),
+            )),
+        }
+    }
+
+    /// Returns the amount of a decimal value in DASH, as in `12.5`, with at most 8 decimal places.
+    pub fn from_dash_str(dash_value: &str) -> Result<Self, AmountError> {
+        let invalid = || AmountError::InvalidAmount(dash_value.into());
+        let (integer, fraction) = match dash_value.find('.') {
+            Some(index) => (&dash_value[..index], &dash_value[index + 1..]),
+            None => (dash_value, ""),
+        };
+        let precision = Denomination::Dash.precision() as usize;
+        if (integer.is_empty() && fraction.is_empty())
+            || fraction.len() > precision
+            || !integer.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let integer = match integer.is_empty() {
+            true => 0,
+            false => integer.parse::<i64>().map_err(|_| invalid())?,
+        };
+        let fraction = format!("{:0<1$}", fraction, precision)
+            .parse::<i64>()
+            .map_err(|_| invalid())?;
+        match integer.checked_mul(COIN).and_then(|duffs| duffs.checked_add(fraction)) {
+            Some(duffs) => Self::from_duffs(duffs),
+            None => Err(invalid()),
+        }
+    }
+
+    /// Returns the decimal value of the amount in DASH, without trailing zeros, as in `12.5`.
+    pub fn to_dash_string(&self) -> String {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let (integer, fraction) = (self.0.abs() / COIN, self.0.abs() % COIN);
+        match fraction {
+            0 => format!("{}{}", sign, integer),
+            _ => format!("{}{}.{:08}", sign, integer, fraction)
+                .trim_end_matches('0')
+                .to_string(),
+        }
+    }
+
+    pub fn add(self, b: Self) -> Result<Self, AmountError> {
+        Self::from_duffs(self.0 + b.0)
+    }
+
+    pub fn sub(self, b: DashAmount) -> Result<Self, AmountError> {
+        Self::from_duffs(self.0 - b.0)
+    }
+}
+
+impl fmt::Display for DashAmount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duffs_conversion() {
+        assert_eq!(DashAmount(0), DashAmount::from_duffs(0).unwrap());
+        assert_eq!(DashAmount(1), DashAmount::from_duffs(1).unwrap());
+        assert_eq!(DashAmount(MAX_COINS), DashAmount::from_duffs(MAX_COINS).unwrap());
+        assert!(DashAmount::from_duffs(MAX_COINS + 1).is_err());
+        assert!(DashAmount::from_duffs(-MAX_COINS - 1).is_err());
+    }
+
+    #[test]
+    fn test_dash_conversion() {
+        assert_eq!(DashAmount::ONE_DASH, DashAmount::from_dash(1).unwrap());
+        assert_eq!(DashAmount(69_000_000_000), DashAmount::from_dash(690).unwrap());
+        assert_eq!(DashAmount(MAX_COINS), DashAmount::from_dash(21_000_000).unwrap());
+        assert!(DashAmount::from_dash(21_000_001).is_err());
+        assert!(DashAmount::from_dash(i64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_dash_str_conversion() {
+        let pairs = [
+            ("0", 0),
+            ("1", 100_000_000),
+            ("12.5", 1_250_000_000),
+            (".5", 50_000_000),
+            ("0.00000001", 1),
+            ("21000000", MAX_COINS),
+        ];
+        pairs.iter().for_each(|(dash_value, duffs)| {
+            let amount = DashAmount::from_dash_str(dash_value).unwrap();
+            assert_eq!(DashAmount(*duffs), amount);
+        });
+        assert_eq!("12.5", DashAmount(1_250_000_000).to_dash_string());
+        assert_eq!("0.00000001", DashAmount(1).to_dash_string());
+        assert_eq!("-3", DashAmount(-300_000_000).to_dash_string());
+
+        let invalid = ["", ".", "-1", "1e8", "0.000000001", "1.0.0", "10000000000.00000001"];
+        invalid.iter().for_each(|dash_value| {
+            assert!(DashAmount::from_dash_str(dash_value).is_err(), "{}", dash_value);
+        });
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = DashAmount::from_dash(100).unwrap();
+        let b = DashAmount::from_duffs(1).unwrap();
+        assert_eq!(DashAmount(10_000_000_001), a.add(b).unwrap());
+        assert_eq!(DashAmount(9_999_999_999), a.sub(b).unwrap());
+        assert!(DashAmount(MAX_COINS).add(b).is_err());
+        assert!(DashAmount(-MAX_COINS).sub(b).is_err());
+    }
+}
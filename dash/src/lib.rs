@@ -0,0 +1,24 @@
+//! # Dash
+//!
+//! A library for generating Dash wallets.
+//!
+//! The addresses, keys, and mnemonics of Dash are those of `wagyu-base58`,
+//! configured by the base58 version bytes of the Dash networks.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(unused_extern_crates, dead_code)]
+#![forbid(unsafe_code)]
+
+pub mod amount;
+pub use self::amount::*;
+
+pub mod network;
+pub use self::network::*;
+
+pub use wagyu_base58::wordlist::{self, *};
+pub use wagyu_base58::{
+    Base58Address as DashAddress, Base58DerivationPath as DashDerivationPath,
+    Base58ExtendedPrivateKey as DashExtendedPrivateKey, Base58ExtendedPublicKey as DashExtendedPublicKey,
+    Base58Format as DashFormat, Base58Mnemonic as DashMnemonic, Base58PrivateKey as DashPrivateKey,
+    Base58PublicKey as DashPublicKey, Base58WalletIter as DashWalletIter, Base58Wordlist as DashWordlist,
+};
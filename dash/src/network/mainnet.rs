@@ -0,0 +1,40 @@
+use crate::network::DashNetwork;
+use wagyu_model::{Base58Network, ChildIndex, Network, NetworkError};
+
+use core::{fmt, str::FromStr};
+use serde::Serialize;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct Mainnet;
+
+impl Network for Mainnet {
+    const NAME: &'static str = "mainnet";
+}
+
+impl Base58Network for Mainnet {
+    const HD_COIN_TYPE: ChildIndex = ChildIndex::Hardened(5);
+    const P2PKH_PREFIX: u8 = 0x4C;
+    const P2SH_PREFIX: u8 = 0x10;
+    const WIF_PREFIX: u8 = 0xCC;
+    const EXTENDED_PRIVATE_KEY_VERSION_BYTES: [u8; 4] = [0x04, 0x88, 0xAD, 0xE4]; // xprv
+    const EXTENDED_PUBLIC_KEY_VERSION_BYTES: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E]; // xpub
+}
+
+impl DashNetwork for Mainnet {}
+
+impl FromStr for Mainnet {
+    type Err = NetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            Self::NAME => Ok(Self),
+            _ => Err(NetworkError::InvalidNetwork(s.into())),
+        }
+    }
+}
+
+impl fmt::Display for Mainnet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::NAME)
+    }
+}
@@ -0,0 +1,65 @@
+use wagyu_model::Base58Network;
+
+pub mod any_network;
+pub use self::any_network::*;
+
+pub mod mainnet;
+pub use self::mainnet::*;
+
+pub mod testnet;
+pub use self::testnet::*;
+
+/// The interface for a Dash network, configured by its table of base58 version bytes.
+pub trait DashNetwork: Base58Network {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DashAddress, DashDerivationPath, DashExtendedPrivateKey, DashFormat, DashPrivateKey};
+    use wagyu_model::{Address, ExtendedPrivateKey};
+
+    use core::str::FromStr;
+
+    fn test_address<N: DashNetwork>(private_key: &str, expected_address: &str) {
+        let private_key = DashPrivateKey::<N>::from_str(private_key).unwrap();
+        let address = DashAddress::from_private_key(&private_key, &DashFormat::P2PKH).unwrap();
+        assert_eq!(expected_address, address.to_string());
+        assert_eq!(address, DashAddress::<N>::from_str(expected_address).unwrap());
+    }
+
+    #[test]
+    fn mainnet() {
+        test_address::<Mainnet>(
+            "XGs2skA6BKmxsHPNoYVS9YK4Go9epaccZPzaR8DtaT4xjfJBZTbc",
+            "XrAnYruqqGpDNz8ZAS3zFPfWZF3QLv6ZrZ",
+        );
+
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let path = DashDerivationPath::from_str("m/0'").unwrap();
+        let extended_private_key = DashExtendedPrivateKey::<Mainnet>::new(&seed, &DashFormat::P2PKH, &path).unwrap();
+        assert_eq!(
+            "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7",
+            extended_private_key.to_string()
+        );
+
+        // The BIP44 paths of the network use its coin type
+        let path = DashDerivationPath::<Mainnet>::from_str("m/44'/5'/0'/0/0").unwrap();
+        assert!(matches!(path, DashDerivationPath::BIP44(_)));
+    }
+
+    #[test]
+    fn testnet() {
+        test_address::<Testnet>(
+            "cSCkpm1oSHTUtX5CHdQ4FzTv9qxLQWKx2SXMg22hbGSTNVcsUcCX",
+            "ybziEtHnGWG5AvaXekCQMsvSXcXkhTrmsU",
+        );
+        assert!(DashAddress::<Mainnet>::from_str("ybziEtHnGWG5AvaXekCQMsvSXcXkhTrmsU").is_err());
+
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let extended_private_key = DashExtendedPrivateKey::<Testnet>::new_master(&seed, &DashFormat::P2PKH).unwrap();
+        assert_eq!(
+            "tprv8ZgxMBicQKsPeDgjzdC36fs6bMjGApWDNLR9erAXMs5skhMv36j9MV5ecvfavji5khqjWaWSFhN3YcCUUdiKH6isR4Pwy3U5y5egddBr16m",
+            extended_private_key.to_string()
+        );
+    }
+}
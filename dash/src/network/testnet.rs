@@ -0,0 +1,40 @@
+use crate::network::DashNetwork;
+use wagyu_model::{Base58Network, ChildIndex, Network, NetworkError};
+
+use core::{fmt, str::FromStr};
+use serde::Serialize;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct Testnet;
+
+impl Network for Testnet {
+    const NAME: &'static str = "testnet";
+}
+
+impl Base58Network for Testnet {
+    const HD_COIN_TYPE: ChildIndex = ChildIndex::Hardened(1);
+    const P2PKH_PREFIX: u8 = 0x8C;
+    const P2SH_PREFIX: u8 = 0x13;
+    const WIF_PREFIX: u8 = 0xEF;
+    const EXTENDED_PRIVATE_KEY_VERSION_BYTES: [u8; 4] = [0x04, 0x35, 0x83, 0x94]; // tprv
+    const EXTENDED_PUBLIC_KEY_VERSION_BYTES: [u8; 4] = [0x04, 0x35, 0x87, 0xCF]; // tpub
+}
+
+impl DashNetwork for Testnet {}
+
+impl FromStr for Testnet {
+    type Err = NetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            Self::NAME => Ok(Self),
+            _ => Err(NetworkError::InvalidNetwork(s.into())),
+        }
+    }
+}
+
+impl fmt::Display for Testnet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::NAME)
+    }
+}
@@ -0,0 +1,191 @@
+use wagyu_model::no_std::*;
+use wagyu_model::{Amount, AmountError};
+
+use core::fmt;
+use serde::Serialize;
+
+// Number of koinu (base unit) per DOGE
+const COIN: i64 = 1_0000_0000;
+
+// Maximum number of koinu
+const MAX_COINS: i64 = 10_000_000_000 * COIN;
+
+/// Represents the amount of Dogecoin in koinu
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct DogecoinAmount(pub i64);
+
+pub enum Denomination {
+    // koinu
+    Koinu,
+    // DOGE
+    Dogecoin,
+}
+
+impl Denomination {
+    /// The number of decimal places more than a koinu.
+    fn precision(self) -> u32 {
+        match self {
+            Denomination::Koinu => 0,
+            Denomination::Dogecoin => 8,
+        }
+    }
+}
+
+impl fmt::Display for Denomination {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Denomination::Koinu => "koinu",
+                Denomination::Dogecoin => "DOGE",
+            }
+        )
+    }
+}
+
+impl Amount for DogecoinAmount {}
+
+impl DogecoinAmount {
+    /// The zero amount.
+    pub const ZERO: DogecoinAmount = DogecoinAmount(0);
+    /// Exactly one koinu.
+    pub const ONE_KOINU: DogecoinAmount = DogecoinAmount(1);
+    /// Exactly one dogecoin.
+    pub const ONE_DOGE: DogecoinAmount = DogecoinAmount(COIN);
+
+    pub fn from_koinu(koinu: i64) -> Result<Self, AmountError> {
+        if (-MAX_COINS..=MAX_COINS).contains(&koinu) {
+            Ok(Self(koinu))
+        } else {
+            Err(AmountError::AmountOutOfBounds(koinu.to_string(), MAX_COINS.to_string()))
+        }
+    }
+
+    pub fn from_doge(doge_value: i64) -> Result<Self, AmountError> {
+        match doge_value.checked_mul(10_i64.pow(Denomination::Dogecoin.precision())) {
+            Some(koinu) => Self::from_koinu(koinu),
+            None => Err(AmountError::AmountOutOfBounds(
+                doge_value.to_string(),
+                (MAX_COINS / COIN).to_string(),
+            )),
+        }
+    }
+
+    /// Returns the amount of a decimal value in DOGE, as in `12.5`, with at most 8 decimal places.
+    pub fn from_doge_str(doge_value: &str) -> Result<Self, AmountError> {
+        let invalid = || AmountError::InvalidAmount(doge_value.into());
+        let (integer, fraction) = match doge_value.find('.') {
+            Some(index) => (&doge_value[..index], &doge_value[index + 1..]),
+            None => (doge_value, ""),
+        };
+        let precision = Denomination::Dogecoin.precision() as usize;
+        if (integer.is_empty() && fraction.is_empty())
+            || fraction.len() > precision
+            || !integer.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let integer = match integer.is_empty() {
+            true => 0,
+            false => integer.parse::<i64>().map_err(|_| invalid())?,
+        };
+        let fraction = format!("{:0<1$}", fraction, precision)
+            .parse::<i64>()
+            .map_err(|_| invalid())?;
+        match integer.checked_mul(COIN).and_then(|koinu| koinu.checked_add(fraction)) {
+            Some(koinu) => Self::from_koinu(koinu),
+            None => Err(invalid()),
+        }
+    }
+
+    /// Returns the decimal value of the amount in DOGE, without trailing zeros, as in `12.5`.
+    pub fn to_doge_string(&self) -> String {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let (integer, fraction) = (self.0.abs() / COIN, self.0.abs() % COIN);
+        match fraction {
+            0 => format!("{}{}", sign, integer),
+            _ => format!("{}{}.{:08}", sign, integer, fraction)
+                .trim_end_matches('0')
+                .to_string(),
+        }
+    }
+
+    pub fn add(self, b: Self) -> Result<Self, AmountError> {
+        Self::from_koinu(self.0 + b.0)
+    }
+
+    pub fn sub(self, b: DogecoinAmount) -> Result<Self, AmountError> {
+        Self::from_koinu(self.0 - b.0)
+    }
+}
+
+impl fmt::Display for DogecoinAmount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_koinu_conversion() {
+        assert_eq!(DogecoinAmount(0), DogecoinAmount::from_koinu(0).unwrap());
+        assert_eq!(DogecoinAmount(1), DogecoinAmount::from_koinu(1).unwrap());
+        assert_eq!(
+            DogecoinAmount(MAX_COINS),
+            DogecoinAmount::from_koinu(MAX_COINS).unwrap()
+        );
+        assert!(DogecoinAmount::from_koinu(MAX_COINS + 1).is_err());
+        assert!(DogecoinAmount::from_koinu(-MAX_COINS - 1).is_err());
+    }
+
+    #[test]
+    fn test_doge_conversion() {
+        assert_eq!(DogecoinAmount::ONE_DOGE, DogecoinAmount::from_doge(1).unwrap());
+        assert_eq!(DogecoinAmount(69_000_000_000), DogecoinAmount::from_doge(690).unwrap());
+        assert_eq!(
+            DogecoinAmount(MAX_COINS),
+            DogecoinAmount::from_doge(10_000_000_000).unwrap()
+        );
+        assert!(DogecoinAmount::from_doge(10_000_000_001).is_err());
+        assert!(DogecoinAmount::from_doge(i64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_doge_str_conversion() {
+        let pairs = [
+            ("0", 0),
+            ("1", 100_000_000),
+            ("12.5", 1_250_000_000),
+            (".5", 50_000_000),
+            ("0.00000001", 1),
+            ("10000000000", MAX_COINS),
+        ];
+        pairs.iter().for_each(|(doge_value, koinu)| {
+            let amount = DogecoinAmount::from_doge_str(doge_value).unwrap();
+            assert_eq!(DogecoinAmount(*koinu), amount);
+        });
+        assert_eq!("12.5", DogecoinAmount(1_250_000_000).to_doge_string());
+        assert_eq!("0.00000001", DogecoinAmount(1).to_doge_string());
+        assert_eq!("-3", DogecoinAmount(-300_000_000).to_doge_string());
+
+        let invalid = ["", ".", "-1", "1e8", "0.000000001", "1.0.0", "10000000000.00000001"];
+        invalid.iter().for_each(|doge_value| {
+            assert!(DogecoinAmount::from_doge_str(doge_value).is_err(), "{}", doge_value);
+        });
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = DogecoinAmount::from_doge(100).unwrap();
+        let b = DogecoinAmount::from_koinu(1).unwrap();
+        assert_eq!(DogecoinAmount(10_000_000_001), a.add(b).unwrap());
+        assert_eq!(DogecoinAmount(9_999_999_999), a.sub(b).unwrap());
+        assert!(DogecoinAmount(MAX_COINS).add(b).is_err());
+        assert!(DogecoinAmount(-MAX_COINS).sub(b).is_err());
+    }
+}
@@ -0,0 +1,24 @@
+//! # Dogecoin
+//!
+//! A library for generating Dogecoin wallets.
+//!
+//! The addresses, keys, and mnemonics of Dogecoin are those of `wagyu-base58`,
+//! configured by the base58 version bytes of the Dogecoin networks.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(unused_extern_crates, dead_code)]
+#![forbid(unsafe_code)]
+
+pub mod amount;
+pub use self::amount::*;
+
+pub mod network;
+pub use self::network::*;
+
+pub use wagyu_base58::wordlist::{self, *};
+pub use wagyu_base58::{
+    Base58Address as DogecoinAddress, Base58DerivationPath as DogecoinDerivationPath,
+    Base58ExtendedPrivateKey as DogecoinExtendedPrivateKey, Base58ExtendedPublicKey as DogecoinExtendedPublicKey,
+    Base58Format as DogecoinFormat, Base58Mnemonic as DogecoinMnemonic, Base58PrivateKey as DogecoinPrivateKey,
+    Base58PublicKey as DogecoinPublicKey, Base58WalletIter as DogecoinWalletIter, Base58Wordlist as DogecoinWordlist,
+};
@@ -0,0 +1,69 @@
+use wagyu_model::Base58Network;
+
+pub mod any_network;
+pub use self::any_network::*;
+
+pub mod mainnet;
+pub use self::mainnet::*;
+
+pub mod testnet;
+pub use self::testnet::*;
+
+/// The interface for a Dogecoin network, configured by its table of base58 version bytes.
+pub trait DogecoinNetwork: Base58Network {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        DogecoinAddress, DogecoinDerivationPath, DogecoinExtendedPrivateKey, DogecoinFormat, DogecoinPrivateKey,
+    };
+    use wagyu_model::{Address, ExtendedPrivateKey};
+
+    use core::str::FromStr;
+
+    fn test_address<N: DogecoinNetwork>(private_key: &str, expected_address: &str) {
+        let private_key = DogecoinPrivateKey::<N>::from_str(private_key).unwrap();
+        let address = DogecoinAddress::from_private_key(&private_key, &DogecoinFormat::P2PKH).unwrap();
+        assert_eq!(expected_address, address.to_string());
+        assert_eq!(address, DogecoinAddress::<N>::from_str(expected_address).unwrap());
+    }
+
+    #[test]
+    fn mainnet() {
+        test_address::<Mainnet>(
+            "QUC2aKai8Fde3Un3N4LMXXxepoueRL9B55LagEHXzSQDnS5DFrHm",
+            "DLd3FsCbAyVum3ia38jKwd9Kc3C1aftCva",
+        );
+
+        let seed = hex::decode("747f302d9c916698912d5f70be53a6cf53bc495803a5523d3a7c3afa2afba94ec3803f838b3e1929ab5481f9da35441372283690fdcf27372c38f40ba134fe03").unwrap();
+        let path = DogecoinDerivationPath::from_str("m/0'/0'").unwrap();
+        let extended_private_key =
+            DogecoinExtendedPrivateKey::<Mainnet>::new(&seed, &DogecoinFormat::P2PKH, &path).unwrap();
+        assert_eq!(
+            "dgpv55izM1V42ZQgHpXdKzUU8hnc3nftBziD6Lu6214CyRir48Ui4YK8J9YVFXPFRMJisS2nYExmCkDtL1FPsBHBiRvduSWHR51fZUr94JiWR4v",
+            extended_private_key.to_string()
+        );
+
+        // The BIP44 paths of the network use its coin type
+        let path = DogecoinDerivationPath::<Mainnet>::from_str("m/44'/3'/0'/0/0").unwrap();
+        assert!(matches!(path, DogecoinDerivationPath::BIP44(_)));
+    }
+
+    #[test]
+    fn testnet() {
+        test_address::<Testnet>(
+            "cjN1y5jbnbHkqZ6jPnBqrLeteiMe5LsQMKmcnvitaDwsw9yDzdTw",
+            "njsRexF16dkR6DpBzRCoGVaCXzaHUYALa6",
+        );
+        assert!(DogecoinAddress::<Mainnet>::from_str("njsRexF16dkR6DpBzRCoGVaCXzaHUYALa6").is_err());
+
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let extended_private_key =
+            DogecoinExtendedPrivateKey::<Testnet>::new_master(&seed, &DogecoinFormat::P2PKH).unwrap();
+        assert_eq!(
+            "tprv8ZgxMBicQKsPeDgjzdC36fs6bMjGApWDNLR9erAXMs5skhMv36j9MV5ecvfavji5khqjWaWSFhN3YcCUUdiKH6isR4Pwy3U5y5egddBr16m",
+            extended_private_key.to_string()
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use wagyu_ethereum::{
+    EthereumAddress, EthereumAmount, EthereumFormat, EthereumNetwork, EthereumPrivateKey, EthereumTransaction,
+    EthereumTransactionParameters, EthereumTransactionType, Mainnet,
+};
+use wagyu_model::{PrivateKey, Transaction};
+
+use core::str::FromStr;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ethereum_types::U256;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+const PRIVATE_KEY: &str = "6f8b51f3bad09ad6cd5be4b0f3ae40a9bc9fc7ab3e10bd5d3a5f3a1e6b0a92ee";
+const RECEIVER: &str = "0x52c3a0b8cb6bd6d4e6c5dc4c7f1e6a1c5e8b47c5";
+
+fn parameters() -> EthereumTransactionParameters {
+    EthereumTransactionParameters {
+        receiver: EthereumAddress::from_str(RECEIVER).unwrap(),
+        amount: EthereumAmount::from_wei("1000000000000000000").unwrap(),
+        gas: U256::from(21000),
+        gas_price: EthereumAmount::from_wei("20000000000").unwrap(),
+        nonce: U256::from(0),
+        data: vec![],
+        transaction_type: EthereumTransactionType::Legacy,
+    }
+}
+
+fn private_key_new(c: &mut Criterion) {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    c.bench_function("ethereum_private_key_new", |b| {
+        b.iter(|| EthereumPrivateKey::new(rng).unwrap())
+    });
+}
+
+fn to_address(c: &mut Criterion) {
+    let private_key = EthereumPrivateKey::from_str(PRIVATE_KEY).unwrap();
+    c.bench_function("ethereum_to_address", |b| {
+        b.iter(|| black_box(&private_key).to_address(&EthereumFormat::Standard).unwrap())
+    });
+}
+
+fn sign<N: EthereumNetwork>(c: &mut Criterion) {
+    let private_key = EthereumPrivateKey::from_str(PRIVATE_KEY).unwrap();
+    let transaction = EthereumTransaction::<N>::new(&parameters()).unwrap();
+    c.bench_function("ethereum_transaction_sign", |b| {
+        b.iter(|| black_box(&transaction).sign(&private_key).unwrap())
+    });
+}
+
+criterion_group!(benches, private_key_new, to_address, sign::<Mainnet>);
+criterion_main!(benches);
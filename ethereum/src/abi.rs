@@ -0,0 +1,1016 @@
+//!
+//! ABI
+//!
+//! This module contains the encoding and decoding of Ethereum contract function calls and their
+//! return data, as specified by the Solidity contract ABI (https://docs.soliditylang.org/en/latest/abi-spec.html).
+//!
+//! Functions are parsed from a signature (`transfer(address,uint256)`), from a JSON ABI fragment,
+//! or from a call with its arguments (`transfer(0x52C3a8a79a521D10b25569847CB1a3FfB66550D6,1000e18)`),
+//! where the type of each argument is inferred from its value or prefixed to it (`uint8 5`).
+//!
+
+use crate::address::EthereumAddress;
+use wagyu_model::{AddressError, TransactionError};
+
+use core::{fmt, str::FromStr};
+use ethereum_types::U256;
+use serde_json::Value;
+use tiny_keccak::keccak256;
+
+#[derive(Debug, Fail)]
+pub enum AbiError {
+    #[fail(display = "{}: {}", _0, _1)]
+    Crate(&'static str, String),
+
+    #[fail(display = "invalid ABI data: {}", _0)]
+    InvalidData(String),
+
+    #[fail(display = "invalid ABI JSON: {}", _0)]
+    InvalidJson(String),
+
+    #[fail(display = "invalid function signature: {}", _0)]
+    InvalidSignature(String),
+
+    #[fail(display = "invalid ABI type: {}", _0)]
+    InvalidType(String),
+
+    #[fail(display = "invalid {} value: {}", _0, _1)]
+    InvalidValue(String, String),
+
+    #[fail(display = "invalid number of arguments: {{ expected: {:?}, found: {:?} }}", _0, _1)]
+    MismatchedArguments(usize, usize),
+
+    #[fail(display = "invalid function selector: {{ expected: {:?}, found: {:?} }}", _0, _1)]
+    MismatchedSelector(String, String),
+}
+
+impl From<AddressError> for AbiError {
+    fn from(error: AddressError) -> Self {
+        AbiError::Crate("address", format!("{:?}", error))
+    }
+}
+
+impl From<hex::FromHexError> for AbiError {
+    fn from(error: hex::FromHexError) -> Self {
+        AbiError::Crate("hex", format!("{:?}", error))
+    }
+}
+
+impl From<serde_json::error::Error> for AbiError {
+    fn from(error: serde_json::error::Error) -> Self {
+        AbiError::InvalidJson(error.to_string())
+    }
+}
+
+impl From<AbiError> for TransactionError {
+    fn from(error: AbiError) -> Self {
+        TransactionError::Crate("abi", error.to_string())
+    }
+}
+
+/// Represents the type of a parameter of a contract function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiType {
+    Address,
+    Bool,
+    Bytes,
+    FixedBytes(usize),
+    Int(usize),
+    String,
+    Uint(usize),
+    Array(Box<AbiType>),
+    FixedArray(Box<AbiType>, usize),
+    Tuple(Vec<AbiType>),
+}
+
+impl AbiType {
+    /// Returns `true` if the encoding of the type has a dynamic length,
+    /// in which case it is encoded after the static parameters, at an offset.
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            AbiType::Bytes | AbiType::String | AbiType::Array(_) => true,
+            AbiType::FixedArray(element, _) => element.is_dynamic(),
+            AbiType::Tuple(elements) => elements.iter().any(|element| element.is_dynamic()),
+            _ => false,
+        }
+    }
+
+    /// Returns the number of bytes of the type in the head of an encoding.
+    fn head_size(&self) -> usize {
+        match self {
+            _ if self.is_dynamic() => 32,
+            AbiType::FixedArray(element, length) => element.head_size() * length,
+            AbiType::Tuple(elements) => elements.iter().map(|element| element.head_size()).sum(),
+            _ => 32,
+        }
+    }
+
+    /// Returns the type of the specified value, for a call with untyped arguments.
+    /// A 20-byte hex value is an address, any other hex value is bytes, a negative
+    /// number is an `int256`, and any other number is a `uint256`.
+    fn infer(value: &str) -> Result<Self, AbiError> {
+        let value = value.trim();
+        match value {
+            "true" | "false" => Ok(AbiType::Bool),
+            _ if value.starts_with('"') => Ok(AbiType::String),
+            _ if value.starts_with("0x") => match value.len() {
+                42 => Ok(AbiType::Address),
+                _ => Ok(AbiType::Bytes),
+            },
+            _ if value.starts_with('-') => Ok(AbiType::Int(256)),
+            _ if value.starts_with('[') && value.ends_with(']') => match split(&value[1..value.len() - 1])?.first() {
+                Some(element) => Ok(AbiType::Array(Box::new(Self::infer(element)?))),
+                None => Err(AbiError::InvalidValue(
+                    "array".into(),
+                    format!("{} (specify the type of an empty array, e.g. uint256[] [])", value),
+                )),
+            },
+            _ if value.starts_with('(') && value.ends_with(')') => Ok(AbiType::Tuple(
+                split(&value[1..value.len() - 1])?
+                    .iter()
+                    .map(|element| Self::infer(element))
+                    .collect::<Result<_, _>>()?,
+            )),
+            _ => Ok(AbiType::Uint(256)),
+        }
+    }
+
+    /// Returns the type of a parameter of a JSON ABI fragment,
+    /// replacing the `tuple` type with the types of its components.
+    fn from_json(parameter: &Value) -> Result<Self, AbiError> {
+        let abi_type = match parameter["type"].as_str() {
+            Some(abi_type) => abi_type,
+            None => {
+                return Err(AbiError::InvalidJson(format!(
+                    "parameter without a type: {}",
+                    parameter
+                )))
+            }
+        };
+        match abi_type.strip_prefix("tuple") {
+            Some(suffix) => {
+                let components = match parameter["components"].as_array() {
+                    Some(components) => components
+                        .iter()
+                        .map(|component| Ok(Self::from_json(component)?.to_string()))
+                        .collect::<Result<Vec<String>, AbiError>>()?,
+                    None => {
+                        return Err(AbiError::InvalidJson(format!(
+                            "tuple without components: {}",
+                            parameter
+                        )))
+                    }
+                };
+                Self::from_str(&format!("({}){}", components.join(","), suffix))
+            }
+            None => Self::from_str(abi_type),
+        }
+    }
+}
+
+impl FromStr for AbiType {
+    type Err = AbiError;
+
+    fn from_str(abi_type: &str) -> Result<Self, Self::Err> {
+        let abi_type = abi_type.trim();
+        let invalid = || AbiError::InvalidType(abi_type.into());
+
+        // Parses the last array suffix of the type, as in `uint256[2][]`
+        if abi_type.ends_with(']') {
+            let start = abi_type.rfind('[').ok_or_else(invalid)?;
+            let element = Box::new(Self::from_str(&abi_type[..start])?);
+            return match &abi_type[start + 1..abi_type.len() - 1] {
+                "" => Ok(AbiType::Array(element)),
+                length => Ok(AbiType::FixedArray(element, length.parse().map_err(|_| invalid())?)),
+            };
+        }
+
+        if abi_type.starts_with('(') && abi_type.ends_with(')') {
+            return Ok(AbiType::Tuple(
+                split(&abi_type[1..abi_type.len() - 1])?
+                    .iter()
+                    .map(|element| Self::from_str(element))
+                    .collect::<Result<_, _>>()?,
+            ));
+        }
+
+        let size = |prefix: &str| -> Result<Option<usize>, AbiError> {
+            match &abi_type[prefix.len()..] {
+                "" => Ok(None),
+                size => size.parse().map(Some).map_err(|_| invalid()),
+            }
+        };
+        match abi_type {
+            "address" => Ok(AbiType::Address),
+            "bool" => Ok(AbiType::Bool),
+            "bytes" => Ok(AbiType::Bytes),
+            "string" => Ok(AbiType::String),
+            _ if abi_type.starts_with("bytes") => match size("bytes")? {
+                Some(size) if (1..=32).contains(&size) => Ok(AbiType::FixedBytes(size)),
+                _ => Err(invalid()),
+            },
+            _ if abi_type.starts_with("int") || abi_type.starts_with("uint") => {
+                let signed = abi_type.starts_with("int");
+                let bits = match size(if signed { "int" } else { "uint" })? {
+                    Some(bits) if bits % 8 == 0 && (8..=256).contains(&bits) => bits,
+                    Some(_) => return Err(invalid()),
+                    None => 256,
+                };
+                match signed {
+                    true => Ok(AbiType::Int(bits)),
+                    false => Ok(AbiType::Uint(bits)),
+                }
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl fmt::Display for AbiType {
+    /// Writes the canonical form of the type, as used in a function signature.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AbiType::Address => write!(f, "address"),
+            AbiType::Bool => write!(f, "bool"),
+            AbiType::Bytes => write!(f, "bytes"),
+            AbiType::FixedBytes(size) => write!(f, "bytes{}", size),
+            AbiType::Int(bits) => write!(f, "int{}", bits),
+            AbiType::String => write!(f, "string"),
+            AbiType::Uint(bits) => write!(f, "uint{}", bits),
+            AbiType::Array(element) => write!(f, "{}[]", element),
+            AbiType::FixedArray(element, length) => write!(f, "{}[{}]", element, length),
+            AbiType::Tuple(elements) => write!(f, "({})", join(elements)),
+        }
+    }
+}
+
+/// Represents the value of a parameter of a contract function.
+/// The value of an `int` is stored in two's complement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiToken {
+    Address(EthereumAddress),
+    Bool(bool),
+    Bytes(Vec<u8>),
+    FixedBytes(Vec<u8>),
+    Int(U256),
+    String(String),
+    Uint(U256),
+    Array(Vec<AbiToken>),
+    FixedArray(Vec<AbiToken>),
+    Tuple(Vec<AbiToken>),
+}
+
+impl AbiToken {
+    /// Returns the token of the specified type from its string value. Numbers are in decimal,
+    /// with an optional exponent (`1000e18`, `1.5e18`), or in hex (`0x3e8`). Bytes are in hex,
+    /// arrays are enclosed in brackets (`[1,2]`), tuples in parentheses (`(1,true)`),
+    /// and strings are optionally enclosed in quotes, which are required for a string with a comma.
+    pub fn parse(abi_type: &AbiType, value: &str) -> Result<Self, AbiError> {
+        let value = value.trim();
+        let invalid = || AbiError::InvalidValue(abi_type.to_string(), value.into());
+        let token = match abi_type {
+            AbiType::Address => {
+                hex::decode(value.strip_prefix("0x").unwrap_or(value)).map_err(|_| invalid())?;
+                AbiToken::Address(EthereumAddress::from_str(value).map_err(|_| invalid())?)
+            }
+            AbiType::Bool => match value {
+                "true" => AbiToken::Bool(true),
+                "false" => AbiToken::Bool(false),
+                _ => return Err(invalid()),
+            },
+            AbiType::Bytes => AbiToken::Bytes(from_hex(value).ok_or_else(invalid)?),
+            AbiType::FixedBytes(_) => AbiToken::FixedBytes(from_hex(value).ok_or_else(invalid)?),
+            AbiType::Int(_) => match value.strip_prefix('-') {
+                // The magnitude of a negative number is at most 2^255
+                Some(magnitude) => match parse_number(magnitude).ok_or_else(invalid)? {
+                    magnitude if magnitude.bits() <= 255 || magnitude == U256::one() << 255 => {
+                        AbiToken::Int(negate(magnitude))
+                    }
+                    _ => return Err(invalid()),
+                },
+                None => match parse_number(value).ok_or_else(invalid)? {
+                    number if number.bits() <= 255 => AbiToken::Int(number),
+                    _ => return Err(invalid()),
+                },
+            },
+            AbiType::String => AbiToken::String(
+                match value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+                    true => &value[1..value.len() - 1],
+                    false => value,
+                }
+                .into(),
+            ),
+            AbiType::Uint(_) => AbiToken::Uint(parse_number(value).ok_or_else(invalid)?),
+            AbiType::Array(element) | AbiType::FixedArray(element, _) => {
+                if !value.starts_with('[') || !value.ends_with(']') {
+                    return Err(invalid());
+                }
+                let elements = split(&value[1..value.len() - 1])?
+                    .iter()
+                    .map(|value| Self::parse(element, value))
+                    .collect::<Result<_, _>>()?;
+                match abi_type {
+                    AbiType::Array(_) => AbiToken::Array(elements),
+                    _ => AbiToken::FixedArray(elements),
+                }
+            }
+            AbiType::Tuple(elements) => {
+                if !value.starts_with('(') || !value.ends_with(')') {
+                    return Err(invalid());
+                }
+                let values = split(&value[1..value.len() - 1])?;
+                if values.len() != elements.len() {
+                    return Err(invalid());
+                }
+                AbiToken::Tuple(
+                    elements
+                        .iter()
+                        .zip(values)
+                        .map(|(element, value)| Self::parse(element, value))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+        };
+        match token.is_type(abi_type) {
+            true => Ok(token),
+            false => Err(invalid()),
+        }
+    }
+
+    /// Returns `true` if the token is a valid value of the specified type.
+    pub fn is_type(&self, abi_type: &AbiType) -> bool {
+        match (self, abi_type) {
+            (AbiToken::Address(_), AbiType::Address)
+            | (AbiToken::Bool(_), AbiType::Bool)
+            | (AbiToken::Bytes(_), AbiType::Bytes)
+            | (AbiToken::String(_), AbiType::String) => true,
+            (AbiToken::FixedBytes(bytes), AbiType::FixedBytes(size)) => bytes.len() == *size,
+            (AbiToken::Int(number), AbiType::Int(bits)) => {
+                // The number is sign-extended from its lowest `bits` bits
+                let bound = U256::one() << (bits - 1);
+                *bits == 256 || *number < bound || *number >= negate(bound)
+            }
+            (AbiToken::Uint(number), AbiType::Uint(bits)) => number.bits() <= *bits,
+            (AbiToken::Array(tokens), AbiType::Array(element)) => tokens.iter().all(|token| token.is_type(element)),
+            (AbiToken::FixedArray(tokens), AbiType::FixedArray(element, length)) => {
+                tokens.len() == *length && tokens.iter().all(|token| token.is_type(element))
+            }
+            (AbiToken::Tuple(tokens), AbiType::Tuple(elements)) => {
+                tokens.len() == elements.len()
+                    && tokens
+                        .iter()
+                        .zip(elements)
+                        .all(|(token, element)| token.is_type(element))
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the encoding of the token has a dynamic length.
+    fn is_dynamic(&self) -> bool {
+        match self {
+            AbiToken::Bytes(_) | AbiToken::String(_) | AbiToken::Array(_) => true,
+            AbiToken::FixedArray(tokens) | AbiToken::Tuple(tokens) => tokens.iter().any(|token| token.is_dynamic()),
+            _ => false,
+        }
+    }
+
+    /// Returns the number of bytes of the token in the head of an encoding.
+    fn head_size(&self) -> usize {
+        match self {
+            _ if self.is_dynamic() => 32,
+            AbiToken::FixedArray(tokens) | AbiToken::Tuple(tokens) => {
+                tokens.iter().map(|token| token.head_size()).sum()
+            }
+            _ => 32,
+        }
+    }
+
+    /// Returns the encoding of the token, excluding the offset of a dynamic token.
+    fn encode(&self) -> Result<Vec<u8>, AbiError> {
+        Ok(match self {
+            AbiToken::Address(address) => {
+                let mut word = vec![0u8; 12];
+                word.extend(hex::decode(&address.to_string()[2..])?);
+                word
+            }
+            AbiToken::Bool(value) => to_word(U256::from(*value as u8)),
+            AbiToken::Bytes(bytes) => [to_word(U256::from(bytes.len())), pad_right(bytes)].concat(),
+            AbiToken::FixedBytes(bytes) => pad_right(bytes),
+            AbiToken::Int(number) | AbiToken::Uint(number) => to_word(*number),
+            AbiToken::String(string) => [to_word(U256::from(string.len())), pad_right(string.as_bytes())].concat(),
+            AbiToken::Array(tokens) => [to_word(U256::from(tokens.len())), encode(tokens)?].concat(),
+            AbiToken::FixedArray(tokens) | AbiToken::Tuple(tokens) => encode(tokens)?,
+        })
+    }
+}
+
+impl fmt::Display for AbiToken {
+    /// Writes the token in the format of its string value, as parsed by `AbiToken::parse`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AbiToken::Address(address) => write!(f, "{}", address),
+            AbiToken::Bool(value) => write!(f, "{}", value),
+            AbiToken::Bytes(bytes) | AbiToken::FixedBytes(bytes) => write!(f, "0x{}", hex::encode(bytes)),
+            AbiToken::Int(number) => match number.bit(255) {
+                true => write!(f, "-{}", negate(*number)),
+                false => write!(f, "{}", number),
+            },
+            AbiToken::String(string) => write!(f, "\"{}\"", string),
+            AbiToken::Uint(number) => write!(f, "{}", number),
+            AbiToken::Array(tokens) | AbiToken::FixedArray(tokens) => write!(f, "[{}]", join(tokens)),
+            AbiToken::Tuple(tokens) => write!(f, "({})", join(tokens)),
+        }
+    }
+}
+
+/// Represents a contract function, with the types of its inputs and outputs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiFunction {
+    /// The name of the function
+    pub name: String,
+    /// The types of the inputs of the function
+    pub inputs: Vec<AbiType>,
+    /// The types of the outputs of the function
+    pub outputs: Vec<AbiType>,
+}
+
+impl AbiFunction {
+    /// Returns the function and its arguments from a call, as in `transfer(0x...,1000e18)`.
+    /// The type of each argument is prefixed to its value (`uint8 5`), or otherwise inferred from it.
+    pub fn from_call(call: &str) -> Result<(Self, Vec<AbiToken>), AbiError> {
+        let (name, arguments, _) = split_signature(call)?;
+        let (inputs, tokens) = split(arguments)?
+            .iter()
+            .map(|argument| {
+                let (abi_type, value) = match split_type(argument) {
+                    Some((abi_type, value)) => (abi_type, value),
+                    None => (AbiType::infer(argument)?, *argument),
+                };
+                let token = AbiToken::parse(&abi_type, value)?;
+                Ok((abi_type, token))
+            })
+            .collect::<Result<Vec<_>, AbiError>>()?
+            .into_iter()
+            .unzip();
+        let function = Self {
+            name: name.into(),
+            inputs,
+            outputs: vec![],
+        };
+        Ok((function, tokens))
+    }
+
+    /// Returns the function from a JSON ABI fragment, as an object or as an array
+    /// with one function, as in `{"type":"function","name":"transfer","inputs":[...],"outputs":[...]}`.
+    pub fn from_json(fragment: &str) -> Result<Self, AbiError> {
+        let fragment: Value = serde_json::from_str(fragment)?;
+        let is_function = |entry: &&Value| entry["type"].as_str().unwrap_or("function") == "function";
+        let function = match &fragment {
+            Value::Array(entries) => match entries.iter().filter(is_function).collect::<Vec<_>>()[..] {
+                [function] => function,
+                _ => return Err(AbiError::InvalidJson("expected an array with one function".into())),
+            },
+            function if is_function(&function) => function,
+            _ => return Err(AbiError::InvalidJson("expected a function".into())),
+        };
+        let parameters = |key: &str| -> Result<Vec<AbiType>, AbiError> {
+            match function[key].as_array() {
+                Some(parameters) => parameters.iter().map(AbiType::from_json).collect(),
+                None => Ok(vec![]),
+            }
+        };
+        match function["name"].as_str() {
+            Some(name) => Ok(Self {
+                name: name.into(),
+                inputs: parameters("inputs")?,
+                outputs: parameters("outputs")?,
+            }),
+            None => Err(AbiError::InvalidJson("function without a name".into())),
+        }
+    }
+
+    /// Returns the canonical signature of the function, as in `transfer(address,uint256)`.
+    pub fn signature(&self) -> String {
+        format!("{}({})", self.name, join(&self.inputs))
+    }
+
+    /// Returns the selector of the function, the first 4 bytes of the Keccak-256 hash of its signature.
+    pub fn selector(&self) -> [u8; 4] {
+        let mut selector = [0u8; 4];
+        selector.copy_from_slice(&keccak256(self.signature().as_bytes())[..4]);
+        selector
+    }
+
+    /// Returns the calldata of a call of the function with the specified arguments.
+    pub fn encode_input(&self, arguments: &[AbiToken]) -> Result<Vec<u8>, AbiError> {
+        if arguments.len() != self.inputs.len() {
+            return Err(AbiError::MismatchedArguments(self.inputs.len(), arguments.len()));
+        }
+        if let Some((argument, input)) = arguments
+            .iter()
+            .zip(&self.inputs)
+            .find(|(argument, input)| !argument.is_type(input))
+        {
+            return Err(AbiError::InvalidValue(input.to_string(), argument.to_string()));
+        }
+        Ok([&self.selector()[..], &encode(arguments)?].concat())
+    }
+
+    /// Returns the arguments of a call of the function from its calldata.
+    pub fn decode_input(&self, data: &[u8]) -> Result<Vec<AbiToken>, AbiError> {
+        let selector = self.selector();
+        match data.get(..4) {
+            Some(prefix) if prefix == selector => decode(&self.inputs, &data[4..]),
+            prefix => Err(AbiError::MismatchedSelector(
+                hex::encode(selector),
+                hex::encode(prefix.unwrap_or(data)),
+            )),
+        }
+    }
+
+    /// Returns the outputs of the function from the return data of a call.
+    pub fn decode_output(&self, data: &[u8]) -> Result<Vec<AbiToken>, AbiError> {
+        decode(&self.outputs, data)
+    }
+}
+
+impl FromStr for AbiFunction {
+    type Err = AbiError;
+
+    /// Returns the function from its signature, with optional outputs, as in `balanceOf(address)(uint256)`.
+    fn from_str(signature: &str) -> Result<Self, Self::Err> {
+        let (name, inputs, outputs) = split_signature(signature)?;
+        let types = |types: &str| -> Result<Vec<AbiType>, AbiError> {
+            split(types)?
+                .iter()
+                .map(|abi_type| AbiType::from_str(abi_type))
+                .collect()
+        };
+        Ok(Self {
+            name: name.into(),
+            inputs: types(inputs)?,
+            outputs: match outputs {
+                Some(outputs) => types(outputs)?,
+                None => vec![],
+            },
+        })
+    }
+}
+
+/// Returns the encoding of the specified tokens, as the elements of a tuple.
+/// The static tokens are encoded in the head, followed by the dynamic tokens,
+/// each of which is referenced in the head by its offset from the start of the encoding.
+pub fn encode(tokens: &[AbiToken]) -> Result<Vec<u8>, AbiError> {
+    let head_size: usize = tokens.iter().map(|token| token.head_size()).sum();
+    let mut head = Vec::with_capacity(head_size);
+    let mut tail = vec![];
+    for token in tokens {
+        match token.is_dynamic() {
+            true => {
+                head.extend(to_word(U256::from(head_size + tail.len())));
+                tail.extend(token.encode()?);
+            }
+            false => head.extend(token.encode()?),
+        }
+    }
+    head.extend(tail);
+    Ok(head)
+}
+
+/// Returns the tokens of the specified types from their encoding, as the elements of a tuple.
+pub fn decode(types: &[AbiType], data: &[u8]) -> Result<Vec<AbiToken>, AbiError> {
+    let mut head = 0;
+    types
+        .iter()
+        .map(|abi_type| {
+            let token = match abi_type.is_dynamic() {
+                true => {
+                    let offset = read_length(data, head)?;
+                    decode_token(abi_type, &data[offset..])?
+                }
+                false => decode_token(abi_type, data.get(head..).unwrap_or_default())?,
+            };
+            head += abi_type.head_size();
+            Ok(token)
+        })
+        .collect()
+}
+
+/// Returns the token of the specified type from its encoding, excluding the offset of a dynamic token.
+fn decode_token(abi_type: &AbiType, data: &[u8]) -> Result<AbiToken, AbiError> {
+    let invalid = |data: &[u8]| AbiError::InvalidData(format!("invalid {} value 0x{}", abi_type, hex::encode(data)));
+    let token = match abi_type {
+        AbiType::Address => {
+            let word = read_word(data, 0)?;
+            match word[..12].iter().all(|byte| *byte == 0) {
+                true => AbiToken::Address(EthereumAddress::from_str(&hex::encode(&word[12..]))?),
+                false => return Err(invalid(word)),
+            }
+        }
+        AbiType::Bool => match U256::from_big_endian(read_word(data, 0)?) {
+            number if number.is_zero() => AbiToken::Bool(false),
+            number if number == U256::one() => AbiToken::Bool(true),
+            _ => return Err(invalid(read_word(data, 0)?)),
+        },
+        AbiType::Bytes | AbiType::String => {
+            let length = read_length(data, 0)?;
+            let bytes = data
+                .get(32..32 + length)
+                .ok_or_else(|| AbiError::InvalidData(format!("{} of length {} out of bounds", abi_type, length)))?
+                .to_vec();
+            match abi_type {
+                AbiType::Bytes => AbiToken::Bytes(bytes),
+                _ => AbiToken::String(String::from_utf8(bytes).map_err(|error| invalid(error.as_bytes()))?),
+            }
+        }
+        AbiType::FixedBytes(size) => {
+            let word = read_word(data, 0)?;
+            match word[*size..].iter().all(|byte| *byte == 0) {
+                true => AbiToken::FixedBytes(word[..*size].to_vec()),
+                false => return Err(invalid(word)),
+            }
+        }
+        AbiType::Int(_) => AbiToken::Int(U256::from_big_endian(read_word(data, 0)?)),
+        AbiType::Uint(_) => AbiToken::Uint(U256::from_big_endian(read_word(data, 0)?)),
+        AbiType::Array(element) => {
+            // Bounds the length by the data, before allocating the types of the elements
+            let length = read_length(data, 0)?;
+            if length.saturating_mul(element.head_size()) > data.len() - 32 {
+                return Err(AbiError::InvalidData(format!(
+                    "{} of length {} out of bounds",
+                    abi_type, length
+                )));
+            }
+            AbiToken::Array(decode(&vec![*element.clone(); length], &data[32..])?)
+        }
+        AbiType::FixedArray(element, length) => AbiToken::FixedArray(decode(&vec![*element.clone(); *length], data)?),
+        AbiType::Tuple(elements) => AbiToken::Tuple(decode(elements, data)?),
+    };
+    match token.is_type(abi_type) {
+        true => Ok(token),
+        false => Err(invalid(read_word(data, 0)?)),
+    }
+}
+
+/// Returns the 32-byte word at the specified position of the data.
+fn read_word(data: &[u8], position: usize) -> Result<&[u8], AbiError> {
+    data.get(position..position + 32)
+        .ok_or_else(|| AbiError::InvalidData(format!("word at {} out of bounds of {} bytes", position, data.len())))
+}
+
+/// Returns the offset or length at the specified position of the data,
+/// which is at most the length of the data.
+fn read_length(data: &[u8], position: usize) -> Result<usize, AbiError> {
+    match U256::from_big_endian(read_word(data, position)?) {
+        length if length <= U256::from(data.len()) => Ok(length.as_usize()),
+        length => Err(AbiError::InvalidData(format!(
+            "offset or length {} out of bounds",
+            length
+        ))),
+    }
+}
+
+/// Returns the number as a 32-byte big-endian word.
+fn to_word(number: U256) -> Vec<u8> {
+    let mut word = vec![0u8; 32];
+    number.to_big_endian(&mut word);
+    word
+}
+
+/// Returns the bytes padded with zeros to a multiple of 32 bytes.
+fn pad_right(bytes: &[u8]) -> Vec<u8> {
+    let mut padded = bytes.to_vec();
+    padded.resize(bytes.len().div_ceil(32) * 32, 0);
+    padded
+}
+
+/// Returns the two's complement of the number.
+fn negate(number: U256) -> U256 {
+    (!number).overflowing_add(U256::one()).0
+}
+
+/// Returns the bytes of a hex value, with an optional `0x` prefix.
+fn from_hex(value: &str) -> Option<Vec<u8>> {
+    hex::decode(value.strip_prefix("0x").unwrap_or(value)).ok()
+}
+
+/// Returns a number in hex (`0x3e8`), or in decimal with an optional fraction and exponent
+/// (`1000e18`, `1.5e18`), if it is an integer of at most 256 bits.
+fn parse_number(value: &str) -> Option<U256> {
+    if let Some(hex) = value.strip_prefix("0x") {
+        let hex = format!("{:0>width$}", hex, width = hex.len().div_ceil(2) * 2);
+        return match hex::decode(&hex) {
+            Ok(bytes) if !bytes.is_empty() && bytes.len() <= 32 => Some(U256::from_big_endian(&bytes)),
+            _ => None,
+        };
+    }
+    let (mantissa, exponent) = match value.find(&['e', 'E'][..]) {
+        Some(index) => (&value[..index], value[index + 1..].parse::<usize>().ok()?),
+        None => (value, 0),
+    };
+    let (integer, fraction) = match mantissa.find('.') {
+        Some(index) => (&mantissa[..index], mantissa[index + 1..].trim_end_matches('0')),
+        None => (mantissa, ""),
+    };
+    let digits = format!("{}{}", integer, fraction);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) || fraction.len() > exponent {
+        return None;
+    }
+    match exponent - fraction.len() {
+        exponent if exponent <= 77 => U256::from_dec_str(&digits).ok()?.checked_mul(U256::exp10(exponent)),
+        _ => None,
+    }
+}
+
+/// Returns the name, the inputs, and the optional outputs of a signature or call,
+/// as in `balanceOf(address)(uint256)`.
+fn split_signature(signature: &str) -> Result<(&str, &str, Option<&str>), AbiError> {
+    let signature = signature.trim();
+    let invalid = || AbiError::InvalidSignature(signature.into());
+    let start = signature.find('(').ok_or_else(invalid)?;
+    let end = start + closing_index(&signature[start..]).ok_or_else(invalid)?;
+    let name = signature[..start].trim();
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$') {
+        return Err(invalid());
+    }
+    let outputs = match signature[end + 1..].trim() {
+        "" => None,
+        outputs if outputs.starts_with('(') && closing_index(outputs) == Some(outputs.len() - 1) => {
+            Some(&outputs[1..outputs.len() - 1])
+        }
+        _ => return Err(invalid()),
+    };
+    Ok((name, &signature[start + 1..end], outputs))
+}
+
+/// Returns the index of the parenthesis closing the opening parenthesis at the start of the value.
+fn closing_index(value: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut quoted = false;
+    for (index, c) in value.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '(' | '[' if !quoted => depth += 1,
+            ')' | ']' if !quoted => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Returns the comma-separated elements of a list, excluding the commas
+/// within nested parentheses, brackets, and quotes.
+fn split(list: &str) -> Result<Vec<&str>, AbiError> {
+    if list.trim().is_empty() {
+        return Ok(vec![]);
+    }
+    let mut elements = vec![];
+    let mut depth = 0i32;
+    let mut quoted = false;
+    let mut start = 0;
+    for (index, c) in list.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '(' | '[' if !quoted => depth += 1,
+            ')' | ']' if !quoted => depth -= 1,
+            ',' if !quoted && depth == 0 => {
+                elements.push(list[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+        if depth < 0 {
+            return Err(AbiError::InvalidValue("list".into(), list.into()));
+        }
+    }
+    match depth == 0 && !quoted {
+        true => {
+            elements.push(list[start..].trim());
+            Ok(elements)
+        }
+        false => Err(AbiError::InvalidValue("list".into(), list.into())),
+    }
+}
+
+/// Returns the type and the value of an argument prefixed with its type, as in `uint8 5`.
+fn split_type(argument: &str) -> Option<(AbiType, &str)> {
+    let mut depth = 0;
+    for (index, c) in argument.char_indices() {
+        match c {
+            '"' => return None,
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            _ if c.is_whitespace() && depth == 0 => {
+                let abi_type = AbiType::from_str(&argument[..index]).ok()?;
+                return Some((abi_type, argument[index..].trim()));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Returns the comma-separated list of the specified values.
+fn join<T: fmt::Display>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_encode_input(expected_data: &str, signature: &str, arguments: &[&str]) {
+        let function = AbiFunction::from_str(signature).unwrap();
+        let tokens = function
+            .inputs
+            .iter()
+            .zip(arguments)
+            .map(|(abi_type, argument)| AbiToken::parse(abi_type, argument).unwrap())
+            .collect::<Vec<_>>();
+        let data = function.encode_input(&tokens).unwrap();
+        assert_eq!(expected_data, hex::encode(&data));
+        assert_eq!(tokens, function.decode_input(&data).unwrap());
+    }
+
+    #[test]
+    fn encode_input() {
+        // Examples of the Solidity contract ABI specification
+        test_encode_input(
+            "cdcd77c0\
+             0000000000000000000000000000000000000000000000000000000000000045\
+             0000000000000000000000000000000000000000000000000000000000000001",
+            "baz(uint32,bool)",
+            &["69", "true"],
+        );
+        test_encode_input(
+            "a5643bf2\
+             0000000000000000000000000000000000000000000000000000000000000060\
+             0000000000000000000000000000000000000000000000000000000000000001\
+             00000000000000000000000000000000000000000000000000000000000000a0\
+             0000000000000000000000000000000000000000000000000000000000000004\
+             6461766500000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000003\
+             0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             0000000000000000000000000000000000000000000000000000000000000003",
+            "sam(bytes,bool,uint256[])",
+            &["0x64617665", "true", "[1,2,3]"],
+        );
+        test_encode_input(
+            "8be65246\
+             0000000000000000000000000000000000000000000000000000000000000123\
+             0000000000000000000000000000000000000000000000000000000000000080\
+             3132333435363738393000000000000000000000000000000000000000000000\
+             00000000000000000000000000000000000000000000000000000000000000e0\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             0000000000000000000000000000000000000000000000000000000000000456\
+             0000000000000000000000000000000000000000000000000000000000000789\
+             000000000000000000000000000000000000000000000000000000000000000d\
+             48656c6c6f2c20776f726c642100000000000000000000000000000000000000",
+            "f(uint256,uint32[],bytes10,bytes)",
+            &[
+                "0x123",
+                "[0x456,0x789]",
+                "0x31323334353637383930",
+                "0x48656c6c6f2c20776f726c6421",
+            ],
+        );
+        test_encode_input(
+            "a9059cbb\
+             00000000000000000000000052c3a8a79a521d10b25569847cb1a3ffb66550d6\
+             00000000000000000000000000000000000000000000003635c9adc5dea00000",
+            "transfer(address,uint256)",
+            &["0x52C3a8a79a521D10b25569847CB1a3FfB66550D6", "1000e18"],
+        );
+        test_encode_input(
+            "b72ef458\
+             ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff80\
+             0000000000000000000000000000000000000000000000000000000000000040\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             0000000000000000000000000000000000000000000000000000000000000040\
+             00000000000000000000000000000000000000000000000000000000000000c0\
+             0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000040\
+             0000000000000000000000000000000000000000000000000000000000000002\
+             6869000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000040\
+             0000000000000000000000000000000000000000000000000000000000000000",
+            "g(int8,(bool,string)[])",
+            &["-128", "[(true,\"hi\"),(false,\"\")]"],
+        );
+    }
+
+    #[test]
+    fn from_call() {
+        let (function, arguments) =
+            AbiFunction::from_call("approve(0x52C3a8a79a521D10b25569847CB1a3FfB66550D6, 1.5e18)").unwrap();
+        assert_eq!("approve(address,uint256)", function.signature());
+        assert_eq!([0x09, 0x5e, 0xa7, 0xb3], function.selector());
+        assert_eq!(
+            "0x52C3a8a79a521D10b25569847CB1a3FfB66550D6,1500000000000000000",
+            join(&arguments)
+        );
+
+        let (function, arguments) =
+            AbiFunction::from_call("f(uint8 255, -1, [\"a,b\",\"c\"], (true, 0x00ff), uint256[] [], bytes2 0x00ff)")
+                .unwrap();
+        assert_eq!(
+            "f(uint8,int256,string[],(bool,bytes),uint256[],bytes2)",
+            function.signature()
+        );
+        assert_eq!("255,-1,[\"a,b\",\"c\"],(true,0x00ff),[],0x00ff", join(&arguments));
+
+        assert!(AbiFunction::from_call("f(uint8 256)").is_err());
+        assert!(AbiFunction::from_call("f(int8 -129)").is_err());
+        assert!(AbiFunction::from_call("f(1.5)").is_err());
+        assert!(AbiFunction::from_call("f([])").is_err());
+        assert!(AbiFunction::from_call("f(0xzz)").is_err());
+        assert!(AbiFunction::from_call("f(1").is_err());
+        assert!(AbiFunction::from_call("(1)").is_err());
+    }
+
+    #[test]
+    fn from_json() {
+        let function = AbiFunction::from_json(
+            r#"[
+                {"type":"event","name":"Transfer","inputs":[]},
+                {"type":"function","name":"swap","inputs":[
+                    {"name":"path","type":"tuple[]","components":[
+                        {"name":"token","type":"address"},
+                        {"name":"fees","type":"uint24[2]"}
+                    ]},
+                    {"name":"deadline","type":"uint256"}
+                ],"outputs":[{"name":"amounts","type":"uint256[]"}]}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!("swap((address,uint24[2])[],uint256)", function.signature());
+        assert_eq!(
+            AbiFunction::from_str("swap((address,uint24[2])[],uint256)(uint256[])").unwrap(),
+            function
+        );
+
+        assert!(AbiFunction::from_json(r#"{"type":"event","name":"Transfer"}"#).is_err());
+        assert!(AbiFunction::from_json(r#"{"name":"f","inputs":[{"type":"tuple"}]}"#).is_err());
+        assert!(AbiFunction::from_json(r#"{"name":"f","inputs":[{"type":"uint7"}]}"#).is_err());
+    }
+
+    #[test]
+    fn decode_output() {
+        let function = AbiFunction::from_str("f()(string,uint256[],bool)").unwrap();
+        let tokens = vec![
+            AbiToken::String("wagyu".into()),
+            AbiToken::Array(vec![AbiToken::Uint(U256::from(1)), AbiToken::Uint(U256::from(2))]),
+            AbiToken::Bool(true),
+        ];
+        let data = encode(&tokens).unwrap();
+        assert_eq!(tokens, function.decode_output(&data).unwrap());
+
+        // Truncated data, an out of bounds offset, and an invalid boolean
+        assert!(function.decode_output(&data[..data.len() - 1]).is_err());
+        let mut invalid = data.clone();
+        invalid[31] = 0xff;
+        assert!(function.decode_output(&invalid).is_err());
+        let mut invalid = data.clone();
+        invalid[95] = 2;
+        assert!(function.decode_output(&invalid).is_err());
+
+        // A length of an array beyond the data
+        let function = AbiFunction::from_str("f()(uint256[])").unwrap();
+        let mut data = to_word(U256::from(32));
+        data.extend(to_word(U256::from(64)));
+        data.extend(to_word(U256::zero()));
+        assert!(function.decode_output(&data).is_err());
+    }
+
+    #[test]
+    fn decode_input_invalid_selector() {
+        let function = AbiFunction::from_str("transfer(address,uint256)").unwrap();
+        assert!(function.decode_input(&[0x09, 0x5e, 0xa7, 0xb3]).is_err());
+        assert!(function.decode_input(&[0xa9]).is_err());
+    }
+
+    #[test]
+    fn parse_number() {
+        assert_eq!(Some(U256::from(1000)), super::parse_number("1000"));
+        assert_eq!(Some(U256::from(1000)), super::parse_number("0x3e8"));
+        assert_eq!(Some(U256::from(1500)), super::parse_number("1.5e3"));
+        assert_eq!(Some(U256::from(1500)), super::parse_number("1.500e3"));
+        assert_eq!(Some(U256::exp10(77)), super::parse_number("1e77"));
+        assert_eq!(None, super::parse_number("1.5"));
+        assert_eq!(None, super::parse_number("1e78"));
+        assert_eq!(None, super::parse_number("e3"));
+        assert_eq!(None, super::parse_number("1,000"));
+    }
+}
@@ -4,6 +4,7 @@ use crate::public_key::EthereumPublicKey;
 use wagyu_model::{to_hex_string, Address, AddressError, PrivateKey};
 
 use core::{convert::TryFrom, fmt, str::FromStr};
+use ethereum_types::U256;
 use regex::Regex;
 use serde::Serialize;
 use tiny_keccak::keccak256;
@@ -47,6 +48,72 @@ impl EthereumAddress {
 
         EthereumAddress(checksum_address)
     }
+
+    /// Returns the address given its ICAP encoding (Inter exchange Client Address Protocol),
+    /// an IBAN with the country code `XE` and the address in base 36, as a direct ICAP of 34 characters
+    /// or a basic ICAP of 35 characters. An indirect ICAP refers to an institution, not an address.
+    pub fn from_icap(icap: &str) -> Result<Self, AddressError> {
+        let icap = icap.replace(' ', "").to_uppercase();
+        if !icap.starts_with("XE") || !icap.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(AddressError::InvalidAddress(icap));
+        }
+        if icap.len() != 34 && icap.len() != 35 {
+            return Err(AddressError::InvalidCharacterLength(icap.len()));
+        }
+
+        let (checksum, bban) = (&icap[2..4], &icap[4..]);
+        let expected_checksum = icap_checksum(bban);
+        if checksum != expected_checksum {
+            return Err(AddressError::InvalidChecksum(expected_checksum, checksum.into()));
+        }
+
+        let mut address = U256::zero();
+        for c in bban.chars() {
+            let digit = U256::from(c.to_digit(36).unwrap_or_default());
+            address = match address
+                .checked_mul(U256::from(36))
+                .and_then(|address| address.checked_add(digit))
+            {
+                Some(address) if address.bits() <= 160 => address,
+                _ => return Err(AddressError::InvalidAddress(icap)),
+            };
+        }
+        let mut bytes = [0u8; 32];
+        address.to_big_endian(&mut bytes);
+        Self::from_str(&hex::encode(&bytes[12..]))
+    }
+
+    /// Returns the ICAP encoding of the address, as a direct ICAP if the address fits
+    /// in its 30 base 36 characters (less than 2^155), or as a basic ICAP otherwise.
+    pub fn to_icap(&self) -> String {
+        let mut address = U256::from_big_endian(&hex::decode(&self.0[2..]).unwrap_or_default());
+        let length = match address < U256::from(36).pow(U256::from(30)) {
+            true => 30,
+            false => 31,
+        };
+        let mut bban = vec!['0'; length];
+        for c in bban.iter_mut().rev() {
+            *c = core::char::from_digit((address % 36).as_u32(), 36)
+                .unwrap_or_default()
+                .to_ascii_uppercase();
+            address /= 36;
+        }
+        let bban = bban.into_iter().collect::<String>();
+        format!("XE{}{}", icap_checksum(&bban), bban)
+    }
+}
+
+/// Returns the two check digits of an ICAP with the specified BBAN (the address in base 36),
+/// computed as in an IBAN from the remainder of `BBAN || XE00`, as a number, modulo 97.
+fn icap_checksum(bban: &str) -> String {
+    let remainder = format!("{}XE00", bban)
+        .chars()
+        .fold(0, |remainder, c| match c.to_digit(36) {
+            Some(digit) if digit >= 10 => (remainder * 100 + digit) % 97,
+            Some(digit) => (remainder * 10 + digit) % 97,
+            None => remainder,
+        });
+    format!("{:02}", 98 - remainder)
 }
 
 impl<'a> TryFrom<&'a str> for EthereumAddress {
@@ -69,6 +136,10 @@ impl FromStr for EthereumAddress {
             return Err(AddressError::InvalidCharacterLength(address.len()));
         }
 
+        if !address.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(AddressError::InvalidAddress(address));
+        }
+
         let hash = to_hex_string(&keccak256(address.as_bytes()));
         let mut checksum_address = "0x".to_string();
         for c in 0..40 {
@@ -172,6 +243,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn icap() {
+        let icaps = [
+            (
+                "0x00c5496aEe77C1bA1f0854206A26DdA82a81D6D8",
+                "XE7338O073KYGTWWZN0F2WZ0R8PX5ZPPZS",
+            ),
+            (
+                "0x9141B7539E7902872095C408BfA294435e2b8c8a",
+                "XE20GYU0HZYGUIOXR024BOBZ4VLY6XFGZUY",
+            ),
+            (
+                "0x0000000000000000000000000000000000000000",
+                "XE50000000000000000000000000000000",
+            ),
+        ];
+        icaps.iter().for_each(|(address, icap)| {
+            assert_eq!(*icap, EthereumAddress::from_str(address).unwrap().to_icap());
+            assert_eq!(*address, EthereumAddress::from_icap(icap).unwrap().to_string());
+        });
+
+        assert!(EthereumAddress::from_icap("xe73 38o0 73ky gtww zn0f 2wz0 r8px 5zpp zs").is_ok());
+        assert!(EthereumAddress::from_icap("XE7438O073KYGTWWZN0F2WZ0R8PX5ZPPZS").is_err());
+        assert!(EthereumAddress::from_icap("XE7338O073KYGTWWZN0F2WZ0R8PX5ZPPZ").is_err());
+        assert!(EthereumAddress::from_icap("XE81ETHXREGGAVOFYORK").is_err());
+        assert!(EthereumAddress::from_icap("XE20ZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZZ").is_err());
+    }
+
     #[test]
     fn test_checksum_address_invalid() {
         // Mismatched keypair
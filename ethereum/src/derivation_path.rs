@@ -1,5 +1,5 @@
 use crate::network::EthereumNetwork;
-use wagyu_model::derivation_path::{ChildIndex, DerivationPath, DerivationPathError};
+use wagyu_model::derivation_path::{BIP32Path, ChildIndex, DerivationPath, DerivationPathError};
 
 use core::convert::TryFrom;
 use core::{fmt, marker::PhantomData, str::FromStr};
@@ -126,14 +126,7 @@ impl<N: EthereumNetwork> FromStr for EthereumDerivationPath<N> {
     type Err = DerivationPathError;
 
     fn from_str(path: &str) -> Result<Self, Self::Err> {
-        let mut parts = path.split("/");
-
-        if parts.next().unwrap() != "m" {
-            return Err(DerivationPathError::InvalidDerivationPath(path.to_string()));
-        }
-
-        let path: Result<Vec<ChildIndex>, Self::Err> = parts.map(str::parse).collect();
-        Self::from_vec(&path?)
+        Self::from_vec(&BIP32Path::from_str(path)?.to_vec()?)
     }
 }
 
@@ -161,15 +154,8 @@ impl<N: EthereumNetwork> fmt::Debug for EthereumDerivationPath<N> {
 
 impl<N: EthereumNetwork> fmt::Display for EthereumDerivationPath<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self.to_vec() {
-            Ok(path) => {
-                f.write_str("m")?;
-                for index in path.iter() {
-                    f.write_str("/")?;
-                    fmt::Display::fmt(index, f)?;
-                }
-                Ok(())
-            }
+        match self.to_vec().and_then(|path| BIP32Path::from_vec(&path)) {
+            Ok(path) => fmt::Display::fmt(&path, f),
             Err(_) => Err(fmt::Error),
         }
     }
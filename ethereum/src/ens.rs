@@ -0,0 +1,87 @@
+//!
+//! ENS
+//!
+//! This module contains the name hash of Ethereum Name Service (ENS) names, as specified in
+//! EIP-137 (https://eips.ethereum.org/EIPS/eip-137), which identifies a name in the ENS registry.
+//!
+//! A name is resolved to an address by querying the registry for the resolver of its name hash,
+//! and then the resolver for its address. The reverse record of an address, `<address>.addr.reverse`,
+//! resolves to its primary name, to verify that the owner of the address claims the name.
+//!
+
+use crate::address::EthereumAddress;
+use wagyu_model::AddressError;
+
+use tiny_keccak::keccak256;
+
+/// The address of the ENS registry, deployed at the same address on mainnet and the test networks
+pub const ENS_REGISTRY: &str = "0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e";
+
+/// Returns `true` if the specified value is an ENS name rather than an address, as in `vitalik.eth`.
+pub fn is_ens_name(value: &str) -> bool {
+    value.contains('.')
+}
+
+/// Returns the normalized ENS name, in lowercase, or an error if it has an empty label.
+/// Names are normalized by case folding only, which is the complete normalization of ASCII names.
+pub fn normalize(name: &str) -> Result<String, AddressError> {
+    let name = name.trim().to_lowercase();
+    match name.split('.').any(|label| label.is_empty()) {
+        true => Err(AddressError::InvalidAddress(name)),
+        false => Ok(name),
+    }
+}
+
+/// Returns the name hash of the specified normalized ENS name, which is computed recursively
+/// from the root as `namehash(label.name) = keccak256(namehash(name) || keccak256(label))`.
+pub fn namehash(name: &str) -> [u8; 32] {
+    match name.is_empty() {
+        true => [0u8; 32],
+        false => name.rsplit('.').fold([0u8; 32], |node, label| {
+            keccak256(&[&node[..], &keccak256(label.as_bytes())[..]].concat())
+        }),
+    }
+}
+
+/// Returns the name of the reverse record of the specified address, as in `<address>.addr.reverse`.
+pub fn reverse_name(address: &EthereumAddress) -> String {
+    format!("{}.addr.reverse", address.to_string()[2..].to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::str::FromStr;
+
+    #[test]
+    fn namehash() {
+        // Examples of EIP-137
+        assert_eq!([0u8; 32], super::namehash(""));
+        assert_eq!(
+            "93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae",
+            hex::encode(super::namehash("eth"))
+        );
+        assert_eq!(
+            "de9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f",
+            hex::encode(super::namehash("foo.eth"))
+        );
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!("vitalik.eth", super::normalize("Vitalik.ETH").unwrap());
+        assert!(super::normalize("vitalik..eth").is_err());
+        assert!(super::normalize(".eth").is_err());
+    }
+
+    #[test]
+    fn reverse_name() {
+        let address = EthereumAddress::from_str("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045").unwrap();
+        assert_eq!(
+            "d8da6bf26964af9d7eed9e03e53415d37aa96045.addr.reverse",
+            super::reverse_name(&address)
+        );
+        assert!(is_ens_name("vitalik.eth"));
+        assert!(!is_ens_name("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"));
+    }
+}
@@ -6,16 +6,16 @@ use crate::network::EthereumNetwork;
 use crate::private_key::EthereumPrivateKey;
 use crate::public_key::EthereumPublicKey;
 use wagyu_model::{
-    crypto::{checksum, hash160},
-    AddressError, ChildIndex, DerivationPath, ExtendedPrivateKey, ExtendedPrivateKeyError, ExtendedPublicKey,
-    PrivateKey,
+    crypto::hash160, encoding::base58check, AddressError, ChildIndex, DerivationPath, ExtendedPrivateKey,
+    ExtendedPrivateKeyError, ExtendedPublicKey, PrivateKey, WalletGenerator,
 };
 
-use base58::{FromBase58, ToBase58};
+use base58::FromBase58;
 use core::{convert::TryFrom, fmt, fmt::Display, marker::PhantomData, str::FromStr};
 use hmac::{Hmac, Mac};
 use secp256k1::{PublicKey, SecretKey};
 use sha2::Sha512;
+use zeroize::Zeroize;
 
 type HmacSha512 = Hmac<Sha512>;
 
@@ -36,6 +36,9 @@ pub struct EthereumExtendedPrivateKey<N> {
     _network: PhantomData<N>,
 }
 
+/// Represents a lazy iterator over the Ethereum wallets of a derivation path template, e.g. `m/44'/60'/0'/0/*`
+pub type EthereumWalletIter<N> = WalletGenerator<EthereumExtendedPrivateKey<N>>;
+
 impl<N: EthereumNetwork> ExtendedPrivateKey for EthereumExtendedPrivateKey<N> {
     type Address = EthereumAddress;
     type DerivationPath = EthereumDerivationPath<N>;
@@ -71,51 +74,10 @@ impl<N: EthereumNetwork> ExtendedPrivateKey for EthereumExtendedPrivateKey<N> {
 
     /// Returns the extended private key of the given derivation path.
     fn derive(&self, path: &Self::DerivationPath) -> Result<Self, ExtendedPrivateKeyError> {
-        if self.depth == 255 {
-            return Err(ExtendedPrivateKeyError::MaximumChildDepthReached(self.depth));
-        }
-
         let mut extended_private_key = self.clone();
-
         for index in path.to_vec()?.into_iter() {
-            let public_key = &PublicKey::from_secret_key(&extended_private_key.private_key.to_secp256k1_secret_key())
-                .serialize_compressed();
-
-            let mut mac = HmacSha512::new_varkey(&extended_private_key.chain_code)?;
-            match index {
-                // HMAC-SHA512(Key = cpar, Data = serP(point(kpar)) || ser32(i)).
-                ChildIndex::Normal(_) => mac.input(public_key),
-                // HMAC-SHA512(Key = cpar, Data = 0x00 || ser256(kpar) || ser32(i))
-                // (Note: The 0x00 pads the private key to make it 33 bytes long.)
-                ChildIndex::Hardened(_) => {
-                    mac.input(&[0u8]);
-                    mac.input(&extended_private_key.private_key.to_secp256k1_secret_key().serialize());
-                }
-            }
-            // Append the child index in big-endian format
-            mac.input(&u32::from(index).to_be_bytes());
-            let hmac = mac.result().code();
-
-            let mut secret_key = SecretKey::parse_slice(&hmac[0..32])?;
-            secret_key.tweak_add_assign(&extended_private_key.private_key.to_secp256k1_secret_key())?;
-            let private_key = Self::PrivateKey::from_secp256k1_secret_key(&secret_key);
-
-            let mut chain_code = [0u8; 32];
-            chain_code[0..32].copy_from_slice(&hmac[32..]);
-
-            let mut parent_fingerprint = [0u8; 4];
-            parent_fingerprint.copy_from_slice(&hash160(public_key)[0..4]);
-
-            extended_private_key = Self {
-                depth: extended_private_key.depth + 1,
-                parent_fingerprint,
-                child_index: index,
-                chain_code,
-                private_key,
-                _network: PhantomData,
-            }
+            extended_private_key = extended_private_key.derive_child(index)?;
         }
-
         Ok(extended_private_key)
     }
 
@@ -140,6 +102,53 @@ impl<N: EthereumNetwork> ExtendedPrivateKey for EthereumExtendedPrivateKey<N> {
     }
 }
 
+impl<N: EthereumNetwork> EthereumExtendedPrivateKey<N> {
+    /// Returns the extended private key of the given child index.
+    /// Deriving the children of a cached parent, e.g. the chain of an account, skips re-deriving its path.
+    pub fn derive_child(&self, index: ChildIndex) -> Result<Self, ExtendedPrivateKeyError> {
+        if self.depth == 255 {
+            return Err(ExtendedPrivateKeyError::MaximumChildDepthReached(self.depth));
+        }
+
+        let public_key =
+            &PublicKey::from_secret_key(&self.private_key.to_secp256k1_secret_key()).serialize_compressed();
+
+        let mut mac = HmacSha512::new_varkey(&self.chain_code)?;
+        match index {
+            // HMAC-SHA512(Key = cpar, Data = serP(point(kpar)) || ser32(i)).
+            ChildIndex::Normal(_) => mac.input(public_key),
+            // HMAC-SHA512(Key = cpar, Data = 0x00 || ser256(kpar) || ser32(i))
+            // (Note: The 0x00 pads the private key to make it 33 bytes long.)
+            ChildIndex::Hardened(_) => {
+                mac.input(&[0u8]);
+                mac.input(&self.private_key.to_secp256k1_secret_key().serialize());
+            }
+        }
+        // Append the child index in big-endian format
+        mac.input(&u32::from(index).to_be_bytes());
+        let hmac = mac.result().code();
+
+        let mut secret_key = SecretKey::parse_slice(&hmac[0..32])?;
+        secret_key.tweak_add_assign(&self.private_key.to_secp256k1_secret_key())?;
+        let private_key = EthereumPrivateKey::from_secp256k1_secret_key(&secret_key);
+
+        let mut chain_code = [0u8; 32];
+        chain_code[0..32].copy_from_slice(&hmac[32..]);
+
+        let mut parent_fingerprint = [0u8; 4];
+        parent_fingerprint.copy_from_slice(&hash160(public_key)[0..4]);
+
+        Ok(Self {
+            depth: self.depth + 1,
+            parent_fingerprint,
+            child_index: index,
+            chain_code,
+            private_key,
+            _network: PhantomData,
+        })
+    }
+}
+
 impl<N: EthereumNetwork> FromStr for EthereumExtendedPrivateKey<N> {
     type Err = ExtendedPrivateKeyError;
 
@@ -166,13 +175,7 @@ impl<N: EthereumNetwork> FromStr for EthereumExtendedPrivateKey<N> {
 
         let private_key = EthereumPrivateKey::from_secp256k1_secret_key(&SecretKey::parse_slice(&data[46..78])?);
 
-        let expected = &data[78..82];
-        let checksum = &checksum(&data[0..78])[0..4];
-        if *expected != *checksum {
-            let expected = expected.to_base58();
-            let found = checksum.to_base58();
-            return Err(ExtendedPrivateKeyError::InvalidChecksum(expected, found));
-        }
+        base58check::verify(&data)?;
 
         Ok(Self {
             depth,
@@ -189,7 +192,7 @@ impl<N: EthereumNetwork> Display for EthereumExtendedPrivateKey<N> {
     /// BIP32 serialization format:
     /// https://github.com/ethereum/bips/blob/master/bip-0032.mediawiki#serialization-format
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let mut result = [0u8; 82];
+        let mut result = [0u8; 78];
         result[0..4].copy_from_slice(&[0x04, 0x88, 0xAD, 0xE4][..]);
         result[4] = self.depth as u8;
         result[5..9].copy_from_slice(&self.parent_fingerprint[..]);
@@ -198,10 +201,21 @@ impl<N: EthereumNetwork> Display for EthereumExtendedPrivateKey<N> {
         result[45] = 0;
         result[46..78].copy_from_slice(&self.private_key.to_secp256k1_secret_key().serialize());
 
-        let checksum = &checksum(&result[0..78])[0..4];
-        result[78..82].copy_from_slice(&checksum);
+        fmt.write_str(&base58check::encode(&result))
+    }
+}
 
-        fmt.write_str(&result.to_base58())
+impl<N> Zeroize for EthereumExtendedPrivateKey<N> {
+    /// Overwrites the chain code and the private key.
+    fn zeroize(&mut self) {
+        self.chain_code.zeroize();
+        self.private_key.zeroize();
+    }
+}
+
+impl<N> Drop for EthereumExtendedPrivateKey<N> {
+    fn drop(&mut self) {
+        self.zeroize();
     }
 }
 
@@ -590,6 +604,31 @@ mod tests {
                 extended_private_key.to_string()
             );
         }
+
+        #[test]
+        fn test_wallet_iter() {
+            use crate::{format::EthereumFormat, mnemonic::EthereumMnemonic, wordlist::English};
+            use wagyu_model::Mnemonic;
+
+            type N = Mainnet;
+
+            let phrase =
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+            let mnemonic = EthereumMnemonic::<N, English>::from_phrase(phrase).unwrap();
+            let mut wallets =
+                EthereumWalletIter::hd(&mnemonic, None, "m/44'/60'/0'/0/*", &EthereumFormat::Standard).unwrap();
+
+            let (path, private_key, address) = wallets.next().unwrap().unwrap();
+            assert_eq!("m/44'/60'/0'/0/0", path.to_string());
+            assert_eq!("0x9858EfFD232B4033E47d90003D41EC34EcaEda94", address.to_string());
+            assert_eq!(address, private_key.to_address(&EthereumFormat::Standard).unwrap());
+
+            let (path, _, address) = wallets.nth(1).unwrap().unwrap();
+            assert_eq!("m/44'/60'/0'/0/2", path.to_string());
+            assert_eq!("0xb6716976A3ebe8D39aCEB04372f22Ff8e6802D7A", address.to_string());
+
+            assert!(EthereumWalletIter::hd(&mnemonic, None, "m/44'/60'/0'/0/0", &EthereumFormat::Standard).is_err());
+        }
     }
 
     mod test_invalid {
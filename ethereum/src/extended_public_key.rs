@@ -5,11 +5,11 @@ use crate::format::EthereumFormat;
 use crate::network::EthereumNetwork;
 use crate::public_key::EthereumPublicKey;
 use wagyu_model::{
-    crypto::{checksum, hash160},
-    AddressError, ChildIndex, DerivationPath, ExtendedPrivateKey, ExtendedPublicKey, ExtendedPublicKeyError, PublicKey,
+    crypto::hash160, encoding::base58check, AddressError, ChildIndex, DerivationPath, ExtendedPrivateKey,
+    ExtendedPublicKey, ExtendedPublicKeyError, NonHardenedDerivationPath, PublicKey,
 };
 
-use base58::{FromBase58, ToBase58};
+use base58::FromBase58;
 use core::{convert::TryFrom, fmt, marker::PhantomData, str::FromStr};
 use hex;
 use hmac::{Hmac, Mac};
@@ -56,24 +56,25 @@ impl<N: EthereumNetwork> ExtendedPublicKey for EthereumExtendedPublicKey<N> {
 
     /// Returns the extended public key for the given derivation path.
     fn derive(&self, path: &Self::DerivationPath) -> Result<Self, ExtendedPublicKeyError> {
-        if self.depth == 255 {
-            return Err(ExtendedPublicKeyError::MaximumChildDepthReached(self.depth));
-        }
+        self.derive_public(&path.to_non_hardened()?)
+    }
 
+    /// Returns the extended public key for the given non-hardened derivation path.
+    fn derive_public(&self, path: &NonHardenedDerivationPath) -> Result<Self, ExtendedPublicKeyError> {
         let mut extended_public_key = self.clone();
 
-        for index in path.to_vec()?.into_iter() {
-            let public_key_serialized = &self.public_key.to_secp256k1_public_key().serialize()[..];
-
-            let mut mac = HmacSha512::new_varkey(&self.chain_code)?;
-            match index {
-                // HMAC-SHA512(Key = cpar, Data = serP(Kpar) || ser32(i))
-                ChildIndex::Normal(_) => mac.input(public_key_serialized),
-                // Return failure
-                ChildIndex::Hardened(_) => {
-                    return Err(ExtendedPublicKeyError::InvalidChildNumber(1 << 31, u32::from(index)))
-                }
+        for index in path.to_vec().into_iter() {
+            if extended_public_key.depth == 255 {
+                return Err(ExtendedPublicKeyError::MaximumChildDepthReached(
+                    extended_public_key.depth,
+                ));
             }
+
+            let public_key_serialized = &extended_public_key.public_key.to_secp256k1_public_key().serialize()[..];
+
+            // HMAC-SHA512(Key = cpar, Data = serP(Kpar) || ser32(i))
+            let mut mac = HmacSha512::new_varkey(&extended_public_key.chain_code)?;
+            mac.input(public_key_serialized);
             // Append the child index in big-endian format
             mac.input(&u32::from(index).to_be_bytes());
             let hmac = mac.result().code();
@@ -81,7 +82,7 @@ impl<N: EthereumNetwork> ExtendedPublicKey for EthereumExtendedPublicKey<N> {
             let mut chain_code = [0u8; 32];
             chain_code[0..32].copy_from_slice(&hmac[32..]);
 
-            let mut public_key = self.public_key.to_secp256k1_public_key();
+            let mut public_key = extended_public_key.public_key.to_secp256k1_public_key();
             public_key.tweak_add_assign(&SecretKey::parse_slice(&hmac[..32])?)?;
             let public_key = Self::PublicKey::from_secp256k1_public_key(public_key);
 
@@ -139,13 +140,7 @@ impl<N: EthereumNetwork> FromStr for EthereumExtendedPublicKey<N> {
             &Secp256k1_PublicKey::parse_slice(&data[45..78], None)?.serialize()[1..],
         ))?;
 
-        let expected = &data[78..82];
-        let checksum = &checksum(&data[0..78])[0..4];
-        if *expected != *checksum {
-            let expected = expected.to_base58();
-            let found = checksum.to_base58();
-            return Err(ExtendedPublicKeyError::InvalidChecksum(expected, found));
-        }
+        base58check::verify(&data)?;
 
         Ok(Self {
             depth,
@@ -162,7 +157,7 @@ impl<N: EthereumNetwork> fmt::Display for EthereumExtendedPublicKey<N> {
     /// BIP32 serialization format
     /// https://github.com/ethereum/bips/blob/master/bip-0032.mediawiki#serialization-format
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let mut result = [0u8; 82];
+        let mut result = [0u8; 78];
         result[0..4].copy_from_slice(&[0x04u8, 0x88, 0xB2, 0x1E][..]);
         result[4] = self.depth as u8;
         result[5..9].copy_from_slice(&self.parent_fingerprint[..]);
@@ -170,10 +165,7 @@ impl<N: EthereumNetwork> fmt::Display for EthereumExtendedPublicKey<N> {
         result[13..45].copy_from_slice(&self.chain_code[..]);
         result[45..78].copy_from_slice(&self.public_key.to_secp256k1_public_key().serialize_compressed());
 
-        let sum = &checksum(&result[0..78])[0..4];
-        result[78..82].copy_from_slice(sum);
-
-        fmt.write_str(&result.to_base58())
+        fmt.write_str(&base58check::encode(&result))
     }
 }
 
@@ -4,7 +4,17 @@
 //!
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(unused_extern_crates, dead_code)]
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "wasm"), forbid(unsafe_code))]
+
+// PBKDF2 over rayon spawns threads, which are unavailable to wasm32-unknown-unknown
+#[cfg(all(target_arch = "wasm32", feature = "parallel"))]
+compile_error!("the `parallel` feature is unsupported on wasm32, build with `--no-default-features --features wasm`");
+
+#[macro_use]
+extern crate failure;
+
+pub mod abi;
+pub use self::abi::{AbiError, AbiFunction, AbiToken, AbiType};
 
 pub mod address;
 pub use self::address::*;
@@ -12,6 +22,9 @@ pub use self::address::*;
 pub mod amount;
 pub use self::amount::*;
 
+pub mod ens;
+pub use self::ens::{is_ens_name, namehash, ENS_REGISTRY};
+
 pub mod derivation_path;
 pub use self::derivation_path::*;
 
@@ -39,5 +52,11 @@ pub use self::public_key::*;
 pub mod transaction;
 pub use self::transaction::*;
 
+pub mod uri;
+pub use self::uri::*;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 pub mod wordlist;
 pub use self::wordlist::*;
@@ -14,6 +14,7 @@ use hmac::Hmac;
 use pbkdf2::pbkdf2;
 use rand::Rng;
 use sha2::{Digest, Sha256, Sha512};
+use zeroize::Zeroize;
 
 const PBKDF2_ROUNDS: usize = 2048;
 const PBKDF2_BYTES: usize = 64;
@@ -167,10 +168,10 @@ impl<N: EthereumNetwork, W: EthereumWordlist> MnemonicExtended for EthereumMnemo
 
     /// Returns the extended private key of the corresponding mnemonic.
     fn to_extended_private_key(&self, password: Option<&str>) -> Result<Self::ExtendedPrivateKey, MnemonicError> {
-        Ok(Self::ExtendedPrivateKey::new_master(
-            self.to_seed(password)?.as_slice(),
-            &EthereumFormat::Standard,
-        )?)
+        let mut seed = self.to_seed(password)?;
+        let extended_private_key = Self::ExtendedPrivateKey::new_master(seed.as_slice(), &EthereumFormat::Standard);
+        seed.zeroize();
+        Ok(extended_private_key?)
     }
 
     /// Returns the extended public key of the corresponding mnemonic.
@@ -188,8 +189,11 @@ impl<N: EthereumNetwork, W: EthereumWordlist> EthereumMnemonic<N, W> {
     /// Returns a seed using the given password and mnemonic.
     fn to_seed(&self, password: Option<&str>) -> Result<Vec<u8>, MnemonicError> {
         let mut seed = vec![0u8; PBKDF2_BYTES];
-        let salt = format!("mnemonic{}", password.unwrap_or(""));
-        pbkdf2::<Hmac<Sha512>>(&self.to_phrase()?.as_bytes(), salt.as_bytes(), PBKDF2_ROUNDS, &mut seed);
+        let mut salt = format!("mnemonic{}", password.unwrap_or(""));
+        let mut phrase = self.to_phrase()?;
+        pbkdf2::<Hmac<Sha512>>(phrase.as_bytes(), salt.as_bytes(), PBKDF2_ROUNDS, &mut seed);
+        salt.zeroize();
+        phrase.zeroize();
         Ok(seed)
     }
 }
@@ -215,6 +219,19 @@ impl<N: EthereumNetwork, W: EthereumWordlist> fmt::Display for EthereumMnemonic<
     }
 }
 
+impl<N: EthereumNetwork, W: EthereumWordlist> Zeroize for EthereumMnemonic<N, W> {
+    /// Overwrites the mnemonic entropy with zeros.
+    fn zeroize(&mut self) {
+        self.entropy.zeroize();
+    }
+}
+
+impl<N: EthereumNetwork, W: EthereumWordlist> Drop for EthereumMnemonic<N, W> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,220 @@
+use crate::network::{EthereumNetwork, Goerli, Kovan, Mainnet, Rinkeby, Ropsten};
+use wagyu_model::{Network, NetworkError};
+
+use core::{convert::TryFrom, fmt, str::FromStr};
+
+/// Represents an Ethereum network selected at runtime.
+///
+/// Each variant corresponds to a type-level network, to and from which it converts, and `apply`
+/// runs a function that is generic over the Ethereum networks on the type-level network it selects.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AnyNetwork {
+    Goerli,
+    Kovan,
+    Mainnet,
+    Rinkeby,
+    Ropsten,
+}
+
+/// The interface for a function that is generic over the Ethereum networks,
+/// which is applied to a network selected at runtime with `AnyNetwork::apply`.
+pub trait EthereumNetworkFn {
+    type Output;
+
+    /// Returns the output of the function on the network `N`.
+    fn call<N: EthereumNetwork>(self) -> Self::Output;
+}
+
+impl AnyNetwork {
+    /// The Ethereum networks
+    pub const ALL: [AnyNetwork; 5] = [
+        AnyNetwork::Goerli,
+        AnyNetwork::Kovan,
+        AnyNetwork::Mainnet,
+        AnyNetwork::Rinkeby,
+        AnyNetwork::Ropsten,
+    ];
+
+    /// Returns the runtime network of the type-level network `N`.
+    pub fn of<N: EthereumNetwork>() -> Result<Self, NetworkError> {
+        Self::from_str(N::NAME)
+    }
+
+    /// Returns the name of the network.
+    pub fn name(&self) -> &'static str {
+        match self {
+            AnyNetwork::Goerli => Goerli::NAME,
+            AnyNetwork::Kovan => Kovan::NAME,
+            AnyNetwork::Mainnet => Mainnet::NAME,
+            AnyNetwork::Rinkeby => Rinkeby::NAME,
+            AnyNetwork::Ropsten => Ropsten::NAME,
+        }
+    }
+
+    /// Returns the network of the given EIP-155 chain id.
+    pub fn from_chain_id(chain_id: u32) -> Result<Self, NetworkError> {
+        Self::ALL
+            .iter()
+            .find(|network| network.chain_id() == chain_id)
+            .copied()
+            .ok_or_else(|| NetworkError::InvalidNetwork(format!("chain id {}", chain_id)))
+    }
+
+    /// Returns the EIP-155 chain id of the network.
+    pub fn chain_id(&self) -> u32 {
+        match self {
+            AnyNetwork::Goerli => Goerli::CHAIN_ID,
+            AnyNetwork::Kovan => Kovan::CHAIN_ID,
+            AnyNetwork::Mainnet => Mainnet::CHAIN_ID,
+            AnyNetwork::Rinkeby => Rinkeby::CHAIN_ID,
+            AnyNetwork::Ropsten => Ropsten::CHAIN_ID,
+        }
+    }
+
+    /// Returns the output of the function on the type-level network of the network.
+    pub fn apply<F: EthereumNetworkFn>(&self, function: F) -> F::Output {
+        match self {
+            AnyNetwork::Goerli => function.call::<Goerli>(),
+            AnyNetwork::Kovan => function.call::<Kovan>(),
+            AnyNetwork::Mainnet => function.call::<Mainnet>(),
+            AnyNetwork::Rinkeby => function.call::<Rinkeby>(),
+            AnyNetwork::Ropsten => function.call::<Ropsten>(),
+        }
+    }
+}
+
+impl From<Goerli> for AnyNetwork {
+    fn from(_: Goerli) -> Self {
+        AnyNetwork::Goerli
+    }
+}
+
+impl TryFrom<AnyNetwork> for Goerli {
+    type Error = NetworkError;
+
+    fn try_from(network: AnyNetwork) -> Result<Self, Self::Error> {
+        match network {
+            AnyNetwork::Goerli => Ok(Goerli),
+            _ => Err(NetworkError::InvalidNetwork(network.name().into())),
+        }
+    }
+}
+
+impl From<Kovan> for AnyNetwork {
+    fn from(_: Kovan) -> Self {
+        AnyNetwork::Kovan
+    }
+}
+
+impl TryFrom<AnyNetwork> for Kovan {
+    type Error = NetworkError;
+
+    fn try_from(network: AnyNetwork) -> Result<Self, Self::Error> {
+        match network {
+            AnyNetwork::Kovan => Ok(Kovan),
+            _ => Err(NetworkError::InvalidNetwork(network.name().into())),
+        }
+    }
+}
+
+impl From<Mainnet> for AnyNetwork {
+    fn from(_: Mainnet) -> Self {
+        AnyNetwork::Mainnet
+    }
+}
+
+impl TryFrom<AnyNetwork> for Mainnet {
+    type Error = NetworkError;
+
+    fn try_from(network: AnyNetwork) -> Result<Self, Self::Error> {
+        match network {
+            AnyNetwork::Mainnet => Ok(Mainnet),
+            _ => Err(NetworkError::InvalidNetwork(network.name().into())),
+        }
+    }
+}
+
+impl From<Rinkeby> for AnyNetwork {
+    fn from(_: Rinkeby) -> Self {
+        AnyNetwork::Rinkeby
+    }
+}
+
+impl TryFrom<AnyNetwork> for Rinkeby {
+    type Error = NetworkError;
+
+    fn try_from(network: AnyNetwork) -> Result<Self, Self::Error> {
+        match network {
+            AnyNetwork::Rinkeby => Ok(Rinkeby),
+            _ => Err(NetworkError::InvalidNetwork(network.name().into())),
+        }
+    }
+}
+
+impl From<Ropsten> for AnyNetwork {
+    fn from(_: Ropsten) -> Self {
+        AnyNetwork::Ropsten
+    }
+}
+
+impl TryFrom<AnyNetwork> for Ropsten {
+    type Error = NetworkError;
+
+    fn try_from(network: AnyNetwork) -> Result<Self, Self::Error> {
+        match network {
+            AnyNetwork::Ropsten => Ok(Ropsten),
+            _ => Err(NetworkError::InvalidNetwork(network.name().into())),
+        }
+    }
+}
+
+impl FromStr for AnyNetwork {
+    type Err = NetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|network| network.name() == s)
+            .copied()
+            .ok_or_else(|| NetworkError::InvalidNetwork(s.into()))
+    }
+}
+
+impl fmt::Display for AnyNetwork {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct ChainId;
+
+    impl EthereumNetworkFn for ChainId {
+        type Output = u32;
+
+        fn call<N: EthereumNetwork>(self) -> u32 {
+            N::CHAIN_ID
+        }
+    }
+
+    #[test]
+    fn apply() {
+        for network in AnyNetwork::ALL.iter() {
+            assert_eq!(network.chain_id(), network.apply(ChainId));
+            assert_eq!(*network, AnyNetwork::from_chain_id(network.chain_id()).unwrap());
+            assert_eq!(*network, AnyNetwork::from_str(&network.to_string()).unwrap());
+        }
+        assert!(AnyNetwork::from_chain_id(56).is_err());
+    }
+
+    #[test]
+    fn convert() {
+        assert_eq!(AnyNetwork::Goerli, AnyNetwork::from(Goerli));
+        assert_eq!(AnyNetwork::Goerli, AnyNetwork::of::<Goerli>().unwrap());
+        assert_eq!(Goerli, Goerli::try_from(AnyNetwork::Goerli).unwrap());
+        assert!(Mainnet::try_from(AnyNetwork::Goerli).is_err());
+    }
+}
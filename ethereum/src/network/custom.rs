@@ -0,0 +1,38 @@
+use crate::network::EthereumNetwork;
+use wagyu_model::{ChildIndex, Network, NetworkError};
+
+use serde::Serialize;
+use std::{fmt, str::FromStr};
+
+/// Represents an EVM-compatible network, such as Polygon or BNB Smart Chain, whose chain id
+/// is given at runtime with `EthereumTransaction::<Custom>::new_with_chain_id`, or is read from
+/// the transaction bytes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct Custom;
+
+impl Network for Custom {
+    const NAME: &'static str = "custom";
+}
+
+impl EthereumNetwork for Custom {
+    const CHAIN_ID: u32 = 0;
+    const NETWORK_ID: u32 = 0;
+    const HD_COIN_TYPE: ChildIndex = ChildIndex::Hardened(60);
+}
+
+impl FromStr for Custom {
+    type Err = NetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            Self::NAME => Ok(Self),
+            _ => Err(NetworkError::InvalidNetwork(s.into())),
+        }
+    }
+}
+
+impl fmt::Display for Custom {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::NAME)
+    }
+}
@@ -1,5 +1,11 @@
 use wagyu_model::{ChildIndex, Network};
 
+pub mod any_network;
+pub use self::any_network::*;
+
+pub mod custom;
+pub use self::custom::*;
+
 pub mod goerli;
 pub use self::goerli::*;
 
@@ -17,6 +23,7 @@ pub use self::ropsten::*;
 
 /// The interface for an Ethereum network.
 pub trait EthereumNetwork: Network {
+    /// The EIP-155 chain id of the network, or 0 if the chain id is given with each transaction
     const CHAIN_ID: u32;
     const NETWORK_ID: u32;
     const HD_PURPOSE: ChildIndex = ChildIndex::Hardened(44);
@@ -6,6 +6,7 @@ use wagyu_model::{Address, AddressError, PrivateKey, PrivateKeyError, PublicKey}
 use core::{fmt, fmt::Display, str::FromStr};
 use rand::Rng;
 use secp256k1;
+use zeroize::Zeroize;
 
 /// Represents an Ethereum private key
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -66,6 +67,19 @@ impl Display for EthereumPrivateKey {
     }
 }
 
+impl Zeroize for EthereumPrivateKey {
+    /// Replaces the secret key with a placeholder, clearing the original secret key on drop.
+    fn zeroize(&mut self) {
+        self.0 = secp256k1::SecretKey::default();
+    }
+}
+
+impl Drop for EthereumPrivateKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,7 +188,7 @@ mod tests {
                         expected_private_key,
                         expected_public_key,
                         expected_address,
-                        private_key.0,
+                        private_key.0.clone(),
                     );
                 });
         }
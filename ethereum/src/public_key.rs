@@ -5,6 +5,7 @@ use wagyu_model::{Address, AddressError, PublicKey, PublicKeyError};
 
 use core::{fmt, fmt::Display, str::FromStr};
 use secp256k1;
+use tiny_keccak::keccak256;
 
 /// Represents an Ethereum public key
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -38,6 +39,43 @@ impl EthereumPublicKey {
     pub fn to_secp256k1_public_key(&self) -> secp256k1::PublicKey {
         self.0.clone()
     }
+
+    /// Returns the public key that signed the given 32-byte hash, such as a transaction sighash,
+    /// with the given 65-byte signature of r, s, and v. The recovery id in v is 0 or 1, offset by 27,
+    /// or offset by 35 and twice a chain id (EIP-155).
+    pub fn recover(hash: &[u8], signature: &[u8]) -> Result<Self, PublicKeyError> {
+        if hash.len() != 32 {
+            return Err(PublicKeyError::InvalidByteLength(hash.len()));
+        }
+        if signature.len() != 65 {
+            return Err(PublicKeyError::InvalidByteLength(signature.len()));
+        }
+        let recovery_id = match signature[64] {
+            v @ 0..=1 => v,
+            v @ 27..=28 => v - 27,
+            v @ 35..=255 => (v - 35) % 2,
+            v => return Err(PublicKeyError::InvalidRecoveryId(v)),
+        };
+        Ok(Self(secp256k1::recover(
+            &secp256k1::Message::parse_slice(hash)?,
+            &secp256k1::Signature::parse_slice(&signature[..64])?,
+            &secp256k1::RecoveryId::parse(recovery_id)?,
+        )?))
+    }
+
+    /// Returns the public key that signed the given message, as signed by `eth_sign` and `personal_sign`,
+    /// with the given 65-byte signature of r, s, and v.
+    /// https://github.com/ethereum/EIPs/blob/master/EIPS/eip-191.md
+    pub fn recover_message(message: &[u8], signature: &[u8]) -> Result<Self, PublicKeyError> {
+        Self::recover(&Self::to_message_hash(message), signature)
+    }
+
+    /// Returns the hash of the message signed by `eth_sign` and `personal_sign`, the Keccak-256 hash
+    /// of the message with the prefix "\x19Ethereum Signed Message:\n" and the length of the message.
+    pub fn to_message_hash(message: &[u8]) -> [u8; 32] {
+        let prefix = format!("\x19Ethereum Signed Message:\n{}", message.len());
+        keccak256(&[prefix.as_bytes(), message].concat())
+    }
 }
 
 impl FromStr for EthereumPublicKey {
@@ -150,6 +188,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn recover_message() {
+        // The signature of web3.eth.accounts.sign("Some data", private key)
+        let private_key =
+            EthereumPrivateKey::from_str("4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318").unwrap();
+        let expected_public_key = EthereumPublicKey::from_private_key(&private_key);
+        let message = b"Some data";
+        let hash = EthereumPublicKey::to_message_hash(message);
+        assert_eq!(
+            "1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655",
+            hex::encode(hash)
+        );
+
+        let mut signature = hex::decode("b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c").unwrap();
+        let public_key = EthereumPublicKey::recover_message(message, &signature).unwrap();
+        assert_eq!(expected_public_key, public_key);
+        assert_eq!(
+            "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23",
+            public_key.to_address(&EthereumFormat::Standard).unwrap().to_string()
+        );
+        assert_eq!(
+            expected_public_key,
+            EthereumPublicKey::recover(&hash, &signature).unwrap()
+        );
+
+        // The recovery id in v may be unoffset
+        signature[64] -= 27;
+        assert_eq!(
+            expected_public_key,
+            EthereumPublicKey::recover(&hash, &signature).unwrap()
+        );
+
+        // The other recovery id recovers another public key
+        signature[64] ^= 1;
+        assert_ne!(
+            expected_public_key,
+            EthereumPublicKey::recover(&hash, &signature).unwrap()
+        );
+
+        signature[64] = 2;
+        assert!(EthereumPublicKey::recover(&hash, &signature).is_err());
+        assert!(EthereumPublicKey::recover(&hash, &signature[..64]).is_err());
+        assert!(EthereumPublicKey::recover(&hash[..31], &signature).is_err());
+    }
+
     #[test]
     fn test_checksum_address_invalid() {
         // Invalid public key length
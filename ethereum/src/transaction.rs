@@ -1,41 +1,79 @@
 use crate::address::EthereumAddress;
 use crate::amount::EthereumAmount;
 use crate::format::EthereumFormat;
-use crate::network::EthereumNetwork;
+use crate::network::{Custom, EthereumNetwork};
 use crate::private_key::EthereumPrivateKey;
 use crate::public_key::EthereumPublicKey;
 use wagyu_model::{PrivateKey, PublicKey, Transaction, TransactionError, TransactionId};
 
-use core::{fmt, marker::PhantomData, str::FromStr};
+use core::{convert::TryFrom, fmt, marker::PhantomData, str::FromStr};
 use ethereum_types::U256;
-use rlp::{decode_list, RlpStream};
+use rlp::{Rlp, RlpStream};
 use secp256k1;
 use tiny_keccak::keccak256;
 
-pub fn to_bytes(value: u32) -> Result<Vec<u8>, TransactionError> {
-    match value {
-        // bounded by u8::max_value()
-        0..=255 => Ok(vec![value as u8]),
-        // bounded by u16::max_value()
-        256..=65535 => Ok((value as u16).to_le_bytes().to_vec()),
-        // bounded by u32::max_value()
-        _ => Ok(value.to_le_bytes().to_vec()),
-    }
+/// Returns the RLP encoding of an integer, in big-endian bytes without leading zeros,
+/// so that zero is encoded as the empty string.
+/// https://github.com/ethereum/wiki/wiki/RLP
+pub fn to_bytes(value: u64) -> Result<Vec<u8>, TransactionError> {
+    let leading_zero_bytes = (value.leading_zeros() / 8) as usize;
+    Ok(value.to_be_bytes()[leading_zero_bytes..].to_vec())
 }
 
-pub fn from_bytes(value: &Vec<u8>) -> Result<u32, TransactionError> {
+/// Returns the integer of its RLP encoding, or errors if the encoding is longer than 8 bytes
+/// or has leading zeros.
+pub fn from_bytes(value: &[u8]) -> Result<u64, TransactionError> {
     match value.len() {
-        0 => Ok(0u32),
-        1 => Ok(u32::from_le_bytes([value[0], 0, 0, 0])),
-        2 => Ok(u32::from_le_bytes([value[0], value[1], 0, 0])),
-        3 => Ok(u32::from_le_bytes([value[0], value[1], value[2], 0])),
-        4 => Ok(u32::from_le_bytes([value[0], value[1], value[2], value[3]])),
-        _ => Err(TransactionError::Message(
-            "invalid byte length for u32 value".to_string(),
-        )),
+        0 => Ok(0),
+        1..=8 if value[0] != 0 => {
+            let mut bytes = [0u8; 8];
+            bytes[8 - value.len()..].copy_from_slice(value);
+            Ok(u64::from_be_bytes(bytes))
+        }
+        _ => Err(TransactionError::Message(format!(
+            "invalid integer encoding {}",
+            hex::encode(value)
+        ))),
+    }
+}
+
+/// Returns the signature of its r and s values, in 64 bytes, or errors if its s value is in the upper half
+/// of the curve order, as a malleable signature is rejected by the network (EIP-2).
+/// https://github.com/ethereum/EIPs/blob/master/EIPS/eip-2.md
+fn to_low_s_signature(signature: &[u8]) -> Result<secp256k1::Signature, TransactionError> {
+    let signature = secp256k1::Signature::parse_slice(signature)?;
+    match signature.s.is_high() {
+        true => Err(TransactionError::NonCanonicalSignature),
+        false => Ok(signature),
     }
 }
 
+/// Represents an address and the storage keys of its contract accessed by an Ethereum transaction (EIP-2930)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EthereumAccessListItem {
+    /// The address of the contract
+    pub address: EthereumAddress,
+    /// The storage keys of the contract
+    pub storage_keys: Vec<[u8; 32]>,
+}
+
+/// Represents the type of an Ethereum transaction (EIP-2718)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EthereumTransactionType {
+    /// A legacy transaction, replay protected with the chain id in its signature (EIP-155)
+    Legacy,
+    /// A legacy transaction without replay protection, signed with a `v` of 27 or 28 (pre-EIP-155),
+    /// which is valid on every network
+    Unprotected,
+    /// A transaction of type 0x01, with a list of the addresses and storage keys it accesses (EIP-2930)
+    AccessList(Vec<EthereumAccessListItem>),
+}
+
+impl EthereumTransactionType {
+    /// The type of an access list transaction, prefixed to its encoding
+    const ACCESS_LIST: u8 = 0x01;
+}
+
 /// Represents the parameters for an Ethereum transaction
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct EthereumTransactionParameters {
@@ -51,17 +89,20 @@ pub struct EthereumTransactionParameters {
     pub nonce: U256,
     /// The transaction data
     pub data: Vec<u8>,
+    /// The transaction type, and its access list if it is an access list transaction
+    pub transaction_type: EthereumTransactionType,
 }
 
 /// Represents an Ethereum transaction signature
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 struct EthereumTransactionSignature {
-    /// The V field of the signature protected with a chain_id
+    /// The V field of the signature protected with a chain_id,
+    /// or the parity of the y-coordinate of the signature of an access list transaction
     v: Vec<u8>,
     /// The R field of the signature
-    r: Vec<u8>,
+    r: U256,
     /// The S field of the signature
-    s: Vec<u8>,
+    s: U256,
 }
 
 /// Represents an Ethereum transaction id
@@ -87,12 +128,15 @@ pub struct EthereumTransaction<N: EthereumNetwork> {
     parameters: EthereumTransactionParameters,
     /// The transaction signature
     signature: Option<EthereumTransactionSignature>,
+    /// The EIP-155 chain id of the transaction
+    chain_id: u32,
     /// PhantomData
     _network: PhantomData<N>,
 }
 
 impl<N: EthereumNetwork> Transaction for EthereumTransaction<N> {
     type Address = EthereumAddress;
+    type Amount = EthereumAmount;
     type Format = EthereumFormat;
     type PrivateKey = EthereumPrivateKey;
     type PublicKey = EthereumPublicKey;
@@ -105,6 +149,7 @@ impl<N: EthereumNetwork> Transaction for EthereumTransaction<N> {
             sender: None,
             parameters: parameters.clone(),
             signature: None,
+            chain_id: N::CHAIN_ID,
             _network: PhantomData,
         })
     }
@@ -112,24 +157,52 @@ impl<N: EthereumNetwork> Transaction for EthereumTransaction<N> {
     /// Returns a signed transaction given the private key of the sender.
     /// https://github.com/ethereum/EIPs/blob/master/EIPS/eip-155.md
     fn sign(&self, private_key: &Self::PrivateKey) -> Result<Self, TransactionError> {
+        match (&self.sender, &self.signature) {
+            (Some(_), Some(_)) => Ok(self.clone()),
+            (Some(_), None) | (None, Some(_)) => Err(TransactionError::InvalidTransactionState),
+            (None, None) => self.sign_with_secret_key(
+                &private_key.to_secp256k1_secret_key(),
+                private_key.to_address(&EthereumFormat::Standard)?,
+            ),
+        }
+    }
+
+    /// Returns a transaction signed with a signature produced outside of the library, such as by a
+    /// hardware device, given the public key of the sender. The recovery id of the signature is
+    /// recomputed from the public key, or errors if the signature is not of the sighash by the public key.
+    fn sign_with_signature(&self, signature: &[u8], public_key: &Self::PublicKey) -> Result<Self, TransactionError> {
         match (&self.sender, &self.signature) {
             (Some(_), Some(_)) => Ok(self.clone()),
             (Some(_), None) | (None, Some(_)) => Err(TransactionError::InvalidTransactionState),
             (None, None) => {
-                let (signature, v) = secp256k1::sign(
-                    &secp256k1::Message::parse_slice(&self.to_transaction_id()?.txid)?,
-                    &private_key.to_secp256k1_secret_key(),
-                );
-                let signature = signature.serialize();
-
-                let mut transaction = self.clone();
-                transaction.sender = Some(private_key.to_address(&EthereumFormat::Standard)?);
-                transaction.signature = Some(EthereumTransactionSignature {
-                    v: to_bytes(Into::<i32>::into(v) as u32 + N::CHAIN_ID * 2 + 35)?, // EIP155
-                    r: signature[0..32].to_vec(),
-                    s: signature[32..64].to_vec(),
-                });
-                Ok(transaction)
+                let mut signature = match signature.len() {
+                    64 | 65 => secp256k1::Signature::parse_slice(&signature[..64])?,
+                    length => {
+                        return Err(TransactionError::Message(format!(
+                            "invalid signature length {}",
+                            length
+                        )))
+                    }
+                };
+                signature.normalize_s();
+
+                let message = secp256k1::Message::parse_slice(&self.to_sighash(0)?)?;
+                let secp256k1_public_key = public_key.to_secp256k1_public_key();
+                let recovery_id = (0..2)
+                    .filter_map(|recovery_id| secp256k1::RecoveryId::parse(recovery_id).ok())
+                    .find(|recovery_id| {
+                        secp256k1::recover(&message, &signature, recovery_id).as_ref() == Ok(&secp256k1_public_key)
+                    });
+                match recovery_id {
+                    Some(recovery_id) => self.with_signature(
+                        &signature,
+                        recovery_id,
+                        public_key.to_address(&EthereumFormat::Standard)?,
+                    ),
+                    None => Err(TransactionError::Message(
+                        "the signature does not sign the transaction with the public key".into(),
+                    )),
+                }
             }
         }
     }
@@ -137,70 +210,94 @@ impl<N: EthereumNetwork> Transaction for EthereumTransaction<N> {
     /// Returns a transaction given the transaction bytes.
     /// https://github.com/ethereum/EIPs/blob/master/EIPS/eip-155.md
     fn from_transaction_bytes(transaction: &Vec<u8>) -> Result<Self, TransactionError> {
-        let list: Vec<Vec<u8>> = decode_list(&transaction);
-        if list.len() != 9 {
-            return Err(TransactionError::InvalidRlpLength(list.len()));
+        if transaction.first() == Some(&EthereumTransactionType::ACCESS_LIST) {
+            return Self::from_access_list_transaction_bytes(&transaction[1..]);
         }
 
-        let parameters = EthereumTransactionParameters {
-            receiver: EthereumAddress::from_str(&hex::encode(&list[3]))?,
-            amount: match list[4].is_empty() {
-                true => EthereumAmount::from_u256(U256::zero()),
-                false => EthereumAmount::from_u256(U256::from(list[4].as_slice())),
-            },
-            gas: match list[2].is_empty() {
-                true => U256::zero(),
-                false => U256::from(list[2].as_slice()),
-            },
-            gas_price: match list[1].is_empty() {
-                true => EthereumAmount::from_u256(U256::zero()),
-                false => EthereumAmount::from_u256(U256::from(list[1].as_slice())),
-            },
-            nonce: match list[0].is_empty() {
-                true => U256::zero(),
-                false => U256::from(list[0].as_slice()),
-            },
-            data: list[5].clone(),
+        let list = Rlp::new(transaction);
+        let length = list.item_count()?;
+        if length != 6 && length != 9 {
+            return Err(TransactionError::InvalidRlpLength(length));
+        }
+
+        let mut parameters = EthereumTransactionParameters {
+            receiver: EthereumAddress::from_str(&hex::encode(list.at(3)?.data()?))?,
+            amount: EthereumAmount::from_u256(list.val_at(4)?),
+            gas: list.val_at(2)?,
+            gas_price: EthereumAmount::from_u256(list.val_at(1)?),
+            nonce: list.val_at(0)?,
+            data: list.val_at(5)?,
+            transaction_type: EthereumTransactionType::Legacy,
         };
 
-        match list[7].is_empty() && list[8].is_empty() {
+        if length == 6 {
+            // Raw transaction without replay protection
+            parameters.transaction_type = EthereumTransactionType::Unprotected;
+            return Ok(Self {
+                sender: None,
+                parameters,
+                signature: None,
+                chain_id: N::CHAIN_ID,
+                _network: PhantomData,
+            });
+        }
+
+        let v: Vec<u8> = list.val_at(6)?;
+        let r: U256 = list.val_at(7)?;
+        let s: U256 = list.val_at(8)?;
+
+        match r.is_zero() && s.is_zero() {
             true => {
-                // Raw transaction
+                // Raw transaction, with the chain id in place of v
                 Ok(Self {
                     sender: None,
                     parameters,
                     signature: None,
+                    chain_id: Self::to_chain_id(from_bytes(&v)?)?,
                     _network: PhantomData,
                 })
             }
             false => {
-                // Signed transaction
-                let v = from_bytes(&list[6])?;
-                let recovery_id = secp256k1::RecoveryId::parse((v - N::CHAIN_ID * 2 - 35) as u8)?;
-                let mut signature = list[7].clone();
-                signature.extend_from_slice(&list[8]);
+                // Signed transaction, with the recovery id in v, offset by 27 without replay protection,
+                // or by 35 and twice the chain id otherwise (EIP-155)
+                let v_value = from_bytes(&v)?;
+                let (chain_id, recovery_id) = match v_value {
+                    27 | 28 => {
+                        parameters.transaction_type = EthereumTransactionType::Unprotected;
+                        (N::CHAIN_ID, v_value - 27)
+                    }
+                    35..=u64::MAX => (Self::to_chain_id((v_value - 35) / 2)?, (v_value - 35) % 2),
+                    _ => {
+                        return Err(TransactionError::Message(format!(
+                            "invalid signature v value {}",
+                            v_value
+                        )))
+                    }
+                };
+                let recovery_id = secp256k1::RecoveryId::parse(recovery_id as u8)?;
+                let mut signature = [0u8; 64];
+                r.to_big_endian(&mut signature[0..32]);
+                s.to_big_endian(&mut signature[32..64]);
 
                 let raw_transaction = Self {
                     sender: None,
                     parameters: parameters.clone(),
                     signature: None,
+                    chain_id,
                     _network: PhantomData,
                 };
-                let message = secp256k1::Message::parse_slice(&raw_transaction.to_transaction_id()?.txid)?;
+                let message = secp256k1::Message::parse_slice(&raw_transaction.to_sighash(0)?)?;
                 let public_key = EthereumPublicKey::from_secp256k1_public_key(secp256k1::recover(
                     &message,
-                    &secp256k1::Signature::parse_slice(signature.as_slice())?,
+                    &to_low_s_signature(&signature)?,
                     &recovery_id,
                 )?);
 
                 Ok(Self {
                     sender: Some(public_key.to_address(&EthereumFormat::Standard)?),
                     parameters,
-                    signature: Some(EthereumTransactionSignature {
-                        v: list[6].clone(),
-                        r: list[7].clone(),
-                        s: list[8].clone(),
-                    }),
+                    signature: Some(EthereumTransactionSignature { v, r, s }),
+                    chain_id,
                     _network: PhantomData,
                 })
             }
@@ -226,13 +323,14 @@ impl<N: EthereumNetwork> Transaction for EthereumTransaction<N> {
         }
 
         // Returns the raw transaction (in RLP).
-        fn raw_transaction<N: EthereumNetwork>(
+        fn raw_transaction(
             parameters: &EthereumTransactionParameters,
+            chain_id: u32,
         ) -> Result<RlpStream, TransactionError> {
             let mut transaction_rlp = RlpStream::new();
             transaction_rlp.begin_list(9);
             encode_transaction(&mut transaction_rlp, parameters)?;
-            transaction_rlp.append(&to_bytes(N::CHAIN_ID)?);
+            transaction_rlp.append(&to_bytes(chain_id.into())?);
             transaction_rlp.append(&0u8);
             transaction_rlp.append(&0u8);
             Ok(transaction_rlp)
@@ -252,17 +350,281 @@ impl<N: EthereumNetwork> Transaction for EthereumTransaction<N> {
             Ok(transaction_rlp)
         }
 
-        match &self.signature {
-            Some(signature) => Ok(signed_transaction(&self.parameters, signature)?.out()),
-            None => Ok(raw_transaction::<N>(&self.parameters)?.out()),
+        // Returns the access list transaction, signed if the signature is present (in RLP),
+        // prefixed with its transaction type.
+        // https://github.com/ethereum/EIPs/blob/master/EIPS/eip-2930.md
+        fn access_list_transaction(
+            parameters: &EthereumTransactionParameters,
+            access_list: &[EthereumAccessListItem],
+            signature: Option<&EthereumTransactionSignature>,
+            chain_id: u32,
+        ) -> Result<Vec<u8>, TransactionError> {
+            let mut transaction_rlp = RlpStream::new();
+            transaction_rlp.begin_list(if signature.is_some() { 11 } else { 8 });
+            transaction_rlp.append(&U256::from(chain_id));
+            encode_transaction(&mut transaction_rlp, parameters)?;
+            transaction_rlp.begin_list(access_list.len());
+            for item in access_list {
+                transaction_rlp.begin_list(2);
+                transaction_rlp.append(&hex::decode(&item.address.to_string()[2..])?);
+                transaction_rlp.begin_list(item.storage_keys.len());
+                for storage_key in &item.storage_keys {
+                    transaction_rlp.append(&storage_key.to_vec());
+                }
+            }
+            if let Some(signature) = signature {
+                transaction_rlp.append(&signature.v);
+                transaction_rlp.append(&signature.r);
+                transaction_rlp.append(&signature.s);
+            }
+            Ok([vec![EthereumTransactionType::ACCESS_LIST], transaction_rlp.out()].concat())
+        }
+
+        match (&self.parameters.transaction_type, &self.signature) {
+            (EthereumTransactionType::AccessList(access_list), signature) => {
+                access_list_transaction(&self.parameters, access_list, signature.as_ref(), self.chain_id)
+            }
+            (EthereumTransactionType::Legacy, Some(signature))
+            | (EthereumTransactionType::Unprotected, Some(signature)) => {
+                Ok(signed_transaction(&self.parameters, signature)?.out())
+            }
+            (EthereumTransactionType::Legacy, None) => Ok(raw_transaction(&self.parameters, self.chain_id)?.out()),
+            (EthereumTransactionType::Unprotected, None) => {
+                let mut transaction_rlp = RlpStream::new();
+                transaction_rlp.begin_list(6);
+                encode_transaction(&mut transaction_rlp, &self.parameters)?;
+                Ok(transaction_rlp.out())
+            }
         }
     }
 
-    /// Returns the hash of the signed transaction, if the signature is present.
-    /// Otherwise, returns the hash of the raw transaction.
+    /// Returns the hash signed by the sender, the hash of the raw transaction without its signature.
+    /// A transaction has a single sender, so its only sighash is at index 0.
+    /// https://github.com/ethereum/EIPs/blob/master/EIPS/eip-155.md
+    fn to_sighash(&self, index: usize) -> Result<Vec<u8>, TransactionError> {
+        if index != 0 {
+            return Err(TransactionError::InvalidInputs(format!(
+                "no sighash at index {}",
+                index
+            )));
+        }
+        let raw_transaction = Self {
+            sender: None,
+            parameters: self.parameters.clone(),
+            signature: None,
+            chain_id: self.chain_id,
+            _network: PhantomData,
+        };
+        Ok(keccak256(&raw_transaction.to_transaction_bytes()?).to_vec())
+    }
+
+    /// Returns the hash of the signed transaction, or errors if the transaction is not signed,
+    /// as its sighash is not the hash it is broadcast with.
     fn to_transaction_id(&self) -> Result<Self::TransactionId, TransactionError> {
-        Ok(Self::TransactionId {
-            txid: keccak256(&self.to_transaction_bytes()?).iter().cloned().collect(),
+        match self.signature {
+            Some(_) => Ok(Self::TransactionId {
+                txid: keccak256(&self.to_transaction_bytes()?).to_vec(),
+            }),
+            None => Err(TransactionError::InvalidTransactionState),
+        }
+    }
+
+    /// Returns the maximum fee of the transaction, its gas limit times its gas price.
+    /// The fee paid is lower if the transaction uses less gas than its limit, as the rest is refunded.
+    fn fee(&self) -> Option<Self::Amount> {
+        self.parameters
+            .gas
+            .checked_mul(self.parameters.gas_price.0)
+            .map(EthereumAmount::from_u256)
+    }
+}
+
+impl<N: EthereumNetwork> EthereumTransaction<N> {
+    /// Returns the address of the sender, if the transaction is signed.
+    pub fn sender(&self) -> Option<&EthereumAddress> {
+        self.sender.as_ref()
+    }
+
+    /// Returns the transaction parameters.
+    pub fn parameters(&self) -> &EthereumTransactionParameters {
+        &self.parameters
+    }
+
+    /// Returns an unsigned transaction given the transaction parameters and its EIP-155 chain id,
+    /// which is any chain id of an EVM-compatible network for the `Custom` network, e.g. 137 for Polygon,
+    /// or the chain id of the network otherwise.
+    pub fn new_with_chain_id(
+        parameters: &EthereumTransactionParameters,
+        chain_id: u32,
+    ) -> Result<Self, TransactionError> {
+        Ok(Self {
+            chain_id: Self::to_chain_id(chain_id.into())?,
+            ..Self::new(parameters)?
+        })
+    }
+
+    /// Returns the EIP-155 chain id of the transaction, which an `Unprotected` transaction is not bound to.
+    pub fn chain_id(&self) -> u32 {
+        self.chain_id
+    }
+
+    /// Returns the given chain id of a transaction, or errors if it is not the chain id of the network,
+    /// or, for a custom network, if it is the placeholder chain id.
+    fn to_chain_id(chain_id: u64) -> Result<u32, TransactionError> {
+        match (N::CHAIN_ID, u32::try_from(chain_id)) {
+            (_, Ok(Custom::CHAIN_ID)) | (_, Err(_)) => Err(TransactionError::InvalidChainId(chain_id)),
+            (Custom::CHAIN_ID, Ok(chain_id)) => Ok(chain_id),
+            (chain_id_of_network, Ok(chain_id)) if chain_id == chain_id_of_network => Ok(chain_id),
+            _ => Err(TransactionError::InvalidChainId(chain_id)),
+        }
+    }
+
+    /// Returns the transactions signed with the given private key of the sender, in order.
+    /// The secret key and address of the sender are computed once for the batch, instead of
+    /// once for each transaction as by `sign`.
+    pub fn sign_batch(transactions: &[Self], private_key: &EthereumPrivateKey) -> Result<Vec<Self>, TransactionError> {
+        let secret_key = private_key.to_secp256k1_secret_key();
+        let sender = private_key.to_address(&EthereumFormat::Standard)?;
+        transactions
+            .iter()
+            .map(|transaction| match (&transaction.sender, &transaction.signature) {
+                (Some(_), Some(_)) => Ok(transaction.clone()),
+                (Some(_), None) | (None, Some(_)) => Err(TransactionError::InvalidTransactionState),
+                (None, None) => transaction.sign_with_secret_key(&secret_key, sender.clone()),
+            })
+            .collect()
+    }
+
+    /// Returns the transaction signed with the given secret key of the given sender.
+    fn sign_with_secret_key(
+        &self,
+        secret_key: &secp256k1::SecretKey,
+        sender: EthereumAddress,
+    ) -> Result<Self, TransactionError> {
+        let (signature, recovery_id) =
+            secp256k1::sign(&secp256k1::Message::parse_slice(&self.to_sighash(0)?)?, secret_key);
+        self.with_signature(&signature, recovery_id, sender)
+    }
+
+    /// Returns the transaction signed with the given signature of its sighash by the given sender,
+    /// with the recovery id in `v` as specified by EIP-155, offset by 27 for an unprotected transaction,
+    /// or as specified by EIP-2930 for an access list transaction.
+    /// Errors if the signature is not normalized to a low s value.
+    fn with_signature(
+        &self,
+        signature: &secp256k1::Signature,
+        recovery_id: secp256k1::RecoveryId,
+        sender: EthereumAddress,
+    ) -> Result<Self, TransactionError> {
+        if signature.s.is_high() {
+            return Err(TransactionError::NonCanonicalSignature);
+        }
+        let signature = signature.serialize();
+        let recovery_id = Into::<i32>::into(recovery_id) as u64;
+        let v = match self.parameters.transaction_type {
+            EthereumTransactionType::Legacy => to_bytes(recovery_id + u64::from(self.chain_id) * 2 + 35)?, // EIP155
+            EthereumTransactionType::Unprotected => to_bytes(recovery_id + 27)?,
+            EthereumTransactionType::AccessList(_) => match recovery_id {
+                0 => vec![],
+                _ => vec![recovery_id as u8],
+            },
+        };
+
+        let mut transaction = self.clone();
+        transaction.sender = Some(sender);
+        transaction.signature = Some(EthereumTransactionSignature {
+            v,
+            r: U256::from(&signature[0..32]),
+            s: U256::from(&signature[32..64]),
+        });
+        Ok(transaction)
+    }
+
+    /// Returns an access list transaction given its bytes, without its transaction type.
+    /// https://github.com/ethereum/EIPs/blob/master/EIPS/eip-2930.md
+    fn from_access_list_transaction_bytes(transaction: &[u8]) -> Result<Self, TransactionError> {
+        let list = Rlp::new(transaction);
+        let length = list.item_count()?;
+        if length != 8 && length != 11 {
+            return Err(TransactionError::InvalidRlpLength(length));
+        }
+
+        let chain_id: U256 = list.val_at(0)?;
+        let chain_id = match chain_id <= U256::from(u64::max_value()) {
+            true => Self::to_chain_id(chain_id.low_u64())?,
+            false => return Err(TransactionError::Message(format!("invalid chain id {}", chain_id))),
+        };
+
+        let mut access_list = vec![];
+        for item in list.at(7)?.iter() {
+            let mut storage_keys = vec![];
+            for storage_key in item.at(1)?.iter() {
+                let mut key = [0u8; 32];
+                match storage_key.data()? {
+                    data if data.len() == 32 => key.copy_from_slice(data),
+                    data => {
+                        return Err(TransactionError::Message(format!(
+                            "invalid storage key length {}",
+                            data.len()
+                        )))
+                    }
+                };
+                storage_keys.push(key);
+            }
+            access_list.push(EthereumAccessListItem {
+                address: EthereumAddress::from_str(&hex::encode(item.at(0)?.data()?))?,
+                storage_keys,
+            });
+        }
+
+        let parameters = EthereumTransactionParameters {
+            receiver: EthereumAddress::from_str(&hex::encode(list.at(4)?.data()?))?,
+            amount: EthereumAmount::from_u256(list.val_at(5)?),
+            gas: list.val_at(3)?,
+            gas_price: EthereumAmount::from_u256(list.val_at(2)?),
+            nonce: list.val_at(1)?,
+            data: list.val_at(6)?,
+            transaction_type: EthereumTransactionType::AccessList(access_list),
+        };
+        let raw_transaction = Self {
+            sender: None,
+            parameters,
+            signature: None,
+            chain_id,
+            _network: PhantomData,
+        };
+        if length == 8 {
+            return Ok(raw_transaction);
+        }
+
+        // Signed transaction
+        let v: Vec<u8> = list.val_at(8)?;
+        let recovery_id = match v.as_slice() {
+            [] => 0,
+            [1] => 1,
+            _ => {
+                return Err(TransactionError::Message(format!(
+                    "invalid signature y parity {}",
+                    hex::encode(&v)
+                )))
+            }
+        };
+        let (r, s): (U256, U256) = (list.val_at(9)?, list.val_at(10)?);
+        let mut signature = [0u8; 64];
+        r.to_big_endian(&mut signature[0..32]);
+        s.to_big_endian(&mut signature[32..64]);
+
+        let message = secp256k1::Message::parse_slice(&raw_transaction.to_sighash(0)?)?;
+        let public_key = EthereumPublicKey::from_secp256k1_public_key(secp256k1::recover(
+            &message,
+            &to_low_s_signature(&signature)?,
+            &secp256k1::RecoveryId::parse(recovery_id)?,
+        )?);
+
+        Ok(Self {
+            sender: Some(public_key.to_address(&EthereumFormat::Standard)?),
+            signature: Some(EthereumTransactionSignature { v, r, s }),
+            ..raw_transaction
         })
     }
 }
@@ -319,6 +681,7 @@ mod tests {
             gas_price: EthereumAmount::from_wei(transaction.gas_price).unwrap(),
             nonce: U256::from_dec_str(transaction.nonce).unwrap(),
             data: transaction.data.as_bytes().to_vec(),
+            transaction_type: EthereumTransactionType::Legacy,
         };
 
         let transaction = EthereumTransaction::<N>::new(&parameters).unwrap();
@@ -340,6 +703,7 @@ mod tests {
             gas_price: EthereumAmount::from_wei(transaction.gas_price).unwrap(),
             nonce: U256::from_dec_str(transaction.nonce).unwrap(),
             data: transaction.data.as_bytes().to_vec(),
+            transaction_type: EthereumTransactionType::Legacy,
         };
 
         let transaction = EthereumTransaction::<N>::new(&parameters).unwrap();
@@ -353,6 +717,17 @@ mod tests {
 
         assert_eq!(parameters, transaction.parameters);
         assert_eq!(expected_signed_transaction, signed_transaction.to_string());
+
+        // A batch signs each of its transactions as they are signed on their own, in order
+        let mut next_parameters = parameters.clone();
+        next_parameters.nonce = parameters.nonce + 1;
+        let next_transaction = EthereumTransaction::<N>::new(&next_parameters).unwrap();
+        let signed_transactions =
+            EthereumTransaction::sign_batch(&[transaction, next_transaction.clone()], &private_key).unwrap();
+        assert_eq!(
+            vec![signed_transaction, next_transaction.sign(&private_key).unwrap()],
+            signed_transactions
+        );
     }
 
     fn test_from_transaction_bytes<N: EthereumNetwork>(transaction: &TransactionTestCase) {
@@ -365,10 +740,15 @@ mod tests {
             gas_price: EthereumAmount::from_wei(transaction.gas_price).unwrap(),
             nonce: U256::from_dec_str(transaction.nonce).unwrap(),
             data: transaction.data.as_bytes().to_vec(),
+            transaction_type: EthereumTransactionType::Legacy,
         };
         let signed_transaction_bytes = hex::decode(&transaction.signed_transaction[2..]).unwrap();
 
+        let expected_fee = expected_parameters.gas * expected_parameters.gas_price.0;
+
         let transaction = EthereumTransaction::<N>::from_transaction_bytes(&signed_transaction_bytes).unwrap();
+        assert_eq!(Some(EthereumAmount::from_u256(expected_fee)), transaction.fee());
+        assert_eq!(signed_transaction_bytes.len(), transaction.size().unwrap());
         assert_eq!(expected_sender, transaction.sender);
         assert_eq!(expected_parameters, transaction.parameters);
         assert_eq!(signed_transaction_bytes, transaction.to_transaction_bytes().unwrap());
@@ -384,6 +764,7 @@ mod tests {
             gas_price: EthereumAmount::from_wei(transaction.gas_price).unwrap(),
             nonce: U256::from_dec_str(transaction.nonce).unwrap(),
             data: transaction.data.as_bytes().to_vec(),
+            transaction_type: EthereumTransactionType::Legacy,
         };
 
         let transaction = EthereumTransaction::<N>::new(&parameters).unwrap();
@@ -404,6 +785,7 @@ mod tests {
             gas_price: EthereumAmount::from_wei(transaction.gas_price).unwrap(),
             nonce: U256::from_dec_str(transaction.nonce).unwrap(),
             data: transaction.data.as_bytes().to_vec(),
+            transaction_type: EthereumTransactionType::Legacy,
         };
 
         let transaction = EthereumTransaction::<N>::new(&parameters).unwrap();
@@ -412,6 +794,37 @@ mod tests {
             expected_signed_transaction_hash,
             signed_transaction.to_transaction_id().unwrap().to_string()
         );
+
+        // The sighash is the same before and after signing, and is not the transaction id
+        let sighash = transaction.to_sighash(0).unwrap();
+        assert_eq!(sighash, signed_transaction.to_sighash(0).unwrap());
+        assert_ne!(sighash, signed_transaction.to_transaction_id().unwrap().txid);
+        assert!(transaction.to_transaction_id().is_err());
+        assert!(transaction.to_sighash(1).is_err());
+
+        // A detached signature of the sighash signs the same transaction as the private key
+        let (signature, _) = secp256k1::sign(
+            &secp256k1::Message::parse_slice(&sighash).unwrap(),
+            &private_key.to_secp256k1_secret_key(),
+        );
+        let public_key = private_key.to_public_key();
+        let detached_transaction = transaction
+            .sign_with_signature(&signature.serialize(), &public_key)
+            .unwrap();
+        assert_eq!(signed_transaction, detached_transaction);
+
+        // A detached signature with a high s value is normalized to its low s value
+        let mut high_s_signature = signature.clone();
+        high_s_signature.s = -high_s_signature.s;
+        let detached_transaction = transaction
+            .sign_with_signature(&high_s_signature.serialize(), &public_key)
+            .unwrap();
+        assert_eq!(signed_transaction, detached_transaction);
+
+        let other_public_key = EthereumPrivateKey::from_str(&"11".repeat(32)).unwrap().to_public_key();
+        assert!(transaction
+            .sign_with_signature(&signature.serialize(), &other_public_key)
+            .is_err());
     }
 
     fn test_to_string<N: EthereumNetwork>(transaction: &TransactionTestCase) {
@@ -424,6 +837,7 @@ mod tests {
             gas_price: EthereumAmount::from_wei(transaction.gas_price).unwrap(),
             nonce: U256::from_dec_str(transaction.nonce).unwrap(),
             data: transaction.data.as_bytes().to_vec(),
+            transaction_type: EthereumTransactionType::Legacy,
         };
 
         let transaction = EthereumTransaction::<N>::new(&parameters).unwrap();
@@ -492,6 +906,14 @@ mod tests {
         fn to_string() {
             FAKE_TRANSACTIONS.iter().for_each(test_to_string::<N>);
         }
+
+        #[test]
+        fn from_transaction_bytes_invalid_network() {
+            FAKE_TRANSACTIONS.iter().for_each(|transaction| {
+                let signed_transaction_bytes = hex::decode(&transaction.signed_transaction[2..]).unwrap();
+                assert!(EthereumTransaction::<Rinkeby>::from_transaction_bytes(&signed_transaction_bytes).is_err());
+            });
+        }
     }
 
     mod rinkeby {
@@ -825,4 +1247,388 @@ mod tests {
                 .for_each(test_to_string::<N>);
         }
     }
+
+    mod access_list {
+        use super::*;
+
+        type N = Mainnet;
+
+        const PRIVATE_KEY: &str = "51ce358ffdcf208fadfb01a339f3ab715a89045a093777a44784d9e215277c1c";
+        const RAW_TRANSACTION: &str = "01f8860180843b9aca0082753094b5d590a6abf5e349c1b6c511bc87ceabfb3d7e65880de0b6b3a764000080f85bf8599452c3a8a79a521d10b25569847cb1a3ffb66550d6f842a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000001";
+
+        fn parameters() -> EthereumTransactionParameters {
+            let mut storage_key = [0u8; 32];
+            storage_key[31] = 1;
+            EthereumTransactionParameters {
+                receiver: EthereumAddress::from_str("0xB5D590A6aBf5E349C1b6C511Bc87CEAbFB3D7e65").unwrap(),
+                amount: EthereumAmount::from_wei("1000000000000000000").unwrap(),
+                gas: U256::from_dec_str("30000").unwrap(),
+                gas_price: EthereumAmount::from_wei("1000000000").unwrap(),
+                nonce: U256::zero(),
+                data: vec![],
+                transaction_type: EthereumTransactionType::AccessList(vec![EthereumAccessListItem {
+                    address: EthereumAddress::from_str("0x52C3a8a79a521D10b25569847CB1a3FfB66550D6").unwrap(),
+                    storage_keys: vec![[0u8; 32], storage_key],
+                }]),
+            }
+        }
+
+        #[test]
+        fn to_transaction_bytes() {
+            let transaction = EthereumTransaction::<N>::new(&parameters()).unwrap();
+            assert_eq!(
+                RAW_TRANSACTION,
+                hex::encode(transaction.to_transaction_bytes().unwrap())
+            );
+
+            let transaction =
+                EthereumTransaction::<N>::from_transaction_bytes(&hex::decode(RAW_TRANSACTION).unwrap()).unwrap();
+            assert_eq!(parameters(), transaction.parameters);
+            assert_eq!(None, transaction.sender);
+        }
+
+        #[test]
+        fn sign() {
+            let private_key = EthereumPrivateKey::from_str(PRIVATE_KEY).unwrap();
+            let transaction = EthereumTransaction::<N>::new(&parameters()).unwrap();
+            let signed_transaction = transaction.sign(&private_key).unwrap();
+            let signed_transaction_bytes = signed_transaction.to_transaction_bytes().unwrap();
+            assert_eq!(EthereumTransactionType::ACCESS_LIST, signed_transaction_bytes[0]);
+
+            let decoded_transaction =
+                EthereumTransaction::<N>::from_transaction_bytes(&signed_transaction_bytes).unwrap();
+            assert_eq!(
+                Some(private_key.to_address(&EthereumFormat::Standard).unwrap()),
+                decoded_transaction.sender
+            );
+            assert_eq!(parameters(), decoded_transaction.parameters);
+            assert_eq!(
+                signed_transaction_bytes,
+                decoded_transaction.to_transaction_bytes().unwrap()
+            );
+            assert_eq!(
+                signed_transaction.to_transaction_id().unwrap(),
+                decoded_transaction.to_transaction_id().unwrap()
+            );
+        }
+
+        #[test]
+        fn from_transaction_bytes_invalid_network() {
+            let private_key = EthereumPrivateKey::from_str(PRIVATE_KEY).unwrap();
+            let transaction = EthereumTransaction::<N>::new(&parameters()).unwrap();
+            let signed_transaction_bytes = transaction.sign(&private_key).unwrap().to_transaction_bytes().unwrap();
+            assert!(EthereumTransaction::<Rinkeby>::from_transaction_bytes(&signed_transaction_bytes).is_err());
+            assert!(
+                EthereumTransaction::<N>::from_transaction_bytes(&signed_transaction_bytes[..40].to_vec()).is_err()
+            );
+        }
+    }
+
+    mod custom {
+        use super::*;
+        use crate::Custom;
+
+        const PRIVATE_KEY: &str = "51ce358ffdcf208fadfb01a339f3ab715a89045a093777a44784d9e215277c1c";
+
+        fn parameters(transaction_type: EthereumTransactionType) -> EthereumTransactionParameters {
+            EthereumTransactionParameters {
+                receiver: EthereumAddress::from_str("0xB5D590A6aBf5E349C1b6C511Bc87CEAbFB3D7e65").unwrap(),
+                amount: EthereumAmount::from_wei("1000000000000000000").unwrap(),
+                gas: U256::from_dec_str("21000").unwrap(),
+                gas_price: EthereumAmount::from_wei("1000000000").unwrap(),
+                nonce: U256::zero(),
+                data: vec![],
+                transaction_type,
+            }
+        }
+
+        #[test]
+        fn sign_with_chain_id_of_network() {
+            // A custom network with the chain id of a built-in network signs as the built-in network
+            let private_key = EthereumPrivateKey::from_str(PRIVATE_KEY).unwrap();
+            for transaction_type in vec![
+                EthereumTransactionType::Legacy,
+                EthereumTransactionType::AccessList(vec![]),
+            ] {
+                let parameters = parameters(transaction_type);
+                let expected_transaction = EthereumTransaction::<Goerli>::new(&parameters)
+                    .unwrap()
+                    .sign(&private_key)
+                    .unwrap();
+                let transaction = EthereumTransaction::<Custom>::new_with_chain_id(&parameters, Goerli::CHAIN_ID)
+                    .unwrap()
+                    .sign(&private_key)
+                    .unwrap();
+                assert_eq!(
+                    expected_transaction.to_transaction_bytes().unwrap(),
+                    transaction.to_transaction_bytes().unwrap()
+                );
+            }
+        }
+
+        #[test]
+        fn from_transaction_bytes() {
+            // BNB Smart Chain
+            let chain_id = 56;
+            let private_key = EthereumPrivateKey::from_str(PRIVATE_KEY).unwrap();
+            for transaction_type in vec![
+                EthereumTransactionType::Legacy,
+                EthereumTransactionType::AccessList(vec![]),
+            ] {
+                let transaction =
+                    EthereumTransaction::<Custom>::new_with_chain_id(&parameters(transaction_type), chain_id).unwrap();
+                let raw_transaction_bytes = transaction.to_transaction_bytes().unwrap();
+                let raw_transaction = EthereumTransaction::<Custom>::from_transaction_bytes(&raw_transaction_bytes);
+                assert_eq!(transaction, raw_transaction.unwrap());
+
+                let signed_transaction_bytes = transaction.sign(&private_key).unwrap().to_transaction_bytes().unwrap();
+                let signed_transaction =
+                    EthereumTransaction::<Custom>::from_transaction_bytes(&signed_transaction_bytes).unwrap();
+                assert_eq!(chain_id, signed_transaction.chain_id());
+                assert_eq!(
+                    Some(&private_key.to_address(&EthereumFormat::Standard).unwrap()),
+                    signed_transaction.sender()
+                );
+                assert!(EthereumTransaction::<Mainnet>::from_transaction_bytes(&signed_transaction_bytes).is_err());
+            }
+        }
+
+        #[test]
+        fn multi_byte_chain_id() {
+            // Avalanche C-Chain, with the chain id 43114 (0xa86a) and a v of 86263 or 86264 (0x0150f7 or 0x0150f8)
+            let chain_id = 43114;
+            let private_key = EthereumPrivateKey::from_str(PRIVATE_KEY).unwrap();
+            let transaction = EthereumTransaction::<Custom>::new_with_chain_id(
+                &parameters(EthereumTransactionType::Legacy),
+                chain_id,
+            )
+            .unwrap();
+
+            let raw_transaction_bytes = transaction.to_transaction_bytes().unwrap();
+            let raw_transaction_rlp = Rlp::new(&raw_transaction_bytes);
+            assert_eq!(vec![0xa8, 0x6a], raw_transaction_rlp.val_at::<Vec<u8>>(6).unwrap());
+            assert_eq!(U256::from(chain_id), raw_transaction_rlp.val_at::<U256>(6).unwrap());
+            assert_eq!(
+                transaction,
+                EthereumTransaction::<Custom>::from_transaction_bytes(&raw_transaction_bytes).unwrap()
+            );
+
+            let signed_transaction_bytes = transaction.sign(&private_key).unwrap().to_transaction_bytes().unwrap();
+            let v: Vec<u8> = Rlp::new(&signed_transaction_bytes).val_at(6).unwrap();
+            assert!(v == vec![0x01, 0x50, 0xf7] || v == vec![0x01, 0x50, 0xf8]);
+
+            let signed_transaction =
+                EthereumTransaction::<Custom>::from_transaction_bytes(&signed_transaction_bytes).unwrap();
+            assert_eq!(chain_id, signed_transaction.chain_id());
+            assert_eq!(
+                Some(&private_key.to_address(&EthereumFormat::Standard).unwrap()),
+                signed_transaction.sender()
+            );
+        }
+
+        #[test]
+        fn new_with_invalid_chain_id() {
+            let parameters = parameters(EthereumTransactionType::Legacy);
+            assert!(EthereumTransaction::<Custom>::new_with_chain_id(&parameters, Custom::CHAIN_ID).is_err());
+            assert!(EthereumTransaction::<Mainnet>::new_with_chain_id(&parameters, 56).is_err());
+            assert!(EthereumTransaction::<Mainnet>::new_with_chain_id(&parameters, Mainnet::CHAIN_ID).is_ok());
+        }
+    }
+
+    mod integer_encoding {
+        use super::*;
+
+        #[test]
+        fn to_bytes_and_from_bytes() {
+            let vectors: Vec<(u64, Vec<u8>)> = vec![
+                (0, vec![]),
+                (1, vec![0x01]),
+                (37, vec![0x25]),
+                (255, vec![0xff]),
+                (256, vec![0x01, 0x00]),
+                (43114, vec![0xa8, 0x6a]),
+                (86264, vec![0x01, 0x50, 0xf8]),
+                (u32::max_value() as u64, vec![0xff; 4]),
+                (u32::max_value() as u64 * 2 + 36, vec![0x02, 0x00, 0x00, 0x00, 0x22]),
+                (u64::max_value(), vec![0xff; 8]),
+            ];
+            for (value, bytes) in vectors {
+                assert_eq!(bytes, to_bytes(value).unwrap());
+                assert_eq!(value, from_bytes(&bytes).unwrap());
+                // The encoding of an integer is the encoding of its bytes in RLP
+                assert_eq!(rlp::encode(&U256::from(value)), rlp::encode(&bytes));
+            }
+        }
+
+        #[test]
+        fn from_bytes_invalid() {
+            assert!(from_bytes(&[0x00]).is_err());
+            assert!(from_bytes(&[0x00, 0x01]).is_err());
+            assert!(from_bytes(&[0x01; 9]).is_err());
+        }
+    }
+
+    mod malleability {
+        use super::*;
+
+        #[test]
+        fn from_transaction_bytes_high_s() {
+            let private_key = EthereumPrivateKey::from_str(&"11".repeat(32)).unwrap();
+            for transaction_type in vec![
+                EthereumTransactionType::Legacy,
+                EthereumTransactionType::Unprotected,
+                EthereumTransactionType::AccessList(vec![]),
+            ] {
+                let parameters = EthereumTransactionParameters {
+                    receiver: EthereumAddress::from_str("0xB5D590A6aBf5E349C1b6C511Bc87CEAbFB3D7e65").unwrap(),
+                    amount: EthereumAmount::from_wei("1").unwrap(),
+                    gas: U256::from_dec_str("21000").unwrap(),
+                    gas_price: EthereumAmount::from_wei("1000000000").unwrap(),
+                    nonce: U256::zero(),
+                    data: vec![],
+                    transaction_type,
+                };
+                let mut transaction = EthereumTransaction::<Mainnet>::new(&parameters)
+                    .unwrap()
+                    .sign(&private_key)
+                    .unwrap();
+                let transaction_bytes = transaction.to_transaction_bytes().unwrap();
+                assert!(EthereumTransaction::<Mainnet>::from_transaction_bytes(&transaction_bytes).is_ok());
+
+                // The signature with the negated s value is a malleable signature of the same transaction
+                let signature = transaction.signature.as_mut().unwrap();
+                let mut signature_bytes = [0u8; 64];
+                signature.r.to_big_endian(&mut signature_bytes[0..32]);
+                signature.s.to_big_endian(&mut signature_bytes[32..64]);
+                let mut high_s_signature = secp256k1::Signature::parse(&signature_bytes);
+                high_s_signature.s = -high_s_signature.s;
+                signature.s = U256::from(&high_s_signature.serialize()[32..64]);
+                let transaction_bytes = transaction.to_transaction_bytes().unwrap();
+                assert!(matches!(
+                    EthereumTransaction::<Mainnet>::from_transaction_bytes(&transaction_bytes),
+                    Err(TransactionError::NonCanonicalSignature)
+                ));
+            }
+        }
+    }
+
+    mod leading_zero_signature {
+        use super::*;
+
+        #[test]
+        fn round_trip() {
+            // The r value of the signature of this transaction begins with a zero byte, which is dropped
+            // from its canonical RLP encoding as an integer
+            let private_key = EthereumPrivateKey::from_str(&"46".repeat(32)).unwrap();
+            let parameters = EthereumTransactionParameters {
+                receiver: EthereumAddress::from_str(&format!("0x{}", "35".repeat(20))).unwrap(),
+                amount: EthereumAmount::from_wei("1").unwrap(),
+                gas: U256::from_dec_str("21000").unwrap(),
+                gas_price: EthereumAmount::from_wei("1").unwrap(),
+                nonce: U256::from(13),
+                data: vec![],
+                transaction_type: EthereumTransactionType::Legacy,
+            };
+            let transaction = EthereumTransaction::<Mainnet>::new(&parameters)
+                .unwrap()
+                .sign(&private_key)
+                .unwrap();
+            let transaction_hex = hex::encode(transaction.to_transaction_bytes().unwrap());
+            assert!(transaction_hex.starts_with("f85e0d01"));
+            assert!(transaction_hex.contains("259fee84"));
+
+            let decoded = EthereumTransaction::<Mainnet>::from_str(&transaction_hex).unwrap();
+            assert_eq!(transaction, decoded);
+            assert_eq!(transaction_hex, hex::encode(decoded.to_transaction_bytes().unwrap()));
+        }
+    }
+
+    mod replay_protection {
+        use super::*;
+        use crate::Custom;
+
+        const PRIVATE_KEY: &str = "51ce358ffdcf208fadfb01a339f3ab715a89045a093777a44784d9e215277c1c";
+
+        fn parameters(transaction_type: EthereumTransactionType) -> EthereumTransactionParameters {
+            EthereumTransactionParameters {
+                receiver: EthereumAddress::from_str("0xB5D590A6aBf5E349C1b6C511Bc87CEAbFB3D7e65").unwrap(),
+                amount: EthereumAmount::from_wei("1000000000000000000").unwrap(),
+                gas: U256::from_dec_str("21000").unwrap(),
+                gas_price: EthereumAmount::from_wei("1000000000").unwrap(),
+                nonce: U256::zero(),
+                data: vec![],
+                transaction_type,
+            }
+        }
+
+        #[test]
+        fn unprotected() {
+            let private_key = EthereumPrivateKey::from_str(PRIVATE_KEY).unwrap();
+            let sender = private_key.to_address(&EthereumFormat::Standard).unwrap();
+            let transaction =
+                EthereumTransaction::<Mainnet>::new(&parameters(EthereumTransactionType::Unprotected)).unwrap();
+
+            let raw_transaction_bytes = transaction.to_transaction_bytes().unwrap();
+            assert_eq!(6, Rlp::new(&raw_transaction_bytes).item_count().unwrap());
+            assert_eq!(
+                transaction,
+                EthereumTransaction::<Mainnet>::from_transaction_bytes(&raw_transaction_bytes).unwrap()
+            );
+
+            let signed_transaction = transaction.sign(&private_key).unwrap();
+            let v = &signed_transaction.signature.as_ref().unwrap().v;
+            assert!(v == &vec![27] || v == &vec![28]);
+
+            // The transaction is valid on every network
+            let signed_transaction_bytes = signed_transaction.to_transaction_bytes().unwrap();
+            let mainnet_transaction =
+                EthereumTransaction::<Mainnet>::from_transaction_bytes(&signed_transaction_bytes).unwrap();
+            let ropsten_transaction =
+                EthereumTransaction::<Ropsten>::from_transaction_bytes(&signed_transaction_bytes).unwrap();
+            let custom_transaction =
+                EthereumTransaction::<Custom>::from_transaction_bytes(&signed_transaction_bytes).unwrap();
+            assert_eq!(signed_transaction, mainnet_transaction);
+            assert_eq!(Some(&sender), ropsten_transaction.sender());
+            assert_eq!(Some(&sender), custom_transaction.sender());
+            assert_eq!(
+                EthereumTransactionType::Unprotected,
+                custom_transaction.parameters().transaction_type
+            );
+        }
+
+        #[test]
+        fn invalid_chain_id() {
+            let private_key = EthereumPrivateKey::from_str(PRIVATE_KEY).unwrap();
+            let transaction =
+                EthereumTransaction::<Ropsten>::new(&parameters(EthereumTransactionType::Legacy)).unwrap();
+
+            let raw_transaction_bytes = transaction.to_transaction_bytes().unwrap();
+            assert!(matches!(
+                EthereumTransaction::<Mainnet>::from_transaction_bytes(&raw_transaction_bytes),
+                Err(TransactionError::InvalidChainId(3))
+            ));
+
+            let signed_transaction_bytes = transaction.sign(&private_key).unwrap().to_transaction_bytes().unwrap();
+            assert!(matches!(
+                EthereumTransaction::<Mainnet>::from_transaction_bytes(&signed_transaction_bytes),
+                Err(TransactionError::InvalidChainId(3))
+            ));
+        }
+
+        #[test]
+        fn invalid_v() {
+            let private_key = EthereumPrivateKey::from_str(PRIVATE_KEY).unwrap();
+            let transaction =
+                EthereumTransaction::<Mainnet>::new(&parameters(EthereumTransactionType::Legacy)).unwrap();
+            let mut signed_transaction = transaction.sign(&private_key).unwrap();
+
+            // A v of 35 or 36 has the placeholder chain id 0
+            for v in vec![vec![], vec![1], vec![26], vec![29], vec![34], vec![35], vec![36]] {
+                signed_transaction.signature.as_mut().unwrap().v = v;
+                let signed_transaction_bytes = signed_transaction.to_transaction_bytes().unwrap();
+                assert!(EthereumTransaction::<Mainnet>::from_transaction_bytes(&signed_transaction_bytes).is_err());
+                assert!(EthereumTransaction::<Custom>::from_transaction_bytes(&signed_transaction_bytes).is_err());
+            }
+        }
+    }
 }
@@ -0,0 +1,299 @@
+//!
+//! URI
+//!
+//! This module contains the `ethereum:` payment request URIs specified in EIP-681
+//! (https://eips.ethereum.org/EIPS/eip-681), for a payment to an address or a contract function call:
+//!
+//! `ethereum:[pay-]<address>[@<chain id>][/<function>][?<parameters>]`
+//!
+//! The parameters are the `value`, `gasLimit` (or `gas`), and `gasPrice` of the transaction in wei,
+//! and the arguments of the function, each keyed by its ABI type, as in
+//! `ethereum:0x89d24a6b4ccb1b6faa2625fe562bdd9a23260359/transfer?address=0x8e23...&uint256=1e18`.
+//!
+
+use crate::abi::{AbiError, AbiFunction, AbiToken, AbiType};
+use crate::address::EthereumAddress;
+use crate::ens::is_ens_name;
+
+use core::{fmt, str::FromStr};
+use ethereum_types::U256;
+
+/// Represents an Ethereum payment request URI (EIP-681)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EthereumUri {
+    /// The receiver of the payment, or the contract of the function call, as an address or an ENS name
+    pub target: String,
+    /// The chain id of the network, or `None` for the current network of the wallet
+    pub chain_id: Option<u64>,
+    /// The name of the contract function to call
+    pub function: Option<String>,
+    /// The arguments of the contract function, with their types
+    pub arguments: Vec<(AbiType, AbiToken)>,
+    /// The value of the transaction (in wei)
+    pub value: Option<U256>,
+    /// The gas limit of the transaction
+    pub gas: Option<U256>,
+    /// The gas price of the transaction (in wei)
+    pub gas_price: Option<U256>,
+}
+
+impl EthereumUri {
+    /// Returns a payment request URI for the specified target address or ENS name.
+    pub fn new(target: &str) -> Result<Self, AbiError> {
+        let target = match is_ens_name(target) {
+            true => target.to_lowercase(),
+            false => EthereumAddress::from_str(target)?.to_string(),
+        };
+        if !is_ens_name(&target) && hex::decode(&target[2..]).is_err() {
+            return Err(AbiError::InvalidValue("address".into(), target));
+        }
+        Ok(Self {
+            target,
+            chain_id: None,
+            function: None,
+            arguments: vec![],
+            value: None,
+            gas: None,
+            gas_price: None,
+        })
+    }
+
+    /// Returns the contract function of the URI, with the types of its arguments,
+    /// or `None` if the URI is a payment to the target.
+    pub fn to_function(&self) -> Option<AbiFunction> {
+        Some(AbiFunction {
+            name: self.function.clone()?,
+            inputs: self.arguments.iter().map(|(abi_type, _)| abi_type.clone()).collect(),
+            outputs: vec![],
+        })
+    }
+
+    /// Returns the data of the transaction, the calldata of the contract function call,
+    /// or no data if the URI is a payment to the target.
+    pub fn to_data(&self) -> Result<Vec<u8>, AbiError> {
+        match self.to_function() {
+            Some(function) => {
+                let arguments = self
+                    .arguments
+                    .iter()
+                    .map(|(_, token)| token.clone())
+                    .collect::<Vec<_>>();
+                function.encode_input(&arguments)
+            }
+            None => Ok(vec![]),
+        }
+    }
+}
+
+impl FromStr for EthereumUri {
+    type Err = AbiError;
+
+    fn from_str(uri: &str) -> Result<Self, Self::Err> {
+        let invalid = || AbiError::InvalidValue("URI".into(), uri.into());
+        let request = match uri.get(..9) {
+            Some(scheme) if scheme.eq_ignore_ascii_case("ethereum:") => &uri[9..],
+            _ => return Err(invalid()),
+        };
+        let (path, query) = match request.find('?') {
+            Some(index) => (&request[..index], Some(&request[index + 1..])),
+            None => (request, None),
+        };
+        let path = path.strip_prefix("pay-").unwrap_or(path);
+        let (target, function) = match path.find('/') {
+            Some(index) => (&path[..index], Some(&path[index + 1..])),
+            None => (path, None),
+        };
+        let (target, chain_id) = match target.find('@') {
+            Some(index) => (
+                &target[..index],
+                Some(target[index + 1..].parse().map_err(|_| invalid())?),
+            ),
+            None => (target, None),
+        };
+
+        let mut uri = Self::new(target)?;
+        uri.chain_id = chain_id;
+        uri.function = match function {
+            Some(function) if !function.is_empty() => Some(function.into()),
+            Some(_) => return Err(invalid()),
+            None => None,
+        };
+
+        for parameter in query
+            .unwrap_or_default()
+            .split('&')
+            .filter(|parameter| !parameter.is_empty())
+        {
+            let (key, value) = match parameter.find('=') {
+                Some(index) => (&parameter[..index], percent_decode(&parameter[index + 1..])?),
+                None => return Err(invalid()),
+            };
+            let number = || match AbiToken::parse(&AbiType::Uint(256), &value)? {
+                AbiToken::Uint(number) => Ok(Some(number)),
+                _ => Err(invalid()),
+            };
+            match key {
+                "value" => uri.value = number()?,
+                "gas" | "gasLimit" => uri.gas = number()?,
+                "gasPrice" => uri.gas_price = number()?,
+                _ => {
+                    let abi_type = AbiType::from_str(key)?;
+                    let token = AbiToken::parse(&abi_type, &value)?;
+                    uri.arguments.push((abi_type, token));
+                }
+            }
+        }
+        if uri.function.is_none() && !uri.arguments.is_empty() {
+            return Err(invalid());
+        }
+        Ok(uri)
+    }
+}
+
+impl fmt::Display for EthereumUri {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ethereum:{}", self.target)?;
+        if let Some(chain_id) = self.chain_id {
+            write!(f, "@{}", chain_id)?;
+        }
+        if let Some(function) = &self.function {
+            write!(f, "/{}", function)?;
+        }
+
+        let mut parameters = self
+            .arguments
+            .iter()
+            .map(|(abi_type, token)| format!("{}={}", abi_type, percent_encode(&to_value(token))))
+            .collect::<Vec<String>>();
+        let numbers = [
+            ("value", self.value),
+            ("gasLimit", self.gas),
+            ("gasPrice", self.gas_price),
+        ];
+        for (key, number) in numbers.iter() {
+            if let Some(number) = number {
+                parameters.push(format!("{}={}", key, number));
+            }
+        }
+        match parameters.is_empty() {
+            true => Ok(()),
+            false => write!(f, "?{}", parameters.join("&")),
+        }
+    }
+}
+
+/// Returns the value of a URI parameter, which is the string value of the token without the quotes of a string.
+fn to_value(token: &AbiToken) -> String {
+    match token {
+        AbiToken::String(string) if !string.contains(',') => string.clone(),
+        token => token.to_string(),
+    }
+}
+
+/// Returns the value with each character other than an unreserved character
+/// or a delimiter allowed in a query parameter value percent-encoded.
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (byte as char).to_string(),
+            b'!' | b'$' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b':' | b'@' | b'/' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Returns the value with its percent-encoded characters decoded.
+fn percent_decode(value: &str) -> Result<String, AbiError> {
+    let invalid = || AbiError::InvalidValue("URI parameter".into(), value.into());
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut input = value.bytes();
+    while let Some(byte) = input.next() {
+        match byte {
+            b'%' => {
+                let hex = [input.next().ok_or_else(invalid)?, input.next().ok_or_else(invalid)?];
+                bytes.extend(hex::decode(hex).map_err(|_| invalid())?);
+            }
+            byte => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        // Examples of EIP-681
+        let uri = EthereumUri::from_str("ethereum:0xfb6916095ca1df60bb79Ce92ce3ea74c37c5d359?value=2.014e18").unwrap();
+        assert_eq!("0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359", uri.target);
+        assert_eq!(Some(U256::from(2_014_000_000_000_000_000u64)), uri.value);
+        assert_eq!(None, uri.to_function());
+        assert!(uri.to_data().unwrap().is_empty());
+
+        let uri = EthereumUri::from_str(
+            "ethereum:0x89d24a6b4ccb1b6faa2625fe562bdd9a23260359/transfer?address=0x8e23ee67d1332ad560396262c48ffbb01f93d052&uint256=1",
+        )
+        .unwrap();
+        assert_eq!(Some("transfer".into()), uri.function);
+        assert_eq!("transfer(address,uint256)", uri.to_function().unwrap().signature());
+        assert_eq!(
+            "a9059cbb\
+             0000000000000000000000008e23ee67d1332ad560396262c48ffbb01f93d052\
+             0000000000000000000000000000000000000000000000000000000000000001",
+            hex::encode(uri.to_data().unwrap())
+        );
+
+        let uri = EthereumUri::from_str("ethereum:pay-vitalik.eth@5?gas=21000&gasPrice=1e9").unwrap();
+        assert_eq!("vitalik.eth", uri.target);
+        assert_eq!(Some(5), uri.chain_id);
+        assert_eq!(Some(U256::from(21000)), uri.gas);
+        assert_eq!(Some(U256::from(1_000_000_000)), uri.gas_price);
+
+        let uri = EthereumUri::from_str("ethereum:vitalik.eth/greet?string=hello%20world").unwrap();
+        assert_eq!(AbiToken::String("hello world".into()), uri.arguments[0].1);
+
+        assert!(EthereumUri::from_str("bitcoin:0xfb6916095ca1df60bb79Ce92ce3ea74c37c5d359").is_err());
+        assert!(EthereumUri::from_str("ethereum:0xfb6916095ca1df60bb79Ce92ce3ea74c37c5d35").is_err());
+        assert!(EthereumUri::from_str("ethereum:0xfb6916095ca1df60bb79Ce92ce3ea74c37c5d359@main").is_err());
+        assert!(EthereumUri::from_str("ethereum:0xfb6916095ca1df60bb79Ce92ce3ea74c37c5d359?value=-1").is_err());
+        assert!(EthereumUri::from_str("ethereum:0xfb6916095ca1df60bb79Ce92ce3ea74c37c5d359?uint256=1").is_err());
+        assert!(EthereumUri::from_str("ethereum:0xfb6916095ca1df60bb79Ce92ce3ea74c37c5d359?amount=1").is_err());
+        assert!(EthereumUri::from_str("ethereum:vitalik.eth/greet?string=%2").is_err());
+    }
+
+    #[test]
+    fn to_string() {
+        let mut uri = EthereumUri::new("0x89d24a6b4ccb1b6faa2625fe562bdd9a23260359").unwrap();
+        uri.chain_id = Some(1);
+        uri.function = Some("transfer".into());
+        uri.arguments = vec![
+            (
+                AbiType::Address,
+                AbiToken::parse(&AbiType::Address, "0x8e23ee67d1332ad560396262c48ffbb01f93d052").unwrap(),
+            ),
+            (AbiType::Uint(256), AbiToken::Uint(U256::exp10(18))),
+        ];
+        uri.gas = Some(U256::from(60000));
+        let expected = "ethereum:0x89d24A6b4CcB1B6fAA2625fE562bDD9a23260359@1/transfer\
+                        ?address=0x8e23Ee67d1332aD560396262C48ffbB01F93D052&uint256=1000000000000000000&gasLimit=60000";
+        assert_eq!(expected, uri.to_string());
+        assert_eq!(uri, EthereumUri::from_str(expected).unwrap());
+
+        let mut uri = EthereumUri::new("Vitalik.eth").unwrap();
+        uri.function = Some("greet".into());
+        uri.arguments = vec![
+            (AbiType::String, AbiToken::String("hello world".into())),
+            (AbiType::String, AbiToken::String("a,b".into())),
+        ];
+        assert_eq!(
+            "ethereum:vitalik.eth/greet?string=hello%20world&string=%22a,b%22",
+            uri.to_string()
+        );
+        assert_eq!(uri, EthereumUri::from_str(&uri.to_string()).unwrap());
+    }
+}
@@ -0,0 +1,212 @@
+//! WebAssembly bindings for generating Ethereum wallets, importing mnemonics, and signing transactions.
+
+use crate::address::EthereumAddress;
+use crate::amount::EthereumAmount;
+use crate::derivation_path::EthereumDerivationPath;
+use crate::extended_private_key::EthereumExtendedPrivateKey;
+use crate::format::EthereumFormat;
+use crate::mnemonic::EthereumMnemonic;
+use crate::network::{Custom, EthereumNetwork, Goerli, Kovan, Mainnet, Rinkeby, Ropsten};
+use crate::private_key::EthereumPrivateKey;
+use crate::transaction::{EthereumTransaction, EthereumTransactionParameters, EthereumTransactionType};
+use crate::wordlist::English;
+use wagyu_model::no_std::{format, String, ToString, Vec};
+use wagyu_model::{
+    ExtendedPrivateKey, Mnemonic, MnemonicCount, MnemonicExtended, OsWalletRng, PrivateKey, PublicKey, Transaction,
+};
+
+use core::{fmt::Display, str::FromStr};
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroize;
+
+/// Represents an Ethereum wallet exposed to JavaScript
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct EthereumWallet {
+    mnemonic: Option<String>,
+    extended_private_key: Option<String>,
+    private_key: String,
+    public_key: String,
+    address: String,
+}
+
+#[wasm_bindgen]
+impl EthereumWallet {
+    /// Returns a randomly-generated wallet.
+    pub fn new() -> Result<EthereumWallet, JsValue> {
+        let private_key = EthereumPrivateKey::new(&mut OsWalletRng::new()).map_err(to_error)?;
+        Self::from_private_key(&private_key)
+    }
+
+    /// Returns a randomly-generated HD wallet for the given English mnemonic word count, password,
+    /// and derivation path.
+    pub fn new_hd(word_count: u8, password: Option<String>, path: &str) -> Result<EthereumWallet, JsValue> {
+        let mnemonic = EthereumMnemonic::<Mainnet, English>::new_with_count(&mut OsWalletRng::new(), word_count)
+            .map_err(to_error)?;
+        Self::from_ethereum_mnemonic(&mnemonic, password.as_deref(), path)
+    }
+
+    /// Returns the HD wallet of the given English mnemonic, password, and derivation path.
+    pub fn from_mnemonic(mnemonic: &str, password: Option<String>, path: &str) -> Result<EthereumWallet, JsValue> {
+        let mnemonic = EthereumMnemonic::<Mainnet, English>::from_phrase(mnemonic).map_err(to_error)?;
+        Self::from_ethereum_mnemonic(&mnemonic, password.as_deref(), path)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn mnemonic(&self) -> Option<String> {
+        self.mnemonic.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn extended_private_key(&self) -> Option<String> {
+        self.extended_private_key.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn private_key(&self) -> String {
+        self.private_key.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn public_key(&self) -> String {
+        self.public_key.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn address(&self) -> String {
+        self.address.clone()
+    }
+}
+
+impl EthereumWallet {
+    fn from_private_key(private_key: &EthereumPrivateKey) -> Result<Self, JsValue> {
+        let public_key = private_key.to_public_key();
+        Ok(Self {
+            mnemonic: None,
+            extended_private_key: None,
+            private_key: private_key.to_string(),
+            public_key: public_key.to_string(),
+            address: public_key
+                .to_address(&EthereumFormat::Standard)
+                .map_err(to_error)?
+                .to_string(),
+        })
+    }
+
+    fn from_ethereum_mnemonic<N: EthereumNetwork>(
+        mnemonic: &EthereumMnemonic<N, English>,
+        password: Option<&str>,
+        path: &str,
+    ) -> Result<Self, JsValue> {
+        let master_extended_private_key = mnemonic.to_extended_private_key(password).map_err(to_error)?;
+        let derivation_path = EthereumDerivationPath::from_str(path).map_err(to_error)?;
+        let extended_private_key: EthereumExtendedPrivateKey<N> =
+            master_extended_private_key.derive(&derivation_path).map_err(to_error)?;
+        let mut wallet = Self::from_private_key(&extended_private_key.to_private_key())?;
+        wallet.mnemonic = Some(mnemonic.to_string());
+        wallet.extended_private_key = Some(extended_private_key.to_string());
+        Ok(wallet)
+    }
+}
+
+impl Drop for EthereumWallet {
+    fn drop(&mut self) {
+        self.mnemonic.zeroize();
+        self.extended_private_key.zeroize();
+        self.private_key.zeroize();
+    }
+}
+
+/// Returns the signed transaction hex of the given chain id, private key, receiver, value (in wei),
+/// gas limit, gas price (in wei), nonce, and optional hex-encoded data.
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn sign_ethereum_transaction(
+    chain_id: u32,
+    private_key: &str,
+    to: &str,
+    value: &str,
+    gas: &str,
+    gas_price: &str,
+    nonce: &str,
+    data: Option<String>,
+) -> Result<String, JsValue> {
+    let data = match data {
+        Some(data) => hex::decode(data.trim_start_matches("0x")).map_err(to_error)?,
+        None => Vec::new(),
+    };
+    let parameters = EthereumTransactionParameters {
+        receiver: EthereumAddress::from_str(to).map_err(to_error)?,
+        amount: EthereumAmount::from_wei(value).map_err(to_error)?,
+        gas: EthereumAmount::u256_from_str(gas).map_err(to_error)?,
+        gas_price: EthereumAmount::from_wei(gas_price).map_err(to_error)?,
+        nonce: EthereumAmount::u256_from_str(nonce).map_err(to_error)?,
+        data,
+        transaction_type: EthereumTransactionType::Legacy,
+    };
+    let private_key = EthereumPrivateKey::from_str(private_key).map_err(to_error)?;
+
+    fn sign<N: EthereumNetwork>(
+        parameters: &EthereumTransactionParameters,
+        private_key: &EthereumPrivateKey,
+        chain_id: u32,
+    ) -> Result<String, JsValue> {
+        let transaction = EthereumTransaction::<N>::new_with_chain_id(parameters, chain_id)
+            .and_then(|transaction| transaction.sign(private_key))
+            .map_err(to_error)?;
+        Ok(format!(
+            "0x{}",
+            hex::encode(&transaction.to_transaction_bytes().map_err(to_error)?)
+        ))
+    }
+
+    match chain_id {
+        Mainnet::CHAIN_ID => sign::<Mainnet>(&parameters, &private_key, chain_id),
+        Ropsten::CHAIN_ID => sign::<Ropsten>(&parameters, &private_key, chain_id),
+        Rinkeby::CHAIN_ID => sign::<Rinkeby>(&parameters, &private_key, chain_id),
+        Goerli::CHAIN_ID => sign::<Goerli>(&parameters, &private_key, chain_id),
+        Kovan::CHAIN_ID => sign::<Kovan>(&parameters, &private_key, chain_id),
+        _ => sign::<Custom>(&parameters, &private_key, chain_id),
+    }
+}
+
+/// Returns a JavaScript error with the message of the given error.
+fn to_error<E: Display>(error: E) -> JsValue {
+    JsValue::from_str(&error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_mnemonic() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let wallet = EthereumWallet::from_mnemonic(mnemonic, None, "m/44'/60'/0'/0/0").unwrap();
+        assert_eq!(Some(mnemonic.to_string()), wallet.mnemonic());
+        assert_eq!(
+            "1ab42cc412b618bdea3a599e3c9bae199ebf030895b039e9db1e30dafb12b727",
+            wallet.private_key()
+        );
+        assert_eq!("0x9858EfFD232B4033E47d90003D41EC34EcaEda94", wallet.address());
+    }
+
+    #[test]
+    fn sign_transaction() {
+        let signed_transaction = sign_ethereum_transaction(
+            Mainnet::CHAIN_ID,
+            "51ce358ffdcf208fadfb01a339f3ab715a89045a093777a44784d9e215277c1c",
+            "0xB5D590A6aBf5E349C1b6C511Bc87CEAbFB3D7e65",
+            "1000000000000000000",
+            "21000",
+            "1000000000",
+            "0",
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            "0xf86b80843b9aca0082520894b5d590a6abf5e349c1b6c511bc87ceabfb3d7e65880de0b6b3a76400008026a0e19742af3c215eca3b0391ab9edbf3cbad726a18c5209388ebdcccda028197baa034ec566c3d7bf23441873205a7abd6f5c37996a1a3889cdb83ecc20b14f9dcc3",
+            signed_transaction
+        );
+    }
+}
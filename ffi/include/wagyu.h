@@ -0,0 +1,125 @@
+#ifndef WAGYU_H
+#define WAGYU_H
+
+/* This file is generated by cbindgen from the wagyu-ffi crate. Do not edit it by hand. */
+
+#include <stdint.h>
+
+// Returns the extended private key at the specified derivation path relative to the extended private key,
+// e.g. "m/0/1" for the child 1 of its child 0.
+//
+// # Safety
+//
+// The extended private key and path must be NUL-terminated strings.
+char *wagyu_bitcoin_extended_private_key_derive(const char *extended_private_key, const char *path);
+
+// Returns the private key of the extended private key, in wallet import format.
+//
+// # Safety
+//
+// The extended private key must be a NUL-terminated string.
+char *wagyu_bitcoin_extended_private_key_to_private_key(const char *extended_private_key);
+
+// Returns a new English mnemonic with the specified word count (12, 15, 18, 21, or 24),
+// for the specified network ("mainnet" or "testnet").
+//
+// # Safety
+//
+// The network must be a NUL-terminated string.
+char *wagyu_bitcoin_mnemonic_new(const char *network, uint8_t word_count);
+
+// Returns the extended private key of the mnemonic at the specified derivation path, e.g. "m/44'/0'/0'/0/0",
+// for the specified network ("mainnet" or "testnet"). The mnemonic may be in any BIP39 language.
+//
+// # Safety
+//
+// The network, mnemonic, and path must be NUL-terminated strings, and the password must be null
+// or a NUL-terminated string.
+char *wagyu_bitcoin_mnemonic_to_extended_private_key(const char *network,
+                                                     const char *mnemonic,
+                                                     const char *password,
+                                                     const char *path);
+
+// Returns the address of the private key, in wallet import format, for the specified address format
+// ("p2pkh", "p2sh_p2wpkh", or "bech32"), or P2PKH if the format is null.
+//
+// # Safety
+//
+// The private key must be a NUL-terminated string, and the format must be null or a NUL-terminated string.
+char *wagyu_bitcoin_private_key_to_address(const char *private_key, const char *format);
+
+// Returns the raw transaction, in hex, with its inputs that spend the specified output signed by the private key.
+// The output is identified by its transaction id, in hex, and index, and holds the specified amount of satoshi
+// at the specified address of the private key. To sign every input of a transaction, call this function once
+// for each output it spends, with the transaction returned by the previous call.
+//
+// # Safety
+//
+// The transaction, transaction id, address, and private key must be NUL-terminated strings.
+char *wagyu_bitcoin_transaction_sign_input(const char *transaction_hex,
+                                           const char *transaction_id,
+                                           uint32_t index,
+                                           uint64_t amount,
+                                           const char *address,
+                                           const char *private_key);
+
+// Returns the extended private key at the specified derivation path relative to the extended private key,
+// e.g. "m/0" for its child 0.
+//
+// # Safety
+//
+// The extended private key and path must be NUL-terminated strings.
+char *wagyu_ethereum_extended_private_key_derive(const char *extended_private_key, const char *path);
+
+// Returns the private key of the extended private key, in hex.
+//
+// # Safety
+//
+// The extended private key must be a NUL-terminated string.
+char *wagyu_ethereum_extended_private_key_to_private_key(const char *extended_private_key);
+
+// Returns a new English mnemonic with the specified word count (12, 15, 18, 21, or 24).
+//
+// # Safety
+//
+// This function has no preconditions; it is `unsafe` only for uniformity with the rest of the library.
+char *wagyu_ethereum_mnemonic_new(uint8_t word_count);
+
+// Returns the extended private key of the mnemonic at the specified derivation path, e.g. "m/44'/60'/0'/0".
+// The mnemonic may be in any BIP39 language.
+//
+// # Safety
+//
+// The mnemonic and path must be NUL-terminated strings, and the password must be null or a NUL-terminated string.
+char *wagyu_ethereum_mnemonic_to_extended_private_key(const char *mnemonic,
+                                                      const char *password,
+                                                      const char *path);
+
+// Returns the checksummed address of the private key, in hex, with or without a "0x" prefix.
+//
+// # Safety
+//
+// The private key must be a NUL-terminated string.
+char *wagyu_ethereum_private_key_to_address(const char *private_key);
+
+// Returns the raw transaction, in hex with a "0x" prefix, signed by the private key for the specified chain id
+// (1 for Mainnet, 3 for Ropsten, 4 for Rinkeby, 5 for Goerli, 42 for Kovan, or the chain id of an EVM-compatible
+// network, e.g. 137 for Polygon). The unsigned transaction and private key are in hex, with or without a "0x" prefix.
+//
+// # Safety
+//
+// The transaction and private key must be NUL-terminated strings.
+char *wagyu_ethereum_transaction_sign(const char *transaction_hex, const char *private_key, uint32_t chain_id);
+
+// Returns the message of the last error on the calling thread, or null if no function has failed on it.
+// The message must be released with `wagyu_string_free`.
+char *wagyu_last_error(void);
+
+// Zeroizes and releases a string returned by this library. Null is ignored.
+//
+// # Safety
+//
+// The string must have been returned by this library, and must not be used or released again.
+void wagyu_string_free(char *string);
+
+#endif /* WAGYU_H */
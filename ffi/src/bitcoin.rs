@@ -0,0 +1,308 @@
+use crate::{to_c_string, to_optional_str, to_str, FFIError};
+use wagyu_bitcoin::{
+    wordlist::*, AnyNetwork, BitcoinAddress, BitcoinAmount, BitcoinDerivationPath, BitcoinExtendedPrivateKey,
+    BitcoinFormat, BitcoinMnemonic, BitcoinNetwork, BitcoinNetworkFn, BitcoinPrivateKey, BitcoinTransaction,
+    BitcoinWordlist, Mainnet, Outpoint, Testnet,
+};
+use wagyu_model::{
+    crypto::hash160, BIP32Path, Bip39Language, ExtendedPrivateKey, Mnemonic, MnemonicCount, MnemonicExtended,
+    PrivateKey, Transaction,
+};
+
+use rand::rngs::OsRng;
+use std::{os::raw::c_char, str::FromStr};
+
+/// Returns the network of the specified name.
+unsafe fn to_network(network: *const c_char) -> Result<AnyNetwork, FFIError> {
+    let network = to_str("network", network)?;
+    AnyNetwork::from_str(network).map_err(|_| FFIError::InvalidNetwork(network.into()))
+}
+
+/// Returns the address format of the specified name, or P2PKH if no name is specified.
+fn to_format(format: Option<&str>) -> Result<BitcoinFormat, FFIError> {
+    match format {
+        None | Some("legacy") | Some("p2pkh") => Ok(BitcoinFormat::P2PKH),
+        Some("segwit") | Some("p2sh_p2wpkh") => Ok(BitcoinFormat::P2SH_P2WPKH),
+        Some("bech32") => Ok(BitcoinFormat::Bech32),
+        Some(format) => Err(FFIError::InvalidFormat(format.into())),
+    }
+}
+
+/// Returns a new English mnemonic with the given word count.
+struct MnemonicNew(u8);
+
+impl BitcoinNetworkFn for MnemonicNew {
+    type Output = Result<String, FFIError>;
+
+    fn call<N: BitcoinNetwork>(self) -> Self::Output {
+        Ok(BitcoinMnemonic::<N, English>::new_with_count(&mut OsRng, self.0)?.to_string())
+    }
+}
+
+/// Returns the extended private key of the mnemonic, in any BIP39 language, at the derivation path.
+struct MnemonicToExtendedPrivateKey<'a> {
+    mnemonic: &'a str,
+    password: Option<&'a str>,
+    path: &'a str,
+}
+
+impl<'a> MnemonicToExtendedPrivateKey<'a> {
+    fn derive<N: BitcoinNetwork, W: BitcoinWordlist>(&self) -> Result<String, FFIError> {
+        let master_extended_private_key =
+            BitcoinMnemonic::<N, W>::from_phrase(self.mnemonic)?.to_extended_private_key(self.password)?;
+        Ok(master_extended_private_key
+            .derive(&BitcoinDerivationPath::from_str(self.path)?)?
+            .to_string())
+    }
+}
+
+impl<'a> BitcoinNetworkFn for MnemonicToExtendedPrivateKey<'a> {
+    type Output = Result<String, FFIError>;
+
+    fn call<N: BitcoinNetwork>(self) -> Self::Output {
+        match Bip39Language::detect_language(self.mnemonic)? {
+            Bip39Language::ChineseSimplified => self.derive::<N, ChineseSimplified>(),
+            Bip39Language::ChineseTraditional => self.derive::<N, ChineseTraditional>(),
+            Bip39Language::English => self.derive::<N, English>(),
+            Bip39Language::French => self.derive::<N, French>(),
+            Bip39Language::Italian => self.derive::<N, Italian>(),
+            Bip39Language::Japanese => self.derive::<N, Japanese>(),
+            Bip39Language::Korean => self.derive::<N, Korean>(),
+            Bip39Language::Spanish => self.derive::<N, Spanish>(),
+        }
+    }
+}
+
+fn extended_private_key_derive<N: BitcoinNetwork>(extended_private_key: &str, path: &str) -> Result<String, FFIError> {
+    let extended_private_key = BitcoinExtendedPrivateKey::<N>::from_str(extended_private_key)?;
+    Ok(extended_private_key
+        .derive_path(&BIP32Path::from_str(path)?)?
+        .to_string())
+}
+
+fn extended_private_key_to_private_key<N: BitcoinNetwork>(extended_private_key: &str) -> Result<String, FFIError> {
+    Ok(BitcoinExtendedPrivateKey::<N>::from_str(extended_private_key)?
+        .to_private_key()
+        .to_string())
+}
+
+fn private_key_to_address<N: BitcoinNetwork>(private_key: &str, format: &BitcoinFormat) -> Result<String, FFIError> {
+    Ok(BitcoinPrivateKey::<N>::from_str(private_key)?
+        .to_address(format)?
+        .to_string())
+}
+
+fn transaction_sign_input<N: BitcoinNetwork>(
+    transaction_hex: &str,
+    transaction_id: &str,
+    index: u32,
+    amount: u64,
+    address: &str,
+    private_key: &str,
+) -> Result<String, FFIError> {
+    let transaction = BitcoinTransaction::<N>::from_transaction_bytes(&hex::decode(transaction_hex)?)?;
+    let private_key = BitcoinPrivateKey::<N>::from_str(private_key)?;
+    let address = BitcoinAddress::<N>::from_str(address)?;
+
+    let redeem_script = match address.format() {
+        BitcoinFormat::P2SH_P2WPKH => {
+            let mut redeem_script = vec![0x00, 0x14];
+            redeem_script.extend(&hash160(
+                &private_key
+                    .to_public_key()
+                    .to_secp256k1_public_key()
+                    .serialize_compressed(),
+            ));
+            Some(redeem_script)
+        }
+        _ => None,
+    };
+
+    let mut reverse_transaction_id = hex::decode(transaction_id)?;
+    reverse_transaction_id.reverse();
+
+    let outpoint = Outpoint::<N>::new(
+        reverse_transaction_id,
+        index,
+        Some(address),
+        Some(BitcoinAmount::from_satoshi(amount as i64)?),
+        redeem_script,
+        None,
+    )?;
+
+    let transaction = transaction.update_outpoint(outpoint).sign(&private_key)?;
+    Ok(hex::encode(transaction.to_transaction_bytes()?))
+}
+
+/// Returns a new English mnemonic with the specified word count (12, 15, 18, 21, or 24),
+/// for the specified network ("mainnet" or "testnet").
+///
+/// # Safety
+///
+/// The network must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wagyu_bitcoin_mnemonic_new(network: *const c_char, word_count: u8) -> *mut c_char {
+    to_c_string(|| to_network(network)?.apply(MnemonicNew(word_count)))
+}
+
+/// Returns the extended private key of the mnemonic at the specified derivation path, e.g. "m/44'/0'/0'/0/0",
+/// for the specified network ("mainnet" or "testnet"). The mnemonic may be in any BIP39 language.
+///
+/// # Safety
+///
+/// The network, mnemonic, and path must be NUL-terminated strings, and the password must be null
+/// or a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wagyu_bitcoin_mnemonic_to_extended_private_key(
+    network: *const c_char,
+    mnemonic: *const c_char,
+    password: *const c_char,
+    path: *const c_char,
+) -> *mut c_char {
+    to_c_string(|| {
+        let network = to_network(network)?;
+        network.apply(MnemonicToExtendedPrivateKey {
+            mnemonic: to_str("mnemonic", mnemonic)?,
+            password: to_optional_str("password", password)?,
+            path: to_str("path", path)?,
+        })
+    })
+}
+
+/// Returns the extended private key at the specified derivation path relative to the extended private key,
+/// e.g. "m/0/1" for the child 1 of its child 0.
+///
+/// # Safety
+///
+/// The extended private key and path must be NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn wagyu_bitcoin_extended_private_key_derive(
+    extended_private_key: *const c_char,
+    path: *const c_char,
+) -> *mut c_char {
+    to_c_string(|| {
+        let extended_private_key = to_str("extended private key", extended_private_key)?;
+        let path = to_str("path", path)?;
+        extended_private_key_derive::<Mainnet>(extended_private_key, path)
+            .or_else(|_| extended_private_key_derive::<Testnet>(extended_private_key, path))
+    })
+}
+
+/// Returns the private key of the extended private key, in wallet import format.
+///
+/// # Safety
+///
+/// The extended private key must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wagyu_bitcoin_extended_private_key_to_private_key(
+    extended_private_key: *const c_char,
+) -> *mut c_char {
+    to_c_string(|| {
+        let extended_private_key = to_str("extended private key", extended_private_key)?;
+        extended_private_key_to_private_key::<Mainnet>(extended_private_key)
+            .or_else(|_| extended_private_key_to_private_key::<Testnet>(extended_private_key))
+    })
+}
+
+/// Returns the address of the private key, in wallet import format, for the specified address format
+/// ("p2pkh", "p2sh_p2wpkh", or "bech32"), or P2PKH if the format is null.
+///
+/// # Safety
+///
+/// The private key must be a NUL-terminated string, and the format must be null or a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wagyu_bitcoin_private_key_to_address(
+    private_key: *const c_char,
+    format: *const c_char,
+) -> *mut c_char {
+    to_c_string(|| {
+        let private_key = to_str("private key", private_key)?;
+        let format = to_format(to_optional_str("format", format)?)?;
+        private_key_to_address::<Mainnet>(private_key, &format)
+            .or_else(|_| private_key_to_address::<Testnet>(private_key, &format))
+    })
+}
+
+/// Returns the raw transaction, in hex, with its inputs that spend the specified output signed by the private key.
+/// The output is identified by its transaction id, in hex, and index, and holds the specified amount of satoshi
+/// at the specified address of the private key. To sign every input of a transaction, call this function once
+/// for each output it spends, with the transaction returned by the previous call.
+///
+/// # Safety
+///
+/// The transaction, transaction id, address, and private key must be NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn wagyu_bitcoin_transaction_sign_input(
+    transaction_hex: *const c_char,
+    transaction_id: *const c_char,
+    index: u32,
+    amount: u64,
+    address: *const c_char,
+    private_key: *const c_char,
+) -> *mut c_char {
+    to_c_string(|| {
+        let transaction_hex = to_str("transaction", transaction_hex)?;
+        let transaction_id = to_str("transaction id", transaction_id)?;
+        let address = to_str("address", address)?;
+        let private_key = to_str("private key", private_key)?;
+        match BitcoinPrivateKey::<Mainnet>::from_str(private_key) {
+            Ok(_) => {
+                transaction_sign_input::<Mainnet>(transaction_hex, transaction_id, index, amount, address, private_key)
+            }
+            Err(_) => {
+                transaction_sign_input::<Testnet>(transaction_hex, transaction_id, index, amount, address, private_key)
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::take;
+
+    use std::{ffi::CString, ptr};
+
+    #[test]
+    fn hd_wallet() {
+        let network = CString::new("mainnet").unwrap();
+        let mnemonic = take(unsafe { wagyu_bitcoin_mnemonic_new(network.as_ptr(), 24) }).unwrap();
+        assert_eq!(24, mnemonic.split_whitespace().count());
+        assert!(take(unsafe { wagyu_bitcoin_mnemonic_new(network.as_ptr(), 13) }).is_err());
+
+        let mnemonic = CString::new(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        )
+        .unwrap();
+        let path = CString::new("m/84'/0'/0'").unwrap();
+        let account = take(unsafe {
+            wagyu_bitcoin_mnemonic_to_extended_private_key(
+                network.as_ptr(),
+                mnemonic.as_ptr(),
+                ptr::null(),
+                path.as_ptr(),
+            )
+        })
+        .unwrap();
+
+        let account = CString::new(account).unwrap();
+        let path = CString::new("m/0/0").unwrap();
+        let extended_private_key =
+            take(unsafe { wagyu_bitcoin_extended_private_key_derive(account.as_ptr(), path.as_ptr()) }).unwrap();
+
+        let extended_private_key = CString::new(extended_private_key).unwrap();
+        let private_key =
+            take(unsafe { wagyu_bitcoin_extended_private_key_to_private_key(extended_private_key.as_ptr()) }).unwrap();
+        assert_eq!("KyZpNDKnfs94vbrwhJneDi77V6jF64PWPF8x5cdJb8ifgg2DUc9d", private_key);
+
+        // BIP84 test vector of m/84'/0'/0'/0/0
+        let private_key = CString::new(private_key).unwrap();
+        let format = CString::new("bech32").unwrap();
+        let address = take(unsafe { wagyu_bitcoin_private_key_to_address(private_key.as_ptr(), format.as_ptr()) });
+        assert_eq!(Ok("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu".to_string()), address);
+
+        let format = CString::new("p2tr").unwrap();
+        let address = take(unsafe { wagyu_bitcoin_private_key_to_address(private_key.as_ptr(), format.as_ptr()) });
+        assert_eq!(Err("unsupported format: p2tr".to_string()), address);
+    }
+}
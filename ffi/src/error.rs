@@ -0,0 +1,94 @@
+use wagyu_model::{
+    AddressError, AmountError, DerivationPathError, ExtendedPrivateKeyError, MnemonicError, PrivateKeyError,
+    TransactionError, WordlistError,
+};
+
+#[derive(Debug, Error)]
+pub enum FFIError {
+    #[error("{0}")]
+    AddressError(AddressError),
+
+    #[error("{0}")]
+    AmountError(AmountError),
+
+    #[error("{0}: {1}")]
+    Crate(&'static str, String),
+
+    #[error("{0}")]
+    DerivationPathError(DerivationPathError),
+
+    #[error("{0}")]
+    ExtendedPrivateKeyError(ExtendedPrivateKeyError),
+
+    #[error("invalid argument: {0}")]
+    InvalidArgument(String),
+
+    #[error("unsupported format: {0}")]
+    InvalidFormat(String),
+
+    #[error("unsupported network: {0}")]
+    InvalidNetwork(String),
+
+    #[error("{0}")]
+    MnemonicError(MnemonicError),
+
+    #[error("{0}")]
+    PrivateKeyError(PrivateKeyError),
+
+    #[error("{0}")]
+    TransactionError(TransactionError),
+}
+
+impl From<AddressError> for FFIError {
+    fn from(error: AddressError) -> Self {
+        FFIError::AddressError(error)
+    }
+}
+
+impl From<AmountError> for FFIError {
+    fn from(error: AmountError) -> Self {
+        FFIError::AmountError(error)
+    }
+}
+
+impl From<DerivationPathError> for FFIError {
+    fn from(error: DerivationPathError) -> Self {
+        FFIError::DerivationPathError(error)
+    }
+}
+
+impl From<ExtendedPrivateKeyError> for FFIError {
+    fn from(error: ExtendedPrivateKeyError) -> Self {
+        FFIError::ExtendedPrivateKeyError(error)
+    }
+}
+
+impl From<hex::FromHexError> for FFIError {
+    fn from(error: hex::FromHexError) -> Self {
+        FFIError::Crate("hex", format!("{:?}", error))
+    }
+}
+
+impl From<MnemonicError> for FFIError {
+    fn from(error: MnemonicError) -> Self {
+        FFIError::MnemonicError(error)
+    }
+}
+
+impl From<PrivateKeyError> for FFIError {
+    fn from(error: PrivateKeyError) -> Self {
+        FFIError::PrivateKeyError(error)
+    }
+}
+
+impl From<TransactionError> for FFIError {
+    fn from(error: TransactionError) -> Self {
+        FFIError::TransactionError(error)
+    }
+}
+
+impl From<WordlistError> for FFIError {
+    fn from(error: WordlistError) -> Self {
+        FFIError::MnemonicError(error.into())
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{to_c_string, to_optional_str, to_str, FFIError};
+use wagyu_ethereum::{
+    wordlist::*, AnyNetwork, Custom, EthereumDerivationPath, EthereumExtendedPrivateKey, EthereumFormat,
+    EthereumMnemonic, EthereumNetwork, EthereumNetworkFn, EthereumPrivateKey, EthereumTransaction, EthereumWordlist,
+    Mainnet,
+};
+use wagyu_model::{
+    BIP32Path, Bip39Language, ExtendedPrivateKey, Mnemonic, MnemonicCount, MnemonicExtended, PrivateKey, Transaction,
+};
+
+use rand::rngs::OsRng;
+use std::{os::raw::c_char, str::FromStr};
+
+fn mnemonic_to_extended_private_key<W: EthereumWordlist>(
+    mnemonic: &str,
+    password: Option<&str>,
+    path: &str,
+) -> Result<String, FFIError> {
+    let master_extended_private_key =
+        EthereumMnemonic::<Mainnet, W>::from_phrase(mnemonic)?.to_extended_private_key(password)?;
+    Ok(master_extended_private_key
+        .derive(&EthereumDerivationPath::<Mainnet>::from_str(path)?)?
+        .to_string())
+}
+
+/// Returns the raw transaction, in hex, signed by the private key, in hex, for the chain id.
+struct TransactionSign<'a> {
+    transaction_hex: &'a str,
+    private_key: &'a str,
+    chain_id: u32,
+}
+
+impl<'a> EthereumNetworkFn for TransactionSign<'a> {
+    type Output = Result<String, FFIError>;
+
+    fn call<N: EthereumNetwork>(self) -> Self::Output {
+        let transaction_hex = self.transaction_hex.trim_start_matches("0x");
+        let transaction = EthereumTransaction::<N>::from_transaction_bytes(&hex::decode(transaction_hex)?)?;
+        if transaction.chain_id() != self.chain_id {
+            return Err(FFIError::InvalidArgument(format!(
+                "transaction is for chain id {}",
+                transaction.chain_id()
+            )));
+        }
+        let private_key = EthereumPrivateKey::from_str(self.private_key.trim_start_matches("0x"))?;
+        Ok(transaction.sign(&private_key)?.to_string())
+    }
+}
+
+/// Returns a new English mnemonic with the specified word count (12, 15, 18, 21, or 24).
+///
+/// # Safety
+///
+/// This function has no preconditions; it is `unsafe` only for uniformity with the rest of the library.
+#[no_mangle]
+pub unsafe extern "C" fn wagyu_ethereum_mnemonic_new(word_count: u8) -> *mut c_char {
+    to_c_string(|| Ok(EthereumMnemonic::<Mainnet, English>::new_with_count(&mut OsRng, word_count)?.to_string()))
+}
+
+/// Returns the extended private key of the mnemonic at the specified derivation path, e.g. "m/44'/60'/0'/0".
+/// The mnemonic may be in any BIP39 language.
+///
+/// # Safety
+///
+/// The mnemonic and path must be NUL-terminated strings, and the password must be null or a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wagyu_ethereum_mnemonic_to_extended_private_key(
+    mnemonic: *const c_char,
+    password: *const c_char,
+    path: *const c_char,
+) -> *mut c_char {
+    to_c_string(|| {
+        let mnemonic = to_str("mnemonic", mnemonic)?;
+        let password = to_optional_str("password", password)?;
+        let path = to_str("path", path)?;
+        match Bip39Language::detect_language(mnemonic)? {
+            Bip39Language::ChineseSimplified => {
+                mnemonic_to_extended_private_key::<ChineseSimplified>(mnemonic, password, path)
+            }
+            Bip39Language::ChineseTraditional => {
+                mnemonic_to_extended_private_key::<ChineseTraditional>(mnemonic, password, path)
+            }
+            Bip39Language::English => mnemonic_to_extended_private_key::<English>(mnemonic, password, path),
+            Bip39Language::French => mnemonic_to_extended_private_key::<French>(mnemonic, password, path),
+            Bip39Language::Italian => mnemonic_to_extended_private_key::<Italian>(mnemonic, password, path),
+            Bip39Language::Japanese => mnemonic_to_extended_private_key::<Japanese>(mnemonic, password, path),
+            Bip39Language::Korean => mnemonic_to_extended_private_key::<Korean>(mnemonic, password, path),
+            Bip39Language::Spanish => mnemonic_to_extended_private_key::<Spanish>(mnemonic, password, path),
+        }
+    })
+}
+
+/// Returns the extended private key at the specified derivation path relative to the extended private key,
+/// e.g. "m/0" for its child 0.
+///
+/// # Safety
+///
+/// The extended private key and path must be NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn wagyu_ethereum_extended_private_key_derive(
+    extended_private_key: *const c_char,
+    path: *const c_char,
+) -> *mut c_char {
+    to_c_string(|| {
+        let extended_private_key =
+            EthereumExtendedPrivateKey::<Mainnet>::from_str(to_str("extended private key", extended_private_key)?)?;
+        let path = BIP32Path::from_str(to_str("path", path)?)?;
+        Ok(extended_private_key.derive_path(&path)?.to_string())
+    })
+}
+
+/// Returns the private key of the extended private key, in hex.
+///
+/// # Safety
+///
+/// The extended private key must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wagyu_ethereum_extended_private_key_to_private_key(
+    extended_private_key: *const c_char,
+) -> *mut c_char {
+    to_c_string(|| {
+        let extended_private_key =
+            EthereumExtendedPrivateKey::<Mainnet>::from_str(to_str("extended private key", extended_private_key)?)?;
+        Ok(extended_private_key.to_private_key().to_string())
+    })
+}
+
+/// Returns the checksummed address of the private key, in hex, with or without a "0x" prefix.
+///
+/// # Safety
+///
+/// The private key must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wagyu_ethereum_private_key_to_address(private_key: *const c_char) -> *mut c_char {
+    to_c_string(|| {
+        let private_key = EthereumPrivateKey::from_str(to_str("private key", private_key)?.trim_start_matches("0x"))?;
+        Ok(private_key.to_address(&EthereumFormat::Standard)?.to_string())
+    })
+}
+
+/// Returns the raw transaction, in hex with a "0x" prefix, signed by the private key for the specified chain id
+/// (1 for Mainnet, 3 for Ropsten, 4 for Rinkeby, 5 for Goerli, 42 for Kovan, or the chain id of an EVM-compatible
+/// network, e.g. 137 for Polygon). The unsigned transaction and private key are in hex, with or without a "0x" prefix.
+///
+/// # Safety
+///
+/// The transaction and private key must be NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn wagyu_ethereum_transaction_sign(
+    transaction_hex: *const c_char,
+    private_key: *const c_char,
+    chain_id: u32,
+) -> *mut c_char {
+    to_c_string(|| {
+        let transaction_sign = TransactionSign {
+            transaction_hex: to_str("transaction", transaction_hex)?,
+            private_key: to_str("private key", private_key)?,
+            chain_id,
+        };
+        match AnyNetwork::from_chain_id(chain_id) {
+            Ok(network) => network.apply(transaction_sign),
+            Err(_) => transaction_sign.call::<Custom>(),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::take;
+    use wagyu_ethereum::{EthereumAddress, EthereumAmount, EthereumTransactionParameters, EthereumTransactionType};
+
+    use ethereum_types::U256;
+    use std::{ffi::CString, ptr};
+
+    #[test]
+    fn hd_wallet() {
+        let mnemonic = take(unsafe { wagyu_ethereum_mnemonic_new(12) }).unwrap();
+        assert_eq!(12, mnemonic.split_whitespace().count());
+
+        let mnemonic = CString::new(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        )
+        .unwrap();
+        let path = CString::new("m/44'/60'/0'/0").unwrap();
+        let account = take(unsafe {
+            wagyu_ethereum_mnemonic_to_extended_private_key(mnemonic.as_ptr(), ptr::null(), path.as_ptr())
+        })
+        .unwrap();
+
+        let account = CString::new(account).unwrap();
+        let path = CString::new("m/0").unwrap();
+        let extended_private_key =
+            take(unsafe { wagyu_ethereum_extended_private_key_derive(account.as_ptr(), path.as_ptr()) }).unwrap();
+
+        let extended_private_key = CString::new(extended_private_key).unwrap();
+        let private_key =
+            take(unsafe { wagyu_ethereum_extended_private_key_to_private_key(extended_private_key.as_ptr()) }).unwrap();
+
+        let private_key = CString::new(format!("0x{}", private_key)).unwrap();
+        let address = take(unsafe { wagyu_ethereum_private_key_to_address(private_key.as_ptr()) });
+        assert_eq!(Ok("0x9858EfFD232B4033E47d90003D41EC34EcaEda94".to_string()), address);
+    }
+
+    #[test]
+    fn transaction_sign() {
+        let parameters = EthereumTransactionParameters {
+            receiver: EthereumAddress::from_str("0xB5D590A6aBf5E349C1b6C511Bc87CEAbFB3D7e65").unwrap(),
+            amount: EthereumAmount::from_wei("1000000000000000000").unwrap(),
+            gas: U256::from_dec_str("21000").unwrap(),
+            gas_price: EthereumAmount::from_wei("1000000000").unwrap(),
+            nonce: U256::from_dec_str("0").unwrap(),
+            data: vec![],
+            transaction_type: EthereumTransactionType::Legacy,
+        };
+        let transaction = EthereumTransaction::<Mainnet>::new(&parameters).unwrap();
+        let transaction = CString::new(hex::encode(transaction.to_transaction_bytes().unwrap())).unwrap();
+        let private_key = CString::new("51ce358ffdcf208fadfb01a339f3ab715a89045a093777a44784d9e215277c1c").unwrap();
+
+        let signed_transaction =
+            take(unsafe { wagyu_ethereum_transaction_sign(transaction.as_ptr(), private_key.as_ptr(), 1) });
+        assert_eq!(
+            Ok("0xf86b80843b9aca0082520894b5d590a6abf5e349c1b6c511bc87ceabfb3d7e65880de0b6b3a76400008026a0e19742af3c215eca3b0391ab9edbf3cbad726a18c5209388ebdcccda028197baa034ec566c3d7bf23441873205a7abd6f5c37996a1a3889cdb83ecc20b14f9dcc3".to_string()),
+            signed_transaction
+        );
+
+        let signed_transaction =
+            take(unsafe { wagyu_ethereum_transaction_sign(transaction.as_ptr(), private_key.as_ptr(), 56) });
+        assert_eq!(
+            Err("invalid argument: transaction is for chain id 1".to_string()),
+            signed_transaction
+        );
+
+        let transaction = EthereumTransaction::<Custom>::new_with_chain_id(&parameters, 56).unwrap();
+        let transaction = CString::new(hex::encode(transaction.to_transaction_bytes().unwrap())).unwrap();
+        let signed_transaction =
+            take(unsafe { wagyu_ethereum_transaction_sign(transaction.as_ptr(), private_key.as_ptr(), 56) }).unwrap();
+        let signed_transaction = EthereumTransaction::<Custom>::from_str(&signed_transaction[2..]).unwrap();
+        assert_eq!(56, signed_transaction.chain_id());
+        assert!(signed_transaction.sender().is_some());
+    }
+}
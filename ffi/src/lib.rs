@@ -0,0 +1,138 @@
+//! # FFI
+//!
+//! C bindings for generating wallets and signing transactions with wagyu.
+//!
+//! Every function that returns a string returns a new, NUL-terminated string owned by the caller,
+//! which must be released with `wagyu_string_free`. On failure, a function returns null and records
+//! an error message for the calling thread, which is returned by `wagyu_last_error`.
+//!
+#![warn(unused_extern_crates, dead_code)]
+
+#[macro_use]
+extern crate thiserror;
+
+pub mod bitcoin;
+pub use self::bitcoin::*;
+
+pub mod error;
+pub use self::error::*;
+
+pub mod ethereum;
+pub use self::ethereum::*;
+
+use std::{
+    cell::RefCell,
+    ffi::{CStr, CString},
+    os::raw::c_char,
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr,
+};
+use zeroize::Zeroize;
+
+thread_local! {
+    /// The message of the last error on the thread
+    static LAST_ERROR: RefCell<Option<String>> = RefCell::new(None);
+}
+
+/// Returns the message of the last error on the calling thread, or null if no function has failed on it.
+/// The message must be released with `wagyu_string_free`.
+#[no_mangle]
+pub extern "C" fn wagyu_last_error() -> *mut c_char {
+    LAST_ERROR.with(|error| match error.borrow().as_ref() {
+        Some(message) => CString::new(message.replace('\0', "")).map_or(ptr::null_mut(), CString::into_raw),
+        None => ptr::null_mut(),
+    })
+}
+
+/// Zeroizes and releases a string returned by this library. Null is ignored.
+///
+/// # Safety
+///
+/// The string must have been returned by this library, and must not be used or released again.
+#[no_mangle]
+pub unsafe extern "C" fn wagyu_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        CString::from_raw(string).into_bytes().zeroize();
+    }
+}
+
+/// Returns the string of the specified argument, or errors if it is null or is not UTF-8.
+///
+/// # Safety
+///
+/// The argument must be null or a NUL-terminated string that outlives the returned string.
+pub(crate) unsafe fn to_str<'a>(name: &str, argument: *const c_char) -> Result<&'a str, FFIError> {
+    match argument.is_null() {
+        true => Err(FFIError::InvalidArgument(format!("{} is null", name))),
+        false => CStr::from_ptr(argument)
+            .to_str()
+            .map_err(|_| FFIError::InvalidArgument(format!("{} is not UTF-8", name))),
+    }
+}
+
+/// Returns the string of the specified optional argument, or `None` if it is null.
+///
+/// # Safety
+///
+/// The argument must be null or a NUL-terminated string that outlives the returned string.
+pub(crate) unsafe fn to_optional_str<'a>(name: &str, argument: *const c_char) -> Result<Option<&'a str>, FFIError> {
+    match argument.is_null() {
+        true => Ok(None),
+        false => to_str(name, argument).map(Some),
+    }
+}
+
+/// Returns the string returned by the specified function as a string owned by the caller,
+/// or returns null and records the error of the function, or its panic, for the calling thread.
+pub(crate) fn to_c_string<F: FnOnce() -> Result<String, FFIError>>(function: F) -> *mut c_char {
+    let message = match catch_unwind(AssertUnwindSafe(function)) {
+        Ok(Ok(string)) => match CString::new(string) {
+            Ok(string) => return string.into_raw(),
+            Err(error) => {
+                error.into_vec().zeroize();
+                "invalid string: contains a NUL byte".to_string()
+            }
+        },
+        Ok(Err(error)) => error.to_string(),
+        Err(_) => "internal error: wagyu panicked".to_string(),
+    };
+    LAST_ERROR.with(|error| *error.borrow_mut() = Some(message));
+    ptr::null_mut()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Returns the string owned by the caller as a Rust string, after releasing it, or the last error.
+    pub(crate) fn take(string: *mut c_char) -> Result<String, String> {
+        match string.is_null() {
+            true => {
+                let error = wagyu_last_error();
+                let message = unsafe { CStr::from_ptr(error) }.to_str().unwrap().to_string();
+                unsafe { wagyu_string_free(error) };
+                Err(message)
+            }
+            false => {
+                let result = unsafe { CStr::from_ptr(string) }.to_str().unwrap().to_string();
+                unsafe { wagyu_string_free(string) };
+                Ok(result)
+            }
+        }
+    }
+
+    #[test]
+    fn last_error() {
+        assert_eq!(
+            Err("invalid argument: value is null".to_string()),
+            take(to_c_string(|| unsafe {
+                to_str("value", ptr::null()).map(str::to_string)
+            }))
+        );
+        assert_eq!(Ok("wagyu".to_string()), take(to_c_string(|| Ok("wagyu".to_string()))));
+        assert_eq!(
+            Err("internal error: wagyu panicked".to_string()),
+            take(to_c_string(|| panic!("unwind")))
+        );
+    }
+}
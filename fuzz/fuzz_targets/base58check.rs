@@ -0,0 +1,10 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use wagyu_model::encoding::base58check;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = base58check::verify(data);
+    if let Ok(string) = std::str::from_utf8(data) {
+        let _ = base58check::decode(string);
+    }
+});
@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use wagyu_model::encoding::{bech32_decode, BECH32_MAX_LENGTH};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(string) = std::str::from_utf8(data) {
+        let _ = bech32_decode(string, BECH32_MAX_LENGTH);
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use std::str::FromStr;
+use wagyu_bitcoin::{BitcoinAddress, Mainnet, Testnet};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(string) = std::str::from_utf8(data) {
+        let _ = BitcoinAddress::<Mainnet>::from_str(string);
+        let _ = BitcoinAddress::<Testnet>::from_str(string);
+    }
+});
@@ -0,0 +1,8 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use wagyu_bitcoin::{BitcoinTransaction, Mainnet};
+use wagyu_model::Transaction;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = BitcoinTransaction::<Mainnet>::from_transaction_bytes(&data.to_vec());
+});
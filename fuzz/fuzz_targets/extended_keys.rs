@@ -0,0 +1,14 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use std::str::FromStr;
+use wagyu_bitcoin::{BitcoinExtendedPrivateKey, BitcoinExtendedPublicKey, Mainnet as BitcoinMainnet};
+use wagyu_ethereum::{EthereumExtendedPrivateKey, EthereumExtendedPublicKey, Mainnet as EthereumMainnet};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(string) = std::str::from_utf8(data) {
+        let _ = BitcoinExtendedPrivateKey::<BitcoinMainnet>::from_str(string);
+        let _ = BitcoinExtendedPublicKey::<BitcoinMainnet>::from_str(string);
+        let _ = EthereumExtendedPrivateKey::<EthereumMainnet>::from_str(string);
+        let _ = EthereumExtendedPublicKey::<EthereumMainnet>::from_str(string);
+    }
+});
@@ -0,0 +1,10 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use std::str::FromStr;
+use wagyu_monero::{Mainnet, MoneroAddress};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(string) = std::str::from_utf8(data) {
+        let _ = MoneroAddress::<Mainnet>::from_str(string);
+    }
+});
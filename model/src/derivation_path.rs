@@ -1,7 +1,9 @@
 use crate::no_std::*;
 use core::{
+    convert::TryFrom,
     fmt,
     fmt::{Debug, Display},
+    slice,
     str::FromStr,
 };
 
@@ -12,38 +14,54 @@ pub trait DerivationPath: Clone + Debug + Display + FromStr + Send + Sync + 'sta
 
     /// Returns a derivation path given the child index vector.
     fn from_vec(path: &Vec<ChildIndex>) -> Result<Self, DerivationPathError>;
+
+    /// Returns a child index vector given the derivation path, or errors if any index is not hardened.
+    /// Curves without public child derivation, such as ed25519 in SLIP-0010, only support hardened paths.
+    fn to_hardened_vec(&self) -> Result<Vec<ChildIndex>, DerivationPathError> {
+        let path = self.to_vec()?;
+        match path.iter().all(ChildIndex::is_hardened) {
+            true => Ok(path),
+            false => Err(DerivationPathError::ExpectedHardenedPath),
+        }
+    }
+
+    /// Returns a non-hardened derivation path given the derivation path, or errors if any index is hardened.
+    fn to_non_hardened(&self) -> Result<NonHardenedDerivationPath, DerivationPathError> {
+        NonHardenedDerivationPath::new(self.to_vec()?)
+    }
 }
 
-#[derive(Debug, Fail, PartialEq, Eq)]
+#[derive(Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum DerivationPathError {
-    #[fail(display = "expected BIP32 path")]
+    #[error("expected BIP32 path")]
     ExpectedBIP32Path,
 
-    #[fail(display = "expected BIP44 path")]
+    #[error("expected BIP44 path")]
     ExpectedBIP44Path,
 
-    #[fail(display = "expected BIP49 path")]
+    #[error("expected BIP49 path")]
     ExpectedBIP49Path,
 
-    #[fail(display = "expected valid Ethereum derivation path")]
+    #[error("expected valid Ethereum derivation path")]
     ExpectedValidEthereumDerivationPath,
 
-    #[fail(display = "expected ZIP32 path")]
+    #[error("expected ZIP32 path")]
     ExpectedZIP32Path,
 
-    #[fail(display = "expected hardened path")]
+    #[error("expected hardened path")]
     ExpectedHardenedPath,
 
-    #[fail(display = "expected normal path")]
+    #[error("expected normal path")]
     ExpectedNormalPath,
 
-    #[fail(display = "invalid child number: {}", _0)]
+    #[error("invalid child number: {0}")]
     InvalidChildNumber(u32),
 
-    #[fail(display = "invalid child number format")]
+    #[error("invalid child number format")]
     InvalidChildNumberFormat,
 
-    #[fail(display = "invalid derivation path: {}", _0)]
+    #[error("invalid derivation path: {0}")]
     InvalidDerivationPath(String),
 }
 
@@ -140,6 +158,188 @@ impl fmt::Display for ChildIndex {
     }
 }
 
+/// Represents a derivation path of only non-hardened indices, as used for public child derivation
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NonHardenedDerivationPath(Vec<ChildIndex>);
+
+impl NonHardenedDerivationPath {
+    /// Returns a non-hardened derivation path given the child index vector, or errors if any index is hardened.
+    pub fn new(path: Vec<ChildIndex>) -> Result<Self, DerivationPathError> {
+        match path.iter().all(ChildIndex::is_normal) {
+            true => Ok(Self(path)),
+            false => Err(DerivationPathError::ExpectedNormalPath),
+        }
+    }
+
+    /// Returns the child index vector of the derivation path.
+    pub fn to_vec(&self) -> Vec<ChildIndex> {
+        self.0.clone()
+    }
+
+    /// Returns the number of child indices in the derivation path.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the derivation path has no child indices.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl TryFrom<Vec<ChildIndex>> for NonHardenedDerivationPath {
+    type Error = DerivationPathError;
+
+    fn try_from(path: Vec<ChildIndex>) -> Result<Self, Self::Error> {
+        Self::new(path)
+    }
+}
+
+impl FromStr for NonHardenedDerivationPath {
+    type Err = DerivationPathError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        Self::new(BIP32Path::from_str(path)?.0)
+    }
+}
+
+impl fmt::Display for NonHardenedDerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&BIP32Path(self.0.clone()), f)
+    }
+}
+
+/// Represents a generic BIP32 derivation path, e.g. `m/44'/0'/0'/0/0`
+///
+/// This is the canonical path notation shared by every currency: a leading `m`, followed by
+/// up to 255 child indices separated by `/`, where a hardened index ends in `'` or `h`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BIP32Path(Vec<ChildIndex>);
+
+impl BIP32Path {
+    /// Returns the BIP44 path `m/44'/{coin}'/{account}'/{change}/{index}`, or errors if any index is not within [0, 2^31 - 1].
+    pub fn bip44(coin: u32, account: u32, change: u32, index: u32) -> Result<Self, DerivationPathError> {
+        Ok(Self(vec![
+            ChildIndex::Hardened(44),
+            ChildIndex::hardened(coin)?,
+            ChildIndex::hardened(account)?,
+            ChildIndex::normal(change)?,
+            ChildIndex::normal(index)?,
+        ]))
+    }
+
+    /// Returns the derivation path of the given child, or errors if the maximum depth is reached.
+    pub fn child(&self, index: ChildIndex) -> Result<Self, DerivationPathError> {
+        self.extend(Some(index))
+    }
+
+    /// Returns the derivation path of the parent, or `None` for the root path `m`.
+    pub fn parent(&self) -> Option<Self> {
+        self.0.split_last().map(|(_, parent)| Self(parent.to_vec()))
+    }
+
+    /// Returns the derivation path followed by the given child indices, or errors if the maximum depth is reached.
+    pub fn extend<I: IntoIterator<Item = ChildIndex>>(&self, indices: I) -> Result<Self, DerivationPathError> {
+        let mut path = self.0.clone();
+        path.extend(indices);
+        Self::from_vec(&path)
+    }
+
+    /// Returns an iterator over the child indices of the derivation path.
+    pub fn iter(&self) -> slice::Iter<'_, ChildIndex> {
+        self.0.iter()
+    }
+
+    /// Returns the number of child indices in the derivation path.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the derivation path has no child indices.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl DerivationPath for BIP32Path {
+    /// Returns a child index vector given the derivation path.
+    fn to_vec(&self) -> Result<Vec<ChildIndex>, DerivationPathError> {
+        Ok(self.0.clone())
+    }
+
+    /// Returns a derivation path given the child index vector.
+    fn from_vec(path: &Vec<ChildIndex>) -> Result<Self, DerivationPathError> {
+        match path.len() < 256 {
+            true => Ok(Self(path.clone())),
+            false => Err(DerivationPathError::ExpectedBIP32Path),
+        }
+    }
+}
+
+impl TryFrom<Vec<ChildIndex>> for BIP32Path {
+    type Error = DerivationPathError;
+
+    fn try_from(path: Vec<ChildIndex>) -> Result<Self, Self::Error> {
+        Self::from_vec(&path)
+    }
+}
+
+impl<'a> TryFrom<&'a [ChildIndex]> for BIP32Path {
+    type Error = DerivationPathError;
+
+    fn try_from(path: &'a [ChildIndex]) -> Result<Self, Self::Error> {
+        Self::from_vec(&path.to_vec())
+    }
+}
+
+impl From<NonHardenedDerivationPath> for BIP32Path {
+    fn from(path: NonHardenedDerivationPath) -> Self {
+        Self(path.0)
+    }
+}
+
+impl<'a> IntoIterator for &'a BIP32Path {
+    type Item = &'a ChildIndex;
+    type IntoIter = slice::Iter<'a, ChildIndex>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for BIP32Path {
+    type Item = ChildIndex;
+    type IntoIter = vec::IntoIter<ChildIndex>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl FromStr for BIP32Path {
+    type Err = DerivationPathError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let mut parts = path.split('/');
+
+        if parts.next() != Some("m") {
+            return Err(DerivationPathError::InvalidDerivationPath(path.to_string()));
+        }
+
+        Self::from_vec(&parts.map(ChildIndex::from_str).collect::<Result<Vec<_>, _>>()?)
+    }
+}
+
+impl fmt::Display for BIP32Path {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("m")?;
+        for index in self.0.iter() {
+            write!(f, "/{}", index)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,4 +425,177 @@ mod tests {
             }
         }
     }
+
+    mod bip32_path {
+        use super::*;
+
+        #[test]
+        fn from_str() {
+            assert!(BIP32Path::from_str("m").unwrap().is_empty());
+            assert_eq!(
+                vec![
+                    ChildIndex::Hardened(44),
+                    ChildIndex::Hardened(0),
+                    ChildIndex::Hardened(0),
+                    ChildIndex::Normal(0),
+                    ChildIndex::Normal(1)
+                ],
+                BIP32Path::from_str("m/44'/0'/0'/0/1").unwrap().to_vec().unwrap()
+            );
+            assert_eq!(
+                BIP32Path::from_str("m/44'/0'/0'/0/1").unwrap(),
+                BIP32Path::from_str("m/44h/0h/0h/0/1").unwrap()
+            );
+            assert_eq!(
+                Err(DerivationPathError::InvalidDerivationPath("".to_string())),
+                BIP32Path::from_str("")
+            );
+            assert_eq!(
+                Err(DerivationPathError::InvalidDerivationPath("44'/0'".to_string())),
+                BIP32Path::from_str("44'/0'")
+            );
+            assert_eq!(
+                Err(DerivationPathError::InvalidChildNumberFormat),
+                BIP32Path::from_str("m/44'/0x")
+            );
+            assert_eq!(
+                Err(DerivationPathError::ExpectedBIP32Path),
+                BIP32Path::from_str(&format!("m{}", "/0".repeat(256)))
+            );
+        }
+
+        #[test]
+        fn to_string() {
+            assert_eq!("m", BIP32Path::default().to_string());
+            assert_eq!(
+                "m/44'/0'/0'/0/1",
+                BIP32Path::from_str("m/44h/0h/0h/0/1").unwrap().to_string()
+            );
+        }
+
+        #[test]
+        fn bip44() {
+            assert_eq!("m/44'/0'/0'/0/0", BIP32Path::bip44(0, 0, 0, 0).unwrap().to_string());
+            assert_eq!("m/44'/60'/1'/1/5", BIP32Path::bip44(60, 1, 1, 5).unwrap().to_string());
+            assert_eq!(
+                Err(DerivationPathError::InvalidChildNumber(1 << 31)),
+                BIP32Path::bip44(0, 1 << 31, 0, 0)
+            );
+        }
+
+        #[test]
+        fn child() {
+            let path = BIP32Path::default().child(ChildIndex::hardened(0).unwrap()).unwrap();
+            assert_eq!("m/0'", path.to_string());
+            assert_eq!("m/0'/1", path.child(ChildIndex::Normal(1)).unwrap().to_string());
+
+            let path = BIP32Path::from_vec(&vec![ChildIndex::Normal(0); 255]).unwrap();
+            assert_eq!(
+                Err(DerivationPathError::ExpectedBIP32Path),
+                path.child(ChildIndex::Normal(0))
+            );
+        }
+
+        #[test]
+        fn parent() {
+            let path = BIP32Path::from_str("m/44'/0'/0'/0/1").unwrap();
+            assert_eq!("m/44'/0'/0'/0", path.parent().unwrap().to_string());
+            assert_eq!(None, BIP32Path::default().parent());
+            assert_eq!(Some(BIP32Path::default()), BIP32Path::from_str("m/0").unwrap().parent());
+        }
+
+        #[test]
+        fn extend() {
+            let account = BIP32Path::from_str("m/84'/0'/0'").unwrap();
+            let path = account
+                .extend(vec![ChildIndex::Normal(1), ChildIndex::Normal(7)])
+                .unwrap();
+            assert_eq!("m/84'/0'/0'/1/7", path.to_string());
+            assert_eq!(account, account.extend(vec![]).unwrap());
+        }
+
+        #[test]
+        fn iter() {
+            let path = BIP32Path::from_str("m/0'/1/2'").unwrap();
+            let indices: Vec<u32> = path.iter().map(ChildIndex::to_index).collect();
+            assert_eq!(vec![(1 << 31), 1, 2 | (1 << 31)], indices);
+            assert_eq!(path.to_vec().unwrap(), path.into_iter().collect::<Vec<_>>());
+        }
+    }
+
+    mod non_hardened_derivation_path {
+        use super::*;
+
+        #[test]
+        fn new() {
+            let path = vec![ChildIndex::Normal(0), ChildIndex::Normal(1)];
+            assert_eq!(path, NonHardenedDerivationPath::new(path.clone()).unwrap().to_vec());
+            assert_eq!(
+                Err(DerivationPathError::ExpectedNormalPath),
+                NonHardenedDerivationPath::new(vec![ChildIndex::Normal(0), ChildIndex::Hardened(1)])
+            );
+        }
+
+        #[test]
+        fn from_str() {
+            assert!(NonHardenedDerivationPath::from_str("m").unwrap().is_empty());
+            assert_eq!(
+                vec![ChildIndex::Normal(0), ChildIndex::Normal(2147483647)],
+                NonHardenedDerivationPath::from_str("m/0/2147483647").unwrap().to_vec()
+            );
+            assert_eq!(
+                Err(DerivationPathError::ExpectedNormalPath),
+                NonHardenedDerivationPath::from_str("m/0/1'")
+            );
+            assert_eq!(
+                Err(DerivationPathError::ExpectedNormalPath),
+                NonHardenedDerivationPath::from_str("m/0h/1")
+            );
+            assert_eq!(
+                Err(DerivationPathError::InvalidChildNumber(2147483648)),
+                NonHardenedDerivationPath::from_str("m/2147483648")
+            );
+            assert_eq!(
+                Err(DerivationPathError::InvalidChildNumberFormat),
+                NonHardenedDerivationPath::from_str("m/0/")
+            );
+            assert_eq!(
+                Err(DerivationPathError::InvalidDerivationPath("0/1".to_string())),
+                NonHardenedDerivationPath::from_str("0/1")
+            );
+        }
+
+        #[test]
+        fn to_string() {
+            assert_eq!("m", NonHardenedDerivationPath::default().to_string());
+            assert_eq!(
+                "m/0/1",
+                NonHardenedDerivationPath::from_str("m/0/1").unwrap().to_string()
+            );
+        }
+    }
+
+    mod derivation_path_error {
+        use super::*;
+
+        #[test]
+        fn to_string() {
+            assert_eq!(
+                "expected BIP44 path",
+                DerivationPathError::ExpectedBIP44Path.to_string()
+            );
+            assert_eq!(
+                "invalid child number: 2147483648",
+                DerivationPathError::InvalidChildNumber(1 << 31).to_string()
+            );
+        }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn source() {
+            let error: Box<dyn std::error::Error> = Box::new(DerivationPathError::InvalidChildNumberFormat);
+            assert!(error.source().is_none());
+            assert_eq!("invalid child number format", error.to_string());
+        }
+    }
 }
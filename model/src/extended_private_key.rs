@@ -1,19 +1,21 @@
 use crate::address::{Address, AddressError};
-use crate::derivation_path::{DerivationPath, DerivationPathError};
+use crate::derivation_path::{BIP32Path, DerivationPath, DerivationPathError};
 use crate::extended_public_key::ExtendedPublicKey;
 use crate::format::Format;
 use crate::network::NetworkError;
 use crate::private_key::PrivateKey;
 use crate::public_key::PublicKey;
+use crate::utilities::encoding::EncodingError;
 
 use crate::no_std::*;
 use core::{
     fmt::{Debug, Display},
     str::FromStr,
 };
+use zeroize::Zeroize;
 
 /// The interface for a generic extended private key.
-pub trait ExtendedPrivateKey: Clone + Debug + Display + FromStr + Send + Sync + 'static + Eq + Sized {
+pub trait ExtendedPrivateKey: Clone + Debug + Display + FromStr + Send + Sync + 'static + Eq + Sized + Zeroize {
     type Address: Address;
     type DerivationPath: DerivationPath;
     type ExtendedPublicKey: ExtendedPublicKey;
@@ -30,6 +32,11 @@ pub trait ExtendedPrivateKey: Clone + Debug + Display + FromStr + Send + Sync +
     /// Returns the extended private key of the given derivation path.
     fn derive(&self, path: &Self::DerivationPath) -> Result<Self, ExtendedPrivateKeyError>;
 
+    /// Returns the extended private key of the given BIP32 path.
+    fn derive_path(&self, path: &BIP32Path) -> Result<Self, ExtendedPrivateKeyError> {
+        self.derive(&Self::DerivationPath::from_vec(&path.to_vec()?)?)
+    }
+
     /// Returns the extended public key of the corresponding extended private key.
     fn to_extended_public_key(&self) -> Self::ExtendedPublicKey;
 
@@ -43,36 +50,34 @@ pub trait ExtendedPrivateKey: Clone + Debug + Display + FromStr + Send + Sync +
     fn to_address(&self, format: &Self::Format) -> Result<Self::Address, AddressError>;
 }
 
-#[derive(Debug, Fail)]
+#[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum ExtendedPrivateKeyError {
-    #[fail(display = "{}: {}", _0, _1)]
+    #[error("{0}: {1}")]
     Crate(&'static str, String),
 
-    #[fail(display = "{}", _0)]
+    #[error("{0}")]
     DerivationPathError(DerivationPathError),
 
-    #[fail(display = "invalid byte length: {}", _0)]
+    #[error("invalid byte length: {0}")]
     InvalidByteLength(usize),
 
-    #[fail(
-        display = "invalid extended private key checksum: {{ expected: {:?}, found: {:?} }}",
-        _0, _1
-    )]
+    #[error("invalid extended private key checksum: {{ expected: {0:?}, found: {1:?} }}")]
     InvalidChecksum(String, String),
 
-    #[fail(display = "invalid version bytes: {:?}", _0)]
+    #[error("invalid version bytes: {0:?}")]
     InvalidVersionBytes(Vec<u8>),
 
-    #[fail(display = "maximum child depth reached: {}", _0)]
+    #[error("maximum child depth reached: {0}")]
     MaximumChildDepthReached(u8),
 
-    #[fail(display = "{}", _0)]
+    #[error("{0}")]
     Message(String),
 
-    #[fail(display = "{}", _0)]
+    #[error("{0}")]
     NetworkError(NetworkError),
 
-    #[fail(display = "unsupported format: {}", _0)]
+    #[error("unsupported format: {0}")]
     UnsupportedFormat(String),
 }
 
@@ -94,6 +99,19 @@ impl From<NetworkError> for ExtendedPrivateKeyError {
     }
 }
 
+impl From<EncodingError> for ExtendedPrivateKeyError {
+    fn from(error: EncodingError) -> Self {
+        match error {
+            EncodingError::InvalidBase58(error) => ExtendedPrivateKeyError::Crate("base58", error),
+            EncodingError::InvalidBase58Checksum(expected, found) => {
+                ExtendedPrivateKeyError::InvalidChecksum(expected, found)
+            }
+            EncodingError::InvalidByteLength(length) => ExtendedPrivateKeyError::InvalidByteLength(length),
+            error => ExtendedPrivateKeyError::Crate("encoding", format!("{:?}", error)),
+        }
+    }
+}
+
 impl From<base58::FromBase58Error> for ExtendedPrivateKeyError {
     fn from(error: base58::FromBase58Error) -> Self {
         ExtendedPrivateKeyError::Crate("base58", format!("{:?}", error))
@@ -1,9 +1,10 @@
 use crate::address::{Address, AddressError};
-use crate::derivation_path::{DerivationPath, DerivationPathError};
+use crate::derivation_path::{BIP32Path, DerivationPath, DerivationPathError, NonHardenedDerivationPath};
 use crate::extended_private_key::ExtendedPrivateKey;
 use crate::format::Format;
 use crate::network::NetworkError;
 use crate::public_key::{PublicKey, PublicKeyError};
+use crate::utilities::encoding::EncodingError;
 
 use crate::no_std::*;
 use core::{
@@ -25,6 +26,16 @@ pub trait ExtendedPublicKey: Clone + Debug + Display + FromStr + Send + Sync + '
     /// Returns the extended public key for the given derivation path.
     fn derive(&self, path: &Self::DerivationPath) -> Result<Self, ExtendedPublicKeyError>;
 
+    /// Returns the extended public key for the given BIP32 path.
+    fn derive_path(&self, path: &BIP32Path) -> Result<Self, ExtendedPublicKeyError> {
+        self.derive(&Self::DerivationPath::from_vec(&path.to_vec()?)?)
+    }
+
+    /// Returns the extended public key for the given non-hardened derivation path.
+    fn derive_public(&self, path: &NonHardenedDerivationPath) -> Result<Self, ExtendedPublicKeyError> {
+        self.derive(&Self::DerivationPath::from_vec(&path.to_vec())?)
+    }
+
     /// Returns the public key of the corresponding extended public key.
     fn to_public_key(&self) -> Self::PublicKey;
 
@@ -32,42 +43,40 @@ pub trait ExtendedPublicKey: Clone + Debug + Display + FromStr + Send + Sync + '
     fn to_address(&self, format: &Self::Format) -> Result<Self::Address, AddressError>;
 }
 
-#[derive(Debug, Fail)]
+#[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum ExtendedPublicKeyError {
-    #[fail(display = "{}: {}", _0, _1)]
+    #[error("{0}: {1}")]
     Crate(&'static str, String),
 
-    #[fail(display = "{}", _0)]
+    #[error("{0}")]
     DerivationPathError(DerivationPathError),
 
-    #[fail(display = "invalid byte length: {}", _0)]
+    #[error("invalid byte length: {0}")]
     InvalidByteLength(usize),
 
-    #[fail(
-        display = "invalid extended private key checksum: {{ expected: {:?}, found: {:?} }}",
-        _0, _1
-    )]
+    #[error("invalid extended private key checksum: {{ expected: {0:?}, found: {1:?} }}")]
     InvalidChecksum(String, String),
 
-    #[fail(display = "invalid child number: {{ expected: {:?}, found: {:?} }}", _0, _1)]
+    #[error("invalid child number: {{ expected: {0:?}, found: {1:?} }}")]
     InvalidChildNumber(u32, u32),
 
-    #[fail(display = "invalid version bytes: {:?}", _0)]
+    #[error("invalid version bytes: {0:?}")]
     InvalidVersionBytes(Vec<u8>),
 
-    #[fail(display = "maximum child depth reached: {}", _0)]
+    #[error("maximum child depth reached: {0}")]
     MaximumChildDepthReached(u8),
 
-    #[fail(display = "{}", _0)]
+    #[error("{0}")]
     Message(String),
 
-    #[fail(display = "{}", _0)]
+    #[error("{0}")]
     NetworkError(NetworkError),
 
-    #[fail(display = "{}", _0)]
+    #[error("{0}")]
     PublicKeyError(PublicKeyError),
 
-    #[fail(display = "unsupported format: {}", _0)]
+    #[error("unsupported format: {0}")]
     UnsupportedFormat(String),
 }
 
@@ -95,6 +104,19 @@ impl From<PublicKeyError> for ExtendedPublicKeyError {
     }
 }
 
+impl From<EncodingError> for ExtendedPublicKeyError {
+    fn from(error: EncodingError) -> Self {
+        match error {
+            EncodingError::InvalidBase58(error) => ExtendedPublicKeyError::Crate("base58", error),
+            EncodingError::InvalidBase58Checksum(expected, found) => {
+                ExtendedPublicKeyError::InvalidChecksum(expected, found)
+            }
+            EncodingError::InvalidByteLength(length) => ExtendedPublicKeyError::InvalidByteLength(length),
+            error => ExtendedPublicKeyError::Crate("encoding", format!("{:?}", error)),
+        }
+    }
+}
+
 impl From<base58::FromBase58Error> for ExtendedPublicKeyError {
     fn from(error: base58::FromBase58Error) -> Self {
         ExtendedPublicKeyError::Crate("base58", format!("{:?}", error))
@@ -13,7 +13,7 @@
 extern crate alloc;
 
 #[macro_use]
-extern crate failure;
+extern crate thiserror;
 
 pub mod no_std;
 
@@ -41,17 +41,26 @@ pub use self::mnemonic::*;
 pub mod network;
 pub use self::network::*;
 
+pub mod one_time_key;
+pub use self::one_time_key::*;
+
 pub mod private_key;
 pub use self::private_key::*;
 
 pub mod public_key;
 pub use self::public_key::*;
 
+pub mod rng;
+pub use self::rng::*;
+
 pub mod transaction;
 pub use self::transaction::*;
 
 pub mod utilities;
 pub use self::utilities::*;
 
+pub mod wallet_generator;
+pub use self::wallet_generator::*;
+
 pub mod wordlist;
 pub use self::wordlist::*;
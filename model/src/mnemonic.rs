@@ -12,9 +12,10 @@ use core::{
     str::FromStr,
 };
 use rand::Rng;
+use zeroize::Zeroize;
 
 /// The interface for a generic mnemonic.
-pub trait Mnemonic: Clone + Debug + Display + FromStr + Send + Sync + 'static + Eq + Sized {
+pub trait Mnemonic: Clone + Debug + Display + FromStr + Send + Sync + 'static + Eq + Sized + Zeroize {
     type Address: Address;
     type Format: Format;
     type PrivateKey: PrivateKey;
@@ -57,48 +58,52 @@ pub trait MnemonicExtended: Mnemonic {
     fn to_extended_public_key(&self, password: Option<&str>) -> Result<Self::ExtendedPublicKey, MnemonicError>;
 }
 
-#[derive(Debug, Fail)]
+#[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum MnemonicError {
-    #[fail(display = "{}", _0)]
+    #[error("{0}")]
     AddressError(AddressError),
 
-    #[fail(display = "{}: {}", _0, _1)]
+    #[error("{0}: {1}")]
     Crate(&'static str, String),
 
-    #[fail(display = "{}", _0)]
+    #[error("{0}")]
     ExtendedPrivateKeyError(ExtendedPrivateKeyError),
 
-    #[fail(display = "Invalid checksum word: {{ expected: {:?}, found: {:?} }}", _0, _1)]
+    #[error("Invalid checksum word: {{ expected: {0:?}, found: {1:?} }}")]
     InvalidChecksumWord(String, String),
 
-    #[fail(display = "Invalid decoding from word to seed")]
+    #[error("Invalid decoding from word to seed")]
     InvalidDecoding,
 
-    #[fail(display = "Invalid entropy length: {}", _0)]
+    #[error("Invalid entropy length: {0}")]
     InvalidEntropyLength(usize),
 
-    #[fail(display = "Invalid wordlist index: {}", _0)]
+    #[error("Invalid wordlist index: {0}")]
     InvalidIndex(usize),
 
-    #[fail(display = "Invalid phrase: {}", _0)]
+    #[error("Invalid phrase: {0}")]
     InvalidPhrase(String),
 
-    #[fail(display = "Invalid word not found in monero: {}", _0)]
+    #[error("Invalid word not found in monero: {0}")]
     InvalidWord(String),
 
-    #[fail(display = "Invalid mnemonic word count: {}", _0)]
+    #[error("Invalid mnemonic word count: {0}")]
     InvalidWordCount(u8),
 
-    #[fail(display = "Missing the last word (checksum)")]
+    #[error("Mismatched mnemonic word count: {{ expected: {0}, found: {1} }}")]
+    MismatchedWordCount(u8, usize),
+
+    #[error("Missing the last word (checksum)")]
     MissingChecksumWord,
 
-    #[fail(display = "Missing word(s) in mnemonic")]
+    #[error("Missing word(s) in mnemonic")]
     MissingWord,
 
-    #[fail(display = "{}", _0)]
+    #[error("{0}")]
     PrivateKeyError(PrivateKeyError),
 
-    #[fail(display = "{}", _0)]
+    #[error("{0}")]
     WordlistError(WordlistError),
 }
 
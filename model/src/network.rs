@@ -1,4 +1,9 @@
+use crate::address::AddressError;
+use crate::derivation_path::ChildIndex;
+use crate::extended_private_key::ExtendedPrivateKeyError;
+use crate::extended_public_key::ExtendedPublicKeyError;
 use crate::no_std::*;
+use crate::private_key::PrivateKeyError;
 use core::{
     fmt::{Debug, Display},
     hash::Hash,
@@ -10,14 +15,65 @@ pub trait Network: Copy + Clone + Debug + Display + FromStr + Send + Sync + 'sta
     const NAME: &'static str;
 }
 
-#[derive(Debug, Fail)]
+/// The interface for a network of a Bitcoin fork with base58 version bytes.
+/// A network is configured by its table of version bytes, from which the prefixes
+/// of its addresses, private keys, and extended keys are serialized and validated.
+pub trait Base58Network: Network + Default {
+    /// The BIP44 coin type of the network, as registered in SLIP-0044
+    const HD_COIN_TYPE: ChildIndex;
+    /// The version byte of a P2PKH address
+    const P2PKH_PREFIX: u8;
+    /// The version byte of a P2SH address
+    const P2SH_PREFIX: u8;
+    /// The version byte of a WIF private key
+    const WIF_PREFIX: u8;
+    /// The version bytes of a BIP32 extended private key
+    const EXTENDED_PRIVATE_KEY_VERSION_BYTES: [u8; 4];
+    /// The version bytes of a BIP32 extended public key
+    const EXTENDED_PUBLIC_KEY_VERSION_BYTES: [u8; 4];
+
+    /// Returns the network of the given address prefix.
+    fn from_address_prefix(prefix: &[u8]) -> Result<Self, AddressError> {
+        match prefix.first() {
+            Some(&version) if version == Self::P2PKH_PREFIX || version == Self::P2SH_PREFIX => Ok(Self::default()),
+            _ => Err(AddressError::InvalidPrefix(prefix.to_vec())),
+        }
+    }
+
+    /// Returns the network of the given wif prefix.
+    fn from_private_key_prefix(prefix: u8) -> Result<Self, PrivateKeyError> {
+        match prefix == Self::WIF_PREFIX {
+            true => Ok(Self::default()),
+            false => Err(PrivateKeyError::InvalidPrefix(vec![prefix])),
+        }
+    }
+
+    /// Returns the network of the given extended private key version bytes.
+    fn from_extended_private_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPrivateKeyError> {
+        match prefix.get(0..4) == Some(&Self::EXTENDED_PRIVATE_KEY_VERSION_BYTES[..]) {
+            true => Ok(Self::default()),
+            false => Err(ExtendedPrivateKeyError::InvalidVersionBytes(prefix.to_vec())),
+        }
+    }
+
+    /// Returns the network of the given extended public key version bytes.
+    fn from_extended_public_key_version_bytes(prefix: &[u8]) -> Result<Self, ExtendedPublicKeyError> {
+        match prefix.get(0..4) == Some(&Self::EXTENDED_PUBLIC_KEY_VERSION_BYTES[..]) {
+            true => Ok(Self::default()),
+            false => Err(ExtendedPublicKeyError::InvalidVersionBytes(prefix.to_vec())),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum NetworkError {
-    #[fail(display = "invalid extended private key prefix: {}", _0)]
+    #[error("invalid extended private key prefix: {0}")]
     InvalidExtendedPrivateKeyPrefix(String),
 
-    #[fail(display = "invalid extended public key prefix: {}", _0)]
+    #[error("invalid extended public key prefix: {0}")]
     InvalidExtendedPublicKeyPrefix(String),
 
-    #[fail(display = "invalid network: {}", _0)]
+    #[error("invalid network: {0}")]
     InvalidNetwork(String),
 }
@@ -0,0 +1,108 @@
+use crate::private_key::PrivateKey;
+use crate::public_key::{PublicKey, PublicKeyError};
+use crate::transaction::TransactionError;
+
+use crate::no_std::*;
+use core::fmt::Debug;
+use zeroize::Zeroize;
+
+/// The interface for a generic one time key, the key of an output derived by a sender from the public key
+/// of a receiver and randomness of the sender, which only the holder of the private key can recognize and spend.
+/// Stealth addresses, such as Monero outputs and silent payments, are built on one time keys.
+pub trait OneTimeKey: Clone + Debug + Send + Sync + 'static + Eq + Sized {
+    type KeyImage: Clone + Debug + Eq;
+    type PrivateKey: PrivateKey;
+    type PublicKey: PublicKey;
+    type SecretKey: Clone + Debug + Eq + Zeroize;
+
+    /// Returns the one time key of the output with the given index, derived by the sender
+    /// from the public key of the receiver and the given randomness.
+    fn derive_public(public_key: &Self::PublicKey, randomness: &[u8; 32], index: u64) -> Result<Self, OneTimeKeyError>;
+
+    /// Returns the one time private key of the output with the given index, derived by the receiver
+    /// from its private key.
+    fn derive_secret(&self, private_key: &Self::PrivateKey, index: u64) -> Result<Self::SecretKey, OneTimeKeyError>;
+
+    /// Returns the key image of the given one time private key, which is the same for every
+    /// spend of the output, to mark it as spent without revealing which output is spent.
+    fn to_key_image(secret_key: &Self::SecretKey) -> Self::KeyImage;
+
+    /// Returns `true` if the output with the given index belongs to the given private key,
+    /// as its one time private key derives the one time public key.
+    fn verify(&self, private_key: &Self::PrivateKey, index: u64) -> Result<bool, OneTimeKeyError>;
+}
+
+/// Checks the properties shared by every one time key implementation, for the given receiver keys,
+/// the private key of another receiver, the sender randomness, and output index. Panics if a property
+/// does not hold, for the tests of each implementation to run against its test vectors.
+pub fn test_one_time_key<K: OneTimeKey>(
+    public_key: &K::PublicKey,
+    private_key: &K::PrivateKey,
+    other_private_key: &K::PrivateKey,
+    randomness: &[u8; 32],
+    index: u64,
+) {
+    // The key is deterministic, and recognized by its receiver only
+    let one_time_key = K::derive_public(public_key, randomness, index).unwrap();
+    assert_eq!(one_time_key, K::derive_public(public_key, randomness, index).unwrap());
+    assert!(one_time_key.verify(private_key, index).unwrap());
+    assert!(!one_time_key.verify(other_private_key, index).unwrap());
+
+    // The key image of an output is the same for every derivation of its one time private key
+    let mut secret_key = one_time_key.derive_secret(private_key, index).unwrap();
+    let key_image = K::to_key_image(&secret_key);
+    assert_eq!(
+        key_image,
+        K::to_key_image(&one_time_key.derive_secret(private_key, index).unwrap())
+    );
+
+    // The outputs of the same randomness are unlinkable by their index
+    let next_index = index.wrapping_add(1);
+    let next_one_time_key = K::derive_public(public_key, randomness, next_index).unwrap();
+    assert_ne!(one_time_key, next_one_time_key);
+    assert!(!next_one_time_key.verify(private_key, index).unwrap());
+
+    let mut next_secret_key = next_one_time_key.derive_secret(private_key, next_index).unwrap();
+    assert_ne!(secret_key, next_secret_key);
+    assert_ne!(key_image, K::to_key_image(&next_secret_key));
+
+    secret_key.zeroize();
+    next_secret_key.zeroize();
+}
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum OneTimeKeyError {
+    #[error("{0}: {1}")]
+    Crate(&'static str, String),
+
+    #[error("could not decode a curve point from {0:?}")]
+    InvalidPoint(Vec<u8>),
+
+    #[error("{0}")]
+    Message(String),
+
+    #[error("{0}")]
+    PublicKeyError(PublicKeyError),
+
+    #[error("{0}")]
+    TransactionError(TransactionError),
+}
+
+impl From<&'static str> for OneTimeKeyError {
+    fn from(msg: &'static str) -> Self {
+        OneTimeKeyError::Message(msg.into())
+    }
+}
+
+impl From<PublicKeyError> for OneTimeKeyError {
+    fn from(error: PublicKeyError) -> Self {
+        OneTimeKeyError::PublicKeyError(error)
+    }
+}
+
+impl From<TransactionError> for OneTimeKeyError {
+    fn from(error: TransactionError) -> Self {
+        OneTimeKeyError::TransactionError(error)
+    }
+}
@@ -1,6 +1,7 @@
 use crate::address::{Address, AddressError};
 use crate::format::Format;
 use crate::public_key::PublicKey;
+use crate::utilities::encoding::EncodingError;
 
 use crate::no_std::*;
 use core::{
@@ -8,9 +9,10 @@ use core::{
     str::FromStr,
 };
 use rand::Rng;
+use zeroize::Zeroize;
 
 /// The interface for a generic private key.
-pub trait PrivateKey: Clone + Debug + Display + FromStr + Send + Sync + 'static + Eq + Sized {
+pub trait PrivateKey: Clone + Debug + Display + FromStr + Send + Sync + 'static + Eq + Sized + Zeroize {
     type Address: Address;
     type Format: Format;
     type PublicKey: PublicKey;
@@ -25,30 +27,31 @@ pub trait PrivateKey: Clone + Debug + Display + FromStr + Send + Sync + 'static
     fn to_address(&self, format: &Self::Format) -> Result<Self::Address, AddressError>;
 }
 
-#[derive(Debug, Fail)]
+#[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum PrivateKeyError {
-    #[fail(display = "{}: {}", _0, _1)]
+    #[error("{0}: {1}")]
     Crate(&'static str, String),
 
-    #[fail(display = "invalid byte length: {}", _0)]
+    #[error("invalid byte length: {0}")]
     InvalidByteLength(usize),
 
-    #[fail(display = "invalid character length: {}", _0)]
+    #[error("invalid character length: {0}")]
     InvalidCharacterLength(usize),
 
-    #[fail(display = "invalid private key checksum: {{ expected: {:?}, found: {:?} }}", _0, _1)]
+    #[error("invalid private key checksum: {{ expected: {0:?}, found: {1:?} }}")]
     InvalidChecksum(String, String),
 
-    #[fail(display = "invalid network: {{ expected: {:?}, found: {:?} }}", _0, _1)]
+    #[error("invalid network: {{ expected: {0:?}, found: {1:?} }}")]
     InvalidNetwork(String, String),
 
-    #[fail(display = "invalid private key prefix: {:?}", _0)]
+    #[error("invalid private key prefix: {0:?}")]
     InvalidPrefix(Vec<u8>),
 
-    #[fail(display = "{}", _0)]
+    #[error("{0}")]
     Message(String),
 
-    #[fail(display = "unsupported format")]
+    #[error("unsupported format")]
     UnsupportedFormat,
 }
 
@@ -64,6 +67,17 @@ impl From<&'static str> for PrivateKeyError {
     }
 }
 
+impl From<EncodingError> for PrivateKeyError {
+    fn from(error: EncodingError) -> Self {
+        match error {
+            EncodingError::InvalidBase58(error) => PrivateKeyError::Crate("base58", error),
+            EncodingError::InvalidBase58Checksum(expected, found) => PrivateKeyError::InvalidChecksum(expected, found),
+            EncodingError::InvalidByteLength(length) => PrivateKeyError::InvalidByteLength(length),
+            error => PrivateKeyError::Crate("encoding", format!("{:?}", error)),
+        }
+    }
+}
+
 impl From<base58::FromBase58Error> for PrivateKeyError {
     fn from(error: base58::FromBase58Error) -> Self {
         PrivateKeyError::Crate("base58", format!("{:?}", error))
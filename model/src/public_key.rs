@@ -1,6 +1,7 @@
 use crate::address::{Address, AddressError};
 use crate::format::Format;
 use crate::private_key::PrivateKey;
+use crate::utilities::encoding::EncodingError;
 
 use crate::no_std::*;
 use core::{
@@ -21,24 +22,28 @@ pub trait PublicKey: Clone + Debug + Display + FromStr + Send + Sync + 'static +
     fn to_address(&self, format: &Self::Format) -> Result<Self::Address, AddressError>;
 }
 
-#[derive(Debug, Fail)]
+#[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum PublicKeyError {
-    #[fail(display = "{}: {}", _0, _1)]
+    #[error("{0}: {1}")]
     Crate(&'static str, String),
 
-    #[fail(display = "invalid byte length: {}", _0)]
+    #[error("invalid byte length: {0}")]
     InvalidByteLength(usize),
 
-    #[fail(display = "invalid character length: {}", _0)]
+    #[error("invalid character length: {0}")]
     InvalidCharacterLength(usize),
 
-    #[fail(display = "invalid public key prefix: {:?}", _0)]
+    #[error("invalid public key prefix: {0:?}")]
     InvalidPrefix(String),
 
-    #[fail(display = "no public spending key found")]
+    #[error("invalid recovery id: {0}")]
+    InvalidRecoveryId(u8),
+
+    #[error("no public spending key found")]
     NoSpendingKey,
 
-    #[fail(display = "no public viewing key found")]
+    #[error("no public viewing key found")]
     NoViewingKey,
 }
 
@@ -48,6 +53,16 @@ impl From<crate::no_std::io::Error> for PublicKeyError {
     }
 }
 
+impl From<EncodingError> for PublicKeyError {
+    fn from(error: EncodingError) -> Self {
+        match error {
+            EncodingError::InvalidBase58(error) => PublicKeyError::Crate("base58", error),
+            EncodingError::InvalidByteLength(length) => PublicKeyError::InvalidByteLength(length),
+            error => PublicKeyError::Crate("encoding", format!("{:?}", error)),
+        }
+    }
+}
+
 impl From<base58::FromBase58Error> for PublicKeyError {
     fn from(error: base58::FromBase58Error) -> Self {
         PublicKeyError::Crate("base58", format!("{:?}", error))
@@ -0,0 +1,174 @@
+use rand::{rngs::StdRng, CryptoRng, Error, RngCore, SeedableRng};
+
+/// The interface for a source of randomness for generating private keys and mnemonics.
+/// Every wallet RNG is a cryptographically secure `RngCore`, so it may be passed to
+/// `PrivateKey::new` and `Mnemonic::new` in place of a raw RNG.
+pub trait WalletRng: RngCore + CryptoRng {}
+
+/// A wallet RNG drawing from the entropy of the operating system.
+#[cfg(feature = "std")]
+pub struct OsWalletRng(StdRng);
+
+#[cfg(feature = "std")]
+impl OsWalletRng {
+    /// Returns a new wallet RNG, seeded from the entropy of the operating system.
+    pub fn new() -> Self {
+        Self(StdRng::from_entropy())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for OsWalletRng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl RngCore for OsWalletRng {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+#[cfg(feature = "std")]
+impl CryptoRng for OsWalletRng {}
+
+#[cfg(feature = "std")]
+impl WalletRng for OsWalletRng {}
+
+/// A deterministic wallet RNG for a given seed, to generate reproducible wallets in tests and test vectors.
+/// It must never be used to generate wallets holding funds.
+pub struct SeededWalletRng(StdRng);
+
+impl SeededWalletRng {
+    /// Returns a new wallet RNG for the given seed.
+    pub fn new(seed: [u8; 32]) -> Self {
+        Self(StdRng::from_seed(seed))
+    }
+
+    /// Returns a new wallet RNG for the given integer seed.
+    pub fn from_u64(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+}
+
+impl RngCore for SeededWalletRng {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+impl CryptoRng for SeededWalletRng {}
+
+impl WalletRng for SeededWalletRng {}
+
+/// A wallet RNG that audits the entropy drawn from an inner wallet RNG,
+/// calling the given logger with the number of bytes of every draw.
+pub struct AuditWalletRng<R: WalletRng, F: FnMut(usize)> {
+    rng: R,
+    logger: F,
+    consumed: usize,
+}
+
+impl<R: WalletRng, F: FnMut(usize)> AuditWalletRng<R, F> {
+    /// Returns a new wallet RNG auditing the given wallet RNG with the given logger.
+    pub fn new(rng: R, logger: F) -> Self {
+        Self {
+            rng,
+            logger,
+            consumed: 0,
+        }
+    }
+
+    /// Returns the total number of bytes of entropy drawn.
+    pub fn consumed(&self) -> usize {
+        self.consumed
+    }
+
+    /// Records a draw of the given number of bytes of entropy.
+    fn audit(&mut self, bytes: usize) {
+        self.consumed += bytes;
+        (self.logger)(bytes);
+    }
+}
+
+impl<R: WalletRng, F: FnMut(usize)> RngCore for AuditWalletRng<R, F> {
+    fn next_u32(&mut self) -> u32 {
+        self.audit(4);
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.audit(8);
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.audit(dest.len());
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.audit(dest.len());
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+impl<R: WalletRng, F: FnMut(usize)> CryptoRng for AuditWalletRng<R, F> {}
+
+impl<R: WalletRng, F: FnMut(usize)> WalletRng for AuditWalletRng<R, F> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded_is_deterministic() {
+        let (mut a, mut b) = (SeededWalletRng::from_u64(7), SeededWalletRng::from_u64(7));
+        let (mut first, mut second) = ([0u8; 32], [0u8; 32]);
+        a.fill_bytes(&mut first);
+        b.fill_bytes(&mut second);
+        assert_eq!(first, second);
+
+        let mut other = [0u8; 32];
+        SeededWalletRng::from_u64(8).fill_bytes(&mut other);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn audit_consumption() {
+        let mut draws = 0;
+        let mut rng = AuditWalletRng::new(SeededWalletRng::from_u64(0), |_| draws += 1);
+        rng.fill_bytes(&mut [0u8; 32]);
+        rng.next_u32();
+        rng.next_u64();
+        assert_eq!(44, rng.consumed());
+        drop(rng);
+        assert_eq!(3, draws);
+    }
+}
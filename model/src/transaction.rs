@@ -1,5 +1,5 @@
 use crate::address::{Address, AddressError};
-use crate::amount::AmountError;
+use crate::amount::{Amount, AmountError};
 use crate::extended_private_key::ExtendedPrivateKeyError;
 use crate::format::Format;
 use crate::private_key::{PrivateKey, PrivateKeyError};
@@ -18,6 +18,7 @@ pub trait TransactionId: Clone + Debug + Display + Send + Sync + 'static + Eq +
 /// The interface for a generic transactions.
 pub trait Transaction: Clone + Send + Sync + 'static {
     type Address: Address;
+    type Amount: Amount;
     type Format: Format;
     type PrivateKey: PrivateKey;
     type PublicKey: PublicKey;
@@ -30,110 +31,166 @@ pub trait Transaction: Clone + Send + Sync + 'static {
     /// Returns a signed transaction given the private key of the sender.
     fn sign(&self, private_key: &Self::PrivateKey) -> Result<Self, TransactionError>;
 
+    /// Returns a transaction signed with a signature of its sighash produced outside of the library,
+    /// such as by a hardware device, given the public key of the signer. The signature is 64 bytes
+    /// of `r || s`, optionally followed by its recovery id.
+    fn sign_with_signature(&self, signature: &[u8], public_key: &Self::PublicKey) -> Result<Self, TransactionError>;
+
     /// Returns a transaction given the transaction bytes.
     fn from_transaction_bytes(transaction: &Vec<u8>) -> Result<Self, TransactionError>;
 
     /// Returns the transaction in bytes.
     fn to_transaction_bytes(&self) -> Result<Vec<u8>, TransactionError>;
 
-    /// Returns the transaction id.
+    /// Returns the hash signed for the input at the given index, which commits to the transaction
+    /// without its signatures. A transaction signed once for all of its inputs has a sighash at index 0.
+    fn to_sighash(&self, index: usize) -> Result<Vec<u8>, TransactionError>;
+
+    /// Returns the transaction id, the hash that identifies the transaction once it is broadcast.
     fn to_transaction_id(&self) -> Result<Self::TransactionId, TransactionError>;
+
+    /// Returns the fee of the transaction, or `None` if it is not known from the transaction alone.
+    fn fee(&self) -> Option<Self::Amount> {
+        None
+    }
+
+    /// Returns the size of the transaction in bytes.
+    fn size(&self) -> Result<usize, TransactionError> {
+        Ok(self.to_transaction_bytes()?.len())
+    }
+
+    /// Returns the virtual size of the transaction, to compute its fee rate.
+    /// This is its size, unless the currency discounts the weight of part of the transaction.
+    fn vsize(&self) -> Result<usize, TransactionError> {
+        self.size()
+    }
 }
 
-#[derive(Debug, Fail)]
+#[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum TransactionError {
-    #[fail(display = "{}", _0)]
+    #[error("{0}")]
     AddressError(AddressError),
 
-    #[fail(display = "{}", _0)]
+    #[error("{0}")]
     AmountError(AmountError),
 
-    #[fail(display = "witnesses have a conflicting anchor")]
+    #[error("witnesses have a conflicting anchor")]
     ConflictingWitnessAnchors(),
 
-    #[fail(display = "{}: {}", _0, _1)]
+    #[error("{0}: {1}")]
     Crate(&'static str, String),
 
-    #[fail(display = "{}", _0)]
+    #[error("output {0} of {1} is below the dust limit of {2}")]
+    DustOutput(usize, i64, i64),
+
+    #[error("fee of {0} exceeds {2}% of the outputs of {1}")]
+    ExcessiveFee(i64, i64, u64),
+
+    #[error("{0}")]
     ExtendedPrivateKeyError(ExtendedPrivateKeyError),
 
-    #[fail(display = "Failed note decryption for enc_cyphertext: {}", _0)]
+    #[error("Failed note decryption for enc_cyphertext: {0}")]
     FailedNoteDecryption(String),
 
-    #[fail(display = "invalid binding signature for the transaction")]
+    #[error("insufficient fee: {0} given, {1} required to replace the transaction")]
+    InsufficientFee(i64, i64),
+
+    #[error("insufficient funds: {0} available, {1} required")]
+    InsufficientFunds(i64, i64),
+
+    #[error("invalid binding signature for the transaction")]
     InvalidBindingSig(),
 
-    #[fail(display = "invalid chain id {:?}", _0)]
-    InvalidChainId(u8),
+    #[error("invalid chain id {0}")]
+    InvalidChainId(u64),
 
-    #[fail(display = "invalid ephemeral key {}", _0)]
+    #[error("invalid consensus branch {0}, expected a network upgrade or a 4-byte id in hex")]
+    InvalidConsensusBranch(String),
+
+    #[error("invalid ephemeral key {0}")]
     InvalidEphemeralKey(String),
 
-    #[fail(display = "insufficient information to craft transaction. missing: {}", _0)]
+    #[error("insufficient information to craft transaction. missing: {0}")]
     InvalidInputs(String),
 
-    #[fail(display = "invalid output address: {}", _0)]
+    #[error("invalid output address: {0}")]
     InvalidOutputAddress(String),
 
-    #[fail(display = "invalid ouptut description for address: {}", _0)]
+    #[error("invalid ouptut description for address: {0}")]
     InvalidOutputDescription(String),
 
-    #[fail(display = "invalid transaction RLP length: expected - 9, found - {:?}", _0)]
+    #[error("invalid transaction RLP length: expected - 9, found - {0:?}")]
     InvalidRlpLength(usize),
 
-    #[fail(display = "invalid script pub key for format: {}", _0)]
+    #[error("invalid script pub key for format: {0}")]
     InvalidScriptPubKey(String),
 
-    #[fail(display = "invalid segwit flag: {:?}", _0)]
+    #[error("invalid segwit flag: {0:?}")]
     InvalidSegwitFlag(usize),
 
-    #[fail(display = "invalid spend description for address")]
+    #[error("invalid spend description for address")]
     InvalidSpendDescription,
 
-    #[fail(display = "invalid transaction id {:?}", _0)]
+    #[error("invalid transaction id {0:?}")]
     InvalidTransactionId(usize),
 
-    #[fail(display = "invalid transaction - either both sender and signature should be present, or neither")]
+    #[error("invalid transaction - either both sender and signature should be present, or neither")]
     InvalidTransactionState,
 
-    #[fail(display = "invalid variable size integer: {:?}", _0)]
+    #[error("invalid variable size integer: {0:?}")]
     InvalidVariableSizeInteger(usize),
 
-    #[fail(display = "{}", _0)]
+    #[error("invalid version group id {1:#010x} for transaction version {0}")]
+    InvalidVersionGroupId(u32, u32),
+
+    #[error("{0}")]
     Message(String),
 
-    #[fail(display = "missing diversifier, check that the address is a Sapling address")]
+    #[error("missing diversifier, check that the address is a Sapling address")]
     MissingDiversifier,
 
-    #[fail(display = "missing outpoint address")]
+    #[error("missing outpoint address")]
     MissingOutpointAddress,
 
-    #[fail(display = "missing outpoint amount")]
+    #[error("missing outpoint amount")]
     MissingOutpointAmount,
 
-    #[fail(display = "missing outpoint script public key")]
+    #[error("missing outpoint script public key")]
     MissingOutpointScriptPublicKey,
 
-    #[fail(display = "missing output parameters")]
+    #[error("missing output parameters")]
     MissingOutputParameters,
 
-    #[fail(display = "missing spend description")]
+    #[error("missing spend description")]
     MissingSpendDescription,
 
-    #[fail(display = "missing spend parameters")]
+    #[error("missing spend parameters")]
     MissingSpendParameters,
 
-    #[fail(display = "Null Error {:?}", _0)]
+    #[error("non-canonical signature with an s value in the upper half of the curve order")]
+    NonCanonicalSignature,
+
+    #[error("Null Error {0:?}")]
     NullError(()),
 
-    #[fail(display = "{}", _0)]
+    #[error("null data output {0} of {1} bytes exceeds the limit of {2} bytes")]
+    OversizedNullData(usize, usize, usize),
+
+    #[error("{0}")]
     PrivateKeyError(PrivateKeyError),
 
-    #[fail(display = "Joinsplits are not supported")]
+    #[error("Joinsplits are not supported")]
     UnsupportedJoinsplits,
 
-    #[fail(display = "unsupported preimage operation on address format of {}", _0)]
+    #[error("unsupported transaction operation: {0}")]
+    UnsupportedOperation(String),
+
+    #[error("unsupported preimage operation on address format of {0}")]
     UnsupportedPreimage(String),
+
+    #[error("unsupported transaction version: {0}")]
+    UnsupportedTransactionVersion(String),
 }
 
 impl From<crate::no_std::io::Error> for TransactionError {
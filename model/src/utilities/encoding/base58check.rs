@@ -0,0 +1,95 @@
+use super::EncodingError;
+use crate::crypto::checksum;
+use crate::no_std::*;
+
+use base58::{FromBase58, ToBase58};
+
+/// The number of bytes of the checksum
+const CHECKSUM_LENGTH: usize = 4;
+
+/// Returns the base58check string of the given payload, the base58 encoding of the payload
+/// and the first four bytes of its double SHA-256 hash.
+pub fn encode(payload: &[u8]) -> String {
+    let mut data = Vec::with_capacity(payload.len() + CHECKSUM_LENGTH);
+    data.extend_from_slice(payload);
+    data.extend_from_slice(&checksum(payload)[..CHECKSUM_LENGTH]);
+    data.to_base58()
+}
+
+/// Returns the payload of the given base58check string, if its checksum is valid.
+pub fn decode(encoded: &str) -> Result<Vec<u8>, EncodingError> {
+    let data = encoded
+        .from_base58()
+        .map_err(|error| EncodingError::InvalidBase58(format!("{:?}", error)))?;
+    Ok(verify(&data)?.to_vec())
+}
+
+/// Returns the payload of the given base58-decoded data, if its last four bytes are the checksum of the payload.
+/// The checksum is compared in constant time.
+pub fn verify(data: &[u8]) -> Result<&[u8], EncodingError> {
+    if data.len() < CHECKSUM_LENGTH {
+        return Err(EncodingError::InvalidByteLength(data.len()));
+    }
+
+    let (payload, expected) = data.split_at(data.len() - CHECKSUM_LENGTH);
+    let found = &checksum(payload)[..CHECKSUM_LENGTH];
+    let difference = expected
+        .iter()
+        .zip(found)
+        .fold(0u8, |difference, (a, b)| difference | (a ^ b));
+    if difference != 0 {
+        return Err(EncodingError::InvalidBase58Checksum(
+            expected.to_base58(),
+            found.to_base58(),
+        ));
+    }
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // (payload, base58check)
+    const VECTORS: [(&str, &str); 4] = [
+        // Bitcoin P2PKH address
+        (
+            "00751e76e8199196d454941c45d1b3a323f1433bd6",
+            "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH",
+        ),
+        // Bitcoin WIF of the private key 1, uncompressed
+        (
+            "800000000000000000000000000000000000000000000000000000000000000001",
+            "5HpHagT65TZzG1PH3CSu63k8DbpvD8s5ip4nEB3kEsreAnchuDf",
+        ),
+        // Bitcoin WIF of the private key 1, compressed
+        (
+            "80000000000000000000000000000000000000000000000000000000000000000101",
+            "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn",
+        ),
+        ("", "3QJmnh"),
+    ];
+
+    #[test]
+    fn encode_decode() {
+        VECTORS.iter().for_each(|(payload, encoded)| {
+            let payload = hex::decode(payload).unwrap();
+            assert_eq!(*encoded, encode(&payload));
+            assert_eq!(payload, decode(encoded).unwrap());
+        });
+    }
+
+    #[test]
+    fn invalid() {
+        // The Bitcoin P2PKH address with its last character altered
+        assert!(match decode("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMJ") {
+            Err(EncodingError::InvalidBase58Checksum(_, _)) => true,
+            _ => false,
+        });
+        assert!(match decode("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAM0") {
+            Err(EncodingError::InvalidBase58(_)) => true,
+            _ => false,
+        });
+        assert_eq!(Err(EncodingError::InvalidByteLength(2)), verify(&[0, 1]));
+    }
+}
@@ -0,0 +1,346 @@
+pub mod base58check;
+
+use crate::no_std::*;
+
+/// The characters of the bech32 alphabet, indexed by their 5-bit values
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+/// The generator of the BCH code of the bech32 checksum
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+/// The separator of the human-readable part and the data part
+const SEPARATOR: char = '1';
+/// The number of characters of the checksum
+const CHECKSUM_LENGTH: usize = 6;
+
+/// The maximum length of a bech32 string, as specified by BIP-173 for segwit addresses
+pub const BECH32_MAX_LENGTH: usize = 90;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum EncodingError {
+    #[error("invalid base58: {0}")]
+    InvalidBase58(String),
+
+    #[error("invalid base58 checksum: {{ expected: {0}, found: {1} }}")]
+    InvalidBase58Checksum(String, String),
+
+    #[error("invalid byte length: {0}")]
+    InvalidByteLength(usize),
+
+    #[error("invalid character: {0:?}")]
+    InvalidCharacter(char),
+
+    #[error("invalid checksum")]
+    InvalidChecksum,
+
+    #[error("invalid data value: {0}")]
+    InvalidData(u8),
+
+    #[error("invalid human-readable part length: {0}")]
+    InvalidHrpLength(usize),
+
+    #[error("invalid length: {{ maximum: {0}, found: {1} }}")]
+    InvalidLength(usize, usize),
+
+    #[error("invalid padding")]
+    InvalidPadding,
+
+    #[error("missing separator")]
+    MissingSeparator,
+
+    #[error("mixed case")]
+    MixedCase,
+}
+
+/// Represents the checksum variant of a bech32 string
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bech32Variant {
+    /// Bech32, as specified by BIP-173
+    Bech32,
+    /// Bech32m, as specified by BIP-350
+    Bech32m,
+}
+
+impl Bech32Variant {
+    /// Returns the checksum variant of the segwit address of the given witness version,
+    /// Bech32 for version 0 and Bech32m for versions 1 to 16, as specified by BIP-350.
+    pub fn from_witness_version(version: u8) -> Self {
+        match version {
+            0 => Bech32Variant::Bech32,
+            _ => Bech32Variant::Bech32m,
+        }
+    }
+
+    /// Returns the constant the checksum of the variant is xored with.
+    fn constant(&self) -> u32 {
+        match self {
+            Bech32Variant::Bech32 => 1,
+            Bech32Variant::Bech32m => 0x2bc830a3,
+        }
+    }
+
+    /// Returns the variant of the given checksum residue, if any.
+    fn from_constant(constant: u32) -> Option<Self> {
+        match constant {
+            1 => Some(Bech32Variant::Bech32),
+            0x2bc830a3 => Some(Bech32Variant::Bech32m),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the bech32 string of the given human-readable part and 5-bit data, with the checksum of the given variant,
+/// if the string is at most the given length. Uppercase human-readable parts are encoded in lowercase.
+pub fn bech32_encode(hrp: &str, data: &[u8], variant: Bech32Variant, limit: usize) -> Result<String, EncodingError> {
+    let hrp = check_hrp(hrp)?;
+    if let Some(value) = data.iter().find(|value| **value >= 32) {
+        return Err(EncodingError::InvalidData(*value));
+    }
+
+    let length = hrp.len() + 1 + data.len() + CHECKSUM_LENGTH;
+    if length > limit {
+        return Err(EncodingError::InvalidLength(limit, length));
+    }
+
+    let checksum = create_checksum(hrp.as_bytes(), data, variant);
+    let mut encoded = String::with_capacity(length);
+    encoded.push_str(&hrp);
+    encoded.push(SEPARATOR);
+    data.iter()
+        .chain(checksum.iter())
+        .for_each(|value| encoded.push(CHARSET[*value as usize] as char));
+    Ok(encoded)
+}
+
+/// Returns the lowercase human-readable part, 5-bit data, and checksum variant of the given bech32 string,
+/// if the string is at most the given length. Strings of mixed case are rejected, as specified by BIP-173.
+pub fn bech32_decode(encoded: &str, limit: usize) -> Result<(String, Vec<u8>, Bech32Variant), EncodingError> {
+    if encoded.len() > limit {
+        return Err(EncodingError::InvalidLength(limit, encoded.len()));
+    }
+    if encoded.chars().any(|c| c.is_ascii_lowercase()) && encoded.chars().any(|c| c.is_ascii_uppercase()) {
+        return Err(EncodingError::MixedCase);
+    }
+
+    let position = encoded.rfind(SEPARATOR).ok_or(EncodingError::MissingSeparator)?;
+    let hrp = check_hrp(&encoded[..position])?;
+    let data_part = &encoded[position + 1..];
+    if data_part.len() < CHECKSUM_LENGTH {
+        return Err(EncodingError::InvalidChecksum);
+    }
+
+    let data = data_part
+        .chars()
+        .map(|c| {
+            let c = c.to_ascii_lowercase();
+            match CHARSET.iter().position(|value| *value as char == c) {
+                Some(value) => Ok(value as u8),
+                None => Err(EncodingError::InvalidCharacter(c)),
+            }
+        })
+        .collect::<Result<Vec<u8>, EncodingError>>()?;
+
+    let variant = Bech32Variant::from_constant(polymod(&[&hrp_expand(hrp.as_bytes())[..], &data].concat()))
+        .ok_or(EncodingError::InvalidChecksum)?;
+    Ok((hrp, data[..data.len() - CHECKSUM_LENGTH].to_vec(), variant))
+}
+
+/// Returns the given data regrouped from words of `from` bits to words of `to` bits,
+/// with the last word padded with zeros if `pad` is true.
+pub fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Result<Vec<u8>, EncodingError> {
+    let mut accumulator = 0u32;
+    let mut bits = 0u32;
+    let maximum = (1u32 << to) - 1;
+    let mut converted = Vec::with_capacity(data.len() * from as usize / to as usize + 1);
+    for value in data {
+        let value = *value as u32;
+        if value >> from != 0 {
+            return Err(EncodingError::InvalidData(value as u8));
+        }
+        accumulator = (accumulator << from) | value;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            converted.push(((accumulator >> bits) & maximum) as u8);
+        }
+    }
+
+    if pad {
+        if bits > 0 {
+            converted.push(((accumulator << (to - bits)) & maximum) as u8);
+        }
+    } else if bits >= from || ((accumulator << (to - bits)) & maximum) != 0 {
+        return Err(EncodingError::InvalidPadding);
+    }
+    Ok(converted)
+}
+
+/// Returns the lowercase human-readable part, if it is 1 to 83 characters in the range 33 to 126 of one case.
+fn check_hrp(hrp: &str) -> Result<String, EncodingError> {
+    if hrp.is_empty() || hrp.len() > 83 {
+        return Err(EncodingError::InvalidHrpLength(hrp.len()));
+    }
+    if let Some(c) = hrp.chars().find(|c| (*c as u32) < 33 || (*c as u32) > 126) {
+        return Err(EncodingError::InvalidCharacter(c));
+    }
+    if hrp.chars().any(|c| c.is_ascii_lowercase()) && hrp.chars().any(|c| c.is_ascii_uppercase()) {
+        return Err(EncodingError::MixedCase);
+    }
+    Ok(hrp.to_ascii_lowercase())
+}
+
+/// Returns the human-readable part expanded for the checksum, the high bits of each character,
+/// a zero separator, and the low bits of each character.
+fn hrp_expand(hrp: &[u8]) -> Vec<u8> {
+    let mut expanded: Vec<u8> = hrp.iter().map(|c| c >> 5).collect();
+    expanded.push(0);
+    expanded.extend(hrp.iter().map(|c| c & 31));
+    expanded
+}
+
+/// Returns the checksum of the human-readable part and data for the given variant.
+fn create_checksum(hrp: &[u8], data: &[u8], variant: Bech32Variant) -> [u8; CHECKSUM_LENGTH] {
+    let values = [&hrp_expand(hrp)[..], data, &[0u8; CHECKSUM_LENGTH]].concat();
+    let residue = polymod(&values) ^ variant.constant();
+    let mut checksum = [0u8; CHECKSUM_LENGTH];
+    for (i, value) in checksum.iter_mut().enumerate() {
+        *value = ((residue >> (5 * (5 - i))) & 31) as u8;
+    }
+    checksum
+}
+
+/// Returns the residue of the BCH code of the bech32 checksum over the given 5-bit values.
+fn polymod(values: &[u8]) -> u32 {
+    values.iter().fold(1u32, |checksum, value| {
+        let top = checksum >> 25;
+        let checksum = ((checksum & 0x1ffffff) << 5) ^ *value as u32;
+        GENERATOR
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (top >> i) & 1 == 1)
+            .fold(checksum, |checksum, (_, generator)| checksum ^ generator)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_valid(encoded: &str, expected_variant: Bech32Variant) {
+        let (hrp, data, variant) = bech32_decode(encoded, BECH32_MAX_LENGTH).unwrap();
+        assert_eq!(expected_variant, variant);
+        assert_eq!(
+            encoded.to_lowercase(),
+            bech32_encode(&hrp, &data, variant, BECH32_MAX_LENGTH).unwrap()
+        );
+    }
+
+    #[test]
+    fn valid_bech32() {
+        [
+            "A12UEL5L",
+            "a12uel5l",
+            "an83characterlonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1tt5tgs",
+            "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw",
+            "11qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqc8247j",
+            "split1checkupstagehandshakeupstreamerranterredcaperred2y9e3w",
+            "?1ezyfcl",
+        ]
+        .iter()
+        .for_each(|encoded| test_valid(encoded, Bech32Variant::Bech32));
+    }
+
+    #[test]
+    fn valid_bech32m() {
+        [
+            "A1LQFN3A",
+            "a1lqfn3a",
+            "an83characterlonghumanreadablepartthatcontainsthetheexcludedcharactersbioandnumber11sg7hg6",
+            "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx",
+            "11llllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllllludsr8",
+            "split1checkupstagehandshakeupstreamerranterredcaperredlc445v",
+            "?1v759aa",
+        ]
+        .iter()
+        .for_each(|encoded| test_valid(encoded, Bech32Variant::Bech32m));
+    }
+
+    #[test]
+    fn invalid_bech32m() {
+        [
+            ("\u{20}1xj0phk", EncodingError::InvalidCharacter('\u{20}')),
+            ("\u{7f}1g6xzxy", EncodingError::InvalidCharacter('\u{7f}')),
+            (
+                "an84characterslonghumanreadablepartthatcontainsthetheexcludedcharactersbioandnumber11d6pts4",
+                EncodingError::InvalidLength(90, 91),
+            ),
+            ("qyrz8wqd2c9m", EncodingError::MissingSeparator),
+            ("1qyrz8wqd2c9m", EncodingError::InvalidHrpLength(0)),
+            ("y1b0jsk6g", EncodingError::InvalidCharacter('b')),
+            ("lt1igcx5c0", EncodingError::InvalidCharacter('i')),
+            ("in1muywd", EncodingError::InvalidChecksum),
+            ("mm1crxm3i", EncodingError::InvalidCharacter('i')),
+            ("au1s5cgom", EncodingError::InvalidCharacter('o')),
+            ("M1VUXWEZ", EncodingError::InvalidChecksum),
+            ("16plkw9", EncodingError::InvalidHrpLength(0)),
+            ("1p2gdwpf", EncodingError::InvalidHrpLength(0)),
+            ("A1LqFN3A", EncodingError::MixedCase),
+        ]
+        .iter()
+        .for_each(|(encoded, error)| {
+            assert_eq!(Err(error.clone()), bech32_decode(encoded, BECH32_MAX_LENGTH));
+        });
+    }
+
+    #[test]
+    fn variants_are_distinct() {
+        // The same data does not verify under the checksum of the other variant
+        let bech32 = bech32_encode("bc", &[1, 2, 3], Bech32Variant::Bech32, BECH32_MAX_LENGTH).unwrap();
+        let bech32m = bech32_encode("bc", &[1, 2, 3], Bech32Variant::Bech32m, BECH32_MAX_LENGTH).unwrap();
+        assert_ne!(bech32, bech32m);
+        assert_eq!(
+            Bech32Variant::Bech32,
+            bech32_decode(&bech32, BECH32_MAX_LENGTH).unwrap().2
+        );
+        assert_eq!(
+            Bech32Variant::Bech32m,
+            bech32_decode(&bech32m, BECH32_MAX_LENGTH).unwrap().2
+        );
+
+        assert_eq!(Bech32Variant::Bech32, Bech32Variant::from_witness_version(0));
+        assert_eq!(Bech32Variant::Bech32m, Bech32Variant::from_witness_version(1));
+    }
+
+    #[test]
+    fn length_limit() {
+        let data = [0u8; 100];
+        assert_eq!(
+            Err(EncodingError::InvalidLength(90, 108)),
+            bech32_encode("a", &data, Bech32Variant::Bech32m, BECH32_MAX_LENGTH)
+        );
+        // Longer strings, such as unified addresses, are encoded with a greater limit
+        let encoded = bech32_encode("a", &data, Bech32Variant::Bech32m, usize::MAX).unwrap();
+        assert_eq!(
+            ("a".to_string(), data.to_vec(), Bech32Variant::Bech32m),
+            bech32_decode(&encoded, usize::MAX).unwrap()
+        );
+    }
+
+    #[test]
+    fn segwit_program() {
+        // The taproot output of BIP-350
+        let encoded = "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0";
+        let (hrp, data, variant) = bech32_decode(encoded, BECH32_MAX_LENGTH).unwrap();
+        assert_eq!("bc", hrp);
+        assert_eq!(Bech32Variant::from_witness_version(data[0]), variant);
+
+        let program = convert_bits(&data[1..], 5, 8, false).unwrap();
+        assert_eq!(
+            "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            hex::encode(&program)
+        );
+        let mut expected = vec![data[0]];
+        expected.extend(convert_bits(&program, 8, 5, true).unwrap());
+        assert_eq!(data, expected);
+
+        assert_eq!(Err(EncodingError::InvalidPadding), convert_bits(&[31], 5, 8, false));
+    }
+}
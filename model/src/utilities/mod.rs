@@ -3,6 +3,9 @@ use crate::no_std::*;
 #[cfg_attr(test, macro_use)]
 pub mod crypto;
 
+pub mod encoding;
+pub use self::encoding::*;
+
 pub fn to_hex_string(bytes: &[u8]) -> String {
     bytes
         .iter()
@@ -0,0 +1,140 @@
+use crate::address::AddressError;
+use crate::derivation_path::{BIP32Path, ChildIndex, DerivationPathError};
+use crate::extended_private_key::{ExtendedPrivateKey, ExtendedPrivateKeyError};
+use crate::mnemonic::{MnemonicError, MnemonicExtended};
+
+use crate::no_std::*;
+use core::{convert::TryFrom, str::FromStr};
+
+/// The wallet yielded by a wallet generator, as its derivation path, private key, and address.
+pub type GeneratedWallet<E> = (
+    BIP32Path,
+    <E as ExtendedPrivateKey>::PrivateKey,
+    <E as ExtendedPrivateKey>::Address,
+);
+
+/// Represents a lazy iterator over the wallets of a derivation path template, e.g. `m/44'/0'/0'/0/*`,
+/// which derives the parent of the template once and each wallet as a child of it, on demand.
+///
+/// The last child index of the template is the wildcard `*`, or `*'` for hardened children.
+#[derive(Clone, Debug)]
+pub struct WalletGenerator<E: ExtendedPrivateKey> {
+    /// The extended private key of the parent of the template
+    parent: E,
+    /// The derivation path of the parent of the template
+    parent_path: BIP32Path,
+    /// The address format of the generated wallets
+    format: E::Format,
+    /// If `true`, the wallets are derived at hardened child indices
+    hardened: bool,
+    /// The child index of the next wallet, or `None` once the child indices are exhausted
+    index: Option<u32>,
+}
+
+impl<E: ExtendedPrivateKey> WalletGenerator<E> {
+    /// Returns a wallet generator for the derivation path template, relative to the given extended private key.
+    pub fn new(
+        extended_private_key: &E,
+        path_template: &str,
+        format: &E::Format,
+    ) -> Result<Self, WalletGeneratorError> {
+        let (parent_path, hardened) = match path_template.rsplitn(2, '/').collect::<Vec<&str>>().as_slice() {
+            ["*", parent_path] => (BIP32Path::from_str(parent_path)?, false),
+            ["*'", parent_path] | ["*h", parent_path] => (BIP32Path::from_str(parent_path)?, true),
+            _ => return Err(WalletGeneratorError::InvalidPathTemplate(path_template.into())),
+        };
+
+        Ok(Self {
+            parent: extended_private_key.derive_path(&parent_path)?,
+            parent_path,
+            format: format.clone(),
+            hardened,
+            index: Some(0),
+        })
+    }
+
+    /// Returns a wallet generator for the derivation path template, relative to the master key of the mnemonic.
+    pub fn hd<M: MnemonicExtended<ExtendedPrivateKey = E>>(
+        mnemonic: &M,
+        password: Option<&str>,
+        path_template: &str,
+        format: &E::Format,
+    ) -> Result<Self, WalletGeneratorError> {
+        Self::new(&mnemonic.to_extended_private_key(password)?, path_template, format)
+    }
+
+    /// Returns the wallet at the given child index of the template.
+    pub fn wallet(&self, index: u32) -> Result<GeneratedWallet<E>, WalletGeneratorError> {
+        let index = match self.hardened {
+            true => ChildIndex::hardened(index)?,
+            false => ChildIndex::normal(index)?,
+        };
+        let extended_private_key = self.parent.derive_path(&BIP32Path::default().child(index)?)?;
+        Ok((
+            self.parent_path.child(index)?,
+            extended_private_key.to_private_key(),
+            extended_private_key.to_address(&self.format)?,
+        ))
+    }
+}
+
+impl<E: ExtendedPrivateKey> Iterator for WalletGenerator<E> {
+    type Item = Result<GeneratedWallet<E>, WalletGeneratorError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    /// Skips the first `n` child indices without deriving their wallets.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let index = u32::try_from(n)
+            .ok()
+            .and_then(|n| self.index?.checked_add(n))
+            .filter(|index| *index < 1 << 31);
+        self.index = index.map(|index| index + 1).filter(|index| *index < 1 << 31);
+        index.map(|index| self.wallet(index))
+    }
+}
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum WalletGeneratorError {
+    #[error("{0}")]
+    AddressError(AddressError),
+
+    #[error("{0}")]
+    DerivationPathError(DerivationPathError),
+
+    #[error("{0}")]
+    ExtendedPrivateKeyError(ExtendedPrivateKeyError),
+
+    #[error("invalid path template, expected a last child index of `*` or `*'`: {0}")]
+    InvalidPathTemplate(String),
+
+    #[error("{0}")]
+    MnemonicError(MnemonicError),
+}
+
+impl From<AddressError> for WalletGeneratorError {
+    fn from(error: AddressError) -> Self {
+        WalletGeneratorError::AddressError(error)
+    }
+}
+
+impl From<DerivationPathError> for WalletGeneratorError {
+    fn from(error: DerivationPathError) -> Self {
+        WalletGeneratorError::DerivationPathError(error)
+    }
+}
+
+impl From<ExtendedPrivateKeyError> for WalletGeneratorError {
+    fn from(error: ExtendedPrivateKeyError) -> Self {
+        WalletGeneratorError::ExtendedPrivateKeyError(error)
+    }
+}
+
+impl From<MnemonicError> for WalletGeneratorError {
+    fn from(error: MnemonicError) -> Self {
+        WalletGeneratorError::MnemonicError(error)
+    }
+}
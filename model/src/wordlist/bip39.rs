@@ -1,3 +1,8 @@
+use crate::no_std::*;
+use crate::wordlist::WordlistError;
+use core::{fmt, str::FromStr};
+use sha2::{Digest, Sha256};
+
 pub const CHINESE_SIMPLIFIED: &'static str = include_str!("./bip39/chinese_simplified.txt");
 pub const CHINESE_TRADITIONAL: &'static str = include_str!("./bip39/chinese_traditional.txt");
 pub const ENGLISH: &'static str = include_str!("./bip39/english.txt");
@@ -6,3 +11,219 @@ pub const ITALIAN: &'static str = include_str!("./bip39/italian.txt");
 pub const JAPANESE: &'static str = include_str!("./bip39/japanese.txt");
 pub const KOREAN: &'static str = include_str!("./bip39/korean.txt");
 pub const SPANISH: &'static str = include_str!("./bip39/spanish.txt");
+
+/// Represents the language of a BIP39 wordlist
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Bip39Language {
+    ChineseSimplified,
+    ChineseTraditional,
+    English,
+    French,
+    Italian,
+    Japanese,
+    Korean,
+    Spanish,
+}
+
+impl Bip39Language {
+    /// The BIP39 languages, in the order of preference for interchangeable wordlists
+    pub const ALL: [Bip39Language; 8] = [
+        Bip39Language::ChineseSimplified,
+        Bip39Language::ChineseTraditional,
+        Bip39Language::English,
+        Bip39Language::French,
+        Bip39Language::Italian,
+        Bip39Language::Japanese,
+        Bip39Language::Korean,
+        Bip39Language::Spanish,
+    ];
+
+    /// Returns the wordlist of the language, with one word per line.
+    pub fn wordlist(&self) -> &'static str {
+        match self {
+            Bip39Language::ChineseSimplified => CHINESE_SIMPLIFIED,
+            Bip39Language::ChineseTraditional => CHINESE_TRADITIONAL,
+            Bip39Language::English => ENGLISH,
+            Bip39Language::French => FRENCH,
+            Bip39Language::Italian => ITALIAN,
+            Bip39Language::Japanese => JAPANESE,
+            Bip39Language::Korean => KOREAN,
+            Bip39Language::Spanish => SPANISH,
+        }
+    }
+
+    /// Returns the index of the given word in the wordlist of the language, if it exists.
+    pub fn get_index(&self, word: &str) -> Option<usize> {
+        self.wordlist().lines().position(|candidate| candidate == word)
+    }
+
+    /// Returns the language of the given mnemonic phrase.
+    ///
+    /// If no wordlist contains every word, the error names the first word missing from
+    /// the wordlist that contains the most words of the phrase. If several wordlists contain
+    /// every word, the checksum of the phrase is used to tell them apart. Wordlists that map
+    /// the phrase to the same indices are interchangeable, and the first of them is returned.
+    /// If the phrase has an invalid checksum in every candidate wordlist, the first candidate
+    /// is returned, so that the checksum error is reported when the phrase is parsed.
+    pub fn detect_language(phrase: &str) -> Result<Self, WordlistError> {
+        let words = phrase.split_whitespace().collect::<Vec<&str>>();
+        if words.is_empty() {
+            return Err(WordlistError::InvalidWord(phrase.into()));
+        }
+
+        let candidates = Self::ALL
+            .iter()
+            .filter(|language| words.iter().all(|word| language.get_index(word).is_some()))
+            .cloned()
+            .collect::<Vec<Self>>();
+
+        if candidates.is_empty() {
+            let closest = Self::ALL
+                .iter()
+                .rev()
+                .max_by_key(|language| words.iter().filter(|word| language.get_index(word).is_some()).count())
+                .unwrap_or(&Bip39Language::English);
+            let word = words.iter().find(|word| closest.get_index(word).is_none());
+            return Err(WordlistError::InvalidWord(word.unwrap_or(&phrase).to_string()));
+        }
+
+        let valid = candidates
+            .iter()
+            .filter(|language| language.is_valid_checksum(&words))
+            .cloned()
+            .collect::<Vec<Self>>();
+
+        match valid.as_slice() {
+            [] => Ok(candidates[0]),
+            [language, remaining @ ..] => {
+                let indices = language.to_indices(&words);
+                match remaining.iter().all(|other| other.to_indices(&words) == indices) {
+                    true => Ok(*language),
+                    false => Err(WordlistError::AmbiguousLanguage(valid)),
+                }
+            }
+        }
+    }
+
+    /// Returns the indices of the given words in the wordlist of the language.
+    fn to_indices(self, words: &[&str]) -> Vec<Option<usize>> {
+        words.iter().map(|word| self.get_index(word)).collect()
+    }
+
+    /// Returns `true` if the given words form a phrase with a valid BIP39 checksum.
+    fn is_valid_checksum(&self, words: &[&str]) -> bool {
+        if words.len() < 12 || words.len() > 24 || !words.len().is_multiple_of(3) {
+            return false;
+        }
+
+        let mut bits = Vec::with_capacity(words.len() * 11);
+        for index in self.to_indices(words) {
+            match index {
+                Some(index) => (0..11).rev().for_each(|i| bits.push((index >> i) & 1 == 1)),
+                None => return false,
+            }
+        }
+
+        let checksum_length = words.len() / 3;
+        let (entropy, checksum) = bits.split_at(bits.len() - checksum_length);
+        let entropy = entropy
+            .chunks(8)
+            .map(|byte| byte.iter().fold(0u8, |acc, &bit| (acc << 1) | bit as u8))
+            .collect::<Vec<u8>>();
+        let hash = Sha256::digest(&entropy);
+
+        checksum
+            .iter()
+            .enumerate()
+            .all(|(i, &bit)| bit == ((hash[0] >> (7 - i)) & 1 == 1))
+    }
+}
+
+impl FromStr for Bip39Language {
+    type Err = WordlistError;
+
+    fn from_str(language: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|candidate| candidate.to_string() == language)
+            .cloned()
+            .ok_or_else(|| WordlistError::InvalidLanguage(language.into()))
+    }
+}
+
+impl fmt::Display for Bip39Language {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Bip39Language::ChineseSimplified => write!(f, "chinese_simplified"),
+            Bip39Language::ChineseTraditional => write!(f, "chinese_traditional"),
+            Bip39Language::English => write!(f, "english"),
+            Bip39Language::French => write!(f, "french"),
+            Bip39Language::Italian => write!(f, "italian"),
+            Bip39Language::Japanese => write!(f, "japanese"),
+            Bip39Language::Korean => write!(f, "korean"),
+            Bip39Language::Spanish => write!(f, "spanish"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_language() {
+        let phrases = [
+            (
+                Bip39Language::English,
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            ),
+            // Every word is also in the English wordlist, but the checksum is only valid in French
+            (
+                Bip39Language::French,
+                "service surprise amateur caution miracle concert badge dragon fatigue salon mobile danger",
+            ),
+            // Every word shares its index in both Chinese wordlists
+            (Bip39Language::ChineseSimplified, "仰 振 啥 侵 合 努 要 接 完 佳 刷 都"),
+            (Bip39Language::ChineseTraditional, "久 沿 袋 班 挺 航 渠 旗 分 泰 呵 煙"),
+        ];
+
+        phrases.iter().for_each(|(language, phrase)| {
+            assert_eq!(*language, Bip39Language::detect_language(phrase).unwrap());
+        });
+    }
+
+    #[test]
+    fn detect_language_invalid_checksum() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        assert_eq!(Bip39Language::English, Bip39Language::detect_language(phrase).unwrap());
+    }
+
+    #[test]
+    fn detect_language_ambiguous() {
+        let phrase = "volume innocent science phrase figure lecture intact concert guide fatigue sentence wagon";
+        match Bip39Language::detect_language(phrase) {
+            Err(WordlistError::AmbiguousLanguage(languages)) => {
+                assert_eq!(vec![Bip39Language::English, Bip39Language::French], languages)
+            }
+            result => panic!("expected an ambiguous language, found {:?}", result),
+        }
+    }
+
+    #[test]
+    fn detect_language_invalid_word() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandonn abandon abandon abandon abandon about";
+        match Bip39Language::detect_language(phrase) {
+            Err(WordlistError::InvalidWord(word)) => assert_eq!("abandonn", word),
+            result => panic!("expected an invalid word, found {:?}", result),
+        }
+        assert!(Bip39Language::detect_language("").is_err());
+    }
+
+    #[test]
+    fn from_str() {
+        Bip39Language::ALL.iter().for_each(|language| {
+            assert_eq!(*language, Bip39Language::from_str(&language.to_string()).unwrap());
+        });
+        assert!(Bip39Language::from_str("klingon").is_err());
+    }
+}
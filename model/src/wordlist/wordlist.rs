@@ -1,14 +1,22 @@
 use crate::no_std::*;
+use crate::wordlist::Bip39Language;
 use core::{fmt::Debug, hash::Hash};
 
 /// The interface for a generic network.
 pub trait Wordlist: Copy + Clone + Debug + Send + Sync + 'static + Eq + Ord + Sized + Hash {}
 
-#[derive(Debug, Fail)]
+#[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum WordlistError {
-    #[fail(display = "invalid index: {}", _0)]
+    #[error("ambiguous language, the phrase is valid in: {}", .0.iter().map(|language| language.to_string()).collect::<Vec<String>>().join(", "))]
+    AmbiguousLanguage(Vec<Bip39Language>),
+
+    #[error("invalid index: {0}")]
     InvalidIndex(usize),
 
-    #[fail(display = "invalid word: {}", _0)]
+    #[error("invalid language: {0}")]
+    InvalidLanguage(String),
+
+    #[error("invalid word: {0}")]
     InvalidWord(String),
 }
@@ -1,14 +1,83 @@
 use crate::format::MoneroFormat;
-use crate::network::MoneroNetwork;
+use crate::network::{AnyNetwork, Mainnet, MoneroNetwork, Stagenet, Testnet};
 use crate::private_key::MoneroPrivateKey;
 use crate::public_key::MoneroPublicKey;
-use wagyu_model::no_std::{vec, String, ToString};
+use wagyu_model::no_std::{format, vec, String, ToString};
 use wagyu_model::{Address, AddressError, PrivateKey};
 
 use base58_monero as base58;
 use core::{convert::TryFrom, fmt, marker::PhantomData, str::FromStr};
+use curve25519_dalek::edwards::CompressedEdwardsY;
 use tiny_keccak::keccak256;
 
+/// Represents the fields of a Monero address of any network, decoded from its base58 encoding
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MoneroDecodedAddress {
+    /// The network of the address prefix
+    pub network: AnyNetwork,
+    /// The format of the address prefix, with the payment id of an integrated address
+    pub format: MoneroFormat,
+    /// The public spending key
+    pub public_spend_key: [u8; 32],
+    /// The public viewing key
+    pub public_view_key: [u8; 32],
+}
+
+impl MoneroDecodedAddress {
+    /// Returns the decoded fields of the given address, after checking its length, prefix, checksum,
+    /// and that its public keys are points of the curve.
+    pub fn decode(address: &str) -> Result<Self, AddressError> {
+        let bytes = base58::decode(address)?;
+        let format = MoneroFormat::from_address(&bytes)?;
+
+        let network = match bytes[0] {
+            prefix if Mainnet::from_address_prefix(prefix).is_ok() => AnyNetwork::Mainnet,
+            prefix if Stagenet::from_address_prefix(prefix).is_ok() => AnyNetwork::Stagenet,
+            prefix if Testnet::from_address_prefix(prefix).is_ok() => AnyNetwork::Testnet,
+            prefix => return Err(AddressError::InvalidPrefix(vec![prefix])),
+        };
+
+        let (data, checksum) = bytes.split_at(bytes.len() - 4);
+        let expected = &keccak256(data)[0..4];
+        if expected != checksum {
+            return Err(AddressError::InvalidChecksum(
+                base58::encode(expected)?,
+                base58::encode(checksum)?,
+            ));
+        }
+
+        let mut public_spend_key = [0u8; 32];
+        public_spend_key.copy_from_slice(&data[1..33]);
+        let mut public_view_key = [0u8; 32];
+        public_view_key.copy_from_slice(&data[33..65]);
+
+        for (name, key) in &[("spend", public_spend_key), ("view", public_view_key)] {
+            if CompressedEdwardsY(*key).decompress().is_none() {
+                return Err(AddressError::Message(format!(
+                    "invalid public {} key {}, which is not a point of the curve",
+                    name,
+                    hex::encode(key)
+                )));
+            }
+        }
+
+        Ok(Self {
+            network,
+            format,
+            public_spend_key,
+            public_view_key,
+        })
+    }
+
+    /// Returns the payment id of an integrated address, or returns `None`.
+    pub fn to_payment_id(&self) -> Option<[u8; 8]> {
+        match self.format {
+            MoneroFormat::Integrated(payment_id) => Some(payment_id),
+            _ => None,
+        }
+    }
+}
+
 /// Represents a Monero address
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct MoneroAddress<N: MoneroNetwork> {
@@ -151,29 +220,21 @@ impl<N: MoneroNetwork> FromStr for MoneroAddress<N> {
         if address.len() != 95 && address.len() != 106 {
             return Err(AddressError::InvalidCharacterLength(address.len()));
         }
-        let bytes = base58::decode(address)?;
+        let decoded = MoneroDecodedAddress::decode(address)?;
 
         // Check that the network byte correspond with the correct network.
-        let _ = N::from_address_prefix(bytes[0])?;
-        let format = MoneroFormat::from_address(&bytes)?;
-
-        let (checksum_bytes, checksum) = match format {
-            MoneroFormat::Standard | MoneroFormat::Subaddress(_, _) => (&bytes[0..65], &bytes[65..69]),
-            MoneroFormat::Integrated(_) => (&bytes[0..73], &bytes[73..77]),
-        };
-
-        let verify_checksum = &keccak256(checksum_bytes);
-        if &verify_checksum[0..4] != checksum {
-            let expected = base58::encode(&verify_checksum[0..4])?;
-            let found = base58::encode(checksum)?;
-            return Err(AddressError::InvalidChecksum(expected, found));
+        if decoded.network.name() != N::NAME {
+            return Err(AddressError::InvalidNetwork(
+                N::NAME.into(),
+                decoded.network.name().into(),
+            ));
         }
 
-        let public_spend_key = hex::encode(&bytes[1..33]);
-        let public_view_key = hex::encode(&bytes[33..65]);
-        let public_key = MoneroPublicKey::from(public_spend_key.as_str(), public_view_key.as_str(), &format)?;
+        let public_spend_key = hex::encode(&decoded.public_spend_key);
+        let public_view_key = hex::encode(&decoded.public_view_key);
+        let public_key = MoneroPublicKey::from(public_spend_key.as_str(), public_view_key.as_str(), &decoded.format)?;
 
-        Self::generate_address(&public_key, &format)
+        Self::generate_address(&public_key, &decoded.format)
     }
 }
 
@@ -423,6 +484,137 @@ mod tests {
         }
     }
 
+    mod standard_stagenet {
+        use super::*;
+
+        type N = Stagenet;
+        const FORMAT: &MoneroFormat = &MoneroFormat::Standard;
+
+        const KEYPAIRS: [(&str, &str); 3] = [
+            (
+                "f6aceb9caa1d04bb3a6a3d5614a731dd58d24da957f33448fa50600c3d928404",
+                "53BwHWZU5Qk4aRLYS82WNXfgY1eK8XH2V4hgwPjyuAEE56M4tbxqyLATxSrKPtxxEQETnhmFxW741RMYTaM9neiWCNEEQdD",
+            ),
+            (
+                "7130e7a7657a75590fc00c2926bbcbd252044ca2210fde0dc74a6dfdd2512501",
+                "54o1mqQJ2ZD2qpYQV74zxdZt9H3bQiFba57K9Gdj118CKg7XLvyMtyA21qnzvKcFxw7zSH6yE4SaZMiTzyLzSjNT1qikVvi",
+            ),
+            (
+                "a22b4a3418db16214f1a278e1f0b115ede224f043bc1d0596a74f9748f41b00b",
+                "52BJnRLH4SdKfZYggXQn9GCz27cbKaTSTDh3dAWDh8kGD8xVAVqEhATQErgFZYVG1AASYmzuMA9pMP9V92fW71uKDvxXWUi",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(seed, address)| {
+                let private_key = MoneroPrivateKey::<N>::from_seed(seed, FORMAT).unwrap();
+                test_from_private_key(address, &private_key, FORMAT);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, address)| {
+                test_from_str::<N>(address);
+            });
+        }
+
+        #[test]
+        fn other_networks() {
+            KEYPAIRS.iter().for_each(|(_, address)| {
+                assert!(MoneroAddress::<Mainnet>::from_str(address).is_err());
+                assert!(MoneroAddress::<Testnet>::from_str(address).is_err());
+            });
+        }
+    }
+
+    mod decoded_address {
+        use super::*;
+
+        #[test]
+        fn decode() {
+            let address =
+                "42yuCfeWRoe4aRLYS82WNXfgY1eK8XH2V4hgwPjyuAEE56M4tbxqyLATxSrKPtxxEQETnhmFxW741RMYTaM9neiWCK2uvkW";
+            let public_key = MoneroAddress::<Mainnet>::from_str(address)
+                .unwrap()
+                .to_public_key()
+                .unwrap();
+            let decoded = MoneroDecodedAddress::decode(address).unwrap();
+            assert_eq!(AnyNetwork::Mainnet, decoded.network);
+            assert_eq!(MoneroFormat::Standard, decoded.format);
+            assert_eq!(public_key.to_public_spend_key(), Some(decoded.public_spend_key));
+            assert_eq!(public_key.to_public_view_key(), Some(decoded.public_view_key));
+            assert_eq!(None, decoded.to_payment_id());
+
+            let integrated =
+                "4CgaDUU135A4aRLYS82WNXfgY1eK8XH2V4hgwPjyuAEE56M4tbxqyLATxSrKPtxxEQETnhmFxW741RMYTaM9neiWHo4qiLKX62u76x816i";
+            let decoded_integrated = MoneroDecodedAddress::decode(integrated).unwrap();
+            assert_eq!(
+                "67feb00802e01236",
+                hex::encode(decoded_integrated.to_payment_id().unwrap())
+            );
+            assert_eq!(decoded.public_spend_key, decoded_integrated.public_spend_key);
+
+            let subaddress =
+                "83NfRiAk5AiBZDyTjGDejpap8VJDgspsyQhHfGZAVATpfPwaJ9SXRcHEkTC8chu8gEcrudMymLT8dFKkidrqTEVRKApoPSx";
+            match MoneroDecodedAddress::decode(subaddress).unwrap().format {
+                MoneroFormat::Subaddress(_, _) => {}
+                format => panic!("unexpected format {}", format),
+            };
+
+            let stagenet =
+                "53BwHWZU5Qk4aRLYS82WNXfgY1eK8XH2V4hgwPjyuAEE56M4tbxqyLATxSrKPtxxEQETnhmFxW741RMYTaM9neiWCNEEQdD";
+            assert_eq!(
+                AnyNetwork::Stagenet,
+                MoneroDecodedAddress::decode(stagenet).unwrap().network
+            );
+        }
+
+        #[test]
+        fn decode_invalid() {
+            let address =
+                "42yuCfeWRoe4aRLYS82WNXfgY1eK8XH2V4hgwPjyuAEE56M4tbxqyLATxSrKPtxxEQETnhmFxW741RMYTaM9neiWCK2uvkW";
+            let mut bytes = base58::decode(address).unwrap();
+
+            // Invalid checksum
+            bytes[68] ^= 1;
+            let invalid = base58::encode(&bytes).unwrap();
+            assert!(matches!(
+                MoneroDecodedAddress::decode(&invalid),
+                Err(AddressError::InvalidChecksum(_, _))
+            ));
+
+            // Invalid prefix
+            bytes[0] = 17;
+            let invalid = base58::encode(&bytes).unwrap();
+            assert!(matches!(
+                MoneroDecodedAddress::decode(&invalid),
+                Err(AddressError::InvalidPrefix(_))
+            ));
+
+            // Invalid length of an integrated address prefix
+            bytes[0] = 19;
+            let invalid = base58::encode(&bytes).unwrap();
+            assert!(matches!(
+                MoneroDecodedAddress::decode(&invalid),
+                Err(AddressError::InvalidByteLength(69))
+            ));
+
+            // A public spend key which is not a point of the curve, with a valid checksum
+            bytes[0] = 18;
+            bytes[1..33].copy_from_slice(&[2u8; 32]);
+            let checksum = keccak256(&bytes[..65]);
+            bytes[65..].copy_from_slice(&checksum[..4]);
+            let invalid = base58::encode(&bytes).unwrap();
+            assert!(matches!(
+                MoneroDecodedAddress::decode(&invalid),
+                Err(AddressError::Message(_))
+            ));
+            assert!(MoneroAddress::<Mainnet>::from_str(&invalid).is_err());
+        }
+    }
+
     mod invalid_address {
         use super::*;
         type N = Mainnet;
@@ -1,5 +1,5 @@
-use wagyu_model::no_std::ToString;
-use wagyu_model::Amount;
+use wagyu_model::no_std::{format, String, ToString};
+use wagyu_model::{Amount, AmountError};
 
 use core::fmt;
 use serde::Serialize;
@@ -104,6 +104,51 @@ impl MoneroAmount {
         Self::from_piconero(piconeros)
     }
 
+    /// Returns the amount of a decimal value in monero, as in `0.25`, with at most 12 decimal places.
+    pub fn from_monero_str(monero_value: &str) -> Result<Self, AmountError> {
+        let invalid = || AmountError::InvalidAmount(monero_value.into());
+        let (integer, fraction) = match monero_value.find('.') {
+            Some(index) => (&monero_value[..index], &monero_value[index + 1..]),
+            None => (monero_value, ""),
+        };
+        let precision = Denomination::Monero.precision();
+        if (integer.is_empty() && fraction.is_empty())
+            || fraction.len() > precision as usize
+            || !integer.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let integer = match integer.is_empty() {
+            true => 0,
+            false => integer.parse::<i128>().map_err(|_| invalid())?,
+        };
+        let fraction = format!("{:0<1$}", fraction, precision as usize)
+            .parse::<i128>()
+            .map_err(|_| invalid())?;
+        let monero = 10_i128.pow(precision);
+        match integer
+            .checked_mul(monero)
+            .and_then(|piconeros| piconeros.checked_add(fraction))
+        {
+            Some(piconeros) => Ok(Self::from_piconero(piconeros)),
+            None => Err(invalid()),
+        }
+    }
+
+    /// Returns the decimal value of the amount in monero, without trailing zeros, as in `0.25`.
+    pub fn to_monero_string(&self) -> String {
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let monero = 10_i128.pow(Denomination::Monero.precision());
+        let (integer, fraction) = (self.0.abs() / monero, self.0.abs() % monero);
+        match fraction {
+            0 => format!("{}{}", sign, integer),
+            _ => format!("{}{}.{:012}", sign, integer, fraction)
+                .trim_end_matches('0')
+                .to_string(),
+        }
+    }
+
     pub fn add(self, b: Self) -> Self {
         Self::from_piconero(self.0 + b.0)
     }
@@ -283,6 +328,32 @@ mod tests {
                 .iter()
                 .for_each(|amounts| test_from_monero(amounts.monero, MoneroAmount(amounts.piconero)));
         }
+
+        #[test]
+        fn test_monero_str_conversion() {
+            TEST_AMOUNTS.iter().for_each(|amounts| {
+                let amount = MoneroAmount::from_monero_str(&amounts.monero.to_string()).unwrap();
+                assert_eq!(MoneroAmount(amounts.piconero), amount);
+                assert_eq!(amounts.monero.to_string(), amount.to_monero_string());
+            });
+            [
+                ("0.25", 250000000000),
+                ("1.000000000001", 1000000000001),
+                (".5", 500000000000),
+            ]
+            .iter()
+            .for_each(|(monero_value, piconero)| {
+                assert_eq!(
+                    MoneroAmount(*piconero),
+                    MoneroAmount::from_monero_str(monero_value).unwrap()
+                );
+            });
+            assert_eq!("0.25", MoneroAmount(250000000000).to_monero_string());
+            assert_eq!("-0.000000000001", MoneroAmount(-1).to_monero_string());
+            ["", ".", "-1", "1e12", "0.0000000000001", "1,000"]
+                .iter()
+                .for_each(|monero_value| assert!(MoneroAmount::from_monero_str(monero_value).is_err()));
+        }
     }
 
     mod valid_arithmetic {
@@ -0,0 +1,439 @@
+#![allow(non_snake_case)]
+
+use crate::key_image::hash_to_point;
+use crate::ringct::{hash_to_scalar, random_scalar};
+use wagyu_model::no_std::{vec, Vec};
+
+use curve25519_dalek::{
+    constants::{ED25519_BASEPOINT_POINT, ED25519_BASEPOINT_TABLE},
+    edwards::EdwardsPoint,
+    scalar::Scalar,
+    traits::{IsIdentity, VartimeMultiscalarMul},
+};
+use rand::Rng;
+
+/// The domain separators of the aggregation coefficients and the round hashes, padded to 32 bytes
+const CLSAG_AGG_0: &[u8] = b"CLSAG_agg_0";
+const CLSAG_AGG_1: &[u8] = b"CLSAG_agg_1";
+const CLSAG_ROUND: &[u8] = b"CLSAG_round";
+
+#[derive(Debug, Error)]
+pub enum ClsagError {
+    #[error("invalid real index {0} of a ring of size {1}")]
+    InvalidRealIndex(usize, usize),
+
+    #[error("the commitment to zero does not match the mask difference")]
+    InvalidMask,
+
+    #[error("the private key does not match the public key of the real ring member")]
+    InvalidPrivateKey,
+}
+
+/// Represents a member of a ring, which is an output given by its one time public key and amount commitment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RingMember {
+    /// The one time public key of the output
+    pub key: EdwardsPoint,
+    /// The commitment to the amount of the output
+    pub commitment: EdwardsPoint,
+}
+
+/// Represents a CLSAG ring signature, which proves knowledge of the private key of one ring member
+/// and of a commitment to zero between its amount commitment and the pseudo output commitment.
+/// https://github.com/monero-project/monero/blob/v0.18.1.2/src/ringct/rctSigs.cpp#L238
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Clsag {
+    /// The responses, one per ring member
+    pub s: Vec<Scalar>,
+    /// The challenge of the first ring member
+    pub c1: Scalar,
+    /// The auxiliary key image of the commitment mask, multiplied by the inverse of eight
+    pub D: EdwardsPoint,
+}
+
+impl Clsag {
+    /// Returns the signature of the given message by the ring member at the real index, with its one time private key
+    /// and the difference of its commitment mask and the mask of the pseudo output commitment, along with its key image.
+    pub fn sign<R: Rng>(
+        message: &[u8; 32],
+        ring: &[RingMember],
+        pseudo_output: &EdwardsPoint,
+        real_index: usize,
+        private_key: &Scalar,
+        mask_difference: &Scalar,
+        rng: &mut R,
+    ) -> Result<(Self, EdwardsPoint), ClsagError> {
+        let n = ring.len();
+        let real = match ring.get(real_index) {
+            Some(member) => member,
+            None => return Err(ClsagError::InvalidRealIndex(real_index, n)),
+        };
+        if private_key * &ED25519_BASEPOINT_TABLE != real.key {
+            return Err(ClsagError::InvalidPrivateKey);
+        }
+        if mask_difference * &ED25519_BASEPOINT_TABLE != real.commitment - pseudo_output {
+            return Err(ClsagError::InvalidMask);
+        }
+
+        let Hp = hash_to_point(&real.key.compress().to_bytes());
+        let I = private_key * Hp;
+        let D = mask_difference * Hp;
+        let D_inv_eight = D * Scalar::from(8u64).invert();
+
+        let (mu_P, mu_C) = aggregation_coefficients(ring, &I, &D_inv_eight, pseudo_output);
+        let round = round_prefix(ring, pseudo_output, message);
+
+        let a = random_scalar(rng);
+        let mut c = round_hash(&round, &(&a * &ED25519_BASEPOINT_TABLE), &(a * Hp));
+
+        let mut s = vec![Scalar::zero(); n];
+        // The challenge of the first ring member is recorded when the rounds wrap around
+        let mut c1 = c;
+        let mut i = (real_index + 1) % n;
+        while i != real_index {
+            s[i] = random_scalar(rng);
+            c = round_hash(
+                &round,
+                &round_L(&ring[i], pseudo_output, &s[i], &(mu_P * c), &(mu_C * c)),
+                &round_R(&ring[i], &I, &D, &s[i], &(mu_P * c), &(mu_C * c)),
+            );
+            i = (i + 1) % n;
+            if i == 0 {
+                c1 = c;
+            }
+        }
+        s[real_index] = a - c * (mu_P * private_key + mu_C * mask_difference);
+
+        Ok((Self { s, c1, D: D_inv_eight }, I))
+    }
+
+    /// Returns `true` if the signature of the given message is valid for the given ring,
+    /// pseudo output commitment, and key image.
+    pub fn verify(
+        &self,
+        message: &[u8; 32],
+        ring: &[RingMember],
+        pseudo_output: &EdwardsPoint,
+        I: &EdwardsPoint,
+    ) -> bool {
+        if ring.is_empty() || self.s.len() != ring.len() || I.is_identity() || !I.is_torsion_free() {
+            return false;
+        }
+
+        let D = self.D.mul_by_cofactor();
+        let (mu_P, mu_C) = aggregation_coefficients(ring, I, &self.D, pseudo_output);
+        let round = round_prefix(ring, pseudo_output, message);
+
+        let mut c = self.c1;
+        for (member, s) in ring.iter().zip(&self.s) {
+            c = round_hash(
+                &round,
+                &round_L(member, pseudo_output, s, &(mu_P * c), &(mu_C * c)),
+                &round_R(member, I, &D, s, &(mu_P * c), &(mu_C * c)),
+            );
+        }
+        c == self.c1
+    }
+
+    /// Returns the serialized signature, as in the prunable RingCT signatures.
+    /// The number of responses is not serialized, as it is the ring size.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32 * (self.s.len() + 2));
+        self.s.iter().for_each(|s| bytes.extend(s.as_bytes()));
+        bytes.extend(self.c1.as_bytes());
+        bytes.extend(&self.D.compress().to_bytes());
+        bytes
+    }
+}
+
+/// Returns the aggregation coefficients of the one time keys and the commitments to zero.
+fn aggregation_coefficients(
+    ring: &[RingMember],
+    I: &EdwardsPoint,
+    D_inv_eight: &EdwardsPoint,
+    pseudo_output: &EdwardsPoint,
+) -> (Scalar, Scalar) {
+    let coefficient = |domain: &[u8]| {
+        let mut data = padded(domain);
+        ring.iter()
+            .for_each(|member| data.extend(&member.key.compress().to_bytes()));
+        ring.iter()
+            .for_each(|member| data.extend(&member.commitment.compress().to_bytes()));
+        for point in &[I, D_inv_eight, pseudo_output] {
+            data.extend(&point.compress().to_bytes());
+        }
+        hash_to_scalar(&data)
+    };
+    (coefficient(CLSAG_AGG_0), coefficient(CLSAG_AGG_1))
+}
+
+/// Returns the prefix of the round hashes, which commits to the ring, the pseudo output commitment, and the message.
+fn round_prefix(ring: &[RingMember], pseudo_output: &EdwardsPoint, message: &[u8; 32]) -> Vec<u8> {
+    let mut data = padded(CLSAG_ROUND);
+    ring.iter()
+        .for_each(|member| data.extend(&member.key.compress().to_bytes()));
+    ring.iter()
+        .for_each(|member| data.extend(&member.commitment.compress().to_bytes()));
+    data.extend(&pseudo_output.compress().to_bytes());
+    data.extend(message);
+    data
+}
+
+/// Returns the challenge of the next ring member.
+fn round_hash(prefix: &[u8], L: &EdwardsPoint, R: &EdwardsPoint) -> Scalar {
+    let mut data = prefix.to_vec();
+    data.extend(&L.compress().to_bytes());
+    data.extend(&R.compress().to_bytes());
+    hash_to_scalar(&data)
+}
+
+/// Returns L = s * G + c_p * P + c_c * (C - C_offset).
+fn round_L(member: &RingMember, pseudo_output: &EdwardsPoint, s: &Scalar, c_p: &Scalar, c_c: &Scalar) -> EdwardsPoint {
+    EdwardsPoint::vartime_multiscalar_mul(
+        &[*s, *c_p, *c_c],
+        &[ED25519_BASEPOINT_POINT, member.key, member.commitment - pseudo_output],
+    )
+}
+
+/// Returns R = s * Hp(P) + c_p * I + c_c * D.
+fn round_R(
+    member: &RingMember,
+    I: &EdwardsPoint,
+    D: &EdwardsPoint,
+    s: &Scalar,
+    c_p: &Scalar,
+    c_c: &Scalar,
+) -> EdwardsPoint {
+    let Hp = hash_to_point(&member.key.compress().to_bytes());
+    EdwardsPoint::vartime_multiscalar_mul(&[*s, *c_p, *c_c], &[Hp, *I, *D])
+}
+
+/// Returns the domain separator padded with zeros to 32 bytes.
+fn padded(domain: &[u8]) -> Vec<u8> {
+    let mut data = domain.to_vec();
+    data.resize(32, 0);
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ringct::commit;
+    use curve25519_dalek::edwards::CompressedEdwardsY;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    const MESSAGE: [u8; 32] = [7u8; 32];
+
+    /// Returns a ring of the given size, the one time private key and commitment mask of the real member,
+    /// and the pseudo output commitment with its mask.
+    fn ring(
+        size: usize,
+        real_index: usize,
+        rng: &mut StdRng,
+    ) -> (Vec<RingMember>, Scalar, Scalar, EdwardsPoint, Scalar) {
+        let amount = 1_000_000;
+        let (private_key, mask, pseudo_mask) = (random_scalar(rng), random_scalar(rng), random_scalar(rng));
+        let ring = (0..size)
+            .map(|i| match i == real_index {
+                true => RingMember {
+                    key: &private_key * &ED25519_BASEPOINT_TABLE,
+                    commitment: commit(amount, &mask),
+                },
+                false => RingMember {
+                    key: &random_scalar(rng) * &ED25519_BASEPOINT_TABLE,
+                    commitment: commit(rng.gen(), &random_scalar(rng)),
+                },
+            })
+            .collect();
+        (ring, private_key, mask, commit(amount, &pseudo_mask), pseudo_mask)
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        for (size, real_index) in &[(1, 0), (2, 1), (11, 0), (11, 5), (16, 15)] {
+            let (ring, private_key, mask, pseudo_output, pseudo_mask) = ring(*size, *real_index, rng);
+            let (signature, key_image) = Clsag::sign(
+                &MESSAGE,
+                &ring,
+                &pseudo_output,
+                *real_index,
+                &private_key,
+                &(mask - pseudo_mask),
+                rng,
+            )
+            .unwrap();
+
+            assert!(signature.verify(&MESSAGE, &ring, &pseudo_output, &key_image));
+            assert_eq!(
+                private_key * hash_to_point(&ring[*real_index].key.compress().to_bytes()),
+                key_image
+            );
+            assert_eq!(32 * (size + 2), signature.to_bytes().len());
+        }
+    }
+
+    #[test]
+    fn verify_vector() {
+        // The first input of a CLSAG transaction of the monero-oxide test vectors,
+        // with the one time keys and commitments of its ring read from the chain
+        let point = |point: &str| {
+            let mut bytes = [0u8; 32];
+            bytes.copy_from_slice(&hex::decode(point).unwrap());
+            CompressedEdwardsY(bytes).decompress().unwrap()
+        };
+        let scalar = |scalar: &str| {
+            let mut bytes = [0u8; 32];
+            bytes.copy_from_slice(&hex::decode(scalar).unwrap());
+            Scalar::from_canonical_bytes(bytes).unwrap()
+        };
+
+        let ring = [
+            (
+                "a1abc026eb4a18ca197ca7dbd32f7a4e66cda075a7c07ee6cbe68639a4b4ee46",
+                "48d7f0b8796720c7edef5e3797135b3e5ad2ae23db1d934bcf6d6bc396b8ed47",
+            ),
+            (
+                "a374121e22ed620248c970e7f32ea7598b054f73c1edec33c4e1b18a73c35c14",
+                "15beeeedc9b33615097e0fac0acc6a0984e139fa2b4196896877a8cc3ebc3590",
+            ),
+            (
+                "e2ac4d36f9567092563a09c7a19c5e21c39598f5d9d9dd8733b61cebb3ea8662",
+                "3d9105f85f9edd3f7f72b62385bb9a42d549331d3babea6cf73bbbcde8e4f53c",
+            ),
+            (
+                "68c08bbbfdb3ad736dfed5854264a3b410de40d8f3d02b22f5cf75f69f6e2e1f",
+                "36c39958ddcad401d85d63883da510505650321ad7a26859e8b1b6c28204d274",
+            ),
+            (
+                "7b8b580f7a2288040a0755810c5708c5a8277d139762545082785260275678e4",
+                "498105ec1dc7559becfb833140c5049382b846eff812616a2414494d7a46930d",
+            ),
+            (
+                "348d9be3f2b42686c2a919ba1515c5a540c5ffb4c1762e4a371b42643ff69b3b",
+                "eeca9ed04ba72a89dbd85564cf3084daad577634db09d048895524f1ded26b19",
+            ),
+            (
+                "91a59666453bcc55d2a02480dfe2029082e24548cdfd7d614be31657fdd75357",
+                "ae7f14cbb31d24b727d8680fbd03bcc177fc67b982edeca54e6b2b47d6b8d012",
+            ),
+            (
+                "9868cb5201d4b00e5a3552a7f485662dfb3ca74b79f6bd069ee0a4650597abbc",
+                "570e3b126e429022177d22fd09d73c6950676c82a4872addb3afa950646c5f1d",
+            ),
+            (
+                "56d05fced0eb9dda981a26fdd4170f46de2b0a35c70f02ceae23ad9f2ed8a5b0",
+                "a0e20ecd8526bd2a640c4df42c187fcf75d05660ba61262c93b19384b8fad49b",
+            ),
+            (
+                "9e82f65349da1e0dacf5d96a9c0f80c0c5fd0fc2437cafbcc38b2f20e721abc5",
+                "e83344061c0632631eec627bb2103898cfc230b35e0177681e48f0ee4b6d37c8",
+            ),
+            (
+                "2590a255607ab619fcd62142f4b002818f2d55dbb5b8665500854203b83e5c86",
+                "e9c103485b3f4dadab560e8efc67c594ba11f16513685f0faff78c6fdf4de061",
+            ),
+            (
+                "c0e22332d897f0637440ad151089652e59dcbf27dc84b11c2efbe686a9e7afb5",
+                "363d5dcbc765854e830dc52762e24f71d7c85f6095227551f3ef6ada6aa25964",
+            ),
+            (
+                "360e4efb484e8d419bdda5f581703de716671e3516d1c9deb97204f9b4c9c0d4",
+                "29ef141fa24ef86af35af48094928392543a9e7e7726ae92a9da322178e680ad",
+            ),
+            (
+                "5bb515d131f03bbb3be4e710b83589f62f07f185b9ad344095df47092f41b8e0",
+                "94fd6083b669533eebfa49a1cb47b94555e8be7d5f84573354b0201229d07bed",
+            ),
+            (
+                "5ce647c3017ec3c36a2385e2b11fb9a452a5766987d80531bec75952924ed896",
+                "8f61d7be3b4f2252810fbade3bbac970ccff55c453e34405836545f3e49be6f5",
+            ),
+            (
+                "dbc787f7ca41996a981a0ebb498a8d565dfa62a3b3b169c4c3018fff2233a757",
+                "9bb749be705747d9c28168c0446d589b3ac18949fa0087e230805aaff5a9982f",
+            ),
+        ]
+        .iter()
+        .map(|(key, commitment)| RingMember {
+            key: point(key),
+            commitment: point(commitment),
+        })
+        .collect::<Vec<_>>();
+        let s = [
+            "b055da149139c347f7c0b2a381dfaa12aaaabe076f38fe12372d1ba17cd0d808",
+            "ed5b4b911f8cee2e45841a4c879f40968e455ba5a796b27c968be0f7e88daf0b",
+            "766fcf2c5986fbe14b2e0433cecb04af100ec81d03e2875d25483d0a9dc9dc0a",
+            "42150a64e894af1655e9ab99f629826f63c01e44b366c5fe2959c7396450360a",
+            "3156ad081764b5904a7654fe82a2b1d52db46361c0b08dfeee383165641e6e0e",
+            "5733e5fb99fc8c75ba5cf230518b1e384d4441251840e810aed950eb27899809",
+            "711d42c54f8fc0647537e249e510738412c399b915ff923e9209cdd12820720b",
+            "8b07086f3361d6b95934f994a8ac4fb6a9598f11d54bbbcfc33e71b9f7357001",
+            "2b3520914dfab3f3fe15abad981d8ed71dab71ac8f45f187f62ad440a83d000e",
+            "08fc039ece25e7eadd0ce169ccda8182321cd73eba6f6d0e4f482a061eb4190f",
+            "e4051e6988a47165cb2cf39973b1a555cc92d662f4e856a91c0cd51a486b960c",
+            "fc850c4fc854f9a4aade4336942cb50cb50ae3bc31d3da50b719196d5fd40f02",
+            "b1addad16de443e825bf7177beaac79adc6b198115f408a391a94a8517b7e50f",
+            "d57663df52309c0a00b0b61373f895206771be8b185c54da6f805b561264aa01",
+            "9ef3bd1dcded26fc45a6a0e39cbb7bc6a7025ab858bc8e54a99da3aedce68f00",
+            "bacc83a7eb3553ac626881188329b6ba86a53aaaaed9bd9efb0528f08c649c09",
+        ];
+        let signature = Clsag {
+            s: s.iter().map(|s| scalar(s)).collect(),
+            c1: scalar("3f005dd0fa9620b0a40fc3f248c1d0edb8f70ff05c7254de0f8faab831544302"),
+            D: point("1b3d279f5a4218c3126dee5d6eceae1c49eabdd04d8a0cdb6814c422b3ea69b3"),
+        };
+
+        let mut message = [0u8; 32];
+        message
+            .copy_from_slice(&hex::decode("8311c33650ac49e94bb1227895f70e6e4424dedc9ac56c32a8d768955f96de8a").unwrap());
+        let pseudo_output = point("1374d7aa7f6e6f4a5b340a9954d9cf8bd5d2f4b4a37f946e15bca800978ae745");
+        let key_image = point("d8c6f077bb201ffdc16407df206cb5962ec635a4a4c9cd7551b88698d1bef497");
+
+        assert!(signature.verify(&message, &ring, &pseudo_output, &key_image));
+        assert!(!signature.verify(&MESSAGE, &ring, &pseudo_output, &key_image));
+
+        let mut invalid = signature.clone();
+        invalid.c1 += Scalar::one();
+        assert!(!invalid.verify(&message, &ring, &pseudo_output, &key_image));
+    }
+
+    #[test]
+    fn invalid_signature() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let (ring, private_key, mask, pseudo_output, pseudo_mask) = ring(11, 3, rng);
+        let (signature, key_image) = Clsag::sign(
+            &MESSAGE,
+            &ring,
+            &pseudo_output,
+            3,
+            &private_key,
+            &(mask - pseudo_mask),
+            rng,
+        )
+        .unwrap();
+
+        assert!(!signature.verify(&[8u8; 32], &ring, &pseudo_output, &key_image));
+        assert!(!signature.verify(&MESSAGE, &ring[1..], &pseudo_output, &key_image));
+        assert!(!signature.verify(&MESSAGE, &ring, &(pseudo_output + ED25519_BASEPOINT_POINT), &key_image));
+        assert!(!signature.verify(&MESSAGE, &ring, &pseudo_output, &(key_image + key_image)));
+
+        let mut invalid = signature.clone();
+        invalid.s[0] += Scalar::one();
+        assert!(!invalid.verify(&MESSAGE, &ring, &pseudo_output, &key_image));
+
+        // The real ring member must match the private key and the commitment to zero
+        assert!(Clsag::sign(
+            &MESSAGE,
+            &ring,
+            &pseudo_output,
+            4,
+            &private_key,
+            &(mask - pseudo_mask),
+            rng
+        )
+        .is_err());
+        assert!(Clsag::sign(&MESSAGE, &ring, &pseudo_output, 3, &private_key, &mask, rng).is_err());
+        assert!(Clsag::sign(&MESSAGE, &ring, &pseudo_output, 11, &private_key, &mask, rng).is_err());
+    }
+}
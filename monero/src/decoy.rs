@@ -0,0 +1,277 @@
+use wagyu_model::no_std::{vec, Vec};
+
+use rand::Rng;
+
+/// The shape of the gamma distribution of the log of the age in seconds of spent outputs
+const GAMMA_SHAPE: f64 = 19.28;
+/// The scale of the gamma distribution of the log of the age in seconds of spent outputs
+const GAMMA_SCALE: f64 = 1.0 / 1.61;
+/// The target time between blocks in seconds
+const DIFFICULTY_TARGET: f64 = 120.0;
+/// The number of blocks until an output is spendable
+pub const SPENDABLE_AGE: u64 = 10;
+/// The default number of members of a ring, including the real output
+pub const DEFAULT_RING_SIZE: usize = 16;
+/// The number of draws per decoy before selection gives up
+const MAX_DRAWS_PER_DECOY: usize = 100;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DecoyError {
+    #[error("insufficient candidates to select {0} decoys, found {1} spendable candidates")]
+    InsufficientCandidates(usize, usize),
+
+    #[error("invalid ring size {0}")]
+    InvalidRingSize(usize),
+
+    #[error("the real output is also a candidate with global index {0}")]
+    RealOutputIsCandidate(u64),
+}
+
+/// Represents a candidate output for a ring, as known to the caller from the blockchain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecoyCandidate {
+    /// The index of the output in its transaction
+    pub index: u64,
+    /// The global index of the output among all RingCT outputs
+    pub global_index: u64,
+    /// The age of the output in blocks
+    pub age: u64,
+}
+
+/// Represents the members of a ring, sorted by global index, and the position of the real output among them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ring {
+    /// The ring members, sorted by global index
+    pub members: Vec<DecoyCandidate>,
+    /// The position of the real output in the members
+    pub real_index: usize,
+}
+
+impl Ring {
+    /// Returns a ring of the given size for the real output, with decoys selected from the given candidates
+    /// by the gamma distribution of the age of spent outputs, as in the reference wallet.
+    /// https://github.com/monero-project/monero/blob/v0.18.1.2/src/wallet/wallet2.cpp#L8513
+    ///
+    /// Each draw samples an age in seconds from the distribution, and selects a candidate uniformly among
+    /// the spendable candidates of the nearest age at least as old, redrawing ages older than every candidate.
+    /// As consensus requires ring members sorted by global index, the real output is at a random position.
+    pub fn new<R: Rng>(
+        real_output: &DecoyCandidate,
+        candidates: &[DecoyCandidate],
+        ring_size: usize,
+        rng: &mut R,
+    ) -> Result<Self, DecoyError> {
+        if ring_size == 0 {
+            return Err(DecoyError::InvalidRingSize(ring_size));
+        }
+        if candidates
+            .iter()
+            .any(|candidate| candidate.global_index == real_output.global_index)
+        {
+            return Err(DecoyError::RealOutputIsCandidate(real_output.global_index));
+        }
+
+        // The spendable candidates, sorted by age
+        let mut spendable: Vec<DecoyCandidate> = candidates
+            .iter()
+            .filter(|candidate| candidate.age >= SPENDABLE_AGE)
+            .cloned()
+            .collect();
+        spendable.sort_by_key(|candidate| (candidate.age, candidate.global_index));
+        spendable.dedup_by_key(|candidate| candidate.global_index);
+
+        let decoys = ring_size - 1;
+        if spendable.len() < decoys {
+            return Err(DecoyError::InsufficientCandidates(decoys, spendable.len()));
+        }
+
+        let oldest = spendable.last().map_or(0, |candidate| candidate.age);
+        let mut selected = vec![false; spendable.len()];
+        let mut members = vec![*real_output];
+        let mut draws = 0;
+        while members.len() < ring_size {
+            draws += 1;
+            if draws > decoys * MAX_DRAWS_PER_DECOY {
+                // Fill the remainder uniformly, if the candidates are too few for the distribution to find
+                let remaining: Vec<usize> = (0..spendable.len()).filter(|i| !selected[*i]).collect();
+                for _ in members.len()..ring_size {
+                    let position = remaining[rng.gen_range(0, remaining.len())];
+                    if !selected[position] {
+                        selected[position] = true;
+                        members.push(spendable[position]);
+                    }
+                }
+                continue;
+            }
+
+            let age = sample_age(rng);
+            if age > oldest {
+                continue;
+            }
+
+            // The candidates of the nearest age at least as old as the sampled age
+            let start = spendable.partition_point(|candidate| candidate.age < age);
+            let nearest = spendable[start].age;
+            let end = spendable.partition_point(|candidate| candidate.age <= nearest);
+
+            let position = rng.gen_range(start, end);
+            if !selected[position] {
+                selected[position] = true;
+                members.push(spendable[position]);
+            }
+        }
+
+        members.sort_by_key(|member| member.global_index);
+        let real_index = members
+            .iter()
+            .position(|member| member.global_index == real_output.global_index)
+            .expect("the real output is a ring member");
+
+        Ok(Self { members, real_index })
+    }
+
+    /// Returns the key offsets of the ring members, which are the differences of successive global indices,
+    /// as serialized in the input of a transaction.
+    pub fn to_key_offsets(&self) -> Vec<u64> {
+        let mut previous = 0;
+        self.members
+            .iter()
+            .map(|member| {
+                let offset = member.global_index - previous;
+                previous = member.global_index;
+                offset
+            })
+            .collect()
+    }
+}
+
+/// Returns an age in blocks sampled from the gamma distribution of the log of the age in seconds of spent outputs,
+/// less the spendable age, as outputs younger than the spendable age can not be spent.
+fn sample_age<R: Rng>(rng: &mut R) -> u64 {
+    let seconds = sample_gamma(rng).exp();
+    let spendable_seconds = SPENDABLE_AGE as f64 * DIFFICULTY_TARGET;
+    let seconds = match seconds > spendable_seconds {
+        true => seconds - spendable_seconds,
+        false => rng.gen_range(0.0, spendable_seconds),
+    };
+    SPENDABLE_AGE + (seconds / DIFFICULTY_TARGET) as u64
+}
+
+/// Returns a sample from the gamma distribution, with the method of Marsaglia and Tsang for a shape of at least one.
+fn sample_gamma<R: Rng>(rng: &mut R) -> f64 {
+    let d = GAMMA_SHAPE - 1.0 / 3.0;
+    let c = 1.0 / (9.0 * d).sqrt();
+    loop {
+        let x = sample_normal(rng);
+        let v = (1.0 + c * x).powi(3);
+        if v <= 0.0 {
+            continue;
+        }
+        let u: f64 = rng.gen();
+        if u.ln() < 0.5 * x * x + d - d * v + d * v.ln() {
+            return d * v * GAMMA_SCALE;
+        }
+    }
+}
+
+/// Returns a sample from the standard normal distribution, with the Box-Muller transform.
+fn sample_normal<R: Rng>(rng: &mut R) -> f64 {
+    let (u, v): (f64, f64) = (1.0 - rng.gen::<f64>(), rng.gen());
+    (-2.0 * u.ln()).sqrt() * (2.0 * core::f64::consts::PI * v).cos()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    const REAL_OUTPUT: DecoyCandidate = DecoyCandidate {
+        index: 0,
+        global_index: 399_800,
+        age: 50,
+    };
+
+    fn candidates(count: u64) -> Vec<DecoyCandidate> {
+        (0..count)
+            .filter(|i| *i != REAL_OUTPUT.global_index)
+            .map(|i| DecoyCandidate {
+                index: i % 2,
+                global_index: i,
+                age: SPENDABLE_AGE + (count - i) / 4,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn new() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let candidates = candidates(400_000);
+        let mut real_indices = vec![];
+        for _ in 0..20 {
+            let ring = Ring::new(&REAL_OUTPUT, &candidates, DEFAULT_RING_SIZE, rng).unwrap();
+            assert_eq!(DEFAULT_RING_SIZE, ring.members.len());
+            assert_eq!(REAL_OUTPUT, ring.members[ring.real_index]);
+            assert!(ring
+                .members
+                .windows(2)
+                .all(|pair| pair[0].global_index < pair[1].global_index));
+            assert!(ring
+                .members
+                .iter()
+                .all(|member| *member == REAL_OUTPUT || member.age >= SPENDABLE_AGE));
+            real_indices.push(ring.real_index);
+        }
+        // The position of the real output varies between rings
+        real_indices.dedup();
+        assert!(real_indices.len() > 1);
+    }
+
+    #[test]
+    fn recent_outputs_are_preferred() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let candidates = candidates(400_000);
+        let ring = Ring::new(&REAL_OUTPUT, &candidates, 101, rng).unwrap();
+
+        // Half of spent outputs are younger than about two days, far younger than the oldest candidates
+        let mut ages: Vec<u64> = ring.members.iter().map(|member| member.age).collect();
+        ages.sort();
+        assert!(ages[50] < 10 * 24 * 30);
+    }
+
+    #[test]
+    fn few_candidates() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let candidates = candidates(40);
+        let ring = Ring::new(&REAL_OUTPUT, &candidates, 4, rng).unwrap();
+        assert_eq!(4, ring.members.len());
+
+        assert_eq!(
+            Err(DecoyError::InsufficientCandidates(15, 10)),
+            Ring::new(&REAL_OUTPUT, &candidates[30..], DEFAULT_RING_SIZE, rng)
+        );
+        assert_eq!(
+            Err(DecoyError::RealOutputIsCandidate(5)),
+            Ring::new(&candidates[5], &candidates, 4, rng)
+        );
+        assert_eq!(
+            Err(DecoyError::InvalidRingSize(0)),
+            Ring::new(&REAL_OUTPUT, &candidates, 0, rng)
+        );
+    }
+
+    #[test]
+    fn key_offsets() {
+        let ring = Ring {
+            members: [10, 25, 26, 1_000]
+                .iter()
+                .map(|global_index| DecoyCandidate {
+                    index: 0,
+                    global_index: *global_index,
+                    age: 100,
+                })
+                .collect(),
+            real_index: 1,
+        };
+        assert_eq!(vec![10, 15, 1, 974], ring.to_key_offsets());
+    }
+}
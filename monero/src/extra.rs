@@ -0,0 +1,431 @@
+use crate::address::MoneroAddress;
+use crate::network::MoneroNetwork;
+use crate::one_time_key::MoneroOneTimeKey;
+use crate::private_key::MoneroPrivateKey;
+use crate::raw_transaction::{decode_varint, encode_varint};
+use wagyu_model::no_std::{String, Vec};
+use wagyu_model::{AddressError, OneTimeKeyError, PublicKeyError, TransactionError};
+
+use core::marker::PhantomData;
+use curve25519_dalek::{constants::ED25519_BASEPOINT_TABLE, scalar::Scalar};
+use tiny_keccak::keccak256;
+
+/// The tag of the padding field of the extra field
+pub const TX_EXTRA_TAG_PADDING: u8 = 0x00;
+/// The tag of the transaction public key field of the extra field
+pub const TX_EXTRA_TAG_PUBKEY: u8 = 0x01;
+/// The tag of the nonce field of the extra field
+pub const TX_EXTRA_NONCE: u8 = 0x02;
+/// The tag of the merge mining field of the extra field
+pub const TX_EXTRA_MERGE_MINING_TAG: u8 = 0x03;
+/// The tag of the additional public keys field of the extra field
+pub const TX_EXTRA_TAG_ADDITIONAL_PUBKEYS: u8 = 0x04;
+/// The tag of the nonce of an unencrypted 32 byte payment id
+pub const TX_EXTRA_NONCE_PAYMENT_ID: u8 = 0x00;
+/// The tag of the nonce of an encrypted 8 byte payment id
+pub const TX_EXTRA_NONCE_ENCRYPTED_PAYMENT_ID: u8 = 0x01;
+
+/// The byte appended to the key derivation to derive the payment id encryption key
+const ENCRYPTED_PAYMENT_ID_TAIL: u8 = 0x8d;
+
+#[derive(Debug, Error)]
+pub enum ExtraError {
+    #[error("{0}")]
+    AddressError(AddressError),
+
+    #[error("destinations have to have exactly one output to support encrypted payment ids")]
+    InvalidDestinations,
+
+    #[error("invalid extra field tag {0}")]
+    InvalidTag(u8),
+
+    #[error("{0}")]
+    OneTimeKeyError(OneTimeKeyError),
+
+    #[error("{0}")]
+    PublicKeyError(PublicKeyError),
+
+    #[error("{0}")]
+    TransactionError(TransactionError),
+}
+
+impl From<AddressError> for ExtraError {
+    fn from(error: AddressError) -> Self {
+        ExtraError::AddressError(error)
+    }
+}
+
+impl From<OneTimeKeyError> for ExtraError {
+    fn from(error: OneTimeKeyError) -> Self {
+        ExtraError::OneTimeKeyError(error)
+    }
+}
+
+impl From<PublicKeyError> for ExtraError {
+    fn from(error: PublicKeyError) -> Self {
+        ExtraError::PublicKeyError(error)
+    }
+}
+
+impl From<TransactionError> for ExtraError {
+    fn from(error: TransactionError) -> Self {
+        ExtraError::TransactionError(error)
+    }
+}
+
+/// Represents the extra field of a Monero transaction, holding the transaction public key,
+/// the additional transaction public keys of outputs to subaddresses, and an optional encrypted payment id
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MoneroTransactionExtra<N: MoneroNetwork> {
+    /// The transaction public key
+    transaction_public_key: [u8; 32],
+    /// The additional transaction public keys, one for each output
+    additional_public_keys: Vec<[u8; 32]>,
+    /// The encrypted payment id
+    encrypted_payment_id: Option<[u8; 8]>,
+    /// PhantomData
+    _network: PhantomData<N>,
+}
+
+impl<N: MoneroNetwork> MoneroTransactionExtra<N> {
+    /// Returns the extra field of a transaction with the given transaction private key to the given destinations,
+    /// as constructed by `construct_tx_with_tx_key` of the reference wallet.
+    ///
+    /// The payment id is encrypted with the public view key of the only destination other than the change address.
+    /// If no payment id is given and `add_dummy_payment_id` is enabled, an encrypted null payment id is added,
+    /// so transactions with and without payment ids are indistinguishable, provided there is a single destination.
+    pub fn new(
+        transaction_private_key: &[u8; 32],
+        payment_id: Option<[u8; 8]>,
+        destinations: &[MoneroAddress<N>],
+        change_address: Option<&MoneroAddress<N>>,
+        add_dummy_payment_id: bool,
+    ) -> Result<Self, ExtraError> {
+        let transaction_public_key = (&Scalar::from_bits(*transaction_private_key) * &ED25519_BASEPOINT_TABLE)
+            .compress()
+            .to_bytes();
+        let public_view_key = Self::destination_public_view_key(destinations, change_address)?;
+
+        let encrypted_payment_id = match (payment_id, public_view_key) {
+            (Some(payment_id), Some(public_view_key)) => Some(Self::encrypt_payment_id(
+                &payment_id,
+                &public_view_key,
+                transaction_private_key,
+            )?),
+            (Some(_), None) => return Err(ExtraError::InvalidDestinations),
+            (None, Some(public_view_key)) if add_dummy_payment_id => Some(Self::encrypt_payment_id(
+                &[0u8; 8],
+                &public_view_key,
+                transaction_private_key,
+            )?),
+            (None, _) => None,
+        };
+
+        Ok(Self {
+            transaction_public_key,
+            additional_public_keys: Vec::new(),
+            encrypted_payment_id,
+            _network: PhantomData,
+        })
+    }
+
+    /// Returns the extra field with the given additional transaction public keys, one for each output,
+    /// which a transaction to a subaddress uses in place of the transaction public key.
+    pub fn with_additional_public_keys(mut self, additional_public_keys: Vec<[u8; 32]>) -> Self {
+        self.additional_public_keys = additional_public_keys;
+        self
+    }
+
+    /// Returns the extra field of the given serialized extra field.
+    pub fn from_bytes(extra: &[u8]) -> Result<Self, ExtraError> {
+        let mut transaction_public_key = None;
+        let mut additional_public_keys = None;
+        let mut encrypted_payment_id = None;
+
+        let mut position = 0;
+        while position < extra.len() {
+            let tag = extra[position];
+            position += 1;
+            let length = match tag {
+                // The padding is the remainder of the extra field
+                TX_EXTRA_TAG_PADDING => extra.len() - position,
+                TX_EXTRA_TAG_PUBKEY => 32,
+                TX_EXTRA_NONCE | TX_EXTRA_MERGE_MINING_TAG => {
+                    let (length, varint_length) = decode_varint(&extra[position..])?;
+                    position += varint_length;
+                    length as usize
+                }
+                TX_EXTRA_TAG_ADDITIONAL_PUBKEYS => {
+                    let (count, varint_length) = decode_varint(&extra[position..])?;
+                    position += varint_length;
+                    (count as usize).saturating_mul(32)
+                }
+                tag => return Err(ExtraError::InvalidTag(tag)),
+            };
+
+            let field = match extra.get(position..position.saturating_add(length)) {
+                Some(field) => field,
+                None => return Err(TransactionError::Message(String::from("extra field is truncated")).into()),
+            };
+            position += length;
+
+            match (tag, field.first()) {
+                (TX_EXTRA_TAG_PUBKEY, _) if transaction_public_key.is_none() => {
+                    let mut key = [0u8; 32];
+                    key.copy_from_slice(field);
+                    transaction_public_key = Some(key);
+                }
+                (TX_EXTRA_TAG_ADDITIONAL_PUBKEYS, _) if additional_public_keys.is_none() => {
+                    additional_public_keys = Some(
+                        field
+                            .chunks(32)
+                            .map(|chunk| {
+                                let mut key = [0u8; 32];
+                                key.copy_from_slice(chunk);
+                                key
+                            })
+                            .collect(),
+                    );
+                }
+                (TX_EXTRA_NONCE, Some(&TX_EXTRA_NONCE_ENCRYPTED_PAYMENT_ID)) if field.len() == 9 => {
+                    let mut payment_id = [0u8; 8];
+                    payment_id.copy_from_slice(&field[1..]);
+                    encrypted_payment_id = Some(payment_id);
+                }
+                _ => {}
+            }
+        }
+
+        match transaction_public_key {
+            Some(transaction_public_key) => Ok(Self {
+                transaction_public_key,
+                additional_public_keys: additional_public_keys.unwrap_or_default(),
+                encrypted_payment_id,
+                _network: PhantomData,
+            }),
+            None => Err(TransactionError::Message(String::from("extra field has no transaction public key")).into()),
+        }
+    }
+
+    /// Returns the serialized extra field, with the transaction public key followed by the payment id nonce
+    /// and the additional transaction public keys, in the order of their tags.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut extra = Vec::with_capacity(44);
+        extra.push(TX_EXTRA_TAG_PUBKEY);
+        extra.extend(&self.transaction_public_key);
+
+        if let Some(encrypted_payment_id) = self.encrypted_payment_id {
+            extra.push(TX_EXTRA_NONCE);
+            extra.extend(encode_varint(9));
+            extra.push(TX_EXTRA_NONCE_ENCRYPTED_PAYMENT_ID);
+            extra.extend(&encrypted_payment_id);
+        }
+
+        if !self.additional_public_keys.is_empty() {
+            extra.push(TX_EXTRA_TAG_ADDITIONAL_PUBKEYS);
+            extra.extend(encode_varint(self.additional_public_keys.len() as u64));
+            self.additional_public_keys.iter().for_each(|key| extra.extend(key));
+        }
+        extra
+    }
+
+    /// Returns the transaction public key.
+    pub fn to_transaction_public_key(&self) -> [u8; 32] {
+        self.transaction_public_key
+    }
+
+    /// Returns the additional transaction public keys, which are empty if the transaction has none.
+    pub fn to_additional_public_keys(&self) -> Vec<[u8; 32]> {
+        self.additional_public_keys.clone()
+    }
+
+    /// Returns the encrypted payment id, or returns `None`.
+    pub fn to_encrypted_payment_id(&self) -> Option<[u8; 8]> {
+        self.encrypted_payment_id
+    }
+
+    /// Returns the decrypted payment id for the recipient with the given private key, or returns `None`.
+    /// A dummy payment id decrypts to the null payment id.
+    pub fn to_payment_id(&self, private_key: &MoneroPrivateKey<N>) -> Result<Option<[u8; 8]>, ExtraError> {
+        match self.encrypted_payment_id {
+            Some(encrypted_payment_id) => Ok(Some(Self::encrypt_payment_id(
+                &encrypted_payment_id,
+                &self.transaction_public_key,
+                &private_key.to_private_view_key(),
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the payment id XOR the keccak256 hash of the key derivation of the given public and secret key.
+    /// Encryption is symmetric: the sender uses the recipient public view key and the transaction private key,
+    /// and the recipient uses the transaction public key and its private view key.
+    pub fn encrypt_payment_id(
+        payment_id: &[u8; 8],
+        public_key: &[u8; 32],
+        secret_key: &[u8; 32],
+    ) -> Result<[u8; 8], ExtraError> {
+        let mut derivation = Vec::<u8>::new();
+        MoneroOneTimeKey::<N>::generate_key_derivation(public_key, secret_key, &mut derivation)?;
+        derivation.push(ENCRYPTED_PAYMENT_ID_TAIL);
+        let hash = keccak256(&derivation);
+
+        let mut encrypted = *payment_id;
+        encrypted
+            .iter_mut()
+            .zip(hash.iter())
+            .for_each(|(byte, key)| *byte ^= key);
+        Ok(encrypted)
+    }
+
+    /// Returns the public view key of the only destination other than the change address, or returns `None`
+    /// if there are several such destinations. If the change address is the only destination, its key is returned.
+    fn destination_public_view_key(
+        destinations: &[MoneroAddress<N>],
+        change_address: Option<&MoneroAddress<N>>,
+    ) -> Result<Option<[u8; 32]>, ExtraError> {
+        let mut destination = None;
+        for address in destinations {
+            if Some(address) == change_address || Some(address) == destination {
+                continue;
+            }
+            if destination.is_some() {
+                return Ok(None);
+            }
+            destination = Some(address);
+        }
+
+        match destination.or(change_address) {
+            Some(address) => match address.to_public_key()?.to_public_view_key() {
+                Some(public_view_key) => Ok(Some(public_view_key)),
+                None => Err(PublicKeyError::NoViewingKey.into()),
+            },
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::MoneroFormat;
+    use crate::Mainnet;
+    use wagyu_model::Address;
+
+    type N = Mainnet;
+
+    const FORMAT: &MoneroFormat = &MoneroFormat::Standard;
+    const TRANSACTION_PRIVATE_KEY: &str = "c91ae3053f640fcad393fb6c74ad9f064c25314c8993c5545306154e070b1f0f";
+
+    fn wallet(seed: &str) -> (MoneroPrivateKey<N>, MoneroAddress<N>) {
+        let private_key = MoneroPrivateKey::<N>::from_seed(seed, FORMAT).unwrap();
+        let address = MoneroAddress::from_private_key(&private_key, FORMAT).unwrap();
+        (private_key, address)
+    }
+
+    fn transaction_private_key() -> [u8; 32] {
+        let mut key = [0u8; 32];
+        key.copy_from_slice(&hex::decode(TRANSACTION_PRIVATE_KEY).unwrap());
+        key
+    }
+
+    #[test]
+    fn encrypted_payment_id() {
+        let (recipient, recipient_address) = wallet("3eb8e283b45559d4d2fb6b3a3f3f6a1b5d6e0e8b5d1b7e7d1f0f2c7f1c3e1a0b");
+        let (_, change_address) = wallet("1d4a2f0e9c8b7a6f5e4d3c2b1a0f9e8d7c6b5a4f3e2d1c0b9a8f7e6d5c4b3a02");
+        let payment_id = [0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0];
+
+        let extra = MoneroTransactionExtra::new(
+            &transaction_private_key(),
+            Some(payment_id),
+            &[recipient_address, change_address.clone()],
+            Some(&change_address),
+            true,
+        )
+        .unwrap();
+        assert_ne!(Some(payment_id), extra.to_encrypted_payment_id());
+
+        let bytes = extra.to_bytes();
+        assert_eq!(44, bytes.len());
+        assert_eq!(TX_EXTRA_TAG_PUBKEY, bytes[0]);
+        assert_eq!(
+            "396fc23bc389046b214087a9522c0fbd673d2f3f00ab9768f35fa52f953fef22",
+            hex::encode(&bytes[1..33])
+        );
+        assert_eq!([TX_EXTRA_NONCE, 9, TX_EXTRA_NONCE_ENCRYPTED_PAYMENT_ID], bytes[33..36]);
+
+        let parsed = MoneroTransactionExtra::<N>::from_bytes(&bytes).unwrap();
+        assert_eq!(extra, parsed);
+        assert_eq!(Some(payment_id), parsed.to_payment_id(&recipient).unwrap());
+    }
+
+    #[test]
+    fn dummy_payment_id() {
+        let (recipient, recipient_address) = wallet("3eb8e283b45559d4d2fb6b3a3f3f6a1b5d6e0e8b5d1b7e7d1f0f2c7f1c3e1a0b");
+        let (_, change_address) = wallet("1d4a2f0e9c8b7a6f5e4d3c2b1a0f9e8d7c6b5a4f3e2d1c0b9a8f7e6d5c4b3a02");
+        let destinations = [recipient_address, change_address.clone()];
+
+        // The dummy payment id is indistinguishable from an encrypted payment id, and decrypts to the null payment id
+        let extra = MoneroTransactionExtra::new(
+            &transaction_private_key(),
+            None,
+            &destinations,
+            Some(&change_address),
+            true,
+        )
+        .unwrap();
+        assert_eq!(44, extra.to_bytes().len());
+        assert_ne!(Some([0u8; 8]), extra.to_encrypted_payment_id());
+        assert_eq!(Some([0u8; 8]), extra.to_payment_id(&recipient).unwrap());
+
+        let extra = MoneroTransactionExtra::new(
+            &transaction_private_key(),
+            None,
+            &destinations,
+            Some(&change_address),
+            false,
+        )
+        .unwrap();
+        assert_eq!(33, extra.to_bytes().len());
+        assert_eq!(None, extra.to_payment_id(&recipient).unwrap());
+    }
+
+    #[test]
+    fn multiple_destinations() {
+        let (_, first) = wallet("3eb8e283b45559d4d2fb6b3a3f3f6a1b5d6e0e8b5d1b7e7d1f0f2c7f1c3e1a0b");
+        let (_, second) = wallet("1d4a2f0e9c8b7a6f5e4d3c2b1a0f9e8d7c6b5a4f3e2d1c0b9a8f7e6d5c4b3a02");
+        let destinations = [first, second];
+
+        // A payment id can not be encrypted for several destinations, and no dummy is added
+        assert!(
+            MoneroTransactionExtra::new(&transaction_private_key(), Some([1u8; 8]), &destinations, None, true).is_err()
+        );
+        let extra = MoneroTransactionExtra::new(&transaction_private_key(), None, &destinations, None, true).unwrap();
+        assert_eq!(None, extra.to_encrypted_payment_id());
+    }
+
+    #[test]
+    fn additional_public_keys() {
+        let (_, recipient_address) = wallet("3eb8e283b45559d4d2fb6b3a3f3f6a1b5d6e0e8b5d1b7e7d1f0f2c7f1c3e1a0b");
+        let additional_public_keys = vec![[1u8; 32], [2u8; 32]];
+
+        let extra = MoneroTransactionExtra::new(&transaction_private_key(), None, &[recipient_address], None, true)
+            .unwrap()
+            .with_additional_public_keys(additional_public_keys.clone());
+
+        let bytes = extra.to_bytes();
+        assert_eq!(44 + 2 + 64, bytes.len());
+        assert_eq!([TX_EXTRA_TAG_ADDITIONAL_PUBKEYS, 2], bytes[44..46]);
+
+        let parsed = MoneroTransactionExtra::<N>::from_bytes(&bytes).unwrap();
+        assert_eq!(extra, parsed);
+        assert_eq!(additional_public_keys, parsed.to_additional_public_keys());
+    }
+
+    #[test]
+    fn invalid_extra() {
+        assert!(MoneroTransactionExtra::<N>::from_bytes(&[]).is_err());
+        assert!(MoneroTransactionExtra::<N>::from_bytes(&[TX_EXTRA_TAG_PUBKEY, 0x00]).is_err());
+        assert!(MoneroTransactionExtra::<N>::from_bytes(&[0xff]).is_err());
+    }
+}
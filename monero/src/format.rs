@@ -23,10 +23,16 @@ impl MoneroFormat {
         N::to_address_prefix(self)
     }
 
-    /// Returns the format of the given address.
+    /// Returns the format of the given address, with its checksum, checking its length against its prefix.
     pub fn from_address(address: &[u8]) -> Result<Self, AddressError> {
+        if address.is_empty() {
+            return Err(AddressError::InvalidByteLength(0));
+        }
+
         match address[0] {
+            18 | 24 | 53 | 42 | 36 | 63 if address.len() != 69 => Err(AddressError::InvalidByteLength(address.len())),
             18 | 24 | 53 => Ok(MoneroFormat::Standard),
+            19 | 25 | 54 if address.len() != 77 => Err(AddressError::InvalidByteLength(address.len())),
             19 | 25 | 54 => {
                 let mut data = [0u8; 8];
                 data.copy_from_slice(&address[65..73]);
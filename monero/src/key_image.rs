@@ -0,0 +1,446 @@
+use crate::format::MoneroFormat;
+use crate::network::MoneroNetwork;
+use crate::one_time_key::MoneroOneTimeKey;
+use crate::private_key::{to_subaddress_secret_key, MoneroPrivateKey};
+use wagyu_model::no_std::{String, Vec};
+use wagyu_model::OneTimeKeyError;
+
+use core::{fmt, marker::PhantomData};
+use curve25519_dalek::{
+    constants::ED25519_BASEPOINT_TABLE, edwards::EdwardsPoint, montgomery::MontgomeryPoint, scalar::Scalar,
+};
+use tiny_keccak::keccak256;
+use zeroize::Zeroize;
+
+#[derive(Debug, Error)]
+pub enum KeyImageError {
+    #[error("{0}: {1}")]
+    Crate(&'static str, String),
+
+    #[error("output with one time key {0} does not belong to the private key")]
+    InvalidOutput(String),
+
+    #[error("{0}")]
+    OneTimeKeyError(OneTimeKeyError),
+}
+
+impl From<OneTimeKeyError> for KeyImageError {
+    fn from(error: OneTimeKeyError) -> Self {
+        KeyImageError::OneTimeKeyError(error)
+    }
+}
+
+/// Represents a Monero key image, which marks the output of a one time key as spent.
+/// Key images are generated by the holder of the private spend key, and imported
+/// into a view-only wallet for it to compute the balance of the spent outputs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MoneroKeyImage<N: MoneroNetwork> {
+    /// The one time public key of the output
+    one_time_public_key: [u8; 32],
+    /// The key image of the output
+    key_image: [u8; 32],
+    /// PhantomData
+    _network: PhantomData<N>,
+}
+
+impl<N: MoneroNetwork> MoneroKeyImage<N> {
+    /// Returns the key image of the given one time private key.
+    pub fn generate_key_image(one_time_private_key: &[u8; 32]) -> Self {
+        // key_image = one_time_private_key * hash_to_point(one_time_private_key * G)
+        let x = Scalar::from_bits(*one_time_private_key);
+        let one_time_public_key = (&x * &ED25519_BASEPOINT_TABLE).compress().to_bytes();
+        let key_image = x * hash_to_point(&one_time_public_key);
+
+        Self {
+            one_time_public_key,
+            key_image: key_image.compress().to_bytes(),
+            _network: PhantomData,
+        }
+    }
+
+    /// Returns the one time private key and the key image of the output of the given one time key and index,
+    /// received by the address of the given format. Returns an error if the output does not belong to
+    /// the private key, as its one time private key does not derive its destination key.
+    pub fn generate_keys_and_key_image(
+        private_key: &MoneroPrivateKey<N>,
+        one_time_key: &MoneroOneTimeKey<N>,
+        index: u64,
+        format: &MoneroFormat,
+    ) -> Result<([u8; 32], Self), KeyImageError> {
+        // one_time_private_key = hash((private_view_key * transaction_public_key) || index) + private_spend_key
+        //                        + subaddress_secret_key
+        let (major, minor) = match format {
+            MoneroFormat::Subaddress(major, minor) => (*major, *minor),
+            _ => (0, 0),
+        };
+        let mut subaddress_secret_key = to_subaddress_secret_key(&private_key.to_private_view_key(), major, minor);
+        let mut main_private_key = one_time_key.to_private(private_key, index)?;
+
+        let x = Scalar::from_bits(main_private_key) + Scalar::from_bits(subaddress_secret_key);
+        main_private_key.zeroize();
+        subaddress_secret_key.zeroize();
+
+        let one_time_private_key = x.to_bytes();
+        let key_image = Self::generate_key_image(&one_time_private_key);
+        match key_image.one_time_public_key == one_time_key.to_destination_key() {
+            true => Ok((one_time_private_key, key_image)),
+            false => Err(KeyImageError::InvalidOutput(hex::encode(
+                one_time_key.to_destination_key(),
+            ))),
+        }
+    }
+
+    /// Returns the key images of the given outputs, each a one time key and index received by
+    /// the address of the given format. Returns an error if an output does not belong to the private key.
+    pub fn generate_key_images(
+        private_key: &MoneroPrivateKey<N>,
+        outputs: &[(MoneroOneTimeKey<N>, u64, MoneroFormat)],
+    ) -> Result<Vec<Self>, KeyImageError> {
+        outputs
+            .iter()
+            .map(|(one_time_key, index, format)| {
+                let (mut one_time_private_key, key_image) =
+                    Self::generate_keys_and_key_image(private_key, one_time_key, *index, format)?;
+                one_time_private_key.zeroize();
+                Ok(key_image)
+            })
+            .collect()
+    }
+
+    /// Returns the one time public key of the output of the key image.
+    pub fn to_one_time_public_key(&self) -> [u8; 32] {
+        self.one_time_public_key
+    }
+
+    /// Returns the key image.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.key_image
+    }
+}
+
+impl<N: MoneroNetwork> fmt::Display for MoneroKeyImage<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", hex::encode(self.key_image))
+    }
+}
+
+/// Returns the point of the given public key, as Monero `hash_to_ec`. The Keccak-256 hash of the key
+/// is mapped to a point with Elligator 2 and multiplied by the cofactor.
+/// https://github.com/monero-project/monero/blob/v0.17.1.9/src/crypto/crypto-ops.c#L2309
+pub(crate) fn hash_to_point(public_key: &[u8; 32]) -> EdwardsPoint {
+    // Exactly one of the Montgomery coordinates -A / w and -A * 2u^2 / w is on the curve,
+    // where w = 2u^2 + 1, and Monero selects its Edwards point of negative or nonnegative x respectively.
+    let u = FieldElement::from_bytes(&keccak256(public_key));
+    let u2 = u.mul(&u).add(&u.mul(&u));
+    let w = u2.add(&FieldElement::ONE);
+    let a = FieldElement([486662, 0, 0, 0]);
+
+    let v = a.mul(&w.invert()).neg();
+    let point = MontgomeryPoint(a.neg().sub(&v).to_bytes())
+        .to_edwards(0)
+        .or_else(|| MontgomeryPoint(v.to_bytes()).to_edwards(1))
+        .expect("one of the Elligator 2 coordinates is on the curve");
+
+    point.mul_by_cofactor()
+}
+
+/// Represents an element of the field of integers modulo p = 2^255 - 19, in 64-bit little-endian limbs.
+/// Only the operations to hash to a point are implemented, as curve25519-dalek does not expose its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FieldElement([u64; 4]);
+
+impl FieldElement {
+    const ONE: Self = FieldElement([1, 0, 0, 0]);
+    const P: Self = FieldElement([
+        0xffff_ffff_ffff_ffed,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0x7fff_ffff_ffff_ffff,
+    ]);
+
+    /// Returns the field element of the given 256-bit little-endian integer, reduced modulo p.
+    fn from_bytes(bytes: &[u8; 32]) -> Self {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let mut word = [0u8; 8];
+            word.copy_from_slice(&bytes[i * 8..(i + 1) * 8]);
+            *limb = u64::from_le_bytes(word);
+        }
+        Self::reduce(limbs, 0)
+    }
+
+    /// Returns the canonical 32-byte little-endian encoding of the field element.
+    fn to_bytes(self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (i, limb) in self.0.iter().enumerate() {
+            bytes[i * 8..(i + 1) * 8].copy_from_slice(&limb.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Returns the field element of the integer limbs + carry * 2^256, for a carry of at most 2^57.
+    fn reduce(limbs: [u64; 4], carry: u64) -> Self {
+        // 2^255 = 19 (mod p), so the bits from the 255th are folded into the lowest limb
+        let high = ((carry as u128) << 1) | (limbs[3] >> 63) as u128;
+        let mut limbs = limbs;
+        limbs[3] &= 0x7fff_ffff_ffff_ffff;
+
+        let mut sum = high * 19;
+        for limb in limbs.iter_mut() {
+            sum += *limb as u128;
+            *limb = sum as u64;
+            sum >>= 64;
+        }
+
+        // The sum is less than 2p, and is reduced once more by the bits from the 255th
+        let high = limbs[3] >> 63;
+        limbs[3] &= 0x7fff_ffff_ffff_ffff;
+        let mut sum = (high * 19) as u128;
+        for limb in limbs.iter_mut() {
+            sum += *limb as u128;
+            *limb = sum as u64;
+            sum >>= 64;
+        }
+
+        // Subtract p if the element is at least p
+        let element = FieldElement(limbs);
+        match element.geq(&Self::P) {
+            true => element.sub_limbs(&Self::P),
+            false => element,
+        }
+    }
+
+    /// Returns true if the field element is greater than or equal to the given element, as integers.
+    fn geq(&self, other: &Self) -> bool {
+        for i in (0..4).rev() {
+            if self.0[i] != other.0[i] {
+                return self.0[i] > other.0[i];
+            }
+        }
+        true
+    }
+
+    /// Returns the difference of the limbs, for a field element greater than or equal to the given element.
+    fn sub_limbs(&self, other: &Self) -> Self {
+        let mut limbs = [0u64; 4];
+        let mut borrow = 0u64;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (difference, overflow_1) = self.0[i].overflowing_sub(other.0[i]);
+            let (difference, overflow_2) = difference.overflowing_sub(borrow);
+            *limb = difference;
+            borrow = (overflow_1 || overflow_2) as u64;
+        }
+        FieldElement(limbs)
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        let mut limbs = [0u64; 4];
+        let mut sum = 0u128;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            sum += self.0[i] as u128 + other.0[i] as u128;
+            *limb = sum as u64;
+            sum >>= 64;
+        }
+        Self::reduce(limbs, sum as u64)
+    }
+
+    fn neg(&self) -> Self {
+        match self.0 == [0u64; 4] {
+            true => *self,
+            false => Self::P.sub_limbs(self),
+        }
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        let mut product = [0u128; 8];
+        for i in 0..4 {
+            let mut carry = 0u128;
+            for j in 0..4 {
+                let term = self.0[i] as u128 * other.0[j] as u128 + product[i + j] + carry;
+                product[i + j] = term & 0xffff_ffff_ffff_ffff;
+                carry = term >> 64;
+            }
+            product[i + 4] = carry;
+        }
+
+        // 2^256 = 38 (mod p), so the high limbs are folded into the low limbs
+        let mut limbs = [0u64; 4];
+        let mut sum = 0u128;
+        for i in 0..4 {
+            sum += product[i] + product[i + 4] * 38;
+            limbs[i] = sum as u64;
+            sum >>= 64;
+        }
+        Self::reduce(limbs, sum as u64)
+    }
+
+    /// Returns the multiplicative inverse of the field element, as its (p - 2)th power.
+    fn invert(&self) -> Self {
+        let mut exponent = Self::P.0;
+        exponent[0] -= 2;
+
+        let mut result = Self::ONE;
+        for i in (0..256).rev() {
+            result = result.mul(&result);
+            if (exponent[i / 64] >> (i % 64)) & 1 == 1 {
+                result = result.mul(self);
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::public_key::MoneroPublicKey;
+    use crate::Mainnet;
+    use wagyu_model::PublicKey;
+
+    use curve25519_dalek::edwards::CompressedEdwardsY;
+
+    type N = Mainnet;
+
+    const FORMAT: &MoneroFormat = &MoneroFormat::Standard;
+
+    // (
+    //      private_spend_key,
+    //      (
+    //          one_time_public_key,
+    //          transaction_public_key,
+    //          output_index
+    //      ),
+    //      one_time_private_key,
+    //      key_image
+    // )
+    const KEY_IMAGES: [(&str, (&str, &str, u64), &str, &str); 1] = [(
+        "c595161ea20ccd8c692947c2d3ced471e9b13a18b150c881232794e8042bf107",
+        (
+            "6cabaac48d3b9043525a703e9e5feb72132f69ea6deca9b4acf9228beb74cd8f",
+            "396fc23bc389046b214087a9522c0fbd673d2f3f00ab9768f35fa52f953fef22",
+            0,
+        ),
+        "97df43cb906896405a8b54ecd4610c92b99de5090b404e5e64b17af17da01601",
+        "2ba7ee37314d4a1edbeef727f49099c79d55797570cb1206ee2685c94b6550b1",
+    )];
+
+    fn to_bytes(hex: &str) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&hex::decode(hex).unwrap());
+        bytes
+    }
+
+    /// Returns the one time key of an output sent to the public key with the given randomness,
+    /// with the transaction public key of a subaddress, r * D, for a subaddress public key.
+    fn to_output(public_key: &MoneroPublicKey<N>, random: &[u8; 32], index: u64) -> MoneroOneTimeKey<N> {
+        let r = Scalar::from_bits(*random);
+        let spend = CompressedEdwardsY(public_key.to_public_spend_key().unwrap())
+            .decompress()
+            .unwrap();
+        let view = CompressedEdwardsY(public_key.to_public_view_key().unwrap())
+            .decompress()
+            .unwrap();
+
+        let mut derivation = (r * view).mul_by_cofactor().compress().to_bytes().to_vec();
+        derivation.push(index as u8);
+        let hash = Scalar::from_bytes_mod_order(keccak256(&derivation));
+
+        let destination_key = &hash * &ED25519_BASEPOINT_TABLE + spend;
+        let transaction_public_key = match public_key.format() {
+            MoneroFormat::Subaddress(_, _) => r * spend,
+            _ => &r * &ED25519_BASEPOINT_TABLE,
+        };
+        MoneroOneTimeKey::from(
+            &destination_key.compress().to_bytes(),
+            &transaction_public_key.compress().to_bytes(),
+        )
+    }
+
+    #[test]
+    fn hash_to_point() {
+        let point = super::hash_to_point(&[0u8; 32]);
+        assert_eq!(
+            "2d2c4d74df05ba930eaab01825af274eaabcd217bf99dfd54fdf2efe574033f3",
+            hex::encode(point.compress().to_bytes())
+        );
+        assert!(point.is_torsion_free());
+    }
+
+    #[test]
+    fn generate_keys_and_key_image() {
+        KEY_IMAGES.iter().for_each(
+            |(
+                private_spend_key,
+                (one_time_public_key, transaction_public_key, index),
+                one_time_private_key,
+                key_image,
+            )| {
+                let private_key = MoneroPrivateKey::<N>::from_private_spend_key(private_spend_key, FORMAT).unwrap();
+                let one_time_key =
+                    MoneroOneTimeKey::from(&to_bytes(one_time_public_key), &to_bytes(transaction_public_key));
+
+                let (private, image) =
+                    MoneroKeyImage::generate_keys_and_key_image(&private_key, &one_time_key, *index, FORMAT).unwrap();
+                assert_eq!(*one_time_private_key, hex::encode(private));
+                assert_eq!(*one_time_public_key, hex::encode(image.to_one_time_public_key()));
+                assert_eq!(*key_image, image.to_string());
+                assert_eq!(image, MoneroKeyImage::generate_key_image(&private));
+            },
+        );
+    }
+
+    #[test]
+    fn generate_keys_and_key_image_subaddress() {
+        let format = &MoneroFormat::Subaddress(1, 2);
+        let private_key = MoneroPrivateKey::<N>::from_private_spend_key(KEY_IMAGES[0].0, format).unwrap();
+        let public_key = MoneroPublicKey::from_private_key(&private_key);
+        let one_time_key = to_output(&public_key, &[1u8; 32], 3);
+
+        let (private, image) =
+            MoneroKeyImage::generate_keys_and_key_image(&private_key, &one_time_key, 3, format).unwrap();
+        assert_eq!(one_time_key.to_destination_key(), image.to_one_time_public_key());
+        assert_eq!(image, MoneroKeyImage::generate_key_image(&private));
+
+        // The output of a subaddress does not belong to the main address
+        assert!(MoneroKeyImage::generate_keys_and_key_image(&private_key, &one_time_key, 3, FORMAT).is_err());
+    }
+
+    #[test]
+    fn generate_key_images() {
+        let format = &MoneroFormat::Subaddress(0, 1);
+        let private_key = MoneroPrivateKey::<N>::from_private_spend_key(KEY_IMAGES[0].0, FORMAT).unwrap();
+        let subaddress_private_key = MoneroPrivateKey::<N>::from_private_spend_key(KEY_IMAGES[0].0, format).unwrap();
+        let outputs = [
+            (
+                to_output(&MoneroPublicKey::from_private_key(&private_key), &[1u8; 32], 0),
+                0,
+                *FORMAT,
+            ),
+            (
+                to_output(
+                    &MoneroPublicKey::from_private_key(&subaddress_private_key),
+                    &[2u8; 32],
+                    1,
+                ),
+                1,
+                *format,
+            ),
+        ];
+
+        let key_images = MoneroKeyImage::generate_key_images(&private_key, &outputs).unwrap();
+        assert_eq!(outputs.len(), key_images.len());
+        for ((one_time_key, index, format), key_image) in outputs.iter().zip(key_images) {
+            let (_, expected) =
+                MoneroKeyImage::generate_keys_and_key_image(&private_key, one_time_key, *index, format).unwrap();
+            assert_eq!(expected, key_image);
+        }
+
+        // An output of the wrong index fails the batch
+        let outputs = [(outputs[0].0.clone(), 1, *FORMAT)];
+        assert!(MoneroKeyImage::generate_key_images(&private_key, &outputs).is_err());
+    }
+}
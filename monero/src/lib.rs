@@ -5,7 +5,7 @@
 #![warn(unused_extern_crates, dead_code)]
 
 #[macro_use]
-extern crate failure;
+extern crate thiserror;
 
 pub mod address;
 pub use self::address::*;
@@ -13,9 +13,23 @@ pub use self::address::*;
 pub mod amount;
 pub use self::amount::*;
 
+pub mod key_image;
+pub use self::key_image::*;
+
 pub mod mnemonic;
 pub use self::mnemonic::*;
 
+pub mod clsag;
+pub use self::clsag::*;
+
+#[cfg(feature = "std")]
+pub mod decoy;
+#[cfg(feature = "std")]
+pub use self::decoy::*;
+
+pub mod extra;
+pub use self::extra::*;
+
 pub mod format;
 pub use self::format::*;
 
@@ -31,10 +45,28 @@ pub use self::private_key::*;
 pub mod public_key;
 pub use self::public_key::*;
 
+pub mod raw_transaction;
+pub use self::raw_transaction::*;
+
+pub mod ringct;
+pub use self::ringct::*;
+
+pub mod scan;
+pub use self::scan::*;
+
 #[cfg(transaction)]
 pub mod transaction;
 #[cfg(transaction)]
 pub use self::transaction::*;
 
+pub mod uri;
+pub use self::uri::*;
+
+pub mod view_keypair;
+pub use self::view_keypair::*;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 pub mod wordlist;
 pub use self::wordlist::*;
@@ -14,6 +14,7 @@ use core::{fmt, marker::PhantomData, str, str::FromStr};
 use crc::{crc32, Hasher32};
 use curve25519_dalek::scalar::Scalar;
 use rand::Rng;
+use zeroize::Zeroize;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// Represents a Monero mnemonic
@@ -192,6 +193,19 @@ impl<N: MoneroNetwork, W: MoneroWordlist> fmt::Display for MoneroMnemonic<N, W>
     }
 }
 
+impl<N: MoneroNetwork, W: MoneroWordlist> Zeroize for MoneroMnemonic<N, W> {
+    /// Overwrites the mnemonic seed with zeros.
+    fn zeroize(&mut self) {
+        self.seed.zeroize();
+    }
+}
+
+impl<N: MoneroNetwork, W: MoneroWordlist> Drop for MoneroMnemonic<N, W> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
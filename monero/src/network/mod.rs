@@ -1,6 +1,9 @@
 use crate::format::MoneroFormat;
 use wagyu_model::{AddressError, Network};
 
+pub mod any_network;
+pub use self::any_network::*;
+
 pub mod mainnet;
 pub use self::mainnet::*;
 
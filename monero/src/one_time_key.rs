@@ -1,46 +1,21 @@
 #![allow(non_snake_case)]
 
+use crate::key_image::MoneroKeyImage;
 use crate::network::MoneroNetwork;
 use crate::private_key::MoneroPrivateKey;
 use crate::public_key::MoneroPublicKey;
-use wagyu_model::no_std::{vec, String, Vec};
-use wagyu_model::{PublicKeyError, TransactionError};
+use crate::raw_transaction::encode_varint;
+use wagyu_model::no_std::Vec;
+use wagyu_model::{OneTimeKey, OneTimeKeyError, PublicKeyError};
 
 use core::marker::PhantomData;
 use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
 use curve25519_dalek::{constants::ED25519_BASEPOINT_TABLE, edwards::EdwardsBasepointTable, scalar::Scalar};
 use tiny_keccak::keccak256;
 
-#[derive(Debug, Fail)]
-pub enum OneTimeKeyError {
-    #[fail(display = "{}: {}", _0, _1)]
-    Crate(&'static str, String),
-
-    #[fail(display = "could not generate Edwards point from slice {:?}", _0)]
-    EdwardsPointError([u8; 32]),
-
-    #[fail(display = "{}", _0)]
-    PublicKeyError(PublicKeyError),
-
-    #[fail(display = "{}", _0)]
-    TransactionError(TransactionError),
-}
-
-impl From<PublicKeyError> for OneTimeKeyError {
-    fn from(error: PublicKeyError) -> Self {
-        OneTimeKeyError::PublicKeyError(error)
-    }
-}
-
-impl From<TransactionError> for OneTimeKeyError {
-    fn from(error: TransactionError) -> Self {
-        OneTimeKeyError::TransactionError(error)
-    }
-}
-
-/// Represents a one time key
+/// Represents a Monero one time key
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct OneTimeKey<N: MoneroNetwork> {
+pub struct MoneroOneTimeKey<N: MoneroNetwork> {
     /// Destination key from receiver's public keys and sender randomness
     destination_key: [u8; 32],
     /// Transaction identifier from sender randomness and base point
@@ -49,9 +24,13 @@ pub struct OneTimeKey<N: MoneroNetwork> {
     _network: PhantomData<N>,
 }
 
-impl<N: MoneroNetwork> OneTimeKey<N> {
+impl<N: MoneroNetwork> MoneroOneTimeKey<N> {
     /// Returns one time key given recipient public keys, randomness, and output index
-    pub fn new(public: &MoneroPublicKey<N>, rand: &[u8; 32], index: u64) -> Result<OneTimeKey<N>, OneTimeKeyError> {
+    pub fn new(
+        public: &MoneroPublicKey<N>,
+        rand: &[u8; 32],
+        index: u64,
+    ) -> Result<MoneroOneTimeKey<N>, OneTimeKeyError> {
         //destination_key = hash((random * public_view_key) || index) * generator + public_spend_key
         const G: &EdwardsBasepointTable = &ED25519_BASEPOINT_TABLE;
 
@@ -66,7 +45,7 @@ impl<N: MoneroNetwork> OneTimeKey<N> {
 
         let public_spend_point = &match CompressedEdwardsY::from_slice(&public_spend_key).decompress() {
             Some(point) => point,
-            None => return Err(OneTimeKeyError::EdwardsPointError(public_spend_key)),
+            None => return Err(OneTimeKeyError::InvalidPoint(public_spend_key.to_vec())),
         };
         let mut concat = Vec::<u8>::new();
 
@@ -84,6 +63,15 @@ impl<N: MoneroNetwork> OneTimeKey<N> {
         })
     }
 
+    /// Returns the one time key of a received output given its destination key and transaction public key
+    pub fn from(destination_key: &[u8; 32], transaction_public_key: &[u8; 32]) -> MoneroOneTimeKey<N> {
+        Self {
+            destination_key: *destination_key,
+            transaction_public_key: *transaction_public_key,
+            _network: PhantomData,
+        }
+    }
+
     /// Returns the one time private key given recipient private keys
     pub fn to_private(&self, private: &MoneroPrivateKey<N>, index: u64) -> Result<[u8; 32], OneTimeKeyError> {
         //one_time_private_key = hash((private_view_key * transaction_public_key) || index) + private_spend_key
@@ -119,34 +107,8 @@ impl<N: MoneroNetwork> OneTimeKey<N> {
         Ok(self.to_destination_key() == expected)
     }
 
-    /// Encodes the index to conform to Monero consensus
-    fn encode_varint(index: u64) -> Vec<u8> {
-        // used here: https://github.com/monero-project/monero/blob/50d48d611867ffcd41037e2ab4fec2526c08a7f5/src/crypto/crypto.cpp#L195
-        // impl here: https://github.com/monero-project/monero/blob/50d48d611867ffcd41037e2ab4fec2526c08a7f5/src/common/varint.h#L69
-        let mut res: Vec<u8> = vec![];
-        let mut n = index;
-        loop {
-            let bits = (n & 0b0111_1111) as u8;
-            n = n >> 7;
-            res.push(bits);
-            if n == 0u64 {
-                break;
-            }
-        }
-        let mut encoded_bytes = vec![];
-        match res.split_last() {
-            Some((last, arr)) => {
-                let _a: Vec<_> = arr.iter().map(|bits| encoded_bytes.push(*bits | 0b1000_0000)).collect();
-                encoded_bytes.push(*last);
-            }
-            None => encoded_bytes.push(0x00),
-        }
-
-        encoded_bytes
-    }
-
     /// Returns scalar base multiplication of public and secret key then multiplies result by cofactor
-    fn generate_key_derivation(
+    pub(crate) fn generate_key_derivation(
         public: &[u8; 32],
         secret_key: &[u8; 32],
         dest: &mut Vec<u8>,
@@ -155,7 +117,7 @@ impl<N: MoneroNetwork> OneTimeKey<N> {
         let r = Scalar::from_bits(*secret_key);
         let A = &match CompressedEdwardsY::from_slice(public).decompress() {
             Some(point) => point,
-            None => return Err(OneTimeKeyError::EdwardsPointError(*public)),
+            None => return Err(OneTimeKeyError::InvalidPoint(public.to_vec())),
         };
 
         let mut rA: EdwardsPoint = r * A;
@@ -168,10 +130,10 @@ impl<N: MoneroNetwork> OneTimeKey<N> {
     }
 
     /// Returns keccak256 hash of key derivation extended by output index as a scalar
-    fn derivation_to_scalar(derivation: &Vec<u8>, output_index: u64) -> Scalar {
+    pub(crate) fn derivation_to_scalar(derivation: &Vec<u8>, output_index: u64) -> Scalar {
         // H_s(derivation || output_index)
         let mut derivation = derivation.clone();
-        derivation.extend(&Self::encode_varint(output_index));
+        derivation.extend(&encode_varint(output_index));
 
         Scalar::from_bytes_mod_order(keccak256(&derivation))
     }
@@ -185,12 +147,40 @@ impl<N: MoneroNetwork> OneTimeKey<N> {
     }
 }
 
+impl<N: MoneroNetwork> OneTimeKey for MoneroOneTimeKey<N> {
+    type KeyImage = MoneroKeyImage<N>;
+    type PrivateKey = MoneroPrivateKey<N>;
+    type PublicKey = MoneroPublicKey<N>;
+    type SecretKey = [u8; 32];
+
+    /// Returns the one time key given recipient public keys, transaction private key, and output index.
+    fn derive_public(public_key: &Self::PublicKey, randomness: &[u8; 32], index: u64) -> Result<Self, OneTimeKeyError> {
+        Self::new(public_key, randomness, index)
+    }
+
+    /// Returns the one time private key given recipient private keys.
+    fn derive_secret(&self, private_key: &Self::PrivateKey, index: u64) -> Result<Self::SecretKey, OneTimeKeyError> {
+        self.to_private(private_key, index)
+    }
+
+    /// Returns the key image of the one time private key.
+    fn to_key_image(secret_key: &Self::SecretKey) -> Self::KeyImage {
+        MoneroKeyImage::generate_key_image(secret_key)
+    }
+
+    /// Verifies that the one time public key can be generated from recipient private keys.
+    fn verify(&self, private_key: &Self::PrivateKey, index: u64) -> Result<bool, OneTimeKeyError> {
+        Self::verify(self, private_key, index)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::format::MoneroFormat;
     use crate::Mainnet;
     use hex;
+    use wagyu_model::{test_one_time_key, PrivateKey};
 
     type N = Mainnet;
 
@@ -237,7 +227,7 @@ mod tests {
         one_time_private_key: &'static str,
         transaction_public_key: &'static str,
     ) {
-        let one_time_key = OneTimeKey::new(receiver_public_key, random_bytes, output_index).unwrap();
+        let one_time_key = MoneroOneTimeKey::new(receiver_public_key, random_bytes, output_index).unwrap();
 
         assert_eq!(hex::encode(one_time_key.to_destination_key()), one_time_public_key);
         assert_eq!(
@@ -285,4 +275,28 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn one_time_key_properties() {
+        let (private_spend_key, _, random_str, _, _, _) = KEYPAIRS[0];
+        let private_key = MoneroPrivateKey::<N>::from_private_spend_key(private_spend_key, FORMAT).unwrap();
+        let other_private_key = MoneroPrivateKey::<N>::from_private_spend_key(
+            "a3b7b6f3d1f4c6f1a0f0d0c0b0a09080706050403020100f0e0d0c0b0a090807",
+            FORMAT,
+        )
+        .unwrap();
+
+        let mut random_bytes: [u8; 32] = [0u8; 32];
+        random_bytes.copy_from_slice(hex::decode(random_str).unwrap().as_slice());
+
+        for index in 0..3 {
+            test_one_time_key::<MoneroOneTimeKey<N>>(
+                &private_key.to_public_key(),
+                &private_key,
+                &other_private_key,
+                &random_bytes,
+                index,
+            );
+        }
+    }
 }
@@ -9,6 +9,7 @@ use curve25519_dalek::scalar::Scalar;
 use hex;
 use rand::Rng;
 use tiny_keccak::keccak256;
+use zeroize::Zeroize;
 
 /// Represents a Monero private key
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -95,16 +96,7 @@ impl<N: MoneroNetwork> MoneroPrivateKey<N> {
 
     /// Update the private key format and returns a subaddress private view key.
     pub fn to_subaddress_private_view_key(&self, major: u32, minor: u32) -> [u8; 32] {
-        if major == 0 && minor == 0 {
-            [0u8; 32]
-        } else {
-            let mut derivation: Vec<u8> = b"SubAddr\x00"[..].into();
-            derivation.extend(&self.view_key);
-            derivation.extend(&major.to_le_bytes());
-            derivation.extend(&minor.to_le_bytes());
-
-            Scalar::from_bytes_mod_order(keccak256(&derivation)).to_bytes()
-        }
+        to_subaddress_secret_key(&self.view_key, major, minor)
     }
 
     /// Returns the private spend key of the Monero private key.
@@ -123,6 +115,20 @@ impl<N: MoneroNetwork> MoneroPrivateKey<N> {
     }
 }
 
+/// Returns the secret key of a subaddress, given the private view key and the major and minor index.
+pub(crate) fn to_subaddress_secret_key(private_view_key: &[u8; 32], major: u32, minor: u32) -> [u8; 32] {
+    if major == 0 && minor == 0 {
+        [0u8; 32]
+    } else {
+        let mut derivation: Vec<u8> = b"SubAddr\x00"[..].into();
+        derivation.extend(private_view_key);
+        derivation.extend(&major.to_le_bytes());
+        derivation.extend(&minor.to_le_bytes());
+
+        Scalar::from_bytes_mod_order(keccak256(&derivation)).to_bytes()
+    }
+}
+
 impl<N: MoneroNetwork> FromStr for MoneroPrivateKey<N> {
     type Err = PrivateKeyError;
     fn from_str(seed: &str) -> Result<Self, PrivateKeyError> {
@@ -145,6 +151,20 @@ impl<N: MoneroNetwork> Display for MoneroPrivateKey<N> {
     }
 }
 
+impl<N: MoneroNetwork> Zeroize for MoneroPrivateKey<N> {
+    /// Overwrites the private spend key and private view key with zeros.
+    fn zeroize(&mut self) {
+        self.spend_key.zeroize();
+        self.view_key.zeroize();
+    }
+}
+
+impl<N: MoneroNetwork> Drop for MoneroPrivateKey<N> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -336,6 +356,16 @@ mod tests {
                     test_to_str(private_spend_key, private_view_key, &private_key);
                 });
         }
+
+        #[test]
+        fn zeroize() {
+            KEYPAIRS.iter().for_each(|(seed, _, _, _)| {
+                let mut private_key = MoneroPrivateKey::<N>::from_seed(seed, FORMAT).unwrap();
+                private_key.zeroize();
+                assert_eq!([0u8; 32], private_key.to_private_spend_key());
+                assert_eq!([0u8; 32], private_key.to_private_view_key());
+            });
+        }
     }
 
     mod integrated_mainnet {
@@ -0,0 +1,469 @@
+use wagyu_model::no_std::{format, vec, Vec};
+use wagyu_model::TransactionError;
+
+use tiny_keccak::keccak256;
+
+/// The tag of a coinbase input
+pub const TXIN_GEN_TAG: u8 = 0xff;
+/// The tag of an input spending one time keys
+pub const TXIN_TO_KEY_TAG: u8 = 0x02;
+/// The tag of an output to a one time key
+pub const TXOUT_TO_KEY_TAG: u8 = 0x02;
+/// The tag of an output to a one time key with a view tag
+pub const TXOUT_TO_TAGGED_KEY_TAG: u8 = 0x03;
+
+/// The RingCT type of a transaction without RingCT signatures, such as a coinbase transaction
+pub const RCT_TYPE_NULL: u8 = 0;
+/// The RingCT type of a transaction with simple RingCT signatures
+pub const RCT_TYPE_SIMPLE: u8 = 2;
+/// The first RingCT type with compact 8 byte encrypted amounts
+pub const RCT_TYPE_BULLETPROOF_2: u8 = 4;
+/// The RingCT type of a transaction with CLSAG signatures and Bulletproofs+ range proofs
+pub const RCT_TYPE_BULLETPROOF_PLUS: u8 = 6;
+
+/// The maximum length of a 64-bit varint
+const MAX_VARINT_LENGTH: usize = 10;
+
+/// Returns the varint encoding of the given integer, as defined by Monero consensus.
+/// https://github.com/monero-project/monero/blob/50d48d611867ffcd41037e2ab4fec2526c08a7f5/src/common/varint.h#L69
+pub fn encode_varint(value: u64) -> Vec<u8> {
+    let mut encoded = vec![];
+    let mut n = value;
+    while n >= 0b1000_0000 {
+        encoded.push((n & 0b0111_1111) as u8 | 0b1000_0000);
+        n >>= 7;
+    }
+    encoded.push(n as u8);
+    encoded
+}
+
+/// Returns the integer of the varint at the start of the given bytes and the length of its encoding.
+/// Rejects varints that overflow 64 bits or are not in their canonical (shortest) encoding.
+pub fn decode_varint(bytes: &[u8]) -> Result<(u64, usize), TransactionError> {
+    let mut value = 0u64;
+    for (i, byte) in bytes.iter().enumerate().take(MAX_VARINT_LENGTH) {
+        let bits = (byte & 0b0111_1111) as u64;
+        if i == MAX_VARINT_LENGTH - 1 && bits > 1 {
+            return Err("varint overflows 64 bits".into());
+        }
+        value |= bits << (7 * i);
+
+        if byte & 0b1000_0000 == 0 {
+            if i > 0 && *byte == 0 {
+                return Err("varint is not canonically encoded".into());
+            }
+            return Ok((value, i + 1));
+        }
+    }
+    Err("varint is truncated".into())
+}
+
+/// Represents an input of a Monero transaction
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MoneroTransactionInput {
+    /// A coinbase input, minting the block reward at the given height
+    Gen { height: u64 },
+    /// An input spending one of a ring of one time keys, given as offsets of global output indices
+    ToKey {
+        amount: u64,
+        key_offsets: Vec<u64>,
+        key_image: [u8; 32],
+    },
+}
+
+/// Represents the target of an output of a Monero transaction
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MoneroTransactionTarget {
+    /// A one time key
+    ToKey { key: [u8; 32] },
+    /// A one time key with a view tag, as introduced in the v15 hard fork
+    ToTaggedKey { key: [u8; 32], view_tag: u8 },
+}
+
+/// Represents an output of a Monero transaction
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MoneroTransactionOutput {
+    /// The amount, which is zero for RingCT outputs
+    pub amount: u64,
+    /// The target of the output
+    pub target: MoneroTransactionTarget,
+}
+
+/// Represents the prefix of a Monero transaction, which is the part of the transaction signed by its inputs
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MoneroTransactionPrefix {
+    /// The transaction version, 1 for CryptoNote and 2 for RingCT transactions
+    pub version: u64,
+    /// The block height or timestamp until which the outputs are locked
+    pub unlock_time: u64,
+    /// The inputs
+    pub inputs: Vec<MoneroTransactionInput>,
+    /// The outputs
+    pub outputs: Vec<MoneroTransactionOutput>,
+    /// The extra field, holding the transaction public key, payment id, and other tagged fields
+    pub extra: Vec<u8>,
+}
+
+impl MoneroTransactionPrefix {
+    /// Returns the serialized transaction prefix, as defined by Monero consensus.
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut serialized = vec![];
+        serialized.extend(encode_varint(self.version));
+        serialized.extend(encode_varint(self.unlock_time));
+
+        serialized.extend(encode_varint(self.inputs.len() as u64));
+        for input in &self.inputs {
+            match input {
+                MoneroTransactionInput::Gen { height } => {
+                    serialized.push(TXIN_GEN_TAG);
+                    serialized.extend(encode_varint(*height));
+                }
+                MoneroTransactionInput::ToKey {
+                    amount,
+                    key_offsets,
+                    key_image,
+                } => {
+                    serialized.push(TXIN_TO_KEY_TAG);
+                    serialized.extend(encode_varint(*amount));
+                    serialized.extend(encode_varint(key_offsets.len() as u64));
+                    key_offsets
+                        .iter()
+                        .for_each(|offset| serialized.extend(encode_varint(*offset)));
+                    serialized.extend(key_image);
+                }
+            }
+        }
+
+        serialized.extend(encode_varint(self.outputs.len() as u64));
+        for output in &self.outputs {
+            serialized.extend(encode_varint(output.amount));
+            match &output.target {
+                MoneroTransactionTarget::ToKey { key } => {
+                    serialized.push(TXOUT_TO_KEY_TAG);
+                    serialized.extend(key);
+                }
+                MoneroTransactionTarget::ToTaggedKey { key, view_tag } => {
+                    serialized.push(TXOUT_TO_TAGGED_KEY_TAG);
+                    serialized.extend(key);
+                    serialized.push(*view_tag);
+                }
+            }
+        }
+
+        serialized.extend(encode_varint(self.extra.len() as u64));
+        serialized.extend(&self.extra);
+        serialized
+    }
+
+    /// Returns the transaction prefix at the start of the given bytes and the length of its encoding.
+    pub fn deserialize(bytes: &[u8]) -> Result<(Self, usize), TransactionError> {
+        let mut reader = Reader::new(bytes);
+        let version = reader.varint()?;
+        let unlock_time = reader.varint()?;
+
+        let mut inputs = vec![];
+        for _ in 0..reader.length()? {
+            let input = match reader.byte()? {
+                TXIN_GEN_TAG => MoneroTransactionInput::Gen {
+                    height: reader.varint()?,
+                },
+                TXIN_TO_KEY_TAG => {
+                    let amount = reader.varint()?;
+                    let mut key_offsets = vec![];
+                    for _ in 0..reader.length()? {
+                        key_offsets.push(reader.varint()?);
+                    }
+                    let key_image = reader.key()?;
+                    MoneroTransactionInput::ToKey {
+                        amount,
+                        key_offsets,
+                        key_image,
+                    }
+                }
+                tag => return Err(TransactionError::Message(format!("invalid input tag {}", tag))),
+            };
+            inputs.push(input);
+        }
+
+        let mut outputs = vec![];
+        for _ in 0..reader.length()? {
+            let amount = reader.varint()?;
+            let target = match reader.byte()? {
+                TXOUT_TO_KEY_TAG => MoneroTransactionTarget::ToKey { key: reader.key()? },
+                TXOUT_TO_TAGGED_KEY_TAG => MoneroTransactionTarget::ToTaggedKey {
+                    key: reader.key()?,
+                    view_tag: reader.byte()?,
+                },
+                tag => return Err(TransactionError::Message(format!("invalid output tag {}", tag))),
+            };
+            outputs.push(MoneroTransactionOutput { amount, target });
+        }
+
+        let extra_length = reader.length()?;
+        let extra = reader.bytes(extra_length)?.to_vec();
+
+        let prefix = Self {
+            version,
+            unlock_time,
+            inputs,
+            outputs,
+            extra,
+        };
+        Ok((prefix, reader.position))
+    }
+
+    /// Returns the hash of the transaction prefix, which is the message signed by the inputs.
+    pub fn to_hash(&self) -> [u8; 32] {
+        keccak256(&self.serialize())
+    }
+}
+
+/// Represents a serialized Monero transaction, as its parsed prefix and its raw signatures.
+/// The signatures are ring signatures for version 1 transactions and RingCT signatures for
+/// version 2 transactions.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MoneroRawTransaction {
+    /// The transaction prefix
+    pub prefix: MoneroTransactionPrefix,
+    /// The serialized signatures following the prefix
+    pub signatures: Vec<u8>,
+}
+
+impl MoneroRawTransaction {
+    /// Returns the raw transaction of the given serialized transaction.
+    pub fn new(transaction: &[u8]) -> Result<Self, TransactionError> {
+        let (prefix, length) = MoneroTransactionPrefix::deserialize(transaction)?;
+        Ok(Self {
+            prefix,
+            signatures: transaction[length..].to_vec(),
+        })
+    }
+
+    /// Returns the serialized transaction.
+    pub fn serialize_transaction(&self) -> Vec<u8> {
+        let mut serialized = self.prefix.serialize();
+        serialized.extend(&self.signatures);
+        serialized
+    }
+
+    /// Returns the hash of the transaction prefix.
+    pub fn get_transaction_prefix_hash(&self) -> [u8; 32] {
+        self.prefix.to_hash()
+    }
+
+    /// Returns the transaction hash, which is the transaction id.
+    /// A version 1 transaction is hashed in full. A version 2 transaction is hashed as the hashes of
+    /// its prefix, its RingCT base, and its prunable RingCT signatures, so it may be pruned.
+    pub fn get_transaction_hash(&self) -> Result<[u8; 32], TransactionError> {
+        if self.prefix.version == 1 {
+            return Ok(keccak256(&self.serialize_transaction()));
+        }
+
+        let base_length = self.rct_base_length()?;
+        let (base, prunable) = self.signatures.split_at(base_length);
+        let prunable_hash = match base[0] {
+            RCT_TYPE_NULL => [0u8; 32],
+            _ => keccak256(prunable),
+        };
+
+        let mut hashes = Vec::with_capacity(96);
+        hashes.extend(&self.get_transaction_prefix_hash());
+        hashes.extend(&keccak256(base));
+        hashes.extend(&prunable_hash);
+        Ok(keccak256(&hashes))
+    }
+
+    /// Returns the length of the RingCT base, which consists of the RingCT type, the fee,
+    /// the pseudo outputs of simple RingCT signatures, the encrypted amounts, and the output commitments.
+    fn rct_base_length(&self) -> Result<usize, TransactionError> {
+        let mut reader = Reader::new(&self.signatures);
+        let rct_type = reader.byte()?;
+        if rct_type == RCT_TYPE_NULL {
+            return Ok(reader.position);
+        }
+
+        reader.varint()?;
+        if rct_type == RCT_TYPE_SIMPLE {
+            reader.bytes(32 * self.prefix.inputs.len())?;
+        }
+        let ecdh_info_length = match rct_type {
+            t if t < RCT_TYPE_BULLETPROOF_2 => 64,
+            _ => 8,
+        };
+        reader.bytes((ecdh_info_length + 32) * self.prefix.outputs.len())?;
+        Ok(reader.position)
+    }
+}
+
+/// A cursor over serialized transaction bytes
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn bytes(&mut self, length: usize) -> Result<&'a [u8], TransactionError> {
+        match self.bytes.get(self.position..self.position.saturating_add(length)) {
+            Some(bytes) => {
+                self.position += length;
+                Ok(bytes)
+            }
+            None => Err("transaction is truncated".into()),
+        }
+    }
+
+    fn byte(&mut self) -> Result<u8, TransactionError> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn key(&mut self) -> Result<[u8; 32], TransactionError> {
+        let mut key = [0u8; 32];
+        key.copy_from_slice(self.bytes(32)?);
+        Ok(key)
+    }
+
+    fn varint(&mut self) -> Result<u64, TransactionError> {
+        let (value, length) = decode_varint(&self.bytes[self.position..])?;
+        self.position += length;
+        Ok(value)
+    }
+
+    /// Reads a varint length, bounded by the remaining bytes to reject malicious lengths
+    fn length(&mut self) -> Result<usize, TransactionError> {
+        let length = self.varint()?;
+        match length <= (self.bytes.len() - self.position) as u64 {
+            true => Ok(length as usize),
+            false => Err(TransactionError::Message(format!("invalid length {}", length))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The coinbase transaction of the Monero mainnet genesis block
+    const GENESIS_TRANSACTION: &str = "013c01ff0001ffffffffffff03029b2e4c0281c0b02e7c53291a94d1d0cbff8883f8024f5142ee494ffbbd08807121017767aafcde9be00dcfd098715ebcf7f410daebc582fda69d24a28e9d0bc890d1";
+    const GENESIS_TRANSACTION_HASH: &str = "c88ce9783b4f11190d7b9c17a69c1c52200f9faaee8e98dd07e6811175177139";
+
+    #[test]
+    fn varint() {
+        let vectors: [(u64, &str); 6] = [
+            (0, "00"),
+            (127, "7f"),
+            (128, "8001"),
+            (300, "ac02"),
+            (17592186044415, "ffffffffffff03"),
+            (u64::MAX, "ffffffffffffffffff01"),
+        ];
+        for (value, encoded) in vectors.iter() {
+            assert_eq!(*encoded, hex::encode(encode_varint(*value)));
+            let bytes = hex::decode(encoded).unwrap();
+            assert_eq!((*value, bytes.len()), decode_varint(&bytes).unwrap());
+        }
+    }
+
+    #[test]
+    fn invalid_varint() {
+        // Non-canonical, overflowing, and truncated
+        assert!(decode_varint(&[0x80, 0x00]).is_err());
+        assert!(decode_varint(&[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02]).is_err());
+        assert!(decode_varint(&[0x80]).is_err());
+        assert!(decode_varint(&[]).is_err());
+    }
+
+    #[test]
+    fn genesis_transaction() {
+        let bytes = hex::decode(GENESIS_TRANSACTION).unwrap();
+        let transaction = MoneroRawTransaction::new(&bytes).unwrap();
+
+        let prefix = &transaction.prefix;
+        assert_eq!(1, prefix.version);
+        assert_eq!(60, prefix.unlock_time);
+        assert_eq!(vec![MoneroTransactionInput::Gen { height: 0 }], prefix.inputs);
+        assert_eq!(1, prefix.outputs.len());
+        assert_eq!(17592186044415, prefix.outputs[0].amount);
+        assert_eq!(33, prefix.extra.len());
+        assert!(transaction.signatures.is_empty());
+
+        assert_eq!(bytes, transaction.serialize_transaction());
+        assert_eq!(
+            GENESIS_TRANSACTION_HASH,
+            hex::encode(transaction.get_transaction_hash().unwrap())
+        );
+        // A coinbase transaction has no signatures, so its prefix hash is its transaction hash
+        assert_eq!(
+            GENESIS_TRANSACTION_HASH,
+            hex::encode(transaction.get_transaction_prefix_hash())
+        );
+    }
+
+    #[test]
+    fn ringct_transaction() {
+        let prefix = MoneroTransactionPrefix {
+            version: 2,
+            unlock_time: 0,
+            inputs: vec![MoneroTransactionInput::ToKey {
+                amount: 0,
+                key_offsets: vec![1_000_000, 300, 1],
+                key_image: [1u8; 32],
+            }],
+            outputs: vec![
+                MoneroTransactionOutput {
+                    amount: 0,
+                    target: MoneroTransactionTarget::ToTaggedKey {
+                        key: [2u8; 32],
+                        view_tag: 0x5a,
+                    },
+                },
+                MoneroTransactionOutput {
+                    amount: 0,
+                    target: MoneroTransactionTarget::ToKey { key: [3u8; 32] },
+                },
+            ],
+            extra: vec![0x01; 33],
+        };
+
+        // A CLSAG base of the type, the fee, two 8 byte encrypted amounts, and two output commitments
+        let mut base = vec![5u8];
+        base.extend(encode_varint(30_000_000));
+        base.extend(vec![4u8; 2 * (8 + 32)]);
+        let prunable = vec![6u8; 100];
+
+        let mut bytes = prefix.serialize();
+        bytes.extend(&base);
+        bytes.extend(&prunable);
+
+        let transaction = MoneroRawTransaction::new(&bytes).unwrap();
+        assert_eq!(prefix, transaction.prefix);
+        assert_eq!(bytes, transaction.serialize_transaction());
+
+        let mut hashes = prefix.to_hash().to_vec();
+        hashes.extend(&keccak256(&base));
+        hashes.extend(&keccak256(&prunable));
+        assert_eq!(keccak256(&hashes), transaction.get_transaction_hash().unwrap());
+    }
+
+    #[test]
+    fn invalid_transaction() {
+        let bytes = hex::decode(GENESIS_TRANSACTION).unwrap();
+        assert!(MoneroRawTransaction::new(&bytes[..bytes.len() - 1]).is_err());
+
+        let mut invalid_tag = bytes.clone();
+        invalid_tag[3] = 0x01;
+        assert!(MoneroRawTransaction::new(&invalid_tag).is_err());
+
+        // A version 2 transaction with a truncated RingCT base
+        let mut truncated = bytes.clone();
+        truncated[0] = 0x02;
+        truncated.extend(&[5u8, 0x80]);
+        assert!(MoneroRawTransaction::new(&truncated)
+            .unwrap()
+            .get_transaction_hash()
+            .is_err());
+    }
+}
@@ -0,0 +1,619 @@
+#![allow(non_snake_case)]
+
+use crate::key_image::hash_to_point;
+use crate::raw_transaction::encode_varint;
+use wagyu_model::no_std::{vec, Vec};
+
+use curve25519_dalek::{
+    constants::ED25519_BASEPOINT_POINT,
+    edwards::{CompressedEdwardsY, EdwardsPoint},
+    scalar::Scalar,
+    traits::{IsIdentity, VartimeMultiscalarMul},
+};
+use rand::Rng;
+use tiny_keccak::keccak256;
+
+/// The compressed generator H of the amounts of Pedersen commitments, which is 8 * to_point(keccak256(G))
+pub const GENERATOR_H: [u8; 32] = [
+    0x8b, 0x65, 0x59, 0x70, 0x15, 0x37, 0x99, 0xaf, 0x2a, 0xea, 0xdc, 0x9f, 0xf1, 0xad, 0xd0, 0xea, 0x6c, 0x72, 0x51,
+    0xd5, 0x41, 0x54, 0xcf, 0xa9, 0x2c, 0x17, 0x3a, 0x0d, 0xd3, 0x9c, 0x1f, 0x94,
+];
+
+/// The maximum number of amounts in an aggregated range proof
+pub const BULLETPROOF_PLUS_MAX_OUTPUTS: usize = 16;
+/// The number of bits of a proven amount
+const BITS: usize = 64;
+/// The salt of the hash of the vector generators
+const BULLETPROOF_PLUS_EXPONENT: &[u8] = b"bulletproof_plus";
+/// The domain separator of the initial transcript
+const BULLETPROOF_PLUS_TRANSCRIPT: &[u8] = b"bulletproof_plus_transcript";
+
+#[derive(Debug, Error)]
+pub enum RingCTError {
+    #[error("invalid number of amounts {0}, expected between 1 and {1}")]
+    InvalidAmounts(usize, usize),
+
+    #[error("mismatched number of amounts {0} and masks {1}")]
+    MismatchedMasks(usize, usize),
+
+    #[error("transcript challenge is zero")]
+    ZeroChallenge,
+}
+
+/// Returns the Keccak-256 hash of the given bytes reduced to a scalar, as Monero `hash_to_scalar`.
+pub fn hash_to_scalar(data: &[u8]) -> Scalar {
+    Scalar::from_bytes_mod_order(keccak256(data))
+}
+
+/// Returns the generator H of the amounts of Pedersen commitments.
+pub fn amount_generator() -> EdwardsPoint {
+    CompressedEdwardsY(GENERATOR_H)
+        .decompress()
+        .expect("H is a valid point")
+}
+
+/// Returns the Pedersen commitment to the given amount with the given mask, mask * G + amount * H.
+pub fn commit(amount: u64, mask: &Scalar) -> EdwardsPoint {
+    mask * ED25519_BASEPOINT_POINT + Scalar::from(amount) * amount_generator()
+}
+
+/// Returns the Pedersen commitment to the given amount with a mask of one, as used for fees and coinbase outputs.
+pub fn zero_commit(amount: u64) -> EdwardsPoint {
+    commit(amount, &Scalar::one())
+}
+
+/// Returns the commitment mask of an output with the given shared secret,
+/// which is the scalar derived from the key derivation and the output index.
+pub fn generate_commitment_mask(shared_secret: &[u8; 32]) -> Scalar {
+    let mut data = b"commitment_mask".to_vec();
+    data.extend(shared_secret);
+    hash_to_scalar(&data)
+}
+
+/// Returns the amount of an output encrypted with the given shared secret, as the 8 byte `ecdhInfo`.
+/// Encryption is symmetric, so the same function decrypts the amount.
+pub fn encrypt_amount(amount: &[u8; 8], shared_secret: &[u8; 32]) -> [u8; 8] {
+    let mut data = b"amount".to_vec();
+    data.extend(shared_secret);
+    let hash = keccak256(&data);
+
+    let mut encrypted = *amount;
+    encrypted
+        .iter_mut()
+        .zip(hash.iter())
+        .for_each(|(byte, key)| *byte ^= key);
+    encrypted
+}
+
+/// Represents an aggregated Bulletproofs+ range proof that each committed amount is in [0, 2^64).
+/// The commitments and points are stored multiplied by the inverse of eight, as in Monero consensus.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BulletproofPlus {
+    /// The commitments to the amounts
+    pub V: Vec<EdwardsPoint>,
+    /// The commitment to the bits of the amounts
+    pub A: EdwardsPoint,
+    /// The first commitment of the final round of the inner product argument
+    pub A1: EdwardsPoint,
+    /// The second commitment of the final round of the inner product argument
+    pub B: EdwardsPoint,
+    /// The responses of the final round of the inner product argument
+    pub r1: Scalar,
+    pub s1: Scalar,
+    pub d1: Scalar,
+    /// The left and right commitments of each round of the inner product argument
+    pub L: Vec<EdwardsPoint>,
+    pub R: Vec<EdwardsPoint>,
+}
+
+impl BulletproofPlus {
+    /// Returns a range proof of the given amounts, committed with the given masks.
+    pub fn prove<R: Rng>(amounts: &[u64], masks: &[Scalar], rng: &mut R) -> Result<Self, RingCTError> {
+        if amounts.is_empty() || amounts.len() > BULLETPROOF_PLUS_MAX_OUTPUTS {
+            return Err(RingCTError::InvalidAmounts(amounts.len(), BULLETPROOF_PLUS_MAX_OUTPUTS));
+        }
+        if amounts.len() != masks.len() {
+            return Err(RingCTError::MismatchedMasks(amounts.len(), masks.len()));
+        }
+
+        let inv_eight = Scalar::from(8u64).invert();
+        let (G, H) = (ED25519_BASEPOINT_POINT, amount_generator());
+        let M = amounts.len().next_power_of_two();
+        let MN = M * BITS;
+        let (Gi, Hi) = generators(MN);
+
+        let V: Vec<EdwardsPoint> = amounts
+            .iter()
+            .zip(masks)
+            .map(|(amount, mask)| commit(*amount, mask) * inv_eight)
+            .collect();
+
+        // The bits of the padded amounts
+        let aL: Vec<Scalar> = (0..MN)
+            .map(|i| match amounts.get(i / BITS) {
+                Some(amount) => Scalar::from((amount >> (i % BITS)) & 1),
+                None => Scalar::zero(),
+            })
+            .collect();
+        let aR: Vec<Scalar> = aL.iter().map(|bit| bit - Scalar::one()).collect();
+
+        let alpha = random_scalar(rng);
+        let A = EdwardsPoint::vartime_multiscalar_mul(
+            aL.iter().chain(aR.iter()).chain(Some(&alpha)).map(|s| s * inv_eight),
+            Gi.iter().chain(Hi.iter()).chain(Some(&G)),
+        );
+
+        let mut transcript = initial_transcript(&V);
+        let y = transcript_update(&transcript, &[&A.compress().to_bytes()])?;
+        let z = hash_to_scalar(y.as_bytes());
+        if z == Scalar::zero() {
+            return Err(RingCTError::ZeroChallenge);
+        }
+        transcript = z;
+
+        let y_powers = powers(&y, MN + 2);
+        let d = d_vector(&z, M);
+
+        let a: Vec<Scalar> = aL.iter().map(|bit| bit - z).collect();
+        let b: Vec<Scalar> = (0..MN).map(|i| aR[i] + z + d[i] * y_powers[MN - i]).collect();
+
+        let mut alpha1 = alpha;
+        let mut z_squared_power = z * z;
+        for mask in masks {
+            alpha1 += z_squared_power * y_powers[MN + 1] * mask;
+            z_squared_power *= z * z;
+        }
+
+        // The weighted inner product argument, halving the vectors each round
+        let y_inv = y.invert();
+        let (mut Gprime, mut Hprime, mut aprime, mut bprime) = (Gi, Hi, a, b);
+        let (mut L, mut R) = (vec![], vec![]);
+        let mut nprime = MN;
+        while nprime > 1 {
+            nprime /= 2;
+            let (y_nprime, y_inv_nprime) = (y_powers[nprime], scalar_pow(&y_inv, nprime));
+
+            let cL = weighted_inner_product(&aprime[..nprime], &bprime[nprime..], &y_powers);
+            let a_scaled: Vec<Scalar> = aprime[nprime..].iter().map(|a| a * y_nprime).collect();
+            let cR = weighted_inner_product(&a_scaled, &bprime[..nprime], &y_powers);
+            let (dL, dR) = (random_scalar(rng), random_scalar(rng));
+
+            let L_k = EdwardsPoint::vartime_multiscalar_mul(
+                aprime[..nprime]
+                    .iter()
+                    .map(|a| a * y_inv_nprime)
+                    .chain(bprime[nprime..].iter().cloned())
+                    .chain(vec![cL, dL])
+                    .map(|s| s * inv_eight),
+                Gprime[nprime..].iter().chain(Hprime[..nprime].iter()).chain(&[H, G]),
+            );
+            let R_k = EdwardsPoint::vartime_multiscalar_mul(
+                a_scaled
+                    .iter()
+                    .cloned()
+                    .chain(bprime[..nprime].iter().cloned())
+                    .chain(vec![cR, dR])
+                    .map(|s| s * inv_eight),
+                Gprime[..nprime].iter().chain(Hprime[nprime..].iter()).chain(&[H, G]),
+            );
+
+            let e = transcript_update(&transcript, &[&L_k.compress().to_bytes(), &R_k.compress().to_bytes()])?;
+            let e_inv = e.invert();
+            transcript = e;
+            L.push(L_k);
+            R.push(R_k);
+
+            Gprime = (0..nprime)
+                .map(|i| e_inv * Gprime[i] + (e * y_inv_nprime) * Gprime[nprime + i])
+                .collect();
+            Hprime = (0..nprime)
+                .map(|i| e * Hprime[i] + e_inv * Hprime[nprime + i])
+                .collect();
+            aprime = (0..nprime)
+                .map(|i| e * aprime[i] + e_inv * y_nprime * aprime[nprime + i])
+                .collect();
+            bprime = (0..nprime)
+                .map(|i| e_inv * bprime[i] + e * bprime[nprime + i])
+                .collect();
+            alpha1 += dL * e * e + dR * e_inv * e_inv;
+        }
+
+        // The final round of the inner product argument
+        let (r, s, d_, eta) = (
+            random_scalar(rng),
+            random_scalar(rng),
+            random_scalar(rng),
+            random_scalar(rng),
+        );
+        let A1 = EdwardsPoint::vartime_multiscalar_mul(
+            &[r, s, d_, r * y * bprime[0] + s * y * aprime[0]]
+                .iter()
+                .map(|s| s * inv_eight)
+                .collect::<Vec<_>>(),
+            &[Gprime[0], Hprime[0], G, H],
+        );
+        let B = EdwardsPoint::vartime_multiscalar_mul(&[eta * inv_eight, r * y * s * inv_eight], &[G, H]);
+
+        let e = transcript_update(&transcript, &[&A1.compress().to_bytes(), &B.compress().to_bytes()])?;
+
+        Ok(Self {
+            V,
+            A,
+            A1,
+            B,
+            r1: r + aprime[0] * e,
+            s1: s + bprime[0] * e,
+            d1: eta + d_ * e + alpha1 * e * e,
+            L,
+            R,
+        })
+    }
+
+    /// Returns `true` if the range proof is valid for its commitments.
+    pub fn verify(&self) -> bool {
+        if self.V.is_empty()
+            || self.V.len() > BULLETPROOF_PLUS_MAX_OUTPUTS
+            || self.L.len() != (self.V.len().next_power_of_two() * BITS).trailing_zeros() as usize
+            || self.R.len() != self.L.len()
+        {
+            return false;
+        }
+
+        match self.verification_equation() {
+            Ok(point) => point.is_identity(),
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the commitments to the amounts, mask * G + amount * H.
+    pub fn commitments(&self) -> Vec<EdwardsPoint> {
+        self.V.iter().map(|V| V.mul_by_cofactor()).collect()
+    }
+
+    /// Returns the serialized range proof, as in the prunable RingCT signatures.
+    /// The commitments are not serialized, as they are restored from the output commitments.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        for point in &[self.A, self.A1, self.B] {
+            bytes.extend(&point.compress().to_bytes());
+        }
+        for scalar in &[self.r1, self.s1, self.d1] {
+            bytes.extend(scalar.as_bytes());
+        }
+        for points in &[&self.L, &self.R] {
+            bytes.extend(encode_varint(points.len() as u64));
+            points
+                .iter()
+                .for_each(|point| bytes.extend(&point.compress().to_bytes()));
+        }
+        bytes
+    }
+
+    /// Returns the point which is the identity if and only if the range proof is valid,
+    /// combining the range statement, the rounds of the inner product argument, and its final round.
+    fn verification_equation(&self) -> Result<EdwardsPoint, RingCTError> {
+        let (G, H) = (ED25519_BASEPOINT_POINT, amount_generator());
+        let M = self.V.len().next_power_of_two();
+        let MN = M * BITS;
+        let (Gi, Hi) = generators(MN);
+
+        let mut transcript = initial_transcript(&self.V);
+        let y = transcript_update(&transcript, &[&self.A.compress().to_bytes()])?;
+        let z = hash_to_scalar(y.as_bytes());
+        if z == Scalar::zero() {
+            return Err(RingCTError::ZeroChallenge);
+        }
+        transcript = z;
+
+        let mut challenges = vec![];
+        for (L, R) in self.L.iter().zip(&self.R) {
+            let e = transcript_update(&transcript, &[&L.compress().to_bytes(), &R.compress().to_bytes()])?;
+            challenges.push(e);
+            transcript = e;
+        }
+        let e = transcript_update(
+            &transcript,
+            &[&self.A1.compress().to_bytes(), &self.B.compress().to_bytes()],
+        )?;
+
+        let y_powers = powers(&y, MN + 2);
+        let y_inv = y.invert();
+        let d = d_vector(&z, M);
+        let e_squared = e * e;
+
+        let mut scalars = vec![];
+        let mut points = vec![];
+
+        // The statement, e^2 * (A - z * Gi + (z + d * y^(MN - i)) * Hi + y^(MN + 1) * z^2j * V + c * H)
+        scalars.push(e_squared);
+        points.push(self.A.mul_by_cofactor());
+
+        let mut z_squared_power = z * z;
+        let mut d_sum = Scalar::zero();
+        for j in 0..M {
+            if let Some(V) = self.V.get(j) {
+                scalars.push(e_squared * y_powers[MN + 1] * z_squared_power);
+                points.push(V.mul_by_cofactor());
+            }
+            d_sum += z_squared_power * Scalar::from(u64::MAX);
+            z_squared_power *= z * z;
+        }
+        let y_sum: Scalar = y_powers[1..=MN].iter().sum();
+        let c = (z - z * z) * y_sum - z * y_powers[MN + 1] * d_sum;
+
+        // The final round, e^2 * P + e * A1 + B = r1 * e * G' + s1 * e * H' + r1 * y * s1 * H + d1 * G
+        scalars.push(e_squared * c - self.r1 * y * self.s1);
+        points.push(H);
+        scalars.push(-self.d1);
+        points.push(G);
+        scalars.push(e);
+        points.push(self.A1.mul_by_cofactor());
+        scalars.push(Scalar::one());
+        points.push(self.B.mul_by_cofactor());
+
+        for (i, (Gi, Hi)) in Gi.iter().zip(&Hi).enumerate() {
+            // The coefficients of the folded generators G' and H' for the generators at index i
+            let (mut g, mut h) = (Scalar::one(), Scalar::one());
+            let mut nprime = MN;
+            for e_k in &challenges {
+                nprime /= 2;
+                match i & nprime != 0 {
+                    true => {
+                        g *= e_k * scalar_pow(&y_inv, nprime);
+                        h *= e_k.invert();
+                    }
+                    false => {
+                        g *= e_k.invert();
+                        h *= e_k;
+                    }
+                }
+            }
+
+            scalars.push(-e_squared * z - self.r1 * e * g);
+            points.push(*Gi);
+            scalars.push(e_squared * (z + d[i] * y_powers[MN - i]) - self.s1 * e * h);
+            points.push(*Hi);
+        }
+
+        // The rounds, e^2 * (e_k^2 * L_k + e_k^-2 * R_k)
+        for ((L, R), e_k) in self.L.iter().zip(&self.R).zip(&challenges) {
+            let e_k_squared = e_k * e_k;
+            scalars.push(e_squared * e_k_squared);
+            points.push(L.mul_by_cofactor());
+            scalars.push(e_squared * e_k_squared.invert());
+            points.push(R.mul_by_cofactor());
+        }
+
+        Ok(EdwardsPoint::vartime_multiscalar_mul(scalars, points))
+    }
+}
+
+/// Returns the first `n` vector generators Gi and Hi, where Hi is the generator of even index 2i
+/// and Gi is the generator of odd index 2i + 1, each hashed to a point from H, the salt, and the index.
+fn generators(n: usize) -> (Vec<EdwardsPoint>, Vec<EdwardsPoint>) {
+    let generator = |index: usize| {
+        let mut data = GENERATOR_H.to_vec();
+        data.extend(BULLETPROOF_PLUS_EXPONENT);
+        data.extend(encode_varint(index as u64));
+        hash_to_point(&keccak256(&data))
+    };
+    (0..n).map(|i| (generator(2 * i + 1), generator(2 * i))).unzip()
+}
+
+/// Returns the transcript seeded with the domain separator and the commitments.
+/// As in Monero, the domain separator is hashed to a point, rather than to a scalar.
+fn initial_transcript(V: &[EdwardsPoint]) -> Scalar {
+    let commitments: Vec<u8> = V.iter().flat_map(|V| V.compress().to_bytes().to_vec()).collect();
+    let mut data = hash_to_point(&keccak256(BULLETPROOF_PLUS_TRANSCRIPT))
+        .compress()
+        .to_bytes()
+        .to_vec();
+    data.extend(hash_to_scalar(&commitments).as_bytes());
+    hash_to_scalar(&data)
+}
+
+/// Returns the hash of the transcript and the given points, which is the next challenge.
+fn transcript_update(transcript: &Scalar, points: &[&[u8; 32]]) -> Result<Scalar, RingCTError> {
+    let mut data = transcript.to_bytes().to_vec();
+    points.iter().for_each(|point| data.extend(point.iter()));
+    match hash_to_scalar(&data) {
+        challenge if challenge == Scalar::zero() => Err(RingCTError::ZeroChallenge),
+        challenge => Ok(challenge),
+    }
+}
+
+/// Returns the vector d, where d[j * N + i] = z^(2 * (j + 1)) * 2^i.
+fn d_vector(z: &Scalar, M: usize) -> Vec<Scalar> {
+    let two_powers = powers(&Scalar::from(2u64), BITS);
+    let mut z_squared_power = z * z;
+    let mut d = Vec::with_capacity(M * BITS);
+    for _ in 0..M {
+        d.extend(two_powers.iter().map(|two_power| z_squared_power * two_power));
+        z_squared_power *= z * z;
+    }
+    d
+}
+
+/// Returns the inner product of the given vectors weighted by the powers of y, sum(a_i * b_i * y^(i + 1)).
+fn weighted_inner_product(a: &[Scalar], b: &[Scalar], y_powers: &[Scalar]) -> Scalar {
+    a.iter().zip(b).zip(&y_powers[1..]).map(|((a, b), y)| a * b * y).sum()
+}
+
+/// Returns the first `n` powers of the given scalar, starting from one.
+fn powers(x: &Scalar, n: usize) -> Vec<Scalar> {
+    let mut powers = Vec::with_capacity(n);
+    let mut power = Scalar::one();
+    for _ in 0..n {
+        powers.push(power);
+        power *= x;
+    }
+    powers
+}
+
+/// Returns the scalar raised to the given power.
+fn scalar_pow(x: &Scalar, n: usize) -> Scalar {
+    (0..n).fold(Scalar::one(), |power, _| power * x)
+}
+
+/// Returns a uniformly random scalar.
+pub(crate) fn random_scalar<R: Rng>(rng: &mut R) -> Scalar {
+    let mut bytes = [0u8; 64];
+    rng.fill(&mut bytes[..]);
+    Scalar::from_bytes_mod_order_wide(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn masks(n: usize) -> Vec<Scalar> {
+        (0..n).map(|i| Scalar::from(i as u64 + 7)).collect()
+    }
+
+    #[test]
+    fn amount_generator() {
+        // H = 8 * to_point(keccak256(G)), where the hash is decompressed as a point
+        let hash = keccak256(ED25519_BASEPOINT_POINT.compress().as_bytes());
+        let point = CompressedEdwardsY(hash).decompress().unwrap().mul_by_cofactor();
+        assert_eq!(GENERATOR_H, point.compress().to_bytes());
+    }
+
+    #[test]
+    fn commitments() {
+        let mask = Scalar::from(3u64);
+        assert_eq!(commit(5, &mask) + commit(7, &mask), commit(12, &(mask + mask)));
+        assert_eq!(zero_commit(0), ED25519_BASEPOINT_POINT);
+    }
+
+    #[test]
+    fn encrypted_amount() {
+        let shared_secret = [9u8; 32];
+        let amount = 1_000_000_000_000u64.to_le_bytes();
+        let encrypted = encrypt_amount(&amount, &shared_secret);
+        assert_ne!(amount, encrypted);
+        assert_eq!(amount, encrypt_amount(&encrypted, &shared_secret));
+        assert_ne!(
+            generate_commitment_mask(&shared_secret),
+            generate_commitment_mask(&[8u8; 32])
+        );
+    }
+
+    #[test]
+    fn prove_and_verify() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        for amounts in &[vec![0], vec![u64::MAX], vec![1, 2, 3]] {
+            let masks = masks(amounts.len());
+            let proof = BulletproofPlus::prove(amounts, &masks, rng).unwrap();
+            assert!(proof.verify());
+            assert_eq!(
+                proof.commitments(),
+                amounts
+                    .iter()
+                    .zip(&masks)
+                    .map(|(amount, mask)| commit(*amount, mask))
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(
+                proof.L.len(),
+                (amounts.len().next_power_of_two() * BITS).trailing_zeros() as usize
+            );
+            assert_eq!(32 * 6 + 2 + 32 * 2 * proof.L.len(), proof.to_bytes().len());
+        }
+    }
+
+    #[test]
+    fn verify_vector() {
+        // The range proof of the transaction 2f650db5bafd37ce8982f37ee443f2ecf0a8f08f639591583aecb6cd74d5a80c,
+        // as serialized in its prunable RingCT signatures, and the commitments of its outputs
+        let commitments = [
+            "9c4bc6ac562b6859a40ad8f3bc85ca35c98badb4b4c5d43832f330d6fedb08e8",
+            "f9e2acd339c648bf03957cb02aa69b8ab15326e3bbe1ce35df677306edabd89e",
+            "5635f226a743068500e25028fbdbf1ea19d0921a27c8baec842b753080f407ee",
+            "4b9a87f2c525e9bfb61fb4d14187c0577e799bf20e53a86359cb75f40ee4d291",
+        ];
+        let serialized = hex::decode(
+            [
+                "7c2b59e7278c94b6296dee9ac65ed5ccf61a77ba4f1b3edfb13c5d02748763b23a6bac2f6a891b474d55b625030b35f9",
+                "b7b564e747afd4cb8e1ce830a9bc59fd6e146443965494f94a8433de054080fcb71f8d48803598cc91db3c7b3fd190ea",
+                "8ff5f67980a63de4cb9cd06568a9b27aa994992bc33d70990225acb09faf68066aa27c1118c685cb8f3516c3b664450f",
+                "abdced384de01650d6455287bc0f210aaa5c173c491844155736a64d7cbdda79f0c8a5ccc07d187ca112664a0e6eb500",
+                "087178983179f1ba2ffb030d577638001b58f5e621b4723e5b0bd0853fb430113d03efd026660a18a23c7582e9788f77",
+                "0212b604759aa242b35b3ca4a835bb18881c8593ac4247ba51ea95946cf079721588bac494f563a687fe1010818caa94",
+                "583969b0f4a4a40eeee395cbb7881a53d98cad51b1e5d12c7071a7424b4c534e32c53a31b11e6151edd0a13ef9695021",
+                "bff9bd4c62df9a62d9e0fbd01e750d0b6abc56cc96d55ef06f6428b42fc63f6610633ecf023211e64a1ff89dcabfeeb4",
+                "b938e64312dcc849929e8d4a290eff601e06dc65141665d7b312ac1f0f859a00fd6d6ccf7dc695e7ae3cf44bed1d9c86",
+                "59ee3451dd3498f462912ba881a473c9bc0866e4fb33114b2ef7c25869f9cc3c40a06fc2407e2c678126ff1c38a35c5c",
+                "949bc219fb33ba15730510c41554c727d5adfce33a518148234e0aa5411cb20c115e749792ad47ee19e9f1544dba6159",
+                "3d95cb98d4720a8ae6e60146416d673e5707c3de31d91043422ab848d4676a6845ced6e7075c5a09bc8b4e0ad706c8c0",
+                "7bda527a7325771438e04f37517f3ca5262ef2ddfc9e13db988a90c50be5422a83ad75b93f4faae980d6e6a3abfd0e96",
+                "387121101afaf55f425dc876d9a8735c1e29d823e19fee5e502c18d16ec9225f232cfbc3dcd143aaa1904f42e880b612",
+                "beeea3e5a745a7f32e6b2135a75f71117e2947c99647f14702417a9a76f6130b5d62",
+            ]
+            .concat(),
+        )
+        .unwrap();
+
+        let point = |offset: usize| {
+            let mut bytes = [0u8; 32];
+            bytes.copy_from_slice(&serialized[offset..offset + 32]);
+            CompressedEdwardsY(bytes).decompress().unwrap()
+        };
+        let scalar = |offset: usize| {
+            let mut bytes = [0u8; 32];
+            bytes.copy_from_slice(&serialized[offset..offset + 32]);
+            Scalar::from_canonical_bytes(bytes).unwrap()
+        };
+        // The 8 rounds of 4 aggregated amounts follow the final round, each prefixed by its varint length
+        let rounds = serialized[192] as usize;
+        let inv_eight = Scalar::from(8u64).invert();
+        let proof = BulletproofPlus {
+            V: commitments
+                .iter()
+                .map(|commitment| {
+                    let mut bytes = [0u8; 32];
+                    bytes.copy_from_slice(&hex::decode(commitment).unwrap());
+                    CompressedEdwardsY(bytes).decompress().unwrap() * inv_eight
+                })
+                .collect(),
+            A: point(0),
+            A1: point(32),
+            B: point(64),
+            r1: scalar(96),
+            s1: scalar(128),
+            d1: scalar(160),
+            L: (0..rounds).map(|i| point(193 + 32 * i)).collect(),
+            R: (0..rounds).map(|i| point(194 + 32 * (rounds + i))).collect(),
+        };
+
+        assert_eq!(8, rounds);
+        assert_eq!(serialized, proof.to_bytes());
+        assert!(proof.verify());
+    }
+
+    #[test]
+    fn invalid_proof() {
+        let rng = &mut StdRng::seed_from_u64(0);
+        let proof = BulletproofPlus::prove(&[10, 20], &masks(2), rng).unwrap();
+
+        // A commitment to a different amount
+        let mut invalid = proof.clone();
+        invalid.V[0] = commit(11, &Scalar::from(7u64)) * Scalar::from(8u64).invert();
+        assert!(!invalid.verify());
+
+        // A tampered response
+        let mut invalid = proof.clone();
+        invalid.r1 += Scalar::one();
+        assert!(!invalid.verify());
+
+        // A missing round
+        let mut invalid = proof.clone();
+        invalid.L.pop();
+        assert!(!invalid.verify());
+
+        let invalid = BulletproofPlus { V: vec![], ..proof };
+        assert!(!invalid.verify());
+        assert!(BulletproofPlus::prove(&[], &[], rng).is_err());
+        assert!(BulletproofPlus::prove(&[1], &[], rng).is_err());
+    }
+}
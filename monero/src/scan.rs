@@ -0,0 +1,454 @@
+use crate::extra::{ExtraError, MoneroTransactionExtra};
+use crate::format::MoneroFormat;
+use crate::network::MoneroNetwork;
+use crate::one_time_key::MoneroOneTimeKey;
+use crate::raw_transaction::{
+    encode_varint, MoneroTransactionOutput, MoneroTransactionPrefix, MoneroTransactionTarget,
+};
+use crate::ringct::{commit, encrypt_amount, generate_commitment_mask};
+use crate::view_keypair::MoneroViewKeypair;
+use wagyu_model::no_std::{vec, Vec};
+use wagyu_model::{OneTimeKeyError, PublicKeyError};
+
+use curve25519_dalek::{constants::ED25519_BASEPOINT_TABLE, edwards::CompressedEdwardsY, scalar::Scalar};
+use tiny_keccak::keccak256;
+
+/// The salt of the hash of the view tag of an output
+const VIEW_TAG_SALT: &[u8] = b"view_tag";
+
+#[derive(Debug, Error)]
+pub enum ScanError {
+    #[error("{0}")]
+    ExtraError(ExtraError),
+
+    #[error("decoded amount of output {0} does not match its commitment")]
+    InvalidCommitment(usize),
+
+    #[error("invalid number of {0} {1}, expected {2}")]
+    InvalidLength(&'static str, usize, usize),
+
+    #[error("{0}")]
+    OneTimeKeyError(OneTimeKeyError),
+
+    #[error("{0}")]
+    PublicKeyError(PublicKeyError),
+}
+
+impl From<ExtraError> for ScanError {
+    fn from(error: ExtraError) -> Self {
+        ScanError::ExtraError(error)
+    }
+}
+
+impl From<OneTimeKeyError> for ScanError {
+    fn from(error: OneTimeKeyError) -> Self {
+        ScanError::OneTimeKeyError(error)
+    }
+}
+
+impl From<PublicKeyError> for ScanError {
+    fn from(error: PublicKeyError) -> Self {
+        ScanError::PublicKeyError(error)
+    }
+}
+
+/// Returns the view tag of the output with the given key derivation and index,
+/// which is the first byte of keccak256("view_tag" || derivation || index).
+pub fn derive_view_tag(derivation: &[u8], output_index: u64) -> u8 {
+    let mut data = VIEW_TAG_SALT.to_vec();
+    data.extend(derivation);
+    data.extend(encode_varint(output_index));
+    keccak256(&data)[0]
+}
+
+/// Represents an output of a transaction owned by a scanned wallet
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MoneroOwnedOutput {
+    /// The index of the output in the transaction
+    pub index: usize,
+    /// The one time key of the output
+    pub one_time_key: [u8; 32],
+    /// The major and minor index of the receiving address, which is (0, 0) for the primary address
+    pub subaddress: (u32, u32),
+    /// The amount in piconero
+    pub amount: u64,
+    /// The commitment mask of the amount
+    pub mask: [u8; 32],
+    /// The scalar H_s(derivation || index), which added to the private spend key of the receiving address
+    /// is the one time private key of the output
+    pub key_offset: [u8; 32],
+}
+
+/// Represents a scanner of the outputs received by a wallet, given its view keypair.
+/// The scanner detects outputs to the primary address and to the subaddresses it is given,
+/// and decodes their amounts, without the private spend key of the wallet.
+#[derive(Debug, Clone)]
+pub struct MoneroOutputScanner<N: MoneroNetwork> {
+    /// The view keypair of the wallet
+    view_keypair: MoneroViewKeypair<N>,
+    /// The public spend keys of the scanned addresses, with their major and minor index
+    spend_keys: Vec<([u8; 32], (u32, u32))>,
+}
+
+impl<N: MoneroNetwork> MoneroOutputScanner<N> {
+    /// Returns a scanner of the outputs to the primary address of the given view keypair.
+    pub fn new(view_keypair: &MoneroViewKeypair<N>) -> Self {
+        Self {
+            view_keypair: view_keypair.clone(),
+            spend_keys: vec![(view_keypair.to_public_spend_key(), (0, 0))],
+        }
+    }
+
+    /// Returns the scanner, also scanning the subaddresses of the given major index
+    /// with a minor index less than the given count.
+    pub fn with_subaddresses(mut self, major: u32, count: u32) -> Result<Self, ScanError> {
+        for minor in 0..count {
+            if (major, minor) == (0, 0) {
+                continue;
+            }
+            let public_key = self
+                .view_keypair
+                .to_public_key(&MoneroFormat::Subaddress(major, minor))?;
+            match public_key.to_public_spend_key() {
+                Some(spend_key) => self.spend_keys.push((spend_key, (major, minor))),
+                None => return Err(PublicKeyError::NoSpendingKey.into()),
+            }
+        }
+        Ok(self)
+    }
+
+    /// Returns the owned outputs of the given transaction prefix, with the transaction public keys
+    /// of its extra field, and the encrypted amounts (`ecdhInfo`) and commitments of its RingCT signatures.
+    pub fn scan_transaction(
+        &self,
+        prefix: &MoneroTransactionPrefix,
+        encrypted_amounts: &[[u8; 8]],
+        commitments: &[[u8; 32]],
+    ) -> Result<Vec<MoneroOwnedOutput>, ScanError> {
+        let extra = MoneroTransactionExtra::<N>::from_bytes(&prefix.extra)?;
+        self.scan(
+            &[extra.to_transaction_public_key()],
+            &extra.to_additional_public_keys(),
+            &prefix.outputs,
+            encrypted_amounts,
+            commitments,
+        )
+    }
+
+    /// Returns the owned outputs of a transaction, given its transaction public keys, its additional
+    /// transaction public keys (one for each output, or none), and its outputs.
+    ///
+    /// The amounts of RingCT outputs are decrypted from the given encrypted amounts, and checked against
+    /// the given commitments, if any. Without encrypted amounts, the amounts are those of the outputs.
+    /// Transaction public keys which are not valid points are skipped, as by the reference wallet.
+    pub fn scan(
+        &self,
+        transaction_public_keys: &[[u8; 32]],
+        additional_public_keys: &[[u8; 32]],
+        outputs: &[MoneroTransactionOutput],
+        encrypted_amounts: &[[u8; 8]],
+        commitments: &[[u8; 32]],
+    ) -> Result<Vec<MoneroOwnedOutput>, ScanError> {
+        for (name, length) in &[
+            ("additional public keys", additional_public_keys.len()),
+            ("encrypted amounts", encrypted_amounts.len()),
+            ("commitments", commitments.len()),
+        ] {
+            if *length != 0 && *length != outputs.len() {
+                return Err(ScanError::InvalidLength(*name, *length, outputs.len()));
+            }
+        }
+
+        let derivations: Vec<Vec<u8>> = transaction_public_keys
+            .iter()
+            .filter_map(|key| self.to_key_derivation(key))
+            .collect();
+
+        let mut owned_outputs = vec![];
+        for (index, output) in outputs.iter().enumerate() {
+            let additional_derivation = additional_public_keys
+                .get(index)
+                .and_then(|key| self.to_key_derivation(key));
+
+            for derivation in derivations.iter().chain(additional_derivation.iter()) {
+                if let Some(owned_output) = self.scan_output(derivation, index, output) {
+                    owned_outputs.push(self.decode_amount(owned_output, output, encrypted_amounts, commitments)?);
+                    break;
+                }
+            }
+        }
+        Ok(owned_outputs)
+    }
+
+    /// Returns the key derivation of the given transaction public key and the private view key,
+    /// or returns `None` if the transaction public key is not a valid point.
+    fn to_key_derivation(&self, transaction_public_key: &[u8; 32]) -> Option<Vec<u8>> {
+        let mut derivation = Vec::new();
+        MoneroOneTimeKey::<N>::generate_key_derivation(
+            transaction_public_key,
+            &self.view_keypair.to_private_view_key(),
+            &mut derivation,
+        )
+        .ok()
+        .map(|_| derivation)
+    }
+
+    /// Returns the owned output at the given index with the given key derivation, without its amount,
+    /// if its one time key minus H_s(derivation || index) * G is the public spend key of a scanned address.
+    fn scan_output(
+        &self,
+        derivation: &Vec<u8>,
+        index: usize,
+        output: &MoneroTransactionOutput,
+    ) -> Option<MoneroOwnedOutput> {
+        let one_time_key = match output.target {
+            MoneroTransactionTarget::ToKey { key } => key,
+            MoneroTransactionTarget::ToTaggedKey { key, view_tag } => {
+                // The view tag skips the point arithmetic for almost all outputs of other wallets
+                if derive_view_tag(derivation, index as u64) != view_tag {
+                    return None;
+                }
+                key
+            }
+        };
+
+        let key_offset = MoneroOneTimeKey::<N>::derivation_to_scalar(derivation, index as u64);
+        let spend_key = CompressedEdwardsY(one_time_key).decompress()? - &key_offset * &ED25519_BASEPOINT_TABLE;
+        let spend_key = spend_key.compress().to_bytes();
+
+        self.spend_keys
+            .iter()
+            .find(|(key, _)| *key == spend_key)
+            .map(|(_, subaddress)| MoneroOwnedOutput {
+                index,
+                one_time_key,
+                subaddress: *subaddress,
+                amount: output.amount,
+                mask: Scalar::one().to_bytes(),
+                key_offset: key_offset.to_bytes(),
+            })
+    }
+
+    /// Returns the owned output with its amount decrypted with its shared secret, the key offset,
+    /// and checked against its commitment, if the transaction has encrypted amounts.
+    fn decode_amount(
+        &self,
+        mut owned_output: MoneroOwnedOutput,
+        output: &MoneroTransactionOutput,
+        encrypted_amounts: &[[u8; 8]],
+        commitments: &[[u8; 32]],
+    ) -> Result<MoneroOwnedOutput, ScanError> {
+        let index = owned_output.index;
+        if let Some(encrypted_amount) = encrypted_amounts.get(index) {
+            let amount = u64::from_le_bytes(encrypt_amount(encrypted_amount, &owned_output.key_offset));
+            let mask = generate_commitment_mask(&owned_output.key_offset);
+
+            if let Some(commitment) = commitments.get(index) {
+                if commit(amount, &mask).compress().to_bytes() != *commitment {
+                    return Err(ScanError::InvalidCommitment(index));
+                }
+            }
+
+            owned_output.amount = amount;
+            owned_output.mask = mask.to_bytes();
+        } else {
+            owned_output.amount = output.amount;
+        }
+        Ok(owned_output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::Mainnet;
+    use crate::private_key::MoneroPrivateKey;
+    use crate::public_key::MoneroPublicKey;
+
+    type N = Mainnet;
+
+    const RECIPIENT_SEED: &str = "3eb8e283b45559d4d2fb6b3a4f52443b420e6da2b38832ea0eb642100c92d600";
+    const STRANGER_SEED: &str = "8d8c8eeca38ac3b46aa293fd519b3860e96b5f873c12a95e3e1cdeda0bac4903";
+
+    fn public_key(seed: &str, format: &MoneroFormat) -> MoneroPublicKey<N> {
+        let private_key = MoneroPrivateKey::<N>::from_seed(seed, &MoneroFormat::Standard).unwrap();
+        MoneroViewKeypair::from_private_key(&private_key)
+            .to_public_key(format)
+            .unwrap()
+    }
+
+    fn scanner() -> MoneroOutputScanner<N> {
+        let private_key = MoneroPrivateKey::<N>::from_seed(RECIPIENT_SEED, &MoneroFormat::Standard).unwrap();
+        MoneroOutputScanner::new(&MoneroViewKeypair::from_private_key(&private_key))
+    }
+
+    /// Returns the output, encrypted amount, and commitment of a RingCT output of the given amount,
+    /// sent to the given public key with the given transaction private key, as by the reference wallet.
+    fn output(
+        public_key: &MoneroPublicKey<N>,
+        transaction_private_key: &[u8; 32],
+        index: u64,
+        amount: u64,
+    ) -> (MoneroTransactionOutput, [u8; 8], [u8; 32]) {
+        let one_time_key = MoneroOneTimeKey::new(public_key, transaction_private_key, index).unwrap();
+
+        let mut derivation = Vec::new();
+        MoneroOneTimeKey::<N>::generate_key_derivation(
+            &public_key.to_public_view_key().unwrap(),
+            transaction_private_key,
+            &mut derivation,
+        )
+        .unwrap();
+        let shared_secret = MoneroOneTimeKey::<N>::derivation_to_scalar(&derivation, index).to_bytes();
+
+        let output = MoneroTransactionOutput {
+            amount: 0,
+            target: MoneroTransactionTarget::ToTaggedKey {
+                key: one_time_key.to_destination_key(),
+                view_tag: derive_view_tag(&derivation, index),
+            },
+        };
+        let encrypted_amount = encrypt_amount(&amount.to_le_bytes(), &shared_secret);
+        let commitment = commit(amount, &generate_commitment_mask(&shared_secret))
+            .compress()
+            .to_bytes();
+        (output, encrypted_amount, commitment)
+    }
+
+    fn transaction_public_key(transaction_private_key: &[u8; 32]) -> [u8; 32] {
+        (&Scalar::from_bits(*transaction_private_key) * &ED25519_BASEPOINT_TABLE)
+            .compress()
+            .to_bytes()
+    }
+
+    #[test]
+    fn scan_primary_address() {
+        let r = [7u8; 32];
+        let recipient = public_key(RECIPIENT_SEED, &MoneroFormat::Standard);
+        let stranger = public_key(STRANGER_SEED, &MoneroFormat::Standard);
+        let (output_0, amount_0, commitment_0) = output(&stranger, &r, 0, 5_000);
+        let (output_1, amount_1, commitment_1) = output(&recipient, &r, 1, 1_250_000_000_000);
+
+        let owned = scanner()
+            .scan(
+                &[transaction_public_key(&r)],
+                &[],
+                &[output_0.clone(), output_1.clone()],
+                &[amount_0, amount_1],
+                &[commitment_0, commitment_1],
+            )
+            .unwrap();
+        assert_eq!(1, owned.len());
+        assert_eq!(1, owned[0].index);
+        assert_eq!((0, 0), owned[0].subaddress);
+        assert_eq!(1_250_000_000_000, owned[0].amount);
+
+        // The key offset added to the private spend key is the one time private key
+        let private_key = MoneroPrivateKey::<N>::from_seed(RECIPIENT_SEED, &MoneroFormat::Standard).unwrap();
+        let one_time_key = MoneroOneTimeKey::<N>::from(&owned[0].one_time_key, &transaction_public_key(&r));
+        assert_eq!(
+            one_time_key.to_private(&private_key, 1).unwrap(),
+            (Scalar::from_bits(owned[0].key_offset) + Scalar::from_bits(private_key.to_private_spend_key())).to_bytes()
+        );
+
+        // A tampered commitment is rejected
+        assert!(scanner()
+            .scan(
+                &[transaction_public_key(&r)],
+                &[],
+                &[output_0, output_1],
+                &[amount_0, amount_1],
+                &[commitment_0, commitment_0],
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn scan_subaddress() {
+        let r = [9u8; 32];
+        let subaddress = public_key(RECIPIENT_SEED, &MoneroFormat::Subaddress(0, 3));
+        let stranger = public_key(STRANGER_SEED, &MoneroFormat::Standard);
+        let (output_0, amount_0, _) = output(&stranger, &r, 0, 5_000);
+        let (output_1, amount_1, _) = output(&subaddress, &r, 1, 42);
+
+        // The transaction public key of an output to a subaddress is r * D, for its public spend key D
+        let spend_key = CompressedEdwardsY(subaddress.to_public_spend_key().unwrap())
+            .decompress()
+            .unwrap();
+        let additional_public_keys = [
+            transaction_public_key(&r),
+            (Scalar::from_bits(r) * spend_key).compress().to_bytes(),
+        ];
+        let outputs = [output_0, output_1];
+
+        // Outputs to unscanned subaddresses are not detected
+        let owned = scanner()
+            .scan(
+                &[[0u8; 32]],
+                &additional_public_keys,
+                &outputs,
+                &[amount_0, amount_1],
+                &[],
+            )
+            .unwrap();
+        assert!(owned.is_empty());
+
+        let owned = scanner()
+            .with_subaddresses(0, 5)
+            .unwrap()
+            .scan(
+                &[[0u8; 32]],
+                &additional_public_keys,
+                &outputs,
+                &[amount_0, amount_1],
+                &[],
+            )
+            .unwrap();
+        assert_eq!(1, owned.len());
+        assert_eq!((1, (0, 3), 42), (owned[0].index, owned[0].subaddress, owned[0].amount));
+    }
+
+    #[test]
+    fn scan_transaction() {
+        let r = [7u8; 32];
+        let recipient = public_key(RECIPIENT_SEED, &MoneroFormat::Standard);
+        let (output, encrypted_amount, commitment) = output(&recipient, &r, 0, 1_000);
+
+        let mut extra = vec![crate::extra::TX_EXTRA_TAG_PUBKEY];
+        extra.extend(&transaction_public_key(&r));
+        let prefix = MoneroTransactionPrefix {
+            version: 2,
+            unlock_time: 0,
+            inputs: vec![],
+            outputs: vec![output],
+            extra,
+        };
+
+        let owned = scanner()
+            .scan_transaction(&prefix, &[encrypted_amount], &[commitment])
+            .unwrap();
+        assert_eq!(1, owned.len());
+        assert_eq!(1_000, owned[0].amount);
+    }
+
+    #[test]
+    fn invalid_lengths() {
+        let r = [7u8; 32];
+        let recipient = public_key(RECIPIENT_SEED, &MoneroFormat::Standard);
+        let (output, encrypted_amount, _) = output(&recipient, &r, 0, 1_000);
+        let transaction_public_key = transaction_public_key(&r);
+
+        assert!(scanner()
+            .scan(
+                &[transaction_public_key],
+                &[],
+                &[output.clone()],
+                &[encrypted_amount; 2],
+                &[]
+            )
+            .is_err());
+        assert!(scanner()
+            .scan(&[transaction_public_key], &[[0u8; 32]; 2], &[output], &[], &[])
+            .is_err());
+    }
+}
@@ -2,10 +2,13 @@
 #![allow(non_snake_case)]
 
 use crate::address::MoneroAddress;
+use crate::amount::MoneroAmount;
+use crate::extra::MoneroTransactionExtra;
 use crate::format::MoneroFormat;
 use crate::network::MoneroNetwork;
 use crate::private_key::MoneroPrivateKey;
 use crate::public_key::MoneroPublicKey;
+use crate::raw_transaction::MoneroRawTransaction;
 use wagyu_model::{Transaction, TransactionError, TransactionId};
 
 use core::{
@@ -43,6 +46,7 @@ impl fmt::Display for MoneroTransactionId {
 
 impl<N: MoneroNetwork> Transaction for MoneroTransaction<N> {
     type Address = MoneroAddress<N>;
+    type Amount = MoneroAmount;
     type Format = MoneroFormat;
     type PrivateKey = MoneroPrivateKey<N>;
     type PublicKey = MoneroPublicKey<N>;
@@ -57,16 +61,53 @@ impl<N: MoneroNetwork> Transaction for MoneroTransaction<N> {
         unimplemented!();
     }
 
-    fn from_transaction_bytes(_transaction: &Vec<u8>) -> Result<Self, TransactionError> {
-        unimplemented!();
+    /// Returns an error, as a Monero transaction is signed with ring signatures rather than
+    /// a detached signature of a single public key.
+    fn sign_with_signature(&self, _signature: &[u8], _public_key: &Self::PublicKey) -> Result<Self, TransactionError> {
+        Err(TransactionError::UnsupportedOperation(
+            "Monero transactions are not signed with a detached signature".into(),
+        ))
+    }
+
+    /// Returns the transaction of the given serialized signed transaction, with the transaction
+    /// public key of its extra field. The transaction private key is not part of the transaction.
+    fn from_transaction_bytes(transaction: &Vec<u8>) -> Result<Self, TransactionError> {
+        let raw_transaction = MoneroRawTransaction::new(transaction)?;
+        let tx_pub_key = match MoneroTransactionExtra::<N>::from_bytes(&raw_transaction.prefix.extra) {
+            Ok(extra) => hex::encode(extra.to_transaction_public_key()),
+            Err(_) => String::new(),
+        };
+        Ok(Self {
+            tx_must_be_reconstructed: false,
+            serialized_signed_tx: hex::encode(transaction),
+            tx_hash: MoneroTransactionId {
+                tx_hash: hex::encode(raw_transaction.get_transaction_hash()?),
+            },
+            tx_key: String::new(),
+            tx_pub_key,
+            _network: PhantomData,
+        })
     }
 
+    /// Returns the serialized signed transaction.
     fn to_transaction_bytes(&self) -> Result<Vec<u8>, TransactionError> {
-        unimplemented!();
+        self.serialize_transaction()
+    }
+
+    /// Returns an error, as the inputs of a Monero transaction are signed with ring signatures over
+    /// a message that commits to its RingCT signatures, rather than a sighash per input.
+    /// The hash of the transaction prefix is returned by `get_transaction_prefix_hash`.
+    fn to_sighash(&self, _index: usize) -> Result<Vec<u8>, TransactionError> {
+        Err(TransactionError::UnsupportedOperation(
+            "Monero transactions have no sighash per input".into(),
+        ))
     }
 
+    /// Returns the transaction hash of the serialized signed transaction.
     fn to_transaction_id(&self) -> Result<Self::TransactionId, TransactionError> {
-        unimplemented!();
+        Ok(MoneroTransactionId {
+            tx_hash: hex::encode(self.get_transaction_hash()?),
+        })
     }
 }
 
@@ -330,6 +371,28 @@ impl<N: MoneroNetwork> MoneroTransaction<N> {
     }
 }
 
+impl<N: MoneroNetwork> MoneroTransaction<N> {
+    /// Returns the serialized signed transaction.
+    pub fn serialize_transaction(&self) -> Result<Vec<u8>, TransactionError> {
+        Ok(self.to_raw_transaction()?.serialize_transaction())
+    }
+
+    /// Returns the hash of the transaction prefix, which is the message signed by the inputs.
+    pub fn get_transaction_prefix_hash(&self) -> Result<[u8; 32], TransactionError> {
+        Ok(self.to_raw_transaction()?.get_transaction_prefix_hash())
+    }
+
+    /// Returns the transaction hash, which is the transaction id.
+    pub fn get_transaction_hash(&self) -> Result<[u8; 32], TransactionError> {
+        self.to_raw_transaction()?.get_transaction_hash()
+    }
+
+    /// Returns the raw transaction of the serialized signed transaction.
+    fn to_raw_transaction(&self) -> Result<MoneroRawTransaction, TransactionError> {
+        MoneroRawTransaction::new(&hex::decode(&self.serialized_signed_tx)?)
+    }
+}
+
 /// Make an unsafe external call to a C function
 /// the C function should take a character array argument and return a character array
 #[cfg(not(target_os = "linux"))]
@@ -953,4 +1016,28 @@ mod tests {
             });
         }
     }
+
+    mod serialization {
+        use super::*;
+        use crate::Mainnet;
+
+        type N = Mainnet;
+
+        /// The coinbase transaction of the Monero mainnet genesis block
+        const GENESIS_TRANSACTION: &str = "013c01ff0001ffffffffffff03029b2e4c0281c0b02e7c53291a94d1d0cbff8883f8024f5142ee494ffbbd08807121017767aafcde9be00dcfd098715ebcf7f410daebc582fda69d24a28e9d0bc890d1";
+        const GENESIS_TRANSACTION_HASH: &str = "c88ce9783b4f11190d7b9c17a69c1c52200f9faaee8e98dd07e6811175177139";
+
+        #[test]
+        fn transaction_bytes_and_id() {
+            let bytes = hex::decode(GENESIS_TRANSACTION).unwrap();
+            let transaction = MoneroTransaction::<N>::from_transaction_bytes(&bytes).unwrap();
+            assert_eq!(bytes, transaction.to_transaction_bytes().unwrap());
+            assert_eq!(
+                GENESIS_TRANSACTION_HASH,
+                transaction.to_transaction_id().unwrap().to_string()
+            );
+            assert!(transaction.to_sighash(0).is_err());
+            assert!(MoneroTransaction::<N>::from_transaction_bytes(&bytes[..bytes.len() - 1].to_vec()).is_err());
+        }
+    }
 }
@@ -0,0 +1,251 @@
+//!
+//! URI
+//!
+//! This module contains the `monero:` payment request URIs of the Monero wallets
+//! (https://github.com/monero-project/monero/wiki/URI-Formatting):
+//!
+//! `monero:<address>[?tx_payment_id=<payment id>][&recipient_name=<name>][&tx_amount=<amount>][&tx_description=<description>]`
+//!
+//! The amount is a decimal value in monero, and the recipient name and description are percent-encoded.
+//! The address is validated against the network of the URI, and a payment ID is 16 or 64 hexadecimal
+//! characters, which may not be specified with an integrated address, as it carries its own payment ID.
+//!
+
+use crate::address::MoneroAddress;
+use crate::amount::MoneroAmount;
+use crate::format::MoneroFormat;
+use crate::network::MoneroNetwork;
+use wagyu_model::no_std::*;
+use wagyu_model::{AddressError, AmountError};
+
+use core::{fmt, str::FromStr};
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum UriError {
+    #[error("{0}: {1}")]
+    Crate(&'static str, String),
+
+    #[error("duplicate URI parameter: {0}")]
+    DuplicateParameter(String),
+
+    #[error("a payment ID cannot be specified with an integrated address")]
+    IntegratedPaymentId,
+
+    #[error("invalid payment ID: {0}")]
+    InvalidPaymentId(String),
+
+    #[error("invalid URI: {0}")]
+    InvalidUri(String),
+
+    #[error("invalid URI parameter: {0}")]
+    InvalidParameter(String),
+}
+
+impl From<AddressError> for UriError {
+    fn from(error: AddressError) -> Self {
+        UriError::Crate("address", format!("{}", error))
+    }
+}
+
+impl From<AmountError> for UriError {
+    fn from(error: AmountError) -> Self {
+        UriError::Crate("amount", format!("{}", error))
+    }
+}
+
+/// Represents a Monero payment request URI
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoneroUri<N: MoneroNetwork> {
+    /// The address of the recipient
+    pub address: MoneroAddress<N>,
+    /// The payment ID of the payment, in hexadecimal
+    pub payment_id: Option<String>,
+    /// The name of the recipient
+    pub recipient_name: Option<String>,
+    /// The amount requested
+    pub amount: Option<MoneroAmount>,
+    /// The description of the payment
+    pub description: Option<String>,
+    /// The other parameters of the URI, in order
+    pub parameters: Vec<(String, String)>,
+}
+
+impl<N: MoneroNetwork> MoneroUri<N> {
+    /// Returns a payment request URI for the specified address.
+    pub fn new(address: MoneroAddress<N>) -> Self {
+        Self {
+            address,
+            payment_id: None,
+            recipient_name: None,
+            amount: None,
+            description: None,
+            parameters: vec![],
+        }
+    }
+
+    /// Sets the payment ID of the payment request, or returns an error if it is not 16 or 64
+    /// hexadecimal characters, or if the address of the URI is an integrated address.
+    pub fn set_payment_id(&mut self, payment_id: &str) -> Result<(), UriError> {
+        if payment_id.len() != 16 && payment_id.len() != 64 || hex::decode(payment_id).is_err() {
+            return Err(UriError::InvalidPaymentId(payment_id.into()));
+        }
+        if let MoneroFormat::Integrated(_) = self.address.format()? {
+            return Err(UriError::IntegratedPaymentId);
+        }
+        self.payment_id = Some(payment_id.to_lowercase());
+        Ok(())
+    }
+}
+
+impl<N: MoneroNetwork> FromStr for MoneroUri<N> {
+    type Err = UriError;
+
+    fn from_str(uri: &str) -> Result<Self, Self::Err> {
+        let request = match uri.get(..7) {
+            Some(scheme) if scheme.eq_ignore_ascii_case("monero:") => &uri[7..],
+            _ => return Err(UriError::InvalidUri(uri.into())),
+        };
+        let (address, query) = match request.find('?') {
+            Some(index) => (&request[..index], Some(&request[index + 1..])),
+            None => (request, None),
+        };
+
+        let mut uri = Self::new(MoneroAddress::from_str(address)?);
+        for parameter in query
+            .unwrap_or_default()
+            .split('&')
+            .filter(|parameter| !parameter.is_empty())
+        {
+            let (key, value) = match parameter.find('=') {
+                Some(index) => (&parameter[..index], percent_decode(&parameter[index + 1..])?),
+                None => return Err(UriError::InvalidParameter(parameter.into())),
+            };
+            let duplicate = || UriError::DuplicateParameter(key.into());
+            match key {
+                "tx_payment_id" if uri.payment_id.is_some() => return Err(duplicate()),
+                "tx_payment_id" => uri.set_payment_id(&value)?,
+                "recipient_name" if uri.recipient_name.is_some() => return Err(duplicate()),
+                "recipient_name" => uri.recipient_name = Some(value),
+                "tx_amount" if uri.amount.is_some() => return Err(duplicate()),
+                "tx_amount" => uri.amount = Some(MoneroAmount::from_monero_str(&value)?),
+                "tx_description" if uri.description.is_some() => return Err(duplicate()),
+                "tx_description" => uri.description = Some(value),
+                key => uri.parameters.push((key.into(), value)),
+            }
+        }
+        Ok(uri)
+    }
+}
+
+impl<N: MoneroNetwork> fmt::Display for MoneroUri<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "monero:{}", self.address)?;
+
+        let mut parameters = vec![];
+        if let Some(payment_id) = &self.payment_id {
+            parameters.push(format!("tx_payment_id={}", payment_id));
+        }
+        if let Some(recipient_name) = &self.recipient_name {
+            parameters.push(format!("recipient_name={}", percent_encode(recipient_name)));
+        }
+        if let Some(amount) = self.amount {
+            parameters.push(format!("tx_amount={}", amount.to_monero_string()));
+        }
+        if let Some(description) = &self.description {
+            parameters.push(format!("tx_description={}", percent_encode(description)));
+        }
+        for (key, value) in &self.parameters {
+            parameters.push(format!("{}={}", key, percent_encode(value)));
+        }
+        match parameters.is_empty() {
+            true => Ok(()),
+            false => write!(f, "?{}", parameters.join("&")),
+        }
+    }
+}
+
+/// Returns the value with each character other than an unreserved character percent-encoded.
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Returns the value with its percent-encoded characters decoded.
+fn percent_decode(value: &str) -> Result<String, UriError> {
+    let invalid = || UriError::InvalidParameter(value.into());
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut input = value.bytes();
+    while let Some(byte) = input.next() {
+        match byte {
+            b'%' => {
+                let hex = [input.next().ok_or_else(invalid)?, input.next().ok_or_else(invalid)?];
+                bytes.extend(hex::decode(hex).map_err(|_| invalid())?);
+            }
+            byte => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    const ADDRESS: &str =
+        "44aygzVLNx72qpYQV74zxdZt9H3bQiFba57K9Gdj118CKg7XLvyMtyA21qnzvKcFxw7zSH6yE4SaZMiTzyLzSjNT1oW4seP";
+    const INTEGRATED_ADDRESS: &str =
+        "4EHehoJpzDd2qpYQV74zxdZt9H3bQiFba57K9Gdj118CKg7XLvyMtyA21qnzvKcFxw7zSH6yE4SaZMiTzyLzSjNT2FPj5adNgMwBBKmfsx";
+
+    #[test]
+    fn from_str() {
+        let uri = MoneroUri::<Mainnet>::from_str(&format!(
+            "monero:{}?tx_payment_id=0123456789abcdef&recipient_name=Monero%20Project&tx_amount=0.25&tx_description=Donation",
+            ADDRESS
+        ))
+        .unwrap();
+        assert_eq!(ADDRESS, uri.address.to_string());
+        assert_eq!(Some("0123456789abcdef".into()), uri.payment_id);
+        assert_eq!(Some("Monero Project".into()), uri.recipient_name);
+        assert_eq!(Some(MoneroAmount(250_000_000_000)), uri.amount);
+        assert_eq!(Some("Donation".into()), uri.description);
+
+        let uri = MoneroUri::<Mainnet>::from_str(&format!("monero:{}", INTEGRATED_ADDRESS)).unwrap();
+        assert_eq!(None, uri.payment_id);
+        assert_eq!(None, uri.amount);
+
+        assert!(MoneroUri::<Testnet>::from_str(&format!("monero:{}", ADDRESS)).is_err());
+        assert!(MoneroUri::<Stagenet>::from_str(&format!("monero:{}", ADDRESS)).is_err());
+        assert!(MoneroUri::<Mainnet>::from_str(&format!("bitcoin:{}", ADDRESS)).is_err());
+        assert!(MoneroUri::<Mainnet>::from_str(&format!("monero:{}?tx_amount=1e12", ADDRESS)).is_err());
+        assert!(MoneroUri::<Mainnet>::from_str(&format!("monero:{}?tx_amount=1&tx_amount=2", ADDRESS)).is_err());
+        assert!(MoneroUri::<Mainnet>::from_str(&format!("monero:{}?tx_payment_id=0123", ADDRESS)).is_err());
+        assert_eq!(
+            Err(UriError::IntegratedPaymentId),
+            MoneroUri::<Mainnet>::from_str(&format!("monero:{}?tx_payment_id=0123456789abcdef", INTEGRATED_ADDRESS))
+        );
+    }
+
+    #[test]
+    fn to_string() {
+        let mut uri = MoneroUri::new(MoneroAddress::<Mainnet>::from_str(ADDRESS).unwrap());
+        assert_eq!(format!("monero:{}", ADDRESS), uri.to_string());
+
+        uri.set_payment_id("0123456789ABCDEF").unwrap();
+        uri.recipient_name = Some("Monero Project".into());
+        uri.amount = Some(MoneroAmount(1_000_000_000_001));
+        uri.description = Some("Coffee & cake".into());
+        let expected = format!(
+            "monero:{}?tx_payment_id=0123456789abcdef&recipient_name=Monero%20Project\
+             &tx_amount=1.000000000001&tx_description=Coffee%20%26%20cake",
+            ADDRESS
+        );
+        assert_eq!(expected, uri.to_string());
+        assert_eq!(uri, MoneroUri::from_str(&expected).unwrap());
+    }
+}
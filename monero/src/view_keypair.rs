@@ -0,0 +1,248 @@
+use crate::address::MoneroAddress;
+use crate::format::MoneroFormat;
+use crate::network::MoneroNetwork;
+use crate::private_key::{to_subaddress_secret_key, MoneroPrivateKey};
+use crate::public_key::MoneroPublicKey;
+use wagyu_model::{AddressError, PublicKey, PublicKeyError};
+
+use core::marker::PhantomData;
+use curve25519_dalek::{
+    constants::ED25519_BASEPOINT_TABLE,
+    edwards::{CompressedEdwardsY, EdwardsPoint},
+    scalar::Scalar,
+};
+use zeroize::Zeroize;
+
+/// Represents a Monero view keypair, the private view key and public spend key of a view-only wallet.
+/// A view keypair derives the addresses of a wallet and detects their incoming transactions,
+/// without the private spend key to spend from them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoneroViewKeypair<N: MoneroNetwork> {
+    /// The private viewing key
+    view_key: [u8; 32],
+    /// The public spending key
+    spend_key: [u8; 32],
+    /// PhantomData
+    _network: PhantomData<N>,
+}
+
+impl<N: MoneroNetwork> MoneroViewKeypair<N> {
+    /// Returns the view keypair of the given private key.
+    pub fn from_private_key(private_key: &MoneroPrivateKey<N>) -> Self {
+        let private_spend = &Scalar::from_bits(private_key.to_private_spend_key());
+        let public_spend = private_spend * &ED25519_BASEPOINT_TABLE;
+
+        Self {
+            view_key: private_key.to_private_view_key(),
+            spend_key: *public_spend.compress().as_bytes(),
+            _network: PhantomData,
+        }
+    }
+
+    /// Returns a view keypair given a private view key and public spend key.
+    pub fn from(private_view_key: &str, public_spend_key: &str) -> Result<Self, PublicKeyError> {
+        let private_view_key = hex::decode(private_view_key)?;
+        if private_view_key.len() != 32 {
+            return Err(PublicKeyError::InvalidByteLength(private_view_key.len()));
+        }
+
+        let public_spend_key = hex::decode(public_spend_key)?;
+        if public_spend_key.len() != 32 {
+            return Err(PublicKeyError::InvalidByteLength(public_spend_key.len()));
+        }
+
+        let mut view_key = [0u8; 32];
+        view_key.copy_from_slice(private_view_key.as_slice());
+
+        let mut spend_key = [0u8; 32];
+        spend_key.copy_from_slice(public_spend_key.as_slice());
+
+        let view_keypair = Self {
+            view_key,
+            spend_key,
+            _network: PhantomData,
+        };
+        view_keypair.to_public_spend_point()?;
+        Ok(view_keypair)
+    }
+
+    /// Returns the public key of the given format. The public keys of a subaddress are derived
+    /// from the public spend key, the private view key, and its major and minor index.
+    pub fn to_public_key(&self, format: &MoneroFormat) -> Result<MoneroPublicKey<N>, PublicKeyError> {
+        let private_view = &Scalar::from_bits(self.view_key);
+        let public_spend = self.to_public_spend_point()?;
+
+        let (public_spend, public_view) = match format {
+            MoneroFormat::Subaddress(major, minor) if *major != 0 || *minor != 0 => {
+                let secret = &Scalar::from_bits(to_subaddress_secret_key(&self.view_key, *major, *minor));
+                let public_spend_subaddress = public_spend + secret * &ED25519_BASEPOINT_TABLE;
+                (public_spend_subaddress, private_view * public_spend_subaddress)
+            }
+            _ => (public_spend, private_view * &ED25519_BASEPOINT_TABLE),
+        };
+
+        MoneroPublicKey::<N>::from(
+            &hex::encode(public_spend.compress().as_bytes()),
+            &hex::encode(public_view.compress().as_bytes()),
+            format,
+        )
+    }
+
+    /// Returns the address of the given format.
+    pub fn to_address(&self, format: &MoneroFormat) -> Result<MoneroAddress<N>, AddressError> {
+        self.to_public_key(format)?.to_address(format)
+    }
+
+    /// Returns the private view key of the view keypair.
+    pub fn to_private_view_key(&self) -> [u8; 32] {
+        self.view_key
+    }
+
+    /// Returns the public spend key of the view keypair.
+    pub fn to_public_spend_key(&self) -> [u8; 32] {
+        self.spend_key
+    }
+
+    /// Returns the public spend key as a point of the curve.
+    fn to_public_spend_point(&self) -> Result<EdwardsPoint, PublicKeyError> {
+        match CompressedEdwardsY(self.spend_key).decompress() {
+            Some(point) => Ok(point),
+            None => Err(PublicKeyError::Crate(
+                "curve25519_dalek",
+                "invalid public spend key".into(),
+            )),
+        }
+    }
+}
+
+impl<N: MoneroNetwork> Zeroize for MoneroViewKeypair<N> {
+    /// Overwrites the private view key with zeros.
+    fn zeroize(&mut self) {
+        self.view_key.zeroize();
+    }
+}
+
+impl<N: MoneroNetwork> Drop for MoneroViewKeypair<N> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+    use wagyu_model::PrivateKey;
+
+    fn test_to_address<N: MoneroNetwork>(expected_address: &str, seed: &str, format: &MoneroFormat) {
+        let private_key = MoneroPrivateKey::<N>::from_seed(seed, &MoneroFormat::Standard).unwrap();
+        let view_keypair = MoneroViewKeypair::from_private_key(&private_key);
+        assert_eq!(expected_address, view_keypair.to_address(format).unwrap().to_string());
+        assert_eq!(
+            private_key.to_address(format).unwrap(),
+            view_keypair.to_address(format).unwrap()
+        );
+    }
+
+    fn test_from<N: MoneroNetwork>(
+        expected_private_view_key: &str,
+        expected_public_spend_key: &str,
+        expected_address: &str,
+    ) {
+        let view_keypair = MoneroViewKeypair::<N>::from(expected_private_view_key, expected_public_spend_key).unwrap();
+        let address = view_keypair.to_address(&MoneroFormat::Standard).unwrap();
+        assert_eq!(
+            expected_private_view_key,
+            hex::encode(view_keypair.to_private_view_key())
+        );
+        assert_eq!(
+            expected_public_spend_key,
+            hex::encode(view_keypair.to_public_spend_key())
+        );
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    mod subaddress_mainnet {
+        use super::*;
+
+        type N = Mainnet;
+
+        // (seed, major index, minor index, address)
+        const KEYPAIRS: [(&str, u32, u32, &str); 5] = [
+            (
+                "3eb8e283b45559d4d2fb6b3a4f52443b420e6da2b38832ea0eb642100c92d600",
+                0,
+                0,
+                "48fRSJiQSp3Da61k8NSR5J9ibWMBkrJHL3hGDxSaZJvsfK7jpigPWyyGy5jqs8MSgeCBQb1HR4NDS84goPRaLV2xTungkh5",
+            ),
+            (
+                "8d8c8eeca38ac3b46aa293fd519b3860e96b5f873c12a95e3e1cdeda0bac4903",
+                0,
+                1,
+                "8C5zHM5ud8nGC4hC2ULiBLSWx9infi8JUUmWEat4fcTf8J4H38iWYVdFmPCA9UmfLTZxD43RsyKnGEdZkoGij6csDeUnbEB",
+            ),
+            (
+                "71d7544ca57116d3cc460e65bffbb124ae876a3e2d75e65e6e205053b951520b",
+                18,
+                225,
+                "89sosC3dvS3bGbkFfapXx2U47upjsn9SPbC6w4voYzhgYdWV6kotq4Z76J8TUUCw2aiVZ7yxsBySi21sfH5Zyh8nJkYpwHf",
+            ),
+            (
+                "6e3c82cf5a2fe62b26eb6b09f1ffc8dc71a0e88f634aed371957428d546cd908",
+                5,
+                7,
+                "82hbfaudQXXezE7wRfRQJf1ncBMySUg2MQs1XpjyetpWYZj98mRo5sCg3i5y2XtJRV3nmjshgfaPX2V74B9cHFEZKYgezJB",
+            ),
+            (
+                "ed8b4cae6db5548a129280750583bda7774d4ed9d4d44ecd7bc6cd7b17ace908",
+                20000,
+                35000,
+                "88rDu7NuEyiY1GGgLnFbXbDDJ1X85oa38bMeYrzmMMRL2LGTQqr7DgjciAqsntKTzhMLiaLFbKkMHUdH1tN7FBcPVYWuNYw",
+            ),
+        ];
+
+        #[test]
+        fn to_address() {
+            KEYPAIRS.iter().for_each(|(seed, major, minor, address)| {
+                test_to_address::<N>(address, seed, &MoneroFormat::Subaddress(*major, *minor));
+            });
+        }
+    }
+
+    #[test]
+    fn to_address_integrated() {
+        let mut payment_id = [0u8; 8];
+        payment_id.copy_from_slice(&hex::decode("b5a615cb2a72673e").unwrap());
+        test_to_address::<Mainnet>(
+            "4JN6T7Xu45ZDa61k8NSR5J9ibWMBkrJHL3hGDxSaZJvsfK7jpigPWyyGy5jqs8MSgeCBQb1HR4NDS84goPRaLV2xgvnLzaB9RDG84J2X9m",
+            "3eb8e283b45559d4d2fb6b3a4f52443b420e6da2b38832ea0eb642100c92d600",
+            &MoneroFormat::Integrated(payment_id),
+        );
+    }
+
+    #[test]
+    fn from() {
+        test_from::<Mainnet>(
+            "5177c436f032666c572df97ab591cc6ac2da96ab6818a2f38d72b430aebbdc0a",
+            "b9c5610a07f4344b27625155614fb1341dd0392c68482f101b820bc1e2b908e5",
+            "48fRSJiQSp3Da61k8NSR5J9ibWMBkrJHL3hGDxSaZJvsfK7jpigPWyyGy5jqs8MSgeCBQb1HR4NDS84goPRaLV2xTungkh5",
+        );
+    }
+
+    #[test]
+    fn from_invalid() {
+        type N = Mainnet;
+
+        let private_view_key = "5177c436f032666c572df97ab591cc6ac2da96ab6818a2f38d72b430aebbdc0a";
+        let public_spend_key = "b9c5610a07f4344b27625155614fb1341dd0392c68482f101b820bc1e2b908e5";
+
+        // Invalid private view key length
+        assert!(MoneroViewKeypair::<N>::from(&private_view_key[..62], public_spend_key).is_err());
+
+        // Invalid public spend key length
+        assert!(MoneroViewKeypair::<N>::from(private_view_key, &public_spend_key[..62]).is_err());
+
+        // Invalid hex
+        assert!(MoneroViewKeypair::<N>::from("z", public_spend_key).is_err());
+    }
+}
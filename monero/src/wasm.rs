@@ -0,0 +1,143 @@
+//! WebAssembly bindings for generating Monero wallets and importing mnemonics.
+
+use crate::format::MoneroFormat;
+use crate::mnemonic::MoneroMnemonic;
+use crate::network::{Mainnet, MoneroNetwork, Stagenet, Testnet};
+use crate::wordlist::English;
+use wagyu_model::{Mnemonic, Network, OsWalletRng, PrivateKey};
+
+use core::fmt::Display;
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroize;
+
+/// Represents a Monero wallet exposed to JavaScript
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct MoneroWallet {
+    mnemonic: String,
+    private_spend_key: String,
+    private_view_key: String,
+    public_spend_key: String,
+    public_view_key: String,
+    address: String,
+}
+
+#[wasm_bindgen]
+impl MoneroWallet {
+    /// Returns a randomly-generated wallet with an English mnemonic for the given network.
+    pub fn new(network: &str) -> Result<MoneroWallet, JsValue> {
+        fn new<N: MoneroNetwork>() -> Result<MoneroWallet, JsValue> {
+            let mnemonic = MoneroMnemonic::<N, English>::new(&mut OsWalletRng::new()).map_err(to_error)?;
+            MoneroWallet::from_monero_mnemonic(&mnemonic)
+        }
+
+        match network {
+            Mainnet::NAME => new::<Mainnet>(),
+            Stagenet::NAME => new::<Stagenet>(),
+            Testnet::NAME => new::<Testnet>(),
+            _ => Err(to_error(format_args!("invalid network: {}", network))),
+        }
+    }
+
+    /// Returns the wallet of the given network and English mnemonic.
+    pub fn from_mnemonic(network: &str, mnemonic: &str) -> Result<MoneroWallet, JsValue> {
+        fn from_mnemonic<N: MoneroNetwork>(mnemonic: &str) -> Result<MoneroWallet, JsValue> {
+            let mnemonic = MoneroMnemonic::<N, English>::from_phrase(mnemonic).map_err(to_error)?;
+            MoneroWallet::from_monero_mnemonic(&mnemonic)
+        }
+
+        match network {
+            Mainnet::NAME => from_mnemonic::<Mainnet>(mnemonic),
+            Stagenet::NAME => from_mnemonic::<Stagenet>(mnemonic),
+            Testnet::NAME => from_mnemonic::<Testnet>(mnemonic),
+            _ => Err(to_error(format_args!("invalid network: {}", network))),
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn mnemonic(&self) -> String {
+        self.mnemonic.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn private_spend_key(&self) -> String {
+        self.private_spend_key.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn private_view_key(&self) -> String {
+        self.private_view_key.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn public_spend_key(&self) -> String {
+        self.public_spend_key.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn public_view_key(&self) -> String {
+        self.public_view_key.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn address(&self) -> String {
+        self.address.clone()
+    }
+}
+
+impl MoneroWallet {
+    fn from_monero_mnemonic<N: MoneroNetwork>(mnemonic: &MoneroMnemonic<N, English>) -> Result<Self, JsValue> {
+        let private_key = mnemonic.to_private_key(None).map_err(to_error)?;
+        let public_key = private_key.to_public_key();
+        let public_spend_key = public_key.to_public_spend_key().ok_or("invalid public spend key")?;
+        let public_view_key = public_key.to_public_view_key().ok_or("invalid public view key")?;
+        Ok(Self {
+            mnemonic: mnemonic.to_string(),
+            private_spend_key: hex::encode(private_key.to_private_spend_key()),
+            private_view_key: hex::encode(private_key.to_private_view_key()),
+            public_spend_key: hex::encode(public_spend_key),
+            public_view_key: hex::encode(public_view_key),
+            address: private_key
+                .to_address(&MoneroFormat::Standard)
+                .map_err(to_error)?
+                .to_string(),
+        })
+    }
+}
+
+impl Drop for MoneroWallet {
+    fn drop(&mut self) {
+        self.mnemonic.zeroize();
+        self.private_spend_key.zeroize();
+        self.private_view_key.zeroize();
+    }
+}
+
+/// Returns a JavaScript error with the message of the given error.
+fn to_error<E: Display>(error: E) -> JsValue {
+    JsValue::from_str(&error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_mnemonic() {
+        let mnemonic = "reruns today hookup itself thorn nirvana symptoms jukebox patio unquoted sushi long diode digit rewind hacksaw obvious soothe nightly return agile hobby algebra awesome nirvana";
+        let wallet = MoneroWallet::from_mnemonic("mainnet", mnemonic).unwrap();
+        assert_eq!(mnemonic, wallet.mnemonic());
+        assert_eq!(
+            "82a13b87b69555ba976601302e2498aed4875185c87b9133bf8d214f16e9eb0b",
+            wallet.private_spend_key()
+        );
+        assert_eq!(
+            "5ea51b4da3e87ded053383ca38945d38c3bb35d6b84bf7a1c45b2a4f713f8705",
+            wallet.private_view_key()
+        );
+        assert_eq!(
+            "4BGKFihji4RUj1cygoQjNkDZCRQJ7HvjT82C3bwYkY6zeEP71Ny62nBBy7jVrzojYYKDZfbu5JYoobH7NvdQRfG6MCvjJ59",
+            wallet.address()
+        );
+    }
+}
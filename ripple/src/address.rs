@@ -0,0 +1,388 @@
+use crate::alphabet::{FromRippleBase58, ToRippleBase58};
+use crate::format::RippleFormat;
+use crate::network::RippleNetwork;
+use crate::private_key::RipplePrivateKey;
+use crate::public_key::RipplePublicKey;
+use wagyu_model::{
+    crypto::{checksum, hash160},
+    no_std::*,
+    Address, AddressError, PrivateKey,
+};
+
+use base58::ToBase58;
+use core::{convert::TryFrom, fmt, marker::PhantomData, str::FromStr};
+
+/// The prefix byte of a classic address
+pub const CLASSIC_ADDRESS_PREFIX: u8 = 0x00;
+
+/// Represents a Ripple address
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RippleAddress<N: RippleNetwork> {
+    /// The Ripple address
+    address: String,
+    /// The 20-byte account ID, the hash160 of the public key
+    account_id: [u8; 20],
+    /// The format of the address
+    format: RippleFormat,
+    /// PhantomData
+    _network: PhantomData<N>,
+}
+
+impl<N: RippleNetwork> Address for RippleAddress<N> {
+    type Format = RippleFormat;
+    type PrivateKey = RipplePrivateKey<N>;
+    type PublicKey = RipplePublicKey<N>;
+
+    /// Returns the address corresponding to the given Ripple private key.
+    fn from_private_key(private_key: &Self::PrivateKey, format: &Self::Format) -> Result<Self, AddressError> {
+        Self::from_public_key(&private_key.to_public_key(), format)
+    }
+
+    /// Returns the address corresponding to the given Ripple public key.
+    fn from_public_key(public_key: &Self::PublicKey, format: &Self::Format) -> Result<Self, AddressError> {
+        Self::from_account_id(&hash160(&public_key.to_bytes()), format)
+    }
+}
+
+impl<N: RippleNetwork> RippleAddress<N> {
+    /// Returns the address of the given 20-byte account ID in the given format.
+    pub fn from_account_id(account_id: &[u8], format: &RippleFormat) -> Result<Self, AddressError> {
+        if account_id.len() != 20 {
+            return Err(AddressError::InvalidByteLength(account_id.len()));
+        }
+
+        let mut data = match format {
+            RippleFormat::Classic => vec![CLASSIC_ADDRESS_PREFIX],
+            RippleFormat::XAddress(_) => N::X_ADDRESS_PREFIX.to_vec(),
+        };
+        data.extend_from_slice(account_id);
+
+        // An X-address appends a flag, the destination tag in little-endian, and 4 reserved bytes.
+        if let RippleFormat::XAddress(tag) = format {
+            data.push(tag.is_some() as u8);
+            data.extend_from_slice(&tag.unwrap_or(0).to_le_bytes());
+            data.extend_from_slice(&[0u8; 4]);
+        }
+
+        let sum = checksum(&data);
+        data.extend_from_slice(&sum[0..4]);
+
+        let mut id = [0u8; 20];
+        id.copy_from_slice(account_id);
+        Ok(Self {
+            address: data.to_ripple_base58(),
+            account_id: id,
+            format: format.clone(),
+            _network: PhantomData,
+        })
+    }
+
+    /// Returns the 20-byte account ID of the address.
+    pub fn to_account_id(&self) -> [u8; 20] {
+        self.account_id
+    }
+
+    /// Returns the format of the address.
+    pub fn format(&self) -> RippleFormat {
+        self.format.clone()
+    }
+
+    /// Returns the destination tag of the address, if it is an X-address with a tag.
+    pub fn tag(&self) -> Option<u32> {
+        match self.format {
+            RippleFormat::XAddress(tag) => tag,
+            RippleFormat::Classic => None,
+        }
+    }
+
+    /// Returns the classic address of the same account.
+    pub fn to_classic_address(&self) -> Result<Self, AddressError> {
+        Self::from_account_id(&self.account_id, &RippleFormat::Classic)
+    }
+
+    /// Returns the X-address of the same account with the given destination tag.
+    pub fn to_x_address(&self, tag: Option<u32>) -> Result<Self, AddressError> {
+        Self::from_account_id(&self.account_id, &RippleFormat::XAddress(tag))
+    }
+}
+
+impl<'a, N: RippleNetwork> TryFrom<&'a str> for RippleAddress<N> {
+    type Error = AddressError;
+
+    fn try_from(address: &'a str) -> Result<Self, Self::Error> {
+        Self::from_str(address)
+    }
+}
+
+impl<N: RippleNetwork> FromStr for RippleAddress<N> {
+    type Err = AddressError;
+
+    /// Returns the address given its classic encoding `r...`, or its X-address encoding
+    /// on the network `N`, `X...` on mainnet and `T...` on testnet.
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        if address.len() < 25 || address.len() > 47 {
+            return Err(AddressError::InvalidCharacterLength(address.len()));
+        }
+
+        let data = address.from_ripple_base58()?;
+        if data.len() != 25 && data.len() != 35 {
+            return Err(AddressError::InvalidByteLength(data.len()));
+        }
+
+        let expected = &data[data.len() - 4..];
+        let checksum = &checksum(&data[..data.len() - 4])[0..4];
+        if *expected != *checksum {
+            let expected = expected.to_base58();
+            let found = checksum.to_base58();
+            return Err(AddressError::InvalidChecksum(expected, found));
+        }
+
+        if data.len() == 25 {
+            if data[0] != CLASSIC_ADDRESS_PREFIX {
+                return Err(AddressError::InvalidPrefix(data[0..1].to_vec()));
+            }
+            return Self::from_account_id(&data[1..21], &RippleFormat::Classic);
+        }
+
+        if data[0..2] != N::X_ADDRESS_PREFIX {
+            return Err(AddressError::InvalidPrefix(data[0..2].to_vec()));
+        }
+
+        let mut tag = [0u8; 4];
+        tag.copy_from_slice(&data[23..27]);
+        let tag = u32::from_le_bytes(tag);
+
+        // The flag must be 0 without a tag or 1 with a tag, and the reserved bytes must be zero.
+        let tag = match data[22] {
+            0 if tag == 0 => None,
+            1 => Some(tag),
+            _ => return Err(AddressError::InvalidAddress(address.into())),
+        };
+        if data[27..31] != [0u8; 4] {
+            return Err(AddressError::InvalidAddress(address.into()));
+        }
+
+        Self::from_account_id(&data[2..22], &RippleFormat::XAddress(tag))
+    }
+}
+
+impl<N: RippleNetwork> fmt::Display for RippleAddress<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    fn test_from_private_key<N: RippleNetwork>(
+        expected_address: &str,
+        private_key: &RipplePrivateKey<N>,
+        format: &RippleFormat,
+    ) {
+        let address = RippleAddress::from_private_key(private_key, format).unwrap();
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    fn test_from_public_key<N: RippleNetwork>(
+        expected_address: &str,
+        public_key: &RipplePublicKey<N>,
+        format: &RippleFormat,
+    ) {
+        let address = RippleAddress::from_public_key(public_key, format).unwrap();
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    fn test_from_str<N: RippleNetwork>(expected_address: &str, expected_format: &RippleFormat) {
+        let address = RippleAddress::<N>::from_str(expected_address).unwrap();
+        assert_eq!(expected_address, address.to_string());
+        assert_eq!(*expected_format, address.format());
+    }
+
+    fn test_to_x_address<N: RippleNetwork>(expected_x_address: &str, classic_address: &str, tag: Option<u32>) {
+        let address = RippleAddress::<N>::from_str(classic_address).unwrap();
+        let x_address = address.to_x_address(tag).unwrap();
+        assert_eq!(expected_x_address, x_address.to_string());
+        assert_eq!(tag, x_address.tag());
+        assert_eq!(classic_address, x_address.to_classic_address().unwrap().to_string());
+    }
+
+    const SEEDS: [(&str, &str); 2] = [
+        ("snoPBrXtMeMyMHUVTgbuqAfg1SUTb", "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"),
+        ("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r", "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD"),
+    ];
+
+    mod classic {
+        use super::*;
+
+        type N = Mainnet;
+
+        #[test]
+        fn from_private_key() {
+            SEEDS.iter().for_each(|(seed, address)| {
+                let private_key = RipplePrivateKey::<N>::from_str(seed).unwrap();
+                test_from_private_key(address, &private_key, &RippleFormat::Classic);
+            });
+        }
+
+        #[test]
+        fn from_public_key() {
+            SEEDS.iter().for_each(|(seed, address)| {
+                let public_key = RipplePrivateKey::<N>::from_str(seed).unwrap().to_public_key();
+                test_from_public_key(address, &public_key, &RippleFormat::Classic);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            SEEDS.iter().for_each(|(_, address)| {
+                test_from_str::<N>(address, &RippleFormat::Classic);
+            });
+            test_from_str::<N>("rrrrrrrrrrrrrrrrrrrrrhoLvTp", &RippleFormat::Classic);
+            test_from_str::<Testnet>("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf", &RippleFormat::Classic);
+        }
+    }
+
+    mod x_address_mainnet {
+        use super::*;
+
+        type N = Mainnet;
+
+        const X_ADDRESSES: [(&str, Option<u32>, &str); 10] = [
+            (
+                "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+                None,
+                "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb",
+            ),
+            (
+                "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+                Some(0),
+                "XVLhHMPHU98es4dbozjVtdWzVrDjtV8AqEL4xcZj5whKbmc",
+            ),
+            (
+                "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+                Some(1),
+                "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC",
+            ),
+            (
+                "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+                Some(32),
+                "XVLhHMPHU98es4dbozjVtdWzVrDjtVoYiC9UvKfjKar4LJe",
+            ),
+            (
+                "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+                Some(11747),
+                "XVLhHMPHU98es4dbozjVtdWzVrDjtV1N75zgFKga4R1B9Mk",
+            ),
+            (
+                "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+                None,
+                "XVPcpSm47b1CZkf5AkKM9a84dQHe3m4sBhsrA4XtnBECTAc",
+            ),
+            (
+                "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+                Some(1),
+                "XVPcpSm47b1CZkf5AkKM9a84dQHe3mStTFRCftmx38NkCG9",
+            ),
+            (
+                "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+                Some(4294967295),
+                "XVPcpSm47b1CZkf5AkKM9a84dQHe3mX6ZcxNZjq2wMvKo8a",
+            ),
+            (
+                "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD",
+                None,
+                "XVYaPuwjbmRPA9pdyiXAGXsw8NhgJqESZxvSGuTLKhngUD4",
+            ),
+            (
+                "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD",
+                Some(11747),
+                "XVYaPuwjbmRPA9pdyiXAGXsw8NhgJqQZQjsMFUydoMWFqfu",
+            ),
+        ];
+
+        #[test]
+        fn to_x_address() {
+            X_ADDRESSES.iter().for_each(|(classic_address, tag, x_address)| {
+                test_to_x_address::<N>(x_address, classic_address, *tag);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            X_ADDRESSES.iter().for_each(|(_, tag, x_address)| {
+                test_from_str::<N>(x_address, &RippleFormat::XAddress(*tag));
+            });
+        }
+
+        #[test]
+        fn from_private_key() {
+            let private_key = RipplePrivateKey::<N>::from_str(SEEDS[0].0).unwrap();
+            test_from_private_key(
+                "XVPcpSm47b1CZkf5AkKM9a84dQHe3mXwrF9A7ghBZ4aawzp",
+                &private_key,
+                &RippleFormat::XAddress(Some(11747)),
+            );
+        }
+    }
+
+    mod x_address_testnet {
+        use super::*;
+
+        type N = Testnet;
+
+        const X_ADDRESSES: [(&str, Option<u32>, &str); 4] = [
+            (
+                "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+                None,
+                "TVK3SYvMLZR6rEtLDZh3saYHaqFSeMpn3Dz7EsmQC63VUwh",
+            ),
+            (
+                "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+                Some(11747),
+                "TVK3SYvMLZR6rEtLDZh3saYHaqFSeMBBxzfP8MH5wrM8wor",
+            ),
+            (
+                "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD",
+                Some(1),
+                "TVTcFrK5T5yR4eoEECG5wWonECb7FQzKouGXmavvyHJCAKK",
+            ),
+            (
+                "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD",
+                Some(4294967295),
+                "TVTcFrK5T5yR4eoEECG5wWonECb7FRn3u6ofUAzYzo8nRJD",
+            ),
+        ];
+
+        #[test]
+        fn to_x_address() {
+            X_ADDRESSES.iter().for_each(|(classic_address, tag, x_address)| {
+                test_to_x_address::<N>(x_address, classic_address, *tag);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            X_ADDRESSES.iter().for_each(|(_, tag, x_address)| {
+                test_from_str::<N>(x_address, &RippleFormat::XAddress(*tag));
+            });
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        // Invalid checksum
+        assert!(RippleAddress::<Mainnet>::from_str("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTi").is_err());
+        assert!(RippleAddress::<Mainnet>::from_str("XVPcpSm47b1CZkf5AkKM9a84dQHe3m4sBhsrA4XtnBECTAd").is_err());
+
+        // Invalid network
+        assert!(RippleAddress::<Testnet>::from_str("XVPcpSm47b1CZkf5AkKM9a84dQHe3m4sBhsrA4XtnBECTAc").is_err());
+        assert!(RippleAddress::<Mainnet>::from_str("TVK3SYvMLZR6rEtLDZh3saYHaqFSeMpn3Dz7EsmQC63VUwh").is_err());
+
+        // Invalid character length
+        assert!(RippleAddress::<Mainnet>::from_str("").is_err());
+        assert!(RippleAddress::<Mainnet>::from_str("rHb9CJAWyB4rj91VRWn96D").is_err());
+    }
+}
@@ -0,0 +1,74 @@
+use wagyu_model::no_std::*;
+
+use base58::{FromBase58, FromBase58Error, ToBase58};
+
+/// The base58 alphabet used by the XRP Ledger, a permutation of the Bitcoin alphabet
+/// that begins with `r` so that account addresses start with it.
+pub const RIPPLE_ALPHABET: &[u8; 58] = b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
+
+/// The base58 alphabet used by Bitcoin.
+const BITCOIN_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Returns the given string with every character of the `from` alphabet replaced
+/// by the character at the same position of the `to` alphabet. Both alphabets share
+/// the same set of characters, so any other character is left as is and remains invalid.
+fn translate(input: &str, from: &[u8; 58], to: &[u8; 58]) -> String {
+    input
+        .bytes()
+        .map(|c| match from.iter().position(|&f| f == c) {
+            Some(index) => to[index] as char,
+            None => c as char,
+        })
+        .collect()
+}
+
+/// A trait for encoding bytes with the Ripple base58 alphabet.
+pub trait ToRippleBase58 {
+    /// Returns the Ripple base58 encoding of the bytes.
+    fn to_ripple_base58(&self) -> String;
+}
+
+impl ToRippleBase58 for [u8] {
+    fn to_ripple_base58(&self) -> String {
+        translate(&self.to_base58(), BITCOIN_ALPHABET, RIPPLE_ALPHABET)
+    }
+}
+
+/// A trait for decoding strings in the Ripple base58 alphabet.
+pub trait FromRippleBase58 {
+    /// Returns the bytes of the Ripple base58 encoded string.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_ripple_base58(&self) -> Result<Vec<u8>, FromBase58Error>;
+}
+
+impl FromRippleBase58 for str {
+    fn from_ripple_base58(&self) -> Result<Vec<u8>, FromBase58Error> {
+        translate(self, RIPPLE_ALPHABET, BITCOIN_ALPHABET).from_base58()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_ripple_base58() {
+        assert_eq!("r", [0u8].to_ripple_base58());
+        assert_eq!("rrr", [0u8; 3].to_ripple_base58());
+        assert_eq!("p", [1u8].to_ripple_base58());
+    }
+
+    #[test]
+    fn from_ripple_base58() {
+        assert_eq!(vec![0u8], "r".from_ripple_base58().unwrap());
+        assert_eq!(vec![1u8], "p".from_ripple_base58().unwrap());
+        assert!("0".from_ripple_base58().is_err());
+        assert!("l".from_ripple_base58().is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        let data = hex::decode("00b5f762798a53d543a014caf8b297cff8f2f937e8").unwrap();
+        assert_eq!(data, data.to_ripple_base58().from_ripple_base58().unwrap());
+    }
+}
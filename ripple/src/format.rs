@@ -0,0 +1,25 @@
+use wagyu_model::Format;
+
+use core::fmt;
+use serde::Serialize;
+
+/// Represents the format of a Ripple address
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RippleFormat {
+    /// Classic address, e.g. rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh
+    Classic,
+    /// X-address with an optional destination tag, e.g. XVPcpSm47b1CZkf5AkKM9a84dQHe3m4sBhsrA4XtnBECTAc
+    XAddress(Option<u32>),
+}
+
+impl Format for RippleFormat {}
+
+impl fmt::Display for RippleFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RippleFormat::Classic => write!(f, "classic"),
+            RippleFormat::XAddress(None) => write!(f, "x-address"),
+            RippleFormat::XAddress(Some(tag)) => write!(f, "x-address (tag {})", tag),
+        }
+    }
+}
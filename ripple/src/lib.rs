@@ -0,0 +1,25 @@
+//! # Ripple
+//!
+//! A library for generating Ripple wallets.
+//!
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(unused_extern_crates, dead_code)]
+#![forbid(unsafe_code)]
+
+pub mod address;
+pub use self::address::*;
+
+pub mod alphabet;
+pub use self::alphabet::*;
+
+pub mod format;
+pub use self::format::*;
+
+pub mod network;
+pub use self::network::*;
+
+pub mod private_key;
+pub use self::private_key::*;
+
+pub mod public_key;
+pub use self::public_key::*;
@@ -0,0 +1,34 @@
+use crate::network::RippleNetwork;
+use wagyu_model::{Network, NetworkError};
+
+use core::{fmt, str::FromStr};
+use serde::Serialize;
+
+/// Represents a Ripple main network.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct Mainnet;
+
+impl Network for Mainnet {
+    const NAME: &'static str = "mainnet";
+}
+
+impl RippleNetwork for Mainnet {
+    const X_ADDRESS_PREFIX: [u8; 2] = [0x05, 0x44];
+}
+
+impl FromStr for Mainnet {
+    type Err = NetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            Self::NAME => Ok(Self),
+            _ => Err(NetworkError::InvalidNetwork(s.into())),
+        }
+    }
+}
+
+impl fmt::Display for Mainnet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::NAME)
+    }
+}
@@ -0,0 +1,18 @@
+use wagyu_model::Network;
+
+pub mod any_network;
+pub use self::any_network::*;
+
+pub mod mainnet;
+pub use self::mainnet::*;
+
+pub mod testnet;
+pub use self::testnet::*;
+
+/// The interface for a Ripple network.
+///
+/// Classic addresses are the same on every network, while X-addresses
+/// encode the network in their two prefix bytes.
+pub trait RippleNetwork: Network {
+    const X_ADDRESS_PREFIX: [u8; 2];
+}
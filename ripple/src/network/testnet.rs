@@ -0,0 +1,34 @@
+use crate::network::RippleNetwork;
+use wagyu_model::{Network, NetworkError};
+
+use core::{fmt, str::FromStr};
+use serde::Serialize;
+
+/// Represents a Ripple test network.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct Testnet;
+
+impl Network for Testnet {
+    const NAME: &'static str = "testnet";
+}
+
+impl RippleNetwork for Testnet {
+    const X_ADDRESS_PREFIX: [u8; 2] = [0x04, 0x93];
+}
+
+impl FromStr for Testnet {
+    type Err = NetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            Self::NAME => Ok(Self),
+            _ => Err(NetworkError::InvalidNetwork(s.into())),
+        }
+    }
+}
+
+impl fmt::Display for Testnet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Self::NAME)
+    }
+}
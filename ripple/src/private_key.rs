@@ -0,0 +1,542 @@
+use crate::address::RippleAddress;
+use crate::alphabet::{FromRippleBase58, ToRippleBase58};
+use crate::format::RippleFormat;
+use crate::network::RippleNetwork;
+use crate::public_key::RipplePublicKey;
+use wagyu_model::{crypto::checksum, no_std::*, Address, AddressError, PrivateKey, PrivateKeyError, PublicKey};
+
+use base58::ToBase58;
+use core::{fmt, fmt::Display, marker::PhantomData, str::FromStr};
+use curve25519_dalek::scalar::Scalar;
+use rand::Rng;
+use serde::Serialize;
+use sha2::{Digest, Sha512};
+use zeroize::Zeroize;
+
+/// The prefix byte of a secp256k1 family seed, encoded as `s...`
+pub const FAMILY_SEED_PREFIX: u8 = 0x21;
+
+/// The prefix bytes of an ed25519 seed, encoded as `sEd...`
+pub const ED25519_SEED_PREFIX: [u8; 3] = [0x01, 0xE1, 0x4B];
+
+/// The prefix byte of a hexadecimal ed25519 key
+pub const ED25519_KEY_PREFIX: u8 = 0xED;
+
+/// Returns the first half of the SHA-512 hash of the given data.
+pub(crate) fn sha512_half(data: &[u8]) -> [u8; 32] {
+    let mut half = [0u8; 32];
+    half.copy_from_slice(&Sha512::digest(data)[0..32]);
+    half
+}
+
+/// Represents the signature algorithm of a Ripple key pair
+#[derive(Serialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RippleKeyType {
+    /// ECDSA over secp256k1, the default key type of the XRP Ledger
+    Secp256k1,
+    /// EdDSA over ed25519
+    Ed25519,
+}
+
+impl FromStr for RippleKeyType {
+    type Err = PrivateKeyError;
+
+    fn from_str(key_type: &str) -> Result<Self, Self::Err> {
+        match key_type {
+            "secp256k1" => Ok(RippleKeyType::Secp256k1),
+            "ed25519" => Ok(RippleKeyType::Ed25519),
+            _ => Err(PrivateKeyError::Message(format!("invalid key type {}", key_type))),
+        }
+    }
+}
+
+impl Display for RippleKeyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RippleKeyType::Secp256k1 => write!(f, "secp256k1"),
+            RippleKeyType::Ed25519 => write!(f, "ed25519"),
+        }
+    }
+}
+
+/// Represents the secret key of a Ripple private key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RippleSecretKey {
+    /// The secp256k1 secret key
+    Secp256k1(secp256k1::SecretKey),
+    /// The 32-byte ed25519 secret seed
+    Ed25519([u8; 32]),
+}
+
+impl RippleSecretKey {
+    /// Returns the key type of the secret key.
+    pub fn key_type(&self) -> RippleKeyType {
+        match self {
+            RippleSecretKey::Secp256k1(_) => RippleKeyType::Secp256k1,
+            RippleSecretKey::Ed25519(_) => RippleKeyType::Ed25519,
+        }
+    }
+
+    /// Returns the 32-byte encoding of the secret key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        match self {
+            RippleSecretKey::Secp256k1(secret_key) => secret_key.serialize(),
+            RippleSecretKey::Ed25519(secret_key) => *secret_key,
+        }
+    }
+}
+
+impl Zeroize for RippleSecretKey {
+    /// Replaces the secret key with a placeholder, clearing the original secret key on drop.
+    fn zeroize(&mut self) {
+        match self {
+            RippleSecretKey::Secp256k1(secret_key) => *secret_key = secp256k1::SecretKey::default(),
+            RippleSecretKey::Ed25519(secret_key) => secret_key.zeroize(),
+        }
+    }
+}
+
+/// Represents a Ripple private key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RipplePrivateKey<N: RippleNetwork> {
+    /// The secret key
+    secret_key: RippleSecretKey,
+    /// The 16-byte seed the secret key was derived from, if known
+    seed: Option<[u8; 16]>,
+    /// PhantomData
+    _network: PhantomData<N>,
+}
+
+impl<N: RippleNetwork> PrivateKey for RipplePrivateKey<N> {
+    type Address = RippleAddress<N>;
+    type Format = RippleFormat;
+    type PublicKey = RipplePublicKey<N>;
+
+    /// Returns a randomly-generated secp256k1 Ripple private key.
+    fn new<R: Rng>(rng: &mut R) -> Result<Self, PrivateKeyError> {
+        Self::new_with_key_type(rng, RippleKeyType::Secp256k1)
+    }
+
+    /// Returns the public key of the corresponding Ripple private key.
+    fn to_public_key(&self) -> Self::PublicKey {
+        RipplePublicKey::from_private_key(self)
+    }
+
+    /// Returns the address of the corresponding Ripple private key.
+    fn to_address(&self, format: &Self::Format) -> Result<Self::Address, AddressError> {
+        RippleAddress::from_private_key(self, format)
+    }
+}
+
+impl<N: RippleNetwork> RipplePrivateKey<N> {
+    /// Returns a private key of the given key type derived from a random 16-byte seed.
+    pub fn new_with_key_type<R: Rng>(rng: &mut R, key_type: RippleKeyType) -> Result<Self, PrivateKeyError> {
+        let mut seed: [u8; 16] = rng.gen();
+        let private_key = Self::from_seed_bytes(&seed, key_type);
+        seed.zeroize();
+        private_key
+    }
+
+    /// Returns a private key given a secret key.
+    pub fn from_secret_key(secret_key: RippleSecretKey) -> Self {
+        Self {
+            secret_key,
+            seed: None,
+            _network: PhantomData,
+        }
+    }
+
+    /// Returns the secret key of the private key.
+    pub fn to_secret_key(&self) -> RippleSecretKey {
+        self.secret_key.clone()
+    }
+
+    /// Returns the key type of the private key.
+    pub fn key_type(&self) -> RippleKeyType {
+        self.secret_key.key_type()
+    }
+
+    /// Returns the private key derived from the given 16-byte seed.
+    ///
+    /// An ed25519 secret key is the SHA-512 half of the seed. A secp256k1 secret key is
+    /// the sum of the root key, the first valid SHA-512 half of the seed and a sequence number,
+    /// and the intermediate key of account family 0 derived from the root public key.
+    pub fn from_seed_bytes(seed: &[u8], key_type: RippleKeyType) -> Result<Self, PrivateKeyError> {
+        if seed.len() != 16 {
+            return Err(PrivateKeyError::InvalidByteLength(seed.len()));
+        }
+
+        let secret_key = match key_type {
+            RippleKeyType::Secp256k1 => {
+                let mut secret_key = Self::first_valid_secp256k1_key(seed)?;
+                let root_public_key = secp256k1::PublicKey::from_secret_key(&secret_key).serialize_compressed();
+
+                let mut family = [0u8; 37];
+                family[0..33].copy_from_slice(&root_public_key);
+                let intermediate = Self::first_valid_secp256k1_key(&family)?;
+                secret_key.tweak_add_assign(&intermediate)?;
+
+                RippleSecretKey::Secp256k1(secret_key)
+            }
+            RippleKeyType::Ed25519 => RippleSecretKey::Ed25519(sha512_half(seed)),
+        };
+
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(seed);
+        Ok(Self {
+            secret_key,
+            seed: Some(bytes),
+            _network: PhantomData,
+        })
+    }
+
+    /// Returns the private key given its seed, a family seed `s...` or an ed25519 seed `sEd...`.
+    pub fn from_seed(seed: &str) -> Result<Self, PrivateKeyError> {
+        let data = seed.from_ripple_base58()?;
+        let (key_type, prefix_length) = match data.len() {
+            21 => (RippleKeyType::Secp256k1, 1),
+            23 => (RippleKeyType::Ed25519, 3),
+            _ => return Err(PrivateKeyError::InvalidByteLength(data.len())),
+        };
+
+        let expected = &data[data.len() - 4..];
+        let checksum = &checksum(&data[..data.len() - 4])[0..4];
+        if *expected != *checksum {
+            let expected = expected.to_base58();
+            let found = checksum.to_base58();
+            return Err(PrivateKeyError::InvalidChecksum(expected, found));
+        }
+
+        let prefix = &data[0..prefix_length];
+        match key_type {
+            RippleKeyType::Secp256k1 if prefix[0] == FAMILY_SEED_PREFIX => (),
+            RippleKeyType::Ed25519 if prefix == ED25519_SEED_PREFIX => (),
+            _ => return Err(PrivateKeyError::InvalidPrefix(prefix.to_vec())),
+        };
+
+        Self::from_seed_bytes(&data[prefix_length..prefix_length + 16], key_type)
+    }
+
+    /// Returns the seed of the private key, if it was derived from one.
+    pub fn to_seed(&self) -> Option<String> {
+        let seed = self.seed?;
+        let mut data = match self.key_type() {
+            RippleKeyType::Secp256k1 => vec![FAMILY_SEED_PREFIX],
+            RippleKeyType::Ed25519 => ED25519_SEED_PREFIX.to_vec(),
+        };
+        data.extend_from_slice(&seed);
+
+        let sum = checksum(&data);
+        data.extend_from_slice(&sum[0..4]);
+        Some(data.to_ripple_base58())
+    }
+
+    /// Returns the ed25519 signing scalar, the clamped lower half of the SHA-512 hash of the secret seed.
+    pub fn to_ed25519_scalar(&self) -> Option<Scalar> {
+        match &self.secret_key {
+            RippleSecretKey::Ed25519(secret_key) => {
+                let mut hash = Sha512::digest(secret_key);
+                let mut bits = [0u8; 32];
+                bits.copy_from_slice(&hash[0..32]);
+                bits[0] &= 248;
+                bits[31] &= 127;
+                bits[31] |= 64;
+
+                let scalar = Scalar::from_bits(bits);
+                bits.zeroize();
+                hash.as_mut_slice().zeroize();
+                Some(scalar)
+            }
+            RippleSecretKey::Secp256k1(_) => None,
+        }
+    }
+
+    /// Returns the first secp256k1 secret key in the SHA-512 halves of the given data
+    /// followed by an increasing 4-byte big-endian sequence number.
+    fn first_valid_secp256k1_key(data: &[u8]) -> Result<secp256k1::SecretKey, PrivateKeyError> {
+        let mut input = data.to_vec();
+        input.extend_from_slice(&[0u8; 4]);
+        let length = input.len();
+
+        for sequence in 0..=u32::MAX {
+            input[length - 4..].copy_from_slice(&sequence.to_be_bytes());
+            let mut hash = sha512_half(&input);
+            let secret_key = secp256k1::SecretKey::parse(&hash);
+            hash.zeroize();
+            if let Ok(secret_key) = secret_key {
+                input.zeroize();
+                return Ok(secret_key);
+            }
+        }
+
+        input.zeroize();
+        Err(PrivateKeyError::Message(
+            "no valid secp256k1 key in the seed sequence".into(),
+        ))
+    }
+}
+
+impl<N: RippleNetwork> FromStr for RipplePrivateKey<N> {
+    type Err = PrivateKeyError;
+
+    /// Returns the private key given its seed, its 66-character hexadecimal encoding
+    /// prefixed by `00` or `ED`, or a 64-character secp256k1 hexadecimal secret key.
+    fn from_str(private_key: &str) -> Result<Self, Self::Err> {
+        if private_key.starts_with('s') {
+            return Self::from_seed(private_key);
+        }
+
+        let secret_key = match private_key.len() {
+            64 => secp256k1::SecretKey::parse_slice(&hex::decode(private_key)?).map(RippleSecretKey::Secp256k1)?,
+            66 => {
+                let data = hex::decode(private_key)?;
+                match data[0] {
+                    0x00 => RippleSecretKey::Secp256k1(secp256k1::SecretKey::parse_slice(&data[1..])?),
+                    ED25519_KEY_PREFIX => {
+                        let mut secret_key = [0u8; 32];
+                        secret_key.copy_from_slice(&data[1..]);
+                        RippleSecretKey::Ed25519(secret_key)
+                    }
+                    prefix => return Err(PrivateKeyError::InvalidPrefix(vec![prefix])),
+                }
+            }
+            length => return Err(PrivateKeyError::InvalidCharacterLength(length)),
+        };
+
+        Ok(Self::from_secret_key(secret_key))
+    }
+}
+
+impl<N: RippleNetwork> Display for RipplePrivateKey<N> {
+    /// Writes the secret key in hexadecimal, prefixed by `00` for secp256k1 and `ed` for ed25519.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let prefix = match self.key_type() {
+            RippleKeyType::Secp256k1 => 0x00,
+            RippleKeyType::Ed25519 => ED25519_KEY_PREFIX,
+        };
+        write!(f, "{:02x}{}", prefix, hex::encode(self.secret_key.to_bytes()))
+    }
+}
+
+impl<N: RippleNetwork> Zeroize for RipplePrivateKey<N> {
+    /// Replaces the secret key and seed with placeholders, clearing the originals on drop.
+    fn zeroize(&mut self) {
+        self.secret_key.zeroize();
+        self.seed.zeroize();
+    }
+}
+
+impl<N: RippleNetwork> Drop for RipplePrivateKey<N> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    fn test_from_seed<N: RippleNetwork>(
+        expected_private_key: &str,
+        expected_public_key: &str,
+        expected_address: &str,
+        seed: &str,
+    ) {
+        let private_key = RipplePrivateKey::<N>::from_seed(seed).unwrap();
+        assert_eq!(expected_private_key, private_key.to_string());
+        assert_eq!(expected_public_key, private_key.to_public_key().to_string());
+        assert_eq!(
+            expected_address,
+            private_key.to_address(&RippleFormat::Classic).unwrap().to_string()
+        );
+        assert_eq!(seed, private_key.to_seed().unwrap());
+    }
+
+    fn test_from_seed_bytes<N: RippleNetwork>(expected_seed: &str, entropy: &str, key_type: RippleKeyType) {
+        let private_key = RipplePrivateKey::<N>::from_seed_bytes(&hex::decode(entropy).unwrap(), key_type).unwrap();
+        assert_eq!(expected_seed, private_key.to_seed().unwrap());
+        assert_eq!(key_type, private_key.key_type());
+    }
+
+    fn test_from_str<N: RippleNetwork>(expected_public_key: &str, expected_address: &str, private_key: &str) {
+        let private_key = RipplePrivateKey::<N>::from_str(private_key).unwrap();
+        assert_eq!(expected_public_key, private_key.to_public_key().to_string());
+        assert_eq!(
+            expected_address,
+            private_key.to_address(&RippleFormat::Classic).unwrap().to_string()
+        );
+        assert_eq!(None, private_key.to_seed());
+    }
+
+    fn test_to_str<N: RippleNetwork>(expected_private_key: &str, private_key: &RipplePrivateKey<N>) {
+        assert_eq!(expected_private_key, private_key.to_string());
+    }
+
+    mod secp256k1_mainnet {
+        use super::*;
+
+        type N = Mainnet;
+
+        const KEYPAIRS: [(&str, &str, &str, &str, &str); 5] = [
+            (
+                "snoPBrXtMeMyMHUVTgbuqAfg1SUTb",
+                "dedce9ce67b451d852fd4e846fcde31c",
+                "001acaaedece405b2a958212629e16f2eb46b153eee94cdd350fdeff52795525b7",
+                "0330e7fc9d56bb25d6893ba3f317ae5bcf33b3291bd63db32654a313222f7fd020",
+                "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+            ),
+            (
+                "sn259rEFXrQrWyx3Q7XneWcwV6dfL",
+                "cf2de378fbdd7e2ee87d486dfb5a7bff",
+                "0048d93a3b5948e5f9b323bf654bfad6e8ff75b5fcab03c5a55ad30cb2515b461f",
+                "0203f2d90bc50012ec7cb20b07a1b818d6863636fb1e945d17449092cfb5495e1e",
+                "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN",
+            ),
+            (
+                "sp5fghtJtpUorTwvof1NpDXAzNwf5",
+                "0102030405060708090a0b0c0d0e0f10",
+                "00d78b9735c3f26501c7337b8a5727fd53a6efdbc6aa55984f098488561f985e23",
+                "030d58eb48b4420b1f7b9df55087e0e29fef0e8468f9a6825b01ca2c361042d435",
+                "rU6K7V3Po4snVhBBaU29sesqs2qTQJWDw1",
+            ),
+            (
+                "shuvNCTwuaG9pTbPV88YvbMEZfuDp",
+                "9f2c1b7e4d5a6c3b8e0f1a2d3c4b5a69",
+                "0018acfa4eb149ca5b7b30d37890efd7104b36d215412d4c36c7f9ee5fe2b001cd",
+                "02a8bc379747e97a21068a07fe3a91807308c21d3e9f2e400c6de7f43ecc2aedf6",
+                "rQBxdxsEg28mCRk6rikwCdJG13XvyK6mnk",
+            ),
+            (
+                "shr6DJxi5UiFQBgYKjPAHyFhR6q7P",
+                "5e8a7c6b4f3d2e1a0b9c8d7e6f5a4b3c",
+                "0092fb69f3cf1b8e4c2f818b7d64b24b52f730e4b04ddcdaba5e3d1455b54252e1",
+                "0345372449b4cb7b472b3aab70f645fdae6a221cf2f062b7eb3c87501ab1ddfd93",
+                "rMB71cwLhKeX9YrVWT783wjU8qtZ2waRAh",
+            ),
+        ];
+
+        #[test]
+        fn from_seed() {
+            KEYPAIRS.iter().for_each(|(seed, _, private_key, public_key, address)| {
+                test_from_seed::<N>(private_key, public_key, address, seed);
+            });
+        }
+
+        #[test]
+        fn from_seed_bytes() {
+            KEYPAIRS.iter().for_each(|(seed, entropy, _, _, _)| {
+                test_from_seed_bytes::<N>(seed, entropy, RippleKeyType::Secp256k1);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, _, private_key, public_key, address)| {
+                test_from_str::<N>(public_key, address, private_key);
+                test_from_str::<N>(public_key, address, &private_key[2..]);
+            });
+        }
+
+        #[test]
+        fn to_str() {
+            KEYPAIRS.iter().for_each(|(_, _, expected_private_key, _, _)| {
+                let private_key = RipplePrivateKey::<N>::from_str(expected_private_key).unwrap();
+                test_to_str(expected_private_key, &private_key);
+            });
+        }
+    }
+
+    mod ed25519_mainnet {
+        use super::*;
+
+        type N = Mainnet;
+
+        const KEYPAIRS: [(&str, &str, &str, &str, &str); 3] = [
+            (
+                "sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r",
+                "0102030405060708090a0b0c0d0e0f10",
+                "edb4c4e046826bd26190d09715fc31f4e6a728204eadd112905b08b14b7f15c4f3",
+                "ed01fa53fa5a7e77798f882ece20b1abc00bb358a9e55a202d0d0676bd0ce37a63",
+                "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD",
+            ),
+            (
+                "sEdTMtagF8sbUZMDX7FZDAFC2raTS7b",
+                "4c3b2a1908f7e6d5c4b3a29180f7e6d5",
+                "eda6ab3d60b6e948330586eb209ab200b5e09635933dedb658448dffd38f946fff",
+                "ed1beeed2c537ede6030b2dfd680ed1604b9bcd60934759603979f9b216652d312",
+                "rsDjRxNrvxf9Td7sg8duGKL9YFGQc6FkFs",
+            ),
+            (
+                "sEd7YAcijguHjmvsr9iaATJyzqoPRgr",
+                "a1b2c3d4e5f60718293a4b5c6d7e8f90",
+                "ed4af63e2420f5ccdbdfd783b2e20397c3f61aa5323c841d457ce19931aeaf86a3",
+                "edac1ae464f2fa3e025d44095bda4feeb71bfea22700aa821d2d3c6d51f198919b",
+                "rwPhFzSsKqGgP9q7339VTKkLzfiQH7yXjC",
+            ),
+        ];
+
+        #[test]
+        fn from_seed() {
+            KEYPAIRS.iter().for_each(|(seed, _, private_key, public_key, address)| {
+                test_from_seed::<N>(private_key, public_key, address, seed);
+            });
+        }
+
+        #[test]
+        fn from_seed_bytes() {
+            KEYPAIRS.iter().for_each(|(seed, entropy, _, _, _)| {
+                test_from_seed_bytes::<N>(seed, entropy, RippleKeyType::Ed25519);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, _, private_key, public_key, address)| {
+                test_from_str::<N>(public_key, address, private_key);
+            });
+        }
+
+        #[test]
+        fn to_str() {
+            KEYPAIRS.iter().for_each(|(_, _, expected_private_key, _, _)| {
+                let private_key = RipplePrivateKey::<N>::from_str(expected_private_key).unwrap();
+                test_to_str(expected_private_key, &private_key);
+            });
+        }
+    }
+
+    #[test]
+    fn test_new_with_key_type() {
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        for key_type in [RippleKeyType::Secp256k1, RippleKeyType::Ed25519].iter() {
+            let private_key = RipplePrivateKey::<Testnet>::new_with_key_type(rng, *key_type).unwrap();
+            let seed = private_key.to_seed().unwrap();
+            assert_eq!(private_key, RipplePrivateKey::<Testnet>::from_seed(&seed).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        type N = Mainnet;
+
+        // Invalid seed checksum
+        assert!(RipplePrivateKey::<N>::from_str("snoPBrXtMeMyMHUVTgbuqAfg1SUTc").is_err());
+        assert!(RipplePrivateKey::<N>::from_str("sEdSKaCy2JT7JaM7v95H9SxkhP9wS2s").is_err());
+
+        // Invalid hexadecimal prefix
+        assert!(
+            RipplePrivateKey::<N>::from_str("011acaaedece405b2a958212629e16f2eb46b153eee94cdd350fdeff52795525b7")
+                .is_err()
+        );
+
+        // Invalid character length
+        assert!(RipplePrivateKey::<N>::from_str("").is_err());
+        assert!(
+            RipplePrivateKey::<N>::from_str("1acaaedece405b2a958212629e16f2eb46b153eee94cdd350fdeff52795525b").is_err()
+        );
+    }
+}
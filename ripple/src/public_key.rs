@@ -0,0 +1,195 @@
+use crate::address::RippleAddress;
+use crate::format::RippleFormat;
+use crate::network::RippleNetwork;
+use crate::private_key::{RippleKeyType, RipplePrivateKey, RippleSecretKey, ED25519_KEY_PREFIX};
+use wagyu_model::{Address, AddressError, PublicKey, PublicKeyError};
+
+use core::{fmt, fmt::Display, marker::PhantomData, str::FromStr};
+use curve25519_dalek::{constants::ED25519_BASEPOINT_TABLE, edwards::CompressedEdwardsY};
+
+/// Represents a Ripple public key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RipplePublicKey<N: RippleNetwork> {
+    /// The 33-byte public key, a compressed secp256k1 point or `ED` followed by an ed25519 point
+    public_key: [u8; 33],
+    /// PhantomData
+    _network: PhantomData<N>,
+}
+
+impl<N: RippleNetwork> PublicKey for RipplePublicKey<N> {
+    type Address = RippleAddress<N>;
+    type Format = RippleFormat;
+    type PrivateKey = RipplePrivateKey<N>;
+
+    /// Returns the public key corresponding to the given private key.
+    fn from_private_key(private_key: &Self::PrivateKey) -> Self {
+        let mut public_key = [0u8; 33];
+        match private_key.to_secret_key() {
+            RippleSecretKey::Secp256k1(secret_key) => {
+                public_key.copy_from_slice(&secp256k1::PublicKey::from_secret_key(&secret_key).serialize_compressed())
+            }
+            RippleSecretKey::Ed25519(_) => {
+                if let Some(scalar) = private_key.to_ed25519_scalar() {
+                    public_key[0] = ED25519_KEY_PREFIX;
+                    public_key[1..].copy_from_slice((&scalar * &ED25519_BASEPOINT_TABLE).compress().as_bytes());
+                }
+            }
+        };
+
+        Self {
+            public_key,
+            _network: PhantomData,
+        }
+    }
+
+    /// Returns the address of the corresponding private key.
+    fn to_address(&self, format: &Self::Format) -> Result<Self::Address, AddressError> {
+        RippleAddress::from_public_key(self, format)
+    }
+}
+
+impl<N: RippleNetwork> RipplePublicKey<N> {
+    /// Returns a public key given its 33-byte encoding.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PublicKeyError> {
+        if bytes.len() != 33 {
+            return Err(PublicKeyError::InvalidByteLength(bytes.len()));
+        }
+
+        match bytes[0] {
+            0x02 | 0x03 => {
+                secp256k1::PublicKey::parse_slice(bytes, None)?;
+            }
+            ED25519_KEY_PREFIX => {
+                if CompressedEdwardsY::from_slice(&bytes[1..]).decompress().is_none() {
+                    return Err(PublicKeyError::Crate(
+                        "curve25519-dalek",
+                        "invalid ed25519 point".into(),
+                    ));
+                }
+            }
+            prefix => return Err(PublicKeyError::InvalidPrefix(format!("{:02x}", prefix))),
+        };
+
+        let mut public_key = [0u8; 33];
+        public_key.copy_from_slice(bytes);
+        Ok(Self {
+            public_key,
+            _network: PhantomData,
+        })
+    }
+
+    /// Returns the 33-byte encoding of the public key.
+    pub fn to_bytes(&self) -> [u8; 33] {
+        self.public_key
+    }
+
+    /// Returns the key type of the public key.
+    pub fn key_type(&self) -> RippleKeyType {
+        match self.public_key[0] {
+            ED25519_KEY_PREFIX => RippleKeyType::Ed25519,
+            _ => RippleKeyType::Secp256k1,
+        }
+    }
+}
+
+impl<N: RippleNetwork> FromStr for RipplePublicKey<N> {
+    type Err = PublicKeyError;
+
+    fn from_str(public_key: &str) -> Result<Self, Self::Err> {
+        if public_key.len() != 66 {
+            return Err(PublicKeyError::InvalidCharacterLength(public_key.len()));
+        }
+
+        Self::from_bytes(&hex::decode(public_key)?)
+    }
+}
+
+impl<N: RippleNetwork> Display for RipplePublicKey<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", hex::encode(&self.public_key[..]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    fn test_from_private_key<N: RippleNetwork>(expected_public_key: &str, private_key: &RipplePrivateKey<N>) {
+        let public_key = RipplePublicKey::from_private_key(private_key);
+        assert_eq!(expected_public_key, public_key.to_string());
+    }
+
+    fn test_from_str<N: RippleNetwork>(expected_public_key: &str, expected_address: &str, key_type: RippleKeyType) {
+        let public_key = RipplePublicKey::<N>::from_str(expected_public_key).unwrap();
+        let address = public_key.to_address(&RippleFormat::Classic).unwrap();
+        assert_eq!(expected_public_key, public_key.to_string());
+        assert_eq!(expected_address, address.to_string());
+        assert_eq!(key_type, public_key.key_type());
+    }
+
+    mod mainnet {
+        use super::*;
+
+        type N = Mainnet;
+
+        const KEYPAIRS: [(&str, &str, &str, RippleKeyType); 4] = [
+            (
+                "snoPBrXtMeMyMHUVTgbuqAfg1SUTb",
+                "0330e7fc9d56bb25d6893ba3f317ae5bcf33b3291bd63db32654a313222f7fd020",
+                "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+                RippleKeyType::Secp256k1,
+            ),
+            (
+                "sn259rEFXrQrWyx3Q7XneWcwV6dfL",
+                "0203f2d90bc50012ec7cb20b07a1b818d6863636fb1e945d17449092cfb5495e1e",
+                "rJrRMgiRgrU6hDF4pgu5DXQdWyPbY35ErN",
+                RippleKeyType::Secp256k1,
+            ),
+            (
+                "sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r",
+                "ed01fa53fa5a7e77798f882ece20b1abc00bb358a9e55a202d0d0676bd0ce37a63",
+                "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD",
+                RippleKeyType::Ed25519,
+            ),
+            (
+                "sEdTMtagF8sbUZMDX7FZDAFC2raTS7b",
+                "ed1beeed2c537ede6030b2dfd680ed1604b9bcd60934759603979f9b216652d312",
+                "rsDjRxNrvxf9Td7sg8duGKL9YFGQc6FkFs",
+                RippleKeyType::Ed25519,
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(seed, public_key, _, _)| {
+                let private_key = RipplePrivateKey::<N>::from_str(seed).unwrap();
+                test_from_private_key(public_key, &private_key);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, public_key, address, key_type)| {
+                test_from_str::<N>(public_key, address, *key_type);
+            });
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        type N = Mainnet;
+
+        // Invalid prefix
+        assert!(
+            RipplePublicKey::<N>::from_str("0430e7fc9d56bb25d6893ba3f317ae5bcf33b3291bd63db32654a313222f7fd020")
+                .is_err()
+        );
+
+        // Invalid character length
+        assert!(RipplePublicKey::<N>::from_str("").is_err());
+        assert!(
+            RipplePublicKey::<N>::from_str("30e7fc9d56bb25d6893ba3f317ae5bcf33b3291bd63db32654a313222f7fd020").is_err()
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use crate::format::SolanaFormat;
+use crate::private_key::SolanaPrivateKey;
+use crate::public_key::SolanaPublicKey;
+use wagyu_model::{Address, AddressError, PrivateKey};
+
+use base58::{FromBase58, ToBase58};
+use core::{convert::TryFrom, fmt, str::FromStr};
+use serde::Serialize;
+
+/// Represents a Solana address
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Hash)]
+pub struct SolanaAddress(String);
+
+impl Address for SolanaAddress {
+    type Format = SolanaFormat;
+    type PrivateKey = SolanaPrivateKey;
+    type PublicKey = SolanaPublicKey;
+
+    /// Returns the address corresponding to the given private key.
+    fn from_private_key(private_key: &Self::PrivateKey, format: &Self::Format) -> Result<Self, AddressError> {
+        Self::from_public_key(&private_key.to_public_key(), format)
+    }
+
+    /// Returns the address corresponding to the given public key.
+    fn from_public_key(public_key: &Self::PublicKey, _: &Self::Format) -> Result<Self, AddressError> {
+        Ok(Self(public_key.to_bytes().to_base58()))
+    }
+}
+
+impl<'a> TryFrom<&'a str> for SolanaAddress {
+    type Error = AddressError;
+
+    fn try_from(address: &'a str) -> Result<Self, Self::Error> {
+        Self::from_str(address)
+    }
+}
+
+impl FromStr for SolanaAddress {
+    type Err = AddressError;
+
+    /// Returns the address given the base58 encoding of its ed25519 public key.
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        if address.len() < 32 || address.len() > 44 {
+            return Err(AddressError::InvalidCharacterLength(address.len()));
+        }
+
+        let data = address.from_base58()?;
+        if data.len() != 32 {
+            return Err(AddressError::InvalidByteLength(data.len()));
+        }
+
+        Ok(Self(address.into()))
+    }
+}
+
+impl fmt::Display for SolanaAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wagyu_model::public_key::PublicKey;
+
+    fn test_from_private_key(expected_address: &str, private_key: &SolanaPrivateKey) {
+        let address = SolanaAddress::from_private_key(private_key, &SolanaFormat::Standard).unwrap();
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    fn test_from_public_key(expected_address: &str, public_key: &SolanaPublicKey) {
+        let address = SolanaAddress::from_public_key(public_key, &SolanaFormat::Standard).unwrap();
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    fn test_from_str(expected_address: &str) {
+        let address = SolanaAddress::from_str(expected_address).unwrap();
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    fn test_to_str(expected_address: &str, address: &SolanaAddress) {
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    mod standard {
+        use super::*;
+
+        const KEYPAIRS: [(&str, &str); 5] = [
+            (
+                "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+                "FVen3X669xLzsi6N2V91DoiyzHzg1uAgqiT8jZ9nS96Z",
+            ),
+            (
+                "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+                "586Z7H2vpX9qNhN2T4e9Utugie3ogjbxzGaMtM3E6HR5",
+            ),
+            (
+                "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+                "Hyx62wPQGyvXCoihZq1BrbUjBRh2LuNxWiiqMkfAuSZr",
+            ),
+            (
+                "f5e5767cf153319517630f226876b86c8160cc583bc013744c6bf255f5cc0ee5",
+                "3fD58whN2KJaN9T4r5uE3ELFmzRW1dQNuszrmC6gnhx1",
+            ),
+            (
+                "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42",
+                "Gtbi6WQDB6wUePiZm8aYs5XZ5pUqx9jMMLvRVHPESTjU",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = SolanaPrivateKey::from_str(private_key).unwrap();
+                test_from_private_key(address, &private_key);
+            });
+        }
+
+        #[test]
+        fn from_public_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = SolanaPrivateKey::from_str(private_key).unwrap();
+                let public_key = SolanaPublicKey::from_private_key(&private_key);
+                test_from_public_key(address, &public_key);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, address)| {
+                test_from_str(address);
+            });
+        }
+
+        #[test]
+        fn to_str() {
+            KEYPAIRS.iter().for_each(|(_, expected_address)| {
+                let address = SolanaAddress::from_str(expected_address).unwrap();
+                test_to_str(expected_address, &address);
+            });
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        // Mismatched keypair
+
+        let private_key = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+        let expected_address = "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk";
+
+        let private_key = SolanaPrivateKey::from_str(private_key).unwrap();
+        let address = SolanaAddress::from_private_key(&private_key, &SolanaFormat::Standard).unwrap();
+        assert_ne!(expected_address, address.to_string());
+
+        // Invalid address length
+
+        let address = "F";
+        assert!(SolanaAddress::from_str(address).is_err());
+
+        let address = "FVen3X669xLzsi6N2V91DoiyzHzg1uAgqiT8jZ9nS9";
+        assert!(SolanaAddress::from_str(address).is_err());
+
+        let address = "FVen3X669xLzsi6N2V91DoiyzHzg1uAgqiT8jZ9nS96ZF";
+        assert!(SolanaAddress::from_str(address).is_err());
+
+        // Invalid base58 character
+
+        let address = "FVen3X669xLzsi6N2V91DoiyzHzg1uAgqiT8jZ9nS960";
+        assert!(SolanaAddress::from_str(address).is_err());
+    }
+}
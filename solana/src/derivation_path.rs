@@ -0,0 +1,264 @@
+use crate::network::SolanaNetwork;
+use wagyu_model::derivation_path::{BIP32Path, ChildIndex, DerivationPath, DerivationPathError};
+use wagyu_model::no_std::*;
+
+use core::{convert::TryFrom, fmt, marker::PhantomData, str::FromStr};
+
+/// Represents a Solana derivation path
+#[derive(Clone, PartialEq, Eq)]
+pub enum SolanaDerivationPath<N: SolanaNetwork> {
+    /// SLIP-0010 - hardened-only ed25519 derivation
+    /// https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+    SLIP10(Vec<ChildIndex>, PhantomData<N>),
+    /// BIP44 - m/44'/501'/{account}'/{change}' - as used by Phantom and solana-keygen
+    /// https://github.com/bitcoin/bips/blob/master/bip-0044.mediawiki
+    BIP44([ChildIndex; 2]),
+}
+
+impl<N: SolanaNetwork> DerivationPath for SolanaDerivationPath<N> {
+    /// Returns a child index vector given the derivation path.
+    fn to_vec(&self) -> Result<Vec<ChildIndex>, DerivationPathError> {
+        match self {
+            SolanaDerivationPath::SLIP10(path, _) => match path.len() < 256 {
+                true => Ok(path.clone()),
+                false => Err(DerivationPathError::ExpectedBIP32Path),
+            },
+            SolanaDerivationPath::BIP44(path) => match path[0].is_hardened() && path[1].is_hardened() {
+                true => Ok(vec![ChildIndex::Hardened(44), N::HD_COIN_TYPE, path[0], path[1]]),
+                false => Err(DerivationPathError::ExpectedBIP44Path),
+            },
+        }
+    }
+
+    /// Returns a derivation path given the child index vector.
+    fn from_vec(path: &Vec<ChildIndex>) -> Result<Self, DerivationPathError> {
+        if !path.iter().all(ChildIndex::is_hardened) {
+            return Err(DerivationPathError::ExpectedHardenedPath);
+        }
+
+        if path.len() == 4 && path[0] == ChildIndex::Hardened(44) && path[1] == N::HD_COIN_TYPE {
+            // Path length 4 - BIP44
+            Ok(SolanaDerivationPath::BIP44([path[2], path[3]]))
+        } else {
+            // Path length 0 - SLIP-0010 root key
+            // Path length i - SLIP-0010
+            Ok(SolanaDerivationPath::SLIP10(path.to_vec(), PhantomData))
+        }
+    }
+}
+
+impl<N: SolanaNetwork> FromStr for SolanaDerivationPath<N> {
+    type Err = DerivationPathError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        Self::from_vec(&BIP32Path::from_str(path)?.to_vec()?)
+    }
+}
+
+impl<N: SolanaNetwork> TryFrom<Vec<ChildIndex>> for SolanaDerivationPath<N> {
+    type Error = DerivationPathError;
+
+    fn try_from(path: Vec<ChildIndex>) -> Result<Self, Self::Error> {
+        Self::from_vec(&path)
+    }
+}
+
+impl<'a, N: SolanaNetwork> TryFrom<&'a [ChildIndex]> for SolanaDerivationPath<N> {
+    type Error = DerivationPathError;
+
+    fn try_from(path: &'a [ChildIndex]) -> Result<Self, Self::Error> {
+        Self::try_from(path.to_vec())
+    }
+}
+
+impl<N: SolanaNetwork> fmt::Debug for SolanaDerivationPath<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self, f)
+    }
+}
+
+impl<N: SolanaNetwork> fmt::Display for SolanaDerivationPath<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.to_vec().and_then(|path| BIP32Path::from_vec(&path)) {
+            Ok(path) => fmt::Display::fmt(&path, f),
+            Err(_) => Err(fmt::Error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::network::*;
+
+    use core::convert::TryInto;
+
+    #[test]
+    fn slip10() {
+        use super::*;
+
+        type N = Mainnet;
+
+        assert_eq!(
+            SolanaDerivationPath::<N>::SLIP10(vec![], PhantomData),
+            SolanaDerivationPath::<N>::from_str("m").unwrap()
+        );
+        assert_eq!(
+            SolanaDerivationPath::<N>::SLIP10(vec![ChildIndex::Hardened(0)], PhantomData),
+            SolanaDerivationPath::<N>::from_str("m/0'").unwrap()
+        );
+        assert_eq!(
+            SolanaDerivationPath::<N>::SLIP10(vec![ChildIndex::Hardened(0), ChildIndex::Hardened(1)], PhantomData),
+            SolanaDerivationPath::<N>::from_str("m/0'/1'").unwrap()
+        );
+        assert_eq!(
+            SolanaDerivationPath::<N>::SLIP10(
+                vec![
+                    ChildIndex::Hardened(44),
+                    ChildIndex::Hardened(501),
+                    ChildIndex::Hardened(0)
+                ],
+                PhantomData
+            ),
+            SolanaDerivationPath::<N>::from_str("m/44'/501'/0'").unwrap()
+        );
+        assert_eq!(
+            SolanaDerivationPath::<N>::SLIP10(
+                vec![
+                    ChildIndex::Hardened(44),
+                    ChildIndex::Hardened(501),
+                    ChildIndex::Hardened(0),
+                    ChildIndex::Hardened(0),
+                    ChildIndex::Hardened(0)
+                ],
+                PhantomData
+            ),
+            SolanaDerivationPath::<N>::from_str("m/44'/501'/0'/0'/0'").unwrap()
+        );
+    }
+
+    #[test]
+    fn bip44_mainnet() {
+        use super::*;
+
+        type N = Mainnet;
+
+        assert_eq!(
+            SolanaDerivationPath::<N>::BIP44([ChildIndex::Hardened(0), ChildIndex::Hardened(0)]),
+            SolanaDerivationPath::<N>::from_str("m/44'/501'/0'/0'").unwrap()
+        );
+        assert_eq!(
+            SolanaDerivationPath::<N>::BIP44([ChildIndex::Hardened(0), ChildIndex::Hardened(1)]),
+            SolanaDerivationPath::<N>::from_str("m/44'/501'/0'/1'").unwrap()
+        );
+        assert_eq!(
+            SolanaDerivationPath::<N>::BIP44([ChildIndex::Hardened(1), ChildIndex::Hardened(0)]),
+            SolanaDerivationPath::<N>::from_str("m/44'/501'/1'/0'").unwrap()
+        );
+        assert_eq!(
+            SolanaDerivationPath::<N>::BIP44([ChildIndex::Hardened(1), ChildIndex::Hardened(1)]),
+            SolanaDerivationPath::<N>::from_str("m/44h/501h/1h/1h").unwrap()
+        );
+        assert_eq!(
+            "m/44'/501'/2'/0'",
+            SolanaDerivationPath::<N>::BIP44([ChildIndex::Hardened(2), ChildIndex::Hardened(0)]).to_string()
+        );
+    }
+
+    #[test]
+    fn valid_path() {
+        use super::*;
+
+        type N = Mainnet;
+
+        assert_eq!(SolanaDerivationPath::<N>::from_str("m"), Ok(vec![].try_into().unwrap()));
+        assert_eq!(
+            SolanaDerivationPath::<N>::from_str("m/0'"),
+            Ok(vec![ChildIndex::hardened(0).unwrap()].try_into().unwrap())
+        );
+        assert_eq!(
+            SolanaDerivationPath::<N>::from_str("m/0h/1'"),
+            Ok(vec![ChildIndex::hardened(0).unwrap(), ChildIndex::hardened(1).unwrap()]
+                .try_into()
+                .unwrap())
+        );
+        assert_eq!(
+            SolanaDerivationPath::<N>::from_str("m/0'/1h/2'"),
+            Ok(vec![
+                ChildIndex::hardened(0).unwrap(),
+                ChildIndex::hardened(1).unwrap(),
+                ChildIndex::hardened(2).unwrap(),
+            ]
+            .try_into()
+            .unwrap())
+        );
+    }
+
+    #[test]
+    fn to_hardened_vec() {
+        use super::*;
+
+        type N = Mainnet;
+
+        assert_eq!(
+            Ok(vec![
+                ChildIndex::Hardened(44),
+                ChildIndex::Hardened(501),
+                ChildIndex::Hardened(0),
+                ChildIndex::Hardened(0)
+            ]),
+            SolanaDerivationPath::<N>::from_str("m/44'/501'/0'/0'")
+                .unwrap()
+                .to_hardened_vec()
+        );
+        assert_eq!(
+            Err(DerivationPathError::ExpectedHardenedPath),
+            SolanaDerivationPath::<N>::SLIP10(vec![ChildIndex::Normal(0)], PhantomData).to_hardened_vec()
+        );
+        assert_eq!(
+            Err(DerivationPathError::ExpectedBIP44Path),
+            SolanaDerivationPath::<N>::BIP44([ChildIndex::Hardened(0), ChildIndex::Normal(0)]).to_hardened_vec()
+        );
+    }
+
+    #[test]
+    fn invalid_path() {
+        use super::*;
+
+        type N = Mainnet;
+
+        assert_eq!(
+            SolanaDerivationPath::<N>::from_str("n"),
+            Err(DerivationPathError::InvalidDerivationPath("n".into()))
+        );
+        assert_eq!(
+            SolanaDerivationPath::<N>::from_str("1/0'"),
+            Err(DerivationPathError::InvalidDerivationPath("1/0'".into()))
+        );
+        assert_eq!(
+            SolanaDerivationPath::<N>::from_str("m/0x"),
+            Err(DerivationPathError::InvalidChildNumberFormat)
+        );
+        assert_eq!(
+            SolanaDerivationPath::<N>::from_str("m//0'"),
+            Err(DerivationPathError::InvalidChildNumberFormat)
+        );
+        assert_eq!(
+            SolanaDerivationPath::<N>::from_str("m/2147483648'"),
+            Err(DerivationPathError::InvalidChildNumber(2147483648))
+        );
+
+        // Normal indices cannot be derived on ed25519
+        assert_eq!(
+            SolanaDerivationPath::<N>::from_str("m/0"),
+            Err(DerivationPathError::ExpectedHardenedPath)
+        );
+        assert_eq!(
+            SolanaDerivationPath::<N>::from_str("m/44'/501'/0'/0"),
+            Err(DerivationPathError::ExpectedHardenedPath)
+        );
+        assert_eq!(
+            SolanaDerivationPath::<N>::from_str("m/44'/501'/0'/0/0"),
+            Err(DerivationPathError::ExpectedHardenedPath)
+        );
+    }
+}
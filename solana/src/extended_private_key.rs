@@ -0,0 +1,531 @@
+use crate::address::SolanaAddress;
+use crate::derivation_path::SolanaDerivationPath;
+use crate::extended_public_key::SolanaExtendedPublicKey;
+use crate::format::SolanaFormat;
+use crate::network::SolanaNetwork;
+use crate::private_key::SolanaPrivateKey;
+use crate::public_key::SolanaPublicKey;
+use wagyu_model::{
+    crypto::hash160, encoding::base58check, AddressError, ChildIndex, DerivationPath, ExtendedPrivateKey,
+    ExtendedPrivateKeyError, ExtendedPublicKey, PrivateKey, WalletGenerator,
+};
+
+use base58::FromBase58;
+use core::{convert::TryFrom, fmt, fmt::Display, marker::PhantomData, str::FromStr};
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use zeroize::Zeroize;
+
+type HmacSha512 = Hmac<Sha512>;
+
+/// Represents a Solana Extended Private Key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolanaExtendedPrivateKey<N> {
+    /// The depth of key derivation, e.g. 0x00 for master nodes, 0x01 for level-1 derived keys, ...
+    pub(super) depth: u8,
+    /// The first 32 bits of the key identifier (hash160(0x00 || ed25519_public_key))
+    pub(super) parent_fingerprint: [u8; 4],
+    /// The child index of the key (0 for master key)
+    pub(super) child_index: ChildIndex,
+    /// The chain code for this extended private key
+    pub(super) chain_code: [u8; 32],
+    /// The Solana private key
+    private_key: SolanaPrivateKey,
+    /// PhantomData
+    _network: PhantomData<N>,
+}
+
+/// Represents a lazy iterator over the Solana wallets of a derivation path template, e.g. `m/44'/501'/*'`
+pub type SolanaWalletIter<N> = WalletGenerator<SolanaExtendedPrivateKey<N>>;
+
+impl<N: SolanaNetwork> ExtendedPrivateKey for SolanaExtendedPrivateKey<N> {
+    type Address = SolanaAddress;
+    type DerivationPath = SolanaDerivationPath<N>;
+    type ExtendedPublicKey = SolanaExtendedPublicKey<N>;
+    type Format = SolanaFormat;
+    type PrivateKey = SolanaPrivateKey;
+    type PublicKey = SolanaPublicKey;
+
+    /// Returns a new Solana extended private key.
+    fn new(seed: &[u8], _format: &Self::Format, path: &Self::DerivationPath) -> Result<Self, ExtendedPrivateKeyError> {
+        Ok(Self::new_master(seed, _format)?.derive(path)?)
+    }
+
+    /// Returns a new Solana extended private key.
+    fn new_master(seed: &[u8], _format: &Self::Format) -> Result<Self, ExtendedPrivateKeyError> {
+        let mut mac = HmacSha512::new_varkey(b"ed25519 seed")?; // SLIP-0010 master key generation for ed25519
+        mac.input(seed);
+        let hmac = mac.result().code();
+        let private_key = Self::PrivateKey::from_bytes(&hmac[0..32])
+            .map_err(|error| ExtendedPrivateKeyError::Message(error.to_string()))?;
+
+        let mut chain_code = [0u8; 32];
+        chain_code[0..32].copy_from_slice(&hmac[32..]);
+
+        Ok(Self {
+            depth: 0,
+            parent_fingerprint: [0u8; 4],
+            child_index: ChildIndex::Normal(0),
+            chain_code,
+            private_key,
+            _network: PhantomData,
+        })
+    }
+
+    /// Returns the extended private key of the given derivation path.
+    fn derive(&self, path: &Self::DerivationPath) -> Result<Self, ExtendedPrivateKeyError> {
+        if self.depth == 255 {
+            return Err(ExtendedPrivateKeyError::MaximumChildDepthReached(self.depth));
+        }
+
+        let mut extended_private_key = self.clone();
+
+        // SLIP-0010 only defines hardened child derivation for ed25519.
+        for index in path.to_hardened_vec()?.into_iter() {
+            // HMAC-SHA512(Key = cpar, Data = 0x00 || ser256(kpar) || ser32(i))
+            let mut mac = HmacSha512::new_varkey(&extended_private_key.chain_code)?;
+            mac.input(&[0u8]);
+            mac.input(&extended_private_key.private_key.to_bytes());
+            // Append the child index in big-endian format
+            mac.input(&u32::from(index).to_be_bytes());
+            let hmac = mac.result().code();
+
+            // Unlike BIP32, the child key is the left half of the HMAC itself.
+            let private_key = Self::PrivateKey::from_bytes(&hmac[0..32])
+                .map_err(|error| ExtendedPrivateKeyError::Message(error.to_string()))?;
+
+            let mut chain_code = [0u8; 32];
+            chain_code[0..32].copy_from_slice(&hmac[32..]);
+
+            let mut public_key = [0u8; 33];
+            public_key[1..33].copy_from_slice(&extended_private_key.private_key.to_public_key().to_bytes());
+
+            let mut parent_fingerprint = [0u8; 4];
+            parent_fingerprint.copy_from_slice(&hash160(&public_key)[0..4]);
+
+            extended_private_key = Self {
+                depth: extended_private_key.depth + 1,
+                parent_fingerprint,
+                child_index: index,
+                chain_code,
+                private_key,
+                _network: PhantomData,
+            }
+        }
+
+        Ok(extended_private_key)
+    }
+
+    /// Returns the extended public key of the corresponding extended private key.
+    fn to_extended_public_key(&self) -> Self::ExtendedPublicKey {
+        Self::ExtendedPublicKey::from_extended_private_key(&self)
+    }
+
+    /// Returns the private key of the corresponding extended private key.
+    fn to_private_key(&self) -> Self::PrivateKey {
+        self.private_key.clone()
+    }
+
+    /// Returns the public key of the corresponding extended private key.
+    fn to_public_key(&self) -> Self::PublicKey {
+        self.private_key.to_public_key()
+    }
+
+    /// Returns the address of the corresponding extended private key.
+    fn to_address(&self, _format: &Self::Format) -> Result<Self::Address, AddressError> {
+        self.private_key.to_address(_format)
+    }
+}
+
+impl<N: SolanaNetwork> FromStr for SolanaExtendedPrivateKey<N> {
+    type Err = ExtendedPrivateKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data = s.from_base58()?;
+        if data.len() != 82 {
+            return Err(ExtendedPrivateKeyError::InvalidByteLength(data.len()));
+        }
+
+        // Solana xkeys are mainnet only
+        if &data[0..4] != [0x04u8, 0x88, 0xAD, 0xE4] {
+            return Err(ExtendedPrivateKeyError::InvalidVersionBytes(data[0..4].to_vec()));
+        };
+
+        let depth = data[4] as u8;
+
+        let mut parent_fingerprint = [0u8; 4];
+        parent_fingerprint.copy_from_slice(&data[5..9]);
+
+        let child_index = ChildIndex::from(u32::from_be_bytes(<[u8; 4]>::try_from(&data[9..13])?));
+
+        let mut chain_code = [0u8; 32];
+        chain_code.copy_from_slice(&data[13..45]);
+
+        let private_key = SolanaPrivateKey::from_bytes(&data[46..78])
+            .map_err(|error| ExtendedPrivateKeyError::Message(error.to_string()))?;
+
+        base58check::verify(&data)?;
+
+        Ok(Self {
+            depth,
+            parent_fingerprint,
+            child_index,
+            chain_code,
+            private_key,
+            _network: PhantomData,
+        })
+    }
+}
+
+impl<N: SolanaNetwork> Display for SolanaExtendedPrivateKey<N> {
+    /// BIP32 serialization format, with the ed25519 secret key in place of the secp256k1 secret key:
+    /// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let mut result = [0u8; 78];
+        result[0..4].copy_from_slice(&[0x04, 0x88, 0xAD, 0xE4][..]);
+        result[4] = self.depth as u8;
+        result[5..9].copy_from_slice(&self.parent_fingerprint[..]);
+        result[9..13].copy_from_slice(&u32::from(self.child_index).to_be_bytes());
+        result[13..45].copy_from_slice(&self.chain_code[..]);
+        result[45] = 0;
+        result[46..78].copy_from_slice(&self.private_key.to_bytes());
+
+        fmt.write_str(&base58check::encode(&result))
+    }
+}
+
+impl<N> Zeroize for SolanaExtendedPrivateKey<N> {
+    /// Overwrites the chain code and the private key.
+    fn zeroize(&mut self) {
+        self.chain_code.zeroize();
+        self.private_key.zeroize();
+    }
+}
+
+impl<N> Drop for SolanaExtendedPrivateKey<N> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    use hex;
+    use std::convert::TryInto;
+
+    fn test_new<N: SolanaNetwork>(
+        expected_extended_private_key: &str,
+        expected_parent_fingerprint: &str,
+        expected_child_index: u32,
+        expected_chain_code: &str,
+        expected_secret_key: &str,
+        expected_public_key: &str,
+        seed: &str,
+        path: &SolanaDerivationPath<N>,
+    ) {
+        let extended_private_key =
+            SolanaExtendedPrivateKey::new(&hex::decode(seed).unwrap(), &SolanaFormat::Standard, path).unwrap();
+        assert_eq!(expected_extended_private_key, extended_private_key.to_string());
+        assert_eq!(
+            expected_parent_fingerprint,
+            hex::encode(extended_private_key.parent_fingerprint)
+        );
+        assert_eq!(expected_child_index, u32::from(extended_private_key.child_index));
+        assert_eq!(expected_chain_code, hex::encode(extended_private_key.chain_code));
+        assert_eq!(
+            expected_secret_key,
+            hex::encode(&extended_private_key.private_key.to_bytes())
+        );
+        assert_eq!(expected_public_key, extended_private_key.to_public_key().to_string());
+    }
+
+    // Check: (extended_private_key1 -> extended_private_key2) == (expected_extended_private_key2)
+    fn test_derive<N: SolanaNetwork>(
+        expected_extended_private_key1: &str,
+        expected_extended_private_key2: &str,
+        expected_child_index2: u32,
+    ) {
+        let path = vec![ChildIndex::from(expected_child_index2)].try_into().unwrap();
+
+        let extended_private_key1 = SolanaExtendedPrivateKey::<N>::from_str(expected_extended_private_key1).unwrap();
+        let extended_private_key2 = extended_private_key1.derive(&path).unwrap();
+
+        let expected_extended_private_key2 =
+            SolanaExtendedPrivateKey::<N>::from_str(&expected_extended_private_key2).unwrap();
+
+        assert_eq!(expected_extended_private_key2, extended_private_key2);
+    }
+
+    fn test_to_extended_public_key<N: SolanaNetwork>(
+        expected_extended_public_key: &str,
+        seed: &str,
+        path: &SolanaDerivationPath<N>,
+    ) {
+        let extended_private_key =
+            SolanaExtendedPrivateKey::<N>::new(&hex::decode(seed).unwrap(), &SolanaFormat::Standard, path).unwrap();
+        let extended_public_key = extended_private_key.to_extended_public_key();
+        assert_eq!(expected_extended_public_key, extended_public_key.to_string());
+    }
+
+    fn test_to_string<N: SolanaNetwork>(expected_extended_private_key: &str) {
+        let extended_private_key = SolanaExtendedPrivateKey::<N>::from_str(expected_extended_private_key).unwrap();
+        assert_eq!(expected_extended_private_key, extended_private_key.to_string());
+    }
+
+    /// Test vectors from https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+    mod slip10 {
+        use super::*;
+
+        type N = Mainnet;
+
+        // (path, seed, child_index, secret_key, chain_code, parent_fingerprint, public_key, extended_private_key, extended_public_key)
+        const KEYPAIRS: [(&str, &str, &str, &str, &str, &str, &str, &str, &str); 12] = [
+            // Test vector 1 for ed25519
+            (
+                "m",
+                "000102030405060708090a0b0c0d0e0f",
+                "0",
+                "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7",
+                "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb",
+                "00000000",
+                "a4b2856bfec510abab89753fac1ac0e1112364e7d250545963f135f2a33188ed",
+                "xprv9s21ZrQH143K3VX7GQTkxonbgca94bts9EdRC1ZKN2Z9BA3JXZxbUwo4kS28ECmXhK1NicjQ7yBwWbZXgjRVktP6Tzi4YqetK5ueSA2CaXP",
+                "xpub661MyMwAqRbcFybaNRzmKwjLEeQdU4ciWTZ1zPxvvN683xNT57Gr2k7Ybe5sLdMAtszjE1cd1Q1Wmb82QjvjtYomxGdbfLN5wnDyCpd3t6e",
+            ),
+            (
+                "m/0'",
+                "000102030405060708090a0b0c0d0e0f",
+                "2147483648",
+                "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3",
+                "8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69",
+                "ddebc675",
+                "8c8a13df77a28f3445213a0f432fde644acaa215fc72dcdf300d5efaa85d350c",
+                "xprv9vXkeS59SNYr567MAZPtjkhjp645NGj7VTAGZ7LqaZtvfgKGDEyv27HMw6nfHWcSnKnJ6BtTKrhgsKUkxtR3K6juACC8Qw4DRWr7hrAJxKX",
+                "xpub69X73wc3Gk79HaBpGavu6teUN7tZmjSxrg5sMVkT8uRuYUeQknJAZubqnJCeGqq5Tm1SamntUPcnAAkLaZMjXjAHBM85e5L4bV3HebS74ou",
+            ),
+            (
+                "m/0'/1'",
+                "000102030405060708090a0b0c0d0e0f",
+                "2147483649",
+                "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2",
+                "a320425f77d1b5c2505a6b1b27382b37368ee640e3557c315416801243552f14",
+                "13dab143",
+                "1932a5270f335bed617d5b935c80aedb1a35bd9fc1e31acafd5372c30f5c1187",
+                "xprv9vvkCbYPgLdiGwVBabM8r6NUDKGGerPb4t2kbqAJDWWbbn68EG4Zmwzpq7eRjbQ78MFnnyasFqt9WiEEnVBpE878KQB3fxYjCkUcUjLBXjg",
+                "xpub69v6c75HWiC1VRZegct9DEKCmM6m4K7SS6xMQDZumr3aUaRGmoNpKkKJgHdVR1RL6VjDxUBWyRAJwJLPbBQmEvnT7k9MSXinpyGcWTDKPPt",
+            ),
+            (
+                "m/0'/1'/2'",
+                "000102030405060708090a0b0c0d0e0f",
+                "2147483650",
+                "92a5b23c0b8a99e37d07df3fb9966917f5d06e02ddbd909c7e184371463e9fc9",
+                "2e69929e00b5ab250f49c3fb1c12f252de4fed2c1db88387094a0f8c4c9ccd6c",
+                "ebe4cb29",
+                "ae98736566d30ed0e9d2f4486a64bc95740d89c7db33f52121f8ea8f76ff0fc1",
+                "xprv9zPyrQoMg2watGWZTFYWrcgL9tEt7fannRDrHSzX7ZwuGqSzRn87jeRaTtEbwReQdnWzWDk82R6o13r56u9Q9w6WecqiswiQbsknzXnEnCR",
+                "xpub6DPLFvLFWQVt6kb2ZH5XDkd4hv5NX8Je9e9T5qQ8fuUt9dn8yKSNHSk4K5bBvr3j4VcTF2zJoWanvQf59zz4FDokFj5mNHUqdgXj5z4s4mz",
+            ),
+            (
+                "m/0'/1'/2'/2'",
+                "000102030405060708090a0b0c0d0e0f",
+                "2147483650",
+                "30d1dc7e5fc04c31219ab25a27ae00b50f6fd66622f6e9c913253d6511d1e662",
+                "8f6d87f93d750e0efccda017d662a1b31a266e4a6f5993b15f5c1f07f74dd5cc",
+                "316ec1c6",
+                "8abae2d66361c879b900d204ad2cc4984fa2aa344dd7ddc46007329ac76c429c",
+                "xprv9zudGmYJHgxA7mY2TFMeWFv18jPZw3oy2cg5C3CRPwVrGyrotS4wDjxtWsnswR7mmG1ysEZBVZscqbymKaGCQkbiA6QEka9tBALGqmt4d2w",
+                "xpub6DtygH5C84WTLFcVZGtesPrjgmE4LWXpPqbfzRc2xH2q9nBxRyPBmYHNN5ckfXGLJjMXc2BPePB5PzJFJypfftX21G3eJYWVzpSF899Nxeq",
+            ),
+            (
+                "m/0'/1'/2'/2'/1000000000'",
+                "000102030405060708090a0b0c0d0e0f",
+                "3147483648",
+                "8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793",
+                "68789923a0cac2cd5a29172a475fe9e0fb14cd6adb5ad98a3fa70333e7afa230",
+                "d6322ccd",
+                "3c24da049451555d51a7014a37337aa4e12d41e485abccfa46b47dfb2af54b7a",
+                "xprvA41155k5uwbawzysQ7FQYvUX6dRG3PPDqvyvGs4iQdYVojtrZRUH5SHeg2153NJCehKfTCRcJj2JYhtZnZunAhM6U6JsTdEhB5h6dxH3dg4",
+                "xpub6GzMUbGykK9tAV4LW8nQv4RFefFkSr75D9uX5FUKxy5UgYE16xnXdEc8XCWbqMD6vzQDvf7BDsQ3yvoWS3VPVVTSwpxyncSJxXpdJBfP7bh",
+            ),
+            // Test vector 2 for ed25519
+            (
+                "m",
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "0",
+                "171cb88b1b3c1db25add599712e36245d75bc65a1a5c9e18d76f9f2b1eab4012",
+                "ef70a74db9c3a5af931b5fe73ed8e1a53464133654fd55e7a66f8570b8e33c3b",
+                "00000000",
+                "8fe9693f8fa62a4305a140b9764c5ee01e455963744fe18204b4fb948249308a",
+                "xprv9s21ZrQH143K4Sd1z5fLT9D6CsVWg33mA1TDfKPzKavYR47H1cJaX16paqoyUuw3g1Zm6GHruGNpXqdVk8BVoZ8bLE3DYQpudN4C9H391kJ",
+                "xpub661MyMwAqRbcGvhV67CLpH9pkuL15VmcXENpThobsvTXHrSRZ9cq4oRJS3sTEY93ZJeoRxEdEofbMdPYQRWixwx2aFSWV51s3n2NQbe4oqt",
+            ),
+            (
+                "m/0'",
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "2147483648",
+                "1559eb2bbec5790b0c65d8693e4d0875b1747f4970ae8b650486ed7470845635",
+                "0b78a3226f915c082bf118f83618a618ab6dec793752624cbeb622acb562862d",
+                "31981b50",
+                "86fab68dcb57aa196c77c5f264f215a112c22a912c10d123b0d03c3c28ef1037",
+                "xprv9uGHh49ujaoHqQ2cp8sRmUALBStm8demw229jvVQDFCxgYZbGhgTo58JJPWE84Yqukks3CEsoUX1T61y5r6pMh59woxdZbncKbJsHSMbq42",
+                "xpub68Fe6ZgoZxMb3t75vAQS8c74jUjFY6NdJEwkYJu1majwZLtjpEziLsSn9bWYgvf5Uv6JzZZZHZJpo431VZhjXdehLdTdYaRyXLF7w24AkYs",
+            ),
+            (
+                "m/0'/2147483647'",
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "4294967295",
+                "ea4f5bfe8694d8bb74b7b59404632fd5968b774ed545e810de9c32a4fb4192f4",
+                "138f0b2551bcafeca6ff2aa88ba8ed0ed8de070841f0c4ef0165df8181eaad7f",
+                "1e9411b1",
+                "5ba3b9ac6e90e83effcd25ac4e58a1365a9e35a3d3ae5eb07b9e4d90bcf7506d",
+                "xprv9w1KNACRREEJrriwWHinXRoEpET3c9wfmBe5Giw1ju9EJfwe793TKgZz9LYKq4cJoMpoBzTAToDFv7GctoZnpBoSEHRaCPpubaCLeqXanCu",
+                "xpub69zfmfjKFbnc5LoQcKFntZjyNGHY1cfX8QZg57LdJEgDBUGnegMhsUtTzWbmbPJ8bwhk9wAv4Pb27p7tXpg14EdjtQzzj4GGFQXfUhGA9X6",
+            ),
+            (
+                "m/0'/2147483647'/1'",
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "2147483649",
+                "3757c7577170179c7868353ada796c839135b3d30554bbb74a4b1e4a5a58505c",
+                "73bd9fff1cfbde33a1b846c27085f711c0fe2d66fd32e139d3ebc28e5a4a6b90",
+                "fcadf38c",
+                "2e66aa57069c86cc18249aecf5cb5a9cebbfd6fadeab056254763874a9352b45",
+                "xprv9zX8u2jhF8C9xiNBaWcAbYdMVhQRF26KVJx2HsSHrUa3z9dobfGEAyP5gpUGyyCecurJkGKZWq15f1L4UYcfcVnoMmzwoXaH3ghtQwq4soQ",
+                "xpub6DWVJYGb5VkTBCSegY9Axga63jEueUpArXsd6FquQp72rwxx9CaUimhZY1YcfWS4PrZijf3kgPDHBK4LzWxs5Zp9ao3TkXCnFJqGH3vaCLw",
+            ),
+            (
+                "m/0'/2147483647'/1'/2147483646'",
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "4294967294",
+                "5837736c89570de861ebc173b1086da4f505d4adb387c6a1b1342d5e4ac9ec72",
+                "0902fe8a29f9140480a00ef244bd183e8a13288e4412d8389d140aac1794825a",
+                "aca70953",
+                "e33c0f7d81d843c572275f287498e8d408654fdf0d1e065b84e2e6f157aab09b",
+                "xprvA1pA1PtdkCdx5FhjvoYZpGck3VgU3GtAoXEWJwWmT15vg34XeYMUAuw15e3VzZYnvSFERzrB4Pih42T1D7WFmNmN5Y1S77jdY2PWZoSGjLd",
+                "xpub6EoWQuRXaaCFHjnD2q5aBQZUbXWxSjc2AkA77KvP1LcuYqPgC5fiiiFUvrF17PCqcyfR6sG8G13RmjbNvmuHzqvrBZY335vCKS9NxhA1ygr",
+            ),
+            (
+                "m/0'/2147483647'/1'/2147483646'/2'",
+                "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a29f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542",
+                "2147483650",
+                "551d333177df541ad876a60ea71f00447931c0a9da16f227c11ea080d7391b8d",
+                "5d70af781f3a37b829f0d060924d5e960bdc02e85423494afc0b1a41bbe196d4",
+                "422c654b",
+                "47150c75db263559a70d5778bf36abbab30fb061ad69f69ece61a72b0cfa4fc0",
+                "xprvA2uu4eHvBTCGQj4XrxztSJ323FeUN4fiFpMPYLEQXeX6CMGxqwTYoVCKyczbQmhPTHN4J3MxfvSu3pPCKRmG5SbooBcnq3TUCLvZ417Cspw",
+                "xpub6FuFU9pp1pkZdD8zxzXtoRykbHUxmXPZd3GzLie25z4559c7PUmoMHWopp2h5KfxEyWUdL1bNPxncaNmxdzf3qpLA3eJhdgHWb1xf4Mc7Ff",
+            ),
+        ];
+
+        #[test]
+        fn new() {
+            KEYPAIRS.iter().for_each(
+                |(
+                    path,
+                    seed,
+                    child_index,
+                    secret_key,
+                    chain_code,
+                    parent_fingerprint,
+                    public_key,
+                    extended_private_key,
+                    _,
+                )| {
+                    test_new::<N>(
+                        extended_private_key,
+                        parent_fingerprint,
+                        child_index.parse().unwrap(),
+                        chain_code,
+                        secret_key,
+                        public_key,
+                        seed,
+                        &SolanaDerivationPath::from_str(path).unwrap(),
+                    );
+                },
+            );
+        }
+
+        #[test]
+        fn derive() {
+            KEYPAIRS
+                .windows(2)
+                .filter(|pair| pair[0].1 == pair[1].1)
+                .for_each(|pair| {
+                    let (_, _, _, _, _, _, _, expected_extended_private_key1, _) = pair[0];
+                    let (_, _, expected_child_index2, _, _, _, _, expected_extended_private_key2, _) = pair[1];
+                    test_derive::<N>(
+                        expected_extended_private_key1,
+                        expected_extended_private_key2,
+                        expected_child_index2.parse().unwrap(),
+                    );
+                });
+        }
+
+        #[test]
+        fn to_extended_public_key() {
+            KEYPAIRS
+                .iter()
+                .for_each(|(path, seed, _, _, _, _, _, _, expected_extended_public_key)| {
+                    test_to_extended_public_key::<N>(
+                        expected_extended_public_key,
+                        seed,
+                        &SolanaDerivationPath::from_str(path).unwrap(),
+                    );
+                });
+        }
+
+        #[test]
+        fn to_string() {
+            KEYPAIRS
+                .iter()
+                .for_each(|(_, _, _, _, _, _, _, extended_private_key, _)| {
+                    test_to_string::<N>(extended_private_key);
+                });
+        }
+    }
+
+    mod test_invalid {
+        use super::*;
+
+        type N = Mainnet;
+
+        const INVALID_EXTENDED_PRIVATE_KEY_NETWORK: &str = "xprv1ZKKpwtvh3uwNWZnUnLAvY5DfcthJjRow9xEhsvdW6HjDU7gSiTFrFVgRa6zi5CcGr1F78qHkDwhHGcfR5vCgWCPw3B4XfiheE4f7ncZMn9";
+        const INVALID_EXTENDED_PRIVATE_KEY_CHECKSUM: &str = "xprv9s21ZrQH143K3VX7GQTkxonbgca94bts9EdRC1ZKN2Z9BA3JXZxbUwo4kS28ECmXhK1NicjQ7yBwWbZXgjRVktP6Tzi4YqetK5ueSA2CaXQ";
+        const VALID_EXTENDED_PRIVATE_KEY: &str = "xprv9s21ZrQH143K3VX7GQTkxonbgca94bts9EdRC1ZKN2Z9BA3JXZxbUwo4kS28ECmXhK1NicjQ7yBwWbZXgjRVktP6Tzi4YqetK5ueSA2CaXP";
+
+        #[test]
+        #[should_panic(expected = "InvalidVersionBytes([4, 136, 173, 227])")]
+        fn from_str_invalid_network() {
+            let _result = SolanaExtendedPrivateKey::<N>::from_str(INVALID_EXTENDED_PRIVATE_KEY_NETWORK).unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "InvalidChecksum(\"6XKPfk\", \"6XKPfj\")")]
+        fn from_str_invalid_checksum() {
+            let _result = SolanaExtendedPrivateKey::<N>::from_str(INVALID_EXTENDED_PRIVATE_KEY_CHECKSUM).unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "InvalidByteLength(81)")]
+        fn from_str_short() {
+            let _result = SolanaExtendedPrivateKey::<N>::from_str(&VALID_EXTENDED_PRIVATE_KEY[1..]).unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "InvalidByteLength(83)")]
+        fn from_str_long() {
+            let mut string = String::from(VALID_EXTENDED_PRIVATE_KEY);
+            string.push('a');
+            let _result = SolanaExtendedPrivateKey::<N>::from_str(&string).unwrap();
+        }
+
+        #[test]
+        fn derive_normal_index() {
+            let extended_private_key = SolanaExtendedPrivateKey::<N>::from_str(VALID_EXTENDED_PRIVATE_KEY).unwrap();
+            let path = SolanaDerivationPath::<N>::SLIP10(vec![ChildIndex::Normal(0)], PhantomData);
+            assert!(extended_private_key.derive(&path).is_err());
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use crate::address::SolanaAddress;
+use crate::derivation_path::SolanaDerivationPath;
+use crate::extended_private_key::SolanaExtendedPrivateKey;
+use crate::format::SolanaFormat;
+use crate::network::SolanaNetwork;
+use crate::public_key::SolanaPublicKey;
+use wagyu_model::{
+    encoding::base58check, AddressError, ChildIndex, DerivationPath, ExtendedPrivateKey, ExtendedPublicKey,
+    ExtendedPublicKeyError, PublicKey, PublicKeyError,
+};
+
+use base58::FromBase58;
+use core::{convert::TryFrom, fmt, marker::PhantomData, str::FromStr};
+
+/// Represents a Solana extended public key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolanaExtendedPublicKey<N: SolanaNetwork> {
+    /// The depth of key derivation, e.g. 0x00 for master nodes, 0x01 for level-1 derived keys, ...
+    depth: u8,
+    /// The first 32 bits of the key identifier (hash160(0x00 || ed25519_public_key))
+    parent_fingerprint: [u8; 4],
+    /// The child index of the key (0 for master key)
+    child_index: ChildIndex,
+    /// The chain code from the extended private key
+    chain_code: [u8; 32],
+    /// The Solana public key
+    public_key: SolanaPublicKey,
+    /// PhantomData
+    _network: PhantomData<N>,
+}
+
+impl<N: SolanaNetwork> ExtendedPublicKey for SolanaExtendedPublicKey<N> {
+    type Address = SolanaAddress;
+    type DerivationPath = SolanaDerivationPath<N>;
+    type ExtendedPrivateKey = SolanaExtendedPrivateKey<N>;
+    type Format = SolanaFormat;
+    type PublicKey = SolanaPublicKey;
+
+    /// Returns the extended public key of the corresponding extended private key.
+    fn from_extended_private_key(extended_private_key: &Self::ExtendedPrivateKey) -> Self {
+        Self {
+            depth: extended_private_key.depth,
+            parent_fingerprint: extended_private_key.parent_fingerprint,
+            child_index: extended_private_key.child_index,
+            chain_code: extended_private_key.chain_code,
+            public_key: extended_private_key.to_public_key(),
+            _network: PhantomData,
+        }
+    }
+
+    /// Returns the extended public key for the given derivation path.
+    /// SLIP-0010 only defines hardened derivation for ed25519, so only the empty path is supported.
+    fn derive(&self, path: &Self::DerivationPath) -> Result<Self, ExtendedPublicKeyError> {
+        match path.to_hardened_vec()?.first() {
+            Some(index) => Err(ExtendedPublicKeyError::InvalidChildNumber(1 << 31, u32::from(*index))),
+            None => Ok(self.clone()),
+        }
+    }
+
+    /// Returns the public key of the corresponding extended public key.
+    fn to_public_key(&self) -> Self::PublicKey {
+        self.public_key.clone()
+    }
+
+    /// Returns the address of the corresponding extended public key.
+    fn to_address(&self, _format: &Self::Format) -> Result<Self::Address, AddressError> {
+        self.public_key.to_address(_format)
+    }
+}
+
+impl<N: SolanaNetwork> FromStr for SolanaExtendedPublicKey<N> {
+    type Err = ExtendedPublicKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data = s.from_base58()?;
+        if data.len() != 82 {
+            return Err(ExtendedPublicKeyError::InvalidByteLength(data.len()));
+        }
+
+        if &data[0..4] != [0x04u8, 0x88, 0xB2, 0x1E] {
+            return Err(ExtendedPublicKeyError::InvalidVersionBytes(data[0..4].to_vec()));
+        };
+
+        let depth = data[4] as u8;
+
+        let mut parent_fingerprint = [0u8; 4];
+        parent_fingerprint.copy_from_slice(&data[5..9]);
+
+        let child_index = ChildIndex::from(u32::from_be_bytes(<[u8; 4]>::try_from(&data[9..13])?));
+
+        let mut chain_code = [0u8; 32];
+        chain_code.copy_from_slice(&data[13..45]);
+
+        // SLIP-0010 serializes ed25519 public keys with a 0x00 prefix
+        if data[45] != 0 {
+            return Err(PublicKeyError::InvalidPrefix(hex::encode(&data[45..46])).into());
+        }
+        let public_key = SolanaPublicKey::from_bytes(&data[46..78])?;
+
+        base58check::verify(&data)?;
+
+        Ok(Self {
+            depth,
+            parent_fingerprint,
+            child_index,
+            chain_code,
+            public_key,
+            _network: PhantomData,
+        })
+    }
+}
+
+impl<N: SolanaNetwork> fmt::Display for SolanaExtendedPublicKey<N> {
+    /// BIP32 serialization format, with the SLIP-0010 ed25519 public key in place of the secp256k1 public key:
+    /// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let mut result = [0u8; 78];
+        result[0..4].copy_from_slice(&[0x04u8, 0x88, 0xB2, 0x1E][..]);
+        result[4] = self.depth as u8;
+        result[5..9].copy_from_slice(&self.parent_fingerprint[..]);
+        result[9..13].copy_from_slice(&u32::from(self.child_index).to_be_bytes());
+        result[13..45].copy_from_slice(&self.chain_code[..]);
+        result[45] = 0;
+        result[46..78].copy_from_slice(&self.public_key.to_bytes());
+
+        fmt.write_str(&base58check::encode(&result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    type N = Mainnet;
+
+    // (extended_private_key, extended_public_key, public_key)
+    const KEYPAIRS: [(&str, &str, &str); 2] = [
+        (
+            "xprv9s21ZrQH143K3VX7GQTkxonbgca94bts9EdRC1ZKN2Z9BA3JXZxbUwo4kS28ECmXhK1NicjQ7yBwWbZXgjRVktP6Tzi4YqetK5ueSA2CaXP",
+            "xpub661MyMwAqRbcFybaNRzmKwjLEeQdU4ciWTZ1zPxvvN683xNT57Gr2k7Ybe5sLdMAtszjE1cd1Q1Wmb82QjvjtYomxGdbfLN5wnDyCpd3t6e",
+            "a4b2856bfec510abab89753fac1ac0e1112364e7d250545963f135f2a33188ed",
+        ),
+        (
+            "xprvA41155k5uwbawzysQ7FQYvUX6dRG3PPDqvyvGs4iQdYVojtrZRUH5SHeg2153NJCehKfTCRcJj2JYhtZnZunAhM6U6JsTdEhB5h6dxH3dg4",
+            "xpub6GzMUbGykK9tAV4LW8nQv4RFefFkSr75D9uX5FUKxy5UgYE16xnXdEc8XCWbqMD6vzQDvf7BDsQ3yvoWS3VPVVTSwpxyncSJxXpdJBfP7bh",
+            "3c24da049451555d51a7014a37337aa4e12d41e485abccfa46b47dfb2af54b7a",
+        ),
+    ];
+
+    #[test]
+    fn from_extended_private_key() {
+        KEYPAIRS.iter().for_each(
+            |(extended_private_key, expected_extended_public_key, expected_public_key)| {
+                let extended_private_key = SolanaExtendedPrivateKey::<N>::from_str(extended_private_key).unwrap();
+                let extended_public_key =
+                    SolanaExtendedPublicKey::<N>::from_extended_private_key(&extended_private_key);
+                assert_eq!(*expected_extended_public_key, extended_public_key.to_string());
+                assert_eq!(*expected_public_key, extended_public_key.to_public_key().to_string());
+            },
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        KEYPAIRS.iter().for_each(|(_, expected_extended_public_key, _)| {
+            let extended_public_key = SolanaExtendedPublicKey::<N>::from_str(expected_extended_public_key).unwrap();
+            assert_eq!(*expected_extended_public_key, extended_public_key.to_string());
+        });
+    }
+
+    #[test]
+    fn derive() {
+        let (_, extended_public_key, _) = KEYPAIRS[0];
+        let extended_public_key = SolanaExtendedPublicKey::<N>::from_str(extended_public_key).unwrap();
+
+        let path = SolanaDerivationPath::<N>::from_str("m").unwrap();
+        assert_eq!(extended_public_key, extended_public_key.derive(&path).unwrap());
+
+        let path = SolanaDerivationPath::<N>::from_str("m/0'").unwrap();
+        assert!(extended_public_key.derive(&path).is_err());
+    }
+
+    #[test]
+    fn test_invalid() {
+        let (extended_private_key, extended_public_key, _) = KEYPAIRS[0];
+
+        assert!(SolanaExtendedPublicKey::<N>::from_str(extended_private_key).is_err());
+        assert!(SolanaExtendedPublicKey::<N>::from_str(&extended_public_key[1..]).is_err());
+        assert!(SolanaExtendedPublicKey::<N>::from_str(&format!("{}a", extended_public_key)).is_err());
+        assert!(SolanaExtendedPublicKey::<N>::from_str(&format!(
+            "{}f",
+            &extended_public_key[..extended_public_key.len() - 1]
+        ))
+        .is_err());
+    }
+}
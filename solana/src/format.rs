@@ -0,0 +1,18 @@
+use wagyu_model::Format;
+
+use core::fmt;
+use serde::Serialize;
+
+/// Represents the format of a Solana address
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SolanaFormat {
+    Standard,
+}
+
+impl Format for SolanaFormat {}
+
+impl fmt::Display for SolanaFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SolanaFormat")
+    }
+}
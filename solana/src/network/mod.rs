@@ -0,0 +1,12 @@
+use wagyu_model::{ChildIndex, Network};
+
+pub mod any_network;
+pub use self::any_network::*;
+
+pub mod mainnet;
+pub use self::mainnet::*;
+
+/// The interface for a Solana network.
+pub trait SolanaNetwork: Network {
+    const HD_COIN_TYPE: ChildIndex;
+}
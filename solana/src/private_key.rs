@@ -0,0 +1,327 @@
+use crate::address::SolanaAddress;
+use crate::format::SolanaFormat;
+use crate::public_key::SolanaPublicKey;
+use wagyu_model::{Address, AddressError, PrivateKey, PrivateKeyError, PublicKey};
+
+use base58::{FromBase58, ToBase58};
+use core::{fmt, fmt::Display, str::FromStr};
+use curve25519_dalek::scalar::Scalar;
+use rand::Rng;
+use sha2::{Digest, Sha512};
+use zeroize::Zeroize;
+
+/// Represents a Solana private key, the 32-byte ed25519 secret seed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolanaPrivateKey([u8; 32]);
+
+impl PrivateKey for SolanaPrivateKey {
+    type Address = SolanaAddress;
+    type Format = SolanaFormat;
+    type PublicKey = SolanaPublicKey;
+
+    /// Returns a randomly-generated Solana private key.
+    fn new<R: Rng>(rng: &mut R) -> Result<Self, PrivateKeyError> {
+        Ok(Self(rng.gen()))
+    }
+
+    /// Returns the public key of the corresponding Solana private key.
+    fn to_public_key(&self) -> Self::PublicKey {
+        SolanaPublicKey::from_private_key(self)
+    }
+
+    /// Returns the address of the corresponding Solana private key.
+    fn to_address(&self, _format: &Self::Format) -> Result<Self::Address, AddressError> {
+        SolanaAddress::from_private_key(self, _format)
+    }
+}
+
+impl SolanaPrivateKey {
+    /// Returns a private key given a 32-byte ed25519 secret seed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PrivateKeyError> {
+        if bytes.len() != 32 {
+            return Err(PrivateKeyError::InvalidByteLength(bytes.len()));
+        }
+
+        let mut secret_key = [0u8; 32];
+        secret_key.copy_from_slice(bytes);
+        Ok(Self(secret_key))
+    }
+
+    /// Returns the 32-byte ed25519 secret seed of the private key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Returns the ed25519 signing scalar, the clamped lower half of the SHA-512 hash of the secret seed.
+    pub fn to_scalar(&self) -> Scalar {
+        let mut hash = Sha512::digest(&self.0);
+        let mut bits = [0u8; 32];
+        bits.copy_from_slice(&hash[0..32]);
+        bits[0] &= 248;
+        bits[31] &= 127;
+        bits[31] |= 64;
+
+        let scalar = Scalar::from_bits(bits);
+        bits.zeroize();
+        hash.as_mut_slice().zeroize();
+        scalar
+    }
+
+    /// Returns a private key given a 64-byte keypair, the secret seed followed by the public key.
+    pub fn from_keypair_bytes(keypair: &[u8]) -> Result<Self, PrivateKeyError> {
+        if keypair.len() != 64 {
+            return Err(PrivateKeyError::InvalidByteLength(keypair.len()));
+        }
+
+        let private_key = Self::from_bytes(&keypair[0..32])?;
+        if private_key.to_public_key().to_bytes()[..] != keypair[32..64] {
+            return Err(PrivateKeyError::Message(
+                "keypair public key does not match the secret key".into(),
+            ));
+        }
+
+        Ok(private_key)
+    }
+
+    /// Returns the 64-byte keypair, the secret seed followed by the public key.
+    pub fn to_keypair_bytes(&self) -> [u8; 64] {
+        let mut keypair = [0u8; 64];
+        keypair[0..32].copy_from_slice(&self.0);
+        keypair[32..64].copy_from_slice(&self.to_public_key().to_bytes());
+        keypair
+    }
+
+    /// Returns a private key given the contents of a `solana-keygen` JSON keypair file.
+    pub fn from_json_keypair(json: &str) -> Result<Self, PrivateKeyError> {
+        let keypair: Vec<u8> =
+            serde_json::from_str(json).map_err(|error| PrivateKeyError::Crate("serde_json", format!("{:?}", error)))?;
+        Self::from_keypair_bytes(&keypair)
+    }
+
+    /// Returns the contents of a `solana-keygen` JSON keypair file, a JSON array of the 64 keypair bytes.
+    pub fn to_json_keypair(&self) -> String {
+        let mut keypair = self.to_keypair_bytes();
+        let json = serde_json::to_string(&keypair[..]).unwrap_or_default();
+        keypair.zeroize();
+        json
+    }
+}
+
+impl FromStr for SolanaPrivateKey {
+    type Err = PrivateKeyError;
+
+    /// Returns the private key given a base58 keypair, as exported by Phantom,
+    /// a `solana-keygen` JSON keypair, or a hexadecimal secret seed.
+    fn from_str(private_key: &str) -> Result<Self, PrivateKeyError> {
+        let private_key = private_key.trim();
+
+        if private_key.starts_with('[') {
+            return Self::from_json_keypair(private_key);
+        }
+
+        if private_key.len() == 64 {
+            return Self::from_bytes(&hex::decode(private_key)?);
+        }
+
+        if private_key.len() < 64 || private_key.len() > 88 {
+            return Err(PrivateKeyError::InvalidCharacterLength(private_key.len()));
+        }
+
+        Self::from_keypair_bytes(&private_key.from_base58()?)
+    }
+}
+
+impl Display for SolanaPrivateKey {
+    /// Writes the base58 encoding of the 64-byte keypair.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut keypair = self.to_keypair_bytes();
+        let result = write!(f, "{}", keypair.to_base58());
+        keypair.zeroize();
+        result
+    }
+}
+
+impl Zeroize for SolanaPrivateKey {
+    /// Overwrites the secret seed.
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Drop for SolanaPrivateKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_to_public_key(expected_public_key: &SolanaPublicKey, private_key: &SolanaPrivateKey) {
+        let public_key = private_key.to_public_key();
+        assert_eq!(*expected_public_key, public_key);
+    }
+
+    fn test_to_address(expected_address: &SolanaAddress, private_key: &SolanaPrivateKey) {
+        let address = private_key.to_address(&SolanaFormat::Standard).unwrap();
+        assert_eq!(*expected_address, address);
+    }
+
+    fn test_from_bytes(
+        expected_private_key: &str,
+        expected_public_key: &str,
+        expected_address: &str,
+        secret_key: &str,
+    ) {
+        let private_key = SolanaPrivateKey::from_bytes(&hex::decode(secret_key).unwrap()).unwrap();
+        assert_eq!(secret_key, hex::encode(private_key.to_bytes()));
+        assert_eq!(expected_private_key, private_key.to_string());
+        assert_eq!(expected_public_key, private_key.to_public_key().to_string());
+        assert_eq!(
+            expected_address,
+            private_key.to_address(&SolanaFormat::Standard).unwrap().to_string()
+        );
+    }
+
+    fn test_from_str(expected_secret_key: &str, expected_public_key: &str, expected_address: &str, private_key: &str) {
+        let private_key = SolanaPrivateKey::from_str(private_key).unwrap();
+        assert_eq!(expected_secret_key, hex::encode(private_key.to_bytes()));
+        assert_eq!(expected_public_key, private_key.to_public_key().to_string());
+        assert_eq!(
+            expected_address,
+            private_key.to_address(&SolanaFormat::Standard).unwrap().to_string()
+        );
+    }
+
+    fn test_to_str(expected_private_key: &str, private_key: &SolanaPrivateKey) {
+        assert_eq!(expected_private_key, private_key.to_string());
+    }
+
+    fn test_json_keypair(private_key: &SolanaPrivateKey) {
+        let json = private_key.to_json_keypair();
+        assert!(json.starts_with('[') && json.ends_with(']'));
+        assert_eq!(64, json[1..json.len() - 1].split(',').count());
+        assert_eq!(*private_key, SolanaPrivateKey::from_json_keypair(&json).unwrap());
+        assert_eq!(*private_key, SolanaPrivateKey::from_str(&json).unwrap());
+    }
+
+    /// Test vectors from https://tools.ietf.org/html/rfc8032#section-7.1
+    mod standard {
+        use super::*;
+
+        // (secret_key, keypair, public_key, address)
+        const KEYPAIRS: [(&str, &str, &str, &str); 5] = [
+            (
+                "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+                "49W385L4rePHy6PAaQUovbD2aacgN4HsKXSMeUzRg4fmwXszN91JuMFrQRj3vMDpZuRF3ZknQBuRBoWQJEfXstMw",
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                "FVen3X669xLzsi6N2V91DoiyzHzg1uAgqiT8jZ9nS96Z",
+            ),
+            (
+                "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+                "2Y4QjyJVZf9tTmTPP1SY9ACpFYTo7brW9iCQ8SunQht5yQ2r1U9KsVv5aMsCGnzj3NR8KG9P3NY7FKBiYbbTJ2no",
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+                "586Z7H2vpX9qNhN2T4e9Utugie3ogjbxzGaMtM3E6HR5",
+            ),
+            (
+                "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+                "4xDTvTsPP83tEE4h6hMxHRsikH4upVGVsK2ChECxED2nMVGMtVtSMvHpo2z3vCpJeUQDPZQJ6wRZAHzSgkhSCrHS",
+                "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+                "Hyx62wPQGyvXCoihZq1BrbUjBRh2LuNxWiiqMkfAuSZr",
+            ),
+            (
+                "f5e5767cf153319517630f226876b86c8160cc583bc013744c6bf255f5cc0ee5",
+                "5v9HRm1hVUhp8W4PprVGMawGbcnbBvDT2pDpYu4CnQaM8NL345qTy5KBWkXUDprYWeVYBzVayn3ePhNQ32xyJ9aD",
+                "278117fc144c72340f67d0f2316e8386ceffbf2b2428c9c51fef7c597f1d426e",
+                "3fD58whN2KJaN9T4r5uE3ELFmzRW1dQNuszrmC6gnhx1",
+            ),
+            (
+                "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42",
+                "3dCUQ9PpvVRK6G5x3swrXCLqujGbc3Nm2DCyBvAXJDWAhNMTVQxADCqVVANg3F9pbtimh2JU3RwFBFNsAzxLqAfG",
+                "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf",
+                "Gtbi6WQDB6wUePiZm8aYs5XZ5pUqx9jMMLvRVHPESTjU",
+            ),
+        ];
+
+        #[test]
+        fn to_public_key() {
+            KEYPAIRS.iter().for_each(|(_, private_key, public_key, _)| {
+                let public_key = SolanaPublicKey::from_str(public_key).unwrap();
+                let private_key = SolanaPrivateKey::from_str(&private_key).unwrap();
+                test_to_public_key(&public_key, &private_key);
+            });
+        }
+
+        #[test]
+        fn to_address() {
+            KEYPAIRS.iter().for_each(|(_, private_key, _, address)| {
+                let address = SolanaAddress::from_str(address).unwrap();
+                let private_key = SolanaPrivateKey::from_str(&private_key).unwrap();
+                test_to_address(&address, &private_key);
+            });
+        }
+
+        #[test]
+        fn from_bytes() {
+            KEYPAIRS
+                .iter()
+                .for_each(|(secret_key, private_key, public_key, address)| {
+                    test_from_bytes(private_key, public_key, address, secret_key);
+                });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS
+                .iter()
+                .for_each(|(secret_key, private_key, public_key, address)| {
+                    test_from_str(secret_key, public_key, address, private_key);
+                    test_from_str(secret_key, public_key, address, secret_key);
+                });
+        }
+
+        #[test]
+        fn to_str() {
+            KEYPAIRS.iter().for_each(|(_, expected_private_key, _, _)| {
+                let private_key = SolanaPrivateKey::from_str(expected_private_key).unwrap();
+                test_to_str(expected_private_key, &private_key);
+            });
+        }
+
+        #[test]
+        fn json_keypair() {
+            KEYPAIRS.iter().for_each(|(_, private_key, _, _)| {
+                let private_key = SolanaPrivateKey::from_str(private_key).unwrap();
+                test_json_keypair(&private_key);
+            });
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        // Invalid keypair length
+
+        let private_key = "49W385L4rePHy6PAaQUovbD2aacgN4HsKXSMeUzRg4fmwXszN91JuMFrQRj3vMDpZuRF3ZknQBuRBoWQJEfXstM";
+        assert!(SolanaPrivateKey::from_str(private_key).is_err());
+
+        let private_key = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f6";
+        assert!(SolanaPrivateKey::from_str(private_key).is_err());
+
+        // Mismatched keypair
+
+        let mut keypair =
+            SolanaPrivateKey::from_str("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+                .unwrap()
+                .to_keypair_bytes();
+        keypair[63] ^= 1;
+        assert!(SolanaPrivateKey::from_keypair_bytes(&keypair).is_err());
+        assert!(SolanaPrivateKey::from_str(&keypair.to_base58()).is_err());
+
+        // Invalid JSON keypair
+
+        assert!(SolanaPrivateKey::from_json_keypair("[1,2,3]").is_err());
+        assert!(SolanaPrivateKey::from_json_keypair("[256]").is_err());
+        assert!(SolanaPrivateKey::from_json_keypair("{}").is_err());
+    }
+}
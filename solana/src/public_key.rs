@@ -0,0 +1,187 @@
+use crate::address::SolanaAddress;
+use crate::format::SolanaFormat;
+use crate::private_key::SolanaPrivateKey;
+use wagyu_model::{Address, AddressError, PublicKey, PublicKeyError};
+
+use core::{fmt, fmt::Display, str::FromStr};
+use curve25519_dalek::{constants::ED25519_BASEPOINT_TABLE, edwards::CompressedEdwardsY};
+
+/// Represents a Solana public key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolanaPublicKey(CompressedEdwardsY);
+
+impl PublicKey for SolanaPublicKey {
+    type Address = SolanaAddress;
+    type Format = SolanaFormat;
+    type PrivateKey = SolanaPrivateKey;
+
+    /// Returns the address corresponding to the given public key.
+    fn from_private_key(private_key: &Self::PrivateKey) -> Self {
+        Self((&private_key.to_scalar() * &ED25519_BASEPOINT_TABLE).compress())
+    }
+
+    /// Returns the address of the corresponding private key.
+    fn to_address(&self, _format: &Self::Format) -> Result<Self::Address, AddressError> {
+        SolanaAddress::from_public_key(self, _format)
+    }
+}
+
+impl SolanaPublicKey {
+    /// Returns a public key given its 32-byte compressed ed25519 encoding.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PublicKeyError> {
+        if bytes.len() != 32 {
+            return Err(PublicKeyError::InvalidByteLength(bytes.len()));
+        }
+
+        let public_key = CompressedEdwardsY::from_slice(bytes);
+        match public_key.decompress() {
+            Some(_) => Ok(Self(public_key)),
+            None => Err(PublicKeyError::Crate(
+                "curve25519-dalek",
+                "invalid ed25519 point".into(),
+            )),
+        }
+    }
+
+    /// Returns the 32-byte compressed ed25519 encoding of the public key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+}
+
+impl FromStr for SolanaPublicKey {
+    type Err = PublicKeyError;
+
+    fn from_str(public_key: &str) -> Result<Self, Self::Err> {
+        if public_key.len() != 64 {
+            return Err(PublicKeyError::InvalidCharacterLength(public_key.len()));
+        }
+
+        Self::from_bytes(&hex::decode(public_key)?)
+    }
+}
+
+impl Display for SolanaPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for s in self.0.as_bytes() {
+            write!(f, "{:02x}", s)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wagyu_model::PrivateKey;
+
+    fn test_from_private_key(expected_public_key: &SolanaPublicKey, private_key: &SolanaPrivateKey) {
+        let public_key = SolanaPublicKey::from_private_key(private_key);
+        assert_eq!(*expected_public_key, public_key);
+    }
+
+    fn test_to_address(expected_address: &SolanaAddress, public_key: &SolanaPublicKey) {
+        let address = public_key.to_address(&SolanaFormat::Standard).unwrap();
+        assert_eq!(*expected_address, address);
+    }
+
+    fn test_from_str(expected_public_key: &str, expected_address: &str) {
+        let public_key = SolanaPublicKey::from_str(expected_public_key).unwrap();
+        let address = public_key.to_address(&SolanaFormat::Standard).unwrap();
+        assert_eq!(expected_public_key, public_key.to_string());
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    fn test_to_str(expected_public_key: &str, public_key: &SolanaPublicKey) {
+        assert_eq!(expected_public_key, public_key.to_string());
+    }
+
+    /// Test vectors from https://tools.ietf.org/html/rfc8032#section-7.1
+    mod standard {
+        use super::*;
+
+        const KEYPAIRS: [(&str, &str, &str); 5] = [
+            (
+                "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                "FVen3X669xLzsi6N2V91DoiyzHzg1uAgqiT8jZ9nS96Z",
+            ),
+            (
+                "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+                "586Z7H2vpX9qNhN2T4e9Utugie3ogjbxzGaMtM3E6HR5",
+            ),
+            (
+                "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+                "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+                "Hyx62wPQGyvXCoihZq1BrbUjBRh2LuNxWiiqMkfAuSZr",
+            ),
+            (
+                "f5e5767cf153319517630f226876b86c8160cc583bc013744c6bf255f5cc0ee5",
+                "278117fc144c72340f67d0f2316e8386ceffbf2b2428c9c51fef7c597f1d426e",
+                "3fD58whN2KJaN9T4r5uE3ELFmzRW1dQNuszrmC6gnhx1",
+            ),
+            (
+                "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42",
+                "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf",
+                "Gtbi6WQDB6wUePiZm8aYs5XZ5pUqx9jMMLvRVHPESTjU",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(private_key, public_key, _)| {
+                let public_key = SolanaPublicKey::from_str(public_key).unwrap();
+                let private_key = SolanaPrivateKey::from_str(&private_key).unwrap();
+                test_from_private_key(&public_key, &private_key);
+            });
+        }
+
+        #[test]
+        fn to_address() {
+            KEYPAIRS.iter().for_each(|(_, public_key, address)| {
+                let address = SolanaAddress::from_str(address).unwrap();
+                let public_key = SolanaPublicKey::from_str(&public_key).unwrap();
+                test_to_address(&address, &public_key);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, expected_public_key, expected_address)| {
+                test_from_str(expected_public_key, expected_address);
+            });
+        }
+
+        #[test]
+        fn to_str() {
+            KEYPAIRS.iter().for_each(|(_, expected_public_key, _)| {
+                let public_key = SolanaPublicKey::from_str(expected_public_key).unwrap();
+                test_to_str(expected_public_key, &public_key);
+            });
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        let valid_private_key = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+        let valid_public_key = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+
+        // Mismatched keypair
+
+        let invalid_public_key = "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c";
+        let private_key = SolanaPrivateKey::from_str(valid_private_key).unwrap();
+        let public_key = SolanaPublicKey::from_str(invalid_public_key).unwrap();
+        assert_ne!(public_key, private_key.to_public_key());
+
+        // Invalid public key length
+
+        assert!(SolanaPublicKey::from_str(&valid_public_key[0..62]).is_err());
+        assert!(SolanaPublicKey::from_str(&format!("{}00", valid_public_key)).is_err());
+
+        // Invalid ed25519 point
+
+        let invalid_public_key = "0200000000000000000000000000000000000000000000000000000000000000";
+        assert!(SolanaPublicKey::from_str(invalid_public_key).is_err());
+    }
+}
@@ -0,0 +1,38 @@
+//! # Tests
+//!
+//! Property-based tests and cross-implementation test vectors for wagyu.
+//!
+//! The `strategies` module generates arbitrary keys, addresses, mnemonics, and transactions for
+//! proptest, and the `vectors` module loads test vector files published by other implementations.
+
+#![warn(unused_extern_crates, dead_code)]
+#![forbid(unsafe_code)]
+
+#[macro_use]
+extern crate failure;
+
+pub mod strategies;
+pub use self::strategies::*;
+
+pub mod vectors;
+pub use self::vectors::*;
+
+use core::{fmt::Debug, fmt::Display, str::FromStr};
+use proptest::test_runner::TestCaseError;
+
+/// Returns an error if the given value is not equal to the value parsed from its string.
+pub fn round_trip<T>(value: &T) -> Result<(), TestCaseError>
+where
+    T: Debug + Display + FromStr + PartialEq,
+    <T as FromStr>::Err: Debug,
+{
+    let string = value.to_string();
+    match T::from_str(&string) {
+        Ok(parsed) if parsed == *value => Ok(()),
+        Ok(parsed) => Err(TestCaseError::fail(format!(
+            "{} parsed to {:?}, expected {:?}",
+            string, parsed, value
+        ))),
+        Err(error) => Err(TestCaseError::fail(format!("{} failed to parse: {:?}", string, error))),
+    }
+}
@@ -0,0 +1,218 @@
+use wagyu_bitcoin::{
+    BitcoinDerivationPath, BitcoinExtendedPrivateKey, BitcoinFormat, BitcoinMnemonic, BitcoinNetwork,
+    BitcoinPrivateKey, BitcoinWordlist,
+};
+use wagyu_ethereum::{
+    EthereumAccessListItem, EthereumAddress, EthereumAmount, EthereumFormat, EthereumPrivateKey,
+    EthereumTransactionParameters, EthereumTransactionType,
+};
+use wagyu_model::{ChildIndex, ExtendedPrivateKey, MnemonicCount, PrivateKey};
+use wagyu_monero::{MoneroFormat, MoneroNetwork, MoneroPrivateKey};
+
+use core::convert::TryFrom;
+use ethereum_types::U256;
+use proptest::{collection::vec, prelude::*};
+use rand::{rngs::StdRng, SeedableRng};
+
+/// Returns a strategy of seeded random number generators, for constructors that take a `Rng`.
+pub fn rng() -> impl Strategy<Value = StdRng> {
+    any::<[u8; 32]>().prop_map(StdRng::from_seed)
+}
+
+/// Returns a strategy of seeds for extended private keys, of 16 to 64 bytes as in BIP-32.
+pub fn seed() -> impl Strategy<Value = Vec<u8>> {
+    vec(any::<u8>(), 16..=64)
+}
+
+/// Returns a strategy of normal and hardened child indices.
+pub fn child_index() -> impl Strategy<Value = ChildIndex> {
+    prop_oneof![
+        (0..(1u32 << 31)).prop_map(ChildIndex::Normal),
+        (0..(1u32 << 31)).prop_map(ChildIndex::Hardened),
+    ]
+}
+
+/// Returns a strategy of secp256k1 secret keys.
+pub fn secp256k1_secret_key() -> impl Strategy<Value = secp256k1::SecretKey> {
+    any::<[u8; 32]>().prop_filter_map("invalid secp256k1 secret key", |bytes| {
+        secp256k1::SecretKey::parse(&bytes).ok()
+    })
+}
+
+/// Returns a strategy of compressed and uncompressed Bitcoin private keys.
+pub fn bitcoin_private_key<N: BitcoinNetwork>() -> impl Strategy<Value = BitcoinPrivateKey<N>> {
+    (secp256k1_secret_key(), any::<bool>())
+        .prop_map(|(secret_key, compressed)| BitcoinPrivateKey::from_secp256k1_secret_key(&secret_key, compressed))
+}
+
+/// Returns a strategy of the Bitcoin address formats derived from a single public key.
+pub fn bitcoin_format() -> impl Strategy<Value = BitcoinFormat> {
+    prop_oneof![
+        Just(BitcoinFormat::P2PKH),
+        Just(BitcoinFormat::P2SH_P2WPKH),
+        Just(BitcoinFormat::Bech32),
+    ]
+}
+
+/// Returns a strategy of Bitcoin extended private keys, derived from a random seed along a path of up to 4 indices.
+pub fn bitcoin_extended_private_key<N: BitcoinNetwork>() -> impl Strategy<Value = BitcoinExtendedPrivateKey<N>> {
+    (seed(), bitcoin_format(), vec(child_index(), 0..=4)).prop_filter_map(
+        "invalid extended private key",
+        |(seed, format, path)| {
+            let path = BitcoinDerivationPath::<N>::try_from(path).ok()?;
+            BitcoinExtendedPrivateKey::new(&seed, &format, &path).ok()
+        },
+    )
+}
+
+/// Returns a strategy of Bitcoin mnemonics of every word count.
+pub fn bitcoin_mnemonic<N: BitcoinNetwork, W: BitcoinWordlist>() -> impl Strategy<Value = BitcoinMnemonic<N, W>> {
+    (rng(), prop_oneof![Just(12u8), Just(15), Just(18), Just(21), Just(24)]).prop_map(|(mut rng, word_count)| {
+        BitcoinMnemonic::new_with_count(&mut rng, word_count).expect("valid mnemonic word count")
+    })
+}
+
+/// Returns a strategy of Ethereum private keys.
+pub fn ethereum_private_key() -> impl Strategy<Value = EthereumPrivateKey> {
+    secp256k1_secret_key().prop_map(|secret_key| EthereumPrivateKey::from_secp256k1_secret_key(&secret_key))
+}
+
+/// Returns a strategy of Ethereum addresses.
+pub fn ethereum_address() -> impl Strategy<Value = EthereumAddress> {
+    ethereum_private_key().prop_map(|private_key| {
+        private_key
+            .to_address(&EthereumFormat::Standard)
+            .expect("valid ethereum address")
+    })
+}
+
+/// Returns a strategy of 256-bit unsigned integers, biased toward the small values of gas, nonces, and amounts.
+pub fn u256() -> impl Strategy<Value = U256> {
+    prop_oneof![
+        Just(U256::zero()),
+        any::<u64>().prop_map(U256::from),
+        any::<[u8; 32]>().prop_map(|bytes| U256::from(&bytes[..])),
+    ]
+}
+
+/// Returns a strategy of legacy, unprotected, and access list Ethereum transaction types.
+pub fn ethereum_transaction_type() -> impl Strategy<Value = EthereumTransactionType> {
+    let item = (ethereum_address(), vec(any::<[u8; 32]>(), 0..3))
+        .prop_map(|(address, storage_keys)| EthereumAccessListItem { address, storage_keys });
+    prop_oneof![
+        Just(EthereumTransactionType::Legacy),
+        Just(EthereumTransactionType::Unprotected),
+        vec(item, 0..3).prop_map(EthereumTransactionType::AccessList),
+    ]
+}
+
+/// Returns a strategy of Ethereum transaction parameters.
+pub fn ethereum_transaction_parameters() -> impl Strategy<Value = EthereumTransactionParameters> {
+    (
+        ethereum_address(),
+        u256(),
+        u256(),
+        u256(),
+        u256(),
+        vec(any::<u8>(), 0..128),
+        ethereum_transaction_type(),
+    )
+        .prop_map(|(receiver, amount, gas, gas_price, nonce, data, transaction_type)| {
+            EthereumTransactionParameters {
+                receiver,
+                amount: EthereumAmount::from_u256(amount),
+                gas,
+                gas_price: EthereumAmount::from_u256(gas_price),
+                nonce,
+                data,
+                transaction_type,
+            }
+        })
+}
+
+/// Returns a strategy of Monero private keys of standard addresses.
+pub fn monero_private_key<N: MoneroNetwork>() -> impl Strategy<Value = MoneroPrivateKey<N>> {
+    any::<[u8; 32]>().prop_map(|seed| {
+        MoneroPrivateKey::from_seed(&hex::encode(seed), &MoneroFormat::Standard).expect("valid monero seed")
+    })
+}
+
+/// Represents an edit of a string or byte sequence, by position modulo its length
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mutation {
+    /// Removes the element at the position
+    Delete(usize),
+    /// Inserts the byte, or an alphanumeric character, at the position
+    Insert(usize, u8),
+    /// Replaces the element at the position with the byte, or an alphanumeric character
+    Replace(usize, u8),
+    /// Removes every element from the position
+    Truncate(usize),
+}
+
+impl Mutation {
+    /// Returns the given bytes with the mutation applied.
+    pub fn apply(&self, bytes: &[u8]) -> Vec<u8> {
+        let mut bytes = bytes.to_vec();
+        let position = |index: usize, length: usize| match length {
+            0 => 0,
+            length => index % length,
+        };
+        match *self {
+            Mutation::Delete(index) if !bytes.is_empty() => {
+                bytes.remove(position(index, bytes.len()));
+            }
+            Mutation::Insert(index, byte) => bytes.insert(position(index, bytes.len() + 1), byte),
+            Mutation::Replace(index, byte) if !bytes.is_empty() => {
+                let index = position(index, bytes.len());
+                bytes[index] = byte;
+            }
+            Mutation::Truncate(index) => bytes.truncate(position(index, bytes.len() + 1)),
+            _ => {}
+        };
+        bytes
+    }
+
+    /// Returns the given string with the mutation applied, inserting alphanumeric characters.
+    pub fn apply_str(&self, string: &str) -> String {
+        const ALPHANUMERIC: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+        let mutation = match *self {
+            Mutation::Insert(index, byte) => Mutation::Insert(index, ALPHANUMERIC[byte as usize % ALPHANUMERIC.len()]),
+            Mutation::Replace(index, byte) => {
+                Mutation::Replace(index, ALPHANUMERIC[byte as usize % ALPHANUMERIC.len()])
+            }
+            mutation => mutation,
+        };
+        // Strings are ASCII, so every edit of a byte is an edit of a character
+        String::from_utf8_lossy(&mutation.apply(string.as_bytes())).into_owned()
+    }
+}
+
+/// Returns a strategy of mutations.
+pub fn mutation() -> impl Strategy<Value = Mutation> {
+    prop_oneof![
+        any::<usize>().prop_map(Mutation::Delete),
+        (any::<usize>(), any::<u8>()).prop_map(|(index, byte)| Mutation::Insert(index, byte)),
+        (any::<usize>(), any::<u8>()).prop_map(|(index, byte)| Mutation::Replace(index, byte)),
+        any::<usize>().prop_map(Mutation::Truncate),
+    ]
+}
+
+/// Returns a strategy of up to 3 mutations.
+pub fn mutations() -> impl Strategy<Value = Vec<Mutation>> {
+    vec(mutation(), 1..=3)
+}
+
+/// Returns the given string with the mutations applied.
+pub fn mutate_str(string: &str, mutations: &[Mutation]) -> String {
+    mutations
+        .iter()
+        .fold(string.to_string(), |string, mutation| mutation.apply_str(&string))
+}
+
+/// Returns the given bytes with the mutations applied.
+pub fn mutate(bytes: &[u8], mutations: &[Mutation]) -> Vec<u8> {
+    mutations
+        .iter()
+        .fold(bytes.to_vec(), |bytes, mutation| mutation.apply(&bytes))
+}
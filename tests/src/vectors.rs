@@ -0,0 +1,166 @@
+use serde::{de::DeserializeOwned, Deserialize};
+use std::{collections::BTreeMap, fs, path::Path};
+
+/// The directory of the test vector files of this crate
+pub const VECTORS_DIRECTORY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/vectors");
+
+/// The passphrase of the BIP-39 test vectors of the Trezor reference implementation
+pub const BIP39_PASSPHRASE: &str = "TREZOR";
+
+#[derive(Debug, Fail)]
+pub enum VectorError {
+    #[fail(display = "{}: {}", _0, _1)]
+    Crate(&'static str, String),
+
+    #[fail(display = "invalid test vector: {}", _0)]
+    InvalidVector(String),
+}
+
+impl From<std::io::Error> for VectorError {
+    fn from(error: std::io::Error) -> Self {
+        VectorError::Crate("std::io", format!("{:?}", error))
+    }
+}
+
+impl From<serde_json::Error> for VectorError {
+    fn from(error: serde_json::Error) -> Self {
+        VectorError::Crate("serde_json", format!("{:?}", error))
+    }
+}
+
+/// Represents a BIP-32 test vector, a seed and the extended keys of its derivation chains.
+/// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#test-vectors
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Bip32Vector {
+    /// The seed in hexadecimal
+    pub seed: String,
+    /// The extended keys at each derivation path
+    pub chains: Vec<Bip32Chain>,
+}
+
+/// Represents the extended keys of a BIP-32 test vector at a derivation path
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Bip32Chain {
+    /// The derivation path, e.g. m/0'/1
+    pub path: String,
+    /// The extended public key
+    pub extended_public_key: String,
+    /// The extended private key
+    pub extended_private_key: String,
+}
+
+/// Represents a BIP-39 test vector, in the format of the Trezor reference implementation,
+/// where every seed and extended private key is derived with the passphrase "TREZOR".
+/// https://github.com/trezor/python-mnemonic/blob/master/vectors.json
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "(String, String, String, String)")]
+pub struct Bip39Vector {
+    /// The entropy in hexadecimal
+    pub entropy: String,
+    /// The mnemonic phrase
+    pub mnemonic: String,
+    /// The seed in hexadecimal
+    pub seed: String,
+    /// The extended private key of the seed
+    pub extended_private_key: String,
+}
+
+impl From<(String, String, String, String)> for Bip39Vector {
+    fn from((entropy, mnemonic, seed, extended_private_key): (String, String, String, String)) -> Self {
+        Self {
+            entropy,
+            mnemonic,
+            seed,
+            extended_private_key,
+        }
+    }
+}
+
+/// Represents a BIP-44 test vector, the address of a mnemonic at a derivation path.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Bip44Vector {
+    /// The currency of the address, e.g. bitcoin
+    pub currency: String,
+    /// The mnemonic phrase
+    pub mnemonic: String,
+    /// The passphrase of the mnemonic, if any
+    pub passphrase: Option<String>,
+    /// The derivation path, e.g. m/44'/0'/0'/0/0
+    pub path: String,
+    /// The address
+    pub address: String,
+}
+
+/// Represents an EIP-155 test vector, a transaction signed with replay protection.
+/// https://github.com/ethereum/EIPs/blob/master/EIPS/eip-155.md
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Eip155Vector {
+    /// The chain id of the network
+    pub chain_id: u32,
+    /// The private key of the sender in hexadecimal
+    pub private_key: String,
+    /// The nonce in decimal
+    pub nonce: String,
+    /// The gas price in wei, in decimal
+    pub gas_price: String,
+    /// The gas limit in decimal
+    pub gas: String,
+    /// The address of the receiver
+    pub receiver: String,
+    /// The amount in wei, in decimal
+    pub amount: String,
+    /// The data in hexadecimal
+    pub data: String,
+    /// The signed transaction in hexadecimal
+    pub signed_transaction: String,
+}
+
+/// Represents a Monero test vector, the standard address of a seed.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct MoneroVector {
+    /// The network of the address, one of mainnet, testnet, or stagenet
+    pub network: String,
+    /// The seed in hexadecimal, reduced to the private spend key
+    pub seed: String,
+    /// The standard address
+    pub address: String,
+}
+
+/// Returns the test vectors of the given JSON file.
+pub fn load<T: DeserializeOwned, P: AsRef<Path>>(path: P) -> Result<T, VectorError> {
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+/// Returns the BIP-32 test vectors of the given JSON file.
+pub fn load_bip32<P: AsRef<Path>>(path: P) -> Result<Vec<Bip32Vector>, VectorError> {
+    load(path)
+}
+
+/// Returns the BIP-39 test vectors of the given JSON file, by language.
+pub fn load_bip39<P: AsRef<Path>>(path: P) -> Result<BTreeMap<String, Vec<Bip39Vector>>, VectorError> {
+    let vectors: BTreeMap<String, Vec<Bip39Vector>> = load(path)?;
+    match vectors.iter().find(|(_, vectors)| vectors.is_empty()) {
+        Some((language, _)) => Err(VectorError::InvalidVector(format!("no vectors for {}", language))),
+        None => Ok(vectors),
+    }
+}
+
+/// Returns the BIP-44 test vectors of the given JSON file.
+pub fn load_bip44<P: AsRef<Path>>(path: P) -> Result<Vec<Bip44Vector>, VectorError> {
+    load(path)
+}
+
+/// Returns the EIP-155 test vectors of the given JSON file.
+pub fn load_eip155<P: AsRef<Path>>(path: P) -> Result<Vec<Eip155Vector>, VectorError> {
+    load(path)
+}
+
+/// Returns the Monero test vectors of the given JSON file.
+pub fn load_monero<P: AsRef<Path>>(path: P) -> Result<Vec<MoneroVector>, VectorError> {
+    load(path)
+}
+
+/// Returns the path of the given test vector file of this crate.
+pub fn vector_file(name: &str) -> std::path::PathBuf {
+    Path::new(VECTORS_DIRECTORY).join(name)
+}
@@ -0,0 +1,144 @@
+//! Parsers of untrusted input must return errors instead of panicking,
+//! for arbitrary input and for valid input with a few edits.
+
+use wagyu_bitcoin::{
+    BitcoinAddress, BitcoinExtendedPrivateKey, BitcoinExtendedPublicKey, BitcoinPrivateKey, BitcoinTransaction, Mainnet,
+};
+use wagyu_bitcoincash::{BitcoinCashAddress, BitcoinCashFormat, BitcoinCashPrivateKey, Mainnet as BitcoinCashMainnet};
+use wagyu_ethereum::{
+    EthereumAddress, EthereumExtendedPrivateKey, EthereumExtendedPublicKey, EthereumFormat, EthereumPrivateKey,
+    EthereumTransaction, Mainnet as EthereumMainnet,
+};
+use wagyu_model::{
+    encoding::{base58check, bech32_decode, BECH32_MAX_LENGTH},
+    ExtendedPrivateKey, PrivateKey, Transaction,
+};
+use wagyu_monero::{Mainnet as MoneroMainnet, MoneroAddress, MoneroFormat};
+use wagyu_tests::*;
+use wagyu_zcash::{Mainnet as ZcashMainnet, ZcashAddress, ZcashFormat, ZcashPrivateKey};
+
+use core::str::FromStr;
+use proptest::{collection::vec, prelude::*};
+
+/// A signed Bitcoin transaction with a segwit input
+const BITCOIN_TRANSACTION: &str = "01000000000101db6b1b20aa0fd7b23880be2ecbd4a98130974cf4748fb66092ac4d3ceb1a5477010000001716001479091972186c449eb1ded22b78e40d009bdf0089feffffff02b8b4eb0b000000001976a914a457b684d7f0d539a46a45bbc043f35b59d0d96388ac0008af2f000000001976a914fd270b1ee6abcaea97fea7ad0402e8bd8ad6d77c88ac02473044022047ac8e878352d3ebbde1c94ce3a10d057c24175747116f8288e5d794d12d482f0220217f36a485cae903c713331d877c1f64677e3622ad4010726870540656fe9dcb012103ad1d8e89212f0b92c74d23bb710c00662ad1470198ac48c43f7d6f93a2a2687392040000";
+
+/// A Zcash Sapling address
+const ZCASH_SAPLING_ADDRESS: &str = "zs1dq9dlh6u6hna0u96aqtynxt3acddtgkgdx4re65500nmc2aze0my65ky36vaqvj4hkc9ut66eyf";
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(256))]
+
+    #[test]
+    fn bitcoin_address(private_key in bitcoin_private_key::<Mainnet>(), format in bitcoin_format(), mutations in mutations()) {
+        let address = private_key.to_address(&format).unwrap().to_string();
+        let _ = BitcoinAddress::<Mainnet>::from_str(&mutate_str(&address, &mutations));
+    }
+
+    #[test]
+    fn bitcoin_private_key_wif(private_key in bitcoin_private_key::<Mainnet>(), mutations in mutations()) {
+        let _ = BitcoinPrivateKey::<Mainnet>::from_str(&mutate_str(&private_key.to_string(), &mutations));
+    }
+
+    #[test]
+    fn bitcoin_extended_keys(extended_private_key in bitcoin_extended_private_key::<Mainnet>(), mutations in mutations()) {
+        let extended_public_key = extended_private_key.to_extended_public_key();
+        let _ = BitcoinExtendedPrivateKey::<Mainnet>::from_str(&mutate_str(&extended_private_key.to_string(), &mutations));
+        let _ = BitcoinExtendedPublicKey::<Mainnet>::from_str(&mutate_str(&extended_public_key.to_string(), &mutations));
+    }
+
+    #[test]
+    fn bitcoin_transaction(bytes in vec(any::<u8>(), 0..256), mutations in mutations()) {
+        let _ = BitcoinTransaction::<Mainnet>::from_transaction_bytes(&bytes);
+        let transaction = mutate(&hex::decode(BITCOIN_TRANSACTION).unwrap(), &mutations);
+        let _ = BitcoinTransaction::<Mainnet>::from_transaction_bytes(&transaction);
+    }
+
+    #[test]
+    fn bitcoin_cash_address(secret_key in secp256k1_secret_key(), mutations in mutations()) {
+        let private_key = BitcoinCashPrivateKey::<BitcoinCashMainnet>::from_secp256k1_secret_key(&secret_key, true);
+        for format in &[BitcoinCashFormat::P2PKH, BitcoinCashFormat::CashAddr] {
+            let address = private_key.to_address(format).unwrap().to_string();
+            let _ = BitcoinCashAddress::<BitcoinCashMainnet>::from_str(&mutate_str(&address, &mutations));
+        }
+    }
+
+    #[test]
+    fn ethereum_address(string in "(0x)?[0-9a-fA-F]{0,42}") {
+        let _ = EthereumAddress::from_str(&string);
+    }
+
+    #[test]
+    fn ethereum_private_key_hex(string in "[0-9a-fA-F]{0,66}") {
+        let _ = EthereumPrivateKey::from_str(&string);
+    }
+
+    #[test]
+    fn ethereum_extended_keys(seed in seed(), mutations in mutations()) {
+        let extended_private_key = EthereumExtendedPrivateKey::<EthereumMainnet>::new_master(&seed, &EthereumFormat::Standard).unwrap();
+        let extended_public_key = extended_private_key.to_extended_public_key();
+        let _ = EthereumExtendedPrivateKey::<EthereumMainnet>::from_str(&mutate_str(&extended_private_key.to_string(), &mutations));
+        let _ = EthereumExtendedPublicKey::<EthereumMainnet>::from_str(&mutate_str(&extended_public_key.to_string(), &mutations));
+    }
+
+    #[test]
+    fn ethereum_transaction(
+        parameters in ethereum_transaction_parameters(),
+        private_key in ethereum_private_key(),
+        mutations in mutations(),
+        bytes in vec(any::<u8>(), 0..256)
+    ) {
+        let _ = EthereumTransaction::<EthereumMainnet>::from_transaction_bytes(&bytes);
+        let transaction = EthereumTransaction::<EthereumMainnet>::new(&parameters).unwrap().sign(&private_key).unwrap();
+        let bytes = mutate(&transaction.to_transaction_bytes().unwrap(), &mutations);
+        let _ = EthereumTransaction::<EthereumMainnet>::from_transaction_bytes(&bytes);
+    }
+
+    #[test]
+    fn monero_address(private_key in monero_private_key::<MoneroMainnet>(), mutations in mutations()) {
+        let address = private_key.to_address(&MoneroFormat::Standard).unwrap().to_string();
+        let _ = MoneroAddress::<MoneroMainnet>::from_str(&mutate_str(&address, &mutations));
+    }
+
+    #[test]
+    fn zcash_address(secret_key in secp256k1_secret_key(), mutations in mutations()) {
+        let private_key = ZcashPrivateKey::<ZcashMainnet>::from_str(
+            &BitcoinPrivateKey::<Mainnet>::from_secp256k1_secret_key(&secret_key, true).to_string(),
+        );
+        if let Ok(private_key) = private_key {
+            let address = private_key.to_address(&ZcashFormat::P2PKH).unwrap().to_string();
+            let _ = ZcashAddress::<ZcashMainnet>::from_str(&mutate_str(&address, &mutations));
+        }
+        let _ = ZcashAddress::<ZcashMainnet>::from_str(&mutate_str(ZCASH_SAPLING_ADDRESS, &mutations));
+    }
+
+    #[test]
+    fn zcash_private_key(string in "[0-9A-Za-z-]{0,120}") {
+        let _ = ZcashPrivateKey::<ZcashMainnet>::from_str(&string);
+    }
+
+    #[test]
+    fn base58check_decode(string in "[1-9A-HJ-NP-Za-km-z]{0,120}") {
+        let _ = base58check::decode(&string);
+    }
+
+    #[test]
+    fn bech32(string in "[0-9a-zA-Z]{0,12}1[qpzry9x8gf2tvdw0s3jn54khce6mua7l]{0,100}") {
+        let _ = bech32_decode(&string, BECH32_MAX_LENGTH);
+    }
+
+    #[test]
+    fn arbitrary_strings(string in any::<String>()) {
+        let _ = BitcoinAddress::<Mainnet>::from_str(&string);
+        let _ = BitcoinPrivateKey::<Mainnet>::from_str(&string);
+        let _ = BitcoinExtendedPublicKey::<Mainnet>::from_str(&string);
+        let _ = BitcoinCashAddress::<BitcoinCashMainnet>::from_str(&string);
+        let _ = EthereumAddress::from_str(&string);
+        let _ = EthereumExtendedPublicKey::<EthereumMainnet>::from_str(&string);
+        let _ = MoneroAddress::<MoneroMainnet>::from_str(&string);
+        let _ = ZcashAddress::<ZcashMainnet>::from_str(&string);
+        let _ = ZcashPrivateKey::<ZcashMainnet>::from_str(&string);
+        let _ = base58check::decode(&string);
+        let _ = bech32_decode(&string, BECH32_MAX_LENGTH);
+    }
+}
@@ -0,0 +1,89 @@
+use wagyu_bitcoin::{
+    BitcoinAddress, BitcoinExtendedPublicKey, ChineseSimplified, English, French, Japanese, Mainnet, Spanish, Testnet,
+};
+use wagyu_ethereum::{EthereumFormat, EthereumTransaction, Mainnet as EthereumMainnet};
+use wagyu_model::{ExtendedPrivateKey, PrivateKey, Transaction};
+use wagyu_monero::{Mainnet as MoneroMainnet, MoneroAddress, MoneroFormat};
+use wagyu_tests::*;
+
+use core::str::FromStr;
+use proptest::prelude::*;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn bitcoin_wif(private_key in bitcoin_private_key::<Mainnet>()) {
+        round_trip(&private_key)?;
+    }
+
+    #[test]
+    fn bitcoin_wif_testnet(private_key in bitcoin_private_key::<Testnet>()) {
+        round_trip(&private_key)?;
+    }
+
+    #[test]
+    fn bitcoin_address(private_key in bitcoin_private_key::<Mainnet>(), format in bitcoin_format()) {
+        let address = private_key.to_address(&format).unwrap();
+        round_trip(&address)?;
+        prop_assert_eq!(format, BitcoinAddress::<Mainnet>::from_str(&address.to_string()).unwrap().format());
+    }
+
+    #[test]
+    fn bitcoin_extended_keys(extended_private_key in bitcoin_extended_private_key::<Mainnet>()) {
+        round_trip(&extended_private_key)?;
+        let extended_public_key: BitcoinExtendedPublicKey<Mainnet> = extended_private_key.to_extended_public_key();
+        round_trip(&extended_public_key)?;
+    }
+
+    #[test]
+    fn bitcoin_mnemonic_english(mnemonic in bitcoin_mnemonic::<Mainnet, English>()) {
+        round_trip(&mnemonic)?;
+    }
+
+    #[test]
+    fn bitcoin_mnemonic_chinese_simplified(mnemonic in bitcoin_mnemonic::<Mainnet, ChineseSimplified>()) {
+        round_trip(&mnemonic)?;
+    }
+
+    #[test]
+    fn bitcoin_mnemonic_french(mnemonic in bitcoin_mnemonic::<Mainnet, French>()) {
+        round_trip(&mnemonic)?;
+    }
+
+    #[test]
+    fn bitcoin_mnemonic_japanese(mnemonic in bitcoin_mnemonic::<Mainnet, Japanese>()) {
+        round_trip(&mnemonic)?;
+    }
+
+    #[test]
+    fn bitcoin_mnemonic_spanish(mnemonic in bitcoin_mnemonic::<Mainnet, Spanish>()) {
+        round_trip(&mnemonic)?;
+    }
+
+    #[test]
+    fn ethereum_private_key_and_address(private_key in ethereum_private_key()) {
+        round_trip(&private_key)?;
+        round_trip(&private_key.to_address(&EthereumFormat::Standard).unwrap())?;
+    }
+
+    #[test]
+    fn ethereum_transaction(
+        parameters in ethereum_transaction_parameters(),
+        private_key in ethereum_private_key(),
+    ) {
+        let transaction = EthereumTransaction::<EthereumMainnet>::new(&parameters).unwrap();
+        let bytes = transaction.to_transaction_bytes().unwrap();
+        prop_assert_eq!(&transaction, &EthereumTransaction::from_transaction_bytes(&bytes).unwrap());
+
+        let signed = transaction.sign(&private_key).unwrap();
+        let bytes = signed.to_transaction_bytes().unwrap();
+        prop_assert_eq!(&signed, &EthereumTransaction::from_transaction_bytes(&bytes).unwrap());
+    }
+
+    #[test]
+    fn monero_address(private_key in monero_private_key::<MoneroMainnet>()) {
+        let address: MoneroAddress<MoneroMainnet> = private_key.to_address(&MoneroFormat::Standard).unwrap();
+        round_trip(&address)?;
+    }
+}
@@ -0,0 +1,131 @@
+use wagyu_bitcoin::{
+    BitcoinAddress, BitcoinDerivationPath, BitcoinExtendedPrivateKey, BitcoinFormat, BitcoinMnemonic, English, Mainnet,
+};
+use wagyu_ethereum::{
+    English as EthereumEnglish, EthereumAddress, EthereumAmount, EthereumDerivationPath, EthereumMnemonic,
+    EthereumPrivateKey, EthereumTransaction, EthereumTransactionParameters, EthereumTransactionType,
+    Mainnet as EthereumMainnet,
+};
+use wagyu_model::{ExtendedPrivateKey, Mnemonic, MnemonicExtended, PrivateKey, Transaction};
+use wagyu_monero::{
+    Mainnet as MoneroMainnet, MoneroFormat, MoneroNetwork, MoneroPrivateKey, Stagenet as MoneroStagenet,
+    Testnet as MoneroTestnet,
+};
+use wagyu_tests::*;
+
+use core::str::FromStr;
+use ethereum_types::U256;
+
+#[test]
+fn bip32() {
+    for vector in load_bip32(vector_file("bip32.json")).unwrap() {
+        let seed = hex::decode(&vector.seed).unwrap();
+        for chain in vector.chains {
+            let path = BitcoinDerivationPath::<Mainnet>::from_str(&chain.path).unwrap();
+            let extended_private_key =
+                BitcoinExtendedPrivateKey::<Mainnet>::new(&seed, &BitcoinFormat::P2PKH, &path).unwrap();
+            assert_eq!(chain.extended_private_key, extended_private_key.to_string());
+            assert_eq!(
+                chain.extended_public_key,
+                extended_private_key.to_extended_public_key().to_string()
+            );
+        }
+    }
+}
+
+#[test]
+fn bip39() {
+    let vectors = load_bip39(vector_file("bip39.json")).unwrap();
+    for vector in &vectors["english"] {
+        let mnemonic = BitcoinMnemonic::<Mainnet, English>::from_phrase(&vector.mnemonic).unwrap();
+        assert_eq!(vector.mnemonic, mnemonic.to_phrase().unwrap());
+        assert_eq!(
+            vector.seed,
+            hex::encode(mnemonic.to_seed(Some(BIP39_PASSPHRASE)).unwrap())
+        );
+        assert_eq!(
+            vector.extended_private_key,
+            mnemonic
+                .to_extended_private_key(Some(BIP39_PASSPHRASE))
+                .unwrap()
+                .to_string()
+        );
+    }
+}
+
+#[test]
+fn bip44() {
+    for vector in load_bip44(vector_file("bip44.json")).unwrap() {
+        let passphrase = vector.passphrase.as_deref();
+        match vector.currency.as_str() {
+            "bitcoin" => {
+                let mnemonic = BitcoinMnemonic::<Mainnet, English>::from_phrase(&vector.mnemonic).unwrap();
+                let path = BitcoinDerivationPath::<Mainnet>::from_str(&vector.path).unwrap();
+                let extended_private_key = mnemonic.to_extended_private_key(passphrase).unwrap();
+                let address: BitcoinAddress<Mainnet> = extended_private_key
+                    .derive(&path)
+                    .unwrap()
+                    .to_address(&BitcoinFormat::P2PKH)
+                    .unwrap();
+                assert_eq!(vector.address, address.to_string());
+            }
+            "ethereum" => {
+                let mnemonic =
+                    EthereumMnemonic::<EthereumMainnet, EthereumEnglish>::from_phrase(&vector.mnemonic).unwrap();
+                let path = EthereumDerivationPath::<EthereumMainnet>::from_str(&vector.path).unwrap();
+                let extended_private_key = mnemonic.to_extended_private_key(passphrase).unwrap();
+                let address: EthereumAddress = extended_private_key
+                    .derive(&path)
+                    .unwrap()
+                    .to_address(&wagyu_ethereum::EthereumFormat::Standard)
+                    .unwrap();
+                assert_eq!(vector.address, address.to_string());
+            }
+            currency => panic!("unsupported currency {}", currency),
+        }
+    }
+}
+
+#[test]
+fn eip155() {
+    for vector in load_eip155(vector_file("eip155.json")).unwrap() {
+        assert_eq!(1, vector.chain_id, "only mainnet vectors are supported");
+
+        let private_key = EthereumPrivateKey::from_str(&vector.private_key).unwrap();
+        let parameters = EthereumTransactionParameters {
+            receiver: EthereumAddress::from_str(&vector.receiver).unwrap(),
+            amount: EthereumAmount::from_wei(&vector.amount).unwrap(),
+            gas: U256::from_dec_str(&vector.gas).unwrap(),
+            gas_price: EthereumAmount::from_wei(&vector.gas_price).unwrap(),
+            nonce: U256::from_dec_str(&vector.nonce).unwrap(),
+            data: hex::decode(&vector.data).unwrap(),
+            transaction_type: EthereumTransactionType::Legacy,
+        };
+        let transaction = EthereumTransaction::<EthereumMainnet>::new(&parameters)
+            .unwrap()
+            .sign(&private_key)
+            .unwrap();
+        assert_eq!(
+            vector.signed_transaction,
+            hex::encode(transaction.to_transaction_bytes().unwrap())
+        );
+    }
+}
+
+#[test]
+fn monero() {
+    fn address<N: MoneroNetwork>(seed: &str) -> String {
+        let private_key = MoneroPrivateKey::<N>::from_seed(seed, &MoneroFormat::Standard).unwrap();
+        private_key.to_address(&MoneroFormat::Standard).unwrap().to_string()
+    }
+
+    for vector in load_monero(vector_file("monero.json")).unwrap() {
+        let address = match vector.network.as_str() {
+            "mainnet" => address::<MoneroMainnet>(&vector.seed),
+            "testnet" => address::<MoneroTestnet>(&vector.seed),
+            "stagenet" => address::<MoneroStagenet>(&vector.seed),
+            network => panic!("unsupported network {}", network),
+        };
+        assert_eq!(vector.address, address);
+    }
+}
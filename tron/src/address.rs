@@ -0,0 +1,239 @@
+use crate::format::TronFormat;
+use crate::private_key::TronPrivateKey;
+use crate::public_key::TronPublicKey;
+use wagyu_model::{encoding::base58check, Address, AddressError, PrivateKey};
+
+use base58::FromBase58;
+use core::{convert::TryFrom, fmt, str::FromStr};
+use serde::Serialize;
+use tiny_keccak::keccak256;
+
+/// The prefix byte of a Tron address
+pub const ADDRESS_PREFIX: u8 = 0x41;
+
+/// Represents a Tron address
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Hash)]
+pub struct TronAddress(String);
+
+impl Address for TronAddress {
+    type Format = TronFormat;
+    type PrivateKey = TronPrivateKey;
+    type PublicKey = TronPublicKey;
+
+    /// Returns the address corresponding to the given private key.
+    fn from_private_key(private_key: &Self::PrivateKey, format: &Self::Format) -> Result<Self, AddressError> {
+        Self::from_public_key(&private_key.to_public_key(), format)
+    }
+
+    /// Returns the address corresponding to the given public key.
+    fn from_public_key(public_key: &Self::PublicKey, _: &Self::Format) -> Result<Self, AddressError> {
+        let hash = keccak256(&public_key.to_secp256k1_public_key().serialize()[1..]);
+        Ok(Self::from_hash(&hash[12..]))
+    }
+}
+
+impl TronAddress {
+    /// Returns the base58check address of the given 20-byte account hash,
+    /// the last 20 bytes of the Keccak-256 hash of the uncompressed public key.
+    fn from_hash(hash: &[u8]) -> Self {
+        let mut address = [0u8; 21];
+        address[0] = ADDRESS_PREFIX;
+        address[1..21].copy_from_slice(hash);
+
+        Self(base58check::encode(&address))
+    }
+
+    /// Returns the address in hexadecimal, as in `41` followed by the account hash,
+    /// the form used by the Tron node APIs.
+    pub fn to_hex(&self) -> String {
+        let data = self.0.from_base58().unwrap_or_default();
+        hex::encode(&data[..data.len().min(21)])
+    }
+}
+
+impl<'a> TryFrom<&'a str> for TronAddress {
+    type Error = AddressError;
+
+    fn try_from(address: &'a str) -> Result<Self, Self::Error> {
+        Self::from_str(address)
+    }
+}
+
+impl FromStr for TronAddress {
+    type Err = AddressError;
+
+    /// Returns the address given its base58check encoding, or its hexadecimal encoding with the `41` prefix.
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        if address.len() == 42 {
+            let data = hex::decode(address).map_err(|_| AddressError::InvalidAddress(address.into()))?;
+            if data[0] != ADDRESS_PREFIX {
+                return Err(AddressError::InvalidPrefix(data[0..1].to_vec()));
+            }
+            return Ok(Self::from_hash(&data[1..]));
+        }
+
+        if address.len() != 34 {
+            return Err(AddressError::InvalidCharacterLength(address.len()));
+        }
+
+        let data = address.from_base58()?;
+        if data.len() != 25 {
+            return Err(AddressError::InvalidByteLength(data.len()));
+        }
+
+        base58check::verify(&data)?;
+
+        if data[0] != ADDRESS_PREFIX {
+            return Err(AddressError::InvalidPrefix(data[0..1].to_vec()));
+        }
+
+        Ok(Self(address.into()))
+    }
+}
+
+impl fmt::Display for TronAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wagyu_model::public_key::PublicKey;
+
+    fn test_from_private_key(expected_address: &str, private_key: &TronPrivateKey) {
+        let address = TronAddress::from_private_key(private_key, &TronFormat::Standard).unwrap();
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    fn test_from_public_key(expected_address: &str, public_key: &TronPublicKey) {
+        let address = TronAddress::from_public_key(public_key, &TronFormat::Standard).unwrap();
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    fn test_from_str(expected_address: &str) {
+        let address = TronAddress::from_str(expected_address).unwrap();
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    fn test_to_str(expected_address: &str, address: &TronAddress) {
+        assert_eq!(expected_address, address.to_string());
+    }
+
+    mod standard {
+        use super::*;
+
+        const KEYPAIRS: [(&str, &str); 5] = [
+            (
+                "f89f23eaeac18252fedf81bb8318d3c111d48c19b0680dcf6e0a8d5136caf287",
+                "TPDFhum222kAaz9zfyj7vLpaUMYivkcwmN",
+            ),
+            (
+                "a93701ea343247db13466f6448ffbca658726e2b4a77530db3eca3c9250b4f0d",
+                "TQcKPMLVEeJNEpvJ2zJmaxJTV2MAmW1BwM",
+            ),
+            (
+                "de61e35e2e5eb9504d52f5042126591d80144d49f74b8ced68f4959a3e8edffd",
+                "TVTmb5fEcCEJTgnkevbzWzwDAE2oj9DpTn",
+            ),
+            (
+                "56f01d5e01b6fd1cc123d8d1eae0d148e00c025b5be2ef624775f7a1b802e9c1",
+                "TTs4N3ZnvoUixXHXz3rsbxHYSKFkm1bGV6",
+            ),
+            (
+                "363af8b4d3ff22bb0e4ffc2ff198b4b5be0316f8a507ad5fe32f021c3d1ae8ad",
+                "TYfoQ5cxrDqfUk5hpCGkd8geud58PxKRvf",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = TronPrivateKey::from_str(private_key).unwrap();
+                test_from_private_key(address, &private_key);
+            });
+        }
+
+        #[test]
+        fn from_public_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = TronPrivateKey::from_str(private_key).unwrap();
+                let public_key = TronPublicKey::from_private_key(&private_key);
+                test_from_public_key(address, &public_key);
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, address)| {
+                test_from_str(address);
+            });
+        }
+
+        #[test]
+        fn to_str() {
+            KEYPAIRS.iter().for_each(|(_, expected_address)| {
+                let address = TronAddress::from_str(expected_address).unwrap();
+                test_to_str(expected_address, &address);
+            });
+        }
+    }
+
+    #[test]
+    fn hex() {
+        let addresses = [
+            (
+                "TPDFhum222kAaz9zfyj7vLpaUMYivkcwmN",
+                "419141b7539e7902872095c408bfa294435e2b8c8a",
+            ),
+            (
+                "T9yD14Nj9j7xAB4dbGeiX9h8unkKHxuWwb",
+                "410000000000000000000000000000000000000000",
+            ),
+        ];
+        addresses.iter().for_each(|(address, hex)| {
+            assert_eq!(*hex, TronAddress::from_str(address).unwrap().to_hex());
+            assert_eq!(*address, TronAddress::from_str(hex).unwrap().to_string());
+        });
+    }
+
+    #[test]
+    fn test_invalid() {
+        // Mismatched keypair
+
+        let private_key = "f89f23eaeac18252fedf81bb8318d3c111d48c19b0680dcf6e0a8d5136caf287";
+        let expected_address = "TYfoQ5cxrDqfUk5hpCGkd8geud58PxKRvf";
+
+        let private_key = TronPrivateKey::from_str(private_key).unwrap();
+        let address = TronAddress::from_private_key(&private_key, &TronFormat::Standard).unwrap();
+        assert_ne!(expected_address, address.to_string());
+
+        // Invalid address length
+
+        let address = "T";
+        assert!(TronAddress::from_str(address).is_err());
+
+        let address = "TPDFhum222kAaz9zfyj7vLpaUMYivkcwm";
+        assert!(TronAddress::from_str(address).is_err());
+
+        let address = "TPDFhum222kAaz9zfyj7vLpaUMYivkcwmNT";
+        assert!(TronAddress::from_str(address).is_err());
+
+        // Invalid checksum
+
+        let address = "TPDFhum222kAaz9zfyj7vLpaUMYivkcwmM";
+        assert!(TronAddress::from_str(address).is_err());
+
+        // Invalid prefix
+
+        let address = "1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm";
+        assert!(TronAddress::from_str(address).is_err());
+
+        let address = "009141b7539e7902872095c408bfa294435e2b8c8a";
+        assert!(TronAddress::from_str(address).is_err());
+
+        let address = "0x9141B7539E7902872095C408BfA294435e2b8c8a";
+        assert!(TronAddress::from_str(address).is_err());
+    }
+}
@@ -1,29 +1,50 @@
 use crate::bitcoin::{
     format::BitcoinFormat, wordlist::*, BitcoinAddress, BitcoinAmount, BitcoinDerivationPath,
-    BitcoinExtendedPrivateKey, BitcoinExtendedPublicKey, BitcoinMnemonic, BitcoinNetwork, BitcoinPrivateKey,
-    BitcoinPublicKey, BitcoinTransaction, BitcoinTransactionInput, BitcoinTransactionOutput,
-    BitcoinTransactionParameters, BitcoinWordlist, Mainnet as BitcoinMainnet, Outpoint, SignatureHash,
-    Testnet as BitcoinTestnet,
+    BitcoinExtendedPrivateKey, BitcoinExtendedPublicKey, BitcoinMnemonic, BitcoinNetwork, BitcoinPaymentCode,
+    BitcoinPrivateKey, BitcoinPublicKey, BitcoinTransaction, BitcoinTransactionInput, BitcoinTransactionOutput,
+    BitcoinTransactionParameters, BitcoinTransactionPolicy, BitcoinUri, BitcoinWordlist, CoinSelection,
+    CoinSelectionStrategy, ElectrumMnemonic, Mainnet as BitcoinMainnet, Miniscript, Outpoint,
+    Regtest as BitcoinRegtest, Script, SignatureHash, Testnet as BitcoinTestnet, UriError, Utxo,
 };
-use crate::cli::{flag, option, subcommand, types::*, CLIError, CLI};
+use crate::cli::diagnostics::{self, from_base58_check, from_bech32, AddressDiagnostics};
+use crate::cli::entropy::Entropy;
+#[cfg(feature = "hw-ledger")]
+use crate::cli::ledger::Ledger;
+use crate::cli::output::{write_output, WalletWriter};
+#[cfg(feature = "broadcast")]
+use crate::cli::rpc;
+use crate::cli::vectors::TestVectors;
+use crate::cli::{
+    check_word_count, fingerprint, flag, master_fingerprint, option, paper, paper::PaperWallet, parse_index_range, qr,
+    subcommand, types::*, value_or_stdin, wallet_id, wallet_rng, warn_high_fee, CLIError, CLI,
+};
+#[cfg(feature = "hw-ledger")]
+use crate::model::DerivationPath;
 use crate::model::{
-    crypto::hash160, ExtendedPrivateKey, ExtendedPublicKey, Mnemonic, MnemonicCount, MnemonicExtended, PrivateKey,
-    PublicKey, Transaction,
+    crypto::hash160, encoding::base58check, AddressError, BIP32Path, Base58Network, Bip39Language, ChildIndex,
+    ExtendedPrivateKey, ExtendedPublicKey, Mnemonic, MnemonicCount, MnemonicExtended, Network,
+    NonHardenedDerivationPath, PrivateKey, PublicKey, Transaction, TransactionError,
 };
 
+use base58::FromBase58;
+use bech32::FromBase32;
 use clap::{ArgMatches, Values};
 use colored::*;
-use core::{fmt, fmt::Display, str::FromStr};
-use rand::{rngs::StdRng, Rng};
-use rand_core::SeedableRng;
+use core::{fmt, fmt::Display, iter, str::FromStr};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use serde_json::from_str;
+use zeroize::Zeroize;
 
 use crate::model::no_std::{format, vec, String, ToOwned, ToString, Vec};
 
+/// The fee of a signed transaction, in satoshi, above which it is warned of as absurdly high,
+/// the default maximum fee of a transaction sent by Bitcoin Core
+const MAXIMUM_FEE: i64 = 10_000_000;
+
 /// Represents a generic wallet to output
 #[derive(Serialize, Debug, Default)]
-struct BitcoinWallet {
+pub struct BitcoinWallet {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -40,20 +61,558 @@ struct BitcoinWallet {
     pub public_key: Option<String>,
     pub address: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub master_fingerprint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wallet_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub format: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub compressed: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inputs: Option<Vec<BitcoinWalletInput>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outputs: Option<Vec<BitcoinWalletOutput>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_time: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction_hex: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
+
+/// Represents a decoded Bitcoin transaction input to output
+#[derive(Serialize, Debug)]
+pub struct BitcoinWalletInput {
+    pub txid: String,
+    pub vout: u32,
+    pub sequence: String,
+    pub signed: bool,
+}
+
+/// Represents a decoded Bitcoin transaction output to output
+#[derive(Serialize, Debug)]
+pub struct BitcoinWalletOutput {
+    pub amount: i64,
+    pub script_pub_key: String,
+}
+
+/// Represents a key-origin descriptor to output, as consumed by HWI and Bitcoin Core `importdescriptors`
+#[derive(Serialize, Debug)]
+pub struct BitcoinDescriptor {
+    pub desc: String,
+    pub range: [u32; 2],
+    pub timestamp: String,
+    pub internal: bool,
+    pub keypool: bool,
+    pub active: bool,
+    pub watchonly: bool,
+}
+
+impl BitcoinDescriptor {
+    /// The character set of descriptors, ordered for the descriptor checksum
+    const INPUT_CHARSET: &'static str =
+        "0123456789()[],'/*abcdefgh@:$%{}IJKLMNOPQRSTUVWXYZ&+-.;<=>?!^_|~ijklmnopqrstuvwxyzABCDEFGH`#\"\\ ";
+    /// The character set of the descriptor checksum
+    const CHECKSUM_CHARSET: &'static [u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+    /// Returns a watch-only descriptor for the given descriptor and range of indices (end exclusive),
+    /// appending its checksum.
+    pub fn new(descriptor: &str, range: (u32, u32), internal: bool) -> Result<Self, CLIError> {
+        Ok(Self {
+            desc: format!("{}#{}", descriptor, Self::checksum(descriptor)?),
+            range: [range.0, range.1 - 1],
+            timestamp: "now".into(),
+            internal,
+            keypool: true,
+            active: true,
+            watchonly: true,
+        })
+    }
+
+    /// Returns the checksum of the given descriptor.
+    /// https://github.com/bitcoin/bips/blob/master/bip-0380.mediawiki#checksum
+    fn checksum(descriptor: &str) -> Result<String, CLIError> {
+        fn polymod(c: u64, value: u64) -> u64 {
+            let c0 = c >> 35;
+            let mut c = ((c & 0x7ffffffff) << 5) ^ value;
+            let generator = [0xf5dee51989, 0xa9fdca3312, 0x1bab10e32d, 0x3706b1677a, 0x644d626ffd];
+            for (i, g) in generator.iter().enumerate() {
+                if (c0 >> i) & 1 == 1 {
+                    c ^= g;
+                }
+            }
+            c
+        }
+
+        let mut c = 1;
+        let mut class = 0;
+        let mut class_count = 0;
+        for character in descriptor.chars() {
+            let position = match Self::INPUT_CHARSET.find(character) {
+                Some(position) => position as u64,
+                None => return Err(CLIError::InvalidDescriptor(descriptor.into())),
+            };
+            // Emit a symbol for the position inside the group, for every character
+            c = polymod(c, position & 31);
+            // Accumulate the group numbers, and emit a symbol for every three characters
+            class = class * 3 + (position >> 5);
+            class_count += 1;
+            if class_count == 3 {
+                c = polymod(c, class);
+                class = 0;
+                class_count = 0;
+            }
+        }
+        if class_count > 0 {
+            c = polymod(c, class);
+        }
+        (0..8).for_each(|_| c = polymod(c, 0));
+        c ^= 1;
+
+        Ok((0..8)
+            .map(|i| Self::CHECKSUM_CHARSET[((c >> (5 * (7 - i))) & 31) as usize] as char)
+            .collect())
+    }
+}
+
+/// Returns the seed of the mnemonic, in the language detected from its words.
+fn to_seed_any_language<N: BitcoinNetwork>(mnemonic: &str, password: Option<&str>) -> Result<Vec<u8>, CLIError> {
+    fn to_seed<N: BitcoinNetwork, W: BitcoinWordlist>(
+        mnemonic: &str,
+        password: Option<&str>,
+    ) -> Result<Vec<u8>, CLIError> {
+        Ok(BitcoinMnemonic::<N, W>::from_phrase(mnemonic)?.to_seed(password)?)
+    }
+
+    match Bip39Language::detect_language(mnemonic)? {
+        Bip39Language::ChineseSimplified => to_seed::<N, ChineseSimplified>(mnemonic, password),
+        Bip39Language::ChineseTraditional => to_seed::<N, ChineseTraditional>(mnemonic, password),
+        Bip39Language::English => to_seed::<N, English>(mnemonic, password),
+        Bip39Language::French => to_seed::<N, French>(mnemonic, password),
+        Bip39Language::Italian => to_seed::<N, Italian>(mnemonic, password),
+        Bip39Language::Japanese => to_seed::<N, Japanese>(mnemonic, password),
+        Bip39Language::Korean => to_seed::<N, Korean>(mnemonic, password),
+        Bip39Language::Spanish => to_seed::<N, Spanish>(mnemonic, password),
+    }
+}
+
+/// Represents the BIP-32 root key of a mnemonic to output, with the keys of its bip44, bip49, and bip84 accounts
+#[derive(Serialize, Debug)]
+struct BitcoinRootKey {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub master_extended_private_key: Option<String>,
+    pub master_extended_public_key: String,
+    pub master_fingerprint: String,
+    pub accounts: Vec<BitcoinAccountKey>,
+}
+
+/// Represents the extended keys of an account to output, encoded with the SLIP-132 version bytes of its purpose
+#[derive(Serialize, Debug)]
+struct BitcoinAccountKey {
+    pub path: String,
+    pub format: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extended_private_key: Option<String>,
+    pub extended_public_key: String,
+}
+
+impl BitcoinRootKey {
+    /// The fields of the root key, in the order of the columns of a CSV output
+    pub const FIELDS: &'static [&'static str] = &[
+        "seed",
+        "master_extended_private_key",
+        "master_extended_public_key",
+        "master_fingerprint",
+        "accounts",
+    ];
+
+    /// Returns the root key of the mnemonic, in the language detected from its words,
+    /// with the keys of the given account number for each of the bip44, bip49, and bip84 purposes.
+    pub fn from_mnemonic<N: BitcoinNetwork>(
+        mnemonic: &str,
+        password: Option<&str>,
+        account: u32,
+    ) -> Result<Self, CLIError> {
+        let mut seed = to_seed_any_language::<N>(mnemonic, password)?;
+        let master_extended_private_key = BitcoinExtendedPrivateKey::<N>::new_master(&seed, &BitcoinFormat::P2PKH);
+        let seed_hex = hex::encode(&seed);
+        seed.zeroize();
+        let master_extended_private_key = master_extended_private_key?;
+        let master_extended_public_key = master_extended_private_key.to_extended_public_key().to_string();
+
+        let mut accounts = vec![];
+        for purpose in [44, 49, 84].iter() {
+            let path = BIP32Path::default().extend(vec![
+                ChildIndex::hardened(*purpose)?,
+                N::HD_COIN_TYPE,
+                ChildIndex::hardened(account)?,
+            ])?;
+            let format = BitcoinFormat::from_purpose(ChildIndex::hardened(*purpose)?).unwrap();
+            let extended_private_key = path
+                .iter()
+                .try_fold(master_extended_private_key.clone(), |key, index| {
+                    key.derive_child(*index)
+                })?
+                .with_format(&format)?;
+            accounts.push(BitcoinAccountKey {
+                path: path.to_string(),
+                format: format.to_string(),
+                extended_private_key: Some(extended_private_key.to_string()),
+                extended_public_key: extended_private_key.to_extended_public_key().to_string(),
+            });
+        }
+
+        Ok(Self {
+            seed: Some(seed_hex),
+            master_extended_private_key: Some(master_extended_private_key.to_string()),
+            master_fingerprint: master_fingerprint(&master_extended_public_key).unwrap_or_default(),
+            master_extended_public_key,
+            accounts,
+        })
+    }
+}
+
+impl Zeroize for BitcoinRootKey {
+    /// Overwrites the secret fields of the root key.
+    fn zeroize(&mut self) {
+        self.seed.zeroize();
+        self.master_extended_private_key.zeroize();
+        self.accounts
+            .iter_mut()
+            .for_each(|account| account.extended_private_key.zeroize());
+    }
+}
+
+impl Display for BitcoinRootKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut output = [
+            match &self.seed {
+                Some(seed) => format!("      {}                 {}\n", "Seed".cyan().bold(), seed),
+                _ => "".to_owned(),
+            },
+            match &self.master_extended_private_key {
+                Some(extended_private_key) => format!(
+                    "      {} {}\n",
+                    "Extended Private Key".cyan().bold(),
+                    extended_private_key
+                ),
+                _ => "".to_owned(),
+            },
+            format!(
+                "      {}  {}\n",
+                "Extended Public Key".cyan().bold(),
+                self.master_extended_public_key
+            ),
+            format!(
+                "      {}   {}\n",
+                "Master Fingerprint".cyan().bold(),
+                self.master_fingerprint
+            ),
+        ]
+        .concat();
+        for account in &self.accounts {
+            output.push_str(&format!(
+                "\n      {}                 {}\n",
+                "Path".cyan().bold(),
+                account.path
+            ));
+            output.push_str(&format!(
+                "      {}               {}\n",
+                "Format".cyan().bold(),
+                account.format
+            ));
+            if let Some(extended_private_key) = &account.extended_private_key {
+                output.push_str(&format!(
+                    "      {} {}\n",
+                    "Extended Private Key".cyan().bold(),
+                    extended_private_key
+                ));
+            }
+            output.push_str(&format!(
+                "      {}  {}\n",
+                "Extended Public Key".cyan().bold(),
+                account.extended_public_key
+            ));
+        }
+
+        // Removes final new line character
+        let output = output[..output.len() - 1].to_owned();
+        write!(f, "\n{}", output)
+    }
+}
+
+/// Represents a spending policy compiled to miniscript to output, with its witness script and P2WSH address
+#[derive(Serialize, Debug)]
+struct BitcoinMiniscript {
+    pub policy: String,
+    pub miniscript: String,
+    pub witness_script: String,
+    pub script_pub_key: String,
+    pub address: String,
+    pub max_satisfaction_weight: usize,
+}
+
+impl BitcoinMiniscript {
+    /// The fields of the miniscript, in the order of the columns of a CSV output
+    pub const FIELDS: &'static [&'static str] = &[
+        "policy",
+        "miniscript",
+        "witness_script",
+        "script_pub_key",
+        "address",
+        "max_satisfaction_weight",
+    ];
+
+    /// Returns the miniscript compiled from the given policy, with the P2WSH address of its witness script.
+    pub fn from_policy<N: BitcoinNetwork>(policy: &str) -> Result<Self, CLIError> {
+        let miniscript = Miniscript::from_policy(policy)?;
+        Ok(Self {
+            policy: policy.into(),
+            miniscript: miniscript.to_string(),
+            witness_script: miniscript.witness_script.to_string(),
+            script_pub_key: miniscript.to_script_pub_key().to_string(),
+            address: miniscript.to_address::<N>()?.to_string(),
+            max_satisfaction_weight: miniscript.max_satisfaction_weight,
+        })
+    }
+}
+
+impl Display for BitcoinMiniscript {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let output = [
+            format!("      {}                  {}\n", "Policy".cyan().bold(), self.policy),
+            format!(
+                "      {}              {}\n",
+                "Miniscript".cyan().bold(),
+                self.miniscript
+            ),
+            format!(
+                "      {}          {}\n",
+                "Witness Script".cyan().bold(),
+                self.witness_script
+            ),
+            format!(
+                "      {}          {}\n",
+                "Script PubKey".cyan().bold(),
+                self.script_pub_key
+            ),
+            format!("      {}                 {}\n", "Address".cyan().bold(), self.address),
+            format!(
+                "      {} {}\n",
+                "Max Satisfaction Weight".cyan().bold(),
+                self.max_satisfaction_weight
+            ),
+        ]
+        .concat();
+
+        // Removes final new line character
+        let output = output[..output.len() - 1].to_owned();
+        write!(f, "\n{}", output)
+    }
+}
+
+/// Represents a BIP-47 payment code to output, with its notification address and,
+/// for a counterparty payment code, the deposit addresses of a payment index in each direction
+#[derive(Serialize, Debug, Default)]
+struct BitcoinPaymentCodeWallet {
+    pub payment_code: String,
+    pub notification_address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub counterparty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receive_address: Option<String>,
+}
+
+impl BitcoinPaymentCodeWallet {
+    /// The fields of the payment code, in the order of the columns of a CSV output
+    pub const FIELDS: &'static [&'static str] = &[
+        "payment_code",
+        "notification_address",
+        "counterparty",
+        "index",
+        "send_address",
+        "receive_address",
+    ];
+
+    /// Returns the given payment code, parsed, with its notification address.
+    pub fn from_payment_code<N: BitcoinNetwork>(payment_code: &str) -> Result<Self, CLIError> {
+        let payment_code = BitcoinPaymentCode::<N>::from_str(payment_code)?;
+        Ok(Self {
+            payment_code: payment_code.to_string(),
+            notification_address: payment_code.to_notification_address()?.to_string(),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the payment code of the given account of the mnemonic, in the language detected from its words.
+    /// For a counterparty payment code, returns a payment code for each of the given payment indices,
+    /// with the address of the given format to send to the counterparty and to receive from it.
+    pub fn from_mnemonic<N: BitcoinNetwork>(
+        mnemonic: &str,
+        password: Option<&str>,
+        account: u32,
+        counterparty: Option<&str>,
+        indices: core::ops::Range<u32>,
+        format: &BitcoinFormat,
+    ) -> Result<Vec<Self>, CLIError> {
+        let mut seed = to_seed_any_language::<N>(mnemonic, password)?;
+        let master_extended_private_key = BitcoinExtendedPrivateKey::<N>::new_master(&seed, &BitcoinFormat::P2PKH);
+        seed.zeroize();
+        let node = BitcoinPaymentCode::derive_node(&master_extended_private_key?, account)?;
+        let payment_code = BitcoinPaymentCode::from_extended_private_key(&node);
+        let wallet = Self {
+            payment_code: payment_code.to_string(),
+            notification_address: payment_code.to_notification_address()?.to_string(),
+            ..Default::default()
+        };
+
+        let counterparty = match counterparty {
+            Some(counterparty) => BitcoinPaymentCode::<N>::from_str(counterparty)?,
+            None => return Ok(vec![wallet]),
+        };
+        indices
+            .map(|index| {
+                Ok(Self {
+                    counterparty: Some(counterparty.to_string()),
+                    index: Some(index),
+                    send_address: Some(counterparty.to_send_address(&node, index, format)?.to_string()),
+                    receive_address: Some(counterparty.to_receive_address(&node, index, format)?.to_string()),
+                    payment_code: wallet.payment_code.clone(),
+                    notification_address: wallet.notification_address.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl Display for BitcoinPaymentCodeWallet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let output = [
+            format!("      {}         {}\n", "Payment Code".cyan().bold(), self.payment_code),
+            format!(
+                "      {} {}\n",
+                "Notification Address".cyan().bold(),
+                self.notification_address
+            ),
+            match &self.counterparty {
+                Some(counterparty) => format!("      {}         {}\n", "Counterparty".cyan().bold(), counterparty),
+                _ => "".to_owned(),
+            },
+            match &self.index {
+                Some(index) => format!("      {}                {}\n", "Index".cyan().bold(), index),
+                _ => "".to_owned(),
+            },
+            match &self.send_address {
+                Some(send_address) => format!("      {}         {}\n", "Send Address".cyan().bold(), send_address),
+                _ => "".to_owned(),
+            },
+            match &self.receive_address {
+                Some(receive_address) => {
+                    format!("      {}      {}\n", "Receive Address".cyan().bold(), receive_address)
+                }
+                _ => "".to_owned(),
+            },
+        ]
+        .concat();
+
+        // Removes final new line character
+        let output = output[..output.len() - 1].to_owned();
+        write!(f, "\n{}", output)
+    }
+}
+
+impl Zeroize for BitcoinWallet {
+    /// Overwrites the secret fields of the wallet.
+    fn zeroize(&mut self) {
+        self.password.zeroize();
+        self.mnemonic.zeroize();
+        self.extended_private_key.zeroize();
+        self.private_key.zeroize();
+    }
 }
 
 impl BitcoinWallet {
-    pub fn new<N: BitcoinNetwork, R: Rng>(rng: &mut R, format: &BitcoinFormat) -> Result<Self, CLIError> {
-        let private_key = BitcoinPrivateKey::<N>::new(rng)?;
+    /// The fields of the wallet, in the order of the columns of a CSV output
+    pub const FIELDS: &'static [&'static str] = &[
+        "path",
+        "password",
+        "mnemonic",
+        "extended_private_key",
+        "extended_public_key",
+        "private_key",
+        "public_key",
+        "address",
+        "fingerprint",
+        "master_fingerprint",
+        "wallet_id",
+        "format",
+        "network",
+        "amount",
+        "label",
+        "message",
+        "compressed",
+        "version",
+        "inputs",
+        "outputs",
+        "lock_time",
+        "fee",
+        "transaction_hex",
+        "transaction_id",
+        "uri",
+    ];
+
+    /// Withholds the secrets of the wallet from its output, leaving its public material
+    /// and the fingerprint of its public key.
+    pub fn public_only(&mut self) {
+        self.fingerprint = self.public_key.as_deref().or(self.address.as_deref()).map(fingerprint);
+        self.zeroize();
+    }
+
+    /// Reduces the wallet to the extended public key of its derivation path, for a watch-only import,
+    /// withholding its secrets and the public key and address of the derived node.
+    pub fn neuter(&mut self) {
+        let neutered = Self {
+            path: self.path.take(),
+            extended_public_key: self.extended_public_key.take(),
+            master_fingerprint: self.master_fingerprint.take(),
+            wallet_id: self.wallet_id.take(),
+            format: self.format.take(),
+            network: self.network.take(),
+            ..Default::default()
+        };
+        self.zeroize();
+        *self = neutered;
+    }
+
+    pub fn new<N: BitcoinNetwork, R: Rng>(
+        rng: &mut R,
+        format: &BitcoinFormat,
+        compressed: bool,
+    ) -> Result<Self, CLIError> {
+        let secret_key = BitcoinPrivateKey::<N>::new(rng)?.to_secp256k1_secret_key();
+        let private_key = BitcoinPrivateKey::<N>::from_secp256k1_secret_key(&secret_key, compressed);
         let public_key = private_key.to_public_key();
         let address = public_key.to_address(format)?;
         Ok(Self {
@@ -67,13 +626,13 @@ impl BitcoinWallet {
         })
     }
 
-    pub fn new_hd<N: BitcoinNetwork, W: BitcoinWordlist, R: Rng>(
-        rng: &mut R,
+    pub fn new_hd<N: BitcoinNetwork, W: BitcoinWordlist>(
+        entropy: &mut Entropy,
         word_count: u8,
         password: Option<&str>,
         path: &str,
     ) -> Result<Self, CLIError> {
-        let mnemonic = BitcoinMnemonic::<N, W>::new_with_count(rng, word_count)?;
+        let mnemonic = BitcoinMnemonic::<N, W>::new_with_count(entropy, word_count)?;
         let master_extended_private_key = mnemonic.to_extended_private_key(password)?;
         let derivation_path = BitcoinDerivationPath::from_str(path)?;
         let extended_private_key = master_extended_private_key.derive(&derivation_path)?;
@@ -88,6 +647,8 @@ impl BitcoinWallet {
             mnemonic: Some(mnemonic.to_string()),
             extended_private_key: Some(extended_private_key.to_string()),
             extended_public_key: Some(extended_public_key.to_string()),
+            master_fingerprint: master_fingerprint(&master_extended_private_key.to_extended_public_key().to_string()),
+            wallet_id: Some(wallet_id(&extended_public_key.to_string())),
             private_key: Some(private_key.to_string()),
             public_key: Some(public_key.to_string()),
             address: Some(address.to_string()),
@@ -98,14 +659,156 @@ impl BitcoinWallet {
         })
     }
 
+    /// Returns the mainnet test vectors of the entropy: an HD wallet, a wallet of each address format,
+    /// and a transaction of the bech32 wallet, signing a sample output of it over to the HD wallet.
+    pub fn test_vectors(entropy: &mut Entropy) -> Result<TestVectors<Self>, CLIError> {
+        let mut wallets = vec![Self::new_hd::<BitcoinMainnet, English>(
+            entropy,
+            24,
+            None,
+            "m/44'/0'/0'/0/0",
+        )?];
+        for format in [BitcoinFormat::P2PKH, BitcoinFormat::P2SH_P2WPKH, BitcoinFormat::Bech32].iter() {
+            wallets.push(Self::new::<BitcoinMainnet, _>(entropy, format, true)?);
+        }
+
+        let input = BitcoinInput {
+            txid: hex::encode(entropy.gen::<[u8; 32]>()),
+            vout: 0,
+            amount: Some(100_000),
+            address: wallets[3].address.clone(),
+            private_key: wallets[3].private_key.clone(),
+            script_pub_key: None,
+            redeem_script: None,
+            sequence: None,
+            format: None,
+            path: None,
+        };
+        let output = format!("{}:{}", wallets[0].address.as_deref().unwrap_or(""), 90_000);
+        let raw = Self::to_raw_transaction::<BitcoinMainnet>(&vec![input.clone()], &vec![&output], 2, 0, false)?;
+        let transaction =
+            Self::to_signed_transaction::<BitcoinMainnet>(raw.transaction_hex.as_deref().unwrap_or(""), &vec![input])?;
+
+        Ok(TestVectors {
+            wallets,
+            transactions: vec![transaction],
+        })
+    }
+
     pub fn from_mnemonic<N: BitcoinNetwork, W: BitcoinWordlist>(
         mnemonic: &str,
         password: &Option<&str>,
         path: &str,
     ) -> Result<Self, CLIError> {
+        Ok(Self::from_mnemonic_paths::<N, W>(mnemonic, password, &[path.to_string()])?.remove(0))
+    }
+
+    /// Returns the wallets of the mnemonic for the specified derivation paths, deriving its master key once.
+    /// The parent of consecutive paths, e.g. the chain of an account, is derived once and its children from it.
+    pub fn from_mnemonic_paths<N: BitcoinNetwork, W: BitcoinWordlist>(
+        mnemonic: &str,
+        password: &Option<&str>,
+        paths: &[String],
+    ) -> Result<Vec<Self>, CLIError> {
         let mnemonic = BitcoinMnemonic::<N, W>::from_phrase(&mnemonic)?;
         let master_extended_private_key = mnemonic.to_extended_private_key(password.clone())?;
-        let derivation_path = BitcoinDerivationPath::from_str(path)?;
+        let master_fingerprint = master_fingerprint(&master_extended_private_key.to_extended_public_key().to_string());
+
+        let mut parent: Option<(BIP32Path, BitcoinExtendedPrivateKey<N>)> = None;
+        let mut wallets = vec![];
+        for path in paths {
+            let derivation_path = BitcoinDerivationPath::<N>::from_str(path)?;
+            let child_indices = BIP32Path::from_str(path)?;
+            let mut extended_private_key = match (child_indices.parent(), child_indices.iter().last()) {
+                (Some(parent_path), Some(index)) => {
+                    if parent.as_ref().map(|(path, _)| path) != Some(&parent_path) {
+                        let parent_key = parent_path
+                            .iter()
+                            .try_fold(master_extended_private_key.clone(), |key, index| {
+                                key.derive_child(*index)
+                            })?;
+                        parent = Some((parent_path, parent_key));
+                    }
+                    parent.as_ref().unwrap().1.derive_child(*index)?
+                }
+                _ => master_extended_private_key.clone(),
+            };
+            if let BitcoinDerivationPath::BIP49(_) = derivation_path {
+                extended_private_key = extended_private_key.with_format(&BitcoinFormat::P2SH_P2WPKH)?;
+            }
+
+            let extended_public_key = extended_private_key.to_extended_public_key();
+            let private_key = extended_private_key.to_private_key();
+            let public_key = extended_public_key.to_public_key();
+            let address = public_key.to_address(&extended_private_key.format())?;
+            let compressed = private_key.is_compressed();
+            wallets.push(Self {
+                path: Some(path.to_string()),
+                password: password.map(String::from),
+                mnemonic: Some(mnemonic.to_string()),
+                extended_private_key: Some(extended_private_key.to_string()),
+                extended_public_key: Some(extended_public_key.to_string()),
+                master_fingerprint: master_fingerprint.clone(),
+                wallet_id: Some(wallet_id(&extended_public_key.to_string())),
+                private_key: Some(private_key.to_string()),
+                public_key: Some(public_key.to_string()),
+                address: Some(address.to_string()),
+                format: Some(address.format().to_string()),
+                network: Some(N::NAME.to_string()),
+                compressed: Some(compressed),
+                ..Default::default()
+            });
+        }
+        Ok(wallets)
+    }
+
+    /// Returns the wallet of the mnemonic, in the language detected from its words.
+    pub fn from_mnemonic_any_language<N: BitcoinNetwork>(
+        mnemonic: &str,
+        password: &Option<&str>,
+        path: &str,
+    ) -> Result<Self, CLIError> {
+        Ok(Self::from_mnemonic_paths_any_language::<N>(mnemonic, password, &[path.to_string()])?.remove(0))
+    }
+
+    /// Returns the wallets of the mnemonic for the specified derivation paths, in the language detected from its words.
+    pub fn from_mnemonic_paths_any_language<N: BitcoinNetwork>(
+        mnemonic: &str,
+        password: &Option<&str>,
+        paths: &[String],
+    ) -> Result<Vec<Self>, CLIError> {
+        match Bip39Language::detect_language(mnemonic)? {
+            Bip39Language::ChineseSimplified => {
+                Self::from_mnemonic_paths::<N, ChineseSimplified>(mnemonic, password, paths)
+            }
+            Bip39Language::ChineseTraditional => {
+                Self::from_mnemonic_paths::<N, ChineseTraditional>(mnemonic, password, paths)
+            }
+            Bip39Language::English => Self::from_mnemonic_paths::<N, English>(mnemonic, password, paths),
+            Bip39Language::French => Self::from_mnemonic_paths::<N, French>(mnemonic, password, paths),
+            Bip39Language::Italian => Self::from_mnemonic_paths::<N, Italian>(mnemonic, password, paths),
+            Bip39Language::Japanese => Self::from_mnemonic_paths::<N, Japanese>(mnemonic, password, paths),
+            Bip39Language::Korean => Self::from_mnemonic_paths::<N, Korean>(mnemonic, password, paths),
+            Bip39Language::Spanish => Self::from_mnemonic_paths::<N, Spanish>(mnemonic, password, paths),
+        }
+    }
+
+    pub fn from_electrum_mnemonic<N: BitcoinNetwork>(
+        mnemonic: &str,
+        password: &Option<&str>,
+        path: Option<&str>,
+        chain: u32,
+        index: u32,
+    ) -> Result<Self, CLIError> {
+        let mnemonic = ElectrumMnemonic::<N, English>::from_phrase(mnemonic)?;
+        let path = match path {
+            Some(path) => path.to_string(),
+            None => BIP32Path::from_str(mnemonic.seed_type().to_account_path())?
+                .extend(vec![ChildIndex::normal(chain)?, ChildIndex::normal(index)?])?
+                .to_string(),
+        };
+        let master_extended_private_key = mnemonic.to_extended_private_key(*password)?;
+        let derivation_path = BitcoinDerivationPath::from_str(&path)?;
         let extended_private_key = master_extended_private_key.derive(&derivation_path)?;
         let extended_public_key = extended_private_key.to_extended_public_key();
         let private_key = extended_private_key.to_private_key();
@@ -113,11 +816,13 @@ impl BitcoinWallet {
         let address = public_key.to_address(&extended_private_key.format())?;
         let compressed = private_key.is_compressed();
         Ok(Self {
-            path: Some(path.to_string()),
+            path: Some(path),
             password: password.map(String::from),
             mnemonic: Some(mnemonic.to_string()),
             extended_private_key: Some(extended_private_key.to_string()),
             extended_public_key: Some(extended_public_key.to_string()),
+            master_fingerprint: master_fingerprint(&master_extended_private_key.to_extended_public_key().to_string()),
+            wallet_id: Some(wallet_id(&extended_public_key.to_string())),
             private_key: Some(private_key.to_string()),
             public_key: Some(public_key.to_string()),
             address: Some(address.to_string()),
@@ -133,6 +838,7 @@ impl BitcoinWallet {
         path: &Option<String>,
     ) -> Result<Self, CLIError> {
         let mut extended_private_key = BitcoinExtendedPrivateKey::<N>::from_str(extended_private_key)?;
+        let master_fingerprint = master_fingerprint(&extended_private_key.to_extended_public_key().to_string());
         if let Some(derivation_path) = path {
             let derivation_path = BitcoinDerivationPath::from_str(&derivation_path)?;
             extended_private_key = extended_private_key.derive(&derivation_path)?;
@@ -146,6 +852,8 @@ impl BitcoinWallet {
             path: path.clone(),
             extended_private_key: Some(extended_private_key.to_string()),
             extended_public_key: Some(extended_public_key.to_string()),
+            master_fingerprint,
+            wallet_id: Some(wallet_id(&extended_public_key.to_string())),
             private_key: Some(private_key.to_string()),
             public_key: Some(public_key.to_string()),
             address: Some(address.to_string()),
@@ -160,10 +868,11 @@ impl BitcoinWallet {
         extended_public_key: &str,
         path: &Option<String>,
     ) -> Result<Self, CLIError> {
+        let master_fingerprint = master_fingerprint(extended_public_key);
         let mut extended_public_key = BitcoinExtendedPublicKey::<N>::from_str(extended_public_key)?;
         if let Some(derivation_path) = path {
-            let derivation_path = BitcoinDerivationPath::from_str(&derivation_path)?;
-            extended_public_key = extended_public_key.derive(&derivation_path)?;
+            let derivation_path = NonHardenedDerivationPath::from_str(&derivation_path)?;
+            extended_public_key = extended_public_key.derive_public(&derivation_path)?;
         }
         let public_key = extended_public_key.to_public_key();
         let address = public_key.to_address(&extended_public_key.format())?;
@@ -171,6 +880,8 @@ impl BitcoinWallet {
         Ok(Self {
             path: path.clone(),
             extended_public_key: Some(extended_public_key.to_string()),
+            master_fingerprint,
+            wallet_id: Some(wallet_id(&extended_public_key.to_string())),
             public_key: Some(public_key.to_string()),
             address: Some(address.to_string()),
             format: Some(address.format().to_string()),
@@ -180,6 +891,138 @@ impl BitcoinWallet {
         })
     }
 
+    #[cfg(feature = "hw-ledger")]
+    pub fn from_ledger<N: BitcoinNetwork>(
+        ledger: &Ledger,
+        path: &str,
+        format: &BitcoinFormat,
+        verify: bool,
+    ) -> Result<Self, CLIError> {
+        let derivation_path = BitcoinDerivationPath::<N>::from_str(path)?;
+        let indices: Vec<u32> = derivation_path.to_vec()?.into_iter().map(u32::from).collect();
+        let address_format = match format {
+            BitcoinFormat::P2PKH => 0x00,
+            BitcoinFormat::P2SH_P2WPKH => 0x01,
+            BitcoinFormat::Bech32 => 0x02,
+            _ => return Err(CLIError::UnsupportedFormat(format.to_string())),
+        };
+
+        let ledger_public_key = ledger.bitcoin_public_key(&indices, address_format, verify)?;
+        let version = N::to_extended_public_key_version_bytes(format)?;
+        let extended_public_key =
+            BitcoinExtendedPublicKey::<N>::from_str(&ledger_public_key.to_extended_public_key(&version)?)?;
+        let public_key = extended_public_key.to_public_key();
+        let address = public_key.to_address(format)?;
+
+        // Check the address computed by the device against the address of its public key
+        if address.to_string() != ledger_public_key.address {
+            return Err(CLIError::LedgerError(format!(
+                "device address {} does not match derived address {}",
+                ledger_public_key.address, address
+            )));
+        }
+
+        Ok(Self {
+            path: Some(path.to_string()),
+            extended_public_key: Some(extended_public_key.to_string()),
+            wallet_id: Some(wallet_id(&extended_public_key.to_string())),
+            public_key: Some(public_key.to_string()),
+            address: Some(address.to_string()),
+            format: Some(address.format().to_string()),
+            network: Some(N::NAME.to_string()),
+            compressed: Some(public_key.is_compressed()),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the wallet with the address format of the purpose of its derivation path (44', 49', or 84'),
+    /// and its extended keys encoded with the SLIP-132 version bytes of the format, e.g. zprv and zpub for 84'.
+    /// A wallet without a derivation path of one of these purposes is returned as is.
+    pub fn into_slip132(self) -> Result<Self, CLIError> {
+        match self.network.as_deref() {
+            Some("testnet") => self.into_slip132_network::<BitcoinTestnet>(),
+            Some("regtest") => self.into_slip132_network::<BitcoinRegtest>(),
+            _ => self.into_slip132_network::<BitcoinMainnet>(),
+        }
+    }
+
+    fn into_slip132_network<N: BitcoinNetwork>(mut self) -> Result<Self, CLIError> {
+        let purpose = match self.path.as_deref() {
+            Some(path) => BIP32Path::from_str(path)?.iter().next().copied(),
+            None => None,
+        };
+        let format = match purpose.and_then(BitcoinFormat::from_purpose) {
+            Some(format) => format,
+            None => return Ok(self),
+        };
+
+        if let Some(extended_private_key) = self.extended_private_key.as_mut() {
+            let key = BitcoinExtendedPrivateKey::<N>::from_str(extended_private_key)?;
+            extended_private_key.zeroize();
+            *extended_private_key = key.with_format(&format)?.to_string();
+        }
+        if let Some(extended_public_key) = self.extended_public_key.as_mut() {
+            let key = BitcoinExtendedPublicKey::<N>::from_str(extended_public_key)?;
+            *extended_public_key = key.with_format(&format)?.to_string();
+        }
+        if let Some(public_key) = self.public_key.as_deref() {
+            let address = BitcoinPublicKey::<N>::from_str(public_key)?.to_address(&format)?;
+            self.address = Some(address.to_string());
+            self.format = Some(address.format().to_string());
+        }
+        Ok(self)
+    }
+
+    /// Returns the receive and change descriptors of the account of this wallet, for the given
+    /// derivation purpose, master key fingerprint, and range of indices (end exclusive).
+    /// If a fingerprint is given, the descriptors include the key origin of the account.
+    pub fn to_descriptors<N: BitcoinNetwork>(
+        &self,
+        purpose: u32,
+        fingerprint: Option<&str>,
+        range: (u32, u32),
+    ) -> Result<Vec<BitcoinDescriptor>, CLIError> {
+        let extended_public_key = match &self.extended_public_key {
+            Some(extended_public_key) => extended_public_key,
+            None => return Ok(vec![]),
+        };
+
+        // Descriptors only accept extended public keys with the xpub (or tpub) version bytes
+        let mut data = extended_public_key.from_base58()?;
+        if data.len() != 82 {
+            return Err(CLIError::InvalidDescriptor(extended_public_key.clone()));
+        }
+        data[0..4].copy_from_slice(&N::to_extended_public_key_version_bytes(&BitcoinFormat::P2PKH)?);
+        let encoded = base58check::encode(&data[0..78]);
+
+        let key = match (fingerprint, &self.path) {
+            (Some(fingerprint), Some(path)) => format!("[{}{}]{}", fingerprint, &path[1..], encoded),
+            _ => encoded,
+        };
+
+        [false, true]
+            .iter()
+            .map(|&internal| {
+                let key = format!("{}/{}/*", key, internal as u8);
+                let descriptor = match purpose {
+                    44 => format!("pkh({})", key),
+                    49 => format!("sh(wpkh({}))", key),
+                    84 => format!("wpkh({})", key),
+                    _ => return Err(CLIError::InvalidDescriptor(format!("bip{}", purpose))),
+                };
+                BitcoinDescriptor::new(&descriptor, range, internal)
+            })
+            .collect()
+    }
+
+    /// Returns the fingerprint of the public key of this wallet.
+    pub fn to_fingerprint(&self) -> Result<Option<String>, CLIError> {
+        match &self.public_key {
+            Some(public_key) => Ok(Some(hex::encode(&hash160(&hex::decode(public_key)?)[0..4]))),
+            None => Ok(None),
+        }
+    }
+
     pub fn from_private_key<N: BitcoinNetwork>(private_key: &str, format: &BitcoinFormat) -> Result<Self, CLIError> {
         let private_key = BitcoinPrivateKey::<N>::from_str(private_key)?;
         let public_key = private_key.to_public_key();
@@ -195,6 +1038,49 @@ impl BitcoinWallet {
         })
     }
 
+    /// Returns the wallets of a private key in WIF or hex, converted to the specified form (`compressed`,
+    /// `uncompressed`, or `hex`), with a wallet for each address format of the public key of the form.
+    /// A private key in hex is of the network `N`, and converted to hex it has the addresses of both public keys.
+    pub fn from_converted_private_key<N: BitcoinNetwork>(private_key: &str, to: &str) -> Result<Vec<Self>, CLIError> {
+        let private_key = match hex::decode(private_key) {
+            Ok(bytes) => BitcoinPrivateKey::<N>::from_secret_key_bytes(&bytes, true)?,
+            Err(_) => BitcoinPrivateKey::<N>::from_str(private_key)?,
+        };
+        let segwit = [BitcoinFormat::P2PKH, BitcoinFormat::P2SH_P2WPKH, BitcoinFormat::Bech32];
+        let forms: Vec<(bool, &BitcoinFormat)> = match to {
+            "compressed" => segwit.iter().map(|format| (true, format)).collect(),
+            "uncompressed" => vec![(false, &BitcoinFormat::P2PKH)],
+            "hex" => segwit
+                .iter()
+                .map(|format| (true, format))
+                .chain(iter::once((false, &BitcoinFormat::P2PKH)))
+                .collect(),
+            _ => return Err(CLIError::UnsupportedFormat(to.into())),
+        };
+
+        let secret_key = private_key.to_secp256k1_secret_key();
+        forms
+            .into_iter()
+            .map(|(compressed, format)| {
+                let private_key = BitcoinPrivateKey::<N>::from_secp256k1_secret_key(&secret_key, compressed);
+                let public_key = private_key.to_public_key();
+                let address = public_key.to_address(format)?;
+                Ok(Self {
+                    private_key: Some(match to {
+                        "hex" => hex::encode(private_key.to_secret_key_bytes()),
+                        _ => private_key.to_string(),
+                    }),
+                    public_key: Some(public_key.to_string()),
+                    address: Some(address.to_string()),
+                    network: Some(N::NAME.to_string()),
+                    format: Some(address.format().to_string()),
+                    compressed: Some(compressed),
+                    ..Default::default()
+                })
+            })
+            .collect()
+    }
+
     pub fn from_public_key<N: BitcoinNetwork>(public_key: &str, format: &BitcoinFormat) -> Result<Self, CLIError> {
         let public_key = BitcoinPublicKey::<N>::from_str(public_key)?;
         let address = public_key.to_address(format)?;
@@ -218,14 +1104,23 @@ impl BitcoinWallet {
         })
     }
 
+    /// Returns an unsigned transaction of the specified inputs and outputs. The inputs without a sequence number
+    /// signal that the transaction may be replaced if it is replaceable, and enable its lock time if it has one.
     pub fn to_raw_transaction<N: BitcoinNetwork>(
         inputs: &Vec<BitcoinInput>,
         outputs: &Vec<&str>,
         version: u32,
         lock_time: u32,
+        replaceable: bool,
     ) -> Result<Self, CLIError> {
         let mut transaction_inputs = vec![];
         for input in inputs {
+            let sequence = match (input.sequence, replaceable, lock_time) {
+                (Some(sequence), _, _) => Some(sequence),
+                (None, true, _) => Some(BitcoinTransactionInput::<N>::REPLACEABLE_SEQUENCE),
+                (None, false, 0) => None,
+                (None, false, _) => Some(BitcoinTransactionInput::<N>::LOCK_TIME_SEQUENCE),
+            };
             let transaction_input = BitcoinTransactionInput::<N>::new(
                 hex::decode(&input.txid)?,
                 input.vout,
@@ -233,7 +1128,7 @@ impl BitcoinWallet {
                 None,
                 None,
                 None,
-                None,
+                sequence.map(|sequence| sequence.to_le_bytes().to_vec()),
                 SignatureHash::SIGHASH_ALL,
             )?;
             transaction_inputs.push(transaction_input);
@@ -241,12 +1136,7 @@ impl BitcoinWallet {
 
         let mut transaction_outputs = vec![];
         for output in outputs {
-            let values: Vec<&str> = output.split(":").collect();
-            let address = BitcoinAddress::<N>::from_str(values[0])?;
-            transaction_outputs.push(BitcoinTransactionOutput::new(
-                &address,
-                BitcoinAmount::from_satoshi(i64::from_str(values[1])?)?,
-            )?);
+            transaction_outputs.push(to_transaction_output::<N>(output)?);
         }
 
         let transaction_parameters = BitcoinTransactionParameters::<N> {
@@ -266,6 +1156,179 @@ impl BitcoinWallet {
         })
     }
 
+    /// Returns an unsigned transaction of the specified outputs, funded by the unspent outputs selected
+    /// with the specified strategy at the specified fee rate, and sending any change to the specified address.
+    /// The outputs and the fee, as a percentage of the outputs excluding change, are checked against the policy.
+    #[allow(clippy::too_many_arguments)]
+    pub fn to_funded_transaction<N: BitcoinNetwork>(
+        utxos: &[BitcoinInput],
+        outputs: &[&str],
+        fee_rate: u64,
+        change: Option<&str>,
+        strategy: CoinSelectionStrategy,
+        version: u32,
+        lock_time: u32,
+        replaceable: bool,
+        policy: &BitcoinTransactionPolicy,
+    ) -> Result<Self, CLIError> {
+        let mut candidates = vec![];
+        for utxo in utxos {
+            let amount = match utxo.amount {
+                Some(amount) => BitcoinAmount::from_satoshi(amount as i64)?,
+                None => return Err(TransactionError::MissingOutpointAmount.into()),
+            };
+            candidates.push(Utxo::new(amount, &utxo.to_format::<N>(&BitcoinFormat::P2PKH)?)?);
+        }
+
+        let mut transaction_outputs = vec![];
+        for output in outputs {
+            transaction_outputs.push(to_transaction_output::<N>(output)?);
+        }
+
+        // Without a change address, the change output is assumed to be of the largest format
+        let change_format = match change {
+            Some(change) => BitcoinAddress::<N>::from_str(change)?.format(),
+            None => BitcoinFormat::P2PKH,
+        };
+        policy.check_outputs(&transaction_outputs)?;
+        let selection = CoinSelection::new(&candidates, &transaction_outputs, fee_rate, &change_format, strategy)?;
+        policy.check_fee(selection.fee.0, &transaction_outputs)?;
+
+        let inputs = selection.inputs.iter().map(|index| utxos[*index].clone()).collect();
+        let change_output;
+        let mut outputs = outputs.to_vec();
+        if let Some(amount) = selection.change {
+            match change {
+                Some(change) => {
+                    change_output = format!("{}:{}", change, amount.0);
+                    outputs.push(&change_output);
+                }
+                None => return Err(TransactionError::InvalidInputs("change address".into()).into()),
+            }
+        }
+
+        let transaction_hex =
+            Self::to_raw_transaction::<N>(&inputs, &outputs, version, lock_time, replaceable)?.transaction_hex;
+        let mut wallet = Self::from_transaction_hex::<N>(transaction_hex.as_ref().unwrap())?;
+        wallet.fee = Some(selection.fee.0);
+        wallet.transaction_hex = transaction_hex;
+        wallet.transaction_id = None;
+        Ok(wallet)
+    }
+
+    /// Returns a transaction consolidating the specified unspent outputs into a single output to the specified address,
+    /// less the fee of the specified fee rate, signed with the keys derived from the specified extended private key
+    /// at the path of each unspent output. An unspent output worth less than the fee to spend it is left out.
+    /// The output and the fee, as a percentage of the output, are checked against the policy.
+    #[allow(clippy::too_many_arguments)]
+    pub fn to_consolidated_transaction<N: BitcoinNetwork>(
+        utxos: &[BitcoinInput],
+        address: &str,
+        fee_rate: u64,
+        extended_private_key: Option<&str>,
+        version: u32,
+        lock_time: u32,
+        replaceable: bool,
+        policy: &BitcoinTransactionPolicy,
+    ) -> Result<Self, CLIError> {
+        // Resolve the key and address of each unspent output from the path of its key
+        let mut inputs = vec![];
+        for utxo in utxos {
+            let mut input = utxo.clone();
+            if let (None, Some(path), Some(extended_private_key)) =
+                (&input.private_key, &input.path, extended_private_key)
+            {
+                let key = Self::from_extended_private_key::<N>(extended_private_key, &Some(path.clone()))?;
+                let private_key = key.private_key.unwrap_or_default();
+                // The address is of the format of the unspent output, or of the extended private key, e.g. bech32 for a zprv
+                let derived = match (&input.address, &input.format) {
+                    (None, None) => key.address,
+                    _ => {
+                        Self::from_private_key::<N>(&private_key, &input.to_format::<N>(&BitcoinFormat::P2PKH)?)?
+                            .address
+                    }
+                };
+                match &input.address {
+                    Some(address) if Some(address) != derived.as_ref() => {
+                        return Err(TransactionError::Message(format!(
+                            "the key at {} does not control the address {}",
+                            path, address
+                        ))
+                        .into());
+                    }
+                    _ => input.address = derived,
+                }
+                input.private_key = Some(private_key);
+            }
+            inputs.push(input);
+        }
+
+        let mut candidates = vec![];
+        for input in &inputs {
+            let amount = match input.amount {
+                Some(amount) => BitcoinAmount::from_satoshi(amount as i64)?,
+                None => return Err(TransactionError::MissingOutpointAmount.into()),
+            };
+            candidates.push(Utxo::new(amount, &input.to_format::<N>(&BitcoinFormat::P2PKH)?)?);
+        }
+        let destination = BitcoinAddress::<N>::from_str(address)?;
+        let selection = CoinSelection::consolidate(&candidates, fee_rate, &destination.format())?;
+        let amount = selection.change.unwrap_or(BitcoinAmount(0));
+        let transaction_outputs = [BitcoinTransactionOutput::new(&destination, amount)?];
+        policy.check_outputs(&transaction_outputs)?;
+        policy.check_fee(selection.fee.0, &transaction_outputs)?;
+
+        let inputs: Vec<BitcoinInput> = selection.inputs.iter().map(|index| inputs[*index].clone()).collect();
+        let output = format!("{}:{}", address, amount.0);
+        let transaction_hex =
+            Self::to_raw_transaction::<N>(&inputs, &vec![&output], version, lock_time, replaceable)?.transaction_hex;
+        let mut wallet = Self::to_signed_transaction::<N>(transaction_hex.as_deref().unwrap_or(""), &inputs)?;
+        wallet.fee = Some(selection.fee.0);
+        Ok(wallet)
+    }
+
+    /// Returns the replacement of the specified signed transaction, paying the fee of the specified fee rate
+    /// from the output of the specified index, or the last output if none is specified, signed with the inputs.
+    pub fn to_bumped_transaction<N: BitcoinNetwork>(
+        transaction_hex: &str,
+        inputs: &Vec<BitcoinInput>,
+        fee_rate: u64,
+        change_output: Option<usize>,
+    ) -> Result<Self, CLIError> {
+        let mut transaction = BitcoinTransaction::<N>::from_transaction_bytes(&hex::decode(transaction_hex)?)?;
+        if !transaction.is_replaceable() {
+            eprintln!(
+                "{} the transaction does not signal that it may be replaced (BIP 125), \
+                 its replacement is only relayed by nodes with full replace-by-fee",
+                "Warning:".yellow().bold()
+            );
+        }
+
+        // The amounts of the inputs determine the fee of the transaction
+        for input in inputs {
+            let mut reverse_transaction_id = hex::decode(&input.txid)?;
+            reverse_transaction_id.reverse();
+            let amount = match input.amount {
+                Some(amount) => Some(BitcoinAmount::from_satoshi(amount as i64)?),
+                None => None,
+            };
+            transaction = transaction.update_outpoint(Outpoint::<N>::new(
+                reverse_transaction_id,
+                input.vout,
+                None,
+                amount,
+                None,
+                None,
+            )?);
+        }
+
+        let change_output = change_output.unwrap_or(transaction.parameters().outputs.len().saturating_sub(1));
+        let transaction = transaction.bump_fee(fee_rate, change_output)?;
+        let mut wallet = Self::to_signed_transaction::<N>(&hex::encode(&transaction.to_transaction_bytes()?), inputs)?;
+        wallet.fee = transaction.fee().map(|fee| fee.0);
+        Ok(wallet)
+    }
+
     pub fn to_signed_transaction<N: BitcoinNetwork>(
         transaction_hex: &str,
         inputs: &Vec<BitcoinInput>,
@@ -313,6 +1376,7 @@ impl BitcoinWallet {
                 _ => {}
             }
         }
+        warn_high_fee(&transaction, BitcoinAmount(MAXIMUM_FEE), "satoshi");
 
         Ok(Self {
             transaction_id: Some(transaction.to_transaction_id()?.to_string()),
@@ -320,6 +1384,111 @@ impl BitcoinWallet {
             ..Default::default()
         })
     }
+
+    pub fn from_transaction_hex<N: BitcoinNetwork>(transaction_hex: &str) -> Result<Self, CLIError> {
+        let transaction = BitcoinTransaction::<N>::from_transaction_bytes(&hex::decode(transaction_hex)?)?;
+        let parameters = transaction.parameters();
+
+        let inputs = parameters
+            .inputs
+            .iter()
+            .map(|input| {
+                let mut transaction_id = input.outpoint.reverse_transaction_id.clone();
+                transaction_id.reverse();
+                BitcoinWalletInput {
+                    txid: hex::encode(&transaction_id),
+                    vout: input.outpoint.index,
+                    sequence: hex::encode(&input.sequence),
+                    signed: input.is_signed,
+                }
+            })
+            .collect();
+
+        let outputs = parameters
+            .outputs
+            .iter()
+            .map(|output| BitcoinWalletOutput {
+                amount: output.amount.0,
+                script_pub_key: hex::encode(&output.script_pub_key),
+            })
+            .collect();
+
+        Ok(Self {
+            network: Some(N::NAME.to_string()),
+            version: Some(parameters.version),
+            inputs: Some(inputs),
+            outputs: Some(outputs),
+            lock_time: Some(parameters.lock_time),
+            transaction_id: Some(transaction.to_transaction_id()?.to_string()),
+            ..Default::default()
+        })
+    }
+
+    /// Returns a payment request to the specified address, on the network of the address,
+    /// for the specified amount (in BTC), label, and message.
+    pub fn to_uri<N: BitcoinNetwork>(
+        address: &str,
+        amount: Option<&str>,
+        label: Option<&str>,
+        message: Option<&str>,
+    ) -> Result<Self, CLIError> {
+        let amount = amount.map(BitcoinAmount::from_btc_str).transpose()?;
+        let mut uri = BitcoinUri::new(BitcoinAddress::<N>::from_str(address)?);
+        uri.amount = amount;
+        uri.label = label.map(String::from);
+        uri.message = message.map(String::from);
+        Self::from_uri(&uri)
+    }
+
+    /// Returns the payment request of a BIP-21 URI, with the format and network of its address.
+    pub fn from_uri<N: BitcoinNetwork>(uri: &BitcoinUri<N>) -> Result<Self, CLIError> {
+        Ok(Self {
+            address: Some(uri.address.to_string()),
+            format: Some(uri.address.format().to_string()),
+            network: Some(N::NAME.to_string()),
+            amount: uri.amount.map(|amount| amount.to_btc_string()),
+            label: uri.label.clone(),
+            message: uri.message.clone(),
+            uri: Some(uri.to_string()),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the payment request URI, signed transaction, address, or public key of the wallet,
+    /// to render as a QR code.
+    pub fn to_qr_data(&self) -> Option<&str> {
+        self.uri
+            .as_deref()
+            .or(self.transaction_hex.as_deref())
+            .or(self.address.as_deref())
+            .or(self.public_key.as_deref())
+    }
+
+    /// Returns the address, mnemonic or private key, and remaining fields of the wallet, to render on paper.
+    pub fn to_paper_wallet(&self) -> Option<PaperWallet<'_>> {
+        let address = self.address.as_deref()?;
+        let secret = match (self.mnemonic.as_deref(), self.private_key.as_deref()) {
+            (Some(mnemonic), _) => ("Mnemonic", mnemonic),
+            (None, Some(private_key)) => ("Private Key", private_key),
+            (None, None) => return None,
+        };
+        let fields = [
+            ("Path", self.path.as_deref()),
+            ("Private Key", self.private_key.as_deref()),
+            ("Format", self.format.as_deref()),
+            ("Network", self.network.as_deref()),
+        ]
+        .iter()
+        .filter(|(label, _)| *label != secret.0)
+        .filter_map(|(label, value)| value.map(|value| (*label, value)))
+        .collect();
+
+        Some(PaperWallet {
+            address,
+            secret,
+            fields,
+        })
+    }
 }
 
 #[cfg_attr(tarpaulin, skip)]
@@ -366,6 +1535,22 @@ impl Display for BitcoinWallet {
                 Some(address) => format!("      {}              {}\n", "Address".cyan().bold(), address),
                 _ => "".to_owned(),
             },
+            match &self.fingerprint {
+                Some(fingerprint) => format!("      {}          {}\n", "Fingerprint".cyan().bold(), fingerprint),
+                _ => "".to_owned(),
+            },
+            match &self.master_fingerprint {
+                Some(master_fingerprint) => format!(
+                    "      {}   {}\n",
+                    "Master Fingerprint".cyan().bold(),
+                    master_fingerprint
+                ),
+                _ => "".to_owned(),
+            },
+            match &self.wallet_id {
+                Some(wallet_id) => format!("      {}            {}\n", "Wallet ID".cyan().bold(), wallet_id),
+                _ => "".to_owned(),
+            },
             match &self.format {
                 Some(format) => format!("      {}               {}\n", "Format".cyan().bold(), format),
                 _ => "".to_owned(),
@@ -374,10 +1559,66 @@ impl Display for BitcoinWallet {
                 Some(network) => format!("      {}              {}\n", "Network".cyan().bold(), network),
                 _ => "".to_owned(),
             },
+            match &self.amount {
+                Some(amount) => format!("      {}               {} BTC\n", "Amount".cyan().bold(), amount),
+                _ => "".to_owned(),
+            },
+            match &self.label {
+                Some(label) => format!("      {}                {}\n", "Label".cyan().bold(), label),
+                _ => "".to_owned(),
+            },
+            match &self.message {
+                Some(message) => format!("      {}              {}\n", "Message".cyan().bold(), message),
+                _ => "".to_owned(),
+            },
             match &self.compressed {
                 Some(compressed) => format!("      {}           {}\n", "Compressed".cyan().bold(), compressed),
                 _ => "".to_owned(),
             },
+            match &self.version {
+                Some(version) => format!("      {}              {}\n", "Version".cyan().bold(), version),
+                _ => "".to_owned(),
+            },
+            match &self.inputs {
+                Some(inputs) => inputs
+                    .iter()
+                    .map(|input| {
+                        format!(
+                            "      {}                {}:{} (sequence {}, {})\n",
+                            "Input".cyan().bold(),
+                            input.txid,
+                            input.vout,
+                            input.sequence,
+                            if input.signed { "signed" } else { "unsigned" }
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .concat(),
+                _ => "".to_owned(),
+            },
+            match &self.outputs {
+                Some(outputs) => outputs
+                    .iter()
+                    .map(|output| {
+                        format!(
+                            "      {}               {} satoshi to {}\n",
+                            "Output".cyan().bold(),
+                            output.amount,
+                            output.script_pub_key
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .concat(),
+                _ => "".to_owned(),
+            },
+            match &self.lock_time {
+                Some(lock_time) => format!("      {}            {}\n", "Lock Time".cyan().bold(), lock_time),
+                _ => "".to_owned(),
+            },
+            match &self.fee {
+                Some(fee) => format!("      {}                  {} satoshi\n", "Fee".cyan().bold(), fee),
+                _ => "".to_owned(),
+            },
             match &self.transaction_id {
                 Some(transaction_id) => format!("      {}       {}\n", "Transaction Id".cyan().bold(), transaction_id),
                 _ => "".to_owned(),
@@ -388,6 +1629,10 @@ impl Display for BitcoinWallet {
                 }
                 _ => "".to_owned(),
             },
+            match &self.uri {
+                Some(uri) => format!("      {}                  {}\n", "URI".cyan().bold(), uri),
+                _ => "".to_owned(),
+            },
         ]
         .concat();
 
@@ -410,29 +1655,81 @@ pub struct BitcoinInput {
     pub script_pub_key: Option<String>,
     #[serde(rename(deserialize = "redeemScript"))]
     pub redeem_script: Option<String>,
+    pub sequence: Option<u32>,
+    pub format: Option<String>,
+    pub path: Option<String>,
+}
+
+impl BitcoinInput {
+    /// Returns the format of the input, from its address or format,
+    /// or the specified format if the input specifies neither.
+    fn to_format<N: BitcoinNetwork>(&self, default: &BitcoinFormat) -> Result<BitcoinFormat, CLIError> {
+        if let Some(address) = &self.address {
+            return Ok(BitcoinAddress::<N>::from_str(address)?.format());
+        }
+        match self.format.as_deref() {
+            Some("legacy") | Some("p2pkh") => Ok(BitcoinFormat::P2PKH),
+            Some("segwit") | Some("p2sh_p2wpkh") => Ok(BitcoinFormat::P2SH_P2WPKH),
+            Some("bech32") => Ok(BitcoinFormat::Bech32),
+            Some(format) => Err(CLIError::UnsupportedFormat(format.into())),
+            None => Ok(default.clone()),
+        }
+    }
+}
+
+/// Returns the transaction output of the specified `address:amount` pair, in satoshi,
+/// or the null data output of a `data:hex` pair, as in `createrawtransaction` of Bitcoin Core,
+/// or the output to a raw public key script of a `script:hex:amount` triple.
+fn to_transaction_output<N: BitcoinNetwork>(output: &str) -> Result<BitcoinTransactionOutput, CLIError> {
+    let values: Vec<&str> = output.split(":").collect();
+    match values[..] {
+        ["data", data] => Ok(BitcoinTransactionOutput::null_data(&hex::decode(data)?)?),
+        ["script", script, amount] => Ok(BitcoinTransactionOutput::from_script(
+            Script::from_str(script)?,
+            BitcoinAmount::from_satoshi(i64::from_str(amount)?)?,
+        )),
+        [address, amount] => Ok(BitcoinTransactionOutput::new(
+            &BitcoinAddress::<N>::from_str(address)?,
+            BitcoinAmount::from_satoshi(i64::from_str(amount)?)?,
+        )?),
+        _ => Err(TransactionError::InvalidOutputDescription(output.into()).into()),
+    }
 }
 
 /// Represents options for a Bitcoin wallet
 #[derive(Clone, Debug, Serialize)]
 pub struct BitcoinOptions {
     // Standard command
+    audit_rng: bool,
     count: usize,
     format: BitcoinFormat,
-    json: bool,
     network: String,
+    output_file: Option<String>,
+    output_format: String,
+    public_only: bool,
+    qr: bool,
+    qr_file: Option<String>,
     subcommand: Option<String>,
+    uncompressed: bool,
     // HD and Import HD subcommands
     account: u32,
     chain: u32,
     derivation: String,
+    entropy: Option<String>,
+    entropy_file: Option<String>,
     extended_private_key: Option<String>,
     extended_public_key: Option<String>,
     index: u32,
+    indices: u32,
     language: String,
     mnemonic: Option<String>,
+    mnemonic_type: String,
+    neuter: bool,
     password: Option<String>,
     path: Option<String>,
-    word_count: u8,
+    show_root: bool,
+    slip132: bool,
+    word_count: Option<u8>,
     // Import subcommand
     address: Option<String>,
     private: Option<String>,
@@ -441,31 +1738,71 @@ pub struct BitcoinOptions {
     transaction_inputs: Option<String>,
     transaction_hex: Option<String>,
     transaction_outputs: Option<String>,
+    change: Option<String>,
+    change_output: Option<usize>,
+    coin_selection: String,
+    fee_rate: Option<u64>,
     lock_time: Option<u32>,
+    max_fee_percent: Option<u64>,
+    op_return: Option<String>,
+    rbf: bool,
+    rpc: Option<String>,
+    utxos: Option<String>,
     version: Option<u32>,
+    // URI subcommand
+    uri: Option<String>,
+    uri_amount: Option<String>,
+    uri_label: Option<String>,
+    uri_message: Option<String>,
+    // Convert subcommand
+    to: Option<String>,
+    // Export subcommand
+    export_format: String,
+    // Hardware subcommand
+    verify: bool,
+    // Paper subcommand
+    paper_file: Option<String>,
+    // Miniscript subcommand
+    policy: Option<String>,
+    // Payment code subcommand
+    counterparty: Option<String>,
+    payment_code: Option<String>,
 }
 
 impl Default for BitcoinOptions {
     fn default() -> Self {
         Self {
             // Standard command
+            audit_rng: false,
             count: 1,
             format: BitcoinFormat::P2PKH,
-            json: false,
             network: "mainnet".into(),
+            output_file: None,
+            output_format: "text".into(),
+            public_only: false,
+            qr: false,
+            qr_file: None,
             subcommand: None,
+            uncompressed: false,
             // HD and Import HD subcommands
             account: 0,
             chain: 0,
             derivation: "bip32".into(),
+            entropy: None,
+            entropy_file: None,
             extended_private_key: None,
             extended_public_key: None,
             index: 0,
+            indices: 1,
             language: "english".into(),
             mnemonic: None,
+            mnemonic_type: "bip39".into(),
+            neuter: false,
             password: None,
             path: None,
-            word_count: 12,
+            show_root: false,
+            slip132: false,
+            word_count: None,
             // Import subcommand
             address: None,
             private: None,
@@ -474,38 +1811,117 @@ impl Default for BitcoinOptions {
             transaction_inputs: None,
             transaction_hex: None,
             transaction_outputs: None,
+            change: None,
+            change_output: None,
+            coin_selection: "branch-and-bound".into(),
+            fee_rate: None,
             lock_time: None,
+            max_fee_percent: None,
+            op_return: None,
+            rbf: false,
+            rpc: None,
+            utxos: None,
             version: None,
+            // URI subcommand
+            uri: None,
+            uri_amount: None,
+            uri_label: None,
+            uri_message: None,
+            // Convert subcommand
+            to: None,
+            // Export subcommand
+            export_format: "hwi".into(),
+            // Hardware subcommand
+            verify: false,
+            // Paper subcommand
+            paper_file: None,
+            // Miniscript subcommand
+            policy: None,
+            // Payment code subcommand
+            counterparty: None,
+            payment_code: None,
         }
     }
 }
 
 impl BitcoinOptions {
-    fn parse(&mut self, arguments: &ArgMatches, options: &[&str]) {
-        options.iter().for_each(|option| match *option {
-            "account" => self.account(clap::value_t!(arguments.value_of(*option), u32).ok()),
-            "address" => self.address(arguments.value_of(option)),
-            "chain" => self.chain(clap::value_t!(arguments.value_of(*option), u32).ok()),
-            "count" => self.count(clap::value_t!(arguments.value_of(*option), usize).ok()),
-            "createrawtransaction" => self.create_raw_transaction(arguments.values_of(option)),
-            "derivation" => self.derivation(arguments.value_of(option)),
-            "extended private" => self.extended_private(arguments.value_of(option)),
-            "extended public" => self.extended_public(arguments.value_of(option)),
-            "format" => self.format(arguments.value_of(option)),
-            "json" => self.json(arguments.is_present(option)),
-            "index" => self.index(clap::value_t!(arguments.value_of(*option), u32).ok()),
-            "language" => self.language(arguments.value_of(option)),
-            "lock time" => self.lock_time(clap::value_t!(arguments.value_of(*option), u32).ok()),
-            "mnemonic" => self.mnemonic(arguments.value_of(option)),
-            "network" => self.network(arguments.value_of(option)),
-            "password" => self.password(arguments.value_of(option)),
-            "private" => self.private(arguments.value_of(option)),
-            "public" => self.public(arguments.value_of(option)),
-            "signrawtransaction" => self.sign_raw_transaction(arguments.values_of(option)),
-            "word count" => self.word_count(clap::value_t!(arguments.value_of(*option), u8).ok()),
-            "version" => self.version(clap::value_t!(arguments.value_of(*option), u32).ok()),
-            _ => (),
-        });
+    fn parse(&mut self, arguments: &ArgMatches, options: &[&str]) -> Result<(), CLIError> {
+        for option in options {
+            match *option {
+                "account" => self.account(clap::value_t!(arguments.value_of(*option), u32).ok()),
+                "address" => self.address(arguments.value_of(option)),
+                "audit rng" => self.audit_rng(arguments.is_present(option)),
+                "chain" => self.chain(clap::value_t!(arguments.value_of(*option), u32).ok()),
+                "counterparty" => self.counterparty(arguments.value_of(option)),
+                "change" => self.change(arguments.value_of(option)),
+                "change output" => self.change_output(clap::value_t!(arguments.value_of(*option), usize).ok()),
+                "coin selection" => self.coin_selection(arguments.value_of(option)),
+                "count" => self.count(clap::value_t!(arguments.value_of(*option), usize).ok()),
+                "createrawtransaction" => self.create_raw_transaction(arguments.values_of(option)),
+                "derivation" => self.derivation(arguments.value_of(option)),
+                "entropy" => self.entropy(arguments.value_of(option)),
+                "entropy file" => self.entropy_file(arguments.value_of(option)),
+                "export derivation" => self.export_derivation(arguments.value_of(option)),
+                "export format" => self.export_format(arguments.value_of(option)),
+                "extended private" => self.extended_private(arguments.value_of(option)),
+                "extended public" => self.extended_public(arguments.value_of(option)),
+                "fee rate" => self.fee_rate(clap::value_t!(arguments.value_of(*option), u64).ok()),
+                "format" => self.format(arguments.value_of(option)),
+                "json" => self.json(arguments.is_present(option)),
+                "index" => self.index(clap::value_t!(arguments.value_of(*option), u32).ok()),
+                "inputs" => self.inputs(arguments.value_of(option)),
+                "index range" => {
+                    self.index_range(arguments.value_of(option))?;
+                }
+                "language" => self.language(arguments.value_of(option)),
+                "lock time" => self.lock_time(clap::value_t!(arguments.value_of(*option), u32).ok()),
+                "max fee percent" => self.max_fee_percent(clap::value_t!(arguments.value_of(*option), u64).ok()),
+                "mnemonic" => self.mnemonic(value_or_stdin(arguments, option).as_deref()),
+                "mnemonic type" => self.mnemonic_type(arguments.value_of(option)),
+                "neuter" => self.neuter(arguments.is_present(option)),
+                "network" => self.network(arguments.value_of(option)),
+                "op return" => self.op_return(arguments.value_of(option)),
+                "outputs" => self.outputs(arguments.value_of(option)),
+                "paper file" => self.paper_file(arguments.value_of(option)),
+                "password" => self.password(value_or_stdin(arguments, option).as_deref()),
+                "payment code" => self.payment_code(arguments.value_of(option)),
+                "policy" => self.policy(arguments.value_of(option)),
+                "private" => self.private(value_or_stdin(arguments, option).as_deref()),
+                "public" => self.public(arguments.value_of(option)),
+                "output file" => self.output_file(arguments.value_of(option)),
+                "output format" => self.output_format(arguments.value_of(option)),
+                "public only" => self.public_only(arguments.is_present(option)),
+                "qr" => self.qr(arguments.is_present(option)),
+                "qr file" => self.qr_file(arguments.value_of(option)),
+                "raw" => self.raw(arguments.value_of(option)),
+                "rbf" => self.rbf(arguments.is_present(option)),
+                "rpc" => self.rpc(arguments.value_of(option)),
+                "signrawtransaction" => self.sign_raw_transaction(arguments.values_of(option)),
+                "show root" => self.show_root(arguments.is_present(option)),
+                "slip132" => self.slip132(arguments.is_present(option)),
+                "to" => self.to(arguments.value_of(option)),
+                "uncompressed" => self.uncompressed(arguments.is_present(option)),
+                "uri" => self.uri(arguments.value_of(option)),
+                "uri amount" => self.uri_amount(arguments.value_of(option)),
+                "uri label" => self.uri_label(arguments.value_of(option)),
+                "uri message" => self.uri_message(arguments.value_of(option)),
+                "utxos" => self.utxos(arguments.value_of(option)),
+                "word count" => self.word_count(clap::value_t!(arguments.value_of(*option), u8).ok()),
+                "verify" => self.verify(arguments.is_present(option)),
+                "version" => self.version(clap::value_t!(arguments.value_of(*option), u32).ok()),
+                _ => (),
+            };
+        }
+        Ok(())
+    }
+
+    /// Returns the policy of the transaction, the relay policy of Bitcoin Core with the specified maximum fee.
+    fn to_policy(&self) -> BitcoinTransactionPolicy {
+        let policy = BitcoinTransactionPolicy::default();
+        match self.max_fee_percent {
+            Some(max_fee_percent) => policy.with_max_fee_percentage(max_fee_percent),
+            None => policy,
+        }
     }
 
     /// Sets `account` to the specified account index, overriding its previous state.
@@ -524,6 +1940,14 @@ impl BitcoinOptions {
         }
     }
 
+    /// Enables `audit_rng` if the specified boolean value is set.
+    /// Once enabled at any level of the command, the entropy drawn remains audited.
+    fn audit_rng(&mut self, argument: bool) {
+        if argument {
+            self.audit_rng = true;
+        }
+    }
+
     /// Sets `chain` to the specified chain index, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn chain(&mut self, argument: Option<u32>) {
@@ -532,6 +1956,40 @@ impl BitcoinOptions {
         }
     }
 
+    /// Sets `counterparty` to the specified counterparty payment code, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn counterparty(&mut self, argument: Option<&str>) {
+        if let Some(counterparty) = argument {
+            self.counterparty = Some(counterparty.to_string());
+        }
+    }
+
+    /// Sets `change` to the specified change address, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn change(&mut self, argument: Option<&str>) {
+        if let Some(change) = argument {
+            self.change = Some(change.to_string());
+        }
+    }
+
+    /// Sets `change_output` to the specified output index, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn change_output(&mut self, argument: Option<usize>) {
+        if let Some(change_output) = argument {
+            self.change_output = Some(change_output);
+        }
+    }
+
+    /// Sets `coin_selection` to the specified coin selection strategy, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn coin_selection(&mut self, argument: Option<&str>) {
+        match argument {
+            Some("branch-and-bound") => self.coin_selection = "branch-and-bound".into(),
+            Some("largest-first") => self.coin_selection = "largest-first".into(),
+            _ => (),
+        };
+    }
+
     /// Sets `count` to the specified count, overriding its previous state.
     fn count(&mut self, argument: Option<usize>) {
         if let Some(count) = argument {
@@ -565,6 +2023,38 @@ impl BitcoinOptions {
         };
     }
 
+    /// Sets `entropy` to the specified hex entropy, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn entropy(&mut self, argument: Option<&str>) {
+        if let Some(entropy) = argument {
+            self.entropy = Some(entropy.to_string());
+        }
+    }
+
+    /// Sets `entropy_file` to the specified entropy file path, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn entropy_file(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.entropy_file = Some(path.to_string());
+        }
+    }
+
+    /// Sets `derivation` to the specified export derivation, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn export_derivation(&mut self, argument: Option<&str>) {
+        if let Some(derivation) = argument {
+            self.derivation = derivation.to_string();
+        }
+    }
+
+    /// Sets `export_format` to the specified export format, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn export_format(&mut self, argument: Option<&str>) {
+        if let Some(export_format) = argument {
+            self.export_format = export_format.to_string();
+        }
+    }
+
     /// Sets `extended_private_key` to the specified extended private key, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn extended_private(&mut self, argument: Option<&str>) {
@@ -581,6 +2071,14 @@ impl BitcoinOptions {
         }
     }
 
+    /// Sets `fee_rate` to the specified fee rate, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn fee_rate(&mut self, argument: Option<u64>) {
+        if let Some(fee_rate) = argument {
+            self.fee_rate = Some(fee_rate);
+        }
+    }
+
     /// Sets `format` to the specified format, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn format(&mut self, argument: Option<&str>) {
@@ -600,9 +2098,32 @@ impl BitcoinOptions {
         }
     }
 
-    /// Sets `json` to the specified boolean value, overriding its previous state.
+    /// Sets `index` and `indices` to the specified range of indices, overriding their previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    /// Returns the range that was set, or an error if the argument is not a non-empty `start..end` range.
+    fn index_range(&mut self, argument: Option<&str>) -> Result<Option<(u32, u32)>, CLIError> {
+        let index_range = argument.map(parse_index_range).transpose()?;
+        if let Some((start, end)) = index_range {
+            self.index = start;
+            self.indices = end - start;
+        }
+        Ok(index_range)
+    }
+
+    /// Sets `transaction_inputs` to the specified transaction inputs, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn inputs(&mut self, argument: Option<&str>) {
+        if let Some(transaction_inputs) = argument {
+            self.transaction_inputs = Some(transaction_inputs.to_string());
+        }
+    }
+
+    /// Sets `output_format` to JSON if the specified boolean value is set.
+    /// Once enabled at any level of the command, JSON remains the output format.
     fn json(&mut self, argument: bool) {
-        self.json = argument;
+        if argument {
+            self.output_format = "json".into();
+        }
     }
 
     /// Sets `language` to the specified language, overriding its previous state.
@@ -629,6 +2150,14 @@ impl BitcoinOptions {
         }
     }
 
+    /// Sets `max_fee_percent` to the specified percentage, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn max_fee_percent(&mut self, argument: Option<u64>) {
+        if let Some(max_fee_percent) = argument {
+            self.max_fee_percent = Some(max_fee_percent);
+        }
+    }
+
     /// Sets `mnemonic` to the specified mnemonic, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn mnemonic(&mut self, argument: Option<&str>) {
@@ -637,16 +2166,76 @@ impl BitcoinOptions {
         }
     }
 
+    /// Sets `mnemonic_type` to the specified mnemonic type, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn mnemonic_type(&mut self, argument: Option<&str>) {
+        match argument {
+            Some("bip39") => self.mnemonic_type = "bip39".into(),
+            Some("electrum") => self.mnemonic_type = "electrum".into(),
+            _ => (),
+        };
+    }
+
+    /// Enables `neuter` if the specified boolean value is set.
+    fn neuter(&mut self, argument: bool) {
+        if argument {
+            self.neuter = true;
+        }
+    }
+
     /// Sets `network` to the specified network, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn network(&mut self, argument: Option<&str>) {
         match argument {
             Some("mainnet") => self.network = "mainnet".into(),
+            Some("regtest") => self.network = "regtest".into(),
             Some("testnet") => self.network = "testnet".into(),
             _ => (),
         };
     }
 
+    /// Sets `output_file` to the specified file path, overriding its previous state.
+    /// Colored output is disabled, as the file is not a terminal.
+    /// If the specified argument is `None`, then no change occurs.
+    fn output_file(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.output_file = Some(path.to_string());
+            colored::control::set_override(false);
+        }
+    }
+
+    /// Sets `output_format` to the specified output format, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn output_format(&mut self, argument: Option<&str>) {
+        if let Some(output_format) = argument {
+            self.output_format = output_format.to_string();
+        }
+    }
+
+    /// Sets `op_return` to the specified data of a null data output, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn op_return(&mut self, argument: Option<&str>) {
+        if let Some(data) = argument {
+            self.op_return = Some(data.to_string());
+        }
+    }
+
+    /// Sets `transaction_outputs` to the specified transaction outputs, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn outputs(&mut self, argument: Option<&str>) {
+        if let Some(transaction_outputs) = argument {
+            self.transaction_outputs = Some(transaction_outputs.to_string());
+        }
+    }
+
+    /// Sets `paper_file` to the specified file path, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn paper_file(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.paper_file = Some(path.to_string());
+        }
+    }
+
     /// Sets `password` to the specified password, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn password(&mut self, argument: Option<&str>) {
@@ -655,6 +2244,22 @@ impl BitcoinOptions {
         }
     }
 
+    /// Sets `payment_code` to the specified payment code, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn payment_code(&mut self, argument: Option<&str>) {
+        if let Some(payment_code) = argument {
+            self.payment_code = Some(payment_code.to_string());
+        }
+    }
+
+    /// Sets `policy` to the specified spending policy, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn policy(&mut self, argument: Option<&str>) {
+        if let Some(policy) = argument {
+            self.policy = Some(policy.to_string());
+        }
+    }
+
     /// Imports a wallet for the specified private key, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn private(&mut self, argument: Option<&str>) {
@@ -671,6 +2276,48 @@ impl BitcoinOptions {
         }
     }
 
+    /// Enables `public_only` if the specified boolean value is set.
+    /// Once enabled at any level of the command, the secrets of the wallet(s) remain withheld.
+    fn public_only(&mut self, argument: bool) {
+        if argument {
+            self.public_only = true;
+        }
+    }
+
+    /// Sets `qr` to the specified boolean value, overriding its previous state.
+    fn qr(&mut self, argument: bool) {
+        self.qr = argument;
+    }
+
+    /// Sets `qr_file` to the specified file path, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn qr_file(&mut self, argument: Option<&str>) {
+        if let Some(qr_file) = argument {
+            self.qr_file = Some(qr_file.to_string());
+        }
+    }
+
+    /// Sets `transaction_hex` to the specified raw transaction, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn raw(&mut self, argument: Option<&str>) {
+        if let Some(transaction_hex) = argument {
+            self.transaction_hex = Some(transaction_hex.to_string());
+        }
+    }
+
+    /// Sets `rbf` to the specified boolean value, overriding its previous state.
+    fn rbf(&mut self, argument: bool) {
+        self.rbf = argument;
+    }
+
+    /// Sets `rpc` to the specified JSON-RPC URL, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn rpc(&mut self, argument: Option<&str>) {
+        if let Some(rpc) = argument {
+            self.rpc = Some(rpc.to_string());
+        }
+    }
+
     /// Sets `transaction_hex` and `transaction_inputs` to the specified transaction values, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn sign_raw_transaction(&mut self, argument: Option<Values>) {
@@ -681,11 +2328,36 @@ impl BitcoinOptions {
         }
     }
 
+    /// Sets `show_root` to the specified boolean value, overriding its previous state.
+    fn show_root(&mut self, argument: bool) {
+        self.show_root = argument;
+    }
+
+    /// Sets `slip132` to the specified boolean value, overriding its previous state.
+    fn slip132(&mut self, argument: bool) {
+        self.slip132 = argument;
+    }
+
+    /// Sets `to` to the specified form of a converted private key, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn to(&mut self, argument: Option<&str>) {
+        if let Some(to) = argument {
+            self.to = Some(to.to_string());
+        }
+    }
+
+    /// Enables `uncompressed` if the specified boolean value is set.
+    fn uncompressed(&mut self, argument: bool) {
+        if argument {
+            self.uncompressed = true;
+        }
+    }
+
     /// Sets `word_count` to the specified word count, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn word_count(&mut self, argument: Option<u8>) {
         if let Some(word_count) = argument {
-            self.word_count = word_count;
+            self.word_count = Some(word_count);
         }
     }
 
@@ -704,6 +2376,67 @@ impl BitcoinOptions {
         }
     }
 
+    /// Returns the derivation paths with the specified account, chain, derivation, indices, and path.
+    /// If `default` is enabled, then return the default path if no derivation was provided.
+    fn to_derivation_paths(&self, default: bool) -> Vec<Option<String>> {
+        let start = self.index;
+        let end = start + self.indices;
+        let mut options = self.clone();
+        (start..end)
+            .map(|index| {
+                // Sets the index to the specified index
+                options.index(Some(index));
+                // Generates the derivation path for the specified information
+                options.to_derivation_path(default)
+            })
+            .collect()
+    }
+
+    /// Sets `verify` to the specified boolean value, overriding its previous state.
+    fn verify(&mut self, argument: bool) {
+        self.verify = argument;
+    }
+
+    /// Sets `uri` to the specified payment request URI, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn uri(&mut self, argument: Option<&str>) {
+        if let Some(uri) = argument {
+            self.uri = Some(uri.to_string());
+        }
+    }
+
+    /// Sets `uri_amount` to the specified amount of a payment request, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn uri_amount(&mut self, argument: Option<&str>) {
+        if let Some(amount) = argument {
+            self.uri_amount = Some(amount.to_string());
+        }
+    }
+
+    /// Sets `uri_label` to the specified label of a payment request, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn uri_label(&mut self, argument: Option<&str>) {
+        if let Some(label) = argument {
+            self.uri_label = Some(label.to_string());
+        }
+    }
+
+    /// Sets `uri_message` to the specified message of a payment request, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn uri_message(&mut self, argument: Option<&str>) {
+        if let Some(message) = argument {
+            self.uri_message = Some(message.to_string());
+        }
+    }
+
+    /// Sets `utxos` to the specified file of unspent outputs, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn utxos(&mut self, argument: Option<&str>) {
+        if let Some(utxos) = argument {
+            self.utxos = Some(utxos.to_string());
+        }
+    }
+
     /// Sets `version` to the specified transaction version, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn version(&mut self, argument: Option<u32>) {
@@ -720,35 +2453,166 @@ impl CLI for BitcoinCLI {
 
     const NAME: NameType = "bitcoin";
     const ABOUT: AboutType = "Generates a Bitcoin wallet (include -h for more options)";
-    const FLAGS: &'static [FlagType] = &[flag::JSON];
-    const OPTIONS: &'static [OptionType] = &[option::COUNT, option::FORMAT_BITCOIN, option::NETWORK_BITCOIN];
+    const FLAGS: &'static [FlagType] = &[
+        flag::AUDIT_RNG,
+        flag::JSON,
+        flag::OUTPUT_FILE,
+        flag::OUTPUT_FORMAT,
+        flag::PUBLIC_ONLY,
+        flag::QR,
+        flag::QR_FILE,
+    ];
+    const OPTIONS: &'static [OptionType] = &[
+        option::COUNT,
+        option::FORMAT_BITCOIN,
+        option::NETWORK_BITCOIN,
+        option::UNCOMPRESSED_BITCOIN,
+    ];
     const SUBCOMMANDS: &'static [SubCommandType] = &[
+        subcommand::CONVERT_BITCOIN,
+        subcommand::EXPORT_BITCOIN,
         subcommand::HD_BITCOIN,
+        #[cfg(feature = "hw-ledger")]
+        subcommand::HW_BITCOIN,
         subcommand::IMPORT_BITCOIN,
         subcommand::IMPORT_HD_BITCOIN,
+        subcommand::MINISCRIPT_BITCOIN,
+        subcommand::PAPER_BITCOIN,
+        subcommand::PAYMENT_CODE_BITCOIN,
         subcommand::TRANSACTION_BITCOIN,
+        subcommand::URI_BITCOIN,
+        subcommand::VALIDATE_BITCOIN,
     ];
 
     /// Handle all CLI arguments and flags for Bitcoin
     #[cfg_attr(tarpaulin, skip)]
     fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
         let mut options = BitcoinOptions::default();
-        options.parse(arguments, &["count", "format", "json", "network"]);
+        options.parse(
+            arguments,
+            &[
+                "audit rng",
+                "count",
+                "format",
+                "json",
+                "network",
+                "output file",
+                "output format",
+                "public only",
+                "qr",
+                "qr file",
+                "uncompressed",
+            ],
+        )?;
 
         match arguments.subcommand() {
+            ("convert", Some(arguments)) => {
+                options.subcommand = Some("convert".into());
+                options.parse(
+                    arguments,
+                    &["json", "network", "output file", "output format", "public only"],
+                )?;
+                options.parse(arguments, &["private", "to"])?;
+            }
+            ("export", Some(arguments)) => {
+                options.subcommand = Some("export".into());
+                // Export a full keypool by default
+                options.index_range(Some("0..1000"))?;
+                options.parse(arguments, &["network", "output file"])?;
+                options.parse(
+                    arguments,
+                    &[
+                        "account",
+                        "export derivation",
+                        "export format",
+                        "extended private",
+                        "extended public",
+                        "index range",
+                        "mnemonic",
+                        "password",
+                    ],
+                )?;
+            }
             ("hd", Some(arguments)) => {
                 options.subcommand = Some("hd".into());
-                options.parse(arguments, &["count", "json", "network"]);
-                options.parse(arguments, &["derivation", "language", "password", "word count"]);
+                options.parse(
+                    arguments,
+                    &[
+                        "audit rng",
+                        "count",
+                        "json",
+                        "network",
+                        "output file",
+                        "output format",
+                        "public only",
+                        "qr",
+                        "qr file",
+                    ],
+                )?;
+                options.parse(
+                    arguments,
+                    &[
+                        "derivation",
+                        "entropy",
+                        "entropy file",
+                        "index range",
+                        "language",
+                        "password",
+                        "slip132",
+                        "word count",
+                    ],
+                )?;
+            }
+            ("hw", Some(arguments)) => {
+                options.subcommand = Some("hw".into());
+                options.parse(
+                    arguments,
+                    &[
+                        "json",
+                        "network",
+                        "output file",
+                        "output format",
+                        "public only",
+                        "qr",
+                        "qr file",
+                    ],
+                )?;
+                options.parse(
+                    arguments,
+                    &["account", "chain", "derivation", "format", "index", "verify"],
+                )?;
             }
             ("import", Some(arguments)) => {
                 options.subcommand = Some("import".into());
-                options.parse(arguments, &["format", "json", "network"]);
-                options.parse(arguments, &["address", "private", "public"]);
+                options.parse(
+                    arguments,
+                    &[
+                        "format",
+                        "json",
+                        "network",
+                        "output file",
+                        "output format",
+                        "public only",
+                        "qr",
+                        "qr file",
+                    ],
+                )?;
+                options.parse(arguments, &["address", "private", "public"])?;
             }
             ("import-hd", Some(arguments)) => {
                 options.subcommand = Some("import-hd".into());
-                options.parse(arguments, &["json", "network"]);
+                options.parse(
+                    arguments,
+                    &[
+                        "json",
+                        "network",
+                        "output file",
+                        "output format",
+                        "public only",
+                        "qr",
+                        "qr file",
+                    ],
+                )?;
                 options.parse(
                     arguments,
                     &[
@@ -758,17 +2622,221 @@ impl CLI for BitcoinCLI {
                         "extended private",
                         "extended public",
                         "index",
+                        "index range",
+                        "mnemonic",
+                        "mnemonic type",
+                        "neuter",
+                        "password",
+                        "show root",
+                        "slip132",
+                        "word count",
+                    ],
+                )?;
+            }
+            ("miniscript", Some(arguments)) => {
+                options.subcommand = Some("miniscript".into());
+                options.parse(arguments, &["json", "network", "output file", "output format"])?;
+                options.parse(arguments, &["policy"])?;
+            }
+            ("paper", Some(arguments)) => {
+                // A derivation path generates an HD paper wallet with a mnemonic
+                options.subcommand = match arguments.is_present("derivation") {
+                    true => Some("paper hd".into()),
+                    false => Some("paper".into()),
+                };
+                options.parse(arguments, &["audit rng", "count", "format", "network", "uncompressed"])?;
+                options.parse(arguments, &["derivation", "language", "paper file", "word count"])?;
+            }
+            ("paymentcode", Some(arguments)) => {
+                options.subcommand = Some("paymentcode".into());
+                options.parse(arguments, &["json", "network", "output file", "output format"])?;
+                options.parse(
+                    arguments,
+                    &[
+                        "account",
+                        "counterparty",
+                        "format",
+                        "index",
+                        "index range",
                         "mnemonic",
                         "password",
+                        "payment code",
                     ],
-                );
+                )?;
             }
             ("transaction", Some(arguments)) => {
                 options.subcommand = Some("transaction".into());
                 options.parse(
                     arguments,
                     &["createrawtransaction", "lock time", "signrawtransaction", "version"],
-                );
+                )?;
+
+                match arguments.subcommand() {
+                    ("bump-fee", Some(arguments)) => {
+                        options.subcommand = Some("transaction bump-fee".into());
+                        options.parse(
+                            arguments,
+                            &[
+                                "json",
+                                "network",
+                                "output file",
+                                "output format",
+                                "public only",
+                                "qr",
+                                "qr file",
+                            ],
+                        )?;
+                        options.parse(
+                            arguments,
+                            &[
+                                "change output",
+                                "derivation",
+                                "fee rate",
+                                "format",
+                                "inputs",
+                                "mnemonic",
+                                "password",
+                                "private",
+                                "raw",
+                            ],
+                        )?;
+                    }
+                    ("consolidate", Some(arguments)) => {
+                        options.subcommand = Some("transaction consolidate".into());
+                        options.parse(
+                            arguments,
+                            &[
+                                "json",
+                                "network",
+                                "output file",
+                                "output format",
+                                "public only",
+                                "qr",
+                                "qr file",
+                            ],
+                        )?;
+                        options.parse(
+                            arguments,
+                            &[
+                                "address",
+                                "extended private",
+                                "fee rate",
+                                "lock time",
+                                "max fee percent",
+                                "rbf",
+                                "utxos",
+                                "version",
+                            ],
+                        )?;
+                    }
+                    ("create", Some(arguments)) => {
+                        options.subcommand = Some("transaction create".into());
+                        options.parse(
+                            arguments,
+                            &[
+                                "json",
+                                "network",
+                                "output file",
+                                "output format",
+                                "public only",
+                                "qr",
+                                "qr file",
+                            ],
+                        )?;
+                        options.parse(
+                            arguments,
+                            &[
+                                "change",
+                                "coin selection",
+                                "fee rate",
+                                "inputs",
+                                "lock time",
+                                "max fee percent",
+                                "op return",
+                                "outputs",
+                                "rbf",
+                                "utxos",
+                                "version",
+                            ],
+                        )?;
+                    }
+                    ("decode", Some(arguments)) => {
+                        options.subcommand = Some("transaction decode".into());
+                        options.parse(
+                            arguments,
+                            &[
+                                "json",
+                                "network",
+                                "output file",
+                                "output format",
+                                "public only",
+                                "qr",
+                                "qr file",
+                            ],
+                        )?;
+                        options.parse(arguments, &["raw"])?;
+                    }
+                    ("send", Some(arguments)) => {
+                        options.subcommand = Some("transaction send".into());
+                        options.parse(
+                            arguments,
+                            &["json", "output file", "output format", "public only", "qr", "qr file"],
+                        )?;
+                        options.parse(arguments, &["raw", "rpc"])?;
+                    }
+                    ("sign", Some(arguments)) => {
+                        options.subcommand = Some("transaction sign".into());
+                        options.parse(
+                            arguments,
+                            &[
+                                "json",
+                                "network",
+                                "output file",
+                                "output format",
+                                "public only",
+                                "qr",
+                                "qr file",
+                            ],
+                        )?;
+                        options.parse(
+                            arguments,
+                            &[
+                                "derivation",
+                                "format",
+                                "inputs",
+                                "mnemonic",
+                                "password",
+                                "private",
+                                "raw",
+                            ],
+                        )?;
+                    }
+                    _ => {}
+                };
+            }
+            ("uri", Some(arguments)) => match arguments.subcommand() {
+                ("create", Some(arguments)) => {
+                    options.subcommand = Some("uri create".into());
+                    options.parse(
+                        arguments,
+                        &["json", "output file", "output format", "public only", "qr", "qr file"],
+                    )?;
+                    options.parse(arguments, &["address", "uri amount", "uri label", "uri message"])?;
+                }
+                ("parse", Some(arguments)) => {
+                    options.subcommand = Some("uri parse".into());
+                    options.parse(
+                        arguments,
+                        &["json", "output file", "output format", "public only", "qr", "qr file"],
+                    )?;
+                    options.parse(arguments, &["uri"])?;
+                }
+                _ => {}
+            },
+            ("validate", Some(arguments)) => {
+                options.subcommand = Some("validate".into());
+                options.parse(arguments, &["json", "output file", "output format", "public only"])?;
+                options.parse(arguments, &["address"])?;
             }
             _ => {}
         };
@@ -780,160 +2848,1024 @@ impl CLI for BitcoinCLI {
     #[cfg_attr(tarpaulin, skip)]
     fn print(options: Self::Options) -> Result<(), CLIError> {
         fn output<N: BitcoinNetwork, W: BitcoinWordlist>(options: BitcoinOptions) -> Result<(), CLIError> {
-            let wallets =
-                match options.subcommand.as_ref().map(String::as_str) {
-                    Some("hd") => match options.to_derivation_path(true) {
-                        Some(path) => (0..options.count)
-                            .flat_map(|_| {
-                                match BitcoinWallet::new_hd::<N, W, _>(
-                                    &mut StdRng::from_entropy(),
-                                    options.word_count,
-                                    options.password.as_ref().map(String::as_str),
-                                    &path,
-                                ) {
-                                    Ok(wallet) => vec![wallet],
-                                    _ => vec![],
+            match options.subcommand.as_deref() {
+                Some("export") => return export::<N>(options),
+                Some("import-hd") if options.show_root => return show_root::<N>(options),
+                Some("miniscript") => return miniscript::<N>(options),
+                Some("paymentcode") => return payment_code::<N>(options),
+                Some("validate") => return validate(options),
+                _ => {}
+            };
+
+            // An uncompressed public key only has a legacy address
+            if options.uncompressed && options.format != BitcoinFormat::P2PKH {
+                return Err(CLIError::UnsupportedFormat(format!(
+                    "{} with an uncompressed public key",
+                    options.format
+                )));
+            }
+
+            // Generate new wallets lazily, to stream a large count of wallets to the output
+            let (format, compressed) = (&options.format, !options.uncompressed);
+            let mut rng = wallet_rng(options.audit_rng);
+            let generated: Box<dyn Iterator<Item = Result<BitcoinWallet, CLIError>>> =
+                match options.subcommand.as_deref() {
+                    Some("hd") | Some("paper hd") => match options.to_derivation_path(true) {
+                        Some(path) => {
+                            let (word_count, indices) = (options.word_count.unwrap_or(12), options.indices);
+                            let password = options.password.clone();
+                            let mut entropy = Entropy::new(
+                                options.entropy.as_deref(),
+                                options.entropy_file.as_deref(),
+                                options.audit_rng,
+                            )?;
+                            let paths = options
+                                .to_derivation_paths(true)
+                                .into_iter()
+                                .flatten()
+                                .collect::<Vec<_>>();
+                            let mut generate = move || -> Result<Vec<BitcoinWallet>, CLIError> {
+                                let password = &password.as_deref();
+                                // Sample a new HD wallet
+                                let wallet = BitcoinWallet::new_hd::<N, W>(&mut entropy, word_count, *password, &path)?;
+                                if indices <= 1 {
+                                    return Ok(vec![wallet]);
                                 }
-                            })
-                            .collect(),
-                        None => vec![],
+                                let mnemonic = &wallet.mnemonic.unwrap();
+
+                                // Generate the HD wallet, from `index` to a number of specified `indices`
+                                BitcoinWallet::from_mnemonic_paths::<N, W>(mnemonic, password, &paths)
+                            };
+                            Box::new((0..options.count).flat_map(move |_| match generate() {
+                                Ok(wallets) => wallets.into_iter().map(Ok).collect(),
+                                Err(error) => vec![Err(error)],
+                            }))
+                        }
+                        None => Box::new(iter::empty()),
                     },
-                    Some("import") => {
-                        if let Some(private_key) = options.private {
-                            vec![
-                                BitcoinWallet::from_private_key::<BitcoinMainnet>(&private_key, &options.format).or(
-                                    BitcoinWallet::from_private_key::<BitcoinTestnet>(&private_key, &options.format),
-                                )?,
-                            ]
-                        } else if let Some(public_key) = options.public {
-                            vec![BitcoinWallet::from_public_key::<N>(&public_key, &options.format)?]
-                        } else if let Some(address) = options.address {
-                            vec![BitcoinWallet::from_address::<BitcoinMainnet>(&address)
-                                .or(BitcoinWallet::from_address::<BitcoinTestnet>(&address))?]
-                        } else {
-                            vec![]
+                    None | Some("paper") => Box::new(
+                        (0..options.count).map(move |_| BitcoinWallet::new::<N, _>(&mut rng, format, compressed)),
+                    ),
+                    _ => Box::new(iter::empty()),
+                };
+
+            let wallets = match options.subcommand.as_ref().map(String::as_str) {
+                Some("convert") => match (options.private.as_deref(), options.to.as_deref()) {
+                    (Some(private_key), Some(to)) => BitcoinWallet::from_converted_private_key::<N>(private_key, to)
+                        .or(BitcoinWallet::from_converted_private_key::<BitcoinMainnet>(
+                            private_key,
+                            to,
+                        ))
+                        .or(BitcoinWallet::from_converted_private_key::<BitcoinTestnet>(
+                            private_key,
+                            to,
+                        ))
+                        .or(BitcoinWallet::from_converted_private_key::<BitcoinRegtest>(
+                            private_key,
+                            to,
+                        ))?,
+                    _ => vec![],
+                },
+                Some("import") => {
+                    if let Some(private_key) = options.private {
+                        // Regtest private keys share the prefix of testnet, so the specified network is tried first
+                        vec![BitcoinWallet::from_private_key::<N>(&private_key, &options.format)
+                            .or(BitcoinWallet::from_private_key::<BitcoinMainnet>(
+                                &private_key,
+                                &options.format,
+                            ))
+                            .or(BitcoinWallet::from_private_key::<BitcoinTestnet>(
+                                &private_key,
+                                &options.format,
+                            ))?]
+                    } else if let Some(public_key) = options.public {
+                        vec![BitcoinWallet::from_public_key::<N>(&public_key, &options.format)?]
+                    } else if let Some(address) = options.address {
+                        vec![BitcoinWallet::from_address::<N>(&address)
+                            .or(BitcoinWallet::from_address::<BitcoinMainnet>(&address))
+                            .or(BitcoinWallet::from_address::<BitcoinTestnet>(&address))
+                            .or(BitcoinWallet::from_address::<BitcoinRegtest>(&address))?]
+                    } else {
+                        vec![]
+                    }
+                }
+                Some("import-hd") => {
+                    if let Some(mnemonic) = options.mnemonic.as_ref() {
+                        check_word_count(mnemonic, options.word_count)?;
+                    }
+
+                    if let (Some(mnemonic), "electrum") = (options.mnemonic.clone(), options.mnemonic_type.as_str()) {
+                        let password = &options.password.as_deref();
+                        let path = match options.derivation.as_str() {
+                            "custom" => options.path.as_deref(),
+                            _ => None,
+                        };
+
+                        // Generate the Electrum wallets, from `index` to a number of specified `indices`
+                        let mut wallets = vec![];
+                        for index in options.index..options.index + options.indices {
+                            wallets.push(BitcoinWallet::from_electrum_mnemonic::<N>(
+                                &mnemonic,
+                                password,
+                                path,
+                                options.chain,
+                                index,
+                            )?);
                         }
+                        wallets
+                    } else if let Some(mnemonic) = options.mnemonic.clone() {
+                        let password = &options.password.as_ref().map(String::as_str);
+
+                        // Generate the mnemonic wallets, from `index` to a number of specified `indices`
+                        let paths = options
+                            .to_derivation_paths(true)
+                            .into_iter()
+                            .flatten()
+                            .collect::<Vec<_>>();
+                        BitcoinWallet::from_mnemonic_paths_any_language::<N>(&mnemonic, password, &paths)?
+                    } else if let Some(extended_private_key) = options.extended_private_key.clone() {
+                        let key = &extended_private_key;
+
+                        // Generate the extended private keys, from `index` to a number of specified `indices`
+                        let mut wallets = vec![];
+                        for path in &options.to_derivation_paths(false) {
+                            wallets.push(
+                                BitcoinWallet::from_extended_private_key::<N>(key, path)
+                                    .or(BitcoinWallet::from_extended_private_key::<BitcoinMainnet>(key, path))
+                                    .or(BitcoinWallet::from_extended_private_key::<BitcoinTestnet>(key, path))?,
+                            );
+                        }
+                        wallets
+                    } else if let Some(extended_public_key) = options.extended_public_key.clone() {
+                        let key = &extended_public_key;
+
+                        // Generate the extended public keys, from `index` to a number of specified `indices`.
+                        // The hardened paths of the other derivations are not derivable from an extended
+                        // public key, so the addresses of its `chain` are derived, as in m/{chain}/{index}.
+                        let paths = match options.derivation.as_str() {
+                            "custom" => options.to_derivation_paths(false),
+                            _ => (options.index..options.index + options.indices)
+                                .map(|index| Some(format!("m/{}/{}", options.chain, index)))
+                                .collect(),
+                        };
+                        let mut wallets = vec![];
+                        for path in &paths {
+                            wallets.push(
+                                BitcoinWallet::from_extended_public_key::<N>(key, path)
+                                    .or(BitcoinWallet::from_extended_public_key::<BitcoinMainnet>(key, path))
+                                    .or(BitcoinWallet::from_extended_public_key::<BitcoinTestnet>(key, path))?,
+                            );
+                        }
+                        wallets
+                    } else {
+                        vec![]
+                    }
+                }
+                #[cfg(feature = "hw-ledger")]
+                Some("hw") => {
+                    let format = match options.derivation.as_str() {
+                        "bip49" => BitcoinFormat::P2SH_P2WPKH,
+                        _ => options.format.clone(),
+                    };
+                    match options.to_derivation_path(true) {
+                        Some(path) => vec![BitcoinWallet::from_ledger::<N>(
+                            &Ledger::open()?,
+                            &path,
+                            &format,
+                            options.verify,
+                        )?],
+                        None => vec![],
                     }
-                    Some("import-hd") => {
-                        if let Some(mnemonic) = options.mnemonic.clone() {
-                            let password = &options.password.as_ref().map(String::as_str);
-
-                            match options.to_derivation_path(true) {
-                                Some(path) => vec![BitcoinWallet::from_mnemonic::<N, ChineseSimplified>(
-                                    &mnemonic, password, &path,
-                                )
-                                .or(BitcoinWallet::from_mnemonic::<N, ChineseTraditional>(
-                                    &mnemonic, password, &path,
+                }
+                Some("transaction") => {
+                    if let (Some(transaction_inputs), Some(transaction_outputs)) =
+                        (options.transaction_inputs.clone(), options.transaction_outputs.clone())
+                    {
+                        let inputs: &Vec<BitcoinInput> = &from_str(&transaction_inputs)?;
+                        let outputs = transaction_outputs.replace(&['{', '}', '"', ' '][..], "");
+                        let outputs: &Vec<&str> = &outputs.split(",").collect();
+                        let version = options.version.unwrap_or(1);
+                        let lock_time = options.lock_time.unwrap_or(0);
+
+                        vec![
+                            BitcoinWallet::to_raw_transaction::<N>(inputs, outputs, version, lock_time, false)
+                                .or(BitcoinWallet::to_raw_transaction::<BitcoinMainnet>(
+                                    inputs, outputs, version, lock_time, false,
                                 ))
-                                .or(BitcoinWallet::from_mnemonic::<N, English>(&mnemonic, password, &path))
-                                .or(BitcoinWallet::from_mnemonic::<N, French>(&mnemonic, password, &path))
-                                .or(BitcoinWallet::from_mnemonic::<N, Italian>(&mnemonic, password, &path))
-                                .or(BitcoinWallet::from_mnemonic::<N, Japanese>(&mnemonic, password, &path))
-                                .or(BitcoinWallet::from_mnemonic::<N, Korean>(&mnemonic, password, &path))
-                                .or(BitcoinWallet::from_mnemonic::<N, Spanish>(&mnemonic, password, &path))?],
-                                None => vec![],
+                                .or(BitcoinWallet::to_raw_transaction::<BitcoinTestnet>(
+                                    inputs, outputs, version, lock_time, false,
+                                ))?,
+                        ]
+                    } else if let (Some(transaction_hex), Some(transaction_inputs)) =
+                        (options.transaction_hex.clone(), options.transaction_inputs.clone())
+                    {
+                        let inputs: &Vec<BitcoinInput> = &from_str(&transaction_inputs)?;
+
+                        vec![BitcoinWallet::to_signed_transaction::<N>(&transaction_hex, inputs)
+                            .or(BitcoinWallet::to_signed_transaction::<BitcoinMainnet>(
+                                &transaction_hex,
+                                inputs,
+                            ))
+                            .or(BitcoinWallet::to_signed_transaction::<BitcoinTestnet>(
+                                &transaction_hex,
+                                inputs,
+                            ))?]
+                    } else {
+                        vec![]
+                    }
+                }
+                Some("transaction consolidate") => match (options.utxos.as_ref(), options.address.as_ref()) {
+                    (Some(utxos), Some(address)) => {
+                        let utxos: Vec<BitcoinInput> = from_str(&std::fs::read_to_string(utxos)?)?;
+                        vec![BitcoinWallet::to_consolidated_transaction::<N>(
+                            &utxos,
+                            address,
+                            options.fee_rate.unwrap_or(1),
+                            options.extended_private_key.as_deref(),
+                            options.version.unwrap_or(1),
+                            options.lock_time.unwrap_or(0),
+                            options.rbf,
+                            &options.to_policy(),
+                        )?]
+                    }
+                    _ => vec![],
+                },
+                Some("transaction create") => {
+                    let version = options.version.unwrap_or(1);
+                    let lock_time = options.lock_time.unwrap_or(0);
+                    let transaction_outputs = options
+                        .transaction_outputs
+                        .as_ref()
+                        .map(|outputs| outputs.replace(&['{', '}', '"', ' '][..], ""));
+                    let mut outputs: Vec<&str> = transaction_outputs
+                        .iter()
+                        .flat_map(|outputs| outputs.split(","))
+                        .collect();
+                    // The null data output is appended as the `data` output of `createrawtransaction`
+                    let null_data = options.op_return.as_ref().map(|data| format!("data:{}", data));
+                    outputs.extend(null_data.as_deref());
+
+                    match (
+                        options.transaction_inputs.as_ref(),
+                        options.utxos.as_ref(),
+                        outputs.is_empty(),
+                    ) {
+                        (Some(transaction_inputs), _, false) => {
+                            let inputs: &Vec<BitcoinInput> = &from_str(transaction_inputs)?;
+                            vec![BitcoinWallet::to_raw_transaction::<N>(
+                                inputs,
+                                &outputs,
+                                version,
+                                lock_time,
+                                options.rbf,
+                            )?]
+                        }
+                        (None, Some(utxos), false) => {
+                            let utxos: Vec<BitcoinInput> = from_str(&std::fs::read_to_string(utxos)?)?;
+                            let strategy = match options.coin_selection.as_str() {
+                                "largest-first" => CoinSelectionStrategy::LargestFirst,
+                                _ => CoinSelectionStrategy::BranchAndBound,
+                            };
+
+                            vec![BitcoinWallet::to_funded_transaction::<N>(
+                                &utxos,
+                                &outputs,
+                                options.fee_rate.unwrap_or(1),
+                                options.change.as_deref(),
+                                strategy,
+                                version,
+                                lock_time,
+                                options.rbf,
+                                &options.to_policy(),
+                            )?]
+                        }
+                        _ => vec![],
+                    }
+                }
+                Some("transaction decode") => match options.transaction_hex.as_ref() {
+                    Some(transaction_hex) => vec![BitcoinWallet::from_transaction_hex::<N>(transaction_hex)?],
+                    None => vec![],
+                },
+                #[cfg(feature = "broadcast")]
+                Some("transaction send") => match (options.transaction_hex.as_ref(), options.rpc.as_ref()) {
+                    (Some(transaction_hex), Some(rpc)) => vec![BitcoinWallet {
+                        transaction_id: Some(rpc::send_bitcoin_transaction(rpc, transaction_hex)?),
+                        ..Default::default()
+                    }],
+                    _ => vec![],
+                },
+                Some("transaction sign") | Some("transaction bump-fee") => {
+                    match (options.transaction_hex.as_ref(), options.transaction_inputs.as_ref()) {
+                        (Some(transaction_hex), Some(transaction_inputs)) => {
+                            // Resolve the signing key from a private key, or from a mnemonic and derivation path
+                            let private_key = if let Some(private_key) = options.private.clone() {
+                                Some(private_key)
+                            } else if let Some(mnemonic) = options.mnemonic.as_ref() {
+                                let password = &options.password.as_deref();
+                                let path = &options.to_derivation_path(true).unwrap();
+                                BitcoinWallet::from_mnemonic_any_language::<N>(mnemonic, password, path)?.private_key
+                            } else {
+                                None
+                            };
+
+                            // Fill in the key and address of each input that does not specify its own
+                            let mut inputs: Vec<BitcoinInput> = from_str(transaction_inputs)?;
+                            if let Some(private_key) = private_key {
+                                for input in inputs.iter_mut().filter(|input| input.private_key.is_none()) {
+                                    if input.address.is_none() {
+                                        let format = input.to_format::<N>(&options.format)?;
+                                        input.address =
+                                            BitcoinWallet::from_private_key::<N>(&private_key, &format)?.address;
+                                    }
+                                    input.private_key = Some(private_key.clone());
+                                }
+                            }
+
+                            match (options.subcommand.as_deref(), options.fee_rate) {
+                                (Some("transaction bump-fee"), Some(fee_rate)) => {
+                                    vec![BitcoinWallet::to_bumped_transaction::<N>(
+                                        transaction_hex,
+                                        &inputs,
+                                        fee_rate,
+                                        options.change_output,
+                                    )?]
+                                }
+                                (Some("transaction bump-fee"), None) => vec![],
+                                _ => vec![BitcoinWallet::to_signed_transaction::<N>(transaction_hex, &inputs)?],
                             }
-                        } else if let Some(extended_private_key) = options.extended_private_key.clone() {
-                            let key = &extended_private_key;
-                            let path = &options.to_derivation_path(false);
-
-                            vec![BitcoinWallet::from_extended_private_key::<BitcoinMainnet>(key, path)
-                                .or(BitcoinWallet::from_extended_private_key::<BitcoinTestnet>(key, path))?]
-                        } else if let Some(extended_public_key) = options.extended_public_key.clone() {
-                            let key = &extended_public_key;
-                            let path = &options.to_derivation_path(false);
-
-                            vec![BitcoinWallet::from_extended_public_key::<BitcoinMainnet>(key, path)
-                                .or(BitcoinWallet::from_extended_public_key::<BitcoinTestnet>(key, path))?]
-                        } else {
-                            vec![]
                         }
+                        _ => vec![],
                     }
-                    Some("transaction") => {
-                        if let (Some(transaction_inputs), Some(transaction_outputs)) =
-                            (options.transaction_inputs.clone(), options.transaction_outputs.clone())
-                        {
-                            let inputs: &Vec<BitcoinInput> = &from_str(&transaction_inputs)?;
-                            let outputs = transaction_outputs.replace(&['{', '}', '"', ' '][..], "");
-                            let outputs: &Vec<&str> = &outputs.split(",").collect();
-                            let version = options.version.unwrap_or(1);
-                            let lock_time = options.lock_time.unwrap_or(0);
-
-                            vec![BitcoinWallet::to_raw_transaction::<BitcoinMainnet>(
-                                inputs, outputs, version, lock_time,
-                            )
-                            .or(BitcoinWallet::to_raw_transaction::<BitcoinTestnet>(
-                                inputs, outputs, version, lock_time,
-                            ))?]
-                        } else if let (Some(transaction_hex), Some(transaction_inputs)) =
-                            (options.transaction_hex.clone(), options.transaction_inputs.clone())
-                        {
-                            let inputs: &Vec<BitcoinInput> = &from_str(&transaction_inputs)?;
-
-                            vec![
-                                BitcoinWallet::to_signed_transaction::<BitcoinMainnet>(&transaction_hex, inputs).or(
-                                    BitcoinWallet::to_signed_transaction::<BitcoinTestnet>(&transaction_hex, inputs),
-                                )?,
-                            ]
-                        } else {
-                            vec![]
+                }
+                Some("uri create") => match options.address.as_ref() {
+                    Some(address) => {
+                        let (amount, label, message) = (
+                            options.uri_amount.as_deref(),
+                            options.uri_label.as_deref(),
+                            options.uri_message.as_deref(),
+                        );
+                        vec![BitcoinWallet::to_uri::<BitcoinMainnet>(address, amount, label, message)
+                            .or(BitcoinWallet::to_uri::<BitcoinTestnet>(address, amount, label, message))
+                            .or(BitcoinWallet::to_uri::<BitcoinRegtest>(address, amount, label, message))?]
+                    }
+                    None => vec![],
+                },
+                Some("uri parse") => match options.uri.as_ref() {
+                    Some(uri) => match BitcoinUri::<BitcoinMainnet>::from_str(uri) {
+                        // Parse the URI on testnet or regtest if its address is not a mainnet address
+                        Err(UriError::Crate("address", _)) => match BitcoinUri::<BitcoinTestnet>::from_str(uri) {
+                            Err(UriError::Crate("address", _)) => {
+                                vec![BitcoinWallet::from_uri(&BitcoinUri::<BitcoinRegtest>::from_str(uri)?)?]
+                            }
+                            uri => vec![BitcoinWallet::from_uri(&uri?)?],
+                        },
+                        uri => vec![BitcoinWallet::from_uri(&uri?)?],
+                    },
+                    None => vec![],
+                },
+                _ => vec![],
+            };
+            // Encode the HD wallets with the address format and SLIP-132 version bytes of their derivation purpose
+            let slip132 = options.slip132;
+            let wallets = wallets
+                .into_iter()
+                .map(Ok)
+                .chain(generated)
+                .map(|wallet| match slip132 {
+                    true => wallet?.into_slip132(),
+                    false => wallet,
+                });
+
+            match options.paper_file.as_deref() {
+                Some(path) => {
+                    if options.public_only {
+                        return Err(CLIError::UnsupportedFormat("paper wallet with --public-only".into()));
+                    }
+                    let mut wallets = wallets.collect::<Result<Vec<BitcoinWallet>, CLIError>>()?;
+                    let paper_wallets = wallets
+                        .iter()
+                        .filter_map(BitcoinWallet::to_paper_wallet)
+                        .collect::<Vec<PaperWallet>>();
+                    paper::output("Bitcoin", &paper_wallets, path)?;
+                    wallets.iter_mut().for_each(Zeroize::zeroize);
+                }
+                None => {
+                    let path = options.output_file.as_deref();
+                    let mut writer = WalletWriter::new(&options.output_format, path, BitcoinWallet::FIELDS)?;
+                    for (index, wallet) in wallets.enumerate() {
+                        let mut wallet = wallet?;
+                        if options.neuter {
+                            wallet.neuter();
+                        }
+                        if options.public_only {
+                            wallet.public_only();
                         }
+                        writer.write(&wallet)?;
+                        if let Some(qr_data) = wallet.to_qr_data() {
+                            qr::output(qr_data, index, options.qr, options.qr_file.as_deref())?;
+                        }
+                        wallet.zeroize();
                     }
-                    _ => (0..options.count)
-                        .flat_map(
-                            |_| match BitcoinWallet::new::<N, _>(&mut StdRng::from_entropy(), &options.format) {
-                                Ok(wallet) => vec![wallet],
-                                _ => vec![],
-                            },
-                        )
-                        .collect(),
-                };
+                    writer.finish()?;
+                }
+            };
+
+            Ok(())
+        }
+
+        /// Prints the seed, the master keys and fingerprint, and the bip44, bip49, and bip84 account keys
+        /// of the mnemonic in a single block
+        fn show_root<N: BitcoinNetwork>(options: BitcoinOptions) -> Result<(), CLIError> {
+            let mnemonic = match options.mnemonic.as_ref() {
+                Some(mnemonic) => mnemonic,
+                None => return Ok(()),
+            };
+            check_word_count(mnemonic, options.word_count)?;
+
+            let mut root_key =
+                BitcoinRootKey::from_mnemonic::<N>(mnemonic, options.password.as_deref(), options.account)?;
+            if options.public_only {
+                root_key.zeroize();
+            }
+            let mut writer = WalletWriter::new(
+                &options.output_format,
+                options.output_file.as_deref(),
+                BitcoinRootKey::FIELDS,
+            )?;
+            let result = writer.write(&root_key);
+            root_key.zeroize();
+            result?;
+            writer.finish()
+        }
+
+        /// Prints the miniscript, witness script, and P2WSH address compiled from the spending policy
+        fn miniscript<N: BitcoinNetwork>(options: BitcoinOptions) -> Result<(), CLIError> {
+            let policy = match options.policy.as_ref() {
+                Some(policy) => policy,
+                None => return Ok(()),
+            };
+            let mut writer = WalletWriter::new(
+                &options.output_format,
+                options.output_file.as_deref(),
+                BitcoinMiniscript::FIELDS,
+            )?;
+            writer.write(&BitcoinMiniscript::from_policy::<N>(policy)?)?;
+            writer.finish()
+        }
+
+        /// Prints the payment code of the mnemonic, or the given payment code, with its notification address,
+        /// and the deposit addresses shared with the counterparty payment code
+        fn payment_code<N: BitcoinNetwork>(options: BitcoinOptions) -> Result<(), CLIError> {
+            let wallets = match (options.payment_code.as_ref(), options.mnemonic.as_ref()) {
+                (Some(payment_code), _) => vec![BitcoinPaymentCodeWallet::from_payment_code::<N>(payment_code)?],
+                (None, Some(mnemonic)) => BitcoinPaymentCodeWallet::from_mnemonic::<N>(
+                    mnemonic,
+                    options.password.as_deref(),
+                    options.account,
+                    options.counterparty.as_deref(),
+                    options.index..options.index + options.indices,
+                    &options.format,
+                )?,
+                (None, None) => return Ok(()),
+            };
+            let mut writer = WalletWriter::new(
+                &options.output_format,
+                options.output_file.as_deref(),
+                BitcoinPaymentCodeWallet::FIELDS,
+            )?;
+            wallets.iter().try_for_each(|wallet| writer.write(wallet))?;
+            writer.finish()
+        }
+
+        /// Exports the account of the HD wallet as descriptors for HWI and Bitcoin Core
+        fn export<N: BitcoinNetwork>(options: BitcoinOptions) -> Result<(), CLIError> {
+            let purpose = match options.derivation.as_str() {
+                "bip49" => 49,
+                "bip84" => 84,
+                _ => 44,
+            };
+            let path = BIP32Path::default()
+                .extend(vec![
+                    ChildIndex::hardened(purpose)?,
+                    N::HD_COIN_TYPE,
+                    ChildIndex::hardened(options.account)?,
+                ])?
+                .to_string();
+            let range = (options.index, options.index + options.indices);
+
+            // Resolve the master key from a mnemonic or an extended private key
+            let mut master = if let Some(mnemonic) = options.mnemonic.as_ref() {
+                let password = &options.password.as_deref();
+                Some(BitcoinWallet::from_mnemonic_any_language::<N>(mnemonic, password, "m")?)
+            } else if let Some(extended_private_key) = options.extended_private_key.as_ref() {
+                Some(BitcoinWallet::from_extended_private_key::<N>(
+                    extended_private_key,
+                    &None,
+                )?)
+            } else {
+                None
+            };
 
-            match options.json {
-                true => println!("{}\n", serde_json::to_string_pretty(&wallets)?),
-                false => wallets.iter().for_each(|wallet| println!("{}\n", wallet)),
+            let descriptors = match (master.as_ref(), options.extended_public_key.as_ref()) {
+                (Some(master), _) => {
+                    let mut account = BitcoinWallet::from_extended_private_key::<N>(
+                        master.extended_private_key.as_ref().unwrap(),
+                        &Some(path),
+                    )?;
+                    let descriptors = account.to_descriptors::<N>(purpose, master.to_fingerprint()?.as_deref(), range);
+                    account.zeroize();
+                    descriptors?
+                }
+                // An extended public key is exported as the account key, without its key origin
+                (None, Some(extended_public_key)) => {
+                    BitcoinWallet::from_extended_public_key::<N>(extended_public_key, &None)?
+                        .to_descriptors::<N>(purpose, None, range)?
+                }
+                _ => vec![],
+            };
+            master.iter_mut().for_each(Zeroize::zeroize);
+
+            match options.export_format.as_str() {
+                "hwi" => write_output(
+                    &serde_json::to_string_pretty(&descriptors)?,
+                    options.output_file.as_deref(),
+                )?,
+                format => return Err(CLIError::UnsupportedFormat(format.into())),
             };
 
             Ok(())
         }
 
+        /// Validates the address, printing its network, format, checksum, and payload
+        fn validate(options: BitcoinOptions) -> Result<(), CLIError> {
+            let address = options.address.as_deref().unwrap_or_default();
+            let mut diagnostics = AddressDiagnostics::new(address);
+
+            // Decode the checksum and payload of a bech32 or a base58 address, and return its prefix
+            let prefix = match address.get(0..2).map(str::to_lowercase).as_deref() {
+                Some("bc") | Some("tb") => match from_bech32(address) {
+                    Ok((hrp, data)) => {
+                        diagnostics.checksum = Some(data.is_some());
+                        // Skip the witness version of the witness program
+                        if let Some(data) = data.filter(|data| !data.is_empty()) {
+                            match Vec::<u8>::from_base32(&data[1..]) {
+                                Ok(program) => diagnostics.payload = Some(hex::encode(program)),
+                                Err(error) => diagnostics.error(AddressError::from(error)),
+                            }
+                        }
+                        hrp.into_bytes()
+                    }
+                    Err(error) => {
+                        diagnostics.error(error);
+                        vec![]
+                    }
+                },
+                _ => match from_base58_check(address) {
+                    Ok((data, checksum)) => {
+                        diagnostics.checksum = Some(checksum);
+                        diagnostics.payload = Some(hex::encode(&data[1..]));
+                        data
+                    }
+                    Err(error) => {
+                        diagnostics.error(error);
+                        vec![]
+                    }
+                },
+            };
+
+            // Parse the address for the network of its prefix, to verify its format and length
+            // Regtest bech32 addresses share the first characters of mainnet, and its base58 addresses the prefixes of testnet
+            let format = match prefix.get(0..2) {
+                _ if prefix == BitcoinRegtest::to_address_prefix(&BitcoinFormat::Bech32) => {
+                    diagnostics.network = Some(BitcoinRegtest::NAME.into());
+                    BitcoinAddress::<BitcoinRegtest>::from_str(address).map(|address| address.format())
+                }
+                Some(prefix) if BitcoinMainnet::from_address_prefix(prefix).is_ok() => {
+                    diagnostics.network = Some(BitcoinMainnet::NAME.into());
+                    BitcoinAddress::<BitcoinMainnet>::from_str(address).map(|address| address.format())
+                }
+                Some(prefix) if BitcoinTestnet::from_address_prefix(prefix).is_ok() => {
+                    diagnostics.network = Some(BitcoinTestnet::NAME.into());
+                    BitcoinAddress::<BitcoinTestnet>::from_str(address).map(|address| address.format())
+                }
+                _ => Err(AddressError::InvalidPrefix(prefix.into_iter().take(2).collect())),
+            };
+            match format {
+                Ok(format) => diagnostics.format = Some(format.to_string()),
+                Err(error) => diagnostics.error(error),
+            };
+
+            diagnostics::output(
+                &diagnostics.finish(),
+                &options.output_format,
+                options.output_file.as_deref(),
+            )
+        }
+
         match options.language.as_str() {
             "chinese_simplified" => match options.network.as_str() {
                 "testnet" => output::<BitcoinTestnet, ChineseSimplified>(options),
+                "regtest" => output::<BitcoinRegtest, ChineseSimplified>(options),
                 _ => output::<BitcoinMainnet, ChineseSimplified>(options),
             },
             "chinese_traditional" => match options.network.as_str() {
                 "testnet" => output::<BitcoinTestnet, ChineseTraditional>(options),
+                "regtest" => output::<BitcoinRegtest, ChineseTraditional>(options),
                 _ => output::<BitcoinMainnet, ChineseTraditional>(options),
             },
             "english" => match options.network.as_str() {
                 "testnet" => output::<BitcoinTestnet, English>(options),
+                "regtest" => output::<BitcoinRegtest, English>(options),
                 _ => output::<BitcoinMainnet, English>(options),
             },
             "french" => match options.network.as_str() {
                 "testnet" => output::<BitcoinTestnet, French>(options),
+                "regtest" => output::<BitcoinRegtest, French>(options),
                 _ => output::<BitcoinMainnet, French>(options),
             },
             "italian" => match options.network.as_str() {
                 "testnet" => output::<BitcoinTestnet, Italian>(options),
+                "regtest" => output::<BitcoinRegtest, Italian>(options),
                 _ => output::<BitcoinMainnet, Italian>(options),
             },
             "japanese" => match options.network.as_str() {
                 "testnet" => output::<BitcoinTestnet, Japanese>(options),
+                "regtest" => output::<BitcoinRegtest, Japanese>(options),
                 _ => output::<BitcoinMainnet, Japanese>(options),
             },
             "korean" => match options.network.as_str() {
                 "testnet" => output::<BitcoinTestnet, Korean>(options),
+                "regtest" => output::<BitcoinRegtest, Korean>(options),
                 _ => output::<BitcoinMainnet, Korean>(options),
             },
             "spanish" => match options.network.as_str() {
                 "testnet" => output::<BitcoinTestnet, Spanish>(options),
+                "regtest" => output::<BitcoinRegtest, Spanish>(options),
                 _ => output::<BitcoinMainnet, Spanish>(options),
             },
             _ => match options.network.as_str() {
                 "testnet" => output::<BitcoinTestnet, English>(options),
+                "regtest" => output::<BitcoinRegtest, English>(options),
                 _ => output::<BitcoinMainnet, English>(options),
             },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_mnemonic_paths() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let paths = [
+            "m/44'/0'/0'/0/0",
+            "m/44'/0'/0'/0/1",
+            "m/49'/0'/0'/0/0",
+            "m/49'/0'/0'/0/1",
+            "m/0'/0'/1'",
+            "m",
+        ]
+        .iter()
+        .map(|path| path.to_string())
+        .collect::<Vec<String>>();
+
+        // The children of a cached parent are the keys derived from the master key along their full paths
+        let master = BitcoinMnemonic::<BitcoinMainnet, English>::from_phrase(mnemonic)
+            .unwrap()
+            .to_extended_private_key(None)
+            .unwrap();
+        let wallets = BitcoinWallet::from_mnemonic_paths::<BitcoinMainnet, English>(mnemonic, &None, &paths).unwrap();
+        assert_eq!(paths.len(), wallets.len());
+        for (path, wallet) in paths.iter().zip(wallets) {
+            let expected = master.derive(&BitcoinDerivationPath::from_str(path).unwrap()).unwrap();
+            assert_eq!(Some(path.as_str()), wallet.path.as_deref());
+            assert_eq!(Some(expected.to_string()), wallet.extended_private_key);
+            assert_eq!(Some(expected.format().to_string()), wallet.format);
+        }
+    }
+
+    #[test]
+    fn import_word_count() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon agent";
+        assert!(check_word_count(mnemonic, None).is_ok());
+        assert!(check_word_count(mnemonic, Some(18)).is_ok());
+        assert!(check_word_count(mnemonic, Some(12)).is_err());
+
+        let wallet = BitcoinWallet::from_mnemonic_any_language::<BitcoinMainnet>(mnemonic, &None, "m").unwrap();
+        assert_eq!(
+            "xprv9s21ZrQH143K48UaviLRexBBQSB7EmGa5J9hjUf91xP9RSr2e9BrBZ67ks4jLKuXXSzw1gYXmX9vtbYBcANxztsb2yWCQXYyEBtrv88EXDw",
+            wallet.extended_private_key.unwrap()
+        );
+    }
+
+    #[test]
+    fn descriptor_checksum() {
+        assert_eq!("89f8spxm", BitcoinDescriptor::checksum("raw(deadbeef)").unwrap());
+        assert_eq!(
+            "02wpgw69",
+            BitcoinDescriptor::checksum("addr(mkmZxiEcEd8ZqjQWVZuC6so5dFMKEFpN2j)").unwrap()
+        );
+        assert!(BitcoinDescriptor::checksum("raw(deadbeef)\n").is_err());
+    }
+
+    #[test]
+    fn to_descriptors() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let master = BitcoinWallet::from_mnemonic::<BitcoinMainnet, English>(mnemonic, &None, "m").unwrap();
+        let account = BitcoinWallet::from_extended_private_key::<BitcoinMainnet>(
+            master.extended_private_key.as_ref().unwrap(),
+            &Some("m/84'/0'/0'".into()),
+        )
+        .unwrap();
+
+        let descriptors = account
+            .to_descriptors::<BitcoinMainnet>(84, master.to_fingerprint().unwrap().as_deref(), (0, 1000))
+            .unwrap();
+        assert_eq!(2, descriptors.len());
+        assert!(descriptors[0].desc.starts_with(
+            "wpkh([73c5da0a/84'/0'/0']xpub6CatWdiZiodmUeTDp8LT5or8nmbKNcuyvz7WyksVFkKB4RHwCD3XyuvPEbvqAQY3rAPshWcMLoP2fMFMKHPJ4ZeZXYVUhLv1VMrjPC7PW6V/0/*)#"
+        ));
+        assert!(descriptors[1].desc.contains("/1/*)#"));
+        assert_eq!([0, 999], descriptors[1].range);
+        assert!(descriptors[1].internal);
+    }
+
+    #[test]
+    fn into_slip132() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let wallet = BitcoinWallet::from_mnemonic::<BitcoinMainnet, English>(mnemonic, &None, "m/84'/0'/0'/0/0")
+            .unwrap()
+            .into_slip132()
+            .unwrap();
+        assert!(wallet.extended_private_key.unwrap().starts_with("zprv"));
+        assert!(wallet.extended_public_key.unwrap().starts_with("zpub"));
+        assert_eq!(
+            "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu",
+            wallet.address.as_deref().unwrap()
+        );
+        assert_eq!("bech32", wallet.format.as_deref().unwrap());
+
+        // A derivation path without a SLIP-132 purpose is unchanged
+        let wallet = BitcoinWallet::from_mnemonic::<BitcoinMainnet, English>(mnemonic, &None, "m/0'/0'/0'")
+            .unwrap()
+            .into_slip132()
+            .unwrap();
+        assert!(wallet.extended_public_key.unwrap().starts_with("xpub"));
+        assert_eq!("p2pkh", wallet.format.as_deref().unwrap());
+    }
+
+    #[test]
+    fn regtest() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let wallet = BitcoinWallet::from_mnemonic::<BitcoinRegtest, English>(mnemonic, &None, "m/84'/1'/0'/0/0")
+            .unwrap()
+            .into_slip132()
+            .unwrap();
+        assert!(wallet.extended_public_key.as_deref().unwrap().starts_with("vpub"));
+        assert_eq!(
+            "bcrt1q6rz28mcfaxtmd6v789l9rrlrusdprr9pz3cppk",
+            wallet.address.as_deref().unwrap()
+        );
+        assert_eq!("regtest", wallet.network.as_deref().unwrap());
+
+        // Regtest private keys share the prefix of testnet
+        let private_key = wallet.private_key.unwrap();
+        let wallet = BitcoinWallet::from_private_key::<BitcoinRegtest>(&private_key, &BitcoinFormat::Bech32).unwrap();
+        assert_eq!(
+            "bcrt1q6rz28mcfaxtmd6v789l9rrlrusdprr9pz3cppk",
+            wallet.address.as_deref().unwrap()
+        );
+        assert!(BitcoinWallet::from_address::<BitcoinMainnet>("bcrt1q6rz28mcfaxtmd6v789l9rrlrusdprr9pz3cppk").is_err());
+    }
+
+    #[test]
+    fn consolidate() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let master = BitcoinWallet::from_mnemonic::<BitcoinMainnet, English>(mnemonic, &None, "m").unwrap();
+        let xprv = master.extended_private_key.as_deref();
+        let utxos: Vec<BitcoinInput> = from_str(
+            r#"[
+                {"txid": "61d520ccb74288c96bc1a2b20ea1c0d5a704776dd0164a396efec3ea7040349d", "vout": 0, "amount": 60000, "format": "bech32", "path": "m/84'/0'/0'/0/0"},
+                {"txid": "61d520ccb74288c96bc1a2b20ea1c0d5a704776dd0164a396efec3ea7040349d", "vout": 1, "amount": 40000, "format": "bech32", "path": "m/84'/0'/0'/0/1"}
+            ]"#,
+        )
+        .unwrap();
+        let address = "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu";
+        let policy = BitcoinTransactionPolicy::default();
+        let consolidate = |utxos: &[BitcoinInput], fee_rate: u64, policy: &BitcoinTransactionPolicy| {
+            BitcoinWallet::to_consolidated_transaction::<BitcoinMainnet>(
+                utxos, address, fee_rate, xprv, 2, 0, false, policy,
+            )
+        };
+
+        let wallet = consolidate(&utxos, 1, &policy).unwrap();
+        assert_eq!(Some(178), wallet.fee);
+        let decoded = BitcoinWallet::from_transaction_hex::<BitcoinMainnet>(&wallet.transaction_hex.unwrap()).unwrap();
+        assert_eq!(2, decoded.inputs.unwrap().len());
+        let outputs = decoded.outputs.unwrap();
+        assert_eq!(1, outputs.len());
+        assert_eq!(100_000 - 178, outputs[0].amount);
+
+        // The address of an unspent output must be controlled by the key at its path
+        let mut mismatched = utxos.clone();
+        mismatched[1].address = Some(address.into());
+        assert!(consolidate(&mismatched, 1, &policy).is_err());
+
+        // The unspent outputs must cover the fee and a change output above dust
+        assert!(consolidate(&utxos, 1000, &policy).is_err());
+
+        // The fee of 1780 satoshi is above 1% of the output
+        assert!(consolidate(&utxos, 10, &policy).is_ok());
+        assert!(consolidate(&utxos, 10, &policy.with_max_fee_percentage(1)).is_err());
+    }
+
+    #[test]
+    fn null_data() {
+        let utxos: Vec<BitcoinInput> = from_str(
+            r#"[{"txid": "61d520ccb74288c96bc1a2b20ea1c0d5a704776dd0164a396efec3ea7040349d", "vout": 0, "amount": 60000, "format": "bech32"}]"#,
+        )
+        .unwrap();
+        let change = "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu";
+        let policy = BitcoinTransactionPolicy::default();
+        let fund = |outputs: &[&str]| {
+            BitcoinWallet::to_funded_transaction::<BitcoinMainnet>(
+                &utxos,
+                outputs,
+                2,
+                Some(change),
+                CoinSelectionStrategy::LargestFirst,
+                1,
+                0,
+                false,
+                &policy,
+            )
+        };
+
+        // The null data output has no amount, and its size is funded by the fee
+        let wallet = fund(&["data:48656c6c6f"]).unwrap();
+        let outputs = wallet.outputs.unwrap();
+        assert_eq!(
+            (0, "6a0548656c6c6f"),
+            (outputs[0].amount, outputs[0].script_pub_key.as_str())
+        );
+        assert_eq!(60_000 - 252, outputs[1].amount);
+        assert_eq!(Some(252), wallet.fee);
+
+        assert!(fund(&[&format!("data:{}", "ab".repeat(81))]).is_err());
+        assert!(fund(&["data:not hex"]).is_err());
+
+        // An output may pay to a raw public key script, such as a P2WSH of a custom witness script
+        let script = Script::from_str("51").unwrap().to_p2wsh().to_string();
+        let wallet = fund(&[&format!("script:{}:10000", script)]).unwrap();
+        let outputs = wallet.outputs.unwrap();
+        assert_eq!(
+            (10_000, script.as_str()),
+            (outputs[0].amount, outputs[0].script_pub_key.as_str())
+        );
+        assert!(fund(&["script:not hex:10000"]).is_err());
+    }
+
+    #[test]
+    fn root_key_from_mnemonic() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let root = BitcoinRootKey::from_mnemonic::<BitcoinMainnet>(mnemonic, None, 0).unwrap();
+        assert!(root.seed.as_deref().unwrap().starts_with("5eb00bbd"));
+        assert!(root.master_extended_private_key.as_deref().unwrap().starts_with("xprv"));
+        assert_eq!("73c5da0a", root.master_fingerprint);
+
+        let accounts: Vec<(&str, &str, &str)> = root
+            .accounts
+            .iter()
+            .map(|account| {
+                (
+                    account.path.as_str(),
+                    account.format.as_str(),
+                    account.extended_public_key.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                ("m/44'/0'/0'", "p2pkh", "xpub6BosfCnifzxcFwrSzQiqu2DBVTshkCXacvNsWGYJVVhhawA7d4R5WSWGFNbi8Aw6ZRc1brxMyWMzG3DSSSSoekkudhUd9yLb6qx39T9nMdj"),
+                ("m/49'/0'/0'", "p2sh_p2wpkh", "ypub6Ww3ibxVfGzLrAH1PNcjyAWenMTbbAosGNB6VvmSEgytSER9azLDWCxoJwW7Ke7icmizBMXrzBx9979FfaHxHcrArf3zbeJJJUZPf663zsP"),
+                ("m/84'/0'/0'", "bech32", "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs"),
+            ],
+            accounts
+        );
+    }
+
+    #[test]
+    fn miniscript_from_policy() {
+        let (a, b) = (
+            "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+            "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+        );
+        let policy = format!("or(pk({}),and(pk({}),older(144)))", a, b);
+        let miniscript = BitcoinMiniscript::from_policy::<BitcoinTestnet>(&policy).unwrap();
+        assert_eq!(
+            format!("or_d(pk({}),and_v(v:pk({}),older(144)))", a, b),
+            miniscript.miniscript
+        );
+        assert!(miniscript.address.starts_with("tb1q"));
+        assert_eq!(
+            format!("0020{}", &miniscript.script_pub_key[4..]),
+            miniscript.script_pub_key
+        );
+        assert_eq!(154, miniscript.max_satisfaction_weight);
+
+        assert!(BitcoinMiniscript::from_policy::<BitcoinTestnet>("pk(00)").is_err());
+    }
+
+    #[test]
+    fn payment_code_deposit_addresses() {
+        let alice = "response seminar brave tip suit recall often sound stick owner lottery motion";
+        let bob = "PM8TJS2JxQ5ztXUpBBRnpTbcUXbUHy2T1abfrb3KkAAtMEGNbey4oumH7Hc578WgQJhPjBxteQ5GHHToTYHE3A1w6p7tU6KSoFmWBVbFGjKPisZDbP97";
+        let format = &BitcoinFormat::P2PKH;
+        let wallets =
+            BitcoinPaymentCodeWallet::from_mnemonic::<BitcoinMainnet>(alice, None, 0, Some(bob), 0..2, format).unwrap();
+
+        assert_eq!(2, wallets.len());
+        assert_eq!(
+            "PM8TJTLJbPRGxSbc8EJi42Wrr6QbNSaSSVJ5Y3E4pbCYiTHUskHg13935Ubb7q8tx9GVbh2UuRnBc3WSyJHhUrw8KhprKnn9eDznYGieTzFcwQRya4GA",
+            wallets[0].payment_code
+        );
+        assert_eq!("1JDdmqFLhpzcUwPeinhJbUPw4Co3aWLyzW", wallets[0].notification_address);
+        assert_eq!(
+            vec![
+                Some("141fi7TY3h936vRUKh1qfUZr8rSBuYbVBK"),
+                Some("12u3Uued2fuko2nY4SoSFGCoGLCBUGPkk6")
+            ],
+            wallets
+                .iter()
+                .map(|wallet| wallet.send_address.as_deref())
+                .collect::<Vec<_>>()
+        );
+
+        let parsed = BitcoinPaymentCodeWallet::from_payment_code::<BitcoinMainnet>(bob).unwrap();
+        assert_eq!("1ChvUUvht2hUQufHBXF8NgLhW8SwE2ecGV", parsed.notification_address);
+        assert!(parsed.counterparty.is_none() && parsed.send_address.is_none());
+        assert!(BitcoinPaymentCodeWallet::from_payment_code::<BitcoinMainnet>(&bob[..bob.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn public_only() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let mut wallet = BitcoinWallet::from_mnemonic::<BitcoinMainnet, English>(mnemonic, &None, "m").unwrap();
+        let fingerprint = wallet.to_fingerprint().unwrap();
+        wallet.public_only();
+
+        assert_eq!(fingerprint, wallet.fingerprint);
+        assert_eq!(Some("73c5da0a"), wallet.fingerprint.as_deref());
+        assert!(wallet.mnemonic.is_none() && wallet.extended_private_key.is_none() && wallet.private_key.is_none());
+        assert!(wallet.extended_public_key.is_some() && wallet.public_key.is_some() && wallet.address.is_some());
+    }
+
+    #[test]
+    fn neuter() {
+        let xprv = "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi";
+        let parent =
+            BitcoinWallet::from_extended_private_key::<BitcoinMainnet>(xprv, &Some("m/44'/0'".into())).unwrap();
+        let mut wallet =
+            BitcoinWallet::from_extended_private_key::<BitcoinMainnet>(xprv, &Some("m/44'/0'/0'".into())).unwrap();
+        wallet.neuter();
+
+        assert_eq!(Some("m/44'/0'/0'"), wallet.path.as_deref());
+        assert_eq!(Some("3442193e"), wallet.master_fingerprint.as_deref());
+        assert!(wallet.extended_private_key.is_none() && wallet.private_key.is_none());
+        assert!(wallet.public_key.is_none() && wallet.address.is_none());
+
+        // The extended public key is at the depth, with the parent fingerprint and child number, of the path
+        let xpub = wallet.extended_public_key.unwrap().from_base58().unwrap();
+        let parent_public_key = hex::decode(parent.public_key.unwrap()).unwrap();
+        assert_eq!(3, xpub[4]);
+        assert_eq!(&hash160(&parent_public_key)[..4], &xpub[5..9]);
+        assert_eq!([0x80, 0, 0, 0], xpub[9..13]);
+    }
+
+    #[test]
+    fn convert_private_key() {
+        let uncompressed = "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ";
+        let compressed = "KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617";
+        let hex = "0c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d";
+
+        let wallets = BitcoinWallet::from_converted_private_key::<BitcoinMainnet>(uncompressed, "compressed").unwrap();
+        assert_eq!(3, wallets.len());
+        assert!(wallets
+            .iter()
+            .all(|wallet| wallet.private_key.as_deref() == Some(compressed)));
+
+        let wallets = BitcoinWallet::from_converted_private_key::<BitcoinMainnet>(hex, "uncompressed").unwrap();
+        assert_eq!(Some(uncompressed), wallets[0].private_key.as_deref());
+        assert_eq!(
+            Some("1GAehh7TsJAHuUAeKZcXf5CnwuGuGgyX2S"),
+            wallets[0].address.as_deref()
+        );
+
+        let wallets = BitcoinWallet::from_converted_private_key::<BitcoinMainnet>(compressed, "hex").unwrap();
+        assert_eq!(4, wallets.len());
+        assert_eq!(Some(hex), wallets[3].private_key.as_deref());
+        assert_eq!(Some(false), wallets[3].compressed);
+    }
+}
@@ -0,0 +1,27 @@
+use crate::cli::base58_coin::{Base58CLI, Base58Coin, Base58Wallet};
+use crate::cli::{option, subcommand, types::*};
+use crate::dash::{Mainnet, Testnet};
+
+/// Represents Dash, as a coin of the base58 CLI
+pub struct Dash;
+
+impl Base58Coin for Dash {
+    type Mainnet = Mainnet;
+    type Testnet = Testnet;
+
+    const NAME: NameType = "dash";
+    const ABOUT: AboutType = "Generates a Dash wallet (include -h for more options)";
+    const TITLE: &'static str = "Dash";
+    const OPTIONS: &'static [OptionType] = &[option::COUNT, option::NETWORK_DASH];
+    const SUBCOMMANDS: &'static [SubCommandType] = &[
+        subcommand::HD_DASH,
+        subcommand::IMPORT_DASH,
+        subcommand::IMPORT_HD_DASH,
+        subcommand::PAPER_DASH,
+        subcommand::VALIDATE_DASH,
+    ];
+}
+
+pub type DashCLI = Base58CLI<Dash>;
+
+pub type DashWallet = Base58Wallet;
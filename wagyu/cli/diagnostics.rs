@@ -0,0 +1,216 @@
+use crate::cli::output::WalletWriter;
+use crate::cli::CLIError;
+use crate::model::{
+    encoding::{base58check, EncodingError},
+    AddressError,
+};
+
+use base58::FromBase58;
+use bech32::{u5, Bech32};
+use colored::*;
+use core::{fmt, fmt::Display, str::FromStr};
+use serde::Serialize;
+
+use crate::model::no_std::{format, String, ToOwned, ToString, Vec};
+
+/// Represents the diagnostics of an address, as decoded by the `validate` subcommand
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct AddressDiagnostics {
+    pub address: String,
+    pub valid: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_spend_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_view_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl AddressDiagnostics {
+    /// The fields of the diagnostics, in the order of the columns of a CSV output
+    pub const FIELDS: &'static [&'static str] = &[
+        "address",
+        "valid",
+        "network",
+        "format",
+        "checksum",
+        "payload",
+        "public_spend_key",
+        "public_view_key",
+        "payment_id",
+        "error",
+    ];
+
+    /// Returns the diagnostics of the specified address, before it is decoded.
+    pub fn new(address: &str) -> Self {
+        Self {
+            address: address.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Records the specified error, keeping the first error found.
+    pub fn error<E: Display>(&mut self, error: E) {
+        if self.error.is_none() {
+            self.error = Some(error.to_string());
+        }
+    }
+
+    /// Returns the diagnostics, valid if the address was decoded without an error
+    /// and its checksum, if it has one, is valid. An invalid checksum is reported
+    /// as the error, as it precedes any other error of a mistyped address.
+    pub fn finish(mut self) -> Self {
+        if self.checksum == Some(false) {
+            self.error = Some("invalid checksum".into());
+        }
+        self.valid = self.error.is_none();
+        self
+    }
+}
+
+impl Display for AddressDiagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let output = [
+            format!("      {}          {}\n", "Address".cyan().bold(), self.address),
+            match self.valid {
+                true => format!("      {}            {}\n", "Valid".cyan().bold(), "true".green()),
+                false => format!("      {}            {}\n", "Valid".cyan().bold(), "false".red()),
+            },
+            match &self.network {
+                Some(network) => format!("      {}          {}\n", "Network".cyan().bold(), network),
+                _ => "".to_owned(),
+            },
+            match &self.format {
+                Some(format) => format!("      {}           {}\n", "Format".cyan().bold(), format),
+                _ => "".to_owned(),
+            },
+            match &self.checksum {
+                Some(true) => format!("      {}         {}\n", "Checksum".cyan().bold(), "valid"),
+                Some(false) => format!("      {}         {}\n", "Checksum".cyan().bold(), "invalid"),
+                _ => "".to_owned(),
+            },
+            match &self.payload {
+                Some(payload) => format!("      {}          {}\n", "Payload".cyan().bold(), payload),
+                _ => "".to_owned(),
+            },
+            match &self.public_spend_key {
+                Some(public_spend_key) => format!("      {} {}\n", "Public Spend Key".cyan().bold(), public_spend_key),
+                _ => "".to_owned(),
+            },
+            match &self.public_view_key {
+                Some(public_view_key) => format!("      {}  {}\n", "Public View Key".cyan().bold(), public_view_key),
+                _ => "".to_owned(),
+            },
+            match &self.payment_id {
+                Some(payment_id) => format!("      {}       {}\n", "Payment ID".cyan().bold(), payment_id),
+                _ => "".to_owned(),
+            },
+            match &self.error {
+                Some(error) => format!("      {}            {}\n", "Error".cyan().bold(), error.red()),
+                _ => "".to_owned(),
+            },
+        ]
+        .concat();
+
+        // Removes final new line character
+        let output = output[..output.len() - 1].to_owned();
+        write!(f, "\n{}", output)
+    }
+}
+
+/// Writes the specified diagnostics in the output format, and returns an error
+/// if the address is invalid, to exit with a nonzero code.
+pub fn output(diagnostics: &AddressDiagnostics, format: &str, path: Option<&str>) -> Result<(), CLIError> {
+    let mut writer = WalletWriter::new(format, path, AddressDiagnostics::FIELDS)?;
+    writer.write(diagnostics)?;
+    writer.finish()?;
+    match diagnostics.valid {
+        true => Ok(()),
+        false => Err(CLIError::InvalidAddress(diagnostics.address.clone())),
+    }
+}
+
+/// Returns the data of a base58 address with a 4-byte double SHA-256 checksum,
+/// excluding the checksum, and whether the checksum is valid.
+pub fn from_base58_check(address: &str) -> Result<(Vec<u8>, bool), AddressError> {
+    let data = address.from_base58()?;
+    if data.len() < 5 {
+        return Err(AddressError::InvalidByteLength(data.len()));
+    }
+    match base58check::verify(&data) {
+        Ok(payload) => Ok((payload.to_vec(), true)),
+        Err(EncodingError::InvalidBase58Checksum(_, _)) => Ok((data[..data.len() - 4].to_vec(), false)),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// Returns the human-readable part and the data of a bech32 address.
+/// The data is `None` if the checksum is invalid, as it is not decoded.
+pub fn from_bech32(address: &str) -> Result<(String, Option<Vec<u5>>), AddressError> {
+    let hrp = match address.rfind('1') {
+        Some(separator) => address[..separator].to_lowercase(),
+        None => return Err(AddressError::InvalidAddress(address.into())),
+    };
+    match Bech32::from_str(address) {
+        Ok(bech32) => Ok((hrp, Some(bech32.into_parts().1))),
+        Err(bech32::Error::InvalidChecksum) => Ok((hrp, None)),
+        Err(error) => Err(error.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_base58_check() {
+        let (data, checksum) = super::from_base58_check("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2").unwrap();
+        assert_eq!("0077bff20c60e522dfaa3350c39b030a5d004e839a", hex::encode(data));
+        assert!(checksum);
+
+        let (_, checksum) = super::from_base58_check("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3").unwrap();
+        assert!(!checksum);
+
+        assert!(super::from_base58_check("1BvB").is_err());
+        assert!(super::from_base58_check("0OIl").is_err());
+    }
+
+    #[test]
+    fn from_bech32() {
+        let (hrp, data) = super::from_bech32("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4").unwrap();
+        assert_eq!("bc", hrp);
+        assert!(data.is_some());
+
+        let (hrp, data) = super::from_bech32("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5").unwrap();
+        assert_eq!("bc", hrp);
+        assert!(data.is_none());
+
+        assert!(super::from_bech32("bcqw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4").is_err());
+    }
+
+    #[test]
+    fn finish() {
+        let mut diagnostics = AddressDiagnostics::new("address");
+        diagnostics.checksum = Some(true);
+        assert!(diagnostics.clone().finish().valid);
+
+        diagnostics.error("invalid prefix");
+        diagnostics.error("invalid length");
+        assert_eq!(Some("invalid prefix".to_string()), diagnostics.clone().finish().error);
+
+        diagnostics.checksum = Some(false);
+        let diagnostics = diagnostics.finish();
+        assert!(!diagnostics.valid);
+        assert_eq!(Some("invalid checksum".to_string()), diagnostics.error);
+    }
+}
@@ -0,0 +1,27 @@
+use crate::cli::base58_coin::{Base58CLI, Base58Coin, Base58Wallet};
+use crate::cli::{option, subcommand, types::*};
+use crate::dogecoin::{Mainnet, Testnet};
+
+/// Represents Dogecoin, as a coin of the base58 CLI
+pub struct Dogecoin;
+
+impl Base58Coin for Dogecoin {
+    type Mainnet = Mainnet;
+    type Testnet = Testnet;
+
+    const NAME: NameType = "dogecoin";
+    const ABOUT: AboutType = "Generates a Dogecoin wallet (include -h for more options)";
+    const TITLE: &'static str = "Dogecoin";
+    const OPTIONS: &'static [OptionType] = &[option::COUNT, option::NETWORK_DOGECOIN];
+    const SUBCOMMANDS: &'static [SubCommandType] = &[
+        subcommand::HD_DOGECOIN,
+        subcommand::IMPORT_DOGECOIN,
+        subcommand::IMPORT_HD_DOGECOIN,
+        subcommand::PAPER_DOGECOIN,
+        subcommand::VALIDATE_DOGECOIN,
+    ];
+}
+
+pub type DogecoinCLI = Base58CLI<Dogecoin>;
+
+pub type DogecoinWallet = Base58Wallet;
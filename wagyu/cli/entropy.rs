@@ -0,0 +1,168 @@
+use crate::cli::{wallet_rng, CLIError};
+use crate::model::WalletRng;
+
+use colored::*;
+use rand_core::{impls, CryptoRng, Error, RngCore};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+
+/// The number of bits of user-supplied entropy below which a warning is printed
+const MINIMUM_ENTROPY_BITS: usize = 128;
+
+/// Represents the source of entropy for generating the mnemonic of an HD wallet, either the
+/// operating system or user-supplied entropy, such as dice rolls or the output of a hardware RNG.
+pub enum Entropy {
+    /// Entropy from the operating system
+    Os(Box<dyn WalletRng>),
+    /// User-supplied entropy, mixed into a seed by SHA-256 and expanded by SHA-256 in counter mode
+    Mixed { seed: [u8; 32], counter: u64 },
+}
+
+impl Entropy {
+    /// Returns the entropy source of the specified hex or file, falling back to the operating system.
+    /// If `audit` is enabled, every draw of entropy from the operating system is logged to stderr.
+    pub fn new(hex: Option<&str>, file: Option<&str>, audit: bool) -> Result<Self, CLIError> {
+        match (hex, file) {
+            (Some(hex), _) => Self::from_hex(hex),
+            (None, Some(file)) => Self::from_file(file),
+            (None, None) => Ok(Self::os(audit)),
+        }
+    }
+
+    /// Returns an entropy source drawing from the operating system.
+    pub fn os(audit: bool) -> Self {
+        Entropy::Os(wallet_rng(audit))
+    }
+
+    /// Returns an entropy source for the specified user-supplied bytes.
+    /// Prints a warning to stderr if the bytes carry fewer than 128 bits of entropy.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        if bytes.len() * 8 < MINIMUM_ENTROPY_BITS {
+            eprintln!(
+                "{} the specified entropy is {} bits, below the recommended minimum of {} bits",
+                "Warning:".yellow().bold(),
+                bytes.len() * 8,
+                MINIMUM_ENTROPY_BITS
+            );
+        }
+
+        // Mix the entropy into a uniform seed, so biased input, such as dice rolls, is not used verbatim
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&Sha256::digest(bytes));
+        Entropy::Mixed { seed, counter: 0 }
+    }
+
+    /// Returns a deterministic entropy source for the specified seed and label, such as the name of a currency,
+    /// so that sources of the same seed with different labels draw independent streams.
+    /// No warning is printed for a short seed, as the source is meant for reproducible test vectors.
+    pub fn from_seed(seed: &[u8], label: &str) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.input(seed);
+        hasher.input(label.as_bytes());
+        let mut mixed = [0u8; 32];
+        mixed.copy_from_slice(&hasher.result());
+        Entropy::Mixed {
+            seed: mixed,
+            counter: 0,
+        }
+    }
+
+    /// Returns an entropy source for the specified user-supplied hex.
+    pub fn from_hex(hex: &str) -> Result<Self, CLIError> {
+        let mut bytes = hex::decode(hex.trim())?;
+        let entropy = Self::from_bytes(&bytes);
+        bytes.zeroize();
+        Ok(entropy)
+    }
+
+    /// Returns an entropy source for the raw bytes of the specified file.
+    pub fn from_file(path: &str) -> Result<Self, CLIError> {
+        let mut bytes = std::fs::read(path)?;
+        let entropy = Self::from_bytes(&bytes);
+        bytes.zeroize();
+        Ok(entropy)
+    }
+}
+
+impl RngCore for Entropy {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            Entropy::Os(rng) => rng.fill_bytes(dest),
+            Entropy::Mixed { seed, counter } => {
+                for chunk in dest.chunks_mut(32) {
+                    let mut hasher = Sha256::new();
+                    hasher.input(&seed[..]);
+                    hasher.input(&counter.to_be_bytes());
+                    let mut block = hasher.result();
+                    chunk.copy_from_slice(&block[..chunk.len()]);
+                    block.as_mut_slice().zeroize();
+                    *counter += 1;
+                }
+            }
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for Entropy {}
+
+impl WalletRng for Entropy {}
+
+impl Drop for Entropy {
+    fn drop(&mut self) {
+        if let Entropy::Mixed { seed, .. } = self {
+            seed.zeroize();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixed_is_deterministic() {
+        let (mut a, mut b) = (Entropy::from_bytes(&[7u8; 32]), Entropy::from_bytes(&[7u8; 32]));
+        let (mut first, mut second) = ([0u8; 48], [0u8; 48]);
+        a.fill_bytes(&mut first);
+        b.fill_bytes(&mut second);
+        assert_eq!(first[..], second[..]);
+
+        // Subsequent draws continue the stream, rather than repeating it
+        b.fill_bytes(&mut second);
+        assert_ne!(first[..], second[..]);
+
+        // The entropy is mixed, rather than used verbatim
+        let mut verbatim = [0u8; 32];
+        Entropy::from_bytes(&[7u8; 32]).fill_bytes(&mut verbatim);
+        assert_ne!([7u8; 32], verbatim);
+    }
+
+    #[test]
+    fn from_seed_is_labeled() {
+        let (mut first, mut second, mut other) = ([0u8; 32], [0u8; 32], [0u8; 32]);
+        Entropy::from_seed(&[1u8; 16], "bitcoin").fill_bytes(&mut first);
+        Entropy::from_seed(&[1u8; 16], "bitcoin").fill_bytes(&mut second);
+        Entropy::from_seed(&[1u8; 16], "ethereum").fill_bytes(&mut other);
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn from_hex() {
+        assert!(Entropy::from_hex("000102030405060708090a0b0c0d0e0f").is_ok());
+        assert!(Entropy::from_hex("not hex").is_err());
+    }
+}
@@ -1,28 +1,45 @@
-use crate::cli::{flag, option, subcommand, types::*, CLIError, CLI};
+use crate::cli::diagnostics::{self, AddressDiagnostics};
+use crate::cli::entropy::Entropy;
+#[cfg(feature = "hw-ledger")]
+use crate::cli::ledger::Ledger;
+use crate::cli::output::WalletWriter;
+#[cfg(feature = "broadcast")]
+use crate::cli::rpc;
+use crate::cli::vectors::TestVectors;
+use crate::cli::{
+    check_word_count, fingerprint, flag, master_fingerprint, option, paper, paper::PaperWallet, parse_index_range, qr,
+    subcommand, types::*, value_or_stdin, wallet_id, wallet_rng, warn_high_fee, CLIError, CLI,
+};
 use crate::ethereum::{
-    wordlist::*, EthereumAddress, EthereumAmount, EthereumDerivationPath, EthereumExtendedPrivateKey,
-    EthereumExtendedPublicKey, EthereumFormat, EthereumMnemonic, EthereumNetwork, EthereumPrivateKey,
-    EthereumPublicKey, EthereumTransaction, EthereumTransactionParameters, Goerli, Kovan, Mainnet as EthereumMainnet,
-    Rinkeby, Ropsten,
+    is_ens_name, wordlist::*, AbiFunction, Custom, EthereumAccessListItem, EthereumAddress, EthereumAmount,
+    EthereumDerivationPath, EthereumExtendedPrivateKey, EthereumExtendedPublicKey, EthereumFormat, EthereumMnemonic,
+    EthereumNetwork, EthereumPrivateKey, EthereumPublicKey, EthereumTransaction, EthereumTransactionParameters,
+    EthereumTransactionType, EthereumUri, Goerli, Kovan, Mainnet as EthereumMainnet, Rinkeby, Ropsten,
 };
+#[cfg(feature = "hw-ledger")]
+use crate::model::DerivationPath;
 use crate::model::{
-    ExtendedPrivateKey, ExtendedPublicKey, Mnemonic, MnemonicCount, MnemonicExtended, Network, PrivateKey, PublicKey,
-    Transaction,
+    AddressError, BIP32Path, Bip39Language, ExtendedPrivateKey, ExtendedPublicKey, Mnemonic, MnemonicCount,
+    MnemonicExtended, Network, NonHardenedDerivationPath, PrivateKey, PublicKey, Transaction, TransactionError,
 };
 
 use clap::{ArgMatches, Values};
 use colored::*;
-use core::{fmt, fmt::Display, str::FromStr};
-use rand::{rngs::StdRng, Rng};
-use rand_core::SeedableRng;
+use core::{fmt, fmt::Display, iter, str::FromStr};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use serde_json::from_str;
+use zeroize::Zeroize;
 
 use crate::model::no_std::{format, vec, String, ToOwned, ToString, Vec};
 
+/// The maximum fee of a transaction, in wei, above which it is warned of as absurdly high,
+/// the default fee cap of a transaction sent by Geth (1 ether)
+const MAXIMUM_FEE: &str = "1000000000000000000";
+
 /// Represents a generic wallet to output
 #[derive(Serialize, Debug, Default)]
-struct EthereumWallet {
+pub struct EthereumWallet {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -38,16 +55,117 @@ struct EthereumWallet {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub public_key: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub compressed_public_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uncompressed_public_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub address: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub master_fingerprint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wallet_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiver: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icap: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arguments: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_list: Option<Vec<EthereumAccessListInput>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction_hex: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
+
+impl Zeroize for EthereumWallet {
+    /// Overwrites the secret fields of the wallet.
+    fn zeroize(&mut self) {
+        self.password.zeroize();
+        self.mnemonic.zeroize();
+        self.extended_private_key.zeroize();
+        self.private_key.zeroize();
+    }
 }
 
 impl EthereumWallet {
+    /// The fields of the wallet, in the order of the columns of a CSV output
+    pub const FIELDS: &'static [&'static str] = &[
+        "path",
+        "password",
+        "mnemonic",
+        "extended_private_key",
+        "extended_public_key",
+        "private_key",
+        "public_key",
+        "compressed_public_key",
+        "uncompressed_public_key",
+        "address",
+        "fingerprint",
+        "master_fingerprint",
+        "wallet_id",
+        "sender",
+        "receiver",
+        "icap",
+        "chain_id",
+        "function",
+        "arguments",
+        "value",
+        "gas",
+        "gas_price",
+        "nonce",
+        "data",
+        "access_list",
+        "transaction_id",
+        "network",
+        "transaction_hex",
+        "uri",
+    ];
+
+    /// Withholds the secrets of the wallet from its output, leaving its public material
+    /// and the fingerprint of its public key.
+    pub fn public_only(&mut self) {
+        self.fingerprint = self.public_key.as_deref().or(self.address.as_deref()).map(fingerprint);
+        self.zeroize();
+    }
+
+    /// Reduces the wallet to the extended public key of its derivation path, for a watch-only import,
+    /// withholding its secrets and the public key and address of the derived node.
+    pub fn neuter(&mut self) {
+        let neutered = Self {
+            path: self.path.take(),
+            extended_public_key: self.extended_public_key.take(),
+            master_fingerprint: self.master_fingerprint.take(),
+            wallet_id: self.wallet_id.take(),
+            network: self.network.take(),
+            ..Default::default()
+        };
+        self.zeroize();
+        *self = neutered;
+    }
+
     pub fn new<R: Rng>(rng: &mut R) -> Result<Self, CLIError> {
         let private_key = EthereumPrivateKey::new(rng)?;
         let public_key = private_key.to_public_key();
@@ -60,13 +178,13 @@ impl EthereumWallet {
         })
     }
 
-    pub fn new_hd<N: EthereumNetwork, W: EthereumWordlist, R: Rng>(
-        rng: &mut R,
+    pub fn new_hd<N: EthereumNetwork, W: EthereumWordlist>(
+        entropy: &mut Entropy,
         word_count: u8,
         password: Option<&str>,
         path: &str,
     ) -> Result<Self, CLIError> {
-        let mnemonic = EthereumMnemonic::<N, W>::new_with_count(rng, word_count)?;
+        let mnemonic = EthereumMnemonic::<N, W>::new_with_count(entropy, word_count)?;
         let master_extended_private_key = mnemonic.to_extended_private_key(password)?;
         let derivation_path = EthereumDerivationPath::from_str(path)?;
         let extended_private_key = master_extended_private_key.derive(&derivation_path)?;
@@ -80,6 +198,8 @@ impl EthereumWallet {
             mnemonic: Some(mnemonic.to_string()),
             extended_private_key: Some(extended_private_key.to_string()),
             extended_public_key: Some(extended_public_key.to_string()),
+            master_fingerprint: master_fingerprint(&master_extended_private_key.to_extended_public_key().to_string()),
+            wallet_id: Some(wallet_id(&extended_public_key.to_string())),
             private_key: Some(private_key.to_string()),
             public_key: Some(public_key.to_string()),
             address: Some(address.to_string()),
@@ -87,30 +207,126 @@ impl EthereumWallet {
         })
     }
 
+    /// Returns the mainnet test vectors of the entropy: an HD wallet, a wallet of a private key,
+    /// and a transaction of the private key, sending 1 ether to the HD wallet at a gas price of 20 gwei.
+    pub fn test_vectors(entropy: &mut Entropy) -> Result<TestVectors<Self>, CLIError> {
+        let wallets = vec![
+            Self::new_hd::<EthereumMainnet, English>(entropy, 24, None, "m/44'/60'/0'/0/0")?,
+            Self::new(entropy)?,
+        ];
+
+        let parameters = EthereumInput {
+            to: wallets[0].address.clone().unwrap_or_default(),
+            value: "1000000000000000000".into(),
+            gas: "21000".into(),
+            gas_price: "20000000000".into(),
+            nonce: 0,
+            data: None,
+            access_list: None,
+            unprotected: false,
+        };
+        let private_key = wallets[1].private_key.as_deref().unwrap_or("");
+        let transaction = Self::to_signed_transaction_from_parameters::<EthereumMainnet>(
+            parameters,
+            private_key,
+            EthereumMainnet::CHAIN_ID,
+        )?;
+
+        Ok(TestVectors {
+            wallets,
+            transactions: vec![transaction],
+        })
+    }
+
     pub fn from_mnemonic<N: EthereumNetwork, W: EthereumWordlist>(
         mnemonic: &str,
         password: Option<&str>,
         path: &str,
     ) -> Result<Self, CLIError> {
+        Ok(Self::from_mnemonic_paths::<N, W>(mnemonic, password, &[path.to_string()])?.remove(0))
+    }
+
+    /// Returns the wallets of the mnemonic for the specified derivation paths, deriving its master key once.
+    /// The parent of consecutive paths, e.g. the chain of an account, is derived once and its children from it.
+    pub fn from_mnemonic_paths<N: EthereumNetwork, W: EthereumWordlist>(
+        mnemonic: &str,
+        password: Option<&str>,
+        paths: &[String],
+    ) -> Result<Vec<Self>, CLIError> {
         let mnemonic = EthereumMnemonic::<N, W>::from_phrase(&mnemonic)?;
         let master_extended_private_key = mnemonic.to_extended_private_key(password)?;
-        let derivation_path = EthereumDerivationPath::from_str(path)?;
-        let extended_private_key = master_extended_private_key.derive(&derivation_path)?;
-        let extended_public_key = extended_private_key.to_extended_public_key();
-        let private_key = extended_private_key.to_private_key();
-        let public_key = extended_public_key.to_public_key();
-        let address = public_key.to_address(&EthereumFormat::Standard)?;
-        Ok(Self {
-            path: Some(path.to_string()),
-            password: password.map(String::from),
-            mnemonic: Some(mnemonic.to_string()),
-            extended_private_key: Some(extended_private_key.to_string()),
-            extended_public_key: Some(extended_public_key.to_string()),
-            private_key: Some(private_key.to_string()),
-            public_key: Some(public_key.to_string()),
-            address: Some(address.to_string()),
-            ..Default::default()
-        })
+        let master_fingerprint = master_fingerprint(&master_extended_private_key.to_extended_public_key().to_string());
+
+        let mut parent: Option<(BIP32Path, EthereumExtendedPrivateKey<N>)> = None;
+        let mut wallets = vec![];
+        for path in paths {
+            EthereumDerivationPath::<N>::from_str(path)?;
+            let child_indices = BIP32Path::from_str(path)?;
+            let extended_private_key = match (child_indices.parent(), child_indices.iter().last()) {
+                (Some(parent_path), Some(index)) => {
+                    if parent.as_ref().map(|(path, _)| path) != Some(&parent_path) {
+                        let parent_key = parent_path
+                            .iter()
+                            .try_fold(master_extended_private_key.clone(), |key, index| {
+                                key.derive_child(*index)
+                            })?;
+                        parent = Some((parent_path, parent_key));
+                    }
+                    parent.as_ref().unwrap().1.derive_child(*index)?
+                }
+                _ => master_extended_private_key.clone(),
+            };
+
+            let extended_public_key = extended_private_key.to_extended_public_key();
+            let private_key = extended_private_key.to_private_key();
+            let public_key = extended_public_key.to_public_key();
+            let address = public_key.to_address(&EthereumFormat::Standard)?;
+            wallets.push(Self {
+                path: Some(path.to_string()),
+                password: password.map(String::from),
+                mnemonic: Some(mnemonic.to_string()),
+                extended_private_key: Some(extended_private_key.to_string()),
+                extended_public_key: Some(extended_public_key.to_string()),
+                master_fingerprint: master_fingerprint.clone(),
+                wallet_id: Some(wallet_id(&extended_public_key.to_string())),
+                private_key: Some(private_key.to_string()),
+                public_key: Some(public_key.to_string()),
+                address: Some(address.to_string()),
+                ..Default::default()
+            });
+        }
+        Ok(wallets)
+    }
+
+    /// Returns the wallet of the mnemonic, in the language detected from its words.
+    pub fn from_mnemonic_any_language<N: EthereumNetwork>(
+        mnemonic: &str,
+        password: Option<&str>,
+        path: &str,
+    ) -> Result<Self, CLIError> {
+        Ok(Self::from_mnemonic_paths_any_language::<N>(mnemonic, password, &[path.to_string()])?.remove(0))
+    }
+
+    /// Returns the wallets of the mnemonic for the specified derivation paths, in the language detected from its words.
+    pub fn from_mnemonic_paths_any_language<N: EthereumNetwork>(
+        mnemonic: &str,
+        password: Option<&str>,
+        paths: &[String],
+    ) -> Result<Vec<Self>, CLIError> {
+        match Bip39Language::detect_language(mnemonic)? {
+            Bip39Language::ChineseSimplified => {
+                Self::from_mnemonic_paths::<N, ChineseSimplified>(mnemonic, password, paths)
+            }
+            Bip39Language::ChineseTraditional => {
+                Self::from_mnemonic_paths::<N, ChineseTraditional>(mnemonic, password, paths)
+            }
+            Bip39Language::English => Self::from_mnemonic_paths::<N, English>(mnemonic, password, paths),
+            Bip39Language::French => Self::from_mnemonic_paths::<N, French>(mnemonic, password, paths),
+            Bip39Language::Italian => Self::from_mnemonic_paths::<N, Italian>(mnemonic, password, paths),
+            Bip39Language::Japanese => Self::from_mnemonic_paths::<N, Japanese>(mnemonic, password, paths),
+            Bip39Language::Korean => Self::from_mnemonic_paths::<N, Korean>(mnemonic, password, paths),
+            Bip39Language::Spanish => Self::from_mnemonic_paths::<N, Spanish>(mnemonic, password, paths),
+        }
     }
 
     pub fn from_extended_private_key<N: EthereumNetwork>(
@@ -118,6 +334,7 @@ impl EthereumWallet {
         path: &Option<String>,
     ) -> Result<Self, CLIError> {
         let mut extended_private_key = EthereumExtendedPrivateKey::<N>::from_str(extended_private_key)?;
+        let master_fingerprint = master_fingerprint(&extended_private_key.to_extended_public_key().to_string());
         if let Some(derivation_path) = path {
             let derivation_path = EthereumDerivationPath::from_str(&derivation_path)?;
             extended_private_key = extended_private_key.derive(&derivation_path)?;
@@ -130,6 +347,8 @@ impl EthereumWallet {
             path: path.clone(),
             extended_private_key: Some(extended_private_key.to_string()),
             extended_public_key: Some(extended_public_key.to_string()),
+            master_fingerprint,
+            wallet_id: Some(wallet_id(&extended_public_key.to_string())),
             private_key: Some(private_key.to_string()),
             public_key: Some(public_key.to_string()),
             address: Some(address.to_string()),
@@ -141,16 +360,49 @@ impl EthereumWallet {
         extended_public_key: &str,
         path: &Option<String>,
     ) -> Result<Self, CLIError> {
+        let master_fingerprint = master_fingerprint(extended_public_key);
         let mut extended_public_key = EthereumExtendedPublicKey::<N>::from_str(extended_public_key)?;
         if let Some(derivation_path) = path {
-            let derivation_path = EthereumDerivationPath::from_str(&derivation_path)?;
-            extended_public_key = extended_public_key.derive(&derivation_path)?;
+            let derivation_path = NonHardenedDerivationPath::from_str(&derivation_path)?;
+            extended_public_key = extended_public_key.derive_public(&derivation_path)?;
         }
         let public_key = extended_public_key.to_public_key();
         let address = public_key.to_address(&EthereumFormat::Standard)?;
         Ok(Self {
             path: path.clone(),
             extended_public_key: Some(extended_public_key.to_string()),
+            master_fingerprint,
+            wallet_id: Some(wallet_id(&extended_public_key.to_string())),
+            public_key: Some(public_key.to_string()),
+            address: Some(address.to_string()),
+            ..Default::default()
+        })
+    }
+
+    #[cfg(feature = "hw-ledger")]
+    pub fn from_ledger<N: EthereumNetwork>(ledger: &Ledger, path: &str, verify: bool) -> Result<Self, CLIError> {
+        let derivation_path = EthereumDerivationPath::<N>::from_str(path)?;
+        let indices: Vec<u32> = derivation_path.to_vec()?.into_iter().map(u32::from).collect();
+
+        let ledger_public_key = ledger.ethereum_public_key(&indices, verify)?;
+        let extended_public_key = EthereumExtendedPublicKey::<N>::from_str(
+            &ledger_public_key.to_extended_public_key(&[0x04, 0x88, 0xB2, 0x1E])?,
+        )?;
+        let public_key = extended_public_key.to_public_key();
+        let address = public_key.to_address(&EthereumFormat::Standard)?;
+
+        // Check the address computed by the device against the address of its public key
+        if !address.to_string().eq_ignore_ascii_case(&ledger_public_key.address) {
+            return Err(CLIError::LedgerError(format!(
+                "device address {} does not match derived address {}",
+                ledger_public_key.address, address
+            )));
+        }
+
+        Ok(Self {
+            path: Some(path.to_string()),
+            extended_public_key: Some(extended_public_key.to_string()),
+            wallet_id: Some(wallet_id(&extended_public_key.to_string())),
             public_key: Some(public_key.to_string()),
             address: Some(address.to_string()),
             ..Default::default()
@@ -169,6 +421,39 @@ impl EthereumWallet {
         })
     }
 
+    /// Returns the wallet of a private key in hex, with or without the 0x prefix, with its public key
+    /// in the compressed and uncompressed SEC1 encodings.
+    pub fn from_converted_private_key(private_key: &str) -> Result<Self, CLIError> {
+        let private_key = EthereumPrivateKey::from_str(private_key.trim_start_matches("0x"))?;
+        let public_key = private_key.to_public_key();
+        let secp256k1_public_key = public_key.to_secp256k1_public_key();
+        let address = public_key.to_address(&EthereumFormat::Standard)?;
+        Ok(Self {
+            private_key: Some(private_key.to_string()),
+            public_key: Some(public_key.to_string()),
+            compressed_public_key: Some(hex::encode(&secp256k1_public_key.serialize_compressed()[..])),
+            uncompressed_public_key: Some(hex::encode(&secp256k1_public_key.serialize()[..])),
+            address: Some(address.to_string()),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the wallet of the private key of a mnemonic at a derivation path, converted to hex,
+    /// without the mnemonic and extended keys it is derived from.
+    pub fn from_converted_mnemonic<N: EthereumNetwork>(
+        mnemonic: &str,
+        password: Option<&str>,
+        path: &str,
+    ) -> Result<Self, CLIError> {
+        let mut wallet = Self::from_mnemonic_any_language::<N>(mnemonic, password, path)?;
+        let converted = Self::from_converted_private_key(wallet.private_key.as_deref().unwrap_or_default());
+        wallet.zeroize();
+        Ok(Self {
+            path: Some(path.to_string()),
+            ..converted?
+        })
+    }
+
     pub fn from_public_key(public_key: &str) -> Result<Self, CLIError> {
         let public_key = EthereumPublicKey::from_str(public_key)?;
         let address = public_key.to_address(&EthereumFormat::Standard)?;
@@ -179,6 +464,30 @@ impl EthereumWallet {
         })
     }
 
+    /// Returns the public key and address that signed the message, as signed by `eth_sign`
+    /// and `personal_sign`, given the message and the 65-byte signature of r, s, and v, each in hex.
+    pub fn from_message_signature(message: &str, signature: &str) -> Result<Self, CLIError> {
+        let message = hex::decode(message.trim_start_matches("0x"))?;
+        let signature = hex::decode(signature.trim_start_matches("0x"))?;
+        Self::from_recovered_public_key(EthereumPublicKey::recover_message(&message, &signature)?)
+    }
+
+    /// Returns the public key and address that signed the 32-byte hash, such as the sighash
+    /// of a transaction, given the hash and the 65-byte signature of r, s, and v, each in hex.
+    pub fn from_hash_signature(hash: &str, signature: &str) -> Result<Self, CLIError> {
+        let hash = hex::decode(hash.trim_start_matches("0x"))?;
+        let signature = hex::decode(signature.trim_start_matches("0x"))?;
+        Self::from_recovered_public_key(EthereumPublicKey::recover(&hash, &signature)?)
+    }
+
+    fn from_recovered_public_key(public_key: EthereumPublicKey) -> Result<Self, CLIError> {
+        Ok(Self {
+            address: Some(public_key.to_address(&EthereumFormat::Standard)?.to_string()),
+            public_key: Some(public_key.to_string()),
+            ..Default::default()
+        })
+    }
+
     pub fn from_address(address: &str) -> Result<Self, CLIError> {
         let address = EthereumAddress::from_str(address)?;
         Ok(Self {
@@ -188,6 +497,7 @@ impl EthereumWallet {
     }
 
     pub fn to_raw_transaction<N: EthereumNetwork>(parameters: EthereumInput) -> Result<Self, CLIError> {
+        let transaction_type = parameters.to_transaction_type()?;
         let transaction_parameters = EthereumTransactionParameters {
             receiver: EthereumAddress::from_str(&parameters.to)?,
             amount: EthereumAmount::from_wei(&parameters.value)?,
@@ -195,9 +505,11 @@ impl EthereumWallet {
             gas_price: EthereumAmount::from_wei(&parameters.gas_price)?,
             nonce: EthereumAmount::u256_from_str(&parameters.nonce.to_string())?,
             data: parameters.data.unwrap_or("".to_string()).as_bytes().to_vec(),
+            transaction_type,
         };
 
         let raw_transaction = EthereumTransaction::<N>::new(&transaction_parameters)?;
+        warn_high_fee(&raw_transaction, EthereumAmount::from_wei(MAXIMUM_FEE)?, "wei");
         let raw_transaction_hex = hex::encode(raw_transaction.to_transaction_bytes()?);
 
         Ok(Self {
@@ -219,6 +531,7 @@ impl EthereumWallet {
 
         let mut transaction = EthereumTransaction::<N>::from_transaction_bytes(&transaction_bytes)?;
         transaction = transaction.sign(&private_key)?;
+        warn_high_fee(&transaction, EthereumAmount::from_wei(MAXIMUM_FEE)?, "wei");
 
         Ok(Self {
             transaction_id: Some(transaction.to_transaction_id()?.to_string()),
@@ -226,6 +539,153 @@ impl EthereumWallet {
             ..Default::default()
         })
     }
+
+    pub fn to_signed_transaction_from_parameters<N: EthereumNetwork>(
+        parameters: EthereumInput,
+        private_key: &str,
+        chain_id: u32,
+    ) -> Result<Self, CLIError> {
+        let transaction_type = parameters.to_transaction_type()?;
+        let data = match parameters.data {
+            Some(data) => match data.starts_with("0x") {
+                true => hex::decode(&data[2..])?,
+                false => hex::decode(&data)?,
+            },
+            None => vec![],
+        };
+        let transaction_parameters = EthereumTransactionParameters {
+            receiver: EthereumAddress::from_str(&parameters.to)?,
+            amount: EthereumAmount::from_wei(&parameters.value)?,
+            gas: EthereumAmount::u256_from_str(&parameters.gas)?,
+            gas_price: EthereumAmount::from_wei(&parameters.gas_price)?,
+            nonce: EthereumAmount::u256_from_str(&parameters.nonce.to_string())?,
+            data,
+            transaction_type,
+        };
+
+        let private_key = EthereumPrivateKey::from_str(private_key)?;
+        let transaction =
+            EthereumTransaction::<N>::new_with_chain_id(&transaction_parameters, chain_id)?.sign(&private_key)?;
+        warn_high_fee(&transaction, EthereumAmount::from_wei(MAXIMUM_FEE)?, "wei");
+
+        Ok(Self {
+            network: Some(N::NAME.to_string()),
+            // The chain id of a custom network is not implied by its name
+            chain_id: Some(transaction.chain_id() as u64).filter(|_| N::CHAIN_ID == Custom::CHAIN_ID),
+            transaction_id: Some(transaction.to_transaction_id()?.to_string()),
+            transaction_hex: Some(format!("0x{}", hex::encode(&transaction.to_transaction_bytes()?))),
+            ..Default::default()
+        })
+    }
+
+    pub fn from_transaction_hex<N: EthereumNetwork>(transaction_hex: &str) -> Result<Self, CLIError> {
+        let transaction_bytes = match transaction_hex.starts_with("0x") {
+            true => hex::decode(&transaction_hex[2..])?,
+            false => hex::decode(transaction_hex)?,
+        };
+
+        let transaction = EthereumTransaction::<N>::from_transaction_bytes(&transaction_bytes)?;
+        let parameters = transaction.parameters();
+
+        Ok(Self {
+            // An unprotected transaction is valid on every network
+            network: transaction
+                .sender()
+                .filter(|_| parameters.transaction_type != EthereumTransactionType::Unprotected)
+                .map(|_| N::NAME.to_string()),
+            // The chain id of a custom network is not implied by its name
+            chain_id: Some(transaction.chain_id() as u64).filter(|_| N::CHAIN_ID == Custom::CHAIN_ID),
+            sender: transaction.sender().map(|sender| sender.to_string()),
+            receiver: Some(parameters.receiver.to_string()),
+            value: Some(parameters.amount.0.to_string()),
+            gas: Some(parameters.gas.to_string()),
+            gas_price: Some(parameters.gas_price.0.to_string()),
+            nonce: Some(parameters.nonce.to_string()),
+            data: Some(format!("0x{}", hex::encode(&parameters.data))),
+            access_list: match &parameters.transaction_type {
+                EthereumTransactionType::AccessList(access_list) => Some(
+                    access_list
+                        .iter()
+                        .map(|item| EthereumAccessListInput {
+                            address: item.address.to_string(),
+                            storage_keys: item
+                                .storage_keys
+                                .iter()
+                                .map(|storage_key| format!("0x{}", hex::encode(storage_key)))
+                                .collect(),
+                        })
+                        .collect(),
+                ),
+                EthereumTransactionType::Legacy | EthereumTransactionType::Unprotected => None,
+            },
+            // An unsigned transaction has no transaction id until it is signed
+            transaction_id: transaction.to_transaction_id().ok().map(|id| id.to_string()),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the payment request of an EIP-681 URI, with the ICAP of its receiver address
+    /// and the data of its contract function call.
+    pub fn from_uri(uri: &EthereumUri) -> Result<Self, CLIError> {
+        let icap = match is_ens_name(&uri.target) {
+            true => None,
+            false => Some(EthereumAddress::from_str(&uri.target)?.to_icap()),
+        };
+        let (function, arguments, data) = match uri.to_function() {
+            Some(function) => (
+                Some(function.signature()),
+                Some(uri.arguments.iter().map(|(_, token)| token.to_string()).collect()),
+                Some(format!("0x{}", hex::encode(uri.to_data()?))),
+            ),
+            None => (None, None, None),
+        };
+        Ok(Self {
+            receiver: Some(uri.target.clone()),
+            icap,
+            chain_id: uri.chain_id,
+            function,
+            arguments,
+            value: uri.value.map(|value| value.to_string()),
+            gas: uri.gas.map(|gas| gas.to_string()),
+            gas_price: uri.gas_price.map(|gas_price| gas_price.to_string()),
+            data,
+            uri: Some(uri.to_string()),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the payment request URI, raw transaction, address, or public key of the wallet, to render as a QR code.
+    pub fn to_qr_data(&self) -> Option<&str> {
+        self.uri
+            .as_deref()
+            .or(self.transaction_hex.as_deref())
+            .or(self.address.as_deref())
+            .or(self.public_key.as_deref())
+    }
+
+    /// Returns the address, mnemonic or private key, and remaining fields of the wallet, to render on paper.
+    pub fn to_paper_wallet(&self) -> Option<PaperWallet<'_>> {
+        let address = self.address.as_deref()?;
+        let secret = match (self.mnemonic.as_deref(), self.private_key.as_deref()) {
+            (Some(mnemonic), _) => ("Mnemonic", mnemonic),
+            (None, Some(private_key)) => ("Private Key", private_key),
+            (None, None) => return None,
+        };
+        let fields = [
+            ("Path", self.path.as_deref()),
+            ("Private Key", self.private_key.as_deref()),
+        ]
+        .iter()
+        .filter(|(label, _)| *label != secret.0)
+        .filter_map(|(label, value)| value.map(|value| (*label, value)))
+        .collect();
+
+        Some(PaperWallet {
+            address,
+            secret,
+            fields,
+        })
+    }
 }
 
 #[cfg_attr(tarpaulin, skip)]
@@ -268,10 +728,97 @@ impl Display for EthereumWallet {
                 Some(public_key) => format!("      {}           {}\n", "Public Key".cyan().bold(), public_key),
                 _ => "".to_owned(),
             },
+            match &self.compressed_public_key {
+                Some(public_key) => format!("      {}       {}\n", "Compressed Key".cyan().bold(), public_key),
+                _ => "".to_owned(),
+            },
+            match &self.uncompressed_public_key {
+                Some(public_key) => format!("      {}     {}\n", "Uncompressed Key".cyan().bold(), public_key),
+                _ => "".to_owned(),
+            },
             match &self.address {
                 Some(address) => format!("      {}              {}\n", "Address".cyan().bold(), address),
                 _ => "".to_owned(),
             },
+            match &self.fingerprint {
+                Some(fingerprint) => format!("      {}          {}\n", "Fingerprint".cyan().bold(), fingerprint),
+                _ => "".to_owned(),
+            },
+            match &self.master_fingerprint {
+                Some(master_fingerprint) => format!(
+                    "      {}   {}\n",
+                    "Master Fingerprint".cyan().bold(),
+                    master_fingerprint
+                ),
+                _ => "".to_owned(),
+            },
+            match &self.wallet_id {
+                Some(wallet_id) => format!("      {}            {}\n", "Wallet ID".cyan().bold(), wallet_id),
+                _ => "".to_owned(),
+            },
+            match &self.sender {
+                Some(sender) => format!("      {}               {}\n", "Sender".cyan().bold(), sender),
+                _ => "".to_owned(),
+            },
+            match &self.receiver {
+                Some(receiver) => format!("      {}             {}\n", "Receiver".cyan().bold(), receiver),
+                _ => "".to_owned(),
+            },
+            match &self.icap {
+                Some(icap) => format!("      {}                 {}\n", "ICAP".cyan().bold(), icap),
+                _ => "".to_owned(),
+            },
+            match &self.chain_id {
+                Some(chain_id) => format!("      {}             {}\n", "Chain Id".cyan().bold(), chain_id),
+                _ => "".to_owned(),
+            },
+            match &self.function {
+                Some(function) => format!("      {}             {}\n", "Function".cyan().bold(), function),
+                _ => "".to_owned(),
+            },
+            match &self.arguments {
+                Some(arguments) => format!(
+                    "      {}            {}\n",
+                    "Arguments".cyan().bold(),
+                    arguments.join(", ")
+                ),
+                _ => "".to_owned(),
+            },
+            match &self.value {
+                Some(value) => format!("      {}                {}\n", "Value".cyan().bold(), value),
+                _ => "".to_owned(),
+            },
+            match &self.gas {
+                Some(gas) => format!("      {}                  {}\n", "Gas".cyan().bold(), gas),
+                _ => "".to_owned(),
+            },
+            match &self.gas_price {
+                Some(gas_price) => format!("      {}            {}\n", "Gas Price".cyan().bold(), gas_price),
+                _ => "".to_owned(),
+            },
+            match &self.nonce {
+                Some(nonce) => format!("      {}                {}\n", "Nonce".cyan().bold(), nonce),
+                _ => "".to_owned(),
+            },
+            match &self.data {
+                Some(data) => format!("      {}                 {}\n", "Data".cyan().bold(), data),
+                _ => "".to_owned(),
+            },
+            match &self.access_list {
+                Some(access_list) => access_list
+                    .iter()
+                    .map(|item| {
+                        format!(
+                            "      {}          {} [{}]\n",
+                            "Access List".cyan().bold(),
+                            item.address,
+                            item.storage_keys.join(", ")
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .concat(),
+                _ => "".to_owned(),
+            },
             match &self.transaction_id {
                 Some(transaction_id) => format!("      {}       {}\n", "Transaction Id".cyan().bold(), transaction_id),
                 _ => "".to_owned(),
@@ -286,6 +833,10 @@ impl Display for EthereumWallet {
                 }
                 _ => "".to_owned(),
             },
+            match &self.uri {
+                Some(uri) => format!("      {}                  {}\n", "URI".cyan().bold(), uri),
+                _ => "".to_owned(),
+            },
         ]
         .concat();
 
@@ -305,26 +856,103 @@ pub struct EthereumInput {
     pub gas_price: String,
     pub nonce: u64,
     pub data: Option<String>,
+    #[serde(rename(deserialize = "accessList"))]
+    pub access_list: Option<Vec<EthereumAccessListInput>>,
+    #[serde(default)]
+    pub unprotected: bool,
+}
+
+impl EthereumInput {
+    /// Returns the parameters with a value of the specified balance less the fee of the gas limit at the gas price,
+    /// to sweep the balance of the sender. Returns an error if the balance does not cover the fee.
+    pub fn sweep(self, balance: &str) -> Result<Self, CLIError> {
+        let balance = EthereumAmount::u256_from_str(balance)?;
+        let fee = EthereumAmount::u256_from_str(&self.gas)?
+            .checked_mul(EthereumAmount::u256_from_str(&self.gas_price)?)
+            .ok_or(TransactionError::Message(
+                "the fee of the gas limit at the gas price overflows".into(),
+            ))?;
+        match balance.checked_sub(fee) {
+            Some(value) => Ok(Self {
+                value: value.to_string(),
+                ..self
+            }),
+            None => Err(TransactionError::Message(format!(
+                "the balance of {} wei does not cover the fee of {} wei",
+                balance, fee
+            ))
+            .into()),
+        }
+    }
+
+    /// Returns the type of the transaction, an access list transaction if an access list is specified,
+    /// or a legacy transaction without replay protection if it is unprotected.
+    fn to_transaction_type(&self) -> Result<EthereumTransactionType, CLIError> {
+        let access_list = match (&self.access_list, self.unprotected) {
+            (Some(_), true) => {
+                return Err(TransactionError::Message("an access list transaction is replay protected".into()).into())
+            }
+            (Some(access_list), false) => access_list,
+            (None, true) => return Ok(EthereumTransactionType::Unprotected),
+            (None, false) => return Ok(EthereumTransactionType::Legacy),
+        };
+
+        let mut items = vec![];
+        for item in access_list {
+            let mut storage_keys = vec![];
+            for storage_key in &item.storage_keys {
+                let bytes = hex::decode(storage_key.trim_start_matches("0x"))?;
+                if bytes.len() != 32 {
+                    return Err(TransactionError::Message(format!("invalid storage key {}", storage_key)).into());
+                }
+                let mut key = [0u8; 32];
+                key.copy_from_slice(&bytes);
+                storage_keys.push(key);
+            }
+            items.push(EthereumAccessListItem {
+                address: EthereumAddress::from_str(&item.address)?,
+                storage_keys,
+            });
+        }
+        Ok(EthereumTransactionType::AccessList(items))
+    }
+}
+
+/// Represents an address and the storage keys of an access list (EIP-2930)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EthereumAccessListInput {
+    pub address: String,
+    #[serde(rename(deserialize = "storageKeys"), default)]
+    pub storage_keys: Vec<String>,
 }
 
 /// Represents options for an Ethereum wallet
 #[derive(Clone, Debug, Serialize)]
 pub struct EthereumOptions {
     // Standard command
+    audit_rng: bool,
     count: usize,
-    json: bool,
+    network: String,
+    output_file: Option<String>,
+    output_format: String,
+    public_only: bool,
+    qr: bool,
+    qr_file: Option<String>,
     subcommand: Option<String>,
     // HD and Import HD subcommands
     derivation: String,
+    entropy: Option<String>,
+    entropy_file: Option<String>,
     extended_private_key: Option<String>,
     extended_public_key: Option<String>,
     index: u32,
     indices: u32,
     language: String,
     mnemonic: Option<String>,
+    neuter: bool,
     password: Option<String>,
     path: Option<String>,
-    word_count: u8,
+    word_count: Option<u8>,
     // Import subcommand
     address: Option<String>,
     private: Option<String>,
@@ -333,27 +961,64 @@ pub struct EthereumOptions {
     transaction_hex: Option<String>,
     transaction_parameters: Option<String>,
     transaction_private_key: Option<String>,
-    network: Option<String>,
+    rpc: Option<String>,
+    // Transaction sign subcommand
+    access_list: Option<String>,
+    balance: Option<String>,
+    call: Option<String>,
+    chain_id: u32,
+    data: Option<String>,
+    estimate_gas: bool,
+    fetch_nonce: bool,
+    gas: String,
+    gas_price: String,
+    nonce: u64,
+    to: Option<String>,
+    unprotected: bool,
+    value: String,
+    // Hardware subcommand
+    verify: bool,
+    // Paper subcommand
+    paper_file: Option<String>,
+    // Recover subcommand
+    message: Option<String>,
+    signature: Option<String>,
+    tx_hash: Option<String>,
+    // URI subcommand
+    uri: Option<String>,
+    uri_chain_id: Option<u64>,
+    uri_gas: Option<String>,
+    uri_gas_price: Option<String>,
+    uri_value: Option<String>,
 }
 
 impl Default for EthereumOptions {
     fn default() -> Self {
         Self {
             // Standard command
+            audit_rng: false,
             count: 1,
-            json: false,
+            network: "mainnet".into(),
+            output_file: None,
+            output_format: "text".into(),
+            public_only: false,
+            qr: false,
+            qr_file: None,
             subcommand: None,
             // HD and Import HD subcommands
             derivation: "ethereum".into(),
+            entropy: None,
+            entropy_file: None,
             extended_private_key: None,
             extended_public_key: None,
             index: 0,
             indices: 1,
             language: "english".into(),
             mnemonic: None,
+            neuter: false,
             password: None,
             path: None,
-            word_count: 12,
+            word_count: None,
             // Import subcommand
             address: None,
             private: None,
@@ -362,33 +1027,101 @@ impl Default for EthereumOptions {
             transaction_hex: None,
             transaction_parameters: None,
             transaction_private_key: None,
-            network: None,
+            rpc: None,
+            // Transaction sign subcommand
+            access_list: None,
+            balance: None,
+            call: None,
+            chain_id: EthereumMainnet::CHAIN_ID,
+            data: None,
+            estimate_gas: false,
+            fetch_nonce: false,
+            gas: "21000".into(),
+            gas_price: "0".into(),
+            nonce: 0,
+            to: None,
+            unprotected: false,
+            value: "0".into(),
+            // Hardware subcommand
+            verify: false,
+            // Paper subcommand
+            paper_file: None,
+            // Recover subcommand
+            message: None,
+            signature: None,
+            tx_hash: None,
+            // URI subcommand
+            uri: None,
+            uri_chain_id: None,
+            uri_gas: None,
+            uri_gas_price: None,
+            uri_value: None,
         }
     }
 }
 
 impl EthereumOptions {
-    fn parse(&mut self, arguments: &ArgMatches, options: &[&str]) {
-        options.iter().for_each(|option| match *option {
-            "address" => self.address(arguments.value_of(option)),
-            "count" => self.count(clap::value_t!(arguments.value_of(*option), usize).ok()),
-            "createrawtransaction" => self.create_raw_transaction(arguments.value_of(option)),
-            "derivation" => self.derivation(arguments.value_of(option)),
-            "extended private" => self.extended_private(arguments.value_of(option)),
-            "extended public" => self.extended_public(arguments.value_of(option)),
-            "json" => self.json(arguments.is_present(option)),
-            "index" => self.index(clap::value_t!(arguments.value_of(*option), u32).ok()),
-            "indices" => self.indices(clap::value_t!(arguments.value_of(*option), u32).ok()),
-            "language" => self.language(arguments.value_of(option)),
-            "mnemonic" => self.mnemonic(arguments.value_of(option)),
-            "network" => self.network(arguments.value_of(option)),
-            "password" => self.password(arguments.value_of(option)),
-            "private" => self.private(arguments.value_of(option)),
-            "public" => self.public(arguments.value_of(option)),
-            "signrawtransaction" => self.sign_raw_transaction(arguments.values_of(option)),
-            "word count" => self.word_count(clap::value_t!(arguments.value_of(*option), u8).ok()),
-            _ => (),
-        });
+    fn parse(&mut self, arguments: &ArgMatches, options: &[&str]) -> Result<(), CLIError> {
+        for option in options {
+            match *option {
+                "access list" => self.access_list(arguments.value_of(option)),
+                "address" => self.address(arguments.value_of(option)),
+                "audit rng" => self.audit_rng(arguments.is_present(option)),
+                "balance" => self.balance(arguments.value_of(option)),
+                "call" => self.call(arguments.value_of(option)),
+                "chain id" => self.chain_id(clap::value_t!(arguments.value_of(*option), u32).ok()),
+                "count" => self.count(clap::value_t!(arguments.value_of(*option), usize).ok()),
+                "createrawtransaction" => self.create_raw_transaction(arguments.value_of(option)),
+                "data" => self.data(arguments.value_of(option)),
+                "estimate gas" => self.estimate_gas(arguments.is_present(option)),
+                "derivation" => self.derivation(arguments.value_of(option)),
+                "entropy" => self.entropy(arguments.value_of(option)),
+                "entropy file" => self.entropy_file(arguments.value_of(option)),
+                "extended private" => self.extended_private(arguments.value_of(option)),
+                "extended public" => self.extended_public(arguments.value_of(option)),
+                "fetch nonce" => self.fetch_nonce(arguments.is_present(option)),
+                "gas" => self.gas(arguments.value_of(option)),
+                "gas price" => self.gas_price(arguments.value_of(option)),
+                "json" => self.json(arguments.is_present(option)),
+                "index" => self.index(clap::value_t!(arguments.value_of(*option), u32).ok()),
+                "indices" => self.indices(clap::value_t!(arguments.value_of(*option), u32).ok()),
+                "index range" => {
+                    self.index_range(arguments.value_of(option))?;
+                }
+                "language" => self.language(arguments.value_of(option)),
+                "message" => self.message(arguments.value_of(option)),
+                "mnemonic" => self.mnemonic(value_or_stdin(arguments, option).as_deref()),
+                "neuter" => self.neuter(arguments.is_present(option)),
+                "network" => self.network(arguments.value_of(option)),
+                "nonce" => self.nonce(clap::value_t!(arguments.value_of(*option), u64).ok()),
+                "paper file" => self.paper_file(arguments.value_of(option)),
+                "password" => self.password(value_or_stdin(arguments, option).as_deref()),
+                "private" => self.private(value_or_stdin(arguments, option).as_deref()),
+                "public" => self.public(arguments.value_of(option)),
+                "output file" => self.output_file(arguments.value_of(option)),
+                "output format" => self.output_format(arguments.value_of(option)),
+                "public only" => self.public_only(arguments.is_present(option)),
+                "qr" => self.qr(arguments.is_present(option)),
+                "qr file" => self.qr_file(arguments.value_of(option)),
+                "raw" => self.raw(arguments.value_of(option)),
+                "rpc" => self.rpc(arguments.value_of(option)),
+                "signrawtransaction" => self.sign_raw_transaction(arguments.values_of(option)),
+                "signature" => self.signature(arguments.value_of(option)),
+                "to" => self.to(arguments.value_of(option)),
+                "tx hash" => self.tx_hash(arguments.value_of(option)),
+                "unprotected" => self.unprotected(arguments.is_present(option)),
+                "uri" => self.uri(arguments.value_of(option)),
+                "uri chain id" => self.uri_chain_id(clap::value_t!(arguments.value_of(*option), u64).ok()),
+                "uri gas" => self.uri_gas(arguments.value_of(option)),
+                "uri gas price" => self.uri_gas_price(arguments.value_of(option)),
+                "uri value" => self.uri_value(arguments.value_of(option)),
+                "value" => self.value(arguments.value_of(option)),
+                "verify" => self.verify(arguments.is_present(option)),
+                "word count" => self.word_count(clap::value_t!(arguments.value_of(*option), u8).ok()),
+                _ => (),
+            };
+        }
+        Ok(())
     }
 
     /// Imports a wallet for the specified address, overriding its previous state.
@@ -399,6 +1132,46 @@ impl EthereumOptions {
         }
     }
 
+    /// Sets `access_list` to the specified access list, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn access_list(&mut self, argument: Option<&str>) {
+        if let Some(access_list) = argument {
+            self.access_list = Some(access_list.to_string());
+        }
+    }
+
+    /// Enables `audit_rng` if the specified boolean value is set.
+    /// Once enabled at any level of the command, the entropy drawn remains audited.
+    fn audit_rng(&mut self, argument: bool) {
+        if argument {
+            self.audit_rng = true;
+        }
+    }
+
+    /// Sets `balance` to the specified balance to sweep, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn balance(&mut self, argument: Option<&str>) {
+        if let Some(balance) = argument {
+            self.balance = Some(balance.to_string());
+        }
+    }
+
+    /// Sets `call` to the specified contract function call, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn call(&mut self, argument: Option<&str>) {
+        if let Some(call) = argument {
+            self.call = Some(call.to_string());
+        }
+    }
+
+    /// Sets `chain_id` to the specified chain id, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn chain_id(&mut self, argument: Option<u32>) {
+        if let Some(chain_id) = argument {
+            self.chain_id = chain_id;
+        }
+    }
+
     /// Sets `count` to the specified count, overriding its previous state.
     fn count(&mut self, argument: Option<usize>) {
         if let Some(count) = argument {
@@ -414,6 +1187,14 @@ impl EthereumOptions {
         }
     }
 
+    /// Sets `data` to the specified transaction data, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn data(&mut self, argument: Option<&str>) {
+        if let Some(data) = argument {
+            self.data = Some(data.to_string());
+        }
+    }
+
     /// Sets `derivation` to the specified derivation, overriding its previous state.
     /// If `derivation` is `\"custom\"`, then `path` is set to the specified path.
     /// If the specified argument is `None`, then no change occurs.
@@ -422,7 +1203,7 @@ impl EthereumOptions {
             Some("ethereum") => self.derivation = "ethereum".into(),
             Some("keepkey") => self.derivation = "keepkey".into(),
             Some("ledger-legacy") => self.derivation = "ledger-legacy".into(),
-            Some("ledger-live") => self.derivation = "ledger-legacy".into(),
+            Some("ledger-live") => self.derivation = "ledger-live".into(),
             Some("trezor") => self.derivation = "trezor".into(),
             Some(custom) => {
                 self.derivation = "custom".into();
@@ -432,6 +1213,27 @@ impl EthereumOptions {
         };
     }
 
+    /// Sets `entropy` to the specified hex entropy, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn entropy(&mut self, argument: Option<&str>) {
+        if let Some(entropy) = argument {
+            self.entropy = Some(entropy.to_string());
+        }
+    }
+
+    /// Sets `entropy_file` to the specified entropy file path, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn entropy_file(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.entropy_file = Some(path.to_string());
+        }
+    }
+
+    /// Sets `estimate_gas` to the specified boolean value, overriding its previous state.
+    fn estimate_gas(&mut self, argument: bool) {
+        self.estimate_gas = argument;
+    }
+
     /// Sets `extended_private_key` to the specified extended private key, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn extended_private(&mut self, argument: Option<&str>) {
@@ -448,6 +1250,27 @@ impl EthereumOptions {
         }
     }
 
+    /// Sets `fetch_nonce` to the specified boolean value, overriding its previous state.
+    fn fetch_nonce(&mut self, argument: bool) {
+        self.fetch_nonce = argument;
+    }
+
+    /// Sets `gas` to the specified gas limit, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn gas(&mut self, argument: Option<&str>) {
+        if let Some(gas) = argument {
+            self.gas = gas.to_string();
+        }
+    }
+
+    /// Sets `gas_price` to the specified gas price, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn gas_price(&mut self, argument: Option<&str>) {
+        if let Some(gas_price) = argument {
+            self.gas_price = gas_price.to_string();
+        }
+    }
+
     /// Sets `index` to the specified index, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn index(&mut self, argument: Option<u32>) {
@@ -464,9 +1287,24 @@ impl EthereumOptions {
         }
     }
 
-    /// Sets `json` to the specified boolean value, overriding its previous state.
+    /// Sets `index` and `indices` to the specified range of indices, overriding their previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    /// Returns the range that was set, or an error if the argument is not a non-empty `start..end` range.
+    fn index_range(&mut self, argument: Option<&str>) -> Result<Option<(u32, u32)>, CLIError> {
+        let index_range = argument.map(parse_index_range).transpose()?;
+        if let Some((start, end)) = index_range {
+            self.index = start;
+            self.indices = end - start;
+        }
+        Ok(index_range)
+    }
+
+    /// Sets `output_format` to JSON if the specified boolean value is set.
+    /// Once enabled at any level of the command, JSON remains the output format.
     fn json(&mut self, argument: bool) {
-        self.json = argument;
+        if argument {
+            self.output_format = "json".into();
+        }
     }
 
     /// Sets `language` to the specified language, overriding its previous state.
@@ -485,6 +1323,14 @@ impl EthereumOptions {
         };
     }
 
+    /// Sets `message` to the specified signed message, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn message(&mut self, argument: Option<&str>) {
+        if let Some(message) = argument {
+            self.message = Some(message.to_string());
+        }
+    }
+
     /// Sets `mnemonic` to the specified mnemonic, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn mnemonic(&mut self, argument: Option<&str>) {
@@ -493,11 +1339,62 @@ impl EthereumOptions {
         }
     }
 
-    /// Sets `network` to the specified network, overriding its previous state.
+    /// Enables `neuter` if the specified boolean value is set.
+    fn neuter(&mut self, argument: bool) {
+        if argument {
+            self.neuter = true;
+        }
+    }
+
+    /// Sets `network` and `chain_id` to the specified network and its EIP-155 chain id,
+    /// overriding their previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn network(&mut self, argument: Option<&str>) {
+        let chain_id = match argument {
+            Some(Goerli::NAME) => Goerli::CHAIN_ID,
+            Some(Kovan::NAME) => Kovan::CHAIN_ID,
+            Some(EthereumMainnet::NAME) => EthereumMainnet::CHAIN_ID,
+            Some(Rinkeby::NAME) => Rinkeby::CHAIN_ID,
+            Some(Ropsten::NAME) => Ropsten::CHAIN_ID,
+            _ => return,
+        };
         if let Some(network) = argument {
-            self.network = Some(network.to_string());
+            self.network = network.to_string();
+            self.chain_id = chain_id;
+        }
+    }
+
+    /// Sets `nonce` to the specified nonce, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn nonce(&mut self, argument: Option<u64>) {
+        if let Some(nonce) = argument {
+            self.nonce = nonce;
+        }
+    }
+
+    /// Sets `output_file` to the specified file path, overriding its previous state.
+    /// Colored output is disabled, as the file is not a terminal.
+    /// If the specified argument is `None`, then no change occurs.
+    fn output_file(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.output_file = Some(path.to_string());
+            colored::control::set_override(false);
+        }
+    }
+
+    /// Sets `output_format` to the specified output format, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn output_format(&mut self, argument: Option<&str>) {
+        if let Some(output_format) = argument {
+            self.output_format = output_format.to_string();
+        }
+    }
+
+    /// Sets `paper_file` to the specified file path, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn paper_file(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.paper_file = Some(path.to_string());
         }
     }
 
@@ -525,6 +1422,43 @@ impl EthereumOptions {
         }
     }
 
+    /// Enables `public_only` if the specified boolean value is set.
+    /// Once enabled at any level of the command, the secrets of the wallet(s) remain withheld.
+    fn public_only(&mut self, argument: bool) {
+        if argument {
+            self.public_only = true;
+        }
+    }
+
+    /// Sets `qr` to the specified boolean value, overriding its previous state.
+    fn qr(&mut self, argument: bool) {
+        self.qr = argument;
+    }
+
+    /// Sets `qr_file` to the specified file path, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn qr_file(&mut self, argument: Option<&str>) {
+        if let Some(qr_file) = argument {
+            self.qr_file = Some(qr_file.to_string());
+        }
+    }
+
+    /// Sets `transaction_hex` to the specified raw transaction, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn raw(&mut self, argument: Option<&str>) {
+        if let Some(transaction_hex) = argument {
+            self.transaction_hex = Some(transaction_hex.to_string());
+        }
+    }
+
+    /// Sets `rpc` to the specified JSON-RPC URL, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn rpc(&mut self, argument: Option<&str>) {
+        if let Some(rpc) = argument {
+            self.rpc = Some(rpc.to_string());
+        }
+    }
+
     /// Sets `transaction_hex` and `transaction_private_key` to the specified transaction values, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn sign_raw_transaction(&mut self, argument: Option<Values>) {
@@ -535,11 +1469,93 @@ impl EthereumOptions {
         }
     }
 
+    /// Sets `signature` to the specified signature, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn signature(&mut self, argument: Option<&str>) {
+        if let Some(signature) = argument {
+            self.signature = Some(signature.to_string());
+        }
+    }
+
+    /// Sets `to` to the specified receiver address, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn to(&mut self, argument: Option<&str>) {
+        if let Some(to) = argument {
+            self.to = Some(to.to_string());
+        }
+    }
+
+    /// Sets `tx_hash` to the specified signed hash, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn tx_hash(&mut self, argument: Option<&str>) {
+        if let Some(tx_hash) = argument {
+            self.tx_hash = Some(tx_hash.to_string());
+        }
+    }
+
+    /// Sets `unprotected` to the specified boolean value, overriding its previous state.
+    fn unprotected(&mut self, argument: bool) {
+        self.unprotected = argument;
+    }
+
+    /// Sets `uri` to the specified payment request URI, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn uri(&mut self, argument: Option<&str>) {
+        if let Some(uri) = argument {
+            self.uri = Some(uri.to_string());
+        }
+    }
+
+    /// Sets `uri_chain_id` to the specified chain id of a payment request, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn uri_chain_id(&mut self, argument: Option<u64>) {
+        if let Some(chain_id) = argument {
+            self.uri_chain_id = Some(chain_id);
+        }
+    }
+
+    /// Sets `uri_gas` to the specified gas limit of a payment request, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn uri_gas(&mut self, argument: Option<&str>) {
+        if let Some(gas) = argument {
+            self.uri_gas = Some(gas.to_string());
+        }
+    }
+
+    /// Sets `uri_gas_price` to the specified gas price of a payment request, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn uri_gas_price(&mut self, argument: Option<&str>) {
+        if let Some(gas_price) = argument {
+            self.uri_gas_price = Some(gas_price.to_string());
+        }
+    }
+
+    /// Sets `uri_value` to the specified value of a payment request, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn uri_value(&mut self, argument: Option<&str>) {
+        if let Some(value) = argument {
+            self.uri_value = Some(value.to_string());
+        }
+    }
+
+    /// Sets `value` to the specified value, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn value(&mut self, argument: Option<&str>) {
+        if let Some(value) = argument {
+            self.value = value.to_string();
+        }
+    }
+
+    /// Sets `verify` to the specified boolean value, overriding its previous state.
+    fn verify(&mut self, argument: bool) {
+        self.verify = argument;
+    }
+
     /// Sets `word_count` to the specified word count, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn word_count(&mut self, argument: Option<u8>) {
         if let Some(word_count) = argument {
-            self.word_count = word_count;
+            self.word_count = Some(word_count);
         }
     }
 
@@ -575,6 +1591,39 @@ impl EthereumOptions {
             })
             .collect()
     }
+
+    /// Returns the transaction parameters with the receiver resolved from an ENS name, and with
+    /// the nonce, gas limit, and gas price fetched from the node at `rpc`, if `fetch_nonce` or
+    /// `estimate_gas` is enabled.
+    #[cfg(feature = "broadcast")]
+    fn fetch_parameters(&self, mut parameters: EthereumInput, private_key: &str) -> Result<EthereumInput, CLIError> {
+        if is_ens_name(&parameters.to) {
+            parameters.to = match self.rpc.as_ref() {
+                Some(rpc) => rpc::resolve_ens_name(rpc, &parameters.to)?,
+                None => {
+                    return Err(CLIError::RpcError(format!(
+                        "resolving {} requires --rpc",
+                        parameters.to
+                    )))
+                }
+            };
+        }
+        if let (Some(rpc), true) = (self.rpc.as_ref(), self.fetch_nonce || self.estimate_gas) {
+            let sender = EthereumPrivateKey::from_str(private_key)?
+                .to_address(&EthereumFormat::Standard)?
+                .to_string();
+            if self.fetch_nonce {
+                parameters.nonce = rpc::ethereum_transaction_count(rpc, &sender)?;
+            }
+            if self.estimate_gas {
+                let data = parameters.data.as_deref();
+                parameters.gas =
+                    rpc::ethereum_estimate_gas(rpc, &sender, &parameters.to, &parameters.value, data)?.to_string();
+                parameters.gas_price = rpc::ethereum_gas_price(rpc)?.to_string();
+            }
+        }
+        Ok(parameters)
+    }
 }
 
 pub struct EthereumCLI;
@@ -583,39 +1632,108 @@ impl CLI for EthereumCLI {
     type Options = EthereumOptions;
 
     const ABOUT: AboutType = "Generates a Ethereum wallet (include -h for more options)";
-    const FLAGS: &'static [FlagType] = &[flag::JSON];
+    const FLAGS: &'static [FlagType] = &[
+        flag::AUDIT_RNG,
+        flag::JSON,
+        flag::OUTPUT_FILE,
+        flag::OUTPUT_FORMAT,
+        flag::PUBLIC_ONLY,
+        flag::QR,
+        flag::QR_FILE,
+    ];
     const NAME: NameType = "ethereum";
-    const OPTIONS: &'static [OptionType] = &[option::COUNT];
+    const OPTIONS: &'static [OptionType] = &[option::COUNT, option::NETWORK_ETHEREUM];
     const SUBCOMMANDS: &'static [SubCommandType] = &[
+        subcommand::CONVERT_ETHEREUM,
         subcommand::HD_ETHEREUM,
+        #[cfg(feature = "hw-ledger")]
+        subcommand::HW_ETHEREUM,
         subcommand::IMPORT_ETHEREUM,
         subcommand::IMPORT_HD_ETHEREUM,
+        subcommand::PAPER_ETHEREUM,
+        subcommand::RECOVER_ETHEREUM,
         subcommand::TRANSACTION_ETHEREUM,
+        subcommand::URI_ETHEREUM,
+        subcommand::VALIDATE_ETHEREUM,
     ];
 
     /// Handle all CLI arguments and flags for Ethereum
     #[cfg_attr(tarpaulin, skip)]
     fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
         let mut options = EthereumOptions::default();
-        options.parse(arguments, &["count", "json"]);
+        options.parse(
+            arguments,
+            &[
+                "audit rng",
+                "count",
+                "json",
+                "network",
+                "output file",
+                "output format",
+                "public only",
+                "qr",
+                "qr file",
+            ],
+        )?;
 
         match arguments.subcommand() {
             ("hd", Some(arguments)) => {
                 options.subcommand = Some("hd".into());
-                options.parse(arguments, &["count", "json"]);
                 options.parse(
                     arguments,
-                    &["derivation", "index", "indices", "language", "password", "word count"],
-                );
+                    &[
+                        "audit rng",
+                        "count",
+                        "json",
+                        "output file",
+                        "output format",
+                        "public only",
+                        "qr",
+                        "qr file",
+                    ],
+                )?;
+                options.parse(
+                    arguments,
+                    &[
+                        "derivation",
+                        "entropy",
+                        "entropy file",
+                        "index",
+                        "indices",
+                        "index range",
+                        "language",
+                        "password",
+                        "word count",
+                    ],
+                )?;
+            }
+            ("hw", Some(arguments)) => {
+                options.subcommand = Some("hw".into());
+                options.parse(
+                    arguments,
+                    &["json", "output file", "output format", "public only", "qr", "qr file"],
+                )?;
+                options.parse(arguments, &["derivation", "index", "verify"])?;
             }
             ("import", Some(arguments)) => {
                 options.subcommand = Some("import".into());
-                options.parse(arguments, &["json"]);
-                options.parse(arguments, &["address", "private", "public"]);
+                options.parse(
+                    arguments,
+                    &["json", "output file", "output format", "public only", "qr", "qr file"],
+                )?;
+                options.parse(arguments, &["address", "private", "public"])?;
+            }
+            ("convert", Some(arguments)) => {
+                options.subcommand = Some("convert".into());
+                options.parse(arguments, &["json", "output file", "output format", "public only"])?;
+                options.parse(arguments, &["derivation", "mnemonic", "password", "private"])?;
             }
             ("import-hd", Some(arguments)) => {
                 options.subcommand = Some("import-hd".into());
-                options.parse(arguments, &["json"]);
+                options.parse(
+                    arguments,
+                    &["json", "output file", "output format", "public only", "qr", "qr file"],
+                )?;
                 options.parse(
                     arguments,
                     &[
@@ -626,14 +1744,152 @@ impl CLI for EthereumCLI {
                         "extended public",
                         "index",
                         "indices",
+                        "index range",
                         "mnemonic",
+                        "neuter",
                         "password",
+                        "word count",
                     ],
-                );
+                )?;
+            }
+            ("paper", Some(arguments)) => {
+                // A derivation path generates an HD paper wallet with a mnemonic
+                options.subcommand = match arguments.is_present("derivation") {
+                    true => Some("paper hd".into()),
+                    false => Some("paper".into()),
+                };
+                options.parse(arguments, &["audit rng", "count"])?;
+                options.parse(arguments, &["derivation", "language", "paper file", "word count"])?;
             }
             ("transaction", Some(arguments)) => {
                 options.subcommand = Some("transaction".into());
-                options.parse(arguments, &["createrawtransaction", "network", "signrawtransaction"]);
+                options.parse(arguments, &["createrawtransaction", "network", "signrawtransaction"])?;
+
+                match arguments.subcommand() {
+                    ("decode", Some(arguments)) => {
+                        options.subcommand = Some("transaction decode".into());
+                        options.parse(
+                            arguments,
+                            &["json", "output file", "output format", "public only", "qr", "qr file"],
+                        )?;
+                        options.parse(arguments, &["raw"])?;
+                    }
+                    ("send", Some(arguments)) => {
+                        options.subcommand = Some("transaction send".into());
+                        options.parse(
+                            arguments,
+                            &["json", "output file", "output format", "public only", "qr", "qr file"],
+                        )?;
+                        options.parse(arguments, &["raw", "rpc"])?;
+                    }
+                    ("sign", Some(arguments)) => {
+                        options.subcommand = Some("transaction sign".into());
+                        options.parse(
+                            arguments,
+                            &[
+                                "json",
+                                "network",
+                                "output file",
+                                "output format",
+                                "public only",
+                                "qr",
+                                "qr file",
+                            ],
+                        )?;
+                        options.parse(
+                            arguments,
+                            &[
+                                "access list",
+                                "call",
+                                "chain id",
+                                "data",
+                                "estimate gas",
+                                "fetch nonce",
+                                "gas",
+                                "gas price",
+                                "nonce",
+                                "private",
+                                "rpc",
+                                "to",
+                                "unprotected",
+                                "value",
+                            ],
+                        )?;
+                    }
+                    ("sweep", Some(arguments)) => {
+                        options.subcommand = Some("transaction sweep".into());
+                        options.parse(
+                            arguments,
+                            &[
+                                "json",
+                                "network",
+                                "output file",
+                                "output format",
+                                "public only",
+                                "qr",
+                                "qr file",
+                            ],
+                        )?;
+                        options.parse(
+                            arguments,
+                            &[
+                                "balance",
+                                "chain id",
+                                "fetch nonce",
+                                "gas",
+                                "gas price",
+                                "nonce",
+                                "private",
+                                "rpc",
+                                "to",
+                                "unprotected",
+                            ],
+                        )?;
+                    }
+                    _ => {}
+                };
+            }
+            ("recover", Some(arguments)) => {
+                options.subcommand = Some("recover".into());
+                options.parse(
+                    arguments,
+                    &["json", "output file", "output format", "public only", "qr", "qr file"],
+                )?;
+                options.parse(arguments, &["message", "signature", "tx hash"])?;
+            }
+            ("uri", Some(arguments)) => match arguments.subcommand() {
+                ("create", Some(arguments)) => {
+                    options.subcommand = Some("uri create".into());
+                    options.parse(
+                        arguments,
+                        &["json", "output file", "output format", "public only", "qr", "qr file"],
+                    )?;
+                    options.parse(
+                        arguments,
+                        &[
+                            "address",
+                            "call",
+                            "uri chain id",
+                            "uri gas",
+                            "uri gas price",
+                            "uri value",
+                        ],
+                    )?;
+                }
+                ("parse", Some(arguments)) => {
+                    options.subcommand = Some("uri parse".into());
+                    options.parse(
+                        arguments,
+                        &["json", "output file", "output format", "public only", "qr", "qr file"],
+                    )?;
+                    options.parse(arguments, &["uri"])?;
+                }
+                _ => {}
+            },
+            ("validate", Some(arguments)) => {
+                options.subcommand = Some("validate".into());
+                options.parse(arguments, &["json", "output file", "output format", "public only"])?;
+                options.parse(arguments, &["address"])?;
             }
             _ => {}
         };
@@ -645,38 +1901,69 @@ impl CLI for EthereumCLI {
     #[cfg_attr(tarpaulin, skip)]
     fn print(options: Self::Options) -> Result<(), CLIError> {
         fn output<N: EthereumNetwork, W: EthereumWordlist>(options: EthereumOptions) -> Result<(), CLIError> {
+            if let Some("validate") = options.subcommand.as_deref() {
+                return validate(options);
+            }
+
+            // Generate new wallets lazily, to stream a large count of wallets to the output
+            let mut rng = wallet_rng(options.audit_rng);
+            let generated: Box<dyn Iterator<Item = EthereumWallet>> = match options.subcommand.as_deref() {
+                Some("hd") | Some("paper hd") => {
+                    let word_count = options.word_count.unwrap_or(12);
+                    let password = options.password.clone();
+                    let mut entropy = Entropy::new(
+                        options.entropy.as_deref(),
+                        options.entropy_file.as_deref(),
+                        options.audit_rng,
+                    )?;
+                    let path = options.to_derivation_path(true).unwrap();
+                    let paths = options
+                        .to_derivation_paths(true)
+                        .into_iter()
+                        .flatten()
+                        .collect::<Vec<_>>();
+                    Box::new((0..options.count).flat_map(move |_| {
+                        let password = password.as_deref();
+                        // Sample a new HD wallet
+                        let wallet = EthereumWallet::new_hd::<N, W>(&mut entropy, word_count, password, &path).unwrap();
+                        let mnemonic = &wallet.mnemonic.unwrap();
+
+                        // Generate the HD wallet, from `index` to a number of specified `indices`
+                        EthereumWallet::from_mnemonic_paths::<N, W>(mnemonic, password, &paths).unwrap_or_default()
+                    }))
+                }
+                None | Some("paper") => {
+                    Box::new(
+                        (0..options.count).flat_map(|_| match EthereumWallet::new::<_>(&mut rng) {
+                            Ok(wallet) => vec![wallet],
+                            _ => vec![],
+                        }),
+                    )
+                }
+                _ => Box::new(iter::empty()),
+            };
+
             let wallets = match options.subcommand.as_ref().map(String::as_str) {
-                Some("hd") => {
-                    let password = options.password.as_ref().map(String::as_str);
-                    (0..options.count)
-                        .flat_map(|_| {
-                            // Sample a new HD wallet
-                            let wallet = EthereumWallet::new_hd::<N, W, _>(
-                                &mut StdRng::from_entropy(),
-                                options.word_count,
-                                password,
-                                &options.to_derivation_path(true).unwrap(),
-                            )
-                            .unwrap();
-                            let mnemonic = &wallet.mnemonic.unwrap();
-
-                            // Generate the HD wallet, from `index` to a number of specified `indices`
-                            options
-                                .to_derivation_paths(true)
-                                .iter()
-                                .flat_map(|path| {
-                                    match EthereumWallet::from_mnemonic::<N, W>(
-                                        mnemonic,
-                                        password,
-                                        path.as_ref().unwrap(),
-                                    ) {
-                                        Ok(wallet) => vec![wallet],
-                                        _ => vec![],
-                                    }
-                                })
-                                .collect::<Vec<EthereumWallet>>()
-                        })
-                        .collect()
+                #[cfg(feature = "hw-ledger")]
+                Some("hw") => match options.to_derivation_path(true) {
+                    Some(path) => vec![EthereumWallet::from_ledger::<N>(
+                        &Ledger::open()?,
+                        &path,
+                        options.verify,
+                    )?],
+                    None => vec![],
+                },
+                Some("convert") => {
+                    if let Some(private_key) = options.private.as_deref() {
+                        vec![EthereumWallet::from_converted_private_key(private_key)?]
+                    } else if let (Some(mnemonic), Some(path)) =
+                        (options.mnemonic.as_deref(), options.to_derivation_path(true))
+                    {
+                        let password = options.password.as_deref();
+                        vec![EthereumWallet::from_converted_mnemonic::<N>(mnemonic, password, &path)?]
+                    } else {
+                        vec![]
+                    }
                 }
                 Some("import") => {
                     if let Some(private_key) = options.private {
@@ -691,31 +1978,16 @@ impl CLI for EthereumCLI {
                 }
                 Some("import-hd") => {
                     if let Some(mnemonic) = options.mnemonic.clone() {
-                        fn process_mnemonic<EN: EthereumNetwork, EW: EthereumWordlist>(
-                            mnemonic: &String,
-                            options: &EthereumOptions,
-                        ) -> Result<Vec<EthereumWallet>, CLIError> {
-                            // Generate the mnemonic wallets, from `index` to a number of specified `indices`
-                            let mut wallets = vec![];
-                            let password = options.password.as_ref().map(String::as_str);
-                            for path in options.to_derivation_paths(true) {
-                                wallets.push(EthereumWallet::from_mnemonic::<EN, EW>(
-                                    mnemonic,
-                                    password,
-                                    path.as_ref().unwrap(),
-                                )?);
-                            }
-                            Ok(wallets)
-                        }
+                        check_word_count(&mnemonic, options.word_count)?;
 
-                        process_mnemonic::<N, ChineseSimplified>(&mnemonic, &options)
-                            .or(process_mnemonic::<N, ChineseTraditional>(&mnemonic, &options))
-                            .or(process_mnemonic::<N, English>(&mnemonic, &options))
-                            .or(process_mnemonic::<N, French>(&mnemonic, &options))
-                            .or(process_mnemonic::<N, Italian>(&mnemonic, &options))
-                            .or(process_mnemonic::<N, Japanese>(&mnemonic, &options))
-                            .or(process_mnemonic::<N, Korean>(&mnemonic, &options))
-                            .or(process_mnemonic::<N, Spanish>(&mnemonic, &options))?
+                        // Generate the mnemonic wallets, from `index` to a number of specified `indices`
+                        let password = options.password.as_ref().map(String::as_str);
+                        let paths = options
+                            .to_derivation_paths(true)
+                            .into_iter()
+                            .flatten()
+                            .collect::<Vec<_>>();
+                        EthereumWallet::from_mnemonic_paths_any_language::<N>(&mnemonic, password, &paths)?
                     } else if let Some(extended_private_key) = options.extended_private_key.clone() {
                         // Generate the extended private keys, from `index` to a number of specified `indices`
                         options
@@ -753,63 +2025,458 @@ impl CLI for EthereumCLI {
                     } else if let (Some(transaction_hex), Some(transaction_private_key)) =
                         (options.transaction_hex.clone(), options.transaction_private_key.clone())
                     {
-                        match options.network.as_ref().map(String::as_str) {
-                            Some(EthereumMainnet::NAME) => vec![EthereumWallet::to_signed_transaction::<
-                                EthereumMainnet,
-                            >(
-                                transaction_hex, transaction_private_key
-                            )?],
-                            Some(Goerli::NAME) => vec![EthereumWallet::to_signed_transaction::<Goerli>(
-                                transaction_hex,
-                                transaction_private_key,
-                            )?],
-                            Some(Kovan::NAME) => vec![EthereumWallet::to_signed_transaction::<Kovan>(
-                                transaction_hex,
-                                transaction_private_key,
-                            )?],
-                            Some(Rinkeby::NAME) => vec![EthereumWallet::to_signed_transaction::<Rinkeby>(
-                                transaction_hex,
-                                transaction_private_key,
-                            )?],
-                            Some(Ropsten::NAME) => vec![EthereumWallet::to_signed_transaction::<Ropsten>(
-                                transaction_hex,
-                                transaction_private_key,
-                            )?],
-                            _ => vec![EthereumWallet::to_signed_transaction::<EthereumMainnet>(
-                                transaction_hex,
-                                transaction_private_key,
-                            )?],
-                        }
+                        vec![EthereumWallet::to_signed_transaction::<N>(
+                            transaction_hex,
+                            transaction_private_key,
+                        )?]
                     } else {
                         vec![]
                     }
                 }
-                _ => (0..options.count)
-                    .flat_map(|_| match EthereumWallet::new::<_>(&mut StdRng::from_entropy()) {
-                        Ok(wallet) => vec![wallet],
+                Some("recover") => match (&options.message, &options.tx_hash, &options.signature) {
+                    (Some(message), None, Some(signature)) => {
+                        vec![EthereumWallet::from_message_signature(message, signature)?]
+                    }
+                    (None, Some(hash), Some(signature)) => vec![EthereumWallet::from_hash_signature(hash, signature)?],
+                    _ => vec![],
+                },
+                Some("transaction decode") => match options.transaction_hex.as_ref() {
+                    // The network of a signed transaction is determined by its EIP-155 chain id
+                    Some(transaction_hex) => {
+                        vec![EthereumWallet::from_transaction_hex::<EthereumMainnet>(transaction_hex)
+                            .or(EthereumWallet::from_transaction_hex::<Ropsten>(transaction_hex))
+                            .or(EthereumWallet::from_transaction_hex::<Rinkeby>(transaction_hex))
+                            .or(EthereumWallet::from_transaction_hex::<Goerli>(transaction_hex))
+                            .or(EthereumWallet::from_transaction_hex::<Kovan>(transaction_hex))
+                            .or(EthereumWallet::from_transaction_hex::<Custom>(transaction_hex))?]
+                    }
+                    None => vec![],
+                },
+                #[cfg(feature = "broadcast")]
+                Some("transaction send") => match (options.transaction_hex.as_ref(), options.rpc.as_ref()) {
+                    (Some(transaction_hex), Some(rpc)) => vec![EthereumWallet {
+                        transaction_id: Some(rpc::send_ethereum_transaction(rpc, transaction_hex)?),
+                        ..Default::default()
+                    }],
+                    _ => vec![],
+                },
+                Some("transaction sign") | Some("transaction sweep") => {
+                    match (options.to.clone(), options.private.as_ref()) {
+                        (Some(to), Some(private_key)) => {
+                            let parameters = EthereumInput {
+                                to,
+                                value: options.value.clone(),
+                                gas: options.gas.clone(),
+                                gas_price: options.gas_price.clone(),
+                                nonce: options.nonce,
+                                data: match options.call.as_ref() {
+                                    Some(call) => {
+                                        let (function, arguments) = AbiFunction::from_call(call)?;
+                                        Some(format!("0x{}", hex::encode(function.encode_input(&arguments)?)))
+                                    }
+                                    None => options.data.clone(),
+                                },
+                                access_list: match options.access_list.as_ref() {
+                                    Some(access_list) => Some(from_str(access_list)?),
+                                    None => None,
+                                },
+                                unprotected: options.unprotected,
+                            };
+                            #[cfg(feature = "broadcast")]
+                            let parameters = options.fetch_parameters(parameters, private_key)?;
+
+                            // A sweep sends the balance less the fee, so its value is set once the gas is known
+                            let parameters = match options.balance.as_ref() {
+                                Some(balance) => parameters.sweep(balance)?,
+                                None => parameters,
+                            };
+
+                            let chain_id = options.chain_id;
+                            vec![match chain_id {
+                                EthereumMainnet::CHAIN_ID => EthereumWallet::to_signed_transaction_from_parameters::<
+                                    EthereumMainnet,
+                                >(
+                                    parameters, private_key, chain_id
+                                )?,
+                                Goerli::CHAIN_ID => EthereumWallet::to_signed_transaction_from_parameters::<Goerli>(
+                                    parameters,
+                                    private_key,
+                                    chain_id,
+                                )?,
+                                Kovan::CHAIN_ID => EthereumWallet::to_signed_transaction_from_parameters::<Kovan>(
+                                    parameters,
+                                    private_key,
+                                    chain_id,
+                                )?,
+                                Rinkeby::CHAIN_ID => EthereumWallet::to_signed_transaction_from_parameters::<Rinkeby>(
+                                    parameters,
+                                    private_key,
+                                    chain_id,
+                                )?,
+                                Ropsten::CHAIN_ID => EthereumWallet::to_signed_transaction_from_parameters::<Ropsten>(
+                                    parameters,
+                                    private_key,
+                                    chain_id,
+                                )?,
+                                // Any other chain id is of an EVM-compatible network, e.g. 137 for Polygon
+                                _ => EthereumWallet::to_signed_transaction_from_parameters::<Custom>(
+                                    parameters,
+                                    private_key,
+                                    chain_id,
+                                )?,
+                            }]
+                        }
                         _ => vec![],
-                    })
-                    .collect(),
+                    }
+                }
+                Some("uri create") => match options.address.as_ref() {
+                    Some(address) => {
+                        let mut uri = EthereumUri::new(address)?;
+                        uri.chain_id = options.uri_chain_id;
+                        if let Some(call) = options.call.as_ref() {
+                            let (function, arguments) = AbiFunction::from_call(call)?;
+                            uri.function = Some(function.name);
+                            uri.arguments = function.inputs.into_iter().zip(arguments).collect();
+                        }
+                        let number = |number: &Option<String>| match number {
+                            Some(number) => EthereumAmount::u256_from_str(number).map(Some),
+                            None => Ok(None),
+                        };
+                        uri.value = number(&options.uri_value)?;
+                        uri.gas = number(&options.uri_gas)?;
+                        uri.gas_price = number(&options.uri_gas_price)?;
+                        vec![EthereumWallet::from_uri(&uri)?]
+                    }
+                    None => vec![],
+                },
+                Some("uri parse") => match options.uri.as_ref() {
+                    Some(uri) => vec![EthereumWallet::from_uri(&EthereumUri::from_str(uri)?)?],
+                    None => vec![],
+                },
+                _ => vec![],
             };
+            let wallets = wallets.into_iter().chain(generated);
 
-            match options.json {
-                true => println!("{}\n", serde_json::to_string_pretty(&wallets)?),
-                false => wallets.iter().for_each(|wallet| println!("{}\n", wallet)),
+            match options.paper_file.as_deref() {
+                Some(path) => {
+                    if options.public_only {
+                        return Err(CLIError::UnsupportedFormat("paper wallet with --public-only".into()));
+                    }
+                    let mut wallets = wallets.collect::<Vec<EthereumWallet>>();
+                    let paper_wallets = wallets
+                        .iter()
+                        .filter_map(EthereumWallet::to_paper_wallet)
+                        .collect::<Vec<PaperWallet>>();
+                    paper::output("Ethereum", &paper_wallets, path)?;
+                    wallets.iter_mut().for_each(Zeroize::zeroize);
+                }
+                None => {
+                    let path = options.output_file.as_deref();
+                    let mut writer = WalletWriter::new(&options.output_format, path, EthereumWallet::FIELDS)?;
+                    for (index, mut wallet) in wallets.enumerate() {
+                        if options.neuter {
+                            wallet.neuter();
+                        }
+                        if options.public_only {
+                            wallet.public_only();
+                        }
+                        writer.write(&wallet)?;
+                        if let Some(qr_data) = wallet.to_qr_data() {
+                            qr::output(qr_data, index, options.qr, options.qr_file.as_deref())?;
+                        }
+                        wallet.zeroize();
+                    }
+                    writer.finish()?;
+                }
             };
 
             Ok(())
         }
 
+        /// Validates the address, printing its format, checksum, and payload.
+        /// An Ethereum address is the same on every network.
+        fn validate(options: EthereumOptions) -> Result<(), CLIError> {
+            let address = options.address.as_deref().unwrap_or_default();
+            let mut diagnostics = AddressDiagnostics::new(address);
+
+            // An ICAP address is an IBAN with the country code `XE`
+            if address.get(..2).is_some_and(|prefix| prefix.eq_ignore_ascii_case("xe")) {
+                diagnostics.format = Some("icap".into());
+                match EthereumAddress::from_icap(address) {
+                    Ok(address) => {
+                        diagnostics.checksum = Some(true);
+                        diagnostics.payload = Some(address.to_string()[2..].to_lowercase());
+                    }
+                    Err(AddressError::InvalidChecksum(_, _)) => diagnostics.checksum = Some(false),
+                    Err(error) => diagnostics.error(error),
+                };
+                return diagnostics::output(
+                    &diagnostics.finish(),
+                    &options.output_format,
+                    options.output_file.as_deref(),
+                );
+            }
+
+            let hex_address = address.strip_prefix("0x").unwrap_or(address);
+            match hex::decode(hex_address) {
+                Ok(payload) if payload.len() == 20 => {
+                    diagnostics.format = Some("standard".into());
+                    diagnostics.payload = Some(hex::encode(payload));
+                    // Verify the EIP-55 checksum of a mixed-case address
+                    if hex_address.to_lowercase() != hex_address && hex_address.to_uppercase() != hex_address {
+                        let checksum_address = EthereumAddress::from_str(hex_address)?.to_string();
+                        diagnostics.checksum = Some(checksum_address[2..] == *hex_address);
+                    }
+                }
+                Ok(payload) => diagnostics.error(AddressError::InvalidByteLength(payload.len())),
+                Err(error) => diagnostics.error(AddressError::from(error)),
+            };
+
+            diagnostics::output(
+                &diagnostics.finish(),
+                &options.output_format,
+                options.output_file.as_deref(),
+            )
+        }
+
         match options.language.as_str() {
-            "chinese_simplified" => output::<EthereumMainnet, ChineseSimplified>(options),
-            "chinese_traditional" => output::<EthereumMainnet, ChineseTraditional>(options),
-            "english" => output::<EthereumMainnet, English>(options),
-            "french" => output::<EthereumMainnet, French>(options),
-            "italian" => output::<EthereumMainnet, Italian>(options),
-            "japanese" => output::<EthereumMainnet, Japanese>(options),
-            "korean" => output::<EthereumMainnet, Korean>(options),
-            "spanish" => output::<EthereumMainnet, Spanish>(options),
-            _ => output::<EthereumMainnet, English>(options),
+            "chinese_simplified" => match options.network.as_str() {
+                "goerli" => output::<Goerli, ChineseSimplified>(options),
+                "kovan" => output::<Kovan, ChineseSimplified>(options),
+                "rinkeby" => output::<Rinkeby, ChineseSimplified>(options),
+                "ropsten" => output::<Ropsten, ChineseSimplified>(options),
+                _ => output::<EthereumMainnet, ChineseSimplified>(options),
+            },
+            "chinese_traditional" => match options.network.as_str() {
+                "goerli" => output::<Goerli, ChineseTraditional>(options),
+                "kovan" => output::<Kovan, ChineseTraditional>(options),
+                "rinkeby" => output::<Rinkeby, ChineseTraditional>(options),
+                "ropsten" => output::<Ropsten, ChineseTraditional>(options),
+                _ => output::<EthereumMainnet, ChineseTraditional>(options),
+            },
+            "english" => match options.network.as_str() {
+                "goerli" => output::<Goerli, English>(options),
+                "kovan" => output::<Kovan, English>(options),
+                "rinkeby" => output::<Rinkeby, English>(options),
+                "ropsten" => output::<Ropsten, English>(options),
+                _ => output::<EthereumMainnet, English>(options),
+            },
+            "french" => match options.network.as_str() {
+                "goerli" => output::<Goerli, French>(options),
+                "kovan" => output::<Kovan, French>(options),
+                "rinkeby" => output::<Rinkeby, French>(options),
+                "ropsten" => output::<Ropsten, French>(options),
+                _ => output::<EthereumMainnet, French>(options),
+            },
+            "italian" => match options.network.as_str() {
+                "goerli" => output::<Goerli, Italian>(options),
+                "kovan" => output::<Kovan, Italian>(options),
+                "rinkeby" => output::<Rinkeby, Italian>(options),
+                "ropsten" => output::<Ropsten, Italian>(options),
+                _ => output::<EthereumMainnet, Italian>(options),
+            },
+            "japanese" => match options.network.as_str() {
+                "goerli" => output::<Goerli, Japanese>(options),
+                "kovan" => output::<Kovan, Japanese>(options),
+                "rinkeby" => output::<Rinkeby, Japanese>(options),
+                "ropsten" => output::<Ropsten, Japanese>(options),
+                _ => output::<EthereumMainnet, Japanese>(options),
+            },
+            "korean" => match options.network.as_str() {
+                "goerli" => output::<Goerli, Korean>(options),
+                "kovan" => output::<Kovan, Korean>(options),
+                "rinkeby" => output::<Rinkeby, Korean>(options),
+                "ropsten" => output::<Ropsten, Korean>(options),
+                _ => output::<EthereumMainnet, Korean>(options),
+            },
+            "spanish" => match options.network.as_str() {
+                "goerli" => output::<Goerli, Spanish>(options),
+                "kovan" => output::<Kovan, Spanish>(options),
+                "rinkeby" => output::<Rinkeby, Spanish>(options),
+                "ropsten" => output::<Ropsten, Spanish>(options),
+                _ => output::<EthereumMainnet, Spanish>(options),
+            },
+            _ => match options.network.as_str() {
+                "goerli" => output::<Goerli, English>(options),
+                "kovan" => output::<Kovan, English>(options),
+                "rinkeby" => output::<Rinkeby, English>(options),
+                "ropsten" => output::<Ropsten, English>(options),
+                _ => output::<EthereumMainnet, English>(options),
+            },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_private_key() {
+        let private_key = "4646464646464646464646464646464646464646464646464646464646464646";
+        let wallet = EthereumWallet::from_converted_private_key(&format!("0x{}", private_key)).unwrap();
+        assert_eq!(Some(private_key), wallet.private_key.as_deref());
+        assert_eq!(
+            Some("024bc2a31265153f07e70e0bab08724e6b85e217f8cd628ceb62974247bb493382"),
+            wallet.compressed_public_key.as_deref()
+        );
+        assert_eq!(
+            format!("04{}", wallet.public_key.unwrap()),
+            wallet.uncompressed_public_key.unwrap()
+        );
+        assert_eq!(
+            Some("0x9d8A62f656a8d1615C1294fd71e9CFb3E4855A4F"),
+            wallet.address.as_deref()
+        );
+
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let wallet =
+            EthereumWallet::from_converted_mnemonic::<EthereumMainnet>(mnemonic, None, "m/44'/60'/0'/0").unwrap();
+        assert_eq!(Some("m/44'/60'/0'/0"), wallet.path.as_deref());
+        assert!(wallet.mnemonic.is_none() && wallet.extended_private_key.is_none());
+        assert!(wallet.compressed_public_key.is_some());
+    }
+
+    #[test]
+    fn from_mnemonic_paths() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let paths = [
+            "m/44'/60'/0'/0/0",
+            "m/44'/60'/0'/0/1",
+            "m/44'/60'/1'/0/0",
+            "m/44'/60'/0'/0/2",
+            "m",
+        ]
+        .iter()
+        .map(|path| path.to_string())
+        .collect::<Vec<String>>();
+
+        // The children of a cached parent are the keys derived from the master key along their full paths
+        let master = EthereumMnemonic::<EthereumMainnet, English>::from_phrase(mnemonic)
+            .unwrap()
+            .to_extended_private_key(None)
+            .unwrap();
+        let wallets = EthereumWallet::from_mnemonic_paths::<EthereumMainnet, English>(mnemonic, None, &paths).unwrap();
+        assert_eq!(paths.len(), wallets.len());
+        for (path, wallet) in paths.iter().zip(wallets) {
+            let expected = master.derive(&EthereumDerivationPath::from_str(path).unwrap()).unwrap();
+            assert_eq!(Some(path.as_str()), wallet.path.as_deref());
+            assert_eq!(Some(expected.to_string()), wallet.extended_private_key);
+        }
+    }
+
+    #[test]
+    fn to_signed_transaction_with_custom_chain_id() {
+        let parameters = EthereumInput {
+            to: "0xB5D590A6aBf5E349C1b6C511Bc87CEAbFB3D7e65".into(),
+            value: "1".into(),
+            gas: "21000".into(),
+            gas_price: "1000000000".into(),
+            nonce: 0,
+            data: None,
+            access_list: None,
+            unprotected: false,
+        };
+        let private_key = "51ce358ffdcf208fadfb01a339f3ab715a89045a093777a44784d9e215277c1c";
+        let wallet =
+            EthereumWallet::to_signed_transaction_from_parameters::<Custom>(parameters, private_key, 56).unwrap();
+        assert_eq!(Some("custom"), wallet.network.as_deref());
+        assert_eq!(Some(56), wallet.chain_id);
+
+        let transaction_hex = wallet.transaction_hex.unwrap();
+        let decoded = EthereumWallet::from_transaction_hex::<Custom>(&transaction_hex).unwrap();
+        assert_eq!(Some(56), decoded.chain_id);
+        assert_eq!(wallet.transaction_id, decoded.transaction_id);
+        assert!(EthereumWallet::from_transaction_hex::<EthereumMainnet>(&transaction_hex).is_err());
+    }
+
+    #[test]
+    fn sweep() {
+        let parameters = EthereumInput {
+            to: "0xB5D590A6aBf5E349C1b6C511Bc87CEAbFB3D7e65".into(),
+            value: "0".into(),
+            gas: "21000".into(),
+            gas_price: "20000000000".into(),
+            nonce: 0,
+            data: None,
+            access_list: None,
+            unprotected: false,
+        };
+        let swept = parameters.clone().sweep("1000000000000000000").unwrap();
+        assert_eq!("999580000000000000", swept.value);
+
+        // The balance of exactly the fee is swept to a value of zero
+        assert_eq!("0", parameters.clone().sweep("420000000000000").unwrap().value);
+        assert!(parameters.clone().sweep("419999999999999").is_err());
+        assert!(parameters.sweep("-1").is_err());
+
+        let private_key = "51ce358ffdcf208fadfb01a339f3ab715a89045a093777a44784d9e215277c1c";
+        let wallet = EthereumWallet::to_signed_transaction_from_parameters::<EthereumMainnet>(
+            swept,
+            private_key,
+            EthereumMainnet::CHAIN_ID,
+        )
+        .unwrap();
+        let decoded =
+            EthereumWallet::from_transaction_hex::<EthereumMainnet>(&wallet.transaction_hex.unwrap()).unwrap();
+        assert_eq!(Some("999580000000000000"), decoded.value.as_deref());
+    }
+
+    #[test]
+    fn from_signature() {
+        let signature = "0xb91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c";
+        let wallet = EthereumWallet::from_message_signature(&hex::encode("Some data"), signature).unwrap();
+        assert_eq!(
+            Some("0x2c7536E3605D9C16a7a3D7b1898e529396a65c23"),
+            wallet.address.as_deref()
+        );
+
+        let hash = "0x1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655";
+        let hash_wallet = EthereumWallet::from_hash_signature(hash, signature).unwrap();
+        assert_eq!(wallet.public_key, hash_wallet.public_key);
+        assert_eq!(wallet.address, hash_wallet.address);
+
+        assert!(EthereumWallet::from_hash_signature(&hash[..64], signature).is_err());
+        assert!(EthereumWallet::from_hash_signature(hash, &signature[..130]).is_err());
+    }
+
+    #[test]
+    fn to_signed_unprotected_transaction() {
+        let parameters = EthereumInput {
+            to: "0xB5D590A6aBf5E349C1b6C511Bc87CEAbFB3D7e65".into(),
+            value: "1".into(),
+            gas: "21000".into(),
+            gas_price: "1000000000".into(),
+            nonce: 0,
+            data: None,
+            access_list: None,
+            unprotected: true,
+        };
+        let private_key = "51ce358ffdcf208fadfb01a339f3ab715a89045a093777a44784d9e215277c1c";
+        let wallet = EthereumWallet::to_signed_transaction_from_parameters::<EthereumMainnet>(
+            parameters.clone(),
+            private_key,
+            EthereumMainnet::CHAIN_ID,
+        )
+        .unwrap();
+
+        // The transaction is valid on every network, so its network is not decoded
+        let transaction_hex = wallet.transaction_hex.unwrap();
+        let decoded = EthereumWallet::from_transaction_hex::<Goerli>(&transaction_hex).unwrap();
+        assert_eq!(None, decoded.network);
+        assert_eq!(wallet.transaction_id, decoded.transaction_id);
+
+        let parameters = EthereumInput {
+            access_list: Some(vec![]),
+            ..parameters
+        };
+        assert!(
+            EthereumWallet::to_signed_transaction_from_parameters::<EthereumMainnet>(
+                parameters,
+                private_key,
+                EthereumMainnet::CHAIN_ID
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,372 @@
+use crate::cli::bitcoin::BitcoinCLI;
+use crate::cli::bitcoincash::BitcoinCashCLI;
+use crate::cli::cosmos::CosmosCLI;
+use crate::cli::dash::DashCLI;
+use crate::cli::dogecoin::DogecoinCLI;
+use crate::cli::ethereum::EthereumCLI;
+use crate::cli::monero::MoneroCLI;
+use crate::cli::ripple::RippleCLI;
+use crate::cli::solana::SolanaCLI;
+use crate::cli::tron::TronCLI;
+use crate::cli::zcash::ZcashCLI;
+use crate::cli::{types::*, CLIError, CLI};
+
+use clap::{App, AppSettings, SubCommand};
+use colored::*;
+use std::io::{BufRead, IsTerminal, Write};
+use zeroize::Zeroize;
+
+use crate::model::no_std::{format, vec, String, ToString, Vec};
+
+/// Represents a currency of the guided prompt, as described by the arguments of its command
+struct Currency {
+    name: NameType,
+    options: &'static [OptionType],
+    subcommands: &'static [SubCommandType],
+}
+
+impl Currency {
+    fn of<C: CLI>() -> Self {
+        Self {
+            name: C::NAME,
+            options: C::OPTIONS,
+            subcommands: C::SUBCOMMANDS,
+        }
+    }
+
+    /// Returns the options of the specified operation, or `None` if the currency does not support it.
+    fn options(&self, operation: Operation) -> Option<&'static [OptionType]> {
+        let options = match operation.subcommand() {
+            None => self.options,
+            Some(name) => self.subcommands.iter().find(|subcommand| subcommand.0 == name)?.2,
+        };
+        match operation.secrets().is_empty() || operation.secret(options).is_some() {
+            true => Some(options),
+            false => None,
+        }
+    }
+}
+
+/// Returns the currencies of the guided prompt, in the order of the commands of the CLI.
+fn currencies() -> Vec<Currency> {
+    vec![
+        Currency::of::<BitcoinCLI>(),
+        Currency::of::<BitcoinCashCLI>(),
+        Currency::of::<CosmosCLI>(),
+        Currency::of::<DashCLI>(),
+        Currency::of::<DogecoinCLI>(),
+        Currency::of::<EthereumCLI>(),
+        Currency::of::<MoneroCLI>(),
+        Currency::of::<RippleCLI>(),
+        Currency::of::<SolanaCLI>(),
+        Currency::of::<TronCLI>(),
+        Currency::of::<ZcashCLI>(),
+    ]
+}
+
+/// Represents an operation of the guided prompt
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Operation {
+    Generate,
+    GenerateHD,
+    Import,
+    ImportHD,
+}
+
+impl Operation {
+    const ALL: [Operation; 4] = [
+        Operation::Generate,
+        Operation::GenerateHD,
+        Operation::Import,
+        Operation::ImportHD,
+    ];
+
+    /// Returns the description of the operation, naming the secret it imports from the specified options.
+    fn description(&self, options: &'static [OptionType]) -> String {
+        match self {
+            Operation::Generate => "Generate a new wallet".into(),
+            Operation::GenerateHD => "Generate a new HD wallet with a mnemonic".into(),
+            Operation::Import => format!("Import a wallet from a {}", self.secret_name(options)),
+            Operation::ImportHD => format!("Import an HD wallet from a {}", self.secret_name(options)),
+        }
+    }
+
+    /// Returns the subcommand of the operation, or `None` for the command of the currency itself.
+    fn subcommand(&self) -> Option<&'static str> {
+        match self {
+            Operation::Generate => None,
+            Operation::GenerateHD => Some("hd"),
+            Operation::Import => Some("import"),
+            Operation::ImportHD => Some("import-hd"),
+        }
+    }
+
+    /// Returns the names of the options that may take the secret to import, in order of preference.
+    fn secrets(&self) -> &'static [&'static str] {
+        match self {
+            Operation::Generate | Operation::GenerateHD => &[],
+            Operation::Import => &["private", "mnemonic"],
+            Operation::ImportHD => &["mnemonic"],
+        }
+    }
+
+    /// Returns the name of the secret to import with the specified options.
+    fn secret_name(&self, options: &'static [OptionType]) -> &'static str {
+        match self.secret(options) {
+            Some(option) if option.0.starts_with("[private]") => "private key",
+            _ => "mnemonic",
+        }
+    }
+
+    /// Returns the option of the specified options that takes the secret to import.
+    fn secret(&self, options: &'static [OptionType]) -> Option<&'static OptionType> {
+        self.secrets()
+            .iter()
+            .filter_map(|name| find_option(options, name))
+            .next()
+    }
+}
+
+/// Returns the option with the specified name, as in the usage `[name] --long=[value] 'help'`.
+fn find_option(options: &'static [OptionType], name: &str) -> Option<&'static OptionType> {
+    options
+        .iter()
+        .find(|option| option.0.starts_with(&format!("[{}]", name)))
+}
+
+/// Returns the long flag of the specified option usage, as in `--network`.
+fn long_flag(usage: &str) -> Option<&str> {
+    let start = usage.find("--")?;
+    let end = usage[start..]
+        .find(|c: char| c == '=' || c == ' ')
+        .map_or(usage.len(), |end| start + end);
+    Some(&usage[start..end])
+}
+
+/// Reads the answers of a guided prompt from an input and writes its questions to an output.
+/// Secrets are read without echo if the input is a terminal.
+struct Prompt<R: BufRead, W: Write> {
+    input: R,
+    output: W,
+    hidden: bool,
+}
+
+impl<R: BufRead, W: Write> Prompt<R, W> {
+    /// Returns one line of the input, without its line break.
+    fn read_line(&mut self) -> Result<String, CLIError> {
+        let mut line = String::new();
+        match self.input.read_line(&mut line)? {
+            0 => Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into()),
+            _ => Ok(line.trim_end_matches(&['\r', '\n'][..]).to_string()),
+        }
+    }
+
+    /// Returns the index of the choice selected by its number or by its name, asking again until one is selected.
+    fn select(&mut self, question: &str, choices: &[&str]) -> Result<usize, CLIError> {
+        loop {
+            writeln!(self.output, "\n{}", question.cyan().bold())?;
+            for (index, choice) in choices.iter().enumerate() {
+                writeln!(self.output, "  {}) {}", index + 1, choice)?;
+            }
+            write!(self.output, "> ")?;
+            self.output.flush()?;
+
+            let answer = self.read_line()?;
+            let answer = answer.trim();
+            if let Ok(number) = answer.parse::<usize>() {
+                if number >= 1 && number <= choices.len() {
+                    return Ok(number - 1);
+                }
+            }
+            if let Some(index) = choices.iter().position(|choice| choice.eq_ignore_ascii_case(answer)) {
+                return Ok(index);
+            }
+            writeln!(
+                self.output,
+                "{} choose one of 1 to {}",
+                "Invalid choice:".red().bold(),
+                choices.len()
+            )?;
+        }
+    }
+
+    /// Returns the answer to the specified question, read without echo if `secret` is enabled.
+    fn ask(&mut self, question: &str, secret: bool) -> Result<String, CLIError> {
+        if secret && self.hidden {
+            return Ok(rpassword::prompt_password(format!("\n{}: ", question.cyan().bold()))?);
+        }
+        write!(self.output, "\n{}: ", question.cyan().bold())?;
+        self.output.flush()?;
+        self.read_line()
+    }
+
+    /// Returns `true` if the specified question is answered with yes.
+    fn confirm(&mut self, question: &str) -> Result<bool, CLIError> {
+        let answer = self.ask(&format!("{} [y/N]", question), false)?;
+        Ok(answer.trim().eq_ignore_ascii_case("y") || answer.trim().eq_ignore_ascii_case("yes"))
+    }
+}
+
+/// Returns the arguments of the command chosen with the specified prompt,
+/// or `None` if the command is not confirmed.
+fn arguments<R: BufRead, W: Write>(prompt: &mut Prompt<R, W>) -> Result<Option<Vec<String>>, CLIError> {
+    let currencies = currencies();
+    let names = currencies.iter().map(|currency| currency.name).collect::<Vec<&str>>();
+    let currency = &currencies[prompt.select("Choose a currency", &names)?];
+
+    let operations = Operation::ALL
+        .iter()
+        .filter_map(|operation| Some((*operation, currency.options(*operation)?)))
+        .collect::<Vec<(Operation, &'static [OptionType])>>();
+    let descriptions = operations
+        .iter()
+        .map(|(operation, options)| operation.description(options))
+        .collect::<Vec<String>>();
+    let choices = descriptions.iter().map(String::as_str).collect::<Vec<&str>>();
+    let choice = prompt.select("Choose an operation", &choices)?;
+    let (operation, options) = operations[choice];
+
+    let mut arguments = vec!["wagyu".to_string(), currency.name.to_string()];
+    if let Some(subcommand) = operation.subcommand() {
+        arguments.push(subcommand.to_string());
+    }
+
+    let mut summary = vec![
+        ("Currency", currency.name.to_string()),
+        ("Operation", descriptions[choice].clone()),
+    ];
+
+    if let Some(network) = find_option(options, "network").filter(|option| !option.2.is_empty()) {
+        let network = network.2[prompt.select("Choose a network", network.2)?];
+        arguments.push(format!("--network={}", network));
+        summary.push(("Network", network.to_string()));
+    }
+
+    if let Some(secret) = operation.secret(options) {
+        let mut value = prompt.ask(&format!("Enter the {}", operation.secret_name(options)), true)?;
+        if let Some(flag) = long_flag(secret.0) {
+            arguments.push(format!("{}={}", flag, value.trim()));
+        }
+        value.zeroize();
+    }
+
+    if let Some(password) = find_option(options, "password") {
+        let mut value = prompt.ask("Enter a password for the mnemonic (leave empty for none)", true)?;
+        if let (false, Some(flag)) = (value.is_empty(), long_flag(password.0)) {
+            arguments.push(format!("{}={}", flag, value));
+        }
+        value.zeroize();
+    }
+
+    let path = prompt.ask("Enter a file to write the wallet to (leave empty to print it)", false)?;
+    let path = path.trim();
+    if !path.is_empty() {
+        arguments.push(format!("--output-file={}", path));
+        summary.push(("Output", path.to_string()));
+    }
+
+    writeln!(prompt.output)?;
+    for (label, value) in summary.iter() {
+        writeln!(prompt.output, "  {:>9}  {}", label.cyan().bold(), value)?;
+    }
+    writeln!(
+        prompt.output,
+        "\n{} the wallet includes its private key, which spends its funds. \
+         Make sure no one can see your screen or read the output.",
+        "Warning:".yellow().bold()
+    )?;
+
+    let question = match path.is_empty() {
+        true => "Print the wallet?",
+        false => "Write the wallet?",
+    };
+    match prompt.confirm(question)? {
+        true => Ok(Some(arguments)),
+        false => {
+            arguments.zeroize();
+            Ok(None)
+        }
+    }
+}
+
+/// The `interactive` command, which builds the arguments of another command with a guided prompt
+pub struct InteractiveCLI;
+
+impl InteractiveCLI {
+    pub const NAME: NameType = "interactive";
+    pub const ABOUT: AboutType = "Generates or imports a wallet with a guided prompt";
+
+    #[cfg_attr(tarpaulin, skip)]
+    pub fn new<'a, 'b>() -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME).about(Self::ABOUT).settings(&[
+            AppSettings::ColoredHelp,
+            AppSettings::DisableHelpSubcommand,
+            AppSettings::DisableVersion,
+        ])
+    }
+
+    /// Prompts for a currency, network, and operation on stdin, with the questions on stderr,
+    /// and returns the arguments of the chosen command, or `None` if it is not confirmed.
+    #[cfg_attr(tarpaulin, skip)]
+    pub fn prompt() -> Result<Option<Vec<String>>, CLIError> {
+        let stdin = std::io::stdin();
+        let mut prompt = Prompt {
+            input: stdin.lock(),
+            output: std::io::stderr(),
+            hidden: stdin.is_terminal(),
+        };
+        arguments(&mut prompt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_arguments(input: &str) -> Option<Vec<String>> {
+        let mut prompt = Prompt {
+            input: input.as_bytes(),
+            output: vec![],
+            hidden: false,
+        };
+        arguments(&mut prompt).unwrap()
+    }
+
+    #[test]
+    fn generate() {
+        assert_eq!(
+            Some(vec!["wagyu", "bitcoin", "--network=testnet"]),
+            test_arguments("1\n1\ntestnet\n\ny\n")
+                .as_ref()
+                .map(|arguments| arguments.iter().map(String::as_str).collect::<Vec<&str>>())
+        );
+        assert_eq!(None, test_arguments("bitcoin\n1\n1\n\nn\n"));
+    }
+
+    #[test]
+    fn import_hd() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let input = format!("ethereum\n0\n4\n{}\nTREZOR\nwallet.json\nyes\n", mnemonic);
+        assert_eq!(
+            Some(vec![
+                "wagyu".to_string(),
+                "ethereum".to_string(),
+                "import-hd".to_string(),
+                format!("--mnemonic={}", mnemonic),
+                "--password=TREZOR".to_string(),
+                "--output-file=wallet.json".to_string(),
+            ]),
+            test_arguments(&input)
+        );
+    }
+
+    #[test]
+    fn end_of_input() {
+        let mut prompt = Prompt {
+            input: "1\n".as_bytes(),
+            output: vec![],
+            hidden: false,
+        };
+        assert!(arguments(&mut prompt).is_err());
+    }
+}
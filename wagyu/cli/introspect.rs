@@ -0,0 +1,267 @@
+use crate::cli::{types::*, CLI, STDIN_OPTIONS};
+
+use clap::{App, AppSettings, Arg, Shell, SubCommand};
+use serde::Serialize;
+
+use crate::model::no_std::{String, ToString, Vec};
+
+/// Represents an argument of a command, as parsed from its usage `[name] -s --long=[value] 'help'`
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct ArgumentDescription {
+    pub name: String,
+    pub short: Option<String>,
+    pub long: Option<String>,
+    pub value_names: Vec<String>,
+    pub help: String,
+    pub required: bool,
+    pub global: bool,
+    pub stdin: bool,
+    pub possible_values: Vec<String>,
+    pub conflicts_with: Vec<String>,
+    pub requires: Vec<String>,
+}
+
+impl ArgumentDescription {
+    /// Returns the description of a flag, which is global to the subcommands of its command.
+    pub fn from_flag(usage: FlagType) -> Self {
+        let mut argument = Self::from_usage(usage);
+        argument.global = true;
+        argument
+    }
+
+    /// Returns the description of an option, with its possible values, conflicts, and requirements.
+    pub fn from_option(option: &OptionType) -> Self {
+        let mut argument = Self::from_usage(option.0);
+        argument.stdin = STDIN_OPTIONS.iter().any(|name| option.0.starts_with(name));
+        argument.conflicts_with = option.1.iter().map(|name| name.to_string()).collect();
+        argument.possible_values = option.2.iter().map(|value| value.to_string()).collect();
+        argument.requires = option.3.iter().map(|name| name.to_string()).collect();
+        argument
+    }
+
+    /// Returns the description of an argument parsed from its usage, in the syntax of `Arg::from_usage`.
+    fn from_usage(usage: &str) -> Self {
+        let (head, help) = match usage.find('\'') {
+            Some(start) => (&usage[..start], usage[start + 1..].trim_end().trim_end_matches('\'')),
+            None => (usage, ""),
+        };
+        let head = head.trim();
+        let name_end = head.find(|c| c == ']' || c == '>').unwrap_or(head.len());
+        let name = head.get(1..name_end).unwrap_or("");
+        let rest = head.get(name_end + 1..).unwrap_or("");
+
+        let (switches, values) = match rest.find('=') {
+            Some(position) => (&rest[..position], &rest[position + 1..]),
+            None => (rest, ""),
+        };
+        let switches = switches.split_whitespace();
+        let short = switches
+            .clone()
+            .find(|switch| switch.len() == 2 && switch.starts_with('-') && !switch.starts_with("--"))
+            .map(|switch| switch[1..].to_string());
+        let long = switches
+            .clone()
+            .find(|switch| switch.starts_with("--"))
+            .map(|switch| switch[2..].to_string());
+        let value_names = values
+            .split(|c| c == ']' || c == '>')
+            .map(|value| {
+                value
+                    .trim()
+                    .trim_start_matches(|c| c == '[' || c == '<')
+                    .trim_matches('"')
+            })
+            .filter(|value| !value.is_empty() && *value != "...")
+            .map(|value| value.to_string())
+            .collect::<Vec<String>>();
+
+        // A positional argument takes the value of its name, as in `<address>`
+        let value_names = match (&short, &long) {
+            (None, None) => vec![name.to_string()],
+            _ => value_names,
+        };
+
+        Self {
+            name: name.to_string(),
+            short,
+            long,
+            value_names,
+            help: help.to_string(),
+            required: head.starts_with('<'),
+            global: false,
+            stdin: false,
+            possible_values: vec![],
+            conflicts_with: vec![],
+            requires: vec![],
+        }
+    }
+}
+
+/// Represents a command with its flags, options, and subcommands, as defined by the constants of the `CLI` trait
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct CommandDescription {
+    pub name: String,
+    pub about: String,
+    pub flags: Vec<ArgumentDescription>,
+    pub options: Vec<ArgumentDescription>,
+    pub subcommands: Vec<CommandDescription>,
+}
+
+impl CommandDescription {
+    /// Returns the description of a command with the specified name and about, without any arguments.
+    pub fn new(name: NameType, about: AboutType) -> Self {
+        Self {
+            name: name.to_string(),
+            about: about.to_string(),
+            flags: vec![],
+            options: vec![],
+            subcommands: vec![],
+        }
+    }
+
+    /// Returns the description of the command of the specified CLI.
+    pub fn from_cli<C: CLI>() -> Self {
+        Self {
+            flags: C::FLAGS
+                .iter()
+                .map(|flag| ArgumentDescription::from_flag(flag))
+                .collect(),
+            options: C::OPTIONS.iter().map(ArgumentDescription::from_option).collect(),
+            subcommands: C::SUBCOMMANDS.iter().map(Self::from_subcommand).collect(),
+            ..Self::new(C::NAME, C::ABOUT)
+        }
+    }
+
+    /// Returns the description of the specified subcommand, with its nested subcommands.
+    fn from_subcommand(subcommand: &SubCommandType) -> Self {
+        Self {
+            options: subcommand.2.iter().map(ArgumentDescription::from_option).collect(),
+            subcommands: subcommand
+                .4
+                .iter()
+                .map(|nested| Self {
+                    options: nested.2.iter().map(ArgumentDescription::from_option).collect(),
+                    ..Self::new(nested.0, nested.1)
+                })
+                .collect(),
+            ..Self::new(subcommand.0, subcommand.1)
+        }
+    }
+}
+
+/// The `completions` command, which prints a shell completion script for the commands of the CLI
+pub struct CompletionsCLI;
+
+impl CompletionsCLI {
+    pub const NAME: NameType = "completions";
+    pub const ABOUT: AboutType = "Prints a completion script for a specified shell";
+    pub const OPTIONS: &'static [OptionType] = &[(
+        "<shell> 'The shell to print a completion script for'",
+        &[],
+        &["bash", "elvish", "fish", "powershell", "zsh"],
+        &[],
+    )];
+
+    #[cfg_attr(tarpaulin, skip)]
+    pub fn new<'a, 'b>() -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .settings(&[
+                AppSettings::ColoredHelp,
+                AppSettings::DisableHelpSubcommand,
+                AppSettings::DisableVersion,
+            ])
+            .args(
+                &Self::OPTIONS
+                    .iter()
+                    .map(|option| Arg::from_usage(option.0).possible_values(option.2))
+                    .collect::<Vec<Arg<'static, 'static>>>(),
+            )
+    }
+
+    /// Writes the completion script of the specified app for the specified shell to stdout.
+    #[cfg_attr(tarpaulin, skip)]
+    pub fn print(mut app: App, shell: &str) {
+        // The shell is one of the possible values of the argument, which are all supported by clap
+        let shell = shell.parse::<Shell>().unwrap_or(Shell::Bash);
+        let name = app.get_name().to_string();
+        app.gen_completions_to(name, shell, &mut std::io::stdout());
+    }
+
+    /// Returns the description of the command.
+    pub fn describe() -> CommandDescription {
+        CommandDescription {
+            options: Self::OPTIONS.iter().map(ArgumentDescription::from_option).collect(),
+            ..CommandDescription::new(Self::NAME, Self::ABOUT)
+        }
+    }
+}
+
+/// The `introspect` command, which prints the tree of commands and arguments of the CLI as JSON
+pub struct IntrospectCLI;
+
+impl IntrospectCLI {
+    pub const NAME: NameType = "introspect";
+    pub const ABOUT: AboutType = "Prints the commands and arguments of wagyu in JSON format";
+    pub const FLAGS: &'static [FlagType] = &["[json] -j --json 'Prints the commands and arguments in JSON format'"];
+
+    #[cfg_attr(tarpaulin, skip)]
+    pub fn new<'a, 'b>() -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .settings(&[
+                AppSettings::ColoredHelp,
+                AppSettings::DisableHelpSubcommand,
+                AppSettings::DisableVersion,
+            ])
+            .args(
+                &Self::FLAGS
+                    .iter()
+                    .map(|flag| Arg::from_usage(flag))
+                    .collect::<Vec<Arg<'static, 'static>>>(),
+            )
+    }
+
+    /// Returns the description of the command.
+    pub fn describe() -> CommandDescription {
+        CommandDescription {
+            flags: Self::FLAGS
+                .iter()
+                .map(|flag| ArgumentDescription::from_flag(flag))
+                .collect(),
+            ..CommandDescription::new(Self::NAME, Self::ABOUT)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::parameters::{flag, option};
+
+    #[test]
+    fn from_usage() {
+        let network = ArgumentDescription::from_option(&option::NETWORK_MONERO);
+        assert_eq!("network", network.name);
+        assert_eq!(Some("n".to_string()), network.short);
+        assert_eq!(Some("network".to_string()), network.long);
+        assert_eq!(vec!["network".to_string()], network.value_names);
+        assert_eq!(vec!["mainnet", "stagenet", "testnet"], network.possible_values);
+        assert!(!network.required && !network.stdin);
+
+        let mnemonic = ArgumentDescription::from_option(&option::MNEMONIC);
+        assert_eq!(vec!["mnemonic".to_string()], mnemonic.value_names);
+        assert!(mnemonic.stdin);
+
+        let json = ArgumentDescription::from_flag(flag::JSON);
+        assert_eq!(Some("j".to_string()), json.short);
+        assert_eq!(Some("json".to_string()), json.long);
+        assert_eq!("Prints the generated wallet(s) in JSON format", json.help);
+        assert!(json.value_names.is_empty() && json.global);
+
+        let shell = CompletionsCLI::describe().options.remove(0);
+        assert_eq!(("shell", None, None), (shell.name.as_str(), shell.short, shell.long));
+        assert_eq!(vec!["shell".to_string()], shell.value_names);
+        assert!(shell.required);
+    }
+}
@@ -0,0 +1,353 @@
+use crate::cli::CLIError;
+use crate::model::{crypto::hash160, encoding::base58check};
+
+use hidapi::{HidApi, HidDevice};
+
+use crate::model::no_std::{format, vec, String, ToString, Vec};
+
+/// The USB vendor id of Ledger devices
+const VENDOR_ID: u16 = 0x2C97;
+/// The HID usage page of the APDU interface of Ledger devices
+const USAGE_PAGE: u16 = 0xFFA0;
+/// The HID channel and command tag of APDU packets
+const CHANNEL: u16 = 0x0101;
+const TAG_APDU: u8 = 0x05;
+/// The size of HID packets
+const PACKET_SIZE: usize = 64;
+
+/// The APDU class and instructions of the Bitcoin and Ethereum apps
+const CLA: u8 = 0xE0;
+const INS_GET_WALLET_PUBLIC_KEY: u8 = 0x40;
+const INS_GET_ETH_PUBLIC_ADDRESS: u8 = 0x02;
+
+/// The status word of a successful APDU response
+const SW_OK: u16 = 0x9000;
+
+/// Represents a public key fetched from a Ledger device
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LedgerPublicKey {
+    /// The derivation path of the public key
+    pub path: Vec<u32>,
+    /// The public key, as returned by the device
+    pub public_key: Vec<u8>,
+    /// The address of the public key, as computed by the device
+    pub address: String,
+    /// The chain code of the public key
+    pub chain_code: [u8; 32],
+    /// The fingerprint of the parent public key
+    pub parent_fingerprint: [u8; 4],
+}
+
+impl LedgerPublicKey {
+    /// Returns the public key in compressed form.
+    pub fn to_compressed(&self) -> Result<[u8; 33], CLIError> {
+        let mut compressed = [0u8; 33];
+        match self.public_key.len() {
+            33 => compressed.copy_from_slice(&self.public_key),
+            65 if self.public_key[0] == 0x04 => {
+                // Prefix the x-coordinate with the parity of the y-coordinate
+                compressed[0] = 0x02 | (self.public_key[64] & 1);
+                compressed[1..].copy_from_slice(&self.public_key[1..33]);
+            }
+            _ => return Err(CLIError::LedgerError("invalid public key length".into())),
+        };
+        Ok(compressed)
+    }
+
+    /// Returns the fingerprint of the public key.
+    pub fn to_fingerprint(&self) -> Result<[u8; 4], CLIError> {
+        let mut fingerprint = [0u8; 4];
+        fingerprint.copy_from_slice(&hash160(&self.to_compressed()?)[0..4]);
+        Ok(fingerprint)
+    }
+
+    /// Returns the serialized BIP32 extended public key of the public key for the given version bytes.
+    pub fn to_extended_public_key(&self, version: &[u8]) -> Result<String, CLIError> {
+        let mut result = [0u8; 78];
+        result[0..4].copy_from_slice(&version[0..4]);
+        result[4] = self.path.len() as u8;
+        result[5..9].copy_from_slice(&self.parent_fingerprint);
+        result[9..13].copy_from_slice(&self.path.last().copied().unwrap_or(0).to_be_bytes());
+        result[13..45].copy_from_slice(&self.chain_code);
+        result[45..78].copy_from_slice(&self.to_compressed()?);
+
+        Ok(base58check::encode(&result))
+    }
+}
+
+/// Represents a Ledger device connected over USB HID
+pub struct Ledger {
+    device: HidDevice,
+}
+
+impl Ledger {
+    /// Returns the first connected Ledger device.
+    pub fn open() -> Result<Self, CLIError> {
+        let api = HidApi::new().map_err(|error| CLIError::LedgerError(error.to_string()))?;
+        let info = api
+            .device_list()
+            .find(|info| {
+                info.vendor_id() == VENDOR_ID && (info.usage_page() == USAGE_PAGE || info.interface_number() == 0)
+            })
+            .ok_or_else(|| CLIError::LedgerError("no device found".into()))?;
+        let device = info
+            .open_device(&api)
+            .map_err(|error| CLIError::LedgerError(error.to_string()))?;
+        Ok(Self { device })
+    }
+
+    /// Sends the given APDU command to the device and returns the response data.
+    pub fn exchange(&self, apdu: &[u8]) -> Result<Vec<u8>, CLIError> {
+        for packet in to_packets(apdu) {
+            // Prefix each packet with the HID report id
+            let mut report = vec![0u8];
+            report.extend_from_slice(&packet);
+            self.device
+                .write(&report)
+                .map_err(|error| CLIError::LedgerError(error.to_string()))?;
+        }
+
+        let mut response = ResponseReader::default();
+        while !response.is_complete() {
+            let mut packet = [0u8; PACKET_SIZE];
+            // Block until the packet arrives, as the user may be confirming on the device
+            self.device
+                .read_timeout(&mut packet, -1)
+                .map_err(|error| CLIError::LedgerError(error.to_string()))?;
+            response.push(&packet)?;
+        }
+        response.into_data()
+    }
+
+    /// Returns the public key of the given derivation path from the Bitcoin app, with its address
+    /// computed by the device for the given format (0x00 legacy, 0x01 segwit, 0x02 bech32).
+    /// If `display` is enabled, the address is displayed on the device for confirmation.
+    pub fn bitcoin_public_key(&self, path: &[u32], format: u8, display: bool) -> Result<LedgerPublicKey, CLIError> {
+        let mut public_key = self.public_key(INS_GET_WALLET_PUBLIC_KEY, format, path, display)?;
+        public_key.address = public_key.address.trim().to_string();
+        Ok(public_key)
+    }
+
+    /// Returns the public key of the given derivation path from the Ethereum app, with its address
+    /// computed by the device. If `display` is enabled, the address is displayed on the device for confirmation.
+    pub fn ethereum_public_key(&self, path: &[u32], display: bool) -> Result<LedgerPublicKey, CLIError> {
+        // Request the chain code along with the public key
+        let mut public_key = self.public_key(INS_GET_ETH_PUBLIC_ADDRESS, 0x01, path, display)?;
+        public_key.address = format!("0x{}", public_key.address);
+        Ok(public_key)
+    }
+
+    /// Returns the public key of the given derivation path and the fingerprint of its parent,
+    /// for the given instruction and second parameter.
+    fn public_key(&self, ins: u8, p2: u8, path: &[u32], display: bool) -> Result<LedgerPublicKey, CLIError> {
+        let parent_fingerprint = match path.split_last() {
+            Some((_, parent)) => {
+                let response = self.exchange(&to_apdu(CLA, ins, 0x00, p2, &to_path_bytes(parent)))?;
+                from_public_key_response(&response)?.to_fingerprint()?
+            }
+            None => [0u8; 4],
+        };
+
+        let response = self.exchange(&to_apdu(CLA, ins, display as u8, p2, &to_path_bytes(path)))?;
+        Ok(LedgerPublicKey {
+            path: path.to_vec(),
+            parent_fingerprint,
+            ..from_public_key_response(&response)?
+        })
+    }
+}
+
+/// Returns the APDU command of the given class, instruction, parameters, and data.
+fn to_apdu(cla: u8, ins: u8, p1: u8, p2: u8, data: &[u8]) -> Vec<u8> {
+    let mut apdu = vec![cla, ins, p1, p2, data.len() as u8];
+    apdu.extend_from_slice(data);
+    apdu
+}
+
+/// Returns the serialized derivation path, as the number of child indices followed by each
+/// child index in big-endian format.
+fn to_path_bytes(path: &[u32]) -> Vec<u8> {
+    let mut bytes = vec![path.len() as u8];
+    path.iter()
+        .for_each(|index| bytes.extend_from_slice(&index.to_be_bytes()));
+    bytes
+}
+
+/// Returns the HID packets of the given APDU command. Each packet is prefixed with the channel,
+/// tag, and sequence index, and the first packet additionally with the length of the command.
+fn to_packets(apdu: &[u8]) -> Vec<[u8; PACKET_SIZE]> {
+    let mut data = (apdu.len() as u16).to_be_bytes().to_vec();
+    data.extend_from_slice(apdu);
+
+    data.chunks(PACKET_SIZE - 5)
+        .enumerate()
+        .map(|(sequence, chunk)| {
+            let mut packet = [0u8; PACKET_SIZE];
+            packet[0..2].copy_from_slice(&CHANNEL.to_be_bytes());
+            packet[2] = TAG_APDU;
+            packet[3..5].copy_from_slice(&(sequence as u16).to_be_bytes());
+            packet[5..5 + chunk.len()].copy_from_slice(chunk);
+            packet
+        })
+        .collect()
+}
+
+/// Reassembles an APDU response from its HID packets
+#[derive(Debug, Default)]
+struct ResponseReader {
+    length: Option<usize>,
+    sequence: u16,
+    data: Vec<u8>,
+}
+
+impl ResponseReader {
+    /// Appends the payload of the given packet to the response.
+    fn push(&mut self, packet: &[u8]) -> Result<(), CLIError> {
+        if packet.len() < 5
+            || packet[0..2] != CHANNEL.to_be_bytes()
+            || packet[2] != TAG_APDU
+            || packet[3..5] != self.sequence.to_be_bytes()
+        {
+            return Err(CLIError::LedgerError("invalid response packet".into()));
+        }
+
+        let payload = match self.length {
+            Some(_) => &packet[5..],
+            None if packet.len() >= 7 => {
+                self.length = Some(u16::from_be_bytes([packet[5], packet[6]]) as usize);
+                &packet[7..]
+            }
+            None => return Err(CLIError::LedgerError("invalid response packet".into())),
+        };
+
+        let remaining = self.length.unwrap_or(0) - self.data.len();
+        self.data.extend_from_slice(&payload[..remaining.min(payload.len())]);
+        self.sequence += 1;
+        Ok(())
+    }
+
+    /// Returns `true` if the response has been read in full.
+    fn is_complete(&self) -> bool {
+        self.length == Some(self.data.len())
+    }
+
+    /// Returns the response data, if the response status word indicates success.
+    fn into_data(mut self) -> Result<Vec<u8>, CLIError> {
+        if self.data.len() < 2 {
+            return Err(CLIError::LedgerError("missing status word".into()));
+        }
+        let status = self.data.split_off(self.data.len() - 2);
+        match u16::from_be_bytes([status[0], status[1]]) {
+            SW_OK => Ok(self.data),
+            0x6985 => Err(CLIError::LedgerError("request denied on the device".into())),
+            0x6D00 | 0x6E00 => Err(CLIError::LedgerError(
+                "unsupported request, check the app opened on the device".into(),
+            )),
+            status => Err(CLIError::LedgerError(format!("status word {:04x}", status))),
+        }
+    }
+}
+
+/// Returns the public key, address, and chain code of the given response, encoded as
+/// the length-prefixed public key, the length-prefixed address, and the chain code.
+fn from_public_key_response(response: &[u8]) -> Result<LedgerPublicKey, CLIError> {
+    let invalid = || CLIError::LedgerError("invalid public key response".into());
+
+    let public_key_length = *response.first().ok_or_else(invalid)? as usize;
+    let public_key = response.get(1..1 + public_key_length).ok_or_else(invalid)?;
+
+    let address_offset = 1 + public_key_length;
+    let address_length = *response.get(address_offset).ok_or_else(invalid)? as usize;
+    let address = response
+        .get(address_offset + 1..address_offset + 1 + address_length)
+        .ok_or_else(invalid)?;
+
+    let chain_code_offset = address_offset + 1 + address_length;
+    let chain_code = response
+        .get(chain_code_offset..chain_code_offset + 32)
+        .ok_or_else(invalid)?;
+
+    let mut public_key = LedgerPublicKey {
+        public_key: public_key.to_vec(),
+        address: String::from_utf8(address.to_vec()).map_err(|_| invalid())?,
+        ..Default::default()
+    };
+    public_key.chain_code.copy_from_slice(chain_code);
+    Ok(public_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_path_bytes() {
+        let path = [44 | 1 << 31, 60 | 1 << 31, 3 | 1 << 31, 0, 0];
+        assert_eq!(
+            "058000002c8000003c800000030000000000000000",
+            hex::encode(super::to_path_bytes(&path))
+        );
+    }
+
+    #[test]
+    fn to_packets() {
+        let mut apdu = vec![0xAB; 98];
+        apdu.extend_from_slice(&SW_OK.to_be_bytes());
+        let packets = super::to_packets(&apdu);
+        assert_eq!(2, packets.len());
+        assert_eq!([0x01, 0x01, 0x05, 0x00, 0x00, 0x00, 100], packets[0][0..7]);
+        assert_eq!([0x01, 0x01, 0x05, 0x00, 0x01], packets[1][0..5]);
+
+        // The response reader reassembles packets of the same framing
+        let mut response = ResponseReader::default();
+        packets.iter().for_each(|packet| response.push(packet).unwrap());
+        assert!(response.is_complete());
+        assert_eq!(vec![0xAB; 98], response.into_data().unwrap());
+    }
+
+    #[test]
+    fn from_public_key_response() {
+        let mut response = vec![65, 0x04];
+        response.extend_from_slice(&[0x11; 64]);
+        response.push(3);
+        response.extend_from_slice(b"abc");
+        response.extend_from_slice(&[0x22; 32]);
+
+        let public_key = super::from_public_key_response(&response).unwrap();
+        assert_eq!(65, public_key.public_key.len());
+        assert_eq!("abc", public_key.address);
+        assert_eq!([0x22; 32], public_key.chain_code);
+
+        assert!(super::from_public_key_response(&response[..70]).is_err());
+    }
+
+    #[test]
+    fn to_compressed() {
+        let public_key = LedgerPublicKey {
+            public_key: hex::decode("0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8").unwrap(),
+            ..Default::default()
+        };
+        assert_eq!(
+            "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            hex::encode(&public_key.to_compressed().unwrap()[..])
+        );
+    }
+
+    #[test]
+    fn to_extended_public_key() {
+        // BIP32 test vector 1, chain m/0H
+        let mut public_key = LedgerPublicKey {
+            path: vec![1 << 31],
+            public_key: hex::decode("035a784662a4a20a65bf6aab9ae98a6c068a81c52e4b032c0fb5400c706cfccc56").unwrap(),
+            parent_fingerprint: [0x34, 0x42, 0x19, 0x3e],
+            ..Default::default()
+        };
+        public_key
+            .chain_code
+            .copy_from_slice(&hex::decode("47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141").unwrap());
+        assert_eq!(
+            "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw",
+            public_key.to_extended_public_key(&[0x04, 0x88, 0xB2, 0x1E]).unwrap()
+        );
+    }
+}
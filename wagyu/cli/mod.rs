@@ -1,21 +1,55 @@
+use crate::bitcoin::{MiniscriptError, PaymentCodeError, UriError as BitcoinUriError};
+use crate::ethereum::AbiError;
 use crate::model::{
-    AddressError, AmountError, DerivationPathError, ExtendedPrivateKeyError, ExtendedPublicKeyError, MnemonicError,
-    PrivateKeyError, PublicKeyError, TransactionError,
+    crypto::hash160, AddressError, AmountError, AuditWalletRng, DerivationPathError, ExtendedPrivateKeyError,
+    ExtendedPublicKeyError, MnemonicError, OsWalletRng, PrivateKeyError, PublicKeyError, Transaction, TransactionError,
+    WalletRng, WordlistError,
 };
+use crate::monero::UriError as MoneroUriError;
 
+pub mod base58_coin;
 pub mod bitcoin;
+pub mod bitcoincash;
+pub mod cosmos;
+pub mod dash;
+pub mod dogecoin;
 pub mod ethereum;
 pub mod monero;
+pub mod ripple;
+pub mod solana;
+pub mod tron;
 pub mod zcash;
 
 pub mod parameters;
 pub use self::parameters::*;
 
+#[cfg(feature = "hw-ledger")]
+pub mod ledger;
+
+pub mod diagnostics;
+pub mod entropy;
+pub mod interactive;
+pub mod introspect;
+pub mod output;
+pub mod paper;
+pub mod qr;
+pub mod vectors;
+
+#[cfg(feature = "broadcast")]
+pub mod rpc;
+
 use types::*;
 
+use base58::FromBase58;
 use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+use colored::*;
+use std::io::{BufRead, IsTerminal};
+use zeroize::Zeroize;
 
-use crate::model::no_std::{format, String, Vec};
+use crate::model::no_std::{format, String, ToString, Vec};
+
+/// The names of the options that read their value from stdin when specified without a value
+const STDIN_OPTIONS: [&str; 3] = ["[mnemonic]", "[password]", "[private]"];
 
 pub trait CLI {
     type Options;
@@ -32,33 +66,55 @@ pub trait CLI {
             .iter()
             .map(|a| Arg::from_usage(a).global(true))
             .collect::<Vec<Arg<'static, 'static>>>();
-        let options = &Self::OPTIONS
-            .iter()
-            .map(|a| match a.2.len() > 0 {
-                true => Arg::from_usage(a.0)
-                    .conflicts_with_all(a.1)
-                    .possible_values(a.2)
-                    .requires_all(a.3),
-                false => Arg::from_usage(a.0).conflicts_with_all(a.1).requires_all(a.3),
-            })
-            .collect::<Vec<Arg<'static, 'static>>>();
+        // Conflicts are kept only with the arguments of the same command, or its flags,
+        // as a conflict with an argument the command does not define fails to generate completions.
+        fn to_args(options: &'static [OptionType], flags: &'static [FlagType]) -> Vec<Arg<'static, 'static>> {
+            let names = options
+                .iter()
+                .map(|a| a.0)
+                .chain(flags.iter().cloned())
+                .map(argument_name)
+                .collect::<Vec<&str>>();
+            options
+                .iter()
+                .map(|a| {
+                    let conflicts =
+                        a.1.iter()
+                            .cloned()
+                            .filter(|name| names.contains(name))
+                            .collect::<Vec<&str>>();
+                    let arg = match a.2.len() > 0 {
+                        true => Arg::from_usage(a.0)
+                            .conflicts_with_all(&conflicts)
+                            .possible_values(a.2)
+                            .requires_all(a.3),
+                        false => Arg::from_usage(a.0).conflicts_with_all(&conflicts).requires_all(a.3),
+                    };
+                    match STDIN_OPTIONS.iter().any(|name| a.0.starts_with(name)) {
+                        true => arg.min_values(0),
+                        false => arg,
+                    }
+                })
+                .collect::<Vec<Arg<'static, 'static>>>()
+        }
+        let options = &to_args(Self::OPTIONS, Self::FLAGS);
         let subcommands = Self::SUBCOMMANDS
             .iter()
             .map(|s| {
                 SubCommand::with_name(s.0)
                     .about(s.1)
-                    .args(
-                        &s.2.iter()
-                            .map(|a| match a.2.len() > 0 {
-                                true => Arg::from_usage(a.0)
-                                    .conflicts_with_all(a.1)
-                                    .possible_values(a.2)
-                                    .requires_all(a.3),
-                                false => Arg::from_usage(a.0).conflicts_with_all(a.1).requires_all(a.3),
+                    .args(&to_args(s.2, Self::FLAGS))
+                    .settings(s.3)
+                    .subcommands(
+                        s.4.iter()
+                            .map(|n| {
+                                SubCommand::with_name(n.0)
+                                    .about(n.1)
+                                    .args(&to_args(n.2, Self::FLAGS))
+                                    .settings(n.3)
                             })
-                            .collect::<Vec<Arg<'static, 'static>>>(),
+                            .collect::<Vec<App<'static, 'static>>>(),
                     )
-                    .settings(s.3)
             })
             .collect::<Vec<App<'static, 'static>>>();
 
@@ -81,6 +137,156 @@ pub trait CLI {
     fn print(options: Self::Options) -> Result<(), CLIError>;
 }
 
+/// Returns the name of an argument from its usage, as `name` in `[name] --long=[value] 'help'`.
+pub fn argument_name(usage: &str) -> &str {
+    let end = usage.find(|c| c == ']' || c == '>').unwrap_or(usage.len());
+    usage.get(1..end).unwrap_or("")
+}
+
+/// Returns the fingerprint of the specified public key, as the first 4 bytes of the HASH160 of the key in hex.
+/// The key is decoded from hex or base58 if possible, so that the fingerprint of a compressed
+/// secp256k1 public key is its BIP-32 key fingerprint.
+pub fn fingerprint(public_key: &str) -> String {
+    let bytes = hex::decode(public_key)
+        .ok()
+        .or_else(|| public_key.from_base58().ok())
+        .unwrap_or_else(|| public_key.as_bytes().to_vec());
+    hex::encode(&hash160(&bytes)[..4])
+}
+
+/// Returns the BIP-32 fingerprint of the master key of the specified extended public key, if it can be
+/// read from the key: the fingerprint of the key itself at depth 0, or the fingerprint of its parent at depth 1.
+pub fn master_fingerprint(extended_public_key: &str) -> Option<String> {
+    let data = extended_public_key.from_base58().ok().filter(|data| data.len() == 82)?;
+    match data[4] {
+        0 => Some(hex::encode(&hash160(&data[45..78])[..4])),
+        1 => Some(hex::encode(&data[5..9])),
+        _ => None,
+    }
+}
+
+/// Returns a short identifier of the wallet of the specified extended public key, as the first 8 bytes
+/// of the HASH160 of the key in hex. The version bytes of a base58 key are not hashed, so that the key
+/// has the same identifier in any of its SLIP-132 encodings (e.g. xpub and zpub).
+pub fn wallet_id(extended_public_key: &str) -> String {
+    let bytes = match extended_public_key.from_base58() {
+        Ok(data) if data.len() == 82 => data[4..78].to_vec(),
+        _ => extended_public_key.as_bytes().to_vec(),
+    };
+    hex::encode(&hash160(&bytes)[..8])
+}
+
+/// Returns the `(start, end)` bounds of an index range of the form `start..end` (end exclusive)
+/// or `start..=end` (end inclusive). Returns an error if the range is malformed or empty.
+pub fn parse_index_range(range: &str) -> Result<(u32, u32), CLIError> {
+    let error = || CLIError::InvalidIndexRange(range.into());
+    let (start, end) = match range.find("..") {
+        Some(position) => (&range[..position], &range[position + 2..]),
+        None => return Err(error()),
+    };
+    let start = start.trim().parse::<u32>().map_err(|_| error())?;
+    let end = match end.starts_with('=') {
+        true => end[1..]
+            .trim()
+            .parse::<u32>()
+            .ok()
+            .and_then(|end| end.checked_add(1))
+            .ok_or_else(error)?,
+        false => end.trim().parse::<u32>().map_err(|_| error())?,
+    };
+    match end > start {
+        true => Ok((start, end)),
+        false => Err(error()),
+    }
+}
+
+/// Returns an error if a word count is specified and the mnemonic has a different number of words.
+pub fn check_word_count(mnemonic: &str, word_count: Option<u8>) -> Result<(), CLIError> {
+    let found = mnemonic.split_whitespace().count();
+    match word_count {
+        Some(expected) if expected as usize != found => Err(MnemonicError::MismatchedWordCount(expected, found).into()),
+        _ => Ok(()),
+    }
+}
+
+/// Returns `true` if the specified arguments, or those of any of their subcommands,
+/// request a JSON output, with `--json` or an `--output-format` of `json` or `ndjson`.
+pub fn is_json_output(arguments: &ArgMatches) -> bool {
+    let json = arguments.is_present("json")
+        || match arguments.value_of("output format") {
+            Some(format) => format == "json" || format == "ndjson",
+            None => false,
+        };
+    match arguments.subcommand() {
+        (_, Some(subcommand)) => json || is_json_output(subcommand),
+        _ => json,
+    }
+}
+
+/// Returns the wallet RNG to generate wallets with, drawing from the entropy of the operating system.
+/// If `audit` is enabled, every draw of entropy is logged to stderr, to review the entropy consumed by each wallet.
+pub fn wallet_rng(audit: bool) -> Box<dyn WalletRng> {
+    let rng = OsWalletRng::new();
+    match audit {
+        true => {
+            let mut consumed = 0;
+            Box::new(AuditWalletRng::new(rng, move |bytes| {
+                consumed += bytes;
+                eprintln!(
+                    "{} drew {} bytes of entropy ({} bytes in total)",
+                    "Audit:".cyan().bold(),
+                    bytes,
+                    consumed
+                );
+            }))
+        }
+        false => Box::new(rng),
+    }
+}
+
+/// Returns the value of the specified argument. If the argument is present without a value,
+/// the value is read from stdin instead, as a hidden prompt on a terminal or as one line of a pipe,
+/// to keep secrets out of the shell history and the process list.
+pub fn value_or_stdin(arguments: &ArgMatches, name: &str) -> Option<String> {
+    match (arguments.value_of(name), arguments.is_present(name)) {
+        (Some(value), _) => Some(value.to_string()),
+        (None, true) => {
+            let mut value = match std::io::stdin().is_terminal() {
+                true => rpassword::prompt_password(format!("Enter the {}: ", name)).ok()?,
+                false => {
+                    let mut line = String::new();
+                    std::io::stdin().lock().read_line(&mut line).ok()?;
+                    line
+                }
+            };
+            let trimmed = value.trim_end_matches(&['\r', '\n'][..]).to_string();
+            value.zeroize();
+            match trimmed.is_empty() {
+                true => None,
+                false => Some(trimmed),
+            }
+        }
+        (None, false) => None,
+    }
+}
+
+/// Prints a warning to stderr if the fee of the assembled transaction exceeds the specified maximum,
+/// as an absurdly high fee is likely a mistake, such as a missing change output or a misplaced decimal.
+pub fn warn_high_fee<T: Transaction>(transaction: &T, maximum: T::Amount, unit: &str) {
+    if let Some(fee) = transaction.fee() {
+        if fee > maximum {
+            eprintln!(
+                "{} the transaction fee of {} {} exceeds {} {}, check its amounts before broadcasting it",
+                "Warning:".yellow().bold(),
+                fee,
+                unit,
+                maximum,
+                unit
+            );
+        }
+    }
+}
+
 #[derive(Debug, Fail)]
 pub enum CLIError {
     #[fail(display = "{}", _0)]
@@ -101,9 +307,24 @@ pub enum CLIError {
     #[fail(display = "{}", _0)]
     ExtendedPublicKeyError(ExtendedPublicKeyError),
 
+    #[fail(display = "invalid address: {}", _0)]
+    InvalidAddress(String),
+
+    #[fail(display = "invalid descriptor: {}", _0)]
+    InvalidDescriptor(String),
+
+    #[fail(
+        display = "invalid index range: {} (expected a non-empty start..end or start..=end)",
+        _0
+    )]
+    InvalidIndexRange(String),
+
     #[fail(display = "invalid derived mnemonic for a given private spend key")]
     InvalidMnemonicForPrivateSpendKey,
 
+    #[fail(display = "ledger error: {}", _0)]
+    LedgerError(String),
+
     #[fail(display = "{}", _0)]
     PrivateKeyError(PrivateKeyError),
 
@@ -113,13 +334,81 @@ pub enum CLIError {
     #[fail(display = "{}", _0)]
     MnemonicError(MnemonicError),
 
+    #[fail(display = "rpc error: {}", _0)]
+    RpcError(String),
+
     #[fail(display = "{}", _0)]
     TransactionError(TransactionError),
 
+    #[fail(display = "unsupported format: {}", _0)]
+    UnsupportedFormat(String),
+
     #[fail(display = "unsupported mnemonic language")]
     UnsupportedLanguage,
 }
 
+impl CLIError {
+    /// Returns the kind of the error, as reported in the `kind` field of a JSON error.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            CLIError::AddressError(_) | CLIError::InvalidAddress(_) => "address",
+            CLIError::AmountError(_) => "amount",
+            CLIError::Crate("io", _) | CLIError::Crate("image", _) => "io",
+            CLIError::Crate(_, _) | CLIError::InvalidIndexRange(_) => "input",
+            CLIError::DerivationPathError(_) => "derivation_path",
+            CLIError::ExtendedPrivateKeyError(_) => "extended_private_key",
+            CLIError::ExtendedPublicKeyError(_) => "extended_public_key",
+            CLIError::InvalidDescriptor(_) => "descriptor",
+            CLIError::InvalidMnemonicForPrivateSpendKey | CLIError::MnemonicError(_) => "mnemonic",
+            CLIError::LedgerError(_) => "ledger",
+            CLIError::PrivateKeyError(_) => "private_key",
+            CLIError::PublicKeyError(_) => "public_key",
+            CLIError::RpcError(_) => "rpc",
+            CLIError::TransactionError(_) => "transaction",
+            CLIError::UnsupportedFormat(_) | CLIError::UnsupportedLanguage => "unsupported",
+        }
+    }
+
+    /// Returns the exit code of the error, which is unique to its kind.
+    /// The exit code 1 is reserved for invalid command-line arguments.
+    pub fn exit_code(&self) -> i32 {
+        match self.kind() {
+            "io" => 2,
+            "input" => 3,
+            "mnemonic" => 4,
+            "derivation_path" => 5,
+            "private_key" => 6,
+            "public_key" => 7,
+            "extended_private_key" => 8,
+            "extended_public_key" => 9,
+            "address" => 10,
+            "amount" => 11,
+            "transaction" => 12,
+            "descriptor" => 13,
+            "unsupported" => 14,
+            "ledger" => 15,
+            "rpc" => 16,
+            _ => 1,
+        }
+    }
+
+    /// Returns the error as a JSON object of the form `{ "error": { "kind": ..., "message": ... } }`.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "error": {
+                "kind": self.kind(),
+                "message": self.to_string(),
+            }
+        })
+    }
+}
+
+impl From<AbiError> for CLIError {
+    fn from(error: AbiError) -> Self {
+        CLIError::Crate("abi", format!("{}", error))
+    }
+}
+
 impl From<AddressError> for CLIError {
     fn from(error: AddressError) -> Self {
         CLIError::AddressError(error)
@@ -156,12 +445,30 @@ impl From<ExtendedPublicKeyError> for CLIError {
     }
 }
 
+impl From<base58::FromBase58Error> for CLIError {
+    fn from(error: base58::FromBase58Error) -> Self {
+        CLIError::Crate("base58", format!("{:?}", error))
+    }
+}
+
 impl From<hex::FromHexError> for CLIError {
     fn from(error: hex::FromHexError) -> Self {
         CLIError::Crate("hex", format!("{:?}", error))
     }
 }
 
+impl From<std::io::Error> for CLIError {
+    fn from(error: std::io::Error) -> Self {
+        CLIError::Crate("io", format!("{:?}", error))
+    }
+}
+
+impl From<image::ImageError> for CLIError {
+    fn from(error: image::ImageError) -> Self {
+        CLIError::Crate("image", format!("{:?}", error))
+    }
+}
+
 impl From<MnemonicError> for CLIError {
     fn from(error: MnemonicError) -> Self {
         CLIError::MnemonicError(error)
@@ -180,6 +487,12 @@ impl From<PublicKeyError> for CLIError {
     }
 }
 
+impl From<qrcode::types::QrError> for CLIError {
+    fn from(error: qrcode::types::QrError) -> Self {
+        CLIError::Crate("qrcode", format!("{:?}", error))
+    }
+}
+
 impl From<serde_json::error::Error> for CLIError {
     fn from(error: serde_json::error::Error) -> Self {
         CLIError::Crate("serde_json", format!("{:?}", error))
@@ -191,3 +504,112 @@ impl From<TransactionError> for CLIError {
         CLIError::TransactionError(error)
     }
 }
+
+impl From<WordlistError> for CLIError {
+    fn from(error: WordlistError) -> Self {
+        CLIError::MnemonicError(MnemonicError::from(error))
+    }
+}
+
+impl From<BitcoinUriError> for CLIError {
+    fn from(error: BitcoinUriError) -> Self {
+        CLIError::Crate("uri", format!("{}", error))
+    }
+}
+
+impl From<MiniscriptError> for CLIError {
+    fn from(error: MiniscriptError) -> Self {
+        CLIError::Crate("miniscript", format!("{}", error))
+    }
+}
+
+impl From<PaymentCodeError> for CLIError {
+    fn from(error: PaymentCodeError) -> Self {
+        CLIError::Crate("payment_code", format!("{}", error))
+    }
+}
+
+impl From<MoneroUriError> for CLIError {
+    fn from(error: MoneroUriError) -> Self {
+        CLIError::Crate("uri", format!("{}", error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn public_key_fingerprint() {
+        // The master key of BIP-32 test vector 1
+        let public_key = "0339a36013301597daef41fbe593a02cc513d0b55527ec2df1050e2e8ff49c85c2";
+        assert_eq!("3442193e", fingerprint(public_key));
+    }
+
+    #[test]
+    fn extended_public_key_identifiers() {
+        // The master key of BIP-32 test vector 1, and its child m/0'
+        let master = "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8";
+        let child = "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw";
+        assert_eq!(Some("3442193e".to_string()), master_fingerprint(master));
+        assert_eq!(Some("3442193e".to_string()), master_fingerprint(child));
+        assert_eq!(16, wallet_id(master).len());
+        assert_ne!(wallet_id(master), wallet_id(child));
+    }
+
+    #[test]
+    fn index_ranges() {
+        assert_eq!((0, 10), parse_index_range("0..10").unwrap());
+        assert_eq!((5, 11), parse_index_range("5..=10").unwrap());
+        assert_eq!((3, 4), parse_index_range(" 3 ..= 3 ").unwrap());
+        assert_eq!((0, u32::max_value()), parse_index_range("0..4294967295").unwrap());
+    }
+
+    #[test]
+    fn invalid_index_ranges() {
+        let ranges = [
+            "5..3",
+            "3..3",
+            "4..=3",
+            "0..=4294967295",
+            "garbage",
+            "5",
+            "..3",
+            "3..",
+            "-1..3",
+            "a..b",
+        ];
+        for range in ranges.iter() {
+            match parse_index_range(range) {
+                Err(CLIError::InvalidIndexRange(invalid)) => assert_eq!(*range, invalid),
+                result => panic!("expected an invalid index range for {}, found {:?}", range, result),
+            }
+        }
+    }
+
+    #[test]
+    fn error_kinds() {
+        let errors = [
+            (CLIError::Crate("io", "".into()), "io", 2),
+            (CLIError::Crate("hex", "".into()), "input", 3),
+            (CLIError::UnsupportedLanguage, "unsupported", 14),
+            (
+                CLIError::InvalidAddress("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3".into()),
+                "address",
+                10,
+            ),
+            (MnemonicError::InvalidWord("abandoned".into()).into(), "mnemonic", 4),
+            (
+                DerivationPathError::InvalidDerivationPath("m/x".into()).into(),
+                "derivation_path",
+                5,
+            ),
+        ];
+        for (error, kind, exit_code) in errors.iter() {
+            assert_eq!(*kind, error.kind());
+            assert_eq!(*exit_code, error.exit_code());
+            assert_eq!(*kind, error.to_json()["error"]["kind"]);
+            assert_eq!(error.to_string(), error.to_json()["error"]["message"]);
+        }
+    }
+}
@@ -1,22 +1,31 @@
-use crate::cli::{flag, option, subcommand, types::*, CLIError, CLI};
-use crate::model::{Mnemonic, PrivateKey, PublicKey};
+use crate::cli::diagnostics::{self, AddressDiagnostics};
+use crate::cli::entropy::Entropy;
+use crate::cli::output::WalletWriter;
+use crate::cli::vectors::TestVectors;
+use crate::cli::{
+    fingerprint, flag, option, paper, paper::PaperWallet, parse_index_range, qr, subcommand, types::*, value_or_stdin,
+    wallet_rng, CLIError, CLI,
+};
+use crate::model::{AddressError, Mnemonic, Network, PrivateKey, PrivateKeyError, PublicKey};
 use crate::monero::{
-    format::MoneroFormat, wordlist::*, Mainnet as MoneroMainnet, MoneroAddress, MoneroMnemonic, MoneroNetwork,
-    MoneroPublicKey, MoneroWordlist, Stagenet as MoneroStagenet, Testnet as MoneroTestnet,
+    format::MoneroFormat, wordlist::*, Mainnet as MoneroMainnet, MoneroAddress, MoneroAmount, MoneroDecodedAddress,
+    MoneroMnemonic, MoneroNetwork, MoneroPrivateKey, MoneroPublicKey, MoneroUri, MoneroViewKeypair, MoneroWordlist,
+    Stagenet as MoneroStagenet, Testnet as MoneroTestnet,
 };
 
 use clap::{ArgMatches, Values};
 use colored::*;
-use core::{fmt, fmt::Display, str::FromStr};
-use rand::{rngs::StdRng, Rng};
-use rand_core::SeedableRng;
+use core::{fmt, fmt::Display, iter, str::FromStr};
+use rand::Rng;
 use serde::Serialize;
+use tiny_keccak::keccak256;
+use zeroize::Zeroize;
 
 use crate::model::no_std::{format, vec, String, ToOwned, ToString, Vec};
 
 /// Represents a generic wallet to output
 #[derive(Serialize, Debug, Default)]
-struct MoneroWallet {
+pub struct MoneroWallet {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mnemonic: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -30,37 +39,79 @@ struct MoneroWallet {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub address: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub format: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payment_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
+
+impl Zeroize for MoneroWallet {
+    /// Overwrites the secret fields of the wallet.
+    fn zeroize(&mut self) {
+        self.mnemonic.zeroize();
+        self.private_spend_key.zeroize();
+        self.private_view_key.zeroize();
+    }
 }
 
 impl MoneroWallet {
+    /// The fields of the wallet, in the order of the columns of a CSV output
+    pub const FIELDS: &'static [&'static str] = &[
+        "mnemonic",
+        "private_spend_key",
+        "private_view_key",
+        "public_spend_key",
+        "public_view_key",
+        "address",
+        "fingerprint",
+        "format",
+        "payment_id",
+        "network",
+        "recipient_name",
+        "amount",
+        "description",
+        "uri",
+    ];
+
+    /// Withholds the secrets of the wallet from its output, leaving its public material
+    /// and the fingerprint of its public key.
+    pub fn public_only(&mut self) {
+        self.fingerprint = self
+            .public_spend_key
+            .as_deref()
+            .or(self.address.as_deref())
+            .map(fingerprint);
+        self.zeroize();
+    }
+
     pub fn new<N: MoneroNetwork, W: MoneroWordlist, R: Rng>(
         rng: &mut R,
         format: &MoneroFormat,
     ) -> Result<Self, CLIError> {
         let mnemonic = MoneroMnemonic::<N, W>::new(rng)?;
-        let private_key = mnemonic.to_private_key(None)?;
-        let private_spend_key = private_key.to_private_spend_key();
-        let private_view_key = private_key.to_private_view_key();
-        let public_key = private_key.to_public_key();
-        let public_spend_key = public_key.to_public_spend_key().unwrap();
-        let public_view_key = public_key.to_public_view_key().unwrap();
-        let address = public_key.to_address(format)?;
-        Ok(Self {
-            mnemonic: Some(mnemonic.to_string()),
-            private_spend_key: Some(hex::encode(private_spend_key)),
-            private_view_key: Some(hex::encode(private_view_key)),
-            public_spend_key: Some(hex::encode(public_spend_key)),
-            public_view_key: Some(hex::encode(public_view_key)),
-            address: Some(address.to_string()),
-            format: Some(format.to_string()),
-            payment_id: address.to_payment_id(),
-            network: Some(N::NAME.to_string()),
-            ..Default::default()
+        Self::from_hd_mnemonic(&mnemonic, format)
+    }
+
+    /// Returns the mainnet test vectors of the entropy: a wallet with a standard address,
+    /// and a wallet with the first subaddress of its primary account.
+    pub fn test_vectors(entropy: &mut Entropy) -> Result<TestVectors<Self>, CLIError> {
+        Ok(TestVectors {
+            wallets: vec![
+                Self::new::<MoneroMainnet, English, _>(entropy, &MoneroFormat::Standard)?,
+                Self::new::<MoneroMainnet, English, _>(entropy, &MoneroFormat::Subaddress(0, 1))?,
+            ],
+            transactions: vec![],
         })
     }
 
@@ -69,24 +120,18 @@ impl MoneroWallet {
         format: &MoneroFormat,
     ) -> Result<Self, CLIError> {
         let mnemonic = MoneroMnemonic::<N, W>::from_phrase(&mnemonic)?;
+        Self::from_hd_mnemonic(&mnemonic, format)
+    }
+
+    /// Returns the wallet of the specified mnemonic, with the address of the specified format.
+    pub fn from_hd_mnemonic<N: MoneroNetwork, W: MoneroWordlist>(
+        mnemonic: &MoneroMnemonic<N, W>,
+        format: &MoneroFormat,
+    ) -> Result<Self, CLIError> {
         let private_key = mnemonic.to_private_key(None)?;
-        let private_spend_key = private_key.to_private_spend_key();
-        let private_view_key = private_key.to_private_view_key();
-        let public_key = private_key.to_public_key();
-        let public_spend_key = public_key.to_public_spend_key().unwrap();
-        let public_view_key = public_key.to_public_view_key().unwrap();
-        let address = public_key.to_address(format)?;
         Ok(Self {
             mnemonic: Some(mnemonic.to_string()),
-            private_spend_key: Some(hex::encode(private_spend_key)),
-            private_view_key: Some(hex::encode(private_view_key)),
-            public_spend_key: Some(hex::encode(public_spend_key)),
-            public_view_key: Some(hex::encode(public_view_key)),
-            address: Some(address.to_string()),
-            format: Some(format.to_string()),
-            payment_id: address.to_payment_id(),
-            network: Some(N::NAME.to_string()),
-            ..Default::default()
+            ..Self::from_private_key(&private_key, format)?
         })
     }
 
@@ -101,8 +146,36 @@ impl MoneroWallet {
         if private_spend_key.to_string() != hex::encode(private_key.to_private_spend_key()) {
             return Err(CLIError::InvalidMnemonicForPrivateSpendKey);
         }
+        Self::from_private_key(&private_key, format)
+    }
+
+    /// Returns the wallet of the specified private spend key, with its mnemonic in the language of the wordlist.
+    pub fn from_hd_private_spend_key<N: MoneroNetwork, W: MoneroWordlist>(
+        private_spend_key: &str,
+        format: &MoneroFormat,
+    ) -> Result<Self, CLIError> {
+        let private_spend_key = hex::decode(private_spend_key)?;
+        if private_spend_key.len() != 32 {
+            return Err(PrivateKeyError::InvalidByteLength(private_spend_key.len()).into());
+        }
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&private_spend_key);
+        let mnemonic = MoneroMnemonic::<N, W>::from_private_spend_key(&seed);
+        if mnemonic.to_private_key(None)?.to_private_spend_key() != seed {
+            return Err(CLIError::InvalidMnemonicForPrivateSpendKey);
+        }
+        Self::from_hd_mnemonic(&mnemonic, format)
+    }
+
+    /// Returns the wallet of the specified private key, with the address of the specified format.
+    /// The public keys of a subaddress are derived from the private keys, and its major and minor index.
+    fn from_private_key<N: MoneroNetwork>(
+        private_key: &MoneroPrivateKey<N>,
+        format: &MoneroFormat,
+    ) -> Result<Self, CLIError> {
         let private_spend_key = private_key.to_private_spend_key();
         let private_view_key = private_key.to_private_view_key();
+        let private_key = MoneroPrivateKey::<N>::from_private_spend_key(&hex::encode(private_spend_key), format)?;
         let public_key = private_key.to_public_key();
         let public_spend_key = public_key.to_public_spend_key().unwrap();
         let public_view_key = public_key.to_public_view_key().unwrap();
@@ -113,13 +186,44 @@ impl MoneroWallet {
             public_spend_key: Some(hex::encode(public_spend_key)),
             public_view_key: Some(hex::encode(public_view_key)),
             address: Some(address.to_string()),
-            format: Some(format.to_string()),
+            format: Some(private_key.format().to_string()),
             payment_id: address.to_payment_id(),
             network: Some(N::NAME.to_string()),
             ..Default::default()
         })
     }
 
+    /// Returns the view-only wallet of the specified view keypair, with the address of the specified format.
+    pub fn from_view_keypair<N: MoneroNetwork>(
+        view_keypair: &MoneroViewKeypair<N>,
+        format: &MoneroFormat,
+    ) -> Result<Self, CLIError> {
+        let public_key = view_keypair.to_public_key(format)?;
+        let public_spend_key = public_key.to_public_spend_key().unwrap();
+        let public_view_key = public_key.to_public_view_key().unwrap();
+        let address = public_key.to_address(format)?;
+        Ok(Self {
+            private_view_key: Some(hex::encode(view_keypair.to_private_view_key())),
+            public_spend_key: Some(hex::encode(public_spend_key)),
+            public_view_key: Some(hex::encode(public_view_key)),
+            address: Some(address.to_string()),
+            format: Some(public_key.format().to_string()),
+            payment_id: address.to_payment_id(),
+            network: Some(N::NAME.to_string()),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the view-only wallet of the wallet, with the address of the specified format,
+    /// and without its mnemonic and private spend key.
+    pub fn into_view_only<N: MoneroNetwork>(mut self, format: &MoneroFormat) -> Result<Self, CLIError> {
+        let mut private_spend_key = self.private_spend_key.clone().unwrap_or_default();
+        self.zeroize();
+        let private_key = MoneroPrivateKey::<N>::from_private_spend_key(&private_spend_key, &MoneroFormat::Standard);
+        private_spend_key.zeroize();
+        Self::from_view_keypair(&MoneroViewKeypair::from_private_key(&private_key?), format)
+    }
+
     pub fn from_private_view_key<N: MoneroNetwork>(
         private_view_key: &str,
         format: &MoneroFormat,
@@ -165,6 +269,77 @@ impl MoneroWallet {
             ..Default::default()
         })
     }
+
+    /// Returns a payment request to the specified address, with the specified payment ID, recipient name,
+    /// amount (in XMR), and description.
+    pub fn to_uri<N: MoneroNetwork>(
+        address: &str,
+        payment_id: Option<&str>,
+        recipient_name: Option<&str>,
+        amount: Option<&str>,
+        description: Option<&str>,
+    ) -> Result<Self, CLIError> {
+        let mut uri = MoneroUri::new(MoneroAddress::<N>::from_str(address)?);
+        if let Some(payment_id) = payment_id {
+            uri.set_payment_id(payment_id)?;
+        }
+        uri.recipient_name = recipient_name.map(String::from);
+        uri.amount = amount.map(MoneroAmount::from_monero_str).transpose()?;
+        uri.description = description.map(String::from);
+        Self::from_uri(&uri)
+    }
+
+    /// Returns the payment request of a Monero URI, with the format and network of its address,
+    /// and the payment ID of the URI or of its integrated address.
+    pub fn from_uri<N: MoneroNetwork>(uri: &MoneroUri<N>) -> Result<Self, CLIError> {
+        Ok(Self {
+            address: Some(uri.address.to_string()),
+            format: Some(uri.address.format()?.to_string()),
+            payment_id: uri.payment_id.clone().or(uri.address.to_payment_id()),
+            network: Some(N::NAME.to_string()),
+            recipient_name: uri.recipient_name.clone(),
+            amount: uri.amount.map(|amount| amount.to_monero_string()),
+            description: uri.description.clone(),
+            uri: Some(uri.to_string()),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the payment request URI, address, or otherwise the public spend key, of the wallet
+    /// to render as a QR code.
+    pub fn to_qr_data(&self) -> Option<&str> {
+        self.uri
+            .as_deref()
+            .or(self.address.as_deref())
+            .or(self.public_spend_key.as_deref())
+    }
+
+    /// Returns the address, mnemonic or private spend key, and remaining fields of the wallet, to render on paper.
+    pub fn to_paper_wallet(&self) -> Option<PaperWallet<'_>> {
+        let address = self.address.as_deref()?;
+        let secret = match (self.mnemonic.as_deref(), self.private_spend_key.as_deref()) {
+            (Some(mnemonic), _) => ("Mnemonic", mnemonic),
+            (None, Some(private_spend_key)) => ("Private Spend Key", private_spend_key),
+            (None, None) => return None,
+        };
+        let fields = [
+            ("Private Spend Key", self.private_spend_key.as_deref()),
+            ("Private View Key", self.private_view_key.as_deref()),
+            ("Format", self.format.as_deref()),
+            ("Payment ID", self.payment_id.as_deref()),
+            ("Network", self.network.as_deref()),
+        ]
+        .iter()
+        .filter(|(label, _)| *label != secret.0)
+        .filter_map(|(label, value)| value.map(|value| (*label, value)))
+        .collect();
+
+        Some(PaperWallet {
+            address,
+            secret,
+            fields,
+        })
+    }
 }
 
 #[cfg_attr(tarpaulin, skip)]
@@ -203,6 +378,10 @@ impl Display for MoneroWallet {
                 Some(address) => format!("      {}              {}\n", "Address".cyan().bold(), address),
                 _ => "".to_owned(),
             },
+            match &self.fingerprint {
+                Some(fingerprint) => format!("      {}          {}\n", "Fingerprint".cyan().bold(), fingerprint),
+                _ => "".to_owned(),
+            },
             match &self.format {
                 Some(format) => format!("      {}               {}\n", "Format".cyan().bold(), format),
                 _ => "".to_owned(),
@@ -215,6 +394,24 @@ impl Display for MoneroWallet {
                 Some(network) => format!("      {}              {}\n", "Network".cyan().bold(), network),
                 _ => "".to_owned(),
             },
+            match &self.recipient_name {
+                Some(recipient_name) => {
+                    format!("      {}       {}\n", "Recipient Name".cyan().bold(), recipient_name)
+                }
+                _ => "".to_owned(),
+            },
+            match &self.amount {
+                Some(amount) => format!("      {}               {} XMR\n", "Amount".cyan().bold(), amount),
+                _ => "".to_owned(),
+            },
+            match &self.description {
+                Some(description) => format!("      {}          {}\n", "Description".cyan().bold(), description),
+                _ => "".to_owned(),
+            },
+            match &self.uri {
+                Some(uri) => format!("      {}                  {}\n", "URI".cyan().bold(), uri),
+                _ => "".to_owned(),
+            },
         ]
         .concat();
 
@@ -228,12 +425,20 @@ impl Display for MoneroWallet {
 #[derive(Serialize, Clone, Debug)]
 pub struct MoneroOptions {
     // Standard command
+    audit_rng: bool,
     count: usize,
     format: MoneroFormat,
-    json: bool,
     language: String,
     network: String,
+    output_file: Option<String>,
+    output_format: String,
+    public_only: bool,
+    qr: bool,
+    qr_file: Option<String>,
     subcommand: Option<String>,
+    // HD and Import HD subcommands
+    index_range: Option<(u32, u32)>,
+    view_only: bool,
     // Import subcommand
     address: Option<String>,
     mnemonic: Option<String>,
@@ -241,18 +446,34 @@ pub struct MoneroOptions {
     private_view_key: Option<String>,
     public_spend_key: Option<String>,
     public_view_key: Option<String>,
+    // Paper subcommand
+    paper_file: Option<String>,
+    // URI subcommand
+    uri: Option<String>,
+    uri_amount: Option<String>,
+    uri_description: Option<String>,
+    uri_payment_id: Option<String>,
+    uri_recipient_name: Option<String>,
 }
 
 impl Default for MoneroOptions {
     fn default() -> Self {
         Self {
             // Standard command
+            audit_rng: false,
             count: 1,
             format: MoneroFormat::Standard,
-            json: false,
             language: "english".into(),
             network: "mainnet".into(),
+            output_file: None,
+            output_format: "text".into(),
+            public_only: false,
+            qr: false,
+            qr_file: None,
             subcommand: None,
+            // HD and Import HD subcommands
+            index_range: None,
+            view_only: false,
             // Import subcommand
             address: None,
             mnemonic: None,
@@ -260,27 +481,54 @@ impl Default for MoneroOptions {
             private_view_key: None,
             public_spend_key: None,
             public_view_key: None,
+            // Paper subcommand
+            paper_file: None,
+            // URI subcommand
+            uri: None,
+            uri_amount: None,
+            uri_description: None,
+            uri_payment_id: None,
+            uri_recipient_name: None,
         }
     }
 }
 
 impl MoneroOptions {
-    fn parse(&mut self, arguments: &ArgMatches, options: &[&str]) {
-        options.iter().for_each(|option| match *option {
-            "address" => self.address(arguments.value_of(option)),
-            "count" => self.count(clap::value_t!(arguments.value_of(*option), usize).ok()),
-            "integrated" => self.integrated(arguments.value_of(option)),
-            "json" => self.json(arguments.is_present(option)),
-            "language" => self.language(arguments.value_of(option)),
-            "mnemonic" => self.mnemonic(arguments.value_of(option)),
-            "network" => self.network(arguments.value_of(option)),
-            "private spend" => self.private_spend(arguments.value_of(option)),
-            "private view" => self.private_view(arguments.value_of(option)),
-            "public spend" => self.public_spend(arguments.value_of(option)),
-            "public view" => self.public_view(arguments.value_of(option)),
-            "subaddress" => self.subaddress(arguments.values_of(option)),
-            _ => (),
-        });
+    fn parse(&mut self, arguments: &ArgMatches, options: &[&str]) -> Result<(), CLIError> {
+        for option in options {
+            match *option {
+                "address" => self.address(arguments.value_of(option)),
+                "audit rng" => self.audit_rng(arguments.is_present(option)),
+                "count" => self.count(clap::value_t!(arguments.value_of(*option), usize).ok()),
+                "index range" => {
+                    self.index_range(arguments.value_of(option))?;
+                }
+                "integrated" => self.integrated(arguments.value_of(option)),
+                "json" => self.json(arguments.is_present(option)),
+                "language" => self.language(arguments.value_of(option)),
+                "mnemonic" => self.mnemonic(value_or_stdin(arguments, option).as_deref()),
+                "network" => self.network(arguments.value_of(option)),
+                "paper file" => self.paper_file(arguments.value_of(option)),
+                "private spend" => self.private_spend(arguments.value_of(option)),
+                "private view" => self.private_view(arguments.value_of(option)),
+                "public spend" => self.public_spend(arguments.value_of(option)),
+                "public view" => self.public_view(arguments.value_of(option)),
+                "output file" => self.output_file(arguments.value_of(option)),
+                "output format" => self.output_format(arguments.value_of(option)),
+                "public only" => self.public_only(arguments.is_present(option)),
+                "qr" => self.qr(arguments.is_present(option)),
+                "qr file" => self.qr_file(arguments.value_of(option)),
+                "subaddress" => self.subaddress(arguments.values_of(option)),
+                "uri" => self.uri(arguments.value_of(option)),
+                "uri amount" => self.uri_amount(arguments.value_of(option)),
+                "uri description" => self.uri_description(arguments.value_of(option)),
+                "uri payment id" => self.uri_payment_id(arguments.value_of(option)),
+                "uri recipient name" => self.uri_recipient_name(arguments.value_of(option)),
+                "view only" => self.view_only(arguments.is_present(option)),
+                _ => (),
+            };
+        }
+        Ok(())
     }
 
     /// Sets `address` to the specified address, overriding its previous state.
@@ -291,6 +539,14 @@ impl MoneroOptions {
         }
     }
 
+    /// Enables `audit_rng` if the specified boolean value is set.
+    /// Once enabled at any level of the command, the entropy drawn remains audited.
+    fn audit_rng(&mut self, argument: bool) {
+        if argument {
+            self.audit_rng = true;
+        }
+    }
+
     /// Sets `count` to the specified count, overriding its previous state.
     fn count(&mut self, argument: Option<usize>) {
         if let Some(count) = argument {
@@ -298,6 +554,17 @@ impl MoneroOptions {
         }
     }
 
+    /// Sets `index_range` to the specified range of subaddress minor indices, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    /// Returns the range that was set, or an error if the argument is not a non-empty `start..end` range.
+    fn index_range(&mut self, argument: Option<&str>) -> Result<Option<(u32, u32)>, CLIError> {
+        let index_range = argument.map(parse_index_range).transpose()?;
+        if index_range.is_some() {
+            self.index_range = index_range;
+        }
+        Ok(index_range)
+    }
+
     /// Sets `format` to an integrated address with the specified payment ID, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn integrated(&mut self, argument: Option<&str>) {
@@ -308,9 +575,12 @@ impl MoneroOptions {
         }
     }
 
-    /// Sets `json` to the specified boolean value, overriding its previous state.
+    /// Sets `output_format` to JSON if the specified boolean value is set.
+    /// Once enabled at any level of the command, JSON remains the output format.
     fn json(&mut self, argument: bool) {
-        self.json = argument;
+        if argument {
+            self.output_format = "json".into();
+        }
     }
 
     /// Sets `language` to the specified language, overriding its previous state.
@@ -353,6 +623,32 @@ impl MoneroOptions {
         };
     }
 
+    /// Sets `output_file` to the specified file path, overriding its previous state.
+    /// Colored output is disabled, as the file is not a terminal.
+    /// If the specified argument is `None`, then no change occurs.
+    fn output_file(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.output_file = Some(path.to_string());
+            colored::control::set_override(false);
+        }
+    }
+
+    /// Sets `output_format` to the specified output format, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn output_format(&mut self, argument: Option<&str>) {
+        if let Some(output_format) = argument {
+            self.output_format = output_format.to_string();
+        }
+    }
+
+    /// Sets `paper_file` to the specified file path, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn paper_file(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.paper_file = Some(path.to_string());
+        }
+    }
+
     /// Sets `private_spend_key` to the specified private spend key, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn private_spend(&mut self, argument: Option<&str>) {
@@ -385,6 +681,27 @@ impl MoneroOptions {
         }
     }
 
+    /// Enables `public_only` if the specified boolean value is set.
+    /// Once enabled at any level of the command, the secrets of the wallet(s) remain withheld.
+    fn public_only(&mut self, argument: bool) {
+        if argument {
+            self.public_only = true;
+        }
+    }
+
+    /// Sets `qr` to the specified boolean value, overriding its previous state.
+    fn qr(&mut self, argument: bool) {
+        self.qr = argument;
+    }
+
+    /// Sets `qr_file` to the specified file path, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn qr_file(&mut self, argument: Option<&str>) {
+        if let Some(qr_file) = argument {
+            self.qr_file = Some(qr_file.to_string());
+        }
+    }
+
     /// Sets `subaddress` to the specified subaddress indices, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn subaddress(&mut self, arguments: Option<Values>) {
@@ -396,6 +713,51 @@ impl MoneroOptions {
             self.format = MoneroFormat::Subaddress(index[0], index[1]);
         }
     }
+
+    /// Sets `uri` to the specified payment request URI, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn uri(&mut self, argument: Option<&str>) {
+        if let Some(uri) = argument {
+            self.uri = Some(uri.to_string());
+        }
+    }
+
+    /// Sets `uri_amount` to the specified amount of a payment request, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn uri_amount(&mut self, argument: Option<&str>) {
+        if let Some(amount) = argument {
+            self.uri_amount = Some(amount.to_string());
+        }
+    }
+
+    /// Sets `uri_description` to the specified description of a payment request, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn uri_description(&mut self, argument: Option<&str>) {
+        if let Some(description) = argument {
+            self.uri_description = Some(description.to_string());
+        }
+    }
+
+    /// Sets `uri_payment_id` to the specified payment ID of a payment request, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn uri_payment_id(&mut self, argument: Option<&str>) {
+        if let Some(payment_id) = argument {
+            self.uri_payment_id = Some(payment_id.to_string());
+        }
+    }
+
+    /// Sets `uri_recipient_name` to the specified recipient name of a payment request, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn uri_recipient_name(&mut self, argument: Option<&str>) {
+        if let Some(recipient_name) = argument {
+            self.uri_recipient_name = Some(recipient_name.to_string());
+        }
+    }
+
+    /// Sets `view_only` to the specified boolean value, overriding its previous state.
+    fn view_only(&mut self, argument: bool) {
+        self.view_only = argument;
+    }
 }
 
 pub struct MoneroCLI;
@@ -405,7 +767,15 @@ impl CLI for MoneroCLI {
 
     const NAME: NameType = "monero";
     const ABOUT: AboutType = "Generates a Monero wallet (include -h for more options)";
-    const FLAGS: &'static [FlagType] = &[flag::JSON];
+    const FLAGS: &'static [FlagType] = &[
+        flag::AUDIT_RNG,
+        flag::JSON,
+        flag::OUTPUT_FILE,
+        flag::OUTPUT_FORMAT,
+        flag::PUBLIC_ONLY,
+        flag::QR,
+        flag::QR_FILE,
+    ];
     const OPTIONS: &'static [OptionType] = &[
         option::COUNT,
         option::INTEGRATED_MONERO,
@@ -413,7 +783,14 @@ impl CLI for MoneroCLI {
         option::NETWORK_MONERO,
         option::SUBADDRESS_MONERO,
     ];
-    const SUBCOMMANDS: &'static [SubCommandType] = &[subcommand::IMPORT_MONERO];
+    const SUBCOMMANDS: &'static [SubCommandType] = &[
+        subcommand::HD_MONERO,
+        subcommand::IMPORT_MONERO,
+        subcommand::IMPORT_HD_MONERO,
+        subcommand::PAPER_MONERO,
+        subcommand::URI_MONERO,
+        subcommand::VALIDATE_MONERO,
+    ];
 
     /// Handle all CLI arguments and flags for Monero
     #[cfg_attr(tarpaulin, skip)]
@@ -422,23 +799,62 @@ impl CLI for MoneroCLI {
         options.parse(
             arguments,
             &[
+                "audit rng",
                 "count",
                 "format",
                 "integrated",
                 "json",
                 "language",
                 "network",
+                "output file",
+                "output format",
+                "public only",
+                "qr",
+                "qr file",
                 "subaddress",
             ],
-        );
+        )?;
 
         match arguments.subcommand() {
+            ("hd", Some(arguments)) => {
+                options.subcommand = Some("hd".into());
+                options.parse(
+                    arguments,
+                    &[
+                        "audit rng",
+                        "count",
+                        "integrated",
+                        "json",
+                        "language",
+                        "network",
+                        "output file",
+                        "output format",
+                        "public only",
+                        "qr",
+                        "qr file",
+                        "subaddress",
+                    ],
+                )?;
+                options.parse(arguments, &["index range"])?;
+            }
             ("import", Some(arguments)) => {
                 options.subcommand = Some("import".into());
                 options.parse(
                     arguments,
-                    &["format", "integrated", "json", "language", "network", "subaddress"],
-                );
+                    &[
+                        "format",
+                        "integrated",
+                        "json",
+                        "language",
+                        "network",
+                        "output file",
+                        "output format",
+                        "public only",
+                        "qr",
+                        "qr file",
+                        "subaddress",
+                    ],
+                )?;
                 options.parse(
                     arguments,
                     &[
@@ -449,7 +865,80 @@ impl CLI for MoneroCLI {
                         "public spend",
                         "public view",
                     ],
-                );
+                )?;
+            }
+            ("import-hd", Some(arguments)) => {
+                options.subcommand = Some("import-hd".into());
+                options.parse(
+                    arguments,
+                    &[
+                        "integrated",
+                        "json",
+                        "language",
+                        "network",
+                        "output file",
+                        "output format",
+                        "public only",
+                        "qr",
+                        "qr file",
+                        "subaddress",
+                    ],
+                )?;
+                options.parse(arguments, &["index range", "mnemonic", "private spend", "view only"])?;
+            }
+            ("paper", Some(arguments)) => {
+                options.subcommand = Some("paper".into());
+                options.parse(arguments, &["audit rng", "count", "language", "network"])?;
+                options.parse(arguments, &["paper file"])?;
+            }
+            ("uri", Some(arguments)) => match arguments.subcommand() {
+                ("create", Some(arguments)) => {
+                    options.subcommand = Some("uri create".into());
+                    options.parse(
+                        arguments,
+                        &[
+                            "json",
+                            "network",
+                            "output file",
+                            "output format",
+                            "public only",
+                            "qr",
+                            "qr file",
+                        ],
+                    )?;
+                    options.parse(
+                        arguments,
+                        &[
+                            "address",
+                            "uri amount",
+                            "uri description",
+                            "uri payment id",
+                            "uri recipient name",
+                        ],
+                    )?;
+                }
+                ("parse", Some(arguments)) => {
+                    options.subcommand = Some("uri parse".into());
+                    options.parse(
+                        arguments,
+                        &[
+                            "json",
+                            "network",
+                            "output file",
+                            "output format",
+                            "public only",
+                            "qr",
+                            "qr file",
+                        ],
+                    )?;
+                    options.parse(arguments, &["uri"])?;
+                }
+                _ => {}
+            },
+            ("validate", Some(arguments)) => {
+                options.subcommand = Some("validate".into());
+                options.parse(arguments, &["json", "output file", "output format", "public only"])?;
+                options.parse(arguments, &["address"])?;
             }
             _ => {}
         };
@@ -461,70 +950,223 @@ impl CLI for MoneroCLI {
     #[cfg_attr(tarpaulin, skip)]
     fn print(options: Self::Options) -> Result<(), CLIError> {
         fn output<N: MoneroNetwork, W: MoneroWordlist>(options: MoneroOptions) -> Result<(), CLIError> {
-            let wallets =
-                match options.subcommand.as_ref().map(String::as_str) {
-                    Some("import") => {
-                        if let Some(mnemonic) = options.mnemonic {
-                            vec![
-                                MoneroWallet::from_mnemonic::<N, ChineseSimplified>(&mnemonic, &options.format)
-                                    .or(MoneroWallet::from_mnemonic::<N, Dutch>(&mnemonic, &options.format))
-                                    .or(MoneroWallet::from_mnemonic::<N, English>(&mnemonic, &options.format))
-                                    .or(MoneroWallet::from_mnemonic::<N, EnglishOld>(&mnemonic, &options.format))
-                                    .or(MoneroWallet::from_mnemonic::<N, Esperanto>(&mnemonic, &options.format))
-                                    .or(MoneroWallet::from_mnemonic::<N, French>(&mnemonic, &options.format))
-                                    .or(MoneroWallet::from_mnemonic::<N, German>(&mnemonic, &options.format))
-                                    .or(MoneroWallet::from_mnemonic::<N, Italian>(&mnemonic, &options.format))
-                                    .or(MoneroWallet::from_mnemonic::<N, Japanese>(&mnemonic, &options.format))
-                                    .or(MoneroWallet::from_mnemonic::<N, Lojban>(&mnemonic, &options.format))
-                                    .or(MoneroWallet::from_mnemonic::<N, Portuguese>(&mnemonic, &options.format))
-                                    .or(MoneroWallet::from_mnemonic::<N, Russian>(&mnemonic, &options.format))
-                                    .or(MoneroWallet::from_mnemonic::<N, Spanish>(&mnemonic, &options.format))?,
-                            ]
-                        } else if let Some(private_spend_key) = options.private_spend_key {
-                            vec![MoneroWallet::from_private_spend_key::<N, W>(
-                                &private_spend_key,
-                                &options.format,
-                            )?]
-                        } else if let Some(private_view_key) = options.private_view_key {
-                            vec![MoneroWallet::from_private_view_key::<N>(
-                                &private_view_key,
+            if let Some("validate") = options.subcommand.as_deref() {
+                return validate(options);
+            }
+
+            // The formats of HD wallets, with one subaddress of the account per minor index of the index range
+            let formats = match (options.index_range, options.format) {
+                (Some((start, end)), MoneroFormat::Subaddress(major, _)) => (start..end)
+                    .map(|minor| MoneroFormat::Subaddress(major, minor))
+                    .collect(),
+                (Some((start, end)), _) => (start..end).map(|minor| MoneroFormat::Subaddress(0, minor)).collect(),
+                (None, format) => vec![format],
+            };
+            let formats = &formats;
+
+            // Generate new wallets lazily, to stream a large count of wallets to the output
+            let format = &options.format;
+            let mut rng = wallet_rng(options.audit_rng);
+            let generated: Box<dyn Iterator<Item = MoneroWallet>> = match options.subcommand.as_deref() {
+                Some("hd") => Box::new((0..options.count).flat_map(move |_| {
+                    match MoneroMnemonic::<N, W>::new(&mut rng) {
+                        Ok(mnemonic) => formats
+                            .iter()
+                            .filter_map(|format| MoneroWallet::from_hd_mnemonic(&mnemonic, format).ok())
+                            .collect(),
+                        _ => vec![],
+                    }
+                })),
+                None | Some("paper") => Box::new((0..options.count).flat_map(move |_| {
+                    match MoneroWallet::new::<N, W, _>(&mut rng, format) {
+                        Ok(wallet) => vec![wallet],
+                        _ => vec![],
+                    }
+                })),
+                _ => Box::new(iter::empty()),
+            };
+
+            let wallets = match options.subcommand.as_ref().map(String::as_str) {
+                Some("import") => {
+                    if let Some(mnemonic) = options.mnemonic {
+                        vec![import_mnemonic::<N>(&mnemonic, &options.format)?]
+                    } else if let Some(private_spend_key) = options.private_spend_key {
+                        vec![MoneroWallet::from_private_spend_key::<N, W>(
+                            &private_spend_key,
+                            &options.format,
+                        )?]
+                    } else if let Some(private_view_key) = options.private_view_key {
+                        vec![MoneroWallet::from_private_view_key::<N>(
+                            &private_view_key,
+                            &options.format,
+                        )?]
+                    } else if let Some(public_spend_key) = options.public_spend_key {
+                        if let Some(public_view_key) = options.public_view_key {
+                            vec![MoneroWallet::from_public_key::<N>(
+                                &public_spend_key,
+                                &public_view_key,
                                 &options.format,
                             )?]
-                        } else if let Some(public_spend_key) = options.public_spend_key {
-                            if let Some(public_view_key) = options.public_view_key {
-                                vec![MoneroWallet::from_public_key::<N>(
-                                    &public_spend_key,
-                                    &public_view_key,
-                                    &options.format,
-                                )?]
-                            } else {
-                                vec![]
-                            }
-                        } else if let Some(address) = options.address {
-                            vec![MoneroWallet::from_address::<MoneroMainnet>(&address)
-                                .or(MoneroWallet::from_address::<MoneroTestnet>(&address))?]
                         } else {
                             vec![]
                         }
+                    } else if let Some(address) = options.address {
+                        vec![MoneroWallet::from_address::<MoneroMainnet>(&address)
+                            .or(MoneroWallet::from_address::<MoneroTestnet>(&address))
+                            .or(MoneroWallet::from_address::<MoneroStagenet>(&address))?]
+                    } else {
+                        vec![]
                     }
-                    _ => (0..options.count)
-                        .flat_map(|_| {
-                            match MoneroWallet::new::<N, W, _>(&mut StdRng::from_entropy(), &options.format) {
-                                Ok(wallet) => vec![wallet],
-                                _ => vec![],
-                            }
-                        })
-                        .collect(),
-                };
+                }
+                Some("import-hd") => {
+                    let wallets = if let Some(mnemonic) = options.mnemonic {
+                        formats
+                            .iter()
+                            .map(|format| import_mnemonic::<N>(&mnemonic, format))
+                            .collect::<Result<Vec<MoneroWallet>, CLIError>>()?
+                    } else if let Some(private_spend_key) = options.private_spend_key {
+                        formats
+                            .iter()
+                            .map(|format| MoneroWallet::from_hd_private_spend_key::<N, W>(&private_spend_key, format))
+                            .collect::<Result<Vec<MoneroWallet>, CLIError>>()?
+                    } else {
+                        vec![]
+                    };
 
-            match options.json {
-                true => println!("{}\n", serde_json::to_string_pretty(&wallets)?),
-                false => wallets.iter().for_each(|wallet| println!("{}\n", wallet)),
+                    match options.view_only {
+                        true => wallets
+                            .into_iter()
+                            .zip(formats)
+                            .map(|(wallet, format)| wallet.into_view_only::<N>(format))
+                            .collect::<Result<Vec<MoneroWallet>, CLIError>>()?,
+                        false => wallets,
+                    }
+                }
+                Some("uri create") => match options.address.as_ref() {
+                    Some(address) => vec![MoneroWallet::to_uri::<N>(
+                        address,
+                        options.uri_payment_id.as_deref(),
+                        options.uri_recipient_name.as_deref(),
+                        options.uri_amount.as_deref(),
+                        options.uri_description.as_deref(),
+                    )?],
+                    None => vec![],
+                },
+                Some("uri parse") => match options.uri.as_ref() {
+                    Some(uri) => vec![MoneroWallet::from_uri(&MoneroUri::<N>::from_str(uri)?)?],
+                    None => vec![],
+                },
+                _ => vec![],
+            };
+
+            let wallets = wallets.into_iter().chain(generated);
+
+            match options.paper_file.as_deref() {
+                Some(path) => {
+                    if options.public_only {
+                        return Err(CLIError::UnsupportedFormat("paper wallet with --public-only".into()));
+                    }
+                    let mut wallets = wallets.collect::<Vec<MoneroWallet>>();
+                    let paper_wallets = wallets
+                        .iter()
+                        .filter_map(MoneroWallet::to_paper_wallet)
+                        .collect::<Vec<PaperWallet>>();
+                    paper::output("Monero", &paper_wallets, path)?;
+                    wallets.iter_mut().for_each(Zeroize::zeroize);
+                }
+                None => {
+                    let path = options.output_file.as_deref();
+                    let mut writer = WalletWriter::new(&options.output_format, path, MoneroWallet::FIELDS)?;
+                    for (index, mut wallet) in wallets.enumerate() {
+                        if options.public_only {
+                            wallet.public_only();
+                        }
+                        writer.write(&wallet)?;
+                        if let Some(qr_data) = wallet.to_qr_data() {
+                            qr::output(qr_data, index, options.qr, options.qr_file.as_deref())?;
+                        }
+                        wallet.zeroize();
+                    }
+                    writer.finish()?;
+                }
             };
 
             Ok(())
         }
 
+        /// Imports the wallet of the mnemonic, in the language of its wordlist
+        fn import_mnemonic<N: MoneroNetwork>(mnemonic: &str, format: &MoneroFormat) -> Result<MoneroWallet, CLIError> {
+            MoneroWallet::from_mnemonic::<N, ChineseSimplified>(mnemonic, format)
+                .or(MoneroWallet::from_mnemonic::<N, Dutch>(mnemonic, format))
+                .or(MoneroWallet::from_mnemonic::<N, English>(mnemonic, format))
+                .or(MoneroWallet::from_mnemonic::<N, EnglishOld>(mnemonic, format))
+                .or(MoneroWallet::from_mnemonic::<N, Esperanto>(mnemonic, format))
+                .or(MoneroWallet::from_mnemonic::<N, French>(mnemonic, format))
+                .or(MoneroWallet::from_mnemonic::<N, German>(mnemonic, format))
+                .or(MoneroWallet::from_mnemonic::<N, Italian>(mnemonic, format))
+                .or(MoneroWallet::from_mnemonic::<N, Japanese>(mnemonic, format))
+                .or(MoneroWallet::from_mnemonic::<N, Lojban>(mnemonic, format))
+                .or(MoneroWallet::from_mnemonic::<N, Portuguese>(mnemonic, format))
+                .or(MoneroWallet::from_mnemonic::<N, Russian>(mnemonic, format))
+                .or(MoneroWallet::from_mnemonic::<N, Spanish>(mnemonic, format))
+        }
+
+        /// Validates the address, printing its network, format, checksum, and payload
+        fn validate(options: MoneroOptions) -> Result<(), CLIError> {
+            let address = options.address.as_deref().unwrap_or_default();
+            let mut diagnostics = AddressDiagnostics::new(address);
+
+            let bytes = match base58_monero::decode(address) {
+                Ok(bytes) if bytes.len() == 69 || bytes.len() == 77 => bytes,
+                Ok(bytes) => {
+                    diagnostics.error(AddressError::InvalidByteLength(bytes.len()));
+                    vec![]
+                }
+                Err(error) => {
+                    diagnostics.error(AddressError::from(error));
+                    vec![]
+                }
+            };
+
+            if !bytes.is_empty() {
+                // Decode the checksum, and the public spend key, public view key, and payment id of the payload
+                let (data, checksum) = bytes.split_at(bytes.len() - 4);
+                diagnostics.checksum = Some(keccak256(data)[0..4] == *checksum);
+                diagnostics.payload = Some(hex::encode(&data[1..]));
+
+                diagnostics.network = match data[0] {
+                    prefix if MoneroMainnet::from_address_prefix(prefix).is_ok() => Some(MoneroMainnet::NAME.into()),
+                    prefix if MoneroTestnet::from_address_prefix(prefix).is_ok() => Some(MoneroTestnet::NAME.into()),
+                    prefix if MoneroStagenet::from_address_prefix(prefix).is_ok() => Some(MoneroStagenet::NAME.into()),
+                    _ => None,
+                };
+
+                // Decode the address, to verify its prefix, length, checksum, and public keys
+                match MoneroDecodedAddress::decode(address) {
+                    Ok(decoded) => {
+                        diagnostics.format = Some(
+                            match decoded.format {
+                                MoneroFormat::Standard => "standard",
+                                MoneroFormat::Integrated(_) => "integrated",
+                                MoneroFormat::Subaddress(_, _) => "subaddress",
+                            }
+                            .into(),
+                        );
+                        diagnostics.public_spend_key = Some(hex::encode(decoded.public_spend_key));
+                        diagnostics.public_view_key = Some(hex::encode(decoded.public_view_key));
+                        diagnostics.payment_id = decoded.to_payment_id().map(hex::encode);
+                    }
+                    Err(error) => diagnostics.error(error),
+                };
+            }
+
+            diagnostics::output(
+                &diagnostics.finish(),
+                &options.output_format,
+                options.output_file.as_deref(),
+            )
+        }
+
         match options.language.as_str() {
             "chinese_simplified" => match options.network.as_str() {
                 "testnet" => output::<MoneroTestnet, ChineseSimplified>(options),
@@ -0,0 +1,225 @@
+use crate::cli::CLIError;
+
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt::Display;
+use std::io::{BufWriter, Write};
+use zeroize::Zeroize;
+
+use crate::model::no_std::{format, String, ToString, Vec};
+
+/// Represents a format to output wallets
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum OutputFormat {
+    /// One row of comma-separated values per wallet, following a header of the field names
+    Csv,
+    /// A pretty-printed JSON array of wallets
+    Json,
+    /// One JSON object per line per wallet
+    Ndjson,
+    /// The human-readable display of each wallet
+    Text,
+}
+
+/// Writes wallets one at a time in an output format, to a file or to stdout,
+/// so that a large number of wallets is streamed rather than buffered in memory.
+pub struct WalletWriter {
+    format: OutputFormat,
+    fields: &'static [&'static str],
+    output: Box<dyn Write>,
+    count: usize,
+}
+
+impl WalletWriter {
+    /// Returns a new wallet writer for the specified output format (`csv`, `json`, `ndjson`, or `text`),
+    /// writing to the specified file, or to stdout if no file is specified.
+    /// The specified fields are the CSV columns, in order, and are written as the header of a CSV file.
+    pub fn new(format: &str, path: Option<&str>, fields: &'static [&'static str]) -> Result<Self, CLIError> {
+        let format = match format {
+            "csv" => OutputFormat::Csv,
+            "json" => OutputFormat::Json,
+            "ndjson" => OutputFormat::Ndjson,
+            "text" => OutputFormat::Text,
+            format => return Err(CLIError::UnsupportedFormat(format!("output format {}", format))),
+        };
+        let mut writer = Self {
+            format,
+            fields,
+            output: open(path)?,
+            count: 0,
+        };
+        if format == OutputFormat::Csv {
+            let header = fields.iter().map(|field| field.to_string()).collect::<Vec<String>>();
+            writer.output.write_all(to_csv_row(&header).as_bytes())?;
+        }
+        Ok(writer)
+    }
+
+    /// Writes the specified wallet in the output format.
+    pub fn write<W: Display + Serialize>(&mut self, wallet: &W) -> Result<(), CLIError> {
+        let mut output = match self.format {
+            OutputFormat::Csv => {
+                let value = serde_json::to_value(wallet)?;
+                let row = self
+                    .fields
+                    .iter()
+                    .map(|field| match value.get(field) {
+                        None | Some(Value::Null) => String::new(),
+                        Some(Value::String(string)) => string.clone(),
+                        Some(value) => value.to_string(),
+                    })
+                    .collect::<Vec<String>>();
+                to_csv_row(&row)
+            }
+            OutputFormat::Json => {
+                // Indent each wallet as an element of a pretty-printed array
+                let mut wallet = serde_json::to_string_pretty(wallet)?;
+                let element = format!(
+                    "{}  {}",
+                    if self.count == 0 { "[\n" } else { ",\n" },
+                    wallet.replace('\n', "\n  ")
+                );
+                wallet.zeroize();
+                element
+            }
+            OutputFormat::Ndjson => format!("{}\n", serde_json::to_string(wallet)?),
+            OutputFormat::Text => format!("{}\n\n", wallet),
+        };
+        let result = self.output.write_all(output.as_bytes());
+        output.zeroize();
+        self.count += 1;
+        Ok(result?)
+    }
+
+    /// Completes the output, closing the array of a JSON output, and flushes it.
+    pub fn finish(mut self) -> Result<(), CLIError> {
+        if self.format == OutputFormat::Json {
+            let end = if self.count == 0 { "[]\n\n" } else { "\n]\n\n" };
+            self.output.write_all(end.as_bytes())?;
+        }
+        self.output.flush()?;
+        Ok(())
+    }
+}
+
+/// Writes the specified output followed by a blank line to the specified file,
+/// or prints it to stdout if no file is specified.
+pub fn write_output(output: &str, path: Option<&str>) -> Result<(), CLIError> {
+    let mut writer = open(path)?;
+    writer.write_all(output.as_bytes())?;
+    writer.write_all(b"\n\n")?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Writes the specified secret to the specified file, readable and writable by its owner only.
+pub fn write_secret(secret: &str, path: &str) -> Result<(), CLIError> {
+    let mut writer = open(Some(path))?;
+    writer.write_all(secret.as_bytes())?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Returns a writer to the specified file, or to stdout if no file is specified.
+/// As the output may contain secrets, the file is created with permissions
+/// to be read and written by its owner only.
+fn open(path: Option<&str>) -> Result<Box<dyn Write>, CLIError> {
+    match path {
+        Some(path) => {
+            let mut file = std::fs::OpenOptions::new();
+            file.write(true).create(true).truncate(true);
+            #[cfg(unix)]
+            std::os::unix::fs::OpenOptionsExt::mode(&mut file, 0o600);
+            Ok(Box::new(BufWriter::new(file.open(path)?)))
+        }
+        None => Ok(Box::new(std::io::stdout())),
+    }
+}
+
+/// Returns the specified values as a CSV row, quoting each value that contains a separator,
+/// quote, or line break.
+fn to_csv_row(values: &[String]) -> String {
+    let row = values
+        .iter()
+        .map(|value| match value.contains(&[',', '"', '\n', '\r'][..]) {
+            true => format!("\"{}\"", value.replace('"', "\"\"")),
+            false => value.clone(),
+        })
+        .collect::<Vec<String>>()
+        .join(",");
+    format!("{}\n", row)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde::Serialize;
+    use std::fmt;
+
+    #[derive(Serialize)]
+    struct TestWallet {
+        address: &'static str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        private_key: Option<&'static str>,
+        compressed: bool,
+    }
+
+    impl Display for TestWallet {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "Address {}", self.address)
+        }
+    }
+
+    const FIELDS: &[&str] = &["private_key", "address", "compressed"];
+
+    fn to_output(format: &str, wallets: &[TestWallet]) -> String {
+        let path = std::env::temp_dir().join(format!("wagyu-output-test.{}", format));
+        let mut writer = WalletWriter::new(format, path.to_str(), FIELDS).unwrap();
+        for wallet in wallets {
+            writer.write(wallet).unwrap();
+        }
+        writer.finish().unwrap();
+        let output = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        output
+    }
+
+    #[test]
+    fn output_formats() {
+        let wallets = [
+            TestWallet {
+                address: "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2",
+                private_key: None,
+                compressed: true,
+            },
+            TestWallet {
+                address: "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy",
+                private_key: Some("a,\"b\""),
+                compressed: false,
+            },
+        ];
+
+        assert_eq!(
+            "private_key,address,compressed\n\
+             ,1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2,true\n\
+             \"a,\"\"b\"\"\",3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy,false\n",
+            to_output("csv", &wallets)
+        );
+        assert_eq!(
+            format!("{}\n\n", serde_json::to_string_pretty(&wallets).unwrap()),
+            to_output("json", &wallets)
+        );
+        assert_eq!("[]\n\n", to_output("json", &[]));
+        assert_eq!(
+            "{\"address\":\"1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2\",\"compressed\":true}\n\
+             {\"address\":\"3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy\",\"private_key\":\"a,\\\"b\\\"\",\"compressed\":false}\n",
+            to_output("ndjson", &wallets)
+        );
+        assert_eq!(
+            "Address 1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2\n\nAddress 3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy\n\n",
+            to_output("text", &wallets)
+        );
+        assert!(WalletWriter::new("xml", None, FIELDS).is_err());
+    }
+}
@@ -0,0 +1,275 @@
+use crate::cli::CLIError;
+
+use pdf_writer::{Content, Name, Pdf, Rect, Ref, Str};
+use qrcode::render::svg;
+use qrcode::{Color, QrCode};
+use std::path::Path;
+
+use crate::model::no_std::{format, vec, String, ToString, Vec};
+
+/// The HTML template of a paper wallet document
+const TEMPLATE: &str = include_str!("paper.html");
+
+/// The width and height of an A4 page, in points
+const PAGE_DIMENSIONS: (f32, f32) = (595.0, 842.0);
+/// The margin of a page, in points
+const PAGE_MARGIN: f32 = 50.0;
+/// The width and height of a QR code on a page, in points
+const QR_DIMENSION: f32 = 200.0;
+/// The maximum number of characters of a field value on one line of a page
+const LINE_LENGTH: usize = 88;
+
+/// Represents a wallet to render on paper
+pub struct PaperWallet<'a> {
+    /// The address of the wallet, to receive funds
+    pub address: &'a str,
+    /// The label and value of the secret of the wallet, e.g. its mnemonic or private key, to spend funds
+    pub secret: (&'static str, &'a str),
+    /// The labels and values of the remaining fields of the wallet, e.g. its derivation path
+    pub fields: Vec<(&'static str, &'a str)>,
+}
+
+/// Renders the given wallets as a printable paper wallet document, with one page per wallet,
+/// and writes it to an HTML or PDF file by the file extension.
+pub fn output(currency: &str, wallets: &[PaperWallet], path: &str) -> Result<(), CLIError> {
+    let title = format!("{} Paper Wallet", currency);
+    let document = match Path::new(path).extension().and_then(|extension| extension.to_str()) {
+        Some("html") => to_html(&title, wallets)?.into_bytes(),
+        Some("pdf") => to_pdf(&title, wallets)?,
+        _ => return Err(CLIError::UnsupportedFormat(format!("paper wallet file {}", path))),
+    };
+    std::fs::write(path, document)?;
+    Ok(())
+}
+
+/// Returns the paper wallet document in HTML, rendered with the embedded template.
+fn to_html(title: &str, wallets: &[PaperWallet]) -> Result<String, CLIError> {
+    let mut sections = String::new();
+    for wallet in wallets {
+        let (label, secret) = wallet.secret;
+        sections += "<section class=\"wallet\">\n";
+        sections += &format!("  <h1>{}</h1>\n", escape(title));
+        sections += "  <div class=\"codes\">\n";
+        sections += &format!(
+            "    <figure>{}<figcaption>Address</figcaption></figure>\n",
+            to_svg(wallet.address)?
+        );
+        sections += &format!(
+            "    <figure>{}<figcaption>{}</figcaption></figure>\n",
+            to_svg(secret)?,
+            label
+        );
+        sections += "  </div>\n  <dl>\n";
+        for (label, value) in [("Address", wallet.address), wallet.secret]
+            .iter()
+            .chain(wallet.fields.iter())
+        {
+            sections += &format!("    <dt>{}</dt><dd>{}</dd>\n", label, escape(value));
+        }
+        sections += "  </dl>\n</section>\n";
+    }
+
+    Ok(TEMPLATE
+        .replace("{{title}}", &escape(title))
+        .replace("{{wallets}}", sections.trim_end()))
+}
+
+/// Returns the paper wallet document in PDF, using the standard fonts of PDF readers.
+fn to_pdf(title: &str, wallets: &[PaperWallet]) -> Result<Vec<u8>, CLIError> {
+    let (width, height) = PAGE_DIMENSIONS;
+    let catalog_id = Ref::new(1);
+    let page_tree_id = Ref::new(2);
+    let fonts = [
+        (Name(b"F1"), Ref::new(3), Name(b"Helvetica-Bold")),
+        (Name(b"F2"), Ref::new(4), Name(b"Courier")),
+    ];
+    let (bold, monospace) = (fonts[0].0, fonts[1].0);
+    // Each page is followed by its content stream
+    let page_ids = (0..wallets.len())
+        .map(|index| Ref::new(5 + 2 * index as i32))
+        .collect::<Vec<Ref>>();
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id)
+        .kids(page_ids.iter().copied())
+        .count(wallets.len() as i32);
+    for (_, id, base_font) in fonts.iter() {
+        pdf.type1_font(*id).base_font(*base_font);
+    }
+
+    for (wallet, page_id) in wallets.iter().zip(page_ids) {
+        let (label, secret) = wallet.secret;
+        let content_id = Ref::new(page_id.get() + 1);
+        {
+            let mut page = pdf.page(page_id);
+            page.media_box(Rect::new(0.0, 0.0, width, height));
+            page.parent(page_tree_id);
+            page.contents(content_id);
+            let mut resources = page.resources();
+            let mut page_fonts = resources.fonts();
+            for (name, id, _) in fonts.iter() {
+                page_fonts.pair(*name, *id);
+            }
+        }
+
+        let mut content = Content::new();
+        let mut y = height - PAGE_MARGIN - 20.0;
+        write_text(&mut content, bold, 20.0, PAGE_MARGIN, y, title)?;
+
+        // Draw the QR codes of the address and the secret, side by side
+        y -= 20.0 + QR_DIMENSION;
+        let x = width - PAGE_MARGIN - QR_DIMENSION;
+        draw_qr_code(&mut content, PAGE_MARGIN, y, wallet.address)?;
+        draw_qr_code(&mut content, x, y, secret)?;
+        y -= 15.0;
+        write_text(&mut content, bold, 10.0, PAGE_MARGIN, y, "Address")?;
+        write_text(&mut content, bold, 10.0, x, y, label)?;
+
+        // Write the fields, wrapping long values over multiple lines
+        y -= 20.0;
+        for (label, value) in [("Address", wallet.address), wallet.secret]
+            .iter()
+            .chain(wallet.fields.iter())
+        {
+            y -= 15.0;
+            write_text(&mut content, bold, 10.0, PAGE_MARGIN, y, label)?;
+            for line in wrap(value, LINE_LENGTH) {
+                y -= 12.0;
+                write_text(&mut content, monospace, 9.0, PAGE_MARGIN, y, line)?;
+            }
+        }
+
+        pdf.stream(content_id, &content.finish());
+    }
+
+    Ok(pdf.finish())
+}
+
+/// Draws the given value as a QR code, with its bottom left corner at the given coordinates.
+fn draw_qr_code(content: &mut Content, x: f32, y: f32, value: &str) -> Result<(), CLIError> {
+    let code = QrCode::new(value.as_bytes())?;
+    let modules = code.width();
+    let size = QR_DIMENSION / modules as f32;
+
+    // Fill each horizontal run of dark modules as one rectangle
+    for (row, colors) in code.to_colors().chunks(modules).enumerate() {
+        let top = y + QR_DIMENSION - (row + 1) as f32 * size;
+        let mut column = 0;
+        while column < modules {
+            let start = column;
+            while column < modules && colors[column] == Color::Dark {
+                column += 1;
+            }
+            if column > start {
+                content.rect(x + start as f32 * size, top, (column - start) as f32 * size, size);
+            }
+            column += 1;
+        }
+    }
+    content.set_fill_gray(0.0).fill_nonzero();
+    Ok(())
+}
+
+/// Writes the given line of text, starting at the given coordinates.
+/// The standard fonts of PDF readers are limited to ASCII characters.
+fn write_text(content: &mut Content, font: Name, size: f32, x: f32, y: f32, text: &str) -> Result<(), CLIError> {
+    if !text.is_ascii() {
+        return Err(CLIError::UnsupportedFormat(
+            "non-ASCII text in PDF paper wallet, use an HTML file".to_string(),
+        ));
+    }
+    content.begin_text();
+    content.set_font(font, size);
+    content.next_line(x, y);
+    content.show(Str(text.as_bytes()));
+    content.end_text();
+    Ok(())
+}
+
+/// Returns the lines of the given text, broken at spaces where possible, of at most the given length.
+fn wrap(text: &str, length: usize) -> Vec<&str> {
+    let mut lines = vec![];
+    let mut remainder = text;
+    while remainder.len() > length {
+        let end = match remainder.char_indices().nth(length) {
+            Some((end, _)) => end,
+            None => break,
+        };
+        let (line, next) = match remainder[..=end].rfind(' ') {
+            Some(space) if space > 0 => (&remainder[..space], &remainder[space + 1..]),
+            _ => remainder.split_at(end),
+        };
+        lines.push(line);
+        remainder = next;
+    }
+    lines.push(remainder);
+    lines
+}
+
+/// Returns the given value as an inline SVG image of its QR code.
+fn to_svg(value: &str) -> Result<String, CLIError> {
+    let image = QrCode::new(value.as_bytes())?.render::<svg::Color>().build();
+    // Omit the XML declaration, which is invalid inside an HTML document
+    Ok(match image.find("<svg") {
+        Some(start) => image[start..].to_string(),
+        None => image,
+    })
+}
+
+/// Returns the given text with its HTML special characters escaped.
+fn escape(text: &str) -> String {
+    text.chars()
+        .map(|character| match character {
+            '&' => "&amp;".to_string(),
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            '"' => "&quot;".to_string(),
+            '\'' => "&#39;".to_string(),
+            character => character.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap() {
+        assert_eq!(vec!["abandon"], super::wrap("abandon", 10));
+        assert_eq!(
+            vec!["abandon", "ability", "able"],
+            super::wrap("abandon ability able", 10)
+        );
+        assert_eq!(vec!["abandon ability", "able"], super::wrap("abandon ability able", 15));
+        assert_eq!(vec!["0123456789", "abcdef"], super::wrap("0123456789abcdef", 10));
+    }
+
+    #[test]
+    fn output() {
+        let wallets = [PaperWallet {
+            address: "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2",
+            secret: ("Private Key", "L1uyy5qTuGrVXrmrsvHWHgVzW9kKdrp27wBC7Vs6nZDTF2BRUVwy"),
+            fields: vec![("Format", "p2pkh"), ("Network", "mainnet")],
+        }];
+
+        let html = to_html("Bitcoin Paper Wallet", &wallets).unwrap();
+        assert!(html.contains("<title>Bitcoin Paper Wallet</title>"));
+        assert!(html.contains("<dt>Private Key</dt><dd>L1uyy5qTuGrVXrmrsvHWHgVzW9kKdrp27wBC7Vs6nZDTF2BRUVwy</dd>"));
+        assert!(!html.contains("<?xml"));
+
+        let pdf = to_pdf("Bitcoin Paper Wallet", &wallets).unwrap();
+        assert!(pdf.starts_with(b"%PDF-"));
+
+        assert!(super::output("Bitcoin", &wallets, "wallet.txt").is_err());
+    }
+
+    #[test]
+    fn escape() {
+        assert_eq!(
+            "&lt;a href=&quot;&amp;&#39;&quot;&gt;",
+            super::escape("<a href=\"&'\">")
+        );
+    }
+}
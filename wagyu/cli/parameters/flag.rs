@@ -1,3 +1,16 @@
 // Global
 
+pub const AUDIT_RNG: &str =
+    "[audit rng] --audit-rng 'Logs every draw of entropy used to generate the wallet(s) to stderr'";
 pub const JSON: &str = "[json] -j --json 'Prints the generated wallet(s) in JSON format'";
+pub const OUTPUT_FILE: &str =
+    "[output file] --output-file=[path] 'Writes the generated wallet(s) to a specified file instead of stdout'";
+pub const OUTPUT_FORMAT: &str = "[output format] --output-format=[format] 'Prints the generated wallet(s) in a specified format [possible values: csv, json, ndjson, text]'";
+pub const PUBLIC_ONLY: &str = "[public only] --public-only 'Prints only the public keys and addresses of the generated wallet(s) with a fingerprint, withholding private keys, mnemonics, and extended private keys'";
+pub const QR: &str =
+    "[qr] --qr 'Prints the address, public key, or signed transaction of the generated wallet(s) as a QR code'";
+pub const QR_FILE: &str = "[qr file] --qr-file=[path] 'Writes the address, public key, or signed transaction of the generated wallet(s) as a QR code to a .png or .svg file'";
+
+// Solana
+
+pub const KEYPAIR_FILE_SOLANA: &str = "[keypair file] --keypair-file=[path] 'Writes the private key of the generated wallet(s) as a solana-keygen keypair file (in JSON)'";
@@ -23,10 +23,22 @@ pub const FORMAT_BITCOIN: OptionType = (
     &["bech32", "legacy", "segwit"],
     &[],
 );
+pub const FORMAT_BITCOINCASH: OptionType = (
+    "[format] -f --format=[format] 'Generates a wallet with a specified format'",
+    &[],
+    &["cashaddr", "legacy"],
+    &[],
+);
 pub const FORMAT_ZCASH: OptionType = (
     "[format] -f --format=[format] 'Generates a wallet with a specified format'",
     &[],
-    &["sapling", "sprout", "transparent"],
+    &["sapling", "transparent"],
+    &[],
+);
+pub const HRP_COSMOS: OptionType = (
+    "[hrp] --hrp=[hrp] 'Generates a wallet with a specified bech32 address prefix, as in cosmos, osmo, or juno'",
+    &[],
+    &[],
     &[],
 );
 pub const INTEGRATED_MONERO: OptionType = (
@@ -35,6 +47,12 @@ pub const INTEGRATED_MONERO: OptionType = (
     &[],
     &[],
 );
+pub const KEY_TYPE_RIPPLE: OptionType = (
+    "[key type] -k --key-type=[key type] 'Generates a wallet with a specified key type'",
+    &[],
+    &["ed25519", "secp256k1"],
+    &[],
+);
 pub const LANGUAGE_MONERO: OptionType = (
     "[language] -l --language=[language] 'Generates a wallet with a specified language'",
     &[],
@@ -55,21 +73,51 @@ pub const LANGUAGE_MONERO: OptionType = (
     &[],
 );
 pub const NETWORK_BITCOIN: OptionType = (
+    "[network] -n --network=[network] 'Generates a wallet for a specified network'",
+    &[],
+    &["mainnet", "regtest", "testnet"],
+    &[],
+);
+pub const NETWORK_BITCOINCASH: OptionType = (
+    "[network] -n --network=[network] 'Generates a wallet for a specified network'",
+    &[],
+    &["mainnet", "testnet"],
+    &[],
+);
+pub const NETWORK_DASH: OptionType = (
+    "[network] -n --network=[network] 'Generates a wallet for a specified network'",
+    &[],
+    &["mainnet", "testnet"],
+    &[],
+);
+pub const NETWORK_DOGECOIN: OptionType = (
     "[network] -n --network=[network] 'Generates a wallet for a specified network'",
     &[],
     &["mainnet", "testnet"],
     &[],
 );
+pub const NETWORK_ETHEREUM: OptionType = (
+    "[network] -n --network=[network] 'Generates a wallet for a specified network'",
+    &[],
+    &["goerli", "kovan", "mainnet", "rinkeby", "ropsten"],
+    &[],
+);
 pub const NETWORK_MONERO: OptionType = (
     "[network] -n --network=[network] 'Generates a wallet for a specified network'",
     &[],
     &["mainnet", "stagenet", "testnet"],
     &[],
 );
+pub const NETWORK_RIPPLE: OptionType = (
+    "[network] -n --network=[network] 'Generates a wallet with an X-address for a specified network'",
+    &[],
+    &["mainnet", "testnet"],
+    &[],
+);
 pub const NETWORK_ZCASH: OptionType = (
     "[network] -n --network=[network] 'Generates a wallet for a specified network'",
     &[],
-    &["mainnet", "testnet"],
+    &["mainnet", "regtest", "testnet"],
     &[],
 );
 pub const SUBADDRESS_MONERO: OptionType = (
@@ -78,6 +126,24 @@ pub const SUBADDRESS_MONERO: OptionType = (
     &[],
     &[],
 );
+pub const TAG_RIPPLE: OptionType = (
+    "[tag] -t --tag=[tag] 'Generates a wallet with an X-address for a specified destination tag'",
+    &[],
+    &[],
+    &[],
+);
+pub const UNCOMPRESSED_BITCOIN: OptionType = (
+    "[uncompressed] --uncompressed 'Generates a wallet with an uncompressed public key, in the legacy format'",
+    &[],
+    &[],
+    &[],
+);
+pub const VIEWING_KEY_ZCASH: OptionType = (
+    "[viewing key] --viewing-key 'Prints the full and incoming viewing keys of a Sapling wallet, to watch its address without spending'",
+    &[],
+    &[],
+    &[],
+);
 
 // Import
 
@@ -99,12 +165,30 @@ pub const FORMAT_IMPORT_BITCOIN: OptionType = (
     &["bech32", "legacy", "segwit"],
     &[],
 );
+pub const FORMAT_IMPORT_BITCOINCASH: OptionType = (
+    "[format] -f --format=[format] 'Imports a wallet with a specified format'",
+    &[],
+    &["cashaddr", "legacy"],
+    &[],
+);
+pub const HRP_IMPORT_COSMOS: OptionType = (
+    "[hrp] --hrp=[hrp] 'Imports a wallet with a specified bech32 address prefix, as in cosmos, osmo, or juno'",
+    &["address"],
+    &[],
+    &[],
+);
 pub const INTEGRATED_IMPORT_MONERO: OptionType = (
     "[integrated] -i --integrated=[PaymentID] 'Imports a wallet with a specified payment ID'",
     &["address", "private view", "subaddress"],
     &[],
     &[],
 );
+pub const KEYPAIR_IMPORT_SOLANA: OptionType = (
+    "[keypair] --keypair=[file] 'Imports a wallet for a specified solana-keygen keypair file (in JSON)'",
+    &["address", "count", "private", "public"],
+    &[],
+    &[],
+);
 pub const LANGUAGE_IMPORT_MONERO: OptionType = (
     "[language] -l --language=[language] 'Imports a wallet with a specified mnemonic language (requires private spend key)'",
     &[],
@@ -112,7 +196,7 @@ pub const LANGUAGE_IMPORT_MONERO: OptionType = (
     &["private spend"],
 );
 pub const MNEMONIC_IMPORT_MONERO: OptionType = (
-    "[mnemonic] -m --mnemonic=[\"mnemonic\"] 'Imports a wallet for a specified mnemonic (in quotes)'",
+    "[mnemonic] -m --mnemonic=[\"mnemonic\"] 'Imports a wallet for a specified mnemonic (in quotes) (reads from stdin if no value is specified)'",
     &[
         "address",
         "count",
@@ -125,6 +209,24 @@ pub const MNEMONIC_IMPORT_MONERO: OptionType = (
     &[],
 );
 pub const NETWORK_IMPORT_BITCOIN: OptionType = (
+    "[network] -n --network=[network] 'Imports a wallet for a specified network'",
+    &[],
+    &["mainnet", "regtest", "testnet"],
+    &[],
+);
+pub const NETWORK_IMPORT_BITCOINCASH: OptionType = (
+    "[network] -n --network=[network] 'Imports a wallet for a specified network'",
+    &[],
+    &["mainnet", "testnet"],
+    &[],
+);
+pub const NETWORK_IMPORT_DASH: OptionType = (
+    "[network] -n --network=[network] 'Imports a wallet for a specified network'",
+    &[],
+    &["mainnet", "testnet"],
+    &[],
+);
+pub const NETWORK_IMPORT_DOGECOIN: OptionType = (
     "[network] -n --network=[network] 'Imports a wallet for a specified network'",
     &[],
     &["mainnet", "testnet"],
@@ -136,12 +238,42 @@ pub const NETWORK_IMPORT_MONERO: OptionType = (
     &["mainnet", "stagenet", "testnet"],
     &[],
 );
+pub const NETWORK_IMPORT_RIPPLE: OptionType = (
+    "[network] -n --network=[network] 'Imports a wallet with an X-address for a specified network'",
+    &[],
+    &["mainnet", "testnet"],
+    &[],
+);
+pub const NETWORK_IMPORT_ZCASH: OptionType = (
+    "[network] -n --network=[network] 'Imports a wallet for a specified network'",
+    &[],
+    &["mainnet", "regtest", "testnet"],
+    &[],
+);
 pub const PRIVATE: OptionType = (
-    "[private] --private=[private key] 'Imports a wallet for a specified private key'",
+    "[private] --private=[private key] 'Imports a wallet for a specified private key (reads from stdin if no value is specified)'",
     &["address", "count", "network", "public"],
     &[],
     &[],
 );
+pub const PRIVATE_IMPORT_BITCOIN: OptionType = (
+    "[private] --private=[private key] 'Imports a wallet for a specified private key (reads from stdin if no value is specified)'",
+    &["address", "count", "public"],
+    &[],
+    &[],
+);
+pub const PRIVATE_IMPORT_RIPPLE: OptionType = (
+    "[private] --private=[private key] 'Imports a wallet for a specified seed or private key (reads from stdin if no value is specified)'",
+    &["address", "count", "public"],
+    &[],
+    &[],
+);
+pub const PRIVATE_IMPORT_ZCASH: OptionType = (
+    "[private] --private=[private key] 'Imports a wallet for a specified private key (reads from stdin if no value is specified)'",
+    &["address", "count", "public"],
+    &[],
+    &[],
+);
 pub const PRIVATE_SPEND_KEY_MONERO: OptionType = (
     "[private spend] --private-spend=[private spend key] 'Imports a wallet for a specified private spend key'",
     &["address", "count", "public spend", "public view", "private view"],
@@ -178,21 +310,69 @@ pub const SUBADDRESS_IMPORT_MONERO: OptionType = (
     &[],
     &[],
 );
+pub const TAG_IMPORT_RIPPLE: OptionType = (
+    "[tag] -t --tag=[tag] 'Imports a wallet with an X-address for a specified destination tag'",
+    &["address"],
+    &[],
+    &[],
+);
 
 // HD
 
+pub const ACCOUNT_HD_ZCASH: OptionType = (
+    "[account] -a --account=[account] 'Generates an HD wallet for a specified ZIP-32 account number'",
+    &[],
+    &[],
+    &[],
+);
 pub const DERIVATION_BITCOIN: OptionType = (
     "[derivation] -d --derivation=[\"path\"] 'Generates an HD wallet for a specified derivation path (in quotes) [possible values: bip32, bip44, bip49, \"<custom path>\"]'",
     &[],
     &[],
     &[],
 );
+pub const DERIVATION_BITCOINCASH: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Generates an HD wallet for a specified derivation path (in quotes) [possible values: bip32, bip44, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
+pub const DERIVATION_COSMOS: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Generates an HD wallet for a specified derivation path (in quotes) [possible values: bip32, bip44, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
+pub const DERIVATION_DASH: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Generates an HD wallet for a specified derivation path (in quotes) [possible values: bip32, bip44, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
+pub const DERIVATION_DOGECOIN: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Generates an HD wallet for a specified derivation path (in quotes) [possible values: bip32, bip44, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
 pub const DERIVATION_ETHEREUM: OptionType = (
     "[derivation] -d --derivation=[\"path\"] 'Generates an HD wallet for a specified derivation path (in quotes) [possible values: ethereum, keepkey, ledger-legacy, ledger-live, trezor, \"<custom path>\"]'",
     &[],
     &[],
     &[],
 );
+pub const DERIVATION_SOLANA: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Generates an HD wallet for a specified hardened derivation path (in quotes) [possible values: bip44, ledger, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
+pub const DERIVATION_TRON: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Generates an HD wallet for a specified derivation path (in quotes) [possible values: bip32, bip44, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
 pub const DERIVATION_ZCASH: OptionType = (
     "[derivation] -d --derivation=[\"path\"] 'Generates an HD wallet for a specified derivation path (in quotes) [possible values: zip32, \"<custom path>\"]'",
     &[],
@@ -205,6 +385,36 @@ pub const DIVERSIFIER_HD_ZCASH: OptionType = (
     &[],
     &[],
 );
+pub const DIVERSIFIER_INDEX_HD_ZCASH: OptionType = (
+    "[diversifier index] --diversifier-index=[index] 'Generates an HD wallet with the Sapling address of the first valid diversifier at or after a specified index'",
+    &["diversifier"],
+    &[],
+    &[],
+);
+pub const ENTROPY_HD: OptionType = (
+    "[entropy] --entropy=[hex] 'Generates an HD wallet from specified entropy (in hex), such as dice rolls or the output of a hardware RNG'",
+    &["entropy file"],
+    &[],
+    &[],
+);
+pub const ENTROPY_FILE_HD: OptionType = (
+    "[entropy file] --entropy-file=[path] 'Generates an HD wallet from the entropy of a specified file'",
+    &["entropy"],
+    &[],
+    &[],
+);
+pub const FORMAT_HD_BITCOINCASH: OptionType = (
+    "[format] -f --format=[format] 'Generates an HD wallet with a specified format'",
+    &[],
+    &["cashaddr", "legacy"],
+    &[],
+);
+pub const HRP_HD_COSMOS: OptionType = (
+    "[hrp] --hrp=[hrp] 'Generates an HD wallet with a specified bech32 address prefix, as in cosmos, osmo, or juno'",
+    &[],
+    &[],
+    &[],
+);
 pub const INDEX_HD: OptionType = (
     "[index] -i --index=[index] 'Generates an HD wallet with a specified index'",
     &[],
@@ -217,6 +427,24 @@ pub const INDICES_HD: OptionType = (
     &[],
     &[],
 );
+pub const INDEX_RANGE_HD: OptionType = (
+    "[index range] --index-range=[start..end] 'Generates HD wallets for a specified range of indices (end exclusive)'",
+    &["index", "indices"],
+    &[],
+    &[],
+);
+pub const INDEX_RANGE_HD_MONERO: OptionType = (
+    "[index range] --index-range=[start..end] 'Generates HD wallets for a specified range of subaddress minor indices (end exclusive)'",
+    &["integrated"],
+    &[],
+    &[],
+);
+pub const INTEGRATED_HD_MONERO: OptionType = (
+    "[integrated] -i --integrated=[PaymentID] 'Generates an HD wallet with a specified payment ID'",
+    &["index range", "subaddress"],
+    &[],
+    &[],
+);
 pub const LANGUAGE_HD: OptionType = (
     "[language] -l --language=[language] 'Generates an HD wallet with a specified language'",
     &[],
@@ -232,21 +460,103 @@ pub const LANGUAGE_HD: OptionType = (
     ],
     &[],
 );
+pub const LANGUAGE_HD_MONERO: OptionType = (
+    "[language] -l --language=[language] 'Generates an HD wallet with a specified language'",
+    &[],
+    &[
+        "chinese_simplified",
+        "dutch",
+        "english",
+        "esperanto",
+        "french",
+        "german",
+        "italian",
+        "japanese",
+        "lojban",
+        "portuguese",
+        "russian",
+        "spanish",
+    ],
+    &[],
+);
+pub const LANGUAGE_HD_ZCASH: OptionType = (
+    "[language] -l --language=[language] 'Generates a shielded HD wallet with a specified mnemonic language'",
+    &[],
+    &[
+        "chinese_simplified",
+        "chinese_traditional",
+        "english",
+        "french",
+        "italian",
+        "japanese",
+        "korean",
+        "spanish",
+    ],
+    &["shielded"],
+);
 pub const NETWORK_HD_BITCOIN: OptionType = (
+    "[network] -n --network=[network] 'Generates an HD wallet for a specified network'",
+    &[],
+    &["mainnet", "regtest", "testnet"],
+    &[],
+);
+pub const NETWORK_HD_BITCOINCASH: OptionType = (
     "[network] -n --network=[network] 'Generates an HD wallet for a specified network'",
     &[],
     &["mainnet", "testnet"],
     &[],
 );
-pub const NETWORK_HD_ZCASH: OptionType = (
+pub const NETWORK_HD_DASH: OptionType = (
+    "[network] -n --network=[network] 'Generates an HD wallet for a specified network'",
+    &[],
+    &["mainnet", "testnet"],
+    &[],
+);
+pub const NETWORK_HD_DOGECOIN: OptionType = (
     "[network] -n --network=[network] 'Generates an HD wallet for a specified network'",
     &[],
     &["mainnet", "testnet"],
     &[],
 );
+pub const NETWORK_HD_MONERO: OptionType = (
+    "[network] -n --network=[network] 'Generates an HD wallet for a specified network'",
+    &[],
+    &["mainnet", "stagenet", "testnet"],
+    &[],
+);
+pub const NETWORK_HD_ZCASH: OptionType = (
+    "[network] -n --network=[network] 'Generates an HD wallet for a specified network'",
+    &[],
+    &["mainnet", "regtest", "testnet"],
+    &[],
+);
 pub const PASSWORD_HD: OptionType = (
-    "[password] -p --password=[password] 'Generates an HD wallet with a specified password'",
+    "[password] -p --password=[password] 'Generates an HD wallet with a specified password (reads from stdin if no value is specified)'",
+    &[],
+    &[],
+    &[],
+);
+pub const PASSWORD_HD_ZCASH: OptionType = (
+    "[password] -p --password=[password] 'Generates a shielded HD wallet with a specified mnemonic password (reads from stdin if no value is specified)'",
+    &[],
+    &[],
+    &["shielded"],
+);
+pub const SHIELDED_HD_ZCASH: OptionType = (
+    "[shielded] --shielded 'Generates a shielded HD wallet from a mnemonic, for the ZIP-32 path of an account (m/32h/133h/account h)'",
+    &["derivation"],
+    &[],
+    &[],
+);
+pub const SLIP132_HD_BITCOIN: OptionType = (
+    "[slip132] --slip132 'Generates an HD wallet with the address format and SLIP-132 extended key version bytes of the purpose of the derivation path (44h: xpub, 49h: ypub, 84h: zpub)'",
+    &[],
+    &[],
     &[],
+);
+pub const SUBADDRESS_HD_MONERO: OptionType = (
+    "[subaddress] -s --subaddress=[Major Index][Minor Index] 'Generates an HD wallet with a specified major and minor index'",
+    &["integrated"],
     &[],
     &[],
 );
@@ -256,6 +566,12 @@ pub const WORD_COUNT: OptionType = (
     &["12", "15", "18", "21", "24"],
     &[],
 );
+pub const WORD_COUNT_HD_ZCASH: OptionType = (
+    "[word count] -w --word-count=[word count] 'Generates a shielded HD wallet with a specified mnemonic word count'",
+    &[],
+    &["12", "15", "18", "21", "24"],
+    &["shielded"],
+);
 
 // Import HD
 
@@ -265,6 +581,12 @@ pub const ACCOUNT: OptionType = (
     &[],
     &[],
 );
+pub const ALL_FORMATS_IMPORT_HD_ZCASH: OptionType = (
+    "[all formats] --all-formats 'Imports the transparent (BIP-44) and Sapling (ZIP-32) addresses and viewing keys of the same account of a mnemonic'",
+    &["derivation", "diversifier"],
+    &[],
+    &["mnemonic"],
+);
 pub const CHAIN: OptionType = (
     "[chain] -c --chain=[chain] 'Imports an HD wallet for a specified (external/internal) chain for bip44 and bip49 derivations'",
     &[],
@@ -277,12 +599,48 @@ pub const DERIVATION_IMPORT_BITCOIN: OptionType = (
     &[],
     &[],
 );
+pub const DERIVATION_IMPORT_BITCOINCASH: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Imports an HD wallet for a specified derivation path (in quotes) [possible values: bip32, bip44, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
+pub const DERIVATION_IMPORT_COSMOS: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Imports an HD wallet for a specified derivation path (in quotes) [possible values: bip32, bip44, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
+pub const DERIVATION_IMPORT_DASH: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Imports an HD wallet for a specified derivation path (in quotes) [possible values: bip32, bip44, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
+pub const DERIVATION_IMPORT_DOGECOIN: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Imports an HD wallet for a specified derivation path (in quotes) [possible values: bip32, bip44, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
 pub const DERIVATION_IMPORT_ETHEREUM: OptionType = (
     "[derivation] -d --derivation=[\"path\"] 'Imports an HD wallet for a specified derivation path (in quotes) [possible values: ethereum, keepkey, ledger-legacy, ledger-live, trezor, \"<custom path>\"]'",
     &[],
     &[],
     &[],
 );
+pub const DERIVATION_IMPORT_SOLANA: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Imports an HD wallet for a specified hardened derivation path (in quotes) [possible values: bip44, ledger, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
+pub const DERIVATION_IMPORT_TRON: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Imports an HD wallet for a specified derivation path (in quotes) [possible values: bip32, bip44, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
 pub const DERIVATION_IMPORT_ZCASH: OptionType = (
     "[derivation] -d --derivation=[\"path\"] 'Imports an HD wallet for a specified derivation path (in quotes) [possible values: zip32, \"<custom path>\"]'",
     &[],
@@ -295,6 +653,12 @@ pub const DIVERSIFIER_IMPORT_HD_ZCASH: OptionType = (
     &[],
     &[],
 );
+pub const DIVERSIFIER_INDEX_IMPORT_HD_ZCASH: OptionType = (
+    "[diversifier index] --diversifier-index=[index] 'Imports an HD wallet with the Sapling address of the first valid diversifier at or after a specified index'",
+    &["diversifier"],
+    &[],
+    &[],
+);
 pub const EXTENDED_PUBLIC: OptionType = (
     "[extended public] --extended-public=[extended public] 'Imports a partial HD wallet for a specified extended public key'",
     &["account", "count", "extended private", "index", "mnemonic", "password"],
@@ -307,77 +671,807 @@ pub const EXTENDED_PRIVATE: OptionType = (
     &[],
     &[],
 );
-
-pub const NETWORK_IMPORT_HD_BITCOIN: OptionType = (
-    "[network] -n --network=[network] 'Imports an HD wallet for a specified network'",
+pub const FORMAT_IMPORT_HD_BITCOINCASH: OptionType = (
+    "[format] -f --format=[format] 'Imports an HD wallet with a specified format'",
     &[],
-    &["mainnet", "testnet"],
+    &["cashaddr", "legacy"],
     &[],
 );
-pub const INDEX_IMPORT_HD: OptionType = (
-    "[index] -i --index=[index] 'Imports an HD wallet with a specified index'",
+pub const HRP_IMPORT_HD_COSMOS: OptionType = (
+    "[hrp] --hrp=[hrp] 'Imports an HD wallet with a specified bech32 address prefix, as in cosmos, osmo, or juno'",
     &[],
     &[],
     &[],
 );
-pub const INDICES_IMPORT_HD: OptionType = (
-    "[indices] -k --indices=[num_indices] 'Imports an HD wallet with a specified number of indices'",
-    &[],
+
+pub const NETWORK_IMPORT_HD_BITCOIN: OptionType = (
+    "[network] -n --network=[network] 'Imports an HD wallet for a specified network'",
     &[],
+    &["mainnet", "regtest", "testnet"],
     &[],
 );
-pub const MNEMONIC: OptionType = (
-    "[mnemonic] -m --mnemonic=[\"mnemonic\"] 'Imports an HD wallet for a specified mnemonic (in quotes)'",
-    &["count", "extended private", "extended public"],
+pub const NETWORK_IMPORT_HD_BITCOINCASH: OptionType = (
+    "[network] -n --network=[network] 'Imports an HD wallet for a specified network'",
+    &[],
+    &["mainnet", "testnet"],
+    &[],
+);
+pub const NETWORK_IMPORT_HD_DASH: OptionType = (
+    "[network] -n --network=[network] 'Imports an HD wallet for a specified network'",
+    &[],
+    &["mainnet", "testnet"],
+    &[],
+);
+pub const NETWORK_IMPORT_HD_DOGECOIN: OptionType = (
+    "[network] -n --network=[network] 'Imports an HD wallet for a specified network'",
+    &[],
+    &["mainnet", "testnet"],
+    &[],
+);
+pub const INDEX_IMPORT_HD: OptionType = (
+    "[index] -i --index=[index] 'Imports an HD wallet with a specified index'",
+    &[],
+    &[],
+    &[],
+);
+pub const INDICES_IMPORT_HD: OptionType = (
+    "[indices] -k --indices=[num_indices] 'Imports an HD wallet with a specified number of indices'",
+    &[],
+    &[],
+    &[],
+);
+pub const INDEX_RANGE_IMPORT_HD: OptionType = (
+    "[index range] --index-range=[start..end] 'Imports HD wallets for a specified range of indices (end exclusive)'",
+    &["index", "indices"],
+    &[],
+    &[],
+);
+pub const INDEX_RANGE_IMPORT_HD_MONERO: OptionType = (
+    "[index range] --index-range=[start..end] 'Imports HD wallets for a specified range of subaddress minor indices (end exclusive)'",
+    &["integrated"],
+    &[],
+    &[],
+);
+pub const INTEGRATED_IMPORT_HD_MONERO: OptionType = (
+    "[integrated] -i --integrated=[PaymentID] 'Imports an HD wallet with a specified payment ID'",
+    &["index range", "subaddress"],
+    &[],
+    &[],
+);
+pub const LANGUAGE_IMPORT_HD_MONERO: OptionType = (
+    "[language] -l --language=[language] 'Imports an HD wallet with a specified mnemonic language (requires private spend key)'",
+    &[],
+    &[
+        "chinese_simplified",
+        "dutch",
+        "english",
+        "esperanto",
+        "french",
+        "german",
+        "italian",
+        "japanese",
+        "lojban",
+        "portuguese",
+        "russian",
+        "spanish",
+    ],
+    &["private spend"],
+);
+pub const MNEMONIC: OptionType = (
+    "[mnemonic] -m --mnemonic=[\"mnemonic\"] 'Imports an HD wallet for a specified mnemonic (in quotes) (reads from stdin if no value is specified)'",
+    &["count", "extended private", "extended public"],
+    &[],
+    &[],
+);
+pub const MNEMONIC_IMPORT_HD_MONERO: OptionType = (
+    "[mnemonic] -m --mnemonic=[\"mnemonic\"] 'Imports an HD wallet for a specified mnemonic (in quotes) (reads from stdin if no value is specified)'",
+    &["private spend"],
+    &[],
+    &[],
+);
+pub const MNEMONIC_TYPE_BITCOIN: OptionType = (
+    "[mnemonic type] --mnemonic-type=[type] 'Imports an HD wallet for a specified mnemonic type'",
+    &["extended private", "extended public"],
+    &["bip39", "electrum"],
+    &["mnemonic"],
+);
+pub const NETWORK_IMPORT_HD_MONERO: OptionType = (
+    "[network] -n --network=[network] 'Imports an HD wallet for a specified network'",
+    &[],
+    &["mainnet", "stagenet", "testnet"],
+    &[],
+);
+pub const NETWORK_IMPORT_HD_ZCASH: OptionType = (
+    "[network] -n --network=[network] 'Imports an HD wallet for a specified network (requires mnemonic)'",
+    &[],
+    &["mainnet", "regtest", "testnet"],
+    &["mnemonic"],
+);
+pub const NEUTER_IMPORT_HD: OptionType = (
+    "[neuter] --neuter 'Imports an HD wallet as only the extended public key of its derivation path, without its secrets, public key, or address'",
+    &["extended public"],
+    &[],
+    &[],
+);
+pub const PASSWORD_IMPORT_HD: OptionType = (
+    "[password] -p --password=[password] 'Imports an HD wallet with a specified password (reads from stdin if no value is specified)'",
+    &["extended private", "extended public"],
+    &[],
+    &[],
+);
+pub const PRIVATE_SPEND_KEY_IMPORT_HD_MONERO: OptionType = (
+    "[private spend] --private-spend=[private spend key] 'Imports an HD wallet for a specified private spend key'",
+    &["mnemonic"],
+    &[],
+    &[],
+);
+pub const SHOW_ROOT_IMPORT_HD_BITCOIN: OptionType = (
+    "[show root] --show-root 'Imports the BIP-39 seed, master keys, and master fingerprint of a mnemonic, with its bip44, bip49, and bip84 account keys in their SLIP-132 formats'",
+    &["mnemonic type", "neuter", "slip132"],
+    &[],
+    &["mnemonic"],
+);
+pub const SLIP132_IMPORT_HD_BITCOIN: OptionType = (
+    "[slip132] --slip132 'Imports an HD wallet with the address format and SLIP-132 extended key version bytes of the purpose of the derivation path (44h: xpub, 49h: ypub, 84h: zpub)'",
+    &[],
+    &[],
+    &[],
+);
+pub const SUBADDRESS_IMPORT_HD_MONERO: OptionType = (
+    "[subaddress] -s --subaddress=[Major Index][Minor Index] 'Imports an HD wallet with a specified major and minor index'",
+    &["integrated"],
+    &[],
+    &[],
+);
+pub const VIEW_ONLY_IMPORT_HD_MONERO: OptionType = (
+    "[view only] --view-only 'Imports a view-only wallet, with the private view key and without the mnemonic and private spend key'",
+    &[],
+    &[],
+    &[],
+);
+pub const WORD_COUNT_IMPORT_HD: OptionType = (
+    "[word count] -w --word-count=[word count] 'Imports an HD wallet only if its mnemonic has the specified word count'",
+    &[],
+    &["12", "15", "18", "21", "24"],
+    &["mnemonic"],
+);
+
+// Export
+
+pub const DERIVATION_EXPORT_BITCOIN: OptionType = (
+    "[export derivation] -d --derivation=[derivation] 'Exports an HD wallet account for a specified derivation'",
+    &[],
+    &["bip44", "bip49", "bip84"],
+    &[],
+);
+pub const FORMAT_EXPORT: OptionType = (
+    "[export format] -f --format=[format] 'Exports an HD wallet account in a specified format'",
+    &[],
+    &["hwi"],
+    &[],
+);
+pub const INDEX_RANGE_EXPORT: OptionType = (
+    "[index range] --index-range=[start..end] 'Exports an HD wallet account for a specified range of indices (end exclusive)'",
+    &[],
+    &[],
+    &[],
+);
+pub const NETWORK_EXPORT_BITCOIN: OptionType = (
+    "[network] -n --network=[network] 'Exports an HD wallet account for a specified network'",
+    &[],
+    &["mainnet", "regtest", "testnet"],
+    &[],
+);
+
+// Convert
+
+pub const DERIVATION_CONVERT_ETHEREUM: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Converts the private key of a mnemonic at a specified derivation path (in quotes) [possible values: ethereum, keepkey, ledger-legacy, ledger-live, trezor, \"<custom path>\"]'",
+    &[],
+    &[],
+    &["mnemonic"],
+);
+pub const MNEMONIC_CONVERT_ETHEREUM: OptionType = (
+    "[mnemonic] -m --mnemonic=[\"mnemonic\"] 'Converts the private key of a specified mnemonic (in quotes) (reads from stdin if no value is specified)'",
+    &["private"],
+    &[],
+    &[],
+);
+pub const NETWORK_CONVERT_BITCOIN: OptionType = (
+    "[network] -n --network=[network] 'Converts a private key in hex for a specified network'",
+    &[],
+    &["mainnet", "regtest", "testnet"],
+    &[],
+);
+pub const PRIVATE_CONVERT_BITCOIN: OptionType = (
+    "[private] --private=[private key] 'Converts a specified private key in WIF or hex (reads from stdin if no value is specified)'",
+    &[],
+    &[],
+    &[],
+);
+pub const PASSWORD_CONVERT_ETHEREUM: OptionType = (
+    "[password] -p --password=[password] 'Converts the private key of a mnemonic with a specified password (reads from stdin if no value is specified)'",
+    &[],
+    &[],
+    &["mnemonic"],
+);
+pub const PRIVATE_CONVERT_ETHEREUM: OptionType = (
+    "[private] --private=[private key] 'Converts a specified private key in hex (reads from stdin if no value is specified)'",
+    &["mnemonic"],
+    &[],
+    &[],
+);
+pub const TO_CONVERT_BITCOIN: OptionType = (
+    "<to> --to=<form> 'Converts the private key to a specified form'",
+    &[],
+    &["compressed", "hex", "uncompressed"],
+    &[],
+);
+
+// Hardware
+
+#[cfg(feature = "hw-ledger")]
+pub const DERIVATION_HW_BITCOIN: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Fetches a wallet from a Ledger device for a specified derivation path (in quotes) [possible values: bip32, bip44, bip49, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
+#[cfg(feature = "hw-ledger")]
+pub const DERIVATION_HW_ETHEREUM: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Fetches a wallet from a Ledger device for a specified derivation path (in quotes) [possible values: ethereum, keepkey, ledger-legacy, ledger-live, trezor, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
+#[cfg(feature = "hw-ledger")]
+pub const FORMAT_HW_BITCOIN: OptionType = (
+    "[format] -f --format=[format] 'Fetches a wallet from a Ledger device with a specified format'",
+    &[],
+    &["bech32", "legacy", "segwit"],
+    &[],
+);
+#[cfg(feature = "hw-ledger")]
+pub const INDEX_HW: OptionType = (
+    "[index] -i --index=[index] 'Fetches a wallet from a Ledger device with a specified index'",
+    &[],
+    &[],
+    &[],
+);
+#[cfg(feature = "hw-ledger")]
+pub const NETWORK_HW_BITCOIN: OptionType = (
+    "[network] -n --network=[network] 'Fetches a wallet from a Ledger device for a specified network'",
+    &[],
+    &["mainnet", "regtest", "testnet"],
+    &[],
+);
+#[cfg(feature = "hw-ledger")]
+pub const VERIFY_HW: OptionType = (
+    "[verify] --verify 'Displays the address on the Ledger device for confirmation'",
+    &[],
+    &[],
+    &[],
+);
+
+// Paper
+
+pub const DERIVATION_PAPER_BITCOIN: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Generates an HD paper wallet for a specified derivation path (in quotes) [possible values: bip32, bip44, bip49, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
+pub const DERIVATION_PAPER_BITCOINCASH: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Generates an HD paper wallet for a specified derivation path (in quotes) [possible values: bip32, bip44, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
+pub const DERIVATION_PAPER_COSMOS: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Generates an HD paper wallet for a specified derivation path (in quotes) [possible values: bip32, bip44, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
+pub const DERIVATION_PAPER_DASH: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Generates an HD paper wallet for a specified derivation path (in quotes) [possible values: bip32, bip44, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
+pub const DERIVATION_PAPER_DOGECOIN: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Generates an HD paper wallet for a specified derivation path (in quotes) [possible values: bip32, bip44, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
+pub const DERIVATION_PAPER_ETHEREUM: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Generates an HD paper wallet for a specified derivation path (in quotes) [possible values: ethereum, keepkey, ledger-legacy, ledger-live, trezor, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
+pub const DERIVATION_PAPER_SOLANA: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Generates an HD paper wallet for a specified hardened derivation path (in quotes) [possible values: bip44, ledger, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
+pub const DERIVATION_PAPER_TRON: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Generates an HD paper wallet for a specified derivation path (in quotes) [possible values: bip32, bip44, \"<custom path>\"]'",
+    &[],
+    &[],
+    &[],
+);
+pub const FORMAT_PAPER_BITCOIN: OptionType = (
+    "[format] -f --format=[format] 'Generates a paper wallet with a specified format'",
+    &["derivation"],
+    &["bech32", "legacy", "segwit"],
+    &[],
+);
+pub const FORMAT_PAPER_BITCOINCASH: OptionType = (
+    "[format] -f --format=[format] 'Generates a paper wallet with a specified format'",
+    &[],
+    &["cashaddr", "legacy"],
+    &[],
+);
+pub const FORMAT_PAPER_ZCASH: OptionType = (
+    "[format] -f --format=[format] 'Generates a paper wallet with a specified format'",
+    &[],
+    &["sapling", "transparent"],
+    &[],
+);
+pub const HRP_PAPER_COSMOS: OptionType = (
+    "[hrp] --hrp=[hrp] 'Generates a paper wallet with a specified bech32 address prefix, as in cosmos, osmo, or juno'",
+    &[],
+    &[],
+    &[],
+);
+pub const KEY_TYPE_PAPER_RIPPLE: OptionType = (
+    "[key type] -k --key-type=[key type] 'Generates a paper wallet with a specified key type'",
+    &[],
+    &["ed25519", "secp256k1"],
+    &[],
+);
+pub const LANGUAGE_PAPER: OptionType = (
+    "[language] -l --language=[language] 'Generates an HD paper wallet with a specified language'",
+    &[],
+    &[
+        "chinese_simplified",
+        "chinese_traditional",
+        "english",
+        "french",
+        "italian",
+        "japanese",
+        "korean",
+        "spanish",
+    ],
+    &["derivation"],
+);
+pub const LANGUAGE_PAPER_MONERO: OptionType = (
+    "[language] -l --language=[language] 'Generates a paper wallet with a specified language'",
+    &[],
+    &[
+        "chinese_simplified",
+        "dutch",
+        "english",
+        "esperanto",
+        "french",
+        "german",
+        "italian",
+        "japanese",
+        "lojban",
+        "portuguese",
+        "russian",
+        "spanish",
+    ],
+    &[],
+);
+pub const NETWORK_PAPER_BITCOIN: OptionType = (
+    "[network] -n --network=[network] 'Generates a paper wallet for a specified network'",
+    &[],
+    &["mainnet", "regtest", "testnet"],
+    &[],
+);
+pub const NETWORK_PAPER_BITCOINCASH: OptionType = (
+    "[network] -n --network=[network] 'Generates a paper wallet for a specified network'",
+    &[],
+    &["mainnet", "testnet"],
+    &[],
+);
+pub const NETWORK_PAPER_DASH: OptionType = (
+    "[network] -n --network=[network] 'Generates a paper wallet for a specified network'",
+    &[],
+    &["mainnet", "testnet"],
+    &[],
+);
+pub const NETWORK_PAPER_DOGECOIN: OptionType = (
+    "[network] -n --network=[network] 'Generates a paper wallet for a specified network'",
+    &[],
+    &["mainnet", "testnet"],
+    &[],
+);
+pub const NETWORK_PAPER_MONERO: OptionType = (
+    "[network] -n --network=[network] 'Generates a paper wallet for a specified network'",
+    &[],
+    &["mainnet", "stagenet", "testnet"],
+    &[],
+);
+pub const NETWORK_PAPER_RIPPLE: OptionType = (
+    "[network] -n --network=[network] 'Generates a paper wallet with an X-address for a specified network'",
+    &[],
+    &["mainnet", "testnet"],
+    &[],
+);
+pub const NETWORK_PAPER_ZCASH: OptionType = (
+    "[network] -n --network=[network] 'Generates a paper wallet for a specified network'",
+    &[],
+    &["mainnet", "regtest", "testnet"],
+    &[],
+);
+pub const OUTPUT_PAPER: OptionType = (
+    "<paper file> -o --output=<path> 'Writes the paper wallet(s) to a specified .html or .pdf file'",
+    &[],
+    &[],
+    &[],
+);
+pub const UNCOMPRESSED_PAPER_BITCOIN: OptionType = (
+    "[uncompressed] --uncompressed 'Generates a paper wallet with an uncompressed public key, in the legacy format'",
+    &["derivation"],
+    &[],
+    &[],
+);
+pub const WORD_COUNT_PAPER: OptionType = (
+    "[word count] -w --word-count=[word count] 'Generates an HD paper wallet with a specified word count'",
+    &[],
+    &["12", "15", "18", "21", "24"],
+    &["derivation"],
+);
+
+// Recover
+
+pub const MESSAGE_RECOVER_ETHEREUM: OptionType = (
+    "[message] --message=[message] 'Recovers the signer of a specified message signed by eth_sign or personal_sign (in hex)'",
+    &["tx hash"],
+    &[],
+    &["signature"],
+);
+pub const SIGNATURE_RECOVER_ETHEREUM: OptionType = (
+    "[signature] --signature=[signature] 'Recovers the signer of a specified 65-byte signature of r, s, and v (in hex)'",
+    &[],
+    &[],
+    &[],
+);
+pub const TX_HASH_RECOVER_ETHEREUM: OptionType = (
+    "[tx hash] --tx-hash=[hash] 'Recovers the signer of a specified 32-byte hash, such as the sighash of a transaction (in hex)'",
+    &["message"],
+    &[],
+    &["signature"],
+);
+
+// Transaction
+
+pub const CREATE_RAW_TRANSACTION_BITCOIN: OptionType = (
+    "[createrawtransaction] --createrawtransaction= [inputs] [outputs] 'Generates a raw Bitcoin transaction
+    Inputs format: '[{\"txid\":\"txid\", \"vout\":index},...]'
+    Outputs format: '{\"address\":amount,...}'
+    '",
+    &["signrawtransaction"],
+    &[],
+    &[],
+);
+
+pub const SIGN_RAW_TRANSACTION_BITCOIN: OptionType = (
+    "[signrawtransaction] --signrawtransaction=[transaction hex] [inputs] 'Sign a raw Bitcoin transaction
+    Inputs format: '[{\"txid\":\"txid\", \"vout\":index, \"amount\":amount, \"address\":\"address\", \"privatekey\":\"private_key\"},...]'
+    (Optional: manually specify scriptPubKey and redeemScript)
+    '",
+    &["createrawtransaction", "lock time", "version"],
+    &[],
+    &[],
+);
+
+pub const TRANSACTION_LOCK_TIME_BITCOIN: OptionType = (
+    "[lock time] --lock-time=[lock time] 'Specify a Bitcoin transaction lock time'",
+    &["signrawtransaction"],
+    &[],
+    &["createrawtransaction"],
+);
+
+pub const TRANSACTION_VERSION_BITCOIN: OptionType = (
+    "[version] --version=[version] 'Specify a Bitcoin transaction version'",
+    &["signrawtransaction"],
+    &[],
+    &["createrawtransaction"],
+);
+
+pub const BUMP_FEE_RATE_TRANSACTION_BITCOIN: OptionType = (
+    "[fee rate] --feerate=[fee rate] 'Replaces the Bitcoin transaction with one of a specified fee rate (in satoshi per vbyte)'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const CHANGE_OUTPUT_TRANSACTION_BITCOIN: OptionType = (
+    "[change output] --change-output=[index] 'Pays the increase of the fee from the output of a specified index (defaults to the last output)'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const CHANGE_TRANSACTION_BITCOIN: OptionType = (
+    "[change] --change=[address] 'Sends the change of the selected unspent outputs to a specified address'",
+    &[],
+    &[],
+    &["utxos"],
+);
+
+pub const COIN_SELECTION_TRANSACTION_BITCOIN: OptionType = (
+    "[coin selection] --coin-selection=[strategy] 'Selects the unspent outputs with a specified strategy'",
+    &[],
+    &["branch-and-bound", "largest-first"],
+    &["utxos"],
+);
+
+pub const DERIVATION_TRANSACTION_BITCOIN: OptionType = (
+    "[derivation] -d --derivation=[\"path\"] 'Signs a Bitcoin transaction with a key derived from a specified derivation path (in quotes) [possible values: bip32, bip44, bip49, \"<custom path>\"]'",
+    &[],
+    &[],
+    &["mnemonic"],
+);
+
+pub const EXTENDED_PRIVATE_TRANSACTION_BITCOIN: OptionType = (
+    "[extended private] --extended-private=[extended private key] 'Signs each unspent output with a key derived from a specified extended private key at the path of the unspent output'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const FEE_RATE_TRANSACTION_BITCOIN: OptionType = (
+    "[fee rate] --feerate=[fee rate] 'Selects the unspent outputs to fund a specified fee rate (in satoshi per vbyte)'",
+    &[],
+    &[],
+    &["utxos"],
+);
+
+pub const FORMAT_TRANSACTION_BITCOIN: OptionType = (
+    "[format] -f --format=[format] 'Signs inputs without a specified address or format using a specified format'",
+    &[],
+    &["bech32", "legacy", "segwit"],
+    &[],
+);
+
+pub const INPUTS_TRANSACTION_BITCOIN: OptionType = (
+    "[inputs] --inputs=[inputs] 'Specifies the Bitcoin transaction inputs (in JSON)
+    Inputs format: [{\"txid\":\"txid\", \"vout\":index, \"amount\":amount, \"format\":\"format\"},...]
+    (Optional: specify an address, privatekey, scriptPubKey, redeemScript and sequence per input)
+    '",
+    &[],
+    &[],
+    &[],
+);
+
+pub const LOCK_TIME_TRANSACTION_BITCOIN: OptionType = (
+    "[lock time] --lock-time=[lock time] 'Creates a Bitcoin transaction with a specified lock time'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const MNEMONIC_TRANSACTION_BITCOIN: OptionType = (
+    "[mnemonic] -m --mnemonic=[\"mnemonic\"] 'Signs a Bitcoin transaction with a key derived from a specified mnemonic (in quotes) (reads from stdin if no value is specified)'",
+    &["private"],
+    &[],
+    &[],
+);
+
+pub const MAX_FEE_PERCENT_TRANSACTION_BITCOIN: OptionType = (
+    "[max fee percent] --max-fee-percent=[percent] 'Refuses to create a Bitcoin transaction with a fee above a specified percentage of its outputs, excluding change'",
+    &[],
+    &[],
+    &["utxos"],
+);
+
+pub const NETWORK_TRANSACTION_BITCOIN: OptionType = (
+    "[network] -n --network=[network] 'Uses a specified network for the Bitcoin transaction'",
+    &[],
+    &["mainnet", "regtest", "testnet"],
+    &[],
+);
+
+pub const OP_RETURN_TRANSACTION_BITCOIN: OptionType = (
+    "[op return] --op-return=[hex] 'Includes an unspendable null data output of specified data (in hex, up to 80 bytes)'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const OUTPUTS_TRANSACTION_BITCOIN: OptionType = (
+    "[outputs] --outputs=[outputs] 'Specifies the Bitcoin transaction outputs (in JSON)
+    Outputs format: {\"address\":amount,...}
+    (Optional: specify \"data\":\"hex\" for a null data output, or \"script:hex\":amount for a raw script)
+    '",
+    &[],
+    &[],
+    &[],
+);
+
+pub const PASSWORD_TRANSACTION_BITCOIN: OptionType = (
+    "[password] -p --password=[password] 'Signs a Bitcoin transaction with a key derived from a mnemonic with a specified password (reads from stdin if no value is specified)'",
+    &[],
+    &[],
+    &["mnemonic"],
+);
+
+pub const PRIVATE_TRANSACTION_BITCOIN: OptionType = (
+    "[private] --private=[private key] 'Signs a Bitcoin transaction with a specified private key (reads from stdin if no value is specified)'",
+    &["mnemonic"],
+    &[],
+    &[],
+);
+
+pub const RAW_TRANSACTION_BITCOIN: OptionType = (
+    "[raw] --raw=[transaction hex] 'Specifies a raw Bitcoin transaction (in hex)'",
+    &[],
     &[],
     &[],
 );
-pub const PASSWORD_IMPORT_HD: OptionType = (
-    "[password] -p --password=[password] 'Imports an HD wallet with a specified password'",
-    &["extended private", "extended public"],
+
+pub const RBF_TRANSACTION_BITCOIN: OptionType = (
+    "[rbf] --rbf 'Signals that the Bitcoin transaction may be replaced by one with a higher fee (BIP 125)'",
+    &[],
     &[],
     &[],
 );
 
-// Transaction
+pub const TO_CONSOLIDATE_TRANSACTION_BITCOIN: OptionType = (
+    "<address> --to=<address> 'Consolidates the unspent outputs into a single output to a specified address'",
+    &[],
+    &[],
+    &[],
+);
 
-pub const CREATE_RAW_TRANSACTION_BITCOIN: OptionType = (
-    "[createrawtransaction] --createrawtransaction= [inputs] [outputs] 'Generates a raw Bitcoin transaction
-    Inputs format: '[{\"txid\":\"txid\", \"vout\":index},...]'
-    Outputs format: '{\"address\":amount,...}'
+pub const UTXOS_TRANSACTION_BITCOIN: OptionType = (
+    "[utxos] --utxos=[file] 'Selects the Bitcoin transaction inputs from the unspent outputs of a specified file (in JSON)
+    Unspent outputs format: [{\"txid\":\"txid\", \"vout\":index, \"amount\":amount, \"address\":\"address\"},...]
+    (Optional: specify a format instead of an address per unspent output)
     '",
-    &["signrawtransaction"],
-    &[],
+    &["inputs"],
     &[],
+    &["fee rate"],
 );
 
-pub const SIGN_RAW_TRANSACTION_BITCOIN: OptionType = (
-    "[signrawtransaction] --signrawtransaction=[transaction hex] [inputs] 'Sign a raw Bitcoin transaction
-    Inputs format: '[{\"txid\":\"txid\", \"vout\":index, \"amount\":amount, \"address\":\"address\", \"privatekey\":\"private_key\"},...]'
-    (Optional: manually specify scriptPubKey and redeemScript)
+pub const UTXOS_CONSOLIDATE_TRANSACTION_BITCOIN: OptionType = (
+    "<utxos> --utxos=<file> 'Consolidates the unspent outputs of a specified file (in JSON)
+    Unspent outputs format: [{\"txid\":\"txid\", \"vout\":index, \"amount\":amount, \"format\":\"format\", \"path\":\"path\"},...]
+    (Optional: specify an address, or a privatekey instead of a path, per unspent output)
     '",
-    &["createrawtransaction", "lock time", "version"],
+    &[],
     &[],
     &[],
 );
 
-pub const TRANSACTION_LOCK_TIME_BITCOIN: OptionType = (
-    "[lock time] --lock-time=[lock time] 'Specify a Bitcoin transaction lock time'",
-    &["signrawtransaction"],
+pub const VERSION_TRANSACTION_BITCOIN: OptionType = (
+    "[version] --version=[version] 'Creates a Bitcoin transaction with a specified version'",
+    &[],
+    &[],
     &[],
-    &["createrawtransaction"],
 );
 
-pub const TRANSACTION_VERSION_BITCOIN: OptionType = (
-    "[version] --version=[version] 'Specify a Bitcoin transaction version'",
-    &["signrawtransaction"],
+pub const NETWORK_MINISCRIPT_BITCOIN: OptionType = (
+    "[network] -n --network=[network] 'Derives the P2WSH address for a specified network'",
+    &[],
+    &["mainnet", "regtest", "testnet"],
+    &[],
+);
+
+pub const POLICY_MINISCRIPT_BITCOIN: OptionType = (
+    "<policy> 'Compiles a specified spending policy, such as or(pk(<key>),and(pk(<key>),older(144)))'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const ACCOUNT_PAYMENT_CODE_BITCOIN: OptionType = (
+    "[account] -a --account=[account] 'Derives the payment code of a specified account number'",
+    &[],
+    &[],
+    &[],
+);
+pub const COUNTERPARTY_PAYMENT_CODE_BITCOIN: OptionType = (
+    "[counterparty] --counterparty=[payment code] 'Derives the deposit addresses shared with a specified counterparty payment code'",
+    &[],
+    &[],
+    &["mnemonic"],
+);
+pub const FORMAT_PAYMENT_CODE_BITCOIN: OptionType = (
+    "[format] -f --format=[format] 'Derives deposit addresses with a specified format'",
+    &[],
+    &["bech32", "legacy", "segwit"],
+    &["counterparty"],
+);
+pub const INDEX_PAYMENT_CODE_BITCOIN: OptionType = (
+    "[index] -i --index=[index] 'Derives deposit addresses starting from a specified payment index'",
+    &[],
+    &[],
+    &["counterparty"],
+);
+pub const INDEX_RANGE_PAYMENT_CODE_BITCOIN: OptionType = (
+    "[index range] --index-range=[start..end] 'Derives deposit addresses for a specified range of payment indices (end exclusive)'",
+    &["index"],
+    &[],
+    &["counterparty"],
+);
+pub const MNEMONIC_PAYMENT_CODE_BITCOIN: OptionType = (
+    "[mnemonic] -m --mnemonic=[\"mnemonic\"] 'Derives the payment code of a specified mnemonic (in quotes) (reads from stdin if no value is specified)'",
+    &["payment code"],
+    &[],
+    &[],
+);
+pub const NETWORK_PAYMENT_CODE_BITCOIN: OptionType = (
+    "[network] -n --network=[network] 'Derives the payment code for a specified network'",
+    &[],
+    &["mainnet", "regtest", "testnet"],
+    &[],
+);
+pub const PASSWORD_PAYMENT_CODE_BITCOIN: OptionType = (
+    "[password] -p --password=[password] 'Derives the payment code with a specified password (reads from stdin if no value is specified)'",
+    &[],
+    &[],
+    &["mnemonic"],
+);
+pub const PAYMENT_CODE_BITCOIN: OptionType = (
+    "[payment code] 'Parses a specified payment code and derives its notification address'",
+    &["mnemonic"],
+    &[],
+    &[],
+);
+
+pub const ADDRESS_URI_BITCOIN: OptionType = (
+    "[address] --address=[address] 'Creates a payment request to a specified receiver address'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const AMOUNT_URI_BITCOIN: OptionType = (
+    "[uri amount] --amount=[amount] 'Creates a payment request for a specified amount (in BTC)'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const LABEL_URI_BITCOIN: OptionType = (
+    "[uri label] --label=[label] 'Creates a payment request with a specified label of the receiver'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const MESSAGE_URI_BITCOIN: OptionType = (
+    "[uri message] --message=[message] 'Creates a payment request with a specified message describing the payment'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const URI_BITCOIN: OptionType = (
+    "<uri> 'Parses a specified Bitcoin payment request URI (BIP-21)'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const RPC_TRANSACTION: OptionType = (
+    "[rpc] --rpc=[url] 'Broadcasts a transaction to a node at a specified JSON-RPC URL'",
+    &[],
+    &[],
     &[],
-    &["createrawtransaction"],
 );
 
 pub const CREATE_RAW_TRANSACTION_ETHEREUM: OptionType = (
     "[createrawtransaction] --createrawtransaction= ['{\"to\":\"address\", \"value\":\"value\", \"gas\":\"gas\", \"gasPrice\":\"gas_price\", \"nonce\":nonce, \"network\":\"network\"}'] 'Generates a raw Ethereum transaction
-    (Optional: Add a data field)'",
+    (Optional: Add a data field, and an accessList field for an access list transaction)'",
     &["network", "signrawtransaction"],
     &[],
     &[],
@@ -392,11 +1486,237 @@ pub const SIGN_RAW_TRANSACTION_ETHEREUM: OptionType = (
 
 pub const TRANSACTION_NETWORK_ETHEREUM: OptionType = (
     "[network] --network=[network] 'Specify an Ethereum transaction network'",
+    &["createrawtransaction"],
+    &["goerli", "kovan", "mainnet", "rinkeby", "ropsten"],
     &["signrawtransaction"],
+);
+
+pub const ACCESS_LIST_ETHEREUM: OptionType = (
+    "[access list] --access-list=[access list] 'Signs an access list transaction (EIP-2930) with a specified access list (in JSON)
+    Access list format: [{\"address\":\"address\", \"storageKeys\":[\"storage key\",...]},...]
+    '",
+    &[],
+    &[],
+    &[],
+);
+
+pub const CALL_ETHEREUM: OptionType = (
+    "[call] --call=[call] 'Signs an Ethereum transaction with data encoding a specified contract function call
+    Call format: \"function(argument,...)\", with an argument type inferred from its value or prefixed to it (e.g. \"approve(0x...,1000e18)\", \"f(uint8 5)\")
+    '",
+    &["data"],
+    &[],
+    &[],
+);
+
+pub const BALANCE_ETHEREUM: OptionType = (
+    "<balance> --balance=<balance> 'Sweeps a specified balance of the sender (in wei), less the fee of the gas limit at the gas price'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const CHAIN_ID_ETHEREUM: OptionType = (
+    "[chain id] --chain-id=[chain id] 'Signs an Ethereum transaction for a specified EIP-155 chain id, such as 137 for Polygon (defaults to the chain id of the network)'",
+    &["network"],
+    &[],
+    &[],
+);
+
+pub const NETWORK_SIGN_ETHEREUM: OptionType = (
+    "[network] -n --network=[network] 'Signs an Ethereum transaction for a specified network'",
+    &["chain id"],
+    &["goerli", "kovan", "mainnet", "rinkeby", "ropsten"],
+    &[],
+);
+
+pub const DATA_ETHEREUM: OptionType = (
+    "[data] --data=[data] 'Signs an Ethereum transaction with specified data (in hex)'",
+    &[],
+    &[],
     &[],
-    &["createrawtransaction"],
 );
 
+#[cfg(feature = "broadcast")]
+pub const ESTIMATE_GAS_ETHEREUM: OptionType = (
+    "[estimate gas] --estimate-gas 'Signs an Ethereum transaction with a gas limit and gas price estimated by the node at the specified JSON-RPC URL'",
+    &["gas", "gas price"],
+    &[],
+    &["rpc"],
+);
+
+#[cfg(feature = "broadcast")]
+pub const FETCH_NONCE_ETHEREUM: OptionType = (
+    "[fetch nonce] --fetch-nonce 'Signs an Ethereum transaction with the next nonce of the sender fetched from the node at the specified JSON-RPC URL'",
+    &["nonce"],
+    &[],
+    &["rpc"],
+);
+
+pub const GAS_ETHEREUM: OptionType = (
+    "[gas] --gas=[gas] 'Signs an Ethereum transaction with a specified gas limit'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const GAS_PRICE_ETHEREUM: OptionType = (
+    "[gas price] --gas-price=[gas price] 'Signs an Ethereum transaction with a specified gas price (in wei)'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const NONCE_ETHEREUM: OptionType = (
+    "[nonce] --nonce=[nonce] 'Signs an Ethereum transaction with a specified nonce'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const PRIVATE_TRANSACTION_ETHEREUM: OptionType = (
+    "[private] --private=[private key] 'Signs an Ethereum transaction with a specified private key (reads from stdin if no value is specified)'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const RAW_TRANSACTION_ETHEREUM: OptionType = (
+    "[raw] --raw=[transaction hex] 'Decodes a specified raw Ethereum transaction (in hex)'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const RAW_SEND_TRANSACTION_ETHEREUM: OptionType = (
+    "[raw] --raw=[transaction hex] 'Broadcasts a specified signed Ethereum transaction (in hex)'",
+    &[],
+    &[],
+    &[],
+);
+
+#[cfg(feature = "broadcast")]
+pub const RPC_SIGN_TRANSACTION_ETHEREUM: OptionType = (
+    "[rpc] --rpc=[url] 'Queries a node at a specified JSON-RPC URL for the transaction nonce and gas'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const TO_ETHEREUM: OptionType = (
+    "[to] --to=[address] 'Signs an Ethereum transaction to a specified receiver address (or an ENS name, resolved by the node at --rpc)'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const UNPROTECTED_ETHEREUM: OptionType = (
+    "[unprotected] --unprotected 'Signs a legacy Ethereum transaction without EIP-155 replay protection, which is valid on every chain'",
+    &["access list", "chain id"],
+    &[],
+    &[],
+);
+
+pub const VALUE_ETHEREUM: OptionType = (
+    "[value] --value=[value] 'Signs an Ethereum transaction with a specified value (in wei)'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const ADDRESS_URI_ETHEREUM: OptionType = (
+    "[address] --address=[address] 'Creates a payment request to a specified receiver or contract address (or an ENS name)'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const CALL_URI_ETHEREUM: OptionType = (
+    "[call] --call=[call] 'Creates a payment request for a specified contract function call (e.g. \"transfer(0x...,1000e18)\")'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const CHAIN_ID_URI_ETHEREUM: OptionType = (
+    "[uri chain id] --chain-id=[chain id] 'Creates a payment request for a specified EIP-155 chain id'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const GAS_URI_ETHEREUM: OptionType = (
+    "[uri gas] --gas=[gas] 'Creates a payment request with a specified gas limit'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const GAS_PRICE_URI_ETHEREUM: OptionType = (
+    "[uri gas price] --gas-price=[gas price] 'Creates a payment request with a specified gas price (in wei)'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const URI_ETHEREUM: OptionType = (
+    "<uri> 'Parses a specified Ethereum payment request URI (EIP-681)'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const VALUE_URI_ETHEREUM: OptionType = (
+    "[uri value] --value=[value] 'Creates a payment request with a specified value (in wei)'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const ADDRESS_URI_MONERO: OptionType = (
+    "[address] --address=[address] 'Creates a payment request to a specified recipient address'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const AMOUNT_URI_MONERO: OptionType = (
+    "[uri amount] --amount=[amount] 'Creates a payment request for a specified amount (in XMR)'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const DESCRIPTION_URI_MONERO: OptionType = (
+    "[uri description] --description=[description] 'Creates a payment request with a specified description of the payment'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const NETWORK_URI_MONERO: OptionType = (
+    "[network] -n --network=[network] 'Creates or parses a payment request for a specified network'",
+    &[],
+    &["mainnet", "stagenet", "testnet"],
+    &[],
+);
+
+pub const PAYMENT_ID_URI_MONERO: OptionType = (
+    "[uri payment id] --payment-id=[payment id] 'Creates a payment request with a specified payment ID (16 or 64 hex characters)'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const RECIPIENT_NAME_URI_MONERO: OptionType = (
+    "[uri recipient name] --recipient-name=[name] 'Creates a payment request with a specified name of the recipient'",
+    &[],
+    &[],
+    &[],
+);
+
+pub const URI_MONERO: OptionType = ("<uri> 'Parses a specified Monero payment request URI'", &[], &[], &[]);
+
 pub const CREATE_RAW_TRANSACTION_ZCASH: OptionType = (
     "[createrawtransaction] --createrawtransaction= [inputs] [outputs] 'Generates a raw Zcash transaction
     Inputs format: '[{\"txid\":\"txid\", \"vout\":index},...]'
@@ -431,9 +1751,20 @@ pub const TRANSACTION_EXPIRY_HEIGHT_ZCASH: OptionType = (
     &["createrawtransaction"],
 );
 
+pub const TRANSACTION_BRANCH_ZCASH: OptionType = (
+    "[branch] --branch=[branch] 'Specify the Zcash network upgrade to sign for (overwinter, sapling, blossom, heartwood, canopy, nu5, nu6) or a consensus branch id in hex'",
+    &[],
+    &[],
+    &[],
+);
+
 pub const TRANSACTION_VERSION_ZCASH: OptionType = (
-    "[version] --version=[version] 'Specify a Zcash transaction version'",
+    "[version] --version=[version] 'Specify a Zcash transaction version (overwinter is transparent-only)'",
     &["signrawtransaction"],
-    &["sapling"],
+    &["overwinter", "sapling"],
     &["createrawtransaction"],
 );
+
+// Validate
+
+pub const ADDRESS_VALIDATE: OptionType = ("<address> 'Validates a specified address'", &[], &[], &[]);
@@ -3,7 +3,65 @@ use crate::cli::{option, types::*};
 use clap::AppSettings;
 
 // Format
-// (name, about, options, settings)
+// (name, about, options, settings, subcommands)
+
+pub const CONVERT_BITCOIN: SubCommandType = (
+    "convert",
+    "Converts a private key to its compressed WIF, uncompressed WIF, or hex form (include -h for more options)",
+    &[
+        option::NETWORK_CONVERT_BITCOIN,
+        option::PRIVATE_CONVERT_BITCOIN,
+        option::TO_CONVERT_BITCOIN,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const CONVERT_ETHEREUM: SubCommandType = (
+    "convert",
+    "Converts a private key in hex, or the private key of a mnemonic, to its public keys and address (include -h for more options)",
+    &[
+        option::DERIVATION_CONVERT_ETHEREUM,
+        option::MNEMONIC_CONVERT_ETHEREUM,
+        option::PASSWORD_CONVERT_ETHEREUM,
+        option::PRIVATE_CONVERT_ETHEREUM,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const EXPORT_BITCOIN: SubCommandType = (
+    "export",
+    "Exports an HD wallet account for hardware wallet coordinators (include -h for more options)",
+    &[
+        option::ACCOUNT,
+        option::DERIVATION_EXPORT_BITCOIN,
+        option::EXTENDED_PUBLIC,
+        option::EXTENDED_PRIVATE,
+        option::FORMAT_EXPORT,
+        option::INDEX_RANGE_EXPORT,
+        option::MNEMONIC,
+        option::NETWORK_EXPORT_BITCOIN,
+        option::PASSWORD_IMPORT_HD,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
 
 pub const HD_BITCOIN: SubCommandType = (
     "hd",
@@ -11,9 +69,80 @@ pub const HD_BITCOIN: SubCommandType = (
     &[
         option::COUNT,
         option::DERIVATION_BITCOIN,
+        option::ENTROPY_HD,
+        option::ENTROPY_FILE_HD,
+        option::INDEX_RANGE_HD,
         option::LANGUAGE_HD,
         option::NETWORK_HD_BITCOIN,
         option::PASSWORD_HD,
+        option::SLIP132_HD_BITCOIN,
+        option::WORD_COUNT,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
+);
+
+pub const HD_BITCOINCASH: SubCommandType = (
+    "hd",
+    "Generates an HD wallet (include -h for more options)",
+    &[
+        option::COUNT,
+        option::DERIVATION_BITCOINCASH,
+        option::ENTROPY_HD,
+        option::ENTROPY_FILE_HD,
+        option::FORMAT_HD_BITCOINCASH,
+        option::INDEX_RANGE_HD,
+        option::LANGUAGE_HD,
+        option::NETWORK_HD_BITCOINCASH,
+        option::PASSWORD_HD,
+        option::WORD_COUNT,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
+);
+
+pub const HD_COSMOS: SubCommandType = (
+    "hd",
+    "Generates an HD wallet (include -h for more options)",
+    &[
+        option::COUNT,
+        option::DERIVATION_COSMOS,
+        option::ENTROPY_HD,
+        option::ENTROPY_FILE_HD,
+        option::HRP_HD_COSMOS,
+        option::INDEX_RANGE_HD,
+        option::LANGUAGE_HD,
+        option::PASSWORD_HD,
+        option::WORD_COUNT,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
+);
+
+pub const HD_DASH: SubCommandType = (
+    "hd",
+    "Generates an HD wallet (include -h for more options)",
+    &[
+        option::COUNT,
+        option::DERIVATION_DASH,
+        option::ENTROPY_HD,
+        option::ENTROPY_FILE_HD,
+        option::INDEX_RANGE_HD,
+        option::LANGUAGE_HD,
+        option::NETWORK_HD_DASH,
+        option::PASSWORD_HD,
         option::WORD_COUNT,
     ],
     &[
@@ -21,6 +150,29 @@ pub const HD_BITCOIN: SubCommandType = (
         AppSettings::DisableHelpSubcommand,
         AppSettings::DisableVersion,
     ],
+    &[],
+);
+
+pub const HD_DOGECOIN: SubCommandType = (
+    "hd",
+    "Generates an HD wallet (include -h for more options)",
+    &[
+        option::COUNT,
+        option::DERIVATION_DOGECOIN,
+        option::ENTROPY_HD,
+        option::ENTROPY_FILE_HD,
+        option::INDEX_RANGE_HD,
+        option::LANGUAGE_HD,
+        option::NETWORK_HD_DOGECOIN,
+        option::PASSWORD_HD,
+        option::WORD_COUNT,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
 );
 
 pub const HD_ETHEREUM: SubCommandType = (
@@ -29,8 +181,72 @@ pub const HD_ETHEREUM: SubCommandType = (
     &[
         option::COUNT,
         option::DERIVATION_ETHEREUM,
+        option::ENTROPY_HD,
+        option::ENTROPY_FILE_HD,
         option::INDEX_HD,
         option::INDICES_HD,
+        option::INDEX_RANGE_HD,
+        option::LANGUAGE_HD,
+        option::PASSWORD_HD,
+        option::WORD_COUNT,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
+);
+
+pub const HD_MONERO: SubCommandType = (
+    "hd",
+    "Generates an HD wallet (include -h for more options)",
+    &[
+        option::COUNT,
+        option::INDEX_RANGE_HD_MONERO,
+        option::INTEGRATED_HD_MONERO,
+        option::LANGUAGE_HD_MONERO,
+        option::NETWORK_HD_MONERO,
+        option::SUBADDRESS_HD_MONERO,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
+);
+
+pub const HD_SOLANA: SubCommandType = (
+    "hd",
+    "Generates an HD wallet (include -h for more options)",
+    &[
+        option::COUNT,
+        option::DERIVATION_SOLANA,
+        option::ENTROPY_HD,
+        option::ENTROPY_FILE_HD,
+        option::INDEX_RANGE_HD,
+        option::LANGUAGE_HD,
+        option::PASSWORD_HD,
+        option::WORD_COUNT,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
+);
+
+pub const HD_TRON: SubCommandType = (
+    "hd",
+    "Generates an HD wallet (include -h for more options)",
+    &[
+        option::COUNT,
+        option::DERIVATION_TRON,
+        option::ENTROPY_HD,
+        option::ENTROPY_FILE_HD,
+        option::INDEX_RANGE_HD,
         option::LANGUAGE_HD,
         option::PASSWORD_HD,
         option::WORD_COUNT,
@@ -40,22 +256,67 @@ pub const HD_ETHEREUM: SubCommandType = (
         AppSettings::DisableHelpSubcommand,
         AppSettings::DisableVersion,
     ],
+    &[],
 );
 
 pub const HD_ZCASH: SubCommandType = (
     "hd",
     "Generates an HD wallet (include -h for more options)",
     &[
+        option::ACCOUNT_HD_ZCASH,
         option::COUNT,
         option::DERIVATION_ZCASH,
         option::DIVERSIFIER_IMPORT_ZCASH,
+        option::DIVERSIFIER_INDEX_HD_ZCASH,
+        option::ENTROPY_HD,
+        option::ENTROPY_FILE_HD,
+        option::LANGUAGE_HD_ZCASH,
         option::NETWORK_HD_ZCASH,
+        option::PASSWORD_HD_ZCASH,
+        option::SHIELDED_HD_ZCASH,
+        option::VIEWING_KEY_ZCASH,
+        option::WORD_COUNT_HD_ZCASH,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
+);
+
+#[cfg(feature = "hw-ledger")]
+pub const HW_BITCOIN: SubCommandType = (
+    "hw",
+    "Fetches a wallet from a Ledger device (include -h for more options)",
+    &[
+        option::ACCOUNT,
+        option::CHAIN,
+        option::DERIVATION_HW_BITCOIN,
+        option::FORMAT_HW_BITCOIN,
+        option::INDEX_HW,
+        option::NETWORK_HW_BITCOIN,
+        option::VERIFY_HW,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
     ],
+    &[],
+);
+
+#[cfg(feature = "hw-ledger")]
+pub const HW_ETHEREUM: SubCommandType = (
+    "hw",
+    "Fetches a wallet from a Ledger device (include -h for more options)",
+    &[option::DERIVATION_HW_ETHEREUM, option::INDEX_HW, option::VERIFY_HW],
     &[
         AppSettings::ColoredHelp,
         AppSettings::DisableHelpSubcommand,
         AppSettings::DisableVersion,
     ],
+    &[],
 );
 
 pub const IMPORT_BITCOIN: SubCommandType = (
@@ -65,7 +326,7 @@ pub const IMPORT_BITCOIN: SubCommandType = (
         option::ADDRESS,
         option::FORMAT_IMPORT_BITCOIN,
         option::NETWORK_IMPORT_BITCOIN,
-        option::PRIVATE,
+        option::PRIVATE_IMPORT_BITCOIN,
         option::PUBLIC,
     ],
     &[
@@ -74,34 +335,36 @@ pub const IMPORT_BITCOIN: SubCommandType = (
         AppSettings::DisableVersion,
         AppSettings::ArgRequiredElseHelp,
     ],
+    &[],
 );
 
-pub const IMPORT_ETHEREUM: SubCommandType = (
+pub const IMPORT_BITCOINCASH: SubCommandType = (
     "import",
     "Imports a wallet (include -h for more options)",
-    &[option::ADDRESS, option::PRIVATE, option::PUBLIC],
+    &[
+        option::ADDRESS,
+        option::FORMAT_IMPORT_BITCOINCASH,
+        option::NETWORK_IMPORT_BITCOINCASH,
+        option::PRIVATE,
+        option::PUBLIC,
+    ],
     &[
         AppSettings::ColoredHelp,
         AppSettings::DisableHelpSubcommand,
         AppSettings::DisableVersion,
         AppSettings::ArgRequiredElseHelp,
     ],
+    &[],
 );
 
-pub const IMPORT_MONERO: SubCommandType = (
+pub const IMPORT_COSMOS: SubCommandType = (
     "import",
     "Imports a wallet (include -h for more options)",
     &[
         option::ADDRESS,
-        option::INTEGRATED_IMPORT_MONERO,
-        option::LANGUAGE_IMPORT_MONERO,
-        option::MNEMONIC_IMPORT_MONERO,
-        option::NETWORK_IMPORT_MONERO,
-        option::PRIVATE_SPEND_KEY_MONERO,
-        option::PRIVATE_VIEW_KEY_MONERO,
-        option::PUBLIC_SPEND_KEY_MONERO,
-        option::PUBLIC_VIEW_KEY_MONERO,
-        option::SUBADDRESS_IMPORT_MONERO,
+        option::HRP_IMPORT_COSMOS,
+        option::PRIVATE,
+        option::PUBLIC,
     ],
     &[
         AppSettings::ColoredHelp,
@@ -109,14 +372,15 @@ pub const IMPORT_MONERO: SubCommandType = (
         AppSettings::DisableVersion,
         AppSettings::ArgRequiredElseHelp,
     ],
+    &[],
 );
 
-pub const IMPORT_ZCASH: SubCommandType = (
+pub const IMPORT_DASH: SubCommandType = (
     "import",
     "Imports a wallet (include -h for more options)",
     &[
         option::ADDRESS,
-        option::DIVERSIFIER_IMPORT_ZCASH,
+        option::NETWORK_IMPORT_DASH,
         option::PRIVATE,
         option::PUBLIC,
     ],
@@ -126,21 +390,17 @@ pub const IMPORT_ZCASH: SubCommandType = (
         AppSettings::DisableVersion,
         AppSettings::ArgRequiredElseHelp,
     ],
+    &[],
 );
 
-pub const IMPORT_HD_BITCOIN: SubCommandType = (
-    "import-hd",
-    "Imports an HD wallet (include -h for more options)",
+pub const IMPORT_DOGECOIN: SubCommandType = (
+    "import",
+    "Imports a wallet (include -h for more options)",
     &[
-        option::ACCOUNT,
-        option::CHAIN,
-        option::DERIVATION_IMPORT_BITCOIN,
-        option::EXTENDED_PUBLIC,
-        option::EXTENDED_PRIVATE,
-        option::NETWORK_IMPORT_HD_BITCOIN,
-        option::INDEX_IMPORT_HD,
-        option::MNEMONIC,
-        option::PASSWORD_IMPORT_HD,
+        option::ADDRESS,
+        option::NETWORK_IMPORT_DOGECOIN,
+        option::PRIVATE,
+        option::PUBLIC,
     ],
     &[
         AppSettings::ColoredHelp,
@@ -148,38 +408,36 @@ pub const IMPORT_HD_BITCOIN: SubCommandType = (
         AppSettings::DisableVersion,
         AppSettings::ArgRequiredElseHelp,
     ],
+    &[],
 );
 
-pub const IMPORT_HD_ETHEREUM: SubCommandType = (
-    "import-hd",
-    "Imports an HD wallet (include -h for more options)",
-    &[
-        option::DERIVATION_IMPORT_ETHEREUM,
-        option::EXTENDED_PUBLIC,
-        option::EXTENDED_PRIVATE,
-        option::INDEX_IMPORT_HD,
-        option::INDICES_IMPORT_HD,
-        option::MNEMONIC,
-        option::PASSWORD_IMPORT_HD,
-    ],
+pub const IMPORT_ETHEREUM: SubCommandType = (
+    "import",
+    "Imports a wallet (include -h for more options)",
+    &[option::ADDRESS, option::PRIVATE, option::PUBLIC],
     &[
         AppSettings::ColoredHelp,
         AppSettings::DisableHelpSubcommand,
         AppSettings::DisableVersion,
         AppSettings::ArgRequiredElseHelp,
     ],
+    &[],
 );
 
-pub const IMPORT_HD_ZCASH: SubCommandType = (
-    "import-hd",
-    "Imports an HD wallet (include -h for more options)",
+pub const IMPORT_MONERO: SubCommandType = (
+    "import",
+    "Imports a wallet (include -h for more options)",
     &[
-        option::ACCOUNT,
-        option::DERIVATION_IMPORT_ZCASH,
-        option::DIVERSIFIER_IMPORT_HD_ZCASH,
-        option::EXTENDED_PUBLIC,
-        option::EXTENDED_PRIVATE,
-        option::INDEX_IMPORT_HD,
+        option::ADDRESS,
+        option::INTEGRATED_IMPORT_MONERO,
+        option::LANGUAGE_IMPORT_MONERO,
+        option::MNEMONIC_IMPORT_MONERO,
+        option::NETWORK_IMPORT_MONERO,
+        option::PRIVATE_SPEND_KEY_MONERO,
+        option::PRIVATE_VIEW_KEY_MONERO,
+        option::PUBLIC_SPEND_KEY_MONERO,
+        option::PUBLIC_VIEW_KEY_MONERO,
+        option::SUBADDRESS_IMPORT_MONERO,
     ],
     &[
         AppSettings::ColoredHelp,
@@ -187,16 +445,18 @@ pub const IMPORT_HD_ZCASH: SubCommandType = (
         AppSettings::DisableVersion,
         AppSettings::ArgRequiredElseHelp,
     ],
+    &[],
 );
 
-pub const TRANSACTION_BITCOIN: SubCommandType = (
-    "transaction",
-    "Generates a Bitcoin transaction (include -h for more options)",
+pub const IMPORT_RIPPLE: SubCommandType = (
+    "import",
+    "Imports a wallet (include -h for more options)",
     &[
-        option::CREATE_RAW_TRANSACTION_BITCOIN,
-        option::SIGN_RAW_TRANSACTION_BITCOIN,
-        option::TRANSACTION_LOCK_TIME_BITCOIN,
-        option::TRANSACTION_VERSION_BITCOIN,
+        option::ADDRESS,
+        option::NETWORK_IMPORT_RIPPLE,
+        option::PRIVATE_IMPORT_RIPPLE,
+        option::PUBLIC,
+        option::TAG_IMPORT_RIPPLE,
     ],
     &[
         AppSettings::ColoredHelp,
@@ -204,15 +464,17 @@ pub const TRANSACTION_BITCOIN: SubCommandType = (
         AppSettings::DisableVersion,
         AppSettings::ArgRequiredElseHelp,
     ],
+    &[],
 );
 
-pub const TRANSACTION_ETHEREUM: SubCommandType = (
-    "transaction",
-    "Generates a Ethereum transaction (include -h for more options)",
+pub const IMPORT_SOLANA: SubCommandType = (
+    "import",
+    "Imports a wallet (include -h for more options)",
     &[
-        option::CREATE_RAW_TRANSACTION_ETHEREUM,
-        option::SIGN_RAW_TRANSACTION_ETHEREUM,
-        option::TRANSACTION_NETWORK_ETHEREUM,
+        option::ADDRESS,
+        option::KEYPAIR_IMPORT_SOLANA,
+        option::PRIVATE,
+        option::PUBLIC,
     ],
     &[
         AppSettings::ColoredHelp,
@@ -220,14 +482,794 @@ pub const TRANSACTION_ETHEREUM: SubCommandType = (
         AppSettings::DisableVersion,
         AppSettings::ArgRequiredElseHelp,
     ],
+    &[],
 );
 
-pub const TRANSACTION_ZCASH: SubCommandType = (
-    "transaction",
+pub const IMPORT_TRON: SubCommandType = (
+    "import",
+    "Imports a wallet (include -h for more options)",
+    &[option::ADDRESS, option::PRIVATE, option::PUBLIC],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const IMPORT_ZCASH: SubCommandType = (
+    "import",
+    "Imports a wallet (include -h for more options)",
+    &[
+        option::ADDRESS,
+        option::DIVERSIFIER_IMPORT_ZCASH,
+        option::NETWORK_IMPORT_ZCASH,
+        option::PRIVATE_IMPORT_ZCASH,
+        option::PUBLIC,
+        option::VIEWING_KEY_ZCASH,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const IMPORT_HD_BITCOIN: SubCommandType = (
+    "import-hd",
+    "Imports an HD wallet (include -h for more options)",
+    &[
+        option::ACCOUNT,
+        option::CHAIN,
+        option::DERIVATION_IMPORT_BITCOIN,
+        option::EXTENDED_PUBLIC,
+        option::EXTENDED_PRIVATE,
+        option::NETWORK_IMPORT_HD_BITCOIN,
+        option::INDEX_IMPORT_HD,
+        option::INDEX_RANGE_IMPORT_HD,
+        option::MNEMONIC,
+        option::MNEMONIC_TYPE_BITCOIN,
+        option::NEUTER_IMPORT_HD,
+        option::PASSWORD_IMPORT_HD,
+        option::SHOW_ROOT_IMPORT_HD_BITCOIN,
+        option::SLIP132_IMPORT_HD_BITCOIN,
+        option::WORD_COUNT_IMPORT_HD,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const IMPORT_HD_BITCOINCASH: SubCommandType = (
+    "import-hd",
+    "Imports an HD wallet (include -h for more options)",
+    &[
+        option::ACCOUNT,
+        option::CHAIN,
+        option::DERIVATION_IMPORT_BITCOINCASH,
+        option::EXTENDED_PUBLIC,
+        option::EXTENDED_PRIVATE,
+        option::FORMAT_IMPORT_HD_BITCOINCASH,
+        option::NETWORK_IMPORT_HD_BITCOINCASH,
+        option::INDEX_IMPORT_HD,
+        option::INDEX_RANGE_IMPORT_HD,
+        option::MNEMONIC,
+        option::PASSWORD_IMPORT_HD,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const IMPORT_HD_COSMOS: SubCommandType = (
+    "import-hd",
+    "Imports an HD wallet (include -h for more options)",
+    &[
+        option::ACCOUNT,
+        option::CHAIN,
+        option::DERIVATION_IMPORT_COSMOS,
+        option::EXTENDED_PUBLIC,
+        option::EXTENDED_PRIVATE,
+        option::HRP_IMPORT_HD_COSMOS,
+        option::INDEX_IMPORT_HD,
+        option::INDEX_RANGE_IMPORT_HD,
+        option::MNEMONIC,
+        option::PASSWORD_IMPORT_HD,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const IMPORT_HD_DASH: SubCommandType = (
+    "import-hd",
+    "Imports an HD wallet (include -h for more options)",
+    &[
+        option::ACCOUNT,
+        option::CHAIN,
+        option::DERIVATION_IMPORT_DASH,
+        option::EXTENDED_PUBLIC,
+        option::EXTENDED_PRIVATE,
+        option::NETWORK_IMPORT_HD_DASH,
+        option::INDEX_IMPORT_HD,
+        option::INDEX_RANGE_IMPORT_HD,
+        option::MNEMONIC,
+        option::PASSWORD_IMPORT_HD,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const IMPORT_HD_DOGECOIN: SubCommandType = (
+    "import-hd",
+    "Imports an HD wallet (include -h for more options)",
+    &[
+        option::ACCOUNT,
+        option::CHAIN,
+        option::DERIVATION_IMPORT_DOGECOIN,
+        option::EXTENDED_PUBLIC,
+        option::EXTENDED_PRIVATE,
+        option::NETWORK_IMPORT_HD_DOGECOIN,
+        option::INDEX_IMPORT_HD,
+        option::INDEX_RANGE_IMPORT_HD,
+        option::MNEMONIC,
+        option::PASSWORD_IMPORT_HD,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const IMPORT_HD_ETHEREUM: SubCommandType = (
+    "import-hd",
+    "Imports an HD wallet (include -h for more options)",
+    &[
+        option::DERIVATION_IMPORT_ETHEREUM,
+        option::EXTENDED_PUBLIC,
+        option::EXTENDED_PRIVATE,
+        option::INDEX_IMPORT_HD,
+        option::INDICES_IMPORT_HD,
+        option::INDEX_RANGE_IMPORT_HD,
+        option::MNEMONIC,
+        option::NEUTER_IMPORT_HD,
+        option::PASSWORD_IMPORT_HD,
+        option::WORD_COUNT_IMPORT_HD,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const IMPORT_HD_MONERO: SubCommandType = (
+    "import-hd",
+    "Imports an HD wallet (include -h for more options)",
+    &[
+        option::INDEX_RANGE_IMPORT_HD_MONERO,
+        option::INTEGRATED_IMPORT_HD_MONERO,
+        option::LANGUAGE_IMPORT_HD_MONERO,
+        option::MNEMONIC_IMPORT_HD_MONERO,
+        option::NETWORK_IMPORT_HD_MONERO,
+        option::PRIVATE_SPEND_KEY_IMPORT_HD_MONERO,
+        option::SUBADDRESS_IMPORT_HD_MONERO,
+        option::VIEW_ONLY_IMPORT_HD_MONERO,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const IMPORT_HD_SOLANA: SubCommandType = (
+    "import-hd",
+    "Imports an HD wallet (include -h for more options)",
+    &[
+        option::DERIVATION_IMPORT_SOLANA,
+        option::EXTENDED_PUBLIC,
+        option::EXTENDED_PRIVATE,
+        option::INDEX_IMPORT_HD,
+        option::INDEX_RANGE_IMPORT_HD,
+        option::MNEMONIC,
+        option::PASSWORD_IMPORT_HD,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const IMPORT_HD_TRON: SubCommandType = (
+    "import-hd",
+    "Imports an HD wallet (include -h for more options)",
+    &[
+        option::ACCOUNT,
+        option::CHAIN,
+        option::DERIVATION_IMPORT_TRON,
+        option::EXTENDED_PUBLIC,
+        option::EXTENDED_PRIVATE,
+        option::INDEX_IMPORT_HD,
+        option::INDEX_RANGE_IMPORT_HD,
+        option::MNEMONIC,
+        option::PASSWORD_IMPORT_HD,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const IMPORT_HD_ZCASH: SubCommandType = (
+    "import-hd",
+    "Imports an HD wallet (include -h for more options)",
+    &[
+        option::ACCOUNT,
+        option::ALL_FORMATS_IMPORT_HD_ZCASH,
+        option::DERIVATION_IMPORT_ZCASH,
+        option::DIVERSIFIER_IMPORT_HD_ZCASH,
+        option::DIVERSIFIER_INDEX_IMPORT_HD_ZCASH,
+        option::EXTENDED_PUBLIC,
+        option::EXTENDED_PRIVATE,
+        option::INDEX_IMPORT_HD,
+        option::MNEMONIC,
+        option::NETWORK_IMPORT_HD_ZCASH,
+        option::PASSWORD_IMPORT_HD,
+        option::VIEWING_KEY_ZCASH,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const MINISCRIPT_BITCOIN: SubCommandType = (
+    "miniscript",
+    "Compiles a spending policy to miniscript and its P2WSH address (include -h for more options)",
+    &[option::NETWORK_MINISCRIPT_BITCOIN, option::POLICY_MINISCRIPT_BITCOIN],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const PAYMENT_CODE_BITCOIN: SubCommandType = (
+    "paymentcode",
+    "Derives a BIP-47 payment code and its deposit addresses (include -h for more options)",
+    &[
+        option::ACCOUNT_PAYMENT_CODE_BITCOIN,
+        option::COUNTERPARTY_PAYMENT_CODE_BITCOIN,
+        option::FORMAT_PAYMENT_CODE_BITCOIN,
+        option::INDEX_PAYMENT_CODE_BITCOIN,
+        option::INDEX_RANGE_PAYMENT_CODE_BITCOIN,
+        option::MNEMONIC_PAYMENT_CODE_BITCOIN,
+        option::NETWORK_PAYMENT_CODE_BITCOIN,
+        option::PASSWORD_PAYMENT_CODE_BITCOIN,
+        option::PAYMENT_CODE_BITCOIN,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const PAPER_BITCOIN: SubCommandType = (
+    "paper",
+    "Generates a printable paper wallet (include -h for more options)",
+    &[
+        option::COUNT,
+        option::DERIVATION_PAPER_BITCOIN,
+        option::FORMAT_PAPER_BITCOIN,
+        option::LANGUAGE_PAPER,
+        option::NETWORK_PAPER_BITCOIN,
+        option::OUTPUT_PAPER,
+        option::UNCOMPRESSED_PAPER_BITCOIN,
+        option::WORD_COUNT_PAPER,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
+);
+
+pub const PAPER_BITCOINCASH: SubCommandType = (
+    "paper",
+    "Generates a printable paper wallet (include -h for more options)",
+    &[
+        option::COUNT,
+        option::DERIVATION_PAPER_BITCOINCASH,
+        option::FORMAT_PAPER_BITCOINCASH,
+        option::LANGUAGE_PAPER,
+        option::NETWORK_PAPER_BITCOINCASH,
+        option::OUTPUT_PAPER,
+        option::WORD_COUNT_PAPER,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
+);
+
+pub const PAPER_COSMOS: SubCommandType = (
+    "paper",
+    "Generates a printable paper wallet (include -h for more options)",
+    &[
+        option::COUNT,
+        option::DERIVATION_PAPER_COSMOS,
+        option::HRP_PAPER_COSMOS,
+        option::LANGUAGE_PAPER,
+        option::OUTPUT_PAPER,
+        option::WORD_COUNT_PAPER,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
+);
+
+pub const PAPER_DASH: SubCommandType = (
+    "paper",
+    "Generates a printable paper wallet (include -h for more options)",
+    &[
+        option::COUNT,
+        option::DERIVATION_PAPER_DASH,
+        option::LANGUAGE_PAPER,
+        option::NETWORK_PAPER_DASH,
+        option::OUTPUT_PAPER,
+        option::WORD_COUNT_PAPER,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
+);
+
+pub const PAPER_DOGECOIN: SubCommandType = (
+    "paper",
+    "Generates a printable paper wallet (include -h for more options)",
+    &[
+        option::COUNT,
+        option::DERIVATION_PAPER_DOGECOIN,
+        option::LANGUAGE_PAPER,
+        option::NETWORK_PAPER_DOGECOIN,
+        option::OUTPUT_PAPER,
+        option::WORD_COUNT_PAPER,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
+);
+
+pub const PAPER_ETHEREUM: SubCommandType = (
+    "paper",
+    "Generates a printable paper wallet (include -h for more options)",
+    &[
+        option::COUNT,
+        option::DERIVATION_PAPER_ETHEREUM,
+        option::LANGUAGE_PAPER,
+        option::OUTPUT_PAPER,
+        option::WORD_COUNT_PAPER,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
+);
+
+pub const PAPER_MONERO: SubCommandType = (
+    "paper",
+    "Generates a printable paper wallet (include -h for more options)",
+    &[
+        option::COUNT,
+        option::LANGUAGE_PAPER_MONERO,
+        option::NETWORK_PAPER_MONERO,
+        option::OUTPUT_PAPER,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
+);
+
+pub const PAPER_RIPPLE: SubCommandType = (
+    "paper",
+    "Generates a printable paper wallet (include -h for more options)",
+    &[
+        option::COUNT,
+        option::KEY_TYPE_PAPER_RIPPLE,
+        option::NETWORK_PAPER_RIPPLE,
+        option::OUTPUT_PAPER,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
+);
+
+pub const PAPER_SOLANA: SubCommandType = (
+    "paper",
+    "Generates a printable paper wallet (include -h for more options)",
+    &[
+        option::COUNT,
+        option::DERIVATION_PAPER_SOLANA,
+        option::LANGUAGE_PAPER,
+        option::OUTPUT_PAPER,
+        option::WORD_COUNT_PAPER,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
+);
+
+pub const PAPER_TRON: SubCommandType = (
+    "paper",
+    "Generates a printable paper wallet (include -h for more options)",
+    &[
+        option::COUNT,
+        option::DERIVATION_PAPER_TRON,
+        option::LANGUAGE_PAPER,
+        option::OUTPUT_PAPER,
+        option::WORD_COUNT_PAPER,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
+);
+
+pub const PAPER_ZCASH: SubCommandType = (
+    "paper",
+    "Generates a printable paper wallet (include -h for more options)",
+    &[
+        option::COUNT,
+        option::FORMAT_PAPER_ZCASH,
+        option::NETWORK_PAPER_ZCASH,
+        option::OUTPUT_PAPER,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+    ],
+    &[],
+);
+
+pub const RECOVER_ETHEREUM: SubCommandType = (
+    "recover",
+    "Recovers the public key and address that signed a message or a transaction hash (include -h for more options)",
+    &[
+        option::MESSAGE_RECOVER_ETHEREUM,
+        option::SIGNATURE_RECOVER_ETHEREUM,
+        option::TX_HASH_RECOVER_ETHEREUM,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const TRANSACTION_BITCOIN: SubCommandType = (
+    "transaction",
+    "Generates a Bitcoin transaction (include -h for more options)",
+    &[
+        option::CREATE_RAW_TRANSACTION_BITCOIN,
+        option::SIGN_RAW_TRANSACTION_BITCOIN,
+        option::TRANSACTION_LOCK_TIME_BITCOIN,
+        option::TRANSACTION_VERSION_BITCOIN,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[
+        TRANSACTION_BUMP_FEE_BITCOIN,
+        TRANSACTION_CONSOLIDATE_BITCOIN,
+        TRANSACTION_CREATE_BITCOIN,
+        TRANSACTION_DECODE_BITCOIN,
+        #[cfg(feature = "broadcast")]
+        TRANSACTION_SEND_BITCOIN,
+        TRANSACTION_SIGN_BITCOIN,
+    ],
+);
+
+pub const TRANSACTION_BUMP_FEE_BITCOIN: NestedSubCommandType = (
+    "bump-fee",
+    "Replaces a signed Bitcoin transaction with one of a higher fee, and signs it offline (include -h for more options)",
+    &[
+        option::CHANGE_OUTPUT_TRANSACTION_BITCOIN,
+        option::DERIVATION_TRANSACTION_BITCOIN,
+        option::BUMP_FEE_RATE_TRANSACTION_BITCOIN,
+        option::FORMAT_TRANSACTION_BITCOIN,
+        option::INPUTS_TRANSACTION_BITCOIN,
+        option::MNEMONIC_TRANSACTION_BITCOIN,
+        option::NETWORK_TRANSACTION_BITCOIN,
+        option::PASSWORD_TRANSACTION_BITCOIN,
+        option::PRIVATE_TRANSACTION_BITCOIN,
+        option::RAW_TRANSACTION_BITCOIN,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+);
+
+pub const TRANSACTION_CONSOLIDATE_BITCOIN: NestedSubCommandType = (
+    "consolidate",
+    "Consolidates the unspent outputs of an HD account into a single output, and signs it offline (include -h for more options)",
+    &[
+        option::EXTENDED_PRIVATE_TRANSACTION_BITCOIN,
+        option::FEE_RATE_TRANSACTION_BITCOIN,
+        option::LOCK_TIME_TRANSACTION_BITCOIN,
+        option::MAX_FEE_PERCENT_TRANSACTION_BITCOIN,
+        option::NETWORK_TRANSACTION_BITCOIN,
+        option::RBF_TRANSACTION_BITCOIN,
+        option::TO_CONSOLIDATE_TRANSACTION_BITCOIN,
+        option::UTXOS_CONSOLIDATE_TRANSACTION_BITCOIN,
+        option::VERSION_TRANSACTION_BITCOIN,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+);
+
+pub const TRANSACTION_CREATE_BITCOIN: NestedSubCommandType = (
+    "create",
+    "Creates an unsigned Bitcoin transaction from specified inputs and outputs (include -h for more options)",
+    &[
+        option::CHANGE_TRANSACTION_BITCOIN,
+        option::COIN_SELECTION_TRANSACTION_BITCOIN,
+        option::FEE_RATE_TRANSACTION_BITCOIN,
+        option::INPUTS_TRANSACTION_BITCOIN,
+        option::LOCK_TIME_TRANSACTION_BITCOIN,
+        option::MAX_FEE_PERCENT_TRANSACTION_BITCOIN,
+        option::NETWORK_TRANSACTION_BITCOIN,
+        option::OP_RETURN_TRANSACTION_BITCOIN,
+        option::OUTPUTS_TRANSACTION_BITCOIN,
+        option::RBF_TRANSACTION_BITCOIN,
+        option::UTXOS_TRANSACTION_BITCOIN,
+        option::VERSION_TRANSACTION_BITCOIN,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+);
+
+pub const TRANSACTION_DECODE_BITCOIN: NestedSubCommandType = (
+    "decode",
+    "Decodes a raw Bitcoin transaction (include -h for more options)",
+    &[option::NETWORK_TRANSACTION_BITCOIN, option::RAW_TRANSACTION_BITCOIN],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+);
+
+#[cfg(feature = "broadcast")]
+pub const TRANSACTION_SEND_BITCOIN: NestedSubCommandType = (
+    "send",
+    "Broadcasts a signed Bitcoin transaction to a node (include -h for more options)",
+    &[option::RAW_TRANSACTION_BITCOIN, option::RPC_TRANSACTION],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+);
+
+pub const TRANSACTION_SIGN_BITCOIN: NestedSubCommandType = (
+    "sign",
+    "Signs a raw Bitcoin transaction offline (include -h for more options)",
+    &[
+        option::DERIVATION_TRANSACTION_BITCOIN,
+        option::FORMAT_TRANSACTION_BITCOIN,
+        option::INPUTS_TRANSACTION_BITCOIN,
+        option::MNEMONIC_TRANSACTION_BITCOIN,
+        option::NETWORK_TRANSACTION_BITCOIN,
+        option::PASSWORD_TRANSACTION_BITCOIN,
+        option::PRIVATE_TRANSACTION_BITCOIN,
+        option::RAW_TRANSACTION_BITCOIN,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+);
+
+pub const TRANSACTION_ETHEREUM: SubCommandType = (
+    "transaction",
+    "Generates a Ethereum transaction (include -h for more options)",
+    &[
+        option::CREATE_RAW_TRANSACTION_ETHEREUM,
+        option::SIGN_RAW_TRANSACTION_ETHEREUM,
+        option::TRANSACTION_NETWORK_ETHEREUM,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[
+        TRANSACTION_DECODE_ETHEREUM,
+        #[cfg(feature = "broadcast")]
+        TRANSACTION_SEND_ETHEREUM,
+        TRANSACTION_SIGN_ETHEREUM,
+        TRANSACTION_SWEEP_ETHEREUM,
+    ],
+);
+
+pub const TRANSACTION_DECODE_ETHEREUM: NestedSubCommandType = (
+    "decode",
+    "Decodes a raw Ethereum transaction and recovers its sender (include -h for more options)",
+    &[option::RAW_TRANSACTION_ETHEREUM],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+);
+
+#[cfg(feature = "broadcast")]
+pub const TRANSACTION_SEND_ETHEREUM: NestedSubCommandType = (
+    "send",
+    "Broadcasts a signed Ethereum transaction to a node (include -h for more options)",
+    &[option::RAW_SEND_TRANSACTION_ETHEREUM, option::RPC_TRANSACTION],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+);
+
+pub const TRANSACTION_SIGN_ETHEREUM: NestedSubCommandType = (
+    "sign",
+    "Creates and signs an Ethereum transaction offline (include -h for more options)",
+    &[
+        option::ACCESS_LIST_ETHEREUM,
+        option::CALL_ETHEREUM,
+        option::CHAIN_ID_ETHEREUM,
+        option::DATA_ETHEREUM,
+        #[cfg(feature = "broadcast")]
+        option::ESTIMATE_GAS_ETHEREUM,
+        #[cfg(feature = "broadcast")]
+        option::FETCH_NONCE_ETHEREUM,
+        option::GAS_ETHEREUM,
+        option::GAS_PRICE_ETHEREUM,
+        option::NETWORK_SIGN_ETHEREUM,
+        option::NONCE_ETHEREUM,
+        option::PRIVATE_TRANSACTION_ETHEREUM,
+        #[cfg(feature = "broadcast")]
+        option::RPC_SIGN_TRANSACTION_ETHEREUM,
+        option::TO_ETHEREUM,
+        option::UNPROTECTED_ETHEREUM,
+        option::VALUE_ETHEREUM,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+);
+
+pub const TRANSACTION_SWEEP_ETHEREUM: NestedSubCommandType = (
+    "sweep",
+    "Signs an Ethereum transaction sending the whole balance of the sender, less the fee (include -h for more options)",
+    &[
+        option::BALANCE_ETHEREUM,
+        option::CHAIN_ID_ETHEREUM,
+        #[cfg(feature = "broadcast")]
+        option::FETCH_NONCE_ETHEREUM,
+        option::GAS_ETHEREUM,
+        option::GAS_PRICE_ETHEREUM,
+        option::NETWORK_SIGN_ETHEREUM,
+        option::NONCE_ETHEREUM,
+        option::PRIVATE_TRANSACTION_ETHEREUM,
+        #[cfg(feature = "broadcast")]
+        option::RPC_SIGN_TRANSACTION_ETHEREUM,
+        option::TO_ETHEREUM,
+        option::UNPROTECTED_ETHEREUM,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+);
+
+pub const TRANSACTION_ZCASH: SubCommandType = (
+    "transaction",
     "Generates a Zcash transaction (include -h for more options)",
     &[
         option::CREATE_RAW_TRANSACTION_ZCASH,
         option::SIGN_RAW_TRANSACTION_ZCASH,
+        option::TRANSACTION_BRANCH_ZCASH,
         option::TRANSACTION_EXPIRY_HEIGHT_ZCASH,
         option::TRANSACTION_LOCK_TIME_ZCASH,
         option::TRANSACTION_VERSION_ZCASH,
@@ -238,4 +1280,278 @@ pub const TRANSACTION_ZCASH: SubCommandType = (
         AppSettings::DisableVersion,
         AppSettings::ArgRequiredElseHelp,
     ],
+    &[],
+);
+
+pub const URI_BITCOIN: SubCommandType = (
+    "uri",
+    "Creates or parses a Bitcoin payment request URI (include -h for more options)",
+    &[],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[URI_CREATE_BITCOIN, URI_PARSE_BITCOIN],
+);
+
+pub const URI_CREATE_BITCOIN: NestedSubCommandType = (
+    "create",
+    "Creates a Bitcoin payment request URI (BIP-21) (include -h for more options)",
+    &[
+        option::ADDRESS_URI_BITCOIN,
+        option::AMOUNT_URI_BITCOIN,
+        option::LABEL_URI_BITCOIN,
+        option::MESSAGE_URI_BITCOIN,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+);
+
+pub const URI_PARSE_BITCOIN: NestedSubCommandType = (
+    "parse",
+    "Parses a Bitcoin payment request URI (BIP-21) (include -h for more options)",
+    &[option::URI_BITCOIN],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+);
+
+pub const URI_ETHEREUM: SubCommandType = (
+    "uri",
+    "Creates or parses an Ethereum payment request URI (include -h for more options)",
+    &[],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[URI_CREATE_ETHEREUM, URI_PARSE_ETHEREUM],
+);
+
+pub const URI_CREATE_ETHEREUM: NestedSubCommandType = (
+    "create",
+    "Creates an Ethereum payment request URI (EIP-681) (include -h for more options)",
+    &[
+        option::ADDRESS_URI_ETHEREUM,
+        option::CALL_URI_ETHEREUM,
+        option::CHAIN_ID_URI_ETHEREUM,
+        option::GAS_URI_ETHEREUM,
+        option::GAS_PRICE_URI_ETHEREUM,
+        option::VALUE_URI_ETHEREUM,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+);
+
+pub const URI_PARSE_ETHEREUM: NestedSubCommandType = (
+    "parse",
+    "Parses an Ethereum payment request URI (EIP-681) (include -h for more options)",
+    &[option::URI_ETHEREUM],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+);
+
+pub const URI_MONERO: SubCommandType = (
+    "uri",
+    "Creates or parses a Monero payment request URI (include -h for more options)",
+    &[],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[URI_CREATE_MONERO, URI_PARSE_MONERO],
+);
+
+pub const URI_CREATE_MONERO: NestedSubCommandType = (
+    "create",
+    "Creates a Monero payment request URI (include -h for more options)",
+    &[
+        option::ADDRESS_URI_MONERO,
+        option::AMOUNT_URI_MONERO,
+        option::DESCRIPTION_URI_MONERO,
+        option::NETWORK_URI_MONERO,
+        option::PAYMENT_ID_URI_MONERO,
+        option::RECIPIENT_NAME_URI_MONERO,
+    ],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+);
+
+pub const URI_PARSE_MONERO: NestedSubCommandType = (
+    "parse",
+    "Parses a Monero payment request URI (include -h for more options)",
+    &[option::NETWORK_URI_MONERO, option::URI_MONERO],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+);
+
+pub const VALIDATE_BITCOIN: SubCommandType = (
+    "validate",
+    "Validates an address and prints its network, format, checksum, and payload (include -h for more options)",
+    &[option::ADDRESS_VALIDATE],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const VALIDATE_BITCOINCASH: SubCommandType = (
+    "validate",
+    "Validates an address and prints its network, format, checksum, and payload (include -h for more options)",
+    &[option::ADDRESS_VALIDATE],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const VALIDATE_COSMOS: SubCommandType = (
+    "validate",
+    "Validates an address and prints its network, format, checksum, and payload (include -h for more options)",
+    &[option::ADDRESS_VALIDATE],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const VALIDATE_DASH: SubCommandType = (
+    "validate",
+    "Validates an address and prints its network, format, checksum, and payload (include -h for more options)",
+    &[option::ADDRESS_VALIDATE],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const VALIDATE_DOGECOIN: SubCommandType = (
+    "validate",
+    "Validates an address and prints its network, format, checksum, and payload (include -h for more options)",
+    &[option::ADDRESS_VALIDATE],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const VALIDATE_ETHEREUM: SubCommandType = (
+    "validate",
+    "Validates an address and prints its network, format, checksum, and payload (include -h for more options)",
+    &[option::ADDRESS_VALIDATE],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const VALIDATE_MONERO: SubCommandType = (
+    "validate",
+    "Validates an address and prints its network, format, checksum, and payload (include -h for more options)",
+    &[option::ADDRESS_VALIDATE],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const VALIDATE_RIPPLE: SubCommandType = (
+    "validate",
+    "Validates an address and prints its network, format, checksum, and payload (include -h for more options)",
+    &[option::ADDRESS_VALIDATE],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const VALIDATE_SOLANA: SubCommandType = (
+    "validate",
+    "Validates an address and prints its network, format, and payload (include -h for more options)",
+    &[option::ADDRESS_VALIDATE],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const VALIDATE_TRON: SubCommandType = (
+    "validate",
+    "Validates an address and prints its network, format, checksum, and payload (include -h for more options)",
+    &[option::ADDRESS_VALIDATE],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
+);
+
+pub const VALIDATE_ZCASH: SubCommandType = (
+    "validate",
+    "Validates an address and prints its network, format, checksum, and payload (include -h for more options)",
+    &[option::ADDRESS_VALIDATE],
+    &[
+        AppSettings::ColoredHelp,
+        AppSettings::DisableHelpSubcommand,
+        AppSettings::DisableVersion,
+        AppSettings::ArgRequiredElseHelp,
+    ],
+    &[],
 );
@@ -9,4 +9,11 @@ pub type OptionType = (
     &'static [&'static str],
     &'static [&'static str],
 );
-pub type SubCommandType = (NameType, AboutType, &'static [OptionType], &'static [AppSettings]);
+pub type SubCommandType = (
+    NameType,
+    AboutType,
+    &'static [OptionType],
+    &'static [AppSettings],
+    &'static [NestedSubCommandType],
+);
+pub type NestedSubCommandType = (NameType, AboutType, &'static [OptionType], &'static [AppSettings]);
@@ -0,0 +1,99 @@
+use crate::cli::CLIError;
+
+use image::Luma;
+use qrcode::render::{svg, unicode::Dense1x2};
+use qrcode::QrCode;
+use std::path::Path;
+
+use crate::model::no_std::{format, String, ToString};
+
+/// The minimum width and height of QR code images, in pixels
+const IMAGE_DIMENSION: u32 = 256;
+
+/// Renders the given value as a QR code, printing it to the terminal if `print` is enabled
+/// and writing it to an image file if `path` is specified. The given index of the value is appended
+/// to the file name after the first value, e.g. `out.png`, `out-1.png`, to render several values to files.
+pub fn output(value: &str, index: usize, print: bool, path: Option<&str>) -> Result<(), CLIError> {
+    if !print && path.is_none() {
+        return Ok(());
+    }
+    let code = QrCode::new(value.as_bytes())?;
+
+    if print {
+        // Invert the colors, as terminals are typically light text on a dark background
+        let image = code
+            .render::<Dense1x2>()
+            .dark_color(Dense1x2::Light)
+            .light_color(Dense1x2::Dark)
+            .build();
+        println!("{}\n", image);
+    }
+
+    if let Some(path) = path {
+        save(&code, &to_indexed_path(path, index))?;
+    }
+    Ok(())
+}
+
+/// Writes the given QR code to an image file, encoded as SVG or PNG by the file extension.
+fn save(code: &QrCode, path: &str) -> Result<(), CLIError> {
+    match Path::new(path).extension().and_then(|extension| extension.to_str()) {
+        Some("svg") => {
+            let image = code
+                .render::<svg::Color>()
+                .min_dimensions(IMAGE_DIMENSION, IMAGE_DIMENSION)
+                .build();
+            std::fs::write(path, image)?;
+        }
+        Some("png") => {
+            code.render::<Luma<u8>>()
+                .min_dimensions(IMAGE_DIMENSION, IMAGE_DIMENSION)
+                .build()
+                .save(path)?;
+        }
+        _ => return Err(CLIError::UnsupportedFormat(format!("QR code file {}", path))),
+    };
+    Ok(())
+}
+
+/// Returns the file path with the given index appended to its file name, if the index is nonzero.
+pub fn to_indexed_path(path: &str, index: usize) -> String {
+    let file = Path::new(path);
+    match (index, file.file_stem(), file.extension()) {
+        (0, _, _) => path.to_string(),
+        (_, Some(stem), Some(extension)) => file
+            .with_file_name(format!(
+                "{}-{}.{}",
+                stem.to_string_lossy(),
+                index,
+                extension.to_string_lossy()
+            ))
+            .to_string_lossy()
+            .to_string(),
+        _ => format!("{}-{}", path, index),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_indexed_path() {
+        assert_eq!("out.png", super::to_indexed_path("out.png", 0));
+        assert_eq!("out-2.svg", super::to_indexed_path("out.svg", 2));
+        assert_eq!("out-1", super::to_indexed_path("out", 1));
+        assert_eq!("qr.d/out-1.png", super::to_indexed_path("qr.d/out.png", 1));
+    }
+
+    #[test]
+    fn save() {
+        let code = QrCode::new(b"1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2").unwrap();
+        let path = std::env::temp_dir().join("wagyu-qr-test.svg");
+        super::save(&code, path.to_str().unwrap()).unwrap();
+        assert!(std::fs::read_to_string(&path).unwrap().contains("<svg"));
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(super::save(&code, "out.gif").is_err());
+    }
+}
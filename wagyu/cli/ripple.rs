@@ -0,0 +1,642 @@
+use crate::cli::diagnostics::{self, AddressDiagnostics};
+use crate::cli::entropy::Entropy;
+use crate::cli::output::WalletWriter;
+use crate::cli::vectors::TestVectors;
+use crate::cli::{
+    fingerprint, flag, option, paper, paper::PaperWallet, qr, subcommand, types::*, value_or_stdin, wallet_rng,
+    CLIError, CLI,
+};
+use crate::model::{crypto::checksum, AddressError, Network, PrivateKey, PublicKey};
+use crate::ripple::{
+    FromRippleBase58, Mainnet as RippleMainnet, RippleAddress, RippleFormat, RippleKeyType, RippleNetwork,
+    RipplePrivateKey, RipplePublicKey, Testnet as RippleTestnet,
+};
+
+use clap::ArgMatches;
+use colored::*;
+use core::{fmt, fmt::Display, iter, str::FromStr};
+use rand::Rng;
+use serde::Serialize;
+use zeroize::Zeroize;
+
+use crate::model::no_std::{format, vec, String, ToOwned, ToString, Vec};
+
+/// Represents a generic wallet to output
+#[derive(Serialize, Debug, Default)]
+pub struct RippleWallet {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+    pub address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+impl Zeroize for RippleWallet {
+    /// Overwrites the secret fields of the wallet.
+    fn zeroize(&mut self) {
+        self.seed.zeroize();
+        self.private_key.zeroize();
+    }
+}
+
+impl RippleWallet {
+    /// The fields of the wallet, in the order of the columns of a CSV output
+    pub const FIELDS: &'static [&'static str] = &[
+        "seed",
+        "key_type",
+        "private_key",
+        "public_key",
+        "address",
+        "fingerprint",
+        "x_address",
+        "tag",
+        "network",
+    ];
+
+    /// Withholds the secrets of the wallet from its output, leaving its public material
+    /// and the fingerprint of its public key.
+    pub fn public_only(&mut self) {
+        self.fingerprint = self.public_key.as_deref().or(self.address.as_deref()).map(fingerprint);
+        self.zeroize();
+    }
+
+    pub fn new<N: RippleNetwork, R: Rng>(
+        rng: &mut R,
+        key_type: RippleKeyType,
+        tag: Option<u32>,
+    ) -> Result<Self, CLIError> {
+        let private_key = RipplePrivateKey::<N>::new_with_key_type(rng, key_type)?;
+        Self::from_ripple_private_key(&private_key, tag)
+    }
+
+    /// Returns the mainnet test vectors of the entropy: a wallet of each key type.
+    pub fn test_vectors(entropy: &mut Entropy) -> Result<TestVectors<Self>, CLIError> {
+        Ok(TestVectors {
+            wallets: vec![
+                Self::new::<RippleMainnet, _>(entropy, RippleKeyType::Secp256k1, None)?,
+                Self::new::<RippleMainnet, _>(entropy, RippleKeyType::Ed25519, None)?,
+            ],
+            transactions: vec![],
+        })
+    }
+
+    pub fn from_private_key<N: RippleNetwork>(private_key: &str, tag: Option<u32>) -> Result<Self, CLIError> {
+        let private_key = RipplePrivateKey::<N>::from_str(private_key)?;
+        Self::from_ripple_private_key(&private_key, tag)
+    }
+
+    fn from_ripple_private_key<N: RippleNetwork>(
+        private_key: &RipplePrivateKey<N>,
+        tag: Option<u32>,
+    ) -> Result<Self, CLIError> {
+        let public_key = private_key.to_public_key();
+        Ok(Self {
+            seed: private_key.to_seed(),
+            private_key: Some(private_key.to_string()),
+            ..Self::from_ripple_public_key(&public_key, tag)?
+        })
+    }
+
+    pub fn from_public_key<N: RippleNetwork>(public_key: &str, tag: Option<u32>) -> Result<Self, CLIError> {
+        let public_key = RipplePublicKey::<N>::from_str(public_key)?;
+        Self::from_ripple_public_key(&public_key, tag)
+    }
+
+    fn from_ripple_public_key<N: RippleNetwork>(
+        public_key: &RipplePublicKey<N>,
+        tag: Option<u32>,
+    ) -> Result<Self, CLIError> {
+        let address = public_key.to_address(&RippleFormat::Classic)?;
+        Ok(Self {
+            key_type: Some(public_key.key_type().to_string()),
+            public_key: Some(public_key.to_string()),
+            ..Self::from_ripple_address(&address.to_x_address(tag)?)?
+        })
+    }
+
+    pub fn from_address<N: RippleNetwork>(address: &str) -> Result<Self, CLIError> {
+        let address = RippleAddress::<N>::from_str(address)?;
+        Self::from_ripple_address(&address)
+    }
+
+    fn from_ripple_address<N: RippleNetwork>(address: &RippleAddress<N>) -> Result<Self, CLIError> {
+        let classic_address = address.to_classic_address()?;
+        let x_address = address.to_x_address(address.tag())?;
+        Ok(Self {
+            address: Some(classic_address.to_string()),
+            x_address: Some(x_address.to_string()),
+            tag: address.tag(),
+            network: Some(N::NAME.to_string()),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the X-address of a wallet with a destination tag, or else its address or public key,
+    /// to render as a QR code.
+    pub fn to_qr_data(&self) -> Option<&str> {
+        match self.tag {
+            Some(_) => self.x_address.as_deref(),
+            None => self.address.as_deref().or(self.public_key.as_deref()),
+        }
+    }
+
+    /// Returns the address, seed or private key, and remaining fields of the wallet, to render on paper.
+    pub fn to_paper_wallet(&self) -> Option<PaperWallet<'_>> {
+        let address = self.address.as_deref()?;
+        let secret = match (self.seed.as_deref(), self.private_key.as_deref()) {
+            (Some(seed), _) => ("Seed", seed),
+            (None, Some(private_key)) => ("Private Key", private_key),
+            (None, None) => return None,
+        };
+        let fields = [
+            ("Private Key", self.private_key.as_deref()),
+            ("Key Type", self.key_type.as_deref()),
+            ("X-Address", self.x_address.as_deref()),
+            ("Network", self.network.as_deref()),
+        ]
+        .iter()
+        .filter(|(label, _)| *label != secret.0)
+        .filter_map(|(label, value)| value.map(|value| (*label, value)))
+        .collect();
+
+        Some(PaperWallet {
+            address,
+            secret,
+            fields,
+        })
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+impl Display for RippleWallet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let output = [
+            match &self.seed {
+                Some(seed) => format!("      {}         {}\n", "Seed".cyan().bold(), seed),
+                _ => "".to_owned(),
+            },
+            match &self.key_type {
+                Some(key_type) => format!("      {}     {}\n", "Key Type".cyan().bold(), key_type),
+                _ => "".to_owned(),
+            },
+            match &self.private_key {
+                Some(private_key) => format!("      {}  {}\n", "Private Key".cyan().bold(), private_key),
+                _ => "".to_owned(),
+            },
+            match &self.public_key {
+                Some(public_key) => format!("      {}   {}\n", "Public Key".cyan().bold(), public_key),
+                _ => "".to_owned(),
+            },
+            match &self.address {
+                Some(address) => format!("      {}      {}\n", "Address".cyan().bold(), address),
+                _ => "".to_owned(),
+            },
+            match &self.fingerprint {
+                Some(fingerprint) => format!("      {}  {}\n", "Fingerprint".cyan().bold(), fingerprint),
+                _ => "".to_owned(),
+            },
+            match &self.x_address {
+                Some(x_address) => format!("      {}    {}\n", "X-Address".cyan().bold(), x_address),
+                _ => "".to_owned(),
+            },
+            match &self.tag {
+                Some(tag) => format!("      {}          {}\n", "Tag".cyan().bold(), tag),
+                _ => "".to_owned(),
+            },
+            match &self.network {
+                Some(network) => format!("      {}      {}\n", "Network".cyan().bold(), network),
+                _ => "".to_owned(),
+            },
+        ]
+        .concat();
+
+        // Removes final new line character
+        let output = output[..output.len() - 1].to_owned();
+        write!(f, "\n{}", output)
+    }
+}
+
+/// Represents options for a Ripple wallet
+#[derive(Clone, Debug, Serialize)]
+pub struct RippleOptions {
+    // Standard command
+    audit_rng: bool,
+    count: usize,
+    key_type: String,
+    network: String,
+    output_file: Option<String>,
+    output_format: String,
+    public_only: bool,
+    qr: bool,
+    qr_file: Option<String>,
+    subcommand: Option<String>,
+    tag: Option<u32>,
+    // Import subcommand
+    address: Option<String>,
+    private: Option<String>,
+    public: Option<String>,
+    // Paper subcommand
+    paper_file: Option<String>,
+}
+
+impl Default for RippleOptions {
+    fn default() -> Self {
+        Self {
+            // Standard command
+            audit_rng: false,
+            count: 1,
+            key_type: "secp256k1".into(),
+            network: "mainnet".into(),
+            output_file: None,
+            output_format: "text".into(),
+            public_only: false,
+            qr: false,
+            qr_file: None,
+            subcommand: None,
+            tag: None,
+            // Import subcommand
+            address: None,
+            private: None,
+            public: None,
+            // Paper subcommand
+            paper_file: None,
+        }
+    }
+}
+
+impl RippleOptions {
+    fn parse(&mut self, arguments: &ArgMatches, options: &[&str]) {
+        options.iter().for_each(|option| match *option {
+            "address" => self.address(arguments.value_of(option)),
+            "audit rng" => self.audit_rng(arguments.is_present(option)),
+            "count" => self.count(clap::value_t!(arguments.value_of(*option), usize).ok()),
+            "json" => self.json(arguments.is_present(option)),
+            "key type" => self.key_type(arguments.value_of(option)),
+            "network" => self.network(arguments.value_of(option)),
+            "output file" => self.output_file(arguments.value_of(option)),
+            "output format" => self.output_format(arguments.value_of(option)),
+            "paper file" => self.paper_file(arguments.value_of(option)),
+            "private" => self.private(value_or_stdin(arguments, option).as_deref()),
+            "public" => self.public(arguments.value_of(option)),
+            "public only" => self.public_only(arguments.is_present(option)),
+            "qr" => self.qr(arguments.is_present(option)),
+            "qr file" => self.qr_file(arguments.value_of(option)),
+            "tag" => self.tag(clap::value_t!(arguments.value_of(*option), u32).ok()),
+            _ => (),
+        });
+    }
+
+    /// Imports a wallet for the specified address, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn address(&mut self, argument: Option<&str>) {
+        if let Some(address) = argument {
+            self.address = Some(address.to_string());
+        }
+    }
+
+    /// Enables `audit_rng` if the specified boolean value is set.
+    /// Once enabled at any level of the command, the entropy drawn remains audited.
+    fn audit_rng(&mut self, argument: bool) {
+        if argument {
+            self.audit_rng = true;
+        }
+    }
+
+    /// Sets `count` to the specified count, overriding its previous state.
+    fn count(&mut self, argument: Option<usize>) {
+        if let Some(count) = argument {
+            self.count = count;
+        }
+    }
+
+    /// Sets `output_format` to JSON if the specified boolean value is set.
+    /// Once enabled at any level of the command, JSON remains the output format.
+    fn json(&mut self, argument: bool) {
+        if argument {
+            self.output_format = "json".into();
+        }
+    }
+
+    /// Sets `key_type` to the specified key type, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn key_type(&mut self, argument: Option<&str>) {
+        match argument {
+            Some("ed25519") => self.key_type = "ed25519".into(),
+            Some("secp256k1") => self.key_type = "secp256k1".into(),
+            _ => (),
+        };
+    }
+
+    /// Sets `network` to the specified network, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn network(&mut self, argument: Option<&str>) {
+        match argument {
+            Some("mainnet") => self.network = "mainnet".into(),
+            Some("testnet") => self.network = "testnet".into(),
+            _ => (),
+        };
+    }
+
+    /// Sets `output_file` to the specified file path, overriding its previous state.
+    /// Colored output is disabled, as the file is not a terminal.
+    /// If the specified argument is `None`, then no change occurs.
+    fn output_file(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.output_file = Some(path.to_string());
+            colored::control::set_override(false);
+        }
+    }
+
+    /// Sets `output_format` to the specified output format, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn output_format(&mut self, argument: Option<&str>) {
+        if let Some(output_format) = argument {
+            self.output_format = output_format.to_string();
+        }
+    }
+
+    /// Sets `paper_file` to the specified file path, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn paper_file(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.paper_file = Some(path.to_string());
+        }
+    }
+
+    /// Imports a wallet for the specified private key or seed, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn private(&mut self, argument: Option<&str>) {
+        if let Some(private_key) = argument {
+            self.private = Some(private_key.to_string());
+        }
+    }
+
+    /// Imports a wallet for the specified public key, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn public(&mut self, argument: Option<&str>) {
+        if let Some(public_key) = argument {
+            self.public = Some(public_key.to_string())
+        }
+    }
+
+    /// Enables `public_only` if the specified boolean value is set.
+    /// Once enabled at any level of the command, the secrets of the wallet(s) remain withheld.
+    fn public_only(&mut self, argument: bool) {
+        if argument {
+            self.public_only = true;
+        }
+    }
+
+    /// Sets `qr` to the specified boolean value, overriding its previous state.
+    fn qr(&mut self, argument: bool) {
+        self.qr = argument;
+    }
+
+    /// Sets `qr_file` to the specified file path, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn qr_file(&mut self, argument: Option<&str>) {
+        if let Some(qr_file) = argument {
+            self.qr_file = Some(qr_file.to_string());
+        }
+    }
+
+    /// Sets `tag` to the specified destination tag, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn tag(&mut self, argument: Option<u32>) {
+        if let Some(tag) = argument {
+            self.tag = Some(tag);
+        }
+    }
+
+    /// Returns the key type of the wallets to generate.
+    fn to_key_type(&self) -> RippleKeyType {
+        match self.key_type.as_str() {
+            "ed25519" => RippleKeyType::Ed25519,
+            _ => RippleKeyType::Secp256k1,
+        }
+    }
+}
+
+pub struct RippleCLI;
+
+impl CLI for RippleCLI {
+    type Options = RippleOptions;
+
+    const NAME: NameType = "ripple";
+    const ABOUT: AboutType = "Generates a Ripple wallet (include -h for more options)";
+    const FLAGS: &'static [FlagType] = &[
+        flag::AUDIT_RNG,
+        flag::JSON,
+        flag::OUTPUT_FILE,
+        flag::OUTPUT_FORMAT,
+        flag::PUBLIC_ONLY,
+        flag::QR,
+        flag::QR_FILE,
+    ];
+    const OPTIONS: &'static [OptionType] = &[
+        option::COUNT,
+        option::KEY_TYPE_RIPPLE,
+        option::NETWORK_RIPPLE,
+        option::TAG_RIPPLE,
+    ];
+    const SUBCOMMANDS: &'static [SubCommandType] = &[
+        subcommand::IMPORT_RIPPLE,
+        subcommand::PAPER_RIPPLE,
+        subcommand::VALIDATE_RIPPLE,
+    ];
+
+    /// Handle all CLI arguments and flags for Ripple
+    #[cfg_attr(tarpaulin, skip)]
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        let mut options = RippleOptions::default();
+        options.parse(
+            arguments,
+            &[
+                "audit rng",
+                "count",
+                "json",
+                "key type",
+                "network",
+                "output file",
+                "output format",
+                "public only",
+                "qr",
+                "qr file",
+                "tag",
+            ],
+        );
+
+        match arguments.subcommand() {
+            ("import", Some(arguments)) => {
+                options.subcommand = Some("import".into());
+                options.parse(
+                    arguments,
+                    &[
+                        "json",
+                        "network",
+                        "output file",
+                        "output format",
+                        "public only",
+                        "qr",
+                        "qr file",
+                    ],
+                );
+                options.parse(arguments, &["address", "private", "public", "tag"]);
+            }
+            ("paper", Some(arguments)) => {
+                options.subcommand = Some("paper".into());
+                options.parse(arguments, &["audit rng", "count", "key type", "network", "paper file"]);
+            }
+            ("validate", Some(arguments)) => {
+                options.subcommand = Some("validate".into());
+                options.parse(arguments, &["json", "output file", "output format", "public only"]);
+                options.parse(arguments, &["address"]);
+            }
+            _ => {}
+        };
+
+        Ok(options)
+    }
+
+    /// Generate the Ripple wallet and print the relevant fields
+    #[cfg_attr(tarpaulin, skip)]
+    fn print(options: Self::Options) -> Result<(), CLIError> {
+        fn output<N: RippleNetwork>(options: RippleOptions) -> Result<(), CLIError> {
+            if let Some("validate") = options.subcommand.as_deref() {
+                return validate(options);
+            }
+
+            // Generate new wallets lazily, to stream a large count of wallets to the output
+            let (key_type, tag) = (options.to_key_type(), options.tag);
+            let mut rng = wallet_rng(options.audit_rng);
+            let generated: Box<dyn Iterator<Item = RippleWallet>> = match options.subcommand.as_deref() {
+                None | Some("paper") => Box::new((0..options.count).flat_map(move |_| {
+                    match RippleWallet::new::<N, _>(&mut rng, key_type, tag) {
+                        Ok(wallet) => vec![wallet],
+                        _ => vec![],
+                    }
+                })),
+                _ => Box::new(iter::empty()),
+            };
+
+            let wallets = match options.subcommand.as_deref() {
+                Some("import") => {
+                    if let Some(private_key) = options.private {
+                        vec![RippleWallet::from_private_key::<N>(&private_key, tag)?]
+                    } else if let Some(public_key) = options.public {
+                        vec![RippleWallet::from_public_key::<N>(&public_key, tag)?]
+                    } else if let Some(address) = options.address {
+                        // An X-address encodes its network, which is read from its prefix
+                        vec![RippleWallet::from_address::<RippleMainnet>(&address)
+                            .or_else(|_| RippleWallet::from_address::<RippleTestnet>(&address))?]
+                    } else {
+                        vec![]
+                    }
+                }
+                _ => vec![],
+            };
+            let wallets = wallets.into_iter().chain(generated);
+
+            match options.paper_file.as_deref() {
+                Some(path) => {
+                    if options.public_only {
+                        return Err(CLIError::UnsupportedFormat("paper wallet with --public-only".into()));
+                    }
+                    let mut wallets = wallets.collect::<Vec<RippleWallet>>();
+                    let paper_wallets = wallets
+                        .iter()
+                        .filter_map(RippleWallet::to_paper_wallet)
+                        .collect::<Vec<PaperWallet>>();
+                    paper::output("Ripple", &paper_wallets, path)?;
+                    wallets.iter_mut().for_each(Zeroize::zeroize);
+                }
+                None => {
+                    let path = options.output_file.as_deref();
+                    let mut writer = WalletWriter::new(&options.output_format, path, RippleWallet::FIELDS)?;
+                    for (index, mut wallet) in wallets.enumerate() {
+                        if options.public_only {
+                            wallet.public_only();
+                        }
+                        writer.write(&wallet)?;
+                        if let Some(qr_data) = wallet.to_qr_data() {
+                            qr::output(qr_data, index, options.qr, options.qr_file.as_deref())?;
+                        }
+                        wallet.zeroize();
+                    }
+                    writer.finish()?;
+                }
+            };
+
+            Ok(())
+        }
+
+        /// Validates the address, printing its network, format, checksum, and payload
+        fn validate(options: RippleOptions) -> Result<(), CLIError> {
+            let address = options.address.as_deref().unwrap_or_default();
+            let mut diagnostics = AddressDiagnostics::new(address);
+
+            // Decode the checksum and payload of the base58 address, and return its prefix.
+            // A classic address has a 1-byte prefix, and an X-address has a 2-byte prefix.
+            let prefix = match address.from_ripple_base58() {
+                Ok(data) if data.len() >= 5 => {
+                    let (data, expected) = data.split_at(data.len() - 4);
+                    diagnostics.checksum = Some(checksum(data)[0..4] == *expected);
+                    let prefix_length = if data.len() > 21 { 2 } else { 1 };
+                    diagnostics.payload = Some(hex::encode(&data[prefix_length..]));
+                    data[..prefix_length].to_vec()
+                }
+                Ok(data) => {
+                    diagnostics.error(AddressError::InvalidByteLength(data.len()));
+                    vec![]
+                }
+                Err(error) => {
+                    diagnostics.error(AddressError::from(error));
+                    vec![]
+                }
+            };
+
+            // Parse the address for the network of its prefix, to verify its format and length.
+            // A classic address is valid on every network.
+            let format = match prefix.as_slice() {
+                [0x00] => RippleAddress::<RippleMainnet>::from_str(address).map(|address| address.format()),
+                prefix if prefix == RippleMainnet::X_ADDRESS_PREFIX => {
+                    diagnostics.network = Some(RippleMainnet::NAME.into());
+                    RippleAddress::<RippleMainnet>::from_str(address).map(|address| address.format())
+                }
+                prefix if prefix == RippleTestnet::X_ADDRESS_PREFIX => {
+                    diagnostics.network = Some(RippleTestnet::NAME.into());
+                    RippleAddress::<RippleTestnet>::from_str(address).map(|address| address.format())
+                }
+                prefix => Err(AddressError::InvalidPrefix(prefix.to_vec())),
+            };
+            match format {
+                Ok(format) => diagnostics.format = Some(format.to_string()),
+                Err(AddressError::InvalidChecksum(_, _)) => {}
+                Err(error) => diagnostics.error(error),
+            };
+
+            diagnostics::output(
+                &diagnostics.finish(),
+                &options.output_format,
+                options.output_file.as_deref(),
+            )
+        }
+
+        match options.network.as_str() {
+            "testnet" => output::<RippleTestnet>(options),
+            _ => output::<RippleMainnet>(options),
+        }
+    }
+}
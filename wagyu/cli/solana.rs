@@ -0,0 +1,1079 @@
+use crate::cli::diagnostics::{self, AddressDiagnostics};
+use crate::cli::entropy::Entropy;
+use crate::cli::output::{self, WalletWriter};
+use crate::cli::vectors::TestVectors;
+use crate::cli::{
+    fingerprint, flag, master_fingerprint, option, paper, paper::PaperWallet, parse_index_range, qr, subcommand,
+    types::*, value_or_stdin, wallet_id, wallet_rng, CLIError, CLI,
+};
+use crate::model::{
+    AddressError, Bip39Language, ExtendedPrivateKey, ExtendedPublicKey, Mnemonic, MnemonicCount, MnemonicExtended,
+    Network, PrivateKey, PublicKey,
+};
+use crate::solana::{
+    wordlist::*, Mainnet as SolanaMainnet, SolanaAddress, SolanaDerivationPath, SolanaExtendedPrivateKey,
+    SolanaExtendedPublicKey, SolanaFormat, SolanaMnemonic, SolanaNetwork, SolanaPrivateKey, SolanaPublicKey,
+    SolanaWordlist,
+};
+
+use base58::FromBase58;
+use clap::ArgMatches;
+use colored::*;
+use core::{fmt, fmt::Display, iter, str::FromStr};
+use rand::Rng;
+use serde::Serialize;
+use zeroize::Zeroize;
+
+use crate::model::no_std::{format, vec, String, ToOwned, ToString, Vec};
+
+/// Represents a generic wallet to output
+#[derive(Serialize, Debug, Default)]
+pub struct SolanaWallet {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mnemonic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extended_private_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extended_public_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+    pub address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub master_fingerprint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wallet_id: Option<String>,
+}
+
+impl Zeroize for SolanaWallet {
+    /// Overwrites the secret fields of the wallet.
+    fn zeroize(&mut self) {
+        self.password.zeroize();
+        self.mnemonic.zeroize();
+        self.extended_private_key.zeroize();
+        self.private_key.zeroize();
+    }
+}
+
+impl SolanaWallet {
+    /// The fields of the wallet, in the order of the columns of a CSV output
+    pub const FIELDS: &'static [&'static str] = &[
+        "path",
+        "password",
+        "mnemonic",
+        "extended_private_key",
+        "extended_public_key",
+        "private_key",
+        "public_key",
+        "address",
+        "fingerprint",
+        "master_fingerprint",
+        "wallet_id",
+    ];
+
+    /// Withholds the secrets of the wallet from its output, leaving its public material
+    /// and the fingerprint of its public key.
+    pub fn public_only(&mut self) {
+        self.fingerprint = self.public_key.as_deref().or(self.address.as_deref()).map(fingerprint);
+        self.zeroize();
+    }
+
+    pub fn new<R: Rng>(rng: &mut R) -> Result<Self, CLIError> {
+        let private_key = SolanaPrivateKey::new(rng)?;
+        let public_key = private_key.to_public_key();
+        let address = public_key.to_address(&SolanaFormat::Standard)?;
+        Ok(Self {
+            private_key: Some(private_key.to_string()),
+            public_key: Some(public_key.to_string()),
+            address: Some(address.to_string()),
+            ..Default::default()
+        })
+    }
+
+    pub fn new_hd<N: SolanaNetwork, W: SolanaWordlist>(
+        entropy: &mut Entropy,
+        word_count: u8,
+        password: Option<&str>,
+        path: &str,
+    ) -> Result<Self, CLIError> {
+        let mnemonic = SolanaMnemonic::<N, W>::new_with_count(entropy, word_count)?;
+        let master_extended_private_key = mnemonic.to_extended_private_key(password)?;
+        let derivation_path = SolanaDerivationPath::from_str(path)?;
+        let extended_private_key = master_extended_private_key.derive(&derivation_path)?;
+        let extended_public_key = extended_private_key.to_extended_public_key();
+        let private_key = extended_private_key.to_private_key();
+        let public_key = extended_public_key.to_public_key();
+        let address = public_key.to_address(&SolanaFormat::Standard)?;
+        Ok(Self {
+            path: Some(path.to_string()),
+            password: password.map(String::from),
+            mnemonic: Some(mnemonic.to_string()),
+            extended_private_key: Some(extended_private_key.to_string()),
+            extended_public_key: Some(extended_public_key.to_string()),
+            master_fingerprint: master_fingerprint(&master_extended_private_key.to_extended_public_key().to_string()),
+            wallet_id: Some(wallet_id(&extended_public_key.to_string())),
+            private_key: Some(private_key.to_string()),
+            public_key: Some(public_key.to_string()),
+            address: Some(address.to_string()),
+            fingerprint: None,
+        })
+    }
+
+    /// Returns the mainnet test vectors of the entropy: an HD wallet, derived as by Phantom and Solflare,
+    /// and a wallet of a private key.
+    pub fn test_vectors(entropy: &mut Entropy) -> Result<TestVectors<Self>, CLIError> {
+        Ok(TestVectors {
+            wallets: vec![
+                Self::new_hd::<SolanaMainnet, English>(entropy, 24, None, "m/44'/501'/0'/0'")?,
+                Self::new(entropy)?,
+            ],
+            transactions: vec![],
+        })
+    }
+
+    pub fn from_mnemonic<N: SolanaNetwork, W: SolanaWordlist>(
+        mnemonic: &str,
+        password: &Option<&str>,
+        path: &str,
+    ) -> Result<Self, CLIError> {
+        let mnemonic = SolanaMnemonic::<N, W>::from_phrase(mnemonic)?;
+        let master_extended_private_key = mnemonic.to_extended_private_key(*password)?;
+        let derivation_path = SolanaDerivationPath::from_str(path)?;
+        let extended_private_key = master_extended_private_key.derive(&derivation_path)?;
+        let extended_public_key = extended_private_key.to_extended_public_key();
+        let private_key = extended_private_key.to_private_key();
+        let public_key = extended_public_key.to_public_key();
+        let address = public_key.to_address(&SolanaFormat::Standard)?;
+        Ok(Self {
+            path: Some(path.to_string()),
+            password: password.map(String::from),
+            mnemonic: Some(mnemonic.to_string()),
+            extended_private_key: Some(extended_private_key.to_string()),
+            extended_public_key: Some(extended_public_key.to_string()),
+            master_fingerprint: master_fingerprint(&master_extended_private_key.to_extended_public_key().to_string()),
+            wallet_id: Some(wallet_id(&extended_public_key.to_string())),
+            private_key: Some(private_key.to_string()),
+            public_key: Some(public_key.to_string()),
+            address: Some(address.to_string()),
+            fingerprint: None,
+        })
+    }
+
+    /// Returns the wallet of the mnemonic, in the language detected from its words.
+    pub fn from_mnemonic_any_language<N: SolanaNetwork>(
+        mnemonic: &str,
+        password: &Option<&str>,
+        path: &str,
+    ) -> Result<Self, CLIError> {
+        match Bip39Language::detect_language(mnemonic)? {
+            Bip39Language::ChineseSimplified => Self::from_mnemonic::<N, ChineseSimplified>(mnemonic, password, path),
+            Bip39Language::ChineseTraditional => Self::from_mnemonic::<N, ChineseTraditional>(mnemonic, password, path),
+            Bip39Language::English => Self::from_mnemonic::<N, English>(mnemonic, password, path),
+            Bip39Language::French => Self::from_mnemonic::<N, French>(mnemonic, password, path),
+            Bip39Language::Italian => Self::from_mnemonic::<N, Italian>(mnemonic, password, path),
+            Bip39Language::Japanese => Self::from_mnemonic::<N, Japanese>(mnemonic, password, path),
+            Bip39Language::Korean => Self::from_mnemonic::<N, Korean>(mnemonic, password, path),
+            Bip39Language::Spanish => Self::from_mnemonic::<N, Spanish>(mnemonic, password, path),
+        }
+    }
+
+    pub fn from_extended_private_key<N: SolanaNetwork>(
+        extended_private_key: &str,
+        path: &Option<String>,
+    ) -> Result<Self, CLIError> {
+        let mut extended_private_key = SolanaExtendedPrivateKey::<N>::from_str(extended_private_key)?;
+        let master_fingerprint = master_fingerprint(&extended_private_key.to_extended_public_key().to_string());
+        if let Some(derivation_path) = path {
+            let derivation_path = SolanaDerivationPath::from_str(derivation_path)?;
+            extended_private_key = extended_private_key.derive(&derivation_path)?;
+        }
+        let extended_public_key = extended_private_key.to_extended_public_key();
+        let private_key = extended_private_key.to_private_key();
+        let public_key = extended_public_key.to_public_key();
+        let address = public_key.to_address(&SolanaFormat::Standard)?;
+        Ok(Self {
+            path: path.clone(),
+            extended_private_key: Some(extended_private_key.to_string()),
+            extended_public_key: Some(extended_public_key.to_string()),
+            master_fingerprint,
+            wallet_id: Some(wallet_id(&extended_public_key.to_string())),
+            private_key: Some(private_key.to_string()),
+            public_key: Some(public_key.to_string()),
+            address: Some(address.to_string()),
+            ..Default::default()
+        })
+    }
+
+    pub fn from_extended_public_key<N: SolanaNetwork>(
+        extended_public_key: &str,
+        path: &Option<String>,
+    ) -> Result<Self, CLIError> {
+        let master_fingerprint = master_fingerprint(extended_public_key);
+        let mut extended_public_key = SolanaExtendedPublicKey::<N>::from_str(extended_public_key)?;
+        if let Some(derivation_path) = path {
+            let derivation_path = SolanaDerivationPath::from_str(derivation_path)?;
+            extended_public_key = extended_public_key.derive(&derivation_path)?;
+        }
+        let public_key = extended_public_key.to_public_key();
+        let address = public_key.to_address(&SolanaFormat::Standard)?;
+        Ok(Self {
+            path: path.clone(),
+            extended_public_key: Some(extended_public_key.to_string()),
+            master_fingerprint,
+            wallet_id: Some(wallet_id(&extended_public_key.to_string())),
+            public_key: Some(public_key.to_string()),
+            address: Some(address.to_string()),
+            ..Default::default()
+        })
+    }
+
+    pub fn from_private_key(private_key: &str) -> Result<Self, CLIError> {
+        let private_key = SolanaPrivateKey::from_str(private_key)?;
+        let public_key = private_key.to_public_key();
+        let address = public_key.to_address(&SolanaFormat::Standard)?;
+        Ok(Self {
+            private_key: Some(private_key.to_string()),
+            public_key: Some(public_key.to_string()),
+            address: Some(address.to_string()),
+            ..Default::default()
+        })
+    }
+
+    pub fn from_keypair(keypair: &str) -> Result<Self, CLIError> {
+        let private_key = SolanaPrivateKey::from_json_keypair(keypair)?;
+        let public_key = private_key.to_public_key();
+        let address = public_key.to_address(&SolanaFormat::Standard)?;
+        Ok(Self {
+            private_key: Some(private_key.to_string()),
+            public_key: Some(public_key.to_string()),
+            address: Some(address.to_string()),
+            ..Default::default()
+        })
+    }
+
+    pub fn from_public_key(public_key: &str) -> Result<Self, CLIError> {
+        let public_key = SolanaPublicKey::from_str(public_key)?;
+        let address = public_key.to_address(&SolanaFormat::Standard)?;
+        Ok(Self {
+            public_key: Some(public_key.to_string()),
+            address: Some(address.to_string()),
+            ..Default::default()
+        })
+    }
+
+    pub fn from_address(address: &str) -> Result<Self, CLIError> {
+        let address = SolanaAddress::from_str(address)?;
+        Ok(Self {
+            address: Some(address.to_string()),
+            ..Default::default()
+        })
+    }
+
+    /// Returns the private key of the wallet as a solana-keygen keypair file, if the wallet has a private key.
+    pub fn to_json_keypair(&self) -> Result<Option<String>, CLIError> {
+        match self.private_key.as_deref() {
+            Some(private_key) => Ok(Some(SolanaPrivateKey::from_str(private_key)?.to_json_keypair())),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the address or public key of the wallet, to render as a QR code.
+    pub fn to_qr_data(&self) -> Option<&str> {
+        self.address.as_deref().or(self.public_key.as_deref())
+    }
+
+    /// Returns the address, mnemonic or private key, and remaining fields of the wallet, to render on paper.
+    pub fn to_paper_wallet(&self) -> Option<PaperWallet<'_>> {
+        let address = self.address.as_deref()?;
+        let secret = match (self.mnemonic.as_deref(), self.private_key.as_deref()) {
+            (Some(mnemonic), _) => ("Mnemonic", mnemonic),
+            (None, Some(private_key)) => ("Private Key", private_key),
+            (None, None) => return None,
+        };
+        let fields = [
+            ("Path", self.path.as_deref()),
+            ("Private Key", self.private_key.as_deref()),
+        ]
+        .iter()
+        .filter(|(label, _)| *label != secret.0)
+        .filter_map(|(label, value)| value.map(|value| (*label, value)))
+        .collect();
+
+        Some(PaperWallet {
+            address,
+            secret,
+            fields,
+        })
+    }
+}
+
+#[cfg_attr(tarpaulin, skip)]
+impl Display for SolanaWallet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let output = [
+            match &self.path {
+                Some(path) => format!("      {}                 {}\n", "Path".cyan().bold(), path),
+                _ => "".to_owned(),
+            },
+            match &self.password {
+                Some(password) => format!("      {}             {}\n", "Password".cyan().bold(), password),
+                _ => "".to_owned(),
+            },
+            match &self.mnemonic {
+                Some(mnemonic) => format!("      {}             {}\n", "Mnemonic".cyan().bold(), mnemonic),
+                _ => "".to_owned(),
+            },
+            match &self.extended_private_key {
+                Some(extended_private_key) => format!(
+                    "      {} {}\n",
+                    "Extended Private Key".cyan().bold(),
+                    extended_private_key
+                ),
+                _ => "".to_owned(),
+            },
+            match &self.extended_public_key {
+                Some(extended_public_key) => format!(
+                    "      {}  {}\n",
+                    "Extended Public Key".cyan().bold(),
+                    extended_public_key
+                ),
+                _ => "".to_owned(),
+            },
+            match &self.private_key {
+                Some(private_key) => format!("      {}          {}\n", "Private Key".cyan().bold(), private_key),
+                _ => "".to_owned(),
+            },
+            match &self.public_key {
+                Some(public_key) => format!("      {}           {}\n", "Public Key".cyan().bold(), public_key),
+                _ => "".to_owned(),
+            },
+            match &self.address {
+                Some(address) => format!("      {}              {}\n", "Address".cyan().bold(), address),
+                _ => "".to_owned(),
+            },
+            match &self.fingerprint {
+                Some(fingerprint) => format!("      {}          {}\n", "Fingerprint".cyan().bold(), fingerprint),
+                _ => "".to_owned(),
+            },
+            match &self.master_fingerprint {
+                Some(master_fingerprint) => format!(
+                    "      {}   {}\n",
+                    "Master Fingerprint".cyan().bold(),
+                    master_fingerprint
+                ),
+                _ => "".to_owned(),
+            },
+            match &self.wallet_id {
+                Some(wallet_id) => format!("      {}            {}\n", "Wallet ID".cyan().bold(), wallet_id),
+                _ => "".to_owned(),
+            },
+        ]
+        .concat();
+
+        // Removes final new line character
+        let output = output[..output.len() - 1].to_owned();
+        write!(f, "\n{}", output)
+    }
+}
+
+/// Represents options for a Solana wallet
+#[derive(Clone, Debug, Serialize)]
+pub struct SolanaOptions {
+    // Standard command
+    audit_rng: bool,
+    count: usize,
+    output_file: Option<String>,
+    output_format: String,
+    public_only: bool,
+    qr: bool,
+    keypair_file: Option<String>,
+    qr_file: Option<String>,
+    subcommand: Option<String>,
+    // HD and Import HD subcommands
+    derivation: String,
+    entropy: Option<String>,
+    entropy_file: Option<String>,
+    extended_private_key: Option<String>,
+    extended_public_key: Option<String>,
+    index: u32,
+    indices: u32,
+    language: String,
+    mnemonic: Option<String>,
+    password: Option<String>,
+    path: Option<String>,
+    word_count: u8,
+    // Import subcommand
+    address: Option<String>,
+    keypair: Option<String>,
+    private: Option<String>,
+    public: Option<String>,
+    // Paper subcommand
+    paper_file: Option<String>,
+}
+
+impl Default for SolanaOptions {
+    fn default() -> Self {
+        Self {
+            // Standard command
+            audit_rng: false,
+            count: 1,
+            output_file: None,
+            output_format: "text".into(),
+            public_only: false,
+            qr: false,
+            keypair_file: None,
+            qr_file: None,
+            subcommand: None,
+            // HD and Import HD subcommands
+            derivation: "bip44".into(),
+            entropy: None,
+            entropy_file: None,
+            extended_private_key: None,
+            extended_public_key: None,
+            index: 0,
+            indices: 1,
+            language: "english".into(),
+            mnemonic: None,
+            password: None,
+            path: None,
+            word_count: 12,
+            // Import subcommand
+            address: None,
+            keypair: None,
+            private: None,
+            public: None,
+            // Paper subcommand
+            paper_file: None,
+        }
+    }
+}
+
+impl SolanaOptions {
+    fn parse(&mut self, arguments: &ArgMatches, options: &[&str]) -> Result<(), CLIError> {
+        for option in options {
+            match *option {
+                "address" => self.address(arguments.value_of(option)),
+                "audit rng" => self.audit_rng(arguments.is_present(option)),
+                "count" => self.count(clap::value_t!(arguments.value_of(*option), usize).ok()),
+                "derivation" => self.derivation(arguments.value_of(option)),
+                "entropy" => self.entropy(arguments.value_of(option)),
+                "entropy file" => self.entropy_file(arguments.value_of(option)),
+                "extended private" => self.extended_private(arguments.value_of(option)),
+                "extended public" => self.extended_public(arguments.value_of(option)),
+                "index" => self.index(clap::value_t!(arguments.value_of(*option), u32).ok()),
+                "index range" => {
+                    self.index_range(arguments.value_of(option))?;
+                }
+                "json" => self.json(arguments.is_present(option)),
+                "keypair" => self.keypair(arguments.value_of(option)),
+                "keypair file" => self.keypair_file(arguments.value_of(option)),
+                "language" => self.language(arguments.value_of(option)),
+                "mnemonic" => self.mnemonic(value_or_stdin(arguments, option).as_deref()),
+                "output file" => self.output_file(arguments.value_of(option)),
+                "output format" => self.output_format(arguments.value_of(option)),
+                "paper file" => self.paper_file(arguments.value_of(option)),
+                "password" => self.password(value_or_stdin(arguments, option).as_deref()),
+                "private" => self.private(value_or_stdin(arguments, option).as_deref()),
+                "public" => self.public(arguments.value_of(option)),
+                "public only" => self.public_only(arguments.is_present(option)),
+                "qr" => self.qr(arguments.is_present(option)),
+                "qr file" => self.qr_file(arguments.value_of(option)),
+                "word count" => self.word_count(clap::value_t!(arguments.value_of(*option), u8).ok()),
+                _ => (),
+            };
+        }
+        Ok(())
+    }
+
+    /// Imports a wallet for the specified address, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn address(&mut self, argument: Option<&str>) {
+        if let Some(address) = argument {
+            self.address = Some(address.to_string());
+        }
+    }
+
+    /// Enables `audit_rng` if the specified boolean value is set.
+    /// Once enabled at any level of the command, the entropy drawn remains audited.
+    fn audit_rng(&mut self, argument: bool) {
+        if argument {
+            self.audit_rng = true;
+        }
+    }
+
+    /// Sets `count` to the specified count, overriding its previous state.
+    fn count(&mut self, argument: Option<usize>) {
+        if let Some(count) = argument {
+            self.count = count;
+        }
+    }
+
+    /// Sets `derivation` to the specified derivation, overriding its previous state.
+    /// If `derivation` is `\"custom\"`, then `path` is set to the specified path.
+    /// If the specified argument is `None`, then no change occurs.
+    fn derivation(&mut self, argument: Option<&str>) {
+        match argument {
+            Some("bip44") => self.derivation = "bip44".into(),
+            Some("ledger") => self.derivation = "ledger".into(),
+            Some(custom) => {
+                self.derivation = "custom".into();
+                self.path = Some(custom.to_string());
+            }
+            _ => (),
+        };
+    }
+
+    /// Sets `entropy` to the specified hex entropy, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn entropy(&mut self, argument: Option<&str>) {
+        if let Some(entropy) = argument {
+            self.entropy = Some(entropy.to_string());
+        }
+    }
+
+    /// Sets `entropy_file` to the specified entropy file path, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn entropy_file(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.entropy_file = Some(path.to_string());
+        }
+    }
+
+    /// Sets `extended_private_key` to the specified extended private key, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn extended_private(&mut self, argument: Option<&str>) {
+        if let Some(extended_private_key) = argument {
+            self.extended_private_key = Some(extended_private_key.to_string());
+        }
+    }
+
+    /// Sets `extended_public_key` to the specified extended public key, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn extended_public(&mut self, argument: Option<&str>) {
+        if let Some(extended_public_key) = argument {
+            self.extended_public_key = Some(extended_public_key.to_string());
+        }
+    }
+
+    /// Sets `index` to the specified index, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn index(&mut self, argument: Option<u32>) {
+        if let Some(index) = argument {
+            self.index = index;
+        }
+    }
+
+    /// Sets `index` and `indices` to the specified range of indices, overriding their previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    /// Returns the range that was set, or an error if the argument is not a non-empty `start..end` range.
+    fn index_range(&mut self, argument: Option<&str>) -> Result<Option<(u32, u32)>, CLIError> {
+        let index_range = argument.map(parse_index_range).transpose()?;
+        if let Some((start, end)) = index_range {
+            self.index = start;
+            self.indices = end - start;
+        }
+        Ok(index_range)
+    }
+
+    /// Sets `output_format` to JSON if the specified boolean value is set.
+    /// Once enabled at any level of the command, JSON remains the output format.
+    fn json(&mut self, argument: bool) {
+        if argument {
+            self.output_format = "json".into();
+        }
+    }
+
+    /// Imports a wallet for the specified keypair file, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn keypair(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.keypair = Some(path.to_string());
+        }
+    }
+
+    /// Sets `keypair_file` to the specified file path, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn keypair_file(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.keypair_file = Some(path.to_string());
+        }
+    }
+
+    /// Sets `language` to the specified language, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn language(&mut self, argument: Option<&str>) {
+        match argument {
+            Some("chinese_simplified") => self.language = "chinese_simplified".into(),
+            Some("chinese_traditional") => self.language = "chinese_traditional".into(),
+            Some("english") => self.language = "english".into(),
+            Some("french") => self.language = "french".into(),
+            Some("italian") => self.language = "italian".into(),
+            Some("japanese") => self.language = "japanese".into(),
+            Some("korean") => self.language = "korean".into(),
+            Some("spanish") => self.language = "spanish".into(),
+            _ => (),
+        };
+    }
+
+    /// Sets `mnemonic` to the specified mnemonic, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn mnemonic(&mut self, argument: Option<&str>) {
+        if let Some(mnemonic) = argument {
+            self.mnemonic = Some(mnemonic.to_string());
+        }
+    }
+
+    /// Sets `output_file` to the specified file path, overriding its previous state.
+    /// Colored output is disabled, as the file is not a terminal.
+    /// If the specified argument is `None`, then no change occurs.
+    fn output_file(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.output_file = Some(path.to_string());
+            colored::control::set_override(false);
+        }
+    }
+
+    /// Sets `output_format` to the specified output format, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn output_format(&mut self, argument: Option<&str>) {
+        if let Some(output_format) = argument {
+            self.output_format = output_format.to_string();
+        }
+    }
+
+    /// Sets `paper_file` to the specified file path, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn paper_file(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.paper_file = Some(path.to_string());
+        }
+    }
+
+    /// Sets `password` to the specified password, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn password(&mut self, argument: Option<&str>) {
+        if let Some(password) = argument {
+            self.password = Some(password.to_string());
+        }
+    }
+
+    /// Imports a wallet for the specified private key, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn private(&mut self, argument: Option<&str>) {
+        if let Some(private_key) = argument {
+            self.private = Some(private_key.to_string());
+        }
+    }
+
+    /// Imports a wallet for the specified public key, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn public(&mut self, argument: Option<&str>) {
+        if let Some(public_key) = argument {
+            self.public = Some(public_key.to_string())
+        }
+    }
+
+    /// Enables `public_only` if the specified boolean value is set.
+    /// Once enabled at any level of the command, the secrets of the wallet(s) remain withheld.
+    fn public_only(&mut self, argument: bool) {
+        if argument {
+            self.public_only = true;
+        }
+    }
+
+    /// Sets `qr` to the specified boolean value, overriding its previous state.
+    fn qr(&mut self, argument: bool) {
+        self.qr = argument;
+    }
+
+    /// Sets `qr_file` to the specified file path, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn qr_file(&mut self, argument: Option<&str>) {
+        if let Some(qr_file) = argument {
+            self.qr_file = Some(qr_file.to_string());
+        }
+    }
+
+    /// Sets `word_count` to the specified word count, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn word_count(&mut self, argument: Option<u8>) {
+        if let Some(word_count) = argument {
+            self.word_count = word_count;
+        }
+    }
+
+    /// Returns the derivation path with the specified derivation, index, and path.
+    /// As ed25519 only supports hardened derivation, the index selects the hardened account,
+    /// e.g. `m/44'/501'/{index}'/0'` as used by Phantom and solana-keygen, or `m/44'/501'/{index}'` by Ledger Live.
+    /// If `default` is enabled, then return the default path if no derivation was provided.
+    fn to_derivation_path<N: SolanaNetwork>(&self, default: bool) -> Option<String> {
+        let bip44 = format!("m/44'/{}/{}'/0'", N::HD_COIN_TYPE, self.index);
+        match self.derivation.as_str() {
+            "bip44" => Some(bip44),
+            "ledger" => Some(format!("m/44'/{}/{}'", N::HD_COIN_TYPE, self.index)),
+            "custom" => self.path.clone(),
+            _ => match default {
+                true => Some(bip44),
+                false => None,
+            },
+        }
+    }
+
+    /// Returns the derivation paths with the specified derivation, indices, and path.
+    /// If `default` is enabled, then return the default path if no derivation was provided.
+    fn to_derivation_paths<N: SolanaNetwork>(&self, default: bool) -> Vec<Option<String>> {
+        let start = self.index;
+        let end = start + self.indices;
+        let mut options = self.clone();
+        (start..end)
+            .map(|index| {
+                // Sets the index to the specified index
+                options.index(Some(index));
+                // Generates the derivation path for the specified information
+                options.to_derivation_path::<N>(default)
+            })
+            .collect()
+    }
+}
+
+pub struct SolanaCLI;
+
+impl CLI for SolanaCLI {
+    type Options = SolanaOptions;
+
+    const NAME: NameType = "solana";
+    const ABOUT: AboutType = "Generates a Solana wallet (include -h for more options)";
+    const FLAGS: &'static [FlagType] = &[
+        flag::AUDIT_RNG,
+        flag::JSON,
+        flag::OUTPUT_FILE,
+        flag::OUTPUT_FORMAT,
+        flag::PUBLIC_ONLY,
+        flag::QR,
+        flag::QR_FILE,
+        flag::KEYPAIR_FILE_SOLANA,
+    ];
+    const OPTIONS: &'static [OptionType] = &[option::COUNT];
+    const SUBCOMMANDS: &'static [SubCommandType] = &[
+        subcommand::HD_SOLANA,
+        subcommand::IMPORT_SOLANA,
+        subcommand::IMPORT_HD_SOLANA,
+        subcommand::PAPER_SOLANA,
+        subcommand::VALIDATE_SOLANA,
+    ];
+
+    /// Handle all CLI arguments and flags for Solana
+    #[cfg_attr(tarpaulin, skip)]
+    fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
+        let mut options = SolanaOptions::default();
+        options.parse(
+            arguments,
+            &[
+                "audit rng",
+                "count",
+                "json",
+                "output file",
+                "output format",
+                "public only",
+                "qr",
+                "qr file",
+                "keypair file",
+            ],
+        )?;
+
+        match arguments.subcommand() {
+            ("hd", Some(arguments)) => {
+                options.subcommand = Some("hd".into());
+                options.parse(
+                    arguments,
+                    &[
+                        "audit rng",
+                        "count",
+                        "json",
+                        "output file",
+                        "output format",
+                        "public only",
+                        "qr",
+                        "qr file",
+                        "keypair file",
+                    ],
+                )?;
+                options.parse(
+                    arguments,
+                    &[
+                        "derivation",
+                        "entropy",
+                        "entropy file",
+                        "index range",
+                        "language",
+                        "password",
+                        "word count",
+                    ],
+                )?;
+            }
+            ("import", Some(arguments)) => {
+                options.subcommand = Some("import".into());
+                options.parse(
+                    arguments,
+                    &[
+                        "json",
+                        "output file",
+                        "output format",
+                        "public only",
+                        "qr",
+                        "qr file",
+                        "keypair file",
+                    ],
+                )?;
+                options.parse(arguments, &["address", "keypair", "private", "public"])?;
+            }
+            ("import-hd", Some(arguments)) => {
+                options.subcommand = Some("import-hd".into());
+                options.parse(
+                    arguments,
+                    &[
+                        "json",
+                        "output file",
+                        "output format",
+                        "public only",
+                        "qr",
+                        "qr file",
+                        "keypair file",
+                    ],
+                )?;
+                options.parse(
+                    arguments,
+                    &[
+                        "derivation",
+                        "extended private",
+                        "extended public",
+                        "index",
+                        "index range",
+                        "mnemonic",
+                        "password",
+                    ],
+                )?;
+            }
+            ("paper", Some(arguments)) => {
+                // A derivation path generates an HD paper wallet with a mnemonic
+                options.subcommand = match arguments.is_present("derivation") {
+                    true => Some("paper hd".into()),
+                    false => Some("paper".into()),
+                };
+                options.parse(arguments, &["audit rng", "count"])?;
+                options.parse(arguments, &["derivation", "language", "paper file", "word count"])?;
+            }
+            ("validate", Some(arguments)) => {
+                options.subcommand = Some("validate".into());
+                options.parse(arguments, &["json", "output file", "output format", "public only"])?;
+                options.parse(arguments, &["address"])?;
+            }
+            _ => {}
+        };
+
+        Ok(options)
+    }
+
+    /// Generate the Solana wallet and print the relevant fields
+    #[cfg_attr(tarpaulin, skip)]
+    fn print(options: Self::Options) -> Result<(), CLIError> {
+        fn output<N: SolanaNetwork, W: SolanaWordlist>(options: SolanaOptions) -> Result<(), CLIError> {
+            if let Some("validate") = options.subcommand.as_deref() {
+                return validate(options);
+            }
+
+            // Generate new wallets lazily, to stream a large count of wallets to the output
+            let mut rng = wallet_rng(options.audit_rng);
+            let generated: Box<dyn Iterator<Item = SolanaWallet>> = match options.subcommand.as_deref() {
+                Some("hd") | Some("paper hd") => match options.to_derivation_path::<N>(true) {
+                    Some(path) => {
+                        let (word_count, indices) = (options.word_count, options.indices);
+                        let password = options.password.clone();
+                        let mut entropy = Entropy::new(
+                            options.entropy.as_deref(),
+                            options.entropy_file.as_deref(),
+                            options.audit_rng,
+                        )?;
+                        let paths = options.to_derivation_paths::<N>(true);
+                        Box::new((0..options.count).flat_map(move |_| {
+                            let password = &password.as_deref();
+                            // Sample a new HD wallet
+                            let wallet = match SolanaWallet::new_hd::<N, W>(&mut entropy, word_count, *password, &path)
+                            {
+                                Ok(wallet) => wallet,
+                                _ => return vec![],
+                            };
+                            if indices <= 1 {
+                                return vec![wallet];
+                            }
+                            let mnemonic = &wallet.mnemonic.unwrap();
+
+                            // Generate the HD wallet, from `index` to a number of specified `indices`
+                            paths
+                                .iter()
+                                .flat_map(|path| {
+                                    match SolanaWallet::from_mnemonic::<N, W>(
+                                        mnemonic,
+                                        password,
+                                        path.as_ref().unwrap(),
+                                    ) {
+                                        Ok(wallet) => vec![wallet],
+                                        _ => vec![],
+                                    }
+                                })
+                                .collect::<Vec<SolanaWallet>>()
+                        }))
+                    }
+                    None => Box::new(iter::empty()),
+                },
+                None | Some("paper") => {
+                    Box::new((0..options.count).flat_map(move |_| match SolanaWallet::new(&mut rng) {
+                        Ok(wallet) => vec![wallet],
+                        _ => vec![],
+                    }))
+                }
+                _ => Box::new(iter::empty()),
+            };
+
+            let wallets = match options.subcommand.as_deref() {
+                Some("import") => {
+                    if let Some(keypair) = options.keypair.as_deref() {
+                        vec![SolanaWallet::from_keypair(&std::fs::read_to_string(keypair)?)?]
+                    } else if let Some(private_key) = options.private.as_deref() {
+                        vec![SolanaWallet::from_private_key(private_key)?]
+                    } else if let Some(public_key) = options.public.as_deref() {
+                        vec![SolanaWallet::from_public_key(public_key)?]
+                    } else if let Some(address) = options.address.as_deref() {
+                        vec![SolanaWallet::from_address(address)?]
+                    } else {
+                        vec![]
+                    }
+                }
+                Some("import-hd") => {
+                    if let Some(mnemonic) = options.mnemonic.clone() {
+                        let password = &options.password.as_deref();
+
+                        // Generate the mnemonic wallets, from `index` to a number of specified `indices`
+                        let mut wallets = vec![];
+                        for path in options.to_derivation_paths::<N>(true).iter().flatten() {
+                            // Reject a path with normal indices, before trying the mnemonic in each wordlist
+                            SolanaDerivationPath::<N>::from_str(path)?;
+                            wallets.push(SolanaWallet::from_mnemonic_any_language::<N>(
+                                &mnemonic, password, path,
+                            )?);
+                        }
+                        wallets
+                    } else if let Some(extended_private_key) = options.extended_private_key.clone() {
+                        // Generate the extended private keys, from `index` to a number of specified `indices`
+                        let mut wallets = vec![];
+                        for path in &options.to_derivation_paths::<N>(false) {
+                            wallets.push(SolanaWallet::from_extended_private_key::<N>(
+                                &extended_private_key,
+                                path,
+                            )?);
+                        }
+                        wallets
+                    } else if let Some(extended_public_key) = options.extended_public_key.clone() {
+                        // Generate the extended public keys, from `index` to a number of specified `indices`
+                        let mut wallets = vec![];
+                        for path in &options.to_derivation_paths::<N>(false) {
+                            wallets.push(SolanaWallet::from_extended_public_key::<N>(&extended_public_key, path)?);
+                        }
+                        wallets
+                    } else {
+                        vec![]
+                    }
+                }
+                _ => vec![],
+            };
+            let wallets = wallets.into_iter().chain(generated);
+
+            match options.paper_file.as_deref() {
+                Some(path) => {
+                    if options.public_only {
+                        return Err(CLIError::UnsupportedFormat("paper wallet with --public-only".into()));
+                    }
+                    let mut wallets = wallets.collect::<Vec<SolanaWallet>>();
+                    let paper_wallets = wallets
+                        .iter()
+                        .filter_map(SolanaWallet::to_paper_wallet)
+                        .collect::<Vec<PaperWallet>>();
+                    paper::output("Solana", &paper_wallets, path)?;
+                    wallets.iter_mut().for_each(Zeroize::zeroize);
+                }
+                None => {
+                    if options.public_only && options.keypair_file.is_some() {
+                        return Err(CLIError::UnsupportedFormat("keypair file with --public-only".into()));
+                    }
+                    let path = options.output_file.as_deref();
+                    let mut writer = WalletWriter::new(&options.output_format, path, SolanaWallet::FIELDS)?;
+                    for (index, mut wallet) in wallets.enumerate() {
+                        if options.public_only {
+                            wallet.public_only();
+                        }
+                        writer.write(&wallet)?;
+                        if let Some(qr_data) = wallet.to_qr_data() {
+                            qr::output(qr_data, index, options.qr, options.qr_file.as_deref())?;
+                        }
+                        if let (Some(keypair), Some(path)) =
+                            (wallet.to_json_keypair()?, options.keypair_file.as_deref())
+                        {
+                            output::write_secret(&keypair, &qr::to_indexed_path(path, index))?;
+                        }
+                        wallet.zeroize();
+                    }
+                    writer.finish()?;
+                }
+            };
+
+            Ok(())
+        }
+
+        /// Validates the address, printing its network, format, checksum, and payload
+        fn validate(options: SolanaOptions) -> Result<(), CLIError> {
+            let address = options.address.as_deref().unwrap_or_default();
+            let mut diagnostics = AddressDiagnostics::new(address);
+
+            // Decode the payload of the base58 address, and parse it to verify its length and ed25519 point.
+            // As Solana addresses are raw public keys, they have no checksum.
+            match address.from_base58() {
+                Ok(data) => {
+                    diagnostics.payload = Some(hex::encode(&data));
+                    match SolanaAddress::from_str(address) {
+                        Ok(_) => {
+                            diagnostics.network = Some(SolanaMainnet::NAME.into());
+                            diagnostics.format = Some("standard".into());
+                        }
+                        Err(error) => diagnostics.error(error),
+                    };
+                }
+                Err(error) => diagnostics.error(AddressError::from(error)),
+            };
+
+            diagnostics::output(
+                &diagnostics.finish(),
+                &options.output_format,
+                options.output_file.as_deref(),
+            )
+        }
+
+        match options.language.as_str() {
+            "chinese_simplified" => output::<SolanaMainnet, ChineseSimplified>(options),
+            "chinese_traditional" => output::<SolanaMainnet, ChineseTraditional>(options),
+            "english" => output::<SolanaMainnet, English>(options),
+            "french" => output::<SolanaMainnet, French>(options),
+            "italian" => output::<SolanaMainnet, Italian>(options),
+            "japanese" => output::<SolanaMainnet, Japanese>(options),
+            "korean" => output::<SolanaMainnet, Korean>(options),
+            "spanish" => output::<SolanaMainnet, Spanish>(options),
+            _ => output::<SolanaMainnet, English>(options),
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use crate::cli::bitcoin::BitcoinWallet;
+use crate::cli::bitcoincash::BitcoinCashWallet;
+use crate::cli::cosmos::CosmosWallet;
+use crate::cli::dash::{Dash, DashWallet};
+use crate::cli::dogecoin::{Dogecoin, DogecoinWallet};
+use crate::cli::entropy::Entropy;
+use crate::cli::ethereum::EthereumWallet;
+use crate::cli::introspect::{ArgumentDescription, CommandDescription};
+use crate::cli::monero::MoneroWallet;
+use crate::cli::ripple::RippleWallet;
+use crate::cli::solana::SolanaWallet;
+use crate::cli::tron::TronWallet;
+use crate::cli::zcash::ZcashWallet;
+use crate::cli::{types::*, CLIError};
+
+use clap::{App, AppSettings, Arg, SubCommand};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use zeroize::Zeroize;
+
+use crate::model::no_std::{format, ToString, Vec};
+
+/// The currencies of the test vectors, in the order of their output
+pub const CURRENCIES: [&str; 11] = [
+    "bitcoin",
+    "bitcoincash",
+    "cosmos",
+    "dash",
+    "dogecoin",
+    "ethereum",
+    "monero",
+    "ripple",
+    "solana",
+    "tron",
+    "zcash",
+];
+
+/// Represents the test vectors of a currency, as its wallets and its signed sample transactions
+#[derive(Serialize, Clone, Debug)]
+pub struct TestVectors<W: Serialize> {
+    pub wallets: Vec<W>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub transactions: Vec<W>,
+}
+
+/// The `test-vectors` command, which prints the wallets and signed sample transactions
+/// generated deterministically from a seed, for integration tests and audits of downstream tools
+pub struct TestVectorsCLI;
+
+impl TestVectorsCLI {
+    pub const NAME: NameType = "test-vectors";
+    pub const ABOUT: AboutType = "Prints the wallets and signed transactions of a seed as JSON test vectors";
+    pub const OPTIONS: &'static [OptionType] = &[
+        (
+            "[currency] -c --currency=[currency] 'Generates the test vectors of the specified currency (default: all)'",
+            &[],
+            &[
+                "all",
+                "bitcoin",
+                "bitcoincash",
+                "cosmos",
+                "dash",
+                "dogecoin",
+                "ethereum",
+                "monero",
+                "ripple",
+                "solana",
+                "tron",
+                "zcash",
+            ],
+            &[],
+        ),
+        (
+            "<seed> -s --seed=<seed> 'Generates the test vectors from the specified seed in hex'",
+            &[],
+            &[],
+            &[],
+        ),
+    ];
+
+    #[cfg_attr(tarpaulin, skip)]
+    pub fn new<'a, 'b>() -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .settings(&[
+                AppSettings::ColoredHelp,
+                AppSettings::DisableHelpSubcommand,
+                AppSettings::DisableVersion,
+            ])
+            .args(
+                &Self::OPTIONS
+                    .iter()
+                    .map(|option| match option.2.len() > 0 {
+                        true => Arg::from_usage(option.0).possible_values(option.2),
+                        false => Arg::from_usage(option.0),
+                    })
+                    .collect::<Vec<Arg<'static, 'static>>>(),
+            )
+    }
+
+    /// Returns the test vectors of the specified currency, or of every currency for `all`, generated from the seed.
+    /// Each currency draws from its own stream of the seed, so its test vectors are the same in either case.
+    pub fn generate(currency: &str, seed: &str) -> Result<Value, CLIError> {
+        let mut bytes = hex::decode(seed.trim())?;
+        let mut currencies = Map::new();
+        for name in CURRENCIES.iter().filter(|name| currency == "all" || currency == **name) {
+            let entropy = &mut Entropy::from_seed(&bytes, name);
+            let vectors = match *name {
+                "bitcoin" => serde_json::to_value(BitcoinWallet::test_vectors(entropy)?)?,
+                "bitcoincash" => serde_json::to_value(BitcoinCashWallet::test_vectors(entropy)?)?,
+                "cosmos" => serde_json::to_value(CosmosWallet::test_vectors(entropy)?)?,
+                "dash" => serde_json::to_value(DashWallet::test_vectors::<Dash>(entropy)?)?,
+                "dogecoin" => serde_json::to_value(DogecoinWallet::test_vectors::<Dogecoin>(entropy)?)?,
+                "ethereum" => serde_json::to_value(EthereumWallet::test_vectors(entropy)?)?,
+                "monero" => serde_json::to_value(MoneroWallet::test_vectors(entropy)?)?,
+                "ripple" => serde_json::to_value(RippleWallet::test_vectors(entropy)?)?,
+                "solana" => serde_json::to_value(SolanaWallet::test_vectors(entropy)?)?,
+                "tron" => serde_json::to_value(TronWallet::test_vectors(entropy)?)?,
+                _ => serde_json::to_value(ZcashWallet::test_vectors(entropy)?)?,
+            };
+            currencies.insert(name.to_string(), vectors);
+        }
+        bytes.zeroize();
+
+        if currencies.is_empty() {
+            return Err(CLIError::Crate(
+                "currency",
+                format!("unsupported currency {}", currency),
+            ));
+        }
+
+        Ok(serde_json::json!({
+            "seed": seed.trim().to_lowercase(),
+            "currencies": currencies,
+        }))
+    }
+
+    /// Writes the test vectors of the specified currency and seed to stdout, in JSON format.
+    #[cfg_attr(tarpaulin, skip)]
+    pub fn print(currency: &str, seed: &str) -> Result<(), CLIError> {
+        println!("{}", serde_json::to_string_pretty(&Self::generate(currency, seed)?)?);
+        Ok(())
+    }
+
+    /// Returns the description of the command.
+    pub fn describe() -> CommandDescription {
+        CommandDescription {
+            options: Self::OPTIONS.iter().map(ArgumentDescription::from_option).collect(),
+            ..CommandDescription::new(Self::NAME, Self::ABOUT)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: &str = "000102030405060708090a0b0c0d0e0f";
+
+    #[test]
+    fn deterministic() {
+        let vectors = TestVectorsCLI::generate("all", SEED).unwrap();
+        assert_eq!(vectors, TestVectorsCLI::generate("all", SEED).unwrap());
+        assert_eq!(SEED, vectors["seed"]);
+        for name in CURRENCIES.iter() {
+            assert!(!vectors["currencies"][name]["wallets"].as_array().unwrap().is_empty());
+            // The test vectors of a currency do not depend on the other currencies generated
+            let single = TestVectorsCLI::generate(name, SEED).unwrap();
+            assert_eq!(vectors["currencies"][name], single["currencies"][name]);
+        }
+
+        let other = TestVectorsCLI::generate("all", "0f0e0d0c0b0a09080706050403020100").unwrap();
+        assert_ne!(vectors["currencies"], other["currencies"]);
+    }
+
+    #[test]
+    fn signed_transactions() {
+        let vectors = TestVectorsCLI::generate("all", SEED).unwrap();
+        for name in ["bitcoin", "ethereum", "zcash"].iter() {
+            let transactions = vectors["currencies"][name]["transactions"].as_array().unwrap();
+            assert!(!transactions.is_empty());
+            for transaction in transactions {
+                assert!(transaction["transaction_id"].is_string());
+                assert!(transaction["transaction_hex"].is_string());
+            }
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(TestVectorsCLI::generate("all", "not hex").is_err());
+        assert!(TestVectorsCLI::generate("litecoin", SEED).is_err());
+    }
+}
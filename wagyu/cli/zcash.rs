@@ -1,19 +1,35 @@
-use crate::cli::{flag, option, subcommand, types::*, CLIError, CLI};
-use crate::model::{ExtendedPrivateKey, ExtendedPublicKey, PrivateKey, PublicKey, Transaction};
+use crate::bitcoin::{
+    wordlist::*, BitcoinDerivationPath, BitcoinExtendedPrivateKey, BitcoinFormat, BitcoinMnemonic, BitcoinWordlist,
+    Mainnet as BitcoinMainnet,
+};
+use crate::cli::diagnostics::{self, from_base58_check, from_bech32, AddressDiagnostics};
+use crate::cli::entropy::Entropy;
+use crate::cli::output::WalletWriter;
+use crate::cli::vectors::TestVectors;
+use crate::cli::{
+    fingerprint, flag, option, paper, paper::PaperWallet, qr, subcommand, types::*, value_or_stdin, wallet_id,
+    wallet_rng, CLIError, CLI,
+};
+use crate::model::{
+    AddressError, Bip39Language, ExtendedPrivateKey, ExtendedPublicKey, Mnemonic, MnemonicCount, Network,
+    NonHardenedDerivationPath, PrivateKey, PublicKey, Transaction,
+};
 use crate::zcash::{
     format::ZcashFormat, initialize_proving_context, initialize_verifying_context, load_sapling_parameters,
-    Mainnet as ZcashMainnet, Outpoint, SignatureHash, Testnet as ZcashTestnet, ZcashAddress, ZcashAmount,
-    ZcashDerivationPath, ZcashExtendedPrivateKey, ZcashExtendedPublicKey, ZcashNetwork, ZcashPrivateKey,
-    ZcashPublicKey, ZcashTransaction, ZcashTransactionParameters,
+    Mainnet as ZcashMainnet, Outpoint, P2PKHSpendingKey, Regtest as ZcashRegtest, SignatureHash, SproutMigration,
+    Testnet as ZcashTestnet, ZcashAddress, ZcashAmount, ZcashConsensusBranch, ZcashDerivationPath,
+    ZcashExtendedPrivateKey, ZcashExtendedPublicKey, ZcashNetwork, ZcashPrivateKey, ZcashPublicKey, ZcashTransaction,
+    ZcashTransactionParameters, SPROUT_MIGRATION_GUIDANCE,
 };
 
+use bech32::FromBase32;
 use clap::{ArgMatches, Values};
 use colored::*;
-use core::{fmt, fmt::Display, str::FromStr};
-use rand::{rngs::StdRng, Rng};
-use rand_core::SeedableRng;
+use core::{fmt, fmt::Display, iter, str::FromStr};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use serde_json::from_str;
+use zeroize::Zeroize;
 
 use crate::model::no_std::{format, vec, String, ToOwned, ToString, Vec};
 
@@ -37,10 +53,14 @@ pub struct HdValues {
 
 /// Represents a generic wallet to output
 #[derive(Serialize, Debug, Default)]
-struct ZcashWallet {
+pub struct ZcashWallet {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mnemonic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub extended_private_key: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub extended_public_key: Option<String>,
@@ -49,12 +69,24 @@ struct ZcashWallet {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub public_key: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_viewing_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incoming_viewing_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub address: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub master_fingerprint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wallet_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub format: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub diversifier: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub diversifier_index: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub network: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub outgoing_view_key: Option<String>,
@@ -64,11 +96,56 @@ struct ZcashWallet {
     pub transaction_hex: Option<String>,
 }
 
+impl Zeroize for ZcashWallet {
+    /// Overwrites the secret fields of the wallet.
+    fn zeroize(&mut self) {
+        self.password.zeroize();
+        self.mnemonic.zeroize();
+        self.extended_private_key.zeroize();
+        self.private_key.zeroize();
+    }
+}
+
 impl ZcashWallet {
+    /// The fields of the wallet, in the order of the columns of a CSV output
+    pub const FIELDS: &'static [&'static str] = &[
+        "path",
+        "password",
+        "mnemonic",
+        "extended_private_key",
+        "extended_public_key",
+        "private_key",
+        "public_key",
+        "full_viewing_key",
+        "incoming_viewing_key",
+        "address",
+        "fingerprint",
+        "master_fingerprint",
+        "wallet_id",
+        "format",
+        "diversifier",
+        "diversifier_index",
+        "network",
+        "outgoing_view_key",
+        "transaction_id",
+        "transaction_hex",
+    ];
+
+    /// Withholds the secrets of the wallet from its output, leaving its public material
+    /// and the fingerprint of its public key.
+    pub fn public_only(&mut self) {
+        self.fingerprint = self.public_key.as_deref().or(self.address.as_deref()).map(fingerprint);
+        self.zeroize();
+    }
+
     pub fn new<N: ZcashNetwork, R: Rng>(rng: &mut R, format: &ZcashFormat) -> Result<Self, CLIError> {
         let private_key = match format {
             ZcashFormat::P2PKH => ZcashPrivateKey::<N>::new_p2pkh(rng)?,
-            ZcashFormat::Sprout => ZcashPrivateKey::<N>::new_sprout(rng)?,
+            ZcashFormat::Sprout => {
+                return Err(CLIError::UnsupportedFormat(
+                    "sprout is import-only, generate a sapling wallet instead".into(),
+                ))
+            }
             ZcashFormat::Sapling(_) => ZcashPrivateKey::<N>::new_sapling(rng)?,
             _ => ZcashPrivateKey::<N>::new_p2pkh(rng)?,
         };
@@ -85,19 +162,120 @@ impl ZcashWallet {
         })
     }
 
-    pub fn new_hd<N: ZcashNetwork, R: Rng>(rng: &mut R, path: &str, format: &ZcashFormat) -> Result<Self, CLIError> {
-        let seed: [u8; 32] = rng.gen();
+    pub fn new_hd<N: ZcashNetwork>(entropy: &mut Entropy, path: &str, format: &ZcashFormat) -> Result<Self, CLIError> {
+        let seed: [u8; 32] = entropy.gen();
         let master_extended_private_key = ZcashExtendedPrivateKey::<N>::new_master(&seed, format)?;
         let derivation_path = ZcashDerivationPath::from_str(path)?;
         let extended_private_key = master_extended_private_key.derive(&derivation_path)?;
         let extended_public_key = extended_private_key.to_extended_public_key();
         let private_key = extended_private_key.to_private_key();
         let public_key = extended_public_key.to_public_key();
-        let address = public_key.to_address(format)?;
+        // Use the default address of the extended key, unless a diversifier is specified
+        let format = match format {
+            ZcashFormat::Sapling(Some(_)) => format.clone(),
+            _ => ZcashFormat::Sapling(extended_private_key.to_default_diversifier()),
+        };
+        let address = public_key.to_address(&format)?;
+        Ok(Self {
+            path: Some(path.to_string()),
+            extended_private_key: Some(extended_private_key.to_string()),
+            extended_public_key: Some(extended_public_key.to_string()),
+            master_fingerprint: Some(hex::encode(
+                master_extended_private_key.to_extended_public_key().to_fingerprint(),
+            )),
+            wallet_id: Some(wallet_id(&extended_public_key.to_string())),
+            private_key: Some(private_key.to_string()),
+            public_key: Some(public_key.to_string()),
+            address: Some(address.to_string()),
+            format: Some(address.format().to_string()),
+            diversifier: address.to_diversifier(),
+            network: Some(N::NAME.to_string()),
+            ..Default::default()
+        })
+    }
+
+    pub fn new_shielded<N: ZcashNetwork, W: BitcoinWordlist>(
+        entropy: &mut Entropy,
+        word_count: u8,
+        password: Option<&str>,
+        path: &str,
+        format: &ZcashFormat,
+    ) -> Result<Self, CLIError> {
+        let mnemonic = BitcoinMnemonic::<BitcoinMainnet, W>::new_with_count(entropy, word_count)?;
+        Self::from_mnemonic::<N, W>(&mnemonic.to_string(), password, path, format)
+    }
+
+    /// Returns the mainnet test vectors of the entropy: the Sapling and transparent HD wallets of a mnemonic,
+    /// a transparent wallet of a private key, and a transparent transaction of the private key,
+    /// signing a sample output of it over to the transparent HD wallet.
+    pub fn test_vectors(entropy: &mut Entropy) -> Result<TestVectors<Self>, CLIError> {
+        let mnemonic = BitcoinMnemonic::<BitcoinMainnet, English>::new_with_count(entropy, 24)?;
+        let mut wallets = Self::from_mnemonic_all_formats::<ZcashMainnet>(&mnemonic.to_string(), None, 0, 0)?;
+        wallets.push(Self::new::<ZcashMainnet, _>(entropy, &ZcashFormat::P2PKH)?);
+
+        let input = ZcashInput {
+            txid: hex::encode(entropy.gen::<[u8; 32]>()),
+            vout: 0,
+            amount: Some(100_000),
+            address: wallets[2].address.clone(),
+            private_key: wallets[2].private_key.clone(),
+            script_pub_key: None,
+            redeem_script: None,
+        };
+        let output = format!("{}:{}", wallets[1].address.as_deref().unwrap_or(""), 90_000);
+        let raw = Self::to_raw_transaction::<ZcashMainnet>(
+            &vec![input.clone()],
+            &vec![&output],
+            "sapling".into(),
+            0,
+            0,
+            None,
+        )?;
+        let transaction = Self::to_signed_transaction::<ZcashMainnet>(
+            raw.transaction_hex.as_deref().unwrap_or(""),
+            &vec![input],
+            None,
+        )?;
+
+        Ok(TestVectors {
+            wallets,
+            transactions: vec![transaction],
+        })
+    }
+
+    /// Returns the shielded HD wallet of the mnemonic, with the Sapling extended spending key
+    /// derived by ZIP-32 from the BIP-39 seed of the mnemonic and password.
+    pub fn from_mnemonic<N: ZcashNetwork, W: BitcoinWordlist>(
+        mnemonic: &str,
+        password: Option<&str>,
+        path: &str,
+        format: &ZcashFormat,
+    ) -> Result<Self, CLIError> {
+        let mnemonic = BitcoinMnemonic::<BitcoinMainnet, W>::from_phrase(mnemonic)?;
+        let mut seed = mnemonic.to_seed(password)?;
+        let master_extended_private_key = ZcashExtendedPrivateKey::<N>::new_master(&seed, format)?;
+        seed.zeroize();
+        let derivation_path = ZcashDerivationPath::from_str(path)?;
+        let extended_private_key = master_extended_private_key.derive(&derivation_path)?;
+        let extended_public_key = extended_private_key.to_extended_public_key();
+        let private_key = extended_private_key.to_private_key();
+        let public_key = extended_public_key.to_public_key();
+        // Use the default address of the extended key, unless a diversifier is specified
+        let format = match format {
+            ZcashFormat::Sapling(Some(_)) => format.clone(),
+            _ => ZcashFormat::Sapling(extended_private_key.to_default_diversifier()),
+        };
+        let address = public_key.to_address(&format)?;
         Ok(Self {
             path: Some(path.to_string()),
+            password: password.map(String::from),
+            mnemonic: Some(mnemonic.to_string()),
             extended_private_key: Some(extended_private_key.to_string()),
             extended_public_key: Some(extended_public_key.to_string()),
+            master_fingerprint: Some(hex::encode(
+                master_extended_private_key.to_extended_public_key().to_fingerprint(),
+            )),
+            wallet_id: Some(wallet_id(&extended_public_key.to_string())),
             private_key: Some(private_key.to_string()),
             public_key: Some(public_key.to_string()),
             address: Some(address.to_string()),
@@ -108,6 +286,84 @@ impl ZcashWallet {
         })
     }
 
+    /// Returns the wallet of the mnemonic, in the language detected from its words.
+    pub fn from_mnemonic_any_language<N: ZcashNetwork>(
+        mnemonic: &str,
+        password: Option<&str>,
+        path: &str,
+        format: &ZcashFormat,
+    ) -> Result<Self, CLIError> {
+        match Bip39Language::detect_language(mnemonic)? {
+            Bip39Language::ChineseSimplified => {
+                Self::from_mnemonic::<N, ChineseSimplified>(mnemonic, password, path, format)
+            }
+            Bip39Language::ChineseTraditional => {
+                Self::from_mnemonic::<N, ChineseTraditional>(mnemonic, password, path, format)
+            }
+            Bip39Language::English => Self::from_mnemonic::<N, English>(mnemonic, password, path, format),
+            Bip39Language::French => Self::from_mnemonic::<N, French>(mnemonic, password, path, format),
+            Bip39Language::Italian => Self::from_mnemonic::<N, Italian>(mnemonic, password, path, format),
+            Bip39Language::Japanese => Self::from_mnemonic::<N, Japanese>(mnemonic, password, path, format),
+            Bip39Language::Korean => Self::from_mnemonic::<N, Korean>(mnemonic, password, path, format),
+            Bip39Language::Spanish => Self::from_mnemonic::<N, Spanish>(mnemonic, password, path, format),
+        }
+    }
+
+    /// Returns the Sapling and transparent wallets of the same account of the mnemonic, in the language
+    /// detected from its words. The Sapling wallet is derived by ZIP-32 at `m/32'/coin_type'/account'`,
+    /// with its viewing keys, and the transparent wallet by BIP-44 at `m/44'/coin_type'/account'/0/index`.
+    pub fn from_mnemonic_all_formats<N: ZcashNetwork>(
+        mnemonic: &str,
+        password: Option<&str>,
+        account: u32,
+        index: u32,
+    ) -> Result<Vec<Self>, CLIError> {
+        let path = format!("m/32'/{}/{}'", N::HD_COIN_TYPE, account);
+        let sapling = Self::from_mnemonic_any_language::<N>(mnemonic, password, &path, &ZcashFormat::Sapling(None))?
+            .with_viewing_keys();
+
+        let mut seed = match Bip39Language::detect_language(mnemonic)? {
+            Bip39Language::ChineseSimplified => Self::to_seed::<ChineseSimplified>(mnemonic, password)?,
+            Bip39Language::ChineseTraditional => Self::to_seed::<ChineseTraditional>(mnemonic, password)?,
+            Bip39Language::English => Self::to_seed::<English>(mnemonic, password)?,
+            Bip39Language::French => Self::to_seed::<French>(mnemonic, password)?,
+            Bip39Language::Italian => Self::to_seed::<Italian>(mnemonic, password)?,
+            Bip39Language::Japanese => Self::to_seed::<Japanese>(mnemonic, password)?,
+            Bip39Language::Korean => Self::to_seed::<Korean>(mnemonic, password)?,
+            Bip39Language::Spanish => Self::to_seed::<Spanish>(mnemonic, password)?,
+        };
+        let path = format!("m/44'/{}/{}'/0/{}", N::HD_COIN_TYPE, account, index);
+        let transparent = Self::from_seed_transparent::<N>(&seed, &path);
+        seed.zeroize();
+
+        Ok(vec![sapling, transparent?])
+    }
+
+    /// Returns the BIP-39 seed of the mnemonic and password.
+    fn to_seed<W: BitcoinWordlist>(mnemonic: &str, password: Option<&str>) -> Result<Vec<u8>, CLIError> {
+        Ok(BitcoinMnemonic::<BitcoinMainnet, W>::from_phrase(mnemonic)?.to_seed(password)?)
+    }
+
+    /// Returns the transparent P2PKH wallet of the seed, with the secp256k1 private key derived by BIP-32 at the path.
+    fn from_seed_transparent<N: ZcashNetwork>(seed: &[u8], path: &str) -> Result<Self, CLIError> {
+        let master_extended_private_key =
+            BitcoinExtendedPrivateKey::<BitcoinMainnet>::new_master(seed, &BitcoinFormat::P2PKH)?;
+        let extended_private_key = master_extended_private_key.derive(&BitcoinDerivationPath::from_str(path)?)?;
+        let secret_key = extended_private_key.to_private_key().to_secp256k1_secret_key();
+        let private_key = ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::new(secret_key, true));
+        let public_key = private_key.to_public_key();
+        let address = public_key.to_address(&ZcashFormat::P2PKH)?;
+        Ok(Self {
+            path: Some(path.to_string()),
+            private_key: Some(private_key.to_string()),
+            public_key: Some(public_key.to_string()),
+            address: Some(address.to_string()),
+            format: Some(address.format().to_string()),
+            network: Some(N::NAME.to_string()),
+            ..Default::default()
+        })
+    }
+
     pub fn from_extended_private_key<N: ZcashNetwork>(
         extended_private_key: &str,
         path: &Option<String>,
@@ -121,11 +377,17 @@ impl ZcashWallet {
         let extended_public_key = extended_private_key.to_extended_public_key();
         let private_key = extended_private_key.to_private_key();
         let public_key = extended_public_key.to_public_key();
-        let address = public_key.to_address(format)?;
+        // Use the default address of the extended key, unless a diversifier is specified
+        let format = match format {
+            ZcashFormat::Sapling(Some(_)) => format.clone(),
+            _ => ZcashFormat::Sapling(extended_private_key.to_default_diversifier()),
+        };
+        let address = public_key.to_address(&format)?;
         Ok(Self {
             path: path.clone(),
             extended_private_key: Some(extended_private_key.to_string()),
             extended_public_key: Some(extended_public_key.to_string()),
+            wallet_id: Some(wallet_id(&extended_public_key.to_string())),
             private_key: Some(private_key.to_string()),
             public_key: Some(public_key.to_string()),
             address: Some(address.to_string()),
@@ -143,14 +405,20 @@ impl ZcashWallet {
     ) -> Result<Self, CLIError> {
         let mut extended_public_key = ZcashExtendedPublicKey::<N>::from_str(extended_public_key)?;
         if let Some(derivation_path) = path {
-            let derivation_path = ZcashDerivationPath::from_str(&derivation_path)?;
-            extended_public_key = extended_public_key.derive(&derivation_path)?;
+            let derivation_path = NonHardenedDerivationPath::from_str(&derivation_path)?;
+            extended_public_key = extended_public_key.derive_public(&derivation_path)?;
         }
         let public_key = extended_public_key.to_public_key();
-        let address = public_key.to_address(format)?;
+        // Use the default address of the extended key, unless a diversifier is specified
+        let format = match format {
+            ZcashFormat::Sapling(Some(_)) => format.clone(),
+            _ => ZcashFormat::Sapling(extended_public_key.to_default_diversifier()),
+        };
+        let address = public_key.to_address(&format)?;
         Ok(Self {
             path: path.clone(),
             extended_public_key: Some(extended_public_key.to_string()),
+            wallet_id: Some(wallet_id(&extended_public_key.to_string())),
             public_key: Some(public_key.to_string()),
             address: Some(address.to_string()),
             format: Some(address.format().to_string()),
@@ -162,6 +430,9 @@ impl ZcashWallet {
 
     pub fn from_private_key<N: ZcashNetwork>(private_key: &str, format: &ZcashFormat) -> Result<Self, CLIError> {
         let private_key = ZcashPrivateKey::<N>::from_str(private_key)?;
+        if let ZcashPrivateKey::<N>::Sprout(_) = private_key {
+            return Self::from_sprout_private_key::<N>(&private_key.to_string());
+        }
         let public_key = private_key.to_public_key();
         let address = public_key.to_address(format)?;
         Ok(Self {
@@ -175,6 +446,20 @@ impl ZcashWallet {
         })
     }
 
+    /// Returns the wallet of a Sprout spending key, with its viewing key and address to recover its funds.
+    pub fn from_sprout_private_key<N: ZcashNetwork>(private_key: &str) -> Result<Self, CLIError> {
+        let migration = SproutMigration::<N>::new(private_key)?;
+        let address = migration.to_address();
+        Ok(Self {
+            private_key: Some(migration.to_private_key().to_string()),
+            public_key: Some(migration.to_viewing_key().to_string()),
+            address: Some(address.to_string()),
+            format: Some(address.format().to_string()),
+            network: Some(N::NAME.to_string()),
+            ..Default::default()
+        })
+    }
+
     pub fn from_public_key<N: ZcashNetwork>(public_key: &str, format: &ZcashFormat) -> Result<Self, CLIError> {
         let public_key = ZcashPublicKey::<N>::from_str(public_key)?;
         let address = public_key.to_address(format)?;
@@ -205,8 +490,11 @@ impl ZcashWallet {
         version: String,
         lock_time: u32,
         expiry_height: u32,
+        branch: Option<ZcashConsensusBranch>,
     ) -> Result<Self, CLIError> {
         let parameters = ZcashTransactionParameters::<N>::new(&version, lock_time, expiry_height)?;
+        let branch = Self::consensus_branch_or_default(&parameters, branch);
+        let parameters = parameters.with_consensus_branch(branch);
         let mut transaction = ZcashTransaction::<N>::new(&parameters)?;
 
         for input in inputs {
@@ -272,8 +560,11 @@ impl ZcashWallet {
     pub fn to_signed_transaction<N: ZcashNetwork>(
         transaction_hex: &str,
         inputs: &Vec<ZcashInput>,
+        branch: Option<ZcashConsensusBranch>,
     ) -> Result<Self, CLIError> {
         let mut transaction = ZcashTransaction::<N>::from_transaction_bytes(&hex::decode(transaction_hex)?)?;
+        let branch = Self::consensus_branch_or_default(&transaction.parameters, branch);
+        transaction.parameters = transaction.parameters.with_consensus_branch(branch);
 
         for input in inputs {
             match (input.amount.clone(), input.address.clone(), input.private_key.clone()) {
@@ -316,6 +607,93 @@ impl ZcashWallet {
             ..Default::default()
         })
     }
+
+    /// Returns the specified consensus branch, or by default the branch the transaction version is valid in:
+    /// Overwinter for version 3, and the latest network upgrade for version 4.
+    fn consensus_branch_or_default<N: ZcashNetwork>(
+        parameters: &ZcashTransactionParameters<N>,
+        branch: Option<ZcashConsensusBranch>,
+    ) -> ZcashConsensusBranch {
+        branch.unwrap_or_else(|| match parameters.version() {
+            3 => ZcashConsensusBranch::Overwinter,
+            _ => ZcashConsensusBranch::latest(),
+        })
+    }
+
+    /// Returns the wallet with the full and incoming viewing keys of its public key,
+    /// if it is a Sapling public key.
+    pub fn with_viewing_keys(mut self) -> Self {
+        if let Some(public_key) = &self.public_key {
+            if let Some((full_viewing_key, incoming_viewing_key)) = Self::to_viewing_keys::<ZcashMainnet>(public_key)
+                .or_else(|| Self::to_viewing_keys::<ZcashTestnet>(public_key))
+                .or_else(|| Self::to_viewing_keys::<ZcashRegtest>(public_key))
+            {
+                self.full_viewing_key = Some(full_viewing_key);
+                self.incoming_viewing_key = Some(incoming_viewing_key);
+            }
+        }
+        self
+    }
+
+    /// Returns the wallet with the Sapling address of the first valid diversifier at or after the specified index,
+    /// if it has a Sapling extended public key.
+    pub fn with_diversifier_index(mut self, index: u64) -> Result<Self, CLIError> {
+        if let Some(extended_public_key) = &self.extended_public_key {
+            let (index, address, diversifier) = Self::to_payment_address::<ZcashMainnet>(extended_public_key, index)
+                .or_else(|_| Self::to_payment_address::<ZcashTestnet>(extended_public_key, index))
+                .or_else(|_| Self::to_payment_address::<ZcashRegtest>(extended_public_key, index))?;
+            self.address = Some(address);
+            self.diversifier = diversifier;
+            self.diversifier_index = Some(index.to_string());
+        }
+        Ok(self)
+    }
+
+    /// Returns the diversifier index, Sapling address, and diversifier of the specified extended public key,
+    /// for the first valid diversifier at or after the specified index.
+    fn to_payment_address<N: ZcashNetwork>(
+        extended_public_key: &str,
+        index: u64,
+    ) -> Result<(u64, String, Option<String>), CLIError> {
+        let extended_public_key = ZcashExtendedPublicKey::<N>::from_str(extended_public_key)?;
+        let (index, address) = extended_public_key.to_payment_address(index)?;
+        Ok((index, address.to_string(), address.to_diversifier()))
+    }
+
+    /// Returns the full and incoming viewing keys of the specified Sapling public key.
+    fn to_viewing_keys<N: ZcashNetwork>(public_key: &str) -> Option<(String, String)> {
+        let public_key = ZcashPublicKey::<N>::from_str(public_key).ok()?;
+        let incoming_viewing_key = public_key.to_incoming_viewing_key()?;
+        Some((public_key.to_string(), incoming_viewing_key.to_string()))
+    }
+
+    /// Returns the transaction, address, or public key of the wallet, to render as a QR code.
+    pub fn to_qr_data(&self) -> Option<&str> {
+        self.transaction_hex
+            .as_deref()
+            .or(self.address.as_deref())
+            .or(self.public_key.as_deref())
+    }
+
+    /// Returns the address, private key, and remaining fields of the wallet, to render on paper.
+    pub fn to_paper_wallet(&self) -> Option<PaperWallet<'_>> {
+        let address = self.address.as_deref()?;
+        let secret = ("Private Key", self.private_key.as_deref()?);
+        let fields = [
+            ("Format", self.format.as_deref()),
+            ("Diversifier", self.diversifier.as_deref()),
+            ("Network", self.network.as_deref()),
+        ]
+        .iter()
+        .filter_map(|(label, value)| value.map(|value| (*label, value)))
+        .collect();
+
+        Some(PaperWallet {
+            address,
+            secret,
+            fields,
+        })
+    }
 }
 
 #[cfg_attr(tarpaulin, skip)]
@@ -326,6 +704,14 @@ impl Display for ZcashWallet {
                 Some(path) => format!("      {}                 {}\n", "Path".cyan().bold(), path),
                 _ => "".to_owned(),
             },
+            match &self.password {
+                Some(password) => format!("      {}             {}\n", "Password".cyan().bold(), password),
+                _ => "".to_owned(),
+            },
+            match &self.mnemonic {
+                Some(mnemonic) => format!("      {}             {}\n", "Mnemonic".cyan().bold(), mnemonic),
+                _ => "".to_owned(),
+            },
             match &self.extended_private_key {
                 Some(extended_private_key) => format!(
                     "      {} {}\n",
@@ -350,10 +736,40 @@ impl Display for ZcashWallet {
                 Some(public_key) => format!("      {}           {}\n", "Public Key".cyan().bold(), public_key),
                 _ => "".to_owned(),
             },
+            match &self.full_viewing_key {
+                Some(full_viewing_key) => {
+                    format!("      {}     {}\n", "Full Viewing Key".cyan().bold(), full_viewing_key)
+                }
+                _ => "".to_owned(),
+            },
+            match &self.incoming_viewing_key {
+                Some(incoming_viewing_key) => format!(
+                    "      {} {}\n",
+                    "Incoming Viewing Key".cyan().bold(),
+                    incoming_viewing_key
+                ),
+                _ => "".to_owned(),
+            },
             match &self.address {
                 Some(address) => format!("      {}              {}\n", "Address".cyan().bold(), address),
                 _ => "".to_owned(),
             },
+            match &self.fingerprint {
+                Some(fingerprint) => format!("      {}          {}\n", "Fingerprint".cyan().bold(), fingerprint),
+                _ => "".to_owned(),
+            },
+            match &self.master_fingerprint {
+                Some(master_fingerprint) => format!(
+                    "      {}   {}\n",
+                    "Master Fingerprint".cyan().bold(),
+                    master_fingerprint
+                ),
+                _ => "".to_owned(),
+            },
+            match &self.wallet_id {
+                Some(wallet_id) => format!("      {}            {}\n", "Wallet ID".cyan().bold(), wallet_id),
+                _ => "".to_owned(),
+            },
             match &self.format {
                 Some(format) => format!("      {}               {}\n", "Format".cyan().bold(), format),
                 _ => "".to_owned(),
@@ -362,6 +778,10 @@ impl Display for ZcashWallet {
                 Some(diversifier) => format!("      {}          {}\n", "Diversifier".cyan().bold(), diversifier),
                 _ => "".to_owned(),
             },
+            match &self.diversifier_index {
+                Some(index) => format!("      {}    {}\n", "Diversifier Index".cyan().bold(), index),
+                _ => "".to_owned(),
+            },
             match &self.network {
                 Some(network) => format!("      {}              {}\n", "Network".cyan().bold(), network),
                 _ => "".to_owned(),
@@ -410,16 +830,26 @@ pub struct ZcashInput {
 #[derive(Clone, Debug, Serialize)]
 pub struct ZcashOptions {
     // Standard command
+    audit_rng: bool,
     count: usize,
     diversifier: Option<String>,
     format: ZcashFormat,
-    json: bool,
     network: String,
+    output_file: Option<String>,
+    output_format: String,
+    public_only: bool,
+    qr: bool,
+    qr_file: Option<String>,
     subcommand: Option<String>,
+    viewing_key: bool,
     // HD and Import HD subcommands
     account: u32,
+    all_formats: bool,
     chain: u32,
     derivation: String,
+    diversifier_index: Option<u64>,
+    entropy: Option<String>,
+    entropy_file: Option<String>,
     extended_private_key: Option<String>,
     extended_public_key: Option<String>,
     index: u32,
@@ -427,34 +857,48 @@ pub struct ZcashOptions {
     mnemonic: Option<String>,
     password: Option<String>,
     path: Option<String>,
+    shielded: bool,
     word_count: u8,
     // Import subcommand
     address: Option<String>,
     private: Option<String>,
     public: Option<String>,
     // Transaction subcommand
+    branch: Option<String>,
     transaction_inputs: Option<String>,
     transaction_hex: Option<String>,
     transaction_outputs: Option<String>,
     expiry_height: Option<u32>,
     lock_time: Option<u32>,
     version: Option<String>,
+    // Paper subcommand
+    paper_file: Option<String>,
 }
 
 impl Default for ZcashOptions {
     fn default() -> Self {
         Self {
             // Standard command
+            audit_rng: false,
             count: 1,
             diversifier: None,
             format: ZcashFormat::P2PKH,
-            json: false,
             network: "mainnet".into(),
+            output_file: None,
+            output_format: "text".into(),
+            public_only: false,
+            qr: false,
+            qr_file: None,
             subcommand: None,
+            viewing_key: false,
             // HD and Import HD subcommands
             account: 0,
+            all_formats: false,
             chain: 0,
             derivation: "bip32".into(),
+            diversifier_index: None,
+            entropy: None,
+            entropy_file: None,
             extended_private_key: None,
             extended_public_key: None,
             index: 0,
@@ -462,18 +906,22 @@ impl Default for ZcashOptions {
             mnemonic: None,
             password: None,
             path: None,
+            shielded: false,
             word_count: 12,
             // Import subcommand
             address: None,
             private: None,
             public: None,
             // Transaction subcommand
+            branch: None,
             transaction_inputs: None,
             transaction_hex: None,
             transaction_outputs: None,
             expiry_height: None,
             lock_time: None,
             version: None,
+            // Paper subcommand
+            paper_file: None,
         }
     }
 }
@@ -483,22 +931,40 @@ impl ZcashOptions {
         options.iter().for_each(|option| match *option {
             "account" => self.account(clap::value_t!(arguments.value_of(*option), u32).ok()),
             "address" => self.address(arguments.value_of(option)),
+            "all formats" => self.all_formats(arguments.is_present(option)),
+            "audit rng" => self.audit_rng(arguments.is_present(option)),
             "count" => self.count(clap::value_t!(arguments.value_of(*option), usize).ok()),
+            "branch" => self.branch(arguments.value_of(option)),
             "createrawtransaction" => self.create_raw_transaction(arguments.values_of(option)),
             "derivation" => self.derivation(arguments.value_of(option)),
+            "entropy" => self.entropy(arguments.value_of(option)),
+            "entropy file" => self.entropy_file(arguments.value_of(option)),
             "diversifier" => self.diversifier(arguments.value_of(option)),
+            "diversifier index" => self.diversifier_index(clap::value_t!(arguments.value_of(*option), u64).ok()),
             "expiry height" => self.expiry_height(clap::value_t!(arguments.value_of(*option), u32).ok()),
             "extended private" => self.extended_private(arguments.value_of(option)),
             "extended public" => self.extended_public(arguments.value_of(option)),
             "format" => self.format(arguments.value_of(option)),
             "index" => self.index(clap::value_t!(arguments.value_of(*option), u32).ok()),
             "json" => self.json(arguments.is_present(option)),
+            "language" => self.language(arguments.value_of(option)),
             "lock time" => self.lock_time(clap::value_t!(arguments.value_of(*option), u32).ok()),
+            "mnemonic" => self.mnemonic(value_or_stdin(arguments, option).as_deref()),
             "network" => self.network(arguments.value_of(option)),
-            "private" => self.private(arguments.value_of(option)),
+            "paper file" => self.paper_file(arguments.value_of(option)),
+            "password" => self.password(value_or_stdin(arguments, option).as_deref()),
+            "private" => self.private(value_or_stdin(arguments, option).as_deref()),
             "public" => self.public(arguments.value_of(option)),
+            "output file" => self.output_file(arguments.value_of(option)),
+            "output format" => self.output_format(arguments.value_of(option)),
+            "public only" => self.public_only(arguments.is_present(option)),
+            "qr" => self.qr(arguments.is_present(option)),
+            "qr file" => self.qr_file(arguments.value_of(option)),
+            "shielded" => self.shielded(arguments.is_present(option)),
             "signrawtransaction" => self.sign_raw_transaction(arguments.values_of(option)),
             "version" => self.version(arguments.value_of(option)),
+            "viewing key" => self.viewing_key(arguments.is_present(option)),
+            "word count" => self.word_count(clap::value_t!(arguments.value_of(*option), u8).ok()),
             _ => (),
         });
     }
@@ -511,6 +977,11 @@ impl ZcashOptions {
         }
     }
 
+    /// Sets `all_formats` to the specified boolean value, overriding its previous state.
+    fn all_formats(&mut self, argument: bool) {
+        self.all_formats = argument;
+    }
+
     /// Imports a wallet for the specified address, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn address(&mut self, argument: Option<&str>) {
@@ -519,6 +990,14 @@ impl ZcashOptions {
         }
     }
 
+    /// Enables `audit_rng` if the specified boolean value is set.
+    /// Once enabled at any level of the command, the entropy drawn remains audited.
+    fn audit_rng(&mut self, argument: bool) {
+        if argument {
+            self.audit_rng = true;
+        }
+    }
+
     /// Sets `count` to the specified count, overriding its previous state.
     fn count(&mut self, argument: Option<usize>) {
         if let Some(count) = argument {
@@ -550,6 +1029,30 @@ impl ZcashOptions {
         };
     }
 
+    /// Sets `diversifier_index` to the specified diversifier index, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn diversifier_index(&mut self, argument: Option<u64>) {
+        if let Some(index) = argument {
+            self.diversifier_index = Some(index);
+        }
+    }
+
+    /// Sets `entropy` to the specified hex entropy, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn entropy(&mut self, argument: Option<&str>) {
+        if let Some(entropy) = argument {
+            self.entropy = Some(entropy.to_string());
+        }
+    }
+
+    /// Sets `entropy_file` to the specified entropy file path, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn entropy_file(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.entropy_file = Some(path.to_string());
+        }
+    }
+
     /// Sets `diversifier` to the specified diversifier and `format` to the updated Sapling format,
     /// overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
@@ -563,6 +1066,14 @@ impl ZcashOptions {
         }
     }
 
+    /// Sets `branch` to the specified consensus branch, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn branch(&mut self, argument: Option<&str>) {
+        if let Some(branch) = argument {
+            self.branch = Some(branch.to_string());
+        }
+    }
+
     /// Sets `expiry_height` to the specified transaction lock time, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn expiry_height(&mut self, argument: Option<u32>) {
@@ -599,7 +1110,6 @@ impl ZcashOptions {
                 }
                 None => self.format = ZcashFormat::Sapling(None),
             },
-            Some("sprout") => self.format = ZcashFormat::Sprout,
             Some("transparent") => self.format = ZcashFormat::P2PKH,
             _ => (),
         };
@@ -613,9 +1123,28 @@ impl ZcashOptions {
         }
     }
 
-    /// Sets `json` to the specified boolean value, overriding its previous state.
+    /// Sets `output_format` to JSON if the specified boolean value is set.
+    /// Once enabled at any level of the command, JSON remains the output format.
     fn json(&mut self, argument: bool) {
-        self.json = argument;
+        if argument {
+            self.output_format = "json".into();
+        }
+    }
+
+    /// Sets `language` to the specified language, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn language(&mut self, argument: Option<&str>) {
+        match argument {
+            Some("chinese_simplified") => self.language = "chinese_simplified".into(),
+            Some("chinese_traditional") => self.language = "chinese_traditional".into(),
+            Some("english") => self.language = "english".into(),
+            Some("french") => self.language = "french".into(),
+            Some("italian") => self.language = "italian".into(),
+            Some("japanese") => self.language = "japanese".into(),
+            Some("korean") => self.language = "korean".into(),
+            Some("spanish") => self.language = "spanish".into(),
+            _ => (),
+        };
     }
 
     /// Sets `lock_time` to the specified transaction lock time, overriding its previous state.
@@ -626,16 +1155,59 @@ impl ZcashOptions {
         }
     }
 
+    /// Sets `mnemonic` to the specified mnemonic, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn mnemonic(&mut self, argument: Option<&str>) {
+        if let Some(mnemonic) = argument {
+            self.mnemonic = Some(mnemonic.to_string());
+        }
+    }
+
     /// Sets `network` to the specified network, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn network(&mut self, argument: Option<&str>) {
         match argument {
             Some("mainnet") => self.network = "mainnet".into(),
+            Some("regtest") => self.network = "regtest".into(),
             Some("testnet") => self.network = "testnet".into(),
             _ => (),
         };
     }
 
+    /// Sets `output_file` to the specified file path, overriding its previous state.
+    /// Colored output is disabled, as the file is not a terminal.
+    /// If the specified argument is `None`, then no change occurs.
+    fn output_file(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.output_file = Some(path.to_string());
+            colored::control::set_override(false);
+        }
+    }
+
+    /// Sets `output_format` to the specified output format, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn output_format(&mut self, argument: Option<&str>) {
+        if let Some(output_format) = argument {
+            self.output_format = output_format.to_string();
+        }
+    }
+
+    /// Sets `paper_file` to the specified file path, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn paper_file(&mut self, argument: Option<&str>) {
+        if let Some(path) = argument {
+            self.paper_file = Some(path.to_string());
+        }
+    }
+
+    /// Sets `password` to the specified password, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn password(&mut self, argument: Option<&str>) {
+        if let Some(password) = argument {
+            self.password = Some(password.to_string());
+        }
+    }
+
     /// Imports a wallet for the specified private key, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn private(&mut self, argument: Option<&str>) {
@@ -652,6 +1224,32 @@ impl ZcashOptions {
         }
     }
 
+    /// Enables `public_only` if the specified boolean value is set.
+    /// Once enabled at any level of the command, the secrets of the wallet(s) remain withheld.
+    fn public_only(&mut self, argument: bool) {
+        if argument {
+            self.public_only = true;
+        }
+    }
+
+    /// Sets `qr` to the specified boolean value, overriding its previous state.
+    fn qr(&mut self, argument: bool) {
+        self.qr = argument;
+    }
+
+    /// Sets `qr_file` to the specified file path, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn qr_file(&mut self, argument: Option<&str>) {
+        if let Some(qr_file) = argument {
+            self.qr_file = Some(qr_file.to_string());
+        }
+    }
+
+    /// Sets `shielded` to the specified boolean value, overriding its previous state.
+    fn shielded(&mut self, argument: bool) {
+        self.shielded = argument;
+    }
+
     /// Sets `transaction_hex` and `transaction_inputs` to the specified transaction values, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn sign_raw_transaction(&mut self, argument: Option<Values>) {
@@ -668,14 +1266,14 @@ impl ZcashOptions {
         match self.derivation.as_str() {
             "zip32" => match self.network.as_str() {
                 "mainnet" => Some(format!("m/32'/133'/{}'/{}", self.account, self.index)),
-                "testnet" => Some(format!("m/32'/1'/{}'/{}", self.account, self.index)),
+                "testnet" | "regtest" => Some(format!("m/32'/1'/{}'/{}", self.account, self.index)),
                 _ => None,
             },
             "custom" => self.path.clone(),
             _ => match default {
                 true => match self.network.as_str() {
                     "mainnet" => Some(format!("m/32'/133'/{}'/{}", self.account, self.index)),
-                    "testnet" => Some(format!("m/32'/1'/{}'/{}", self.account, self.index)),
+                    "testnet" | "regtest" => Some(format!("m/32'/1'/{}'/{}", self.account, self.index)),
                     _ => None,
                 },
                 false => None,
@@ -683,6 +1281,14 @@ impl ZcashOptions {
         }
     }
 
+    /// Returns the ZIP-32 derivation path of the account of a shielded wallet, `m/32'/coin_type'/account'`.
+    fn to_account_derivation_path(&self) -> String {
+        match self.network.as_str() {
+            "testnet" | "regtest" => format!("m/32'/1'/{}'", self.account),
+            _ => format!("m/32'/133'/{}'", self.account),
+        }
+    }
+
     /// Sets `version` to the specified transaction version, overriding its previous state.
     /// If the specified argument is `None`, then no change occurs.
     fn version(&mut self, argument: Option<&str>) {
@@ -690,6 +1296,19 @@ impl ZcashOptions {
             self.version = Some(version.to_string());
         }
     }
+
+    /// Sets `viewing_key` to the specified boolean value, overriding its previous state.
+    fn viewing_key(&mut self, argument: bool) {
+        self.viewing_key = argument;
+    }
+
+    /// Sets `word_count` to the specified word count, overriding its previous state.
+    /// If the specified argument is `None`, then no change occurs.
+    fn word_count(&mut self, argument: Option<u8>) {
+        if let Some(word_count) = argument {
+            self.word_count = word_count;
+        }
+    }
 }
 
 pub struct ZcashCLI;
@@ -699,50 +1318,150 @@ impl CLI for ZcashCLI {
 
     const NAME: NameType = "zcash";
     const ABOUT: AboutType = "Generates a Zcash wallet (include -h for more options)";
-    const FLAGS: &'static [FlagType] = &[flag::JSON];
+    const FLAGS: &'static [FlagType] = &[
+        flag::AUDIT_RNG,
+        flag::JSON,
+        flag::OUTPUT_FILE,
+        flag::OUTPUT_FORMAT,
+        flag::PUBLIC_ONLY,
+        flag::QR,
+        flag::QR_FILE,
+    ];
     const OPTIONS: &'static [OptionType] = &[
         option::COUNT,
         option::DIVERSIFIER_ZCASH,
         option::FORMAT_ZCASH,
         option::NETWORK_ZCASH,
+        option::VIEWING_KEY_ZCASH,
     ];
     const SUBCOMMANDS: &'static [SubCommandType] = &[
         subcommand::HD_ZCASH,
         subcommand::IMPORT_ZCASH,
         subcommand::IMPORT_HD_ZCASH,
+        subcommand::PAPER_ZCASH,
         subcommand::TRANSACTION_ZCASH,
+        subcommand::VALIDATE_ZCASH,
     ];
 
     /// Handle all CLI arguments and flags for Zcash
     #[cfg_attr(tarpaulin, skip)]
     fn parse(arguments: &ArgMatches) -> Result<Self::Options, CLIError> {
         let mut options = ZcashOptions::default();
-        options.parse(arguments, &["count", "diversifier", "format", "json", "network"]);
+        options.parse(
+            arguments,
+            &[
+                "audit rng",
+                "count",
+                "diversifier",
+                "format",
+                "json",
+                "network",
+                "output file",
+                "output format",
+                "public only",
+                "qr",
+                "qr file",
+                "viewing key",
+            ],
+        );
 
         match arguments.subcommand() {
             ("hd", Some(arguments)) => {
                 options.subcommand = Some("hd".into());
-                options.parse(arguments, &["count", "diversifier", "format", "json", "network"]);
-                options.parse(arguments, &["derivation"]);
+                options.parse(
+                    arguments,
+                    &[
+                        "audit rng",
+                        "count",
+                        "diversifier",
+                        "format",
+                        "json",
+                        "network",
+                        "output file",
+                        "output format",
+                        "public only",
+                        "qr",
+                        "qr file",
+                        "viewing key",
+                    ],
+                );
+                options.parse(
+                    arguments,
+                    &[
+                        "account",
+                        "derivation",
+                        "diversifier index",
+                        "entropy",
+                        "entropy file",
+                        "language",
+                        "password",
+                        "shielded",
+                        "word count",
+                    ],
+                );
             }
             ("import", Some(arguments)) => {
                 options.subcommand = Some("import".into());
-                options.parse(arguments, &["diversifier", "format", "json", "network"]);
+                options.parse(
+                    arguments,
+                    &[
+                        "diversifier",
+                        "format",
+                        "json",
+                        "network",
+                        "output file",
+                        "output format",
+                        "public only",
+                        "qr",
+                        "qr file",
+                        "viewing key",
+                    ],
+                );
                 options.parse(arguments, &["address", "private", "public"]);
             }
             ("import-hd", Some(arguments)) => {
                 options.subcommand = Some("import-hd".into());
-                options.parse(arguments, &["diversifier", "format", "json", "network"]);
                 options.parse(
                     arguments,
-                    &["account", "derivation", "extended private", "extended public", "index"],
+                    &[
+                        "diversifier",
+                        "format",
+                        "json",
+                        "network",
+                        "output file",
+                        "output format",
+                        "public only",
+                        "qr",
+                        "qr file",
+                        "viewing key",
+                    ],
+                );
+                options.parse(
+                    arguments,
+                    &[
+                        "account",
+                        "all formats",
+                        "derivation",
+                        "diversifier index",
+                        "extended private",
+                        "extended public",
+                        "index",
+                        "mnemonic",
+                        "password",
+                    ],
                 );
             }
+            ("paper", Some(arguments)) => {
+                options.subcommand = Some("paper".into());
+                options.parse(arguments, &["audit rng", "count", "format", "network"]);
+                options.parse(arguments, &["paper file"]);
+            }
             ("transaction", Some(arguments)) => {
                 options.subcommand = Some("transaction".into());
                 options.parse(
                     arguments,
                     &[
+                        "branch",
                         "createrawtransaction",
                         "expiry height",
                         "lock time",
@@ -751,6 +1470,11 @@ impl CLI for ZcashCLI {
                     ],
                 );
             }
+            ("validate", Some(arguments)) => {
+                options.subcommand = Some("validate".into());
+                options.parse(arguments, &["json", "output file", "output format", "public only"]);
+                options.parse(arguments, &["address"]);
+            }
             _ => {}
         };
 
@@ -761,121 +1485,474 @@ impl CLI for ZcashCLI {
     #[cfg_attr(tarpaulin, skip)]
     fn print(options: Self::Options) -> Result<(), CLIError> {
         fn output<N: ZcashNetwork>(options: ZcashOptions) -> Result<(), CLIError> {
-            let wallets =
-                match options.subcommand.as_ref().map(String::as_str) {
+            if let Some("validate") = options.subcommand.as_deref() {
+                return validate(options);
+            }
+
+            // Generate new wallets lazily, to stream a large count of wallets to the output
+            let format = &options.format;
+            let mut rng = wallet_rng(options.audit_rng);
+            let generated: Box<dyn Iterator<Item = ZcashWallet>> =
+                match options.subcommand.as_deref() {
+                    Some("hd") if options.shielded => {
+                        let path = options.to_account_derivation_path();
+                        let options = options.clone();
+                        let mut entropy = Entropy::new(
+                            options.entropy.as_deref(),
+                            options.entropy_file.as_deref(),
+                            options.audit_rng,
+                        )?;
+                        Box::new((0..options.count).flat_map(move |_| {
+                            match new_shielded::<N>(&options, &mut entropy, &path) {
+                                Ok(wallet) => vec![wallet],
+                                _ => vec![],
+                            }
+                        }))
+                    }
                     Some("hd") => match options.to_derivation_path(true) {
-                        Some(path) => (0..options.count)
-                            .flat_map(|_| {
-                                match ZcashWallet::new_hd::<N, _>(&mut StdRng::from_entropy(), &path, &options.format) {
+                        Some(path) => {
+                            let mut entropy = Entropy::new(
+                                options.entropy.as_deref(),
+                                options.entropy_file.as_deref(),
+                                options.audit_rng,
+                            )?;
+                            Box::new((0..options.count).flat_map(move |_| {
+                                match ZcashWallet::new_hd::<N>(&mut entropy, &path, format) {
                                     Ok(wallet) => vec![wallet],
                                     _ => vec![],
                                 }
-                            })
-                            .collect(),
-                        None => vec![],
-                    },
-                    Some("import") => {
-                        if let Some(private_key) = options.private {
-                            vec![
-                                ZcashWallet::from_private_key::<ZcashMainnet>(&private_key, &options.format).or(
-                                    ZcashWallet::from_private_key::<ZcashTestnet>(&private_key, &options.format),
-                                )?,
-                            ]
-                        } else if let Some(public_key) = options.public {
-                            vec![
-                                ZcashWallet::from_public_key::<ZcashMainnet>(&public_key, &options.format).or(
-                                    ZcashWallet::from_public_key::<ZcashTestnet>(&public_key, &options.format),
-                                )?,
-                            ]
-                        } else if let Some(address) = options.address {
-                            vec![ZcashWallet::from_address::<ZcashMainnet>(&address)
-                                .or(ZcashWallet::from_address::<ZcashTestnet>(&address))?]
-                        } else {
-                            vec![]
+                            }))
                         }
-                    }
-                    Some("import-hd") => {
-                        if let Some(extended_private_key) = options.extended_private_key.clone() {
-                            let key = &extended_private_key;
-                            let path = &options.to_derivation_path(false);
-                            let format = &options.format;
-
-                            vec![
-                                ZcashWallet::from_extended_private_key::<ZcashMainnet>(key, path, format).or(
-                                    ZcashWallet::from_extended_private_key::<ZcashTestnet>(key, path, format),
-                                )?,
-                            ]
-                        } else if let Some(extended_public_key) = options.extended_public_key.clone() {
-                            let key = &extended_public_key;
-                            let path = &options.to_derivation_path(false);
-                            let format = &options.format;
-
-                            vec![ZcashWallet::from_extended_public_key::<ZcashMainnet>(key, path, format)
-                                .or(ZcashWallet::from_extended_public_key::<ZcashTestnet>(key, path, format))?]
-                        } else {
-                            vec![]
+                        None => Box::new(iter::empty()),
+                    },
+                    None | Some("paper") => Box::new((0..options.count).flat_map(move |_| {
+                        match ZcashWallet::new::<N, _>(&mut rng, format) {
+                            Ok(wallet) => vec![wallet],
+                            _ => vec![],
                         }
-                    }
-                    Some("transaction") => {
-                        if let (Some(transaction_inputs), Some(transaction_outputs)) =
-                            (options.transaction_inputs.clone(), options.transaction_outputs.clone())
-                        {
-                            let inputs: &Vec<ZcashInput> = &from_str(&transaction_inputs)?;
-                            let outputs = transaction_outputs.replace(&['{', '}', '"', ' '][..], "");
-                            let outputs: &Vec<&str> = &outputs.split(",").collect();
-                            let version = options.version.unwrap_or("sapling".to_string());
-                            let lock_time = options.lock_time.unwrap_or(0);
-                            let expiry_height = options.expiry_height.unwrap_or(0);
-
-                            vec![ZcashWallet::to_raw_transaction::<ZcashMainnet>(
-                                inputs,
-                                outputs,
-                                version.clone(),
-                                lock_time,
-                                expiry_height,
-                            )
-                            .or(ZcashWallet::to_raw_transaction::<ZcashTestnet>(
-                                inputs,
-                                outputs,
-                                version.clone(),
-                                lock_time,
-                                expiry_height,
+                    })),
+                    _ => Box::new(iter::empty()),
+                };
+
+            let wallets = match options.subcommand.as_ref().map(String::as_str) {
+                Some("import") => {
+                    if let Some(private_key) = options.private {
+                        // Regtest keys share the prefixes of testnet, so the specified network is tried first
+                        vec![ZcashWallet::from_private_key::<N>(&private_key, &options.format)
+                            .or(ZcashWallet::from_private_key::<ZcashMainnet>(
+                                &private_key,
+                                &options.format,
+                            ))
+                            .or(ZcashWallet::from_private_key::<ZcashTestnet>(
+                                &private_key,
+                                &options.format,
                             ))?]
-                        } else if let (Some(transaction_hex), Some(transaction_inputs)) =
-                            (options.transaction_hex.clone(), options.transaction_inputs.clone())
-                        {
-                            let inputs: &Vec<ZcashInput> = &from_str(&transaction_inputs)?;
-
-                            vec![
-                                ZcashWallet::to_signed_transaction::<ZcashMainnet>(&transaction_hex, inputs).or(
-                                    ZcashWallet::to_signed_transaction::<ZcashTestnet>(&transaction_hex, inputs),
-                                )?,
-                            ]
-                        } else {
-                            vec![]
-                        }
+                    } else if let Some(public_key) = options.public {
+                        vec![ZcashWallet::from_public_key::<N>(&public_key, &options.format)
+                            .or(ZcashWallet::from_public_key::<ZcashMainnet>(
+                                &public_key,
+                                &options.format,
+                            ))
+                            .or(ZcashWallet::from_public_key::<ZcashTestnet>(
+                                &public_key,
+                                &options.format,
+                            ))?]
+                    } else if let Some(address) = options.address {
+                        vec![ZcashWallet::from_address::<N>(&address)
+                            .or(ZcashWallet::from_address::<ZcashMainnet>(&address))
+                            .or(ZcashWallet::from_address::<ZcashTestnet>(&address))
+                            .or(ZcashWallet::from_address::<ZcashRegtest>(&address))?]
+                    } else {
+                        vec![]
                     }
-                    _ => (0..options.count)
-                        .flat_map(
-                            |_| match ZcashWallet::new::<N, _>(&mut StdRng::from_entropy(), &options.format) {
-                                Ok(wallet) => vec![wallet],
-                                _ => vec![],
-                            },
+                }
+                Some("import-hd") => {
+                    if let (Some(mnemonic), true) = (options.mnemonic.clone(), options.all_formats) {
+                        let password = options.password.as_deref();
+                        ZcashWallet::from_mnemonic_all_formats::<N>(
+                            &mnemonic,
+                            password,
+                            options.account,
+                            options.index,
+                        )?
+                    } else if let Some(mnemonic) = options.mnemonic.clone() {
+                        let password = options.password.as_deref();
+                        let path = &options
+                            .to_derivation_path(false)
+                            .unwrap_or_else(|| options.to_account_derivation_path());
+                        let format = &options.format;
+
+                        vec![ZcashWallet::from_mnemonic_any_language::<N>(
+                            &mnemonic, password, path, format,
+                        )?]
+                    } else if let Some(extended_private_key) = options.extended_private_key.clone() {
+                        let key = &extended_private_key;
+                        let path = &options.to_derivation_path(false);
+                        let format = &options.format;
+
+                        vec![
+                            ZcashWallet::from_extended_private_key::<ZcashMainnet>(key, path, format)
+                                .or(ZcashWallet::from_extended_private_key::<ZcashTestnet>(
+                                    key, path, format,
+                                ))
+                                .or(ZcashWallet::from_extended_private_key::<ZcashRegtest>(
+                                    key, path, format,
+                                ))?,
+                        ]
+                    } else if let Some(extended_public_key) = options.extended_public_key.clone() {
+                        let key = &extended_public_key;
+                        let path = &options.to_derivation_path(false);
+                        let format = &options.format;
+
+                        vec![ZcashWallet::from_extended_public_key::<ZcashMainnet>(key, path, format)
+                            .or(ZcashWallet::from_extended_public_key::<ZcashTestnet>(key, path, format))
+                            .or(ZcashWallet::from_extended_public_key::<ZcashRegtest>(key, path, format))?]
+                    } else {
+                        vec![]
+                    }
+                }
+                Some("transaction") => {
+                    if let (Some(transaction_inputs), Some(transaction_outputs)) =
+                        (options.transaction_inputs.clone(), options.transaction_outputs.clone())
+                    {
+                        let inputs: &Vec<ZcashInput> = &from_str(&transaction_inputs)?;
+                        let outputs = transaction_outputs.replace(&['{', '}', '"', ' '][..], "");
+                        let outputs: &Vec<&str> = &outputs.split(",").collect();
+                        let version = options.version.unwrap_or("sapling".to_string());
+                        let lock_time = options.lock_time.unwrap_or(0);
+                        let expiry_height = options.expiry_height.unwrap_or(0);
+                        let branch = options
+                            .branch
+                            .as_deref()
+                            .map(ZcashConsensusBranch::from_str)
+                            .transpose()?;
+
+                        vec![ZcashWallet::to_raw_transaction::<N>(
+                            inputs,
+                            outputs,
+                            version.clone(),
+                            lock_time,
+                            expiry_height,
+                            branch,
                         )
-                        .collect(),
-                };
+                        .or(ZcashWallet::to_raw_transaction::<ZcashMainnet>(
+                            inputs,
+                            outputs,
+                            version.clone(),
+                            lock_time,
+                            expiry_height,
+                            branch,
+                        ))
+                        .or(ZcashWallet::to_raw_transaction::<ZcashTestnet>(
+                            inputs,
+                            outputs,
+                            version.clone(),
+                            lock_time,
+                            expiry_height,
+                            branch,
+                        ))?]
+                    } else if let (Some(transaction_hex), Some(transaction_inputs)) =
+                        (options.transaction_hex.clone(), options.transaction_inputs.clone())
+                    {
+                        let inputs: &Vec<ZcashInput> = &from_str(&transaction_inputs)?;
+                        let branch = options
+                            .branch
+                            .as_deref()
+                            .map(ZcashConsensusBranch::from_str)
+                            .transpose()?;
+
+                        vec![
+                            ZcashWallet::to_signed_transaction::<N>(&transaction_hex, inputs, branch)
+                                .or(ZcashWallet::to_signed_transaction::<ZcashMainnet>(
+                                    &transaction_hex,
+                                    inputs,
+                                    branch,
+                                ))
+                                .or(ZcashWallet::to_signed_transaction::<ZcashTestnet>(
+                                    &transaction_hex,
+                                    inputs,
+                                    branch,
+                                ))?,
+                        ]
+                    } else {
+                        vec![]
+                    }
+                }
+                _ => vec![],
+            };
 
-            match options.json {
-                true => println!("{}\n", serde_json::to_string_pretty(&wallets)?),
-                false => wallets.iter().for_each(|wallet| println!("{}\n", wallet)),
+            // Sprout wallets are import-only, to recover their funds for migration to Sapling
+            if wallets.iter().any(|wallet| wallet.format.as_deref() == Some("sprout")) {
+                eprintln!("{} {}", "Warning:".yellow().bold(), SPROUT_MIGRATION_GUIDANCE);
+            }
+
+            let (viewing_key, diversifier_index) = (options.viewing_key, options.diversifier_index);
+            let wallets = wallets
+                .into_iter()
+                .chain(generated)
+                .map(move |wallet| match viewing_key {
+                    true => wallet.with_viewing_keys(),
+                    false => wallet,
+                })
+                .map(move |wallet| match diversifier_index {
+                    Some(index) => wallet.with_diversifier_index(index),
+                    None => Ok(wallet),
+                });
+
+            match options.paper_file.as_deref() {
+                Some(path) => {
+                    if options.public_only {
+                        return Err(CLIError::UnsupportedFormat("paper wallet with --public-only".into()));
+                    }
+                    let mut wallets = wallets.collect::<Result<Vec<ZcashWallet>, CLIError>>()?;
+                    let paper_wallets = wallets
+                        .iter()
+                        .filter_map(ZcashWallet::to_paper_wallet)
+                        .collect::<Vec<PaperWallet>>();
+                    paper::output("Zcash", &paper_wallets, path)?;
+                    wallets.iter_mut().for_each(Zeroize::zeroize);
+                }
+                None => {
+                    let path = options.output_file.as_deref();
+                    let mut writer = WalletWriter::new(&options.output_format, path, ZcashWallet::FIELDS)?;
+                    for (index, wallet) in wallets.enumerate() {
+                        let mut wallet = wallet?;
+                        if options.public_only {
+                            wallet.public_only();
+                        }
+                        writer.write(&wallet)?;
+                        if let Some(qr_data) = wallet.to_qr_data() {
+                            qr::output(qr_data, index, options.qr, options.qr_file.as_deref())?;
+                        }
+                        wallet.zeroize();
+                    }
+                    writer.finish()?;
+                }
             };
 
             Ok(())
         }
 
+        /// Generates a shielded HD wallet, with a mnemonic in the language of the options
+        fn new_shielded<N: ZcashNetwork>(
+            options: &ZcashOptions,
+            entropy: &mut Entropy,
+            path: &str,
+        ) -> Result<ZcashWallet, CLIError> {
+            let (word_count, password, format) = (options.word_count, options.password.as_deref(), &options.format);
+            match options.language.as_str() {
+                "chinese_simplified" => {
+                    ZcashWallet::new_shielded::<N, ChineseSimplified>(entropy, word_count, password, path, format)
+                }
+                "chinese_traditional" => {
+                    ZcashWallet::new_shielded::<N, ChineseTraditional>(entropy, word_count, password, path, format)
+                }
+                "french" => ZcashWallet::new_shielded::<N, French>(entropy, word_count, password, path, format),
+                "italian" => ZcashWallet::new_shielded::<N, Italian>(entropy, word_count, password, path, format),
+                "japanese" => ZcashWallet::new_shielded::<N, Japanese>(entropy, word_count, password, path, format),
+                "korean" => ZcashWallet::new_shielded::<N, Korean>(entropy, word_count, password, path, format),
+                "spanish" => ZcashWallet::new_shielded::<N, Spanish>(entropy, word_count, password, path, format),
+                _ => ZcashWallet::new_shielded::<N, English>(entropy, word_count, password, path, format),
+            }
+        }
+
+        /// Validates the address, printing its network, format, checksum, and payload
+        fn validate(options: ZcashOptions) -> Result<(), CLIError> {
+            let address = options.address.as_deref().unwrap_or_default();
+            let mut diagnostics = AddressDiagnostics::new(address);
+
+            // Decode the checksum and payload of a Sapling bech32 address, or a transparent
+            // or Sprout base58 address, and return its prefix and payload length
+            let sapling = ["zs", "ztestsapling", "zregtestsapling"];
+            let (prefix, length) = match sapling.iter().any(|prefix| address.starts_with(prefix)) {
+                true => match from_bech32(address) {
+                    Ok((hrp, data)) => {
+                        diagnostics.checksum = Some(data.is_some());
+                        match data.map(|data| Vec::<u8>::from_base32(&data)) {
+                            Some(Ok(payload)) => diagnostics.payload = Some(hex::encode(payload)),
+                            Some(Err(error)) => diagnostics.error(AddressError::from(error)),
+                            None => {}
+                        };
+                        let length = diagnostics.payload.as_ref().map(|payload| payload.len() / 2);
+                        (hrp.into_bytes(), length)
+                    }
+                    Err(error) => {
+                        diagnostics.error(error);
+                        (vec![], None)
+                    }
+                },
+                false => match from_base58_check(address) {
+                    Ok((data, checksum)) if data.len() > 2 => {
+                        diagnostics.checksum = Some(checksum);
+                        diagnostics.payload = Some(hex::encode(&data[2..]));
+                        (data[0..2].to_vec(), Some(data.len() - 2))
+                    }
+                    Ok((data, _)) => {
+                        diagnostics.error(AddressError::InvalidByteLength(data.len()));
+                        (vec![], None)
+                    }
+                    Err(error) => {
+                        diagnostics.error(error);
+                        (vec![], None)
+                    }
+                },
+            };
+
+            if ZcashMainnet::from_address_prefix(&prefix).is_ok() {
+                diagnostics.network = Some(ZcashMainnet::NAME.into());
+            } else if ZcashTestnet::from_address_prefix(&prefix).is_ok() {
+                diagnostics.network = Some(ZcashTestnet::NAME.into());
+            } else if ZcashRegtest::from_address_prefix(&prefix).is_ok() {
+                diagnostics.network = Some(ZcashRegtest::NAME.into());
+            }
+
+            // Verify the payload length of the format of the prefix
+            match ZcashFormat::from_address_prefix(&prefix) {
+                Ok(format) => {
+                    let expected = match format {
+                        ZcashFormat::P2PKH | ZcashFormat::P2SH => 20,
+                        ZcashFormat::Sprout => 64,
+                        ZcashFormat::Sapling(_) => 43,
+                    };
+                    match length {
+                        Some(length) if length != expected => {
+                            diagnostics.error(AddressError::InvalidByteLength(length))
+                        }
+                        _ => diagnostics.format = Some(format.to_string()),
+                    };
+                }
+                Err(error) => diagnostics.error(error),
+            };
+
+            diagnostics::output(
+                &diagnostics.finish(),
+                &options.output_format,
+                options.output_file.as_deref(),
+            )
+        }
+
         match options.network.as_str() {
             "testnet" => output::<ZcashTestnet>(options),
+            "regtest" => output::<ZcashRegtest>(options),
             _ => output::<ZcashMainnet>(options),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn import_all_formats() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let wallets = ZcashWallet::from_mnemonic_all_formats::<ZcashMainnet>(mnemonic, None, 0, 0).unwrap();
+        assert_eq!(2, wallets.len());
+
+        let (sapling, transparent) = (&wallets[0], &wallets[1]);
+        assert_eq!(Some("m/32'/133'/0'"), sapling.path.as_deref());
+        assert_eq!(Some("sapling"), sapling.format.as_deref());
+        assert!(sapling.full_viewing_key.is_some() && sapling.incoming_viewing_key.is_some());
+
+        assert_eq!(Some("m/44'/133'/0'/0/0"), transparent.path.as_deref());
+        assert_eq!(
+            Some("t1XVXWCvpMgBvUaed4XDqWtgQgJSu1Ghz7F"),
+            transparent.address.as_deref()
+        );
+    }
+
+    #[test]
+    fn overwinter_transaction() {
+        let txid = "d9042195d9a1b65b2f1f79d68ceb1a5ea6459c9651a6ad4dc1f465824785c6a8";
+        let input = ZcashInput {
+            txid: txid.into(),
+            vout: 0,
+            amount: None,
+            address: None,
+            private_key: None,
+            script_pub_key: None,
+            redeem_script: None,
+        };
+        let outputs = vec!["tmMVUvhGDFmCAUsXdeGLhftcPJzB8LQ7VrV:10000"];
+        let raw = ZcashWallet::to_raw_transaction::<ZcashTestnet>(
+            &vec![input.clone()],
+            &outputs,
+            "overwinter".into(),
+            0,
+            0,
+            None,
+        )
+        .unwrap()
+        .transaction_hex
+        .unwrap();
+        assert!(raw.starts_with("030000807082c403"));
+
+        let signing_input = ZcashInput {
+            amount: Some(20000),
+            address: Some("tmKMC7Bswv9Pgqr9DMsYsWbmwfSb61NToF7".into()),
+            private_key: Some("cUacGttX6uipjEPinJv2BHuax2VNNpHGrf3psRABxtuAddpxLep7".into()),
+            ..input.clone()
+        };
+        let signed = ZcashWallet::to_signed_transaction::<ZcashTestnet>(&raw, &vec![signing_input], None).unwrap();
+        let signed = signed.transaction_hex.unwrap();
+        assert!(signed.starts_with("030000807082c403") && signed.len() > raw.len());
+
+        // Overwinter transactions are transparent-only
+        let outputs =
+            vec!["ztestsapling1ml8v92nfl07t7tsncwf9x0upqgncljpcrs3c53esgjupkagfffk98ngwhdqcw5pc8v4r2wmx0lk:10000"];
+        assert!(ZcashWallet::to_raw_transaction::<ZcashTestnet>(
+            &vec![input],
+            &outputs,
+            "overwinter".into(),
+            0,
+            0,
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn consensus_branch_transaction() {
+        let input = ZcashInput {
+            txid: "d9042195d9a1b65b2f1f79d68ceb1a5ea6459c9651a6ad4dc1f465824785c6a8".into(),
+            vout: 0,
+            amount: Some(20000),
+            address: Some("tmKMC7Bswv9Pgqr9DMsYsWbmwfSb61NToF7".into()),
+            private_key: Some("cUacGttX6uipjEPinJv2BHuax2VNNpHGrf3psRABxtuAddpxLep7".into()),
+            script_pub_key: None,
+            redeem_script: None,
+        };
+        let outputs = vec!["tmMVUvhGDFmCAUsXdeGLhftcPJzB8LQ7VrV:10000"];
+        let raw = ZcashWallet::to_raw_transaction::<ZcashTestnet>(
+            &vec![input.clone()],
+            &outputs,
+            "sapling".into(),
+            0,
+            0,
+            None,
+        )
+        .unwrap()
+        .transaction_hex
+        .unwrap();
+
+        let sign = |branch: Option<ZcashConsensusBranch>| {
+            ZcashWallet::to_signed_transaction::<ZcashTestnet>(&raw, &vec![input.clone()], branch)
+                .unwrap()
+                .transaction_hex
+                .unwrap()
+        };
+
+        // Version 4 transactions are signed for the latest network upgrade by default
+        let latest = sign(None);
+        assert_eq!(latest, sign(Some(ZcashConsensusBranch::latest())));
+        assert_eq!(
+            latest,
+            sign(Some(ZcashConsensusBranch::from_str("0xc8e71055").unwrap()))
+        );
+
+        let canopy = sign(Some(ZcashConsensusBranch::Canopy));
+        assert_ne!(latest, canopy);
+        assert_eq!(canopy, sign(Some(ZcashConsensusBranch::from_str("e9ff75a6").unwrap())));
+        assert!(ZcashConsensusBranch::from_str("canopy2").is_err());
+    }
+}
@@ -5,9 +5,16 @@
 extern crate failure;
 
 pub extern crate wagyu_bitcoin as bitcoin;
+pub extern crate wagyu_bitcoincash as bitcoincash;
+pub extern crate wagyu_cosmos as cosmos;
+pub extern crate wagyu_dash as dash;
+pub extern crate wagyu_dogecoin as dogecoin;
 pub extern crate wagyu_ethereum as ethereum;
 pub extern crate wagyu_model as model;
 pub extern crate wagyu_monero as monero;
+pub extern crate wagyu_ripple as ripple;
+pub extern crate wagyu_solana as solana;
+pub extern crate wagyu_tron as tron;
 pub extern crate wagyu_zcash as zcash;
 
 #[cfg_attr(tarpaulin, skip)]
@@ -3,18 +3,34 @@
 //! A command-line tool to generate cryptocurrency wallets.
 
 use wagyu::cli::bitcoin::BitcoinCLI;
+use wagyu::cli::bitcoincash::BitcoinCashCLI;
+use wagyu::cli::cosmos::CosmosCLI;
+use wagyu::cli::dash::DashCLI;
+use wagyu::cli::dogecoin::DogecoinCLI;
 use wagyu::cli::ethereum::EthereumCLI;
+use wagyu::cli::interactive::InteractiveCLI;
+use wagyu::cli::introspect::{CommandDescription, CompletionsCLI, IntrospectCLI};
 use wagyu::cli::monero::MoneroCLI;
+use wagyu::cli::ripple::RippleCLI;
+use wagyu::cli::solana::SolanaCLI;
+use wagyu::cli::tron::TronCLI;
+use wagyu::cli::vectors::TestVectorsCLI;
 use wagyu::cli::zcash::ZcashCLI;
-use wagyu::cli::{CLIError, CLI};
+use wagyu::cli::{is_json_output, CLIError, CLI};
 
-use clap::{App, AppSettings};
+use clap::{App, AppSettings, ArgMatches};
+use colored::*;
+use zeroize::Zeroize;
+
+const NAME: &str = "wagyu";
+const ABOUT: &str =
+    "Generate a wallet for Bitcoin, Bitcoin Cash, Cosmos, Dash, Dogecoin, Ethereum, Monero, Ripple, Solana, Tron, and Zcash";
 
 #[cfg_attr(tarpaulin, skip)]
-fn main() -> Result<(), CLIError> {
-    let arguments = App::new("wagyu")
+fn app<'a, 'b>() -> App<'a, 'b> {
+    App::new(NAME)
         .version("v0.6.3")
-        .about("Generate a wallet for Bitcoin, Ethereum, Monero, and Zcash")
+        .about(ABOUT)
         .author("Aleo <hello@aleo.org>")
         .settings(&[
             AppSettings::ColoredHelp,
@@ -24,18 +40,96 @@ fn main() -> Result<(), CLIError> {
         ])
         .subcommands(vec![
             BitcoinCLI::new(),
+            BitcoinCashCLI::new(),
+            CompletionsCLI::new(),
+            CosmosCLI::new(),
+            DashCLI::new(),
+            DogecoinCLI::new(),
             EthereumCLI::new(),
+            InteractiveCLI::new(),
+            IntrospectCLI::new(),
             MoneroCLI::new(),
+            RippleCLI::new(),
+            SolanaCLI::new(),
+            TestVectorsCLI::new(),
+            TronCLI::new(),
             ZcashCLI::new(),
         ])
         .set_term_width(0)
-        .get_matches();
+}
+
+#[cfg_attr(tarpaulin, skip)]
+fn main() {
+    let arguments = app().get_matches();
+
+    if let Err(error) = run(&arguments) {
+        match is_json_output(&arguments) {
+            true => eprintln!("{}", error.to_json()),
+            false => eprintln!("{} {}", "Error:".red().bold(), error),
+        };
+        std::process::exit(error.exit_code());
+    }
+}
 
+#[cfg_attr(tarpaulin, skip)]
+fn run(arguments: &ArgMatches) -> Result<(), CLIError> {
     match arguments.subcommand() {
         ("bitcoin", Some(arguments)) => BitcoinCLI::print(BitcoinCLI::parse(arguments)?),
+        ("bitcoincash", Some(arguments)) => BitcoinCashCLI::print(BitcoinCashCLI::parse(arguments)?),
+        ("completions", Some(arguments)) => {
+            CompletionsCLI::print(app(), arguments.value_of("shell").unwrap_or("bash"));
+            Ok(())
+        }
+        ("cosmos", Some(arguments)) => CosmosCLI::print(CosmosCLI::parse(arguments)?),
+        ("dash", Some(arguments)) => DashCLI::print(DashCLI::parse(arguments)?),
+        ("dogecoin", Some(arguments)) => DogecoinCLI::print(DogecoinCLI::parse(arguments)?),
         ("ethereum", Some(arguments)) => EthereumCLI::print(EthereumCLI::parse(arguments)?),
+        ("interactive", Some(_)) => match InteractiveCLI::prompt()? {
+            Some(mut arguments) => {
+                let result = run(&app().get_matches_from(&arguments));
+                arguments.zeroize();
+                result
+            }
+            None => Ok(()),
+        },
+        ("introspect", Some(_)) => {
+            println!("{}", serde_json::to_string_pretty(&describe())?);
+            Ok(())
+        }
         ("monero", Some(arguments)) => MoneroCLI::print(MoneroCLI::parse(arguments)?),
+        ("ripple", Some(arguments)) => RippleCLI::print(RippleCLI::parse(arguments)?),
+        ("solana", Some(arguments)) => SolanaCLI::print(SolanaCLI::parse(arguments)?),
+        ("test-vectors", Some(arguments)) => TestVectorsCLI::print(
+            arguments.value_of("currency").unwrap_or("all"),
+            arguments.value_of("seed").unwrap_or(""),
+        ),
+        ("tron", Some(arguments)) => TronCLI::print(TronCLI::parse(arguments)?),
         ("zcash", Some(arguments)) => ZcashCLI::print(ZcashCLI::parse(arguments)?),
         _ => unreachable!(),
     }
 }
+
+/// Returns the description of every command of the CLI, for front-ends to generate their forms from.
+#[cfg_attr(tarpaulin, skip)]
+fn describe() -> CommandDescription {
+    CommandDescription {
+        subcommands: vec![
+            CommandDescription::from_cli::<BitcoinCLI>(),
+            CommandDescription::from_cli::<BitcoinCashCLI>(),
+            CompletionsCLI::describe(),
+            CommandDescription::from_cli::<CosmosCLI>(),
+            CommandDescription::from_cli::<DashCLI>(),
+            CommandDescription::from_cli::<DogecoinCLI>(),
+            CommandDescription::from_cli::<EthereumCLI>(),
+            CommandDescription::new(InteractiveCLI::NAME, InteractiveCLI::ABOUT),
+            IntrospectCLI::describe(),
+            CommandDescription::from_cli::<MoneroCLI>(),
+            CommandDescription::from_cli::<RippleCLI>(),
+            CommandDescription::from_cli::<SolanaCLI>(),
+            TestVectorsCLI::describe(),
+            CommandDescription::from_cli::<TronCLI>(),
+            CommandDescription::from_cli::<ZcashCLI>(),
+        ],
+        ..CommandDescription::new(NAME, ABOUT)
+    }
+}
@@ -0,0 +1,74 @@
+use wagyu_model::{PrivateKey, Transaction};
+use wagyu_zcash::{
+    Mainnet, SignatureHash, ZcashAddress, ZcashAmount, ZcashFormat, ZcashNetwork, ZcashPrivateKey, ZcashTransaction,
+    ZcashTransactionParameters,
+};
+
+use core::str::FromStr;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+const PRIVATE_KEY: &str = "KwbK8JibyGAKz7h7uXAmW2hmM68SDGZenurVMKvUMoH5n97dEekL";
+const RECEIVER: &str = "t1S5TMtjLu73QwjMkYDwa67B39qqneqq4yY";
+const TRANSACTION_ID: [u8; 32] = [0x61; 32];
+
+fn private_key_new<N: ZcashNetwork>(c: &mut Criterion) {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    c.bench_function("zcash_private_key_new_p2pkh", |b| {
+        b.iter(|| ZcashPrivateKey::<N>::new_p2pkh(rng).unwrap())
+    });
+    c.bench_function("zcash_private_key_new_sapling", |b| {
+        b.iter(|| ZcashPrivateKey::<N>::new_sapling(rng).unwrap())
+    });
+}
+
+fn to_address<N: ZcashNetwork>(c: &mut Criterion) {
+    let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+    let p2pkh_private_key = ZcashPrivateKey::<N>::new_p2pkh(rng).unwrap();
+    c.bench_function("zcash_to_address_p2pkh", |b| {
+        b.iter(|| black_box(&p2pkh_private_key).to_address(&ZcashFormat::P2PKH).unwrap())
+    });
+    let sapling_private_key = ZcashPrivateKey::<N>::new_sapling(rng).unwrap();
+    c.bench_function("zcash_to_address_sapling", |b| {
+        b.iter(|| {
+            black_box(&sapling_private_key)
+                .to_address(&ZcashFormat::Sapling(None))
+                .unwrap()
+        })
+    });
+}
+
+fn sign<N: ZcashNetwork>(c: &mut Criterion) {
+    let private_key = ZcashPrivateKey::<N>::from_str(PRIVATE_KEY).unwrap();
+    let parameters = ZcashTransactionParameters::<N>::new("sapling", 0, 0)
+        .unwrap()
+        .add_transparent_input(
+            TRANSACTION_ID.to_vec(),
+            0,
+            Some(private_key.to_address(&ZcashFormat::P2PKH).unwrap()),
+            Some(ZcashAmount::from_zatoshi(100000).unwrap()),
+            None,
+            None,
+            None,
+            SignatureHash::SIGHASH_ALL,
+        )
+        .unwrap()
+        .add_transparent_output(
+            &ZcashAddress::<N>::from_str(RECEIVER).unwrap(),
+            ZcashAmount::from_zatoshi(90000).unwrap(),
+        )
+        .unwrap();
+    let transaction = ZcashTransaction::<N>::new(&parameters).unwrap();
+    c.bench_function("zcash_transaction_sign_p2pkh", |b| {
+        b.iter(|| black_box(&transaction).sign(&private_key).unwrap())
+    });
+}
+
+criterion_group!(
+    benches,
+    private_key_new::<Mainnet>,
+    to_address::<Mainnet>,
+    sign::<Mainnet>
+);
+criterion_main!(benches);
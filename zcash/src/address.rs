@@ -5,12 +5,9 @@ use crate::network::ZcashNetwork;
 use crate::private_key::ZcashPrivateKey;
 use crate::public_key::{P2PKHViewingKey, SaplingFullViewingKey, SproutViewingKey, ZcashPublicKey};
 use wagyu_model::no_std::{vec, String, ToString, Vec};
-use wagyu_model::{
-    crypto::{checksum, hash160},
-    Address, AddressError, PrivateKey,
-};
+use wagyu_model::{crypto::hash160, encoding::base58check, Address, AddressError, PrivateKey};
 
-use base58::{FromBase58, ToBase58};
+use base58::FromBase58;
 use bech32::{Bech32, FromBase32, ToBase32};
 use core::{convert::TryFrom, fmt, marker::PhantomData, str, str::FromStr};
 use curve25519_dalek::constants::ED25519_BASEPOINT_TABLE;
@@ -58,18 +55,15 @@ impl<N: ZcashNetwork> Address for ZcashAddress<N> {
 impl<N: ZcashNetwork> ZcashAddress<N> {
     /// Returns a transparent address from a given Zcash public key.
     pub fn p2pkh(public_key: &P2PKHViewingKey) -> Self {
-        let mut address = [0u8; 26];
+        let mut address = [0u8; 22];
         address[0..2].copy_from_slice(&N::to_address_prefix(&ZcashFormat::P2PKH));
         address[2..22].copy_from_slice(&hash160(&match public_key.compressed {
             true => public_key.public_key.serialize_compressed().to_vec(),
             false => public_key.public_key.serialize().to_vec(),
         }));
 
-        let sum = &checksum(&address[0..22])[0..4];
-        address[22..26].copy_from_slice(sum);
-
         Self {
-            address: address.to_base58(),
+            address: base58check::encode(&address),
             format: ZcashFormat::P2PKH,
             _network: PhantomData,
         }
@@ -84,16 +78,13 @@ impl<N: ZcashNetwork> ZcashAddress<N> {
     pub fn sprout(public_key: &SproutViewingKey) -> Result<Self, AddressError> {
         let pk = &Scalar::from_bits(public_key.key_b) * &ED25519_BASEPOINT_TABLE;
 
-        let mut address = [0u8; 70];
+        let mut address = [0u8; 66];
         address[0..2].copy_from_slice(&N::to_address_prefix(&ZcashFormat::Sprout));
         address[2..34].copy_from_slice(&public_key.key_a);
         address[34..66].copy_from_slice(pk.to_montgomery().as_bytes());
 
-        let sum = &checksum(&address[0..66])[0..4];
-        address[66..].copy_from_slice(sum);
-
         Ok(Self {
-            address: address.to_base58(),
+            address: base58check::encode(&address),
             format: ZcashFormat::Sprout,
             _network: PhantomData,
         })
@@ -184,6 +175,10 @@ impl<N: ZcashNetwork> FromStr for ZcashAddress<N> {
             return Err(AddressError::InvalidCharacterLength(address.len()));
         }
 
+        if !address.is_ascii() {
+            return Err(AddressError::InvalidAddress(address.into()));
+        }
+
         // Transparent
         if &address[0..=0] == "t" && address.len() < 40 {
             match &address[1..=1] {
@@ -193,6 +188,8 @@ impl<N: ZcashNetwork> FromStr for ZcashAddress<N> {
                         return Err(AddressError::InvalidByteLength(data.len()));
                     }
 
+                    base58check::verify(&data)?;
+
                     // Check that the network bytes correspond with the correct network.
                     let _ = N::from_address_prefix(&data[0..2].to_vec())?;
                     let format = ZcashFormat::from_address_prefix(&data[0..2].to_vec())?;
@@ -203,9 +200,6 @@ impl<N: ZcashNetwork> FromStr for ZcashAddress<N> {
                         _network: PhantomData,
                     });
                 }
-                "3" | "2" => {
-                    unimplemented!("");
-                }
                 _ => return Err(AddressError::InvalidAddress(address.into())),
             }
         }
@@ -217,6 +211,16 @@ impl<N: ZcashNetwork> FromStr for ZcashAddress<N> {
                 let _ = N::from_address_prefix(&address[0..12].as_bytes().to_vec())?;
                 let format = ZcashFormat::Sapling(Some(Self::get_diversifier(address)?));
 
+                return Ok(Self {
+                    address: address.into(),
+                    format,
+                    _network: PhantomData,
+                });
+            } else if &address[0..15] == "zregtestsapling" && address.len() > 90 {
+                // Check that the network bytes correspond with the correct network.
+                let _ = N::from_address_prefix(&address[0..15].as_bytes().to_vec())?;
+                let format = ZcashFormat::Sapling(Some(Self::get_diversifier(address)?));
+
                 return Ok(Self {
                     address: address.into(),
                     format,
@@ -233,7 +237,7 @@ impl<N: ZcashNetwork> FromStr for ZcashAddress<N> {
                     _network: PhantomData,
                 });
             } else if &address[0..2] == "zt" && address.len() == 95 {
-                let data = address.from_base58()?;
+                let data = base58check::decode(address)?;
 
                 // Check that the network bytes correspond with the correct network.
                 let _ = N::from_address_prefix(&data[0..2].to_vec())?;
@@ -245,7 +249,7 @@ impl<N: ZcashNetwork> FromStr for ZcashAddress<N> {
                     _network: PhantomData,
                 });
             } else if &address[0..2] == "zc" && address.len() == 95 {
-                let data = address.from_base58()?;
+                let data = base58check::decode(address)?;
 
                 // Check that the network bytes correspond with the correct network.
                 let _ = N::from_address_prefix(&data[0..2].to_vec())?;
@@ -752,6 +756,61 @@ mod tests {
         }
     }
 
+    mod sapling_regtest {
+        use super::*;
+
+        type N = Regtest;
+
+        const KEYPAIRS: [(&str, &str); 3] = [
+            (
+                "secret-spending-key-regtest1fygsm6l3ltqqs63040tq426p85pgzuetdeg6q0wka38nx3rfa70sp9st4u",
+                "zregtestsapling1jzzt7gjscav7lmdpemknv0v8rmmdzpcaqrx95azrgaky94drrvf0fg4wlnlkaclqj3r3s44p8pw",
+            ),
+            (
+                "secret-spending-key-regtest1f6w469xhw65naz4pm4lxnmd8em7ev5ddzszy8js325lr0xe2ay9s0u703y",
+                "zregtestsapling19epsvtxnzf59pr993fq4g0gu0fmrn2jl2z9jm2lgj3220c7r9shyvcpe25ul7wxvzk60zcwtfce",
+            ),
+            (
+                "secret-spending-key-regtest1s4zwnn7xgglz9099kc4l2ejfl5m3ddkvpy3erm960raszl2lakssf8v9ym",
+                "zregtestsapling18ur694qcm6w657u9xt8aekutn98gyvpzwzjgjz99594x775ppeze5vwnp2ndw0u205vkugwzdfm",
+            ),
+        ];
+
+        #[test]
+        fn from_private_key() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                let private_key = ZcashPrivateKey::<N>::from_str(private_key).unwrap();
+                test_from_private_key(
+                    address,
+                    &private_key,
+                    &ZcashFormat::Sapling(Some(ZcashAddress::<N>::get_diversifier(address).unwrap())),
+                );
+            });
+        }
+
+        #[test]
+        fn from_str() {
+            KEYPAIRS.iter().for_each(|(_, address)| {
+                test_from_str::<N>(
+                    address,
+                    &ZcashFormat::Sapling(Some(ZcashAddress::<N>::get_diversifier(address).unwrap())),
+                );
+            });
+        }
+
+        #[test]
+        fn from_other_network() {
+            KEYPAIRS.iter().for_each(|(private_key, address)| {
+                assert!(ZcashAddress::<Mainnet>::from_str(address).is_err());
+                assert!(ZcashAddress::<Testnet>::from_str(address).is_err());
+                assert!(ZcashPrivateKey::<Testnet>::from_str(private_key).is_err());
+            });
+            let testnet_address =
+                "ztestsapling1jzzt7gjscav7lmdpemknv0v8rmmdzpcaqrx95azrgaky94drrvf0fg4wlnlkaclqj3r3s23g2sf";
+            assert!(ZcashAddress::<N>::from_str(testnet_address).is_err());
+        }
+    }
+
     #[test]
     fn test_p2pkh_invalid() {
         type N = Mainnet;
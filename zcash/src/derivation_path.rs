@@ -1,6 +1,6 @@
 use crate::network::ZcashNetwork;
-use wagyu_model::derivation_path::{ChildIndex, DerivationPath, DerivationPathError};
-use wagyu_model::no_std::{vec, ToString, Vec};
+use wagyu_model::derivation_path::{BIP32Path, ChildIndex, DerivationPath, DerivationPathError};
+use wagyu_model::no_std::{vec, Vec};
 
 use core::{convert::TryFrom, fmt, marker::PhantomData, str::FromStr};
 
@@ -61,14 +61,7 @@ impl<N: ZcashNetwork> FromStr for ZcashDerivationPath<N> {
     type Err = DerivationPathError;
 
     fn from_str(path: &str) -> Result<Self, Self::Err> {
-        let mut parts = path.split("/");
-
-        if parts.next().unwrap() != "m" {
-            return Err(DerivationPathError::InvalidDerivationPath(path.to_string()));
-        }
-
-        let path: Result<Vec<ChildIndex>, Self::Err> = parts.map(str::parse).collect();
-        Self::from_vec(&path?)
+        Self::from_vec(&BIP32Path::from_str(path)?.to_vec()?)
     }
 }
 
@@ -96,15 +89,8 @@ impl<N: ZcashNetwork> fmt::Debug for ZcashDerivationPath<N> {
 
 impl<N: ZcashNetwork> fmt::Display for ZcashDerivationPath<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self.to_vec() {
-            Ok(path) => {
-                f.write_str("m")?;
-                for index in path.iter() {
-                    f.write_str("/")?;
-                    fmt::Display::fmt(index, f)?;
-                }
-                Ok(())
-            }
+        match self.to_vec().and_then(|path| BIP32Path::from_vec(&path)) {
+            Ok(path) => fmt::Display::fmt(&path, f),
             Err(_) => Err(fmt::Error),
         }
     }
@@ -8,12 +8,13 @@ use crate::private_key::{SaplingSpendingKey, ZcashPrivateKey};
 use crate::public_key::ZcashPublicKey;
 use wagyu_model::no_std::{vec, ToString, Vec};
 use wagyu_model::{
-    Address, AddressError, ChildIndex, DerivationPath, ExtendedPrivateKey, ExtendedPrivateKeyError, ExtendedPublicKey,
-    PublicKey,
+    Address, AddressError, BIP32Path, ChildIndex, DerivationPath, ExtendedPrivateKey, ExtendedPrivateKeyError,
+    ExtendedPublicKey, PublicKey,
 };
 
 use bech32::{Bech32, FromBase32, ToBase32};
 use core::{cmp::Ordering, fmt, fmt::Display, marker::PhantomData, str::FromStr};
+use zeroize::Zeroize;
 
 /// Represents a Zcash extended private key
 #[derive(Debug, Clone)]
@@ -46,11 +47,7 @@ impl<N: ZcashNetwork> ExtendedPrivateKey for ZcashExtendedPrivateKey<N> {
     fn derive(&self, path: &Self::DerivationPath) -> Result<Self, ExtendedPrivateKeyError> {
         let mut extended_private_key = self.clone();
         for index in path.to_vec()?.into_iter() {
-            extended_private_key = Self {
-                extended_spending_key: extended_private_key
-                    .extended_spending_key
-                    .derive_child(ChildIndex::from(index.to_index())),
-            };
+            extended_private_key = extended_private_key.derive_child(index);
         }
         Ok(extended_private_key)
     }
@@ -87,6 +84,37 @@ impl<N: ZcashNetwork> ZcashExtendedPrivateKey<N> {
     pub fn to_extended_spending_key(&self) -> ExtendedSpendingKey<N> {
         self.extended_spending_key.clone()
     }
+
+    /// Returns the hardened or non-hardened child of the Zcash extended private key at the given index,
+    /// as specified by CKDsk in ZIP-32.
+    pub fn derive_child(&self, index: ChildIndex) -> Self {
+        Self {
+            extended_spending_key: self.extended_spending_key.derive_child(index),
+        }
+    }
+
+    /// Returns the descendant of the Zcash extended private key at the given path, relative to the key.
+    /// Unlike `derive`, the path may be of any depth and hardening, such as the ZIP-32 test vector path m/1/2'/3.
+    pub fn derive_path(&self, path: &BIP32Path) -> Self {
+        path.iter().fold(self.clone(), |extended_private_key, index| {
+            extended_private_key.derive_child(*index)
+        })
+    }
+
+    /// Returns the diversifier of the default address of the Zcash extended private key,
+    /// the first valid diversifier of its diversifier key as specified by ZIP-32.
+    pub fn to_default_diversifier(&self) -> Option<[u8; 11]> {
+        match self.extended_spending_key.default_address() {
+            Ok((_, address)) => Some(address.diversifier.0),
+            Err(_) => None,
+        }
+    }
+
+    /// Returns the diversifier index and Sapling payment address of the first valid diversifier
+    /// at or after the given diversifier index, as specified by ZIP-32.
+    pub fn to_payment_address(&self, index: u64) -> Result<(u64, ZcashAddress<N>), AddressError> {
+        self.to_extended_public_key().to_payment_address(index)
+    }
 }
 
 impl<N: ZcashNetwork> FromStr for ZcashExtendedPrivateKey<N> {
@@ -139,6 +167,19 @@ impl<N: ZcashNetwork> Ord for ZcashExtendedPrivateKey<N> {
     }
 }
 
+impl<N: ZcashNetwork> Zeroize for ZcashExtendedPrivateKey<N> {
+    /// Overwrites the chain code, expanded spending key, and diversifier key.
+    fn zeroize(&mut self) {
+        self.extended_spending_key.zeroize();
+    }
+}
+
+impl<N: ZcashNetwork> Drop for ZcashExtendedPrivateKey<N> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -173,6 +214,15 @@ mod tests {
         assert_eq!(expected_address, address.to_string());
     }
 
+    fn test_to_default_diversifier<N: ZcashNetwork>(expected_address: &str, seed: &str, path: &str) {
+        let seed = hex::decode(seed).unwrap();
+        let path = ZcashDerivationPath::from_str(path).unwrap();
+        let extended_private_key =
+            ZcashExtendedPrivateKey::<N>::new(&seed, &ZcashFormat::Sapling(None), &path).unwrap();
+        let diversifier = extended_private_key.to_default_diversifier();
+        assert_eq!(ZcashAddress::<N>::get_diversifier(expected_address).ok(), diversifier);
+    }
+
     fn test_from_str<N: ZcashNetwork>(expected_extended_private_key: &str) {
         let extended_private_key = ZcashExtendedPrivateKey::<N>::from_str(&expected_extended_private_key).unwrap();
         assert_eq!(expected_extended_private_key, extended_private_key.to_string());
@@ -251,6 +301,13 @@ mod tests {
             });
         }
 
+        #[test]
+        fn to_default_diversifier() {
+            KEYPAIRS.iter().for_each(|(path, seed, _, _, address)| {
+                test_to_default_diversifier::<N>(address, seed, path);
+            });
+        }
+
         #[test]
         fn from_str() {
             KEYPAIRS.iter().for_each(|(_, _, extended_private_key, _, _)| {
@@ -264,6 +321,29 @@ mod tests {
                 test_to_string::<N>(extended_private_key, seed, path);
             });
         }
+
+        #[test]
+        fn derive_path() {
+            let (path, seed, extended_private_key, _, _) = KEYPAIRS[0];
+            let seed = hex::decode(seed).unwrap();
+            let master = ZcashExtendedPrivateKey::<N>::new_master(&seed, &ZcashFormat::Sapling(None)).unwrap();
+            let path = BIP32Path::from_str(path).unwrap();
+            assert_eq!(extended_private_key, master.derive_path(&path).to_string());
+
+            // The non-hardened child of an extended private key is the child of its extended public key
+            let parent = master.derive_path(&BIP32Path::from_str("m/1/2'").unwrap());
+            let child = parent.derive_child(ChildIndex::Normal(3));
+            let extended_public_key = parent.to_extended_public_key();
+            assert_eq!(
+                child.to_extended_public_key(),
+                extended_public_key.derive_child(ChildIndex::Normal(3)).unwrap()
+            );
+            assert!(extended_public_key.derive_child(ChildIndex::Hardened(3)).is_err());
+            assert_eq!(
+                child,
+                ZcashExtendedPrivateKey::<N>::from_str(&child.to_string()).unwrap()
+            );
+        }
     }
 
     mod sapling_testnet {
@@ -331,6 +411,13 @@ mod tests {
             });
         }
 
+        #[test]
+        fn to_default_diversifier() {
+            KEYPAIRS.iter().for_each(|(path, seed, _, _, address)| {
+                test_to_default_diversifier::<N>(address, seed, path);
+            });
+        }
+
         #[test]
         fn from_str() {
             KEYPAIRS.iter().for_each(|(_, _, extended_private_key, _, _)| {
@@ -2,12 +2,13 @@ use crate::address::ZcashAddress;
 use crate::derivation_path::ZcashDerivationPath;
 use crate::extended_private_key::ZcashExtendedPrivateKey;
 use crate::format::ZcashFormat;
-use crate::librustzcash::zip32::ExtendedFullViewingKey;
+use crate::librustzcash::zip32::{DiversifierIndex, ExtendedFullViewingKey};
 use crate::network::ZcashNetwork;
 use crate::public_key::ZcashPublicKey;
-use wagyu_model::no_std::{vec, ToString, Vec};
+use wagyu_model::no_std::{format, vec, ToString, Vec};
 use wagyu_model::{
     Address, AddressError, ChildIndex, DerivationPath, DerivationPathError, ExtendedPublicKey, ExtendedPublicKeyError,
+    NonHardenedDerivationPath,
 };
 
 use bech32::{Bech32, FromBase32, ToBase32};
@@ -36,22 +37,14 @@ impl<N: ZcashNetwork> ExtendedPublicKey for ZcashExtendedPublicKey<N> {
 
     /// Returns the extended public key of the given derivation path.
     fn derive(&self, path: &Self::DerivationPath) -> Result<Self, ExtendedPublicKeyError> {
+        self.derive_public(&path.to_non_hardened()?)
+    }
+
+    /// Returns the extended public key of the given non-hardened derivation path.
+    fn derive_public(&self, path: &NonHardenedDerivationPath) -> Result<Self, ExtendedPublicKeyError> {
         let mut extended_public_key = self.clone();
-        for index in path.to_vec()?.into_iter() {
-            match index {
-                ChildIndex::Hardened(_) => return Err(DerivationPathError::ExpectedNormalPath.into()),
-                ChildIndex::Normal(number) => {
-                    extended_public_key = Self {
-                        extended_full_viewing_key: match extended_public_key
-                            .extended_full_viewing_key
-                            .derive_child(ChildIndex::Normal(number))
-                        {
-                            Ok(extended_full_viewing_key) => extended_full_viewing_key,
-                            _ => return Err(DerivationPathError::InvalidDerivationPath(path.to_string()).into()),
-                        },
-                    }
-                }
-            }
+        for index in path.to_vec().into_iter() {
+            extended_public_key = extended_public_key.derive_child(index)?;
         }
         Ok(extended_public_key)
     }
@@ -72,6 +65,56 @@ impl<N: ZcashNetwork> ZcashExtendedPublicKey<N> {
     pub fn to_extended_full_viewing_key(&self) -> ExtendedFullViewingKey<N> {
         self.extended_full_viewing_key.clone()
     }
+
+    /// Returns the non-hardened child of the Zcash extended public key at the given index,
+    /// as specified by CKDfvk in ZIP-32, or errors if the index is hardened.
+    pub fn derive_child(&self, index: ChildIndex) -> Result<Self, ExtendedPublicKeyError> {
+        match self.extended_full_viewing_key.derive_child(index) {
+            Ok(extended_full_viewing_key) => Ok(Self {
+                extended_full_viewing_key,
+            }),
+            _ => Err(DerivationPathError::ExpectedNormalPath.into()),
+        }
+    }
+
+    /// Returns the diversifier of the default address of the Zcash extended public key,
+    /// the first valid diversifier of its diversifier key as specified by ZIP-32.
+    pub fn to_default_diversifier(&self) -> Option<[u8; 11]> {
+        match self.extended_full_viewing_key.default_address() {
+            Ok((_, address)) => Some(address.diversifier.0),
+            Err(_) => None,
+        }
+    }
+
+    /// Returns the fingerprint of the Zcash extended public key, the tag of its full viewing key
+    /// fingerprint as specified by ZIP-32.
+    pub fn to_fingerprint(&self) -> [u8; 4] {
+        self.extended_full_viewing_key.tag()
+    }
+
+    /// Returns the diversifier index and Sapling payment address of the first valid diversifier
+    /// at or after the given diversifier index, as specified by ZIP-32. Each index yields an address
+    /// of the same key that is unlinkable to the others.
+    pub fn to_payment_address(&self, index: u64) -> Result<(u64, ZcashAddress<N>), AddressError> {
+        let mut diversifier_index = DiversifierIndex::new();
+        diversifier_index.0[..8].copy_from_slice(&index.to_le_bytes());
+
+        let (diversifier_index, address) = self
+            .extended_full_viewing_key
+            .address(diversifier_index)
+            .map_err(|_| AddressError::Message(format!("no valid diversifier at or after index {}", index)))?;
+        if diversifier_index.0[8..] != [0u8; 3] {
+            return Err(AddressError::Message(format!(
+                "no valid diversifier at or after index {}",
+                index
+            )));
+        }
+
+        let mut index = [0u8; 8];
+        index.copy_from_slice(&diversifier_index.0[..8]);
+        let format = ZcashFormat::Sapling(Some(address.diversifier.0));
+        Ok((u64::from_le_bytes(index), self.to_address(&format)?))
+    }
 }
 
 impl<N: ZcashNetwork> FromStr for ZcashExtendedPublicKey<N> {
@@ -153,6 +196,43 @@ mod tests {
         assert_eq!(expected_address, address.to_string());
     }
 
+    fn test_to_default_diversifier<N: ZcashNetwork>(expected_address: &str, seed: &str, path: &str) {
+        let seed = hex::decode(seed).unwrap();
+        let path = ZcashDerivationPath::from_str(path).unwrap();
+        let extended_private_key =
+            ZcashExtendedPrivateKey::<N>::new(&seed, &ZcashFormat::Sapling(None), &path).unwrap();
+        let extended_public_key = ZcashExtendedPublicKey::<N>::from_extended_private_key(&extended_private_key);
+        let diversifier = extended_public_key.to_default_diversifier();
+        assert_eq!(ZcashAddress::<N>::get_diversifier(expected_address).ok(), diversifier);
+    }
+
+    fn test_to_payment_address<N: ZcashNetwork>(expected_address: &str, seed: &str, path: &str) {
+        let seed = hex::decode(seed).unwrap();
+        let path = ZcashDerivationPath::from_str(path).unwrap();
+        let extended_private_key =
+            ZcashExtendedPrivateKey::<N>::new(&seed, &ZcashFormat::Sapling(None), &path).unwrap();
+        let extended_public_key = ZcashExtendedPublicKey::<N>::from_extended_private_key(&extended_private_key);
+
+        // The first valid diversifier is the default address
+        let (_, address) = extended_public_key.to_payment_address(0).unwrap();
+        assert_eq!(expected_address, address.to_string());
+
+        // Each valid diversifier index yields a distinct address of the same key
+        let mut addresses = vec![];
+        let mut index = 0;
+        for _ in 0..5 {
+            let (found, address) = extended_public_key.to_payment_address(index).unwrap();
+            assert!(found >= index);
+            assert_eq!(
+                (found, address.clone()),
+                extended_private_key.to_payment_address(found).unwrap()
+            );
+            assert!(!addresses.contains(&address));
+            addresses.push(address);
+            index = found + 1;
+        }
+    }
+
     fn test_from_str<N: ZcashNetwork>(expected_extended_public_key: &str) {
         let extended_public_key = ZcashExtendedPublicKey::<N>::from_str(&expected_extended_public_key).unwrap();
         assert_eq!(expected_extended_public_key, extended_public_key.to_string());
@@ -225,6 +305,20 @@ mod tests {
             });
         }
 
+        #[test]
+        fn to_default_diversifier() {
+            KEYPAIRS.iter().for_each(|(path, seed, _, _, address)| {
+                test_to_default_diversifier::<N>(address, seed, path);
+            });
+        }
+
+        #[test]
+        fn to_payment_address() {
+            KEYPAIRS.iter().for_each(|(path, seed, _, _, address)| {
+                test_to_payment_address::<N>(address, seed, path);
+            });
+        }
+
         #[test]
         fn from_str() {
             KEYPAIRS.iter().for_each(|(_, _, _, extended_public_key, _)| {
@@ -298,6 +392,20 @@ mod tests {
             });
         }
 
+        #[test]
+        fn to_default_diversifier() {
+            KEYPAIRS.iter().for_each(|(path, seed, _, _, address)| {
+                test_to_default_diversifier::<N>(address, seed, path);
+            });
+        }
+
+        #[test]
+        fn to_payment_address() {
+            KEYPAIRS.iter().for_each(|(path, seed, _, _, address)| {
+                test_to_payment_address::<N>(address, seed, path);
+            });
+        }
+
         #[test]
         fn from_str() {
             KEYPAIRS.iter().for_each(|(_, _, _, extended_public_key, _)| {
@@ -14,7 +14,7 @@ pub enum ZcashFormat {
     P2SH,
     /// Sprout shielded address beginning with "zc" or "zt"
     Sprout,
-    /// Sapling shielded address beginning with "zs" or "ztestsapling"
+    /// Sapling shielded address beginning with "zs", "ztestsapling", or "zregtestsapling"
     Sapling(Option<[u8; 11]>),
 }
 
@@ -36,7 +36,7 @@ impl ZcashFormat {
             0xB8 | 0x25 => Ok(ZcashFormat::P2PKH),
             0xBD | 0xBA => Ok(ZcashFormat::P2SH),
             0x9A | 0xB6 => Ok(ZcashFormat::Sprout),
-            0x73 | 0x74 => Ok(ZcashFormat::Sapling(None)),
+            0x73 | 0x74 | 0x72 => Ok(ZcashFormat::Sapling(None)),
             _ => return Err(AddressError::InvalidPrefix(prefix.clone())),
         }
     }
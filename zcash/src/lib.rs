@@ -3,7 +3,7 @@
 //! A library for generating Zcash wallets.
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(unused_extern_crates, dead_code)]
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "wasm"), forbid(unsafe_code))]
 
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
@@ -43,9 +43,17 @@ pub use self::format::*;
 #[allow(deprecated)]
 pub mod librustzcash;
 
+pub mod migration;
+pub use self::migration::*;
+
 pub mod network;
 pub use self::network::*;
 
+#[cfg(feature = "orchard")]
+pub mod orchard;
+#[cfg(feature = "orchard")]
+pub use self::orchard::*;
+
 pub mod private_key;
 pub use self::private_key::*;
 
@@ -54,3 +62,6 @@ pub use self::public_key::*;
 
 pub mod transaction;
 pub use self::transaction::*;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
@@ -17,6 +17,7 @@ use blake2b_simd::{Hash as Blake2bHash, Params as Blake2bParams};
 use byteorder::{ByteOrder, LittleEndian, ReadBytesExt, WriteBytesExt};
 use core::marker::PhantomData;
 use fpe::ff1::{BinaryNumeralString, FF1};
+use zeroize::Zeroize;
 
 pub const ZIP32_SAPLING_MASTER_PERSONALIZATION: &'static [u8; 16] = b"ZcashIP32Sapling";
 pub const ZIP32_SAPLING_FVFP_PERSONALIZATION: &'static [u8; 16] = b"ZcashSaplingFVFP";
@@ -288,6 +289,14 @@ impl<N: ZcashNetwork> core::cmp::PartialEq for ExtendedSpendingKey<N> {
     }
 }
 
+impl<N: ZcashNetwork> Zeroize for ExtendedSpendingKey<N> {
+    fn zeroize(&mut self) {
+        self.chain_code.0.zeroize();
+        self.expsk.zeroize();
+        self.dk.0.zeroize();
+    }
+}
+
 impl<N: ZcashNetwork> core::fmt::Debug for ExtendedSpendingKey<N> {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
         write!(
@@ -342,6 +351,11 @@ impl<N: ZcashNetwork> ExtendedFullViewingKey<N> {
         Ok(())
     }
 
+    /// Returns the tag of the full viewing key fingerprint, which identifies this key as the parent of its children.
+    pub fn tag(&self) -> [u8; 4] {
+        FVKFingerprint::from(&self.fvk).tag().0
+    }
+
     pub fn derive_child(&self, i: ChildIndex) -> Result<Self, ()> {
         let tmp = match i {
             ChildIndex::Hardened(_) => return Err(()),
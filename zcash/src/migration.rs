@@ -0,0 +1,101 @@
+use crate::address::ZcashAddress;
+use crate::format::ZcashFormat;
+use crate::network::ZcashNetwork;
+use crate::private_key::ZcashPrivateKey;
+use crate::public_key::ZcashPublicKey;
+use wagyu_model::{AddressError, PrivateKey, PrivateKeyError};
+
+use core::str::FromStr;
+
+/// The guidance to migrate the funds of a Sprout spending key to the Sapling pool.
+///
+/// Since the Canopy network upgrade, transactions may no longer add value to the Sprout pool (ZIP 211),
+/// so Sprout keys are only useful to recover existing funds, which are moved to Sapling with the
+/// migration tool of zcashd (ZIP 308).
+pub const SPROUT_MIGRATION_GUIDANCE: &str = "Sprout is deprecated and new funds may not be sent to Sprout \
+    addresses since the Canopy network upgrade (ZIP 211). To recover existing funds, import the spending key \
+    into zcashd with `z_importkey`, or the viewing key with `z_importviewingkey` to watch its balance, \
+    and enable the Sprout to Sapling migration with `z_setmigration true` (ZIP 308).";
+
+/// Represents the artifacts to recover and migrate the funds of a Sprout spending key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SproutMigration<N: ZcashNetwork> {
+    /// The Sprout spending key
+    private_key: ZcashPrivateKey<N>,
+    /// The Sprout viewing key
+    viewing_key: ZcashPublicKey<N>,
+    /// The Sprout address
+    address: ZcashAddress<N>,
+}
+
+impl<N: ZcashNetwork> SproutMigration<N> {
+    /// Returns the migration artifacts of the given Sprout spending key.
+    pub fn new(spending_key: &str) -> Result<Self, AddressError> {
+        let private_key = ZcashPrivateKey::<N>::from_str(spending_key)?;
+        match private_key {
+            ZcashPrivateKey::<N>::Sprout(_) => {}
+            _ => return Err(PrivateKeyError::UnsupportedFormat.into()),
+        };
+
+        let viewing_key = private_key.to_public_key();
+        let address = private_key.to_address(&ZcashFormat::Sprout)?;
+        Ok(Self {
+            private_key,
+            viewing_key,
+            address,
+        })
+    }
+
+    /// Returns the Sprout spending key.
+    pub fn to_private_key(&self) -> ZcashPrivateKey<N> {
+        self.private_key.clone()
+    }
+
+    /// Returns the Sprout viewing key, which reveals incoming funds without the ability to spend them.
+    pub fn to_viewing_key(&self) -> ZcashPublicKey<N> {
+        self.viewing_key.clone()
+    }
+
+    /// Returns the Sprout address.
+    pub fn to_address(&self) -> ZcashAddress<N> {
+        self.address.clone()
+    }
+
+    /// Returns the guidance to migrate the funds of the Sprout spending key to the Sapling pool.
+    pub fn to_guidance(&self) -> &'static str {
+        SPROUT_MIGRATION_GUIDANCE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+
+    type N = Mainnet;
+
+    const KEYPAIR: (&str, &str, &str) = (
+        "SKxt8pwrQipUL5KgZUcBAqyLj9R1YwMuRRR3ijGMCwCCqchmi8ut",
+        "ZiVKYQyUcyAJLKwcosSeDxkGRhygFdAPWsr3m8UgjC5X85yqNyLTtJJJYNH83Wf2AQKU6TZsd65MXBZLFj6eSCAFcnCFuVCFS",
+        "zcJLC7a3aRJohMNCVjSZQ8jFuofhAHJNAY4aX5soDkYfgNejzKnEZbucJmVibLWCwK8dyyfDhNhf3foXDDTouweC382LcX5",
+    );
+
+    #[test]
+    fn new() {
+        let (private_key, viewing_key, address) = KEYPAIR;
+        let migration = SproutMigration::<N>::new(private_key).unwrap();
+        assert_eq!(private_key, migration.to_private_key().to_string());
+        assert_eq!(viewing_key, migration.to_viewing_key().to_string());
+        assert_eq!(address, migration.to_address().to_string());
+        assert_eq!(SPROUT_MIGRATION_GUIDANCE, migration.to_guidance());
+    }
+
+    #[test]
+    fn invalid_format() {
+        // Sapling spending key
+        let spending_key = "secret-spending-key-main1hd5umd08pc4m6f8hw8x3tgv26kxn4w0p4g72kxrtndjazlm64hhsnczrtx";
+        assert!(SproutMigration::<N>::new(spending_key).is_err());
+        // Transparent private key
+        assert!(SproutMigration::<N>::new("Kx7f3xE2TmhczSkFUxxSajE2vuuLrrqinAbTZBxqxHj6XGbhoyrQ").is_err());
+    }
+}
@@ -71,6 +71,17 @@ impl ZcashNetwork for Mainnet {
         "zviews".into()
     }
 
+    /// Returns the Sapling incoming viewing key prefix of the given network.
+    fn to_sapling_incoming_viewing_key_prefix() -> String {
+        "zivks".into()
+    }
+
+    /// Returns the unified address prefix of the given network.
+    /// https://zips.z.cash/zip-0316#encoding-of-unified-addresses
+    fn to_unified_address_prefix() -> String {
+        "u".into()
+    }
+
     /// Returns the extended private key prefix of the given network.
     /// https://github.com/zcash/zips/blob/master/zip-0032.rst#sapling-extended-spending-keys
     fn to_extended_private_key_prefix() -> String {
@@ -2,9 +2,15 @@ use crate::format::ZcashFormat;
 use wagyu_model::no_std::{String, Vec};
 use wagyu_model::{AddressError, ChildIndex, Network, NetworkError, PrivateKeyError};
 
+pub mod any_network;
+pub use self::any_network::*;
+
 pub mod mainnet;
 pub use self::mainnet::*;
 
+pub mod regtest;
+pub use self::regtest::*;
+
 pub mod testnet;
 pub use self::testnet::*;
 
@@ -37,6 +43,12 @@ pub trait ZcashNetwork: Network {
     /// Returns the Sapling viewing key prefix of the given network.
     fn to_sapling_viewing_key_prefix() -> String;
 
+    /// Returns the Sapling incoming viewing key prefix of the given network.
+    fn to_sapling_incoming_viewing_key_prefix() -> String;
+
+    /// Returns the unified address prefix of the given network.
+    fn to_unified_address_prefix() -> String;
+
     /// Returns the extended private key prefix of the given network.
     fn to_extended_private_key_prefix() -> String;
 
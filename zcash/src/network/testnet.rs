@@ -70,6 +70,17 @@ impl ZcashNetwork for Testnet {
         "zviewtestsapling".into()
     }
 
+    /// Returns the Sapling incoming viewing key prefix of the given network.
+    fn to_sapling_incoming_viewing_key_prefix() -> String {
+        "zivktestsapling".into()
+    }
+
+    /// Returns the unified address prefix of the given network.
+    /// https://zips.z.cash/zip-0316#encoding-of-unified-addresses
+    fn to_unified_address_prefix() -> String {
+        "utest".into()
+    }
+
     /// Returns the extended private key prefix of the given network.
     /// https://github.com/zcash/zips/blob/master/zip-0032.rst#sapling-extended-spending-keys
     fn to_extended_private_key_prefix() -> String {
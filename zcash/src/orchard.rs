@@ -0,0 +1,356 @@
+use crate::derivation_path::ZcashDerivationPath;
+use crate::network::ZcashNetwork;
+use wagyu_model::derivation_path::{ChildIndex, DerivationPath};
+use wagyu_model::encoding::{bech32_encode, convert_bits, Bech32Variant};
+use wagyu_model::no_std::{format, String, Vec};
+use wagyu_model::{AddressError, ExtendedPrivateKeyError};
+
+use blake2b_simd::Params;
+use core::{fmt, marker::PhantomData};
+use orchard::keys::{FullViewingKey, Scope, SpendingKey};
+use zeroize::Zeroize;
+
+/// The personalization of the master key of ZIP-32 Orchard derivation
+const ZIP32_ORCHARD_PERSONALIZATION: &[u8; 16] = b"ZcashIP32Orchard";
+/// The personalization of PRF^expand
+const PRF_EXPAND_PERSONALIZATION: &[u8; 16] = b"Zcash_ExpandSeed";
+/// The domain separator of PRF^expand for Orchard child key derivation
+const ORCHARD_ZIP32_CHILD: u8 = 0x81;
+/// The typecode of an Orchard receiver of a unified address
+const ORCHARD_TYPECODE: u8 = 0x03;
+/// The length of the padding of a unified address, which holds its human-readable part
+const UNIFIED_PADDING_LENGTH: usize = 16;
+
+/// Represents an Orchard extended spending key, derived with ZIP-32 from a seed.
+/// https://zips.z.cash/zip-0032#orchard-child-key-derivation
+///
+/// Orchard only defines hardened derivation, so every child index must be hardened.
+#[derive(Clone, PartialEq, Eq)]
+pub struct OrchardExtendedSpendingKey<N: ZcashNetwork> {
+    /// The depth of the key in the derivation tree
+    depth: u8,
+    /// The child index of the key
+    child_index: ChildIndex,
+    /// The chain code of the key
+    chain_code: [u8; 32],
+    /// The Orchard spending key
+    spending_key: [u8; 32],
+    /// PhantomData
+    _network: PhantomData<N>,
+}
+
+impl<N: ZcashNetwork> OrchardExtendedSpendingKey<N> {
+    /// Returns the Orchard extended spending key of the given seed at the given ZIP-32 path,
+    /// m/32'/{133', 1'}/{account}'.
+    pub fn new(seed: &[u8], path: &ZcashDerivationPath<N>) -> Result<Self, ExtendedPrivateKeyError> {
+        Self::new_master(seed)?.derive(path)
+    }
+
+    /// Returns the Orchard master extended spending key of the given seed.
+    pub fn new_master(seed: &[u8]) -> Result<Self, ExtendedPrivateKeyError> {
+        if seed.len() < 32 || seed.len() > 252 {
+            return Err(ExtendedPrivateKeyError::InvalidByteLength(seed.len()));
+        }
+
+        let hash = Params::new()
+            .hash_length(64)
+            .personal(ZIP32_ORCHARD_PERSONALIZATION)
+            .hash(seed);
+        Ok(Self::from_hash(hash.as_bytes(), 0, ChildIndex::Normal(0)))
+    }
+
+    /// Returns the Orchard extended spending key of the given derivation path.
+    pub fn derive(&self, path: &ZcashDerivationPath<N>) -> Result<Self, ExtendedPrivateKeyError> {
+        let mut key = self.clone();
+        for index in path.to_vec()? {
+            key = key.derive_child(index)?;
+        }
+        Ok(key)
+    }
+
+    /// Returns the child of the extended spending key at the given hardened index.
+    pub fn derive_child(&self, index: ChildIndex) -> Result<Self, ExtendedPrivateKeyError> {
+        if self.depth == 255 {
+            return Err(ExtendedPrivateKeyError::MaximumChildDepthReached(self.depth));
+        }
+        if !index.is_hardened() {
+            return Err(ExtendedPrivateKeyError::Message(format!(
+                "Orchard only supports hardened derivation, found {}",
+                index
+            )));
+        }
+
+        let mut message = Vec::with_capacity(37);
+        message.push(ORCHARD_ZIP32_CHILD);
+        message.extend(&self.spending_key);
+        message.extend(&u32::from(index).to_le_bytes());
+
+        let mut hash = prf_expand(&self.chain_code, &message);
+        message.zeroize();
+        let child = Self::from_hash(&hash, self.depth + 1, index);
+        hash.zeroize();
+        Ok(child)
+    }
+
+    /// Returns the full viewing key of the spending key.
+    pub fn to_full_viewing_key(&self) -> Result<OrchardFullViewingKey<N>, ExtendedPrivateKeyError> {
+        // A spending key is invalid if its spend authorizing key is zero, which happens with negligible probability
+        let spending_key = Option::<SpendingKey>::from(SpendingKey::from_bytes(self.spending_key))
+            .ok_or_else(|| ExtendedPrivateKeyError::Message("invalid Orchard spending key".into()))?;
+        Ok(OrchardFullViewingKey {
+            full_viewing_key: FullViewingKey::from(&spending_key),
+            _network: PhantomData,
+        })
+    }
+
+    /// Returns the Orchard spending key.
+    pub fn to_spending_key(&self) -> [u8; 32] {
+        self.spending_key
+    }
+
+    /// Returns the chain code.
+    pub fn to_chain_code(&self) -> [u8; 32] {
+        self.chain_code
+    }
+
+    /// Returns the depth of the key in the derivation tree.
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the child index of the key.
+    pub fn child_index(&self) -> ChildIndex {
+        self.child_index
+    }
+
+    /// Returns the extended spending key of the given 64 byte hash, split into the spending key and chain code.
+    fn from_hash(hash: &[u8], depth: u8, child_index: ChildIndex) -> Self {
+        let mut spending_key = [0u8; 32];
+        let mut chain_code = [0u8; 32];
+        spending_key.copy_from_slice(&hash[..32]);
+        chain_code.copy_from_slice(&hash[32..64]);
+        Self {
+            depth,
+            child_index,
+            chain_code,
+            spending_key,
+            _network: PhantomData,
+        }
+    }
+}
+
+impl<N: ZcashNetwork> Drop for OrchardExtendedSpendingKey<N> {
+    fn drop(&mut self) {
+        self.spending_key.zeroize();
+        self.chain_code.zeroize();
+    }
+}
+
+impl<N: ZcashNetwork> fmt::Debug for OrchardExtendedSpendingKey<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "OrchardExtendedSpendingKey {{ depth: {}, child_index: {} }}",
+            self.depth, self.child_index
+        )
+    }
+}
+
+/// Represents an Orchard full viewing key, which consists of the spend validating key,
+/// the nullifier deriving key, and the commitment randomness of the incoming viewing key.
+/// https://zips.z.cash/protocol/protocol.pdf#orchardkeycomponents
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrchardFullViewingKey<N: ZcashNetwork> {
+    /// The Orchard full viewing key
+    full_viewing_key: FullViewingKey,
+    /// PhantomData
+    _network: PhantomData<N>,
+}
+
+impl<N: ZcashNetwork> OrchardFullViewingKey<N> {
+    /// Returns the external payment address of the full viewing key at the given diversifier index.
+    pub fn to_address(&self, index: u64) -> OrchardAddress<N> {
+        OrchardAddress {
+            address: self
+                .full_viewing_key
+                .address_at(index, Scope::External)
+                .to_raw_address_bytes(),
+            _network: PhantomData,
+        }
+    }
+
+    /// Returns the 96 byte encoding of the full viewing key, ak || nk || rivk.
+    pub fn to_bytes(&self) -> [u8; 96] {
+        self.full_viewing_key.to_bytes()
+    }
+}
+
+/// Represents an Orchard payment address, which is displayed as a unified address with an Orchard receiver.
+/// https://zips.z.cash/zip-0316
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrchardAddress<N: ZcashNetwork> {
+    /// The raw address, the diversifier and the diversified transmission key
+    address: [u8; 43],
+    /// PhantomData
+    _network: PhantomData<N>,
+}
+
+impl<N: ZcashNetwork> OrchardAddress<N> {
+    /// Returns the 43 byte raw address, d || pk_d.
+    pub fn to_raw_address_bytes(&self) -> [u8; 43] {
+        self.address
+    }
+
+    /// Returns the unified address of the Orchard receiver, as the bech32m encoding of its F4Jumbled
+    /// typecode, length, and raw address, followed by the human-readable part padded to 16 bytes.
+    pub fn to_unified_address(&self) -> Result<String, AddressError> {
+        let prefix = N::to_unified_address_prefix();
+
+        let mut data = Vec::with_capacity(2 + self.address.len() + UNIFIED_PADDING_LENGTH);
+        data.push(ORCHARD_TYPECODE);
+        data.push(self.address.len() as u8);
+        data.extend(&self.address);
+        let mut padding = [0u8; UNIFIED_PADDING_LENGTH];
+        padding[..prefix.len()].copy_from_slice(prefix.as_bytes());
+        data.extend(&padding);
+
+        let jumbled =
+            f4jumble::f4jumble(&data).map_err(|error| AddressError::Crate("f4jumble", format!("{}", error)))?;
+        // Unified addresses are not limited to the 90 characters of a bech32 segwit address
+        Ok(bech32_encode(
+            &prefix,
+            &convert_bits(&jumbled, 8, 5, true)?,
+            Bech32Variant::Bech32m,
+            usize::MAX,
+        )?)
+    }
+}
+
+impl<N: ZcashNetwork> fmt::Display for OrchardAddress<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_unified_address().map_err(|_| fmt::Error)?)
+    }
+}
+
+/// Returns PRF^expand(key, message), the BLAKE2b-512 hash of the key and message personalized by "Zcash_ExpandSeed".
+fn prf_expand(key: &[u8; 32], message: &[u8]) -> [u8; 64] {
+    let hash = Params::new()
+        .hash_length(64)
+        .personal(PRF_EXPAND_PERSONALIZATION)
+        .to_state()
+        .update(key)
+        .update(message)
+        .finalize();
+
+    let mut output = [0u8; 64];
+    output.copy_from_slice(hash.as_bytes());
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::*;
+    use core::str::FromStr;
+
+    type N = Mainnet;
+
+    const SEED: [u8; 32] = [
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29,
+        30, 31,
+    ];
+
+    #[test]
+    fn derive() {
+        let master = OrchardExtendedSpendingKey::<N>::new_master(&SEED).unwrap();
+        assert_eq!(0, master.depth());
+
+        let path = ZcashDerivationPath::<N>::from_str("m/32'/133'/0'").unwrap();
+        let account = OrchardExtendedSpendingKey::<N>::new(&SEED, &path).unwrap();
+        assert_eq!(3, account.depth());
+        assert_eq!(ChildIndex::Hardened(0), account.child_index());
+
+        let expected = [32, 133, 0].iter().fold(master, |key, index| {
+            key.derive_child(ChildIndex::Hardened(*index)).unwrap()
+        });
+        assert_eq!(expected, account);
+
+        let other = ZcashDerivationPath::<N>::from_str("m/32'/133'/1'").unwrap();
+        assert_ne!(
+            account.to_spending_key(),
+            OrchardExtendedSpendingKey::<N>::new(&SEED, &other)
+                .unwrap()
+                .to_spending_key()
+        );
+    }
+
+    #[test]
+    fn zip32_vectors() {
+        // https://github.com/zcash/zcash-test-vectors/blob/master/test-vectors/zcash/orchard_zip32.json
+        let vectors = [
+            "7eee3c1017870990a3dd6891b82f80be8976c1e7dc20d60817a5e88e8b2cd4b8",
+            "98d703fcb40504c95b3b6ed10ecd50082cff97dfd1dd9aa0913c78f977c962af",
+            "99afd8894baad58784d0ec08f5148ee2c2a17b2b294b08ef9e0a0cf14bcc0920",
+            "96439ea348a4b2ce4ec7beb4543c70274c8f76495d60c5fa5f018b68f3c32367",
+        ];
+        // The vectors derive m/1'/2'/3'
+        let mut key = OrchardExtendedSpendingKey::<N>::new_master(&SEED).unwrap();
+        for (index, spending_key) in vectors.iter().enumerate() {
+            if index > 0 {
+                key = key.derive_child(ChildIndex::Hardened(index as u32)).unwrap();
+            }
+            assert_eq!(*spending_key, hex::encode(key.to_spending_key()));
+        }
+    }
+
+    #[test]
+    fn unified_address_vectors() {
+        // https://github.com/zcash/zcash-test-vectors/blob/master/test-vectors/zcash/unified_address.json
+        let vectors = [
+            (
+                9,
+                0,
+                "e340636542ece1c81285ed4eab448adbb5a8c0f4d386eeff337e88e6915f6c3ec1b6ea835a88d56612d2bd",
+                "u1ddnjsdcpm36r6aq79n3s68shjweksnmwtdltrh046s8m6xcws9ygyawalxx8n6hg6vegk0wh8zjnafxgh6msppjsljvyt0ynece3lvm0",
+            ),
+            (
+                9,
+                1,
+                "3fadf8edb20a3301e8260aa311f4cbd54d7d6a76baac88c244b0b121c6dc22a8bcce15898e267829fc1e01",
+                "u1nztelxna9h7w0vtpd2xjhxt4lpu8s9cmdl8n8vcr7actf2ny45nd07cy8cyuhuvw3axcp545y0ktq9cezuzx84jyhex8dk4tdvwhu4dl",
+            ),
+        ];
+        for (account, index, raw_address, unified_address) in vectors.iter() {
+            let path = ZcashDerivationPath::<N>::from_str(&format!("m/32'/133'/{}'", account)).unwrap();
+            let full_viewing_key = OrchardExtendedSpendingKey::<N>::new(&SEED, &path)
+                .unwrap()
+                .to_full_viewing_key()
+                .unwrap();
+            let address = full_viewing_key.to_address(*index);
+            assert_eq!(*raw_address, hex::encode(&address.to_raw_address_bytes()[..]));
+            assert_eq!(*unified_address, address.to_string());
+        }
+    }
+
+    #[test]
+    fn full_viewing_key() {
+        let path = ZcashDerivationPath::<N>::from_str("m/32'/133'/0'").unwrap();
+        let key = OrchardExtendedSpendingKey::<N>::new(&SEED, &path).unwrap();
+        let full_viewing_key = key.to_full_viewing_key().unwrap();
+        assert_eq!(full_viewing_key, key.to_full_viewing_key().unwrap());
+        assert_ne!(full_viewing_key.to_address(0), full_viewing_key.to_address(1));
+
+        let testnet = OrchardExtendedSpendingKey::<Testnet>::new_master(&SEED)
+            .unwrap()
+            .to_full_viewing_key()
+            .unwrap();
+        assert!(testnet.to_address(0).to_string().starts_with("utest1"));
+    }
+
+    #[test]
+    fn invalid_derivation() {
+        let master = OrchardExtendedSpendingKey::<N>::new_master(&SEED).unwrap();
+        assert!(master.derive_child(ChildIndex::Normal(0)).is_err());
+        assert!(OrchardExtendedSpendingKey::<N>::new_master(&SEED[..31]).is_err());
+    }
+}
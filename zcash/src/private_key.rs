@@ -1,7 +1,7 @@
 use crate::address::ZcashAddress;
 use crate::format::ZcashFormat;
 use crate::librustzcash::algebra::curve::bls12_381::Bls12;
-use crate::librustzcash::algebra::field::{PrimeField, PrimeFieldRepr};
+use crate::librustzcash::algebra::field::{Field, PrimeField, PrimeFieldRepr};
 use crate::librustzcash::sapling_crypto::{
     jubjub::{FixedGenerators, JubjubEngine, JubjubParams, ToUniform},
     primitives::ProofGenerationKey as SaplingProofGenerationKey,
@@ -13,9 +13,9 @@ use wagyu_model::no_std::{
     io::{self, Read, Write},
     vec, String, ToString, Vec,
 };
-use wagyu_model::{crypto::checksum, Address, AddressError, PrivateKey, PrivateKeyError, PublicKey};
+use wagyu_model::{encoding::base58check, Address, AddressError, PrivateKey, PrivateKeyError, PublicKey};
 
-use base58::{FromBase58, ToBase58};
+use base58::FromBase58;
 use bech32::{Bech32, FromBase32, ToBase32};
 use core::{
     cmp::{Eq, PartialEq},
@@ -26,6 +26,7 @@ use core::{
 use failure::AsFail;
 use rand::Rng;
 use secp256k1;
+use zeroize::Zeroize;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct P2PKHSpendingKey<N: ZcashNetwork> {
@@ -61,25 +62,29 @@ impl<N: ZcashNetwork> Display for P2PKHSpendingKey<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         /// Returns a WIF string given a secp256k1 secret key.
         fn to_wif<N: ZcashNetwork>(secret_key: &secp256k1::SecretKey, compressed: bool) -> String {
-            let mut wif = [0u8; 38];
+            let mut wif = [0u8; 34];
             wif[0] = N::to_wif_prefix();
             wif[1..33].copy_from_slice(&secret_key.serialize());
 
             if compressed {
                 wif[33] = 0x01;
-                let sum = &checksum(&wif[0..34])[0..4];
-                wif[34..].copy_from_slice(sum);
-                wif.to_base58()
+                base58check::encode(&wif)
             } else {
-                let sum = &checksum(&wif[0..33])[0..4];
-                wif[33..37].copy_from_slice(sum);
-                wif[..37].to_base58()
+                base58check::encode(&wif[..33])
             }
         }
         write!(f, "{}", to_wif::<N>(&self.secret_key, self.compressed))
     }
 }
 
+impl<N: ZcashNetwork> Zeroize for P2PKHSpendingKey<N> {
+    /// Replaces the secret key with a placeholder, clearing the original secret key on drop.
+    fn zeroize(&mut self) {
+        self.secret_key = secp256k1::SecretKey::default();
+        self.compressed.zeroize();
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct P2SHSpendingKey {}
 
@@ -102,14 +107,17 @@ impl<N: ZcashNetwork> SproutSpendingKey<N> {
 
 impl<N: ZcashNetwork> Display for SproutSpendingKey<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut spending_key = [0u8; 38];
+        let mut spending_key = [0u8; 34];
         spending_key[0..2].copy_from_slice(&N::to_sprout_spending_key_prefix());
         spending_key[2..34].copy_from_slice(&self.spending_key);
 
-        let sum = &checksum(&spending_key[0..34])[0..4];
-        spending_key[34..].copy_from_slice(sum);
+        write!(f, "{}", base58check::encode(&spending_key))
+    }
+}
 
-        write!(f, "{}", spending_key.to_base58())
+impl<N: ZcashNetwork> Zeroize for SproutSpendingKey<N> {
+    fn zeroize(&mut self) {
+        self.spending_key.zeroize();
     }
 }
 
@@ -237,6 +245,15 @@ impl<N: ZcashNetwork> PartialEq for SaplingSpendingKey<N> {
 
 impl<N: ZcashNetwork> Eq for SaplingSpendingKey<N> {}
 
+impl<N: ZcashNetwork> Zeroize for SaplingSpendingKey<N> {
+    fn zeroize(&mut self) {
+        self.spending_key.zeroize();
+        self.ask = <Bls12 as JubjubEngine>::Fs::zero();
+        self.nsk = <Bls12 as JubjubEngine>::Fs::zero();
+        self.ovk.0.zeroize();
+    }
+}
+
 /// Represents a Zcash private key
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ZcashPrivateKey<N: ZcashNetwork> {
@@ -300,13 +317,7 @@ impl<N: ZcashNetwork> ZcashPrivateKey<N> {
             return Err(PrivateKeyError::InvalidCharacterLength(len));
         }
 
-        let expected = &data[len - 4..][0..4];
-        let checksum = &checksum(&data[0..len - 4])[0..4];
-        if *expected != *checksum {
-            let expected = expected.to_base58();
-            let found = checksum.to_base58();
-            return Err(PrivateKeyError::InvalidChecksum(expected, found));
-        }
+        base58check::verify(&data)?;
 
         Ok(ZcashPrivateKey::<N>::P2PKH(P2PKHSpendingKey::<N>::new(
             secp256k1::SecretKey::parse_slice(&data[1..33])?,
@@ -322,13 +333,7 @@ impl<N: ZcashNetwork> ZcashPrivateKey<N> {
             return Err(PrivateKeyError::InvalidByteLength(len));
         }
 
-        let expected = &data[len - 4..][0..4];
-        let checksum = &checksum(&data[0..len - 4])[0..4];
-        if *expected != *checksum {
-            let expected = expected.to_base58();
-            let found = checksum.to_base58();
-            return Err(PrivateKeyError::InvalidChecksum(expected, found));
-        }
+        base58check::verify(&data)?;
 
         let mut sk = [0u8; 32];
         sk.copy_from_slice(&data[2..34]);
@@ -420,6 +425,23 @@ impl<N: ZcashNetwork> Display for ZcashPrivateKey<N> {
     }
 }
 
+impl<N: ZcashNetwork> Zeroize for ZcashPrivateKey<N> {
+    fn zeroize(&mut self) {
+        match self {
+            ZcashPrivateKey::P2PKH(spending_key) => spending_key.zeroize(),
+            ZcashPrivateKey::P2SH(_) => {}
+            ZcashPrivateKey::Sprout(spending_key) => spending_key.zeroize(),
+            ZcashPrivateKey::Sapling(spending_key) => spending_key.zeroize(),
+        }
+    }
+}
+
+impl<N: ZcashNetwork> Drop for ZcashPrivateKey<N> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,7 @@
 use crate::address::ZcashAddress;
 use crate::format::ZcashFormat;
 use crate::librustzcash::algebra::curve::bls12_381::Bls12;
+use crate::librustzcash::algebra::field::{PrimeField, PrimeFieldRepr};
 use crate::librustzcash::sapling_crypto::{
     jubjub::{edwards, FixedGenerators, JubjubBls12, JubjubEngine, JubjubParams, Unknown},
     primitives::ViewingKey as SaplingViewingKey,
@@ -10,11 +11,11 @@ use crate::network::ZcashNetwork;
 use crate::private_key::{SaplingOutgoingViewingKey, SaplingSpendingKey, ZcashPrivateKey};
 use wagyu_model::no_std::{
     io::{self, Read, Write},
-    ToString, Vec,
+    Vec,
 };
-use wagyu_model::{crypto::checksum, Address, AddressError, PublicKey, PublicKeyError};
+use wagyu_model::{encoding::base58check, Address, AddressError, PublicKey, PublicKeyError};
 
-use base58::{FromBase58, ToBase58};
+use base58::ToBase58;
 use bech32::{Bech32, FromBase32, ToBase32};
 use core::{
     cmp::{Eq, PartialEq},
@@ -164,6 +165,29 @@ impl<N: ZcashNetwork> SaplingFullViewingKey<N> {
             .expect("should be able to serialize a FullViewingKey");
         result
     }
+
+    /// Returns the incoming viewing key of the full viewing key.
+    pub fn to_incoming_viewing_key(&self) -> SaplingIncomingViewingKey<N> {
+        let mut ivk = [0u8; 32];
+        self.vk
+            .ivk()
+            .into_repr()
+            .write_le(&mut ivk[..])
+            .expect("should be able to serialize an IncomingViewingKey");
+        SaplingIncomingViewingKey {
+            ivk,
+            _network: PhantomData,
+        }
+    }
+}
+
+impl<N: ZcashNetwork> Display for SaplingFullViewingKey<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match Bech32::new(N::to_sapling_viewing_key_prefix(), self.to_bytes().to_vec().to_base32()) {
+            Ok(key) => write!(f, "{}", key),
+            Err(_) => Err(fmt::Error),
+        }
+    }
 }
 
 impl<N: ZcashNetwork> PartialEq for SaplingFullViewingKey<N> {
@@ -187,6 +211,58 @@ impl<N: ZcashNetwork> Clone for SaplingFullViewingKey<N> {
     }
 }
 
+/// Represents a Sapling incoming viewing key, which detects and decrypts the incoming notes
+/// of the addresses of a full viewing key, without the ability to spend or view outgoing notes
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SaplingIncomingViewingKey<N: ZcashNetwork> {
+    pub(super) ivk: [u8; 32],
+    pub(super) _network: PhantomData<N>,
+}
+
+impl<N: ZcashNetwork> SaplingIncomingViewingKey<N> {
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.ivk
+    }
+}
+
+impl<N: ZcashNetwork> FromStr for SaplingIncomingViewingKey<N> {
+    type Err = PublicKeyError;
+
+    fn from_str(incoming_viewing_key: &str) -> Result<Self, Self::Err> {
+        let key = Bech32::from_str(incoming_viewing_key)?;
+        let prefix = key.hrp();
+        if prefix != N::to_sapling_incoming_viewing_key_prefix() {
+            return Err(PublicKeyError::InvalidPrefix(prefix.into()));
+        }
+
+        let data: Vec<u8> = FromBase32::from_base32(key.data())?;
+        if data.len() != 32 {
+            return Err(PublicKeyError::InvalidByteLength(data.len()));
+        }
+
+        // The five most significant bits are dropped from the hash, for it to be a Jubjub scalar
+        if data[31] & 0b1111_1000 != 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "ivk not a valid scalar").into());
+        }
+
+        let mut ivk = [0u8; 32];
+        ivk.copy_from_slice(&data);
+        Ok(Self {
+            ivk,
+            _network: PhantomData,
+        })
+    }
+}
+
+impl<N: ZcashNetwork> Display for SaplingIncomingViewingKey<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match Bech32::new(N::to_sapling_incoming_viewing_key_prefix(), self.ivk.to_base32()) {
+            Ok(key) => write!(f, "{}", key),
+            Err(_) => Err(fmt::Error),
+        }
+    }
+}
+
 /// Represents a Zcash public key
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ZcashPublicKey<N: ZcashNetwork> {
@@ -232,6 +308,16 @@ impl<N: ZcashNetwork> PublicKey for ZcashPublicKey<N> {
     }
 }
 
+impl<N: ZcashNetwork> ZcashPublicKey<N> {
+    /// Returns the incoming viewing key of a Sapling public key, and `None` otherwise.
+    pub fn to_incoming_viewing_key(&self) -> Option<SaplingIncomingViewingKey<N>> {
+        match self {
+            ZcashPublicKey::<N>::Sapling(sapling) => Some(sapling.to_incoming_viewing_key()),
+            _ => None,
+        }
+    }
+}
+
 impl<N: ZcashNetwork> FromStr for ZcashPublicKey<N> {
     type Err = PublicKeyError;
 
@@ -242,7 +328,7 @@ impl<N: ZcashNetwork> FromStr for ZcashPublicKey<N> {
                 compressed: public_key.len() == 66,
             })),
             97 => {
-                let data = public_key.from_base58()?;
+                let data = base58check::decode(public_key)?;
                 let prefix = &data[..3];
 
                 if prefix != N::to_sprout_viewing_key_prefix() {
@@ -294,23 +380,14 @@ impl<N: ZcashNetwork> Display for ZcashPublicKey<N> {
                 }
             }
             ZcashPublicKey::<N>::Sprout(sprout) => {
-                let mut data = [0u8; 71];
+                let mut data = [0u8; 67];
                 data[..3].copy_from_slice(&N::to_sprout_viewing_key_prefix());
                 data[3..35].copy_from_slice(&sprout.key_a);
                 data[35..67].copy_from_slice(&sprout.key_b);
 
-                let sum = &checksum(&data[0..67])[0..4];
-                data[67..].copy_from_slice(sum);
-
-                write!(f, "{}", data.to_base58())?
-            }
-            ZcashPublicKey::<N>::Sapling(sapling) => {
-                let key = sapling.to_bytes().to_vec();
-                match Bech32::new(N::to_sapling_viewing_key_prefix(), key.to_base32()) {
-                    Ok(key) => write!(f, "{}", key.to_string())?,
-                    Err(_) => return Err(fmt::Error),
-                }
+                write!(f, "{}", base58check::encode(&data))?
             }
+            ZcashPublicKey::<N>::Sapling(sapling) => write!(f, "{}", sapling)?,
             _ => (),
         }
         Ok(())
@@ -355,6 +432,17 @@ mod tests {
         assert_eq!(expected_public_key, public_key.to_string());
     }
 
+    fn test_to_incoming_viewing_key<N: ZcashNetwork>(expected_public_key: &str) {
+        let public_key = ZcashPublicKey::<N>::from_str(expected_public_key).unwrap();
+        let incoming_viewing_key = public_key.to_incoming_viewing_key().unwrap();
+        let encoded = incoming_viewing_key.to_string();
+        assert!(encoded.starts_with(&N::to_sapling_incoming_viewing_key_prefix()));
+        assert_eq!(
+            incoming_viewing_key,
+            SaplingIncomingViewingKey::<N>::from_str(&encoded).unwrap()
+        );
+    }
+
     mod p2pkh_mainnet_compressed {
         use super::*;
 
@@ -763,6 +851,13 @@ mod tests {
                 test_to_str(expected_public_key, &public_key);
             });
         }
+
+        #[test]
+        fn to_incoming_viewing_key() {
+            KEYPAIRS.iter().for_each(|(_, expected_public_key, _)| {
+                test_to_incoming_viewing_key::<N>(expected_public_key);
+            });
+        }
     }
 
     mod sapling_testnet {
@@ -838,6 +933,40 @@ mod tests {
                 test_to_str(expected_public_key, &public_key);
             });
         }
+
+        #[test]
+        fn to_incoming_viewing_key() {
+            KEYPAIRS.iter().for_each(|(_, expected_public_key, _)| {
+                test_to_incoming_viewing_key::<N>(expected_public_key);
+            });
+        }
+    }
+
+    #[test]
+    fn test_sapling_incoming_viewing_key() {
+        use crate::extended_private_key::ZcashExtendedPrivateKey;
+        use wagyu_model::ExtendedPrivateKey;
+
+        // From https://github.com/zcash-hackworks/zcash-test-vectors/blob/master/sapling_zip32.py
+        let seed = (0..32).collect::<Vec<u8>>();
+        let extended_private_key =
+            ZcashExtendedPrivateKey::<Mainnet>::new_master(&seed, &ZcashFormat::Sapling(None)).unwrap();
+        let public_key = extended_private_key.to_public_key();
+        assert_eq!(
+            "4847a130e799d3dbea36a1c16467d621fb2d80e30b3b1d1a426893415dad6601",
+            hex::encode(public_key.to_incoming_viewing_key().unwrap().to_bytes())
+        );
+
+        // Transparent public keys have no incoming viewing key
+        let public_key =
+            ZcashPublicKey::<Mainnet>::from_str("0310d63f8c2f0a6efd13ce8a77776de26eba1816f73aa73e73a4da3f2368fcc949")
+                .unwrap();
+        assert!(public_key.to_incoming_viewing_key().is_none());
+
+        // Invalid network prefix
+        let public_key = ZcashPublicKey::<Testnet>::from_str("zviewtestsapling1xh2u7cdrmr8nq7q3y6furqu6zsc8z7gq36jlpypgzrgrujs9mz73jsff72uzmm22juadyj4runvwfxssuqul2psxz6vp2ywk4zyv4rk2v65py6tkzt7rr7jwpy52c9z28yud27fmakss7agnu9dxl9ddsqjvgydw").unwrap();
+        let incoming_viewing_key = public_key.to_incoming_viewing_key().unwrap().to_string();
+        assert!(SaplingIncomingViewingKey::<Mainnet>::from_str(&incoming_viewing_key).is_err());
     }
 
     #[test]
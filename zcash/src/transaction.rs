@@ -5,13 +5,13 @@ use crate::format::ZcashFormat;
 use crate::librustzcash::zip32::prf_expand;
 use crate::network::ZcashNetwork;
 use crate::private_key::{SaplingOutgoingViewingKey, ZcashPrivateKey};
-use crate::public_key::ZcashPublicKey;
+use crate::public_key::{P2PKHViewingKey, ZcashPublicKey};
 use wagyu_model::no_std::{
     format,
     io::{self, BufReader, Read},
     vec, String, ToString, Vec,
 };
-use wagyu_model::{ExtendedPrivateKey, PrivateKey, Transaction, TransactionError, TransactionId};
+use wagyu_model::{ExtendedPrivateKey, PrivateKey, PublicKey, Transaction, TransactionError, TransactionId};
 
 use base58::FromBase58;
 use blake2b_simd::{Hash, Params, State};
@@ -41,6 +41,19 @@ use zcash_proofs::sapling::{SaplingProvingContext, SaplingVerificationContext};
 
 const GROTH_PROOF_SIZE: usize = 48 + 96 + 48; // π_A + π_B + π_C
 
+/// The flag of the transaction header of Overwinter and later transactions
+const OVERWINTERED_FLAG: u32 = 1 << 31;
+
+/// The version group id of Overwinter (v3) transactions
+pub const OVERWINTER_VERSION_GROUP_ID: u32 = 0x03C48270;
+/// The version group id of Sapling (v4) transactions
+pub const SAPLING_VERSION_GROUP_ID: u32 = 0x892F2085;
+
+/// The consensus branch id of the Overwinter network upgrade
+pub const OVERWINTER_BRANCH_ID: u32 = 0x5ba81b19;
+/// The consensus branch id of the Sapling network upgrade
+pub const SAPLING_BRANCH_ID: u32 = 0x76b809bb;
+
 /// Returns the variable length integer of the given value.
 /// https://en.bitcoin.it/wiki/Protocol_documentation#Variable_length_integer
 pub fn variable_length_integer(value: u64) -> Result<Vec<u8>, TransactionError> {
@@ -60,13 +73,13 @@ pub fn variable_length_integer(value: u64) -> Result<Vec<u8>, TransactionError>
 /// https://en.bitcoin.it/wiki/Protocol_documentation#Variable_length_integer
 pub fn read_variable_length_integer<R: Read>(mut reader: R) -> Result<usize, TransactionError> {
     let mut flag = [0u8; 1];
-    reader.read(&mut flag)?;
+    reader.read_exact(&mut flag)?;
 
     match flag[0] {
         0..=252 => Ok(flag[0] as usize),
         0xfd => {
             let mut size = [0u8; 2];
-            reader.read(&mut size)?;
+            reader.read_exact(&mut size)?;
             match u16::from_le_bytes(size) {
                 s if s < 253 => return Err(TransactionError::InvalidVariableSizeInteger(s as usize)),
                 s => Ok(s as usize),
@@ -74,7 +87,7 @@ pub fn read_variable_length_integer<R: Read>(mut reader: R) -> Result<usize, Tra
         }
         0xfe => {
             let mut size = [0u8; 4];
-            reader.read(&mut size)?;
+            reader.read_exact(&mut size)?;
             match u32::from_le_bytes(size) {
                 s if s < 65536 => return Err(TransactionError::InvalidVariableSizeInteger(s as usize)),
                 s => Ok(s as usize),
@@ -82,7 +95,7 @@ pub fn read_variable_length_integer<R: Read>(mut reader: R) -> Result<usize, Tra
         }
         _ => {
             let mut size = [0u8; 8];
-            reader.read(&mut size)?;
+            reader.read_exact(&mut size)?;
             match u64::from_le_bytes(size) {
                 s if s < 4294967296 => return Err(TransactionError::InvalidVariableSizeInteger(s as usize)),
                 s => Ok(s as usize),
@@ -216,21 +229,114 @@ pub fn create_script_pub_key<N: ZcashNetwork>(address: &ZcashAddress<N>) -> Resu
     }
 }
 
-/// Return the transaction header given a version
-fn fetch_header_and_version_group_id(version: &str) -> (u32, u32) {
+/// Represents the consensus branch of a Zcash network upgrade, whose id the signatures of a transaction commit to
+/// (ZIP-200), as a named preset, or as the raw id of an upgrade which has no preset yet
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ZcashConsensusBranch {
+    Overwinter,
+    Sapling,
+    Blossom,
+    Heartwood,
+    Canopy,
+    Nu5,
+    Nu6,
+    Custom(u32),
+}
+
+impl ZcashConsensusBranch {
+    /// The named presets, in the order of their activation
+    pub const PRESETS: [ZcashConsensusBranch; 7] = [
+        ZcashConsensusBranch::Overwinter,
+        ZcashConsensusBranch::Sapling,
+        ZcashConsensusBranch::Blossom,
+        ZcashConsensusBranch::Heartwood,
+        ZcashConsensusBranch::Canopy,
+        ZcashConsensusBranch::Nu5,
+        ZcashConsensusBranch::Nu6,
+    ];
+
+    /// Returns the preset of the latest network upgrade.
+    pub fn latest() -> Self {
+        ZcashConsensusBranch::Nu6
+    }
+
+    /// Returns the consensus branch id.
+    pub fn to_branch_id(&self) -> u32 {
+        match self {
+            ZcashConsensusBranch::Overwinter => OVERWINTER_BRANCH_ID,
+            ZcashConsensusBranch::Sapling => SAPLING_BRANCH_ID,
+            ZcashConsensusBranch::Blossom => 0x2bb40e60,
+            ZcashConsensusBranch::Heartwood => 0xf5b9230b,
+            ZcashConsensusBranch::Canopy => 0xe9ff75a6,
+            ZcashConsensusBranch::Nu5 => 0xc2d6d0b4,
+            ZcashConsensusBranch::Nu6 => 0xc8e71055,
+            ZcashConsensusBranch::Custom(consensus_branch_id) => *consensus_branch_id,
+        }
+    }
+
+    /// Returns the consensus branch of the given id, as its preset if it has one.
+    pub fn from_branch_id(consensus_branch_id: u32) -> Self {
+        Self::PRESETS
+            .iter()
+            .find(|branch| branch.to_branch_id() == consensus_branch_id)
+            .copied()
+            .unwrap_or(ZcashConsensusBranch::Custom(consensus_branch_id))
+    }
+}
+
+impl FromStr for ZcashConsensusBranch {
+    type Err = TransactionError;
+
+    /// Returns the consensus branch of the given preset name, or of the given id in hex, as `0xc8e71055`.
+    fn from_str(branch: &str) -> Result<Self, Self::Err> {
+        match branch.to_lowercase().as_str() {
+            "overwinter" => Ok(ZcashConsensusBranch::Overwinter),
+            "sapling" => Ok(ZcashConsensusBranch::Sapling),
+            "blossom" => Ok(ZcashConsensusBranch::Blossom),
+            "heartwood" => Ok(ZcashConsensusBranch::Heartwood),
+            "canopy" => Ok(ZcashConsensusBranch::Canopy),
+            "nu5" => Ok(ZcashConsensusBranch::Nu5),
+            "nu6" => Ok(ZcashConsensusBranch::Nu6),
+            id => {
+                let hex = id.trim_start_matches("0x");
+                match (hex.len(), u32::from_str_radix(hex, 16)) {
+                    (8, Ok(consensus_branch_id)) => Ok(Self::from_branch_id(consensus_branch_id)),
+                    _ => Err(TransactionError::InvalidConsensusBranch(branch.into())),
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for ZcashConsensusBranch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ZcashConsensusBranch::Overwinter => write!(f, "overwinter"),
+            ZcashConsensusBranch::Sapling => write!(f, "sapling"),
+            ZcashConsensusBranch::Blossom => write!(f, "blossom"),
+            ZcashConsensusBranch::Heartwood => write!(f, "heartwood"),
+            ZcashConsensusBranch::Canopy => write!(f, "canopy"),
+            ZcashConsensusBranch::Nu5 => write!(f, "nu5"),
+            ZcashConsensusBranch::Nu6 => write!(f, "nu6"),
+            ZcashConsensusBranch::Custom(consensus_branch_id) => write!(f, "{:#010x}", consensus_branch_id),
+        }
+    }
+}
+
+/// Returns the transaction header, version group id, and default consensus branch id of a version.
+/// Overwinter (v3) transactions are transparent-only, while Sapling (v4) transactions may also be shielded.
+fn fetch_header_and_version_group_id(version: &str) -> Result<(u32, u32, u32), TransactionError> {
     match version {
-        "sapling" => (2147483652, 0x892F2085),
-        // Zcash currently only supports sapling transactions
-        _ => unimplemented!(),
+        "overwinter" => Ok((OVERWINTERED_FLAG | 3, OVERWINTER_VERSION_GROUP_ID, OVERWINTER_BRANCH_ID)),
+        "sapling" => Ok((OVERWINTERED_FLAG | 4, SAPLING_VERSION_GROUP_ID, SAPLING_BRANCH_ID)),
+        _ => Err(TransactionError::UnsupportedTransactionVersion(version.into())),
     }
 }
 
-/// Returns a Blake256 hash of a given personalization, message, and optional Zcash version
-fn blake2_256_hash(personalization: &str, message: Vec<u8>, version: Option<&str>) -> Hash {
-    let personalization = match version {
-        Some("sapling") => [personalization.as_bytes(), &(0x76b809bb as u32).to_le_bytes()].concat(),
-        Some("overwinter") => [personalization.as_bytes(), &(0x5ba81b19 as u32).to_le_bytes()].concat(),
-        Some(_) => [personalization.as_bytes(), &(0x76b809bb as u32).to_le_bytes()].concat(),
+/// Returns a Blake256 hash of a given personalization, message, and optional consensus branch id
+fn blake2_256_hash(personalization: &str, message: Vec<u8>, consensus_branch_id: Option<u32>) -> Hash {
+    let personalization = match consensus_branch_id {
+        Some(consensus_branch_id) => [personalization.as_bytes(), &consensus_branch_id.to_le_bytes()].concat(),
         None => personalization.as_bytes().to_vec(),
     };
 
@@ -412,10 +518,10 @@ impl<N: ZcashNetwork> ZcashTransparentInput<N> {
     /// Read and output a Zcash transaction transparent input
     pub fn read<R: Read>(mut reader: &mut R) -> Result<Self, TransactionError> {
         let mut transaction_hash = [0u8; 32];
-        reader.read(&mut transaction_hash)?;
+        reader.read_exact(&mut transaction_hash)?;
 
         let mut vin = [0u8; 4];
-        reader.read(&mut vin)?;
+        reader.read_exact(&mut vin)?;
 
         let outpoint = Outpoint {
             reverse_transaction_id: transaction_hash.to_vec(),
@@ -428,17 +534,23 @@ impl<N: ZcashNetwork> ZcashTransparentInput<N> {
 
         let script: Vec<u8> = ZcashVector::read(&mut reader, |s| {
             let mut byte = [0u8; 1];
-            s.read(&mut byte)?;
+            s.read_exact(&mut byte)?;
             Ok(byte[0])
         })?;
 
         let mut sequence = [0u8; 4];
-        reader.read(&mut sequence)?;
+        reader.read_exact(&mut sequence)?;
 
-        let script_len = read_variable_length_integer(&script[..])?;
+        let script_len = match script.is_empty() {
+            true => 0,
+            false => read_variable_length_integer(&script[..])?,
+        };
         let sighash_code = SignatureHash::from_byte(&match script_len {
             0 => 0x01,
-            length => script[length],
+            length => match script.get(length) {
+                Some(sighash_code) => *sighash_code,
+                None => return Err(TransactionError::InvalidVariableSizeInteger(length)),
+            },
         });
 
         Ok(Self {
@@ -504,11 +616,11 @@ impl ZcashTransparentOutput {
     /// Read and output a Zcash transaction output
     pub fn read<R: Read>(mut reader: &mut R) -> Result<Self, TransactionError> {
         let mut amount = [0u8; 8];
-        reader.read(&mut amount)?;
+        reader.read_exact(&mut amount)?;
 
         let script_pub_key: Vec<u8> = ZcashVector::read(&mut reader, |s| {
             let mut byte = [0u8; 1];
-            s.read(&mut byte)?;
+            s.read_exact(&mut byte)?;
             Ok(byte[0])
         })?;
 
@@ -569,12 +681,12 @@ impl SaplingSpendDescription {
         let mut zk_proof = [0u8; 192];
         let mut spend_auth_sig = [0u8; 64];
 
-        reader.read(&mut cv)?;
-        reader.read(&mut anchor)?;
-        reader.read(&mut nullifier)?;
-        reader.read(&mut rk)?;
-        reader.read(&mut zk_proof)?;
-        reader.read(&mut spend_auth_sig)?;
+        reader.read_exact(&mut cv)?;
+        reader.read_exact(&mut anchor)?;
+        reader.read_exact(&mut nullifier)?;
+        reader.read_exact(&mut rk)?;
+        reader.read_exact(&mut zk_proof)?;
+        reader.read_exact(&mut spend_auth_sig)?;
 
         Ok(Self {
             cv,
@@ -777,12 +889,12 @@ impl SaplingOutputDescription {
         let mut out_ciphertext = [0u8; 80];
         let mut zk_proof = [0u8; 192];
 
-        reader.read(&mut cv)?;
-        reader.read(&mut cmu)?;
-        reader.read(&mut ephemeral_key)?;
-        reader.read(&mut enc_ciphertext)?;
-        reader.read(&mut out_ciphertext)?;
-        reader.read(&mut zk_proof)?;
+        reader.read_exact(&mut cv)?;
+        reader.read_exact(&mut cmu)?;
+        reader.read_exact(&mut ephemeral_key)?;
+        reader.read_exact(&mut enc_ciphertext)?;
+        reader.read_exact(&mut out_ciphertext)?;
+        reader.read_exact(&mut zk_proof)?;
 
         Ok(Self {
             cv,
@@ -975,10 +1087,14 @@ impl<N: ZcashNetwork> SaplingOutput<N> {
 /// Represents the Zcash transaction parameters
 #[derive(Debug, Clone)]
 pub struct ZcashTransactionParameters<N: ZcashNetwork> {
-    /// The header of the transaction (Overwintered flag and transaction version) (04000080 for Sapling)
+    /// The header of the transaction (Overwintered flag and transaction version)
+    /// (03000080 for Overwinter, 04000080 for Sapling)
     pub header: u32,
-    /// The version group ID (0x892F2085 for Sapling)
+    /// The version group ID (0x03C48270 for Overwinter, 0x892F2085 for Sapling)
     pub version_group_id: u32,
+    /// The consensus branch ID of the network upgrade the transaction is signed for,
+    /// which is not serialized, but committed to by the signatures of the transaction (ZIP-200)
+    pub consensus_branch_id: u32,
     /// The inputs for a transparent transaction, encoded as in Bitcoin.
     pub transparent_inputs: Vec<ZcashTransparentInput<N>>,
     /// The outputs for a transparent transaction, encoded as in Bitcoin,
@@ -1017,13 +1133,15 @@ pub struct ZcashTransactionParameters<N: ZcashNetwork> {
 }
 
 impl<N: ZcashNetwork> ZcashTransactionParameters<N> {
-    /// Returns the Zcash transaction parameters
+    /// Returns the Zcash transaction parameters of the given version, `overwinter` or `sapling`,
+    /// signed for the consensus branch of its network upgrade.
     pub fn new(version: &str, lock_time: u32, expiry_height: u32) -> Result<Self, TransactionError> {
-        let (header, version_group_id) = fetch_header_and_version_group_id(version);
+        let (header, version_group_id, consensus_branch_id) = fetch_header_and_version_group_id(version)?;
 
         Ok(Self {
             header,
             version_group_id,
+            consensus_branch_id,
             transparent_inputs: vec![],
             transparent_outputs: vec![],
             shielded_inputs: vec![],
@@ -1036,6 +1154,27 @@ impl<N: ZcashNetwork> ZcashTransactionParameters<N> {
         })
     }
 
+    /// Returns the transaction parameters signed for the given consensus branch id.
+    pub fn with_consensus_branch_id(mut self, consensus_branch_id: u32) -> Self {
+        self.consensus_branch_id = consensus_branch_id;
+        self
+    }
+
+    /// Returns the transaction parameters signed for the given consensus branch.
+    pub fn with_consensus_branch(self, branch: ZcashConsensusBranch) -> Self {
+        self.with_consensus_branch_id(branch.to_branch_id())
+    }
+
+    /// Returns the consensus branch the transaction is signed for.
+    pub fn consensus_branch(&self) -> ZcashConsensusBranch {
+        ZcashConsensusBranch::from_branch_id(self.consensus_branch_id)
+    }
+
+    /// Returns the version of the transaction, without the Overwintered flag.
+    pub fn version(&self) -> u32 {
+        self.header & !OVERWINTERED_FLAG
+    }
+
     /// Returns the transaction parameters with the given transparent input appended.
     pub fn add_transparent_input(
         &self,
@@ -1085,6 +1224,7 @@ impl<N: ZcashNetwork> ZcashTransactionParameters<N> {
         input_anchor: Fr,
         witness: MerklePath<Node>,
     ) -> Result<Self, TransactionError> {
+        self.check_shielded()?;
         let mut parameters = self.clone();
 
         // Verify all anchors are the same
@@ -1117,6 +1257,7 @@ impl<N: ZcashNetwork> ZcashTransactionParameters<N> {
         address: &ZcashAddress<N>,
         amount: ZcashAmount,
     ) -> Result<Self, TransactionError> {
+        self.check_shielded()?;
         let ovk = match ovk {
             Some(ovk) => ovk,
             None => {
@@ -1145,6 +1286,16 @@ impl<N: ZcashNetwork> ZcashTransactionParameters<N> {
         Ok(parameters)
     }
 
+    /// Checks the transaction may have Sapling spends and outputs, which Overwinter transactions may not.
+    fn check_shielded(&self) -> Result<(), TransactionError> {
+        match self.version() {
+            3 => Err(TransactionError::UnsupportedTransactionVersion(
+                "overwinter transactions are transparent-only".into(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
     /// Read and output the Zcash transaction parameters
     pub fn read<R: Read>(mut reader: R) -> Result<Self, TransactionError> {
         let mut header = [0u8; 4];
@@ -1154,31 +1305,53 @@ impl<N: ZcashNetwork> ZcashTransactionParameters<N> {
         let mut value_balance = [0u8; 8];
         let mut binding_sig = [0u8; 64];
 
-        reader.read(&mut header)?;
-        reader.read(&mut version_group_id)?;
+        reader.read_exact(&mut header)?;
+        reader.read_exact(&mut version_group_id)?;
+
+        // The version group id must match the version of the transaction. The consensus branch is not serialized,
+        // so a Sapling (v4) transaction defaults to the latest network upgrade, as it remains valid on it.
+        let (header, version_group_id) = (u32::from_le_bytes(header), u32::from_le_bytes(version_group_id));
+        let version = header & !OVERWINTERED_FLAG;
+        let consensus_branch_id = match (header & OVERWINTERED_FLAG != 0, version, version_group_id) {
+            (true, 3, OVERWINTER_VERSION_GROUP_ID) => OVERWINTER_BRANCH_ID,
+            (true, 4, SAPLING_VERSION_GROUP_ID) => ZcashConsensusBranch::latest().to_branch_id(),
+            (true, 3, _) | (true, 4, _) => {
+                return Err(TransactionError::InvalidVersionGroupId(version, version_group_id))
+            }
+            _ => return Err(TransactionError::UnsupportedTransactionVersion(version.to_string())),
+        };
 
         let transparent_inputs = ZcashVector::read(&mut reader, ZcashTransparentInput::<N>::read)?;
         let transparent_outputs = ZcashVector::read(&mut reader, ZcashTransparentOutput::read)?;
 
-        reader.read(&mut lock_time)?;
-        reader.read(&mut expiry_height)?;
-        reader.read(&mut value_balance)?;
+        reader.read_exact(&mut lock_time)?;
+        reader.read_exact(&mut expiry_height)?;
 
-        let shielded_inputs = ZcashVector::read(&mut reader, SaplingSpend::<N>::read)?;
-        let shielded_outputs = ZcashVector::read(&mut reader, SaplingOutput::<N>::read)?;
+        // Overwinter transactions end with their (empty) JoinSplits, without Sapling fields
+        let (shielded_inputs, shielded_outputs) = match version {
+            3 => (vec![], vec![]),
+            _ => {
+                reader.read_exact(&mut value_balance)?;
+                (
+                    ZcashVector::read(&mut reader, SaplingSpend::<N>::read)?,
+                    ZcashVector::read(&mut reader, SaplingOutput::<N>::read)?,
+                )
+            }
+        };
 
         if read_variable_length_integer(&mut reader)? > 0 {
             return Err(TransactionError::UnsupportedJoinsplits);
         }
 
-        let binding_signature = match reader.read(&mut binding_sig)? {
-            0 => None,
+        let binding_signature = match (version, reader.read(&mut binding_sig)?) {
+            (3, _) | (_, 0) => None,
             _ => Some(binding_sig.to_vec()),
         };
 
         Ok(Self {
-            header: u32::from_le_bytes(header),
-            version_group_id: u32::from_le_bytes(version_group_id),
+            header,
+            version_group_id,
+            consensus_branch_id,
             transparent_inputs,
             transparent_outputs,
             lock_time: u32::from_le_bytes(lock_time),
@@ -1214,6 +1387,7 @@ pub struct ZcashTransaction<N: ZcashNetwork> {
 
 impl<N: ZcashNetwork> Transaction for ZcashTransaction<N> {
     type Address = ZcashAddress<N>;
+    type Amount = ZcashAmount;
     type Format = ZcashFormat;
     type PrivateKey = ZcashPrivateKey<N>;
     type PublicKey = ZcashPublicKey<N>;
@@ -1241,39 +1415,26 @@ impl<N: ZcashNetwork> Transaction for ZcashTransaction<N> {
             {
                 // Transaction hash
                 let transaction_hash = match &address.format() {
-                    ZcashFormat::P2PKH => transaction.generate_sighash(Some(vin), input.sighash_code)?,
+                    ZcashFormat::P2PKH => transaction.to_sighash(vin)?,
                     _ => unimplemented!(),
                 };
 
                 // Signature
-                let mut signature = match &private_key {
+                let signature = match &private_key {
                     ZcashPrivateKey::<N>::P2PKH(p2pkh_spending_key) => {
                         let (signature, _) = secp256k1::sign(
-                            &secp256k1::Message::parse_slice(&transaction_hash.as_bytes())?,
+                            &secp256k1::Message::parse_slice(&transaction_hash)?,
                             &p2pkh_spending_key.to_secp256k1_secret_key(),
                         );
-                        signature.serialize_der().as_ref().to_vec()
+                        signature
                     }
                     _ => unimplemented!(),
                 };
-                signature.push((input.sighash_code as u32).to_le_bytes()[0]);
-                let signature = [variable_length_integer(signature.len() as u64)?, signature].concat();
 
                 // Public Viewing Key
-                let public_viewing_key = match private_key.to_public_key() {
-                    ZcashPublicKey::<N>::P2PKH(p2pkh_view_key) => match p2pkh_view_key.is_compressed() {
-                        true => p2pkh_view_key.to_secp256k1_public_key().serialize_compressed().to_vec(),
-                        false => p2pkh_view_key.to_secp256k1_public_key().serialize().to_vec(),
-                    },
-                    _ => unimplemented!(),
-                };
-                let public_viewing_key: Vec<u8> = [vec![public_viewing_key.len() as u8], public_viewing_key].concat();
-
-                match &address.format() {
-                    ZcashFormat::P2PKH => {
-                        transaction.parameters.transparent_inputs[vin].script =
-                            [signature.clone(), public_viewing_key].concat();
-                        transaction.parameters.transparent_inputs[vin].is_signed = true;
+                match private_key.to_public_key() {
+                    ZcashPublicKey::<N>::P2PKH(p2pkh_view_key) => {
+                        transaction.insert_signature(vin, &signature, &p2pkh_view_key)?
                     }
                     _ => unimplemented!(),
                 };
@@ -1282,6 +1443,49 @@ impl<N: ZcashNetwork> Transaction for ZcashTransaction<N> {
         Ok(transaction)
     }
 
+    /// Returns a transaction signed with a signature produced outside of the library, such as by a
+    /// hardware device, given the transparent public key of the signer. The signature is inserted into
+    /// each unsigned P2PKH input of the public key whose sighash it signs, or errors if it signs none of them.
+    fn sign_with_signature(&self, signature: &[u8], public_key: &Self::PublicKey) -> Result<Self, TransactionError> {
+        let p2pkh_view_key = match public_key {
+            ZcashPublicKey::<N>::P2PKH(p2pkh_view_key) => p2pkh_view_key,
+            _ => return Err(TransactionError::Message("expected a P2PKH public key".into())),
+        };
+        let mut signature = match signature.len() {
+            64 | 65 => secp256k1::Signature::parse_slice(&signature[..64])?,
+            length => {
+                return Err(TransactionError::Message(format!(
+                    "invalid signature length {}",
+                    length
+                )))
+            }
+        };
+        signature.normalize_s();
+
+        let mut transaction = self.clone();
+        let mut is_signed = false;
+        for (vin, input) in self.parameters.transparent_inputs.iter().enumerate() {
+            let address = match &input.outpoint.address {
+                Some(address) if address.format() == ZcashFormat::P2PKH => address,
+                _ => continue,
+            };
+
+            if address == &public_key.to_address(&address.format())? && !input.is_signed {
+                let message = secp256k1::Message::parse_slice(&transaction.to_sighash(vin)?)?;
+                if secp256k1::verify(&message, &signature, &p2pkh_view_key.to_secp256k1_public_key()) {
+                    transaction.insert_signature(vin, &signature, p2pkh_view_key)?;
+                    is_signed = true;
+                }
+            }
+        }
+        match is_signed {
+            true => Ok(transaction),
+            false => Err(TransactionError::Message(
+                "the signature does not sign an input of the public key".into(),
+            )),
+        }
+    }
+
     /// Returns a transaction given the transaction bytes.
     fn from_transaction_bytes(transaction: &Vec<u8>) -> Result<Self, TransactionError> {
         Ok(Self {
@@ -1311,6 +1515,13 @@ impl<N: ZcashNetwork> Transaction for ZcashTransaction<N> {
 
         transaction.extend(&self.parameters.lock_time.to_le_bytes());
         transaction.extend(&self.parameters.expiry_height.to_le_bytes());
+
+        // Overwinter transactions end with their (empty) JoinSplits, without Sapling fields
+        if self.parameters.version() == 3 {
+            transaction.push(0u8);
+            return Ok(transaction);
+        }
+
         transaction.extend(&self.parameters.value_balance.0.to_le_bytes());
 
         match &self.parameters.shielded_inputs.len() {
@@ -1347,6 +1558,22 @@ impl<N: ZcashNetwork> Transaction for ZcashTransaction<N> {
         Ok(transaction)
     }
 
+    /// Returns the sighash of the transparent input at the given index, with its signature hash type,
+    /// as ZIP-143 for Overwinter transactions, or ZIP-243 for Sapling transactions.
+    /// The spend authorization and binding signatures of Sapling sign `generate_sighash(None, SIGHASH_ALL)`.
+    fn to_sighash(&self, index: usize) -> Result<Vec<u8>, TransactionError> {
+        match self.parameters.transparent_inputs.get(index) {
+            Some(input) => Ok(self
+                .generate_sighash(Some(index), input.sighash_code)?
+                .as_bytes()
+                .to_vec()),
+            None => Err(TransactionError::InvalidInputs(format!(
+                "no transparent input at index {}",
+                index
+            ))),
+        }
+    }
+
     /// Returns the transaction id.
     fn to_transaction_id(&self) -> Result<Self::TransactionId, TransactionError> {
         let mut txid = Sha256::digest(&Sha256::digest(&self.to_transaction_bytes()?)).to_vec();
@@ -1485,8 +1712,32 @@ impl<N: ZcashNetwork> ZcashTransaction<N> {
         }
     }
 
-    /// Generate the sighash
-    /// https://github.com/zcash/zips/blob/master/zip-0243.rst
+    /// Inserts the signature of the P2PKH transparent input at the given index, by the given public key,
+    /// into its script with the signature hash type of the input.
+    fn insert_signature(
+        &mut self,
+        vin: usize,
+        signature: &secp256k1::Signature,
+        public_key: &P2PKHViewingKey,
+    ) -> Result<(), TransactionError> {
+        let mut signature = signature.serialize_der().as_ref().to_vec();
+        signature.push((self.parameters.transparent_inputs[vin].sighash_code as u32).to_le_bytes()[0]);
+        let signature = [variable_length_integer(signature.len() as u64)?, signature].concat();
+
+        let public_viewing_key = match public_key.is_compressed() {
+            true => public_key.to_secp256k1_public_key().serialize_compressed().to_vec(),
+            false => public_key.to_secp256k1_public_key().serialize().to_vec(),
+        };
+        let public_viewing_key: Vec<u8> = [vec![public_viewing_key.len() as u8], public_viewing_key].concat();
+
+        self.parameters.transparent_inputs[vin].script = [signature, public_viewing_key].concat();
+        self.parameters.transparent_inputs[vin].is_signed = true;
+        Ok(())
+    }
+
+    /// Generate the sighash for the consensus branch id of the transaction
+    /// https://github.com/zcash/zips/blob/master/zip-0143.rst (Overwinter)
+    /// https://github.com/zcash/zips/blob/master/zip-0243.rst (Sapling)
     pub fn generate_sighash(
         &self,
         input_index: Option<usize>,
@@ -1548,18 +1799,27 @@ impl<N: ZcashNetwork> ZcashTransaction<N> {
         preimage.extend(hash_sequence.as_bytes());
         preimage.extend(hash_outputs.as_bytes());
         preimage.extend(&hash_joinsplits);
-        preimage.extend(&hash_shielded_spends);
-        preimage.extend(&hash_shielded_outputs);
+        // The Sapling fields are committed to from Sapling (ZIP-243)
+        if self.parameters.version() != 3 {
+            preimage.extend(&hash_shielded_spends);
+            preimage.extend(&hash_shielded_outputs);
+        }
         preimage.extend(&self.parameters.lock_time.to_le_bytes());
         preimage.extend(&self.parameters.expiry_height.to_le_bytes());
-        preimage.extend(&self.parameters.value_balance.0.to_le_bytes());
+        if self.parameters.version() != 3 {
+            preimage.extend(&self.parameters.value_balance.0.to_le_bytes());
+        }
         preimage.extend(&(sighash_code as u32).to_le_bytes());
 
         if let Some(index) = input_index {
             preimage.extend(&self.parameters.transparent_inputs[index].serialize(false, true)?);
         };
 
-        Ok(blake2_256_hash("ZcashSigHash", preimage, Some("sapling")))
+        Ok(blake2_256_hash(
+            "ZcashSigHash",
+            preimage,
+            Some(self.parameters.consensus_branch_id),
+        ))
     }
 
     /// Update a transaction's input outpoint
@@ -1934,12 +2194,20 @@ mod tests {
 
         let unsigned_raw_transaction = hex::encode(&transaction.to_transaction_bytes().unwrap());
 
-        let mut new_transaction = ZcashTransaction::<N>::from_str(&unsigned_raw_transaction).unwrap();
+        // The consensus branch is not serialized, so the reconstructed transaction is signed for that of the vector
+        let consensus_branch_id = transaction.parameters.consensus_branch_id;
+        let reconstruct = |raw_transaction: &str| {
+            let mut transaction = ZcashTransaction::<N>::from_str(raw_transaction).unwrap();
+            transaction.parameters = transaction.parameters.with_consensus_branch_id(consensus_branch_id);
+            transaction
+        };
+
+        let mut new_transaction = reconstruct(&unsigned_raw_transaction);
 
         // Sign the transparent transaction inputs of the transaction reconstructed from hex
         for input in inputs {
             let partial_signed_transaction = hex::encode(&new_transaction.to_transaction_bytes().unwrap());
-            new_transaction = ZcashTransaction::<N>::from_str(&partial_signed_transaction).unwrap();
+            new_transaction = reconstruct(&partial_signed_transaction);
 
             let mut reverse_transaction_id = hex::decode(input.transaction_id).unwrap();
             reverse_transaction_id.reverse();
@@ -2903,6 +3171,156 @@ mod tests {
         }
     }
 
+    mod test_overwinter_transactions {
+        use super::*;
+        use zcash_primitives::{
+            consensus::BranchId, legacy::Script, transaction::signature_hash, transaction::Transaction as Zcash,
+        };
+        type N = Testnet;
+
+        const PRIVATE_KEY: &str = "cUacGttX6uipjEPinJv2BHuax2VNNpHGrf3psRABxtuAddpxLep7";
+        const TRANSACTION_IDS: [&str; 2] = [
+            "d9042195d9a1b65b2f1f79d68ceb1a5ea6459c9651a6ad4dc1f465824785c6a8",
+            "fc9a0c1e8e93bf7dbe7d7fd6f1bfcbc4fd6b7b7e8a1fbc2e0f3e55ad3c8b7b2e",
+        ];
+
+        /// Returns an unsigned transaction of the given version, spending two outputs of the private key.
+        fn transaction(version: &str) -> ZcashTransaction<N> {
+            let address = ZcashPrivateKey::<N>::from_str(PRIVATE_KEY)
+                .unwrap()
+                .to_address(&ZcashFormat::P2PKH)
+                .unwrap();
+            let mut parameters = ZcashTransactionParameters::<N>::new(version, 0, 500_000).unwrap();
+            for (index, transaction_id) in TRANSACTION_IDS.iter().enumerate() {
+                parameters = parameters
+                    .add_transparent_input(
+                        hex::decode(transaction_id).unwrap(),
+                        index as u32,
+                        Some(address.clone()),
+                        Some(ZcashAmount(100_000 * (index as i64 + 1))),
+                        None,
+                        None,
+                        None,
+                        SignatureHash::SIGHASH_ALL,
+                    )
+                    .unwrap();
+            }
+            let output = ZcashAddress::<N>::from_str("tmMVUvhGDFmCAUsXdeGLhftcPJzB8LQ7VrV").unwrap();
+            parameters = parameters
+                .add_transparent_output(&output, ZcashAmount(290_000))
+                .unwrap();
+            ZcashTransaction::<N>::new(&parameters).unwrap()
+        }
+
+        /// Asserts the sighashes of the inputs of the transaction are those of librustzcash.
+        fn assert_sighashes(transaction: &ZcashTransaction<N>, consensus_branch_id: BranchId) {
+            let zcash = Zcash::read(&transaction.to_transaction_bytes().unwrap()[..]).unwrap();
+            for (index, input) in transaction.parameters.transparent_inputs.iter().enumerate() {
+                let script_code = Script(input.outpoint.script_pub_key.clone().unwrap());
+                let amount = Amount::from_i64(input.outpoint.amount.unwrap().0).unwrap();
+                let expected = signature_hash(&zcash, consensus_branch_id, 1, Some((index, &script_code, amount)));
+                assert_eq!(expected, transaction.to_sighash(index).unwrap());
+            }
+        }
+
+        #[test]
+        fn test_overwinter_transaction() {
+            let transaction = transaction("overwinter");
+            let raw = hex::encode(transaction.to_transaction_bytes().unwrap());
+            assert!(raw.starts_with("030000807082c403"));
+            // The lock time, the expiry height, and no JoinSplits end an Overwinter transaction
+            assert!(raw.ends_with("0000000020a1070000"));
+            assert_sighashes(&transaction, BranchId::Overwinter);
+
+            let signed = transaction
+                .sign(&ZcashPrivateKey::from_str(PRIVATE_KEY).unwrap())
+                .unwrap();
+            assert!(signed.parameters.transparent_inputs.iter().all(|input| input.is_signed));
+            let bytes = signed.to_transaction_bytes().unwrap();
+            let reconstructed = ZcashTransaction::<N>::from_transaction_bytes(&bytes).unwrap();
+            assert_eq!(bytes, reconstructed.to_transaction_bytes().unwrap());
+            assert_eq!(OVERWINTER_BRANCH_ID, reconstructed.parameters.consensus_branch_id);
+            assert_eq!(
+                signed.to_transaction_id().unwrap().to_string(),
+                reconstructed.to_transaction_id().unwrap().to_string()
+            );
+        }
+
+        #[test]
+        fn test_consensus_branch_id() {
+            let transaction = transaction("sapling");
+            assert_sighashes(&transaction, BranchId::Sapling);
+
+            // The signatures of a transaction commit to its consensus branch id
+            let mut blossom = transaction.clone();
+            blossom.parameters = blossom.parameters.with_consensus_branch_id(0x2bb40e60);
+            assert_sighashes(&blossom, BranchId::Blossom);
+            assert_ne!(transaction.to_sighash(0).unwrap(), blossom.to_sighash(0).unwrap());
+
+            // A parsed Sapling transaction is signed for the latest network upgrade, unless a branch is specified
+            let bytes = transaction.to_transaction_bytes().unwrap();
+            let reconstructed = ZcashTransaction::<N>::from_transaction_bytes(&bytes).unwrap();
+            assert_eq!(
+                ZcashConsensusBranch::latest(),
+                reconstructed.parameters.consensus_branch()
+            );
+        }
+
+        #[test]
+        fn test_consensus_branch_presets() {
+            assert_eq!(
+                ZcashConsensusBranch::Nu5,
+                ZcashConsensusBranch::from_str("NU5").unwrap()
+            );
+            assert_eq!(0xc2d6d0b4, ZcashConsensusBranch::Nu5.to_branch_id());
+            // The raw id of a preset is the preset, and any other id is kept as it is
+            assert_eq!(
+                ZcashConsensusBranch::Canopy,
+                ZcashConsensusBranch::from_str("0xe9ff75a6").unwrap()
+            );
+            let custom = ZcashConsensusBranch::from_str("4dec4df0").unwrap();
+            assert_eq!(ZcashConsensusBranch::Custom(0x4dec4df0), custom);
+            assert_eq!("0x4dec4df0", custom.to_string());
+            for branch in ZcashConsensusBranch::PRESETS.iter() {
+                assert_eq!(*branch, ZcashConsensusBranch::from_str(&branch.to_string()).unwrap());
+            }
+            assert!(ZcashConsensusBranch::from_str("nu7").is_err());
+            assert!(ZcashConsensusBranch::from_str("0xc2d6d0").is_err());
+
+            let parameters = transaction("sapling")
+                .parameters
+                .with_consensus_branch(ZcashConsensusBranch::Heartwood);
+            assert_eq!(0xf5b9230b, parameters.consensus_branch_id);
+            assert_eq!(ZcashConsensusBranch::Heartwood, parameters.consensus_branch());
+        }
+
+        #[test]
+        fn test_invalid_versions() {
+            assert!(matches!(
+                ZcashTransactionParameters::<N>::new("sprout", 0, 0),
+                Err(TransactionError::UnsupportedTransactionVersion(_))
+            ));
+
+            // Overwinter transactions are transparent-only
+            let parameters = transaction("overwinter").parameters;
+            let address = ZcashAddress::<N>::from_str(
+                "ztestsapling1ml8v92nfl07t7tsncwf9x0upqgncljpcrs3c53esgjupkagfffk98ngwhdqcw5pc8v4r2wmx0lk",
+            )
+            .unwrap();
+            assert!(parameters.add_sapling_output(None, &address, ZcashAmount(1)).is_err());
+
+            // The version group id must match the version of the transaction
+            let mut bytes = transaction("overwinter").to_transaction_bytes().unwrap();
+            bytes[4..8].copy_from_slice(&SAPLING_VERSION_GROUP_ID.to_le_bytes());
+            assert!(matches!(
+                ZcashTransaction::<N>::from_transaction_bytes(&bytes),
+                Err(TransactionError::InvalidVersionGroupId(3, SAPLING_VERSION_GROUP_ID))
+            ));
+            bytes[..4].copy_from_slice(&2u32.to_le_bytes());
+            assert!(ZcashTransaction::<N>::from_transaction_bytes(&bytes).is_err());
+        }
+    }
+
     mod test_invalid_transparent_transactions {
         use super::*;
         type N = Mainnet;
@@ -0,0 +1,150 @@
+//! WebAssembly bindings for generating and importing Zcash wallets.
+//!
+//! Transaction signing is not exposed, as Sapling spends and outputs require
+//! the Groth16 proving parameters to be loaded in the host environment.
+
+use crate::format::ZcashFormat;
+use crate::network::{Mainnet, Regtest, Testnet, ZcashNetwork};
+use crate::private_key::ZcashPrivateKey;
+use wagyu_model::no_std::{String, ToString};
+use wagyu_model::{Network, OsWalletRng, PrivateKey, PublicKey};
+
+use core::{fmt::Display, str::FromStr};
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroize;
+
+/// Represents a Zcash wallet exposed to JavaScript
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct ZcashWallet {
+    private_key: String,
+    public_key: String,
+    address: String,
+    diversifier: Option<String>,
+}
+
+#[wasm_bindgen]
+impl ZcashWallet {
+    /// Returns a randomly-generated wallet for the given network and address format.
+    pub fn new(network: &str, format: &str) -> Result<ZcashWallet, JsValue> {
+        fn generate<N: ZcashNetwork>(format: &ZcashFormat) -> Result<ZcashWallet, JsValue> {
+            let rng = &mut OsWalletRng::new();
+            let private_key = match format {
+                ZcashFormat::Sprout => ZcashPrivateKey::<N>::new_sprout(rng),
+                ZcashFormat::Sapling(_) => ZcashPrivateKey::<N>::new_sapling(rng),
+                _ => ZcashPrivateKey::<N>::new_p2pkh(rng),
+            };
+            ZcashWallet::from_zcash_private_key(&private_key.map_err(to_error)?, format)
+        }
+
+        let format = to_format(format)?;
+        match network {
+            Mainnet::NAME => generate::<Mainnet>(&format),
+            Testnet::NAME => generate::<Testnet>(&format),
+            Regtest::NAME => generate::<Regtest>(&format),
+            _ => Err(to_error(format_args!("invalid network: {}", network))),
+        }
+    }
+
+    /// Returns the wallet of the given network and private key.
+    pub fn from_private_key(network: &str, private_key: &str) -> Result<ZcashWallet, JsValue> {
+        fn import<N: ZcashNetwork>(private_key: &str) -> Result<ZcashWallet, JsValue> {
+            let private_key = ZcashPrivateKey::<N>::from_str(private_key).map_err(to_error)?;
+            let format = match private_key {
+                ZcashPrivateKey::Sprout(_) => ZcashFormat::Sprout,
+                ZcashPrivateKey::Sapling(_) => ZcashFormat::Sapling(None),
+                _ => ZcashFormat::P2PKH,
+            };
+            ZcashWallet::from_zcash_private_key(&private_key, &format)
+        }
+
+        match network {
+            Mainnet::NAME => import::<Mainnet>(private_key),
+            Testnet::NAME => import::<Testnet>(private_key),
+            Regtest::NAME => import::<Regtest>(private_key),
+            _ => Err(to_error(format_args!("invalid network: {}", network))),
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn private_key(&self) -> String {
+        self.private_key.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn public_key(&self) -> String {
+        self.public_key.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn address(&self) -> String {
+        self.address.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn diversifier(&self) -> Option<String> {
+        self.diversifier.clone()
+    }
+}
+
+impl ZcashWallet {
+    fn from_zcash_private_key<N: ZcashNetwork>(
+        private_key: &ZcashPrivateKey<N>,
+        format: &ZcashFormat,
+    ) -> Result<Self, JsValue> {
+        let public_key = private_key.to_public_key();
+        let address = public_key.to_address(format).map_err(to_error)?;
+        Ok(Self {
+            private_key: private_key.to_string(),
+            public_key: public_key.to_string(),
+            address: address.to_string(),
+            diversifier: address.to_diversifier(),
+        })
+    }
+}
+
+impl Drop for ZcashWallet {
+    fn drop(&mut self) {
+        self.private_key.zeroize();
+    }
+}
+
+/// Returns the address format of the given name.
+fn to_format(format: &str) -> Result<ZcashFormat, JsValue> {
+    match format {
+        "transparent" | "p2pkh" => Ok(ZcashFormat::P2PKH),
+        "sprout" => Ok(ZcashFormat::Sprout),
+        "sapling" => Ok(ZcashFormat::Sapling(None)),
+        _ => Err(to_error(format_args!("invalid format: {}", format))),
+    }
+}
+
+/// Returns a JavaScript error with the message of the given error.
+fn to_error<E: Display>(error: E) -> JsValue {
+    JsValue::from_str(&error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_private_key() {
+        let wallet =
+            ZcashWallet::from_private_key("mainnet", "L3a3yRcYATnZQt7ams14Pe5KCyRzrrCSejDyeQzHXGntToffVH4g").unwrap();
+        assert_eq!(
+            "0310d63f8c2f0a6efd13ce8a77776de26eba1816f73aa73e73a4da3f2368fcc949",
+            wallet.public_key()
+        );
+        assert_eq!("t1JwBjJWgNQVqWxGha2RsPZMhVGgfRg2pod", wallet.address());
+        assert_eq!(None, wallet.diversifier());
+    }
+
+    #[test]
+    fn new() {
+        let wallet = ZcashWallet::new("testnet", "sapling").unwrap();
+        let imported = ZcashWallet::from_private_key("testnet", &wallet.private_key()).unwrap();
+        assert_eq!(wallet.public_key(), imported.public_key());
+        assert!(wallet.diversifier().is_some());
+    }
+}